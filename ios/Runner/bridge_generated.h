@@ -14,171 +14,2173 @@ void store_dart_post_cobject(DartPostCObjectFnType ptr);
 // EXTRA END
 typedef struct _Dart_Handle* Dart_Handle;
 
+/**
+ * Handle of the node started with `start_node`
+ */
+#define DEFAULT_NODE_HANDLE 0
+
+/**
+ * How long a proof stays trusted before the peer is challenged again
+ */
+#define VERIFIED_BINDING_TTL_MS ((60 * 60) * 1000)
+
+/**
+ * Shortest accepted backup passphrase
+ */
+#define MIN_PASSPHRASE_LENGTH 8
+
+/**
+ * Values larger than this are offloaded to the blob store
+ */
+#define OFFLOAD_THRESHOLD (64 * 1024)
+
+/**
+ * Most peers taken from a manifest
+ */
+#define MAX_MANIFEST_PEERS 32
+
+/**
+ * Default max gossip message size, iroh-gossip's own default
+ */
+#define DEFAULT_MAX_MESSAGE_SIZE 4096
+
+/**
+ * Smallest max message size chunks still fit in
+ */
+#define MIN_MAX_MESSAGE_SIZE 1024
+
+/**
+ * Most chunks one payload is split into
+ */
+#define MAX_CHUNKS 256
+
+/**
+ * Largest `max_ops_per_response`, keeping responses well under the gossip
+ * message size
+ */
+#define MAX_OPS_PER_RESPONSE_LIMIT 512
+
 #define ED25519_PUBLIC_KEY_LENGTH 32
 
-#define ED25519_SIGNATURE_LENGTH 64
+#define ED25519_SIGNATURE_LENGTH 64
+
+#define MAX_MESSAGE_LENGTH (1024 * 1024)
+
+#define MIN_TIMESTAMP_TOLERANCE 300
+
+#define MAX_TIMESTAMP_TOLERANCE 3600
+
+#define DB_KEY_LENGTH 32
+
+#define XNONCE_LENGTH 24
+
+/**
+ * Most payloads remembered per topic; the oldest are forgotten first
+ */
+#define DEDUP_CAPACITY 4096
+
+/**
+ * Most peers queued at once; the least important are dropped beyond that
+ */
+#define MAX_QUEUED_DIALS 256
+
+/**
+ * Peers measured below this latency count as nearby
+ */
+#define NEARBY_LATENCY_MS 150
+
+/**
+ * How long before a peer is considered expired (no announcement)
+ */
+#define PEER_EXPIRY_SECS 300
+
+/**
+ * How often to announce ourselves
+ */
+#define ANNOUNCE_INTERVAL_SECS 10
+
+/**
+ * Most peers remembered across restarts (the most recently seen are kept)
+ */
+#define MAX_KNOWN_PEERS 64
+
+/**
+ * Known peers not seen for this long are forgotten
+ */
+#define KNOWN_PEER_TTL_SECS ((7 * 24) * 3600)
+
+/**
+ * Announcements timestamped further than this from our clock are rejected,
+ * so captured announcements can't be replayed later
+ */
+#define ANNOUNCEMENT_MAX_SKEW_MS ((5 * 60) * 1000)
+
+/**
+ * Most nodes whose last announcement is remembered (least recently
+ * announced are forgotten first)
+ */
+#define MAX_ANNOUNCEMENT_CACHE 1024
+
+/**
+ * Peers whose dials are remembered; the least recently dialed go first
+ */
+#define MAX_DIAL_RECORDS 512
+
+/**
+ * Longest message text in bytes
+ */
+#define MAX_DM_LENGTH (64 * 1024)
+
+/**
+ * Events kept for replay; the oldest are dropped beyond that
+ */
+#define EVENT_HISTORY_CAPACITY 256
+
+/**
+ * Time to live of a command, in seconds
+ */
+#define DEFAULT_TTL_SECS 28800
+
+#define DEFAULT_GAS_LIMIT 2000
+
+#define DEFAULT_GAS_PRICE 0.0000001
+
+#define WRAPPING_KEY_LENGTH 32
+
+/**
+ * Records kept until `LogBuffer::set_capacity` changes it
+ */
+#define DEFAULT_CAPACITY 500
+
+/**
+ * Largest buffer that can be configured
+ */
+#define MAX_CAPACITY 50000
+
+/**
+ * Buckets (ranges of key hashes) a root commits to
+ */
+#define BUCKETS 16
+
+#define DEFAULT_MQTT_PORT 1883
+
+/**
+ * Operations kept in memory; the least recently used are dropped beyond that
+ */
+#define HOT_OPERATIONS 1024
+
+/**
+ * Battery level at or below which the node saves power (unless charging)
+ */
+#define LOW_BATTERY_PERCENT 20
+
+/**
+ * Longest status string
+ */
+#define MAX_STATUS_LENGTH 256
+
+/**
+ * Upper bound on entries returned by one query
+ */
+#define MAX_QUERY_LIMIT 1000
+
+/**
+ * Largest request or response accepted
+ */
+#define MAX_RPC_MESSAGE_SIZE (1024 * 1024)
+
+/**
+ * Shorter tokens are not indexed (except as a query prefix)
+ */
+#define MIN_TOKEN_CHARS 2
+
+/**
+ * Longer tokens are truncated
+ */
+#define MAX_TOKEN_CHARS 64
+
+/**
+ * Upper bound on hits returned by one search
+ */
+#define MAX_SEARCH_LIMIT 100
+
+/**
+ * Bump when tokenization changes so existing indexes are rebuilt on open
+ */
+#define SEARCH_INDEX_VERSION 1
+
+/**
+ * Snapshot encoding version
+ */
+#define SNAPSHOT_VERSION 1
+
+/**
+ * Full syncs with at least this many operations are answered with snapshots
+ */
+#define SNAPSHOT_MIN_OPS 1000
+
+/**
+ * Oldest change log entries are trimmed beyond this many
+ */
+#define MAX_CHANGE_LOG_ENTRIES 100000
+
+/**
+ * Oldest outbox messages are dropped beyond this many; peers still get
+ * those operations through sync
+ */
+#define MAX_OUTBOX_ENTRIES 10000
+
+/**
+ * Upper bound on entries returned by one scan call
+ */
+#define MAX_SCAN_LIMIT 1000
+
+/**
+ * Default operations per sync response (to avoid oversized payloads), see
+ * `NodeConfig::max_ops_per_response`
+ */
+#define MAX_OPS_PER_RESPONSE 128
+
+/**
+ * Default `OperationLimits::max_value_bytes`
+ */
+#define DEFAULT_MAX_VALUE_BYTES (512 * 1024)
+
+/**
+ * Default `OperationLimits::max_key_len`
+ */
+#define DEFAULT_MAX_KEY_LEN 1024
+
+/**
+ * Most direct addresses put in a ticket, to keep its QR code readable
+ */
+#define MAX_TICKET_ADDRS 6
+
+typedef struct wire_cst_list_prim_u_8_strict {
+  uint8_t *ptr;
+  int32_t len;
+} wire_cst_list_prim_u_8_strict;
+
+typedef struct wire_cst_list_prim_u_8_loose {
+  uint8_t *ptr;
+  int32_t len;
+} wire_cst_list_prim_u_8_loose;
+
+typedef struct wire_cst_log_filter_dto {
+  struct wire_cst_list_prim_u_8_strict *min_level;
+  struct wire_cst_list_prim_u_8_strict *target;
+  struct wire_cst_list_prim_u_8_strict *contains;
+  int64_t *since;
+  int64_t *until;
+  uint32_t *limit;
+} wire_cst_log_filter_dto;
+
+typedef struct wire_cst_list_String {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_String;
+
+typedef struct wire_cst_query_filter_dto {
+  struct wire_cst_list_prim_u_8_strict *field;
+  struct wire_cst_list_prim_u_8_strict *op;
+  struct wire_cst_list_prim_u_8_strict *value_json;
+} wire_cst_query_filter_dto;
+
+typedef struct wire_cst_list_query_filter_dto {
+  struct wire_cst_query_filter_dto *ptr;
+  int32_t len;
+} wire_cst_list_query_filter_dto;
+
+typedef struct wire_cst_query_spec_dto {
+  struct wire_cst_list_prim_u_8_strict *key_prefix;
+  struct wire_cst_list_query_filter_dto *filters;
+  struct wire_cst_list_prim_u_8_strict *sort_by;
+  bool descending;
+  uint32_t limit;
+  uint32_t offset;
+  struct wire_cst_list_String *fields;
+} wire_cst_query_spec_dto;
+
+typedef struct wire_cst_bandwidth_caps_dto {
+  uint64_t *daily_bytes;
+  uint64_t *monthly_bytes;
+  bool metered_only;
+} wire_cst_bandwidth_caps_dto;
+
+typedef struct wire_cst_device_conditions_dto {
+  uint8_t *battery_percent;
+  bool charging;
+  int32_t connection_type;
+  bool metered;
+} wire_cst_device_conditions_dto;
+
+typedef struct wire_cst_mqtt_topic_mapping_dto {
+  struct wire_cst_list_prim_u_8_strict *topic_prefix;
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  int32_t direction;
+} wire_cst_mqtt_topic_mapping_dto;
+
+typedef struct wire_cst_list_mqtt_topic_mapping_dto {
+  struct wire_cst_mqtt_topic_mapping_dto *ptr;
+  int32_t len;
+} wire_cst_list_mqtt_topic_mapping_dto;
+
+typedef struct wire_cst_mqtt_bridge_config_dto {
+  struct wire_cst_list_prim_u_8_strict *host;
+  uint16_t *port;
+  struct wire_cst_list_prim_u_8_strict *client_id;
+  struct wire_cst_list_prim_u_8_strict *username;
+  struct wire_cst_list_prim_u_8_strict *password;
+  struct wire_cst_list_mqtt_topic_mapping_dto *mappings;
+} wire_cst_mqtt_bridge_config_dto;
+
+typedef struct wire_cst_RelayConfigDto_Custom {
+  struct wire_cst_list_String *urls;
+} wire_cst_RelayConfigDto_Custom;
+
+typedef union RelayConfigDtoKind {
+  struct wire_cst_RelayConfigDto_Custom Custom;
+} RelayConfigDtoKind;
+
+typedef struct wire_cst_relay_config_dto {
+  int32_t tag;
+  union RelayConfigDtoKind kind;
+} wire_cst_relay_config_dto;
+
+typedef struct wire_cst_bootstrap_manifest_dto {
+  struct wire_cst_list_prim_u_8_strict *url;
+  struct wire_cst_list_prim_u_8_strict *publisher_key;
+} wire_cst_bootstrap_manifest_dto;
+
+typedef struct wire_cst_oplog_gc_dto {
+  uint32_t keep_versions;
+  uint64_t keep_for_secs;
+} wire_cst_oplog_gc_dto;
+
+typedef struct wire_cst_node_config_dto {
+  struct wire_cst_list_prim_u_8_strict *region;
+  struct wire_cst_list_prim_u_8_strict *network_id;
+  uint32_t max_known_peer_dials;
+  uint64_t announce_interval_secs;
+  uint64_t latency_sweep_interval_secs;
+  uint64_t pinned_peers_check_interval_secs;
+  uint64_t isolation_check_interval_secs;
+  uint32_t isolation_checks_before_reconnect;
+  uint32_t max_ops_per_response;
+  uint32_t max_connection_attempts_per_cycle;
+  uint64_t dial_interval_ms;
+  struct wire_cst_relay_config_dto relay;
+  struct wire_cst_bootstrap_manifest_dto *bootstrap_manifest;
+  uint32_t max_value_bytes;
+  uint32_t max_key_length;
+  struct wire_cst_list_String *allowed_store_types;
+  struct wire_cst_oplog_gc_dto *oplog_gc;
+  uint32_t max_gossip_message_size;
+} wire_cst_node_config_dto;
+
+typedef struct wire_cst_batch_entry_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+  struct wire_cst_list_prim_u_8_strict *public_key;
+  struct wire_cst_list_prim_u_8_strict *signature;
+} wire_cst_batch_entry_dto;
+
+typedef struct wire_cst_list_batch_entry_dto {
+  struct wire_cst_batch_entry_dto *ptr;
+  int32_t len;
+} wire_cst_list_batch_entry_dto;
+
+typedef struct wire_cst_file_offer_dto {
+  struct wire_cst_list_prim_u_8_strict *offer_id;
+  struct wire_cst_list_prim_u_8_strict *from;
+  struct wire_cst_list_prim_u_8_strict *name;
+  uint64_t size;
+  int64_t offered_at;
+} wire_cst_file_offer_dto;
+
+typedef struct wire_cst_node_info {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *public_key;
+  bool is_running;
+} wire_cst_node_info;
+
+typedef struct wire_cst_peer_path_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *path;
+  bool is_mixed;
+  struct wire_cst_list_String *direct_addrs;
+  struct wire_cst_list_prim_u_8_strict *relay_url;
+  int64_t updated_at;
+} wire_cst_peer_path_dto;
+
+typedef struct wire_cst_presence_dto {
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+  bool online;
+  struct wire_cst_list_prim_u_8_strict *status;
+  int64_t last_seen;
+} wire_cst_presence_dto;
+
+typedef struct wire_cst_successor_record_dto {
+  struct wire_cst_list_prim_u_8_strict *old_node_id;
+  struct wire_cst_list_prim_u_8_strict *new_node_id;
+  int64_t timestamp;
+  int64_t grace_until;
+} wire_cst_successor_record_dto;
+
+typedef struct wire_cst_bandwidth_usage_dto {
+  struct wire_cst_list_prim_u_8_strict *name;
+  int32_t subsystem;
+  uint64_t bytes_sent;
+  uint64_t bytes_received;
+} wire_cst_bandwidth_usage_dto;
+
+typedef struct wire_cst_list_bandwidth_usage_dto {
+  struct wire_cst_bandwidth_usage_dto *ptr;
+  int32_t len;
+} wire_cst_list_bandwidth_usage_dto;
+
+typedef struct wire_cst_conflict_dto {
+  struct wire_cst_list_prim_u_8_strict *conflict_key;
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *field;
+  struct wire_cst_list_prim_u_8_strict *kept_op_id;
+  struct wire_cst_list_prim_u_8_strict *discarded_op_id;
+  struct wire_cst_list_prim_u_8_strict *discarded_value;
+  int64_t discarded_timestamp;
+  int64_t resolved_at;
+} wire_cst_conflict_dto;
+
+typedef struct wire_cst_list_conflict_dto {
+  struct wire_cst_conflict_dto *ptr;
+  int32_t len;
+} wire_cst_list_conflict_dto;
+
+typedef struct wire_cst_db_entry_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+  struct wire_cst_list_prim_u_8_strict *value_bytes;
+} wire_cst_db_entry_dto;
+
+typedef struct wire_cst_list_db_entry_dto {
+  struct wire_cst_db_entry_dto *ptr;
+  int32_t len;
+} wire_cst_list_db_entry_dto;
+
+typedef struct wire_cst_direct_message_dto {
+  struct wire_cst_list_prim_u_8_strict *id;
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+  bool outgoing;
+  struct wire_cst_list_prim_u_8_strict *text;
+  int64_t sent_at;
+} wire_cst_direct_message_dto;
+
+typedef struct wire_cst_list_direct_message_dto {
+  struct wire_cst_direct_message_dto *ptr;
+  int32_t len;
+} wire_cst_list_direct_message_dto;
+
+typedef struct wire_cst_list_file_offer_dto {
+  struct wire_cst_file_offer_dto *ptr;
+  int32_t len;
+} wire_cst_list_file_offer_dto;
+
+typedef struct wire_cst_geo_match_dto {
+  struct wire_cst_list_prim_u_8_strict *member;
+  double latitude;
+  double longitude;
+  double distance_m;
+} wire_cst_geo_match_dto;
+
+typedef struct wire_cst_list_geo_match_dto {
+  struct wire_cst_geo_match_dto *ptr;
+  int32_t len;
+} wire_cst_list_geo_match_dto;
+
+typedef struct wire_cst_hash_entry_dto {
+  struct wire_cst_list_prim_u_8_strict *field;
+  struct wire_cst_list_prim_u_8_strict *value;
+} wire_cst_hash_entry_dto;
+
+typedef struct wire_cst_list_hash_entry_dto {
+  struct wire_cst_hash_entry_dto *ptr;
+  int32_t len;
+} wire_cst_list_hash_entry_dto;
+
+typedef struct wire_cst_integrity_issue_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *op_id;
+  struct wire_cst_list_prim_u_8_strict *kind;
+} wire_cst_integrity_issue_dto;
+
+typedef struct wire_cst_list_integrity_issue_dto {
+  struct wire_cst_integrity_issue_dto *ptr;
+  int32_t len;
+} wire_cst_list_integrity_issue_dto;
+
+typedef struct wire_cst_latency_bucket_dto {
+  double *le_ms;
+  uint64_t count;
+} wire_cst_latency_bucket_dto;
+
+typedef struct wire_cst_list_latency_bucket_dto {
+  struct wire_cst_latency_bucket_dto *ptr;
+  int32_t len;
+} wire_cst_list_latency_bucket_dto;
+
+typedef struct wire_cst_linked_device_dto {
+  struct wire_cst_list_prim_u_8_strict *user_public_key;
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *device_name;
+  int64_t linked_at;
+  bool this_device;
+} wire_cst_linked_device_dto;
+
+typedef struct wire_cst_list_linked_device_dto {
+  struct wire_cst_linked_device_dto *ptr;
+  int32_t len;
+} wire_cst_list_linked_device_dto;
+
+typedef struct wire_cst_log_entry {
+  int64_t timestamp;
+  struct wire_cst_list_prim_u_8_strict *level;
+  struct wire_cst_list_prim_u_8_strict *message;
+} wire_cst_log_entry;
+
+typedef struct wire_cst_list_log_entry {
+  struct wire_cst_log_entry *ptr;
+  int32_t len;
+} wire_cst_list_log_entry;
+
+typedef struct wire_cst_NodeEventDto_Started {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *public_key;
+} wire_cst_NodeEventDto_Started;
+
+typedef struct wire_cst_NodeEventDto_PeerConnected {
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+} wire_cst_NodeEventDto_PeerConnected;
+
+typedef struct wire_cst_NodeEventDto_PeerDisconnected {
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+} wire_cst_NodeEventDto_PeerDisconnected;
+
+typedef struct wire_cst_NodeEventDto_PeerDiscovered {
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+  struct wire_cst_list_prim_u_8_strict *address;
+} wire_cst_NodeEventDto_PeerDiscovered;
+
+typedef struct wire_cst_NodeEventDto_GossipReceived {
+  struct wire_cst_list_prim_u_8_strict *topic;
+  struct wire_cst_list_prim_u_8_strict *from;
+  struct wire_cst_list_prim_u_8_strict *content;
+  bool verified;
+} wire_cst_NodeEventDto_GossipReceived;
+
+typedef struct wire_cst_NodeEventDto_SyncReceived {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+} wire_cst_NodeEventDto_SyncReceived;
+
+typedef struct wire_cst_NodeEventDto_SyncProgress {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  uint64_t ops_received;
+  uint64_t ops_total_estimate;
+  uint64_t bytes;
+} wire_cst_NodeEventDto_SyncProgress;
+
+typedef struct wire_cst_NodeEventDto_ConflictResolved {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *kept_op_id;
+  struct wire_cst_list_prim_u_8_strict *discarded_op_id;
+} wire_cst_NodeEventDto_ConflictResolved;
+
+typedef struct wire_cst_NodeEventDto_LatencyMeasured {
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+  uint64_t latency_ms;
+} wire_cst_NodeEventDto_LatencyMeasured;
+
+typedef struct wire_cst_NodeEventDto_PresenceChanged {
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+  bool online;
+  struct wire_cst_list_prim_u_8_strict *status;
+} wire_cst_NodeEventDto_PresenceChanged;
+
+typedef struct wire_cst_NodeEventDto_TopicRecovered {
+  struct wire_cst_list_prim_u_8_strict *topic;
+} wire_cst_NodeEventDto_TopicRecovered;
+
+typedef struct wire_cst_NodeEventDto_RelayConnected {
+  struct wire_cst_list_prim_u_8_strict *relay_url;
+} wire_cst_NodeEventDto_RelayConnected;
+
+typedef struct wire_cst_NodeEventDto_BootstrapConnected {
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+} wire_cst_NodeEventDto_BootstrapConnected;
+
+typedef struct wire_cst_NodeEventDto_IdentityRotated {
+  struct wire_cst_list_prim_u_8_strict *old_node_id;
+  struct wire_cst_list_prim_u_8_strict *new_node_id;
+} wire_cst_NodeEventDto_IdentityRotated;
+
+typedef struct wire_cst_NodeEventDto_DeviceLinked {
+  struct wire_cst_list_prim_u_8_strict *user_public_key;
+  struct wire_cst_list_prim_u_8_strict *device_node_id;
+  struct wire_cst_list_prim_u_8_strict *device_name;
+} wire_cst_NodeEventDto_DeviceLinked;
+
+typedef struct wire_cst_NodeEventDto_ConnectivityChanged {
+  int32_t state;
+  struct wire_cst_list_prim_u_8_strict *reason;
+} wire_cst_NodeEventDto_ConnectivityChanged;
+
+typedef struct wire_cst_NodeEventDto_Error {
+  struct wire_cst_list_prim_u_8_strict *message;
+} wire_cst_NodeEventDto_Error;
+
+typedef union NodeEventDtoKind {
+  struct wire_cst_NodeEventDto_Started Started;
+  struct wire_cst_NodeEventDto_PeerConnected PeerConnected;
+  struct wire_cst_NodeEventDto_PeerDisconnected PeerDisconnected;
+  struct wire_cst_NodeEventDto_PeerDiscovered PeerDiscovered;
+  struct wire_cst_NodeEventDto_GossipReceived GossipReceived;
+  struct wire_cst_NodeEventDto_SyncReceived SyncReceived;
+  struct wire_cst_NodeEventDto_SyncProgress SyncProgress;
+  struct wire_cst_NodeEventDto_ConflictResolved ConflictResolved;
+  struct wire_cst_NodeEventDto_LatencyMeasured LatencyMeasured;
+  struct wire_cst_NodeEventDto_PresenceChanged PresenceChanged;
+  struct wire_cst_NodeEventDto_TopicRecovered TopicRecovered;
+  struct wire_cst_NodeEventDto_RelayConnected RelayConnected;
+  struct wire_cst_NodeEventDto_BootstrapConnected BootstrapConnected;
+  struct wire_cst_NodeEventDto_IdentityRotated IdentityRotated;
+  struct wire_cst_NodeEventDto_DeviceLinked DeviceLinked;
+  struct wire_cst_NodeEventDto_ConnectivityChanged ConnectivityChanged;
+  struct wire_cst_NodeEventDto_Error Error;
+} NodeEventDtoKind;
+
+typedef struct wire_cst_node_event_dto {
+  int32_t tag;
+  union NodeEventDtoKind kind;
+} wire_cst_node_event_dto;
+
+typedef struct wire_cst_node_event_record_dto {
+  uint64_t seq;
+  int64_t timestamp;
+  struct wire_cst_node_event_dto event;
+} wire_cst_node_event_record_dto;
+
+typedef struct wire_cst_list_node_event_record_dto {
+  struct wire_cst_node_event_record_dto *ptr;
+  int32_t len;
+} wire_cst_list_node_event_record_dto;
+
+typedef struct wire_cst_list_opt_list_prim_u_8_strict {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_opt_list_prim_u_8_strict;
+
+typedef struct wire_cst_peer_circuit_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *state;
+  bool unreachable;
+  uint32_t consecutive_failures;
+  int64_t last_failure_at;
+  int64_t *retry_at;
+} wire_cst_peer_circuit_dto;
+
+typedef struct wire_cst_list_peer_circuit_dto {
+  struct wire_cst_peer_circuit_dto *ptr;
+  int32_t len;
+} wire_cst_list_peer_circuit_dto;
+
+typedef struct wire_cst_peer_diagnostics_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_peer_path_dto *path;
+  bool direct_established;
+  uint32_t dial_attempts;
+  uint32_t dial_failures;
+  int64_t *last_dial_at;
+  struct wire_cst_list_prim_u_8_strict *last_error;
+} wire_cst_peer_diagnostics_dto;
+
+typedef struct wire_cst_list_peer_diagnostics_dto {
+  struct wire_cst_peer_diagnostics_dto *ptr;
+  int32_t len;
+} wire_cst_list_peer_diagnostics_dto;
+
+typedef struct wire_cst_peer_info_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *public_key;
+  struct wire_cst_list_prim_u_8_strict *address;
+  struct wire_cst_list_prim_u_8_strict *region;
+  struct wire_cst_list_prim_u_8_strict *version;
+  uint64_t *latency_ms;
+  bool is_mobile;
+} wire_cst_peer_info_dto;
+
+typedef struct wire_cst_list_peer_info_dto {
+  struct wire_cst_peer_info_dto *ptr;
+  int32_t len;
+} wire_cst_list_peer_info_dto;
+
+typedef struct wire_cst_peer_latency_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  uint64_t *latency_ms;
+  double average_ms;
+  double jitter_ms;
+  uint32_t samples;
+} wire_cst_peer_latency_dto;
+
+typedef struct wire_cst_list_peer_latency_dto {
+  struct wire_cst_peer_latency_dto *ptr;
+  int32_t len;
+} wire_cst_list_peer_latency_dto;
+
+typedef struct wire_cst_list_peer_path_dto {
+  struct wire_cst_peer_path_dto *ptr;
+  int32_t len;
+} wire_cst_list_peer_path_dto;
+
+typedef struct wire_cst_peer_sync_status_dto {
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+  uint64_t ops_sent;
+  uint64_t ops_received;
+  uint64_t bytes_sent;
+  uint64_t bytes_received;
+  int64_t *last_request_at;
+  int64_t *last_response_at;
+  bool in_progress;
+  uint64_t ops_acked;
+  int64_t *last_ack_at;
+} wire_cst_peer_sync_status_dto;
+
+typedef struct wire_cst_list_peer_sync_status_dto {
+  struct wire_cst_peer_sync_status_dto *ptr;
+  int32_t len;
+} wire_cst_list_peer_sync_status_dto;
+
+typedef struct wire_cst_pinned_peer_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *address;
+} wire_cst_pinned_peer_dto;
+
+typedef struct wire_cst_list_pinned_peer_dto {
+  struct wire_cst_pinned_peer_dto *ptr;
+  int32_t len;
+} wire_cst_list_pinned_peer_dto;
+
+typedef struct wire_cst_list_prim_u_32_strict {
+  uint32_t *ptr;
+  int32_t len;
+} wire_cst_list_prim_u_32_strict;
+
+typedef struct wire_cst_rekeyed_database_dto {
+  struct wire_cst_list_prim_u_8_strict *old_name;
+  struct wire_cst_list_prim_u_8_strict *new_name;
+} wire_cst_rekeyed_database_dto;
+
+typedef struct wire_cst_list_rekeyed_database_dto {
+  struct wire_cst_rekeyed_database_dto *ptr;
+  int32_t len;
+} wire_cst_list_rekeyed_database_dto;
+
+typedef struct wire_cst_replica_check_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *publisher;
+  bool matches;
+  uint32_t mismatched_buckets;
+  int64_t checked_at;
+} wire_cst_replica_check_dto;
+
+typedef struct wire_cst_list_replica_check_dto {
+  struct wire_cst_replica_check_dto *ptr;
+  int32_t len;
+} wire_cst_list_replica_check_dto;
+
+typedef struct wire_cst_scored_member_dto {
+  struct wire_cst_list_prim_u_8_strict *member;
+  double score;
+} wire_cst_scored_member_dto;
+
+typedef struct wire_cst_list_scored_member_dto {
+  struct wire_cst_scored_member_dto *ptr;
+  int32_t len;
+} wire_cst_list_scored_member_dto;
+
+typedef struct wire_cst_time_point_dto {
+  int64_t timestamp;
+  double value;
+} wire_cst_time_point_dto;
+
+typedef struct wire_cst_list_time_point_dto {
+  struct wire_cst_time_point_dto *ptr;
+  int32_t len;
+} wire_cst_list_time_point_dto;
+
+typedef struct wire_cst_topic_health_dto {
+  struct wire_cst_list_prim_u_8_strict *topic;
+  bool subscribed;
+  uint32_t neighbors;
+} wire_cst_topic_health_dto;
+
+typedef struct wire_cst_list_topic_health_dto {
+  struct wire_cst_topic_health_dto *ptr;
+  int32_t len;
+} wire_cst_list_topic_health_dto;
+
+typedef struct wire_cst_topic_metrics_dto {
+  struct wire_cst_list_prim_u_8_strict *topic;
+  uint64_t messages_sent;
+  uint64_t messages_received;
+  uint64_t duplicates_dropped;
+  uint64_t bytes_sent;
+  uint64_t bytes_received;
+} wire_cst_topic_metrics_dto;
+
+typedef struct wire_cst_list_topic_metrics_dto {
+  struct wire_cst_topic_metrics_dto *ptr;
+  int32_t len;
+} wire_cst_list_topic_metrics_dto;
+
+typedef struct wire_cst_topic_stats_dto {
+  struct wire_cst_list_prim_u_8_strict *topic;
+  uint64_t messages_sent;
+  uint64_t messages_received;
+  uint64_t bytes_sent;
+  uint64_t bytes_received;
+  uint32_t neighbors;
+  int64_t *last_event_at;
+} wire_cst_topic_stats_dto;
+
+typedef struct wire_cst_list_topic_stats_dto {
+  struct wire_cst_topic_stats_dto *ptr;
+  int32_t len;
+} wire_cst_list_topic_stats_dto;
+
+typedef struct wire_cst_accepted_invite_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *owner;
+  bool can_write;
+  struct wire_cst_list_prim_u_8_strict *writer_secret_key;
+  bool encrypted;
+} wire_cst_accepted_invite_dto;
+
+typedef struct wire_cst_backup_info_dto {
+  int64_t created_at;
+  struct wire_cst_list_prim_u_8_strict *public_key;
+  uint32_t tree_count;
+  uint64_t entry_count;
+} wire_cst_backup_info_dto;
+
+typedef struct wire_cst_bandwidth_stats_dto {
+  struct wire_cst_list_bandwidth_usage_dto *topics;
+  struct wire_cst_list_bandwidth_usage_dto *subsystems;
+  uint64_t today_bytes;
+  uint64_t month_bytes;
+  struct wire_cst_bandwidth_caps_dto caps;
+  bool cap_exceeded;
+} wire_cst_bandwidth_stats_dto;
+
+typedef struct wire_cst_change_event_dto {
+  uint64_t seq;
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+  struct wire_cst_list_prim_u_8_strict *value_bytes;
+} wire_cst_change_event_dto;
+
+typedef struct wire_cst_checkpoint_dto {
+  int64_t *high_water_mark;
+  uint32_t known_peers_saved;
+  uint32_t outbox_depth;
+  bool flushed;
+  uint64_t duration_ms;
+} wire_cst_checkpoint_dto;
+
+typedef struct wire_cst_connection_diagnostics_dto {
+  struct wire_cst_list_String *local_addrs;
+  struct wire_cst_list_String *public_addrs;
+  struct wire_cst_list_prim_u_8_strict *relay_url;
+  struct wire_cst_list_prim_u_8_strict *nat_type;
+  struct wire_cst_list_peer_diagnostics_dto *peers;
+} wire_cst_connection_diagnostics_dto;
+
+typedef struct wire_cst_connection_result_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *path;
+  uint64_t handshake_ms;
+} wire_cst_connection_result_dto;
+
+typedef struct wire_cst_connectivity_dto {
+  int32_t state;
+  struct wire_cst_list_prim_u_8_strict *reason;
+} wire_cst_connectivity_dto;
+
+typedef struct wire_cst_db_sync_info_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  uint64_t op_count;
+  int64_t *last_local_write;
+  int64_t *last_remote_op;
+  int64_t *last_sync;
+} wire_cst_db_sync_info_dto;
+
+typedef struct wire_cst_FileEventDto_Offer {
+  struct wire_cst_file_offer_dto *field0;
+} wire_cst_FileEventDto_Offer;
+
+typedef struct wire_cst_FileEventDto_Progress {
+  struct wire_cst_list_prim_u_8_strict *offer_id;
+  uint64_t received_bytes;
+  uint64_t total_bytes;
+} wire_cst_FileEventDto_Progress;
+
+typedef struct wire_cst_FileEventDto_Retrying {
+  struct wire_cst_list_prim_u_8_strict *offer_id;
+  uint32_t attempt;
+  struct wire_cst_list_prim_u_8_strict *error;
+} wire_cst_FileEventDto_Retrying;
+
+typedef struct wire_cst_FileEventDto_Completed {
+  struct wire_cst_list_prim_u_8_strict *offer_id;
+  struct wire_cst_list_prim_u_8_strict *path;
+} wire_cst_FileEventDto_Completed;
+
+typedef struct wire_cst_FileEventDto_Failed {
+  struct wire_cst_list_prim_u_8_strict *offer_id;
+  struct wire_cst_list_prim_u_8_strict *error;
+} wire_cst_FileEventDto_Failed;
+
+typedef union FileEventDtoKind {
+  struct wire_cst_FileEventDto_Offer Offer;
+  struct wire_cst_FileEventDto_Progress Progress;
+  struct wire_cst_FileEventDto_Retrying Retrying;
+  struct wire_cst_FileEventDto_Completed Completed;
+  struct wire_cst_FileEventDto_Failed Failed;
+} FileEventDtoKind;
+
+typedef struct wire_cst_debug_server_dto {
+  struct wire_cst_list_prim_u_8_strict *address;
+  struct wire_cst_list_prim_u_8_strict *token;
+} wire_cst_debug_server_dto;
+
+typedef struct wire_cst_file_event_dto {
+  int32_t tag;
+  union FileEventDtoKind kind;
+} wire_cst_file_event_dto;
+
+typedef struct wire_cst_subsystem_health_dto {
+  int32_t status;
+  struct wire_cst_list_prim_u_8_strict *reason;
+} wire_cst_subsystem_health_dto;
+
+typedef struct wire_cst_health_report_dto {
+  struct wire_cst_subsystem_health_dto storage;
+  struct wire_cst_subsystem_health_dto gossip;
+  struct wire_cst_subsystem_health_dto sync;
+  struct wire_cst_subsystem_health_dto discovery;
+  struct wire_cst_subsystem_health_dto relay;
+  struct wire_cst_list_topic_health_dto *topics;
+  int64_t *last_flush_at;
+  int64_t *last_sync_at;
+  int64_t *last_announcement_at;
+} wire_cst_health_report_dto;
+
+typedef struct wire_cst_identity_rotation_dto {
+  struct wire_cst_node_info node;
+  struct wire_cst_successor_record_dto record;
+  struct wire_cst_list_rekeyed_database_dto *rekeyed_databases;
+  uint32_t rekeyed_operations;
+} wire_cst_identity_rotation_dto;
+
+typedef struct wire_cst_integrity_report_dto {
+  uint64_t checked;
+  struct wire_cst_list_integrity_issue_dto *issues;
+  uint64_t repaired;
+} wire_cst_integrity_report_dto;
+
+typedef struct wire_cst_kadena_transaction_dto {
+  struct wire_cst_list_prim_u_8_strict *hash;
+  struct wire_cst_list_prim_u_8_strict *sig;
+  struct wire_cst_list_prim_u_8_strict *cmd;
+  struct wire_cst_list_prim_u_8_strict *json;
+} wire_cst_kadena_transaction_dto;
+
+typedef struct wire_cst_key_change_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  bool deleted;
+} wire_cst_key_change_dto;
+
+typedef struct wire_cst_key_pair_dto {
+  struct wire_cst_list_prim_u_8_strict *public_key;
+  struct wire_cst_list_prim_u_8_strict *secret_key;
+} wire_cst_key_pair_dto;
+
+typedef struct wire_cst_metrics_dto {
+  struct wire_cst_list_topic_metrics_dto *topics;
+  uint64_t ops_applied;
+  uint64_t verify_failures;
+  uint64_t limit_violations;
+  struct wire_cst_list_latency_bucket_dto *connect_latency;
+  double connect_latency_sum_ms;
+  uint64_t storage_size_bytes;
+  uint64_t storage_keys;
+  uint64_t stored_operations;
+  uint64_t outbox_depth;
+} wire_cst_metrics_dto;
+
+typedef struct wire_cst_node_addr_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_String *direct_addresses;
+  struct wire_cst_list_String *relay_urls;
+} wire_cst_node_addr_dto;
+
+typedef struct wire_cst_node_instance_dto {
+  uint32_t handle;
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *public_key;
+} wire_cst_node_instance_dto;
+
+typedef struct wire_cst_node_stats_dto {
+  uint64_t uptime_seconds;
+  uint64_t total_operations;
+  uint64_t bytes_sent;
+  uint64_t bytes_received;
+  uint64_t connects;
+  uint64_t reconnects;
+  uint64_t lifetime_sessions;
+  uint64_t lifetime_uptime_seconds;
+  uint64_t lifetime_bytes_sent;
+  uint64_t lifetime_bytes_received;
+  uint64_t lifetime_connects;
+  uint64_t lifetime_reconnects;
+  uint64_t outbox_depth;
+} wire_cst_node_stats_dto;
+
+typedef struct wire_cst_node_status_dto {
+  bool is_running;
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  uint32_t connected_peers;
+  uint32_t discovered_peers;
+  uint64_t uptime_seconds;
+  uint64_t gossip_messages_received;
+  uint64_t storage_size_bytes;
+  uint64_t total_keys;
+  uint32_t sync_operations;
+  uint64_t latency_requests_sent;
+  uint64_t latency_responses_received;
+  struct wire_cst_list_topic_stats_dto *topics;
+} wire_cst_node_status_dto;
+
+typedef struct wire_cst_power_policy_dto {
+  bool low_power;
+  bool metered;
+  bool sync_deferred;
+  bool blob_transfers_allowed;
+} wire_cst_power_policy_dto;
+
+typedef struct wire_cst_query_result_dto {
+  struct wire_cst_list_db_entry_dto *entries;
+  bool has_more;
+} wire_cst_query_result_dto;
+
+typedef struct wire_cst_relay_status_dto {
+  struct wire_cst_relay_config_dto relay;
+  struct wire_cst_list_prim_u_8_strict *active_url;
+  uint64_t *rtt_ms;
+} wire_cst_relay_status_dto;
+
+typedef struct wire_cst_rpc_request_dto {
+  uint64_t request_id;
+  struct wire_cst_list_prim_u_8_strict *peer_id;
+  struct wire_cst_list_prim_u_8_strict *method;
+  struct wire_cst_list_prim_u_8_strict *payload;
+} wire_cst_rpc_request_dto;
+
+typedef struct wire_cst_scan_page_dto {
+  struct wire_cst_list_db_entry_dto *entries;
+  struct wire_cst_list_prim_u_8_strict *next_cursor;
+} wire_cst_scan_page_dto;
+
+typedef struct wire_cst_StartupStageDto_EndpointOnline {
+  bool relay_connected;
+} wire_cst_StartupStageDto_EndpointOnline;
+
+typedef struct wire_cst_StartupStageDto_BootstrapConnecting {
+  uint32_t attempt;
+} wire_cst_StartupStageDto_BootstrapConnecting;
+
+typedef union StartupStageDtoKind {
+  struct wire_cst_StartupStageDto_EndpointOnline EndpointOnline;
+  struct wire_cst_StartupStageDto_BootstrapConnecting BootstrapConnecting;
+} StartupStageDtoKind;
+
+typedef struct wire_cst_startup_stage_dto {
+  int32_t tag;
+  union StartupStageDtoKind kind;
+} wire_cst_startup_stage_dto;
+
+typedef struct wire_cst_sync_stats_dto {
+  uint64_t total_operations;
+  uint64_t ops_sent;
+  uint64_t ops_received;
+  uint64_t bytes_sent;
+  uint64_t bytes_received;
+  int64_t *last_sync_at;
+  struct wire_cst_list_peer_sync_status_dto *peers;
+} wire_cst_sync_stats_dto;
+
+typedef struct wire_cst_sync_wait_summary_dto {
+  bool synced;
+  uint32_t queued_ops;
+  uint32_t unacknowledged_ops;
+  uint64_t duration_ms;
+} wire_cst_sync_wait_summary_dto;
+
+typedef struct wire_cst_text_delta_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  uint64_t index;
+  uint64_t deleted;
+  struct wire_cst_list_prim_u_8_strict *inserted;
+} wire_cst_text_delta_dto;
+
+typedef struct wire_cst_topic_message_dto {
+  struct wire_cst_list_prim_u_8_strict *topic;
+  struct wire_cst_list_prim_u_8_strict *delivered_from;
+  struct wire_cst_list_prim_u_8_strict *content;
+} wire_cst_topic_message_dto;
+
+typedef struct wire_cst_value_update_dto {
+  struct wire_cst_list_prim_u_8_strict *db_name;
+  struct wire_cst_list_prim_u_8_strict *key;
+  struct wire_cst_list_prim_u_8_strict *value;
+  struct wire_cst_list_prim_u_8_strict *value_bytes;
+} wire_cst_value_update_dto;
+
+typedef struct wire_cst_verified_binding_dto {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+  struct wire_cst_list_prim_u_8_strict *public_key;
+  int64_t verified_at;
+} wire_cst_verified_binding_dto;
+
+typedef struct wire_cst_wake_sync_summary_dto {
+  bool started_node;
+  uint32_t pinned_peers_dialed;
+  uint32_t pinned_peers_connected;
+  bool synced;
+  uint64_t ops_received;
+  uint64_t ops_sent;
+  uint32_t outbox_sent;
+  uint32_t outbox_remaining;
+  uint64_t duration_ms;
+} wire_cst_wake_sync_summary_dto;
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__accept_db_invite(struct wire_cst_list_prim_u_8_strict *token);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__accept_db_invite_on(uint32_t handle,
+                                                                                       struct wire_cst_list_prim_u_8_strict *token);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__accept_file(int64_t port_,
+                                                               struct wire_cst_list_prim_u_8_strict *offer_id,
+                                                               struct wire_cst_list_prim_u_8_strict *dest_path);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__accept_file_on(int64_t port_,
+                                                                  uint32_t handle,
+                                                                  struct wire_cst_list_prim_u_8_strict *offer_id,
+                                                                  struct wire_cst_list_prim_u_8_strict *dest_path);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__add_log_entry(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *level,
+                                                                 struct wire_cst_list_prim_u_8_strict *message);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__blake3_hash(struct wire_cst_list_prim_u_8_loose *data);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__change_feed(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                               uint64_t since_seq,
+                                                                               struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__change_feed_on(uint32_t handle,
+                                                                                  struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                  uint64_t since_seq,
+                                                                                  struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__checkpoint(int64_t port_, uint64_t *budget_ms);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__checkpoint_on(int64_t port_,
+                                                                 uint32_t handle,
+                                                                 uint64_t *budget_ms);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__clear_logs(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__close_storage_read_only(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__compare_and_swap(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                    struct wire_cst_list_prim_u_8_strict *key,
+                                                                                    struct wire_cst_list_prim_u_8_strict *expected,
+                                                                                    struct wire_cst_list_prim_u_8_strict *new_value);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__compare_and_swap_on(uint32_t handle,
+                                                                                       struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                       struct wire_cst_list_prim_u_8_strict *key,
+                                                                                       struct wire_cst_list_prim_u_8_strict *expected,
+                                                                                       struct wire_cst_list_prim_u_8_strict *new_value);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__connect_peer(int64_t port_,
+                                                                struct wire_cst_list_prim_u_8_strict *node_id,
+                                                                struct wire_cst_list_prim_u_8_strict *address);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__connect_peer_on(int64_t port_,
+                                                                   uint32_t handle,
+                                                                   struct wire_cst_list_prim_u_8_strict *node_id,
+                                                                   struct wire_cst_list_prim_u_8_strict *address);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__create_db_invite(int64_t port_,
+                                                                    struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                    struct wire_cst_list_prim_u_8_strict *owner_secret_key,
+                                                                    bool can_write,
+                                                                    bool include_encryption_key,
+                                                                    uint64_t expires_in_secs);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__create_db_invite_on(int64_t port_,
+                                                                       uint32_t handle,
+                                                                       struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                       struct wire_cst_list_prim_u_8_strict *owner_secret_key,
+                                                                       bool can_write,
+                                                                       bool include_encryption_key,
+                                                                       uint64_t expires_in_secs);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__create_device_link_request(struct wire_cst_list_prim_u_8_strict *device_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__create_device_link_request_on(uint32_t handle,
+                                                                                                 struct wire_cst_list_prim_u_8_strict *device_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__create_encrypted_db(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__create_encrypted_db_on(uint32_t handle,
+                                                                                          struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__decline_file(struct wire_cst_list_prim_u_8_strict *offer_id);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__decline_file_on(uint32_t handle,
+                                                                                   struct wire_cst_list_prim_u_8_strict *offer_id);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__default_node_config(void);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__delete_data(int64_t port_,
+                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                               struct wire_cst_list_prim_u_8_strict *key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__delete_data_on(int64_t port_,
+                                                                  uint32_t handle,
+                                                                  struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                  struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__dm_stream(struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__dm_stream_on(uint32_t handle,
+                                                                                struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__drop_database(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                 struct wire_cst_list_prim_u_8_strict *owner_secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__drop_database_on(int64_t port_,
+                                                                    uint32_t handle,
+                                                                    struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                    struct wire_cst_list_prim_u_8_strict *owner_secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__edit_text(int64_t port_,
+                                                             struct wire_cst_list_prim_u_8_strict *db_name,
+                                                             struct wire_cst_list_prim_u_8_strict *key,
+                                                             uint64_t index,
+                                                             uint64_t delete_,
+                                                             struct wire_cst_list_prim_u_8_strict *insert,
+                                                             struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__edit_text_on(int64_t port_,
+                                                                uint32_t handle,
+                                                                struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                struct wire_cst_list_prim_u_8_strict *key,
+                                                                uint64_t index,
+                                                                uint64_t delete_,
+                                                                struct wire_cst_list_prim_u_8_strict *insert,
+                                                                struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__event_stream(uint64_t since_seq,
+                                                                                struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__event_stream_on(uint32_t handle,
+                                                                                   uint64_t since_seq,
+                                                                                   struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__export_backup(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *path,
+                                                                 struct wire_cst_list_prim_u_8_strict *passphrase);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__export_backup_on(int64_t port_,
+                                                                    uint32_t handle,
+                                                                    struct wire_cst_list_prim_u_8_strict *path,
+                                                                    struct wire_cst_list_prim_u_8_strict *passphrase);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__export_db_key(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__export_db_key_on(uint32_t handle,
+                                                                                    struct wire_cst_list_prim_u_8_strict *db_name);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__export_logs(int64_t port_,
+                                                               struct wire_cst_list_prim_u_8_strict *path);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__extract_name_from_db(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__file_event_stream(struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__file_event_stream_on(uint32_t handle,
+                                                                                        struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__follow_database(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__follow_database_on(uint32_t handle,
+                                                                                      struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__generate_db_name(struct wire_cst_list_prim_u_8_strict *name,
+                                                                                    struct wire_cst_list_prim_u_8_strict *public_key_hex);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__generate_keypair(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__generate_peer_id_from_secret_key(struct wire_cst_list_prim_u_8_strict *secret_key_hex);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__geo_add(int64_t port_,
+                                                           struct wire_cst_list_prim_u_8_strict *db_name,
+                                                           struct wire_cst_list_prim_u_8_strict *key,
+                                                           struct wire_cst_list_prim_u_8_strict *member,
+                                                           double latitude,
+                                                           double longitude,
+                                                           struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__geo_add_on(int64_t port_,
+                                                              uint32_t handle,
+                                                              struct wire_cst_list_prim_u_8_strict *db_name,
+                                                              struct wire_cst_list_prim_u_8_strict *key,
+                                                              struct wire_cst_list_prim_u_8_strict *member,
+                                                              double latitude,
+                                                              double longitude,
+                                                              struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__geo_radius(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                              struct wire_cst_list_prim_u_8_strict *key,
+                                                                              double latitude,
+                                                                              double longitude,
+                                                                              double radius_m);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__geo_radius_on(uint32_t handle,
+                                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                 struct wire_cst_list_prim_u_8_strict *key,
+                                                                                 double latitude,
+                                                                                 double longitude,
+                                                                                 double radius_m);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data(int64_t port_);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_on(int64_t port_, uint32_t handle);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_page(int64_t port_,
+                                                                     uint32_t limit,
+                                                                     struct wire_cst_list_prim_u_8_strict *cursor,
+                                                                     bool keys_only,
+                                                                     uint64_t *max_bytes);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_page_on(int64_t port_,
+                                                                        uint32_t handle,
+                                                                        uint32_t limit,
+                                                                        struct wire_cst_list_prim_u_8_strict *cursor,
+                                                                        bool keys_only,
+                                                                        uint64_t *max_bytes);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_all_entries(int64_t port_,
+                                                                   struct wire_cst_list_prim_u_8_strict *db_name);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_all_entries_on(int64_t port_,
+                                                                      uint32_t handle,
+                                                                      struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_bandwidth_stats(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_bandwidth_stats_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_connection_diagnostics(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_connection_diagnostics_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_connectivity_state(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_connectivity_state_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_counter(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                               struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_counter_on(uint32_t handle,
+                                                                                  struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                  struct wire_cst_list_prim_u_8_strict *key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_data(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *db_name,
+                                                            struct wire_cst_list_prim_u_8_strict *key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_data_on(int64_t port_,
+                                                               uint32_t handle,
+                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                               struct wire_cst_list_prim_u_8_strict *key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_database_root(int64_t port_,
+                                                                     struct wire_cst_list_prim_u_8_strict *db_name);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_database_root_on(int64_t port_,
+                                                                        uint32_t handle,
+                                                                        struct wire_cst_list_prim_u_8_strict *db_name);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_db_sync_info(int64_t port_,
+                                                                    struct wire_cst_list_prim_u_8_strict *db_name);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_db_sync_info_on(int64_t port_,
+                                                                       uint32_t handle,
+                                                                       struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_decrypted(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                 struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_decrypted_on(uint32_t handle,
+                                                                                    struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                    struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_dm_history(struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                                                  uint32_t limit);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_dm_history_on(uint32_t handle,
+                                                                                     struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                                                     uint32_t limit);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_entries_page(int64_t port_,
+                                                                    struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                    uint32_t limit,
+                                                                    struct wire_cst_list_prim_u_8_strict *cursor,
+                                                                    bool keys_only,
+                                                                    uint64_t *max_bytes);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_entries_page_on(int64_t port_,
+                                                                       uint32_t handle,
+                                                                       struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                       uint32_t limit,
+                                                                       struct wire_cst_list_prim_u_8_strict *cursor,
+                                                                       bool keys_only,
+                                                                       uint64_t *max_bytes);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_followed_databases(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_followed_databases_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_log_levels_filter(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_logs(uint32_t *limit);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_logs_filtered(struct wire_cst_log_filter_dto *filter);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_many(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *db_name,
+                                                            struct wire_cst_list_String *keys);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_many_on(int64_t port_,
+                                                               uint32_t handle,
+                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                               struct wire_cst_list_String *keys);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_metrics(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_metrics_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_mqtt_bridge_config(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_mqtt_bridge_config_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_network_id(struct wire_cst_list_prim_u_8_strict *data_dir);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_addr(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_addr_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_config(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_config_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_info(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_info_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_stats(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_stats_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_status(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_status_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_ticket(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_ticket_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_circuits(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_circuits_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_paths(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_paths_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peers(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_filtered(struct wire_cst_list_String *capabilities,
+                                                                                      struct wire_cst_list_prim_u_8_strict *region,
+                                                                                      uint64_t *max_latency_ms);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_filtered_on(uint32_t handle,
+                                                                                         struct wire_cst_list_String *capabilities,
+                                                                                         struct wire_cst_list_prim_u_8_strict *region,
+                                                                                         uint64_t *max_latency_ms);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_pinned_peers(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_pinned_peers_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_power_mode(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_power_mode_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_power_policy(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_power_policy_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_presence(struct wire_cst_list_prim_u_8_strict *peer_id);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_presence_on(uint32_t handle,
+                                                                                   struct wire_cst_list_prim_u_8_strict *peer_id);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_ranked_peers(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_ranked_peers_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_recent_events(uint64_t since_seq);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_recent_events_on(uint32_t handle,
+                                                                                        uint64_t since_seq);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_relay_status(int64_t port_);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_relay_status_on(int64_t port_,
+                                                                       uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_successor(struct wire_cst_list_prim_u_8_strict *node_id);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_successor_on(uint32_t handle,
+                                                                                    struct wire_cst_list_prim_u_8_strict *node_id);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_sync_stats(int64_t port_);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__get_sync_stats_on(int64_t port_,
+                                                                     uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_text(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                            struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_text_on(uint32_t handle,
+                                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                               struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_writers(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_writers_on(uint32_t handle,
+                                                                                  struct wire_cst_list_prim_u_8_strict *db_name);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__go_online(int64_t port_,
+                                                             struct wire_cst_list_String *bootstrap_peers,
+                                                             struct wire_cst_list_prim_u_8_strict *region);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__go_online_on(int64_t port_,
+                                                                uint32_t handle,
+                                                                struct wire_cst_list_String *bootstrap_peers,
+                                                                struct wire_cst_list_prim_u_8_strict *region);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__grant_writer(int64_t port_,
+                                                                struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                struct wire_cst_list_prim_u_8_strict *writer_public_key,
+                                                                struct wire_cst_list_prim_u_8_strict *owner_secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__grant_writer_on(int64_t port_,
+                                                                   uint32_t handle,
+                                                                   struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                   struct wire_cst_list_prim_u_8_strict *writer_public_key,
+                                                                   struct wire_cst_list_prim_u_8_strict *owner_secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__graphql_execute(int64_t port_,
+                                                                   struct wire_cst_list_prim_u_8_strict *query,
+                                                                   struct wire_cst_list_prim_u_8_strict *variables);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__graphql_execute_on(int64_t port_,
+                                                                      uint32_t handle,
+                                                                      struct wire_cst_list_prim_u_8_strict *query,
+                                                                      struct wire_cst_list_prim_u_8_strict *variables);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__greet(struct wire_cst_list_prim_u_8_strict *name);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__hash_get(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *db_name,
+                                                            struct wire_cst_list_prim_u_8_strict *key,
+                                                            struct wire_cst_list_prim_u_8_strict *field);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_all(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_all_on(uint32_t handle,
+                                                                                   struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                   struct wire_cst_list_prim_u_8_strict *key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_on(int64_t port_,
+                                                               uint32_t handle,
+                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                               struct wire_cst_list_prim_u_8_strict *key,
+                                                               struct wire_cst_list_prim_u_8_strict *field);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__hash_set(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *db_name,
+                                                            struct wire_cst_list_prim_u_8_strict *key,
+                                                            struct wire_cst_list_prim_u_8_strict *field,
+                                                            struct wire_cst_list_prim_u_8_strict *value,
+                                                            struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__hash_set_on(int64_t port_,
+                                                               uint32_t handle,
+                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                               struct wire_cst_list_prim_u_8_strict *key,
+                                                               struct wire_cst_list_prim_u_8_strict *field,
+                                                               struct wire_cst_list_prim_u_8_strict *value,
+                                                               struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__health_check(int64_t port_);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__health_check_on(int64_t port_, uint32_t handle);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__import_backup(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                 struct wire_cst_list_prim_u_8_strict *path,
+                                                                 struct wire_cst_list_prim_u_8_strict *passphrase);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__import_db_key(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                 struct wire_cst_list_prim_u_8_strict *key_hex);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__import_db_key_on(uint32_t handle,
+                                                                                    struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                    struct wire_cst_list_prim_u_8_strict *key_hex);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__incr(int64_t port_,
+                                                        struct wire_cst_list_prim_u_8_strict *db_name,
+                                                        struct wire_cst_list_prim_u_8_strict *key,
+                                                        int64_t delta,
+                                                        struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__incr_on(int64_t port_,
+                                                           uint32_t handle,
+                                                           struct wire_cst_list_prim_u_8_strict *db_name,
+                                                           struct wire_cst_list_prim_u_8_strict *key,
+                                                           int64_t delta,
+                                                           struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__init_logging(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_database_local_only(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_database_local_only_on(uint32_t handle,
+                                                                                             struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_networking_paused(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_networking_paused_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_node_key_sealed(struct wire_cst_list_prim_u_8_strict *data_dir);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_node_offline(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_node_offline_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_node_running(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_verified_peer_key(struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                                                        struct wire_cst_list_prim_u_8_strict *public_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_verified_peer_key_on(uint32_t handle,
+                                                                                           struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                                                           struct wire_cst_list_prim_u_8_strict *public_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__kadena_account_from_public_key(struct wire_cst_list_prim_u_8_strict *public_key_hex);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__kadena_node_registration_command(struct wire_cst_list_prim_u_8_strict *network_id,
+                                                                                                    struct wire_cst_list_prim_u_8_strict *chain_id,
+                                                                                                    struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                                                                    struct wire_cst_list_prim_u_8_strict *multiaddr,
+                                                                                                    struct wire_cst_list_prim_u_8_strict *public_key_hex);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__kadena_sign_command(struct wire_cst_list_prim_u_8_strict *cmd,
+                                                                                       struct wire_cst_list_prim_u_8_strict *secret_key_hex);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__link_device(struct wire_cst_list_prim_u_8_strict *qr_payload,
+                                                                               struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__link_device_on(uint32_t handle,
+                                                                                  struct wire_cst_list_prim_u_8_strict *qr_payload,
+                                                                                  struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_conflicts(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_conflicts_on(uint32_t handle,
+                                                                                     struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_databases(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_databases_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_file_offers(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_file_offers_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_keys(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_keys_on(uint32_t handle,
+                                                                                struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_linked_devices(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_linked_devices_on(uint32_t handle);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_node_instances(void);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__list_push(int64_t port_,
+                                                             struct wire_cst_list_prim_u_8_strict *db_name,
+                                                             struct wire_cst_list_prim_u_8_strict *key,
+                                                             struct wire_cst_list_prim_u_8_strict *value,
+                                                             struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__list_push_on(int64_t port_,
+                                                                uint32_t handle,
+                                                                struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                struct wire_cst_list_prim_u_8_strict *key,
+                                                                struct wire_cst_list_prim_u_8_strict *value,
+                                                                struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_range(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                              struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_range_on(uint32_t handle,
+                                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                 struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_replica_checks(void);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_replica_checks_on(uint32_t handle);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__measure_latency(int64_t port_,
+                                                                   struct wire_cst_list_prim_u_8_strict *peer_id);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__measure_latency_on(int64_t port_,
+                                                                      uint32_t handle,
+                                                                      struct wire_cst_list_prim_u_8_strict *peer_id);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__open_storage_read_only(struct wire_cst_list_prim_u_8_strict *data_dir);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__pause_networking(int64_t port_);
 
-#define MAX_MESSAGE_LENGTH (1024 * 1024)
+void frbgen_cyberfly_mobile_node_wire__crate__api__pause_networking_on(int64_t port_,
+                                                                       uint32_t handle);
 
-#define MIN_TIMESTAMP_TOLERANCE 300
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__pin_peer(struct wire_cst_list_prim_u_8_strict *node_id,
+                                                                            struct wire_cst_list_prim_u_8_strict *address);
 
-#define MAX_TIMESTAMP_TOLERANCE 3600
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__pin_peer_on(uint32_t handle,
+                                                                               struct wire_cst_list_prim_u_8_strict *node_id,
+                                                                               struct wire_cst_list_prim_u_8_strict *address);
 
-/**
- * How long before a peer is considered expired (no announcement)
- */
-#define PEER_EXPIRY_SECS 300
+void frbgen_cyberfly_mobile_node_wire__crate__api__prove_ownership(int64_t port_,
+                                                                   struct wire_cst_list_prim_u_8_strict *peer_id);
 
-/**
- * How often to announce ourselves
- */
-#define ANNOUNCE_INTERVAL_SECS 10
+void frbgen_cyberfly_mobile_node_wire__crate__api__prove_ownership_on(int64_t port_,
+                                                                      uint32_t handle,
+                                                                      struct wire_cst_list_prim_u_8_strict *peer_id);
 
-typedef struct wire_cst_list_prim_u_8_strict {
-  uint8_t *ptr;
-  int32_t len;
-} wire_cst_list_prim_u_8_strict;
+void frbgen_cyberfly_mobile_node_wire__crate__api__publish_topic(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *name,
+                                                                 struct wire_cst_list_prim_u_8_loose *content);
 
-typedef struct wire_cst_list_String {
-  struct wire_cst_list_prim_u_8_strict **ptr;
-  int32_t len;
-} wire_cst_list_String;
+void frbgen_cyberfly_mobile_node_wire__crate__api__publish_topic_on(int64_t port_,
+                                                                    uint32_t handle,
+                                                                    struct wire_cst_list_prim_u_8_strict *name,
+                                                                    struct wire_cst_list_prim_u_8_loose *content);
 
-typedef struct wire_cst_list_prim_u_8_loose {
-  uint8_t *ptr;
-  int32_t len;
-} wire_cst_list_prim_u_8_loose;
+void frbgen_cyberfly_mobile_node_wire__crate__api__put_json(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *db_name,
+                                                            struct wire_cst_list_prim_u_8_strict *key,
+                                                            struct wire_cst_list_prim_u_8_strict *json,
+                                                            struct wire_cst_list_prim_u_8_strict *secret_key);
 
-typedef struct wire_cst_node_info {
-  struct wire_cst_list_prim_u_8_strict *node_id;
-  struct wire_cst_list_prim_u_8_strict *public_key;
-  bool is_running;
-} wire_cst_node_info;
+void frbgen_cyberfly_mobile_node_wire__crate__api__put_json_on(int64_t port_,
+                                                               uint32_t handle,
+                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                               struct wire_cst_list_prim_u_8_strict *key,
+                                                               struct wire_cst_list_prim_u_8_strict *json,
+                                                               struct wire_cst_list_prim_u_8_strict *secret_key);
 
-typedef struct wire_cst_db_entry_dto {
-  struct wire_cst_list_prim_u_8_strict *db_name;
-  struct wire_cst_list_prim_u_8_strict *key;
-  struct wire_cst_list_prim_u_8_strict *value;
-  struct wire_cst_list_prim_u_8_strict *value_bytes;
-} wire_cst_db_entry_dto;
+void frbgen_cyberfly_mobile_node_wire__crate__api__put_string(int64_t port_,
+                                                              struct wire_cst_list_prim_u_8_strict *db_name,
+                                                              struct wire_cst_list_prim_u_8_strict *key,
+                                                              struct wire_cst_list_prim_u_8_strict *value,
+                                                              struct wire_cst_list_prim_u_8_strict *secret_key);
 
-typedef struct wire_cst_list_db_entry_dto {
-  struct wire_cst_db_entry_dto *ptr;
-  int32_t len;
-} wire_cst_list_db_entry_dto;
+void frbgen_cyberfly_mobile_node_wire__crate__api__put_string_on(int64_t port_,
+                                                                 uint32_t handle,
+                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                 struct wire_cst_list_prim_u_8_strict *key,
+                                                                 struct wire_cst_list_prim_u_8_strict *value,
+                                                                 struct wire_cst_list_prim_u_8_strict *secret_key);
 
-typedef struct wire_cst_log_entry {
-  int64_t timestamp;
-  struct wire_cst_list_prim_u_8_strict *level;
-  struct wire_cst_list_prim_u_8_strict *message;
-} wire_cst_log_entry;
+void frbgen_cyberfly_mobile_node_wire__crate__api__query(int64_t port_,
+                                                         struct wire_cst_list_prim_u_8_strict *db_name,
+                                                         struct wire_cst_query_spec_dto *spec);
 
-typedef struct wire_cst_list_log_entry {
-  struct wire_cst_log_entry *ptr;
-  int32_t len;
-} wire_cst_list_log_entry;
+void frbgen_cyberfly_mobile_node_wire__crate__api__query_on(int64_t port_,
+                                                            uint32_t handle,
+                                                            struct wire_cst_list_prim_u_8_strict *db_name,
+                                                            struct wire_cst_query_spec_dto *spec);
 
-typedef struct wire_cst_peer_info_dto {
-  struct wire_cst_list_prim_u_8_strict *node_id;
-  struct wire_cst_list_prim_u_8_strict *public_key;
-  struct wire_cst_list_prim_u_8_strict *address;
-  struct wire_cst_list_prim_u_8_strict *region;
-  struct wire_cst_list_prim_u_8_strict *version;
-  uint64_t *latency_ms;
-  bool is_mobile;
-} wire_cst_peer_info_dto;
+void frbgen_cyberfly_mobile_node_wire__crate__api__read_only_export_backup(int64_t port_,
+                                                                           struct wire_cst_list_prim_u_8_strict *path,
+                                                                           struct wire_cst_list_prim_u_8_strict *passphrase,
+                                                                           struct wire_cst_list_prim_u_8_strict *wallet_secret_key);
 
-typedef struct wire_cst_list_peer_info_dto {
-  struct wire_cst_peer_info_dto *ptr;
-  int32_t len;
-} wire_cst_list_peer_info_dto;
+void frbgen_cyberfly_mobile_node_wire__crate__api__read_only_get_data(int64_t port_,
+                                                                      struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                      struct wire_cst_list_prim_u_8_strict *key);
 
-typedef struct wire_cst_key_pair_dto {
-  struct wire_cst_list_prim_u_8_strict *public_key;
-  struct wire_cst_list_prim_u_8_strict *secret_key;
-} wire_cst_key_pair_dto;
+void frbgen_cyberfly_mobile_node_wire__crate__api__read_only_get_entries_page(int64_t port_,
+                                                                              struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                              uint32_t limit,
+                                                                              struct wire_cst_list_prim_u_8_strict *cursor,
+                                                                              bool keys_only,
+                                                                              uint64_t *max_bytes);
 
-typedef struct wire_cst_node_status_dto {
-  bool is_running;
-  struct wire_cst_list_prim_u_8_strict *node_id;
-  uint32_t connected_peers;
-  uint32_t discovered_peers;
-  uint64_t uptime_seconds;
-  uint64_t gossip_messages_received;
-  uint64_t storage_size_bytes;
-  uint64_t total_keys;
-  uint32_t sync_operations;
-  uint64_t latency_requests_sent;
-  uint64_t latency_responses_received;
-} wire_cst_node_status_dto;
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__read_only_list_databases(void);
 
-void frbgen_cyberfly_mobile_node_wire__crate__api__add_log_entry(int64_t port_,
-                                                                 struct wire_cst_list_prim_u_8_strict *level,
-                                                                 struct wire_cst_list_prim_u_8_strict *message);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__reject_rpc(uint64_t request_id,
+                                                                              struct wire_cst_list_prim_u_8_strict *message);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__clear_logs(void);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__reject_rpc_on(uint32_t handle,
+                                                                                 uint64_t request_id,
+                                                                                 struct wire_cst_list_prim_u_8_strict *message);
 
-void frbgen_cyberfly_mobile_node_wire__crate__api__delete_data(int64_t port_,
-                                                               struct wire_cst_list_prim_u_8_strict *db_name,
-                                                               struct wire_cst_list_prim_u_8_strict *key);
+void frbgen_cyberfly_mobile_node_wire__crate__api__request_sync(int64_t port_,
+                                                                int64_t *since_timestamp);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__extract_name_from_db(struct wire_cst_list_prim_u_8_strict *db_name);
+void frbgen_cyberfly_mobile_node_wire__crate__api__request_sync_on(int64_t port_,
+                                                                   uint32_t handle,
+                                                                   int64_t *since_timestamp);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__generate_db_name(struct wire_cst_list_prim_u_8_strict *name,
-                                                                                    struct wire_cst_list_prim_u_8_strict *public_key_hex);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__reset_log_levels(void);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__generate_keypair(void);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__respond_rpc(uint64_t request_id,
+                                                                               struct wire_cst_list_prim_u_8_loose *payload);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__generate_peer_id_from_secret_key(struct wire_cst_list_prim_u_8_strict *secret_key_hex);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__respond_rpc_on(uint32_t handle,
+                                                                                  uint64_t request_id,
+                                                                                  struct wire_cst_list_prim_u_8_loose *payload);
 
-void frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data(int64_t port_);
+void frbgen_cyberfly_mobile_node_wire__crate__api__resume_networking(int64_t port_);
 
-void frbgen_cyberfly_mobile_node_wire__crate__api__get_all_entries(int64_t port_,
-                                                                   struct wire_cst_list_prim_u_8_strict *db_name);
+void frbgen_cyberfly_mobile_node_wire__crate__api__resume_networking_on(int64_t port_,
+                                                                        uint32_t handle);
 
-void frbgen_cyberfly_mobile_node_wire__crate__api__get_data(int64_t port_,
-                                                            struct wire_cst_list_prim_u_8_strict *db_name,
-                                                            struct wire_cst_list_prim_u_8_strict *key);
+void frbgen_cyberfly_mobile_node_wire__crate__api__revoke_writer(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                 struct wire_cst_list_prim_u_8_strict *writer_public_key,
+                                                                 struct wire_cst_list_prim_u_8_strict *owner_secret_key);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_logs(uint32_t *limit);
+void frbgen_cyberfly_mobile_node_wire__crate__api__revoke_writer_on(int64_t port_,
+                                                                    uint32_t handle,
+                                                                    struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                    struct wire_cst_list_prim_u_8_strict *writer_public_key,
+                                                                    struct wire_cst_list_prim_u_8_strict *owner_secret_key);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_info(void);
+void frbgen_cyberfly_mobile_node_wire__crate__api__rotate_identity(int64_t port_,
+                                                                   struct wire_cst_list_prim_u_8_strict *new_secret_key,
+                                                                   uint64_t *grace_secs);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_node_status(void);
+void frbgen_cyberfly_mobile_node_wire__crate__api__rotate_identity_on(int64_t port_,
+                                                                      uint32_t handle,
+                                                                      struct wire_cst_list_prim_u_8_strict *new_secret_key,
+                                                                      uint64_t *grace_secs);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__get_peers(void);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__rotate_key_wrapping_key(struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                                           struct wire_cst_list_prim_u_8_loose *old_wrapping_key,
+                                                                                           struct wire_cst_list_prim_u_8_loose *new_wrapping_key);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__greet(struct wire_cst_list_prim_u_8_strict *name);
+void frbgen_cyberfly_mobile_node_wire__crate__api__rpc_call(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                            struct wire_cst_list_prim_u_8_strict *method,
+                                                            struct wire_cst_list_prim_u_8_loose *payload,
+                                                            uint64_t *timeout_ms);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__init_logging(void);
+void frbgen_cyberfly_mobile_node_wire__crate__api__rpc_call_on(int64_t port_,
+                                                               uint32_t handle,
+                                                               struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                               struct wire_cst_list_prim_u_8_strict *method,
+                                                               struct wire_cst_list_prim_u_8_loose *payload,
+                                                               uint64_t *timeout_ms);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__is_node_running(void);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__scan_prefix(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                               struct wire_cst_list_prim_u_8_strict *prefix,
+                                                                               uint32_t limit,
+                                                                               struct wire_cst_list_prim_u_8_strict *cursor);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_databases(void);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__scan_prefix_on(uint32_t handle,
+                                                                                  struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                  struct wire_cst_list_prim_u_8_strict *prefix,
+                                                                                  uint32_t limit,
+                                                                                  struct wire_cst_list_prim_u_8_strict *cursor);
 
-WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__list_keys(struct wire_cst_list_prim_u_8_strict *db_name);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__scan_range(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                              struct wire_cst_list_prim_u_8_strict *start,
+                                                                              struct wire_cst_list_prim_u_8_strict *end,
+                                                                              uint32_t limit,
+                                                                              struct wire_cst_list_prim_u_8_strict *cursor);
 
-void frbgen_cyberfly_mobile_node_wire__crate__api__request_sync(int64_t port_,
-                                                                int64_t *since_timestamp);
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__scan_range_on(uint32_t handle,
+                                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                 struct wire_cst_list_prim_u_8_strict *start,
+                                                                                 struct wire_cst_list_prim_u_8_strict *end,
+                                                                                 uint32_t limit,
+                                                                                 struct wire_cst_list_prim_u_8_strict *cursor);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__seal_node_key(struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                                 struct wire_cst_list_prim_u_8_loose *wrapping_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__search(int64_t port_,
+                                                          struct wire_cst_list_prim_u_8_strict *db_name,
+                                                          struct wire_cst_list_prim_u_8_strict *query,
+                                                          uint32_t limit);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__search_on(int64_t port_,
+                                                             uint32_t handle,
+                                                             struct wire_cst_list_prim_u_8_strict *db_name,
+                                                             struct wire_cst_list_prim_u_8_strict *query,
+                                                             uint32_t limit);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__send_dm(int64_t port_,
+                                                           struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                           struct wire_cst_list_prim_u_8_strict *text);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__send_dm_on(int64_t port_,
+                                                              uint32_t handle,
+                                                              struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                              struct wire_cst_list_prim_u_8_strict *text);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__send_file(int64_t port_,
+                                                             struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                             struct wire_cst_list_prim_u_8_strict *path);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__send_file_on(int64_t port_,
+                                                                uint32_t handle,
+                                                                struct wire_cst_list_prim_u_8_strict *peer_id,
+                                                                struct wire_cst_list_prim_u_8_strict *path);
 
 void frbgen_cyberfly_mobile_node_wire__crate__api__send_gossip(int64_t port_,
                                                                struct wire_cst_list_prim_u_8_strict *topic,
                                                                struct wire_cst_list_prim_u_8_strict *message);
 
+void frbgen_cyberfly_mobile_node_wire__crate__api__send_gossip_on(int64_t port_,
+                                                                  uint32_t handle,
+                                                                  struct wire_cst_list_prim_u_8_strict *topic,
+                                                                  struct wire_cst_list_prim_u_8_strict *message);
+
 void frbgen_cyberfly_mobile_node_wire__crate__api__send_latency_request(int64_t port_,
                                                                         struct wire_cst_list_prim_u_8_strict *peer_id);
 
+void frbgen_cyberfly_mobile_node_wire__crate__api__send_latency_request_on(int64_t port_,
+                                                                           uint32_t handle,
+                                                                           struct wire_cst_list_prim_u_8_strict *peer_id);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__serve_rpc(struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__serve_rpc_on(uint32_t handle,
+                                                                                struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_add(int64_t port_,
+                                                           struct wire_cst_list_prim_u_8_strict *db_name,
+                                                           struct wire_cst_list_prim_u_8_strict *key,
+                                                           struct wire_cst_list_prim_u_8_strict *member,
+                                                           struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_add_on(int64_t port_,
+                                                              uint32_t handle,
+                                                              struct wire_cst_list_prim_u_8_strict *db_name,
+                                                              struct wire_cst_list_prim_u_8_strict *key,
+                                                              struct wire_cst_list_prim_u_8_strict *member,
+                                                              struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_bandwidth_caps(struct wire_cst_bandwidth_caps_dto *caps);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_bandwidth_caps_on(uint32_t handle,
+                                                                                         struct wire_cst_bandwidth_caps_dto *caps);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_database_local_only(int64_t port_,
+                                                                           struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                           bool local_only);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_database_local_only_on(int64_t port_,
+                                                                              uint32_t handle,
+                                                                              struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                              bool local_only);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_device_conditions(int64_t port_,
+                                                                         struct wire_cst_device_conditions_dto *conditions);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_device_conditions_on(int64_t port_,
+                                                                            uint32_t handle,
+                                                                            struct wire_cst_device_conditions_dto *conditions);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_key_wrapping_key(struct wire_cst_list_prim_u_8_loose *wrapping_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_log_buffer_size(uint32_t size);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_log_level(struct wire_cst_list_prim_u_8_strict *level);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_log_target_level(struct wire_cst_list_prim_u_8_strict *target,
+                                                                                        struct wire_cst_list_prim_u_8_strict *level);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_members(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                               struct wire_cst_list_prim_u_8_strict *key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_members_on(uint32_t handle,
+                                                                                  struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                  struct wire_cst_list_prim_u_8_strict *key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_network_passphrase(int64_t port_,
+                                                                          struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                          struct wire_cst_list_prim_u_8_strict *passphrase);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_power_mode(int64_t port_, int32_t mode);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__set_power_mode_on(int64_t port_,
+                                                                     uint32_t handle,
+                                                                     int32_t mode);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_presence_status(struct wire_cst_list_prim_u_8_strict *status);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_presence_status_on(uint32_t handle,
+                                                                                          struct wire_cst_list_prim_u_8_strict *status);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_retain_conflicts(bool enabled);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_retain_conflicts_on(uint32_t handle,
+                                                                                           bool enabled);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_selective_sync(bool selective);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__set_selective_sync_on(uint32_t handle,
+                                                                                         bool selective);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__sha256_hash(struct wire_cst_list_prim_u_8_loose *data);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__sign_bytes(struct wire_cst_list_prim_u_8_strict *secret_key_hex,
+                                                                              struct wire_cst_list_prim_u_8_loose *message);
+
 WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__sign_message_with_key(struct wire_cst_list_prim_u_8_strict *secret_key_hex,
                                                                                          struct wire_cst_list_prim_u_8_strict *message);
 
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_debug_server(int64_t port_,
+                                                                      struct wire_cst_list_prim_u_8_strict *bind_addr,
+                                                                      bool allow_writes);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_debug_server_on(int64_t port_,
+                                                                         uint32_t handle,
+                                                                         struct wire_cst_list_prim_u_8_strict *bind_addr,
+                                                                         bool allow_writes);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_metrics_server(int64_t port_,
+                                                                        struct wire_cst_list_prim_u_8_strict *bind_addr);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_metrics_server_on(int64_t port_,
+                                                                           uint32_t handle,
+                                                                           struct wire_cst_list_prim_u_8_strict *bind_addr);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_mqtt_bridge(int64_t port_,
+                                                                     struct wire_cst_mqtt_bridge_config_dto *config);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_mqtt_bridge_on(int64_t port_,
+                                                                        uint32_t handle,
+                                                                        struct wire_cst_mqtt_bridge_config_dto *config);
+
 void frbgen_cyberfly_mobile_node_wire__crate__api__start_node(int64_t port_,
                                                               struct wire_cst_list_prim_u_8_strict *data_dir,
                                                               struct wire_cst_list_prim_u_8_strict *wallet_secret_key,
                                                               struct wire_cst_list_String *bootstrap_peers,
                                                               struct wire_cst_list_prim_u_8_strict *region);
 
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_node_in_network(int64_t port_,
+                                                                         struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                         struct wire_cst_list_prim_u_8_strict *wallet_secret_key,
+                                                                         struct wire_cst_list_String *bootstrap_peers,
+                                                                         struct wire_cst_list_prim_u_8_strict *region,
+                                                                         struct wire_cst_list_prim_u_8_strict *network_id);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_node_instance(int64_t port_,
+                                                                       struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                       struct wire_cst_list_prim_u_8_strict *wallet_secret_key,
+                                                                       struct wire_cst_list_String *bootstrap_peers,
+                                                                       struct wire_cst_node_config_dto *config);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_node_with_config(int64_t port_,
+                                                                          struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                          struct wire_cst_list_prim_u_8_strict *wallet_secret_key,
+                                                                          struct wire_cst_list_String *bootstrap_peers,
+                                                                          struct wire_cst_node_config_dto *config);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_node_with_progress(int64_t port_,
+                                                                            struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                            struct wire_cst_list_prim_u_8_strict *wallet_secret_key,
+                                                                            struct wire_cst_list_String *bootstrap_peers,
+                                                                            struct wire_cst_node_config_dto *config,
+                                                                            struct wire_cst_list_prim_u_8_strict *progress);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__start_offline(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *data_dir);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__stop_mqtt_bridge(int64_t port_);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__stop_mqtt_bridge_on(int64_t port_,
+                                                                       uint32_t handle);
+
 void frbgen_cyberfly_mobile_node_wire__crate__api__stop_node(int64_t port_);
 
+void frbgen_cyberfly_mobile_node_wire__crate__api__stop_node_instance(int64_t port_,
+                                                                      uint32_t handle);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_batch(int64_t port_,
+                                                               struct wire_cst_list_batch_entry_dto *entries);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_batch_on(int64_t port_,
+                                                                  uint32_t handle,
+                                                                  struct wire_cst_list_batch_entry_dto *entries);
+
 void frbgen_cyberfly_mobile_node_wire__crate__api__store_data(int64_t port_,
                                                               struct wire_cst_list_prim_u_8_strict *db_name,
                                                               struct wire_cst_list_prim_u_8_strict *key,
@@ -186,13 +2188,149 @@ void frbgen_cyberfly_mobile_node_wire__crate__api__store_data(int64_t port_,
                                                               struct wire_cst_list_prim_u_8_strict *public_key,
                                                               struct wire_cst_list_prim_u_8_strict *signature);
 
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_idempotent(int64_t port_,
+                                                                         struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                         struct wire_cst_list_prim_u_8_strict *key,
+                                                                         struct wire_cst_list_prim_u_8_loose *value,
+                                                                         int64_t timestamp_ms,
+                                                                         struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_idempotent_on(int64_t port_,
+                                                                            uint32_t handle,
+                                                                            struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                            struct wire_cst_list_prim_u_8_strict *key,
+                                                                            struct wire_cst_list_prim_u_8_loose *value,
+                                                                            int64_t timestamp_ms,
+                                                                            struct wire_cst_list_prim_u_8_strict *secret_key);
+
 void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_local(int64_t port_,
                                                                     struct wire_cst_list_prim_u_8_strict *db_name,
                                                                     struct wire_cst_list_prim_u_8_strict *key,
                                                                     struct wire_cst_list_prim_u_8_loose *value);
 
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_local_on(int64_t port_,
+                                                                       uint32_t handle,
+                                                                       struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                       struct wire_cst_list_prim_u_8_strict *key,
+                                                                       struct wire_cst_list_prim_u_8_loose *value);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_on(int64_t port_,
+                                                                 uint32_t handle,
+                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                 struct wire_cst_list_prim_u_8_strict *key,
+                                                                 struct wire_cst_list_prim_u_8_loose *value,
+                                                                 struct wire_cst_list_prim_u_8_strict *public_key,
+                                                                 struct wire_cst_list_prim_u_8_strict *signature);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_signed(int64_t port_,
+                                                                     struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                     struct wire_cst_list_prim_u_8_strict *key,
+                                                                     struct wire_cst_list_prim_u_8_loose *value,
+                                                                     struct wire_cst_list_prim_u_8_strict *store_type);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_signed_on(int64_t port_,
+                                                                        uint32_t handle,
+                                                                        struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                        struct wire_cst_list_prim_u_8_strict *key,
+                                                                        struct wire_cst_list_prim_u_8_loose *value,
+                                                                        struct wire_cst_list_prim_u_8_strict *store_type);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_with_ttl(int64_t port_,
+                                                                       struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                       struct wire_cst_list_prim_u_8_strict *key,
+                                                                       struct wire_cst_list_prim_u_8_loose *value,
+                                                                       uint64_t ttl_secs,
+                                                                       struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_data_with_ttl_on(int64_t port_,
+                                                                          uint32_t handle,
+                                                                          struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                          struct wire_cst_list_prim_u_8_strict *key,
+                                                                          struct wire_cst_list_prim_u_8_loose *value,
+                                                                          uint64_t ttl_secs,
+                                                                          struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_encrypted(int64_t port_,
+                                                                   struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                   struct wire_cst_list_prim_u_8_strict *key,
+                                                                   struct wire_cst_list_prim_u_8_loose *value,
+                                                                   struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__store_encrypted_on(int64_t port_,
+                                                                      uint32_t handle,
+                                                                      struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                      struct wire_cst_list_prim_u_8_strict *key,
+                                                                      struct wire_cst_list_prim_u_8_loose *value,
+                                                                      struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__subscribe_topic(int64_t port_,
+                                                                   struct wire_cst_list_prim_u_8_strict *name,
+                                                                   struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__subscribe_topic_on(int64_t port_,
+                                                                      uint32_t handle,
+                                                                      struct wire_cst_list_prim_u_8_strict *name,
+                                                                      struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__text_delta_stream(struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__text_delta_stream_on(uint32_t handle,
+                                                                                        struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__ts_add(int64_t port_,
+                                                          struct wire_cst_list_prim_u_8_strict *db_name,
+                                                          struct wire_cst_list_prim_u_8_strict *key,
+                                                          int64_t timestamp_ms,
+                                                          double value,
+                                                          struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__ts_add_on(int64_t port_,
+                                                             uint32_t handle,
+                                                             struct wire_cst_list_prim_u_8_strict *db_name,
+                                                             struct wire_cst_list_prim_u_8_strict *key,
+                                                             int64_t timestamp_ms,
+                                                             double value,
+                                                             struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__ts_range(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                            struct wire_cst_list_prim_u_8_strict *key,
+                                                                            int64_t from_ms,
+                                                                            int64_t to_ms);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__ts_range_on(uint32_t handle,
+                                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                               struct wire_cst_list_prim_u_8_strict *key,
+                                                                               int64_t from_ms,
+                                                                               int64_t to_ms);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__unfollow_database(struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__unfollow_database_on(uint32_t handle,
+                                                                                        struct wire_cst_list_prim_u_8_strict *db_name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__unpin_peer(struct wire_cst_list_prim_u_8_strict *node_id);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__unpin_peer_on(uint32_t handle,
+                                                                                 struct wire_cst_list_prim_u_8_strict *node_id);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__unsubscribe_topic(int64_t port_,
+                                                                     struct wire_cst_list_prim_u_8_strict *name);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__unsubscribe_topic_on(int64_t port_,
+                                                                        uint32_t handle,
+                                                                        struct wire_cst_list_prim_u_8_strict *name);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__update_config(struct wire_cst_node_config_dto *config);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__update_config_on(uint32_t handle,
+                                                                                    struct wire_cst_node_config_dto *config);
+
 WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__validate_timestamp(int64_t timestamp);
 
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__verify_bytes(struct wire_cst_list_prim_u_8_strict *public_key_hex,
+                                                                                struct wire_cst_list_prim_u_8_loose *message,
+                                                                                struct wire_cst_list_prim_u_8_strict *signature_hex);
+
 WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__verify_db_name(struct wire_cst_list_prim_u_8_strict *db_name,
                                                                                   struct wire_cst_list_prim_u_8_strict *public_key_hex);
 
@@ -200,67 +2338,565 @@ WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__verify_messag
                                                                                             struct wire_cst_list_prim_u_8_strict *message,
                                                                                             struct wire_cst_list_prim_u_8_strict *signature_hex);
 
+void frbgen_cyberfly_mobile_node_wire__crate__api__verify_storage(int64_t port_, bool repair);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__verify_storage_on(int64_t port_,
+                                                                     uint32_t handle,
+                                                                     bool repair);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__wait_for_sync(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                 uint64_t timeout_ms);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__wait_for_sync_on(int64_t port_,
+                                                                    uint32_t handle,
+                                                                    struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                    uint64_t timeout_ms);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__wake_and_sync(int64_t port_,
+                                                                 struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                 struct wire_cst_list_prim_u_8_strict *wallet_secret_key,
+                                                                 struct wire_cst_list_String *bootstrap_peers,
+                                                                 uint32_t max_duration_secs);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__wake_and_sync_on(int64_t port_,
+                                                                    uint32_t handle,
+                                                                    struct wire_cst_list_prim_u_8_strict *data_dir,
+                                                                    struct wire_cst_list_prim_u_8_strict *wallet_secret_key,
+                                                                    struct wire_cst_list_String *bootstrap_peers,
+                                                                    uint32_t max_duration_secs);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__watch_db(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                            struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__watch_db_on(uint32_t handle,
+                                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                               struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__watch_key(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                             struct wire_cst_list_prim_u_8_strict *key,
+                                                                             struct wire_cst_list_prim_u_8_strict *sink);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__watch_key_on(uint32_t handle,
+                                                                                struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                struct wire_cst_list_prim_u_8_strict *key,
+                                                                                struct wire_cst_list_prim_u_8_strict *sink);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__zset_add(int64_t port_,
+                                                            struct wire_cst_list_prim_u_8_strict *db_name,
+                                                            struct wire_cst_list_prim_u_8_strict *key,
+                                                            struct wire_cst_list_prim_u_8_strict *member,
+                                                            double score,
+                                                            struct wire_cst_list_prim_u_8_strict *secret_key);
+
+void frbgen_cyberfly_mobile_node_wire__crate__api__zset_add_on(int64_t port_,
+                                                               uint32_t handle,
+                                                               struct wire_cst_list_prim_u_8_strict *db_name,
+                                                               struct wire_cst_list_prim_u_8_strict *key,
+                                                               struct wire_cst_list_prim_u_8_strict *member,
+                                                               double score,
+                                                               struct wire_cst_list_prim_u_8_strict *secret_key);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__zset_range(struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                              struct wire_cst_list_prim_u_8_strict *key,
+                                                                              double *min,
+                                                                              double *max);
+
+WireSyncRust2DartDco frbgen_cyberfly_mobile_node_wire__crate__api__zset_range_on(uint32_t handle,
+                                                                                 struct wire_cst_list_prim_u_8_strict *db_name,
+                                                                                 struct wire_cst_list_prim_u_8_strict *key,
+                                                                                 double *min,
+                                                                                 double *max);
+
+struct wire_cst_bandwidth_caps_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_bandwidth_caps_dto(void);
+
+struct wire_cst_bootstrap_manifest_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_bootstrap_manifest_dto(void);
+
+struct wire_cst_device_conditions_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_device_conditions_dto(void);
+
+double *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_f_64(double value);
+
+struct wire_cst_file_offer_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_file_offer_dto(void);
+
 int64_t *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_i_64(int64_t value);
 
+struct wire_cst_log_filter_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_log_filter_dto(void);
+
+struct wire_cst_mqtt_bridge_config_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_mqtt_bridge_config_dto(void);
+
+struct wire_cst_node_config_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_node_config_dto(void);
+
 struct wire_cst_node_info *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_node_info(void);
 
+struct wire_cst_oplog_gc_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_oplog_gc_dto(void);
+
+struct wire_cst_peer_path_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_peer_path_dto(void);
+
+struct wire_cst_presence_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_presence_dto(void);
+
+struct wire_cst_query_spec_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_query_spec_dto(void);
+
+struct wire_cst_successor_record_dto *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_successor_record_dto(void);
+
+uint16_t *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_16(uint16_t value);
+
 uint32_t *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_32(uint32_t value);
 
 uint64_t *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_64(uint64_t value);
 
+uint8_t *frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_8(uint8_t value);
+
 struct wire_cst_list_String *frbgen_cyberfly_mobile_node_cst_new_list_String(int32_t len);
 
+struct wire_cst_list_bandwidth_usage_dto *frbgen_cyberfly_mobile_node_cst_new_list_bandwidth_usage_dto(int32_t len);
+
+struct wire_cst_list_batch_entry_dto *frbgen_cyberfly_mobile_node_cst_new_list_batch_entry_dto(int32_t len);
+
+struct wire_cst_list_conflict_dto *frbgen_cyberfly_mobile_node_cst_new_list_conflict_dto(int32_t len);
+
 struct wire_cst_list_db_entry_dto *frbgen_cyberfly_mobile_node_cst_new_list_db_entry_dto(int32_t len);
 
+struct wire_cst_list_direct_message_dto *frbgen_cyberfly_mobile_node_cst_new_list_direct_message_dto(int32_t len);
+
+struct wire_cst_list_file_offer_dto *frbgen_cyberfly_mobile_node_cst_new_list_file_offer_dto(int32_t len);
+
+struct wire_cst_list_geo_match_dto *frbgen_cyberfly_mobile_node_cst_new_list_geo_match_dto(int32_t len);
+
+struct wire_cst_list_hash_entry_dto *frbgen_cyberfly_mobile_node_cst_new_list_hash_entry_dto(int32_t len);
+
+struct wire_cst_list_integrity_issue_dto *frbgen_cyberfly_mobile_node_cst_new_list_integrity_issue_dto(int32_t len);
+
+struct wire_cst_list_latency_bucket_dto *frbgen_cyberfly_mobile_node_cst_new_list_latency_bucket_dto(int32_t len);
+
+struct wire_cst_list_linked_device_dto *frbgen_cyberfly_mobile_node_cst_new_list_linked_device_dto(int32_t len);
+
 struct wire_cst_list_log_entry *frbgen_cyberfly_mobile_node_cst_new_list_log_entry(int32_t len);
 
+struct wire_cst_list_mqtt_topic_mapping_dto *frbgen_cyberfly_mobile_node_cst_new_list_mqtt_topic_mapping_dto(int32_t len);
+
+struct wire_cst_list_node_event_record_dto *frbgen_cyberfly_mobile_node_cst_new_list_node_event_record_dto(int32_t len);
+
+struct wire_cst_list_opt_list_prim_u_8_strict *frbgen_cyberfly_mobile_node_cst_new_list_opt_list_prim_u_8_strict(int32_t len);
+
+struct wire_cst_list_peer_circuit_dto *frbgen_cyberfly_mobile_node_cst_new_list_peer_circuit_dto(int32_t len);
+
+struct wire_cst_list_peer_diagnostics_dto *frbgen_cyberfly_mobile_node_cst_new_list_peer_diagnostics_dto(int32_t len);
+
 struct wire_cst_list_peer_info_dto *frbgen_cyberfly_mobile_node_cst_new_list_peer_info_dto(int32_t len);
 
+struct wire_cst_list_peer_latency_dto *frbgen_cyberfly_mobile_node_cst_new_list_peer_latency_dto(int32_t len);
+
+struct wire_cst_list_peer_path_dto *frbgen_cyberfly_mobile_node_cst_new_list_peer_path_dto(int32_t len);
+
+struct wire_cst_list_peer_sync_status_dto *frbgen_cyberfly_mobile_node_cst_new_list_peer_sync_status_dto(int32_t len);
+
+struct wire_cst_list_pinned_peer_dto *frbgen_cyberfly_mobile_node_cst_new_list_pinned_peer_dto(int32_t len);
+
+struct wire_cst_list_prim_u_32_strict *frbgen_cyberfly_mobile_node_cst_new_list_prim_u_32_strict(int32_t len);
+
 struct wire_cst_list_prim_u_8_loose *frbgen_cyberfly_mobile_node_cst_new_list_prim_u_8_loose(int32_t len);
 
 struct wire_cst_list_prim_u_8_strict *frbgen_cyberfly_mobile_node_cst_new_list_prim_u_8_strict(int32_t len);
+
+struct wire_cst_list_query_filter_dto *frbgen_cyberfly_mobile_node_cst_new_list_query_filter_dto(int32_t len);
+
+struct wire_cst_list_rekeyed_database_dto *frbgen_cyberfly_mobile_node_cst_new_list_rekeyed_database_dto(int32_t len);
+
+struct wire_cst_list_replica_check_dto *frbgen_cyberfly_mobile_node_cst_new_list_replica_check_dto(int32_t len);
+
+struct wire_cst_list_scored_member_dto *frbgen_cyberfly_mobile_node_cst_new_list_scored_member_dto(int32_t len);
+
+struct wire_cst_list_time_point_dto *frbgen_cyberfly_mobile_node_cst_new_list_time_point_dto(int32_t len);
+
+struct wire_cst_list_topic_health_dto *frbgen_cyberfly_mobile_node_cst_new_list_topic_health_dto(int32_t len);
+
+struct wire_cst_list_topic_metrics_dto *frbgen_cyberfly_mobile_node_cst_new_list_topic_metrics_dto(int32_t len);
+
+struct wire_cst_list_topic_stats_dto *frbgen_cyberfly_mobile_node_cst_new_list_topic_stats_dto(int32_t len);
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_bandwidth_caps_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_bootstrap_manifest_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_device_conditions_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_f_64);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_file_offer_dto);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_i_64);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_log_filter_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_mqtt_bridge_config_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_node_config_dto);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_node_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_oplog_gc_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_peer_path_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_presence_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_query_spec_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_successor_record_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_16);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_32);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_64);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_8);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_String);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_bandwidth_usage_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_batch_entry_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_conflict_dto);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_db_entry_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_direct_message_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_file_offer_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_geo_match_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_hash_entry_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_integrity_issue_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_latency_bucket_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_linked_device_dto);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_log_entry);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_mqtt_topic_mapping_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_node_event_record_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_opt_list_prim_u_8_strict);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_peer_circuit_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_peer_diagnostics_dto);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_peer_info_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_peer_latency_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_peer_path_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_peer_sync_status_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_pinned_peer_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_prim_u_32_strict);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_prim_u_8_loose);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_prim_u_8_strict);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_query_filter_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_rekeyed_database_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_replica_check_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_scored_member_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_time_point_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_topic_health_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_topic_metrics_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_cst_new_list_topic_stats_dto);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__accept_db_invite);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__accept_db_invite_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__accept_file);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__accept_file_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__add_log_entry);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__blake3_hash);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__change_feed);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__change_feed_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__checkpoint);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__checkpoint_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__clear_logs);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__close_storage_read_only);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__compare_and_swap);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__compare_and_swap_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__connect_peer);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__connect_peer_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__create_db_invite);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__create_db_invite_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__create_device_link_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__create_device_link_request_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__create_encrypted_db);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__create_encrypted_db_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__decline_file);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__decline_file_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__default_node_config);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__delete_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__delete_data_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__dm_stream);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__dm_stream_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__drop_database);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__drop_database_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__edit_text);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__edit_text_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__event_stream);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__event_stream_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__export_backup);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__export_backup_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__export_db_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__export_db_key_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__export_logs);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__extract_name_from_db);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__file_event_stream);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__file_event_stream_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__follow_database);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__follow_database_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__generate_db_name);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__generate_keypair);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__generate_peer_id_from_secret_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__geo_add);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__geo_add_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__geo_radius);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__geo_radius_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_page);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_page_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_all_entries);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_all_entries_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_bandwidth_stats);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_bandwidth_stats_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_connection_diagnostics);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_connection_diagnostics_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_connectivity_state);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_connectivity_state_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_counter);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_counter_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_data_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_database_root);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_database_root_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_db_sync_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_db_sync_info_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_decrypted);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_decrypted_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_dm_history);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_dm_history_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_entries_page);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_entries_page_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_followed_databases);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_followed_databases_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_log_levels_filter);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_logs);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_logs_filtered);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_many);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_many_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_metrics);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_metrics_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_mqtt_bridge_config);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_mqtt_bridge_config_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_network_id);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_addr);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_addr_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_config);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_config_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_info_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_stats);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_stats_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_status);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_status_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_ticket);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_node_ticket_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_circuits);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_circuits_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_paths);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_paths_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_peers);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_filtered);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_filtered_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_pinned_peers);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_pinned_peers_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_power_mode);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_power_mode_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_power_policy);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_power_policy_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_presence);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_presence_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_ranked_peers);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_ranked_peers_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_recent_events);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_recent_events_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_relay_status);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_relay_status_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_successor);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_successor_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_sync_stats);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_sync_stats_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_text);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_text_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_writers);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__get_writers_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__go_online);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__go_online_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__grant_writer);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__grant_writer_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__graphql_execute);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__graphql_execute_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__greet);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__hash_get);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_all);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_all_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__hash_set);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__hash_set_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__health_check);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__health_check_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__import_backup);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__import_db_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__import_db_key_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__incr);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__incr_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__init_logging);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_database_local_only);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_database_local_only_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_networking_paused);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_networking_paused_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_node_key_sealed);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_node_offline);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_node_offline_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_node_running);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_verified_peer_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__is_verified_peer_key_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__kadena_account_from_public_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__kadena_node_registration_command);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__kadena_sign_command);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__link_device);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__link_device_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_conflicts);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_conflicts_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_databases);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_databases_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_file_offers);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_file_offers_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_keys);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_keys_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_linked_devices);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_linked_devices_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_node_instances);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_push);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_push_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_range);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_range_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_replica_checks);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__list_replica_checks_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__measure_latency);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__measure_latency_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__open_storage_read_only);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__pause_networking);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__pause_networking_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__pin_peer);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__pin_peer_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__prove_ownership);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__prove_ownership_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__publish_topic);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__publish_topic_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__put_json);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__put_json_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__put_string);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__put_string_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__query);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__query_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__read_only_export_backup);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__read_only_get_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__read_only_get_entries_page);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__read_only_list_databases);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__reject_rpc);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__reject_rpc_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__request_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__request_sync_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__reset_log_levels);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__respond_rpc);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__respond_rpc_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__resume_networking);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__resume_networking_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__revoke_writer);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__revoke_writer_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__rotate_identity);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__rotate_identity_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__rotate_key_wrapping_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__rpc_call);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__rpc_call_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__scan_prefix);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__scan_prefix_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__scan_range);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__scan_range_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__seal_node_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__search);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__search_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__send_dm);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__send_dm_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__send_file);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__send_file_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__send_gossip);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__send_gossip_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__send_latency_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__send_latency_request_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__serve_rpc);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__serve_rpc_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_add);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_add_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_bandwidth_caps);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_bandwidth_caps_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_database_local_only);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_database_local_only_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_device_conditions);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_device_conditions_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_key_wrapping_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_log_buffer_size);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_log_level);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_log_target_level);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_members);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_members_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_network_passphrase);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_power_mode);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_power_mode_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_presence_status);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_presence_status_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_retain_conflicts);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_retain_conflicts_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_selective_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__set_selective_sync_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__sha256_hash);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__sign_bytes);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__sign_message_with_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_debug_server);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_debug_server_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_metrics_server);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_metrics_server_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_mqtt_bridge);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_mqtt_bridge_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_node);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_node_in_network);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_node_instance);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_node_with_config);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_node_with_progress);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__start_offline);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__stop_mqtt_bridge);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__stop_mqtt_bridge_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__stop_node);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__stop_node_instance);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_batch);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_batch_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_idempotent);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_idempotent_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_local);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_local_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_signed);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_signed_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_with_ttl);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_data_with_ttl_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_encrypted);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__store_encrypted_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__subscribe_topic);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__subscribe_topic_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__text_delta_stream);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__text_delta_stream_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__ts_add);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__ts_add_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__ts_range);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__ts_range_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__unfollow_database);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__unfollow_database_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__unpin_peer);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__unpin_peer_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__unsubscribe_topic);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__unsubscribe_topic_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__update_config);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__update_config_on);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__validate_timestamp);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__verify_bytes);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__verify_db_name);
     dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__verify_message_signature);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__verify_storage);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__verify_storage_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__wait_for_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__wait_for_sync_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__wake_and_sync);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__wake_and_sync_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__watch_db);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__watch_db_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__watch_key);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__watch_key_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__zset_add);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__zset_add_on);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__zset_range);
+    dummy_var ^= ((int64_t) (void*) frbgen_cyberfly_mobile_node_wire__crate__api__zset_range_on);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     return dummy_var;
 }
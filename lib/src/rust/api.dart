@@ -8,238 +8,2043 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'api.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_log_buffer`, `get_node_holder`, `get_node`, `get_runtime`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NodeEventDto`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `from`
+            // These functions are ignored because they are not marked as `pub`: `apply_log_levels`, `from_data_page`, `from_page`, `get_log_buffer`, `get_log_levels`, `get_node_handle`, `get_node`, `get_nodes`, `get_read_only_holder`, `get_read_only_storage`, `get_runtime`, `launch_node`, `new`, `new`, `next_change`, `node_key_path`, `node_status`, `peer_path_dto`, `put_typed`, `record_log`, `replay_changes`, `scan_options`, `set_node`, `signing_key_from_hex`, `spawn_node`, `spawn_until_stopped`, `stop_node_handle`, `stream_topic`, `typed_collection`, `wrapping_key_from_bytes`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ReadOnlyStorage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
 
-/// Add a log entry to the buffer (called from our custom logger)
-Future<void> addLogEntry({required String level, required String message}) =>
-    RustLib.instance.api.crateApiAddLogEntry(level: level, message: message);
 
-/// Initialize logging
-void initLogging() => RustLib.instance.api.crateApiInitLogging();
+            /// Add a log entry to the buffer under the "flutter" target, if that's
+/// logged at its level; unknown levels are taken as info
+Future<void>  addLogEntry({required String level , required String message }) => RustLib.instance.api.crateApiAddLogEntry(level: level, message: message);
+
+/// Initialize logging at info, with iroh's internals at warn; see
+/// `set_log_level` and `set_log_target_level` to change that later
+void  initLogging() => RustLib.instance.api.crateApiInitLogging();
+
+/// Log at `level` ("off", "error", "warn", "info", "debug" or "trace") for
+/// every target without its own level
+void  setLogLevel({required String level }) => RustLib.instance.api.crateApiSetLogLevel(level: level);
+
+/// Log `target`, a module path prefix such as "iroh" or
+/// "rust_lib_cyberfly_mobile_node::sync", at `level`; None returns it to the
+/// default level
+void  setLogTargetLevel({required String target , String? level }) => RustLib.instance.api.crateApiSetLogTargetLevel(target: target, level: level);
+
+/// Back to the levels `init_logging` starts with
+void  resetLogLevels() => RustLib.instance.api.crateApiResetLogLevels();
+
+/// Current levels as filter directives, e.g. "info,iroh=debug,quinn=warn"
+String  getLogLevelsFilter() => RustLib.instance.api.crateApiGetLogLevelsFilter();
 
 /// Start the Cyberfly node
-Future<NodeInfo> startNode({
-  required String dataDir,
-  String? walletSecretKey,
-  required List<String> bootstrapPeers,
-  String? region,
-}) => RustLib.instance.api.crateApiStartNode(
-  dataDir: dataDir,
-  walletSecretKey: walletSecretKey,
-  bootstrapPeers: bootstrapPeers,
-  region: region,
-);
+Future<NodeInfo>  startNode({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , String? region }) => RustLib.instance.api.crateApiStartNode(dataDir: dataDir, walletSecretKey: walletSecretKey, bootstrapPeers: bootstrapPeers, region: region);
+
+/// Start the Cyberfly node in the application network `network_id`. Its
+/// gossip topics are separate from the default network's and from other
+/// applications', so it only meets nodes started with the same ID.
+Future<NodeInfo>  startNodeInNetwork({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , String? region , required String networkId }) => RustLib.instance.api.crateApiStartNodeInNetwork(dataDir: dataDir, walletSecretKey: walletSecretKey, bootstrapPeers: bootstrapPeers, region: region, networkId: networkId);
+
+/// Start the Cyberfly node with `config`
+Future<NodeInfo>  startNodeWithConfig({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config }) => RustLib.instance.api.crateApiStartNodeWithConfig(dataDir: dataDir, walletSecretKey: walletSecretKey, bootstrapPeers: bootstrapPeers, config: config);
+
+/// `start_node_with_config`, streaming each step of starting up to
+/// `progress`. The stream ends after `Ready`, or when starting fails.
+Stream<StartupStageDto>  startNodeWithProgress({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config }) => RustLib.instance.api.crateApiStartNodeWithProgress(dataDir: dataDir, walletSecretKey: walletSecretKey, bootstrapPeers: bootstrapPeers, config: config);
+
+/// Configuration `start_node` uses
+NodeConfigDto  defaultNodeConfig() => RustLib.instance.api.crateApiDefaultNodeConfig();
+
+/// Configuration of the running node
+NodeConfigDto  getNodeConfig() => RustLib.instance.api.crateApiGetNodeConfig();
+
+/// `get_node_config` of a `start_node_instance` node
+NodeConfigDto  getNodeConfigOn({required int handle }) => RustLib.instance.api.crateApiGetNodeConfigOn(handle: handle);
+
+/// Change the running node's intervals and limits without restarting it.
+/// Fails without changing anything if a value is out of range or a
+/// startup-only setting differs.
+void  updateConfig({required NodeConfigDto config }) => RustLib.instance.api.crateApiUpdateConfig(config: config);
+
+/// `update_config` on a `start_node_instance` node
+void  updateConfigOn({required int handle , required NodeConfigDto config }) => RustLib.instance.api.crateApiUpdateConfigOn(handle: handle, config: config);
+
+/// Open the node in `data_dir` without networking, e.g. in airplane mode or
+/// until the user opts into sync. Storage and the data functions work;
+/// writes are queued and sent to peers after `go_online`.
+Future<NodeInfo>  startOffline({required String dataDir }) => RustLib.instance.api.crateApiStartOffline(dataDir: dataDir);
+
+/// Connect the node of `start_offline` to the network, keeping its data.
+/// Streams opened while offline end and have to be opened again.
+Future<NodeInfo>  goOnline({required List<String> bootstrapPeers , String? region }) => RustLib.instance.api.crateApiGoOnline(bootstrapPeers: bootstrapPeers, region: region);
+
+/// `go_online` on a `start_node_instance` node
+Future<NodeInfo>  goOnlineOn({required int handle , required List<String> bootstrapPeers , String? region }) => RustLib.instance.api.crateApiGoOnlineOn(handle: handle, bootstrapPeers: bootstrapPeers, region: region);
+
+/// For the background handler of a push notification (FCM/APNs), which the
+/// OS only gives a few seconds: start the node unless it's running, dial
+/// pinned and bootstrap peers, fetch what changed since the last wake, send
+/// queued writes, flush, and stop the node again. Gives up syncing after
+/// `max_duration_secs`, which includes starting and stopping the node.
+Future<WakeSyncSummaryDto>  wakeAndSync({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required int maxDurationSecs }) => RustLib.instance.api.crateApiWakeAndSync(dataDir: dataDir, walletSecretKey: walletSecretKey, bootstrapPeers: bootstrapPeers, maxDurationSecs: maxDurationSecs);
+
+/// `wake_and_sync` of a `start_node_instance` node; a node started for the
+/// wake isn't registered under `handle`
+Future<WakeSyncSummaryDto>  wakeAndSyncOn({required int handle , required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required int maxDurationSecs }) => RustLib.instance.api.crateApiWakeAndSyncOn(handle: handle, dataDir: dataDir, walletSecretKey: walletSecretKey, bootstrapPeers: bootstrapPeers, maxDurationSecs: maxDurationSecs);
+
+/// Retire the node key, e.g. after it was compromised, for
+/// `new_secret_key` (hex; a fresh key if None) and restart the node under
+/// it. Peers learn the new node ID from a record signed by the old key, which
+/// is broadcast for `grace_secs` (a week by default); meanwhile the old key
+/// keeps answering peers. Databases named after the old public key are copied
+/// to the same name under the new one. On failure the node keeps running
+/// under the old key.
+Future<IdentityRotationDto>  rotateIdentity({String? newSecretKey , BigInt? graceSecs }) => RustLib.instance.api.crateApiRotateIdentity(newSecretKey: newSecretKey, graceSecs: graceSecs);
+
+/// `rotate_identity` on a `start_node_instance` node
+Future<IdentityRotationDto>  rotateIdentityOn({required int handle , String? newSecretKey , BigInt? graceSecs }) => RustLib.instance.api.crateApiRotateIdentityOn(handle: handle, newSecretKey: newSecretKey, graceSecs: graceSecs);
+
+/// Successor of a node that rotated its key, if known
+SuccessorRecordDto?  getSuccessor({required String nodeId }) => RustLib.instance.api.crateApiGetSuccessor(nodeId: nodeId);
+
+/// `get_successor` of a `start_node_instance` node
+SuccessorRecordDto?  getSuccessorOn({required int handle , required String nodeId }) => RustLib.instance.api.crateApiGetSuccessorOn(handle: handle, nodeId: nodeId);
+
+/// The node was started with `start_offline` and isn't online yet
+bool  isNodeOffline() => RustLib.instance.api.crateApiIsNodeOffline();
+
+/// `is_node_offline` of a `start_node_instance` node
+bool  isNodeOfflineOn({required int handle }) => RustLib.instance.api.crateApiIsNodeOfflineOn(handle: handle);
+
+/// Join the private network of everyone using `passphrase`, or the public
+/// network with None. Nodes only see members of their own network. Takes
+/// effect when the node starts, so every node must be stopped.
+Future<void>  setNetworkPassphrase({required String dataDir , String? passphrase }) => RustLib.instance.api.crateApiSetNetworkPassphrase(dataDir: dataDir, passphrase: passphrase);
+
+/// Short identifier of the private network the node in `data_dir` is in
+/// (equal on all members), None on the public network
+String?  getNetworkId({required String dataDir }) => RustLib.instance.api.crateApiGetNetworkId(dataDir: dataDir);
 
 /// Stop the node
-Future<void> stopNode() => RustLib.instance.api.crateApiStopNode();
+Future<void>  stopNode() => RustLib.instance.api.crateApiStopNode();
+
+/// Start another node next to the one of `start_node`, e.g. for a second
+/// identity. Each node needs its own `data_dir`. The functions ending in
+/// `_on` take the returned handle; the others use the `start_node` node.
+Future<NodeInstanceDto>  startNodeInstance({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config }) => RustLib.instance.api.crateApiStartNodeInstance(dataDir: dataDir, walletSecretKey: walletSecretKey, bootstrapPeers: bootstrapPeers, config: config);
+
+/// Stop a node started with `start_node_instance`
+Future<void>  stopNodeInstance({required int handle }) => RustLib.instance.api.crateApiStopNodeInstance(handle: handle);
+
+/// Handles of the running nodes, `DEFAULT_NODE_HANDLE` (0) for the node of
+/// `start_node`
+Uint32List  listNodeInstances() => RustLib.instance.api.crateApiListNodeInstances();
+
+/// Write an encrypted backup of all node data (databases, sync log, writer
+/// lists, database keys) and the node identity key to `path`
+Future<BackupInfoDto>  exportBackup({required String path , required String passphrase }) => RustLib.instance.api.crateApiExportBackup(path: path, passphrase: passphrase);
+
+/// `export_backup` on a `start_node_instance` node
+Future<BackupInfoDto>  exportBackupOn({required int handle , required String path , required String passphrase }) => RustLib.instance.api.crateApiExportBackupOn(handle: handle, path: path, passphrase: passphrase);
+
+/// Restore a backup made with `export_backup` into `data_dir` (e.g. on a new
+/// device). The node must be stopped; start it afterwards with the same
+/// `data_dir` and no `wallet_secret_key` to use the restored identity.
+Future<BackupInfoDto>  importBackup({required String dataDir , required String path , required String passphrase }) => RustLib.instance.api.crateApiImportBackup(dataDir: dataDir, path: path, passphrase: passphrase);
+
+/// Set the key sealing the node key at rest, kept by the app in the platform
+/// keystore (Android Keystore, iOS Keychain). Call before starting the node:
+/// a sealed node key can't be read without it, and new keys are sealed with it.
+void  setKeyWrappingKey({required List<int> wrappingKey }) => RustLib.instance.api.crateApiSetKeyWrappingKey(wrappingKey: wrappingKey);
+
+/// Whether the node key in `data_dir` is sealed with a wrapping key
+bool  isNodeKeySealed({required String dataDir }) => RustLib.instance.api.crateApiIsNodeKeySealed(dataDir: dataDir);
+
+/// Seal the plaintext node key in `data_dir` with `wrapping_key`, which is
+/// set as the wrapping key from now on
+void  sealNodeKey({required String dataDir , required List<int> wrappingKey }) => RustLib.instance.api.crateApiSealNodeKey(dataDir: dataDir, wrappingKey: wrappingKey);
+
+/// Seal the node key in `data_dir` with `new_wrapping_key` instead of
+/// `old_wrapping_key`, and use the new one from now on
+void  rotateKeyWrappingKey({required String dataDir , required List<int> oldWrappingKey , required List<int> newWrappingKey }) => RustLib.instance.api.crateApiRotateKeyWrappingKey(dataDir: dataDir, oldWrappingKey: oldWrappingKey, newWrappingKey: newWrappingKey);
+
+/// Open the storage in `data_dir` read-only without starting the node, to
+/// inspect databases or export a backup when the node can't run. Fails while
+/// the node is running.
+void  openStorageReadOnly({required String dataDir }) => RustLib.instance.api.crateApiOpenStorageReadOnly(dataDir: dataDir);
+
+/// Close storage opened with `open_storage_read_only`
+void  closeStorageReadOnly() => RustLib.instance.api.crateApiCloseStorageReadOnly();
+
+/// List databases of the read-only storage
+List<String>  readOnlyListDatabases() => RustLib.instance.api.crateApiReadOnlyListDatabases();
+
+/// Get a raw value from the read-only storage (encrypted databases return ciphertext)
+Future<Uint8List?>  readOnlyGetData({required String dbName , required String key }) => RustLib.instance.api.crateApiReadOnlyGetData(dbName: dbName, key: key);
+
+/// Page through a database of the read-only storage, same options as `get_entries_page`
+Future<ScanPageDto>  readOnlyGetEntriesPage({required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes }) => RustLib.instance.api.crateApiReadOnlyGetEntriesPage(dbName: dbName, limit: limit, cursor: cursor, keysOnly: keysOnly, maxBytes: maxBytes);
+
+/// Export a backup from the read-only storage. The identity is
+/// `wallet_secret_key` if the node is started with one, otherwise the key
+/// file in the data directory.
+Future<BackupInfoDto>  readOnlyExportBackup({required String path , required String passphrase , String? walletSecretKey }) => RustLib.instance.api.crateApiReadOnlyExportBackup(path: path, passphrase: passphrase, walletSecretKey: walletSecretKey);
 
 /// Check if node is running
-bool isNodeRunning() => RustLib.instance.api.crateApiIsNodeRunning();
+bool  isNodeRunning() => RustLib.instance.api.crateApiIsNodeRunning();
 
 /// Get node status - synchronous version using shared state
-NodeStatusDto getNodeStatus() => RustLib.instance.api.crateApiGetNodeStatus();
+NodeStatusDto  getNodeStatus() => RustLib.instance.api.crateApiGetNodeStatus();
+
+/// `get_node_status` of a `start_node_instance` node
+NodeStatusDto  getNodeStatusOn({required int handle }) => RustLib.instance.api.crateApiGetNodeStatusOn(handle: handle);
+
+/// Get session and lifetime statistics
+NodeStatsDto  getNodeStats() => RustLib.instance.api.crateApiGetNodeStats();
+
+/// `get_node_stats` of a `start_node_instance` node
+NodeStatsDto  getNodeStatsOn({required int handle }) => RustLib.instance.api.crateApiGetNodeStatsOn(handle: handle);
 
 /// Get node info
-NodeInfo? getNodeInfo() => RustLib.instance.api.crateApiGetNodeInfo();
+NodeInfo?  getNodeInfo() => RustLib.instance.api.crateApiGetNodeInfo();
+
+/// `get_node_info` of a `start_node_instance` node
+NodeInfo?  getNodeInfoOn({required int handle }) => RustLib.instance.api.crateApiGetNodeInfoOn(handle: handle);
+
+/// The last node events after `since_seq`, oldest first; 0 for all that are
+/// kept. Pass the last `seq` seen to catch up after missing some.
+List<NodeEventRecordDto>  getRecentEvents({required BigInt sinceSeq }) => RustLib.instance.api.crateApiGetRecentEvents(sinceSeq: sinceSeq);
+
+/// `get_recent_events` of a `start_node_instance` node
+List<NodeEventRecordDto>  getRecentEventsOn({required int handle , required BigInt sinceSeq }) => RustLib.instance.api.crateApiGetRecentEventsOn(handle: handle, sinceSeq: sinceSeq);
+
+/// Stream node events: first the kept ones after `since_seq` (0 for all, so
+/// `Started` isn't missed when subscribing late), then new ones as they
+/// happen. Streams can be opened any number of times.
+Stream<NodeEventRecordDto>  eventStream({required BigInt sinceSeq }) => RustLib.instance.api.crateApiEventStream(sinceSeq: sinceSeq);
+
+/// `event_stream` on a `start_node_instance` node
+Stream<NodeEventRecordDto>  eventStreamOn({required int handle , required BigInt sinceSeq }) => RustLib.instance.api.crateApiEventStreamOn(handle: handle, sinceSeq: sinceSeq);
 
 /// Get discovered peers - synchronous version using shared state
-List<PeerInfoDto> getPeers() => RustLib.instance.api.crateApiGetPeers();
+List<PeerInfoDto>  getPeers() => RustLib.instance.api.crateApiGetPeers();
+
+/// `get_peers` of a `start_node_instance` node
+List<PeerInfoDto>  getPeersOn({required int handle }) => RustLib.instance.api.crateApiGetPeersOn(handle: handle);
+
+/// Get active peers that have every listed capability ("mqtt", "streams",
+/// "timeseries", "geo", "blobs"), are in `region` and within
+/// `max_latency_ms`, lowest latency first. E.g. blob-capable peers nearby to
+/// fetch a file from.
+List<PeerInfoDto>  getPeersFiltered({required List<String> capabilities , String? region , BigInt? maxLatencyMs }) => RustLib.instance.api.crateApiGetPeersFiltered(capabilities: capabilities, region: region, maxLatencyMs: maxLatencyMs);
+
+/// `get_peers_filtered` of a `start_node_instance` node
+List<PeerInfoDto>  getPeersFilteredOn({required int handle , required List<String> capabilities , String? region , BigInt? maxLatencyMs }) => RustLib.instance.api.crateApiGetPeersFilteredOn(handle: handle, capabilities: capabilities, region: region, maxLatencyMs: maxLatencyMs);
+
+/// Connection path of every peer we hold a connection to, updated as paths
+/// change. Lets the UI badge peers as P2P or relayed and diagnose NAT issues.
+List<PeerPathDto>  getPeerPaths() => RustLib.instance.api.crateApiGetPeerPaths();
+
+/// `get_peer_paths` of a `start_node_instance` node
+List<PeerPathDto>  getPeerPathsOn({required int handle }) => RustLib.instance.api.crateApiGetPeerPathsOn(handle: handle);
+
+/// Peers the node failed to connect to recently. After repeated failures a
+/// peer's circuit opens and it isn't dialed for a cooldown, then a single
+/// probe connect decides whether it closes again.
+List<PeerCircuitDto>  getPeerCircuits() => RustLib.instance.api.crateApiGetPeerCircuits();
+
+/// `get_peer_circuits` of a `start_node_instance` node
+List<PeerCircuitDto>  getPeerCircuitsOn({required int handle }) => RustLib.instance.api.crateApiGetPeerCircuitsOn(handle: handle);
+
+/// Our addresses, relay and NAT guess, with every connected or dialed peer's
+/// path, dial attempts and last error
+ConnectionDiagnosticsDto  getConnectionDiagnostics() => RustLib.instance.api.crateApiGetConnectionDiagnostics();
+
+/// `get_connection_diagnostics` of a `start_node_instance` node
+ConnectionDiagnosticsDto  getConnectionDiagnosticsOn({required int handle }) => RustLib.instance.api.crateApiGetConnectionDiagnosticsOn(handle: handle);
+
+/// Check storage (with a test write), the gossip topics, sync, discovery and
+/// the relay
+Future<HealthReportDto>  healthCheck() => RustLib.instance.api.crateApiHealthCheck();
+
+/// `health_check` on a `start_node_instance` node
+Future<HealthReportDto>  healthCheckOn({required int handle }) => RustLib.instance.api.crateApiHealthCheckOn(handle: handle);
+
+/// Measured active peers, best first (lowest average latency, then lowest
+/// jitter)
+List<PeerLatencyDto>  getRankedPeers() => RustLib.instance.api.crateApiGetRankedPeers();
+
+/// `get_ranked_peers` of a `start_node_instance` node
+List<PeerLatencyDto>  getRankedPeersOn({required int handle }) => RustLib.instance.api.crateApiGetRankedPeersOn(handle: handle);
+
+/// Pin a peer: it's redialed whenever it isn't connected, joins the gossip
+/// topics like a bootstrap node and never expires from the peer list.
+/// Persisted across restarts.
+void  pinPeer({required String nodeId , String? address }) => RustLib.instance.api.crateApiPinPeer(nodeId: nodeId, address: address);
+
+/// `pin_peer` on a `start_node_instance` node
+void  pinPeerOn({required int handle , required String nodeId , String? address }) => RustLib.instance.api.crateApiPinPeerOn(handle: handle, nodeId: nodeId, address: address);
+
+/// Unpin a peer; it stays connected until it expires like any other
+void  unpinPeer({required String nodeId }) => RustLib.instance.api.crateApiUnpinPeer(nodeId: nodeId);
+
+/// `unpin_peer` on a `start_node_instance` node
+void  unpinPeerOn({required int handle , required String nodeId }) => RustLib.instance.api.crateApiUnpinPeerOn(handle: handle, nodeId: nodeId);
+
+/// List pinned peers
+List<PinnedPeerDto>  getPinnedPeers() => RustLib.instance.api.crateApiGetPinnedPeers();
+
+/// `get_pinned_peers` of a `start_node_instance` node
+List<PinnedPeerDto>  getPinnedPeersOn({required int handle }) => RustLib.instance.api.crateApiGetPinnedPeersOn(handle: handle);
 
 /// Send gossip message
-Future<void> sendGossip({required String topic, required String message}) =>
-    RustLib.instance.api.crateApiSendGossip(topic: topic, message: message);
+Future<void>  sendGossip({required String topic , required String message }) => RustLib.instance.api.crateApiSendGossip(topic: topic, message: message);
+
+/// `send_gossip` on a `start_node_instance` node
+Future<void>  sendGossipOn({required int handle , required String topic , required String message }) => RustLib.instance.api.crateApiSendGossipOn(handle: handle, topic: topic, message: message);
+
+/// Join the gossip topic `name` of this application and stream its messages.
+/// Several streams may watch one topic; it stays joined after they are
+/// cancelled, until `unsubscribe_topic`.
+Stream<TopicMessageDto>  subscribeTopic({required String name }) => RustLib.instance.api.crateApiSubscribeTopic(name: name);
+
+/// `subscribe_topic` on a `start_node_instance` node
+Stream<TopicMessageDto>  subscribeTopicOn({required int handle , required String name }) => RustLib.instance.api.crateApiSubscribeTopicOn(handle: handle, name: name);
+
+/// Broadcast `content` on a topic joined with `subscribe_topic`. Identical
+/// payloads are delivered once, so include an ID or timestamp in messages
+/// that may repeat.
+Future<void>  publishTopic({required String name , required List<int> content }) => RustLib.instance.api.crateApiPublishTopic(name: name, content: content);
+
+/// `publish_topic` on a `start_node_instance` node
+Future<void>  publishTopicOn({required int handle , required String name , required List<int> content }) => RustLib.instance.api.crateApiPublishTopicOn(handle: handle, name: name, content: content);
+
+/// Leave a topic, ending its streams. Returns false if it wasn't joined.
+Future<bool>  unsubscribeTopic({required String name }) => RustLib.instance.api.crateApiUnsubscribeTopic(name: name);
+
+/// `unsubscribe_topic` on a `start_node_instance` node
+Future<bool>  unsubscribeTopicOn({required int handle , required String name }) => RustLib.instance.api.crateApiUnsubscribeTopicOn(handle: handle, name: name);
+
+/// Report battery and network state whenever it changes. On low battery or
+/// metered data the node announces and reconnects less often and defers full
+/// syncs; on metered data it doesn't transfer snapshot blobs.
+Future<void>  setDeviceConditions({required DeviceConditionsDto conditions }) => RustLib.instance.api.crateApiSetDeviceConditions(conditions: conditions);
+
+/// `set_device_conditions` on a `start_node_instance` node
+Future<void>  setDeviceConditionsOn({required int handle , required DeviceConditionsDto conditions }) => RustLib.instance.api.crateApiSetDeviceConditionsOn(handle: handle, conditions: conditions);
+
+/// Get the power policy in effect
+PowerPolicyDto  getPowerPolicy() => RustLib.instance.api.crateApiGetPowerPolicy();
+
+/// `get_power_policy` of a `start_node_instance` node
+PowerPolicyDto  getPowerPolicyOn({required int handle }) => RustLib.instance.api.crateApiGetPowerPolicyOn(handle: handle);
+
+/// Switch the running node between normal and lite mode, e.g. lite while
+/// it runs in an Android foreground service overnight. Lite mode leaves every
+/// topic but sync, so peers aren't discovered and application topics are
+/// silent until it's switched off.
+Future<void>  setPowerMode({required PowerModeDto mode }) => RustLib.instance.api.crateApiSetPowerMode(mode: mode);
+
+/// `set_power_mode` on a `start_node_instance` node
+Future<void>  setPowerModeOn({required int handle , required PowerModeDto mode }) => RustLib.instance.api.crateApiSetPowerModeOn(handle: handle, mode: mode);
+
+PowerModeDto  getPowerMode() => RustLib.instance.api.crateApiGetPowerMode();
+
+/// `get_power_mode` of a `start_node_instance` node
+PowerModeDto  getPowerModeOn({required int handle }) => RustLib.instance.api.crateApiGetPowerModeOn(handle: handle);
+
+/// Get the connectivity found by the last isolation check
+ConnectivityDto  getConnectivityState() => RustLib.instance.api.crateApiGetConnectivityState();
+
+/// `get_connectivity_state` of a `start_node_instance` node
+ConnectivityDto  getConnectivityStateOn({required int handle }) => RustLib.instance.api.crateApiGetConnectivityStateOn(handle: handle);
+
+/// Get the active relay, pinging it to measure the round trip time
+Future<RelayStatusDto>  getRelayStatus() => RustLib.instance.api.crateApiGetRelayStatus();
+
+/// `get_relay_status` of a `start_node_instance` node
+Future<RelayStatusDto>  getRelayStatusOn({required int handle }) => RustLib.instance.api.crateApiGetRelayStatusOn(handle: handle);
+
+/// Get bytes sent and received per topic and subsystem, and data cap usage
+BandwidthStatsDto  getBandwidthStats() => RustLib.instance.api.crateApiGetBandwidthStats();
+
+/// `get_bandwidth_stats` of a `start_node_instance` node
+BandwidthStatsDto  getBandwidthStatsOn({required int handle }) => RustLib.instance.api.crateApiGetBandwidthStatsOn(handle: handle);
+
+/// Set daily and monthly data caps, kept across restarts. Once one is used up
+/// the node stops syncing (and transferring snapshots) until the day or
+/// month is over.
+void  setBandwidthCaps({required BandwidthCapsDto caps }) => RustLib.instance.api.crateApiSetBandwidthCaps(caps: caps);
+
+/// `set_bandwidth_caps` on a `start_node_instance` node
+void  setBandwidthCapsOn({required int handle , required BandwidthCapsDto caps }) => RustLib.instance.api.crateApiSetBandwidthCapsOn(handle: handle, caps: caps);
+
+/// Get counters and histograms for an in-app dashboard: messages and bytes
+/// per topic, sync operations applied, signature verify failures, connect
+/// latencies and storage sizes
+MetricsDto  getMetrics() => RustLib.instance.api.crateApiGetMetrics();
+
+/// `get_metrics` of a `start_node_instance` node
+MetricsDto  getMetricsOn({required int handle }) => RustLib.instance.api.crateApiGetMetricsOn(handle: handle);
+
+/// Serve the metrics in the Prometheus text format at `/metrics` on
+/// `bind_addr` (e.g. "0.0.0.0:9464") until the node stops, for fleet
+/// monitoring of rooted and desktop deployments. Returns the address bound.
+Future<String>  startMetricsServer({required String bindAddr }) => RustLib.instance.api.crateApiStartMetricsServer(bindAddr: bindAddr);
+
+/// `start_metrics_server` on a `start_node_instance` node
+Future<String>  startMetricsServerOn({required int handle , required String bindAddr }) => RustLib.instance.api.crateApiStartMetricsServerOn(handle: handle, bindAddr: bindAddr);
+
+/// Serve a debug API (status, peers, databases, keys, logs, metrics and
+/// GraphQL) on a loopback address such as "127.0.0.1:8765", until the node
+/// stops. Reach it from a computer with `adb forward tcp:8765 tcp:8765`,
+/// sending `Authorization: Bearer <token>` with the returned token.
+/// GraphQL's `submitData` is only served if `allow_writes`.
+Future<DebugServerDto>  startDebugServer({required String bindAddr , required bool allowWrites }) => RustLib.instance.api.crateApiStartDebugServer(bindAddr: bindAddr, allowWrites: allowWrites);
+
+/// `start_debug_server` on a `start_node_instance` node
+Future<DebugServerDto>  startDebugServerOn({required int handle , required String bindAddr , required bool allowWrites }) => RustLib.instance.api.crateApiStartDebugServerOn(handle: handle, bindAddr: bindAddr, allowWrites: allowWrites);
+
+/// Execute a GraphQL query or mutation with the desktop node's schema
+/// (`getString`, `getAll`, `submitData`, ...). `variables` is a JSON object.
+/// Returns the GraphQL response as JSON; query errors are in its `errors`.
+Future<String>  graphqlExecute({required String query , String? variables }) => RustLib.instance.api.crateApiGraphqlExecute(query: query, variables: variables);
+
+/// `graphql_execute` on a `start_node_instance` node
+Future<String>  graphqlExecuteOn({required int handle , required String query , String? variables }) => RustLib.instance.api.crateApiGraphqlExecuteOn(handle: handle, query: query, variables: variables);
+
+/// Act as the gateway for local IoT devices: bridge topics of an MQTT broker
+/// to databases, replacing the running bridge. Device publishes are signed
+/// with the node key; database changes are published retained. The node
+/// announces the `mqtt` capability while the bridge runs.
+Future<void>  startMqttBridge({required MqttBridgeConfigDto config }) => RustLib.instance.api.crateApiStartMqttBridge(config: config);
+
+/// `start_mqtt_bridge` on a `start_node_instance` node
+Future<void>  startMqttBridgeOn({required int handle , required MqttBridgeConfigDto config }) => RustLib.instance.api.crateApiStartMqttBridgeOn(handle: handle, config: config);
+
+/// Stop the MQTT bridge; false if none was running
+Future<bool>  stopMqttBridge() => RustLib.instance.api.crateApiStopMqttBridge();
+
+/// `stop_mqtt_bridge` on a `start_node_instance` node
+Future<bool>  stopMqttBridgeOn({required int handle }) => RustLib.instance.api.crateApiStopMqttBridgeOn(handle: handle);
+
+/// Configuration of the running MQTT bridge (without the password)
+MqttBridgeConfigDto?  getMqttBridgeConfig() => RustLib.instance.api.crateApiGetMqttBridgeConfig();
+
+/// `get_mqtt_bridge_config` of a `start_node_instance` node
+MqttBridgeConfigDto?  getMqttBridgeConfigOn({required int handle }) => RustLib.instance.api.crateApiGetMqttBridgeConfigOn(handle: handle);
+
+/// Leave the gossip network while the app is in the background (call from
+/// the paused lifecycle hook). Storage stays open; joined topics are rejoined
+/// by `resume_networking`.
+Future<void>  pauseNetworking() => RustLib.instance.api.crateApiPauseNetworking();
+
+/// `pause_networking` on a `start_node_instance` node
+Future<void>  pauseNetworkingOn({required int handle }) => RustLib.instance.api.crateApiPauseNetworkingOn(handle: handle);
+
+/// Rejoin the gossip network and sync what was missed while paused (call
+/// from the resumed lifecycle hook)
+Future<void>  resumeNetworking() => RustLib.instance.api.crateApiResumeNetworking();
+
+/// `resume_networking` on a `start_node_instance` node
+Future<void>  resumeNetworkingOn({required int handle }) => RustLib.instance.api.crateApiResumeNetworkingOn(handle: handle);
+
+/// Save the node's in-memory state and flush storage to disk, taking at
+/// most `budget_ms` (2 seconds by default). Call from the paused lifecycle
+/// hook: Android may kill the process afterwards without `stop_node`.
+Future<CheckpointDto>  checkpoint({BigInt? budgetMs }) => RustLib.instance.api.crateApiCheckpoint(budgetMs: budgetMs);
+
+/// `checkpoint` on a `start_node_instance` node
+Future<CheckpointDto>  checkpointOn({required int handle , BigInt? budgetMs }) => RustLib.instance.api.crateApiCheckpointOn(handle: handle, budgetMs: budgetMs);
+
+/// Whether networking is paused
+bool  isNetworkingPaused() => RustLib.instance.api.crateApiIsNetworkingPaused();
+
+/// `is_networking_paused` of a `start_node_instance` node
+bool  isNetworkingPausedOn({required int handle }) => RustLib.instance.api.crateApiIsNetworkingPausedOn(handle: handle);
+
+/// Call `method` on a peer directly and wait for its response, by default
+/// for 30 seconds. Peers answer the built-in method "get" (payload
+/// `{"db_name": .., "key": ..}`) themselves; other methods are answered by
+/// their application through `serve_rpc`.
+Future<Uint8List>  rpcCall({required String peerId , required String method , required List<int> payload , BigInt? timeoutMs }) => RustLib.instance.api.crateApiRpcCall(peerId: peerId, method: method, payload: payload, timeoutMs: timeoutMs);
+
+/// `rpc_call` on a `start_node_instance` node
+Future<Uint8List>  rpcCallOn({required int handle , required String peerId , required String method , required List<int> payload , BigInt? timeoutMs }) => RustLib.instance.api.crateApiRpcCallOn(handle: handle, peerId: peerId, method: method, payload: payload, timeoutMs: timeoutMs);
+
+/// Stream peers' RPC requests to the application. Requests not answered
+/// within 30 seconds fail; only the latest stream receives requests.
+Stream<RpcRequestDto>  serveRpc() => RustLib.instance.api.crateApiServeRpc();
+
+/// `serve_rpc` on a `start_node_instance` node
+Stream<RpcRequestDto>  serveRpcOn({required int handle }) => RustLib.instance.api.crateApiServeRpcOn(handle: handle);
+
+/// Answer an RPC request. Returns false if it timed out already.
+bool  respondRpc({required BigInt requestId , required List<int> payload }) => RustLib.instance.api.crateApiRespondRpc(requestId: requestId, payload: payload);
+
+/// `respond_rpc` on a `start_node_instance` node
+bool  respondRpcOn({required int handle , required BigInt requestId , required List<int> payload }) => RustLib.instance.api.crateApiRespondRpcOn(handle: handle, requestId: requestId, payload: payload);
+
+/// Fail an RPC request with `message`, returned to the caller as its error.
+/// Returns false if it timed out already.
+bool  rejectRpc({required BigInt requestId , required String message }) => RustLib.instance.api.crateApiRejectRpc(requestId: requestId, message: message);
+
+/// `reject_rpc` on a `start_node_instance` node
+bool  rejectRpcOn({required int handle , required BigInt requestId , required String message }) => RustLib.instance.api.crateApiRejectRpcOn(handle: handle, requestId: requestId, message: message);
+
+/// Last known presence of a peer, None if it was never heard from. Peers are
+/// offline 45 seconds after their last heartbeat.
+PresenceDto?  getPresence({required String peerId }) => RustLib.instance.api.crateApiGetPresence(peerId: peerId);
+
+/// `get_presence` of a `start_node_instance` node
+PresenceDto?  getPresenceOn({required int handle , required String peerId }) => RustLib.instance.api.crateApiGetPresenceOn(handle: handle, peerId: peerId);
+
+/// Set the status string (e.g. "away") other nodes see with our presence
+void  setPresenceStatus({String? status }) => RustLib.instance.api.crateApiSetPresenceStatus(status: status);
+
+/// `set_presence_status` on a `start_node_instance` node
+void  setPresenceStatusOn({required int handle , String? status }) => RustLib.instance.api.crateApiSetPresenceStatusOn(handle: handle, status: status);
+
+/// Send an end-to-end encrypted message to a peer. Fails if the peer can't
+/// be reached; delivered messages are kept for `get_dm_history`.
+Future<DirectMessageDto>  sendDm({required String peerId , required String text }) => RustLib.instance.api.crateApiSendDm(peerId: peerId, text: text);
+
+/// `send_dm` on a `start_node_instance` node
+Future<DirectMessageDto>  sendDmOn({required int handle , required String peerId , required String text }) => RustLib.instance.api.crateApiSendDmOn(handle: handle, peerId: peerId, text: text);
+
+/// Stream direct messages received from now on
+Stream<DirectMessageDto>  dmStream() => RustLib.instance.api.crateApiDmStream();
+
+/// `dm_stream` on a `start_node_instance` node
+Stream<DirectMessageDto>  dmStreamOn({required int handle }) => RustLib.instance.api.crateApiDmStreamOn(handle: handle);
+
+/// The last `limit` direct messages exchanged with a peer, oldest first
+List<DirectMessageDto>  getDmHistory({required String peerId , required int limit }) => RustLib.instance.api.crateApiGetDmHistory(peerId: peerId, limit: limit);
+
+/// `get_dm_history` of a `start_node_instance` node
+List<DirectMessageDto>  getDmHistoryOn({required int handle , required String peerId , required int limit }) => RustLib.instance.api.crateApiGetDmHistoryOn(handle: handle, peerId: peerId, limit: limit);
+
+/// Offer the file at `path` to a peer, which receives a `FileEventDto::Offer`.
+/// Fails if the peer can't be reached; the file is served until the node stops.
+Future<FileOfferDto>  sendFile({required String peerId , required String path }) => RustLib.instance.api.crateApiSendFile(peerId: peerId, path: path);
+
+/// `send_file` on a `start_node_instance` node
+Future<FileOfferDto>  sendFileOn({required int handle , required String peerId , required String path }) => RustLib.instance.api.crateApiSendFileOn(handle: handle, peerId: peerId, path: path);
+
+/// Download an offered file to `dest_path`, reporting progress on
+/// `file_event_stream`. Interrupted downloads are retried, and calling this
+/// again after a failure or restart resumes where it stopped. Returns the
+/// file size.
+Future<BigInt>  acceptFile({required String offerId , required String destPath }) => RustLib.instance.api.crateApiAcceptFile(offerId: offerId, destPath: destPath);
+
+/// `accept_file` on a `start_node_instance` node
+Future<BigInt>  acceptFileOn({required int handle , required String offerId , required String destPath }) => RustLib.instance.api.crateApiAcceptFileOn(handle: handle, offerId: offerId, destPath: destPath);
+
+/// Forget a file offer without downloading it
+void  declineFile({required String offerId }) => RustLib.instance.api.crateApiDeclineFile(offerId: offerId);
+
+/// `decline_file` on a `start_node_instance` node
+void  declineFileOn({required int handle , required String offerId }) => RustLib.instance.api.crateApiDeclineFileOn(handle: handle, offerId: offerId);
+
+/// File offers not accepted or declined yet, oldest first
+List<FileOfferDto>  listFileOffers() => RustLib.instance.api.crateApiListFileOffers();
+
+/// `list_file_offers` of a `start_node_instance` node
+List<FileOfferDto>  listFileOffersOn({required int handle }) => RustLib.instance.api.crateApiListFileOffersOn(handle: handle);
+
+/// Stream file offers and transfer progress from now on
+Stream<FileEventDto>  fileEventStream() => RustLib.instance.api.crateApiFileEventStream();
+
+/// `file_event_stream` on a `start_node_instance` node
+Stream<FileEventDto>  fileEventStreamOn({required int handle }) => RustLib.instance.api.crateApiFileEventStreamOn(handle: handle);
+
+/// Payload for the QR code a new device shows to be linked; valid for ten
+/// minutes
+String  createDeviceLinkRequest({required String deviceName }) => RustLib.instance.api.crateApiCreateDeviceLinkRequest(deviceName: deviceName);
+
+/// `create_device_link_request` on a `start_node_instance` node
+String  createDeviceLinkRequestOn({required int handle , required String deviceName }) => RustLib.instance.api.crateApiCreateDeviceLinkRequestOn(handle: handle, deviceName: deviceName);
+
+/// Link the device whose QR code was scanned to the user key `secret_key`
+/// (hex), run on the primary device
+LinkedDeviceDto  linkDevice({required String qrPayload , required String secretKey }) => RustLib.instance.api.crateApiLinkDevice(qrPayload: qrPayload, secretKey: secretKey);
+
+/// `link_device` on a `start_node_instance` node
+LinkedDeviceDto  linkDeviceOn({required int handle , required String qrPayload , required String secretKey }) => RustLib.instance.api.crateApiLinkDeviceOn(handle: handle, qrPayload: qrPayload, secretKey: secretKey);
+
+/// Devices linked to this device's user keys, oldest link first
+List<LinkedDeviceDto>  listLinkedDevices() => RustLib.instance.api.crateApiListLinkedDevices();
+
+/// `list_linked_devices` of a `start_node_instance` node
+List<LinkedDeviceDto>  listLinkedDevicesOn({required int handle }) => RustLib.instance.api.crateApiListLinkedDevicesOn(handle: handle);
 
 /// Send latency request to measure peer latency
-Future<void> sendLatencyRequest({required String peerId}) =>
-    RustLib.instance.api.crateApiSendLatencyRequest(peerId: peerId);
-
-/// Store data in local database with signature for sync
-Future<void> storeData({
-  required String dbName,
-  required String key,
-  required List<int> value,
-  required String publicKey,
-  required String signature,
-}) => RustLib.instance.api.crateApiStoreData(
-  dbName: dbName,
-  key: key,
-  value: value,
-  publicKey: publicKey,
-  signature: signature,
-);
-
-/// Store data without signature (local only, not synced)
-Future<void> storeDataLocal({
-  required String dbName,
-  required String key,
-  required List<int> value,
-}) => RustLib.instance.api.crateApiStoreDataLocal(
-  dbName: dbName,
-  key: key,
-  value: value,
-);
-
-/// Get data from local database
-Future<Uint8List?> getData({required String dbName, required String key}) =>
-    RustLib.instance.api.crateApiGetData(dbName: dbName, key: key);
+Future<void>  sendLatencyRequest({required String peerId }) => RustLib.instance.api.crateApiSendLatencyRequest(peerId: peerId);
+
+/// `send_latency_request` on a `start_node_instance` node
+Future<void>  sendLatencyRequestOn({required int handle , required String peerId }) => RustLib.instance.api.crateApiSendLatencyRequestOn(handle: handle, peerId: peerId);
+
+/// Measure one-way latency to a peer in milliseconds (half a direct ping
+/// round trip). Also updates the peer's `latency_ms`.
+Future<BigInt>  measureLatency({required String peerId }) => RustLib.instance.api.crateApiMeasureLatency(peerId: peerId);
+
+/// `measure_latency` on a `start_node_instance` node
+Future<BigInt>  measureLatencyOn({required int handle , required String peerId }) => RustLib.instance.api.crateApiMeasureLatencyOn(handle: handle, peerId: peerId);
+
+/// Challenge a peer over a direct connection to prove it controls its node
+/// ID and the ed25519 key it advertises. Do this before trusting a peer
+/// with sensitive operations; a proof from the last hour is reused.
+Future<VerifiedBindingDto>  proveOwnership({required String peerId }) => RustLib.instance.api.crateApiProveOwnership(peerId: peerId);
+
+/// `prove_ownership` on a `start_node_instance` node
+Future<VerifiedBindingDto>  proveOwnershipOn({required int handle , required String peerId }) => RustLib.instance.api.crateApiProveOwnershipOn(handle: handle, peerId: peerId);
+
+/// Whether a peer recently proved it holds `public_key` (see `prove_ownership`)
+bool  isVerifiedPeerKey({required String peerId , required String publicKey }) => RustLib.instance.api.crateApiIsVerifiedPeerKey(peerId: peerId, publicKey: publicKey);
+
+/// `is_verified_peer_key` of a `start_node_instance` node
+bool  isVerifiedPeerKeyOn({required int handle , required String peerId , required String publicKey }) => RustLib.instance.api.crateApiIsVerifiedPeerKeyOn(handle: handle, peerId: peerId, publicKey: publicKey);
+
+/// Our node ID, direct addresses and relay URLs
+NodeAddrDto  getNodeAddr() => RustLib.instance.api.crateApiGetNodeAddr();
+
+/// `get_node_addr` of a `start_node_instance` node
+NodeAddrDto  getNodeAddrOn({required int handle }) => RustLib.instance.api.crateApiGetNodeAddrOn(handle: handle);
+
+/// Short string with our node ID and addresses, e.g. to show as a QR code.
+/// Another device passes it to `connect_peer` as the node ID to pair
+/// without gossip discovery.
+String  getNodeTicket() => RustLib.instance.api.crateApiGetNodeTicket();
+
+/// `get_node_ticket` of a `start_node_instance` node
+String  getNodeTicketOn({required int handle }) => RustLib.instance.api.crateApiGetNodeTicketOn(handle: handle);
+
+/// Dial a peer by node ID or ticket (see `get_node_ticket`), optionally at
+/// a known `ip:port` or `host:port` (e.g. pasted or scanned for pairing).
+/// Host names are looked up on every dial. Without an
+/// address the peer is found through the ticket, address lookup and relays.
+Future<ConnectionResultDto>  connectPeer({required String nodeId , String? address }) => RustLib.instance.api.crateApiConnectPeer(nodeId: nodeId, address: address);
+
+/// `connect_peer` on a `start_node_instance` node
+Future<ConnectionResultDto>  connectPeerOn({required int handle , required String nodeId , String? address }) => RustLib.instance.api.crateApiConnectPeerOn(handle: handle, nodeId: nodeId, address: address);
+
+/// Store data in local database with signature for sync. Values over 64 KiB
+/// are kept in the blob store and replicated as a reference; `get_data`
+/// returns the bytes either way.
+Future<void>  storeData({required String dbName , required String key , required List<int> value , required String publicKey , required String signature }) => RustLib.instance.api.crateApiStoreData(dbName: dbName, key: key, value: value, publicKey: publicKey, signature: signature);
+
+/// Store data signed with this node's key, so Flutter doesn't sign anything.
+/// `db_name` must be named after the node's public key (see
+/// `generate_db_name`) or list it as a writer. Returns the op_id.
+Future<String>  storeDataSigned({required String dbName , required String key , required List<int> value , required String storeType }) => RustLib.instance.api.crateApiStoreDataSigned(dbName: dbName, key: key, value: value, storeType: storeType);
+
+/// `store_data_signed` on a `start_node_instance` node
+Future<String>  storeDataSignedOn({required int handle , required String dbName , required String key , required List<int> value , required String storeType }) => RustLib.instance.api.crateApiStoreDataSignedOn(handle: handle, dbName: dbName, key: key, value: value, storeType: storeType);
+
+/// `store_data` on a `start_node_instance` node
+Future<void>  storeDataOn({required int handle , required String dbName , required String key , required List<int> value , required String publicKey , required String signature }) => RustLib.instance.api.crateApiStoreDataOn(handle: handle, dbName: dbName, key: key, value: value, publicKey: publicKey, signature: signature);
+
+/// Store many signed entries at once (e.g. importing contacts): one storage
+/// batch and a single flush instead of one per entry. Returns the count stored.
+Future<int>  storeBatch({required List<BatchEntryDto> entries }) => RustLib.instance.api.crateApiStoreBatch(entries: entries);
+
+/// `store_batch` on a `start_node_instance` node
+Future<int>  storeBatchOn({required int handle , required List<BatchEntryDto> entries }) => RustLib.instance.api.crateApiStoreBatchOn(handle: handle, entries: entries);
+
+/// Store a value that expires after `ttl_secs` on this device and every peer
+/// (e.g. presence/status). Signed with `secret_key`, like `store_encrypted`.
+Future<void>  storeDataWithTtl({required String dbName , required String key , required List<int> value , required BigInt ttlSecs , required String secretKey }) => RustLib.instance.api.crateApiStoreDataWithTtl(dbName: dbName, key: key, value: value, ttlSecs: ttlSecs, secretKey: secretKey);
+
+/// `store_data_with_ttl` on a `start_node_instance` node
+Future<void>  storeDataWithTtlOn({required int handle , required String dbName , required String key , required List<int> value , required BigInt ttlSecs , required String secretKey }) => RustLib.instance.api.crateApiStoreDataWithTtlOn(handle: handle, dbName: dbName, key: key, value: value, ttlSecs: ttlSecs, secretKey: secretKey);
+
+/// Store a value whose op_id is derived from its content and `timestamp_ms`,
+/// so retrying a write that may have failed (with the same timestamp) doesn't
+/// create a duplicate operation. Signed with `secret_key`; returns the op_id.
+Future<String>  storeDataIdempotent({required String dbName , required String key , required List<int> value , required PlatformInt64 timestampMs , required String secretKey }) => RustLib.instance.api.crateApiStoreDataIdempotent(dbName: dbName, key: key, value: value, timestampMs: timestampMs, secretKey: secretKey);
+
+/// `store_data_idempotent` on a `start_node_instance` node
+Future<String>  storeDataIdempotentOn({required int handle , required String dbName , required String key , required List<int> value , required PlatformInt64 timestampMs , required String secretKey }) => RustLib.instance.api.crateApiStoreDataIdempotentOn(handle: handle, dbName: dbName, key: key, value: value, timestampMs: timestampMs, secretKey: secretKey);
+
+/// Add `delta` (may be negative) to a replicated counter and return its new
+/// value. Concurrent increments on different devices add up.
+Future<PlatformInt64>  incr({required String dbName , required String key , required PlatformInt64 delta , required String secretKey }) => RustLib.instance.api.crateApiIncr(dbName: dbName, key: key, delta: delta, secretKey: secretKey);
+
+/// `incr` on a `start_node_instance` node
+Future<PlatformInt64>  incrOn({required int handle , required String dbName , required String key , required PlatformInt64 delta , required String secretKey }) => RustLib.instance.api.crateApiIncrOn(handle: handle, dbName: dbName, key: key, delta: delta, secretKey: secretKey);
+
+/// Current value of a counter (0 if it was never incremented)
+PlatformInt64  getCounter({required String dbName , required String key }) => RustLib.instance.api.crateApiGetCounter(dbName: dbName, key: key);
+
+/// `get_counter` of a `start_node_instance` node
+PlatformInt64  getCounterOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiGetCounterOn(handle: handle, dbName: dbName, key: key);
+
+/// Edit a collaborative text: replace `delete` characters at `index` with
+/// `insert` and return the new text. Positions count Unicode scalar values.
+/// Concurrent edits on different devices merge.
+Future<String>  editText({required String dbName , required String key , required BigInt index , required BigInt delete , required String insert , required String secretKey }) => RustLib.instance.api.crateApiEditText(dbName: dbName, key: key, index: index, delete: delete, insert: insert, secretKey: secretKey);
+
+/// `edit_text` on a `start_node_instance` node
+Future<String>  editTextOn({required int handle , required String dbName , required String key , required BigInt index , required BigInt delete , required String insert , required String secretKey }) => RustLib.instance.api.crateApiEditTextOn(handle: handle, dbName: dbName, key: key, index: index, delete: delete, insert: insert, secretKey: secretKey);
+
+/// Current value of a collaborative text (empty if it was never edited)
+String  getText({required String dbName , required String key }) => RustLib.instance.api.crateApiGetText(dbName: dbName, key: key);
+
+/// `get_text` of a `start_node_instance` node
+String  getTextOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiGetTextOn(handle: handle, dbName: dbName, key: key);
+
+/// Stream changes of collaborative texts made by other devices, to apply to
+/// an open editor
+Stream<TextDeltaDto>  textDeltaStream() => RustLib.instance.api.crateApiTextDeltaStream();
+
+/// `text_delta_stream` on a `start_node_instance` node
+Stream<TextDeltaDto>  textDeltaStreamOn({required int handle }) => RustLib.instance.api.crateApiTextDeltaStreamOn(handle: handle);
+
+/// Set a string value
+Future<void>  putString({required String dbName , required String key , required String value , required String secretKey }) => RustLib.instance.api.crateApiPutString(dbName: dbName, key: key, value: value, secretKey: secretKey);
+
+/// `put_string` on a `start_node_instance` node
+Future<void>  putStringOn({required int handle , required String dbName , required String key , required String value , required String secretKey }) => RustLib.instance.api.crateApiPutStringOn(handle: handle, dbName: dbName, key: key, value: value, secretKey: secretKey);
+
+/// Set a JSON document; `json` must encode a map (e.g. `jsonEncode(map)`)
+Future<void>  putJson({required String dbName , required String key , required String json , required String secretKey }) => RustLib.instance.api.crateApiPutJson(dbName: dbName, key: key, json: json, secretKey: secretKey);
+
+/// `put_json` on a `start_node_instance` node
+Future<void>  putJsonOn({required int handle , required String dbName , required String key , required String json , required String secretKey }) => RustLib.instance.api.crateApiPutJsonOn(handle: handle, dbName: dbName, key: key, json: json, secretKey: secretKey);
+
+/// Set a field of a hash
+Future<void>  hashSet({required String dbName , required String key , required String field , required String value , required String secretKey }) => RustLib.instance.api.crateApiHashSet(dbName: dbName, key: key, field: field, value: value, secretKey: secretKey);
+
+/// `hash_set` on a `start_node_instance` node
+Future<void>  hashSetOn({required int handle , required String dbName , required String key , required String field , required String value , required String secretKey }) => RustLib.instance.api.crateApiHashSetOn(handle: handle, dbName: dbName, key: key, field: field, value: value, secretKey: secretKey);
+
+/// A field of a hash
+Future<String?>  hashGet({required String dbName , required String key , required String field }) => RustLib.instance.api.crateApiHashGet(dbName: dbName, key: key, field: field);
+
+/// `hash_get` on a `start_node_instance` node
+Future<String?>  hashGetOn({required int handle , required String dbName , required String key , required String field }) => RustLib.instance.api.crateApiHashGetOn(handle: handle, dbName: dbName, key: key, field: field);
+
+/// All fields of a hash, by field name
+List<HashEntryDto>  hashGetAll({required String dbName , required String key }) => RustLib.instance.api.crateApiHashGetAll(dbName: dbName, key: key);
+
+/// `hash_get_all` on a `start_node_instance` node
+List<HashEntryDto>  hashGetAllOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiHashGetAllOn(handle: handle, dbName: dbName, key: key);
+
+/// Append an element to a list. Pushes from different devices interleave
+/// by write time.
+Future<void>  listPush({required String dbName , required String key , required String value , required String secretKey }) => RustLib.instance.api.crateApiListPush(dbName: dbName, key: key, value: value, secretKey: secretKey);
+
+/// `list_push` of a `start_node_instance` node
+Future<void>  listPushOn({required int handle , required String dbName , required String key , required String value , required String secretKey }) => RustLib.instance.api.crateApiListPushOn(handle: handle, dbName: dbName, key: key, value: value, secretKey: secretKey);
+
+/// Elements of a list in push order
+List<String>  listRange({required String dbName , required String key }) => RustLib.instance.api.crateApiListRange(dbName: dbName, key: key);
+
+/// `list_range` of a `start_node_instance` node
+List<String>  listRangeOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiListRangeOn(handle: handle, dbName: dbName, key: key);
+
+/// Add a member to a set
+Future<void>  setAdd({required String dbName , required String key , required String member , required String secretKey }) => RustLib.instance.api.crateApiSetAdd(dbName: dbName, key: key, member: member, secretKey: secretKey);
+
+/// `set_add` on a `start_node_instance` node
+Future<void>  setAddOn({required int handle , required String dbName , required String key , required String member , required String secretKey }) => RustLib.instance.api.crateApiSetAddOn(handle: handle, dbName: dbName, key: key, member: member, secretKey: secretKey);
+
+/// Members of a set, sorted
+List<String>  setMembers({required String dbName , required String key }) => RustLib.instance.api.crateApiSetMembers(dbName: dbName, key: key);
+
+/// `set_members` on a `start_node_instance` node
+List<String>  setMembersOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiSetMembersOn(handle: handle, dbName: dbName, key: key);
+
+/// Add a member to a sorted set, or change its score
+Future<void>  zsetAdd({required String dbName , required String key , required String member , required double score , required String secretKey }) => RustLib.instance.api.crateApiZsetAdd(dbName: dbName, key: key, member: member, score: score, secretKey: secretKey);
+
+/// `zset_add` on a `start_node_instance` node
+Future<void>  zsetAddOn({required int handle , required String dbName , required String key , required String member , required double score , required String secretKey }) => RustLib.instance.api.crateApiZsetAddOn(handle: handle, dbName: dbName, key: key, member: member, score: score, secretKey: secretKey);
+
+/// Members of a sorted set with a score between `min` and `max` (inclusive,
+/// unbounded if `None`), lowest score first
+List<ScoredMemberDto>  zsetRange({required String dbName , required String key , double? min , double? max }) => RustLib.instance.api.crateApiZsetRange(dbName: dbName, key: key, min: min, max: max);
+
+/// `zset_range` on a `start_node_instance` node
+List<ScoredMemberDto>  zsetRangeOn({required int handle , required String dbName , required String key , double? min , double? max }) => RustLib.instance.api.crateApiZsetRangeOn(handle: handle, dbName: dbName, key: key, min: min, max: max);
+
+/// Add a sample to a time series; a sample at the same timestamp replaces it
+Future<void>  tsAdd({required String dbName , required String key , required PlatformInt64 timestampMs , required double value , required String secretKey }) => RustLib.instance.api.crateApiTsAdd(dbName: dbName, key: key, timestampMs: timestampMs, value: value, secretKey: secretKey);
+
+/// `ts_add` on a `start_node_instance` node
+Future<void>  tsAddOn({required int handle , required String dbName , required String key , required PlatformInt64 timestampMs , required double value , required String secretKey }) => RustLib.instance.api.crateApiTsAddOn(handle: handle, dbName: dbName, key: key, timestampMs: timestampMs, value: value, secretKey: secretKey);
+
+/// Samples of a time series between `from_ms` and `to_ms` (inclusive),
+/// oldest first
+List<TimePointDto>  tsRange({required String dbName , required String key , required PlatformInt64 fromMs , required PlatformInt64 toMs }) => RustLib.instance.api.crateApiTsRange(dbName: dbName, key: key, fromMs: fromMs, toMs: toMs);
+
+/// `ts_range` on a `start_node_instance` node
+List<TimePointDto>  tsRangeOn({required int handle , required String dbName , required String key , required PlatformInt64 fromMs , required PlatformInt64 toMs }) => RustLib.instance.api.crateApiTsRangeOn(handle: handle, dbName: dbName, key: key, fromMs: fromMs, toMs: toMs);
+
+/// Add a member to a geo set, or move it
+Future<void>  geoAdd({required String dbName , required String key , required String member , required double latitude , required double longitude , required String secretKey }) => RustLib.instance.api.crateApiGeoAdd(dbName: dbName, key: key, member: member, latitude: latitude, longitude: longitude, secretKey: secretKey);
+
+/// `geo_add` on a `start_node_instance` node
+Future<void>  geoAddOn({required int handle , required String dbName , required String key , required String member , required double latitude , required double longitude , required String secretKey }) => RustLib.instance.api.crateApiGeoAddOn(handle: handle, dbName: dbName, key: key, member: member, latitude: latitude, longitude: longitude, secretKey: secretKey);
+
+/// Members of a geo set within `radius_m` meters of a point, nearest first
+List<GeoMatchDto>  geoRadius({required String dbName , required String key , required double latitude , required double longitude , required double radiusM }) => RustLib.instance.api.crateApiGeoRadius(dbName: dbName, key: key, latitude: latitude, longitude: longitude, radiusM: radiusM);
+
+/// `geo_radius` on a `start_node_instance` node
+List<GeoMatchDto>  geoRadiusOn({required int handle , required String dbName , required String key , required double latitude , required double longitude , required double radiusM }) => RustLib.instance.api.crateApiGeoRadiusOn(handle: handle, dbName: dbName, key: key, latitude: latitude, longitude: longitude, radiusM: radiusM);
+
+/// Atomically set `key` to `new_value` if it currently equals `expected`
+/// (`None` = absent; a `new_value` of `None` deletes). Local only, not synced.
+/// Returns whether the swap happened.
+bool  compareAndSwap({required String dbName , required String key , Uint8List? expected , Uint8List? newValue }) => RustLib.instance.api.crateApiCompareAndSwap(dbName: dbName, key: key, expected: expected, newValue: newValue);
+
+/// `compare_and_swap` on a `start_node_instance` node
+bool  compareAndSwapOn({required int handle , required String dbName , required String key , Uint8List? expected , Uint8List? newValue }) => RustLib.instance.api.crateApiCompareAndSwapOn(handle: handle, dbName: dbName, key: key, expected: expected, newValue: newValue);
+
+/// Store data without signature on this device only: it never enters the
+/// oplog and is not sent to peers
+Future<void>  storeDataLocal({required String dbName , required String key , required List<int> value }) => RustLib.instance.api.crateApiStoreDataLocal(dbName: dbName, key: key, value: value);
+
+/// `store_data_local` on a `start_node_instance` node
+Future<void>  storeDataLocalOn({required int handle , required String dbName , required String key , required List<int> value }) => RustLib.instance.api.crateApiStoreDataLocalOn(handle: handle, dbName: dbName, key: key, value: value);
+
+/// Keep a database on this device only (`true`), or sync it again. Marking
+/// it removes its operations from the oplog, so nothing of it is sent to or
+/// accepted from peers; its data stays. Once synced again, only later
+/// writes are replicated.
+Future<void>  setDatabaseLocalOnly({required String dbName , required bool localOnly }) => RustLib.instance.api.crateApiSetDatabaseLocalOnly(dbName: dbName, localOnly: localOnly);
+
+/// `set_database_local_only` on a `start_node_instance` node
+Future<void>  setDatabaseLocalOnlyOn({required int handle , required String dbName , required bool localOnly }) => RustLib.instance.api.crateApiSetDatabaseLocalOnlyOn(handle: handle, dbName: dbName, localOnly: localOnly);
+
+/// Whether a database is kept on this device only
+bool  isDatabaseLocalOnly({required String dbName }) => RustLib.instance.api.crateApiIsDatabaseLocalOnly(dbName: dbName);
+
+/// `is_database_local_only` of a `start_node_instance` node
+bool  isDatabaseLocalOnlyOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiIsDatabaseLocalOnlyOn(handle: handle, dbName: dbName);
+
+/// Get data from local database; large values not on this device yet are
+/// fetched from peers
+Future<Uint8List?>  getData({required String dbName , required String key }) => RustLib.instance.api.crateApiGetData(dbName: dbName, key: key);
+
+/// `get_data` on a `start_node_instance` node
+Future<Uint8List?>  getDataOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiGetDataOn(handle: handle, dbName: dbName, key: key);
+
+/// Get several values of one database at once; results are in the order of
+/// `keys`, `None` for missing keys
+Future<List<Uint8List?>>  getMany({required String dbName , required List<String> keys }) => RustLib.instance.api.crateApiGetMany(dbName: dbName, keys: keys);
+
+/// `get_many` of a `start_node_instance` node
+Future<List<Uint8List?>>  getManyOn({required int handle , required String dbName , required List<String> keys }) => RustLib.instance.api.crateApiGetManyOn(handle: handle, dbName: dbName, keys: keys);
+
+/// Offline full-text search of a database: entries whose (string or JSON)
+/// value contains every word of `query`, the last word matching as a prefix.
+/// Encrypted values are not searchable. At most `limit` (up to 100) hits, in
+/// key order.
+Future<List<DbEntryDto>>  search({required String dbName , required String query , required int limit }) => RustLib.instance.api.crateApiSearch(dbName: dbName, query: query, limit: limit);
+
+/// `search` on a `start_node_instance` node
+Future<List<DbEntryDto>>  searchOn({required int handle , required String dbName , required String query , required int limit }) => RustLib.instance.api.crateApiSearchOn(handle: handle, dbName: dbName, query: query, limit: limit);
+
+/// Query a database: key-prefix and JSON field filters, sorting, offset/limit
+/// and projection, all evaluated in Rust. Values that aren't JSON objects only
+/// match queries without filters, sorting or projection; encrypted databases
+/// aren't queryable.
+Future<QueryResultDto>  query({required String dbName , required QuerySpecDto spec }) => RustLib.instance.api.crateApiQuery(dbName: dbName, spec: spec);
+
+/// `query` on a `start_node_instance` node
+Future<QueryResultDto>  queryOn({required int handle , required String dbName , required QuerySpecDto spec }) => RustLib.instance.api.crateApiQueryOn(handle: handle, dbName: dbName, spec: spec);
 
 /// Request sync from peers
-Future<void> requestSync({PlatformInt64? sinceTimestamp}) =>
-    RustLib.instance.api.crateApiRequestSync(sinceTimestamp: sinceTimestamp);
+Future<void>  requestSync({PlatformInt64? sinceTimestamp }) => RustLib.instance.api.crateApiRequestSync(sinceTimestamp: sinceTimestamp);
+
+/// `request_sync` on a `start_node_instance` node
+Future<void>  requestSyncOn({required int handle , PlatformInt64? sinceTimestamp }) => RustLib.instance.api.crateApiRequestSyncOn(handle: handle, sinceTimestamp: sinceTimestamp);
+
+/// Get sync statistics (ops/bytes transferred, last sync time, per-peer status)
+Future<SyncStatsDto>  getSyncStats() => RustLib.instance.api.crateApiGetSyncStats();
+
+/// `get_sync_stats` of a `start_node_instance` node
+Future<SyncStatsDto>  getSyncStatsOn({required int handle }) => RustLib.instance.api.crateApiGetSyncStatsOn(handle: handle);
+
+/// Authorize another public key to write to a database (owner only)
+Future<void>  grantWriter({required String dbName , required String writerPublicKey , required String ownerSecretKey }) => RustLib.instance.api.crateApiGrantWriter(dbName: dbName, writerPublicKey: writerPublicKey, ownerSecretKey: ownerSecretKey);
+
+/// `grant_writer` on a `start_node_instance` node
+Future<void>  grantWriterOn({required int handle , required String dbName , required String writerPublicKey , required String ownerSecretKey }) => RustLib.instance.api.crateApiGrantWriterOn(handle: handle, dbName: dbName, writerPublicKey: writerPublicKey, ownerSecretKey: ownerSecretKey);
+
+/// Revoke a writer's access to a database (owner only)
+Future<void>  revokeWriter({required String dbName , required String writerPublicKey , required String ownerSecretKey }) => RustLib.instance.api.crateApiRevokeWriter(dbName: dbName, writerPublicKey: writerPublicKey, ownerSecretKey: ownerSecretKey);
+
+/// `revoke_writer` on a `start_node_instance` node
+Future<void>  revokeWriterOn({required int handle , required String dbName , required String writerPublicKey , required String ownerSecretKey }) => RustLib.instance.api.crateApiRevokeWriterOn(handle: handle, dbName: dbName, writerPublicKey: writerPublicKey, ownerSecretKey: ownerSecretKey);
+
+/// Delete a whole database (owner only). The drop is signed and replicated,
+/// so every peer holding the database removes it too.
+Future<void>  dropDatabase({required String dbName , required String ownerSecretKey }) => RustLib.instance.api.crateApiDropDatabase(dbName: dbName, ownerSecretKey: ownerSecretKey);
+
+/// `drop_database` on a `start_node_instance` node
+Future<void>  dropDatabaseOn({required int handle , required String dbName , required String ownerSecretKey }) => RustLib.instance.api.crateApiDropDatabaseOn(handle: handle, dbName: dbName, ownerSecretKey: ownerSecretKey);
+
+/// List public keys authorized to write to a database (besides its owner)
+List<String>  getWriters({required String dbName }) => RustLib.instance.api.crateApiGetWriters(dbName: dbName);
+
+/// `get_writers` of a `start_node_instance` node
+List<String>  getWritersOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiGetWritersOn(handle: handle, dbName: dbName);
+
+/// Create an end-to-end encrypted database: generates its symmetric key,
+/// which never leaves this device unless exported
+void  createEncryptedDb({required String dbName }) => RustLib.instance.api.crateApiCreateEncryptedDb(dbName: dbName);
+
+/// `create_encrypted_db` on a `start_node_instance` node
+void  createEncryptedDbOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiCreateEncryptedDbOn(handle: handle, dbName: dbName);
+
+/// Encrypt a value client-side, sign the ciphertext with `secret_key` and sync it
+Future<void>  storeEncrypted({required String dbName , required String key , required List<int> value , required String secretKey }) => RustLib.instance.api.crateApiStoreEncrypted(dbName: dbName, key: key, value: value, secretKey: secretKey);
+
+/// `store_encrypted` on a `start_node_instance` node
+Future<void>  storeEncryptedOn({required int handle , required String dbName , required String key , required List<int> value , required String secretKey }) => RustLib.instance.api.crateApiStoreEncryptedOn(handle: handle, dbName: dbName, key: key, value: value, secretKey: secretKey);
+
+/// Read and decrypt a value from an encrypted database
+Uint8List?  getDecrypted({required String dbName , required String key }) => RustLib.instance.api.crateApiGetDecrypted(dbName: dbName, key: key);
+
+/// `get_decrypted` of a `start_node_instance` node
+Uint8List?  getDecryptedOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiGetDecryptedOn(handle: handle, dbName: dbName, key: key);
+
+/// Export an encrypted database's key (hex) to explicitly share it
+String  exportDbKey({required String dbName }) => RustLib.instance.api.crateApiExportDbKey(dbName: dbName);
+
+/// `export_db_key` on a `start_node_instance` node
+String  exportDbKeyOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiExportDbKeyOn(handle: handle, dbName: dbName);
+
+/// Import an encrypted database's key shared from another device
+void  importDbKey({required String dbName , required String keyHex }) => RustLib.instance.api.crateApiImportDbKey(dbName: dbName, keyHex: keyHex);
+
+/// `import_db_key` on a `start_node_instance` node
+void  importDbKeyOn({required int handle , required String dbName , required String keyHex }) => RustLib.instance.api.crateApiImportDbKeyOn(handle: handle, dbName: dbName, keyHex: keyHex);
+
+/// Create a signed invite token for a database (owner only). Write invites
+/// authorize a fresh writer key; the token then contains its secret (and the
+/// database key if `include_encryption_key`), so share it privately.
+Future<String>  createDbInvite({required String dbName , required String ownerSecretKey , required bool canWrite , required bool includeEncryptionKey , required BigInt expiresInSecs }) => RustLib.instance.api.crateApiCreateDbInvite(dbName: dbName, ownerSecretKey: ownerSecretKey, canWrite: canWrite, includeEncryptionKey: includeEncryptionKey, expiresInSecs: expiresInSecs);
+
+/// `create_db_invite` on a `start_node_instance` node
+Future<String>  createDbInviteOn({required int handle , required String dbName , required String ownerSecretKey , required bool canWrite , required bool includeEncryptionKey , required BigInt expiresInSecs }) => RustLib.instance.api.crateApiCreateDbInviteOn(handle: handle, dbName: dbName, ownerSecretKey: ownerSecretKey, canWrite: canWrite, includeEncryptionKey: includeEncryptionKey, expiresInSecs: expiresInSecs);
+
+/// Redeem an invite token: registers the database in the sync filter and
+/// applies its writer list and encryption key
+AcceptedInviteDto  acceptDbInvite({required String token }) => RustLib.instance.api.crateApiAcceptDbInvite(token: token);
+
+/// `accept_db_invite` on a `start_node_instance` node
+AcceptedInviteDto  acceptDbInviteOn({required int handle , required String token }) => RustLib.instance.api.crateApiAcceptDbInviteOn(handle: handle, token: token);
+
+/// Only sync followed databases (`true`) or every database (`false`, default)
+void  setSelectiveSync({required bool selective }) => RustLib.instance.api.crateApiSetSelectiveSync(selective: selective);
+
+/// `set_selective_sync` on a `start_node_instance` node
+void  setSelectiveSyncOn({required int handle , required bool selective }) => RustLib.instance.api.crateApiSetSelectiveSyncOn(handle: handle, selective: selective);
+
+/// Follow a database so it is synced in selective sync mode
+void  followDatabase({required String dbName }) => RustLib.instance.api.crateApiFollowDatabase(dbName: dbName);
+
+/// `follow_database` on a `start_node_instance` node
+void  followDatabaseOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiFollowDatabaseOn(handle: handle, dbName: dbName);
+
+/// Stop following a database
+void  unfollowDatabase({required String dbName }) => RustLib.instance.api.crateApiUnfollowDatabase(dbName: dbName);
+
+/// `unfollow_database` on a `start_node_instance` node
+void  unfollowDatabaseOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiUnfollowDatabaseOn(handle: handle, dbName: dbName);
+
+/// List followed databases
+List<String>  getFollowedDatabases() => RustLib.instance.api.crateApiGetFollowedDatabases();
+
+/// `get_followed_databases` of a `start_node_instance` node
+List<String>  getFollowedDatabasesOn({required int handle }) => RustLib.instance.api.crateApiGetFollowedDatabasesOn(handle: handle);
 
 /// Sign a message using Ed25519
-String signMessageWithKey({
-  required String secretKeyHex,
-  required String message,
-}) => RustLib.instance.api.crateApiSignMessageWithKey(
-  secretKeyHex: secretKeyHex,
-  message: message,
-);
+String  signMessageWithKey({required String secretKeyHex , required String message }) => RustLib.instance.api.crateApiSignMessageWithKey(secretKeyHex: secretKeyHex, message: message);
 
 /// Verify an Ed25519 signature
-bool verifyMessageSignature({
-  required String publicKeyHex,
-  required String message,
-  required String signatureHex,
-}) => RustLib.instance.api.crateApiVerifyMessageSignature(
-  publicKeyHex: publicKeyHex,
-  message: message,
-  signatureHex: signatureHex,
-);
+bool  verifyMessageSignature({required String publicKeyHex , required String message , required String signatureHex }) => RustLib.instance.api.crateApiVerifyMessageSignature(publicKeyHex: publicKeyHex, message: message, signatureHex: signatureHex);
+
+/// Sign raw bytes (e.g. a file hash or CBOR payload) using Ed25519
+String  signBytes({required String secretKeyHex , required List<int> message }) => RustLib.instance.api.crateApiSignBytes(secretKeyHex: secretKeyHex, message: message);
+
+/// Verify an Ed25519 signature over raw bytes
+bool  verifyBytes({required String publicKeyHex , required List<int> message , required String signatureHex }) => RustLib.instance.api.crateApiVerifyBytes(publicKeyHex: publicKeyHex, message: message, signatureHex: signatureHex);
+
+/// BLAKE3 hash of `data` (hex), for content addressing
+String  blake3Hash({required List<int> data }) => RustLib.instance.api.crateApiBlake3Hash(data: data);
+
+/// SHA-256 hash of `data` (hex)
+String  sha256Hash({required List<int> data }) => RustLib.instance.api.crateApiSha256Hash(data: data);
 
 /// Generate a new Ed25519 keypair
-KeyPairDto generateKeypair() => RustLib.instance.api.crateApiGenerateKeypair();
+KeyPairDto  generateKeypair() => RustLib.instance.api.crateApiGenerateKeypair();
 
 /// Generate database name from name and public key
 /// Format: <name>-<public_key_hex> (matching cyberfly-rust-node)
-String generateDbName({required String name, required String publicKeyHex}) =>
-    RustLib.instance.api.crateApiGenerateDbName(
-      name: name,
-      publicKeyHex: publicKeyHex,
-    );
+String  generateDbName({required String name , required String publicKeyHex }) => RustLib.instance.api.crateApiGenerateDbName(name: name, publicKeyHex: publicKeyHex);
 
 /// Verify that the database name matches the public key
-bool verifyDbName({required String dbName, required String publicKeyHex}) =>
-    RustLib.instance.api.crateApiVerifyDbName(
-      dbName: dbName,
-      publicKeyHex: publicKeyHex,
-    );
+bool  verifyDbName({required String dbName , required String publicKeyHex }) => RustLib.instance.api.crateApiVerifyDbName(dbName: dbName, publicKeyHex: publicKeyHex);
 
 /// Extract name part from database name (removes public key suffix)
-String? extractNameFromDb({required String dbName}) =>
-    RustLib.instance.api.crateApiExtractNameFromDb(dbName: dbName);
+String?  extractNameFromDb({required String dbName }) => RustLib.instance.api.crateApiExtractNameFromDb(dbName: dbName);
 
 /// Validate timestamp (check if within acceptable range)
-bool validateTimestamp({required PlatformInt64 timestamp}) =>
-    RustLib.instance.api.crateApiValidateTimestamp(timestamp: timestamp);
+bool  validateTimestamp({required PlatformInt64 timestamp }) => RustLib.instance.api.crateApiValidateTimestamp(timestamp: timestamp);
 
 /// Greet function for testing
-String greet({required String name}) =>
-    RustLib.instance.api.crateApiGreet(name: name);
+String  greet({required String name }) => RustLib.instance.api.crateApiGreet(name: name);
 
 /// Generate libp2p PeerId from secret key (for Kadena blockchain registration)
 /// This matches the desktop cyberfly-rust-node implementation for backward compatibility
-String generatePeerIdFromSecretKey({required String secretKeyHex}) => RustLib
-    .instance
-    .api
-    .crateApiGeneratePeerIdFromSecretKey(secretKeyHex: secretKeyHex);
+String  generatePeerIdFromSecretKey({required String secretKeyHex }) => RustLib.instance.api.crateApiGeneratePeerIdFromSecretKey(secretKeyHex: secretKeyHex);
+
+/// Kadena `k:` account of an ed25519 public key (hex)
+String  kadenaAccountFromPublicKey({required String publicKeyHex }) => RustLib.instance.api.crateApiKadenaAccountFromPublicKey(publicKeyHex: publicKeyHex);
+
+/// Hash a Kadena command (its JSON) and sign the hash with a secret key (hex)
+KadenaTransactionDto  kadenaSignCommand({required String cmd , required String secretKeyHex }) => RustLib.instance.api.crateApiKadenaSignCommand(cmd: cmd, secretKeyHex: secretKeyHex);
+
+/// Build the command registering the node in the Cyberfly node contract, to
+/// sign with `kadena_sign_command`. The `k:` account of `public_key_hex`
+/// owns the entry and pays gas.
+String  kadenaNodeRegistrationCommand({required String networkId , required String chainId , required String peerId , required String multiaddr , required String publicKeyHex }) => RustLib.instance.api.crateApiKadenaNodeRegistrationCommand(networkId: networkId, chainId: chainId, peerId: peerId, multiaddr: multiaddr, publicKeyHex: publicKeyHex);
 
 /// List all databases in storage
-List<String> listDatabases() => RustLib.instance.api.crateApiListDatabases();
+List<String>  listDatabases() => RustLib.instance.api.crateApiListDatabases();
 
 /// List all keys in a specific database
-List<String> listKeys({required String dbName}) =>
-    RustLib.instance.api.crateApiListKeys(dbName: dbName);
+List<String>  listKeys({required String dbName }) => RustLib.instance.api.crateApiListKeys(dbName: dbName);
+
+/// `list_databases` of a `start_node_instance` node
+List<String>  listDatabasesOn({required int handle }) => RustLib.instance.api.crateApiListDatabasesOn(handle: handle);
+
+/// `list_keys` of a `start_node_instance` node
+List<String>  listKeysOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiListKeysOn(handle: handle, dbName: dbName);
+
+/// Get all entries from a specific database. Loads the whole database into
+/// memory; prefer `get_entries_page` for large databases.
+Future<List<DbEntryDto>>  getAllEntries({required String dbName }) => RustLib.instance.api.crateApiGetAllEntries(dbName: dbName);
+
+/// `get_all_entries` of a `start_node_instance` node
+Future<List<DbEntryDto>>  getAllEntriesOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiGetAllEntriesOn(handle: handle, dbName: dbName);
+
+/// Get all entries from all databases. Loads everything into memory; prefer
+/// `get_all_data_page`.
+Future<List<DbEntryDto>>  getAllData() => RustLib.instance.api.crateApiGetAllData();
+
+/// `get_all_data` of a `start_node_instance` node
+Future<List<DbEntryDto>>  getAllDataOn({required int handle }) => RustLib.instance.api.crateApiGetAllDataOn(handle: handle);
+
+/// Entries whose key starts with `prefix`, in key order (e.g. `msg:` for
+/// `msg:0001`, `msg:0002`, ...). Pass the returned `next_cursor` to get the
+/// next page; `limit` is capped at 1000.
+ScanPageDto  scanPrefix({required String dbName , required String prefix , required int limit , String? cursor }) => RustLib.instance.api.crateApiScanPrefix(dbName: dbName, prefix: prefix, limit: limit, cursor: cursor);
+
+/// `scan_prefix` on a `start_node_instance` node
+ScanPageDto  scanPrefixOn({required int handle , required String dbName , required String prefix , required int limit , String? cursor }) => RustLib.instance.api.crateApiScanPrefixOn(handle: handle, dbName: dbName, prefix: prefix, limit: limit, cursor: cursor);
+
+/// Entries with `start <= key < end` (either bound optional), in key order.
+/// Pass the returned `next_cursor` to get the next page.
+ScanPageDto  scanRange({required String dbName , String? start , String? end , required int limit , String? cursor }) => RustLib.instance.api.crateApiScanRange(dbName: dbName, start: start, end: end, limit: limit, cursor: cursor);
+
+/// `scan_range` on a `start_node_instance` node
+ScanPageDto  scanRangeOn({required int handle , required String dbName , String? start , String? end , required int limit , String? cursor }) => RustLib.instance.api.crateApiScanRangeOn(handle: handle, dbName: dbName, start: start, end: end, limit: limit, cursor: cursor);
 
-/// Get all entries from a specific database
-Future<List<DbEntryDto>> getAllEntries({required String dbName}) =>
-    RustLib.instance.api.crateApiGetAllEntries(dbName: dbName);
+/// Page through the entries of a database in key order. `keys_only` returns
+/// empty values; `max_bytes` caps the keys + values returned per call (at
+/// least one entry is always returned). Pass `next_cursor` to continue.
+Future<ScanPageDto>  getEntriesPage({required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes }) => RustLib.instance.api.crateApiGetEntriesPage(dbName: dbName, limit: limit, cursor: cursor, keysOnly: keysOnly, maxBytes: maxBytes);
 
-/// Get all entries from all databases
-Future<List<DbEntryDto>> getAllData() =>
-    RustLib.instance.api.crateApiGetAllData();
+/// `get_entries_page` of a `start_node_instance` node
+Future<ScanPageDto>  getEntriesPageOn({required int handle , required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes }) => RustLib.instance.api.crateApiGetEntriesPageOn(handle: handle, dbName: dbName, limit: limit, cursor: cursor, keysOnly: keysOnly, maxBytes: maxBytes);
+
+/// Page through the entries of all databases, same options as `get_entries_page`
+Future<ScanPageDto>  getAllDataPage({required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes }) => RustLib.instance.api.crateApiGetAllDataPage(limit: limit, cursor: cursor, keysOnly: keysOnly, maxBytes: maxBytes);
+
+/// `get_all_data_page` of a `start_node_instance` node
+Future<ScanPageDto>  getAllDataPageOn({required int handle , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes }) => RustLib.instance.api.crateApiGetAllDataPageOn(handle: handle, limit: limit, cursor: cursor, keysOnly: keysOnly, maxBytes: maxBytes);
 
 /// Delete a key from a database
-Future<void> deleteData({required String dbName, required String key}) =>
-    RustLib.instance.api.crateApiDeleteData(dbName: dbName, key: key);
+Future<void>  deleteData({required String dbName , required String key }) => RustLib.instance.api.crateApiDeleteData(dbName: dbName, key: key);
+
+/// `delete_data` on a `start_node_instance` node
+Future<void>  deleteDataOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiDeleteDataOn(handle: handle, dbName: dbName, key: key);
+
+/// Watch a single key: emits its current value, then every change from local
+/// writes or remote sync. The stream ends when Dart cancels it.
+Stream<ValueUpdateDto>  watchKey({required String dbName , required String key }) => RustLib.instance.api.crateApiWatchKey(dbName: dbName, key: key);
+
+/// `watch_key` on a `start_node_instance` node
+Stream<ValueUpdateDto>  watchKeyOn({required int handle , required String dbName , required String key }) => RustLib.instance.api.crateApiWatchKeyOn(handle: handle, dbName: dbName, key: key);
+
+/// Watch every key of a database for changes from local writes or remote sync
+Stream<KeyChangeDto>  watchDb({required String dbName }) => RustLib.instance.api.crateApiWatchDb(dbName: dbName);
+
+/// `watch_db` on a `start_node_instance` node
+Stream<KeyChangeDto>  watchDbOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiWatchDbOn(handle: handle, dbName: dbName);
+
+/// Tail the writes (local and sync-applied) to a database in order, starting
+/// after `since_seq`. Every event carries its persisted `seq`: store the last
+/// one processed and pass it back after a restart to consume each change
+/// exactly once. Only the latest 100,000 changes are kept; a jump in `seq`
+/// past `since_seq + 1` means older ones were trimmed.
+Stream<ChangeEventDto>  changeFeed({required String dbName , required BigInt sinceSeq }) => RustLib.instance.api.crateApiChangeFeed(dbName: dbName, sinceSeq: sinceSeq);
+
+/// `change_feed` on a `start_node_instance` node
+Stream<ChangeEventDto>  changeFeedOn({required int handle , required String dbName , required BigInt sinceSeq }) => RustLib.instance.api.crateApiChangeFeedOn(handle: handle, dbName: dbName, sinceSeq: sinceSeq);
+
+/// Retain both versions on LWW conflicts (the losing local write is kept
+/// under a conflict key) instead of only emitting `ConflictResolved`
+void  setRetainConflicts({required bool enabled }) => RustLib.instance.api.crateApiSetRetainConflicts(enabled: enabled);
+
+/// `set_retain_conflicts` on a `start_node_instance` node
+void  setRetainConflictsOn({required int handle , required bool enabled }) => RustLib.instance.api.crateApiSetRetainConflictsOn(handle: handle, enabled: enabled);
+
+/// List retained conflicts of a database
+List<ConflictDto>  listConflicts({required String dbName }) => RustLib.instance.api.crateApiListConflicts(dbName: dbName);
+
+/// `list_conflicts` of a `start_node_instance` node
+List<ConflictDto>  listConflictsOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiListConflictsOn(handle: handle, dbName: dbName);
+
+/// Check stored values against the latest operation of every key in the sync
+/// log (re-verifying signatures), e.g. after a crash. With `repair`, missing
+/// and mismatched values are rewritten by replaying their operation.
+Future<IntegrityReportDto>  verifyStorage({required bool repair }) => RustLib.instance.api.crateApiVerifyStorage(repair: repair);
+
+/// `verify_storage` on a `start_node_instance` node
+Future<IntegrityReportDto>  verifyStorageOn({required int handle , required bool repair }) => RustLib.instance.api.crateApiVerifyStorageOn(handle: handle, repair: repair);
+
+/// Merkle root (hex) of this device's copy of a database; equal roots mean
+/// equal contents
+Future<String>  getDatabaseRoot({required String dbName }) => RustLib.instance.api.crateApiGetDatabaseRoot(dbName: dbName);
+
+/// `get_database_root` of a `start_node_instance` node
+Future<String>  getDatabaseRootOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiGetDatabaseRootOn(handle: handle, dbName: dbName);
+
+/// When a database last changed locally, got a peer's change and was last
+/// synced, e.g. to show "synced 2 minutes ago" per collection
+Future<DbSyncInfoDto>  getDbSyncInfo({required String dbName }) => RustLib.instance.api.crateApiGetDbSyncInfo(dbName: dbName);
+
+/// `get_db_sync_info` of a `start_node_instance` node
+Future<DbSyncInfoDto>  getDbSyncInfoOn({required int handle , required String dbName }) => RustLib.instance.api.crateApiGetDbSyncInfoOn(handle: handle, dbName: dbName);
+
+/// Wait up to `timeout_ms` until this device's writes to a database were
+/// sent and at least one peer confirmed holding them, e.g. to show "saved &
+/// synced" only when it's true. Not an error when the time runs out; check
+/// `synced`.
+Future<SyncWaitSummaryDto>  waitForSync({required String dbName , required BigInt timeoutMs }) => RustLib.instance.api.crateApiWaitForSync(dbName: dbName, timeoutMs: timeoutMs);
+
+/// `wait_for_sync` on a `start_node_instance` node
+Future<SyncWaitSummaryDto>  waitForSyncOn({required int handle , required String dbName , required BigInt timeoutMs }) => RustLib.instance.api.crateApiWaitForSyncOn(handle: handle, dbName: dbName, timeoutMs: timeoutMs);
+
+/// Whether this device's copy of each database matched the signed root its
+/// owner last published; mismatches are repaired from the owner
+List<ReplicaCheckDto>  listReplicaChecks() => RustLib.instance.api.crateApiListReplicaChecks();
+
+/// `list_replica_checks` of a `start_node_instance` node
+List<ReplicaCheckDto>  listReplicaChecksOn({required int handle }) => RustLib.instance.api.crateApiListReplicaChecksOn(handle: handle);
 
 /// Get recent logs from the buffer
-List<LogEntry> getLogs({int? limit}) =>
-    RustLib.instance.api.crateApiGetLogs(limit: limit);
+List<LogEntry>  getLogs({int? limit }) => RustLib.instance.api.crateApiGetLogs(limit: limit);
+
+/// Logs in the buffer matching `filter`, oldest first
+List<LogEntry>  getLogsFiltered({required LogFilterDto filter }) => RustLib.instance.api.crateApiGetLogsFiltered(filter: filter);
+
+/// Keep up to `size` log entries (500 by default, at most 50000); the
+/// oldest are dropped if the buffer holds more
+void  setLogBufferSize({required int size }) => RustLib.instance.api.crateApiSetLogBufferSize(size: size);
+
+/// Write the whole log buffer to the file at `path`, one entry per line, to
+/// attach to bug reports. Returns the number of entries written.
+Future<int>  exportLogs({required String path }) => RustLib.instance.api.crateApiExportLogs(path: path);
 
 /// Clear the log buffer
-void clearLogs() => RustLib.instance.api.crateApiClearLogs();
+void  clearLogs() => RustLib.instance.api.crateApiClearLogs();
+
+            /// Database shared with this device through an invite
+@freezed
+sealed class AcceptedInviteDto with _$AcceptedInviteDto  {
+                
+                const factory AcceptedInviteDto({ required  String dbName, required  String owner, required  bool canWrite,  String? writerSecretKey, required  bool encrypted,}) = _AcceptedInviteDto;
+                
+                
+                
+            }
+
+/// Contents of an exported or restored backup
+@freezed
+sealed class BackupInfoDto with _$BackupInfoDto  {
+                
+                const factory BackupInfoDto({ required  PlatformInt64 createdAt, required  String publicKey, required  int treeCount, required  BigInt entryCount,}) = _BackupInfoDto;
+                
+                
+                
+            }
+
+/// Data caps for Flutter; None means no cap
+@freezed
+sealed class BandwidthCapsDto with _$BandwidthCapsDto  {
+                
+                const factory BandwidthCapsDto({  BigInt? dailyBytes,  BigInt? monthlyBytes, required  bool meteredOnly,}) = _BandwidthCapsDto;
+                
+                
+                
+            }
+
+/// Bandwidth used this session, and towards the caps today and this month (UTC)
+@freezed
+sealed class BandwidthStatsDto with _$BandwidthStatsDto  {
+                
+                const factory BandwidthStatsDto({ required  List<BandwidthUsageDto> topics, required  List<BandwidthUsageDto> subsystems, required  BigInt todayBytes, required  BigInt monthBytes, required  BandwidthCapsDto caps, required  bool capExceeded,}) = _BandwidthStatsDto;
+                
+                
+                
+            }
+
+/// Part of the node traffic is attributed to
+enum BandwidthSubsystemDto {
+                    gossip,
+sync_,
+blobs,
+discovery,
+                    ;
+                    
+                }
+
+/// Session traffic of a gossip topic (application topics are `app:<name>`)
+/// or subsystem
+@freezed
+sealed class BandwidthUsageDto with _$BandwidthUsageDto  {
+                
+                const factory BandwidthUsageDto({ required  String name, required  BandwidthSubsystemDto subsystem, required  BigInt bytesSent, required  BigInt bytesReceived,}) = _BandwidthUsageDto;
+                
+                
+                
+            }
+
+/// Entry for `store_batch`, signed like `store_data`
+@freezed
+sealed class BatchEntryDto with _$BatchEntryDto  {
+                
+                const factory BatchEntryDto({ required  String dbName, required  String key, required  Uint8List value, required  String publicKey, required  String signature,}) = _BatchEntryDto;
+                
+                
+                
+            }
+
+/// Signed bootstrap peer list to fetch at startup, see `BootstrapManifestSource`
+@freezed
+sealed class BootstrapManifestDto with _$BootstrapManifestDto  {
+                
+                const factory BootstrapManifestDto({ required  String url, required  String publisherKey,}) = _BootstrapManifestDto;
+                
+                
+                
+            }
+
+/// One logged write or delete
+@freezed
+sealed class ChangeEventDto with _$ChangeEventDto  {
+                
+                const factory ChangeEventDto({ required  BigInt seq, required  String dbName, required  String key,  String? value,  Uint8List? valueBytes,}) = _ChangeEventDto;
+                
+                
+                
+            }
+
+/// What `checkpoint` saved
+@freezed
+sealed class CheckpointDto with _$CheckpointDto  {
+                
+                const factory CheckpointDto({  PlatformInt64? highWaterMark, required  int knownPeersSaved, required  int outboxDepth, required  bool flushed, required  BigInt durationMs,}) = _CheckpointDto;
+                
+                
+                
+            }
+
+/// Local write discarded by LWW, retained for manual merge
+@freezed
+sealed class ConflictDto with _$ConflictDto  {
+                
+                const factory ConflictDto({ required  String conflictKey, required  String dbName, required  String key,  String? field, required  String keptOpId, required  String discardedOpId, required  String discardedValue, required  PlatformInt64 discardedTimestamp, required  PlatformInt64 resolvedAt,}) = _ConflictDto;
+                
+                
+                
+            }
+
+/// Connection diagnostics for "can't connect" reports
+@freezed
+sealed class ConnectionDiagnosticsDto with _$ConnectionDiagnosticsDto  {
+                
+                const factory ConnectionDiagnosticsDto({ required  List<String> localAddrs, required  List<String> publicAddrs,  String? relayUrl, required  String natType, required  List<PeerDiagnosticsDto> peers,}) = _ConnectionDiagnosticsDto;
+                
+                
+                
+            }
+
+/// Result of dialing a peer
+@freezed
+sealed class ConnectionResultDto with _$ConnectionResultDto  {
+                
+                const factory ConnectionResultDto({ required  String nodeId, required  String path, required  BigInt handshakeMs,}) = _ConnectionResultDto;
+                
+                
+                
+            }
+
+/// Connection type reported by Flutter
+enum ConnectionTypeDto {
+                    wifi,
+ethernet,
+cellular,
+other,
+offline,
+                    ;
+                    
+                }
+
+/// Connectivity and a short reason, e.g. "no gossip neighbors"
+@freezed
+sealed class ConnectivityDto with _$ConnectivityDto  {
+                
+                const factory ConnectivityDto({ required  ConnectivityStateDto state, required  String reason,}) = _ConnectivityDto;
+                
+                
+                
+            }
+
+/// How well the node is connected, for an offline banner
+enum ConnectivityStateDto {
+                    online,
+/// Connected, but without a relay or still without gossip neighbors
+degraded,
+/// No gossip neighbors, or networking paused
+isolated,
+                    ;
+                    
+                }
 
 /// Database entry for Flutter
 @freezed
-sealed class DbEntryDto with _$DbEntryDto {
-  const factory DbEntryDto({
-    required String dbName,
-    required String key,
-    required String value,
-    required Uint8List valueBytes,
-  }) = _DbEntryDto;
-}
+sealed class DbEntryDto with _$DbEntryDto  {
+                
+                const factory DbEntryDto({ required  String dbName, required  String key, required  String value, required  Uint8List valueBytes,}) = _DbEntryDto;
+                
+                
+                
+            }
+
+/// Sync activity of one database
+@freezed
+sealed class DbSyncInfoDto with _$DbSyncInfoDto  {
+                
+                const factory DbSyncInfoDto({ required  String dbName, required  BigInt opCount,  PlatformInt64? lastLocalWrite,  PlatformInt64? lastRemoteOp,  PlatformInt64? lastSync,}) = _DbSyncInfoDto;
+                
+                
+                
+            }
+
+/// A running debug API server
+@freezed
+sealed class DebugServerDto with _$DebugServerDto  {
+                
+                const factory DebugServerDto({ required  String address, required  String token,}) = _DebugServerDto;
+                
+                
+                
+            }
+
+/// Battery and network state reported by Flutter
+@freezed
+sealed class DeviceConditionsDto with _$DeviceConditionsDto  {
+                
+                const factory DeviceConditionsDto({  int? batteryPercent, required  bool charging, required  ConnectionTypeDto connectionType, required  bool metered,}) = _DeviceConditionsDto;
+                
+                
+                
+            }
+
+/// End-to-end encrypted direct message
+@freezed
+sealed class DirectMessageDto with _$DirectMessageDto  {
+                
+                const factory DirectMessageDto({ required  String id, required  String peerId, required  bool outgoing, required  String text, required  PlatformInt64 sentAt,}) = _DirectMessageDto;
+                
+                
+                
+            }
+
+@freezed
+                sealed class FileEventDto with _$FileEventDto  {
+                    const FileEventDto._();
+
+                     const factory FileEventDto.offer(  FileOfferDto field0,) = FileEventDto_Offer;
+ const factory FileEventDto.progress({   required String offerId ,  required BigInt receivedBytes ,  required BigInt totalBytes , }) = FileEventDto_Progress;
+ /// The download was interrupted and is retried from where it stopped
+const factory FileEventDto.retrying({   required String offerId ,  required int attempt ,  required String error , }) = FileEventDto_Retrying;
+ const factory FileEventDto.completed({   required String offerId ,  required String path , }) = FileEventDto_Completed;
+ /// Gave up retrying; `accept_file` again resumes the download
+const factory FileEventDto.failed({   required String offerId ,  required String error , }) = FileEventDto_Failed;
+
+                    
+
+                    
+                }
+
+/// A file a peer offers to send
+@freezed
+sealed class FileOfferDto with _$FileOfferDto  {
+                
+                const factory FileOfferDto({ required  String offerId, required  String from, required  String name, required  BigInt size, required  PlatformInt64 offeredAt,}) = _FileOfferDto;
+                
+                
+                
+            }
+
+/// A geo set member and its distance from the queried point
+@freezed
+sealed class GeoMatchDto with _$GeoMatchDto  {
+                
+                const factory GeoMatchDto({ required  String member, required  double latitude, required  double longitude, required  double distanceM,}) = _GeoMatchDto;
+                
+                
+                
+            }
+
+/// A field of a hash and its value
+@freezed
+sealed class HashEntryDto with _$HashEntryDto  {
+                
+                const factory HashEntryDto({ required  String field, required  String value,}) = _HashEntryDto;
+                
+                
+                
+            }
+
+/// Health of each subsystem for a diagnostics screen
+@freezed
+sealed class HealthReportDto with _$HealthReportDto  {
+                
+                const factory HealthReportDto({ required  SubsystemHealthDto storage, required  SubsystemHealthDto gossip, required  SubsystemHealthDto sync_, required  SubsystemHealthDto discovery, required  SubsystemHealthDto relay, required  List<TopicHealthDto> topics,  PlatformInt64? lastFlushAt,  PlatformInt64? lastSyncAt,  PlatformInt64? lastAnnouncementAt,}) = _HealthReportDto;
+                
+                
+                
+            }
+
+/// How a subsystem is doing in `health_check`
+enum HealthStatusDto {
+                    ok,
+/// Working, but not fully
+degraded,
+failed,
+                    ;
+                    
+                }
+
+@freezed
+sealed class IdentityRotationDto with _$IdentityRotationDto  {
+                
+                const factory IdentityRotationDto({ required  NodeInfo node, required  SuccessorRecordDto record, required  List<RekeyedDatabaseDto> rekeyedDatabases, required  int rekeyedOperations,}) = _IdentityRotationDto;
+                
+                
+                
+            }
+
+/// Key whose stored value disagrees with the sync log
+@freezed
+sealed class IntegrityIssueDto with _$IntegrityIssueDto  {
+                
+                const factory IntegrityIssueDto({ required  String dbName, required  String key, required  String opId, required  String kind,}) = _IntegrityIssueDto;
+                
+                
+                
+            }
+
+/// Result of a storage integrity check
+@freezed
+sealed class IntegrityReportDto with _$IntegrityReportDto  {
+                
+                const factory IntegrityReportDto({ required  BigInt checked, required  List<IntegrityIssueDto> issues, required  BigInt repaired,}) = _IntegrityReportDto;
+                
+                
+                
+            }
+
+/// Kadena command signed for the Pact API
+@freezed
+sealed class KadenaTransactionDto with _$KadenaTransactionDto  {
+                
+                const factory KadenaTransactionDto({ required  String hash, required  String sig, required  String cmd, required  String json,}) = _KadenaTransactionDto;
+                
+                
+                
+            }
+
+/// Key written or deleted in a watched database
+@freezed
+sealed class KeyChangeDto with _$KeyChangeDto  {
+                
+                const factory KeyChangeDto({ required  String dbName, required  String key, required  bool deleted,}) = _KeyChangeDto;
+                
+                
+                
+            }
 
 /// Keypair for signing
 @freezed
-sealed class KeyPairDto with _$KeyPairDto {
-  const factory KeyPairDto({
-    required String publicKey,
-    required String secretKey,
-  }) = _KeyPairDto;
-}
+sealed class KeyPairDto with _$KeyPairDto  {
+                
+                const factory KeyPairDto({ required  String publicKey, required  String secretKey,}) = _KeyPairDto;
+                
+                
+                
+            }
+
+/// Connects that took at most `le_ms`
+@freezed
+sealed class LatencyBucketDto with _$LatencyBucketDto  {
+                
+                const factory LatencyBucketDto({  double? leMs, required  BigInt count,}) = _LatencyBucketDto;
+                
+                
+                
+            }
+
+/// A device linked to a user key
+@freezed
+sealed class LinkedDeviceDto with _$LinkedDeviceDto  {
+                
+                const factory LinkedDeviceDto({ required  String userPublicKey, required  String nodeId, required  String deviceName, required  PlatformInt64 linkedAt, required  bool thisDevice,}) = _LinkedDeviceDto;
+                
+                
+                
+            }
 
 /// Log entry for Flutter console
 @freezed
-sealed class LogEntry with _$LogEntry {
-  const factory LogEntry({
-    required PlatformInt64 timestamp,
-    required String level,
-    required String message,
-  }) = _LogEntry;
-}
+sealed class LogEntry with _$LogEntry  {
+                
+                const factory LogEntry({ required  PlatformInt64 timestamp, required  String level, required  String message,}) = _LogEntry;
+                
+                
+                
+            }
+
+/// Which logs `get_logs_filtered` returns; unset fields match every entry
+@freezed
+sealed class LogFilterDto with _$LogFilterDto  {
+                
+                const factory LogFilterDto({  String? minLevel,  String? target,  String? contains,  PlatformInt64? since,  PlatformInt64? until,  int? limit,}) = _LogFilterDto;
+                
+                
+                
+            }
+
+@freezed
+sealed class MetricsDto with _$MetricsDto  {
+                
+                const factory MetricsDto({ required  List<TopicMetricsDto> topics, required  BigInt opsApplied, required  BigInt verifyFailures, required  BigInt limitViolations, required  List<LatencyBucketDto> connectLatency, required  double connectLatencySumMs, required  BigInt storageSizeBytes, required  BigInt storageKeys, required  BigInt storedOperations, required  BigInt outboxDepth,}) = _MetricsDto;
+                
+                
+                
+            }
+
+/// Broker and topic mappings of the MQTT bridge
+@freezed
+sealed class MqttBridgeConfigDto with _$MqttBridgeConfigDto  {
+                
+                const factory MqttBridgeConfigDto({ required  String host,  int? port, required  String clientId,  String? username,  String? password, required  List<MqttTopicMappingDto> mappings,}) = _MqttBridgeConfigDto;
+                
+                
+                
+            }
+
+/// Which way an MQTT topic mapping carries messages
+enum MqttDirectionDto {
+                    /// Device publishes become signed operations
+inbound,
+/// Database changes are published to the broker
+outbound,
+both,
+                    ;
+                    
+                }
+
+/// MQTT topics under `topic_prefix` mirror the keys of `db_name`
+@freezed
+sealed class MqttTopicMappingDto with _$MqttTopicMappingDto  {
+                
+                const factory MqttTopicMappingDto({ required  String topicPrefix, required  String dbName, required  MqttDirectionDto direction,}) = _MqttTopicMappingDto;
+                
+                
+                
+            }
+
+/// Where this node can be dialed
+@freezed
+sealed class NodeAddrDto with _$NodeAddrDto  {
+                
+                const factory NodeAddrDto({ required  String nodeId, required  List<String> directAddresses, required  List<String> relayUrls,}) = _NodeAddrDto;
+                
+                
+                
+            }
+
+/// Node configuration for Flutter, see `NodeConfig`. Start from
+/// `default_node_config()`. Region, network ID, relays, the bootstrap
+/// manifest and known peer dials only apply at startup; the rest can be changed with `update_config()`.
+@freezed
+sealed class NodeConfigDto with _$NodeConfigDto  {
+                
+                const factory NodeConfigDto({  String? region,  String? networkId, required  int maxKnownPeerDials, required  BigInt announceIntervalSecs, required  BigInt latencySweepIntervalSecs, required  BigInt pinnedPeersCheckIntervalSecs, required  BigInt isolationCheckIntervalSecs, required  int isolationChecksBeforeReconnect, required  int maxOpsPerResponse, required  int maxConnectionAttemptsPerCycle, required  BigInt dialIntervalMs, required  RelayConfigDto relay,  BootstrapManifestDto? bootstrapManifest, required  int maxValueBytes, required  int maxKeyLength, required  List<String> allowedStoreTypes,  OplogGcDto? oplogGc, required  int maxGossipMessageSize,}) = _NodeConfigDto;
+                
+                
+                
+            }
+
+@freezed
+                sealed class NodeEventDto with _$NodeEventDto  {
+                    const NodeEventDto._();
+
+                     const factory NodeEventDto.started({   required String nodeId ,  required String publicKey , }) = NodeEventDto_Started;
+ const factory NodeEventDto.stopped() = NodeEventDto_Stopped;
+ const factory NodeEventDto.peerConnected({   required String peerId , }) = NodeEventDto_PeerConnected;
+ const factory NodeEventDto.peerDisconnected({   required String peerId , }) = NodeEventDto_PeerDisconnected;
+ const factory NodeEventDto.peerDiscovered({   required String peerId ,  String? address , }) = NodeEventDto_PeerDiscovered;
+ /// `verified` is false if `from` couldn't be authenticated
+const factory NodeEventDto.gossipReceived({   required String topic ,  required String from ,  required String content ,  required bool verified , }) = NodeEventDto_GossipReceived;
+ const factory NodeEventDto.syncReceived({   required String dbName ,  required String key , }) = NodeEventDto_SyncReceived;
+ const factory NodeEventDto.syncProgress({   required String dbName ,  required BigInt opsReceived ,  required BigInt opsTotalEstimate ,  required BigInt bytes , }) = NodeEventDto_SyncProgress;
+ const factory NodeEventDto.conflictResolved({   required String dbName ,  required String key ,  required String keptOpId ,  required String discardedOpId , }) = NodeEventDto_ConflictResolved;
+ const factory NodeEventDto.latencyMeasured({   required String peerId ,  required BigInt latencyMs , }) = NodeEventDto_LatencyMeasured;
+ const factory NodeEventDto.presenceChanged({   required String peerId ,  required bool online ,  String? status , }) = NodeEventDto_PresenceChanged;
+ const factory NodeEventDto.topicRecovered({   required String topic , }) = NodeEventDto_TopicRecovered;
+ const factory NodeEventDto.relayConnected({   required String relayUrl , }) = NodeEventDto_RelayConnected;
+ const factory NodeEventDto.bootstrapConnected({   required String peerId , }) = NodeEventDto_BootstrapConnected;
+ const factory NodeEventDto.identityRotated({   required String oldNodeId ,  required String newNodeId , }) = NodeEventDto_IdentityRotated;
+ const factory NodeEventDto.deviceLinked({   required String userPublicKey ,  required String deviceNodeId ,  required String deviceName , }) = NodeEventDto_DeviceLinked;
+ const factory NodeEventDto.connectivityChanged({   required ConnectivityStateDto state ,  required String reason , }) = NodeEventDto_ConnectivityChanged;
+ const factory NodeEventDto.error({   required String message , }) = NodeEventDto_Error;
+
+                    
+
+                    
+                }
+
+/// A node event with its sequence number, see `get_recent_events`
+@freezed
+sealed class NodeEventRecordDto with _$NodeEventRecordDto  {
+                
+                const factory NodeEventRecordDto({ required  BigInt seq, required  PlatformInt64 timestamp, required  NodeEventDto event,}) = _NodeEventRecordDto;
+                
+                
+                
+            }
 
 /// Node info returned to Flutter
 @freezed
-sealed class NodeInfo with _$NodeInfo {
-  const factory NodeInfo({
-    required String nodeId,
-    required String publicKey,
-    required bool isRunning,
-  }) = _NodeInfo;
-}
+sealed class NodeInfo with _$NodeInfo  {
+                
+                const factory NodeInfo({ required  String nodeId, required  String publicKey, required  bool isRunning,}) = _NodeInfo;
+                
+                
+                
+            }
+
+/// Node started with `start_node_instance`
+@freezed
+sealed class NodeInstanceDto with _$NodeInstanceDto  {
+                
+                const factory NodeInstanceDto({ required  int handle, required  String nodeId, required  String publicKey,}) = _NodeInstanceDto;
+                
+                
+                
+            }
+
+/// Traffic and connection counters for Flutter, for this session and over
+/// every session of the node
+@freezed
+sealed class NodeStatsDto with _$NodeStatsDto  {
+                
+                const factory NodeStatsDto({ required  BigInt uptimeSeconds, required  BigInt totalOperations, required  BigInt bytesSent, required  BigInt bytesReceived, required  BigInt connects, required  BigInt reconnects, required  BigInt lifetimeSessions, required  BigInt lifetimeUptimeSeconds, required  BigInt lifetimeBytesSent, required  BigInt lifetimeBytesReceived, required  BigInt lifetimeConnects, required  BigInt lifetimeReconnects, required  BigInt outboxDepth,}) = _NodeStatsDto;
+                
+                
+                
+            }
 
 /// Node status for Flutter
 @freezed
-sealed class NodeStatusDto with _$NodeStatusDto {
-  const factory NodeStatusDto({
-    required bool isRunning,
-    String? nodeId,
-    required int connectedPeers,
-    required int discoveredPeers,
-    required BigInt uptimeSeconds,
-    required BigInt gossipMessagesReceived,
-    required BigInt storageSizeBytes,
-    required BigInt totalKeys,
-    required int syncOperations,
-    required BigInt latencyRequestsSent,
-    required BigInt latencyResponsesReceived,
-  }) = _NodeStatusDto;
-}
+sealed class NodeStatusDto with _$NodeStatusDto  {
+                
+                const factory NodeStatusDto({ required  bool isRunning,  String? nodeId, required  int connectedPeers, required  int discoveredPeers, required  BigInt uptimeSeconds, required  BigInt gossipMessagesReceived, required  BigInt storageSizeBytes, required  BigInt totalKeys, required  int syncOperations, required  BigInt latencyRequestsSent, required  BigInt latencyResponsesReceived, required  List<TopicStatsDto> topics,}) = _NodeStatusDto;
+                
+                
+                
+            }
+
+/// Superseded operations removed from the oplog, see `OplogGcPolicy`
+@freezed
+sealed class OplogGcDto with _$OplogGcDto  {
+                
+                const factory OplogGcDto({ required  int keepVersions, required  BigInt keepForSecs,}) = _OplogGcDto;
+                
+                
+                
+            }
+
+/// Circuit breaker of a peer that failed to connect since its last success
+@freezed
+sealed class PeerCircuitDto with _$PeerCircuitDto  {
+                
+                const factory PeerCircuitDto({ required  String nodeId, required  String state, required  bool unreachable, required  int consecutiveFailures, required  PlatformInt64 lastFailureAt,  PlatformInt64? retryAt,}) = _PeerCircuitDto;
+                
+                
+                
+            }
+
+/// What the node knows about reaching a peer
+@freezed
+sealed class PeerDiagnosticsDto with _$PeerDiagnosticsDto  {
+                
+                const factory PeerDiagnosticsDto({ required  String nodeId,  PeerPathDto? path, required  bool directEstablished, required  int dialAttempts, required  int dialFailures,  PlatformInt64? lastDialAt,  String? lastError,}) = _PeerDiagnosticsDto;
+                
+                
+                
+            }
 
 /// Peer info for Flutter
 @freezed
-sealed class PeerInfoDto with _$PeerInfoDto {
-  const factory PeerInfoDto({
-    required String nodeId,
-    required String publicKey,
-    String? address,
-    String? region,
-    String? version,
-    BigInt? latencyMs,
-    required bool isMobile,
-  }) = _PeerInfoDto;
-}
+sealed class PeerInfoDto with _$PeerInfoDto  {
+                
+                const factory PeerInfoDto({ required  String nodeId, required  String publicKey,  String? address,  String? region,  String? version,  BigInt? latencyMs, required  bool isMobile,}) = _PeerInfoDto;
+                
+                
+                
+            }
+
+/// Latency of a peer, measured in the background every few minutes
+@freezed
+sealed class PeerLatencyDto with _$PeerLatencyDto  {
+                
+                const factory PeerLatencyDto({ required  String nodeId,  BigInt? latencyMs, required  double averageMs, required  double jitterMs, required  int samples,}) = _PeerLatencyDto;
+                
+                
+                
+            }
+
+/// How a connected peer is reached
+@freezed
+sealed class PeerPathDto with _$PeerPathDto  {
+                
+                const factory PeerPathDto({ required  String nodeId, required  String path, required  bool isMixed, required  List<String> directAddrs,  String? relayUrl, required  PlatformInt64 updatedAt,}) = _PeerPathDto;
+                
+                
+                
+            }
+
+/// Per-peer sync status for Flutter
+@freezed
+sealed class PeerSyncStatusDto with _$PeerSyncStatusDto  {
+                
+                const factory PeerSyncStatusDto({ required  String peerId, required  BigInt opsSent, required  BigInt opsReceived, required  BigInt bytesSent, required  BigInt bytesReceived,  PlatformInt64? lastRequestAt,  PlatformInt64? lastResponseAt, required  bool inProgress, required  BigInt opsAcked,  PlatformInt64? lastAckAt,}) = _PeerSyncStatusDto;
+                
+                
+                
+            }
+
+/// A peer the node always keeps connected
+@freezed
+sealed class PinnedPeerDto with _$PinnedPeerDto  {
+                
+                const factory PinnedPeerDto({ required  String nodeId,  String? address,}) = _PinnedPeerDto;
+                
+                
+                
+            }
+
+/// Power mode picked by the user
+enum PowerModeDto {
+                    normal,
+/// Sync topic only, through one bootstrap peer, with periodic work 6x
+/// less often; for running overnight in a foreground service
+lite,
+                    ;
+                    
+                }
+
+/// How the node currently adapts to the device conditions
+@freezed
+sealed class PowerPolicyDto with _$PowerPolicyDto  {
+                
+                const factory PowerPolicyDto({ required  bool lowPower, required  bool metered, required  bool syncDeferred, required  bool blobTransfersAllowed,}) = _PowerPolicyDto;
+                
+                
+                
+            }
+
+/// Presence of a peer, from its heartbeats
+@freezed
+sealed class PresenceDto with _$PresenceDto  {
+                
+                const factory PresenceDto({ required  String peerId, required  bool online,  String? status, required  PlatformInt64 lastSeen,}) = _PresenceDto;
+                
+                
+                
+            }
+
+/// Field predicate of a query
+@freezed
+sealed class QueryFilterDto with _$QueryFilterDto  {
+                
+                const factory QueryFilterDto({ required  String field, required  String op, required  String valueJson,}) = _QueryFilterDto;
+                
+                
+                
+            }
+
+/// One window of query results
+@freezed
+sealed class QueryResultDto with _$QueryResultDto  {
+                
+                const factory QueryResultDto({ required  List<DbEntryDto> entries, required  bool hasMore,}) = _QueryResultDto;
+                
+                
+                
+            }
+
+/// Query over the JSON values of a database
+@freezed
+sealed class QuerySpecDto with _$QuerySpecDto  {
+                
+                const factory QuerySpecDto({  String? keyPrefix, required  List<QueryFilterDto> filters,  String? sortBy, required  bool descending, required  int limit, required  int offset,  List<String>? fields,}) = _QuerySpecDto;
+                
+                
+                
+            }
+
+@freezed
+sealed class RekeyedDatabaseDto with _$RekeyedDatabaseDto  {
+                
+                const factory RekeyedDatabaseDto({ required  String oldName, required  String newName,}) = _RekeyedDatabaseDto;
+                
+                
+                
+            }
+
+@freezed
+                sealed class RelayConfigDto with _$RelayConfigDto  {
+                    const RelayConfigDto._();
+
+                     /// n0's public relays
+const factory RelayConfigDto.default_() = RelayConfigDto_Default;
+ /// Direct connections only, e.g. LAN-only deployments
+const factory RelayConfigDto.disabled() = RelayConfigDto_Disabled;
+ const factory RelayConfigDto.custom({   required List<String> urls , }) = RelayConfigDto_Custom;
+
+                    
+
+                    
+                }
+
+/// Relay configuration, the relay in use and its round trip time
+@freezed
+sealed class RelayStatusDto with _$RelayStatusDto  {
+                
+                const factory RelayStatusDto({ required  RelayConfigDto relay,  String? activeUrl,  BigInt? rttMs,}) = _RelayStatusDto;
+                
+                
+                
+            }
+
+/// How this replica of a database compared to the root its owner published
+@freezed
+sealed class ReplicaCheckDto with _$ReplicaCheckDto  {
+                
+                const factory ReplicaCheckDto({ required  String dbName, required  String publisher, required  bool matches, required  int mismatchedBuckets, required  PlatformInt64 checkedAt,}) = _ReplicaCheckDto;
+                
+                
+                
+            }
+
+/// RPC request from a peer, answered with `respond_rpc` or `reject_rpc`
+@freezed
+sealed class RpcRequestDto with _$RpcRequestDto  {
+                
+                const factory RpcRequestDto({ required  BigInt requestId, required  String peerId, required  String method, required  Uint8List payload,}) = _RpcRequestDto;
+                
+                
+                
+            }
+
+/// One page of a key-ordered scan
+@freezed
+sealed class ScanPageDto with _$ScanPageDto  {
+                
+                const factory ScanPageDto({ required  List<DbEntryDto> entries,  String? nextCursor,}) = _ScanPageDto;
+                
+                
+                
+            }
+
+/// A sorted set member and its score
+@freezed
+sealed class ScoredMemberDto with _$ScoredMemberDto  {
+                
+                const factory ScoredMemberDto({ required  String member, required  double score,}) = _ScoredMemberDto;
+                
+                
+                
+            }
+
+@freezed
+                sealed class StartupStageDto with _$StartupStageDto  {
+                    const StartupStageDto._();
+
+                     const factory StartupStageDto.keyLoaded() = StartupStageDto_KeyLoaded;
+ /// The endpoint is bound; the relay may still be connecting
+const factory StartupStageDto.endpointOnline({   required bool relayConnected , }) = StartupStageDto_EndpointOnline;
+ /// Bootstrap peers are being dialed in the background
+const factory StartupStageDto.bootstrapConnecting({   required int attempt , }) = StartupStageDto_BootstrapConnecting;
+ const factory StartupStageDto.topicsSubscribed() = StartupStageDto_TopicsSubscribed;
+ const factory StartupStageDto.ready() = StartupStageDto_Ready;
+
+                    
+
+                    
+                }
+
+/// Status of a subsystem and why, e.g. "no relay connected"
+@freezed
+sealed class SubsystemHealthDto with _$SubsystemHealthDto  {
+                
+                const factory SubsystemHealthDto({ required  HealthStatusDto status, required  String reason,}) = _SubsystemHealthDto;
+                
+                
+                
+            }
+
+/// Signed statement that a node's key was retired for a new node ID
+@freezed
+sealed class SuccessorRecordDto with _$SuccessorRecordDto  {
+                
+                const factory SuccessorRecordDto({ required  String oldNodeId, required  String newNodeId, required  PlatformInt64 timestamp, required  PlatformInt64 graceUntil,}) = _SuccessorRecordDto;
+                
+                
+                
+            }
+
+/// Sync statistics for Flutter
+@freezed
+sealed class SyncStatsDto with _$SyncStatsDto  {
+                
+                const factory SyncStatsDto({ required  BigInt totalOperations, required  BigInt opsSent, required  BigInt opsReceived, required  BigInt bytesSent, required  BigInt bytesReceived,  PlatformInt64? lastSyncAt, required  List<PeerSyncStatusDto> peers,}) = _SyncStatsDto;
+                
+                
+                
+            }
+
+/// Where a database's writes stood when `wait_for_sync` returned
+@freezed
+sealed class SyncWaitSummaryDto with _$SyncWaitSummaryDto  {
+                
+                const factory SyncWaitSummaryDto({ required  bool synced, required  int queuedOps, required  int unacknowledgedOps, required  BigInt durationMs,}) = _SyncWaitSummaryDto;
+                
+                
+                
+            }
+
+/// Change of a collaborative text: `deleted` characters at `index` replaced
+/// by `inserted`
+@freezed
+sealed class TextDeltaDto with _$TextDeltaDto  {
+                
+                const factory TextDeltaDto({ required  String dbName, required  String key, required  BigInt index, required  BigInt deleted, required  String inserted,}) = _TextDeltaDto;
+                
+                
+                
+            }
+
+/// A time series sample
+@freezed
+sealed class TimePointDto with _$TimePointDto  {
+                
+                const factory TimePointDto({ required  PlatformInt64 timestamp, required  double value,}) = _TimePointDto;
+                
+                
+                
+            }
+
+/// One of the node's gossip topics
+@freezed
+sealed class TopicHealthDto with _$TopicHealthDto  {
+                
+                const factory TopicHealthDto({ required  String topic, required  bool subscribed, required  int neighbors,}) = _TopicHealthDto;
+                
+                
+                
+            }
+
+/// Message received on an application topic
+@freezed
+sealed class TopicMessageDto with _$TopicMessageDto  {
+                
+                const factory TopicMessageDto({ required  String topic, required  String deliveredFrom, required  Uint8List content,}) = _TopicMessageDto;
+                
+                
+                
+            }
+
+/// Session traffic of one topic
+@freezed
+sealed class TopicMetricsDto with _$TopicMetricsDto  {
+                
+                const factory TopicMetricsDto({ required  String topic, required  BigInt messagesSent, required  BigInt messagesReceived, required  BigInt duplicatesDropped, required  BigInt bytesSent, required  BigInt bytesReceived,}) = _TopicMetricsDto;
+                
+                
+                
+            }
+
+/// Session activity of one gossip topic for Flutter
+@freezed
+sealed class TopicStatsDto with _$TopicStatsDto  {
+                
+                const factory TopicStatsDto({ required  String topic, required  BigInt messagesSent, required  BigInt messagesReceived, required  BigInt bytesSent, required  BigInt bytesReceived, required  int neighbors,  PlatformInt64? lastEventAt,}) = _TopicStatsDto;
+                
+                
+                
+            }
+
+/// New value of a watched key
+@freezed
+sealed class ValueUpdateDto with _$ValueUpdateDto  {
+                
+                const factory ValueUpdateDto({ required  String dbName, required  String key,  String? value,  Uint8List? valueBytes,}) = _ValueUpdateDto;
+                
+                
+                
+            }
+
+/// Peer that proved it controls its node ID and public key
+@freezed
+sealed class VerifiedBindingDto with _$VerifiedBindingDto  {
+                
+                const factory VerifiedBindingDto({ required  String nodeId, required  String publicKey, required  PlatformInt64 verifiedAt,}) = _VerifiedBindingDto;
+                
+                
+                
+            }
+
+/// What `wake_and_sync` got done
+@freezed
+sealed class WakeSyncSummaryDto with _$WakeSyncSummaryDto  {
+                
+                const factory WakeSyncSummaryDto({ required  bool startedNode, required  int pinnedPeersDialed, required  int pinnedPeersConnected, required  bool synced, required  BigInt opsReceived, required  BigInt opsSent, required  int outboxSent, required  int outboxRemaining, required  BigInt durationMs,}) = _WakeSyncSummaryDto;
+                
+                
+                
+            }
+            
\ No newline at end of file
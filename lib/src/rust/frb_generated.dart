@@ -7,1771 +7,12441 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
 
-  RustLib._();
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
+                  RustLib._();
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  @override
-  Future<void> executeRustInitializers() async {}
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
 
-  @override
-  String get codegenVersion => '2.11.1';
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  @override
-  int get rustContentHash => -1121877269;
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_cyberfly_mobile_node',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
+                  @override
+                  int get rustContentHash => -122693047;
 
-abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiAddLogEntry({
-    required String level,
-    required String message,
-  });
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_cyberfly_mobile_node',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  void crateApiClearLogs();
+                abstract class RustLibApi extends BaseApi {
+                  AcceptedInviteDto crateApiAcceptDbInvite({required String token });
 
-  Future<void> crateApiDeleteData({
-    required String dbName,
-    required String key,
-  });
+AcceptedInviteDto crateApiAcceptDbInviteOn({required int handle , required String token });
 
-  String? crateApiExtractNameFromDb({required String dbName});
+Future<BigInt> crateApiAcceptFile({required String offerId , required String destPath });
 
-  String crateApiGenerateDbName({
-    required String name,
-    required String publicKeyHex,
-  });
-
-  KeyPairDto crateApiGenerateKeypair();
-
-  String crateApiGeneratePeerIdFromSecretKey({required String secretKeyHex});
-
-  Future<List<DbEntryDto>> crateApiGetAllData();
-
-  Future<List<DbEntryDto>> crateApiGetAllEntries({required String dbName});
-
-  Future<Uint8List?> crateApiGetData({
-    required String dbName,
-    required String key,
-  });
-
-  List<LogEntry> crateApiGetLogs({int? limit});
-
-  NodeInfo? crateApiGetNodeInfo();
-
-  NodeStatusDto crateApiGetNodeStatus();
-
-  List<PeerInfoDto> crateApiGetPeers();
-
-  String crateApiGreet({required String name});
-
-  void crateApiInitLogging();
-
-  bool crateApiIsNodeRunning();
-
-  List<String> crateApiListDatabases();
-
-  List<String> crateApiListKeys({required String dbName});
-
-  Future<void> crateApiRequestSync({PlatformInt64? sinceTimestamp});
-
-  Future<void> crateApiSendGossip({
-    required String topic,
-    required String message,
-  });
+Future<BigInt> crateApiAcceptFileOn({required int handle , required String offerId , required String destPath });
 
-  Future<void> crateApiSendLatencyRequest({required String peerId});
+Future<void> crateApiAddLogEntry({required String level , required String message });
 
-  String crateApiSignMessageWithKey({
-    required String secretKeyHex,
-    required String message,
-  });
+String crateApiBlake3Hash({required List<int> data });
 
-  Future<NodeInfo> crateApiStartNode({
-    required String dataDir,
-    String? walletSecretKey,
-    required List<String> bootstrapPeers,
-    String? region,
-  });
+Stream<ChangeEventDto> crateApiChangeFeed({required String dbName , required BigInt sinceSeq });
 
-  Future<void> crateApiStopNode();
+Stream<ChangeEventDto> crateApiChangeFeedOn({required int handle , required String dbName , required BigInt sinceSeq });
 
-  Future<void> crateApiStoreData({
-    required String dbName,
-    required String key,
-    required List<int> value,
-    required String publicKey,
-    required String signature,
-  });
+Future<CheckpointDto> crateApiCheckpoint({BigInt? budgetMs });
 
-  Future<void> crateApiStoreDataLocal({
-    required String dbName,
-    required String key,
-    required List<int> value,
-  });
+Future<CheckpointDto> crateApiCheckpointOn({required int handle , BigInt? budgetMs });
 
-  bool crateApiValidateTimestamp({required PlatformInt64 timestamp});
+void crateApiClearLogs();
 
-  bool crateApiVerifyDbName({
-    required String dbName,
-    required String publicKeyHex,
-  });
+void crateApiCloseStorageReadOnly();
 
-  bool crateApiVerifyMessageSignature({
-    required String publicKeyHex,
-    required String message,
-    required String signatureHex,
-  });
-}
+bool crateApiCompareAndSwap({required String dbName , required String key , Uint8List? expected , Uint8List? newValue });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+bool crateApiCompareAndSwapOn({required int handle , required String dbName , required String key , Uint8List? expected , Uint8List? newValue });
 
-  @override
-  Future<void> crateApiAddLogEntry({
-    required String level,
-    required String message,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(level);
-          var arg1 = cst_encode_String(message);
-          return wire.wire__crate__api__add_log_entry(port_, arg0, arg1);
-        },
-        codec: DcoCodec(
+Future<ConnectionResultDto> crateApiConnectPeer({required String nodeId , String? address });
+
+Future<ConnectionResultDto> crateApiConnectPeerOn({required int handle , required String nodeId , String? address });
+
+Future<String> crateApiCreateDbInvite({required String dbName , required String ownerSecretKey , required bool canWrite , required bool includeEncryptionKey , required BigInt expiresInSecs });
+
+Future<String> crateApiCreateDbInviteOn({required int handle , required String dbName , required String ownerSecretKey , required bool canWrite , required bool includeEncryptionKey , required BigInt expiresInSecs });
+
+String crateApiCreateDeviceLinkRequest({required String deviceName });
+
+String crateApiCreateDeviceLinkRequestOn({required int handle , required String deviceName });
+
+void crateApiCreateEncryptedDb({required String dbName });
+
+void crateApiCreateEncryptedDbOn({required int handle , required String dbName });
+
+void crateApiDeclineFile({required String offerId });
+
+void crateApiDeclineFileOn({required int handle , required String offerId });
+
+NodeConfigDto crateApiDefaultNodeConfig();
+
+Future<void> crateApiDeleteData({required String dbName , required String key });
+
+Future<void> crateApiDeleteDataOn({required int handle , required String dbName , required String key });
+
+Stream<DirectMessageDto> crateApiDmStream();
+
+Stream<DirectMessageDto> crateApiDmStreamOn({required int handle });
+
+Future<void> crateApiDropDatabase({required String dbName , required String ownerSecretKey });
+
+Future<void> crateApiDropDatabaseOn({required int handle , required String dbName , required String ownerSecretKey });
+
+Future<String> crateApiEditText({required String dbName , required String key , required BigInt index , required BigInt delete , required String insert , required String secretKey });
+
+Future<String> crateApiEditTextOn({required int handle , required String dbName , required String key , required BigInt index , required BigInt delete , required String insert , required String secretKey });
+
+Stream<NodeEventRecordDto> crateApiEventStream({required BigInt sinceSeq });
+
+Stream<NodeEventRecordDto> crateApiEventStreamOn({required int handle , required BigInt sinceSeq });
+
+Future<BackupInfoDto> crateApiExportBackup({required String path , required String passphrase });
+
+Future<BackupInfoDto> crateApiExportBackupOn({required int handle , required String path , required String passphrase });
+
+String crateApiExportDbKey({required String dbName });
+
+String crateApiExportDbKeyOn({required int handle , required String dbName });
+
+Future<int> crateApiExportLogs({required String path });
+
+String? crateApiExtractNameFromDb({required String dbName });
+
+Stream<FileEventDto> crateApiFileEventStream();
+
+Stream<FileEventDto> crateApiFileEventStreamOn({required int handle });
+
+void crateApiFollowDatabase({required String dbName });
+
+void crateApiFollowDatabaseOn({required int handle , required String dbName });
+
+String crateApiGenerateDbName({required String name , required String publicKeyHex });
+
+KeyPairDto crateApiGenerateKeypair();
+
+String crateApiGeneratePeerIdFromSecretKey({required String secretKeyHex });
+
+Future<void> crateApiGeoAdd({required String dbName , required String key , required String member , required double latitude , required double longitude , required String secretKey });
+
+Future<void> crateApiGeoAddOn({required int handle , required String dbName , required String key , required String member , required double latitude , required double longitude , required String secretKey });
+
+List<GeoMatchDto> crateApiGeoRadius({required String dbName , required String key , required double latitude , required double longitude , required double radiusM });
+
+List<GeoMatchDto> crateApiGeoRadiusOn({required int handle , required String dbName , required String key , required double latitude , required double longitude , required double radiusM });
+
+Future<List<DbEntryDto>> crateApiGetAllData();
+
+Future<List<DbEntryDto>> crateApiGetAllDataOn({required int handle });
+
+Future<ScanPageDto> crateApiGetAllDataPage({required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes });
+
+Future<ScanPageDto> crateApiGetAllDataPageOn({required int handle , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes });
+
+Future<List<DbEntryDto>> crateApiGetAllEntries({required String dbName });
+
+Future<List<DbEntryDto>> crateApiGetAllEntriesOn({required int handle , required String dbName });
+
+BandwidthStatsDto crateApiGetBandwidthStats();
+
+BandwidthStatsDto crateApiGetBandwidthStatsOn({required int handle });
+
+ConnectionDiagnosticsDto crateApiGetConnectionDiagnostics();
+
+ConnectionDiagnosticsDto crateApiGetConnectionDiagnosticsOn({required int handle });
+
+ConnectivityDto crateApiGetConnectivityState();
+
+ConnectivityDto crateApiGetConnectivityStateOn({required int handle });
+
+PlatformInt64 crateApiGetCounter({required String dbName , required String key });
+
+PlatformInt64 crateApiGetCounterOn({required int handle , required String dbName , required String key });
+
+Future<Uint8List?> crateApiGetData({required String dbName , required String key });
+
+Future<Uint8List?> crateApiGetDataOn({required int handle , required String dbName , required String key });
+
+Future<String> crateApiGetDatabaseRoot({required String dbName });
+
+Future<String> crateApiGetDatabaseRootOn({required int handle , required String dbName });
+
+Future<DbSyncInfoDto> crateApiGetDbSyncInfo({required String dbName });
+
+Future<DbSyncInfoDto> crateApiGetDbSyncInfoOn({required int handle , required String dbName });
+
+Uint8List? crateApiGetDecrypted({required String dbName , required String key });
+
+Uint8List? crateApiGetDecryptedOn({required int handle , required String dbName , required String key });
+
+List<DirectMessageDto> crateApiGetDmHistory({required String peerId , required int limit });
+
+List<DirectMessageDto> crateApiGetDmHistoryOn({required int handle , required String peerId , required int limit });
+
+Future<ScanPageDto> crateApiGetEntriesPage({required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes });
+
+Future<ScanPageDto> crateApiGetEntriesPageOn({required int handle , required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes });
+
+List<String> crateApiGetFollowedDatabases();
+
+List<String> crateApiGetFollowedDatabasesOn({required int handle });
+
+String crateApiGetLogLevelsFilter();
+
+List<LogEntry> crateApiGetLogs({int? limit });
+
+List<LogEntry> crateApiGetLogsFiltered({required LogFilterDto filter });
+
+Future<List<Uint8List?>> crateApiGetMany({required String dbName , required List<String> keys });
+
+Future<List<Uint8List?>> crateApiGetManyOn({required int handle , required String dbName , required List<String> keys });
+
+MetricsDto crateApiGetMetrics();
+
+MetricsDto crateApiGetMetricsOn({required int handle });
+
+MqttBridgeConfigDto? crateApiGetMqttBridgeConfig();
+
+MqttBridgeConfigDto? crateApiGetMqttBridgeConfigOn({required int handle });
+
+String? crateApiGetNetworkId({required String dataDir });
+
+NodeAddrDto crateApiGetNodeAddr();
+
+NodeAddrDto crateApiGetNodeAddrOn({required int handle });
+
+NodeConfigDto crateApiGetNodeConfig();
+
+NodeConfigDto crateApiGetNodeConfigOn({required int handle });
+
+NodeInfo? crateApiGetNodeInfo();
+
+NodeInfo? crateApiGetNodeInfoOn({required int handle });
+
+NodeStatsDto crateApiGetNodeStats();
+
+NodeStatsDto crateApiGetNodeStatsOn({required int handle });
+
+NodeStatusDto crateApiGetNodeStatus();
+
+NodeStatusDto crateApiGetNodeStatusOn({required int handle });
+
+String crateApiGetNodeTicket();
+
+String crateApiGetNodeTicketOn({required int handle });
+
+List<PeerCircuitDto> crateApiGetPeerCircuits();
+
+List<PeerCircuitDto> crateApiGetPeerCircuitsOn({required int handle });
+
+List<PeerPathDto> crateApiGetPeerPaths();
+
+List<PeerPathDto> crateApiGetPeerPathsOn({required int handle });
+
+List<PeerInfoDto> crateApiGetPeers();
+
+List<PeerInfoDto> crateApiGetPeersFiltered({required List<String> capabilities , String? region , BigInt? maxLatencyMs });
+
+List<PeerInfoDto> crateApiGetPeersFilteredOn({required int handle , required List<String> capabilities , String? region , BigInt? maxLatencyMs });
+
+List<PeerInfoDto> crateApiGetPeersOn({required int handle });
+
+List<PinnedPeerDto> crateApiGetPinnedPeers();
+
+List<PinnedPeerDto> crateApiGetPinnedPeersOn({required int handle });
+
+PowerModeDto crateApiGetPowerMode();
+
+PowerModeDto crateApiGetPowerModeOn({required int handle });
+
+PowerPolicyDto crateApiGetPowerPolicy();
+
+PowerPolicyDto crateApiGetPowerPolicyOn({required int handle });
+
+PresenceDto? crateApiGetPresence({required String peerId });
+
+PresenceDto? crateApiGetPresenceOn({required int handle , required String peerId });
+
+List<PeerLatencyDto> crateApiGetRankedPeers();
+
+List<PeerLatencyDto> crateApiGetRankedPeersOn({required int handle });
+
+List<NodeEventRecordDto> crateApiGetRecentEvents({required BigInt sinceSeq });
+
+List<NodeEventRecordDto> crateApiGetRecentEventsOn({required int handle , required BigInt sinceSeq });
+
+Future<RelayStatusDto> crateApiGetRelayStatus();
+
+Future<RelayStatusDto> crateApiGetRelayStatusOn({required int handle });
+
+SuccessorRecordDto? crateApiGetSuccessor({required String nodeId });
+
+SuccessorRecordDto? crateApiGetSuccessorOn({required int handle , required String nodeId });
+
+Future<SyncStatsDto> crateApiGetSyncStats();
+
+Future<SyncStatsDto> crateApiGetSyncStatsOn({required int handle });
+
+String crateApiGetText({required String dbName , required String key });
+
+String crateApiGetTextOn({required int handle , required String dbName , required String key });
+
+List<String> crateApiGetWriters({required String dbName });
+
+List<String> crateApiGetWritersOn({required int handle , required String dbName });
+
+Future<NodeInfo> crateApiGoOnline({required List<String> bootstrapPeers , String? region });
+
+Future<NodeInfo> crateApiGoOnlineOn({required int handle , required List<String> bootstrapPeers , String? region });
+
+Future<void> crateApiGrantWriter({required String dbName , required String writerPublicKey , required String ownerSecretKey });
+
+Future<void> crateApiGrantWriterOn({required int handle , required String dbName , required String writerPublicKey , required String ownerSecretKey });
+
+Future<String> crateApiGraphqlExecute({required String query , String? variables });
+
+Future<String> crateApiGraphqlExecuteOn({required int handle , required String query , String? variables });
+
+String crateApiGreet({required String name });
+
+Future<String?> crateApiHashGet({required String dbName , required String key , required String field });
+
+List<HashEntryDto> crateApiHashGetAll({required String dbName , required String key });
+
+List<HashEntryDto> crateApiHashGetAllOn({required int handle , required String dbName , required String key });
+
+Future<String?> crateApiHashGetOn({required int handle , required String dbName , required String key , required String field });
+
+Future<void> crateApiHashSet({required String dbName , required String key , required String field , required String value , required String secretKey });
+
+Future<void> crateApiHashSetOn({required int handle , required String dbName , required String key , required String field , required String value , required String secretKey });
+
+Future<HealthReportDto> crateApiHealthCheck();
+
+Future<HealthReportDto> crateApiHealthCheckOn({required int handle });
+
+Future<BackupInfoDto> crateApiImportBackup({required String dataDir , required String path , required String passphrase });
+
+void crateApiImportDbKey({required String dbName , required String keyHex });
+
+void crateApiImportDbKeyOn({required int handle , required String dbName , required String keyHex });
+
+Future<PlatformInt64> crateApiIncr({required String dbName , required String key , required PlatformInt64 delta , required String secretKey });
+
+Future<PlatformInt64> crateApiIncrOn({required int handle , required String dbName , required String key , required PlatformInt64 delta , required String secretKey });
+
+void crateApiInitLogging();
+
+bool crateApiIsDatabaseLocalOnly({required String dbName });
+
+bool crateApiIsDatabaseLocalOnlyOn({required int handle , required String dbName });
+
+bool crateApiIsNetworkingPaused();
+
+bool crateApiIsNetworkingPausedOn({required int handle });
+
+bool crateApiIsNodeKeySealed({required String dataDir });
+
+bool crateApiIsNodeOffline();
+
+bool crateApiIsNodeOfflineOn({required int handle });
+
+bool crateApiIsNodeRunning();
+
+bool crateApiIsVerifiedPeerKey({required String peerId , required String publicKey });
+
+bool crateApiIsVerifiedPeerKeyOn({required int handle , required String peerId , required String publicKey });
+
+String crateApiKadenaAccountFromPublicKey({required String publicKeyHex });
+
+String crateApiKadenaNodeRegistrationCommand({required String networkId , required String chainId , required String peerId , required String multiaddr , required String publicKeyHex });
+
+KadenaTransactionDto crateApiKadenaSignCommand({required String cmd , required String secretKeyHex });
+
+LinkedDeviceDto crateApiLinkDevice({required String qrPayload , required String secretKey });
+
+LinkedDeviceDto crateApiLinkDeviceOn({required int handle , required String qrPayload , required String secretKey });
+
+List<ConflictDto> crateApiListConflicts({required String dbName });
+
+List<ConflictDto> crateApiListConflictsOn({required int handle , required String dbName });
+
+List<String> crateApiListDatabases();
+
+List<String> crateApiListDatabasesOn({required int handle });
+
+List<FileOfferDto> crateApiListFileOffers();
+
+List<FileOfferDto> crateApiListFileOffersOn({required int handle });
+
+List<String> crateApiListKeys({required String dbName });
+
+List<String> crateApiListKeysOn({required int handle , required String dbName });
+
+List<LinkedDeviceDto> crateApiListLinkedDevices();
+
+List<LinkedDeviceDto> crateApiListLinkedDevicesOn({required int handle });
+
+Uint32List crateApiListNodeInstances();
+
+Future<void> crateApiListPush({required String dbName , required String key , required String value , required String secretKey });
+
+Future<void> crateApiListPushOn({required int handle , required String dbName , required String key , required String value , required String secretKey });
+
+List<String> crateApiListRange({required String dbName , required String key });
+
+List<String> crateApiListRangeOn({required int handle , required String dbName , required String key });
+
+List<ReplicaCheckDto> crateApiListReplicaChecks();
+
+List<ReplicaCheckDto> crateApiListReplicaChecksOn({required int handle });
+
+Future<BigInt> crateApiMeasureLatency({required String peerId });
+
+Future<BigInt> crateApiMeasureLatencyOn({required int handle , required String peerId });
+
+void crateApiOpenStorageReadOnly({required String dataDir });
+
+Future<void> crateApiPauseNetworking();
+
+Future<void> crateApiPauseNetworkingOn({required int handle });
+
+void crateApiPinPeer({required String nodeId , String? address });
+
+void crateApiPinPeerOn({required int handle , required String nodeId , String? address });
+
+Future<VerifiedBindingDto> crateApiProveOwnership({required String peerId });
+
+Future<VerifiedBindingDto> crateApiProveOwnershipOn({required int handle , required String peerId });
+
+Future<void> crateApiPublishTopic({required String name , required List<int> content });
+
+Future<void> crateApiPublishTopicOn({required int handle , required String name , required List<int> content });
+
+Future<void> crateApiPutJson({required String dbName , required String key , required String json , required String secretKey });
+
+Future<void> crateApiPutJsonOn({required int handle , required String dbName , required String key , required String json , required String secretKey });
+
+Future<void> crateApiPutString({required String dbName , required String key , required String value , required String secretKey });
+
+Future<void> crateApiPutStringOn({required int handle , required String dbName , required String key , required String value , required String secretKey });
+
+Future<QueryResultDto> crateApiQuery({required String dbName , required QuerySpecDto spec });
+
+Future<QueryResultDto> crateApiQueryOn({required int handle , required String dbName , required QuerySpecDto spec });
+
+Future<BackupInfoDto> crateApiReadOnlyExportBackup({required String path , required String passphrase , String? walletSecretKey });
+
+Future<Uint8List?> crateApiReadOnlyGetData({required String dbName , required String key });
+
+Future<ScanPageDto> crateApiReadOnlyGetEntriesPage({required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes });
+
+List<String> crateApiReadOnlyListDatabases();
+
+bool crateApiRejectRpc({required BigInt requestId , required String message });
+
+bool crateApiRejectRpcOn({required int handle , required BigInt requestId , required String message });
+
+Future<void> crateApiRequestSync({PlatformInt64? sinceTimestamp });
+
+Future<void> crateApiRequestSyncOn({required int handle , PlatformInt64? sinceTimestamp });
+
+void crateApiResetLogLevels();
+
+bool crateApiRespondRpc({required BigInt requestId , required List<int> payload });
+
+bool crateApiRespondRpcOn({required int handle , required BigInt requestId , required List<int> payload });
+
+Future<void> crateApiResumeNetworking();
+
+Future<void> crateApiResumeNetworkingOn({required int handle });
+
+Future<void> crateApiRevokeWriter({required String dbName , required String writerPublicKey , required String ownerSecretKey });
+
+Future<void> crateApiRevokeWriterOn({required int handle , required String dbName , required String writerPublicKey , required String ownerSecretKey });
+
+Future<IdentityRotationDto> crateApiRotateIdentity({String? newSecretKey , BigInt? graceSecs });
+
+Future<IdentityRotationDto> crateApiRotateIdentityOn({required int handle , String? newSecretKey , BigInt? graceSecs });
+
+void crateApiRotateKeyWrappingKey({required String dataDir , required List<int> oldWrappingKey , required List<int> newWrappingKey });
+
+Future<Uint8List> crateApiRpcCall({required String peerId , required String method , required List<int> payload , BigInt? timeoutMs });
+
+Future<Uint8List> crateApiRpcCallOn({required int handle , required String peerId , required String method , required List<int> payload , BigInt? timeoutMs });
+
+ScanPageDto crateApiScanPrefix({required String dbName , required String prefix , required int limit , String? cursor });
+
+ScanPageDto crateApiScanPrefixOn({required int handle , required String dbName , required String prefix , required int limit , String? cursor });
+
+ScanPageDto crateApiScanRange({required String dbName , String? start , String? end , required int limit , String? cursor });
+
+ScanPageDto crateApiScanRangeOn({required int handle , required String dbName , String? start , String? end , required int limit , String? cursor });
+
+void crateApiSealNodeKey({required String dataDir , required List<int> wrappingKey });
+
+Future<List<DbEntryDto>> crateApiSearch({required String dbName , required String query , required int limit });
+
+Future<List<DbEntryDto>> crateApiSearchOn({required int handle , required String dbName , required String query , required int limit });
+
+Future<DirectMessageDto> crateApiSendDm({required String peerId , required String text });
+
+Future<DirectMessageDto> crateApiSendDmOn({required int handle , required String peerId , required String text });
+
+Future<FileOfferDto> crateApiSendFile({required String peerId , required String path });
+
+Future<FileOfferDto> crateApiSendFileOn({required int handle , required String peerId , required String path });
+
+Future<void> crateApiSendGossip({required String topic , required String message });
+
+Future<void> crateApiSendGossipOn({required int handle , required String topic , required String message });
+
+Future<void> crateApiSendLatencyRequest({required String peerId });
+
+Future<void> crateApiSendLatencyRequestOn({required int handle , required String peerId });
+
+Stream<RpcRequestDto> crateApiServeRpc();
+
+Stream<RpcRequestDto> crateApiServeRpcOn({required int handle });
+
+Future<void> crateApiSetAdd({required String dbName , required String key , required String member , required String secretKey });
+
+Future<void> crateApiSetAddOn({required int handle , required String dbName , required String key , required String member , required String secretKey });
+
+void crateApiSetBandwidthCaps({required BandwidthCapsDto caps });
+
+void crateApiSetBandwidthCapsOn({required int handle , required BandwidthCapsDto caps });
+
+Future<void> crateApiSetDatabaseLocalOnly({required String dbName , required bool localOnly });
+
+Future<void> crateApiSetDatabaseLocalOnlyOn({required int handle , required String dbName , required bool localOnly });
+
+Future<void> crateApiSetDeviceConditions({required DeviceConditionsDto conditions });
+
+Future<void> crateApiSetDeviceConditionsOn({required int handle , required DeviceConditionsDto conditions });
+
+void crateApiSetKeyWrappingKey({required List<int> wrappingKey });
+
+void crateApiSetLogBufferSize({required int size });
+
+void crateApiSetLogLevel({required String level });
+
+void crateApiSetLogTargetLevel({required String target , String? level });
+
+List<String> crateApiSetMembers({required String dbName , required String key });
+
+List<String> crateApiSetMembersOn({required int handle , required String dbName , required String key });
+
+Future<void> crateApiSetNetworkPassphrase({required String dataDir , String? passphrase });
+
+Future<void> crateApiSetPowerMode({required PowerModeDto mode });
+
+Future<void> crateApiSetPowerModeOn({required int handle , required PowerModeDto mode });
+
+void crateApiSetPresenceStatus({String? status });
+
+void crateApiSetPresenceStatusOn({required int handle , String? status });
+
+void crateApiSetRetainConflicts({required bool enabled });
+
+void crateApiSetRetainConflictsOn({required int handle , required bool enabled });
+
+void crateApiSetSelectiveSync({required bool selective });
+
+void crateApiSetSelectiveSyncOn({required int handle , required bool selective });
+
+String crateApiSha256Hash({required List<int> data });
+
+String crateApiSignBytes({required String secretKeyHex , required List<int> message });
+
+String crateApiSignMessageWithKey({required String secretKeyHex , required String message });
+
+Future<DebugServerDto> crateApiStartDebugServer({required String bindAddr , required bool allowWrites });
+
+Future<DebugServerDto> crateApiStartDebugServerOn({required int handle , required String bindAddr , required bool allowWrites });
+
+Future<String> crateApiStartMetricsServer({required String bindAddr });
+
+Future<String> crateApiStartMetricsServerOn({required int handle , required String bindAddr });
+
+Future<void> crateApiStartMqttBridge({required MqttBridgeConfigDto config });
+
+Future<void> crateApiStartMqttBridgeOn({required int handle , required MqttBridgeConfigDto config });
+
+Future<NodeInfo> crateApiStartNode({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , String? region });
+
+Future<NodeInfo> crateApiStartNodeInNetwork({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , String? region , required String networkId });
+
+Future<NodeInstanceDto> crateApiStartNodeInstance({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config });
+
+Future<NodeInfo> crateApiStartNodeWithConfig({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config });
+
+Stream<StartupStageDto> crateApiStartNodeWithProgress({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config });
+
+Future<NodeInfo> crateApiStartOffline({required String dataDir });
+
+Future<bool> crateApiStopMqttBridge();
+
+Future<bool> crateApiStopMqttBridgeOn({required int handle });
+
+Future<void> crateApiStopNode();
+
+Future<void> crateApiStopNodeInstance({required int handle });
+
+Future<int> crateApiStoreBatch({required List<BatchEntryDto> entries });
+
+Future<int> crateApiStoreBatchOn({required int handle , required List<BatchEntryDto> entries });
+
+Future<void> crateApiStoreData({required String dbName , required String key , required List<int> value , required String publicKey , required String signature });
+
+Future<String> crateApiStoreDataIdempotent({required String dbName , required String key , required List<int> value , required PlatformInt64 timestampMs , required String secretKey });
+
+Future<String> crateApiStoreDataIdempotentOn({required int handle , required String dbName , required String key , required List<int> value , required PlatformInt64 timestampMs , required String secretKey });
+
+Future<void> crateApiStoreDataLocal({required String dbName , required String key , required List<int> value });
+
+Future<void> crateApiStoreDataLocalOn({required int handle , required String dbName , required String key , required List<int> value });
+
+Future<void> crateApiStoreDataOn({required int handle , required String dbName , required String key , required List<int> value , required String publicKey , required String signature });
+
+Future<String> crateApiStoreDataSigned({required String dbName , required String key , required List<int> value , required String storeType });
+
+Future<String> crateApiStoreDataSignedOn({required int handle , required String dbName , required String key , required List<int> value , required String storeType });
+
+Future<void> crateApiStoreDataWithTtl({required String dbName , required String key , required List<int> value , required BigInt ttlSecs , required String secretKey });
+
+Future<void> crateApiStoreDataWithTtlOn({required int handle , required String dbName , required String key , required List<int> value , required BigInt ttlSecs , required String secretKey });
+
+Future<void> crateApiStoreEncrypted({required String dbName , required String key , required List<int> value , required String secretKey });
+
+Future<void> crateApiStoreEncryptedOn({required int handle , required String dbName , required String key , required List<int> value , required String secretKey });
+
+Stream<TopicMessageDto> crateApiSubscribeTopic({required String name });
+
+Stream<TopicMessageDto> crateApiSubscribeTopicOn({required int handle , required String name });
+
+Stream<TextDeltaDto> crateApiTextDeltaStream();
+
+Stream<TextDeltaDto> crateApiTextDeltaStreamOn({required int handle });
+
+Future<void> crateApiTsAdd({required String dbName , required String key , required PlatformInt64 timestampMs , required double value , required String secretKey });
+
+Future<void> crateApiTsAddOn({required int handle , required String dbName , required String key , required PlatformInt64 timestampMs , required double value , required String secretKey });
+
+List<TimePointDto> crateApiTsRange({required String dbName , required String key , required PlatformInt64 fromMs , required PlatformInt64 toMs });
+
+List<TimePointDto> crateApiTsRangeOn({required int handle , required String dbName , required String key , required PlatformInt64 fromMs , required PlatformInt64 toMs });
+
+void crateApiUnfollowDatabase({required String dbName });
+
+void crateApiUnfollowDatabaseOn({required int handle , required String dbName });
+
+void crateApiUnpinPeer({required String nodeId });
+
+void crateApiUnpinPeerOn({required int handle , required String nodeId });
+
+Future<bool> crateApiUnsubscribeTopic({required String name });
+
+Future<bool> crateApiUnsubscribeTopicOn({required int handle , required String name });
+
+void crateApiUpdateConfig({required NodeConfigDto config });
+
+void crateApiUpdateConfigOn({required int handle , required NodeConfigDto config });
+
+bool crateApiValidateTimestamp({required PlatformInt64 timestamp });
+
+bool crateApiVerifyBytes({required String publicKeyHex , required List<int> message , required String signatureHex });
+
+bool crateApiVerifyDbName({required String dbName , required String publicKeyHex });
+
+bool crateApiVerifyMessageSignature({required String publicKeyHex , required String message , required String signatureHex });
+
+Future<IntegrityReportDto> crateApiVerifyStorage({required bool repair });
+
+Future<IntegrityReportDto> crateApiVerifyStorageOn({required int handle , required bool repair });
+
+Future<SyncWaitSummaryDto> crateApiWaitForSync({required String dbName , required BigInt timeoutMs });
+
+Future<SyncWaitSummaryDto> crateApiWaitForSyncOn({required int handle , required String dbName , required BigInt timeoutMs });
+
+Future<WakeSyncSummaryDto> crateApiWakeAndSync({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required int maxDurationSecs });
+
+Future<WakeSyncSummaryDto> crateApiWakeAndSyncOn({required int handle , required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required int maxDurationSecs });
+
+Stream<KeyChangeDto> crateApiWatchDb({required String dbName });
+
+Stream<KeyChangeDto> crateApiWatchDbOn({required int handle , required String dbName });
+
+Stream<ValueUpdateDto> crateApiWatchKey({required String dbName , required String key });
+
+Stream<ValueUpdateDto> crateApiWatchKeyOn({required int handle , required String dbName , required String key });
+
+Future<void> crateApiZsetAdd({required String dbName , required String key , required String member , required double score , required String secretKey });
+
+Future<void> crateApiZsetAddOn({required int handle , required String dbName , required String key , required String member , required double score , required String secretKey });
+
+List<ScoredMemberDto> crateApiZsetRange({required String dbName , required String key , double? min , double? max });
+
+List<ScoredMemberDto> crateApiZsetRangeOn({required int handle , required String dbName , required String key , double? min , double? max });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override AcceptedInviteDto crateApiAcceptDbInvite({required String token })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(token);
+            return wire.wire__crate__api__accept_db_invite(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_accepted_invite_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAcceptDbInviteConstMeta,
+            argValues: [token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAcceptDbInviteConstMeta => const TaskConstMeta(
+            debugName: "accept_db_invite",
+            argNames: ["token"],
+        );
+        
+
+@override AcceptedInviteDto crateApiAcceptDbInviteOn({required int handle , required String token })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(token);
+            return wire.wire__crate__api__accept_db_invite_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_accepted_invite_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAcceptDbInviteOnConstMeta,
+            argValues: [handle, token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAcceptDbInviteOnConstMeta => const TaskConstMeta(
+            debugName: "accept_db_invite_on",
+            argNames: ["handle", "token"],
+        );
+        
+
+@override Future<BigInt> crateApiAcceptFile({required String offerId , required String destPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(offerId);
+var arg1 = cst_encode_String(destPath);
+            return wire.wire__crate__api__accept_file(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAcceptFileConstMeta,
+            argValues: [offerId, destPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAcceptFileConstMeta => const TaskConstMeta(
+            debugName: "accept_file",
+            argNames: ["offerId", "destPath"],
+        );
+        
+
+@override Future<BigInt> crateApiAcceptFileOn({required int handle , required String offerId , required String destPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(offerId);
+var arg2 = cst_encode_String(destPath);
+            return wire.wire__crate__api__accept_file_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAcceptFileOnConstMeta,
+            argValues: [handle, offerId, destPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAcceptFileOnConstMeta => const TaskConstMeta(
+            debugName: "accept_file_on",
+            argNames: ["handle", "offerId", "destPath"],
+        );
+        
+
+@override Future<void> crateApiAddLogEntry({required String level , required String message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(level);
+var arg1 = cst_encode_String(message);
+            return wire.wire__crate__api__add_log_entry(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAddLogEntryConstMeta,
+            argValues: [level, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddLogEntryConstMeta => const TaskConstMeta(
+            debugName: "add_log_entry",
+            argNames: ["level", "message"],
+        );
+        
+
+@override String crateApiBlake3Hash({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(data);
+            return wire.wire__crate__api__blake3_hash(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBlake3HashConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBlake3HashConstMeta => const TaskConstMeta(
+            debugName: "blake3_hash",
+            argNames: ["data"],
+        );
+        
+
+@override Stream<ChangeEventDto> crateApiChangeFeed({required String dbName , required BigInt sinceSeq })  { 
+            final sink = RustStreamSink<ChangeEventDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_u_64(sinceSeq);
+var arg2 = cst_encode_StreamSink_change_event_dto_Dco(sink);
+            return wire.wire__crate__api__change_feed(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChangeFeedConstMeta,
+            argValues: [dbName, sinceSeq, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiChangeFeedConstMeta => const TaskConstMeta(
+            debugName: "change_feed",
+            argNames: ["dbName", "sinceSeq", "sink"],
+        );
+        
+
+@override Stream<ChangeEventDto> crateApiChangeFeedOn({required int handle , required String dbName , required BigInt sinceSeq })  { 
+            final sink = RustStreamSink<ChangeEventDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_u_64(sinceSeq);
+var arg3 = cst_encode_StreamSink_change_event_dto_Dco(sink);
+            return wire.wire__crate__api__change_feed_on(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChangeFeedOnConstMeta,
+            argValues: [handle, dbName, sinceSeq, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiChangeFeedOnConstMeta => const TaskConstMeta(
+            debugName: "change_feed_on",
+            argNames: ["handle", "dbName", "sinceSeq", "sink"],
+        );
+        
+
+@override Future<CheckpointDto> crateApiCheckpoint({BigInt? budgetMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_opt_box_autoadd_u_64(budgetMs);
+            return wire.wire__crate__api__checkpoint(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_checkpoint_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCheckpointConstMeta,
+            argValues: [budgetMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckpointConstMeta => const TaskConstMeta(
+            debugName: "checkpoint",
+            argNames: ["budgetMs"],
+        );
+        
+
+@override Future<CheckpointDto> crateApiCheckpointOn({required int handle , BigInt? budgetMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_opt_box_autoadd_u_64(budgetMs);
+            return wire.wire__crate__api__checkpoint_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_checkpoint_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCheckpointOnConstMeta,
+            argValues: [handle, budgetMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckpointOnConstMeta => const TaskConstMeta(
+            debugName: "checkpoint_on",
+            argNames: ["handle", "budgetMs"],
+        );
+        
+
+@override void crateApiClearLogs()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__clear_logs();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiClearLogsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClearLogsConstMeta => const TaskConstMeta(
+            debugName: "clear_logs",
+            argNames: [],
+        );
+        
+
+@override void crateApiCloseStorageReadOnly()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__close_storage_read_only();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCloseStorageReadOnlyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCloseStorageReadOnlyConstMeta => const TaskConstMeta(
+            debugName: "close_storage_read_only",
+            argNames: [],
+        );
+        
+
+@override bool crateApiCompareAndSwap({required String dbName , required String key , Uint8List? expected , Uint8List? newValue })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_opt_list_prim_u_8_strict(expected);
+var arg3 = cst_encode_opt_list_prim_u_8_strict(newValue);
+            return wire.wire__crate__api__compare_and_swap(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCompareAndSwapConstMeta,
+            argValues: [dbName, key, expected, newValue],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompareAndSwapConstMeta => const TaskConstMeta(
+            debugName: "compare_and_swap",
+            argNames: ["dbName", "key", "expected", "newValue"],
+        );
+        
+
+@override bool crateApiCompareAndSwapOn({required int handle , required String dbName , required String key , Uint8List? expected , Uint8List? newValue })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_opt_list_prim_u_8_strict(expected);
+var arg4 = cst_encode_opt_list_prim_u_8_strict(newValue);
+            return wire.wire__crate__api__compare_and_swap_on(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCompareAndSwapOnConstMeta,
+            argValues: [handle, dbName, key, expected, newValue],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompareAndSwapOnConstMeta => const TaskConstMeta(
+            debugName: "compare_and_swap_on",
+            argNames: ["handle", "dbName", "key", "expected", "newValue"],
+        );
+        
+
+@override Future<ConnectionResultDto> crateApiConnectPeer({required String nodeId , String? address })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(nodeId);
+var arg1 = cst_encode_opt_String(address);
+            return wire.wire__crate__api__connect_peer(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_connection_result_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConnectPeerConstMeta,
+            argValues: [nodeId, address],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectPeerConstMeta => const TaskConstMeta(
+            debugName: "connect_peer",
+            argNames: ["nodeId", "address"],
+        );
+        
+
+@override Future<ConnectionResultDto> crateApiConnectPeerOn({required int handle , required String nodeId , String? address })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(nodeId);
+var arg2 = cst_encode_opt_String(address);
+            return wire.wire__crate__api__connect_peer_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_connection_result_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConnectPeerOnConstMeta,
+            argValues: [handle, nodeId, address],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConnectPeerOnConstMeta => const TaskConstMeta(
+            debugName: "connect_peer_on",
+            argNames: ["handle", "nodeId", "address"],
+        );
+        
+
+@override Future<String> crateApiCreateDbInvite({required String dbName , required String ownerSecretKey , required bool canWrite , required bool includeEncryptionKey , required BigInt expiresInSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(ownerSecretKey);
+var arg2 = cst_encode_bool(canWrite);
+var arg3 = cst_encode_bool(includeEncryptionKey);
+var arg4 = cst_encode_u_64(expiresInSecs);
+            return wire.wire__crate__api__create_db_invite(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateDbInviteConstMeta,
+            argValues: [dbName, ownerSecretKey, canWrite, includeEncryptionKey, expiresInSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateDbInviteConstMeta => const TaskConstMeta(
+            debugName: "create_db_invite",
+            argNames: ["dbName", "ownerSecretKey", "canWrite", "includeEncryptionKey", "expiresInSecs"],
+        );
+        
+
+@override Future<String> crateApiCreateDbInviteOn({required int handle , required String dbName , required String ownerSecretKey , required bool canWrite , required bool includeEncryptionKey , required BigInt expiresInSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(ownerSecretKey);
+var arg3 = cst_encode_bool(canWrite);
+var arg4 = cst_encode_bool(includeEncryptionKey);
+var arg5 = cst_encode_u_64(expiresInSecs);
+            return wire.wire__crate__api__create_db_invite_on(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateDbInviteOnConstMeta,
+            argValues: [handle, dbName, ownerSecretKey, canWrite, includeEncryptionKey, expiresInSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateDbInviteOnConstMeta => const TaskConstMeta(
+            debugName: "create_db_invite_on",
+            argNames: ["handle", "dbName", "ownerSecretKey", "canWrite", "includeEncryptionKey", "expiresInSecs"],
+        );
+        
+
+@override String crateApiCreateDeviceLinkRequest({required String deviceName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(deviceName);
+            return wire.wire__crate__api__create_device_link_request(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateDeviceLinkRequestConstMeta,
+            argValues: [deviceName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateDeviceLinkRequestConstMeta => const TaskConstMeta(
+            debugName: "create_device_link_request",
+            argNames: ["deviceName"],
+        );
+        
+
+@override String crateApiCreateDeviceLinkRequestOn({required int handle , required String deviceName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(deviceName);
+            return wire.wire__crate__api__create_device_link_request_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateDeviceLinkRequestOnConstMeta,
+            argValues: [handle, deviceName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateDeviceLinkRequestOnConstMeta => const TaskConstMeta(
+            debugName: "create_device_link_request_on",
+            argNames: ["handle", "deviceName"],
+        );
+        
+
+@override void crateApiCreateEncryptedDb({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__create_encrypted_db(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateEncryptedDbConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateEncryptedDbConstMeta => const TaskConstMeta(
+            debugName: "create_encrypted_db",
+            argNames: ["dbName"],
+        );
+        
+
+@override void crateApiCreateEncryptedDbOn({required int handle , required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__create_encrypted_db_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateEncryptedDbOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateEncryptedDbOnConstMeta => const TaskConstMeta(
+            debugName: "create_encrypted_db_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override void crateApiDeclineFile({required String offerId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(offerId);
+            return wire.wire__crate__api__decline_file(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeclineFileConstMeta,
+            argValues: [offerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeclineFileConstMeta => const TaskConstMeta(
+            debugName: "decline_file",
+            argNames: ["offerId"],
+        );
+        
+
+@override void crateApiDeclineFileOn({required int handle , required String offerId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(offerId);
+            return wire.wire__crate__api__decline_file_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeclineFileOnConstMeta,
+            argValues: [handle, offerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeclineFileOnConstMeta => const TaskConstMeta(
+            debugName: "decline_file_on",
+            argNames: ["handle", "offerId"],
+        );
+        
+
+@override NodeConfigDto crateApiDefaultNodeConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__default_node_config();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_config_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDefaultNodeConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDefaultNodeConfigConstMeta => const TaskConstMeta(
+            debugName: "default_node_config",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiDeleteData({required String dbName , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__delete_data(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteDataConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteDataConstMeta => const TaskConstMeta(
+            debugName: "delete_data",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override Future<void> crateApiDeleteDataOn({required int handle , required String dbName , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+            return wire.wire__crate__api__delete_data_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteDataOnConstMeta,
+            argValues: [handle, dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteDataOnConstMeta => const TaskConstMeta(
+            debugName: "delete_data_on",
+            argNames: ["handle", "dbName", "key"],
+        );
+        
+
+@override Stream<DirectMessageDto> crateApiDmStream()  { 
+            final sink = RustStreamSink<DirectMessageDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_StreamSink_direct_message_dto_Dco(sink);
+            return wire.wire__crate__api__dm_stream(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDmStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiDmStreamConstMeta => const TaskConstMeta(
+            debugName: "dm_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<DirectMessageDto> crateApiDmStreamOn({required int handle })  { 
+            final sink = RustStreamSink<DirectMessageDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_StreamSink_direct_message_dto_Dco(sink);
+            return wire.wire__crate__api__dm_stream_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDmStreamOnConstMeta,
+            argValues: [handle, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiDmStreamOnConstMeta => const TaskConstMeta(
+            debugName: "dm_stream_on",
+            argNames: ["handle", "sink"],
+        );
+        
+
+@override Future<void> crateApiDropDatabase({required String dbName , required String ownerSecretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(ownerSecretKey);
+            return wire.wire__crate__api__drop_database(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDropDatabaseConstMeta,
+            argValues: [dbName, ownerSecretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDropDatabaseConstMeta => const TaskConstMeta(
+            debugName: "drop_database",
+            argNames: ["dbName", "ownerSecretKey"],
+        );
+        
+
+@override Future<void> crateApiDropDatabaseOn({required int handle , required String dbName , required String ownerSecretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(ownerSecretKey);
+            return wire.wire__crate__api__drop_database_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDropDatabaseOnConstMeta,
+            argValues: [handle, dbName, ownerSecretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDropDatabaseOnConstMeta => const TaskConstMeta(
+            debugName: "drop_database_on",
+            argNames: ["handle", "dbName", "ownerSecretKey"],
+        );
+        
+
+@override Future<String> crateApiEditText({required String dbName , required String key , required BigInt index , required BigInt delete , required String insert , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_u_64(index);
+var arg3 = cst_encode_u_64(delete);
+var arg4 = cst_encode_String(insert);
+var arg5 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__edit_text(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEditTextConstMeta,
+            argValues: [dbName, key, index, delete, insert, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEditTextConstMeta => const TaskConstMeta(
+            debugName: "edit_text",
+            argNames: ["dbName", "key", "index", "delete", "insert", "secretKey"],
+        );
+        
+
+@override Future<String> crateApiEditTextOn({required int handle , required String dbName , required String key , required BigInt index , required BigInt delete , required String insert , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_u_64(index);
+var arg4 = cst_encode_u_64(delete);
+var arg5 = cst_encode_String(insert);
+var arg6 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__edit_text_on(port_, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEditTextOnConstMeta,
+            argValues: [handle, dbName, key, index, delete, insert, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEditTextOnConstMeta => const TaskConstMeta(
+            debugName: "edit_text_on",
+            argNames: ["handle", "dbName", "key", "index", "delete", "insert", "secretKey"],
+        );
+        
+
+@override Stream<NodeEventRecordDto> crateApiEventStream({required BigInt sinceSeq })  { 
+            final sink = RustStreamSink<NodeEventRecordDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_64(sinceSeq);
+var arg1 = cst_encode_StreamSink_node_event_record_dto_Dco(sink);
+            return wire.wire__crate__api__event_stream(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEventStreamConstMeta,
+            argValues: [sinceSeq, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiEventStreamConstMeta => const TaskConstMeta(
+            debugName: "event_stream",
+            argNames: ["sinceSeq", "sink"],
+        );
+        
+
+@override Stream<NodeEventRecordDto> crateApiEventStreamOn({required int handle , required BigInt sinceSeq })  { 
+            final sink = RustStreamSink<NodeEventRecordDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_u_64(sinceSeq);
+var arg2 = cst_encode_StreamSink_node_event_record_dto_Dco(sink);
+            return wire.wire__crate__api__event_stream_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiEventStreamOnConstMeta,
+            argValues: [handle, sinceSeq, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiEventStreamOnConstMeta => const TaskConstMeta(
+            debugName: "event_stream_on",
+            argNames: ["handle", "sinceSeq", "sink"],
+        );
+        
+
+@override Future<BackupInfoDto> crateApiExportBackup({required String path , required String passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(path);
+var arg1 = cst_encode_String(passphrase);
+            return wire.wire__crate__api__export_backup(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_backup_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportBackupConstMeta,
+            argValues: [path, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportBackupConstMeta => const TaskConstMeta(
+            debugName: "export_backup",
+            argNames: ["path", "passphrase"],
+        );
+        
+
+@override Future<BackupInfoDto> crateApiExportBackupOn({required int handle , required String path , required String passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(path);
+var arg2 = cst_encode_String(passphrase);
+            return wire.wire__crate__api__export_backup_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_backup_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportBackupOnConstMeta,
+            argValues: [handle, path, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportBackupOnConstMeta => const TaskConstMeta(
+            debugName: "export_backup_on",
+            argNames: ["handle", "path", "passphrase"],
+        );
+        
+
+@override String crateApiExportDbKey({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__export_db_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportDbKeyConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportDbKeyConstMeta => const TaskConstMeta(
+            debugName: "export_db_key",
+            argNames: ["dbName"],
+        );
+        
+
+@override String crateApiExportDbKeyOn({required int handle , required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__export_db_key_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportDbKeyOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportDbKeyOnConstMeta => const TaskConstMeta(
+            debugName: "export_db_key_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override Future<int> crateApiExportLogs({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(path);
+            return wire.wire__crate__api__export_logs(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportLogsConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportLogsConstMeta => const TaskConstMeta(
+            debugName: "export_logs",
+            argNames: ["path"],
+        );
+        
+
+@override String? crateApiExtractNameFromDb({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__extract_name_from_db(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExtractNameFromDbConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExtractNameFromDbConstMeta => const TaskConstMeta(
+            debugName: "extract_name_from_db",
+            argNames: ["dbName"],
+        );
+        
+
+@override Stream<FileEventDto> crateApiFileEventStream()  { 
+            final sink = RustStreamSink<FileEventDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_StreamSink_file_event_dto_Dco(sink);
+            return wire.wire__crate__api__file_event_stream(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFileEventStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiFileEventStreamConstMeta => const TaskConstMeta(
+            debugName: "file_event_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<FileEventDto> crateApiFileEventStreamOn({required int handle })  { 
+            final sink = RustStreamSink<FileEventDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_StreamSink_file_event_dto_Dco(sink);
+            return wire.wire__crate__api__file_event_stream_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFileEventStreamOnConstMeta,
+            argValues: [handle, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiFileEventStreamOnConstMeta => const TaskConstMeta(
+            debugName: "file_event_stream_on",
+            argNames: ["handle", "sink"],
+        );
+        
+
+@override void crateApiFollowDatabase({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__follow_database(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFollowDatabaseConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFollowDatabaseConstMeta => const TaskConstMeta(
+            debugName: "follow_database",
+            argNames: ["dbName"],
+        );
+        
+
+@override void crateApiFollowDatabaseOn({required int handle , required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__follow_database_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiFollowDatabaseOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFollowDatabaseOnConstMeta => const TaskConstMeta(
+            debugName: "follow_database_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override String crateApiGenerateDbName({required String name , required String publicKeyHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(name);
+var arg1 = cst_encode_String(publicKeyHex);
+            return wire.wire__crate__api__generate_db_name(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGenerateDbNameConstMeta,
+            argValues: [name, publicKeyHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGenerateDbNameConstMeta => const TaskConstMeta(
+            debugName: "generate_db_name",
+            argNames: ["name", "publicKeyHex"],
+        );
+        
+
+@override KeyPairDto crateApiGenerateKeypair()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__generate_keypair();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_key_pair_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGenerateKeypairConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGenerateKeypairConstMeta => const TaskConstMeta(
+            debugName: "generate_keypair",
+            argNames: [],
+        );
+        
+
+@override String crateApiGeneratePeerIdFromSecretKey({required String secretKeyHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(secretKeyHex);
+            return wire.wire__crate__api__generate_peer_id_from_secret_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGeneratePeerIdFromSecretKeyConstMeta,
+            argValues: [secretKeyHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGeneratePeerIdFromSecretKeyConstMeta => const TaskConstMeta(
+            debugName: "generate_peer_id_from_secret_key",
+            argNames: ["secretKeyHex"],
+        );
+        
+
+@override Future<void> crateApiGeoAdd({required String dbName , required String key , required String member , required double latitude , required double longitude , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_String(member);
+var arg3 = cst_encode_f_64(latitude);
+var arg4 = cst_encode_f_64(longitude);
+var arg5 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__geo_add(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGeoAddConstMeta,
+            argValues: [dbName, key, member, latitude, longitude, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGeoAddConstMeta => const TaskConstMeta(
+            debugName: "geo_add",
+            argNames: ["dbName", "key", "member", "latitude", "longitude", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiGeoAddOn({required int handle , required String dbName , required String key , required String member , required double latitude , required double longitude , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_String(member);
+var arg4 = cst_encode_f_64(latitude);
+var arg5 = cst_encode_f_64(longitude);
+var arg6 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__geo_add_on(port_, arg0, arg1, arg2, arg3, arg4, arg5, arg6);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGeoAddOnConstMeta,
+            argValues: [handle, dbName, key, member, latitude, longitude, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGeoAddOnConstMeta => const TaskConstMeta(
+            debugName: "geo_add_on",
+            argNames: ["handle", "dbName", "key", "member", "latitude", "longitude", "secretKey"],
+        );
+        
+
+@override List<GeoMatchDto> crateApiGeoRadius({required String dbName , required String key , required double latitude , required double longitude , required double radiusM })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_f_64(latitude);
+var arg3 = cst_encode_f_64(longitude);
+var arg4 = cst_encode_f_64(radiusM);
+            return wire.wire__crate__api__geo_radius(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_geo_match_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGeoRadiusConstMeta,
+            argValues: [dbName, key, latitude, longitude, radiusM],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGeoRadiusConstMeta => const TaskConstMeta(
+            debugName: "geo_radius",
+            argNames: ["dbName", "key", "latitude", "longitude", "radiusM"],
+        );
+        
+
+@override List<GeoMatchDto> crateApiGeoRadiusOn({required int handle , required String dbName , required String key , required double latitude , required double longitude , required double radiusM })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_f_64(latitude);
+var arg4 = cst_encode_f_64(longitude);
+var arg5 = cst_encode_f_64(radiusM);
+            return wire.wire__crate__api__geo_radius_on(arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_geo_match_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGeoRadiusOnConstMeta,
+            argValues: [handle, dbName, key, latitude, longitude, radiusM],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGeoRadiusOnConstMeta => const TaskConstMeta(
+            debugName: "geo_radius_on",
+            argNames: ["handle", "dbName", "key", "latitude", "longitude", "radiusM"],
+        );
+        
+
+@override Future<List<DbEntryDto>> crateApiGetAllData()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            return wire.wire__crate__api__get_all_data(port_);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_db_entry_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllDataConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllDataConstMeta => const TaskConstMeta(
+            debugName: "get_all_data",
+            argNames: [],
+        );
+        
+
+@override Future<List<DbEntryDto>> crateApiGetAllDataOn({required int handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_all_data_on(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_db_entry_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllDataOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllDataOnConstMeta => const TaskConstMeta(
+            debugName: "get_all_data_on",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<ScanPageDto> crateApiGetAllDataPage({required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(limit);
+var arg1 = cst_encode_opt_String(cursor);
+var arg2 = cst_encode_bool(keysOnly);
+var arg3 = cst_encode_opt_box_autoadd_u_64(maxBytes);
+            return wire.wire__crate__api__get_all_data_page(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllDataPageConstMeta,
+            argValues: [limit, cursor, keysOnly, maxBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllDataPageConstMeta => const TaskConstMeta(
+            debugName: "get_all_data_page",
+            argNames: ["limit", "cursor", "keysOnly", "maxBytes"],
+        );
+        
+
+@override Future<ScanPageDto> crateApiGetAllDataPageOn({required int handle , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_u_32(limit);
+var arg2 = cst_encode_opt_String(cursor);
+var arg3 = cst_encode_bool(keysOnly);
+var arg4 = cst_encode_opt_box_autoadd_u_64(maxBytes);
+            return wire.wire__crate__api__get_all_data_page_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllDataPageOnConstMeta,
+            argValues: [handle, limit, cursor, keysOnly, maxBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllDataPageOnConstMeta => const TaskConstMeta(
+            debugName: "get_all_data_page_on",
+            argNames: ["handle", "limit", "cursor", "keysOnly", "maxBytes"],
+        );
+        
+
+@override Future<List<DbEntryDto>> crateApiGetAllEntries({required String dbName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__get_all_entries(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_db_entry_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllEntriesConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllEntriesConstMeta => const TaskConstMeta(
+            debugName: "get_all_entries",
+            argNames: ["dbName"],
+        );
+        
+
+@override Future<List<DbEntryDto>> crateApiGetAllEntriesOn({required int handle , required String dbName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__get_all_entries_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_db_entry_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllEntriesOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllEntriesOnConstMeta => const TaskConstMeta(
+            debugName: "get_all_entries_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override BandwidthStatsDto crateApiGetBandwidthStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_bandwidth_stats();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bandwidth_stats_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetBandwidthStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetBandwidthStatsConstMeta => const TaskConstMeta(
+            debugName: "get_bandwidth_stats",
+            argNames: [],
+        );
+        
+
+@override BandwidthStatsDto crateApiGetBandwidthStatsOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_bandwidth_stats_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bandwidth_stats_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetBandwidthStatsOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetBandwidthStatsOnConstMeta => const TaskConstMeta(
+            debugName: "get_bandwidth_stats_on",
+            argNames: ["handle"],
+        );
+        
+
+@override ConnectionDiagnosticsDto crateApiGetConnectionDiagnostics()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_connection_diagnostics();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_connection_diagnostics_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetConnectionDiagnosticsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetConnectionDiagnosticsConstMeta => const TaskConstMeta(
+            debugName: "get_connection_diagnostics",
+            argNames: [],
+        );
+        
+
+@override ConnectionDiagnosticsDto crateApiGetConnectionDiagnosticsOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_connection_diagnostics_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_connection_diagnostics_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetConnectionDiagnosticsOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetConnectionDiagnosticsOnConstMeta => const TaskConstMeta(
+            debugName: "get_connection_diagnostics_on",
+            argNames: ["handle"],
+        );
+        
+
+@override ConnectivityDto crateApiGetConnectivityState()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_connectivity_state();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_connectivity_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetConnectivityStateConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetConnectivityStateConstMeta => const TaskConstMeta(
+            debugName: "get_connectivity_state",
+            argNames: [],
+        );
+        
+
+@override ConnectivityDto crateApiGetConnectivityStateOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_connectivity_state_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_connectivity_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetConnectivityStateOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetConnectivityStateOnConstMeta => const TaskConstMeta(
+            debugName: "get_connectivity_state_on",
+            argNames: ["handle"],
+        );
+        
+
+@override PlatformInt64 crateApiGetCounter({required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__get_counter(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_i_64,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCounterConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCounterConstMeta => const TaskConstMeta(
+            debugName: "get_counter",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override PlatformInt64 crateApiGetCounterOn({required int handle , required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+            return wire.wire__crate__api__get_counter_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_i_64,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCounterOnConstMeta,
+            argValues: [handle, dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCounterOnConstMeta => const TaskConstMeta(
+            debugName: "get_counter_on",
+            argNames: ["handle", "dbName", "key"],
+        );
+        
+
+@override Future<Uint8List?> crateApiGetData({required String dbName , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__get_data(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDataConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDataConstMeta => const TaskConstMeta(
+            debugName: "get_data",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override Future<Uint8List?> crateApiGetDataOn({required int handle , required String dbName , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+            return wire.wire__crate__api__get_data_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDataOnConstMeta,
+            argValues: [handle, dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDataOnConstMeta => const TaskConstMeta(
+            debugName: "get_data_on",
+            argNames: ["handle", "dbName", "key"],
+        );
+        
+
+@override Future<String> crateApiGetDatabaseRoot({required String dbName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__get_database_root(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDatabaseRootConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDatabaseRootConstMeta => const TaskConstMeta(
+            debugName: "get_database_root",
+            argNames: ["dbName"],
+        );
+        
+
+@override Future<String> crateApiGetDatabaseRootOn({required int handle , required String dbName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__get_database_root_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDatabaseRootOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDatabaseRootOnConstMeta => const TaskConstMeta(
+            debugName: "get_database_root_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override Future<DbSyncInfoDto> crateApiGetDbSyncInfo({required String dbName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__get_db_sync_info(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_db_sync_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDbSyncInfoConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDbSyncInfoConstMeta => const TaskConstMeta(
+            debugName: "get_db_sync_info",
+            argNames: ["dbName"],
+        );
+        
+
+@override Future<DbSyncInfoDto> crateApiGetDbSyncInfoOn({required int handle , required String dbName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__get_db_sync_info_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_db_sync_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDbSyncInfoOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDbSyncInfoOnConstMeta => const TaskConstMeta(
+            debugName: "get_db_sync_info_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override Uint8List? crateApiGetDecrypted({required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__get_decrypted(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDecryptedConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDecryptedConstMeta => const TaskConstMeta(
+            debugName: "get_decrypted",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override Uint8List? crateApiGetDecryptedOn({required int handle , required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+            return wire.wire__crate__api__get_decrypted_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDecryptedOnConstMeta,
+            argValues: [handle, dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDecryptedOnConstMeta => const TaskConstMeta(
+            debugName: "get_decrypted_on",
+            argNames: ["handle", "dbName", "key"],
+        );
+        
+
+@override List<DirectMessageDto> crateApiGetDmHistory({required String peerId , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(peerId);
+var arg1 = cst_encode_u_32(limit);
+            return wire.wire__crate__api__get_dm_history(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_direct_message_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDmHistoryConstMeta,
+            argValues: [peerId, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDmHistoryConstMeta => const TaskConstMeta(
+            debugName: "get_dm_history",
+            argNames: ["peerId", "limit"],
+        );
+        
+
+@override List<DirectMessageDto> crateApiGetDmHistoryOn({required int handle , required String peerId , required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+var arg2 = cst_encode_u_32(limit);
+            return wire.wire__crate__api__get_dm_history_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_direct_message_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDmHistoryOnConstMeta,
+            argValues: [handle, peerId, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDmHistoryOnConstMeta => const TaskConstMeta(
+            debugName: "get_dm_history_on",
+            argNames: ["handle", "peerId", "limit"],
+        );
+        
+
+@override Future<ScanPageDto> crateApiGetEntriesPage({required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_u_32(limit);
+var arg2 = cst_encode_opt_String(cursor);
+var arg3 = cst_encode_bool(keysOnly);
+var arg4 = cst_encode_opt_box_autoadd_u_64(maxBytes);
+            return wire.wire__crate__api__get_entries_page(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetEntriesPageConstMeta,
+            argValues: [dbName, limit, cursor, keysOnly, maxBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetEntriesPageConstMeta => const TaskConstMeta(
+            debugName: "get_entries_page",
+            argNames: ["dbName", "limit", "cursor", "keysOnly", "maxBytes"],
+        );
+        
+
+@override Future<ScanPageDto> crateApiGetEntriesPageOn({required int handle , required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_u_32(limit);
+var arg3 = cst_encode_opt_String(cursor);
+var arg4 = cst_encode_bool(keysOnly);
+var arg5 = cst_encode_opt_box_autoadd_u_64(maxBytes);
+            return wire.wire__crate__api__get_entries_page_on(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetEntriesPageOnConstMeta,
+            argValues: [handle, dbName, limit, cursor, keysOnly, maxBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetEntriesPageOnConstMeta => const TaskConstMeta(
+            debugName: "get_entries_page_on",
+            argNames: ["handle", "dbName", "limit", "cursor", "keysOnly", "maxBytes"],
+        );
+        
+
+@override List<String> crateApiGetFollowedDatabases()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_followed_databases();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetFollowedDatabasesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetFollowedDatabasesConstMeta => const TaskConstMeta(
+            debugName: "get_followed_databases",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiGetFollowedDatabasesOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_followed_databases_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetFollowedDatabasesOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetFollowedDatabasesOnConstMeta => const TaskConstMeta(
+            debugName: "get_followed_databases_on",
+            argNames: ["handle"],
+        );
+        
+
+@override String crateApiGetLogLevelsFilter()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_log_levels_filter();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetLogLevelsFilterConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLogLevelsFilterConstMeta => const TaskConstMeta(
+            debugName: "get_log_levels_filter",
+            argNames: [],
+        );
+        
+
+@override List<LogEntry> crateApiGetLogs({int? limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_opt_box_autoadd_u_32(limit);
+            return wire.wire__crate__api__get_logs(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_log_entry,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetLogsConstMeta,
+            argValues: [limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLogsConstMeta => const TaskConstMeta(
+            debugName: "get_logs",
+            argNames: ["limit"],
+        );
+        
+
+@override List<LogEntry> crateApiGetLogsFiltered({required LogFilterDto filter })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_log_filter_dto(filter);
+            return wire.wire__crate__api__get_logs_filtered(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_log_entry,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetLogsFilteredConstMeta,
+            argValues: [filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLogsFilteredConstMeta => const TaskConstMeta(
+            debugName: "get_logs_filtered",
+            argNames: ["filter"],
+        );
+        
+
+@override Future<List<Uint8List?>> crateApiGetMany({required String dbName , required List<String> keys })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_list_String(keys);
+            return wire.wire__crate__api__get_many(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_opt_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetManyConstMeta,
+            argValues: [dbName, keys],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetManyConstMeta => const TaskConstMeta(
+            debugName: "get_many",
+            argNames: ["dbName", "keys"],
+        );
+        
+
+@override Future<List<Uint8List?>> crateApiGetManyOn({required int handle , required String dbName , required List<String> keys })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_list_String(keys);
+            return wire.wire__crate__api__get_many_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_opt_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetManyOnConstMeta,
+            argValues: [handle, dbName, keys],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetManyOnConstMeta => const TaskConstMeta(
+            debugName: "get_many_on",
+            argNames: ["handle", "dbName", "keys"],
+        );
+        
+
+@override MetricsDto crateApiGetMetrics()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_metrics();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_metrics_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetMetricsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetMetricsConstMeta => const TaskConstMeta(
+            debugName: "get_metrics",
+            argNames: [],
+        );
+        
+
+@override MetricsDto crateApiGetMetricsOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_metrics_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_metrics_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetMetricsOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetMetricsOnConstMeta => const TaskConstMeta(
+            debugName: "get_metrics_on",
+            argNames: ["handle"],
+        );
+        
+
+@override MqttBridgeConfigDto? crateApiGetMqttBridgeConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_mqtt_bridge_config();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_mqtt_bridge_config_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetMqttBridgeConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetMqttBridgeConfigConstMeta => const TaskConstMeta(
+            debugName: "get_mqtt_bridge_config",
+            argNames: [],
+        );
+        
+
+@override MqttBridgeConfigDto? crateApiGetMqttBridgeConfigOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_mqtt_bridge_config_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_mqtt_bridge_config_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetMqttBridgeConfigOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetMqttBridgeConfigOnConstMeta => const TaskConstMeta(
+            debugName: "get_mqtt_bridge_config_on",
+            argNames: ["handle"],
+        );
+        
+
+@override String? crateApiGetNetworkId({required String dataDir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dataDir);
+            return wire.wire__crate__api__get_network_id(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNetworkIdConstMeta,
+            argValues: [dataDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNetworkIdConstMeta => const TaskConstMeta(
+            debugName: "get_network_id",
+            argNames: ["dataDir"],
+        );
+        
+
+@override NodeAddrDto crateApiGetNodeAddr()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_node_addr();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_addr_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeAddrConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeAddrConstMeta => const TaskConstMeta(
+            debugName: "get_node_addr",
+            argNames: [],
+        );
+        
+
+@override NodeAddrDto crateApiGetNodeAddrOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_node_addr_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_addr_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeAddrOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeAddrOnConstMeta => const TaskConstMeta(
+            debugName: "get_node_addr_on",
+            argNames: ["handle"],
+        );
+        
+
+@override NodeConfigDto crateApiGetNodeConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_node_config();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_config_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeConfigConstMeta => const TaskConstMeta(
+            debugName: "get_node_config",
+            argNames: [],
+        );
+        
+
+@override NodeConfigDto crateApiGetNodeConfigOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_node_config_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_config_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeConfigOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeConfigOnConstMeta => const TaskConstMeta(
+            debugName: "get_node_config_on",
+            argNames: ["handle"],
+        );
+        
+
+@override NodeInfo? crateApiGetNodeInfo()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_node_info();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_node_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetNodeInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeInfoConstMeta => const TaskConstMeta(
+            debugName: "get_node_info",
+            argNames: [],
+        );
+        
+
+@override NodeInfo? crateApiGetNodeInfoOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_node_info_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_node_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetNodeInfoOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeInfoOnConstMeta => const TaskConstMeta(
+            debugName: "get_node_info_on",
+            argNames: ["handle"],
+        );
+        
+
+@override NodeStatsDto crateApiGetNodeStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_node_stats();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_stats_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeStatsConstMeta => const TaskConstMeta(
+            debugName: "get_node_stats",
+            argNames: [],
+        );
+        
+
+@override NodeStatsDto crateApiGetNodeStatsOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_node_stats_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_stats_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeStatsOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeStatsOnConstMeta => const TaskConstMeta(
+            debugName: "get_node_stats_on",
+            argNames: ["handle"],
+        );
+        
+
+@override NodeStatusDto crateApiGetNodeStatus()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_node_status();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_status_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeStatusConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeStatusConstMeta => const TaskConstMeta(
+            debugName: "get_node_status",
+            argNames: [],
+        );
+        
+
+@override NodeStatusDto crateApiGetNodeStatusOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_node_status_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_status_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeStatusOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeStatusOnConstMeta => const TaskConstMeta(
+            debugName: "get_node_status_on",
+            argNames: ["handle"],
+        );
+        
+
+@override String crateApiGetNodeTicket()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_node_ticket();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeTicketConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeTicketConstMeta => const TaskConstMeta(
+            debugName: "get_node_ticket",
+            argNames: [],
+        );
+        
+
+@override String crateApiGetNodeTicketOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_node_ticket_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetNodeTicketOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNodeTicketOnConstMeta => const TaskConstMeta(
+            debugName: "get_node_ticket_on",
+            argNames: ["handle"],
+        );
+        
+
+@override List<PeerCircuitDto> crateApiGetPeerCircuits()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_peer_circuits();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_circuit_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPeerCircuitsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPeerCircuitsConstMeta => const TaskConstMeta(
+            debugName: "get_peer_circuits",
+            argNames: [],
+        );
+        
+
+@override List<PeerCircuitDto> crateApiGetPeerCircuitsOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_peer_circuits_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_circuit_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPeerCircuitsOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPeerCircuitsOnConstMeta => const TaskConstMeta(
+            debugName: "get_peer_circuits_on",
+            argNames: ["handle"],
+        );
+        
+
+@override List<PeerPathDto> crateApiGetPeerPaths()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_peer_paths();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_path_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPeerPathsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPeerPathsConstMeta => const TaskConstMeta(
+            debugName: "get_peer_paths",
+            argNames: [],
+        );
+        
+
+@override List<PeerPathDto> crateApiGetPeerPathsOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_peer_paths_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_path_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPeerPathsOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPeerPathsOnConstMeta => const TaskConstMeta(
+            debugName: "get_peer_paths_on",
+            argNames: ["handle"],
+        );
+        
+
+@override List<PeerInfoDto> crateApiGetPeers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_peers();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPeersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPeersConstMeta => const TaskConstMeta(
+            debugName: "get_peers",
+            argNames: [],
+        );
+        
+
+@override List<PeerInfoDto> crateApiGetPeersFiltered({required List<String> capabilities , String? region , BigInt? maxLatencyMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_String(capabilities);
+var arg1 = cst_encode_opt_String(region);
+var arg2 = cst_encode_opt_box_autoadd_u_64(maxLatencyMs);
+            return wire.wire__crate__api__get_peers_filtered(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPeersFilteredConstMeta,
+            argValues: [capabilities, region, maxLatencyMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPeersFilteredConstMeta => const TaskConstMeta(
+            debugName: "get_peers_filtered",
+            argNames: ["capabilities", "region", "maxLatencyMs"],
+        );
+        
+
+@override List<PeerInfoDto> crateApiGetPeersFilteredOn({required int handle , required List<String> capabilities , String? region , BigInt? maxLatencyMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_list_String(capabilities);
+var arg2 = cst_encode_opt_String(region);
+var arg3 = cst_encode_opt_box_autoadd_u_64(maxLatencyMs);
+            return wire.wire__crate__api__get_peers_filtered_on(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPeersFilteredOnConstMeta,
+            argValues: [handle, capabilities, region, maxLatencyMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPeersFilteredOnConstMeta => const TaskConstMeta(
+            debugName: "get_peers_filtered_on",
+            argNames: ["handle", "capabilities", "region", "maxLatencyMs"],
+        );
+        
+
+@override List<PeerInfoDto> crateApiGetPeersOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_peers_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPeersOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPeersOnConstMeta => const TaskConstMeta(
+            debugName: "get_peers_on",
+            argNames: ["handle"],
+        );
+        
+
+@override List<PinnedPeerDto> crateApiGetPinnedPeers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_pinned_peers();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_pinned_peer_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPinnedPeersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPinnedPeersConstMeta => const TaskConstMeta(
+            debugName: "get_pinned_peers",
+            argNames: [],
+        );
+        
+
+@override List<PinnedPeerDto> crateApiGetPinnedPeersOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_pinned_peers_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_pinned_peer_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPinnedPeersOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPinnedPeersOnConstMeta => const TaskConstMeta(
+            debugName: "get_pinned_peers_on",
+            argNames: ["handle"],
+        );
+        
+
+@override PowerModeDto crateApiGetPowerMode()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_power_mode();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_power_mode_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPowerModeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPowerModeConstMeta => const TaskConstMeta(
+            debugName: "get_power_mode",
+            argNames: [],
+        );
+        
+
+@override PowerModeDto crateApiGetPowerModeOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_power_mode_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_power_mode_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPowerModeOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPowerModeOnConstMeta => const TaskConstMeta(
+            debugName: "get_power_mode_on",
+            argNames: ["handle"],
+        );
+        
+
+@override PowerPolicyDto crateApiGetPowerPolicy()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_power_policy();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_power_policy_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPowerPolicyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPowerPolicyConstMeta => const TaskConstMeta(
+            debugName: "get_power_policy",
+            argNames: [],
+        );
+        
+
+@override PowerPolicyDto crateApiGetPowerPolicyOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_power_policy_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_power_policy_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPowerPolicyOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPowerPolicyOnConstMeta => const TaskConstMeta(
+            debugName: "get_power_policy_on",
+            argNames: ["handle"],
+        );
+        
+
+@override PresenceDto? crateApiGetPresence({required String peerId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(peerId);
+            return wire.wire__crate__api__get_presence(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_presence_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPresenceConstMeta,
+            argValues: [peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPresenceConstMeta => const TaskConstMeta(
+            debugName: "get_presence",
+            argNames: ["peerId"],
+        );
+        
+
+@override PresenceDto? crateApiGetPresenceOn({required int handle , required String peerId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+            return wire.wire__crate__api__get_presence_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_presence_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPresenceOnConstMeta,
+            argValues: [handle, peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPresenceOnConstMeta => const TaskConstMeta(
+            debugName: "get_presence_on",
+            argNames: ["handle", "peerId"],
+        );
+        
+
+@override List<PeerLatencyDto> crateApiGetRankedPeers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__get_ranked_peers();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_latency_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRankedPeersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRankedPeersConstMeta => const TaskConstMeta(
+            debugName: "get_ranked_peers",
+            argNames: [],
+        );
+        
+
+@override List<PeerLatencyDto> crateApiGetRankedPeersOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_ranked_peers_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_peer_latency_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRankedPeersOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRankedPeersOnConstMeta => const TaskConstMeta(
+            debugName: "get_ranked_peers_on",
+            argNames: ["handle"],
+        );
+        
+
+@override List<NodeEventRecordDto> crateApiGetRecentEvents({required BigInt sinceSeq })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_64(sinceSeq);
+            return wire.wire__crate__api__get_recent_events(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_node_event_record_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRecentEventsConstMeta,
+            argValues: [sinceSeq],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRecentEventsConstMeta => const TaskConstMeta(
+            debugName: "get_recent_events",
+            argNames: ["sinceSeq"],
+        );
+        
+
+@override List<NodeEventRecordDto> crateApiGetRecentEventsOn({required int handle , required BigInt sinceSeq })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_u_64(sinceSeq);
+            return wire.wire__crate__api__get_recent_events_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_node_event_record_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRecentEventsOnConstMeta,
+            argValues: [handle, sinceSeq],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRecentEventsOnConstMeta => const TaskConstMeta(
+            debugName: "get_recent_events_on",
+            argNames: ["handle", "sinceSeq"],
+        );
+        
+
+@override Future<RelayStatusDto> crateApiGetRelayStatus()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            return wire.wire__crate__api__get_relay_status(port_);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_relay_status_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRelayStatusConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRelayStatusConstMeta => const TaskConstMeta(
+            debugName: "get_relay_status",
+            argNames: [],
+        );
+        
+
+@override Future<RelayStatusDto> crateApiGetRelayStatusOn({required int handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_relay_status_on(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_relay_status_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRelayStatusOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRelayStatusOnConstMeta => const TaskConstMeta(
+            debugName: "get_relay_status_on",
+            argNames: ["handle"],
+        );
+        
+
+@override SuccessorRecordDto? crateApiGetSuccessor({required String nodeId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(nodeId);
+            return wire.wire__crate__api__get_successor(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_successor_record_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSuccessorConstMeta,
+            argValues: [nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSuccessorConstMeta => const TaskConstMeta(
+            debugName: "get_successor",
+            argNames: ["nodeId"],
+        );
+        
+
+@override SuccessorRecordDto? crateApiGetSuccessorOn({required int handle , required String nodeId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(nodeId);
+            return wire.wire__crate__api__get_successor_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_successor_record_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSuccessorOnConstMeta,
+            argValues: [handle, nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSuccessorOnConstMeta => const TaskConstMeta(
+            debugName: "get_successor_on",
+            argNames: ["handle", "nodeId"],
+        );
+        
+
+@override Future<SyncStatsDto> crateApiGetSyncStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            return wire.wire__crate__api__get_sync_stats(port_);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_sync_stats_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSyncStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSyncStatsConstMeta => const TaskConstMeta(
+            debugName: "get_sync_stats",
+            argNames: [],
+        );
+        
+
+@override Future<SyncStatsDto> crateApiGetSyncStatsOn({required int handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__get_sync_stats_on(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_sync_stats_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSyncStatsOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSyncStatsOnConstMeta => const TaskConstMeta(
+            debugName: "get_sync_stats_on",
+            argNames: ["handle"],
+        );
+        
+
+@override String crateApiGetText({required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__get_text(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetTextConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTextConstMeta => const TaskConstMeta(
+            debugName: "get_text",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override String crateApiGetTextOn({required int handle , required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+            return wire.wire__crate__api__get_text_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetTextOnConstMeta,
+            argValues: [handle, dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTextOnConstMeta => const TaskConstMeta(
+            debugName: "get_text_on",
+            argNames: ["handle", "dbName", "key"],
+        );
+        
+
+@override List<String> crateApiGetWriters({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__get_writers(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetWritersConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWritersConstMeta => const TaskConstMeta(
+            debugName: "get_writers",
+            argNames: ["dbName"],
+        );
+        
+
+@override List<String> crateApiGetWritersOn({required int handle , required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__get_writers_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetWritersOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetWritersOnConstMeta => const TaskConstMeta(
+            debugName: "get_writers_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override Future<NodeInfo> crateApiGoOnline({required List<String> bootstrapPeers , String? region })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_list_String(bootstrapPeers);
+var arg1 = cst_encode_opt_String(region);
+            return wire.wire__crate__api__go_online(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_info,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGoOnlineConstMeta,
+            argValues: [bootstrapPeers, region],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGoOnlineConstMeta => const TaskConstMeta(
+            debugName: "go_online",
+            argNames: ["bootstrapPeers", "region"],
+        );
+        
+
+@override Future<NodeInfo> crateApiGoOnlineOn({required int handle , required List<String> bootstrapPeers , String? region })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_list_String(bootstrapPeers);
+var arg2 = cst_encode_opt_String(region);
+            return wire.wire__crate__api__go_online_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_info,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGoOnlineOnConstMeta,
+            argValues: [handle, bootstrapPeers, region],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGoOnlineOnConstMeta => const TaskConstMeta(
+            debugName: "go_online_on",
+            argNames: ["handle", "bootstrapPeers", "region"],
+        );
+        
+
+@override Future<void> crateApiGrantWriter({required String dbName , required String writerPublicKey , required String ownerSecretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(writerPublicKey);
+var arg2 = cst_encode_String(ownerSecretKey);
+            return wire.wire__crate__api__grant_writer(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGrantWriterConstMeta,
+            argValues: [dbName, writerPublicKey, ownerSecretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGrantWriterConstMeta => const TaskConstMeta(
+            debugName: "grant_writer",
+            argNames: ["dbName", "writerPublicKey", "ownerSecretKey"],
+        );
+        
+
+@override Future<void> crateApiGrantWriterOn({required int handle , required String dbName , required String writerPublicKey , required String ownerSecretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(writerPublicKey);
+var arg3 = cst_encode_String(ownerSecretKey);
+            return wire.wire__crate__api__grant_writer_on(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGrantWriterOnConstMeta,
+            argValues: [handle, dbName, writerPublicKey, ownerSecretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGrantWriterOnConstMeta => const TaskConstMeta(
+            debugName: "grant_writer_on",
+            argNames: ["handle", "dbName", "writerPublicKey", "ownerSecretKey"],
+        );
+        
+
+@override Future<String> crateApiGraphqlExecute({required String query , String? variables })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(query);
+var arg1 = cst_encode_opt_String(variables);
+            return wire.wire__crate__api__graphql_execute(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGraphqlExecuteConstMeta,
+            argValues: [query, variables],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGraphqlExecuteConstMeta => const TaskConstMeta(
+            debugName: "graphql_execute",
+            argNames: ["query", "variables"],
+        );
+        
+
+@override Future<String> crateApiGraphqlExecuteOn({required int handle , required String query , String? variables })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(query);
+var arg2 = cst_encode_opt_String(variables);
+            return wire.wire__crate__api__graphql_execute_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGraphqlExecuteOnConstMeta,
+            argValues: [handle, query, variables],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGraphqlExecuteOnConstMeta => const TaskConstMeta(
+            debugName: "graphql_execute_on",
+            argNames: ["handle", "query", "variables"],
+        );
+        
+
+@override String crateApiGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(name);
+            return wire.wire__crate__api__greet(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<String?> crateApiHashGet({required String dbName , required String key , required String field })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_String(field);
+            return wire.wire__crate__api__hash_get(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiHashGetConstMeta,
+            argValues: [dbName, key, field],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHashGetConstMeta => const TaskConstMeta(
+            debugName: "hash_get",
+            argNames: ["dbName", "key", "field"],
+        );
+        
+
+@override List<HashEntryDto> crateApiHashGetAll({required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__hash_get_all(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_hash_entry_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiHashGetAllConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHashGetAllConstMeta => const TaskConstMeta(
+            debugName: "hash_get_all",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override List<HashEntryDto> crateApiHashGetAllOn({required int handle , required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+            return wire.wire__crate__api__hash_get_all_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_hash_entry_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiHashGetAllOnConstMeta,
+            argValues: [handle, dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHashGetAllOnConstMeta => const TaskConstMeta(
+            debugName: "hash_get_all_on",
+            argNames: ["handle", "dbName", "key"],
+        );
+        
+
+@override Future<String?> crateApiHashGetOn({required int handle , required String dbName , required String key , required String field })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_String(field);
+            return wire.wire__crate__api__hash_get_on(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_opt_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiHashGetOnConstMeta,
+            argValues: [handle, dbName, key, field],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHashGetOnConstMeta => const TaskConstMeta(
+            debugName: "hash_get_on",
+            argNames: ["handle", "dbName", "key", "field"],
+        );
+        
+
+@override Future<void> crateApiHashSet({required String dbName , required String key , required String field , required String value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_String(field);
+var arg3 = cst_encode_String(value);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__hash_set(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiHashSetConstMeta,
+            argValues: [dbName, key, field, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHashSetConstMeta => const TaskConstMeta(
+            debugName: "hash_set",
+            argNames: ["dbName", "key", "field", "value", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiHashSetOn({required int handle , required String dbName , required String key , required String field , required String value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_String(field);
+var arg4 = cst_encode_String(value);
+var arg5 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__hash_set_on(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiHashSetOnConstMeta,
+            argValues: [handle, dbName, key, field, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHashSetOnConstMeta => const TaskConstMeta(
+            debugName: "hash_set_on",
+            argNames: ["handle", "dbName", "key", "field", "value", "secretKey"],
+        );
+        
+
+@override Future<HealthReportDto> crateApiHealthCheck()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            return wire.wire__crate__api__health_check(port_);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_health_report_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiHealthCheckConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHealthCheckConstMeta => const TaskConstMeta(
+            debugName: "health_check",
+            argNames: [],
+        );
+        
+
+@override Future<HealthReportDto> crateApiHealthCheckOn({required int handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__health_check_on(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_health_report_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiHealthCheckOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiHealthCheckOnConstMeta => const TaskConstMeta(
+            debugName: "health_check_on",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<BackupInfoDto> crateApiImportBackup({required String dataDir , required String path , required String passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_String(path);
+var arg2 = cst_encode_String(passphrase);
+            return wire.wire__crate__api__import_backup(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_backup_info_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiImportBackupConstMeta,
+            argValues: [dataDir, path, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportBackupConstMeta => const TaskConstMeta(
+            debugName: "import_backup",
+            argNames: ["dataDir", "path", "passphrase"],
+        );
+        
+
+@override void crateApiImportDbKey({required String dbName , required String keyHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(keyHex);
+            return wire.wire__crate__api__import_db_key(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiImportDbKeyConstMeta,
+            argValues: [dbName, keyHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportDbKeyConstMeta => const TaskConstMeta(
+            debugName: "import_db_key",
+            argNames: ["dbName", "keyHex"],
+        );
+        
+
+@override void crateApiImportDbKeyOn({required int handle , required String dbName , required String keyHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(keyHex);
+            return wire.wire__crate__api__import_db_key_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiImportDbKeyOnConstMeta,
+            argValues: [handle, dbName, keyHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportDbKeyOnConstMeta => const TaskConstMeta(
+            debugName: "import_db_key_on",
+            argNames: ["handle", "dbName", "keyHex"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiIncr({required String dbName , required String key , required PlatformInt64 delta , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_i_64(delta);
+var arg3 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__incr(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_i_64,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIncrConstMeta,
+            argValues: [dbName, key, delta, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrConstMeta => const TaskConstMeta(
+            debugName: "incr",
+            argNames: ["dbName", "key", "delta", "secretKey"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiIncrOn({required int handle , required String dbName , required String key , required PlatformInt64 delta , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_i_64(delta);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__incr_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_i_64,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIncrOnConstMeta,
+            argValues: [handle, dbName, key, delta, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIncrOnConstMeta => const TaskConstMeta(
+            debugName: "incr_on",
+            argNames: ["handle", "dbName", "key", "delta", "secretKey"],
+        );
+        
+
+@override void crateApiInitLogging()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__init_logging();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInitLoggingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitLoggingConstMeta => const TaskConstMeta(
+            debugName: "init_logging",
+            argNames: [],
+        );
+        
+
+@override bool crateApiIsDatabaseLocalOnly({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__is_database_local_only(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsDatabaseLocalOnlyConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsDatabaseLocalOnlyConstMeta => const TaskConstMeta(
+            debugName: "is_database_local_only",
+            argNames: ["dbName"],
+        );
+        
+
+@override bool crateApiIsDatabaseLocalOnlyOn({required int handle , required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__is_database_local_only_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsDatabaseLocalOnlyOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsDatabaseLocalOnlyOnConstMeta => const TaskConstMeta(
+            debugName: "is_database_local_only_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override bool crateApiIsNetworkingPaused()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__is_networking_paused();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsNetworkingPausedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsNetworkingPausedConstMeta => const TaskConstMeta(
+            debugName: "is_networking_paused",
+            argNames: [],
+        );
+        
+
+@override bool crateApiIsNetworkingPausedOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__is_networking_paused_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsNetworkingPausedOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsNetworkingPausedOnConstMeta => const TaskConstMeta(
+            debugName: "is_networking_paused_on",
+            argNames: ["handle"],
+        );
+        
+
+@override bool crateApiIsNodeKeySealed({required String dataDir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dataDir);
+            return wire.wire__crate__api__is_node_key_sealed(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsNodeKeySealedConstMeta,
+            argValues: [dataDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsNodeKeySealedConstMeta => const TaskConstMeta(
+            debugName: "is_node_key_sealed",
+            argNames: ["dataDir"],
+        );
+        
+
+@override bool crateApiIsNodeOffline()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__is_node_offline();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsNodeOfflineConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsNodeOfflineConstMeta => const TaskConstMeta(
+            debugName: "is_node_offline",
+            argNames: [],
+        );
+        
+
+@override bool crateApiIsNodeOfflineOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__is_node_offline_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsNodeOfflineOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsNodeOfflineOnConstMeta => const TaskConstMeta(
+            debugName: "is_node_offline_on",
+            argNames: ["handle"],
+        );
+        
+
+@override bool crateApiIsNodeRunning()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__is_node_running();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIsNodeRunningConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsNodeRunningConstMeta => const TaskConstMeta(
+            debugName: "is_node_running",
+            argNames: [],
+        );
+        
+
+@override bool crateApiIsVerifiedPeerKey({required String peerId , required String publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(peerId);
+var arg1 = cst_encode_String(publicKey);
+            return wire.wire__crate__api__is_verified_peer_key(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsVerifiedPeerKeyConstMeta,
+            argValues: [peerId, publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsVerifiedPeerKeyConstMeta => const TaskConstMeta(
+            debugName: "is_verified_peer_key",
+            argNames: ["peerId", "publicKey"],
+        );
+        
+
+@override bool crateApiIsVerifiedPeerKeyOn({required int handle , required String peerId , required String publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+var arg2 = cst_encode_String(publicKey);
+            return wire.wire__crate__api__is_verified_peer_key_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIsVerifiedPeerKeyOnConstMeta,
+            argValues: [handle, peerId, publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsVerifiedPeerKeyOnConstMeta => const TaskConstMeta(
+            debugName: "is_verified_peer_key_on",
+            argNames: ["handle", "peerId", "publicKey"],
+        );
+        
+
+@override String crateApiKadenaAccountFromPublicKey({required String publicKeyHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(publicKeyHex);
+            return wire.wire__crate__api__kadena_account_from_public_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKadenaAccountFromPublicKeyConstMeta,
+            argValues: [publicKeyHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKadenaAccountFromPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "kadena_account_from_public_key",
+            argNames: ["publicKeyHex"],
+        );
+        
+
+@override String crateApiKadenaNodeRegistrationCommand({required String networkId , required String chainId , required String peerId , required String multiaddr , required String publicKeyHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(networkId);
+var arg1 = cst_encode_String(chainId);
+var arg2 = cst_encode_String(peerId);
+var arg3 = cst_encode_String(multiaddr);
+var arg4 = cst_encode_String(publicKeyHex);
+            return wire.wire__crate__api__kadena_node_registration_command(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKadenaNodeRegistrationCommandConstMeta,
+            argValues: [networkId, chainId, peerId, multiaddr, publicKeyHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKadenaNodeRegistrationCommandConstMeta => const TaskConstMeta(
+            debugName: "kadena_node_registration_command",
+            argNames: ["networkId", "chainId", "peerId", "multiaddr", "publicKeyHex"],
+        );
+        
+
+@override KadenaTransactionDto crateApiKadenaSignCommand({required String cmd , required String secretKeyHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(cmd);
+var arg1 = cst_encode_String(secretKeyHex);
+            return wire.wire__crate__api__kadena_sign_command(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_kadena_transaction_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKadenaSignCommandConstMeta,
+            argValues: [cmd, secretKeyHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiKadenaSignCommandConstMeta => const TaskConstMeta(
+            debugName: "kadena_sign_command",
+            argNames: ["cmd", "secretKeyHex"],
+        );
+        
+
+@override LinkedDeviceDto crateApiLinkDevice({required String qrPayload , required String secretKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(qrPayload);
+var arg1 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__link_device(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_linked_device_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLinkDeviceConstMeta,
+            argValues: [qrPayload, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLinkDeviceConstMeta => const TaskConstMeta(
+            debugName: "link_device",
+            argNames: ["qrPayload", "secretKey"],
+        );
+        
+
+@override LinkedDeviceDto crateApiLinkDeviceOn({required int handle , required String qrPayload , required String secretKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(qrPayload);
+var arg2 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__link_device_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_linked_device_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLinkDeviceOnConstMeta,
+            argValues: [handle, qrPayload, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLinkDeviceOnConstMeta => const TaskConstMeta(
+            debugName: "link_device_on",
+            argNames: ["handle", "qrPayload", "secretKey"],
+        );
+        
+
+@override List<ConflictDto> crateApiListConflicts({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__list_conflicts(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_conflict_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListConflictsConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListConflictsConstMeta => const TaskConstMeta(
+            debugName: "list_conflicts",
+            argNames: ["dbName"],
+        );
+        
+
+@override List<ConflictDto> crateApiListConflictsOn({required int handle , required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__list_conflicts_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_conflict_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListConflictsOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListConflictsOnConstMeta => const TaskConstMeta(
+            debugName: "list_conflicts_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override List<String> crateApiListDatabases()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__list_databases();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListDatabasesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListDatabasesConstMeta => const TaskConstMeta(
+            debugName: "list_databases",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiListDatabasesOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__list_databases_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListDatabasesOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListDatabasesOnConstMeta => const TaskConstMeta(
+            debugName: "list_databases_on",
+            argNames: ["handle"],
+        );
+        
+
+@override List<FileOfferDto> crateApiListFileOffers()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__list_file_offers();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_file_offer_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListFileOffersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListFileOffersConstMeta => const TaskConstMeta(
+            debugName: "list_file_offers",
+            argNames: [],
+        );
+        
+
+@override List<FileOfferDto> crateApiListFileOffersOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__list_file_offers_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_file_offer_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListFileOffersOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListFileOffersOnConstMeta => const TaskConstMeta(
+            debugName: "list_file_offers_on",
+            argNames: ["handle"],
+        );
+        
+
+@override List<String> crateApiListKeys({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__list_keys(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListKeysConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListKeysConstMeta => const TaskConstMeta(
+            debugName: "list_keys",
+            argNames: ["dbName"],
+        );
+        
+
+@override List<String> crateApiListKeysOn({required int handle , required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__list_keys_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListKeysOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListKeysOnConstMeta => const TaskConstMeta(
+            debugName: "list_keys_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override List<LinkedDeviceDto> crateApiListLinkedDevices()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__list_linked_devices();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_linked_device_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListLinkedDevicesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListLinkedDevicesConstMeta => const TaskConstMeta(
+            debugName: "list_linked_devices",
+            argNames: [],
+        );
+        
+
+@override List<LinkedDeviceDto> crateApiListLinkedDevicesOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__list_linked_devices_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_linked_device_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListLinkedDevicesOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListLinkedDevicesOnConstMeta => const TaskConstMeta(
+            debugName: "list_linked_devices_on",
+            argNames: ["handle"],
+        );
+        
+
+@override Uint32List crateApiListNodeInstances()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__list_node_instances();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiListNodeInstancesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListNodeInstancesConstMeta => const TaskConstMeta(
+            debugName: "list_node_instances",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiListPush({required String dbName , required String key , required String value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_String(value);
+var arg3 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__list_push(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListPushConstMeta,
+            argValues: [dbName, key, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListPushConstMeta => const TaskConstMeta(
+            debugName: "list_push",
+            argNames: ["dbName", "key", "value", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiListPushOn({required int handle , required String dbName , required String key , required String value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_String(value);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__list_push_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListPushOnConstMeta,
+            argValues: [handle, dbName, key, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListPushOnConstMeta => const TaskConstMeta(
+            debugName: "list_push_on",
+            argNames: ["handle", "dbName", "key", "value", "secretKey"],
+        );
+        
+
+@override List<String> crateApiListRange({required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__list_range(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListRangeConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListRangeConstMeta => const TaskConstMeta(
+            debugName: "list_range",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override List<String> crateApiListRangeOn({required int handle , required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+            return wire.wire__crate__api__list_range_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListRangeOnConstMeta,
+            argValues: [handle, dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListRangeOnConstMeta => const TaskConstMeta(
+            debugName: "list_range_on",
+            argNames: ["handle", "dbName", "key"],
+        );
+        
+
+@override List<ReplicaCheckDto> crateApiListReplicaChecks()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__list_replica_checks();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_replica_check_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListReplicaChecksConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListReplicaChecksConstMeta => const TaskConstMeta(
+            debugName: "list_replica_checks",
+            argNames: [],
+        );
+        
+
+@override List<ReplicaCheckDto> crateApiListReplicaChecksOn({required int handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__list_replica_checks_on(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_replica_check_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiListReplicaChecksOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListReplicaChecksOnConstMeta => const TaskConstMeta(
+            debugName: "list_replica_checks_on",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<BigInt> crateApiMeasureLatency({required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(peerId);
+            return wire.wire__crate__api__measure_latency(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMeasureLatencyConstMeta,
+            argValues: [peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMeasureLatencyConstMeta => const TaskConstMeta(
+            debugName: "measure_latency",
+            argNames: ["peerId"],
+        );
+        
+
+@override Future<BigInt> crateApiMeasureLatencyOn({required int handle , required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+            return wire.wire__crate__api__measure_latency_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMeasureLatencyOnConstMeta,
+            argValues: [handle, peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMeasureLatencyOnConstMeta => const TaskConstMeta(
+            debugName: "measure_latency_on",
+            argNames: ["handle", "peerId"],
+        );
+        
+
+@override void crateApiOpenStorageReadOnly({required String dataDir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dataDir);
+            return wire.wire__crate__api__open_storage_read_only(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiOpenStorageReadOnlyConstMeta,
+            argValues: [dataDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenStorageReadOnlyConstMeta => const TaskConstMeta(
+            debugName: "open_storage_read_only",
+            argNames: ["dataDir"],
+        );
+        
+
+@override Future<void> crateApiPauseNetworking()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            return wire.wire__crate__api__pause_networking(port_);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPauseNetworkingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPauseNetworkingConstMeta => const TaskConstMeta(
+            debugName: "pause_networking",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiPauseNetworkingOn({required int handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__pause_networking_on(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPauseNetworkingOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPauseNetworkingOnConstMeta => const TaskConstMeta(
+            debugName: "pause_networking_on",
+            argNames: ["handle"],
+        );
+        
+
+@override void crateApiPinPeer({required String nodeId , String? address })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(nodeId);
+var arg1 = cst_encode_opt_String(address);
+            return wire.wire__crate__api__pin_peer(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPinPeerConstMeta,
+            argValues: [nodeId, address],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPinPeerConstMeta => const TaskConstMeta(
+            debugName: "pin_peer",
+            argNames: ["nodeId", "address"],
+        );
+        
+
+@override void crateApiPinPeerOn({required int handle , required String nodeId , String? address })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(nodeId);
+var arg2 = cst_encode_opt_String(address);
+            return wire.wire__crate__api__pin_peer_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPinPeerOnConstMeta,
+            argValues: [handle, nodeId, address],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPinPeerOnConstMeta => const TaskConstMeta(
+            debugName: "pin_peer_on",
+            argNames: ["handle", "nodeId", "address"],
+        );
+        
+
+@override Future<VerifiedBindingDto> crateApiProveOwnership({required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(peerId);
+            return wire.wire__crate__api__prove_ownership(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_verified_binding_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProveOwnershipConstMeta,
+            argValues: [peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProveOwnershipConstMeta => const TaskConstMeta(
+            debugName: "prove_ownership",
+            argNames: ["peerId"],
+        );
+        
+
+@override Future<VerifiedBindingDto> crateApiProveOwnershipOn({required int handle , required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+            return wire.wire__crate__api__prove_ownership_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_verified_binding_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProveOwnershipOnConstMeta,
+            argValues: [handle, peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProveOwnershipOnConstMeta => const TaskConstMeta(
+            debugName: "prove_ownership_on",
+            argNames: ["handle", "peerId"],
+        );
+        
+
+@override Future<void> crateApiPublishTopic({required String name , required List<int> content })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(name);
+var arg1 = cst_encode_list_prim_u_8_loose(content);
+            return wire.wire__crate__api__publish_topic(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPublishTopicConstMeta,
+            argValues: [name, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPublishTopicConstMeta => const TaskConstMeta(
+            debugName: "publish_topic",
+            argNames: ["name", "content"],
+        );
+        
+
+@override Future<void> crateApiPublishTopicOn({required int handle , required String name , required List<int> content })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(name);
+var arg2 = cst_encode_list_prim_u_8_loose(content);
+            return wire.wire__crate__api__publish_topic_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPublishTopicOnConstMeta,
+            argValues: [handle, name, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPublishTopicOnConstMeta => const TaskConstMeta(
+            debugName: "publish_topic_on",
+            argNames: ["handle", "name", "content"],
+        );
+        
+
+@override Future<void> crateApiPutJson({required String dbName , required String key , required String json , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_String(json);
+var arg3 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__put_json(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPutJsonConstMeta,
+            argValues: [dbName, key, json, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPutJsonConstMeta => const TaskConstMeta(
+            debugName: "put_json",
+            argNames: ["dbName", "key", "json", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiPutJsonOn({required int handle , required String dbName , required String key , required String json , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_String(json);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__put_json_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAddLogEntryConstMeta,
-        argValues: [level, message],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAddLogEntryConstMeta => const TaskConstMeta(
-    debugName: "add_log_entry",
-    argNames: ["level", "message"],
-  );
-
-  @override
-  void crateApiClearLogs() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__clear_logs();
-        },
-        codec: DcoCodec(
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPutJsonOnConstMeta,
+            argValues: [handle, dbName, key, json, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPutJsonOnConstMeta => const TaskConstMeta(
+            debugName: "put_json_on",
+            argNames: ["handle", "dbName", "key", "json", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiPutString({required String dbName , required String key , required String value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_String(value);
+var arg3 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__put_string(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiClearLogsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiClearLogsConstMeta =>
-      const TaskConstMeta(debugName: "clear_logs", argNames: []);
-
-  @override
-  Future<void> crateApiDeleteData({
-    required String dbName,
-    required String key,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(dbName);
-          var arg1 = cst_encode_String(key);
-          return wire.wire__crate__api__delete_data(port_, arg0, arg1);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiDeleteDataConstMeta,
-        argValues: [dbName, key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDeleteDataConstMeta => const TaskConstMeta(
-    debugName: "delete_data",
-    argNames: ["dbName", "key"],
-  );
-
-  @override
-  String? crateApiExtractNameFromDb({required String dbName}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_String(dbName);
-          return wire.wire__crate__api__extract_name_from_db(arg0);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_opt_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiExtractNameFromDbConstMeta,
-        argValues: [dbName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiExtractNameFromDbConstMeta => const TaskConstMeta(
-    debugName: "extract_name_from_db",
-    argNames: ["dbName"],
-  );
-
-  @override
-  String crateApiGenerateDbName({
-    required String name,
-    required String publicKeyHex,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_String(name);
-          var arg1 = cst_encode_String(publicKeyHex);
-          return wire.wire__crate__api__generate_db_name(arg0, arg1);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGenerateDbNameConstMeta,
-        argValues: [name, publicKeyHex],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGenerateDbNameConstMeta => const TaskConstMeta(
-    debugName: "generate_db_name",
-    argNames: ["name", "publicKeyHex"],
-  );
-
-  @override
-  KeyPairDto crateApiGenerateKeypair() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__generate_keypair();
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_key_pair_dto,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGenerateKeypairConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGenerateKeypairConstMeta =>
-      const TaskConstMeta(debugName: "generate_keypair", argNames: []);
-
-  @override
-  String crateApiGeneratePeerIdFromSecretKey({required String secretKeyHex}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_String(secretKeyHex);
-          return wire.wire__crate__api__generate_peer_id_from_secret_key(arg0);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiGeneratePeerIdFromSecretKeyConstMeta,
-        argValues: [secretKeyHex],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGeneratePeerIdFromSecretKeyConstMeta =>
-      const TaskConstMeta(
-        debugName: "generate_peer_id_from_secret_key",
-        argNames: ["secretKeyHex"],
-      );
-
-  @override
-  Future<List<DbEntryDto>> crateApiGetAllData() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          return wire.wire__crate__api__get_all_data(port_);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_db_entry_dto,
+        )
+        ,
+            constMeta: kCrateApiPutStringConstMeta,
+            argValues: [dbName, key, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPutStringConstMeta => const TaskConstMeta(
+            debugName: "put_string",
+            argNames: ["dbName", "key", "value", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiPutStringOn({required int handle , required String dbName , required String key , required String value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_String(value);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__put_string_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiGetAllDataConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAllDataConstMeta =>
-      const TaskConstMeta(debugName: "get_all_data", argNames: []);
-
-  @override
-  Future<List<DbEntryDto>> crateApiGetAllEntries({required String dbName}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(dbName);
-          return wire.wire__crate__api__get_all_entries(port_, arg0);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_db_entry_dto,
+        )
+        ,
+            constMeta: kCrateApiPutStringOnConstMeta,
+            argValues: [handle, dbName, key, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPutStringOnConstMeta => const TaskConstMeta(
+            debugName: "put_string_on",
+            argNames: ["handle", "dbName", "key", "value", "secretKey"],
+        );
+        
+
+@override Future<QueryResultDto> crateApiQuery({required String dbName , required QuerySpecDto spec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_box_autoadd_query_spec_dto(spec);
+            return wire.wire__crate__api__query(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_query_result_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiQueryConstMeta,
+            argValues: [dbName, spec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryConstMeta => const TaskConstMeta(
+            debugName: "query",
+            argNames: ["dbName", "spec"],
+        );
+        
+
+@override Future<QueryResultDto> crateApiQueryOn({required int handle , required String dbName , required QuerySpecDto spec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_box_autoadd_query_spec_dto(spec);
+            return wire.wire__crate__api__query_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_query_result_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiQueryOnConstMeta,
+            argValues: [handle, dbName, spec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQueryOnConstMeta => const TaskConstMeta(
+            debugName: "query_on",
+            argNames: ["handle", "dbName", "spec"],
+        );
+        
+
+@override Future<BackupInfoDto> crateApiReadOnlyExportBackup({required String path , required String passphrase , String? walletSecretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(path);
+var arg1 = cst_encode_String(passphrase);
+var arg2 = cst_encode_opt_String(walletSecretKey);
+            return wire.wire__crate__api__read_only_export_backup(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_backup_info_dto,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiGetAllEntriesConstMeta,
-        argValues: [dbName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAllEntriesConstMeta =>
-      const TaskConstMeta(debugName: "get_all_entries", argNames: ["dbName"]);
-
-  @override
-  Future<Uint8List?> crateApiGetData({
-    required String dbName,
-    required String key,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(dbName);
-          var arg1 = cst_encode_String(key);
-          return wire.wire__crate__api__get_data(port_, arg0, arg1);
-        },
-        codec: DcoCodec(
+        )
+        ,
+            constMeta: kCrateApiReadOnlyExportBackupConstMeta,
+            argValues: [path, passphrase, walletSecretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReadOnlyExportBackupConstMeta => const TaskConstMeta(
+            debugName: "read_only_export_backup",
+            argNames: ["path", "passphrase", "walletSecretKey"],
+        );
+        
+
+@override Future<Uint8List?> crateApiReadOnlyGetData({required String dbName , required String key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__read_only_get_data(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_opt_list_prim_u_8_strict,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiGetDataConstMeta,
-        argValues: [dbName, key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetDataConstMeta =>
-      const TaskConstMeta(debugName: "get_data", argNames: ["dbName", "key"]);
-
-  @override
-  List<LogEntry> crateApiGetLogs({int? limit}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_opt_box_autoadd_u_32(limit);
-          return wire.wire__crate__api__get_logs(arg0);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_log_entry,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetLogsConstMeta,
-        argValues: [limit],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetLogsConstMeta =>
-      const TaskConstMeta(debugName: "get_logs", argNames: ["limit"]);
-
-  @override
-  NodeInfo? crateApiGetNodeInfo() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__get_node_info();
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_opt_box_autoadd_node_info,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetNodeInfoConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetNodeInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_node_info", argNames: []);
-
-  @override
-  NodeStatusDto crateApiGetNodeStatus() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__get_node_status();
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_node_status_dto,
+        )
+        ,
+            constMeta: kCrateApiReadOnlyGetDataConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReadOnlyGetDataConstMeta => const TaskConstMeta(
+            debugName: "read_only_get_data",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override Future<ScanPageDto> crateApiReadOnlyGetEntriesPage({required String dbName , required int limit , String? cursor , required bool keysOnly , BigInt? maxBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_u_32(limit);
+var arg2 = cst_encode_opt_String(cursor);
+var arg3 = cst_encode_bool(keysOnly);
+var arg4 = cst_encode_opt_box_autoadd_u_64(maxBytes);
+            return wire.wire__crate__api__read_only_get_entries_page(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiGetNodeStatusConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetNodeStatusConstMeta =>
-      const TaskConstMeta(debugName: "get_node_status", argNames: []);
-
-  @override
-  List<PeerInfoDto> crateApiGetPeers() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__get_peers();
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_peer_info_dto,
+        )
+        ,
+            constMeta: kCrateApiReadOnlyGetEntriesPageConstMeta,
+            argValues: [dbName, limit, cursor, keysOnly, maxBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReadOnlyGetEntriesPageConstMeta => const TaskConstMeta(
+            debugName: "read_only_get_entries_page",
+            argNames: ["dbName", "limit", "cursor", "keysOnly", "maxBytes"],
+        );
+        
+
+@override List<String> crateApiReadOnlyListDatabases()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__read_only_list_databases();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiGetPeersConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetPeersConstMeta =>
-      const TaskConstMeta(debugName: "get_peers", argNames: []);
-
-  @override
-  String crateApiGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_String(name);
-          return wire.wire__crate__api__greet(arg0);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  void crateApiInitLogging() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__init_logging();
-        },
-        codec: DcoCodec(
+        )
+        ,
+            constMeta: kCrateApiReadOnlyListDatabasesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReadOnlyListDatabasesConstMeta => const TaskConstMeta(
+            debugName: "read_only_list_databases",
+            argNames: [],
+        );
+        
+
+@override bool crateApiRejectRpc({required BigInt requestId , required String message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_64(requestId);
+var arg1 = cst_encode_String(message);
+            return wire.wire__crate__api__reject_rpc(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRejectRpcConstMeta,
+            argValues: [requestId, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRejectRpcConstMeta => const TaskConstMeta(
+            debugName: "reject_rpc",
+            argNames: ["requestId", "message"],
+        );
+        
+
+@override bool crateApiRejectRpcOn({required int handle , required BigInt requestId , required String message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_u_64(requestId);
+var arg2 = cst_encode_String(message);
+            return wire.wire__crate__api__reject_rpc_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRejectRpcOnConstMeta,
+            argValues: [handle, requestId, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRejectRpcOnConstMeta => const TaskConstMeta(
+            debugName: "reject_rpc_on",
+            argNames: ["handle", "requestId", "message"],
+        );
+        
+
+@override Future<void> crateApiRequestSync({PlatformInt64? sinceTimestamp })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_opt_box_autoadd_i_64(sinceTimestamp);
+            return wire.wire__crate__api__request_sync(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiInitLoggingConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiInitLoggingConstMeta =>
-      const TaskConstMeta(debugName: "init_logging", argNames: []);
-
-  @override
-  bool crateApiIsNodeRunning() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__is_node_running();
-        },
-        codec: DcoCodec(
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRequestSyncConstMeta,
+            argValues: [sinceTimestamp],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRequestSyncConstMeta => const TaskConstMeta(
+            debugName: "request_sync",
+            argNames: ["sinceTimestamp"],
+        );
+        
+
+@override Future<void> crateApiRequestSyncOn({required int handle , PlatformInt64? sinceTimestamp })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_opt_box_autoadd_i_64(sinceTimestamp);
+            return wire.wire__crate__api__request_sync_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRequestSyncOnConstMeta,
+            argValues: [handle, sinceTimestamp],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRequestSyncOnConstMeta => const TaskConstMeta(
+            debugName: "request_sync_on",
+            argNames: ["handle", "sinceTimestamp"],
+        );
+        
+
+@override void crateApiResetLogLevels()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            return wire.wire__crate__api__reset_log_levels();
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiResetLogLevelsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResetLogLevelsConstMeta => const TaskConstMeta(
+            debugName: "reset_log_levels",
+            argNames: [],
+        );
+        
+
+@override bool crateApiRespondRpc({required BigInt requestId , required List<int> payload })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_64(requestId);
+var arg1 = cst_encode_list_prim_u_8_loose(payload);
+            return wire.wire__crate__api__respond_rpc(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIsNodeRunningConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIsNodeRunningConstMeta =>
-      const TaskConstMeta(debugName: "is_node_running", argNames: []);
-
-  @override
-  List<String> crateApiListDatabases() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__list_databases();
-        },
-        codec: DcoCodec(
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRespondRpcConstMeta,
+            argValues: [requestId, payload],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRespondRpcConstMeta => const TaskConstMeta(
+            debugName: "respond_rpc",
+            argNames: ["requestId", "payload"],
+        );
+        
+
+@override bool crateApiRespondRpcOn({required int handle , required BigInt requestId , required List<int> payload })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_u_64(requestId);
+var arg2 = cst_encode_list_prim_u_8_loose(payload);
+            return wire.wire__crate__api__respond_rpc_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRespondRpcOnConstMeta,
+            argValues: [handle, requestId, payload],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRespondRpcOnConstMeta => const TaskConstMeta(
+            debugName: "respond_rpc_on",
+            argNames: ["handle", "requestId", "payload"],
+        );
+        
+
+@override Future<void> crateApiResumeNetworking()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            return wire.wire__crate__api__resume_networking(port_);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiResumeNetworkingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResumeNetworkingConstMeta => const TaskConstMeta(
+            debugName: "resume_networking",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiResumeNetworkingOn({required int handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__resume_networking_on(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiResumeNetworkingOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResumeNetworkingOnConstMeta => const TaskConstMeta(
+            debugName: "resume_networking_on",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<void> crateApiRevokeWriter({required String dbName , required String writerPublicKey , required String ownerSecretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(writerPublicKey);
+var arg2 = cst_encode_String(ownerSecretKey);
+            return wire.wire__crate__api__revoke_writer(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRevokeWriterConstMeta,
+            argValues: [dbName, writerPublicKey, ownerSecretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRevokeWriterConstMeta => const TaskConstMeta(
+            debugName: "revoke_writer",
+            argNames: ["dbName", "writerPublicKey", "ownerSecretKey"],
+        );
+        
+
+@override Future<void> crateApiRevokeWriterOn({required int handle , required String dbName , required String writerPublicKey , required String ownerSecretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(writerPublicKey);
+var arg3 = cst_encode_String(ownerSecretKey);
+            return wire.wire__crate__api__revoke_writer_on(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRevokeWriterOnConstMeta,
+            argValues: [handle, dbName, writerPublicKey, ownerSecretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRevokeWriterOnConstMeta => const TaskConstMeta(
+            debugName: "revoke_writer_on",
+            argNames: ["handle", "dbName", "writerPublicKey", "ownerSecretKey"],
+        );
+        
+
+@override Future<IdentityRotationDto> crateApiRotateIdentity({String? newSecretKey , BigInt? graceSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_opt_String(newSecretKey);
+var arg1 = cst_encode_opt_box_autoadd_u_64(graceSecs);
+            return wire.wire__crate__api__rotate_identity(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_identity_rotation_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRotateIdentityConstMeta,
+            argValues: [newSecretKey, graceSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRotateIdentityConstMeta => const TaskConstMeta(
+            debugName: "rotate_identity",
+            argNames: ["newSecretKey", "graceSecs"],
+        );
+        
+
+@override Future<IdentityRotationDto> crateApiRotateIdentityOn({required int handle , String? newSecretKey , BigInt? graceSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_opt_String(newSecretKey);
+var arg2 = cst_encode_opt_box_autoadd_u_64(graceSecs);
+            return wire.wire__crate__api__rotate_identity_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_identity_rotation_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRotateIdentityOnConstMeta,
+            argValues: [handle, newSecretKey, graceSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRotateIdentityOnConstMeta => const TaskConstMeta(
+            debugName: "rotate_identity_on",
+            argNames: ["handle", "newSecretKey", "graceSecs"],
+        );
+        
+
+@override void crateApiRotateKeyWrappingKey({required String dataDir , required List<int> oldWrappingKey , required List<int> newWrappingKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_list_prim_u_8_loose(oldWrappingKey);
+var arg2 = cst_encode_list_prim_u_8_loose(newWrappingKey);
+            return wire.wire__crate__api__rotate_key_wrapping_key(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRotateKeyWrappingKeyConstMeta,
+            argValues: [dataDir, oldWrappingKey, newWrappingKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRotateKeyWrappingKeyConstMeta => const TaskConstMeta(
+            debugName: "rotate_key_wrapping_key",
+            argNames: ["dataDir", "oldWrappingKey", "newWrappingKey"],
+        );
+        
+
+@override Future<Uint8List> crateApiRpcCall({required String peerId , required String method , required List<int> payload , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(peerId);
+var arg1 = cst_encode_String(method);
+var arg2 = cst_encode_list_prim_u_8_loose(payload);
+var arg3 = cst_encode_opt_box_autoadd_u_64(timeoutMs);
+            return wire.wire__crate__api__rpc_call(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRpcCallConstMeta,
+            argValues: [peerId, method, payload, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRpcCallConstMeta => const TaskConstMeta(
+            debugName: "rpc_call",
+            argNames: ["peerId", "method", "payload", "timeoutMs"],
+        );
+        
+
+@override Future<Uint8List> crateApiRpcCallOn({required int handle , required String peerId , required String method , required List<int> payload , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+var arg2 = cst_encode_String(method);
+var arg3 = cst_encode_list_prim_u_8_loose(payload);
+var arg4 = cst_encode_opt_box_autoadd_u_64(timeoutMs);
+            return wire.wire__crate__api__rpc_call_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_u_8_strict,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRpcCallOnConstMeta,
+            argValues: [handle, peerId, method, payload, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRpcCallOnConstMeta => const TaskConstMeta(
+            debugName: "rpc_call_on",
+            argNames: ["handle", "peerId", "method", "payload", "timeoutMs"],
+        );
+        
+
+@override ScanPageDto crateApiScanPrefix({required String dbName , required String prefix , required int limit , String? cursor })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(prefix);
+var arg2 = cst_encode_u_32(limit);
+var arg3 = cst_encode_opt_String(cursor);
+            return wire.wire__crate__api__scan_prefix(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiScanPrefixConstMeta,
+            argValues: [dbName, prefix, limit, cursor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScanPrefixConstMeta => const TaskConstMeta(
+            debugName: "scan_prefix",
+            argNames: ["dbName", "prefix", "limit", "cursor"],
+        );
+        
+
+@override ScanPageDto crateApiScanPrefixOn({required int handle , required String dbName , required String prefix , required int limit , String? cursor })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(prefix);
+var arg3 = cst_encode_u_32(limit);
+var arg4 = cst_encode_opt_String(cursor);
+            return wire.wire__crate__api__scan_prefix_on(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiScanPrefixOnConstMeta,
+            argValues: [handle, dbName, prefix, limit, cursor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScanPrefixOnConstMeta => const TaskConstMeta(
+            debugName: "scan_prefix_on",
+            argNames: ["handle", "dbName", "prefix", "limit", "cursor"],
+        );
+        
+
+@override ScanPageDto crateApiScanRange({required String dbName , String? start , String? end , required int limit , String? cursor })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_opt_String(start);
+var arg2 = cst_encode_opt_String(end);
+var arg3 = cst_encode_u_32(limit);
+var arg4 = cst_encode_opt_String(cursor);
+            return wire.wire__crate__api__scan_range(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiScanRangeConstMeta,
+            argValues: [dbName, start, end, limit, cursor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScanRangeConstMeta => const TaskConstMeta(
+            debugName: "scan_range",
+            argNames: ["dbName", "start", "end", "limit", "cursor"],
+        );
+        
+
+@override ScanPageDto crateApiScanRangeOn({required int handle , required String dbName , String? start , String? end , required int limit , String? cursor })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_opt_String(start);
+var arg3 = cst_encode_opt_String(end);
+var arg4 = cst_encode_u_32(limit);
+var arg5 = cst_encode_opt_String(cursor);
+            return wire.wire__crate__api__scan_range_on(arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_scan_page_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiScanRangeOnConstMeta,
+            argValues: [handle, dbName, start, end, limit, cursor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScanRangeOnConstMeta => const TaskConstMeta(
+            debugName: "scan_range_on",
+            argNames: ["handle", "dbName", "start", "end", "limit", "cursor"],
+        );
+        
+
+@override void crateApiSealNodeKey({required String dataDir , required List<int> wrappingKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_list_prim_u_8_loose(wrappingKey);
+            return wire.wire__crate__api__seal_node_key(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSealNodeKeyConstMeta,
+            argValues: [dataDir, wrappingKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSealNodeKeyConstMeta => const TaskConstMeta(
+            debugName: "seal_node_key",
+            argNames: ["dataDir", "wrappingKey"],
+        );
+        
+
+@override Future<List<DbEntryDto>> crateApiSearch({required String dbName , required String query , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(query);
+var arg2 = cst_encode_u_32(limit);
+            return wire.wire__crate__api__search(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_db_entry_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchConstMeta,
+            argValues: [dbName, query, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConstMeta => const TaskConstMeta(
+            debugName: "search",
+            argNames: ["dbName", "query", "limit"],
+        );
+        
+
+@override Future<List<DbEntryDto>> crateApiSearchOn({required int handle , required String dbName , required String query , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(query);
+var arg3 = cst_encode_u_32(limit);
+            return wire.wire__crate__api__search_on(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_db_entry_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchOnConstMeta,
+            argValues: [handle, dbName, query, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchOnConstMeta => const TaskConstMeta(
+            debugName: "search_on",
+            argNames: ["handle", "dbName", "query", "limit"],
+        );
+        
+
+@override Future<DirectMessageDto> crateApiSendDm({required String peerId , required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(peerId);
+var arg1 = cst_encode_String(text);
+            return wire.wire__crate__api__send_dm(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_direct_message_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSendDmConstMeta,
+            argValues: [peerId, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendDmConstMeta => const TaskConstMeta(
+            debugName: "send_dm",
+            argNames: ["peerId", "text"],
+        );
+        
+
+@override Future<DirectMessageDto> crateApiSendDmOn({required int handle , required String peerId , required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+var arg2 = cst_encode_String(text);
+            return wire.wire__crate__api__send_dm_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_direct_message_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSendDmOnConstMeta,
+            argValues: [handle, peerId, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendDmOnConstMeta => const TaskConstMeta(
+            debugName: "send_dm_on",
+            argNames: ["handle", "peerId", "text"],
+        );
+        
+
+@override Future<FileOfferDto> crateApiSendFile({required String peerId , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(peerId);
+var arg1 = cst_encode_String(path);
+            return wire.wire__crate__api__send_file(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_file_offer_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSendFileConstMeta,
+            argValues: [peerId, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendFileConstMeta => const TaskConstMeta(
+            debugName: "send_file",
+            argNames: ["peerId", "path"],
+        );
+        
+
+@override Future<FileOfferDto> crateApiSendFileOn({required int handle , required String peerId , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+var arg2 = cst_encode_String(path);
+            return wire.wire__crate__api__send_file_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_file_offer_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSendFileOnConstMeta,
+            argValues: [handle, peerId, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendFileOnConstMeta => const TaskConstMeta(
+            debugName: "send_file_on",
+            argNames: ["handle", "peerId", "path"],
+        );
+        
+
+@override Future<void> crateApiSendGossip({required String topic , required String message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(topic);
+var arg1 = cst_encode_String(message);
+            return wire.wire__crate__api__send_gossip(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSendGossipConstMeta,
+            argValues: [topic, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendGossipConstMeta => const TaskConstMeta(
+            debugName: "send_gossip",
+            argNames: ["topic", "message"],
+        );
+        
+
+@override Future<void> crateApiSendGossipOn({required int handle , required String topic , required String message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(topic);
+var arg2 = cst_encode_String(message);
+            return wire.wire__crate__api__send_gossip_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSendGossipOnConstMeta,
+            argValues: [handle, topic, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendGossipOnConstMeta => const TaskConstMeta(
+            debugName: "send_gossip_on",
+            argNames: ["handle", "topic", "message"],
+        );
+        
+
+@override Future<void> crateApiSendLatencyRequest({required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(peerId);
+            return wire.wire__crate__api__send_latency_request(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSendLatencyRequestConstMeta,
+            argValues: [peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendLatencyRequestConstMeta => const TaskConstMeta(
+            debugName: "send_latency_request",
+            argNames: ["peerId"],
+        );
+        
+
+@override Future<void> crateApiSendLatencyRequestOn({required int handle , required String peerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(peerId);
+            return wire.wire__crate__api__send_latency_request_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSendLatencyRequestOnConstMeta,
+            argValues: [handle, peerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSendLatencyRequestOnConstMeta => const TaskConstMeta(
+            debugName: "send_latency_request_on",
+            argNames: ["handle", "peerId"],
+        );
+        
+
+@override Stream<RpcRequestDto> crateApiServeRpc()  { 
+            final sink = RustStreamSink<RpcRequestDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_StreamSink_rpc_request_dto_Dco(sink);
+            return wire.wire__crate__api__serve_rpc(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServeRpcConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiServeRpcConstMeta => const TaskConstMeta(
+            debugName: "serve_rpc",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<RpcRequestDto> crateApiServeRpcOn({required int handle })  { 
+            final sink = RustStreamSink<RpcRequestDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_StreamSink_rpc_request_dto_Dco(sink);
+            return wire.wire__crate__api__serve_rpc_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiServeRpcOnConstMeta,
+            argValues: [handle, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiServeRpcOnConstMeta => const TaskConstMeta(
+            debugName: "serve_rpc_on",
+            argNames: ["handle", "sink"],
+        );
+        
+
+@override Future<void> crateApiSetAdd({required String dbName , required String key , required String member , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_String(member);
+var arg3 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__set_add(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetAddConstMeta,
+            argValues: [dbName, key, member, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetAddConstMeta => const TaskConstMeta(
+            debugName: "set_add",
+            argNames: ["dbName", "key", "member", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiSetAddOn({required int handle , required String dbName , required String key , required String member , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_String(member);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__set_add_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetAddOnConstMeta,
+            argValues: [handle, dbName, key, member, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetAddOnConstMeta => const TaskConstMeta(
+            debugName: "set_add_on",
+            argNames: ["handle", "dbName", "key", "member", "secretKey"],
+        );
+        
+
+@override void crateApiSetBandwidthCaps({required BandwidthCapsDto caps })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_bandwidth_caps_dto(caps);
+            return wire.wire__crate__api__set_bandwidth_caps(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetBandwidthCapsConstMeta,
+            argValues: [caps],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetBandwidthCapsConstMeta => const TaskConstMeta(
+            debugName: "set_bandwidth_caps",
+            argNames: ["caps"],
+        );
+        
+
+@override void crateApiSetBandwidthCapsOn({required int handle , required BandwidthCapsDto caps })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_box_autoadd_bandwidth_caps_dto(caps);
+            return wire.wire__crate__api__set_bandwidth_caps_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetBandwidthCapsOnConstMeta,
+            argValues: [handle, caps],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetBandwidthCapsOnConstMeta => const TaskConstMeta(
+            debugName: "set_bandwidth_caps_on",
+            argNames: ["handle", "caps"],
+        );
+        
+
+@override Future<void> crateApiSetDatabaseLocalOnly({required String dbName , required bool localOnly })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_bool(localOnly);
+            return wire.wire__crate__api__set_database_local_only(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetDatabaseLocalOnlyConstMeta,
+            argValues: [dbName, localOnly],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetDatabaseLocalOnlyConstMeta => const TaskConstMeta(
+            debugName: "set_database_local_only",
+            argNames: ["dbName", "localOnly"],
+        );
+        
+
+@override Future<void> crateApiSetDatabaseLocalOnlyOn({required int handle , required String dbName , required bool localOnly })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_bool(localOnly);
+            return wire.wire__crate__api__set_database_local_only_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetDatabaseLocalOnlyOnConstMeta,
+            argValues: [handle, dbName, localOnly],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetDatabaseLocalOnlyOnConstMeta => const TaskConstMeta(
+            debugName: "set_database_local_only_on",
+            argNames: ["handle", "dbName", "localOnly"],
+        );
+        
+
+@override Future<void> crateApiSetDeviceConditions({required DeviceConditionsDto conditions })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_box_autoadd_device_conditions_dto(conditions);
+            return wire.wire__crate__api__set_device_conditions(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetDeviceConditionsConstMeta,
+            argValues: [conditions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetDeviceConditionsConstMeta => const TaskConstMeta(
+            debugName: "set_device_conditions",
+            argNames: ["conditions"],
+        );
+        
+
+@override Future<void> crateApiSetDeviceConditionsOn({required int handle , required DeviceConditionsDto conditions })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_box_autoadd_device_conditions_dto(conditions);
+            return wire.wire__crate__api__set_device_conditions_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetDeviceConditionsOnConstMeta,
+            argValues: [handle, conditions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetDeviceConditionsOnConstMeta => const TaskConstMeta(
+            debugName: "set_device_conditions_on",
+            argNames: ["handle", "conditions"],
+        );
+        
+
+@override void crateApiSetKeyWrappingKey({required List<int> wrappingKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(wrappingKey);
+            return wire.wire__crate__api__set_key_wrapping_key(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetKeyWrappingKeyConstMeta,
+            argValues: [wrappingKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetKeyWrappingKeyConstMeta => const TaskConstMeta(
+            debugName: "set_key_wrapping_key",
+            argNames: ["wrappingKey"],
+        );
+        
+
+@override void crateApiSetLogBufferSize({required int size })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(size);
+            return wire.wire__crate__api__set_log_buffer_size(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetLogBufferSizeConstMeta,
+            argValues: [size],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetLogBufferSizeConstMeta => const TaskConstMeta(
+            debugName: "set_log_buffer_size",
+            argNames: ["size"],
+        );
+        
+
+@override void crateApiSetLogLevel({required String level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(level);
+            return wire.wire__crate__api__set_log_level(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetLogLevelConstMeta,
+            argValues: [level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetLogLevelConstMeta => const TaskConstMeta(
+            debugName: "set_log_level",
+            argNames: ["level"],
+        );
+        
+
+@override void crateApiSetLogTargetLevel({required String target , String? level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(target);
+var arg1 = cst_encode_opt_String(level);
+            return wire.wire__crate__api__set_log_target_level(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetLogTargetLevelConstMeta,
+            argValues: [target, level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetLogTargetLevelConstMeta => const TaskConstMeta(
+            debugName: "set_log_target_level",
+            argNames: ["target", "level"],
+        );
+        
+
+@override List<String> crateApiSetMembers({required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+            return wire.wire__crate__api__set_members(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_list_String,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiListDatabasesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiListDatabasesConstMeta =>
-      const TaskConstMeta(debugName: "list_databases", argNames: []);
-
-  @override
-  List<String> crateApiListKeys({required String dbName}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_String(dbName);
-          return wire.wire__crate__api__list_keys(arg0);
-        },
-        codec: DcoCodec(
+        )
+        ,
+            constMeta: kCrateApiSetMembersConstMeta,
+            argValues: [dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetMembersConstMeta => const TaskConstMeta(
+            debugName: "set_members",
+            argNames: ["dbName", "key"],
+        );
+        
+
+@override List<String> crateApiSetMembersOn({required int handle , required String dbName , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+            return wire.wire__crate__api__set_members_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_list_String,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiListKeysConstMeta,
-        argValues: [dbName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiListKeysConstMeta =>
-      const TaskConstMeta(debugName: "list_keys", argNames: ["dbName"]);
-
-  @override
-  Future<void> crateApiRequestSync({PlatformInt64? sinceTimestamp}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_opt_box_autoadd_i_64(sinceTimestamp);
-          return wire.wire__crate__api__request_sync(port_, arg0);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiRequestSyncConstMeta,
-        argValues: [sinceTimestamp],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiRequestSyncConstMeta => const TaskConstMeta(
-    debugName: "request_sync",
-    argNames: ["sinceTimestamp"],
-  );
-
-  @override
-  Future<void> crateApiSendGossip({
-    required String topic,
-    required String message,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(topic);
-          var arg1 = cst_encode_String(message);
-          return wire.wire__crate__api__send_gossip(port_, arg0, arg1);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiSendGossipConstMeta,
-        argValues: [topic, message],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSendGossipConstMeta => const TaskConstMeta(
-    debugName: "send_gossip",
-    argNames: ["topic", "message"],
-  );
-
-  @override
-  Future<void> crateApiSendLatencyRequest({required String peerId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(peerId);
-          return wire.wire__crate__api__send_latency_request(port_, arg0);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiSendLatencyRequestConstMeta,
-        argValues: [peerId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSendLatencyRequestConstMeta => const TaskConstMeta(
-    debugName: "send_latency_request",
-    argNames: ["peerId"],
-  );
-
-  @override
-  String crateApiSignMessageWithKey({
-    required String secretKeyHex,
-    required String message,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_String(secretKeyHex);
-          var arg1 = cst_encode_String(message);
-          return wire.wire__crate__api__sign_message_with_key(arg0, arg1);
-        },
-        codec: DcoCodec(
+        )
+        ,
+            constMeta: kCrateApiSetMembersOnConstMeta,
+            argValues: [handle, dbName, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetMembersOnConstMeta => const TaskConstMeta(
+            debugName: "set_members_on",
+            argNames: ["handle", "dbName", "key"],
+        );
+        
+
+@override Future<void> crateApiSetNetworkPassphrase({required String dataDir , String? passphrase })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_opt_String(passphrase);
+            return wire.wire__crate__api__set_network_passphrase(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetNetworkPassphraseConstMeta,
+            argValues: [dataDir, passphrase],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetNetworkPassphraseConstMeta => const TaskConstMeta(
+            debugName: "set_network_passphrase",
+            argNames: ["dataDir", "passphrase"],
+        );
+        
+
+@override Future<void> crateApiSetPowerMode({required PowerModeDto mode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_power_mode_dto(mode);
+            return wire.wire__crate__api__set_power_mode(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetPowerModeConstMeta,
+            argValues: [mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetPowerModeConstMeta => const TaskConstMeta(
+            debugName: "set_power_mode",
+            argNames: ["mode"],
+        );
+        
+
+@override Future<void> crateApiSetPowerModeOn({required int handle , required PowerModeDto mode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_power_mode_dto(mode);
+            return wire.wire__crate__api__set_power_mode_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetPowerModeOnConstMeta,
+            argValues: [handle, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetPowerModeOnConstMeta => const TaskConstMeta(
+            debugName: "set_power_mode_on",
+            argNames: ["handle", "mode"],
+        );
+        
+
+@override void crateApiSetPresenceStatus({String? status })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_opt_String(status);
+            return wire.wire__crate__api__set_presence_status(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetPresenceStatusConstMeta,
+            argValues: [status],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetPresenceStatusConstMeta => const TaskConstMeta(
+            debugName: "set_presence_status",
+            argNames: ["status"],
+        );
+        
+
+@override void crateApiSetPresenceStatusOn({required int handle , String? status })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_opt_String(status);
+            return wire.wire__crate__api__set_presence_status_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetPresenceStatusOnConstMeta,
+            argValues: [handle, status],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetPresenceStatusOnConstMeta => const TaskConstMeta(
+            debugName: "set_presence_status_on",
+            argNames: ["handle", "status"],
+        );
+        
+
+@override void crateApiSetRetainConflicts({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_bool(enabled);
+            return wire.wire__crate__api__set_retain_conflicts(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetRetainConflictsConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetRetainConflictsConstMeta => const TaskConstMeta(
+            debugName: "set_retain_conflicts",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiSetRetainConflictsOn({required int handle , required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_bool(enabled);
+            return wire.wire__crate__api__set_retain_conflicts_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetRetainConflictsOnConstMeta,
+            argValues: [handle, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetRetainConflictsOnConstMeta => const TaskConstMeta(
+            debugName: "set_retain_conflicts_on",
+            argNames: ["handle", "enabled"],
+        );
+        
+
+@override void crateApiSetSelectiveSync({required bool selective })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_bool(selective);
+            return wire.wire__crate__api__set_selective_sync(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetSelectiveSyncConstMeta,
+            argValues: [selective],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetSelectiveSyncConstMeta => const TaskConstMeta(
+            debugName: "set_selective_sync",
+            argNames: ["selective"],
+        );
+        
+
+@override void crateApiSetSelectiveSyncOn({required int handle , required bool selective })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_bool(selective);
+            return wire.wire__crate__api__set_selective_sync_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetSelectiveSyncOnConstMeta,
+            argValues: [handle, selective],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetSelectiveSyncOnConstMeta => const TaskConstMeta(
+            debugName: "set_selective_sync_on",
+            argNames: ["handle", "selective"],
+        );
+        
+
+@override String crateApiSha256Hash({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_list_prim_u_8_loose(data);
+            return wire.wire__crate__api__sha256_hash(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSha256HashConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSha256HashConstMeta => const TaskConstMeta(
+            debugName: "sha256_hash",
+            argNames: ["data"],
+        );
+        
+
+@override String crateApiSignBytes({required String secretKeyHex , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(secretKeyHex);
+var arg1 = cst_encode_list_prim_u_8_loose(message);
+            return wire.wire__crate__api__sign_bytes(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSignBytesConstMeta,
+            argValues: [secretKeyHex, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSignBytesConstMeta => const TaskConstMeta(
+            debugName: "sign_bytes",
+            argNames: ["secretKeyHex", "message"],
+        );
+        
+
+@override String crateApiSignMessageWithKey({required String secretKeyHex , required String message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(secretKeyHex);
+var arg1 = cst_encode_String(message);
+            return wire.wire__crate__api__sign_message_with_key(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSignMessageWithKeyConstMeta,
+            argValues: [secretKeyHex, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSignMessageWithKeyConstMeta => const TaskConstMeta(
+            debugName: "sign_message_with_key",
+            argNames: ["secretKeyHex", "message"],
+        );
+        
+
+@override Future<DebugServerDto> crateApiStartDebugServer({required String bindAddr , required bool allowWrites })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(bindAddr);
+var arg1 = cst_encode_bool(allowWrites);
+            return wire.wire__crate__api__start_debug_server(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_debug_server_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartDebugServerConstMeta,
+            argValues: [bindAddr, allowWrites],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartDebugServerConstMeta => const TaskConstMeta(
+            debugName: "start_debug_server",
+            argNames: ["bindAddr", "allowWrites"],
+        );
+        
+
+@override Future<DebugServerDto> crateApiStartDebugServerOn({required int handle , required String bindAddr , required bool allowWrites })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(bindAddr);
+var arg2 = cst_encode_bool(allowWrites);
+            return wire.wire__crate__api__start_debug_server_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_debug_server_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartDebugServerOnConstMeta,
+            argValues: [handle, bindAddr, allowWrites],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartDebugServerOnConstMeta => const TaskConstMeta(
+            debugName: "start_debug_server_on",
+            argNames: ["handle", "bindAddr", "allowWrites"],
+        );
+        
+
+@override Future<String> crateApiStartMetricsServer({required String bindAddr })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(bindAddr);
+            return wire.wire__crate__api__start_metrics_server(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartMetricsServerConstMeta,
+            argValues: [bindAddr],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartMetricsServerConstMeta => const TaskConstMeta(
+            debugName: "start_metrics_server",
+            argNames: ["bindAddr"],
+        );
+        
+
+@override Future<String> crateApiStartMetricsServerOn({required int handle , required String bindAddr })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(bindAddr);
+            return wire.wire__crate__api__start_metrics_server_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_String,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiSignMessageWithKeyConstMeta,
-        argValues: [secretKeyHex, message],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSignMessageWithKeyConstMeta => const TaskConstMeta(
-    debugName: "sign_message_with_key",
-    argNames: ["secretKeyHex", "message"],
-  );
-
-  @override
-  Future<NodeInfo> crateApiStartNode({
-    required String dataDir,
-    String? walletSecretKey,
-    required List<String> bootstrapPeers,
-    String? region,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(dataDir);
-          var arg1 = cst_encode_opt_String(walletSecretKey);
-          var arg2 = cst_encode_list_String(bootstrapPeers);
-          var arg3 = cst_encode_opt_String(region);
-          return wire.wire__crate__api__start_node(
-            port_,
-            arg0,
-            arg1,
-            arg2,
-            arg3,
-          );
-        },
-        codec: DcoCodec(
+        )
+        ,
+            constMeta: kCrateApiStartMetricsServerOnConstMeta,
+            argValues: [handle, bindAddr],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartMetricsServerOnConstMeta => const TaskConstMeta(
+            debugName: "start_metrics_server_on",
+            argNames: ["handle", "bindAddr"],
+        );
+        
+
+@override Future<void> crateApiStartMqttBridge({required MqttBridgeConfigDto config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_box_autoadd_mqtt_bridge_config_dto(config);
+            return wire.wire__crate__api__start_mqtt_bridge(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartMqttBridgeConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartMqttBridgeConstMeta => const TaskConstMeta(
+            debugName: "start_mqtt_bridge",
+            argNames: ["config"],
+        );
+        
+
+@override Future<void> crateApiStartMqttBridgeOn({required int handle , required MqttBridgeConfigDto config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_box_autoadd_mqtt_bridge_config_dto(config);
+            return wire.wire__crate__api__start_mqtt_bridge_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartMqttBridgeOnConstMeta,
+            argValues: [handle, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartMqttBridgeOnConstMeta => const TaskConstMeta(
+            debugName: "start_mqtt_bridge_on",
+            argNames: ["handle", "config"],
+        );
+        
+
+@override Future<NodeInfo> crateApiStartNode({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , String? region })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_opt_String(walletSecretKey);
+var arg2 = cst_encode_list_String(bootstrapPeers);
+var arg3 = cst_encode_opt_String(region);
+            return wire.wire__crate__api__start_node(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_info,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartNodeConstMeta,
+            argValues: [dataDir, walletSecretKey, bootstrapPeers, region],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartNodeConstMeta => const TaskConstMeta(
+            debugName: "start_node",
+            argNames: ["dataDir", "walletSecretKey", "bootstrapPeers", "region"],
+        );
+        
+
+@override Future<NodeInfo> crateApiStartNodeInNetwork({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , String? region , required String networkId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_opt_String(walletSecretKey);
+var arg2 = cst_encode_list_String(bootstrapPeers);
+var arg3 = cst_encode_opt_String(region);
+var arg4 = cst_encode_String(networkId);
+            return wire.wire__crate__api__start_node_in_network(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_info,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartNodeInNetworkConstMeta,
+            argValues: [dataDir, walletSecretKey, bootstrapPeers, region, networkId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartNodeInNetworkConstMeta => const TaskConstMeta(
+            debugName: "start_node_in_network",
+            argNames: ["dataDir", "walletSecretKey", "bootstrapPeers", "region", "networkId"],
+        );
+        
+
+@override Future<NodeInstanceDto> crateApiStartNodeInstance({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_opt_String(walletSecretKey);
+var arg2 = cst_encode_list_String(bootstrapPeers);
+var arg3 = cst_encode_box_autoadd_node_config_dto(config);
+            return wire.wire__crate__api__start_node_instance(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_instance_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartNodeInstanceConstMeta,
+            argValues: [dataDir, walletSecretKey, bootstrapPeers, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartNodeInstanceConstMeta => const TaskConstMeta(
+            debugName: "start_node_instance",
+            argNames: ["dataDir", "walletSecretKey", "bootstrapPeers", "config"],
+        );
+        
+
+@override Future<NodeInfo> crateApiStartNodeWithConfig({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_opt_String(walletSecretKey);
+var arg2 = cst_encode_list_String(bootstrapPeers);
+var arg3 = cst_encode_box_autoadd_node_config_dto(config);
+            return wire.wire__crate__api__start_node_with_config(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_info,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartNodeWithConfigConstMeta,
+            argValues: [dataDir, walletSecretKey, bootstrapPeers, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartNodeWithConfigConstMeta => const TaskConstMeta(
+            debugName: "start_node_with_config",
+            argNames: ["dataDir", "walletSecretKey", "bootstrapPeers", "config"],
+        );
+        
+
+@override Stream<StartupStageDto> crateApiStartNodeWithProgress({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required NodeConfigDto config })  { 
+            final progress = RustStreamSink<StartupStageDto>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_opt_String(walletSecretKey);
+var arg2 = cst_encode_list_String(bootstrapPeers);
+var arg3 = cst_encode_box_autoadd_node_config_dto(config);
+var arg4 = cst_encode_StreamSink_startup_stage_dto_Dco(progress);
+            return wire.wire__crate__api__start_node_with_progress(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_node_info,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStartNodeWithProgressConstMeta,
+            argValues: [dataDir, walletSecretKey, bootstrapPeers, config, progress],
+            apiImpl: this,
+        )));
+            return progress.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiStartNodeWithProgressConstMeta => const TaskConstMeta(
+            debugName: "start_node_with_progress",
+            argNames: ["dataDir", "walletSecretKey", "bootstrapPeers", "config", "progress"],
+        );
+        
+
+@override Future<NodeInfo> crateApiStartOffline({required String dataDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+            return wire.wire__crate__api__start_offline(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_node_info,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiStartNodeConstMeta,
-        argValues: [dataDir, walletSecretKey, bootstrapPeers, region],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStartNodeConstMeta => const TaskConstMeta(
-    debugName: "start_node",
-    argNames: ["dataDir", "walletSecretKey", "bootstrapPeers", "region"],
-  );
-
-  @override
-  Future<void> crateApiStopNode() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          return wire.wire__crate__api__stop_node(port_);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiStopNodeConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStopNodeConstMeta =>
-      const TaskConstMeta(debugName: "stop_node", argNames: []);
-
-  @override
-  Future<void> crateApiStoreData({
-    required String dbName,
-    required String key,
-    required List<int> value,
-    required String publicKey,
-    required String signature,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(dbName);
-          var arg1 = cst_encode_String(key);
-          var arg2 = cst_encode_list_prim_u_8_loose(value);
-          var arg3 = cst_encode_String(publicKey);
-          var arg4 = cst_encode_String(signature);
-          return wire.wire__crate__api__store_data(
-            port_,
-            arg0,
-            arg1,
-            arg2,
-            arg3,
-            arg4,
-          );
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiStoreDataConstMeta,
-        argValues: [dbName, key, value, publicKey, signature],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStoreDataConstMeta => const TaskConstMeta(
-    debugName: "store_data",
-    argNames: ["dbName", "key", "value", "publicKey", "signature"],
-  );
-
-  @override
-  Future<void> crateApiStoreDataLocal({
-    required String dbName,
-    required String key,
-    required List<int> value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_String(dbName);
-          var arg1 = cst_encode_String(key);
-          var arg2 = cst_encode_list_prim_u_8_loose(value);
-          return wire.wire__crate__api__store_data_local(
-            port_,
-            arg0,
-            arg1,
-            arg2,
-          );
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiStoreDataLocalConstMeta,
-        argValues: [dbName, key, value],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiStoreDataLocalConstMeta => const TaskConstMeta(
-    debugName: "store_data_local",
-    argNames: ["dbName", "key", "value"],
-  );
-
-  @override
-  bool crateApiValidateTimestamp({required PlatformInt64 timestamp}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_i_64(timestamp);
-          return wire.wire__crate__api__validate_timestamp(arg0);
-        },
-        codec: DcoCodec(
+        )
+        ,
+            constMeta: kCrateApiStartOfflineConstMeta,
+            argValues: [dataDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStartOfflineConstMeta => const TaskConstMeta(
+            debugName: "start_offline",
+            argNames: ["dataDir"],
+        );
+        
+
+@override Future<bool> crateApiStopMqttBridge()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            return wire.wire__crate__api__stop_mqtt_bridge(port_);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStopMqttBridgeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStopMqttBridgeConstMeta => const TaskConstMeta(
+            debugName: "stop_mqtt_bridge",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiStopMqttBridgeOn({required int handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__stop_mqtt_bridge_on(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStopMqttBridgeOnConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStopMqttBridgeOnConstMeta => const TaskConstMeta(
+            debugName: "stop_mqtt_bridge_on",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<void> crateApiStopNode()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            return wire.wire__crate__api__stop_node(port_);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStopNodeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStopNodeConstMeta => const TaskConstMeta(
+            debugName: "stop_node",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiStopNodeInstance({required int handle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+            return wire.wire__crate__api__stop_node_instance(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStopNodeInstanceConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStopNodeInstanceConstMeta => const TaskConstMeta(
+            debugName: "stop_node_instance",
+            argNames: ["handle"],
+        );
+        
+
+@override Future<int> crateApiStoreBatch({required List<BatchEntryDto> entries })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_list_batch_entry_dto(entries);
+            return wire.wire__crate__api__store_batch(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreBatchConstMeta,
+            argValues: [entries],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreBatchConstMeta => const TaskConstMeta(
+            debugName: "store_batch",
+            argNames: ["entries"],
+        );
+        
+
+@override Future<int> crateApiStoreBatchOn({required int handle , required List<BatchEntryDto> entries })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_list_batch_entry_dto(entries);
+            return wire.wire__crate__api__store_batch_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreBatchOnConstMeta,
+            argValues: [handle, entries],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreBatchOnConstMeta => const TaskConstMeta(
+            debugName: "store_batch_on",
+            argNames: ["handle", "entries"],
+        );
+        
+
+@override Future<void> crateApiStoreData({required String dbName , required String key , required List<int> value , required String publicKey , required String signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_list_prim_u_8_loose(value);
+var arg3 = cst_encode_String(publicKey);
+var arg4 = cst_encode_String(signature);
+            return wire.wire__crate__api__store_data(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataConstMeta,
+            argValues: [dbName, key, value, publicKey, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataConstMeta => const TaskConstMeta(
+            debugName: "store_data",
+            argNames: ["dbName", "key", "value", "publicKey", "signature"],
+        );
+        
+
+@override Future<String> crateApiStoreDataIdempotent({required String dbName , required String key , required List<int> value , required PlatformInt64 timestampMs , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_list_prim_u_8_loose(value);
+var arg3 = cst_encode_i_64(timestampMs);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__store_data_idempotent(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataIdempotentConstMeta,
+            argValues: [dbName, key, value, timestampMs, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataIdempotentConstMeta => const TaskConstMeta(
+            debugName: "store_data_idempotent",
+            argNames: ["dbName", "key", "value", "timestampMs", "secretKey"],
+        );
+        
+
+@override Future<String> crateApiStoreDataIdempotentOn({required int handle , required String dbName , required String key , required List<int> value , required PlatformInt64 timestampMs , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_list_prim_u_8_loose(value);
+var arg4 = cst_encode_i_64(timestampMs);
+var arg5 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__store_data_idempotent_on(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataIdempotentOnConstMeta,
+            argValues: [handle, dbName, key, value, timestampMs, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataIdempotentOnConstMeta => const TaskConstMeta(
+            debugName: "store_data_idempotent_on",
+            argNames: ["handle", "dbName", "key", "value", "timestampMs", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiStoreDataLocal({required String dbName , required String key , required List<int> value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_list_prim_u_8_loose(value);
+            return wire.wire__crate__api__store_data_local(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataLocalConstMeta,
+            argValues: [dbName, key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataLocalConstMeta => const TaskConstMeta(
+            debugName: "store_data_local",
+            argNames: ["dbName", "key", "value"],
+        );
+        
+
+@override Future<void> crateApiStoreDataLocalOn({required int handle , required String dbName , required String key , required List<int> value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_list_prim_u_8_loose(value);
+            return wire.wire__crate__api__store_data_local_on(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataLocalOnConstMeta,
+            argValues: [handle, dbName, key, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataLocalOnConstMeta => const TaskConstMeta(
+            debugName: "store_data_local_on",
+            argNames: ["handle", "dbName", "key", "value"],
+        );
+        
+
+@override Future<void> crateApiStoreDataOn({required int handle , required String dbName , required String key , required List<int> value , required String publicKey , required String signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_list_prim_u_8_loose(value);
+var arg4 = cst_encode_String(publicKey);
+var arg5 = cst_encode_String(signature);
+            return wire.wire__crate__api__store_data_on(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataOnConstMeta,
+            argValues: [handle, dbName, key, value, publicKey, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataOnConstMeta => const TaskConstMeta(
+            debugName: "store_data_on",
+            argNames: ["handle", "dbName", "key", "value", "publicKey", "signature"],
+        );
+        
+
+@override Future<String> crateApiStoreDataSigned({required String dbName , required String key , required List<int> value , required String storeType })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_list_prim_u_8_loose(value);
+var arg3 = cst_encode_String(storeType);
+            return wire.wire__crate__api__store_data_signed(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataSignedConstMeta,
+            argValues: [dbName, key, value, storeType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataSignedConstMeta => const TaskConstMeta(
+            debugName: "store_data_signed",
+            argNames: ["dbName", "key", "value", "storeType"],
+        );
+        
+
+@override Future<String> crateApiStoreDataSignedOn({required int handle , required String dbName , required String key , required List<int> value , required String storeType })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_list_prim_u_8_loose(value);
+var arg4 = cst_encode_String(storeType);
+            return wire.wire__crate__api__store_data_signed_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataSignedOnConstMeta,
+            argValues: [handle, dbName, key, value, storeType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataSignedOnConstMeta => const TaskConstMeta(
+            debugName: "store_data_signed_on",
+            argNames: ["handle", "dbName", "key", "value", "storeType"],
+        );
+        
+
+@override Future<void> crateApiStoreDataWithTtl({required String dbName , required String key , required List<int> value , required BigInt ttlSecs , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_list_prim_u_8_loose(value);
+var arg3 = cst_encode_u_64(ttlSecs);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__store_data_with_ttl(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataWithTtlConstMeta,
+            argValues: [dbName, key, value, ttlSecs, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataWithTtlConstMeta => const TaskConstMeta(
+            debugName: "store_data_with_ttl",
+            argNames: ["dbName", "key", "value", "ttlSecs", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiStoreDataWithTtlOn({required int handle , required String dbName , required String key , required List<int> value , required BigInt ttlSecs , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_list_prim_u_8_loose(value);
+var arg4 = cst_encode_u_64(ttlSecs);
+var arg5 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__store_data_with_ttl_on(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreDataWithTtlOnConstMeta,
+            argValues: [handle, dbName, key, value, ttlSecs, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreDataWithTtlOnConstMeta => const TaskConstMeta(
+            debugName: "store_data_with_ttl_on",
+            argNames: ["handle", "dbName", "key", "value", "ttlSecs", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiStoreEncrypted({required String dbName , required String key , required List<int> value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_list_prim_u_8_loose(value);
+var arg3 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__store_encrypted(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreEncryptedConstMeta,
+            argValues: [dbName, key, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreEncryptedConstMeta => const TaskConstMeta(
+            debugName: "store_encrypted",
+            argNames: ["dbName", "key", "value", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiStoreEncryptedOn({required int handle , required String dbName , required String key , required List<int> value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_list_prim_u_8_loose(value);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__store_encrypted_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiStoreEncryptedOnConstMeta,
+            argValues: [handle, dbName, key, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiStoreEncryptedOnConstMeta => const TaskConstMeta(
+            debugName: "store_encrypted_on",
+            argNames: ["handle", "dbName", "key", "value", "secretKey"],
+        );
+        
+
+@override Stream<TopicMessageDto> crateApiSubscribeTopic({required String name })  { 
+            final sink = RustStreamSink<TopicMessageDto>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(name);
+var arg1 = cst_encode_StreamSink_topic_message_dto_Dco(sink);
+            return wire.wire__crate__api__subscribe_topic(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSubscribeTopicConstMeta,
+            argValues: [name, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSubscribeTopicConstMeta => const TaskConstMeta(
+            debugName: "subscribe_topic",
+            argNames: ["name", "sink"],
+        );
+        
+
+@override Stream<TopicMessageDto> crateApiSubscribeTopicOn({required int handle , required String name })  { 
+            final sink = RustStreamSink<TopicMessageDto>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(name);
+var arg2 = cst_encode_StreamSink_topic_message_dto_Dco(sink);
+            return wire.wire__crate__api__subscribe_topic_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSubscribeTopicOnConstMeta,
+            argValues: [handle, name, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSubscribeTopicOnConstMeta => const TaskConstMeta(
+            debugName: "subscribe_topic_on",
+            argNames: ["handle", "name", "sink"],
+        );
+        
+
+@override Stream<TextDeltaDto> crateApiTextDeltaStream()  { 
+            final sink = RustStreamSink<TextDeltaDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_StreamSink_text_delta_dto_Dco(sink);
+            return wire.wire__crate__api__text_delta_stream(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTextDeltaStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiTextDeltaStreamConstMeta => const TaskConstMeta(
+            debugName: "text_delta_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<TextDeltaDto> crateApiTextDeltaStreamOn({required int handle })  { 
+            final sink = RustStreamSink<TextDeltaDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_StreamSink_text_delta_dto_Dco(sink);
+            return wire.wire__crate__api__text_delta_stream_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTextDeltaStreamOnConstMeta,
+            argValues: [handle, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiTextDeltaStreamOnConstMeta => const TaskConstMeta(
+            debugName: "text_delta_stream_on",
+            argNames: ["handle", "sink"],
+        );
+        
+
+@override Future<void> crateApiTsAdd({required String dbName , required String key , required PlatformInt64 timestampMs , required double value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_i_64(timestampMs);
+var arg3 = cst_encode_f_64(value);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__ts_add(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTsAddConstMeta,
+            argValues: [dbName, key, timestampMs, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTsAddConstMeta => const TaskConstMeta(
+            debugName: "ts_add",
+            argNames: ["dbName", "key", "timestampMs", "value", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiTsAddOn({required int handle , required String dbName , required String key , required PlatformInt64 timestampMs , required double value , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_i_64(timestampMs);
+var arg4 = cst_encode_f_64(value);
+var arg5 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__ts_add_on(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTsAddOnConstMeta,
+            argValues: [handle, dbName, key, timestampMs, value, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTsAddOnConstMeta => const TaskConstMeta(
+            debugName: "ts_add_on",
+            argNames: ["handle", "dbName", "key", "timestampMs", "value", "secretKey"],
+        );
+        
+
+@override List<TimePointDto> crateApiTsRange({required String dbName , required String key , required PlatformInt64 fromMs , required PlatformInt64 toMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_i_64(fromMs);
+var arg3 = cst_encode_i_64(toMs);
+            return wire.wire__crate__api__ts_range(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_time_point_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTsRangeConstMeta,
+            argValues: [dbName, key, fromMs, toMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTsRangeConstMeta => const TaskConstMeta(
+            debugName: "ts_range",
+            argNames: ["dbName", "key", "fromMs", "toMs"],
+        );
+        
+
+@override List<TimePointDto> crateApiTsRangeOn({required int handle , required String dbName , required String key , required PlatformInt64 fromMs , required PlatformInt64 toMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_i_64(fromMs);
+var arg4 = cst_encode_i_64(toMs);
+            return wire.wire__crate__api__ts_range_on(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_time_point_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTsRangeOnConstMeta,
+            argValues: [handle, dbName, key, fromMs, toMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTsRangeOnConstMeta => const TaskConstMeta(
+            debugName: "ts_range_on",
+            argNames: ["handle", "dbName", "key", "fromMs", "toMs"],
+        );
+        
+
+@override void crateApiUnfollowDatabase({required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+            return wire.wire__crate__api__unfollow_database(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUnfollowDatabaseConstMeta,
+            argValues: [dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUnfollowDatabaseConstMeta => const TaskConstMeta(
+            debugName: "unfollow_database",
+            argNames: ["dbName"],
+        );
+        
+
+@override void crateApiUnfollowDatabaseOn({required int handle , required String dbName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+            return wire.wire__crate__api__unfollow_database_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUnfollowDatabaseOnConstMeta,
+            argValues: [handle, dbName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUnfollowDatabaseOnConstMeta => const TaskConstMeta(
+            debugName: "unfollow_database_on",
+            argNames: ["handle", "dbName"],
+        );
+        
+
+@override void crateApiUnpinPeer({required String nodeId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(nodeId);
+            return wire.wire__crate__api__unpin_peer(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUnpinPeerConstMeta,
+            argValues: [nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUnpinPeerConstMeta => const TaskConstMeta(
+            debugName: "unpin_peer",
+            argNames: ["nodeId"],
+        );
+        
+
+@override void crateApiUnpinPeerOn({required int handle , required String nodeId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(nodeId);
+            return wire.wire__crate__api__unpin_peer_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUnpinPeerOnConstMeta,
+            argValues: [handle, nodeId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUnpinPeerOnConstMeta => const TaskConstMeta(
+            debugName: "unpin_peer_on",
+            argNames: ["handle", "nodeId"],
+        );
+        
+
+@override Future<bool> crateApiUnsubscribeTopic({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(name);
+            return wire.wire__crate__api__unsubscribe_topic(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUnsubscribeTopicConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUnsubscribeTopicConstMeta => const TaskConstMeta(
+            debugName: "unsubscribe_topic",
+            argNames: ["name"],
+        );
+        
+
+@override Future<bool> crateApiUnsubscribeTopicOn({required int handle , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(name);
+            return wire.wire__crate__api__unsubscribe_topic_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUnsubscribeTopicOnConstMeta,
+            argValues: [handle, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUnsubscribeTopicOnConstMeta => const TaskConstMeta(
+            debugName: "unsubscribe_topic_on",
+            argNames: ["handle", "name"],
+        );
+        
+
+@override void crateApiUpdateConfig({required NodeConfigDto config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_box_autoadd_node_config_dto(config);
+            return wire.wire__crate__api__update_config(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateConfigConstMeta => const TaskConstMeta(
+            debugName: "update_config",
+            argNames: ["config"],
+        );
+        
+
+@override void crateApiUpdateConfigOn({required int handle , required NodeConfigDto config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_box_autoadd_node_config_dto(config);
+            return wire.wire__crate__api__update_config_on(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateConfigOnConstMeta,
+            argValues: [handle, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateConfigOnConstMeta => const TaskConstMeta(
+            debugName: "update_config_on",
+            argNames: ["handle", "config"],
+        );
+        
+
+@override bool crateApiValidateTimestamp({required PlatformInt64 timestamp })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_i_64(timestamp);
+            return wire.wire__crate__api__validate_timestamp(arg0);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_bool,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiValidateTimestampConstMeta,
-        argValues: [timestamp],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiValidateTimestampConstMeta => const TaskConstMeta(
-    debugName: "validate_timestamp",
-    argNames: ["timestamp"],
-  );
-
-  @override
-  bool crateApiVerifyDbName({
-    required String dbName,
-    required String publicKeyHex,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_String(dbName);
-          var arg1 = cst_encode_String(publicKeyHex);
-          return wire.wire__crate__api__verify_db_name(arg0, arg1);
-        },
-        codec: DcoCodec(
+        )
+        ,
+            constMeta: kCrateApiValidateTimestampConstMeta,
+            argValues: [timestamp],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiValidateTimestampConstMeta => const TaskConstMeta(
+            debugName: "validate_timestamp",
+            argNames: ["timestamp"],
+        );
+        
+
+@override bool crateApiVerifyBytes({required String publicKeyHex , required List<int> message , required String signatureHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(publicKeyHex);
+var arg1 = cst_encode_list_prim_u_8_loose(message);
+var arg2 = cst_encode_String(signatureHex);
+            return wire.wire__crate__api__verify_bytes(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_bool,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiVerifyDbNameConstMeta,
-        argValues: [dbName, publicKeyHex],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiVerifyDbNameConstMeta => const TaskConstMeta(
-    debugName: "verify_db_name",
-    argNames: ["dbName", "publicKeyHex"],
-  );
-
-  @override
-  bool crateApiVerifyMessageSignature({
-    required String publicKeyHex,
-    required String message,
-    required String signatureHex,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          var arg0 = cst_encode_String(publicKeyHex);
-          var arg1 = cst_encode_String(message);
-          var arg2 = cst_encode_String(signatureHex);
-          return wire.wire__crate__api__verify_message_signature(
-            arg0,
-            arg1,
-            arg2,
-          );
-        },
-        codec: DcoCodec(
+        )
+        ,
+            constMeta: kCrateApiVerifyBytesConstMeta,
+            argValues: [publicKeyHex, message, signatureHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifyBytesConstMeta => const TaskConstMeta(
+            debugName: "verify_bytes",
+            argNames: ["publicKeyHex", "message", "signatureHex"],
+        );
+        
+
+@override bool crateApiVerifyDbName({required String dbName , required String publicKeyHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(publicKeyHex);
+            return wire.wire__crate__api__verify_db_name(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
           decodeSuccessData: dco_decode_bool,
           decodeErrorData: dco_decode_String,
-        ),
-        constMeta: kCrateApiVerifyMessageSignatureConstMeta,
-        argValues: [publicKeyHex, message, signatureHex],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiVerifyMessageSignatureConstMeta =>
-      const TaskConstMeta(
-        debugName: "verify_message_signature",
-        argNames: ["publicKeyHex", "message", "signatureHex"],
-      );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_i_64(raw);
-  }
-
-  @protected
-  NodeInfo dco_decode_box_autoadd_node_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_node_info(raw);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
-  }
-
-  @protected
-  DbEntryDto dco_decode_db_entry_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return DbEntryDto(
-      dbName: dco_decode_String(arr[0]),
-      key: dco_decode_String(arr[1]),
-      value: dco_decode_String(arr[2]),
-      valueBytes: dco_decode_list_prim_u_8_strict(arr[3]),
-    );
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  KeyPairDto dco_decode_key_pair_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return KeyPairDto(
-      publicKey: dco_decode_String(arr[0]),
-      secretKey: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<DbEntryDto> dco_decode_list_db_entry_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_db_entry_dto).toList();
-  }
-
-  @protected
-  List<LogEntry> dco_decode_list_log_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_log_entry).toList();
-  }
-
-  @protected
-  List<PeerInfoDto> dco_decode_list_peer_info_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_peer_info_dto).toList();
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  LogEntry dco_decode_log_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return LogEntry(
-      timestamp: dco_decode_i_64(arr[0]),
-      level: dco_decode_String(arr[1]),
-      message: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  NodeInfo dco_decode_node_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return NodeInfo(
-      nodeId: dco_decode_String(arr[0]),
-      publicKey: dco_decode_String(arr[1]),
-      isRunning: dco_decode_bool(arr[2]),
-    );
-  }
-
-  @protected
-  NodeStatusDto dco_decode_node_status_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return NodeStatusDto(
-      isRunning: dco_decode_bool(arr[0]),
-      nodeId: dco_decode_opt_String(arr[1]),
-      connectedPeers: dco_decode_u_32(arr[2]),
-      discoveredPeers: dco_decode_u_32(arr[3]),
-      uptimeSeconds: dco_decode_u_64(arr[4]),
-      gossipMessagesReceived: dco_decode_u_64(arr[5]),
-      storageSizeBytes: dco_decode_u_64(arr[6]),
-      totalKeys: dco_decode_u_64(arr[7]),
-      syncOperations: dco_decode_u_32(arr[8]),
-      latencyRequestsSent: dco_decode_u_64(arr[9]),
-      latencyResponsesReceived: dco_decode_u_64(arr[10]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
-  }
-
-  @protected
-  NodeInfo? dco_decode_opt_box_autoadd_node_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_node_info(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
-  }
-
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
-  }
-
-  @protected
-  PeerInfoDto dco_decode_peer_info_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return PeerInfoDto(
-      nodeId: dco_decode_String(arr[0]),
-      publicKey: dco_decode_String(arr[1]),
-      address: dco_decode_opt_String(arr[2]),
-      region: dco_decode_opt_String(arr[3]),
-      version: dco_decode_opt_String(arr[4]),
-      latencyMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
-      isMobile: dco_decode_bool(arr[6]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_64(deserializer));
-  }
-
-  @protected
-  NodeInfo sse_decode_box_autoadd_node_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_node_info(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
-  }
-
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
-  }
-
-  @protected
-  DbEntryDto sse_decode_db_entry_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_dbName = sse_decode_String(deserializer);
-    var var_key = sse_decode_String(deserializer);
-    var var_value = sse_decode_String(deserializer);
-    var var_valueBytes = sse_decode_list_prim_u_8_strict(deserializer);
-    return DbEntryDto(
-      dbName: var_dbName,
-      key: var_key,
-      value: var_value,
-      valueBytes: var_valueBytes,
-    );
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  KeyPairDto sse_decode_key_pair_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_publicKey = sse_decode_String(deserializer);
-    var var_secretKey = sse_decode_String(deserializer);
-    return KeyPairDto(publicKey: var_publicKey, secretKey: var_secretKey);
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<DbEntryDto> sse_decode_list_db_entry_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DbEntryDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_db_entry_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <LogEntry>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_log_entry(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<PeerInfoDto> sse_decode_list_peer_info_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PeerInfoDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_peer_info_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_timestamp = sse_decode_i_64(deserializer);
-    var var_level = sse_decode_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return LogEntry(
-      timestamp: var_timestamp,
-      level: var_level,
-      message: var_message,
-    );
-  }
-
-  @protected
-  NodeInfo sse_decode_node_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_publicKey = sse_decode_String(deserializer);
-    var var_isRunning = sse_decode_bool(deserializer);
-    return NodeInfo(
-      nodeId: var_nodeId,
-      publicKey: var_publicKey,
-      isRunning: var_isRunning,
-    );
-  }
-
-  @protected
-  NodeStatusDto sse_decode_node_status_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_isRunning = sse_decode_bool(deserializer);
-    var var_nodeId = sse_decode_opt_String(deserializer);
-    var var_connectedPeers = sse_decode_u_32(deserializer);
-    var var_discoveredPeers = sse_decode_u_32(deserializer);
-    var var_uptimeSeconds = sse_decode_u_64(deserializer);
-    var var_gossipMessagesReceived = sse_decode_u_64(deserializer);
-    var var_storageSizeBytes = sse_decode_u_64(deserializer);
-    var var_totalKeys = sse_decode_u_64(deserializer);
-    var var_syncOperations = sse_decode_u_32(deserializer);
-    var var_latencyRequestsSent = sse_decode_u_64(deserializer);
-    var var_latencyResponsesReceived = sse_decode_u_64(deserializer);
-    return NodeStatusDto(
-      isRunning: var_isRunning,
-      nodeId: var_nodeId,
-      connectedPeers: var_connectedPeers,
-      discoveredPeers: var_discoveredPeers,
-      uptimeSeconds: var_uptimeSeconds,
-      gossipMessagesReceived: var_gossipMessagesReceived,
-      storageSizeBytes: var_storageSizeBytes,
-      totalKeys: var_totalKeys,
-      syncOperations: var_syncOperations,
-      latencyRequestsSent: var_latencyRequestsSent,
-      latencyResponsesReceived: var_latencyResponsesReceived,
-    );
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  NodeInfo? sse_decode_opt_box_autoadd_node_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_node_info(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PeerInfoDto sse_decode_peer_info_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nodeId = sse_decode_String(deserializer);
-    var var_publicKey = sse_decode_String(deserializer);
-    var var_address = sse_decode_opt_String(deserializer);
-    var var_region = sse_decode_opt_String(deserializer);
-    var var_version = sse_decode_opt_String(deserializer);
-    var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_isMobile = sse_decode_bool(deserializer);
-    return PeerInfoDto(
-      nodeId: var_nodeId,
-      publicKey: var_publicKey,
-      address: var_address,
-      region: var_region,
-      version: var_version,
-      latencyMs: var_latencyMs,
-      isMobile: var_isMobile,
-    );
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool cst_encode_bool(bool raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  int cst_encode_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  int cst_encode_u_8(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  void cst_encode_unit(void raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_node_info(
-    NodeInfo self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_node_info(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_db_entry_dto(DbEntryDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.dbName, serializer);
-    sse_encode_String(self.key, serializer);
-    sse_encode_String(self.value, serializer);
-    sse_encode_list_prim_u_8_strict(self.valueBytes, serializer);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_key_pair_dto(KeyPairDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.publicKey, serializer);
-    sse_encode_String(self.secretKey, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_db_entry_dto(
-    List<DbEntryDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_db_entry_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_log_entry(
-    List<LogEntry> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_log_entry(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_peer_info_dto(
-    List<PeerInfoDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_peer_info_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.timestamp, serializer);
-    sse_encode_String(self.level, serializer);
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_node_info(NodeInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_String(self.publicKey, serializer);
-    sse_encode_bool(self.isRunning, serializer);
-  }
-
-  @protected
-  void sse_encode_node_status_dto(
-    NodeStatusDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isRunning, serializer);
-    sse_encode_opt_String(self.nodeId, serializer);
-    sse_encode_u_32(self.connectedPeers, serializer);
-    sse_encode_u_32(self.discoveredPeers, serializer);
-    sse_encode_u_64(self.uptimeSeconds, serializer);
-    sse_encode_u_64(self.gossipMessagesReceived, serializer);
-    sse_encode_u_64(self.storageSizeBytes, serializer);
-    sse_encode_u_64(self.totalKeys, serializer);
-    sse_encode_u_32(self.syncOperations, serializer);
-    sse_encode_u_64(self.latencyRequestsSent, serializer);
-    sse_encode_u_64(self.latencyResponsesReceived, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_node_info(
-    NodeInfo? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_node_info(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-    Uint8List? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_8_strict(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_peer_info_dto(PeerInfoDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nodeId, serializer);
-    sse_encode_String(self.publicKey, serializer);
-    sse_encode_opt_String(self.address, serializer);
-    sse_encode_opt_String(self.region, serializer);
-    sse_encode_opt_String(self.version, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
-    sse_encode_bool(self.isMobile, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiVerifyDbNameConstMeta,
+            argValues: [dbName, publicKeyHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifyDbNameConstMeta => const TaskConstMeta(
+            debugName: "verify_db_name",
+            argNames: ["dbName", "publicKeyHex"],
+        );
+        
+
+@override bool crateApiVerifyMessageSignature({required String publicKeyHex , required String message , required String signatureHex })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(publicKeyHex);
+var arg1 = cst_encode_String(message);
+var arg2 = cst_encode_String(signatureHex);
+            return wire.wire__crate__api__verify_message_signature(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiVerifyMessageSignatureConstMeta,
+            argValues: [publicKeyHex, message, signatureHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifyMessageSignatureConstMeta => const TaskConstMeta(
+            debugName: "verify_message_signature",
+            argNames: ["publicKeyHex", "message", "signatureHex"],
+        );
+        
+
+@override Future<IntegrityReportDto> crateApiVerifyStorage({required bool repair })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_bool(repair);
+            return wire.wire__crate__api__verify_storage(port_, arg0);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_integrity_report_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiVerifyStorageConstMeta,
+            argValues: [repair],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifyStorageConstMeta => const TaskConstMeta(
+            debugName: "verify_storage",
+            argNames: ["repair"],
+        );
+        
+
+@override Future<IntegrityReportDto> crateApiVerifyStorageOn({required int handle , required bool repair })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_bool(repair);
+            return wire.wire__crate__api__verify_storage_on(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_integrity_report_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiVerifyStorageOnConstMeta,
+            argValues: [handle, repair],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVerifyStorageOnConstMeta => const TaskConstMeta(
+            debugName: "verify_storage_on",
+            argNames: ["handle", "repair"],
+        );
+        
+
+@override Future<SyncWaitSummaryDto> crateApiWaitForSync({required String dbName , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_u_64(timeoutMs);
+            return wire.wire__crate__api__wait_for_sync(port_, arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_sync_wait_summary_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWaitForSyncConstMeta,
+            argValues: [dbName, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWaitForSyncConstMeta => const TaskConstMeta(
+            debugName: "wait_for_sync",
+            argNames: ["dbName", "timeoutMs"],
+        );
+        
+
+@override Future<SyncWaitSummaryDto> crateApiWaitForSyncOn({required int handle , required String dbName , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_u_64(timeoutMs);
+            return wire.wire__crate__api__wait_for_sync_on(port_, arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_sync_wait_summary_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWaitForSyncOnConstMeta,
+            argValues: [handle, dbName, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWaitForSyncOnConstMeta => const TaskConstMeta(
+            debugName: "wait_for_sync_on",
+            argNames: ["handle", "dbName", "timeoutMs"],
+        );
+        
+
+@override Future<WakeSyncSummaryDto> crateApiWakeAndSync({required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required int maxDurationSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dataDir);
+var arg1 = cst_encode_opt_String(walletSecretKey);
+var arg2 = cst_encode_list_String(bootstrapPeers);
+var arg3 = cst_encode_u_32(maxDurationSecs);
+            return wire.wire__crate__api__wake_and_sync(port_, arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_wake_sync_summary_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWakeAndSyncConstMeta,
+            argValues: [dataDir, walletSecretKey, bootstrapPeers, maxDurationSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWakeAndSyncConstMeta => const TaskConstMeta(
+            debugName: "wake_and_sync",
+            argNames: ["dataDir", "walletSecretKey", "bootstrapPeers", "maxDurationSecs"],
+        );
+        
+
+@override Future<WakeSyncSummaryDto> crateApiWakeAndSyncOn({required int handle , required String dataDir , String? walletSecretKey , required List<String> bootstrapPeers , required int maxDurationSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dataDir);
+var arg2 = cst_encode_opt_String(walletSecretKey);
+var arg3 = cst_encode_list_String(bootstrapPeers);
+var arg4 = cst_encode_u_32(maxDurationSecs);
+            return wire.wire__crate__api__wake_and_sync_on(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_wake_sync_summary_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWakeAndSyncOnConstMeta,
+            argValues: [handle, dataDir, walletSecretKey, bootstrapPeers, maxDurationSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWakeAndSyncOnConstMeta => const TaskConstMeta(
+            debugName: "wake_and_sync_on",
+            argNames: ["handle", "dataDir", "walletSecretKey", "bootstrapPeers", "maxDurationSecs"],
+        );
+        
+
+@override Stream<KeyChangeDto> crateApiWatchDb({required String dbName })  { 
+            final sink = RustStreamSink<KeyChangeDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_StreamSink_key_change_dto_Dco(sink);
+            return wire.wire__crate__api__watch_db(arg0, arg1);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWatchDbConstMeta,
+            argValues: [dbName, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiWatchDbConstMeta => const TaskConstMeta(
+            debugName: "watch_db",
+            argNames: ["dbName", "sink"],
+        );
+        
+
+@override Stream<KeyChangeDto> crateApiWatchDbOn({required int handle , required String dbName })  { 
+            final sink = RustStreamSink<KeyChangeDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_StreamSink_key_change_dto_Dco(sink);
+            return wire.wire__crate__api__watch_db_on(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWatchDbOnConstMeta,
+            argValues: [handle, dbName, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiWatchDbOnConstMeta => const TaskConstMeta(
+            debugName: "watch_db_on",
+            argNames: ["handle", "dbName", "sink"],
+        );
+        
+
+@override Stream<ValueUpdateDto> crateApiWatchKey({required String dbName , required String key })  { 
+            final sink = RustStreamSink<ValueUpdateDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_StreamSink_value_update_dto_Dco(sink);
+            return wire.wire__crate__api__watch_key(arg0, arg1, arg2);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWatchKeyConstMeta,
+            argValues: [dbName, key, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiWatchKeyConstMeta => const TaskConstMeta(
+            debugName: "watch_key",
+            argNames: ["dbName", "key", "sink"],
+        );
+        
+
+@override Stream<ValueUpdateDto> crateApiWatchKeyOn({required int handle , required String dbName , required String key })  { 
+            final sink = RustStreamSink<ValueUpdateDto>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_StreamSink_value_update_dto_Dco(sink);
+            return wire.wire__crate__api__watch_key_on(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWatchKeyOnConstMeta,
+            argValues: [handle, dbName, key, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiWatchKeyOnConstMeta => const TaskConstMeta(
+            debugName: "watch_key_on",
+            argNames: ["handle", "dbName", "key", "sink"],
+        );
+        
+
+@override Future<void> crateApiZsetAdd({required String dbName , required String key , required String member , required double score , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_String(member);
+var arg3 = cst_encode_f_64(score);
+var arg4 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__zset_add(port_, arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiZsetAddConstMeta,
+            argValues: [dbName, key, member, score, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiZsetAddConstMeta => const TaskConstMeta(
+            debugName: "zset_add",
+            argNames: ["dbName", "key", "member", "score", "secretKey"],
+        );
+        
+
+@override Future<void> crateApiZsetAddOn({required int handle , required String dbName , required String key , required String member , required double score , required String secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_String(member);
+var arg4 = cst_encode_f_64(score);
+var arg5 = cst_encode_String(secretKey);
+            return wire.wire__crate__api__zset_add_on(port_, arg0, arg1, arg2, arg3, arg4, arg5);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiZsetAddOnConstMeta,
+            argValues: [handle, dbName, key, member, score, secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiZsetAddOnConstMeta => const TaskConstMeta(
+            debugName: "zset_add_on",
+            argNames: ["handle", "dbName", "key", "member", "score", "secretKey"],
+        );
+        
+
+@override List<ScoredMemberDto> crateApiZsetRange({required String dbName , required String key , double? min , double? max })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_String(dbName);
+var arg1 = cst_encode_String(key);
+var arg2 = cst_encode_opt_box_autoadd_f_64(min);
+var arg3 = cst_encode_opt_box_autoadd_f_64(max);
+            return wire.wire__crate__api__zset_range(arg0, arg1, arg2, arg3);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_scored_member_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiZsetRangeConstMeta,
+            argValues: [dbName, key, min, max],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiZsetRangeConstMeta => const TaskConstMeta(
+            debugName: "zset_range",
+            argNames: ["dbName", "key", "min", "max"],
+        );
+        
+
+@override List<ScoredMemberDto> crateApiZsetRangeOn({required int handle , required String dbName , required String key , double? min , double? max })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              var arg0 = cst_encode_u_32(handle);
+var arg1 = cst_encode_String(dbName);
+var arg2 = cst_encode_String(key);
+var arg3 = cst_encode_opt_box_autoadd_f_64(min);
+var arg4 = cst_encode_opt_box_autoadd_f_64(max);
+            return wire.wire__crate__api__zset_range_on(arg0, arg1, arg2, arg3, arg4);
+            
+            },
+            codec: 
+        DcoCodec(
+          decodeSuccessData: dco_decode_list_scored_member_dto,
+          decodeErrorData: dco_decode_String,
+        )
+        ,
+            constMeta: kCrateApiZsetRangeOnConstMeta,
+            argValues: [handle, dbName, key, min, max],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiZsetRangeOnConstMeta => const TaskConstMeta(
+            debugName: "zset_range_on",
+            argNames: ["handle", "dbName", "key", "min", "max"],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<ChangeEventDto> dco_decode_StreamSink_change_event_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<DirectMessageDto> dco_decode_StreamSink_direct_message_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<FileEventDto> dco_decode_StreamSink_file_event_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<KeyChangeDto> dco_decode_StreamSink_key_change_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<NodeEventRecordDto> dco_decode_StreamSink_node_event_record_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<RpcRequestDto> dco_decode_StreamSink_rpc_request_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<StartupStageDto> dco_decode_StreamSink_startup_stage_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<TextDeltaDto> dco_decode_StreamSink_text_delta_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<TopicMessageDto> dco_decode_StreamSink_topic_message_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ValueUpdateDto> dco_decode_StreamSink_value_update_dto_Dco(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AcceptedInviteDto dco_decode_accepted_invite_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return AcceptedInviteDto(dbName: dco_decode_String(arr[0]),
+owner: dco_decode_String(arr[1]),
+canWrite: dco_decode_bool(arr[2]),
+writerSecretKey: dco_decode_opt_String(arr[3]),
+encrypted: dco_decode_bool(arr[4]),); }
+
+@protected BackupInfoDto dco_decode_backup_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BackupInfoDto(createdAt: dco_decode_i_64(arr[0]),
+publicKey: dco_decode_String(arr[1]),
+treeCount: dco_decode_u_32(arr[2]),
+entryCount: dco_decode_u_64(arr[3]),); }
+
+@protected BandwidthCapsDto dco_decode_bandwidth_caps_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BandwidthCapsDto(dailyBytes: dco_decode_opt_box_autoadd_u_64(arr[0]),
+monthlyBytes: dco_decode_opt_box_autoadd_u_64(arr[1]),
+meteredOnly: dco_decode_bool(arr[2]),); }
+
+@protected BandwidthStatsDto dco_decode_bandwidth_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return BandwidthStatsDto(topics: dco_decode_list_bandwidth_usage_dto(arr[0]),
+subsystems: dco_decode_list_bandwidth_usage_dto(arr[1]),
+todayBytes: dco_decode_u_64(arr[2]),
+monthBytes: dco_decode_u_64(arr[3]),
+caps: dco_decode_bandwidth_caps_dto(arr[4]),
+capExceeded: dco_decode_bool(arr[5]),); }
+
+@protected BandwidthSubsystemDto dco_decode_bandwidth_subsystem_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BandwidthSubsystemDto.values[raw as int]; }
+
+@protected BandwidthUsageDto dco_decode_bandwidth_usage_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BandwidthUsageDto(name: dco_decode_String(arr[0]),
+subsystem: dco_decode_bandwidth_subsystem_dto(arr[1]),
+bytesSent: dco_decode_u_64(arr[2]),
+bytesReceived: dco_decode_u_64(arr[3]),); }
+
+@protected BatchEntryDto dco_decode_batch_entry_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return BatchEntryDto(dbName: dco_decode_String(arr[0]),
+key: dco_decode_String(arr[1]),
+value: dco_decode_list_prim_u_8_strict(arr[2]),
+publicKey: dco_decode_String(arr[3]),
+signature: dco_decode_String(arr[4]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected BootstrapManifestDto dco_decode_bootstrap_manifest_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BootstrapManifestDto(url: dco_decode_String(arr[0]),
+publisherKey: dco_decode_String(arr[1]),); }
+
+@protected BandwidthCapsDto dco_decode_box_autoadd_bandwidth_caps_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_bandwidth_caps_dto(raw); }
+
+@protected BootstrapManifestDto dco_decode_box_autoadd_bootstrap_manifest_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_bootstrap_manifest_dto(raw); }
+
+@protected DeviceConditionsDto dco_decode_box_autoadd_device_conditions_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_device_conditions_dto(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FileOfferDto dco_decode_box_autoadd_file_offer_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_file_offer_dto(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected LogFilterDto dco_decode_box_autoadd_log_filter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_log_filter_dto(raw); }
+
+@protected MqttBridgeConfigDto dco_decode_box_autoadd_mqtt_bridge_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_mqtt_bridge_config_dto(raw); }
+
+@protected NodeConfigDto dco_decode_box_autoadd_node_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_node_config_dto(raw); }
+
+@protected NodeInfo dco_decode_box_autoadd_node_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_node_info(raw); }
+
+@protected OplogGcDto dco_decode_box_autoadd_oplog_gc_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_oplog_gc_dto(raw); }
+
+@protected PeerPathDto dco_decode_box_autoadd_peer_path_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_peer_path_dto(raw); }
+
+@protected PresenceDto dco_decode_box_autoadd_presence_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_presence_dto(raw); }
+
+@protected QuerySpecDto dco_decode_box_autoadd_query_spec_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_query_spec_dto(raw); }
+
+@protected SuccessorRecordDto dco_decode_box_autoadd_successor_record_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_successor_record_dto(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected ChangeEventDto dco_decode_change_event_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ChangeEventDto(seq: dco_decode_u_64(arr[0]),
+dbName: dco_decode_String(arr[1]),
+key: dco_decode_String(arr[2]),
+value: dco_decode_opt_String(arr[3]),
+valueBytes: dco_decode_opt_list_prim_u_8_strict(arr[4]),); }
+
+@protected CheckpointDto dco_decode_checkpoint_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CheckpointDto(highWaterMark: dco_decode_opt_box_autoadd_i_64(arr[0]),
+knownPeersSaved: dco_decode_u_32(arr[1]),
+outboxDepth: dco_decode_u_32(arr[2]),
+flushed: dco_decode_bool(arr[3]),
+durationMs: dco_decode_u_64(arr[4]),); }
+
+@protected ConflictDto dco_decode_conflict_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return ConflictDto(conflictKey: dco_decode_String(arr[0]),
+dbName: dco_decode_String(arr[1]),
+key: dco_decode_String(arr[2]),
+field: dco_decode_opt_String(arr[3]),
+keptOpId: dco_decode_String(arr[4]),
+discardedOpId: dco_decode_String(arr[5]),
+discardedValue: dco_decode_String(arr[6]),
+discardedTimestamp: dco_decode_i_64(arr[7]),
+resolvedAt: dco_decode_i_64(arr[8]),); }
+
+@protected ConnectionDiagnosticsDto dco_decode_connection_diagnostics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ConnectionDiagnosticsDto(localAddrs: dco_decode_list_String(arr[0]),
+publicAddrs: dco_decode_list_String(arr[1]),
+relayUrl: dco_decode_opt_String(arr[2]),
+natType: dco_decode_String(arr[3]),
+peers: dco_decode_list_peer_diagnostics_dto(arr[4]),); }
+
+@protected ConnectionResultDto dco_decode_connection_result_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ConnectionResultDto(nodeId: dco_decode_String(arr[0]),
+path: dco_decode_String(arr[1]),
+handshakeMs: dco_decode_u_64(arr[2]),); }
+
+@protected ConnectionTypeDto dco_decode_connection_type_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectionTypeDto.values[raw as int]; }
+
+@protected ConnectivityDto dco_decode_connectivity_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ConnectivityDto(state: dco_decode_connectivity_state_dto(arr[0]),
+reason: dco_decode_String(arr[1]),); }
+
+@protected ConnectivityStateDto dco_decode_connectivity_state_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectivityStateDto.values[raw as int]; }
+
+@protected DbEntryDto dco_decode_db_entry_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DbEntryDto(dbName: dco_decode_String(arr[0]),
+key: dco_decode_String(arr[1]),
+value: dco_decode_String(arr[2]),
+valueBytes: dco_decode_list_prim_u_8_strict(arr[3]),); }
+
+@protected DbSyncInfoDto dco_decode_db_sync_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return DbSyncInfoDto(dbName: dco_decode_String(arr[0]),
+opCount: dco_decode_u_64(arr[1]),
+lastLocalWrite: dco_decode_opt_box_autoadd_i_64(arr[2]),
+lastRemoteOp: dco_decode_opt_box_autoadd_i_64(arr[3]),
+lastSync: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected DebugServerDto dco_decode_debug_server_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DebugServerDto(address: dco_decode_String(arr[0]),
+token: dco_decode_String(arr[1]),); }
+
+@protected DeviceConditionsDto dco_decode_device_conditions_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DeviceConditionsDto(batteryPercent: dco_decode_opt_box_autoadd_u_8(arr[0]),
+charging: dco_decode_bool(arr[1]),
+connectionType: dco_decode_connection_type_dto(arr[2]),
+metered: dco_decode_bool(arr[3]),); }
+
+@protected DirectMessageDto dco_decode_direct_message_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return DirectMessageDto(id: dco_decode_String(arr[0]),
+peerId: dco_decode_String(arr[1]),
+outgoing: dco_decode_bool(arr[2]),
+text: dco_decode_String(arr[3]),
+sentAt: dco_decode_i_64(arr[4]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FileEventDto dco_decode_file_event_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FileEventDto_Offer(dco_decode_box_autoadd_file_offer_dto(raw[1]),);
+case 1: return FileEventDto_Progress(offerId: dco_decode_String(raw[1]),receivedBytes: dco_decode_u_64(raw[2]),totalBytes: dco_decode_u_64(raw[3]),);
+case 2: return FileEventDto_Retrying(offerId: dco_decode_String(raw[1]),attempt: dco_decode_u_32(raw[2]),error: dco_decode_String(raw[3]),);
+case 3: return FileEventDto_Completed(offerId: dco_decode_String(raw[1]),path: dco_decode_String(raw[2]),);
+case 4: return FileEventDto_Failed(offerId: dco_decode_String(raw[1]),error: dco_decode_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected FileOfferDto dco_decode_file_offer_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FileOfferDto(offerId: dco_decode_String(arr[0]),
+from: dco_decode_String(arr[1]),
+name: dco_decode_String(arr[2]),
+size: dco_decode_u_64(arr[3]),
+offeredAt: dco_decode_i_64(arr[4]),); }
+
+@protected GeoMatchDto dco_decode_geo_match_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return GeoMatchDto(member: dco_decode_String(arr[0]),
+latitude: dco_decode_f_64(arr[1]),
+longitude: dco_decode_f_64(arr[2]),
+distanceM: dco_decode_f_64(arr[3]),); }
+
+@protected HashEntryDto dco_decode_hash_entry_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HashEntryDto(field: dco_decode_String(arr[0]),
+value: dco_decode_String(arr[1]),); }
+
+@protected HealthReportDto dco_decode_health_report_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return HealthReportDto(storage: dco_decode_subsystem_health_dto(arr[0]),
+gossip: dco_decode_subsystem_health_dto(arr[1]),
+sync_: dco_decode_subsystem_health_dto(arr[2]),
+discovery: dco_decode_subsystem_health_dto(arr[3]),
+relay: dco_decode_subsystem_health_dto(arr[4]),
+topics: dco_decode_list_topic_health_dto(arr[5]),
+lastFlushAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+lastSyncAt: dco_decode_opt_box_autoadd_i_64(arr[7]),
+lastAnnouncementAt: dco_decode_opt_box_autoadd_i_64(arr[8]),); }
+
+@protected HealthStatusDto dco_decode_health_status_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HealthStatusDto.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected IdentityRotationDto dco_decode_identity_rotation_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return IdentityRotationDto(node: dco_decode_node_info(arr[0]),
+record: dco_decode_successor_record_dto(arr[1]),
+rekeyedDatabases: dco_decode_list_rekeyed_database_dto(arr[2]),
+rekeyedOperations: dco_decode_u_32(arr[3]),); }
+
+@protected IntegrityIssueDto dco_decode_integrity_issue_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return IntegrityIssueDto(dbName: dco_decode_String(arr[0]),
+key: dco_decode_String(arr[1]),
+opId: dco_decode_String(arr[2]),
+kind: dco_decode_String(arr[3]),); }
+
+@protected IntegrityReportDto dco_decode_integrity_report_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return IntegrityReportDto(checked: dco_decode_u_64(arr[0]),
+issues: dco_decode_list_integrity_issue_dto(arr[1]),
+repaired: dco_decode_u_64(arr[2]),); }
+
+@protected KadenaTransactionDto dco_decode_kadena_transaction_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return KadenaTransactionDto(hash: dco_decode_String(arr[0]),
+sig: dco_decode_String(arr[1]),
+cmd: dco_decode_String(arr[2]),
+json: dco_decode_String(arr[3]),); }
+
+@protected KeyChangeDto dco_decode_key_change_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return KeyChangeDto(dbName: dco_decode_String(arr[0]),
+key: dco_decode_String(arr[1]),
+deleted: dco_decode_bool(arr[2]),); }
+
+@protected KeyPairDto dco_decode_key_pair_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return KeyPairDto(publicKey: dco_decode_String(arr[0]),
+secretKey: dco_decode_String(arr[1]),); }
+
+@protected LatencyBucketDto dco_decode_latency_bucket_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LatencyBucketDto(leMs: dco_decode_opt_box_autoadd_f_64(arr[0]),
+count: dco_decode_u_64(arr[1]),); }
+
+@protected LinkedDeviceDto dco_decode_linked_device_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return LinkedDeviceDto(userPublicKey: dco_decode_String(arr[0]),
+nodeId: dco_decode_String(arr[1]),
+deviceName: dco_decode_String(arr[2]),
+linkedAt: dco_decode_i_64(arr[3]),
+thisDevice: dco_decode_bool(arr[4]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<BandwidthUsageDto> dco_decode_list_bandwidth_usage_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_bandwidth_usage_dto).toList(); }
+
+@protected List<BatchEntryDto> dco_decode_list_batch_entry_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_batch_entry_dto).toList(); }
+
+@protected List<ConflictDto> dco_decode_list_conflict_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_conflict_dto).toList(); }
+
+@protected List<DbEntryDto> dco_decode_list_db_entry_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_db_entry_dto).toList(); }
+
+@protected List<DirectMessageDto> dco_decode_list_direct_message_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_direct_message_dto).toList(); }
+
+@protected List<FileOfferDto> dco_decode_list_file_offer_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_file_offer_dto).toList(); }
+
+@protected List<GeoMatchDto> dco_decode_list_geo_match_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_geo_match_dto).toList(); }
+
+@protected List<HashEntryDto> dco_decode_list_hash_entry_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_hash_entry_dto).toList(); }
+
+@protected List<IntegrityIssueDto> dco_decode_list_integrity_issue_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_integrity_issue_dto).toList(); }
+
+@protected List<LatencyBucketDto> dco_decode_list_latency_bucket_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_latency_bucket_dto).toList(); }
+
+@protected List<LinkedDeviceDto> dco_decode_list_linked_device_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_linked_device_dto).toList(); }
+
+@protected List<LogEntry> dco_decode_list_log_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_log_entry).toList(); }
+
+@protected List<MqttTopicMappingDto> dco_decode_list_mqtt_topic_mapping_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mqtt_topic_mapping_dto).toList(); }
+
+@protected List<NodeEventRecordDto> dco_decode_list_node_event_record_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_node_event_record_dto).toList(); }
+
+@protected List<Uint8List?> dco_decode_list_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_list_prim_u_8_strict).toList(); }
+
+@protected List<PeerCircuitDto> dco_decode_list_peer_circuit_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_circuit_dto).toList(); }
+
+@protected List<PeerDiagnosticsDto> dco_decode_list_peer_diagnostics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_diagnostics_dto).toList(); }
+
+@protected List<PeerInfoDto> dco_decode_list_peer_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_info_dto).toList(); }
+
+@protected List<PeerLatencyDto> dco_decode_list_peer_latency_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_latency_dto).toList(); }
+
+@protected List<PeerPathDto> dco_decode_list_peer_path_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_path_dto).toList(); }
+
+@protected List<PeerSyncStatusDto> dco_decode_list_peer_sync_status_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_peer_sync_status_dto).toList(); }
+
+@protected List<PinnedPeerDto> dco_decode_list_pinned_peer_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_pinned_peer_dto).toList(); }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<QueryFilterDto> dco_decode_list_query_filter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_filter_dto).toList(); }
+
+@protected List<RekeyedDatabaseDto> dco_decode_list_rekeyed_database_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_rekeyed_database_dto).toList(); }
+
+@protected List<ReplicaCheckDto> dco_decode_list_replica_check_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_replica_check_dto).toList(); }
+
+@protected List<ScoredMemberDto> dco_decode_list_scored_member_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_scored_member_dto).toList(); }
+
+@protected List<TimePointDto> dco_decode_list_time_point_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_time_point_dto).toList(); }
+
+@protected List<TopicHealthDto> dco_decode_list_topic_health_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_topic_health_dto).toList(); }
+
+@protected List<TopicMetricsDto> dco_decode_list_topic_metrics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_topic_metrics_dto).toList(); }
+
+@protected List<TopicStatsDto> dco_decode_list_topic_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_topic_stats_dto).toList(); }
+
+@protected LogEntry dco_decode_log_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LogEntry(timestamp: dco_decode_i_64(arr[0]),
+level: dco_decode_String(arr[1]),
+message: dco_decode_String(arr[2]),); }
+
+@protected LogFilterDto dco_decode_log_filter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LogFilterDto(minLevel: dco_decode_opt_String(arr[0]),
+target: dco_decode_opt_String(arr[1]),
+contains: dco_decode_opt_String(arr[2]),
+since: dco_decode_opt_box_autoadd_i_64(arr[3]),
+until: dco_decode_opt_box_autoadd_i_64(arr[4]),
+limit: dco_decode_opt_box_autoadd_u_32(arr[5]),); }
+
+@protected MetricsDto dco_decode_metrics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return MetricsDto(topics: dco_decode_list_topic_metrics_dto(arr[0]),
+opsApplied: dco_decode_u_64(arr[1]),
+verifyFailures: dco_decode_u_64(arr[2]),
+limitViolations: dco_decode_u_64(arr[3]),
+connectLatency: dco_decode_list_latency_bucket_dto(arr[4]),
+connectLatencySumMs: dco_decode_f_64(arr[5]),
+storageSizeBytes: dco_decode_u_64(arr[6]),
+storageKeys: dco_decode_u_64(arr[7]),
+storedOperations: dco_decode_u_64(arr[8]),
+outboxDepth: dco_decode_u_64(arr[9]),); }
+
+@protected MqttBridgeConfigDto dco_decode_mqtt_bridge_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MqttBridgeConfigDto(host: dco_decode_String(arr[0]),
+port: dco_decode_opt_box_autoadd_u_16(arr[1]),
+clientId: dco_decode_String(arr[2]),
+username: dco_decode_opt_String(arr[3]),
+password: dco_decode_opt_String(arr[4]),
+mappings: dco_decode_list_mqtt_topic_mapping_dto(arr[5]),); }
+
+@protected MqttDirectionDto dco_decode_mqtt_direction_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MqttDirectionDto.values[raw as int]; }
+
+@protected MqttTopicMappingDto dco_decode_mqtt_topic_mapping_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MqttTopicMappingDto(topicPrefix: dco_decode_String(arr[0]),
+dbName: dco_decode_String(arr[1]),
+direction: dco_decode_mqtt_direction_dto(arr[2]),); }
+
+@protected NodeAddrDto dco_decode_node_addr_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NodeAddrDto(nodeId: dco_decode_String(arr[0]),
+directAddresses: dco_decode_list_String(arr[1]),
+relayUrls: dco_decode_list_String(arr[2]),); }
+
+@protected NodeConfigDto dco_decode_node_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 18) throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+                return NodeConfigDto(region: dco_decode_opt_String(arr[0]),
+networkId: dco_decode_opt_String(arr[1]),
+maxKnownPeerDials: dco_decode_u_32(arr[2]),
+announceIntervalSecs: dco_decode_u_64(arr[3]),
+latencySweepIntervalSecs: dco_decode_u_64(arr[4]),
+pinnedPeersCheckIntervalSecs: dco_decode_u_64(arr[5]),
+isolationCheckIntervalSecs: dco_decode_u_64(arr[6]),
+isolationChecksBeforeReconnect: dco_decode_u_32(arr[7]),
+maxOpsPerResponse: dco_decode_u_32(arr[8]),
+maxConnectionAttemptsPerCycle: dco_decode_u_32(arr[9]),
+dialIntervalMs: dco_decode_u_64(arr[10]),
+relay: dco_decode_relay_config_dto(arr[11]),
+bootstrapManifest: dco_decode_opt_box_autoadd_bootstrap_manifest_dto(arr[12]),
+maxValueBytes: dco_decode_u_32(arr[13]),
+maxKeyLength: dco_decode_u_32(arr[14]),
+allowedStoreTypes: dco_decode_list_String(arr[15]),
+oplogGc: dco_decode_opt_box_autoadd_oplog_gc_dto(arr[16]),
+maxGossipMessageSize: dco_decode_u_32(arr[17]),); }
+
+@protected NodeEventDto dco_decode_node_event_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return NodeEventDto_Started(nodeId: dco_decode_String(raw[1]),publicKey: dco_decode_String(raw[2]),);
+case 1: return NodeEventDto_Stopped();
+case 2: return NodeEventDto_PeerConnected(peerId: dco_decode_String(raw[1]),);
+case 3: return NodeEventDto_PeerDisconnected(peerId: dco_decode_String(raw[1]),);
+case 4: return NodeEventDto_PeerDiscovered(peerId: dco_decode_String(raw[1]),address: dco_decode_opt_String(raw[2]),);
+case 5: return NodeEventDto_GossipReceived(topic: dco_decode_String(raw[1]),from: dco_decode_String(raw[2]),content: dco_decode_String(raw[3]),verified: dco_decode_bool(raw[4]),);
+case 6: return NodeEventDto_SyncReceived(dbName: dco_decode_String(raw[1]),key: dco_decode_String(raw[2]),);
+case 7: return NodeEventDto_SyncProgress(dbName: dco_decode_String(raw[1]),opsReceived: dco_decode_u_64(raw[2]),opsTotalEstimate: dco_decode_u_64(raw[3]),bytes: dco_decode_u_64(raw[4]),);
+case 8: return NodeEventDto_ConflictResolved(dbName: dco_decode_String(raw[1]),key: dco_decode_String(raw[2]),keptOpId: dco_decode_String(raw[3]),discardedOpId: dco_decode_String(raw[4]),);
+case 9: return NodeEventDto_LatencyMeasured(peerId: dco_decode_String(raw[1]),latencyMs: dco_decode_u_64(raw[2]),);
+case 10: return NodeEventDto_PresenceChanged(peerId: dco_decode_String(raw[1]),online: dco_decode_bool(raw[2]),status: dco_decode_opt_String(raw[3]),);
+case 11: return NodeEventDto_TopicRecovered(topic: dco_decode_String(raw[1]),);
+case 12: return NodeEventDto_RelayConnected(relayUrl: dco_decode_String(raw[1]),);
+case 13: return NodeEventDto_BootstrapConnected(peerId: dco_decode_String(raw[1]),);
+case 14: return NodeEventDto_IdentityRotated(oldNodeId: dco_decode_String(raw[1]),newNodeId: dco_decode_String(raw[2]),);
+case 15: return NodeEventDto_DeviceLinked(userPublicKey: dco_decode_String(raw[1]),deviceNodeId: dco_decode_String(raw[2]),deviceName: dco_decode_String(raw[3]),);
+case 16: return NodeEventDto_ConnectivityChanged(state: dco_decode_connectivity_state_dto(raw[1]),reason: dco_decode_String(raw[2]),);
+case 17: return NodeEventDto_Error(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected NodeEventRecordDto dco_decode_node_event_record_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NodeEventRecordDto(seq: dco_decode_u_64(arr[0]),
+timestamp: dco_decode_i_64(arr[1]),
+event: dco_decode_node_event_dto(arr[2]),); }
+
+@protected NodeInfo dco_decode_node_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NodeInfo(nodeId: dco_decode_String(arr[0]),
+publicKey: dco_decode_String(arr[1]),
+isRunning: dco_decode_bool(arr[2]),); }
+
+@protected NodeInstanceDto dco_decode_node_instance_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NodeInstanceDto(handle: dco_decode_u_32(arr[0]),
+nodeId: dco_decode_String(arr[1]),
+publicKey: dco_decode_String(arr[2]),); }
+
+@protected NodeStatsDto dco_decode_node_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return NodeStatsDto(uptimeSeconds: dco_decode_u_64(arr[0]),
+totalOperations: dco_decode_u_64(arr[1]),
+bytesSent: dco_decode_u_64(arr[2]),
+bytesReceived: dco_decode_u_64(arr[3]),
+connects: dco_decode_u_64(arr[4]),
+reconnects: dco_decode_u_64(arr[5]),
+lifetimeSessions: dco_decode_u_64(arr[6]),
+lifetimeUptimeSeconds: dco_decode_u_64(arr[7]),
+lifetimeBytesSent: dco_decode_u_64(arr[8]),
+lifetimeBytesReceived: dco_decode_u_64(arr[9]),
+lifetimeConnects: dco_decode_u_64(arr[10]),
+lifetimeReconnects: dco_decode_u_64(arr[11]),
+outboxDepth: dco_decode_u_64(arr[12]),); }
+
+@protected NodeStatusDto dco_decode_node_status_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return NodeStatusDto(isRunning: dco_decode_bool(arr[0]),
+nodeId: dco_decode_opt_String(arr[1]),
+connectedPeers: dco_decode_u_32(arr[2]),
+discoveredPeers: dco_decode_u_32(arr[3]),
+uptimeSeconds: dco_decode_u_64(arr[4]),
+gossipMessagesReceived: dco_decode_u_64(arr[5]),
+storageSizeBytes: dco_decode_u_64(arr[6]),
+totalKeys: dco_decode_u_64(arr[7]),
+syncOperations: dco_decode_u_32(arr[8]),
+latencyRequestsSent: dco_decode_u_64(arr[9]),
+latencyResponsesReceived: dco_decode_u_64(arr[10]),
+topics: dco_decode_list_topic_stats_dto(arr[11]),); }
+
+@protected OplogGcDto dco_decode_oplog_gc_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return OplogGcDto(keepVersions: dco_decode_u_32(arr[0]),
+keepForSecs: dco_decode_u_64(arr[1]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected BootstrapManifestDto? dco_decode_opt_box_autoadd_bootstrap_manifest_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bootstrap_manifest_dto(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected MqttBridgeConfigDto? dco_decode_opt_box_autoadd_mqtt_bridge_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_mqtt_bridge_config_dto(raw); }
+
+@protected NodeInfo? dco_decode_opt_box_autoadd_node_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_node_info(raw); }
+
+@protected OplogGcDto? dco_decode_opt_box_autoadd_oplog_gc_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_oplog_gc_dto(raw); }
+
+@protected PeerPathDto? dco_decode_opt_box_autoadd_peer_path_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_peer_path_dto(raw); }
+
+@protected PresenceDto? dco_decode_opt_box_autoadd_presence_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_presence_dto(raw); }
+
+@protected SuccessorRecordDto? dco_decode_opt_box_autoadd_successor_record_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_successor_record_dto(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_String(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected PeerCircuitDto dco_decode_peer_circuit_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PeerCircuitDto(nodeId: dco_decode_String(arr[0]),
+state: dco_decode_String(arr[1]),
+unreachable: dco_decode_bool(arr[2]),
+consecutiveFailures: dco_decode_u_32(arr[3]),
+lastFailureAt: dco_decode_i_64(arr[4]),
+retryAt: dco_decode_opt_box_autoadd_i_64(arr[5]),); }
+
+@protected PeerDiagnosticsDto dco_decode_peer_diagnostics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PeerDiagnosticsDto(nodeId: dco_decode_String(arr[0]),
+path: dco_decode_opt_box_autoadd_peer_path_dto(arr[1]),
+directEstablished: dco_decode_bool(arr[2]),
+dialAttempts: dco_decode_u_32(arr[3]),
+dialFailures: dco_decode_u_32(arr[4]),
+lastDialAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+lastError: dco_decode_opt_String(arr[6]),); }
+
+@protected PeerInfoDto dco_decode_peer_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PeerInfoDto(nodeId: dco_decode_String(arr[0]),
+publicKey: dco_decode_String(arr[1]),
+address: dco_decode_opt_String(arr[2]),
+region: dco_decode_opt_String(arr[3]),
+version: dco_decode_opt_String(arr[4]),
+latencyMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+isMobile: dco_decode_bool(arr[6]),); }
+
+@protected PeerLatencyDto dco_decode_peer_latency_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PeerLatencyDto(nodeId: dco_decode_String(arr[0]),
+latencyMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+averageMs: dco_decode_f_64(arr[2]),
+jitterMs: dco_decode_f_64(arr[3]),
+samples: dco_decode_u_32(arr[4]),); }
+
+@protected PeerPathDto dco_decode_peer_path_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PeerPathDto(nodeId: dco_decode_String(arr[0]),
+path: dco_decode_String(arr[1]),
+isMixed: dco_decode_bool(arr[2]),
+directAddrs: dco_decode_list_String(arr[3]),
+relayUrl: dco_decode_opt_String(arr[4]),
+updatedAt: dco_decode_i_64(arr[5]),); }
+
+@protected PeerSyncStatusDto dco_decode_peer_sync_status_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return PeerSyncStatusDto(peerId: dco_decode_String(arr[0]),
+opsSent: dco_decode_u_64(arr[1]),
+opsReceived: dco_decode_u_64(arr[2]),
+bytesSent: dco_decode_u_64(arr[3]),
+bytesReceived: dco_decode_u_64(arr[4]),
+lastRequestAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+lastResponseAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+inProgress: dco_decode_bool(arr[7]),
+opsAcked: dco_decode_u_64(arr[8]),
+lastAckAt: dco_decode_opt_box_autoadd_i_64(arr[9]),); }
+
+@protected PinnedPeerDto dco_decode_pinned_peer_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PinnedPeerDto(nodeId: dco_decode_String(arr[0]),
+address: dco_decode_opt_String(arr[1]),); }
+
+@protected PowerModeDto dco_decode_power_mode_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PowerModeDto.values[raw as int]; }
+
+@protected PowerPolicyDto dco_decode_power_policy_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PowerPolicyDto(lowPower: dco_decode_bool(arr[0]),
+metered: dco_decode_bool(arr[1]),
+syncDeferred: dco_decode_bool(arr[2]),
+blobTransfersAllowed: dco_decode_bool(arr[3]),); }
+
+@protected PresenceDto dco_decode_presence_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PresenceDto(peerId: dco_decode_String(arr[0]),
+online: dco_decode_bool(arr[1]),
+status: dco_decode_opt_String(arr[2]),
+lastSeen: dco_decode_i_64(arr[3]),); }
+
+@protected QueryFilterDto dco_decode_query_filter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return QueryFilterDto(field: dco_decode_String(arr[0]),
+op: dco_decode_String(arr[1]),
+valueJson: dco_decode_String(arr[2]),); }
+
+@protected QueryResultDto dco_decode_query_result_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return QueryResultDto(entries: dco_decode_list_db_entry_dto(arr[0]),
+hasMore: dco_decode_bool(arr[1]),); }
+
+@protected QuerySpecDto dco_decode_query_spec_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return QuerySpecDto(keyPrefix: dco_decode_opt_String(arr[0]),
+filters: dco_decode_list_query_filter_dto(arr[1]),
+sortBy: dco_decode_opt_String(arr[2]),
+descending: dco_decode_bool(arr[3]),
+limit: dco_decode_u_32(arr[4]),
+offset: dco_decode_u_32(arr[5]),
+fields: dco_decode_opt_list_String(arr[6]),); }
+
+@protected RekeyedDatabaseDto dco_decode_rekeyed_database_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RekeyedDatabaseDto(oldName: dco_decode_String(arr[0]),
+newName: dco_decode_String(arr[1]),); }
+
+@protected RelayConfigDto dco_decode_relay_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return RelayConfigDto_Default();
+case 1: return RelayConfigDto_Disabled();
+case 2: return RelayConfigDto_Custom(urls: dco_decode_list_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected RelayStatusDto dco_decode_relay_status_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RelayStatusDto(relay: dco_decode_relay_config_dto(arr[0]),
+activeUrl: dco_decode_opt_String(arr[1]),
+rttMs: dco_decode_opt_box_autoadd_u_64(arr[2]),); }
+
+@protected ReplicaCheckDto dco_decode_replica_check_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ReplicaCheckDto(dbName: dco_decode_String(arr[0]),
+publisher: dco_decode_String(arr[1]),
+matches: dco_decode_bool(arr[2]),
+mismatchedBuckets: dco_decode_u_32(arr[3]),
+checkedAt: dco_decode_i_64(arr[4]),); }
+
+@protected RpcRequestDto dco_decode_rpc_request_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RpcRequestDto(requestId: dco_decode_u_64(arr[0]),
+peerId: dco_decode_String(arr[1]),
+method: dco_decode_String(arr[2]),
+payload: dco_decode_list_prim_u_8_strict(arr[3]),); }
+
+@protected ScanPageDto dco_decode_scan_page_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ScanPageDto(entries: dco_decode_list_db_entry_dto(arr[0]),
+nextCursor: dco_decode_opt_String(arr[1]),); }
+
+@protected ScoredMemberDto dco_decode_scored_member_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ScoredMemberDto(member: dco_decode_String(arr[0]),
+score: dco_decode_f_64(arr[1]),); }
+
+@protected StartupStageDto dco_decode_startup_stage_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return StartupStageDto_KeyLoaded();
+case 1: return StartupStageDto_EndpointOnline(relayConnected: dco_decode_bool(raw[1]),);
+case 2: return StartupStageDto_BootstrapConnecting(attempt: dco_decode_u_32(raw[1]),);
+case 3: return StartupStageDto_TopicsSubscribed();
+case 4: return StartupStageDto_Ready();
+                default: throw Exception("unreachable");
+            } }
+
+@protected SubsystemHealthDto dco_decode_subsystem_health_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SubsystemHealthDto(status: dco_decode_health_status_dto(arr[0]),
+reason: dco_decode_String(arr[1]),); }
+
+@protected SuccessorRecordDto dco_decode_successor_record_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SuccessorRecordDto(oldNodeId: dco_decode_String(arr[0]),
+newNodeId: dco_decode_String(arr[1]),
+timestamp: dco_decode_i_64(arr[2]),
+graceUntil: dco_decode_i_64(arr[3]),); }
+
+@protected SyncStatsDto dco_decode_sync_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SyncStatsDto(totalOperations: dco_decode_u_64(arr[0]),
+opsSent: dco_decode_u_64(arr[1]),
+opsReceived: dco_decode_u_64(arr[2]),
+bytesSent: dco_decode_u_64(arr[3]),
+bytesReceived: dco_decode_u_64(arr[4]),
+lastSyncAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+peers: dco_decode_list_peer_sync_status_dto(arr[6]),); }
+
+@protected SyncWaitSummaryDto dco_decode_sync_wait_summary_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SyncWaitSummaryDto(synced: dco_decode_bool(arr[0]),
+queuedOps: dco_decode_u_32(arr[1]),
+unacknowledgedOps: dco_decode_u_32(arr[2]),
+durationMs: dco_decode_u_64(arr[3]),); }
+
+@protected TextDeltaDto dco_decode_text_delta_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return TextDeltaDto(dbName: dco_decode_String(arr[0]),
+key: dco_decode_String(arr[1]),
+index: dco_decode_u_64(arr[2]),
+deleted: dco_decode_u_64(arr[3]),
+inserted: dco_decode_String(arr[4]),); }
+
+@protected TimePointDto dco_decode_time_point_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TimePointDto(timestamp: dco_decode_i_64(arr[0]),
+value: dco_decode_f_64(arr[1]),); }
+
+@protected TopicHealthDto dco_decode_topic_health_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TopicHealthDto(topic: dco_decode_String(arr[0]),
+subscribed: dco_decode_bool(arr[1]),
+neighbors: dco_decode_u_32(arr[2]),); }
+
+@protected TopicMessageDto dco_decode_topic_message_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TopicMessageDto(topic: dco_decode_String(arr[0]),
+deliveredFrom: dco_decode_String(arr[1]),
+content: dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected TopicMetricsDto dco_decode_topic_metrics_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TopicMetricsDto(topic: dco_decode_String(arr[0]),
+messagesSent: dco_decode_u_64(arr[1]),
+messagesReceived: dco_decode_u_64(arr[2]),
+duplicatesDropped: dco_decode_u_64(arr[3]),
+bytesSent: dco_decode_u_64(arr[4]),
+bytesReceived: dco_decode_u_64(arr[5]),); }
+
+@protected TopicStatsDto dco_decode_topic_stats_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return TopicStatsDto(topic: dco_decode_String(arr[0]),
+messagesSent: dco_decode_u_64(arr[1]),
+messagesReceived: dco_decode_u_64(arr[2]),
+bytesSent: dco_decode_u_64(arr[3]),
+bytesReceived: dco_decode_u_64(arr[4]),
+neighbors: dco_decode_u_32(arr[5]),
+lastEventAt: dco_decode_opt_box_autoadd_i_64(arr[6]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected ValueUpdateDto dco_decode_value_update_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ValueUpdateDto(dbName: dco_decode_String(arr[0]),
+key: dco_decode_String(arr[1]),
+value: dco_decode_opt_String(arr[2]),
+valueBytes: dco_decode_opt_list_prim_u_8_strict(arr[3]),); }
+
+@protected VerifiedBindingDto dco_decode_verified_binding_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return VerifiedBindingDto(nodeId: dco_decode_String(arr[0]),
+publicKey: dco_decode_String(arr[1]),
+verifiedAt: dco_decode_i_64(arr[2]),); }
+
+@protected WakeSyncSummaryDto dco_decode_wake_sync_summary_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return WakeSyncSummaryDto(startedNode: dco_decode_bool(arr[0]),
+pinnedPeersDialed: dco_decode_u_32(arr[1]),
+pinnedPeersConnected: dco_decode_u_32(arr[2]),
+synced: dco_decode_bool(arr[3]),
+opsReceived: dco_decode_u_64(arr[4]),
+opsSent: dco_decode_u_64(arr[5]),
+outboxSent: dco_decode_u_32(arr[6]),
+outboxRemaining: dco_decode_u_32(arr[7]),
+durationMs: dco_decode_u_64(arr[8]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<ChangeEventDto> sse_decode_StreamSink_change_event_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<DirectMessageDto> sse_decode_StreamSink_direct_message_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<FileEventDto> sse_decode_StreamSink_file_event_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<KeyChangeDto> sse_decode_StreamSink_key_change_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<NodeEventRecordDto> sse_decode_StreamSink_node_event_record_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<RpcRequestDto> sse_decode_StreamSink_rpc_request_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<StartupStageDto> sse_decode_StreamSink_startup_stage_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<TextDeltaDto> sse_decode_StreamSink_text_delta_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<TopicMessageDto> sse_decode_StreamSink_topic_message_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ValueUpdateDto> sse_decode_StreamSink_value_update_dto_Dco(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AcceptedInviteDto sse_decode_accepted_invite_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_owner = sse_decode_String(deserializer);
+var var_canWrite = sse_decode_bool(deserializer);
+var var_writerSecretKey = sse_decode_opt_String(deserializer);
+var var_encrypted = sse_decode_bool(deserializer);
+return AcceptedInviteDto(dbName: var_dbName, owner: var_owner, canWrite: var_canWrite, writerSecretKey: var_writerSecretKey, encrypted: var_encrypted); }
+
+@protected BackupInfoDto sse_decode_backup_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+var var_treeCount = sse_decode_u_32(deserializer);
+var var_entryCount = sse_decode_u_64(deserializer);
+return BackupInfoDto(createdAt: var_createdAt, publicKey: var_publicKey, treeCount: var_treeCount, entryCount: var_entryCount); }
+
+@protected BandwidthCapsDto sse_decode_bandwidth_caps_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dailyBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_monthlyBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_meteredOnly = sse_decode_bool(deserializer);
+return BandwidthCapsDto(dailyBytes: var_dailyBytes, monthlyBytes: var_monthlyBytes, meteredOnly: var_meteredOnly); }
+
+@protected BandwidthStatsDto sse_decode_bandwidth_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topics = sse_decode_list_bandwidth_usage_dto(deserializer);
+var var_subsystems = sse_decode_list_bandwidth_usage_dto(deserializer);
+var var_todayBytes = sse_decode_u_64(deserializer);
+var var_monthBytes = sse_decode_u_64(deserializer);
+var var_caps = sse_decode_bandwidth_caps_dto(deserializer);
+var var_capExceeded = sse_decode_bool(deserializer);
+return BandwidthStatsDto(topics: var_topics, subsystems: var_subsystems, todayBytes: var_todayBytes, monthBytes: var_monthBytes, caps: var_caps, capExceeded: var_capExceeded); }
+
+@protected BandwidthSubsystemDto sse_decode_bandwidth_subsystem_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BandwidthSubsystemDto.values[inner]; }
+
+@protected BandwidthUsageDto sse_decode_bandwidth_usage_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_subsystem = sse_decode_bandwidth_subsystem_dto(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+return BandwidthUsageDto(name: var_name, subsystem: var_subsystem, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived); }
+
+@protected BatchEntryDto sse_decode_batch_entry_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_list_prim_u_8_strict(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+var var_signature = sse_decode_String(deserializer);
+return BatchEntryDto(dbName: var_dbName, key: var_key, value: var_value, publicKey: var_publicKey, signature: var_signature); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected BootstrapManifestDto sse_decode_bootstrap_manifest_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_publisherKey = sse_decode_String(deserializer);
+return BootstrapManifestDto(url: var_url, publisherKey: var_publisherKey); }
+
+@protected BandwidthCapsDto sse_decode_box_autoadd_bandwidth_caps_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bandwidth_caps_dto(deserializer)); }
+
+@protected BootstrapManifestDto sse_decode_box_autoadd_bootstrap_manifest_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bootstrap_manifest_dto(deserializer)); }
+
+@protected DeviceConditionsDto sse_decode_box_autoadd_device_conditions_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_device_conditions_dto(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected FileOfferDto sse_decode_box_autoadd_file_offer_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_file_offer_dto(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected LogFilterDto sse_decode_box_autoadd_log_filter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_log_filter_dto(deserializer)); }
+
+@protected MqttBridgeConfigDto sse_decode_box_autoadd_mqtt_bridge_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_mqtt_bridge_config_dto(deserializer)); }
+
+@protected NodeConfigDto sse_decode_box_autoadd_node_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_node_config_dto(deserializer)); }
+
+@protected NodeInfo sse_decode_box_autoadd_node_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_node_info(deserializer)); }
+
+@protected OplogGcDto sse_decode_box_autoadd_oplog_gc_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_oplog_gc_dto(deserializer)); }
+
+@protected PeerPathDto sse_decode_box_autoadd_peer_path_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_peer_path_dto(deserializer)); }
+
+@protected PresenceDto sse_decode_box_autoadd_presence_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_presence_dto(deserializer)); }
+
+@protected QuerySpecDto sse_decode_box_autoadd_query_spec_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_query_spec_dto(deserializer)); }
+
+@protected SuccessorRecordDto sse_decode_box_autoadd_successor_record_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_successor_record_dto(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected ChangeEventDto sse_decode_change_event_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_seq = sse_decode_u_64(deserializer);
+var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_opt_String(deserializer);
+var var_valueBytes = sse_decode_opt_list_prim_u_8_strict(deserializer);
+return ChangeEventDto(seq: var_seq, dbName: var_dbName, key: var_key, value: var_value, valueBytes: var_valueBytes); }
+
+@protected CheckpointDto sse_decode_checkpoint_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_highWaterMark = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_knownPeersSaved = sse_decode_u_32(deserializer);
+var var_outboxDepth = sse_decode_u_32(deserializer);
+var var_flushed = sse_decode_bool(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+return CheckpointDto(highWaterMark: var_highWaterMark, knownPeersSaved: var_knownPeersSaved, outboxDepth: var_outboxDepth, flushed: var_flushed, durationMs: var_durationMs); }
+
+@protected ConflictDto sse_decode_conflict_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_conflictKey = sse_decode_String(deserializer);
+var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_field = sse_decode_opt_String(deserializer);
+var var_keptOpId = sse_decode_String(deserializer);
+var var_discardedOpId = sse_decode_String(deserializer);
+var var_discardedValue = sse_decode_String(deserializer);
+var var_discardedTimestamp = sse_decode_i_64(deserializer);
+var var_resolvedAt = sse_decode_i_64(deserializer);
+return ConflictDto(conflictKey: var_conflictKey, dbName: var_dbName, key: var_key, field: var_field, keptOpId: var_keptOpId, discardedOpId: var_discardedOpId, discardedValue: var_discardedValue, discardedTimestamp: var_discardedTimestamp, resolvedAt: var_resolvedAt); }
+
+@protected ConnectionDiagnosticsDto sse_decode_connection_diagnostics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_localAddrs = sse_decode_list_String(deserializer);
+var var_publicAddrs = sse_decode_list_String(deserializer);
+var var_relayUrl = sse_decode_opt_String(deserializer);
+var var_natType = sse_decode_String(deserializer);
+var var_peers = sse_decode_list_peer_diagnostics_dto(deserializer);
+return ConnectionDiagnosticsDto(localAddrs: var_localAddrs, publicAddrs: var_publicAddrs, relayUrl: var_relayUrl, natType: var_natType, peers: var_peers); }
+
+@protected ConnectionResultDto sse_decode_connection_result_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_handshakeMs = sse_decode_u_64(deserializer);
+return ConnectionResultDto(nodeId: var_nodeId, path: var_path, handshakeMs: var_handshakeMs); }
+
+@protected ConnectionTypeDto sse_decode_connection_type_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectionTypeDto.values[inner]; }
+
+@protected ConnectivityDto sse_decode_connectivity_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_connectivity_state_dto(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return ConnectivityDto(state: var_state, reason: var_reason); }
+
+@protected ConnectivityStateDto sse_decode_connectivity_state_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectivityStateDto.values[inner]; }
+
+@protected DbEntryDto sse_decode_db_entry_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+var var_valueBytes = sse_decode_list_prim_u_8_strict(deserializer);
+return DbEntryDto(dbName: var_dbName, key: var_key, value: var_value, valueBytes: var_valueBytes); }
+
+@protected DbSyncInfoDto sse_decode_db_sync_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_opCount = sse_decode_u_64(deserializer);
+var var_lastLocalWrite = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastRemoteOp = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastSync = sse_decode_opt_box_autoadd_i_64(deserializer);
+return DbSyncInfoDto(dbName: var_dbName, opCount: var_opCount, lastLocalWrite: var_lastLocalWrite, lastRemoteOp: var_lastRemoteOp, lastSync: var_lastSync); }
+
+@protected DebugServerDto sse_decode_debug_server_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_address = sse_decode_String(deserializer);
+var var_token = sse_decode_String(deserializer);
+return DebugServerDto(address: var_address, token: var_token); }
+
+@protected DeviceConditionsDto sse_decode_device_conditions_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_batteryPercent = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_charging = sse_decode_bool(deserializer);
+var var_connectionType = sse_decode_connection_type_dto(deserializer);
+var var_metered = sse_decode_bool(deserializer);
+return DeviceConditionsDto(batteryPercent: var_batteryPercent, charging: var_charging, connectionType: var_connectionType, metered: var_metered); }
+
+@protected DirectMessageDto sse_decode_direct_message_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_peerId = sse_decode_String(deserializer);
+var var_outgoing = sse_decode_bool(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_sentAt = sse_decode_i_64(deserializer);
+return DirectMessageDto(id: var_id, peerId: var_peerId, outgoing: var_outgoing, text: var_text, sentAt: var_sentAt); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FileEventDto sse_decode_file_event_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_box_autoadd_file_offer_dto(deserializer);
+return FileEventDto_Offer(var_field0);case 1: var var_offerId = sse_decode_String(deserializer);
+var var_receivedBytes = sse_decode_u_64(deserializer);
+var var_totalBytes = sse_decode_u_64(deserializer);
+return FileEventDto_Progress(offerId: var_offerId, receivedBytes: var_receivedBytes, totalBytes: var_totalBytes);case 2: var var_offerId = sse_decode_String(deserializer);
+var var_attempt = sse_decode_u_32(deserializer);
+var var_error = sse_decode_String(deserializer);
+return FileEventDto_Retrying(offerId: var_offerId, attempt: var_attempt, error: var_error);case 3: var var_offerId = sse_decode_String(deserializer);
+var var_path = sse_decode_String(deserializer);
+return FileEventDto_Completed(offerId: var_offerId, path: var_path);case 4: var var_offerId = sse_decode_String(deserializer);
+var var_error = sse_decode_String(deserializer);
+return FileEventDto_Failed(offerId: var_offerId, error: var_error); default: throw UnimplementedError(''); }
+             }
+
+@protected FileOfferDto sse_decode_file_offer_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_offerId = sse_decode_String(deserializer);
+var var_from = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_offeredAt = sse_decode_i_64(deserializer);
+return FileOfferDto(offerId: var_offerId, from: var_from, name: var_name, size: var_size, offeredAt: var_offeredAt); }
+
+@protected GeoMatchDto sse_decode_geo_match_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_member = sse_decode_String(deserializer);
+var var_latitude = sse_decode_f_64(deserializer);
+var var_longitude = sse_decode_f_64(deserializer);
+var var_distanceM = sse_decode_f_64(deserializer);
+return GeoMatchDto(member: var_member, latitude: var_latitude, longitude: var_longitude, distanceM: var_distanceM); }
+
+@protected HashEntryDto sse_decode_hash_entry_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return HashEntryDto(field: var_field, value: var_value); }
+
+@protected HealthReportDto sse_decode_health_report_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_storage = sse_decode_subsystem_health_dto(deserializer);
+var var_gossip = sse_decode_subsystem_health_dto(deserializer);
+var var_sync_ = sse_decode_subsystem_health_dto(deserializer);
+var var_discovery = sse_decode_subsystem_health_dto(deserializer);
+var var_relay = sse_decode_subsystem_health_dto(deserializer);
+var var_topics = sse_decode_list_topic_health_dto(deserializer);
+var var_lastFlushAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastSyncAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastAnnouncementAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return HealthReportDto(storage: var_storage, gossip: var_gossip, sync_: var_sync_, discovery: var_discovery, relay: var_relay, topics: var_topics, lastFlushAt: var_lastFlushAt, lastSyncAt: var_lastSyncAt, lastAnnouncementAt: var_lastAnnouncementAt); }
+
+@protected HealthStatusDto sse_decode_health_status_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HealthStatusDto.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected IdentityRotationDto sse_decode_identity_rotation_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_node = sse_decode_node_info(deserializer);
+var var_record = sse_decode_successor_record_dto(deserializer);
+var var_rekeyedDatabases = sse_decode_list_rekeyed_database_dto(deserializer);
+var var_rekeyedOperations = sse_decode_u_32(deserializer);
+return IdentityRotationDto(node: var_node, record: var_record, rekeyedDatabases: var_rekeyedDatabases, rekeyedOperations: var_rekeyedOperations); }
+
+@protected IntegrityIssueDto sse_decode_integrity_issue_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_opId = sse_decode_String(deserializer);
+var var_kind = sse_decode_String(deserializer);
+return IntegrityIssueDto(dbName: var_dbName, key: var_key, opId: var_opId, kind: var_kind); }
+
+@protected IntegrityReportDto sse_decode_integrity_report_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_checked = sse_decode_u_64(deserializer);
+var var_issues = sse_decode_list_integrity_issue_dto(deserializer);
+var var_repaired = sse_decode_u_64(deserializer);
+return IntegrityReportDto(checked: var_checked, issues: var_issues, repaired: var_repaired); }
+
+@protected KadenaTransactionDto sse_decode_kadena_transaction_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hash = sse_decode_String(deserializer);
+var var_sig = sse_decode_String(deserializer);
+var var_cmd = sse_decode_String(deserializer);
+var var_json = sse_decode_String(deserializer);
+return KadenaTransactionDto(hash: var_hash, sig: var_sig, cmd: var_cmd, json: var_json); }
+
+@protected KeyChangeDto sse_decode_key_change_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_deleted = sse_decode_bool(deserializer);
+return KeyChangeDto(dbName: var_dbName, key: var_key, deleted: var_deleted); }
+
+@protected KeyPairDto sse_decode_key_pair_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_publicKey = sse_decode_String(deserializer);
+var var_secretKey = sse_decode_String(deserializer);
+return KeyPairDto(publicKey: var_publicKey, secretKey: var_secretKey); }
+
+@protected LatencyBucketDto sse_decode_latency_bucket_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_leMs = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+return LatencyBucketDto(leMs: var_leMs, count: var_count); }
+
+@protected LinkedDeviceDto sse_decode_linked_device_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userPublicKey = sse_decode_String(deserializer);
+var var_nodeId = sse_decode_String(deserializer);
+var var_deviceName = sse_decode_String(deserializer);
+var var_linkedAt = sse_decode_i_64(deserializer);
+var var_thisDevice = sse_decode_bool(deserializer);
+return LinkedDeviceDto(userPublicKey: var_userPublicKey, nodeId: var_nodeId, deviceName: var_deviceName, linkedAt: var_linkedAt, thisDevice: var_thisDevice); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BandwidthUsageDto> sse_decode_list_bandwidth_usage_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BandwidthUsageDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_bandwidth_usage_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BatchEntryDto> sse_decode_list_batch_entry_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BatchEntryDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_batch_entry_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ConflictDto> sse_decode_list_conflict_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ConflictDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_conflict_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DbEntryDto> sse_decode_list_db_entry_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DbEntryDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_db_entry_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DirectMessageDto> sse_decode_list_direct_message_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DirectMessageDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_direct_message_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FileOfferDto> sse_decode_list_file_offer_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FileOfferDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_file_offer_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GeoMatchDto> sse_decode_list_geo_match_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GeoMatchDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_geo_match_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HashEntryDto> sse_decode_list_hash_entry_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HashEntryDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_hash_entry_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IntegrityIssueDto> sse_decode_list_integrity_issue_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IntegrityIssueDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_integrity_issue_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LatencyBucketDto> sse_decode_list_latency_bucket_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LatencyBucketDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_latency_bucket_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LinkedDeviceDto> sse_decode_list_linked_device_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LinkedDeviceDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_linked_device_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LogEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_log_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MqttTopicMappingDto> sse_decode_list_mqtt_topic_mapping_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MqttTopicMappingDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mqtt_topic_mapping_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NodeEventRecordDto> sse_decode_list_node_event_record_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NodeEventRecordDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_node_event_record_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Uint8List?> sse_decode_list_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Uint8List?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerCircuitDto> sse_decode_list_peer_circuit_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerCircuitDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_circuit_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerDiagnosticsDto> sse_decode_list_peer_diagnostics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerDiagnosticsDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_diagnostics_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerInfoDto> sse_decode_list_peer_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerInfoDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_info_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerLatencyDto> sse_decode_list_peer_latency_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerLatencyDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_latency_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerPathDto> sse_decode_list_peer_path_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerPathDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_path_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PeerSyncStatusDto> sse_decode_list_peer_sync_status_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PeerSyncStatusDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_peer_sync_status_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PinnedPeerDto> sse_decode_list_pinned_peer_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PinnedPeerDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_pinned_peer_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<QueryFilterDto> sse_decode_list_query_filter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QueryFilterDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_filter_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RekeyedDatabaseDto> sse_decode_list_rekeyed_database_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RekeyedDatabaseDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_rekeyed_database_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ReplicaCheckDto> sse_decode_list_replica_check_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReplicaCheckDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_replica_check_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ScoredMemberDto> sse_decode_list_scored_member_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ScoredMemberDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_scored_member_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TimePointDto> sse_decode_list_time_point_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TimePointDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_time_point_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TopicHealthDto> sse_decode_list_topic_health_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TopicHealthDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_topic_health_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TopicMetricsDto> sse_decode_list_topic_metrics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TopicMetricsDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_topic_metrics_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TopicStatsDto> sse_decode_list_topic_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TopicStatsDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_topic_stats_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected LogEntry sse_decode_log_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestamp = sse_decode_i_64(deserializer);
+var var_level = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return LogEntry(timestamp: var_timestamp, level: var_level, message: var_message); }
+
+@protected LogFilterDto sse_decode_log_filter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_minLevel = sse_decode_opt_String(deserializer);
+var var_target = sse_decode_opt_String(deserializer);
+var var_contains = sse_decode_opt_String(deserializer);
+var var_since = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_until = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+return LogFilterDto(minLevel: var_minLevel, target: var_target, contains: var_contains, since: var_since, until: var_until, limit: var_limit); }
+
+@protected MetricsDto sse_decode_metrics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topics = sse_decode_list_topic_metrics_dto(deserializer);
+var var_opsApplied = sse_decode_u_64(deserializer);
+var var_verifyFailures = sse_decode_u_64(deserializer);
+var var_limitViolations = sse_decode_u_64(deserializer);
+var var_connectLatency = sse_decode_list_latency_bucket_dto(deserializer);
+var var_connectLatencySumMs = sse_decode_f_64(deserializer);
+var var_storageSizeBytes = sse_decode_u_64(deserializer);
+var var_storageKeys = sse_decode_u_64(deserializer);
+var var_storedOperations = sse_decode_u_64(deserializer);
+var var_outboxDepth = sse_decode_u_64(deserializer);
+return MetricsDto(topics: var_topics, opsApplied: var_opsApplied, verifyFailures: var_verifyFailures, limitViolations: var_limitViolations, connectLatency: var_connectLatency, connectLatencySumMs: var_connectLatencySumMs, storageSizeBytes: var_storageSizeBytes, storageKeys: var_storageKeys, storedOperations: var_storedOperations, outboxDepth: var_outboxDepth); }
+
+@protected MqttBridgeConfigDto sse_decode_mqtt_bridge_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_clientId = sse_decode_String(deserializer);
+var var_username = sse_decode_opt_String(deserializer);
+var var_password = sse_decode_opt_String(deserializer);
+var var_mappings = sse_decode_list_mqtt_topic_mapping_dto(deserializer);
+return MqttBridgeConfigDto(host: var_host, port: var_port, clientId: var_clientId, username: var_username, password: var_password, mappings: var_mappings); }
+
+@protected MqttDirectionDto sse_decode_mqtt_direction_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MqttDirectionDto.values[inner]; }
+
+@protected MqttTopicMappingDto sse_decode_mqtt_topic_mapping_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topicPrefix = sse_decode_String(deserializer);
+var var_dbName = sse_decode_String(deserializer);
+var var_direction = sse_decode_mqtt_direction_dto(deserializer);
+return MqttTopicMappingDto(topicPrefix: var_topicPrefix, dbName: var_dbName, direction: var_direction); }
+
+@protected NodeAddrDto sse_decode_node_addr_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_directAddresses = sse_decode_list_String(deserializer);
+var var_relayUrls = sse_decode_list_String(deserializer);
+return NodeAddrDto(nodeId: var_nodeId, directAddresses: var_directAddresses, relayUrls: var_relayUrls); }
+
+@protected NodeConfigDto sse_decode_node_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_region = sse_decode_opt_String(deserializer);
+var var_networkId = sse_decode_opt_String(deserializer);
+var var_maxKnownPeerDials = sse_decode_u_32(deserializer);
+var var_announceIntervalSecs = sse_decode_u_64(deserializer);
+var var_latencySweepIntervalSecs = sse_decode_u_64(deserializer);
+var var_pinnedPeersCheckIntervalSecs = sse_decode_u_64(deserializer);
+var var_isolationCheckIntervalSecs = sse_decode_u_64(deserializer);
+var var_isolationChecksBeforeReconnect = sse_decode_u_32(deserializer);
+var var_maxOpsPerResponse = sse_decode_u_32(deserializer);
+var var_maxConnectionAttemptsPerCycle = sse_decode_u_32(deserializer);
+var var_dialIntervalMs = sse_decode_u_64(deserializer);
+var var_relay = sse_decode_relay_config_dto(deserializer);
+var var_bootstrapManifest = sse_decode_opt_box_autoadd_bootstrap_manifest_dto(deserializer);
+var var_maxValueBytes = sse_decode_u_32(deserializer);
+var var_maxKeyLength = sse_decode_u_32(deserializer);
+var var_allowedStoreTypes = sse_decode_list_String(deserializer);
+var var_oplogGc = sse_decode_opt_box_autoadd_oplog_gc_dto(deserializer);
+var var_maxGossipMessageSize = sse_decode_u_32(deserializer);
+return NodeConfigDto(region: var_region, networkId: var_networkId, maxKnownPeerDials: var_maxKnownPeerDials, announceIntervalSecs: var_announceIntervalSecs, latencySweepIntervalSecs: var_latencySweepIntervalSecs, pinnedPeersCheckIntervalSecs: var_pinnedPeersCheckIntervalSecs, isolationCheckIntervalSecs: var_isolationCheckIntervalSecs, isolationChecksBeforeReconnect: var_isolationChecksBeforeReconnect, maxOpsPerResponse: var_maxOpsPerResponse, maxConnectionAttemptsPerCycle: var_maxConnectionAttemptsPerCycle, dialIntervalMs: var_dialIntervalMs, relay: var_relay, bootstrapManifest: var_bootstrapManifest, maxValueBytes: var_maxValueBytes, maxKeyLength: var_maxKeyLength, allowedStoreTypes: var_allowedStoreTypes, oplogGc: var_oplogGc, maxGossipMessageSize: var_maxGossipMessageSize); }
+
+@protected NodeEventDto sse_decode_node_event_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_nodeId = sse_decode_String(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+return NodeEventDto_Started(nodeId: var_nodeId, publicKey: var_publicKey);case 1: return NodeEventDto_Stopped();case 2: var var_peerId = sse_decode_String(deserializer);
+return NodeEventDto_PeerConnected(peerId: var_peerId);case 3: var var_peerId = sse_decode_String(deserializer);
+return NodeEventDto_PeerDisconnected(peerId: var_peerId);case 4: var var_peerId = sse_decode_String(deserializer);
+var var_address = sse_decode_opt_String(deserializer);
+return NodeEventDto_PeerDiscovered(peerId: var_peerId, address: var_address);case 5: var var_topic = sse_decode_String(deserializer);
+var var_from = sse_decode_String(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_verified = sse_decode_bool(deserializer);
+return NodeEventDto_GossipReceived(topic: var_topic, from: var_from, content: var_content, verified: var_verified);case 6: var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+return NodeEventDto_SyncReceived(dbName: var_dbName, key: var_key);case 7: var var_dbName = sse_decode_String(deserializer);
+var var_opsReceived = sse_decode_u_64(deserializer);
+var var_opsTotalEstimate = sse_decode_u_64(deserializer);
+var var_bytes = sse_decode_u_64(deserializer);
+return NodeEventDto_SyncProgress(dbName: var_dbName, opsReceived: var_opsReceived, opsTotalEstimate: var_opsTotalEstimate, bytes: var_bytes);case 8: var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_keptOpId = sse_decode_String(deserializer);
+var var_discardedOpId = sse_decode_String(deserializer);
+return NodeEventDto_ConflictResolved(dbName: var_dbName, key: var_key, keptOpId: var_keptOpId, discardedOpId: var_discardedOpId);case 9: var var_peerId = sse_decode_String(deserializer);
+var var_latencyMs = sse_decode_u_64(deserializer);
+return NodeEventDto_LatencyMeasured(peerId: var_peerId, latencyMs: var_latencyMs);case 10: var var_peerId = sse_decode_String(deserializer);
+var var_online = sse_decode_bool(deserializer);
+var var_status = sse_decode_opt_String(deserializer);
+return NodeEventDto_PresenceChanged(peerId: var_peerId, online: var_online, status: var_status);case 11: var var_topic = sse_decode_String(deserializer);
+return NodeEventDto_TopicRecovered(topic: var_topic);case 12: var var_relayUrl = sse_decode_String(deserializer);
+return NodeEventDto_RelayConnected(relayUrl: var_relayUrl);case 13: var var_peerId = sse_decode_String(deserializer);
+return NodeEventDto_BootstrapConnected(peerId: var_peerId);case 14: var var_oldNodeId = sse_decode_String(deserializer);
+var var_newNodeId = sse_decode_String(deserializer);
+return NodeEventDto_IdentityRotated(oldNodeId: var_oldNodeId, newNodeId: var_newNodeId);case 15: var var_userPublicKey = sse_decode_String(deserializer);
+var var_deviceNodeId = sse_decode_String(deserializer);
+var var_deviceName = sse_decode_String(deserializer);
+return NodeEventDto_DeviceLinked(userPublicKey: var_userPublicKey, deviceNodeId: var_deviceNodeId, deviceName: var_deviceName);case 16: var var_state = sse_decode_connectivity_state_dto(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return NodeEventDto_ConnectivityChanged(state: var_state, reason: var_reason);case 17: var var_message = sse_decode_String(deserializer);
+return NodeEventDto_Error(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected NodeEventRecordDto sse_decode_node_event_record_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_seq = sse_decode_u_64(deserializer);
+var var_timestamp = sse_decode_i_64(deserializer);
+var var_event = sse_decode_node_event_dto(deserializer);
+return NodeEventRecordDto(seq: var_seq, timestamp: var_timestamp, event: var_event); }
+
+@protected NodeInfo sse_decode_node_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+var var_isRunning = sse_decode_bool(deserializer);
+return NodeInfo(nodeId: var_nodeId, publicKey: var_publicKey, isRunning: var_isRunning); }
+
+@protected NodeInstanceDto sse_decode_node_instance_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_handle = sse_decode_u_32(deserializer);
+var var_nodeId = sse_decode_String(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+return NodeInstanceDto(handle: var_handle, nodeId: var_nodeId, publicKey: var_publicKey); }
+
+@protected NodeStatsDto sse_decode_node_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_uptimeSeconds = sse_decode_u_64(deserializer);
+var var_totalOperations = sse_decode_u_64(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+var var_connects = sse_decode_u_64(deserializer);
+var var_reconnects = sse_decode_u_64(deserializer);
+var var_lifetimeSessions = sse_decode_u_64(deserializer);
+var var_lifetimeUptimeSeconds = sse_decode_u_64(deserializer);
+var var_lifetimeBytesSent = sse_decode_u_64(deserializer);
+var var_lifetimeBytesReceived = sse_decode_u_64(deserializer);
+var var_lifetimeConnects = sse_decode_u_64(deserializer);
+var var_lifetimeReconnects = sse_decode_u_64(deserializer);
+var var_outboxDepth = sse_decode_u_64(deserializer);
+return NodeStatsDto(uptimeSeconds: var_uptimeSeconds, totalOperations: var_totalOperations, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived, connects: var_connects, reconnects: var_reconnects, lifetimeSessions: var_lifetimeSessions, lifetimeUptimeSeconds: var_lifetimeUptimeSeconds, lifetimeBytesSent: var_lifetimeBytesSent, lifetimeBytesReceived: var_lifetimeBytesReceived, lifetimeConnects: var_lifetimeConnects, lifetimeReconnects: var_lifetimeReconnects, outboxDepth: var_outboxDepth); }
+
+@protected NodeStatusDto sse_decode_node_status_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_isRunning = sse_decode_bool(deserializer);
+var var_nodeId = sse_decode_opt_String(deserializer);
+var var_connectedPeers = sse_decode_u_32(deserializer);
+var var_discoveredPeers = sse_decode_u_32(deserializer);
+var var_uptimeSeconds = sse_decode_u_64(deserializer);
+var var_gossipMessagesReceived = sse_decode_u_64(deserializer);
+var var_storageSizeBytes = sse_decode_u_64(deserializer);
+var var_totalKeys = sse_decode_u_64(deserializer);
+var var_syncOperations = sse_decode_u_32(deserializer);
+var var_latencyRequestsSent = sse_decode_u_64(deserializer);
+var var_latencyResponsesReceived = sse_decode_u_64(deserializer);
+var var_topics = sse_decode_list_topic_stats_dto(deserializer);
+return NodeStatusDto(isRunning: var_isRunning, nodeId: var_nodeId, connectedPeers: var_connectedPeers, discoveredPeers: var_discoveredPeers, uptimeSeconds: var_uptimeSeconds, gossipMessagesReceived: var_gossipMessagesReceived, storageSizeBytes: var_storageSizeBytes, totalKeys: var_totalKeys, syncOperations: var_syncOperations, latencyRequestsSent: var_latencyRequestsSent, latencyResponsesReceived: var_latencyResponsesReceived, topics: var_topics); }
+
+@protected OplogGcDto sse_decode_oplog_gc_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_keepVersions = sse_decode_u_32(deserializer);
+var var_keepForSecs = sse_decode_u_64(deserializer);
+return OplogGcDto(keepVersions: var_keepVersions, keepForSecs: var_keepForSecs); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BootstrapManifestDto? sse_decode_opt_box_autoadd_bootstrap_manifest_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bootstrap_manifest_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MqttBridgeConfigDto? sse_decode_opt_box_autoadd_mqtt_bridge_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_mqtt_bridge_config_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NodeInfo? sse_decode_opt_box_autoadd_node_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_node_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OplogGcDto? sse_decode_opt_box_autoadd_oplog_gc_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_oplog_gc_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PeerPathDto? sse_decode_opt_box_autoadd_peer_path_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_peer_path_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PresenceDto? sse_decode_opt_box_autoadd_presence_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_presence_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SuccessorRecordDto? sse_decode_opt_box_autoadd_successor_record_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_successor_record_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PeerCircuitDto sse_decode_peer_circuit_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_state = sse_decode_String(deserializer);
+var var_unreachable = sse_decode_bool(deserializer);
+var var_consecutiveFailures = sse_decode_u_32(deserializer);
+var var_lastFailureAt = sse_decode_i_64(deserializer);
+var var_retryAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return PeerCircuitDto(nodeId: var_nodeId, state: var_state, unreachable: var_unreachable, consecutiveFailures: var_consecutiveFailures, lastFailureAt: var_lastFailureAt, retryAt: var_retryAt); }
+
+@protected PeerDiagnosticsDto sse_decode_peer_diagnostics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_path = sse_decode_opt_box_autoadd_peer_path_dto(deserializer);
+var var_directEstablished = sse_decode_bool(deserializer);
+var var_dialAttempts = sse_decode_u_32(deserializer);
+var var_dialFailures = sse_decode_u_32(deserializer);
+var var_lastDialAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+return PeerDiagnosticsDto(nodeId: var_nodeId, path: var_path, directEstablished: var_directEstablished, dialAttempts: var_dialAttempts, dialFailures: var_dialFailures, lastDialAt: var_lastDialAt, lastError: var_lastError); }
+
+@protected PeerInfoDto sse_decode_peer_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+var var_address = sse_decode_opt_String(deserializer);
+var var_region = sse_decode_opt_String(deserializer);
+var var_version = sse_decode_opt_String(deserializer);
+var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_isMobile = sse_decode_bool(deserializer);
+return PeerInfoDto(nodeId: var_nodeId, publicKey: var_publicKey, address: var_address, region: var_region, version: var_version, latencyMs: var_latencyMs, isMobile: var_isMobile); }
+
+@protected PeerLatencyDto sse_decode_peer_latency_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_averageMs = sse_decode_f_64(deserializer);
+var var_jitterMs = sse_decode_f_64(deserializer);
+var var_samples = sse_decode_u_32(deserializer);
+return PeerLatencyDto(nodeId: var_nodeId, latencyMs: var_latencyMs, averageMs: var_averageMs, jitterMs: var_jitterMs, samples: var_samples); }
+
+@protected PeerPathDto sse_decode_peer_path_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_isMixed = sse_decode_bool(deserializer);
+var var_directAddrs = sse_decode_list_String(deserializer);
+var var_relayUrl = sse_decode_opt_String(deserializer);
+var var_updatedAt = sse_decode_i_64(deserializer);
+return PeerPathDto(nodeId: var_nodeId, path: var_path, isMixed: var_isMixed, directAddrs: var_directAddrs, relayUrl: var_relayUrl, updatedAt: var_updatedAt); }
+
+@protected PeerSyncStatusDto sse_decode_peer_sync_status_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_peerId = sse_decode_String(deserializer);
+var var_opsSent = sse_decode_u_64(deserializer);
+var var_opsReceived = sse_decode_u_64(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+var var_lastRequestAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastResponseAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_inProgress = sse_decode_bool(deserializer);
+var var_opsAcked = sse_decode_u_64(deserializer);
+var var_lastAckAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return PeerSyncStatusDto(peerId: var_peerId, opsSent: var_opsSent, opsReceived: var_opsReceived, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived, lastRequestAt: var_lastRequestAt, lastResponseAt: var_lastResponseAt, inProgress: var_inProgress, opsAcked: var_opsAcked, lastAckAt: var_lastAckAt); }
+
+@protected PinnedPeerDto sse_decode_pinned_peer_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_address = sse_decode_opt_String(deserializer);
+return PinnedPeerDto(nodeId: var_nodeId, address: var_address); }
+
+@protected PowerModeDto sse_decode_power_mode_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PowerModeDto.values[inner]; }
+
+@protected PowerPolicyDto sse_decode_power_policy_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_lowPower = sse_decode_bool(deserializer);
+var var_metered = sse_decode_bool(deserializer);
+var var_syncDeferred = sse_decode_bool(deserializer);
+var var_blobTransfersAllowed = sse_decode_bool(deserializer);
+return PowerPolicyDto(lowPower: var_lowPower, metered: var_metered, syncDeferred: var_syncDeferred, blobTransfersAllowed: var_blobTransfersAllowed); }
+
+@protected PresenceDto sse_decode_presence_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_peerId = sse_decode_String(deserializer);
+var var_online = sse_decode_bool(deserializer);
+var var_status = sse_decode_opt_String(deserializer);
+var var_lastSeen = sse_decode_i_64(deserializer);
+return PresenceDto(peerId: var_peerId, online: var_online, status: var_status, lastSeen: var_lastSeen); }
+
+@protected QueryFilterDto sse_decode_query_filter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field = sse_decode_String(deserializer);
+var var_op = sse_decode_String(deserializer);
+var var_valueJson = sse_decode_String(deserializer);
+return QueryFilterDto(field: var_field, op: var_op, valueJson: var_valueJson); }
+
+@protected QueryResultDto sse_decode_query_result_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_entries = sse_decode_list_db_entry_dto(deserializer);
+var var_hasMore = sse_decode_bool(deserializer);
+return QueryResultDto(entries: var_entries, hasMore: var_hasMore); }
+
+@protected QuerySpecDto sse_decode_query_spec_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_keyPrefix = sse_decode_opt_String(deserializer);
+var var_filters = sse_decode_list_query_filter_dto(deserializer);
+var var_sortBy = sse_decode_opt_String(deserializer);
+var var_descending = sse_decode_bool(deserializer);
+var var_limit = sse_decode_u_32(deserializer);
+var var_offset = sse_decode_u_32(deserializer);
+var var_fields = sse_decode_opt_list_String(deserializer);
+return QuerySpecDto(keyPrefix: var_keyPrefix, filters: var_filters, sortBy: var_sortBy, descending: var_descending, limit: var_limit, offset: var_offset, fields: var_fields); }
+
+@protected RekeyedDatabaseDto sse_decode_rekeyed_database_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_oldName = sse_decode_String(deserializer);
+var var_newName = sse_decode_String(deserializer);
+return RekeyedDatabaseDto(oldName: var_oldName, newName: var_newName); }
+
+@protected RelayConfigDto sse_decode_relay_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return RelayConfigDto_Default();case 1: return RelayConfigDto_Disabled();case 2: var var_urls = sse_decode_list_String(deserializer);
+return RelayConfigDto_Custom(urls: var_urls); default: throw UnimplementedError(''); }
+             }
+
+@protected RelayStatusDto sse_decode_relay_status_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relay = sse_decode_relay_config_dto(deserializer);
+var var_activeUrl = sse_decode_opt_String(deserializer);
+var var_rttMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return RelayStatusDto(relay: var_relay, activeUrl: var_activeUrl, rttMs: var_rttMs); }
+
+@protected ReplicaCheckDto sse_decode_replica_check_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_publisher = sse_decode_String(deserializer);
+var var_matches = sse_decode_bool(deserializer);
+var var_mismatchedBuckets = sse_decode_u_32(deserializer);
+var var_checkedAt = sse_decode_i_64(deserializer);
+return ReplicaCheckDto(dbName: var_dbName, publisher: var_publisher, matches: var_matches, mismatchedBuckets: var_mismatchedBuckets, checkedAt: var_checkedAt); }
+
+@protected RpcRequestDto sse_decode_rpc_request_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_requestId = sse_decode_u_64(deserializer);
+var var_peerId = sse_decode_String(deserializer);
+var var_method = sse_decode_String(deserializer);
+var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+return RpcRequestDto(requestId: var_requestId, peerId: var_peerId, method: var_method, payload: var_payload); }
+
+@protected ScanPageDto sse_decode_scan_page_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_entries = sse_decode_list_db_entry_dto(deserializer);
+var var_nextCursor = sse_decode_opt_String(deserializer);
+return ScanPageDto(entries: var_entries, nextCursor: var_nextCursor); }
+
+@protected ScoredMemberDto sse_decode_scored_member_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_member = sse_decode_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+return ScoredMemberDto(member: var_member, score: var_score); }
+
+@protected StartupStageDto sse_decode_startup_stage_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return StartupStageDto_KeyLoaded();case 1: var var_relayConnected = sse_decode_bool(deserializer);
+return StartupStageDto_EndpointOnline(relayConnected: var_relayConnected);case 2: var var_attempt = sse_decode_u_32(deserializer);
+return StartupStageDto_BootstrapConnecting(attempt: var_attempt);case 3: return StartupStageDto_TopicsSubscribed();case 4: return StartupStageDto_Ready(); default: throw UnimplementedError(''); }
+             }
+
+@protected SubsystemHealthDto sse_decode_subsystem_health_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_status = sse_decode_health_status_dto(deserializer);
+var var_reason = sse_decode_String(deserializer);
+return SubsystemHealthDto(status: var_status, reason: var_reason); }
+
+@protected SuccessorRecordDto sse_decode_successor_record_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_oldNodeId = sse_decode_String(deserializer);
+var var_newNodeId = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_i_64(deserializer);
+var var_graceUntil = sse_decode_i_64(deserializer);
+return SuccessorRecordDto(oldNodeId: var_oldNodeId, newNodeId: var_newNodeId, timestamp: var_timestamp, graceUntil: var_graceUntil); }
+
+@protected SyncStatsDto sse_decode_sync_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalOperations = sse_decode_u_64(deserializer);
+var var_opsSent = sse_decode_u_64(deserializer);
+var var_opsReceived = sse_decode_u_64(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+var var_lastSyncAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_peers = sse_decode_list_peer_sync_status_dto(deserializer);
+return SyncStatsDto(totalOperations: var_totalOperations, opsSent: var_opsSent, opsReceived: var_opsReceived, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived, lastSyncAt: var_lastSyncAt, peers: var_peers); }
+
+@protected SyncWaitSummaryDto sse_decode_sync_wait_summary_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_synced = sse_decode_bool(deserializer);
+var var_queuedOps = sse_decode_u_32(deserializer);
+var var_unacknowledgedOps = sse_decode_u_32(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+return SyncWaitSummaryDto(synced: var_synced, queuedOps: var_queuedOps, unacknowledgedOps: var_unacknowledgedOps, durationMs: var_durationMs); }
+
+@protected TextDeltaDto sse_decode_text_delta_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_index = sse_decode_u_64(deserializer);
+var var_deleted = sse_decode_u_64(deserializer);
+var var_inserted = sse_decode_String(deserializer);
+return TextDeltaDto(dbName: var_dbName, key: var_key, index: var_index, deleted: var_deleted, inserted: var_inserted); }
+
+@protected TimePointDto sse_decode_time_point_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestamp = sse_decode_i_64(deserializer);
+var var_value = sse_decode_f_64(deserializer);
+return TimePointDto(timestamp: var_timestamp, value: var_value); }
+
+@protected TopicHealthDto sse_decode_topic_health_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topic = sse_decode_String(deserializer);
+var var_subscribed = sse_decode_bool(deserializer);
+var var_neighbors = sse_decode_u_32(deserializer);
+return TopicHealthDto(topic: var_topic, subscribed: var_subscribed, neighbors: var_neighbors); }
+
+@protected TopicMessageDto sse_decode_topic_message_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topic = sse_decode_String(deserializer);
+var var_deliveredFrom = sse_decode_String(deserializer);
+var var_content = sse_decode_list_prim_u_8_strict(deserializer);
+return TopicMessageDto(topic: var_topic, deliveredFrom: var_deliveredFrom, content: var_content); }
+
+@protected TopicMetricsDto sse_decode_topic_metrics_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topic = sse_decode_String(deserializer);
+var var_messagesSent = sse_decode_u_64(deserializer);
+var var_messagesReceived = sse_decode_u_64(deserializer);
+var var_duplicatesDropped = sse_decode_u_64(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+return TopicMetricsDto(topic: var_topic, messagesSent: var_messagesSent, messagesReceived: var_messagesReceived, duplicatesDropped: var_duplicatesDropped, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived); }
+
+@protected TopicStatsDto sse_decode_topic_stats_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_topic = sse_decode_String(deserializer);
+var var_messagesSent = sse_decode_u_64(deserializer);
+var var_messagesReceived = sse_decode_u_64(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+var var_neighbors = sse_decode_u_32(deserializer);
+var var_lastEventAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return TopicStatsDto(topic: var_topic, messagesSent: var_messagesSent, messagesReceived: var_messagesReceived, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived, neighbors: var_neighbors, lastEventAt: var_lastEventAt); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected ValueUpdateDto sse_decode_value_update_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dbName = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_opt_String(deserializer);
+var var_valueBytes = sse_decode_opt_list_prim_u_8_strict(deserializer);
+return ValueUpdateDto(dbName: var_dbName, key: var_key, value: var_value, valueBytes: var_valueBytes); }
+
+@protected VerifiedBindingDto sse_decode_verified_binding_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nodeId = sse_decode_String(deserializer);
+var var_publicKey = sse_decode_String(deserializer);
+var var_verifiedAt = sse_decode_i_64(deserializer);
+return VerifiedBindingDto(nodeId: var_nodeId, publicKey: var_publicKey, verifiedAt: var_verifiedAt); }
+
+@protected WakeSyncSummaryDto sse_decode_wake_sync_summary_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startedNode = sse_decode_bool(deserializer);
+var var_pinnedPeersDialed = sse_decode_u_32(deserializer);
+var var_pinnedPeersConnected = sse_decode_u_32(deserializer);
+var var_synced = sse_decode_bool(deserializer);
+var var_opsReceived = sse_decode_u_64(deserializer);
+var var_opsSent = sse_decode_u_64(deserializer);
+var var_outboxSent = sse_decode_u_32(deserializer);
+var var_outboxRemaining = sse_decode_u_32(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+return WakeSyncSummaryDto(startedNode: var_startedNode, pinnedPeersDialed: var_pinnedPeersDialed, pinnedPeersConnected: var_pinnedPeersConnected, synced: var_synced, opsReceived: var_opsReceived, opsSent: var_opsSent, outboxSent: var_outboxSent, outboxRemaining: var_outboxRemaining, durationMs: var_durationMs); }
+
+@protected int cst_encode_bandwidth_subsystem_dto(BandwidthSubsystemDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected bool cst_encode_bool(bool raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_connection_type_dto(ConnectionTypeDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_connectivity_state_dto(ConnectivityStateDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected double cst_encode_f_64(double raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_health_status_dto(HealthStatusDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_i_32(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_mqtt_direction_dto(MqttDirectionDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_power_mode_dto(PowerModeDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_32(raw.index); }
+
+@protected int cst_encode_u_16(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_u_32(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected int cst_encode_u_8(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected void cst_encode_unit(void raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_change_event_dto_Dco(RustStreamSink<ChangeEventDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_change_event_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_direct_message_dto_Dco(RustStreamSink<DirectMessageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_direct_message_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_file_event_dto_Dco(RustStreamSink<FileEventDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_file_event_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_key_change_dto_Dco(RustStreamSink<KeyChangeDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_key_change_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_node_event_record_dto_Dco(RustStreamSink<NodeEventRecordDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_node_event_record_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_rpc_request_dto_Dco(RustStreamSink<RpcRequestDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_rpc_request_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_startup_stage_dto_Dco(RustStreamSink<StartupStageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_startup_stage_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_text_delta_dto_Dco(RustStreamSink<TextDeltaDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_text_delta_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_topic_message_dto_Dco(RustStreamSink<TopicMessageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_topic_message_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_value_update_dto_Dco(RustStreamSink<ValueUpdateDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_value_update_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_accepted_invite_dto(AcceptedInviteDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.owner, serializer);
+sse_encode_bool(self.canWrite, serializer);
+sse_encode_opt_String(self.writerSecretKey, serializer);
+sse_encode_bool(self.encrypted, serializer);
+ }
+
+@protected void sse_encode_backup_info_dto(BackupInfoDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_String(self.publicKey, serializer);
+sse_encode_u_32(self.treeCount, serializer);
+sse_encode_u_64(self.entryCount, serializer);
+ }
+
+@protected void sse_encode_bandwidth_caps_dto(BandwidthCapsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_64(self.dailyBytes, serializer);
+sse_encode_opt_box_autoadd_u_64(self.monthlyBytes, serializer);
+sse_encode_bool(self.meteredOnly, serializer);
+ }
+
+@protected void sse_encode_bandwidth_stats_dto(BandwidthStatsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_bandwidth_usage_dto(self.topics, serializer);
+sse_encode_list_bandwidth_usage_dto(self.subsystems, serializer);
+sse_encode_u_64(self.todayBytes, serializer);
+sse_encode_u_64(self.monthBytes, serializer);
+sse_encode_bandwidth_caps_dto(self.caps, serializer);
+sse_encode_bool(self.capExceeded, serializer);
+ }
+
+@protected void sse_encode_bandwidth_subsystem_dto(BandwidthSubsystemDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bandwidth_usage_dto(BandwidthUsageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_bandwidth_subsystem_dto(self.subsystem, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+ }
+
+@protected void sse_encode_batch_entry_dto(BatchEntryDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_list_prim_u_8_strict(self.value, serializer);
+sse_encode_String(self.publicKey, serializer);
+sse_encode_String(self.signature, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_bootstrap_manifest_dto(BootstrapManifestDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.publisherKey, serializer);
+ }
+
+@protected void sse_encode_box_autoadd_bandwidth_caps_dto(BandwidthCapsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bandwidth_caps_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bootstrap_manifest_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_device_conditions_dto(DeviceConditionsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_device_conditions_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_file_offer_dto(FileOfferDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_file_offer_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_log_filter_dto(LogFilterDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_log_filter_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_mqtt_bridge_config_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_node_config_dto(NodeConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_node_config_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_node_info(NodeInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_node_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_oplog_gc_dto(OplogGcDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_oplog_gc_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_peer_path_dto(PeerPathDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_peer_path_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_presence_dto(PresenceDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_presence_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_query_spec_dto(QuerySpecDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query_spec_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_successor_record_dto(SuccessorRecordDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_successor_record_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_change_event_dto(ChangeEventDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.seq, serializer);
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_opt_String(self.value, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.valueBytes, serializer);
+ }
+
+@protected void sse_encode_checkpoint_dto(CheckpointDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_64(self.highWaterMark, serializer);
+sse_encode_u_32(self.knownPeersSaved, serializer);
+sse_encode_u_32(self.outboxDepth, serializer);
+sse_encode_bool(self.flushed, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_conflict_dto(ConflictDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.conflictKey, serializer);
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_opt_String(self.field, serializer);
+sse_encode_String(self.keptOpId, serializer);
+sse_encode_String(self.discardedOpId, serializer);
+sse_encode_String(self.discardedValue, serializer);
+sse_encode_i_64(self.discardedTimestamp, serializer);
+sse_encode_i_64(self.resolvedAt, serializer);
+ }
+
+@protected void sse_encode_connection_diagnostics_dto(ConnectionDiagnosticsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.localAddrs, serializer);
+sse_encode_list_String(self.publicAddrs, serializer);
+sse_encode_opt_String(self.relayUrl, serializer);
+sse_encode_String(self.natType, serializer);
+sse_encode_list_peer_diagnostics_dto(self.peers, serializer);
+ }
+
+@protected void sse_encode_connection_result_dto(ConnectionResultDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.handshakeMs, serializer);
+ }
+
+@protected void sse_encode_connection_type_dto(ConnectionTypeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_connectivity_dto(ConnectivityDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_connectivity_state_dto(self.state, serializer);
+sse_encode_String(self.reason, serializer);
+ }
+
+@protected void sse_encode_connectivity_state_dto(ConnectivityStateDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_db_entry_dto(DbEntryDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.value, serializer);
+sse_encode_list_prim_u_8_strict(self.valueBytes, serializer);
+ }
+
+@protected void sse_encode_db_sync_info_dto(DbSyncInfoDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_u_64(self.opCount, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastLocalWrite, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastRemoteOp, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastSync, serializer);
+ }
+
+@protected void sse_encode_debug_server_dto(DebugServerDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.address, serializer);
+sse_encode_String(self.token, serializer);
+ }
+
+@protected void sse_encode_device_conditions_dto(DeviceConditionsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_8(self.batteryPercent, serializer);
+sse_encode_bool(self.charging, serializer);
+sse_encode_connection_type_dto(self.connectionType, serializer);
+sse_encode_bool(self.metered, serializer);
+ }
+
+@protected void sse_encode_direct_message_dto(DirectMessageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.peerId, serializer);
+sse_encode_bool(self.outgoing, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_i_64(self.sentAt, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_file_event_dto(FileEventDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FileEventDto_Offer(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_file_offer_dto(field0, serializer);
+case FileEventDto_Progress(offerId: final offerId,receivedBytes: final receivedBytes,totalBytes: final totalBytes): sse_encode_i_32(1, serializer); sse_encode_String(offerId, serializer);
+sse_encode_u_64(receivedBytes, serializer);
+sse_encode_u_64(totalBytes, serializer);
+case FileEventDto_Retrying(offerId: final offerId,attempt: final attempt,error: final error): sse_encode_i_32(2, serializer); sse_encode_String(offerId, serializer);
+sse_encode_u_32(attempt, serializer);
+sse_encode_String(error, serializer);
+case FileEventDto_Completed(offerId: final offerId,path: final path): sse_encode_i_32(3, serializer); sse_encode_String(offerId, serializer);
+sse_encode_String(path, serializer);
+case FileEventDto_Failed(offerId: final offerId,error: final error): sse_encode_i_32(4, serializer); sse_encode_String(offerId, serializer);
+sse_encode_String(error, serializer);
+  } }
+
+@protected void sse_encode_file_offer_dto(FileOfferDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.offerId, serializer);
+sse_encode_String(self.from, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_i_64(self.offeredAt, serializer);
+ }
+
+@protected void sse_encode_geo_match_dto(GeoMatchDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.member, serializer);
+sse_encode_f_64(self.latitude, serializer);
+sse_encode_f_64(self.longitude, serializer);
+sse_encode_f_64(self.distanceM, serializer);
+ }
+
+@protected void sse_encode_hash_entry_dto(HashEntryDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.field, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_health_report_dto(HealthReportDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_subsystem_health_dto(self.storage, serializer);
+sse_encode_subsystem_health_dto(self.gossip, serializer);
+sse_encode_subsystem_health_dto(self.sync_, serializer);
+sse_encode_subsystem_health_dto(self.discovery, serializer);
+sse_encode_subsystem_health_dto(self.relay, serializer);
+sse_encode_list_topic_health_dto(self.topics, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastFlushAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastSyncAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastAnnouncementAt, serializer);
+ }
+
+@protected void sse_encode_health_status_dto(HealthStatusDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_identity_rotation_dto(IdentityRotationDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_node_info(self.node, serializer);
+sse_encode_successor_record_dto(self.record, serializer);
+sse_encode_list_rekeyed_database_dto(self.rekeyedDatabases, serializer);
+sse_encode_u_32(self.rekeyedOperations, serializer);
+ }
+
+@protected void sse_encode_integrity_issue_dto(IntegrityIssueDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.opId, serializer);
+sse_encode_String(self.kind, serializer);
+ }
+
+@protected void sse_encode_integrity_report_dto(IntegrityReportDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.checked, serializer);
+sse_encode_list_integrity_issue_dto(self.issues, serializer);
+sse_encode_u_64(self.repaired, serializer);
+ }
+
+@protected void sse_encode_kadena_transaction_dto(KadenaTransactionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.hash, serializer);
+sse_encode_String(self.sig, serializer);
+sse_encode_String(self.cmd, serializer);
+sse_encode_String(self.json, serializer);
+ }
+
+@protected void sse_encode_key_change_dto(KeyChangeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_bool(self.deleted, serializer);
+ }
+
+@protected void sse_encode_key_pair_dto(KeyPairDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.publicKey, serializer);
+sse_encode_String(self.secretKey, serializer);
+ }
+
+@protected void sse_encode_latency_bucket_dto(LatencyBucketDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.leMs, serializer);
+sse_encode_u_64(self.count, serializer);
+ }
+
+@protected void sse_encode_linked_device_dto(LinkedDeviceDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userPublicKey, serializer);
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.deviceName, serializer);
+sse_encode_i_64(self.linkedAt, serializer);
+sse_encode_bool(self.thisDevice, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_bandwidth_usage_dto(List<BandwidthUsageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_bandwidth_usage_dto(item, serializer); } }
+
+@protected void sse_encode_list_batch_entry_dto(List<BatchEntryDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_batch_entry_dto(item, serializer); } }
+
+@protected void sse_encode_list_conflict_dto(List<ConflictDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_conflict_dto(item, serializer); } }
+
+@protected void sse_encode_list_db_entry_dto(List<DbEntryDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_db_entry_dto(item, serializer); } }
+
+@protected void sse_encode_list_direct_message_dto(List<DirectMessageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_direct_message_dto(item, serializer); } }
+
+@protected void sse_encode_list_file_offer_dto(List<FileOfferDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_file_offer_dto(item, serializer); } }
+
+@protected void sse_encode_list_geo_match_dto(List<GeoMatchDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_geo_match_dto(item, serializer); } }
+
+@protected void sse_encode_list_hash_entry_dto(List<HashEntryDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_hash_entry_dto(item, serializer); } }
+
+@protected void sse_encode_list_integrity_issue_dto(List<IntegrityIssueDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_integrity_issue_dto(item, serializer); } }
+
+@protected void sse_encode_list_latency_bucket_dto(List<LatencyBucketDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_latency_bucket_dto(item, serializer); } }
+
+@protected void sse_encode_list_linked_device_dto(List<LinkedDeviceDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_linked_device_dto(item, serializer); } }
+
+@protected void sse_encode_list_log_entry(List<LogEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_log_entry(item, serializer); } }
+
+@protected void sse_encode_list_mqtt_topic_mapping_dto(List<MqttTopicMappingDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mqtt_topic_mapping_dto(item, serializer); } }
+
+@protected void sse_encode_list_node_event_record_dto(List<NodeEventRecordDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_node_event_record_dto(item, serializer); } }
+
+@protected void sse_encode_list_opt_list_prim_u_8_strict(List<Uint8List?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_list_peer_circuit_dto(List<PeerCircuitDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_circuit_dto(item, serializer); } }
+
+@protected void sse_encode_list_peer_diagnostics_dto(List<PeerDiagnosticsDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_diagnostics_dto(item, serializer); } }
+
+@protected void sse_encode_list_peer_info_dto(List<PeerInfoDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_info_dto(item, serializer); } }
+
+@protected void sse_encode_list_peer_latency_dto(List<PeerLatencyDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_latency_dto(item, serializer); } }
+
+@protected void sse_encode_list_peer_path_dto(List<PeerPathDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_path_dto(item, serializer); } }
+
+@protected void sse_encode_list_peer_sync_status_dto(List<PeerSyncStatusDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_peer_sync_status_dto(item, serializer); } }
+
+@protected void sse_encode_list_pinned_peer_dto(List<PinnedPeerDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_pinned_peer_dto(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_query_filter_dto(List<QueryFilterDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_filter_dto(item, serializer); } }
+
+@protected void sse_encode_list_rekeyed_database_dto(List<RekeyedDatabaseDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_rekeyed_database_dto(item, serializer); } }
+
+@protected void sse_encode_list_replica_check_dto(List<ReplicaCheckDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_replica_check_dto(item, serializer); } }
+
+@protected void sse_encode_list_scored_member_dto(List<ScoredMemberDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_scored_member_dto(item, serializer); } }
+
+@protected void sse_encode_list_time_point_dto(List<TimePointDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_time_point_dto(item, serializer); } }
+
+@protected void sse_encode_list_topic_health_dto(List<TopicHealthDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_topic_health_dto(item, serializer); } }
+
+@protected void sse_encode_list_topic_metrics_dto(List<TopicMetricsDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_topic_metrics_dto(item, serializer); } }
+
+@protected void sse_encode_list_topic_stats_dto(List<TopicStatsDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_topic_stats_dto(item, serializer); } }
+
+@protected void sse_encode_log_entry(LogEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.timestamp, serializer);
+sse_encode_String(self.level, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_log_filter_dto(LogFilterDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.minLevel, serializer);
+sse_encode_opt_String(self.target, serializer);
+sse_encode_opt_String(self.contains, serializer);
+sse_encode_opt_box_autoadd_i_64(self.since, serializer);
+sse_encode_opt_box_autoadd_i_64(self.until, serializer);
+sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
+ }
+
+@protected void sse_encode_metrics_dto(MetricsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_topic_metrics_dto(self.topics, serializer);
+sse_encode_u_64(self.opsApplied, serializer);
+sse_encode_u_64(self.verifyFailures, serializer);
+sse_encode_u_64(self.limitViolations, serializer);
+sse_encode_list_latency_bucket_dto(self.connectLatency, serializer);
+sse_encode_f_64(self.connectLatencySumMs, serializer);
+sse_encode_u_64(self.storageSizeBytes, serializer);
+sse_encode_u_64(self.storageKeys, serializer);
+sse_encode_u_64(self.storedOperations, serializer);
+sse_encode_u_64(self.outboxDepth, serializer);
+ }
+
+@protected void sse_encode_mqtt_bridge_config_dto(MqttBridgeConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.host, serializer);
+sse_encode_opt_box_autoadd_u_16(self.port, serializer);
+sse_encode_String(self.clientId, serializer);
+sse_encode_opt_String(self.username, serializer);
+sse_encode_opt_String(self.password, serializer);
+sse_encode_list_mqtt_topic_mapping_dto(self.mappings, serializer);
+ }
+
+@protected void sse_encode_mqtt_direction_dto(MqttDirectionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_mqtt_topic_mapping_dto(MqttTopicMappingDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.topicPrefix, serializer);
+sse_encode_String(self.dbName, serializer);
+sse_encode_mqtt_direction_dto(self.direction, serializer);
+ }
+
+@protected void sse_encode_node_addr_dto(NodeAddrDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_list_String(self.directAddresses, serializer);
+sse_encode_list_String(self.relayUrls, serializer);
+ }
+
+@protected void sse_encode_node_config_dto(NodeConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.region, serializer);
+sse_encode_opt_String(self.networkId, serializer);
+sse_encode_u_32(self.maxKnownPeerDials, serializer);
+sse_encode_u_64(self.announceIntervalSecs, serializer);
+sse_encode_u_64(self.latencySweepIntervalSecs, serializer);
+sse_encode_u_64(self.pinnedPeersCheckIntervalSecs, serializer);
+sse_encode_u_64(self.isolationCheckIntervalSecs, serializer);
+sse_encode_u_32(self.isolationChecksBeforeReconnect, serializer);
+sse_encode_u_32(self.maxOpsPerResponse, serializer);
+sse_encode_u_32(self.maxConnectionAttemptsPerCycle, serializer);
+sse_encode_u_64(self.dialIntervalMs, serializer);
+sse_encode_relay_config_dto(self.relay, serializer);
+sse_encode_opt_box_autoadd_bootstrap_manifest_dto(self.bootstrapManifest, serializer);
+sse_encode_u_32(self.maxValueBytes, serializer);
+sse_encode_u_32(self.maxKeyLength, serializer);
+sse_encode_list_String(self.allowedStoreTypes, serializer);
+sse_encode_opt_box_autoadd_oplog_gc_dto(self.oplogGc, serializer);
+sse_encode_u_32(self.maxGossipMessageSize, serializer);
+ }
+
+@protected void sse_encode_node_event_dto(NodeEventDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case NodeEventDto_Started(nodeId: final nodeId,publicKey: final publicKey): sse_encode_i_32(0, serializer); sse_encode_String(nodeId, serializer);
+sse_encode_String(publicKey, serializer);
+case NodeEventDto_Stopped(): sse_encode_i_32(1, serializer); case NodeEventDto_PeerConnected(peerId: final peerId): sse_encode_i_32(2, serializer); sse_encode_String(peerId, serializer);
+case NodeEventDto_PeerDisconnected(peerId: final peerId): sse_encode_i_32(3, serializer); sse_encode_String(peerId, serializer);
+case NodeEventDto_PeerDiscovered(peerId: final peerId,address: final address): sse_encode_i_32(4, serializer); sse_encode_String(peerId, serializer);
+sse_encode_opt_String(address, serializer);
+case NodeEventDto_GossipReceived(topic: final topic,from: final from,content: final content,verified: final verified): sse_encode_i_32(5, serializer); sse_encode_String(topic, serializer);
+sse_encode_String(from, serializer);
+sse_encode_String(content, serializer);
+sse_encode_bool(verified, serializer);
+case NodeEventDto_SyncReceived(dbName: final dbName,key: final key): sse_encode_i_32(6, serializer); sse_encode_String(dbName, serializer);
+sse_encode_String(key, serializer);
+case NodeEventDto_SyncProgress(dbName: final dbName,opsReceived: final opsReceived,opsTotalEstimate: final opsTotalEstimate,bytes: final bytes): sse_encode_i_32(7, serializer); sse_encode_String(dbName, serializer);
+sse_encode_u_64(opsReceived, serializer);
+sse_encode_u_64(opsTotalEstimate, serializer);
+sse_encode_u_64(bytes, serializer);
+case NodeEventDto_ConflictResolved(dbName: final dbName,key: final key,keptOpId: final keptOpId,discardedOpId: final discardedOpId): sse_encode_i_32(8, serializer); sse_encode_String(dbName, serializer);
+sse_encode_String(key, serializer);
+sse_encode_String(keptOpId, serializer);
+sse_encode_String(discardedOpId, serializer);
+case NodeEventDto_LatencyMeasured(peerId: final peerId,latencyMs: final latencyMs): sse_encode_i_32(9, serializer); sse_encode_String(peerId, serializer);
+sse_encode_u_64(latencyMs, serializer);
+case NodeEventDto_PresenceChanged(peerId: final peerId,online: final online,status: final status): sse_encode_i_32(10, serializer); sse_encode_String(peerId, serializer);
+sse_encode_bool(online, serializer);
+sse_encode_opt_String(status, serializer);
+case NodeEventDto_TopicRecovered(topic: final topic): sse_encode_i_32(11, serializer); sse_encode_String(topic, serializer);
+case NodeEventDto_RelayConnected(relayUrl: final relayUrl): sse_encode_i_32(12, serializer); sse_encode_String(relayUrl, serializer);
+case NodeEventDto_BootstrapConnected(peerId: final peerId): sse_encode_i_32(13, serializer); sse_encode_String(peerId, serializer);
+case NodeEventDto_IdentityRotated(oldNodeId: final oldNodeId,newNodeId: final newNodeId): sse_encode_i_32(14, serializer); sse_encode_String(oldNodeId, serializer);
+sse_encode_String(newNodeId, serializer);
+case NodeEventDto_DeviceLinked(userPublicKey: final userPublicKey,deviceNodeId: final deviceNodeId,deviceName: final deviceName): sse_encode_i_32(15, serializer); sse_encode_String(userPublicKey, serializer);
+sse_encode_String(deviceNodeId, serializer);
+sse_encode_String(deviceName, serializer);
+case NodeEventDto_ConnectivityChanged(state: final state,reason: final reason): sse_encode_i_32(16, serializer); sse_encode_connectivity_state_dto(state, serializer);
+sse_encode_String(reason, serializer);
+case NodeEventDto_Error(message: final message): sse_encode_i_32(17, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_node_event_record_dto(NodeEventRecordDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.seq, serializer);
+sse_encode_i_64(self.timestamp, serializer);
+sse_encode_node_event_dto(self.event, serializer);
+ }
+
+@protected void sse_encode_node_info(NodeInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.publicKey, serializer);
+sse_encode_bool(self.isRunning, serializer);
+ }
+
+@protected void sse_encode_node_instance_dto(NodeInstanceDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.handle, serializer);
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.publicKey, serializer);
+ }
+
+@protected void sse_encode_node_stats_dto(NodeStatsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.uptimeSeconds, serializer);
+sse_encode_u_64(self.totalOperations, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+sse_encode_u_64(self.connects, serializer);
+sse_encode_u_64(self.reconnects, serializer);
+sse_encode_u_64(self.lifetimeSessions, serializer);
+sse_encode_u_64(self.lifetimeUptimeSeconds, serializer);
+sse_encode_u_64(self.lifetimeBytesSent, serializer);
+sse_encode_u_64(self.lifetimeBytesReceived, serializer);
+sse_encode_u_64(self.lifetimeConnects, serializer);
+sse_encode_u_64(self.lifetimeReconnects, serializer);
+sse_encode_u_64(self.outboxDepth, serializer);
+ }
+
+@protected void sse_encode_node_status_dto(NodeStatusDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.isRunning, serializer);
+sse_encode_opt_String(self.nodeId, serializer);
+sse_encode_u_32(self.connectedPeers, serializer);
+sse_encode_u_32(self.discoveredPeers, serializer);
+sse_encode_u_64(self.uptimeSeconds, serializer);
+sse_encode_u_64(self.gossipMessagesReceived, serializer);
+sse_encode_u_64(self.storageSizeBytes, serializer);
+sse_encode_u_64(self.totalKeys, serializer);
+sse_encode_u_32(self.syncOperations, serializer);
+sse_encode_u_64(self.latencyRequestsSent, serializer);
+sse_encode_u_64(self.latencyResponsesReceived, serializer);
+sse_encode_list_topic_stats_dto(self.topics, serializer);
+ }
+
+@protected void sse_encode_oplog_gc_dto(OplogGcDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.keepVersions, serializer);
+sse_encode_u_64(self.keepForSecs, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bootstrap_manifest_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_mqtt_bridge_config_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_node_info(NodeInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_node_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_oplog_gc_dto(OplogGcDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_oplog_gc_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_peer_path_dto(PeerPathDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_peer_path_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_presence_dto(PresenceDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_presence_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_successor_record_dto(SuccessorRecordDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_successor_record_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_peer_circuit_dto(PeerCircuitDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.state, serializer);
+sse_encode_bool(self.unreachable, serializer);
+sse_encode_u_32(self.consecutiveFailures, serializer);
+sse_encode_i_64(self.lastFailureAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.retryAt, serializer);
+ }
+
+@protected void sse_encode_peer_diagnostics_dto(PeerDiagnosticsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_opt_box_autoadd_peer_path_dto(self.path, serializer);
+sse_encode_bool(self.directEstablished, serializer);
+sse_encode_u_32(self.dialAttempts, serializer);
+sse_encode_u_32(self.dialFailures, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastDialAt, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+ }
+
+@protected void sse_encode_peer_info_dto(PeerInfoDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.publicKey, serializer);
+sse_encode_opt_String(self.address, serializer);
+sse_encode_opt_String(self.region, serializer);
+sse_encode_opt_String(self.version, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+sse_encode_bool(self.isMobile, serializer);
+ }
+
+@protected void sse_encode_peer_latency_dto(PeerLatencyDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+sse_encode_f_64(self.averageMs, serializer);
+sse_encode_f_64(self.jitterMs, serializer);
+sse_encode_u_32(self.samples, serializer);
+ }
+
+@protected void sse_encode_peer_path_dto(PeerPathDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_bool(self.isMixed, serializer);
+sse_encode_list_String(self.directAddrs, serializer);
+sse_encode_opt_String(self.relayUrl, serializer);
+sse_encode_i_64(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_peer_sync_status_dto(PeerSyncStatusDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.peerId, serializer);
+sse_encode_u_64(self.opsSent, serializer);
+sse_encode_u_64(self.opsReceived, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastRequestAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastResponseAt, serializer);
+sse_encode_bool(self.inProgress, serializer);
+sse_encode_u_64(self.opsAcked, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastAckAt, serializer);
+ }
+
+@protected void sse_encode_pinned_peer_dto(PinnedPeerDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_opt_String(self.address, serializer);
+ }
+
+@protected void sse_encode_power_mode_dto(PowerModeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_power_policy_dto(PowerPolicyDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.lowPower, serializer);
+sse_encode_bool(self.metered, serializer);
+sse_encode_bool(self.syncDeferred, serializer);
+sse_encode_bool(self.blobTransfersAllowed, serializer);
+ }
+
+@protected void sse_encode_presence_dto(PresenceDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.peerId, serializer);
+sse_encode_bool(self.online, serializer);
+sse_encode_opt_String(self.status, serializer);
+sse_encode_i_64(self.lastSeen, serializer);
+ }
+
+@protected void sse_encode_query_filter_dto(QueryFilterDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.field, serializer);
+sse_encode_String(self.op, serializer);
+sse_encode_String(self.valueJson, serializer);
+ }
+
+@protected void sse_encode_query_result_dto(QueryResultDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_db_entry_dto(self.entries, serializer);
+sse_encode_bool(self.hasMore, serializer);
+ }
+
+@protected void sse_encode_query_spec_dto(QuerySpecDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.keyPrefix, serializer);
+sse_encode_list_query_filter_dto(self.filters, serializer);
+sse_encode_opt_String(self.sortBy, serializer);
+sse_encode_bool(self.descending, serializer);
+sse_encode_u_32(self.limit, serializer);
+sse_encode_u_32(self.offset, serializer);
+sse_encode_opt_list_String(self.fields, serializer);
+ }
+
+@protected void sse_encode_rekeyed_database_dto(RekeyedDatabaseDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.oldName, serializer);
+sse_encode_String(self.newName, serializer);
+ }
+
+@protected void sse_encode_relay_config_dto(RelayConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case RelayConfigDto_Default(): sse_encode_i_32(0, serializer); case RelayConfigDto_Disabled(): sse_encode_i_32(1, serializer); case RelayConfigDto_Custom(urls: final urls): sse_encode_i_32(2, serializer); sse_encode_list_String(urls, serializer);
+  } }
+
+@protected void sse_encode_relay_status_dto(RelayStatusDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_relay_config_dto(self.relay, serializer);
+sse_encode_opt_String(self.activeUrl, serializer);
+sse_encode_opt_box_autoadd_u_64(self.rttMs, serializer);
+ }
+
+@protected void sse_encode_replica_check_dto(ReplicaCheckDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.publisher, serializer);
+sse_encode_bool(self.matches, serializer);
+sse_encode_u_32(self.mismatchedBuckets, serializer);
+sse_encode_i_64(self.checkedAt, serializer);
+ }
+
+@protected void sse_encode_rpc_request_dto(RpcRequestDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.requestId, serializer);
+sse_encode_String(self.peerId, serializer);
+sse_encode_String(self.method, serializer);
+sse_encode_list_prim_u_8_strict(self.payload, serializer);
+ }
+
+@protected void sse_encode_scan_page_dto(ScanPageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_db_entry_dto(self.entries, serializer);
+sse_encode_opt_String(self.nextCursor, serializer);
+ }
+
+@protected void sse_encode_scored_member_dto(ScoredMemberDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.member, serializer);
+sse_encode_f_64(self.score, serializer);
+ }
+
+@protected void sse_encode_startup_stage_dto(StartupStageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case StartupStageDto_KeyLoaded(): sse_encode_i_32(0, serializer); case StartupStageDto_EndpointOnline(relayConnected: final relayConnected): sse_encode_i_32(1, serializer); sse_encode_bool(relayConnected, serializer);
+case StartupStageDto_BootstrapConnecting(attempt: final attempt): sse_encode_i_32(2, serializer); sse_encode_u_32(attempt, serializer);
+case StartupStageDto_TopicsSubscribed(): sse_encode_i_32(3, serializer); case StartupStageDto_Ready(): sse_encode_i_32(4, serializer);   } }
+
+@protected void sse_encode_subsystem_health_dto(SubsystemHealthDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_health_status_dto(self.status, serializer);
+sse_encode_String(self.reason, serializer);
+ }
+
+@protected void sse_encode_successor_record_dto(SuccessorRecordDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.oldNodeId, serializer);
+sse_encode_String(self.newNodeId, serializer);
+sse_encode_i_64(self.timestamp, serializer);
+sse_encode_i_64(self.graceUntil, serializer);
+ }
+
+@protected void sse_encode_sync_stats_dto(SyncStatsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.totalOperations, serializer);
+sse_encode_u_64(self.opsSent, serializer);
+sse_encode_u_64(self.opsReceived, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastSyncAt, serializer);
+sse_encode_list_peer_sync_status_dto(self.peers, serializer);
+ }
+
+@protected void sse_encode_sync_wait_summary_dto(SyncWaitSummaryDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.synced, serializer);
+sse_encode_u_32(self.queuedOps, serializer);
+sse_encode_u_32(self.unacknowledgedOps, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_text_delta_dto(TextDeltaDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_u_64(self.index, serializer);
+sse_encode_u_64(self.deleted, serializer);
+sse_encode_String(self.inserted, serializer);
+ }
+
+@protected void sse_encode_time_point_dto(TimePointDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.timestamp, serializer);
+sse_encode_f_64(self.value, serializer);
+ }
+
+@protected void sse_encode_topic_health_dto(TopicHealthDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.topic, serializer);
+sse_encode_bool(self.subscribed, serializer);
+sse_encode_u_32(self.neighbors, serializer);
+ }
+
+@protected void sse_encode_topic_message_dto(TopicMessageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.topic, serializer);
+sse_encode_String(self.deliveredFrom, serializer);
+sse_encode_list_prim_u_8_strict(self.content, serializer);
+ }
+
+@protected void sse_encode_topic_metrics_dto(TopicMetricsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.topic, serializer);
+sse_encode_u_64(self.messagesSent, serializer);
+sse_encode_u_64(self.messagesReceived, serializer);
+sse_encode_u_64(self.duplicatesDropped, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+ }
+
+@protected void sse_encode_topic_stats_dto(TopicStatsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.topic, serializer);
+sse_encode_u_64(self.messagesSent, serializer);
+sse_encode_u_64(self.messagesReceived, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+sse_encode_u_32(self.neighbors, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastEventAt, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_value_update_dto(ValueUpdateDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.dbName, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_opt_String(self.value, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.valueBytes, serializer);
+ }
+
+@protected void sse_encode_verified_binding_dto(VerifiedBindingDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.nodeId, serializer);
+sse_encode_String(self.publicKey, serializer);
+sse_encode_i_64(self.verifiedAt, serializer);
+ }
+
+@protected void sse_encode_wake_sync_summary_dto(WakeSyncSummaryDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.startedNode, serializer);
+sse_encode_u_32(self.pinnedPeersDialed, serializer);
+sse_encode_u_32(self.pinnedPeersConnected, serializer);
+sse_encode_bool(self.synced, serializer);
+sse_encode_u_64(self.opsReceived, serializer);
+sse_encode_u_64(self.opsSent, serializer);
+sse_encode_u_32(self.outboxSent, serializer);
+sse_encode_u_32(self.outboxRemaining, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+ }
+                }
+                
@@ -10,1497 +10,6513 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  NodeInfo dco_decode_box_autoadd_node_info(dynamic raw);
+                  
 
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+@protected RustStreamSink<ChangeEventDto> dco_decode_StreamSink_change_event_dto_Dco(dynamic raw);
 
-  @protected
-  DbEntryDto dco_decode_db_entry_dto(dynamic raw);
+@protected RustStreamSink<DirectMessageDto> dco_decode_StreamSink_direct_message_dto_Dco(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+@protected RustStreamSink<FileEventDto> dco_decode_StreamSink_file_event_dto_Dco(dynamic raw);
 
-  @protected
-  KeyPairDto dco_decode_key_pair_dto(dynamic raw);
+@protected RustStreamSink<KeyChangeDto> dco_decode_StreamSink_key_change_dto_Dco(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected RustStreamSink<NodeEventRecordDto> dco_decode_StreamSink_node_event_record_dto_Dco(dynamic raw);
 
-  @protected
-  List<DbEntryDto> dco_decode_list_db_entry_dto(dynamic raw);
+@protected RustStreamSink<RpcRequestDto> dco_decode_StreamSink_rpc_request_dto_Dco(dynamic raw);
 
-  @protected
-  List<LogEntry> dco_decode_list_log_entry(dynamic raw);
+@protected RustStreamSink<StartupStageDto> dco_decode_StreamSink_startup_stage_dto_Dco(dynamic raw);
 
-  @protected
-  List<PeerInfoDto> dco_decode_list_peer_info_dto(dynamic raw);
+@protected RustStreamSink<TextDeltaDto> dco_decode_StreamSink_text_delta_dto_Dco(dynamic raw);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected RustStreamSink<TopicMessageDto> dco_decode_StreamSink_topic_message_dto_Dco(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected RustStreamSink<ValueUpdateDto> dco_decode_StreamSink_value_update_dto_Dco(dynamic raw);
 
-  @protected
-  LogEntry dco_decode_log_entry(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  NodeInfo dco_decode_node_info(dynamic raw);
+@protected AcceptedInviteDto dco_decode_accepted_invite_dto(dynamic raw);
 
-  @protected
-  NodeStatusDto dco_decode_node_status_dto(dynamic raw);
+@protected BackupInfoDto dco_decode_backup_info_dto(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected BandwidthCapsDto dco_decode_bandwidth_caps_dto(dynamic raw);
 
-  @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+@protected BandwidthStatsDto dco_decode_bandwidth_stats_dto(dynamic raw);
 
-  @protected
-  NodeInfo? dco_decode_opt_box_autoadd_node_info(dynamic raw);
+@protected BandwidthSubsystemDto dco_decode_bandwidth_subsystem_dto(dynamic raw);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+@protected BandwidthUsageDto dco_decode_bandwidth_usage_dto(dynamic raw);
 
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+@protected BatchEntryDto dco_decode_batch_entry_dto(dynamic raw);
 
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  PeerInfoDto dco_decode_peer_info_dto(dynamic raw);
+@protected BootstrapManifestDto dco_decode_bootstrap_manifest_dto(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected BandwidthCapsDto dco_decode_box_autoadd_bandwidth_caps_dto(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected BootstrapManifestDto dco_decode_box_autoadd_bootstrap_manifest_dto(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected DeviceConditionsDto dco_decode_box_autoadd_device_conditions_dto(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected FileOfferDto dco_decode_box_autoadd_file_offer_dto(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+@protected LogFilterDto dco_decode_box_autoadd_log_filter_dto(dynamic raw);
 
-  @protected
-  NodeInfo sse_decode_box_autoadd_node_info(SseDeserializer deserializer);
+@protected MqttBridgeConfigDto dco_decode_box_autoadd_mqtt_bridge_config_dto(dynamic raw);
 
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+@protected NodeConfigDto dco_decode_box_autoadd_node_config_dto(dynamic raw);
 
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+@protected NodeInfo dco_decode_box_autoadd_node_info(dynamic raw);
 
-  @protected
-  DbEntryDto sse_decode_db_entry_dto(SseDeserializer deserializer);
+@protected OplogGcDto dco_decode_box_autoadd_oplog_gc_dto(dynamic raw);
 
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+@protected PeerPathDto dco_decode_box_autoadd_peer_path_dto(dynamic raw);
 
-  @protected
-  KeyPairDto sse_decode_key_pair_dto(SseDeserializer deserializer);
+@protected PresenceDto dco_decode_box_autoadd_presence_dto(dynamic raw);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected QuerySpecDto dco_decode_box_autoadd_query_spec_dto(dynamic raw);
 
-  @protected
-  List<DbEntryDto> sse_decode_list_db_entry_dto(SseDeserializer deserializer);
+@protected SuccessorRecordDto dco_decode_box_autoadd_successor_record_dto(dynamic raw);
 
-  @protected
-  List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
 
-  @protected
-  List<PeerInfoDto> sse_decode_list_peer_info_dto(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+@protected ChangeEventDto dco_decode_change_event_dto(dynamic raw);
 
-  @protected
-  NodeInfo sse_decode_node_info(SseDeserializer deserializer);
+@protected CheckpointDto dco_decode_checkpoint_dto(dynamic raw);
 
-  @protected
-  NodeStatusDto sse_decode_node_status_dto(SseDeserializer deserializer);
+@protected ConflictDto dco_decode_conflict_dto(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected ConnectionDiagnosticsDto dco_decode_connection_diagnostics_dto(dynamic raw);
 
-  @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+@protected ConnectionResultDto dco_decode_connection_result_dto(dynamic raw);
 
-  @protected
-  NodeInfo? sse_decode_opt_box_autoadd_node_info(SseDeserializer deserializer);
+@protected ConnectionTypeDto dco_decode_connection_type_dto(dynamic raw);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+@protected ConnectivityDto dco_decode_connectivity_dto(dynamic raw);
 
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+@protected ConnectivityStateDto dco_decode_connectivity_state_dto(dynamic raw);
 
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected DbEntryDto dco_decode_db_entry_dto(dynamic raw);
 
-  @protected
-  PeerInfoDto sse_decode_peer_info_dto(SseDeserializer deserializer);
+@protected DbSyncInfoDto dco_decode_db_sync_info_dto(dynamic raw);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected DebugServerDto dco_decode_debug_server_dto(dynamic raw);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected DeviceConditionsDto dco_decode_device_conditions_dto(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected DirectMessageDto dco_decode_direct_message_dto(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected FileEventDto dco_decode_file_event_dto(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw));
-  }
+@protected FileOfferDto dco_decode_file_offer_dto(dynamic raw);
 
-  @protected
-  ffi.Pointer<ffi.Int64> cst_encode_box_autoadd_i_64(PlatformInt64 raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_i_64(cst_encode_i_64(raw));
-  }
+@protected GeoMatchDto dco_decode_geo_match_dto(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_node_info> cst_encode_box_autoadd_node_info(
-    NodeInfo raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_node_info();
-    cst_api_fill_to_wire_node_info(raw, ptr.ref);
-    return ptr;
-  }
+@protected HashEntryDto dco_decode_hash_entry_dto(dynamic raw);
 
-  @protected
-  ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_u_32(cst_encode_u_32(raw));
-  }
+@protected HealthReportDto dco_decode_health_report_dto(dynamic raw);
 
-  @protected
-  ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_u_64(cst_encode_u_64(raw));
-  }
+@protected HealthStatusDto dco_decode_health_status_dto(dynamic raw);
 
-  @protected
-  int cst_encode_i_64(PlatformInt64 raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.toInt();
-  }
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_String(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      ans.ref.ptr[i] = cst_encode_String(raw[i]);
-    }
-    return ans;
-  }
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_db_entry_dto> cst_encode_list_db_entry_dto(
-    List<DbEntryDto> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_db_entry_dto(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_db_entry_dto(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+@protected IdentityRotationDto dco_decode_identity_rotation_dto(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_log_entry> cst_encode_list_log_entry(
-    List<LogEntry> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_log_entry(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_log_entry(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+@protected IntegrityIssueDto dco_decode_integrity_issue_dto(dynamic raw);
 
-  @protected
-  ffi.Pointer<wire_cst_list_peer_info_dto> cst_encode_list_peer_info_dto(
-    List<PeerInfoDto> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_peer_info_dto(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_peer_info_dto(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
-
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_encode_list_prim_u_8_loose(
-    List<int> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_loose(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
-
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(
-    Uint8List raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
-
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(
-    String? raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_String(raw);
-  }
-
-  @protected
-  ffi.Pointer<ffi.Int64> cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_64(raw);
-  }
-
-  @protected
-  ffi.Pointer<wire_cst_node_info> cst_encode_opt_box_autoadd_node_info(
-    NodeInfo? raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_node_info(raw);
-  }
-
-  @protected
-  ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw);
-  }
-
-  @protected
-  ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict>
-  cst_encode_opt_list_prim_u_8_strict(Uint8List? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_list_prim_u_8_strict(raw);
-  }
-
-  @protected
-  int cst_encode_u_64(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.toSigned(64).toInt();
-  }
-
-  @protected
-  void cst_api_fill_to_wire_box_autoadd_node_info(
-    NodeInfo apiObj,
-    ffi.Pointer<wire_cst_node_info> wireObj,
-  ) {
-    cst_api_fill_to_wire_node_info(apiObj, wireObj.ref);
-  }
-
-  @protected
-  void cst_api_fill_to_wire_db_entry_dto(
-    DbEntryDto apiObj,
-    wire_cst_db_entry_dto wireObj,
-  ) {
-    wireObj.db_name = cst_encode_String(apiObj.dbName);
-    wireObj.key = cst_encode_String(apiObj.key);
-    wireObj.value = cst_encode_String(apiObj.value);
-    wireObj.value_bytes = cst_encode_list_prim_u_8_strict(apiObj.valueBytes);
-  }
-
-  @protected
-  void cst_api_fill_to_wire_key_pair_dto(
-    KeyPairDto apiObj,
-    wire_cst_key_pair_dto wireObj,
-  ) {
-    wireObj.public_key = cst_encode_String(apiObj.publicKey);
-    wireObj.secret_key = cst_encode_String(apiObj.secretKey);
-  }
-
-  @protected
-  void cst_api_fill_to_wire_log_entry(
-    LogEntry apiObj,
-    wire_cst_log_entry wireObj,
-  ) {
-    wireObj.timestamp = cst_encode_i_64(apiObj.timestamp);
-    wireObj.level = cst_encode_String(apiObj.level);
-    wireObj.message = cst_encode_String(apiObj.message);
-  }
-
-  @protected
-  void cst_api_fill_to_wire_node_info(
-    NodeInfo apiObj,
-    wire_cst_node_info wireObj,
-  ) {
-    wireObj.node_id = cst_encode_String(apiObj.nodeId);
-    wireObj.public_key = cst_encode_String(apiObj.publicKey);
-    wireObj.is_running = cst_encode_bool(apiObj.isRunning);
-  }
-
-  @protected
-  void cst_api_fill_to_wire_node_status_dto(
-    NodeStatusDto apiObj,
-    wire_cst_node_status_dto wireObj,
-  ) {
-    wireObj.is_running = cst_encode_bool(apiObj.isRunning);
-    wireObj.node_id = cst_encode_opt_String(apiObj.nodeId);
-    wireObj.connected_peers = cst_encode_u_32(apiObj.connectedPeers);
-    wireObj.discovered_peers = cst_encode_u_32(apiObj.discoveredPeers);
-    wireObj.uptime_seconds = cst_encode_u_64(apiObj.uptimeSeconds);
-    wireObj.gossip_messages_received = cst_encode_u_64(
-      apiObj.gossipMessagesReceived,
-    );
-    wireObj.storage_size_bytes = cst_encode_u_64(apiObj.storageSizeBytes);
-    wireObj.total_keys = cst_encode_u_64(apiObj.totalKeys);
-    wireObj.sync_operations = cst_encode_u_32(apiObj.syncOperations);
-    wireObj.latency_requests_sent = cst_encode_u_64(apiObj.latencyRequestsSent);
-    wireObj.latency_responses_received = cst_encode_u_64(
-      apiObj.latencyResponsesReceived,
-    );
-  }
-
-  @protected
-  void cst_api_fill_to_wire_peer_info_dto(
-    PeerInfoDto apiObj,
-    wire_cst_peer_info_dto wireObj,
-  ) {
-    wireObj.node_id = cst_encode_String(apiObj.nodeId);
-    wireObj.public_key = cst_encode_String(apiObj.publicKey);
-    wireObj.address = cst_encode_opt_String(apiObj.address);
-    wireObj.region = cst_encode_opt_String(apiObj.region);
-    wireObj.version = cst_encode_opt_String(apiObj.version);
-    wireObj.latency_ms = cst_encode_opt_box_autoadd_u_64(apiObj.latencyMs);
-    wireObj.is_mobile = cst_encode_bool(apiObj.isMobile);
-  }
-
-  @protected
-  bool cst_encode_bool(bool raw);
-
-  @protected
-  int cst_encode_u_32(int raw);
-
-  @protected
-  int cst_encode_u_8(int raw);
-
-  @protected
-  void cst_encode_unit(void raw);
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_node_info(
-    NodeInfo self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_db_entry_dto(DbEntryDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_key_pair_dto(KeyPairDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_db_entry_dto(
-    List<DbEntryDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_log_entry(List<LogEntry> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_peer_info_dto(
-    List<PeerInfoDto> self,
-    SseSerializer serializer,
-  );
+@protected IntegrityReportDto dco_decode_integrity_report_dto(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected KadenaTransactionDto dco_decode_kadena_transaction_dto(dynamic raw);
 
-  @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+@protected KeyChangeDto dco_decode_key_change_dto(dynamic raw);
 
-  @protected
-  void sse_encode_node_info(NodeInfo self, SseSerializer serializer);
+@protected KeyPairDto dco_decode_key_pair_dto(dynamic raw);
 
-  @protected
-  void sse_encode_node_status_dto(NodeStatusDto self, SseSerializer serializer);
+@protected LatencyBucketDto dco_decode_latency_bucket_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected LinkedDeviceDto dco_decode_linked_device_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
-    SseSerializer serializer,
-  );
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_node_info(
-    NodeInfo? self,
-    SseSerializer serializer,
-  );
+@protected List<BandwidthUsageDto> dco_decode_list_bandwidth_usage_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+@protected List<BatchEntryDto> dco_decode_list_batch_entry_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+@protected List<ConflictDto> dco_decode_list_conflict_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-    Uint8List? self,
-    SseSerializer serializer,
-  );
+@protected List<DbEntryDto> dco_decode_list_db_entry_dto(dynamic raw);
 
-  @protected
-  void sse_encode_peer_info_dto(PeerInfoDto self, SseSerializer serializer);
+@protected List<DirectMessageDto> dco_decode_list_direct_message_dto(dynamic raw);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected List<FileOfferDto> dco_decode_list_file_offer_dto(dynamic raw);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected List<GeoMatchDto> dco_decode_list_geo_match_dto(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected List<HashEntryDto> dco_decode_list_hash_entry_dto(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected List<IntegrityIssueDto> dco_decode_list_integrity_issue_dto(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
+@protected List<LatencyBucketDto> dco_decode_list_latency_bucket_dto(dynamic raw);
 
-// Section: wire_class
+@protected List<LinkedDeviceDto> dco_decode_list_linked_device_dto(dynamic raw);
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
-// ignore_for_file: type=lint, unused_import
+@protected List<LogEntry> dco_decode_list_log_entry(dynamic raw);
 
-/// generated by flutter_rust_bridge
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
+@protected List<MqttTopicMappingDto> dco_decode_list_mqtt_topic_mapping_dto(dynamic raw);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
+@protected List<NodeEventRecordDto> dco_decode_list_node_event_record_dto(dynamic raw);
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
+@protected List<Uint8List?> dco_decode_list_opt_list_prim_u_8_strict(dynamic raw);
 
-  /// The symbols are looked up with [lookup].
-  RustLibWire.fromLookup(
-    ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup,
-  ) : _lookup = lookup;
+@protected List<PeerCircuitDto> dco_decode_list_peer_circuit_dto(dynamic raw);
 
-  void store_dart_post_cobject(DartPostCObjectFnType ptr) {
-    return _store_dart_post_cobject(ptr);
-  }
+@protected List<PeerDiagnosticsDto> dco_decode_list_peer_diagnostics_dto(dynamic raw);
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
-        'store_dart_post_cobject',
-      );
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
-      .asFunction<void Function(DartPostCObjectFnType)>();
+@protected List<PeerInfoDto> dco_decode_list_peer_info_dto(dynamic raw);
 
-  void wire__crate__api__add_log_entry(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> level,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
-  ) {
-    return _wire__crate__api__add_log_entry(port_, level, message);
-  }
+@protected List<PeerLatencyDto> dco_decode_list_peer_latency_dto(dynamic raw);
 
-  late final _wire__crate__api__add_log_entryPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__add_log_entry');
-  late final _wire__crate__api__add_log_entry =
-      _wire__crate__api__add_log_entryPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
-
-  WireSyncRust2DartDco wire__crate__api__clear_logs() {
-    return _wire__crate__api__clear_logs();
-  }
+@protected List<PeerPathDto> dco_decode_list_peer_path_dto(dynamic raw);
 
-  late final _wire__crate__api__clear_logsPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__clear_logs',
-      );
-  late final _wire__crate__api__clear_logs = _wire__crate__api__clear_logsPtr
-      .asFunction<WireSyncRust2DartDco Function()>();
+@protected List<PeerSyncStatusDto> dco_decode_list_peer_sync_status_dto(dynamic raw);
 
-  void wire__crate__api__delete_data(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> key,
-  ) {
-    return _wire__crate__api__delete_data(port_, db_name, key);
-  }
+@protected List<PinnedPeerDto> dco_decode_list_pinned_peer_dto(dynamic raw);
 
-  late final _wire__crate__api__delete_dataPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__delete_data');
-  late final _wire__crate__api__delete_data = _wire__crate__api__delete_dataPtr
-      .asFunction<
-        void Function(
-          int,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
-
-  WireSyncRust2DartDco wire__crate__api__extract_name_from_db(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name,
-  ) {
-    return _wire__crate__api__extract_name_from_db(db_name);
-  }
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
 
-  late final _wire__crate__api__extract_name_from_dbPtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__extract_name_from_db');
-  late final _wire__crate__api__extract_name_from_db =
-      _wire__crate__api__extract_name_from_dbPtr
-          .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
-
-  WireSyncRust2DartDco wire__crate__api__generate_db_name(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> name,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex,
-  ) {
-    return _wire__crate__api__generate_db_name(name, public_key_hex);
-  }
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
-  late final _wire__crate__api__generate_db_namePtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__generate_db_name');
-  late final _wire__crate__api__generate_db_name =
-      _wire__crate__api__generate_db_namePtr
-          .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
-
-  WireSyncRust2DartDco wire__crate__api__generate_keypair() {
-    return _wire__crate__api__generate_keypair();
-  }
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  late final _wire__crate__api__generate_keypairPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__generate_keypair',
-      );
-  late final _wire__crate__api__generate_keypair =
-      _wire__crate__api__generate_keypairPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+@protected List<QueryFilterDto> dco_decode_list_query_filter_dto(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__generate_peer_id_from_secret_key(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key_hex,
-  ) {
-    return _wire__crate__api__generate_peer_id_from_secret_key(secret_key_hex);
-  }
+@protected List<RekeyedDatabaseDto> dco_decode_list_rekeyed_database_dto(dynamic raw);
 
-  late final _wire__crate__api__generate_peer_id_from_secret_keyPtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__generate_peer_id_from_secret_key',
-      );
-  late final _wire__crate__api__generate_peer_id_from_secret_key =
-      _wire__crate__api__generate_peer_id_from_secret_keyPtr
-          .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
+@protected List<ReplicaCheckDto> dco_decode_list_replica_check_dto(dynamic raw);
 
-  void wire__crate__api__get_all_data(int port_) {
-    return _wire__crate__api__get_all_data(port_);
-  }
+@protected List<ScoredMemberDto> dco_decode_list_scored_member_dto(dynamic raw);
 
-  late final _wire__crate__api__get_all_dataPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data',
-      );
-  late final _wire__crate__api__get_all_data =
-      _wire__crate__api__get_all_dataPtr.asFunction<void Function(int)>();
+@protected List<TimePointDto> dco_decode_list_time_point_dto(dynamic raw);
 
-  void wire__crate__api__get_all_entries(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name,
-  ) {
-    return _wire__crate__api__get_all_entries(port_, db_name);
-  }
+@protected List<TopicHealthDto> dco_decode_list_topic_health_dto(dynamic raw);
 
-  late final _wire__crate__api__get_all_entriesPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__get_all_entries');
-  late final _wire__crate__api__get_all_entries =
-      _wire__crate__api__get_all_entriesPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
-
-  void wire__crate__api__get_data(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> key,
-  ) {
-    return _wire__crate__api__get_data(port_, db_name, key);
-  }
+@protected List<TopicMetricsDto> dco_decode_list_topic_metrics_dto(dynamic raw);
 
-  late final _wire__crate__api__get_dataPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__get_data');
-  late final _wire__crate__api__get_data = _wire__crate__api__get_dataPtr
-      .asFunction<
-        void Function(
-          int,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
-
-  WireSyncRust2DartDco wire__crate__api__get_logs(
-    ffi.Pointer<ffi.Uint32> limit,
-  ) {
-    return _wire__crate__api__get_logs(limit);
-  }
+@protected List<TopicStatsDto> dco_decode_list_topic_stats_dto(dynamic raw);
 
-  late final _wire__crate__api__get_logsPtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(ffi.Pointer<ffi.Uint32>)
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__get_logs');
-  late final _wire__crate__api__get_logs = _wire__crate__api__get_logsPtr
-      .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<ffi.Uint32>)>();
+@protected LogEntry dco_decode_log_entry(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__get_node_info() {
-    return _wire__crate__api__get_node_info();
-  }
+@protected LogFilterDto dco_decode_log_filter_dto(dynamic raw);
 
-  late final _wire__crate__api__get_node_infoPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__get_node_info',
-      );
-  late final _wire__crate__api__get_node_info =
-      _wire__crate__api__get_node_infoPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+@protected MetricsDto dco_decode_metrics_dto(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__get_node_status() {
-    return _wire__crate__api__get_node_status();
-  }
+@protected MqttBridgeConfigDto dco_decode_mqtt_bridge_config_dto(dynamic raw);
 
-  late final _wire__crate__api__get_node_statusPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__get_node_status',
-      );
-  late final _wire__crate__api__get_node_status =
-      _wire__crate__api__get_node_statusPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+@protected MqttDirectionDto dco_decode_mqtt_direction_dto(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__get_peers() {
-    return _wire__crate__api__get_peers();
-  }
+@protected MqttTopicMappingDto dco_decode_mqtt_topic_mapping_dto(dynamic raw);
 
-  late final _wire__crate__api__get_peersPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__get_peers',
-      );
-  late final _wire__crate__api__get_peers = _wire__crate__api__get_peersPtr
-      .asFunction<WireSyncRust2DartDco Function()>();
+@protected NodeAddrDto dco_decode_node_addr_dto(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__greet(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> name,
-  ) {
-    return _wire__crate__api__greet(name);
-  }
+@protected NodeConfigDto dco_decode_node_config_dto(dynamic raw);
 
-  late final _wire__crate__api__greetPtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__greet');
-  late final _wire__crate__api__greet = _wire__crate__api__greetPtr
-      .asFunction<
-        WireSyncRust2DartDco Function(
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
-
-  WireSyncRust2DartDco wire__crate__api__init_logging() {
-    return _wire__crate__api__init_logging();
-  }
+@protected NodeEventDto dco_decode_node_event_dto(dynamic raw);
 
-  late final _wire__crate__api__init_loggingPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__init_logging',
-      );
-  late final _wire__crate__api__init_logging =
-      _wire__crate__api__init_loggingPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+@protected NodeEventRecordDto dco_decode_node_event_record_dto(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__is_node_running() {
-    return _wire__crate__api__is_node_running();
-  }
+@protected NodeInfo dco_decode_node_info(dynamic raw);
 
-  late final _wire__crate__api__is_node_runningPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__is_node_running',
-      );
-  late final _wire__crate__api__is_node_running =
-      _wire__crate__api__is_node_runningPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+@protected NodeInstanceDto dco_decode_node_instance_dto(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__list_databases() {
-    return _wire__crate__api__list_databases();
-  }
+@protected NodeStatsDto dco_decode_node_stats_dto(dynamic raw);
 
-  late final _wire__crate__api__list_databasesPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__list_databases',
-      );
-  late final _wire__crate__api__list_databases =
-      _wire__crate__api__list_databasesPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+@protected NodeStatusDto dco_decode_node_status_dto(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__list_keys(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name,
-  ) {
-    return _wire__crate__api__list_keys(db_name);
-  }
+@protected OplogGcDto dco_decode_oplog_gc_dto(dynamic raw);
 
-  late final _wire__crate__api__list_keysPtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__list_keys');
-  late final _wire__crate__api__list_keys = _wire__crate__api__list_keysPtr
-      .asFunction<
-        WireSyncRust2DartDco Function(
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
-
-  void wire__crate__api__request_sync(
-    int port_,
-    ffi.Pointer<ffi.Int64> since_timestamp,
-  ) {
-    return _wire__crate__api__request_sync(port_, since_timestamp);
-  }
+@protected String? dco_decode_opt_String(dynamic raw);
 
-  late final _wire__crate__api__request_syncPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Int64>)>
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__request_sync');
-  late final _wire__crate__api__request_sync =
-      _wire__crate__api__request_syncPtr
-          .asFunction<void Function(int, ffi.Pointer<ffi.Int64>)>();
+@protected BootstrapManifestDto? dco_decode_opt_box_autoadd_bootstrap_manifest_dto(dynamic raw);
 
-  void wire__crate__api__send_gossip(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> topic,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
-  ) {
-    return _wire__crate__api__send_gossip(port_, topic, message);
-  }
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
-  late final _wire__crate__api__send_gossipPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__send_gossip');
-  late final _wire__crate__api__send_gossip = _wire__crate__api__send_gossipPtr
-      .asFunction<
-        void Function(
-          int,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
-
-  void wire__crate__api__send_latency_request(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id,
-  ) {
-    return _wire__crate__api__send_latency_request(port_, peer_id);
-  }
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
-  late final _wire__crate__api__send_latency_requestPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__send_latency_request');
-  late final _wire__crate__api__send_latency_request =
-      _wire__crate__api__send_latency_requestPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
-
-  WireSyncRust2DartDco wire__crate__api__sign_message_with_key(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key_hex,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
-  ) {
-    return _wire__crate__api__sign_message_with_key(secret_key_hex, message);
-  }
+@protected MqttBridgeConfigDto? dco_decode_opt_box_autoadd_mqtt_bridge_config_dto(dynamic raw);
 
-  late final _wire__crate__api__sign_message_with_keyPtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__sign_message_with_key');
-  late final _wire__crate__api__sign_message_with_key =
-      _wire__crate__api__sign_message_with_keyPtr
-          .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
-
-  void wire__crate__api__start_node(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key,
-    ffi.Pointer<wire_cst_list_String> bootstrap_peers,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> region,
-  ) {
-    return _wire__crate__api__start_node(
-      port_,
-      data_dir,
-      wallet_secret_key,
-      bootstrap_peers,
-      region,
-    );
-  }
-
-  late final _wire__crate__api__start_nodePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_String>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__start_node');
-  late final _wire__crate__api__start_node = _wire__crate__api__start_nodePtr
-      .asFunction<
-        void Function(
-          int,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_String>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
+@protected NodeInfo? dco_decode_opt_box_autoadd_node_info(dynamic raw);
 
-  void wire__crate__api__stop_node(int port_) {
-    return _wire__crate__api__stop_node(port_);
-  }
+@protected OplogGcDto? dco_decode_opt_box_autoadd_oplog_gc_dto(dynamic raw);
 
-  late final _wire__crate__api__stop_nodePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__stop_node',
-      );
-  late final _wire__crate__api__stop_node = _wire__crate__api__stop_nodePtr
-      .asFunction<void Function(int)>();
-
-  void wire__crate__api__store_data(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> key,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> value,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> signature,
-  ) {
-    return _wire__crate__api__store_data(
-      port_,
-      db_name,
-      key,
-      value,
-      public_key,
-      signature,
-    );
-  }
-
-  late final _wire__crate__api__store_dataPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__store_data');
-  late final _wire__crate__api__store_data = _wire__crate__api__store_dataPtr
-      .asFunction<
-        void Function(
-          int,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
-
-  void wire__crate__api__store_data_local(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> key,
-    ffi.Pointer<wire_cst_list_prim_u_8_loose> value,
-  ) {
-    return _wire__crate__api__store_data_local(port_, db_name, key, value);
-  }
+@protected PeerPathDto? dco_decode_opt_box_autoadd_peer_path_dto(dynamic raw);
 
-  late final _wire__crate__api__store_data_localPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_local');
-  late final _wire__crate__api__store_data_local =
-      _wire__crate__api__store_data_localPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_loose>,
-            )
-          >();
-
-  WireSyncRust2DartDco wire__crate__api__validate_timestamp(int timestamp) {
-    return _wire__crate__api__validate_timestamp(timestamp);
-  }
+@protected PresenceDto? dco_decode_opt_box_autoadd_presence_dto(dynamic raw);
 
-  late final _wire__crate__api__validate_timestampPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int64)>>(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__validate_timestamp',
-      );
-  late final _wire__crate__api__validate_timestamp =
-      _wire__crate__api__validate_timestampPtr
-          .asFunction<WireSyncRust2DartDco Function(int)>();
+@protected SuccessorRecordDto? dco_decode_opt_box_autoadd_successor_record_dto(dynamic raw);
 
-  WireSyncRust2DartDco wire__crate__api__verify_db_name(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex,
-  ) {
-    return _wire__crate__api__verify_db_name(db_name, public_key_hex);
-  }
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
 
-  late final _wire__crate__api__verify_db_namePtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_cyberfly_mobile_node_wire__crate__api__verify_db_name');
-  late final _wire__crate__api__verify_db_name =
-      _wire__crate__api__verify_db_namePtr
-          .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
-
-  WireSyncRust2DartDco wire__crate__api__verify_message_signature(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> signature_hex,
-  ) {
-    return _wire__crate__api__verify_message_signature(
-      public_key_hex,
-      message,
-      signature_hex,
-    );
-  }
-
-  late final _wire__crate__api__verify_message_signaturePtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >(
-        'frbgen_cyberfly_mobile_node_wire__crate__api__verify_message_signature',
-      );
-  late final _wire__crate__api__verify_message_signature =
-      _wire__crate__api__verify_message_signaturePtr
-          .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
-  ffi.Pointer<ffi.Int64> cst_new_box_autoadd_i_64(int value) {
-    return _cst_new_box_autoadd_i_64(value);
-  }
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
-  late final _cst_new_box_autoadd_i_64Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int64> Function(ffi.Int64)>>(
-        'frbgen_cyberfly_mobile_node_cst_new_box_autoadd_i_64',
-      );
-  late final _cst_new_box_autoadd_i_64 = _cst_new_box_autoadd_i_64Ptr
-      .asFunction<ffi.Pointer<ffi.Int64> Function(int)>();
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
-  ffi.Pointer<wire_cst_node_info> cst_new_box_autoadd_node_info() {
-    return _cst_new_box_autoadd_node_info();
-  }
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
 
-  late final _cst_new_box_autoadd_node_infoPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_node_info> Function()>>(
-        'frbgen_cyberfly_mobile_node_cst_new_box_autoadd_node_info',
-      );
-  late final _cst_new_box_autoadd_node_info = _cst_new_box_autoadd_node_infoPtr
-      .asFunction<ffi.Pointer<wire_cst_node_info> Function()>();
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
-  ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(int value) {
-    return _cst_new_box_autoadd_u_32(value);
-  }
+@protected PeerCircuitDto dco_decode_peer_circuit_dto(dynamic raw);
 
-  late final _cst_new_box_autoadd_u_32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
-        'frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_32',
-      );
-  late final _cst_new_box_autoadd_u_32 = _cst_new_box_autoadd_u_32Ptr
-      .asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+@protected PeerDiagnosticsDto dco_decode_peer_diagnostics_dto(dynamic raw);
 
-  ffi.Pointer<ffi.Uint64> cst_new_box_autoadd_u_64(int value) {
-    return _cst_new_box_autoadd_u_64(value);
-  }
+@protected PeerInfoDto dco_decode_peer_info_dto(dynamic raw);
 
-  late final _cst_new_box_autoadd_u_64Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>(
-        'frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_64',
-      );
-  late final _cst_new_box_autoadd_u_64 = _cst_new_box_autoadd_u_64Ptr
-      .asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+@protected PeerLatencyDto dco_decode_peer_latency_dto(dynamic raw);
 
-  ffi.Pointer<wire_cst_list_String> cst_new_list_String(int len) {
-    return _cst_new_list_String(len);
-  }
+@protected PeerPathDto dco_decode_peer_path_dto(dynamic raw);
 
-  late final _cst_new_list_StringPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)
-        >
-      >('frbgen_cyberfly_mobile_node_cst_new_list_String');
-  late final _cst_new_list_String = _cst_new_list_StringPtr
-      .asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+@protected PeerSyncStatusDto dco_decode_peer_sync_status_dto(dynamic raw);
 
-  ffi.Pointer<wire_cst_list_db_entry_dto> cst_new_list_db_entry_dto(int len) {
-    return _cst_new_list_db_entry_dto(len);
-  }
+@protected PinnedPeerDto dco_decode_pinned_peer_dto(dynamic raw);
 
-  late final _cst_new_list_db_entry_dtoPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_db_entry_dto> Function(ffi.Int32)
-        >
-      >('frbgen_cyberfly_mobile_node_cst_new_list_db_entry_dto');
-  late final _cst_new_list_db_entry_dto = _cst_new_list_db_entry_dtoPtr
-      .asFunction<ffi.Pointer<wire_cst_list_db_entry_dto> Function(int)>();
+@protected PowerModeDto dco_decode_power_mode_dto(dynamic raw);
 
-  ffi.Pointer<wire_cst_list_log_entry> cst_new_list_log_entry(int len) {
-    return _cst_new_list_log_entry(len);
-  }
+@protected PowerPolicyDto dco_decode_power_policy_dto(dynamic raw);
 
-  late final _cst_new_list_log_entryPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_log_entry> Function(ffi.Int32)
-        >
-      >('frbgen_cyberfly_mobile_node_cst_new_list_log_entry');
-  late final _cst_new_list_log_entry = _cst_new_list_log_entryPtr
-      .asFunction<ffi.Pointer<wire_cst_list_log_entry> Function(int)>();
+@protected PresenceDto dco_decode_presence_dto(dynamic raw);
 
-  ffi.Pointer<wire_cst_list_peer_info_dto> cst_new_list_peer_info_dto(int len) {
-    return _cst_new_list_peer_info_dto(len);
-  }
+@protected QueryFilterDto dco_decode_query_filter_dto(dynamic raw);
 
-  late final _cst_new_list_peer_info_dtoPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_peer_info_dto> Function(ffi.Int32)
-        >
-      >('frbgen_cyberfly_mobile_node_cst_new_list_peer_info_dto');
-  late final _cst_new_list_peer_info_dto = _cst_new_list_peer_info_dtoPtr
-      .asFunction<ffi.Pointer<wire_cst_list_peer_info_dto> Function(int)>();
+@protected QueryResultDto dco_decode_query_result_dto(dynamic raw);
 
-  ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_new_list_prim_u_8_loose(
-    int len,
-  ) {
-    return _cst_new_list_prim_u_8_loose(len);
-  }
+@protected QuerySpecDto dco_decode_query_spec_dto(dynamic raw);
 
-  late final _cst_new_list_prim_u_8_loosePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_prim_u_8_loose> Function(ffi.Int32)
-        >
-      >('frbgen_cyberfly_mobile_node_cst_new_list_prim_u_8_loose');
-  late final _cst_new_list_prim_u_8_loose = _cst_new_list_prim_u_8_loosePtr
-      .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_loose> Function(int)>();
+@protected RekeyedDatabaseDto dco_decode_rekeyed_database_dto(dynamic raw);
 
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(
-    int len,
-  ) {
-    return _cst_new_list_prim_u_8_strict(len);
-  }
+@protected RelayConfigDto dco_decode_relay_config_dto(dynamic raw);
 
-  late final _cst_new_list_prim_u_8_strictPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(ffi.Int32)
-        >
-      >('frbgen_cyberfly_mobile_node_cst_new_list_prim_u_8_strict');
-  late final _cst_new_list_prim_u_8_strict = _cst_new_list_prim_u_8_strictPtr
-      .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
+@protected RelayStatusDto dco_decode_relay_status_dto(dynamic raw);
 
-  int dummy_method_to_enforce_bundling() {
-    return _dummy_method_to_enforce_bundling();
-  }
+@protected ReplicaCheckDto dco_decode_replica_check_dto(dynamic raw);
 
-  late final _dummy_method_to_enforce_bundlingPtr =
-      _lookup<ffi.NativeFunction<ffi.Int64 Function()>>(
-        'dummy_method_to_enforce_bundling',
-      );
-  late final _dummy_method_to_enforce_bundling =
-      _dummy_method_to_enforce_bundlingPtr.asFunction<int Function()>();
-}
+@protected RpcRequestDto dco_decode_rpc_request_dto(dynamic raw);
 
-typedef DartPort = ffi.Int64;
-typedef DartDartPort = int;
-typedef DartPostCObjectFnTypeFunction =
-    ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message);
-typedef DartDartPostCObjectFnTypeFunction =
-    bool Function(DartDartPort port_id, ffi.Pointer<ffi.Void> message);
-typedef DartPostCObjectFnType =
-    ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnTypeFunction>>;
+@protected ScanPageDto dco_decode_scan_page_dto(dynamic raw);
 
-final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint8> ptr;
+@protected ScoredMemberDto dco_decode_scored_member_dto(dynamic raw);
 
-  @ffi.Int32()
-  external int len;
-}
+@protected StartupStageDto dco_decode_startup_stage_dto(dynamic raw);
 
-final class wire_cst_list_String extends ffi.Struct {
-  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+@protected SubsystemHealthDto dco_decode_subsystem_health_dto(dynamic raw);
 
-  @ffi.Int32()
-  external int len;
-}
+@protected SuccessorRecordDto dco_decode_successor_record_dto(dynamic raw);
 
-final class wire_cst_list_prim_u_8_loose extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint8> ptr;
+@protected SyncStatsDto dco_decode_sync_stats_dto(dynamic raw);
 
-  @ffi.Int32()
-  external int len;
-}
+@protected SyncWaitSummaryDto dco_decode_sync_wait_summary_dto(dynamic raw);
 
-final class wire_cst_node_info extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+@protected TextDeltaDto dco_decode_text_delta_dto(dynamic raw);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+@protected TimePointDto dco_decode_time_point_dto(dynamic raw);
 
-  @ffi.Bool()
-  external bool is_running;
-}
+@protected TopicHealthDto dco_decode_topic_health_dto(dynamic raw);
 
-final class wire_cst_db_entry_dto extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+@protected TopicMessageDto dco_decode_topic_message_dto(dynamic raw);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+@protected TopicMetricsDto dco_decode_topic_metrics_dto(dynamic raw);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+@protected TopicStatsDto dco_decode_topic_stats_dto(dynamic raw);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value_bytes;
-}
+@protected int dco_decode_u_16(dynamic raw);
 
-final class wire_cst_list_db_entry_dto extends ffi.Struct {
-  external ffi.Pointer<wire_cst_db_entry_dto> ptr;
+@protected int dco_decode_u_32(dynamic raw);
 
-  @ffi.Int32()
-  external int len;
-}
+@protected BigInt dco_decode_u_64(dynamic raw);
 
-final class wire_cst_log_entry extends ffi.Struct {
-  @ffi.Int64()
-  external int timestamp;
+@protected int dco_decode_u_8(dynamic raw);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> level;
+@protected void dco_decode_unit(dynamic raw);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
-}
+@protected ValueUpdateDto dco_decode_value_update_dto(dynamic raw);
 
-final class wire_cst_list_log_entry extends ffi.Struct {
-  external ffi.Pointer<wire_cst_log_entry> ptr;
+@protected VerifiedBindingDto dco_decode_verified_binding_dto(dynamic raw);
 
-  @ffi.Int32()
-  external int len;
-}
+@protected WakeSyncSummaryDto dco_decode_wake_sync_summary_dto(dynamic raw);
 
-final class wire_cst_peer_info_dto extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+@protected RustStreamSink<ChangeEventDto> sse_decode_StreamSink_change_event_dto_Dco(SseDeserializer deserializer);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+@protected RustStreamSink<DirectMessageDto> sse_decode_StreamSink_direct_message_dto_Dco(SseDeserializer deserializer);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> region;
+@protected RustStreamSink<FileEventDto> sse_decode_StreamSink_file_event_dto_Dco(SseDeserializer deserializer);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> version;
+@protected RustStreamSink<KeyChangeDto> sse_decode_StreamSink_key_change_dto_Dco(SseDeserializer deserializer);
 
-  external ffi.Pointer<ffi.Uint64> latency_ms;
+@protected RustStreamSink<NodeEventRecordDto> sse_decode_StreamSink_node_event_record_dto_Dco(SseDeserializer deserializer);
 
-  @ffi.Bool()
-  external bool is_mobile;
-}
+@protected RustStreamSink<RpcRequestDto> sse_decode_StreamSink_rpc_request_dto_Dco(SseDeserializer deserializer);
 
-final class wire_cst_list_peer_info_dto extends ffi.Struct {
-  external ffi.Pointer<wire_cst_peer_info_dto> ptr;
+@protected RustStreamSink<StartupStageDto> sse_decode_StreamSink_startup_stage_dto_Dco(SseDeserializer deserializer);
 
-  @ffi.Int32()
-  external int len;
-}
+@protected RustStreamSink<TextDeltaDto> sse_decode_StreamSink_text_delta_dto_Dco(SseDeserializer deserializer);
 
-final class wire_cst_key_pair_dto extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+@protected RustStreamSink<TopicMessageDto> sse_decode_StreamSink_topic_message_dto_Dco(SseDeserializer deserializer);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key;
-}
+@protected RustStreamSink<ValueUpdateDto> sse_decode_StreamSink_value_update_dto_Dco(SseDeserializer deserializer);
 
-final class wire_cst_node_status_dto extends ffi.Struct {
-  @ffi.Bool()
-  external bool is_running;
+@protected String sse_decode_String(SseDeserializer deserializer);
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+@protected AcceptedInviteDto sse_decode_accepted_invite_dto(SseDeserializer deserializer);
 
-  @ffi.Uint32()
-  external int connected_peers;
+@protected BackupInfoDto sse_decode_backup_info_dto(SseDeserializer deserializer);
 
-  @ffi.Uint32()
-  external int discovered_peers;
+@protected BandwidthCapsDto sse_decode_bandwidth_caps_dto(SseDeserializer deserializer);
 
-  @ffi.Uint64()
-  external int uptime_seconds;
+@protected BandwidthStatsDto sse_decode_bandwidth_stats_dto(SseDeserializer deserializer);
 
-  @ffi.Uint64()
-  external int gossip_messages_received;
+@protected BandwidthSubsystemDto sse_decode_bandwidth_subsystem_dto(SseDeserializer deserializer);
 
-  @ffi.Uint64()
-  external int storage_size_bytes;
+@protected BandwidthUsageDto sse_decode_bandwidth_usage_dto(SseDeserializer deserializer);
 
-  @ffi.Uint64()
-  external int total_keys;
+@protected BatchEntryDto sse_decode_batch_entry_dto(SseDeserializer deserializer);
 
-  @ffi.Uint32()
-  external int sync_operations;
+@protected bool sse_decode_bool(SseDeserializer deserializer);
 
-  @ffi.Uint64()
-  external int latency_requests_sent;
+@protected BootstrapManifestDto sse_decode_bootstrap_manifest_dto(SseDeserializer deserializer);
 
-  @ffi.Uint64()
-  external int latency_responses_received;
-}
+@protected BandwidthCapsDto sse_decode_box_autoadd_bandwidth_caps_dto(SseDeserializer deserializer);
 
-const int ED25519_PUBLIC_KEY_LENGTH = 32;
+@protected BootstrapManifestDto sse_decode_box_autoadd_bootstrap_manifest_dto(SseDeserializer deserializer);
 
-const int ED25519_SIGNATURE_LENGTH = 64;
+@protected DeviceConditionsDto sse_decode_box_autoadd_device_conditions_dto(SseDeserializer deserializer);
 
-const int MAX_MESSAGE_LENGTH = 1048576;
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
-const int MIN_TIMESTAMP_TOLERANCE = 300;
+@protected FileOfferDto sse_decode_box_autoadd_file_offer_dto(SseDeserializer deserializer);
 
-const int MAX_TIMESTAMP_TOLERANCE = 3600;
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
-const int PEER_EXPIRY_SECS = 300;
+@protected LogFilterDto sse_decode_box_autoadd_log_filter_dto(SseDeserializer deserializer);
 
-const int ANNOUNCE_INTERVAL_SECS = 10;
+@protected MqttBridgeConfigDto sse_decode_box_autoadd_mqtt_bridge_config_dto(SseDeserializer deserializer);
+
+@protected NodeConfigDto sse_decode_box_autoadd_node_config_dto(SseDeserializer deserializer);
+
+@protected NodeInfo sse_decode_box_autoadd_node_info(SseDeserializer deserializer);
+
+@protected OplogGcDto sse_decode_box_autoadd_oplog_gc_dto(SseDeserializer deserializer);
+
+@protected PeerPathDto sse_decode_box_autoadd_peer_path_dto(SseDeserializer deserializer);
+
+@protected PresenceDto sse_decode_box_autoadd_presence_dto(SseDeserializer deserializer);
+
+@protected QuerySpecDto sse_decode_box_autoadd_query_spec_dto(SseDeserializer deserializer);
+
+@protected SuccessorRecordDto sse_decode_box_autoadd_successor_record_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected ChangeEventDto sse_decode_change_event_dto(SseDeserializer deserializer);
+
+@protected CheckpointDto sse_decode_checkpoint_dto(SseDeserializer deserializer);
+
+@protected ConflictDto sse_decode_conflict_dto(SseDeserializer deserializer);
+
+@protected ConnectionDiagnosticsDto sse_decode_connection_diagnostics_dto(SseDeserializer deserializer);
+
+@protected ConnectionResultDto sse_decode_connection_result_dto(SseDeserializer deserializer);
+
+@protected ConnectionTypeDto sse_decode_connection_type_dto(SseDeserializer deserializer);
+
+@protected ConnectivityDto sse_decode_connectivity_dto(SseDeserializer deserializer);
+
+@protected ConnectivityStateDto sse_decode_connectivity_state_dto(SseDeserializer deserializer);
+
+@protected DbEntryDto sse_decode_db_entry_dto(SseDeserializer deserializer);
+
+@protected DbSyncInfoDto sse_decode_db_sync_info_dto(SseDeserializer deserializer);
+
+@protected DebugServerDto sse_decode_debug_server_dto(SseDeserializer deserializer);
+
+@protected DeviceConditionsDto sse_decode_device_conditions_dto(SseDeserializer deserializer);
+
+@protected DirectMessageDto sse_decode_direct_message_dto(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FileEventDto sse_decode_file_event_dto(SseDeserializer deserializer);
+
+@protected FileOfferDto sse_decode_file_offer_dto(SseDeserializer deserializer);
+
+@protected GeoMatchDto sse_decode_geo_match_dto(SseDeserializer deserializer);
+
+@protected HashEntryDto sse_decode_hash_entry_dto(SseDeserializer deserializer);
+
+@protected HealthReportDto sse_decode_health_report_dto(SseDeserializer deserializer);
+
+@protected HealthStatusDto sse_decode_health_status_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IdentityRotationDto sse_decode_identity_rotation_dto(SseDeserializer deserializer);
+
+@protected IntegrityIssueDto sse_decode_integrity_issue_dto(SseDeserializer deserializer);
+
+@protected IntegrityReportDto sse_decode_integrity_report_dto(SseDeserializer deserializer);
+
+@protected KadenaTransactionDto sse_decode_kadena_transaction_dto(SseDeserializer deserializer);
+
+@protected KeyChangeDto sse_decode_key_change_dto(SseDeserializer deserializer);
+
+@protected KeyPairDto sse_decode_key_pair_dto(SseDeserializer deserializer);
+
+@protected LatencyBucketDto sse_decode_latency_bucket_dto(SseDeserializer deserializer);
+
+@protected LinkedDeviceDto sse_decode_linked_device_dto(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<BandwidthUsageDto> sse_decode_list_bandwidth_usage_dto(SseDeserializer deserializer);
+
+@protected List<BatchEntryDto> sse_decode_list_batch_entry_dto(SseDeserializer deserializer);
+
+@protected List<ConflictDto> sse_decode_list_conflict_dto(SseDeserializer deserializer);
+
+@protected List<DbEntryDto> sse_decode_list_db_entry_dto(SseDeserializer deserializer);
+
+@protected List<DirectMessageDto> sse_decode_list_direct_message_dto(SseDeserializer deserializer);
+
+@protected List<FileOfferDto> sse_decode_list_file_offer_dto(SseDeserializer deserializer);
+
+@protected List<GeoMatchDto> sse_decode_list_geo_match_dto(SseDeserializer deserializer);
+
+@protected List<HashEntryDto> sse_decode_list_hash_entry_dto(SseDeserializer deserializer);
+
+@protected List<IntegrityIssueDto> sse_decode_list_integrity_issue_dto(SseDeserializer deserializer);
+
+@protected List<LatencyBucketDto> sse_decode_list_latency_bucket_dto(SseDeserializer deserializer);
+
+@protected List<LinkedDeviceDto> sse_decode_list_linked_device_dto(SseDeserializer deserializer);
+
+@protected List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer);
+
+@protected List<MqttTopicMappingDto> sse_decode_list_mqtt_topic_mapping_dto(SseDeserializer deserializer);
+
+@protected List<NodeEventRecordDto> sse_decode_list_node_event_record_dto(SseDeserializer deserializer);
+
+@protected List<Uint8List?> sse_decode_list_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<PeerCircuitDto> sse_decode_list_peer_circuit_dto(SseDeserializer deserializer);
+
+@protected List<PeerDiagnosticsDto> sse_decode_list_peer_diagnostics_dto(SseDeserializer deserializer);
+
+@protected List<PeerInfoDto> sse_decode_list_peer_info_dto(SseDeserializer deserializer);
+
+@protected List<PeerLatencyDto> sse_decode_list_peer_latency_dto(SseDeserializer deserializer);
+
+@protected List<PeerPathDto> sse_decode_list_peer_path_dto(SseDeserializer deserializer);
+
+@protected List<PeerSyncStatusDto> sse_decode_list_peer_sync_status_dto(SseDeserializer deserializer);
+
+@protected List<PinnedPeerDto> sse_decode_list_pinned_peer_dto(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QueryFilterDto> sse_decode_list_query_filter_dto(SseDeserializer deserializer);
+
+@protected List<RekeyedDatabaseDto> sse_decode_list_rekeyed_database_dto(SseDeserializer deserializer);
+
+@protected List<ReplicaCheckDto> sse_decode_list_replica_check_dto(SseDeserializer deserializer);
+
+@protected List<ScoredMemberDto> sse_decode_list_scored_member_dto(SseDeserializer deserializer);
+
+@protected List<TimePointDto> sse_decode_list_time_point_dto(SseDeserializer deserializer);
+
+@protected List<TopicHealthDto> sse_decode_list_topic_health_dto(SseDeserializer deserializer);
+
+@protected List<TopicMetricsDto> sse_decode_list_topic_metrics_dto(SseDeserializer deserializer);
+
+@protected List<TopicStatsDto> sse_decode_list_topic_stats_dto(SseDeserializer deserializer);
+
+@protected LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+@protected LogFilterDto sse_decode_log_filter_dto(SseDeserializer deserializer);
+
+@protected MetricsDto sse_decode_metrics_dto(SseDeserializer deserializer);
+
+@protected MqttBridgeConfigDto sse_decode_mqtt_bridge_config_dto(SseDeserializer deserializer);
+
+@protected MqttDirectionDto sse_decode_mqtt_direction_dto(SseDeserializer deserializer);
+
+@protected MqttTopicMappingDto sse_decode_mqtt_topic_mapping_dto(SseDeserializer deserializer);
+
+@protected NodeAddrDto sse_decode_node_addr_dto(SseDeserializer deserializer);
+
+@protected NodeConfigDto sse_decode_node_config_dto(SseDeserializer deserializer);
+
+@protected NodeEventDto sse_decode_node_event_dto(SseDeserializer deserializer);
+
+@protected NodeEventRecordDto sse_decode_node_event_record_dto(SseDeserializer deserializer);
+
+@protected NodeInfo sse_decode_node_info(SseDeserializer deserializer);
+
+@protected NodeInstanceDto sse_decode_node_instance_dto(SseDeserializer deserializer);
+
+@protected NodeStatsDto sse_decode_node_stats_dto(SseDeserializer deserializer);
+
+@protected NodeStatusDto sse_decode_node_status_dto(SseDeserializer deserializer);
+
+@protected OplogGcDto sse_decode_oplog_gc_dto(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected BootstrapManifestDto? sse_decode_opt_box_autoadd_bootstrap_manifest_dto(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected MqttBridgeConfigDto? sse_decode_opt_box_autoadd_mqtt_bridge_config_dto(SseDeserializer deserializer);
+
+@protected NodeInfo? sse_decode_opt_box_autoadd_node_info(SseDeserializer deserializer);
+
+@protected OplogGcDto? sse_decode_opt_box_autoadd_oplog_gc_dto(SseDeserializer deserializer);
+
+@protected PeerPathDto? sse_decode_opt_box_autoadd_peer_path_dto(SseDeserializer deserializer);
+
+@protected PresenceDto? sse_decode_opt_box_autoadd_presence_dto(SseDeserializer deserializer);
+
+@protected SuccessorRecordDto? sse_decode_opt_box_autoadd_successor_record_dto(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PeerCircuitDto sse_decode_peer_circuit_dto(SseDeserializer deserializer);
+
+@protected PeerDiagnosticsDto sse_decode_peer_diagnostics_dto(SseDeserializer deserializer);
+
+@protected PeerInfoDto sse_decode_peer_info_dto(SseDeserializer deserializer);
+
+@protected PeerLatencyDto sse_decode_peer_latency_dto(SseDeserializer deserializer);
+
+@protected PeerPathDto sse_decode_peer_path_dto(SseDeserializer deserializer);
+
+@protected PeerSyncStatusDto sse_decode_peer_sync_status_dto(SseDeserializer deserializer);
+
+@protected PinnedPeerDto sse_decode_pinned_peer_dto(SseDeserializer deserializer);
+
+@protected PowerModeDto sse_decode_power_mode_dto(SseDeserializer deserializer);
+
+@protected PowerPolicyDto sse_decode_power_policy_dto(SseDeserializer deserializer);
+
+@protected PresenceDto sse_decode_presence_dto(SseDeserializer deserializer);
+
+@protected QueryFilterDto sse_decode_query_filter_dto(SseDeserializer deserializer);
+
+@protected QueryResultDto sse_decode_query_result_dto(SseDeserializer deserializer);
+
+@protected QuerySpecDto sse_decode_query_spec_dto(SseDeserializer deserializer);
+
+@protected RekeyedDatabaseDto sse_decode_rekeyed_database_dto(SseDeserializer deserializer);
+
+@protected RelayConfigDto sse_decode_relay_config_dto(SseDeserializer deserializer);
+
+@protected RelayStatusDto sse_decode_relay_status_dto(SseDeserializer deserializer);
+
+@protected ReplicaCheckDto sse_decode_replica_check_dto(SseDeserializer deserializer);
+
+@protected RpcRequestDto sse_decode_rpc_request_dto(SseDeserializer deserializer);
+
+@protected ScanPageDto sse_decode_scan_page_dto(SseDeserializer deserializer);
+
+@protected ScoredMemberDto sse_decode_scored_member_dto(SseDeserializer deserializer);
+
+@protected StartupStageDto sse_decode_startup_stage_dto(SseDeserializer deserializer);
+
+@protected SubsystemHealthDto sse_decode_subsystem_health_dto(SseDeserializer deserializer);
+
+@protected SuccessorRecordDto sse_decode_successor_record_dto(SseDeserializer deserializer);
+
+@protected SyncStatsDto sse_decode_sync_stats_dto(SseDeserializer deserializer);
+
+@protected SyncWaitSummaryDto sse_decode_sync_wait_summary_dto(SseDeserializer deserializer);
+
+@protected TextDeltaDto sse_decode_text_delta_dto(SseDeserializer deserializer);
+
+@protected TimePointDto sse_decode_time_point_dto(SseDeserializer deserializer);
+
+@protected TopicHealthDto sse_decode_topic_health_dto(SseDeserializer deserializer);
+
+@protected TopicMessageDto sse_decode_topic_message_dto(SseDeserializer deserializer);
+
+@protected TopicMetricsDto sse_decode_topic_metrics_dto(SseDeserializer deserializer);
+
+@protected TopicStatsDto sse_decode_topic_stats_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected ValueUpdateDto sse_decode_value_update_dto(SseDeserializer deserializer);
+
+@protected VerifiedBindingDto sse_decode_verified_binding_dto(SseDeserializer deserializer);
+
+@protected WakeSyncSummaryDto sse_decode_wake_sync_summary_dto(SseDeserializer deserializer);
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(AnyhowException raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_change_event_dto_Dco(RustStreamSink<ChangeEventDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_change_event_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_direct_message_dto_Dco(RustStreamSink<DirectMessageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_direct_message_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_file_event_dto_Dco(RustStreamSink<FileEventDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_file_event_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_key_change_dto_Dco(RustStreamSink<KeyChangeDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_key_change_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_node_event_record_dto_Dco(RustStreamSink<NodeEventRecordDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_node_event_record_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_rpc_request_dto_Dco(RustStreamSink<RpcRequestDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_rpc_request_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_startup_stage_dto_Dco(RustStreamSink<StartupStageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_startup_stage_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_text_delta_dto_Dco(RustStreamSink<TextDeltaDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_text_delta_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_topic_message_dto_Dco(RustStreamSink<TopicMessageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_topic_message_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_StreamSink_value_update_dto_Dco(RustStreamSink<ValueUpdateDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_value_update_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw)); }
+
+@protected ffi.Pointer<wire_cst_bandwidth_caps_dto> cst_encode_box_autoadd_bandwidth_caps_dto(BandwidthCapsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_bandwidth_caps_dto();
+                    cst_api_fill_to_wire_bandwidth_caps_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_bootstrap_manifest_dto> cst_encode_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_bootstrap_manifest_dto();
+                    cst_api_fill_to_wire_bootstrap_manifest_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_device_conditions_dto> cst_encode_box_autoadd_device_conditions_dto(DeviceConditionsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_device_conditions_dto();
+                    cst_api_fill_to_wire_device_conditions_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<ffi.Double> cst_encode_box_autoadd_f_64(double raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_f_64(cst_encode_f_64(raw)); }
+
+@protected ffi.Pointer<wire_cst_file_offer_dto> cst_encode_box_autoadd_file_offer_dto(FileOfferDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_file_offer_dto();
+                    cst_api_fill_to_wire_file_offer_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<ffi.Int64> cst_encode_box_autoadd_i_64(PlatformInt64 raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_i_64(cst_encode_i_64(raw)); }
+
+@protected ffi.Pointer<wire_cst_log_filter_dto> cst_encode_box_autoadd_log_filter_dto(LogFilterDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_log_filter_dto();
+                    cst_api_fill_to_wire_log_filter_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_mqtt_bridge_config_dto> cst_encode_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_mqtt_bridge_config_dto();
+                    cst_api_fill_to_wire_mqtt_bridge_config_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_node_config_dto> cst_encode_box_autoadd_node_config_dto(NodeConfigDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_node_config_dto();
+                    cst_api_fill_to_wire_node_config_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_node_info> cst_encode_box_autoadd_node_info(NodeInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_node_info();
+                    cst_api_fill_to_wire_node_info(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_oplog_gc_dto> cst_encode_box_autoadd_oplog_gc_dto(OplogGcDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_oplog_gc_dto();
+                    cst_api_fill_to_wire_oplog_gc_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_peer_path_dto> cst_encode_box_autoadd_peer_path_dto(PeerPathDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_peer_path_dto();
+                    cst_api_fill_to_wire_peer_path_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_presence_dto> cst_encode_box_autoadd_presence_dto(PresenceDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_presence_dto();
+                    cst_api_fill_to_wire_presence_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_query_spec_dto> cst_encode_box_autoadd_query_spec_dto(QuerySpecDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_query_spec_dto();
+                    cst_api_fill_to_wire_query_spec_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<wire_cst_successor_record_dto> cst_encode_box_autoadd_successor_record_dto(SuccessorRecordDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ptr = wire.cst_new_box_autoadd_successor_record_dto();
+                    cst_api_fill_to_wire_successor_record_dto(raw, ptr.ref);
+                    return ptr; }
+
+@protected ffi.Pointer<ffi.Uint16> cst_encode_box_autoadd_u_16(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_u_16(cst_encode_u_16(raw)); }
+
+@protected ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_u_32(cst_encode_u_32(raw)); }
+
+@protected ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_u_64(cst_encode_u_64(raw)); }
+
+@protected ffi.Pointer<ffi.Uint8> cst_encode_box_autoadd_u_8(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return wire.cst_new_box_autoadd_u_8(cst_encode_u_8(raw)); }
+
+@protected int cst_encode_i_64(PlatformInt64 raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.toInt(); }
+
+@protected ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_String(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    ans.ref.ptr[i] = cst_encode_String(raw[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_bandwidth_usage_dto> cst_encode_list_bandwidth_usage_dto(List<BandwidthUsageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_bandwidth_usage_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_bandwidth_usage_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_batch_entry_dto> cst_encode_list_batch_entry_dto(List<BatchEntryDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_batch_entry_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_batch_entry_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_conflict_dto> cst_encode_list_conflict_dto(List<ConflictDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_conflict_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_conflict_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_db_entry_dto> cst_encode_list_db_entry_dto(List<DbEntryDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_db_entry_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_db_entry_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_direct_message_dto> cst_encode_list_direct_message_dto(List<DirectMessageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_direct_message_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_direct_message_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_file_offer_dto> cst_encode_list_file_offer_dto(List<FileOfferDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_file_offer_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_file_offer_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_geo_match_dto> cst_encode_list_geo_match_dto(List<GeoMatchDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_geo_match_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_geo_match_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_hash_entry_dto> cst_encode_list_hash_entry_dto(List<HashEntryDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_hash_entry_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_hash_entry_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_integrity_issue_dto> cst_encode_list_integrity_issue_dto(List<IntegrityIssueDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_integrity_issue_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_integrity_issue_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_latency_bucket_dto> cst_encode_list_latency_bucket_dto(List<LatencyBucketDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_latency_bucket_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_latency_bucket_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_linked_device_dto> cst_encode_list_linked_device_dto(List<LinkedDeviceDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_linked_device_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_linked_device_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_log_entry> cst_encode_list_log_entry(List<LogEntry> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_log_entry(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_log_entry(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_mqtt_topic_mapping_dto> cst_encode_list_mqtt_topic_mapping_dto(List<MqttTopicMappingDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_mqtt_topic_mapping_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_mqtt_topic_mapping_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_node_event_record_dto> cst_encode_list_node_event_record_dto(List<NodeEventRecordDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_node_event_record_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_node_event_record_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_opt_list_prim_u_8_strict> cst_encode_list_opt_list_prim_u_8_strict(List<Uint8List?> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_opt_list_prim_u_8_strict(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    ans.ref.ptr[i] = cst_encode_opt_list_prim_u_8_strict(raw[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_peer_circuit_dto> cst_encode_list_peer_circuit_dto(List<PeerCircuitDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_peer_circuit_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_peer_circuit_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_peer_diagnostics_dto> cst_encode_list_peer_diagnostics_dto(List<PeerDiagnosticsDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_peer_diagnostics_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_peer_diagnostics_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_peer_info_dto> cst_encode_list_peer_info_dto(List<PeerInfoDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_peer_info_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_peer_info_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_peer_latency_dto> cst_encode_list_peer_latency_dto(List<PeerLatencyDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_peer_latency_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_peer_latency_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_peer_path_dto> cst_encode_list_peer_path_dto(List<PeerPathDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_peer_path_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_peer_path_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_peer_sync_status_dto> cst_encode_list_peer_sync_status_dto(List<PeerSyncStatusDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_peer_sync_status_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_peer_sync_status_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_pinned_peer_dto> cst_encode_list_pinned_peer_dto(List<PinnedPeerDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_pinned_peer_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_pinned_peer_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_32_strict> cst_encode_list_prim_u_32_strict(Uint32List raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_prim_u_32_strict(raw.length);
+                ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+                return ans; }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_encode_list_prim_u_8_loose(List<int> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_prim_u_8_loose(raw.length);
+                ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+                return ans; }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(Uint8List raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
+                ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+                return ans; }
+
+@protected ffi.Pointer<wire_cst_list_query_filter_dto> cst_encode_list_query_filter_dto(List<QueryFilterDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_query_filter_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_query_filter_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_rekeyed_database_dto> cst_encode_list_rekeyed_database_dto(List<RekeyedDatabaseDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_rekeyed_database_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_rekeyed_database_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_replica_check_dto> cst_encode_list_replica_check_dto(List<ReplicaCheckDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_replica_check_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_replica_check_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_scored_member_dto> cst_encode_list_scored_member_dto(List<ScoredMemberDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_scored_member_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_scored_member_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_time_point_dto> cst_encode_list_time_point_dto(List<TimePointDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_time_point_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_time_point_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_topic_health_dto> cst_encode_list_topic_health_dto(List<TopicHealthDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_topic_health_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_topic_health_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_topic_metrics_dto> cst_encode_list_topic_metrics_dto(List<TopicMetricsDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_topic_metrics_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_topic_metrics_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_topic_stats_dto> cst_encode_list_topic_stats_dto(List<TopicStatsDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+final ans = wire.cst_new_list_topic_stats_dto(raw.length);
+                for (var i = 0; i < raw.length; ++i) {
+                    cst_api_fill_to_wire_topic_stats_dto(raw[i], ans.ref.ptr[i]);
+                }
+                return ans;
+                 }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(String? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_String(raw); }
+
+@protected ffi.Pointer<wire_cst_bootstrap_manifest_dto> cst_encode_opt_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_bootstrap_manifest_dto(raw); }
+
+@protected ffi.Pointer<ffi.Double> cst_encode_opt_box_autoadd_f_64(double? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_f_64(raw); }
+
+@protected ffi.Pointer<ffi.Int64> cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_64(raw); }
+
+@protected ffi.Pointer<wire_cst_mqtt_bridge_config_dto> cst_encode_opt_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_mqtt_bridge_config_dto(raw); }
+
+@protected ffi.Pointer<wire_cst_node_info> cst_encode_opt_box_autoadd_node_info(NodeInfo? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_node_info(raw); }
+
+@protected ffi.Pointer<wire_cst_oplog_gc_dto> cst_encode_opt_box_autoadd_oplog_gc_dto(OplogGcDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_oplog_gc_dto(raw); }
+
+@protected ffi.Pointer<wire_cst_peer_path_dto> cst_encode_opt_box_autoadd_peer_path_dto(PeerPathDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_peer_path_dto(raw); }
+
+@protected ffi.Pointer<wire_cst_presence_dto> cst_encode_opt_box_autoadd_presence_dto(PresenceDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_presence_dto(raw); }
+
+@protected ffi.Pointer<wire_cst_successor_record_dto> cst_encode_opt_box_autoadd_successor_record_dto(SuccessorRecordDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_successor_record_dto(raw); }
+
+@protected ffi.Pointer<ffi.Uint16> cst_encode_opt_box_autoadd_u_16(int? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_16(raw); }
+
+@protected ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw); }
+
+@protected ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw); }
+
+@protected ffi.Pointer<ffi.Uint8> cst_encode_opt_box_autoadd_u_8(int? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_8(raw); }
+
+@protected ffi.Pointer<wire_cst_list_String> cst_encode_opt_list_String(List<String>? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_list_String(raw); }
+
+@protected ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_list_prim_u_8_strict(Uint8List? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? ffi.nullptr : cst_encode_list_prim_u_8_strict(raw); }
+
+@protected int cst_encode_u_64(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.toSigned(64).toInt(); }
+
+@protected void cst_api_fill_to_wire_accepted_invite_dto(AcceptedInviteDto apiObj, wire_cst_accepted_invite_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.owner = cst_encode_String(apiObj.owner);
+wireObj.can_write = cst_encode_bool(apiObj.canWrite);
+wireObj.writer_secret_key = cst_encode_opt_String(apiObj.writerSecretKey);
+wireObj.encrypted = cst_encode_bool(apiObj.encrypted); }
+
+@protected void cst_api_fill_to_wire_backup_info_dto(BackupInfoDto apiObj, wire_cst_backup_info_dto wireObj){ wireObj.created_at = cst_encode_i_64(apiObj.createdAt);
+wireObj.public_key = cst_encode_String(apiObj.publicKey);
+wireObj.tree_count = cst_encode_u_32(apiObj.treeCount);
+wireObj.entry_count = cst_encode_u_64(apiObj.entryCount); }
+
+@protected void cst_api_fill_to_wire_bandwidth_caps_dto(BandwidthCapsDto apiObj, wire_cst_bandwidth_caps_dto wireObj){ wireObj.daily_bytes = cst_encode_opt_box_autoadd_u_64(apiObj.dailyBytes);
+wireObj.monthly_bytes = cst_encode_opt_box_autoadd_u_64(apiObj.monthlyBytes);
+wireObj.metered_only = cst_encode_bool(apiObj.meteredOnly); }
+
+@protected void cst_api_fill_to_wire_bandwidth_stats_dto(BandwidthStatsDto apiObj, wire_cst_bandwidth_stats_dto wireObj){ wireObj.topics = cst_encode_list_bandwidth_usage_dto(apiObj.topics);
+wireObj.subsystems = cst_encode_list_bandwidth_usage_dto(apiObj.subsystems);
+wireObj.today_bytes = cst_encode_u_64(apiObj.todayBytes);
+wireObj.month_bytes = cst_encode_u_64(apiObj.monthBytes);
+cst_api_fill_to_wire_bandwidth_caps_dto(apiObj.caps, wireObj.caps);
+wireObj.cap_exceeded = cst_encode_bool(apiObj.capExceeded); }
+
+@protected void cst_api_fill_to_wire_bandwidth_usage_dto(BandwidthUsageDto apiObj, wire_cst_bandwidth_usage_dto wireObj){ wireObj.name = cst_encode_String(apiObj.name);
+wireObj.subsystem = cst_encode_bandwidth_subsystem_dto(apiObj.subsystem);
+wireObj.bytes_sent = cst_encode_u_64(apiObj.bytesSent);
+wireObj.bytes_received = cst_encode_u_64(apiObj.bytesReceived); }
+
+@protected void cst_api_fill_to_wire_batch_entry_dto(BatchEntryDto apiObj, wire_cst_batch_entry_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.key = cst_encode_String(apiObj.key);
+wireObj.value = cst_encode_list_prim_u_8_strict(apiObj.value);
+wireObj.public_key = cst_encode_String(apiObj.publicKey);
+wireObj.signature = cst_encode_String(apiObj.signature); }
+
+@protected void cst_api_fill_to_wire_bootstrap_manifest_dto(BootstrapManifestDto apiObj, wire_cst_bootstrap_manifest_dto wireObj){ wireObj.url = cst_encode_String(apiObj.url);
+wireObj.publisher_key = cst_encode_String(apiObj.publisherKey); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_bandwidth_caps_dto(BandwidthCapsDto apiObj, ffi.Pointer<wire_cst_bandwidth_caps_dto> wireObj){ cst_api_fill_to_wire_bandwidth_caps_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto apiObj, ffi.Pointer<wire_cst_bootstrap_manifest_dto> wireObj){ cst_api_fill_to_wire_bootstrap_manifest_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_device_conditions_dto(DeviceConditionsDto apiObj, ffi.Pointer<wire_cst_device_conditions_dto> wireObj){ cst_api_fill_to_wire_device_conditions_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_file_offer_dto(FileOfferDto apiObj, ffi.Pointer<wire_cst_file_offer_dto> wireObj){ cst_api_fill_to_wire_file_offer_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_log_filter_dto(LogFilterDto apiObj, ffi.Pointer<wire_cst_log_filter_dto> wireObj){ cst_api_fill_to_wire_log_filter_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto apiObj, ffi.Pointer<wire_cst_mqtt_bridge_config_dto> wireObj){ cst_api_fill_to_wire_mqtt_bridge_config_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_node_config_dto(NodeConfigDto apiObj, ffi.Pointer<wire_cst_node_config_dto> wireObj){ cst_api_fill_to_wire_node_config_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_node_info(NodeInfo apiObj, ffi.Pointer<wire_cst_node_info> wireObj){ cst_api_fill_to_wire_node_info(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_oplog_gc_dto(OplogGcDto apiObj, ffi.Pointer<wire_cst_oplog_gc_dto> wireObj){ cst_api_fill_to_wire_oplog_gc_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_peer_path_dto(PeerPathDto apiObj, ffi.Pointer<wire_cst_peer_path_dto> wireObj){ cst_api_fill_to_wire_peer_path_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_presence_dto(PresenceDto apiObj, ffi.Pointer<wire_cst_presence_dto> wireObj){ cst_api_fill_to_wire_presence_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_query_spec_dto(QuerySpecDto apiObj, ffi.Pointer<wire_cst_query_spec_dto> wireObj){ cst_api_fill_to_wire_query_spec_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_box_autoadd_successor_record_dto(SuccessorRecordDto apiObj, ffi.Pointer<wire_cst_successor_record_dto> wireObj){ cst_api_fill_to_wire_successor_record_dto(apiObj, wireObj.ref); }
+
+@protected void cst_api_fill_to_wire_change_event_dto(ChangeEventDto apiObj, wire_cst_change_event_dto wireObj){ wireObj.seq = cst_encode_u_64(apiObj.seq);
+wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.key = cst_encode_String(apiObj.key);
+wireObj.value = cst_encode_opt_String(apiObj.value);
+wireObj.value_bytes = cst_encode_opt_list_prim_u_8_strict(apiObj.valueBytes); }
+
+@protected void cst_api_fill_to_wire_checkpoint_dto(CheckpointDto apiObj, wire_cst_checkpoint_dto wireObj){ wireObj.high_water_mark = cst_encode_opt_box_autoadd_i_64(apiObj.highWaterMark);
+wireObj.known_peers_saved = cst_encode_u_32(apiObj.knownPeersSaved);
+wireObj.outbox_depth = cst_encode_u_32(apiObj.outboxDepth);
+wireObj.flushed = cst_encode_bool(apiObj.flushed);
+wireObj.duration_ms = cst_encode_u_64(apiObj.durationMs); }
+
+@protected void cst_api_fill_to_wire_conflict_dto(ConflictDto apiObj, wire_cst_conflict_dto wireObj){ wireObj.conflict_key = cst_encode_String(apiObj.conflictKey);
+wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.key = cst_encode_String(apiObj.key);
+wireObj.field = cst_encode_opt_String(apiObj.field);
+wireObj.kept_op_id = cst_encode_String(apiObj.keptOpId);
+wireObj.discarded_op_id = cst_encode_String(apiObj.discardedOpId);
+wireObj.discarded_value = cst_encode_String(apiObj.discardedValue);
+wireObj.discarded_timestamp = cst_encode_i_64(apiObj.discardedTimestamp);
+wireObj.resolved_at = cst_encode_i_64(apiObj.resolvedAt); }
+
+@protected void cst_api_fill_to_wire_connection_diagnostics_dto(ConnectionDiagnosticsDto apiObj, wire_cst_connection_diagnostics_dto wireObj){ wireObj.local_addrs = cst_encode_list_String(apiObj.localAddrs);
+wireObj.public_addrs = cst_encode_list_String(apiObj.publicAddrs);
+wireObj.relay_url = cst_encode_opt_String(apiObj.relayUrl);
+wireObj.nat_type = cst_encode_String(apiObj.natType);
+wireObj.peers = cst_encode_list_peer_diagnostics_dto(apiObj.peers); }
+
+@protected void cst_api_fill_to_wire_connection_result_dto(ConnectionResultDto apiObj, wire_cst_connection_result_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.path = cst_encode_String(apiObj.path);
+wireObj.handshake_ms = cst_encode_u_64(apiObj.handshakeMs); }
+
+@protected void cst_api_fill_to_wire_connectivity_dto(ConnectivityDto apiObj, wire_cst_connectivity_dto wireObj){ wireObj.state = cst_encode_connectivity_state_dto(apiObj.state);
+wireObj.reason = cst_encode_String(apiObj.reason); }
+
+@protected void cst_api_fill_to_wire_db_entry_dto(DbEntryDto apiObj, wire_cst_db_entry_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.key = cst_encode_String(apiObj.key);
+wireObj.value = cst_encode_String(apiObj.value);
+wireObj.value_bytes = cst_encode_list_prim_u_8_strict(apiObj.valueBytes); }
+
+@protected void cst_api_fill_to_wire_db_sync_info_dto(DbSyncInfoDto apiObj, wire_cst_db_sync_info_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.op_count = cst_encode_u_64(apiObj.opCount);
+wireObj.last_local_write = cst_encode_opt_box_autoadd_i_64(apiObj.lastLocalWrite);
+wireObj.last_remote_op = cst_encode_opt_box_autoadd_i_64(apiObj.lastRemoteOp);
+wireObj.last_sync = cst_encode_opt_box_autoadd_i_64(apiObj.lastSync); }
+
+@protected void cst_api_fill_to_wire_debug_server_dto(DebugServerDto apiObj, wire_cst_debug_server_dto wireObj){ wireObj.address = cst_encode_String(apiObj.address);
+wireObj.token = cst_encode_String(apiObj.token); }
+
+@protected void cst_api_fill_to_wire_device_conditions_dto(DeviceConditionsDto apiObj, wire_cst_device_conditions_dto wireObj){ wireObj.battery_percent = cst_encode_opt_box_autoadd_u_8(apiObj.batteryPercent);
+wireObj.charging = cst_encode_bool(apiObj.charging);
+wireObj.connection_type = cst_encode_connection_type_dto(apiObj.connectionType);
+wireObj.metered = cst_encode_bool(apiObj.metered); }
+
+@protected void cst_api_fill_to_wire_direct_message_dto(DirectMessageDto apiObj, wire_cst_direct_message_dto wireObj){ wireObj.id = cst_encode_String(apiObj.id);
+wireObj.peer_id = cst_encode_String(apiObj.peerId);
+wireObj.outgoing = cst_encode_bool(apiObj.outgoing);
+wireObj.text = cst_encode_String(apiObj.text);
+wireObj.sent_at = cst_encode_i_64(apiObj.sentAt); }
+
+@protected void cst_api_fill_to_wire_file_event_dto(FileEventDto apiObj, wire_cst_file_event_dto wireObj){ if (apiObj is FileEventDto_Offer) {
+                var pre_field0 = cst_encode_box_autoadd_file_offer_dto(apiObj.field0);wireObj.tag = 0;wireObj.kind.Offer.field0 = pre_field0;
+                return;
+            }
+if (apiObj is FileEventDto_Progress) {
+                var pre_offer_id = cst_encode_String(apiObj.offerId);
+var pre_received_bytes = cst_encode_u_64(apiObj.receivedBytes);
+var pre_total_bytes = cst_encode_u_64(apiObj.totalBytes);wireObj.tag = 1;wireObj.kind.Progress.offer_id = pre_offer_id;
+wireObj.kind.Progress.received_bytes = pre_received_bytes;
+wireObj.kind.Progress.total_bytes = pre_total_bytes;
+                return;
+            }
+if (apiObj is FileEventDto_Retrying) {
+                var pre_offer_id = cst_encode_String(apiObj.offerId);
+var pre_attempt = cst_encode_u_32(apiObj.attempt);
+var pre_error = cst_encode_String(apiObj.error);wireObj.tag = 2;wireObj.kind.Retrying.offer_id = pre_offer_id;
+wireObj.kind.Retrying.attempt = pre_attempt;
+wireObj.kind.Retrying.error = pre_error;
+                return;
+            }
+if (apiObj is FileEventDto_Completed) {
+                var pre_offer_id = cst_encode_String(apiObj.offerId);
+var pre_path = cst_encode_String(apiObj.path);wireObj.tag = 3;wireObj.kind.Completed.offer_id = pre_offer_id;
+wireObj.kind.Completed.path = pre_path;
+                return;
+            }
+if (apiObj is FileEventDto_Failed) {
+                var pre_offer_id = cst_encode_String(apiObj.offerId);
+var pre_error = cst_encode_String(apiObj.error);wireObj.tag = 4;wireObj.kind.Failed.offer_id = pre_offer_id;
+wireObj.kind.Failed.error = pre_error;
+                return;
+            } }
+
+@protected void cst_api_fill_to_wire_file_offer_dto(FileOfferDto apiObj, wire_cst_file_offer_dto wireObj){ wireObj.offer_id = cst_encode_String(apiObj.offerId);
+wireObj.from = cst_encode_String(apiObj.from);
+wireObj.name = cst_encode_String(apiObj.name);
+wireObj.size = cst_encode_u_64(apiObj.size);
+wireObj.offered_at = cst_encode_i_64(apiObj.offeredAt); }
+
+@protected void cst_api_fill_to_wire_geo_match_dto(GeoMatchDto apiObj, wire_cst_geo_match_dto wireObj){ wireObj.member = cst_encode_String(apiObj.member);
+wireObj.latitude = cst_encode_f_64(apiObj.latitude);
+wireObj.longitude = cst_encode_f_64(apiObj.longitude);
+wireObj.distance_m = cst_encode_f_64(apiObj.distanceM); }
+
+@protected void cst_api_fill_to_wire_hash_entry_dto(HashEntryDto apiObj, wire_cst_hash_entry_dto wireObj){ wireObj.field = cst_encode_String(apiObj.field);
+wireObj.value = cst_encode_String(apiObj.value); }
+
+@protected void cst_api_fill_to_wire_health_report_dto(HealthReportDto apiObj, wire_cst_health_report_dto wireObj){ cst_api_fill_to_wire_subsystem_health_dto(apiObj.storage, wireObj.storage);
+cst_api_fill_to_wire_subsystem_health_dto(apiObj.gossip, wireObj.gossip);
+cst_api_fill_to_wire_subsystem_health_dto(apiObj.sync_, wireObj.sync);
+cst_api_fill_to_wire_subsystem_health_dto(apiObj.discovery, wireObj.discovery);
+cst_api_fill_to_wire_subsystem_health_dto(apiObj.relay, wireObj.relay);
+wireObj.topics = cst_encode_list_topic_health_dto(apiObj.topics);
+wireObj.last_flush_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastFlushAt);
+wireObj.last_sync_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastSyncAt);
+wireObj.last_announcement_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastAnnouncementAt); }
+
+@protected void cst_api_fill_to_wire_identity_rotation_dto(IdentityRotationDto apiObj, wire_cst_identity_rotation_dto wireObj){ cst_api_fill_to_wire_node_info(apiObj.node, wireObj.node);
+cst_api_fill_to_wire_successor_record_dto(apiObj.record, wireObj.record);
+wireObj.rekeyed_databases = cst_encode_list_rekeyed_database_dto(apiObj.rekeyedDatabases);
+wireObj.rekeyed_operations = cst_encode_u_32(apiObj.rekeyedOperations); }
+
+@protected void cst_api_fill_to_wire_integrity_issue_dto(IntegrityIssueDto apiObj, wire_cst_integrity_issue_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.key = cst_encode_String(apiObj.key);
+wireObj.op_id = cst_encode_String(apiObj.opId);
+wireObj.kind = cst_encode_String(apiObj.kind); }
+
+@protected void cst_api_fill_to_wire_integrity_report_dto(IntegrityReportDto apiObj, wire_cst_integrity_report_dto wireObj){ wireObj.checked = cst_encode_u_64(apiObj.checked);
+wireObj.issues = cst_encode_list_integrity_issue_dto(apiObj.issues);
+wireObj.repaired = cst_encode_u_64(apiObj.repaired); }
+
+@protected void cst_api_fill_to_wire_kadena_transaction_dto(KadenaTransactionDto apiObj, wire_cst_kadena_transaction_dto wireObj){ wireObj.hash = cst_encode_String(apiObj.hash);
+wireObj.sig = cst_encode_String(apiObj.sig);
+wireObj.cmd = cst_encode_String(apiObj.cmd);
+wireObj.json = cst_encode_String(apiObj.json); }
+
+@protected void cst_api_fill_to_wire_key_change_dto(KeyChangeDto apiObj, wire_cst_key_change_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.key = cst_encode_String(apiObj.key);
+wireObj.deleted = cst_encode_bool(apiObj.deleted); }
+
+@protected void cst_api_fill_to_wire_key_pair_dto(KeyPairDto apiObj, wire_cst_key_pair_dto wireObj){ wireObj.public_key = cst_encode_String(apiObj.publicKey);
+wireObj.secret_key = cst_encode_String(apiObj.secretKey); }
+
+@protected void cst_api_fill_to_wire_latency_bucket_dto(LatencyBucketDto apiObj, wire_cst_latency_bucket_dto wireObj){ wireObj.le_ms = cst_encode_opt_box_autoadd_f_64(apiObj.leMs);
+wireObj.count = cst_encode_u_64(apiObj.count); }
+
+@protected void cst_api_fill_to_wire_linked_device_dto(LinkedDeviceDto apiObj, wire_cst_linked_device_dto wireObj){ wireObj.user_public_key = cst_encode_String(apiObj.userPublicKey);
+wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.device_name = cst_encode_String(apiObj.deviceName);
+wireObj.linked_at = cst_encode_i_64(apiObj.linkedAt);
+wireObj.this_device = cst_encode_bool(apiObj.thisDevice); }
+
+@protected void cst_api_fill_to_wire_log_entry(LogEntry apiObj, wire_cst_log_entry wireObj){ wireObj.timestamp = cst_encode_i_64(apiObj.timestamp);
+wireObj.level = cst_encode_String(apiObj.level);
+wireObj.message = cst_encode_String(apiObj.message); }
+
+@protected void cst_api_fill_to_wire_log_filter_dto(LogFilterDto apiObj, wire_cst_log_filter_dto wireObj){ wireObj.min_level = cst_encode_opt_String(apiObj.minLevel);
+wireObj.target = cst_encode_opt_String(apiObj.target);
+wireObj.contains = cst_encode_opt_String(apiObj.contains);
+wireObj.since = cst_encode_opt_box_autoadd_i_64(apiObj.since);
+wireObj.until = cst_encode_opt_box_autoadd_i_64(apiObj.until);
+wireObj.limit = cst_encode_opt_box_autoadd_u_32(apiObj.limit); }
+
+@protected void cst_api_fill_to_wire_metrics_dto(MetricsDto apiObj, wire_cst_metrics_dto wireObj){ wireObj.topics = cst_encode_list_topic_metrics_dto(apiObj.topics);
+wireObj.ops_applied = cst_encode_u_64(apiObj.opsApplied);
+wireObj.verify_failures = cst_encode_u_64(apiObj.verifyFailures);
+wireObj.limit_violations = cst_encode_u_64(apiObj.limitViolations);
+wireObj.connect_latency = cst_encode_list_latency_bucket_dto(apiObj.connectLatency);
+wireObj.connect_latency_sum_ms = cst_encode_f_64(apiObj.connectLatencySumMs);
+wireObj.storage_size_bytes = cst_encode_u_64(apiObj.storageSizeBytes);
+wireObj.storage_keys = cst_encode_u_64(apiObj.storageKeys);
+wireObj.stored_operations = cst_encode_u_64(apiObj.storedOperations);
+wireObj.outbox_depth = cst_encode_u_64(apiObj.outboxDepth); }
+
+@protected void cst_api_fill_to_wire_mqtt_bridge_config_dto(MqttBridgeConfigDto apiObj, wire_cst_mqtt_bridge_config_dto wireObj){ wireObj.host = cst_encode_String(apiObj.host);
+wireObj.port = cst_encode_opt_box_autoadd_u_16(apiObj.port);
+wireObj.client_id = cst_encode_String(apiObj.clientId);
+wireObj.username = cst_encode_opt_String(apiObj.username);
+wireObj.password = cst_encode_opt_String(apiObj.password);
+wireObj.mappings = cst_encode_list_mqtt_topic_mapping_dto(apiObj.mappings); }
+
+@protected void cst_api_fill_to_wire_mqtt_topic_mapping_dto(MqttTopicMappingDto apiObj, wire_cst_mqtt_topic_mapping_dto wireObj){ wireObj.topic_prefix = cst_encode_String(apiObj.topicPrefix);
+wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.direction = cst_encode_mqtt_direction_dto(apiObj.direction); }
+
+@protected void cst_api_fill_to_wire_node_addr_dto(NodeAddrDto apiObj, wire_cst_node_addr_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.direct_addresses = cst_encode_list_String(apiObj.directAddresses);
+wireObj.relay_urls = cst_encode_list_String(apiObj.relayUrls); }
+
+@protected void cst_api_fill_to_wire_node_config_dto(NodeConfigDto apiObj, wire_cst_node_config_dto wireObj){ wireObj.region = cst_encode_opt_String(apiObj.region);
+wireObj.network_id = cst_encode_opt_String(apiObj.networkId);
+wireObj.max_known_peer_dials = cst_encode_u_32(apiObj.maxKnownPeerDials);
+wireObj.announce_interval_secs = cst_encode_u_64(apiObj.announceIntervalSecs);
+wireObj.latency_sweep_interval_secs = cst_encode_u_64(apiObj.latencySweepIntervalSecs);
+wireObj.pinned_peers_check_interval_secs = cst_encode_u_64(apiObj.pinnedPeersCheckIntervalSecs);
+wireObj.isolation_check_interval_secs = cst_encode_u_64(apiObj.isolationCheckIntervalSecs);
+wireObj.isolation_checks_before_reconnect = cst_encode_u_32(apiObj.isolationChecksBeforeReconnect);
+wireObj.max_ops_per_response = cst_encode_u_32(apiObj.maxOpsPerResponse);
+wireObj.max_connection_attempts_per_cycle = cst_encode_u_32(apiObj.maxConnectionAttemptsPerCycle);
+wireObj.dial_interval_ms = cst_encode_u_64(apiObj.dialIntervalMs);
+cst_api_fill_to_wire_relay_config_dto(apiObj.relay, wireObj.relay);
+wireObj.bootstrap_manifest = cst_encode_opt_box_autoadd_bootstrap_manifest_dto(apiObj.bootstrapManifest);
+wireObj.max_value_bytes = cst_encode_u_32(apiObj.maxValueBytes);
+wireObj.max_key_length = cst_encode_u_32(apiObj.maxKeyLength);
+wireObj.allowed_store_types = cst_encode_list_String(apiObj.allowedStoreTypes);
+wireObj.oplog_gc = cst_encode_opt_box_autoadd_oplog_gc_dto(apiObj.oplogGc);
+wireObj.max_gossip_message_size = cst_encode_u_32(apiObj.maxGossipMessageSize); }
+
+@protected void cst_api_fill_to_wire_node_event_dto(NodeEventDto apiObj, wire_cst_node_event_dto wireObj){ if (apiObj is NodeEventDto_Started) {
+                var pre_node_id = cst_encode_String(apiObj.nodeId);
+var pre_public_key = cst_encode_String(apiObj.publicKey);wireObj.tag = 0;wireObj.kind.Started.node_id = pre_node_id;
+wireObj.kind.Started.public_key = pre_public_key;
+                return;
+            }
+if (apiObj is NodeEventDto_Stopped) {
+                wireObj.tag = 1;
+                return;
+            }
+if (apiObj is NodeEventDto_PeerConnected) {
+                var pre_peer_id = cst_encode_String(apiObj.peerId);wireObj.tag = 2;wireObj.kind.PeerConnected.peer_id = pre_peer_id;
+                return;
+            }
+if (apiObj is NodeEventDto_PeerDisconnected) {
+                var pre_peer_id = cst_encode_String(apiObj.peerId);wireObj.tag = 3;wireObj.kind.PeerDisconnected.peer_id = pre_peer_id;
+                return;
+            }
+if (apiObj is NodeEventDto_PeerDiscovered) {
+                var pre_peer_id = cst_encode_String(apiObj.peerId);
+var pre_address = cst_encode_opt_String(apiObj.address);wireObj.tag = 4;wireObj.kind.PeerDiscovered.peer_id = pre_peer_id;
+wireObj.kind.PeerDiscovered.address = pre_address;
+                return;
+            }
+if (apiObj is NodeEventDto_GossipReceived) {
+                var pre_topic = cst_encode_String(apiObj.topic);
+var pre_from = cst_encode_String(apiObj.from);
+var pre_content = cst_encode_String(apiObj.content);
+var pre_verified = cst_encode_bool(apiObj.verified);wireObj.tag = 5;wireObj.kind.GossipReceived.topic = pre_topic;
+wireObj.kind.GossipReceived.from = pre_from;
+wireObj.kind.GossipReceived.content = pre_content;
+wireObj.kind.GossipReceived.verified = pre_verified;
+                return;
+            }
+if (apiObj is NodeEventDto_SyncReceived) {
+                var pre_db_name = cst_encode_String(apiObj.dbName);
+var pre_key = cst_encode_String(apiObj.key);wireObj.tag = 6;wireObj.kind.SyncReceived.db_name = pre_db_name;
+wireObj.kind.SyncReceived.key = pre_key;
+                return;
+            }
+if (apiObj is NodeEventDto_SyncProgress) {
+                var pre_db_name = cst_encode_String(apiObj.dbName);
+var pre_ops_received = cst_encode_u_64(apiObj.opsReceived);
+var pre_ops_total_estimate = cst_encode_u_64(apiObj.opsTotalEstimate);
+var pre_bytes = cst_encode_u_64(apiObj.bytes);wireObj.tag = 7;wireObj.kind.SyncProgress.db_name = pre_db_name;
+wireObj.kind.SyncProgress.ops_received = pre_ops_received;
+wireObj.kind.SyncProgress.ops_total_estimate = pre_ops_total_estimate;
+wireObj.kind.SyncProgress.bytes = pre_bytes;
+                return;
+            }
+if (apiObj is NodeEventDto_ConflictResolved) {
+                var pre_db_name = cst_encode_String(apiObj.dbName);
+var pre_key = cst_encode_String(apiObj.key);
+var pre_kept_op_id = cst_encode_String(apiObj.keptOpId);
+var pre_discarded_op_id = cst_encode_String(apiObj.discardedOpId);wireObj.tag = 8;wireObj.kind.ConflictResolved.db_name = pre_db_name;
+wireObj.kind.ConflictResolved.key = pre_key;
+wireObj.kind.ConflictResolved.kept_op_id = pre_kept_op_id;
+wireObj.kind.ConflictResolved.discarded_op_id = pre_discarded_op_id;
+                return;
+            }
+if (apiObj is NodeEventDto_LatencyMeasured) {
+                var pre_peer_id = cst_encode_String(apiObj.peerId);
+var pre_latency_ms = cst_encode_u_64(apiObj.latencyMs);wireObj.tag = 9;wireObj.kind.LatencyMeasured.peer_id = pre_peer_id;
+wireObj.kind.LatencyMeasured.latency_ms = pre_latency_ms;
+                return;
+            }
+if (apiObj is NodeEventDto_PresenceChanged) {
+                var pre_peer_id = cst_encode_String(apiObj.peerId);
+var pre_online = cst_encode_bool(apiObj.online);
+var pre_status = cst_encode_opt_String(apiObj.status);wireObj.tag = 10;wireObj.kind.PresenceChanged.peer_id = pre_peer_id;
+wireObj.kind.PresenceChanged.online = pre_online;
+wireObj.kind.PresenceChanged.status = pre_status;
+                return;
+            }
+if (apiObj is NodeEventDto_TopicRecovered) {
+                var pre_topic = cst_encode_String(apiObj.topic);wireObj.tag = 11;wireObj.kind.TopicRecovered.topic = pre_topic;
+                return;
+            }
+if (apiObj is NodeEventDto_RelayConnected) {
+                var pre_relay_url = cst_encode_String(apiObj.relayUrl);wireObj.tag = 12;wireObj.kind.RelayConnected.relay_url = pre_relay_url;
+                return;
+            }
+if (apiObj is NodeEventDto_BootstrapConnected) {
+                var pre_peer_id = cst_encode_String(apiObj.peerId);wireObj.tag = 13;wireObj.kind.BootstrapConnected.peer_id = pre_peer_id;
+                return;
+            }
+if (apiObj is NodeEventDto_IdentityRotated) {
+                var pre_old_node_id = cst_encode_String(apiObj.oldNodeId);
+var pre_new_node_id = cst_encode_String(apiObj.newNodeId);wireObj.tag = 14;wireObj.kind.IdentityRotated.old_node_id = pre_old_node_id;
+wireObj.kind.IdentityRotated.new_node_id = pre_new_node_id;
+                return;
+            }
+if (apiObj is NodeEventDto_DeviceLinked) {
+                var pre_user_public_key = cst_encode_String(apiObj.userPublicKey);
+var pre_device_node_id = cst_encode_String(apiObj.deviceNodeId);
+var pre_device_name = cst_encode_String(apiObj.deviceName);wireObj.tag = 15;wireObj.kind.DeviceLinked.user_public_key = pre_user_public_key;
+wireObj.kind.DeviceLinked.device_node_id = pre_device_node_id;
+wireObj.kind.DeviceLinked.device_name = pre_device_name;
+                return;
+            }
+if (apiObj is NodeEventDto_ConnectivityChanged) {
+                var pre_state = cst_encode_connectivity_state_dto(apiObj.state);
+var pre_reason = cst_encode_String(apiObj.reason);wireObj.tag = 16;wireObj.kind.ConnectivityChanged.state = pre_state;
+wireObj.kind.ConnectivityChanged.reason = pre_reason;
+                return;
+            }
+if (apiObj is NodeEventDto_Error) {
+                var pre_message = cst_encode_String(apiObj.message);wireObj.tag = 17;wireObj.kind.Error.message = pre_message;
+                return;
+            } }
+
+@protected void cst_api_fill_to_wire_node_event_record_dto(NodeEventRecordDto apiObj, wire_cst_node_event_record_dto wireObj){ wireObj.seq = cst_encode_u_64(apiObj.seq);
+wireObj.timestamp = cst_encode_i_64(apiObj.timestamp);
+cst_api_fill_to_wire_node_event_dto(apiObj.event, wireObj.event); }
+
+@protected void cst_api_fill_to_wire_node_info(NodeInfo apiObj, wire_cst_node_info wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.public_key = cst_encode_String(apiObj.publicKey);
+wireObj.is_running = cst_encode_bool(apiObj.isRunning); }
+
+@protected void cst_api_fill_to_wire_node_instance_dto(NodeInstanceDto apiObj, wire_cst_node_instance_dto wireObj){ wireObj.handle = cst_encode_u_32(apiObj.handle);
+wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.public_key = cst_encode_String(apiObj.publicKey); }
+
+@protected void cst_api_fill_to_wire_node_stats_dto(NodeStatsDto apiObj, wire_cst_node_stats_dto wireObj){ wireObj.uptime_seconds = cst_encode_u_64(apiObj.uptimeSeconds);
+wireObj.total_operations = cst_encode_u_64(apiObj.totalOperations);
+wireObj.bytes_sent = cst_encode_u_64(apiObj.bytesSent);
+wireObj.bytes_received = cst_encode_u_64(apiObj.bytesReceived);
+wireObj.connects = cst_encode_u_64(apiObj.connects);
+wireObj.reconnects = cst_encode_u_64(apiObj.reconnects);
+wireObj.lifetime_sessions = cst_encode_u_64(apiObj.lifetimeSessions);
+wireObj.lifetime_uptime_seconds = cst_encode_u_64(apiObj.lifetimeUptimeSeconds);
+wireObj.lifetime_bytes_sent = cst_encode_u_64(apiObj.lifetimeBytesSent);
+wireObj.lifetime_bytes_received = cst_encode_u_64(apiObj.lifetimeBytesReceived);
+wireObj.lifetime_connects = cst_encode_u_64(apiObj.lifetimeConnects);
+wireObj.lifetime_reconnects = cst_encode_u_64(apiObj.lifetimeReconnects);
+wireObj.outbox_depth = cst_encode_u_64(apiObj.outboxDepth); }
+
+@protected void cst_api_fill_to_wire_node_status_dto(NodeStatusDto apiObj, wire_cst_node_status_dto wireObj){ wireObj.is_running = cst_encode_bool(apiObj.isRunning);
+wireObj.node_id = cst_encode_opt_String(apiObj.nodeId);
+wireObj.connected_peers = cst_encode_u_32(apiObj.connectedPeers);
+wireObj.discovered_peers = cst_encode_u_32(apiObj.discoveredPeers);
+wireObj.uptime_seconds = cst_encode_u_64(apiObj.uptimeSeconds);
+wireObj.gossip_messages_received = cst_encode_u_64(apiObj.gossipMessagesReceived);
+wireObj.storage_size_bytes = cst_encode_u_64(apiObj.storageSizeBytes);
+wireObj.total_keys = cst_encode_u_64(apiObj.totalKeys);
+wireObj.sync_operations = cst_encode_u_32(apiObj.syncOperations);
+wireObj.latency_requests_sent = cst_encode_u_64(apiObj.latencyRequestsSent);
+wireObj.latency_responses_received = cst_encode_u_64(apiObj.latencyResponsesReceived);
+wireObj.topics = cst_encode_list_topic_stats_dto(apiObj.topics); }
+
+@protected void cst_api_fill_to_wire_oplog_gc_dto(OplogGcDto apiObj, wire_cst_oplog_gc_dto wireObj){ wireObj.keep_versions = cst_encode_u_32(apiObj.keepVersions);
+wireObj.keep_for_secs = cst_encode_u_64(apiObj.keepForSecs); }
+
+@protected void cst_api_fill_to_wire_peer_circuit_dto(PeerCircuitDto apiObj, wire_cst_peer_circuit_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.state = cst_encode_String(apiObj.state);
+wireObj.unreachable = cst_encode_bool(apiObj.unreachable);
+wireObj.consecutive_failures = cst_encode_u_32(apiObj.consecutiveFailures);
+wireObj.last_failure_at = cst_encode_i_64(apiObj.lastFailureAt);
+wireObj.retry_at = cst_encode_opt_box_autoadd_i_64(apiObj.retryAt); }
+
+@protected void cst_api_fill_to_wire_peer_diagnostics_dto(PeerDiagnosticsDto apiObj, wire_cst_peer_diagnostics_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.path = cst_encode_opt_box_autoadd_peer_path_dto(apiObj.path);
+wireObj.direct_established = cst_encode_bool(apiObj.directEstablished);
+wireObj.dial_attempts = cst_encode_u_32(apiObj.dialAttempts);
+wireObj.dial_failures = cst_encode_u_32(apiObj.dialFailures);
+wireObj.last_dial_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastDialAt);
+wireObj.last_error = cst_encode_opt_String(apiObj.lastError); }
+
+@protected void cst_api_fill_to_wire_peer_info_dto(PeerInfoDto apiObj, wire_cst_peer_info_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.public_key = cst_encode_String(apiObj.publicKey);
+wireObj.address = cst_encode_opt_String(apiObj.address);
+wireObj.region = cst_encode_opt_String(apiObj.region);
+wireObj.version = cst_encode_opt_String(apiObj.version);
+wireObj.latency_ms = cst_encode_opt_box_autoadd_u_64(apiObj.latencyMs);
+wireObj.is_mobile = cst_encode_bool(apiObj.isMobile); }
+
+@protected void cst_api_fill_to_wire_peer_latency_dto(PeerLatencyDto apiObj, wire_cst_peer_latency_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.latency_ms = cst_encode_opt_box_autoadd_u_64(apiObj.latencyMs);
+wireObj.average_ms = cst_encode_f_64(apiObj.averageMs);
+wireObj.jitter_ms = cst_encode_f_64(apiObj.jitterMs);
+wireObj.samples = cst_encode_u_32(apiObj.samples); }
+
+@protected void cst_api_fill_to_wire_peer_path_dto(PeerPathDto apiObj, wire_cst_peer_path_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.path = cst_encode_String(apiObj.path);
+wireObj.is_mixed = cst_encode_bool(apiObj.isMixed);
+wireObj.direct_addrs = cst_encode_list_String(apiObj.directAddrs);
+wireObj.relay_url = cst_encode_opt_String(apiObj.relayUrl);
+wireObj.updated_at = cst_encode_i_64(apiObj.updatedAt); }
+
+@protected void cst_api_fill_to_wire_peer_sync_status_dto(PeerSyncStatusDto apiObj, wire_cst_peer_sync_status_dto wireObj){ wireObj.peer_id = cst_encode_String(apiObj.peerId);
+wireObj.ops_sent = cst_encode_u_64(apiObj.opsSent);
+wireObj.ops_received = cst_encode_u_64(apiObj.opsReceived);
+wireObj.bytes_sent = cst_encode_u_64(apiObj.bytesSent);
+wireObj.bytes_received = cst_encode_u_64(apiObj.bytesReceived);
+wireObj.last_request_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastRequestAt);
+wireObj.last_response_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastResponseAt);
+wireObj.in_progress = cst_encode_bool(apiObj.inProgress);
+wireObj.ops_acked = cst_encode_u_64(apiObj.opsAcked);
+wireObj.last_ack_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastAckAt); }
+
+@protected void cst_api_fill_to_wire_pinned_peer_dto(PinnedPeerDto apiObj, wire_cst_pinned_peer_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.address = cst_encode_opt_String(apiObj.address); }
+
+@protected void cst_api_fill_to_wire_power_policy_dto(PowerPolicyDto apiObj, wire_cst_power_policy_dto wireObj){ wireObj.low_power = cst_encode_bool(apiObj.lowPower);
+wireObj.metered = cst_encode_bool(apiObj.metered);
+wireObj.sync_deferred = cst_encode_bool(apiObj.syncDeferred);
+wireObj.blob_transfers_allowed = cst_encode_bool(apiObj.blobTransfersAllowed); }
+
+@protected void cst_api_fill_to_wire_presence_dto(PresenceDto apiObj, wire_cst_presence_dto wireObj){ wireObj.peer_id = cst_encode_String(apiObj.peerId);
+wireObj.online = cst_encode_bool(apiObj.online);
+wireObj.status = cst_encode_opt_String(apiObj.status);
+wireObj.last_seen = cst_encode_i_64(apiObj.lastSeen); }
+
+@protected void cst_api_fill_to_wire_query_filter_dto(QueryFilterDto apiObj, wire_cst_query_filter_dto wireObj){ wireObj.field = cst_encode_String(apiObj.field);
+wireObj.op = cst_encode_String(apiObj.op);
+wireObj.value_json = cst_encode_String(apiObj.valueJson); }
+
+@protected void cst_api_fill_to_wire_query_result_dto(QueryResultDto apiObj, wire_cst_query_result_dto wireObj){ wireObj.entries = cst_encode_list_db_entry_dto(apiObj.entries);
+wireObj.has_more = cst_encode_bool(apiObj.hasMore); }
+
+@protected void cst_api_fill_to_wire_query_spec_dto(QuerySpecDto apiObj, wire_cst_query_spec_dto wireObj){ wireObj.key_prefix = cst_encode_opt_String(apiObj.keyPrefix);
+wireObj.filters = cst_encode_list_query_filter_dto(apiObj.filters);
+wireObj.sort_by = cst_encode_opt_String(apiObj.sortBy);
+wireObj.descending = cst_encode_bool(apiObj.descending);
+wireObj.limit = cst_encode_u_32(apiObj.limit);
+wireObj.offset = cst_encode_u_32(apiObj.offset);
+wireObj.fields = cst_encode_opt_list_String(apiObj.fields); }
+
+@protected void cst_api_fill_to_wire_rekeyed_database_dto(RekeyedDatabaseDto apiObj, wire_cst_rekeyed_database_dto wireObj){ wireObj.old_name = cst_encode_String(apiObj.oldName);
+wireObj.new_name = cst_encode_String(apiObj.newName); }
+
+@protected void cst_api_fill_to_wire_relay_config_dto(RelayConfigDto apiObj, wire_cst_relay_config_dto wireObj){ if (apiObj is RelayConfigDto_Default) {
+                wireObj.tag = 0;
+                return;
+            }
+if (apiObj is RelayConfigDto_Disabled) {
+                wireObj.tag = 1;
+                return;
+            }
+if (apiObj is RelayConfigDto_Custom) {
+                var pre_urls = cst_encode_list_String(apiObj.urls);wireObj.tag = 2;wireObj.kind.Custom.urls = pre_urls;
+                return;
+            } }
+
+@protected void cst_api_fill_to_wire_relay_status_dto(RelayStatusDto apiObj, wire_cst_relay_status_dto wireObj){ cst_api_fill_to_wire_relay_config_dto(apiObj.relay, wireObj.relay);
+wireObj.active_url = cst_encode_opt_String(apiObj.activeUrl);
+wireObj.rtt_ms = cst_encode_opt_box_autoadd_u_64(apiObj.rttMs); }
+
+@protected void cst_api_fill_to_wire_replica_check_dto(ReplicaCheckDto apiObj, wire_cst_replica_check_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.publisher = cst_encode_String(apiObj.publisher);
+wireObj.matches = cst_encode_bool(apiObj.matches);
+wireObj.mismatched_buckets = cst_encode_u_32(apiObj.mismatchedBuckets);
+wireObj.checked_at = cst_encode_i_64(apiObj.checkedAt); }
+
+@protected void cst_api_fill_to_wire_rpc_request_dto(RpcRequestDto apiObj, wire_cst_rpc_request_dto wireObj){ wireObj.request_id = cst_encode_u_64(apiObj.requestId);
+wireObj.peer_id = cst_encode_String(apiObj.peerId);
+wireObj.method = cst_encode_String(apiObj.method);
+wireObj.payload = cst_encode_list_prim_u_8_strict(apiObj.payload); }
+
+@protected void cst_api_fill_to_wire_scan_page_dto(ScanPageDto apiObj, wire_cst_scan_page_dto wireObj){ wireObj.entries = cst_encode_list_db_entry_dto(apiObj.entries);
+wireObj.next_cursor = cst_encode_opt_String(apiObj.nextCursor); }
+
+@protected void cst_api_fill_to_wire_scored_member_dto(ScoredMemberDto apiObj, wire_cst_scored_member_dto wireObj){ wireObj.member = cst_encode_String(apiObj.member);
+wireObj.score = cst_encode_f_64(apiObj.score); }
+
+@protected void cst_api_fill_to_wire_startup_stage_dto(StartupStageDto apiObj, wire_cst_startup_stage_dto wireObj){ if (apiObj is StartupStageDto_KeyLoaded) {
+                wireObj.tag = 0;
+                return;
+            }
+if (apiObj is StartupStageDto_EndpointOnline) {
+                var pre_relay_connected = cst_encode_bool(apiObj.relayConnected);wireObj.tag = 1;wireObj.kind.EndpointOnline.relay_connected = pre_relay_connected;
+                return;
+            }
+if (apiObj is StartupStageDto_BootstrapConnecting) {
+                var pre_attempt = cst_encode_u_32(apiObj.attempt);wireObj.tag = 2;wireObj.kind.BootstrapConnecting.attempt = pre_attempt;
+                return;
+            }
+if (apiObj is StartupStageDto_TopicsSubscribed) {
+                wireObj.tag = 3;
+                return;
+            }
+if (apiObj is StartupStageDto_Ready) {
+                wireObj.tag = 4;
+                return;
+            } }
+
+@protected void cst_api_fill_to_wire_subsystem_health_dto(SubsystemHealthDto apiObj, wire_cst_subsystem_health_dto wireObj){ wireObj.status = cst_encode_health_status_dto(apiObj.status);
+wireObj.reason = cst_encode_String(apiObj.reason); }
+
+@protected void cst_api_fill_to_wire_successor_record_dto(SuccessorRecordDto apiObj, wire_cst_successor_record_dto wireObj){ wireObj.old_node_id = cst_encode_String(apiObj.oldNodeId);
+wireObj.new_node_id = cst_encode_String(apiObj.newNodeId);
+wireObj.timestamp = cst_encode_i_64(apiObj.timestamp);
+wireObj.grace_until = cst_encode_i_64(apiObj.graceUntil); }
+
+@protected void cst_api_fill_to_wire_sync_stats_dto(SyncStatsDto apiObj, wire_cst_sync_stats_dto wireObj){ wireObj.total_operations = cst_encode_u_64(apiObj.totalOperations);
+wireObj.ops_sent = cst_encode_u_64(apiObj.opsSent);
+wireObj.ops_received = cst_encode_u_64(apiObj.opsReceived);
+wireObj.bytes_sent = cst_encode_u_64(apiObj.bytesSent);
+wireObj.bytes_received = cst_encode_u_64(apiObj.bytesReceived);
+wireObj.last_sync_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastSyncAt);
+wireObj.peers = cst_encode_list_peer_sync_status_dto(apiObj.peers); }
+
+@protected void cst_api_fill_to_wire_sync_wait_summary_dto(SyncWaitSummaryDto apiObj, wire_cst_sync_wait_summary_dto wireObj){ wireObj.synced = cst_encode_bool(apiObj.synced);
+wireObj.queued_ops = cst_encode_u_32(apiObj.queuedOps);
+wireObj.unacknowledged_ops = cst_encode_u_32(apiObj.unacknowledgedOps);
+wireObj.duration_ms = cst_encode_u_64(apiObj.durationMs); }
+
+@protected void cst_api_fill_to_wire_text_delta_dto(TextDeltaDto apiObj, wire_cst_text_delta_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.key = cst_encode_String(apiObj.key);
+wireObj.index = cst_encode_u_64(apiObj.index);
+wireObj.deleted = cst_encode_u_64(apiObj.deleted);
+wireObj.inserted = cst_encode_String(apiObj.inserted); }
+
+@protected void cst_api_fill_to_wire_time_point_dto(TimePointDto apiObj, wire_cst_time_point_dto wireObj){ wireObj.timestamp = cst_encode_i_64(apiObj.timestamp);
+wireObj.value = cst_encode_f_64(apiObj.value); }
+
+@protected void cst_api_fill_to_wire_topic_health_dto(TopicHealthDto apiObj, wire_cst_topic_health_dto wireObj){ wireObj.topic = cst_encode_String(apiObj.topic);
+wireObj.subscribed = cst_encode_bool(apiObj.subscribed);
+wireObj.neighbors = cst_encode_u_32(apiObj.neighbors); }
+
+@protected void cst_api_fill_to_wire_topic_message_dto(TopicMessageDto apiObj, wire_cst_topic_message_dto wireObj){ wireObj.topic = cst_encode_String(apiObj.topic);
+wireObj.delivered_from = cst_encode_String(apiObj.deliveredFrom);
+wireObj.content = cst_encode_list_prim_u_8_strict(apiObj.content); }
+
+@protected void cst_api_fill_to_wire_topic_metrics_dto(TopicMetricsDto apiObj, wire_cst_topic_metrics_dto wireObj){ wireObj.topic = cst_encode_String(apiObj.topic);
+wireObj.messages_sent = cst_encode_u_64(apiObj.messagesSent);
+wireObj.messages_received = cst_encode_u_64(apiObj.messagesReceived);
+wireObj.duplicates_dropped = cst_encode_u_64(apiObj.duplicatesDropped);
+wireObj.bytes_sent = cst_encode_u_64(apiObj.bytesSent);
+wireObj.bytes_received = cst_encode_u_64(apiObj.bytesReceived); }
+
+@protected void cst_api_fill_to_wire_topic_stats_dto(TopicStatsDto apiObj, wire_cst_topic_stats_dto wireObj){ wireObj.topic = cst_encode_String(apiObj.topic);
+wireObj.messages_sent = cst_encode_u_64(apiObj.messagesSent);
+wireObj.messages_received = cst_encode_u_64(apiObj.messagesReceived);
+wireObj.bytes_sent = cst_encode_u_64(apiObj.bytesSent);
+wireObj.bytes_received = cst_encode_u_64(apiObj.bytesReceived);
+wireObj.neighbors = cst_encode_u_32(apiObj.neighbors);
+wireObj.last_event_at = cst_encode_opt_box_autoadd_i_64(apiObj.lastEventAt); }
+
+@protected void cst_api_fill_to_wire_value_update_dto(ValueUpdateDto apiObj, wire_cst_value_update_dto wireObj){ wireObj.db_name = cst_encode_String(apiObj.dbName);
+wireObj.key = cst_encode_String(apiObj.key);
+wireObj.value = cst_encode_opt_String(apiObj.value);
+wireObj.value_bytes = cst_encode_opt_list_prim_u_8_strict(apiObj.valueBytes); }
+
+@protected void cst_api_fill_to_wire_verified_binding_dto(VerifiedBindingDto apiObj, wire_cst_verified_binding_dto wireObj){ wireObj.node_id = cst_encode_String(apiObj.nodeId);
+wireObj.public_key = cst_encode_String(apiObj.publicKey);
+wireObj.verified_at = cst_encode_i_64(apiObj.verifiedAt); }
+
+@protected void cst_api_fill_to_wire_wake_sync_summary_dto(WakeSyncSummaryDto apiObj, wire_cst_wake_sync_summary_dto wireObj){ wireObj.started_node = cst_encode_bool(apiObj.startedNode);
+wireObj.pinned_peers_dialed = cst_encode_u_32(apiObj.pinnedPeersDialed);
+wireObj.pinned_peers_connected = cst_encode_u_32(apiObj.pinnedPeersConnected);
+wireObj.synced = cst_encode_bool(apiObj.synced);
+wireObj.ops_received = cst_encode_u_64(apiObj.opsReceived);
+wireObj.ops_sent = cst_encode_u_64(apiObj.opsSent);
+wireObj.outbox_sent = cst_encode_u_32(apiObj.outboxSent);
+wireObj.outbox_remaining = cst_encode_u_32(apiObj.outboxRemaining);
+wireObj.duration_ms = cst_encode_u_64(apiObj.durationMs); }
+
+@protected int cst_encode_bandwidth_subsystem_dto(BandwidthSubsystemDto raw);
+
+@protected bool cst_encode_bool(bool raw);
+
+@protected int cst_encode_connection_type_dto(ConnectionTypeDto raw);
+
+@protected int cst_encode_connectivity_state_dto(ConnectivityStateDto raw);
+
+@protected double cst_encode_f_64(double raw);
+
+@protected int cst_encode_health_status_dto(HealthStatusDto raw);
+
+@protected int cst_encode_i_32(int raw);
+
+@protected int cst_encode_mqtt_direction_dto(MqttDirectionDto raw);
+
+@protected int cst_encode_power_mode_dto(PowerModeDto raw);
+
+@protected int cst_encode_u_16(int raw);
+
+@protected int cst_encode_u_32(int raw);
+
+@protected int cst_encode_u_8(int raw);
+
+@protected void cst_encode_unit(void raw);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_change_event_dto_Dco(RustStreamSink<ChangeEventDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_direct_message_dto_Dco(RustStreamSink<DirectMessageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_file_event_dto_Dco(RustStreamSink<FileEventDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_key_change_dto_Dco(RustStreamSink<KeyChangeDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_node_event_record_dto_Dco(RustStreamSink<NodeEventRecordDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_rpc_request_dto_Dco(RustStreamSink<RpcRequestDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_startup_stage_dto_Dco(RustStreamSink<StartupStageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_text_delta_dto_Dco(RustStreamSink<TextDeltaDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_topic_message_dto_Dco(RustStreamSink<TopicMessageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_value_update_dto_Dco(RustStreamSink<ValueUpdateDto> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_accepted_invite_dto(AcceptedInviteDto self, SseSerializer serializer);
+
+@protected void sse_encode_backup_info_dto(BackupInfoDto self, SseSerializer serializer);
+
+@protected void sse_encode_bandwidth_caps_dto(BandwidthCapsDto self, SseSerializer serializer);
+
+@protected void sse_encode_bandwidth_stats_dto(BandwidthStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_bandwidth_subsystem_dto(BandwidthSubsystemDto self, SseSerializer serializer);
+
+@protected void sse_encode_bandwidth_usage_dto(BandwidthUsageDto self, SseSerializer serializer);
+
+@protected void sse_encode_batch_entry_dto(BatchEntryDto self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_bootstrap_manifest_dto(BootstrapManifestDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bandwidth_caps_dto(BandwidthCapsDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_device_conditions_dto(DeviceConditionsDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_file_offer_dto(FileOfferDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_log_filter_dto(LogFilterDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_node_config_dto(NodeConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_node_info(NodeInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_oplog_gc_dto(OplogGcDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_peer_path_dto(PeerPathDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_presence_dto(PresenceDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_query_spec_dto(QuerySpecDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_successor_record_dto(SuccessorRecordDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_change_event_dto(ChangeEventDto self, SseSerializer serializer);
+
+@protected void sse_encode_checkpoint_dto(CheckpointDto self, SseSerializer serializer);
+
+@protected void sse_encode_conflict_dto(ConflictDto self, SseSerializer serializer);
+
+@protected void sse_encode_connection_diagnostics_dto(ConnectionDiagnosticsDto self, SseSerializer serializer);
+
+@protected void sse_encode_connection_result_dto(ConnectionResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_connection_type_dto(ConnectionTypeDto self, SseSerializer serializer);
+
+@protected void sse_encode_connectivity_dto(ConnectivityDto self, SseSerializer serializer);
+
+@protected void sse_encode_connectivity_state_dto(ConnectivityStateDto self, SseSerializer serializer);
+
+@protected void sse_encode_db_entry_dto(DbEntryDto self, SseSerializer serializer);
+
+@protected void sse_encode_db_sync_info_dto(DbSyncInfoDto self, SseSerializer serializer);
+
+@protected void sse_encode_debug_server_dto(DebugServerDto self, SseSerializer serializer);
+
+@protected void sse_encode_device_conditions_dto(DeviceConditionsDto self, SseSerializer serializer);
+
+@protected void sse_encode_direct_message_dto(DirectMessageDto self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_file_event_dto(FileEventDto self, SseSerializer serializer);
+
+@protected void sse_encode_file_offer_dto(FileOfferDto self, SseSerializer serializer);
+
+@protected void sse_encode_geo_match_dto(GeoMatchDto self, SseSerializer serializer);
+
+@protected void sse_encode_hash_entry_dto(HashEntryDto self, SseSerializer serializer);
+
+@protected void sse_encode_health_report_dto(HealthReportDto self, SseSerializer serializer);
+
+@protected void sse_encode_health_status_dto(HealthStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_identity_rotation_dto(IdentityRotationDto self, SseSerializer serializer);
+
+@protected void sse_encode_integrity_issue_dto(IntegrityIssueDto self, SseSerializer serializer);
+
+@protected void sse_encode_integrity_report_dto(IntegrityReportDto self, SseSerializer serializer);
+
+@protected void sse_encode_kadena_transaction_dto(KadenaTransactionDto self, SseSerializer serializer);
+
+@protected void sse_encode_key_change_dto(KeyChangeDto self, SseSerializer serializer);
+
+@protected void sse_encode_key_pair_dto(KeyPairDto self, SseSerializer serializer);
+
+@protected void sse_encode_latency_bucket_dto(LatencyBucketDto self, SseSerializer serializer);
+
+@protected void sse_encode_linked_device_dto(LinkedDeviceDto self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_bandwidth_usage_dto(List<BandwidthUsageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_batch_entry_dto(List<BatchEntryDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_conflict_dto(List<ConflictDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_db_entry_dto(List<DbEntryDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_direct_message_dto(List<DirectMessageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_file_offer_dto(List<FileOfferDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_geo_match_dto(List<GeoMatchDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_hash_entry_dto(List<HashEntryDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_integrity_issue_dto(List<IntegrityIssueDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_latency_bucket_dto(List<LatencyBucketDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_linked_device_dto(List<LinkedDeviceDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_log_entry(List<LogEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mqtt_topic_mapping_dto(List<MqttTopicMappingDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_node_event_record_dto(List<NodeEventRecordDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_list_prim_u_8_strict(List<Uint8List?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_circuit_dto(List<PeerCircuitDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_diagnostics_dto(List<PeerDiagnosticsDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_info_dto(List<PeerInfoDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_latency_dto(List<PeerLatencyDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_path_dto(List<PeerPathDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_sync_status_dto(List<PeerSyncStatusDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_pinned_peer_dto(List<PinnedPeerDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_filter_dto(List<QueryFilterDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_rekeyed_database_dto(List<RekeyedDatabaseDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_replica_check_dto(List<ReplicaCheckDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_scored_member_dto(List<ScoredMemberDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_time_point_dto(List<TimePointDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_topic_health_dto(List<TopicHealthDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_topic_metrics_dto(List<TopicMetricsDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_topic_stats_dto(List<TopicStatsDto> self, SseSerializer serializer);
+
+@protected void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+
+@protected void sse_encode_log_filter_dto(LogFilterDto self, SseSerializer serializer);
+
+@protected void sse_encode_metrics_dto(MetricsDto self, SseSerializer serializer);
+
+@protected void sse_encode_mqtt_bridge_config_dto(MqttBridgeConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_mqtt_direction_dto(MqttDirectionDto self, SseSerializer serializer);
+
+@protected void sse_encode_mqtt_topic_mapping_dto(MqttTopicMappingDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_addr_dto(NodeAddrDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_config_dto(NodeConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_event_dto(NodeEventDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_event_record_dto(NodeEventRecordDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_info(NodeInfo self, SseSerializer serializer);
+
+@protected void sse_encode_node_instance_dto(NodeInstanceDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_stats_dto(NodeStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_status_dto(NodeStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_oplog_gc_dto(OplogGcDto self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_node_info(NodeInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_oplog_gc_dto(OplogGcDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_peer_path_dto(PeerPathDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_presence_dto(PresenceDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_successor_record_dto(SuccessorRecordDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_peer_circuit_dto(PeerCircuitDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_diagnostics_dto(PeerDiagnosticsDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_info_dto(PeerInfoDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_latency_dto(PeerLatencyDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_path_dto(PeerPathDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_sync_status_dto(PeerSyncStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_pinned_peer_dto(PinnedPeerDto self, SseSerializer serializer);
+
+@protected void sse_encode_power_mode_dto(PowerModeDto self, SseSerializer serializer);
+
+@protected void sse_encode_power_policy_dto(PowerPolicyDto self, SseSerializer serializer);
+
+@protected void sse_encode_presence_dto(PresenceDto self, SseSerializer serializer);
+
+@protected void sse_encode_query_filter_dto(QueryFilterDto self, SseSerializer serializer);
+
+@protected void sse_encode_query_result_dto(QueryResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_query_spec_dto(QuerySpecDto self, SseSerializer serializer);
+
+@protected void sse_encode_rekeyed_database_dto(RekeyedDatabaseDto self, SseSerializer serializer);
+
+@protected void sse_encode_relay_config_dto(RelayConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_relay_status_dto(RelayStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_replica_check_dto(ReplicaCheckDto self, SseSerializer serializer);
+
+@protected void sse_encode_rpc_request_dto(RpcRequestDto self, SseSerializer serializer);
+
+@protected void sse_encode_scan_page_dto(ScanPageDto self, SseSerializer serializer);
+
+@protected void sse_encode_scored_member_dto(ScoredMemberDto self, SseSerializer serializer);
+
+@protected void sse_encode_startup_stage_dto(StartupStageDto self, SseSerializer serializer);
+
+@protected void sse_encode_subsystem_health_dto(SubsystemHealthDto self, SseSerializer serializer);
+
+@protected void sse_encode_successor_record_dto(SuccessorRecordDto self, SseSerializer serializer);
+
+@protected void sse_encode_sync_stats_dto(SyncStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_sync_wait_summary_dto(SyncWaitSummaryDto self, SseSerializer serializer);
+
+@protected void sse_encode_text_delta_dto(TextDeltaDto self, SseSerializer serializer);
+
+@protected void sse_encode_time_point_dto(TimePointDto self, SseSerializer serializer);
+
+@protected void sse_encode_topic_health_dto(TopicHealthDto self, SseSerializer serializer);
+
+@protected void sse_encode_topic_message_dto(TopicMessageDto self, SseSerializer serializer);
+
+@protected void sse_encode_topic_metrics_dto(TopicMetricsDto self, SseSerializer serializer);
+
+@protected void sse_encode_topic_stats_dto(TopicStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_value_update_dto(ValueUpdateDto self, SseSerializer serializer);
+
+@protected void sse_encode_verified_binding_dto(VerifiedBindingDto self, SseSerializer serializer);
+
+@protected void sse_encode_wake_sync_summary_dto(WakeSyncSummaryDto self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+// ignore_for_file: type=lint
+
+/// generated by flutter_rust_bridge
+class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+      _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+      : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  RustLibWire.fromLookup(
+      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+          lookup)
+      : _lookup = lookup;
+
+  void store_dart_post_cobject(DartPostCObjectFnType ptr) {
+    return _store_dart_post_cobject(ptr);
+  }
+
+  late final _store_dart_post_cobjectPtr = _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>('store_dart_post_cobject');
+  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr.asFunction<void Function(DartPostCObjectFnType)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__accept_db_invite(ffi.Pointer<wire_cst_list_prim_u_8_strict> token) {
+    return _wire__crate__api__accept_db_invite(token);
+  }
+
+  late final _wire__crate__api__accept_db_invitePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__accept_db_invite');
+  late final _wire__crate__api__accept_db_invite = _wire__crate__api__accept_db_invitePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__accept_db_invite_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> token) {
+    return _wire__crate__api__accept_db_invite_on(handle, token);
+  }
+
+  late final _wire__crate__api__accept_db_invite_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__accept_db_invite_on');
+  late final _wire__crate__api__accept_db_invite_on = _wire__crate__api__accept_db_invite_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__accept_file(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> dest_path) {
+    return _wire__crate__api__accept_file(port_, offer_id, dest_path);
+  }
+
+  late final _wire__crate__api__accept_filePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__accept_file');
+  late final _wire__crate__api__accept_file = _wire__crate__api__accept_filePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__accept_file_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> dest_path) {
+    return _wire__crate__api__accept_file_on(port_, handle, offer_id, dest_path);
+  }
+
+  late final _wire__crate__api__accept_file_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__accept_file_on');
+  late final _wire__crate__api__accept_file_on = _wire__crate__api__accept_file_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__add_log_entry(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> level, ffi.Pointer<wire_cst_list_prim_u_8_strict> message) {
+    return _wire__crate__api__add_log_entry(port_, level, message);
+  }
+
+  late final _wire__crate__api__add_log_entryPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__add_log_entry');
+  late final _wire__crate__api__add_log_entry = _wire__crate__api__add_log_entryPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__blake3_hash(ffi.Pointer<wire_cst_list_prim_u_8_loose> data) {
+    return _wire__crate__api__blake3_hash(data);
+  }
+
+  late final _wire__crate__api__blake3_hashPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__blake3_hash');
+  late final _wire__crate__api__blake3_hash = _wire__crate__api__blake3_hashPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__change_feed(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, int since_seq, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__change_feed(db_name, since_seq, sink);
+  }
+
+  late final _wire__crate__api__change_feedPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__change_feed');
+  late final _wire__crate__api__change_feed = _wire__crate__api__change_feedPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__change_feed_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, int since_seq, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__change_feed_on(handle, db_name, since_seq, sink);
+  }
+
+  late final _wire__crate__api__change_feed_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__change_feed_on');
+  late final _wire__crate__api__change_feed_on = _wire__crate__api__change_feed_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__checkpoint(int port_, ffi.Pointer<ffi.Uint64> budget_ms) {
+    return _wire__crate__api__checkpoint(port_, budget_ms);
+  }
+
+  late final _wire__crate__api__checkpointPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__checkpoint');
+  late final _wire__crate__api__checkpoint = _wire__crate__api__checkpointPtr.asFunction<void Function(int, ffi.Pointer<ffi.Uint64>)>();
+
+  void wire__crate__api__checkpoint_on(int port_, int handle, ffi.Pointer<ffi.Uint64> budget_ms) {
+    return _wire__crate__api__checkpoint_on(port_, handle, budget_ms);
+  }
+
+  late final _wire__crate__api__checkpoint_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__checkpoint_on');
+  late final _wire__crate__api__checkpoint_on = _wire__crate__api__checkpoint_onPtr.asFunction<void Function(int, int, ffi.Pointer<ffi.Uint64>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__clear_logs() {
+    return _wire__crate__api__clear_logs();
+  }
+
+  late final _wire__crate__api__clear_logsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__clear_logs');
+  late final _wire__crate__api__clear_logs = _wire__crate__api__clear_logsPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__close_storage_read_only() {
+    return _wire__crate__api__close_storage_read_only();
+  }
+
+  late final _wire__crate__api__close_storage_read_onlyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__close_storage_read_only');
+  late final _wire__crate__api__close_storage_read_only = _wire__crate__api__close_storage_read_onlyPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__compare_and_swap(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> expected, ffi.Pointer<wire_cst_list_prim_u_8_strict> new_value) {
+    return _wire__crate__api__compare_and_swap(db_name, key, expected, new_value);
+  }
+
+  late final _wire__crate__api__compare_and_swapPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__compare_and_swap');
+  late final _wire__crate__api__compare_and_swap = _wire__crate__api__compare_and_swapPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__compare_and_swap_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> expected, ffi.Pointer<wire_cst_list_prim_u_8_strict> new_value) {
+    return _wire__crate__api__compare_and_swap_on(handle, db_name, key, expected, new_value);
+  }
+
+  late final _wire__crate__api__compare_and_swap_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__compare_and_swap_on');
+  late final _wire__crate__api__compare_and_swap_on = _wire__crate__api__compare_and_swap_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__connect_peer(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> address) {
+    return _wire__crate__api__connect_peer(port_, node_id, address);
+  }
+
+  late final _wire__crate__api__connect_peerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__connect_peer');
+  late final _wire__crate__api__connect_peer = _wire__crate__api__connect_peerPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__connect_peer_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> address) {
+    return _wire__crate__api__connect_peer_on(port_, handle, node_id, address);
+  }
+
+  late final _wire__crate__api__connect_peer_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__connect_peer_on');
+  late final _wire__crate__api__connect_peer_on = _wire__crate__api__connect_peer_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__create_db_invite(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> owner_secret_key, bool can_write, bool include_encryption_key, int expires_in_secs) {
+    return _wire__crate__api__create_db_invite(port_, db_name, owner_secret_key, can_write, include_encryption_key, expires_in_secs);
+  }
+
+  late final _wire__crate__api__create_db_invitePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool, ffi.Bool, ffi.Uint64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__create_db_invite');
+  late final _wire__crate__api__create_db_invite = _wire__crate__api__create_db_invitePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool, bool, int)>();
+
+  void wire__crate__api__create_db_invite_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> owner_secret_key, bool can_write, bool include_encryption_key, int expires_in_secs) {
+    return _wire__crate__api__create_db_invite_on(port_, handle, db_name, owner_secret_key, can_write, include_encryption_key, expires_in_secs);
+  }
+
+  late final _wire__crate__api__create_db_invite_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool, ffi.Bool, ffi.Uint64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__create_db_invite_on');
+  late final _wire__crate__api__create_db_invite_on = _wire__crate__api__create_db_invite_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool, bool, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__create_device_link_request(ffi.Pointer<wire_cst_list_prim_u_8_strict> device_name) {
+    return _wire__crate__api__create_device_link_request(device_name);
+  }
+
+  late final _wire__crate__api__create_device_link_requestPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__create_device_link_request');
+  late final _wire__crate__api__create_device_link_request = _wire__crate__api__create_device_link_requestPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__create_device_link_request_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> device_name) {
+    return _wire__crate__api__create_device_link_request_on(handle, device_name);
+  }
+
+  late final _wire__crate__api__create_device_link_request_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__create_device_link_request_on');
+  late final _wire__crate__api__create_device_link_request_on = _wire__crate__api__create_device_link_request_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__create_encrypted_db(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__create_encrypted_db(db_name);
+  }
+
+  late final _wire__crate__api__create_encrypted_dbPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__create_encrypted_db');
+  late final _wire__crate__api__create_encrypted_db = _wire__crate__api__create_encrypted_dbPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__create_encrypted_db_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__create_encrypted_db_on(handle, db_name);
+  }
+
+  late final _wire__crate__api__create_encrypted_db_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__create_encrypted_db_on');
+  late final _wire__crate__api__create_encrypted_db_on = _wire__crate__api__create_encrypted_db_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__decline_file(ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id) {
+    return _wire__crate__api__decline_file(offer_id);
+  }
+
+  late final _wire__crate__api__decline_filePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__decline_file');
+  late final _wire__crate__api__decline_file = _wire__crate__api__decline_filePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__decline_file_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id) {
+    return _wire__crate__api__decline_file_on(handle, offer_id);
+  }
+
+  late final _wire__crate__api__decline_file_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__decline_file_on');
+  late final _wire__crate__api__decline_file_on = _wire__crate__api__decline_file_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__default_node_config() {
+    return _wire__crate__api__default_node_config();
+  }
+
+  late final _wire__crate__api__default_node_configPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__default_node_config');
+  late final _wire__crate__api__default_node_config = _wire__crate__api__default_node_configPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  void wire__crate__api__delete_data(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__delete_data(port_, db_name, key);
+  }
+
+  late final _wire__crate__api__delete_dataPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__delete_data');
+  late final _wire__crate__api__delete_data = _wire__crate__api__delete_dataPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__delete_data_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__delete_data_on(port_, handle, db_name, key);
+  }
+
+  late final _wire__crate__api__delete_data_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__delete_data_on');
+  late final _wire__crate__api__delete_data_on = _wire__crate__api__delete_data_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__dm_stream(ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__dm_stream(sink);
+  }
+
+  late final _wire__crate__api__dm_streamPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__dm_stream');
+  late final _wire__crate__api__dm_stream = _wire__crate__api__dm_streamPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__dm_stream_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__dm_stream_on(handle, sink);
+  }
+
+  late final _wire__crate__api__dm_stream_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__dm_stream_on');
+  late final _wire__crate__api__dm_stream_on = _wire__crate__api__dm_stream_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__drop_database(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> owner_secret_key) {
+    return _wire__crate__api__drop_database(port_, db_name, owner_secret_key);
+  }
+
+  late final _wire__crate__api__drop_databasePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__drop_database');
+  late final _wire__crate__api__drop_database = _wire__crate__api__drop_databasePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__drop_database_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> owner_secret_key) {
+    return _wire__crate__api__drop_database_on(port_, handle, db_name, owner_secret_key);
+  }
+
+  late final _wire__crate__api__drop_database_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__drop_database_on');
+  late final _wire__crate__api__drop_database_on = _wire__crate__api__drop_database_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__edit_text(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, int index, int delete_, ffi.Pointer<wire_cst_list_prim_u_8_strict> insert, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__edit_text(port_, db_name, key, index, delete_, insert, secret_key);
+  }
+
+  late final _wire__crate__api__edit_textPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint64, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__edit_text');
+  late final _wire__crate__api__edit_text = _wire__crate__api__edit_textPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__edit_text_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, int index, int delete_, ffi.Pointer<wire_cst_list_prim_u_8_strict> insert, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__edit_text_on(port_, handle, db_name, key, index, delete_, insert, secret_key);
+  }
+
+  late final _wire__crate__api__edit_text_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint64, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__edit_text_on');
+  late final _wire__crate__api__edit_text_on = _wire__crate__api__edit_text_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__event_stream(int since_seq, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__event_stream(since_seq, sink);
+  }
+
+  late final _wire__crate__api__event_streamPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__event_stream');
+  late final _wire__crate__api__event_stream = _wire__crate__api__event_streamPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__event_stream_on(int handle, int since_seq, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__event_stream_on(handle, since_seq, sink);
+  }
+
+  late final _wire__crate__api__event_stream_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__event_stream_on');
+  late final _wire__crate__api__event_stream_on = _wire__crate__api__event_stream_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__export_backup(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> path, ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase) {
+    return _wire__crate__api__export_backup(port_, path, passphrase);
+  }
+
+  late final _wire__crate__api__export_backupPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__export_backup');
+  late final _wire__crate__api__export_backup = _wire__crate__api__export_backupPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__export_backup_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> path, ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase) {
+    return _wire__crate__api__export_backup_on(port_, handle, path, passphrase);
+  }
+
+  late final _wire__crate__api__export_backup_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__export_backup_on');
+  late final _wire__crate__api__export_backup_on = _wire__crate__api__export_backup_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__export_db_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__export_db_key(db_name);
+  }
+
+  late final _wire__crate__api__export_db_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__export_db_key');
+  late final _wire__crate__api__export_db_key = _wire__crate__api__export_db_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__export_db_key_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__export_db_key_on(handle, db_name);
+  }
+
+  late final _wire__crate__api__export_db_key_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__export_db_key_on');
+  late final _wire__crate__api__export_db_key_on = _wire__crate__api__export_db_key_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__export_logs(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> path) {
+    return _wire__crate__api__export_logs(port_, path);
+  }
+
+  late final _wire__crate__api__export_logsPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__export_logs');
+  late final _wire__crate__api__export_logs = _wire__crate__api__export_logsPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__extract_name_from_db(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__extract_name_from_db(db_name);
+  }
+
+  late final _wire__crate__api__extract_name_from_dbPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__extract_name_from_db');
+  late final _wire__crate__api__extract_name_from_db = _wire__crate__api__extract_name_from_dbPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__file_event_stream(ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__file_event_stream(sink);
+  }
+
+  late final _wire__crate__api__file_event_streamPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__file_event_stream');
+  late final _wire__crate__api__file_event_stream = _wire__crate__api__file_event_streamPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__file_event_stream_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__file_event_stream_on(handle, sink);
+  }
+
+  late final _wire__crate__api__file_event_stream_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__file_event_stream_on');
+  late final _wire__crate__api__file_event_stream_on = _wire__crate__api__file_event_stream_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__follow_database(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__follow_database(db_name);
+  }
+
+  late final _wire__crate__api__follow_databasePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__follow_database');
+  late final _wire__crate__api__follow_database = _wire__crate__api__follow_databasePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__follow_database_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__follow_database_on(handle, db_name);
+  }
+
+  late final _wire__crate__api__follow_database_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__follow_database_on');
+  late final _wire__crate__api__follow_database_on = _wire__crate__api__follow_database_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__generate_db_name(ffi.Pointer<wire_cst_list_prim_u_8_strict> name, ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex) {
+    return _wire__crate__api__generate_db_name(name, public_key_hex);
+  }
+
+  late final _wire__crate__api__generate_db_namePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__generate_db_name');
+  late final _wire__crate__api__generate_db_name = _wire__crate__api__generate_db_namePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__generate_keypair() {
+    return _wire__crate__api__generate_keypair();
+  }
+
+  late final _wire__crate__api__generate_keypairPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__generate_keypair');
+  late final _wire__crate__api__generate_keypair = _wire__crate__api__generate_keypairPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__generate_peer_id_from_secret_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key_hex) {
+    return _wire__crate__api__generate_peer_id_from_secret_key(secret_key_hex);
+  }
+
+  late final _wire__crate__api__generate_peer_id_from_secret_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__generate_peer_id_from_secret_key');
+  late final _wire__crate__api__generate_peer_id_from_secret_key = _wire__crate__api__generate_peer_id_from_secret_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__geo_add(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> member, double latitude, double longitude, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__geo_add(port_, db_name, key, member, latitude, longitude, secret_key);
+  }
+
+  late final _wire__crate__api__geo_addPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Double, ffi.Double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__geo_add');
+  late final _wire__crate__api__geo_add = _wire__crate__api__geo_addPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, double, double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__geo_add_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> member, double latitude, double longitude, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__geo_add_on(port_, handle, db_name, key, member, latitude, longitude, secret_key);
+  }
+
+  late final _wire__crate__api__geo_add_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Double, ffi.Double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__geo_add_on');
+  late final _wire__crate__api__geo_add_on = _wire__crate__api__geo_add_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, double, double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__geo_radius(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, double latitude, double longitude, double radius_m) {
+    return _wire__crate__api__geo_radius(db_name, key, latitude, longitude, radius_m);
+  }
+
+  late final _wire__crate__api__geo_radiusPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Double, ffi.Double, ffi.Double)>>('frbgen_cyberfly_mobile_node_wire__crate__api__geo_radius');
+  late final _wire__crate__api__geo_radius = _wire__crate__api__geo_radiusPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, double, double, double)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__geo_radius_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, double latitude, double longitude, double radius_m) {
+    return _wire__crate__api__geo_radius_on(handle, db_name, key, latitude, longitude, radius_m);
+  }
+
+  late final _wire__crate__api__geo_radius_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Double, ffi.Double, ffi.Double)>>('frbgen_cyberfly_mobile_node_wire__crate__api__geo_radius_on');
+  late final _wire__crate__api__geo_radius_on = _wire__crate__api__geo_radius_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, double, double, double)>();
+
+  void wire__crate__api__get_all_data(int port_) {
+    return _wire__crate__api__get_all_data(port_);
+  }
+
+  late final _wire__crate__api__get_all_dataPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data');
+  late final _wire__crate__api__get_all_data = _wire__crate__api__get_all_dataPtr.asFunction<void Function(int)>();
+
+  void wire__crate__api__get_all_data_on(int port_, int handle) {
+    return _wire__crate__api__get_all_data_on(port_, handle);
+  }
+
+  late final _wire__crate__api__get_all_data_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_on');
+  late final _wire__crate__api__get_all_data_on = _wire__crate__api__get_all_data_onPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__api__get_all_data_page(int port_, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor, bool keys_only, ffi.Pointer<ffi.Uint64> max_bytes) {
+    return _wire__crate__api__get_all_data_page(port_, limit, cursor, keys_only, max_bytes);
+  }
+
+  late final _wire__crate__api__get_all_data_pagePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_page');
+  late final _wire__crate__api__get_all_data_page = _wire__crate__api__get_all_data_pagePtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool, ffi.Pointer<ffi.Uint64>)>();
+
+  void wire__crate__api__get_all_data_page_on(int port_, int handle, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor, bool keys_only, ffi.Pointer<ffi.Uint64> max_bytes) {
+    return _wire__crate__api__get_all_data_page_on(port_, handle, limit, cursor, keys_only, max_bytes);
+  }
+
+  late final _wire__crate__api__get_all_data_page_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_all_data_page_on');
+  late final _wire__crate__api__get_all_data_page_on = _wire__crate__api__get_all_data_page_onPtr.asFunction<void Function(int, int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool, ffi.Pointer<ffi.Uint64>)>();
+
+  void wire__crate__api__get_all_entries(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__get_all_entries(port_, db_name);
+  }
+
+  late final _wire__crate__api__get_all_entriesPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_all_entries');
+  late final _wire__crate__api__get_all_entries = _wire__crate__api__get_all_entriesPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__get_all_entries_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__get_all_entries_on(port_, handle, db_name);
+  }
+
+  late final _wire__crate__api__get_all_entries_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_all_entries_on');
+  late final _wire__crate__api__get_all_entries_on = _wire__crate__api__get_all_entries_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_bandwidth_stats() {
+    return _wire__crate__api__get_bandwidth_stats();
+  }
+
+  late final _wire__crate__api__get_bandwidth_statsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_bandwidth_stats');
+  late final _wire__crate__api__get_bandwidth_stats = _wire__crate__api__get_bandwidth_statsPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_bandwidth_stats_on(int handle) {
+    return _wire__crate__api__get_bandwidth_stats_on(handle);
+  }
+
+  late final _wire__crate__api__get_bandwidth_stats_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_bandwidth_stats_on');
+  late final _wire__crate__api__get_bandwidth_stats_on = _wire__crate__api__get_bandwidth_stats_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_connection_diagnostics() {
+    return _wire__crate__api__get_connection_diagnostics();
+  }
+
+  late final _wire__crate__api__get_connection_diagnosticsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_connection_diagnostics');
+  late final _wire__crate__api__get_connection_diagnostics = _wire__crate__api__get_connection_diagnosticsPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_connection_diagnostics_on(int handle) {
+    return _wire__crate__api__get_connection_diagnostics_on(handle);
+  }
+
+  late final _wire__crate__api__get_connection_diagnostics_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_connection_diagnostics_on');
+  late final _wire__crate__api__get_connection_diagnostics_on = _wire__crate__api__get_connection_diagnostics_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_connectivity_state() {
+    return _wire__crate__api__get_connectivity_state();
+  }
+
+  late final _wire__crate__api__get_connectivity_statePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_connectivity_state');
+  late final _wire__crate__api__get_connectivity_state = _wire__crate__api__get_connectivity_statePtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_connectivity_state_on(int handle) {
+    return _wire__crate__api__get_connectivity_state_on(handle);
+  }
+
+  late final _wire__crate__api__get_connectivity_state_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_connectivity_state_on');
+  late final _wire__crate__api__get_connectivity_state_on = _wire__crate__api__get_connectivity_state_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_counter(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__get_counter(db_name, key);
+  }
+
+  late final _wire__crate__api__get_counterPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_counter');
+  late final _wire__crate__api__get_counter = _wire__crate__api__get_counterPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_counter_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__get_counter_on(handle, db_name, key);
+  }
+
+  late final _wire__crate__api__get_counter_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_counter_on');
+  late final _wire__crate__api__get_counter_on = _wire__crate__api__get_counter_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__get_data(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__get_data(port_, db_name, key);
+  }
+
+  late final _wire__crate__api__get_dataPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_data');
+  late final _wire__crate__api__get_data = _wire__crate__api__get_dataPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__get_data_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__get_data_on(port_, handle, db_name, key);
+  }
+
+  late final _wire__crate__api__get_data_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_data_on');
+  late final _wire__crate__api__get_data_on = _wire__crate__api__get_data_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__get_database_root(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__get_database_root(port_, db_name);
+  }
+
+  late final _wire__crate__api__get_database_rootPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_database_root');
+  late final _wire__crate__api__get_database_root = _wire__crate__api__get_database_rootPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__get_database_root_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__get_database_root_on(port_, handle, db_name);
+  }
+
+  late final _wire__crate__api__get_database_root_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_database_root_on');
+  late final _wire__crate__api__get_database_root_on = _wire__crate__api__get_database_root_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__get_db_sync_info(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__get_db_sync_info(port_, db_name);
+  }
+
+  late final _wire__crate__api__get_db_sync_infoPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_db_sync_info');
+  late final _wire__crate__api__get_db_sync_info = _wire__crate__api__get_db_sync_infoPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__get_db_sync_info_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__get_db_sync_info_on(port_, handle, db_name);
+  }
+
+  late final _wire__crate__api__get_db_sync_info_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_db_sync_info_on');
+  late final _wire__crate__api__get_db_sync_info_on = _wire__crate__api__get_db_sync_info_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_decrypted(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__get_decrypted(db_name, key);
+  }
+
+  late final _wire__crate__api__get_decryptedPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_decrypted');
+  late final _wire__crate__api__get_decrypted = _wire__crate__api__get_decryptedPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_decrypted_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__get_decrypted_on(handle, db_name, key);
+  }
+
+  late final _wire__crate__api__get_decrypted_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_decrypted_on');
+  late final _wire__crate__api__get_decrypted_on = _wire__crate__api__get_decrypted_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_dm_history(ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, int limit) {
+    return _wire__crate__api__get_dm_history(peer_id, limit);
+  }
+
+  late final _wire__crate__api__get_dm_historyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_dm_history');
+  late final _wire__crate__api__get_dm_history = _wire__crate__api__get_dm_historyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_dm_history_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, int limit) {
+    return _wire__crate__api__get_dm_history_on(handle, peer_id, limit);
+  }
+
+  late final _wire__crate__api__get_dm_history_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_dm_history_on');
+  late final _wire__crate__api__get_dm_history_on = _wire__crate__api__get_dm_history_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  void wire__crate__api__get_entries_page(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor, bool keys_only, ffi.Pointer<ffi.Uint64> max_bytes) {
+    return _wire__crate__api__get_entries_page(port_, db_name, limit, cursor, keys_only, max_bytes);
+  }
+
+  late final _wire__crate__api__get_entries_pagePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_entries_page');
+  late final _wire__crate__api__get_entries_page = _wire__crate__api__get_entries_pagePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool, ffi.Pointer<ffi.Uint64>)>();
+
+  void wire__crate__api__get_entries_page_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor, bool keys_only, ffi.Pointer<ffi.Uint64> max_bytes) {
+    return _wire__crate__api__get_entries_page_on(port_, handle, db_name, limit, cursor, keys_only, max_bytes);
+  }
+
+  late final _wire__crate__api__get_entries_page_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_entries_page_on');
+  late final _wire__crate__api__get_entries_page_on = _wire__crate__api__get_entries_page_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool, ffi.Pointer<ffi.Uint64>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_followed_databases() {
+    return _wire__crate__api__get_followed_databases();
+  }
+
+  late final _wire__crate__api__get_followed_databasesPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_followed_databases');
+  late final _wire__crate__api__get_followed_databases = _wire__crate__api__get_followed_databasesPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_followed_databases_on(int handle) {
+    return _wire__crate__api__get_followed_databases_on(handle);
+  }
+
+  late final _wire__crate__api__get_followed_databases_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_followed_databases_on');
+  late final _wire__crate__api__get_followed_databases_on = _wire__crate__api__get_followed_databases_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_log_levels_filter() {
+    return _wire__crate__api__get_log_levels_filter();
+  }
+
+  late final _wire__crate__api__get_log_levels_filterPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_log_levels_filter');
+  late final _wire__crate__api__get_log_levels_filter = _wire__crate__api__get_log_levels_filterPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_logs(ffi.Pointer<ffi.Uint32> limit) {
+    return _wire__crate__api__get_logs(limit);
+  }
+
+  late final _wire__crate__api__get_logsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<ffi.Uint32>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_logs');
+  late final _wire__crate__api__get_logs = _wire__crate__api__get_logsPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<ffi.Uint32>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_logs_filtered(ffi.Pointer<wire_cst_log_filter_dto> filter) {
+    return _wire__crate__api__get_logs_filtered(filter);
+  }
+
+  late final _wire__crate__api__get_logs_filteredPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_log_filter_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_logs_filtered');
+  late final _wire__crate__api__get_logs_filtered = _wire__crate__api__get_logs_filteredPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_log_filter_dto>)>();
+
+  void wire__crate__api__get_many(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_String> keys) {
+    return _wire__crate__api__get_many(port_, db_name, keys);
+  }
+
+  late final _wire__crate__api__get_manyPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_many');
+  late final _wire__crate__api__get_many = _wire__crate__api__get_manyPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>)>();
+
+  void wire__crate__api__get_many_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_String> keys) {
+    return _wire__crate__api__get_many_on(port_, handle, db_name, keys);
+  }
+
+  late final _wire__crate__api__get_many_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_many_on');
+  late final _wire__crate__api__get_many_on = _wire__crate__api__get_many_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_metrics() {
+    return _wire__crate__api__get_metrics();
+  }
+
+  late final _wire__crate__api__get_metricsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_metrics');
+  late final _wire__crate__api__get_metrics = _wire__crate__api__get_metricsPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_metrics_on(int handle) {
+    return _wire__crate__api__get_metrics_on(handle);
+  }
+
+  late final _wire__crate__api__get_metrics_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_metrics_on');
+  late final _wire__crate__api__get_metrics_on = _wire__crate__api__get_metrics_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_mqtt_bridge_config() {
+    return _wire__crate__api__get_mqtt_bridge_config();
+  }
+
+  late final _wire__crate__api__get_mqtt_bridge_configPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_mqtt_bridge_config');
+  late final _wire__crate__api__get_mqtt_bridge_config = _wire__crate__api__get_mqtt_bridge_configPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_mqtt_bridge_config_on(int handle) {
+    return _wire__crate__api__get_mqtt_bridge_config_on(handle);
+  }
+
+  late final _wire__crate__api__get_mqtt_bridge_config_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_mqtt_bridge_config_on');
+  late final _wire__crate__api__get_mqtt_bridge_config_on = _wire__crate__api__get_mqtt_bridge_config_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_network_id(ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir) {
+    return _wire__crate__api__get_network_id(data_dir);
+  }
+
+  late final _wire__crate__api__get_network_idPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_network_id');
+  late final _wire__crate__api__get_network_id = _wire__crate__api__get_network_idPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_addr() {
+    return _wire__crate__api__get_node_addr();
+  }
+
+  late final _wire__crate__api__get_node_addrPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_addr');
+  late final _wire__crate__api__get_node_addr = _wire__crate__api__get_node_addrPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_addr_on(int handle) {
+    return _wire__crate__api__get_node_addr_on(handle);
+  }
+
+  late final _wire__crate__api__get_node_addr_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_addr_on');
+  late final _wire__crate__api__get_node_addr_on = _wire__crate__api__get_node_addr_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_config() {
+    return _wire__crate__api__get_node_config();
+  }
+
+  late final _wire__crate__api__get_node_configPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_config');
+  late final _wire__crate__api__get_node_config = _wire__crate__api__get_node_configPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_config_on(int handle) {
+    return _wire__crate__api__get_node_config_on(handle);
+  }
+
+  late final _wire__crate__api__get_node_config_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_config_on');
+  late final _wire__crate__api__get_node_config_on = _wire__crate__api__get_node_config_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_info() {
+    return _wire__crate__api__get_node_info();
+  }
+
+  late final _wire__crate__api__get_node_infoPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_info');
+  late final _wire__crate__api__get_node_info = _wire__crate__api__get_node_infoPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_info_on(int handle) {
+    return _wire__crate__api__get_node_info_on(handle);
+  }
+
+  late final _wire__crate__api__get_node_info_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_info_on');
+  late final _wire__crate__api__get_node_info_on = _wire__crate__api__get_node_info_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_stats() {
+    return _wire__crate__api__get_node_stats();
+  }
+
+  late final _wire__crate__api__get_node_statsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_stats');
+  late final _wire__crate__api__get_node_stats = _wire__crate__api__get_node_statsPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_stats_on(int handle) {
+    return _wire__crate__api__get_node_stats_on(handle);
+  }
+
+  late final _wire__crate__api__get_node_stats_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_stats_on');
+  late final _wire__crate__api__get_node_stats_on = _wire__crate__api__get_node_stats_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_status() {
+    return _wire__crate__api__get_node_status();
+  }
+
+  late final _wire__crate__api__get_node_statusPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_status');
+  late final _wire__crate__api__get_node_status = _wire__crate__api__get_node_statusPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_status_on(int handle) {
+    return _wire__crate__api__get_node_status_on(handle);
+  }
+
+  late final _wire__crate__api__get_node_status_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_status_on');
+  late final _wire__crate__api__get_node_status_on = _wire__crate__api__get_node_status_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_ticket() {
+    return _wire__crate__api__get_node_ticket();
+  }
+
+  late final _wire__crate__api__get_node_ticketPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_ticket');
+  late final _wire__crate__api__get_node_ticket = _wire__crate__api__get_node_ticketPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_node_ticket_on(int handle) {
+    return _wire__crate__api__get_node_ticket_on(handle);
+  }
+
+  late final _wire__crate__api__get_node_ticket_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_node_ticket_on');
+  late final _wire__crate__api__get_node_ticket_on = _wire__crate__api__get_node_ticket_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_peer_circuits() {
+    return _wire__crate__api__get_peer_circuits();
+  }
+
+  late final _wire__crate__api__get_peer_circuitsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_circuits');
+  late final _wire__crate__api__get_peer_circuits = _wire__crate__api__get_peer_circuitsPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_peer_circuits_on(int handle) {
+    return _wire__crate__api__get_peer_circuits_on(handle);
+  }
+
+  late final _wire__crate__api__get_peer_circuits_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_circuits_on');
+  late final _wire__crate__api__get_peer_circuits_on = _wire__crate__api__get_peer_circuits_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_peer_paths() {
+    return _wire__crate__api__get_peer_paths();
+  }
+
+  late final _wire__crate__api__get_peer_pathsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_paths');
+  late final _wire__crate__api__get_peer_paths = _wire__crate__api__get_peer_pathsPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_peer_paths_on(int handle) {
+    return _wire__crate__api__get_peer_paths_on(handle);
+  }
+
+  late final _wire__crate__api__get_peer_paths_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_peer_paths_on');
+  late final _wire__crate__api__get_peer_paths_on = _wire__crate__api__get_peer_paths_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_peers() {
+    return _wire__crate__api__get_peers();
+  }
+
+  late final _wire__crate__api__get_peersPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_peers');
+  late final _wire__crate__api__get_peers = _wire__crate__api__get_peersPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_peers_filtered(ffi.Pointer<wire_cst_list_String> capabilities, ffi.Pointer<wire_cst_list_prim_u_8_strict> region, ffi.Pointer<ffi.Uint64> max_latency_ms) {
+    return _wire__crate__api__get_peers_filtered(capabilities, region, max_latency_ms);
+  }
+
+  late final _wire__crate__api__get_peers_filteredPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_filtered');
+  late final _wire__crate__api__get_peers_filtered = _wire__crate__api__get_peers_filteredPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Uint64>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_peers_filtered_on(int handle, ffi.Pointer<wire_cst_list_String> capabilities, ffi.Pointer<wire_cst_list_prim_u_8_strict> region, ffi.Pointer<ffi.Uint64> max_latency_ms) {
+    return _wire__crate__api__get_peers_filtered_on(handle, capabilities, region, max_latency_ms);
+  }
+
+  late final _wire__crate__api__get_peers_filtered_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_filtered_on');
+  late final _wire__crate__api__get_peers_filtered_on = _wire__crate__api__get_peers_filtered_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Uint64>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_peers_on(int handle) {
+    return _wire__crate__api__get_peers_on(handle);
+  }
+
+  late final _wire__crate__api__get_peers_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_peers_on');
+  late final _wire__crate__api__get_peers_on = _wire__crate__api__get_peers_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_pinned_peers() {
+    return _wire__crate__api__get_pinned_peers();
+  }
+
+  late final _wire__crate__api__get_pinned_peersPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_pinned_peers');
+  late final _wire__crate__api__get_pinned_peers = _wire__crate__api__get_pinned_peersPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_pinned_peers_on(int handle) {
+    return _wire__crate__api__get_pinned_peers_on(handle);
+  }
+
+  late final _wire__crate__api__get_pinned_peers_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_pinned_peers_on');
+  late final _wire__crate__api__get_pinned_peers_on = _wire__crate__api__get_pinned_peers_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_power_mode() {
+    return _wire__crate__api__get_power_mode();
+  }
+
+  late final _wire__crate__api__get_power_modePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_power_mode');
+  late final _wire__crate__api__get_power_mode = _wire__crate__api__get_power_modePtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_power_mode_on(int handle) {
+    return _wire__crate__api__get_power_mode_on(handle);
+  }
+
+  late final _wire__crate__api__get_power_mode_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_power_mode_on');
+  late final _wire__crate__api__get_power_mode_on = _wire__crate__api__get_power_mode_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_power_policy() {
+    return _wire__crate__api__get_power_policy();
+  }
+
+  late final _wire__crate__api__get_power_policyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_power_policy');
+  late final _wire__crate__api__get_power_policy = _wire__crate__api__get_power_policyPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_power_policy_on(int handle) {
+    return _wire__crate__api__get_power_policy_on(handle);
+  }
+
+  late final _wire__crate__api__get_power_policy_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_power_policy_on');
+  late final _wire__crate__api__get_power_policy_on = _wire__crate__api__get_power_policy_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_presence(ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id) {
+    return _wire__crate__api__get_presence(peer_id);
+  }
+
+  late final _wire__crate__api__get_presencePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_presence');
+  late final _wire__crate__api__get_presence = _wire__crate__api__get_presencePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_presence_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id) {
+    return _wire__crate__api__get_presence_on(handle, peer_id);
+  }
+
+  late final _wire__crate__api__get_presence_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_presence_on');
+  late final _wire__crate__api__get_presence_on = _wire__crate__api__get_presence_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_ranked_peers() {
+    return _wire__crate__api__get_ranked_peers();
+  }
+
+  late final _wire__crate__api__get_ranked_peersPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_ranked_peers');
+  late final _wire__crate__api__get_ranked_peers = _wire__crate__api__get_ranked_peersPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_ranked_peers_on(int handle) {
+    return _wire__crate__api__get_ranked_peers_on(handle);
+  }
+
+  late final _wire__crate__api__get_ranked_peers_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_ranked_peers_on');
+  late final _wire__crate__api__get_ranked_peers_on = _wire__crate__api__get_ranked_peers_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_recent_events(int since_seq) {
+    return _wire__crate__api__get_recent_events(since_seq);
+  }
+
+  late final _wire__crate__api__get_recent_eventsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_recent_events');
+  late final _wire__crate__api__get_recent_events = _wire__crate__api__get_recent_eventsPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_recent_events_on(int handle, int since_seq) {
+    return _wire__crate__api__get_recent_events_on(handle, since_seq);
+  }
+
+  late final _wire__crate__api__get_recent_events_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Uint64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_recent_events_on');
+  late final _wire__crate__api__get_recent_events_on = _wire__crate__api__get_recent_events_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, int)>();
+
+  void wire__crate__api__get_relay_status(int port_) {
+    return _wire__crate__api__get_relay_status(port_);
+  }
+
+  late final _wire__crate__api__get_relay_statusPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_relay_status');
+  late final _wire__crate__api__get_relay_status = _wire__crate__api__get_relay_statusPtr.asFunction<void Function(int)>();
+
+  void wire__crate__api__get_relay_status_on(int port_, int handle) {
+    return _wire__crate__api__get_relay_status_on(port_, handle);
+  }
+
+  late final _wire__crate__api__get_relay_status_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_relay_status_on');
+  late final _wire__crate__api__get_relay_status_on = _wire__crate__api__get_relay_status_onPtr.asFunction<void Function(int, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_successor(ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id) {
+    return _wire__crate__api__get_successor(node_id);
+  }
+
+  late final _wire__crate__api__get_successorPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_successor');
+  late final _wire__crate__api__get_successor = _wire__crate__api__get_successorPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_successor_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id) {
+    return _wire__crate__api__get_successor_on(handle, node_id);
+  }
+
+  late final _wire__crate__api__get_successor_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_successor_on');
+  late final _wire__crate__api__get_successor_on = _wire__crate__api__get_successor_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__get_sync_stats(int port_) {
+    return _wire__crate__api__get_sync_stats(port_);
+  }
+
+  late final _wire__crate__api__get_sync_statsPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_sync_stats');
+  late final _wire__crate__api__get_sync_stats = _wire__crate__api__get_sync_statsPtr.asFunction<void Function(int)>();
+
+  void wire__crate__api__get_sync_stats_on(int port_, int handle) {
+    return _wire__crate__api__get_sync_stats_on(port_, handle);
+  }
+
+  late final _wire__crate__api__get_sync_stats_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_sync_stats_on');
+  late final _wire__crate__api__get_sync_stats_on = _wire__crate__api__get_sync_stats_onPtr.asFunction<void Function(int, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_text(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__get_text(db_name, key);
+  }
+
+  late final _wire__crate__api__get_textPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_text');
+  late final _wire__crate__api__get_text = _wire__crate__api__get_textPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_text_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__get_text_on(handle, db_name, key);
+  }
+
+  late final _wire__crate__api__get_text_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_text_on');
+  late final _wire__crate__api__get_text_on = _wire__crate__api__get_text_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_writers(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__get_writers(db_name);
+  }
+
+  late final _wire__crate__api__get_writersPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_writers');
+  late final _wire__crate__api__get_writers = _wire__crate__api__get_writersPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__get_writers_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__get_writers_on(handle, db_name);
+  }
+
+  late final _wire__crate__api__get_writers_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__get_writers_on');
+  late final _wire__crate__api__get_writers_on = _wire__crate__api__get_writers_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__go_online(int port_, ffi.Pointer<wire_cst_list_String> bootstrap_peers, ffi.Pointer<wire_cst_list_prim_u_8_strict> region) {
+    return _wire__crate__api__go_online(port_, bootstrap_peers, region);
+  }
+
+  late final _wire__crate__api__go_onlinePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__go_online');
+  late final _wire__crate__api__go_online = _wire__crate__api__go_onlinePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__go_online_on(int port_, int handle, ffi.Pointer<wire_cst_list_String> bootstrap_peers, ffi.Pointer<wire_cst_list_prim_u_8_strict> region) {
+    return _wire__crate__api__go_online_on(port_, handle, bootstrap_peers, region);
+  }
+
+  late final _wire__crate__api__go_online_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__go_online_on');
+  late final _wire__crate__api__go_online_on = _wire__crate__api__go_online_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__grant_writer(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> writer_public_key, ffi.Pointer<wire_cst_list_prim_u_8_strict> owner_secret_key) {
+    return _wire__crate__api__grant_writer(port_, db_name, writer_public_key, owner_secret_key);
+  }
+
+  late final _wire__crate__api__grant_writerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__grant_writer');
+  late final _wire__crate__api__grant_writer = _wire__crate__api__grant_writerPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__grant_writer_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> writer_public_key, ffi.Pointer<wire_cst_list_prim_u_8_strict> owner_secret_key) {
+    return _wire__crate__api__grant_writer_on(port_, handle, db_name, writer_public_key, owner_secret_key);
+  }
+
+  late final _wire__crate__api__grant_writer_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__grant_writer_on');
+  late final _wire__crate__api__grant_writer_on = _wire__crate__api__grant_writer_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__graphql_execute(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> query, ffi.Pointer<wire_cst_list_prim_u_8_strict> variables) {
+    return _wire__crate__api__graphql_execute(port_, query, variables);
+  }
+
+  late final _wire__crate__api__graphql_executePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__graphql_execute');
+  late final _wire__crate__api__graphql_execute = _wire__crate__api__graphql_executePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__graphql_execute_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> query, ffi.Pointer<wire_cst_list_prim_u_8_strict> variables) {
+    return _wire__crate__api__graphql_execute_on(port_, handle, query, variables);
+  }
+
+  late final _wire__crate__api__graphql_execute_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__graphql_execute_on');
+  late final _wire__crate__api__graphql_execute_on = _wire__crate__api__graphql_execute_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__greet(ffi.Pointer<wire_cst_list_prim_u_8_strict> name) {
+    return _wire__crate__api__greet(name);
+  }
+
+  late final _wire__crate__api__greetPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__greet');
+  late final _wire__crate__api__greet = _wire__crate__api__greetPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__hash_get(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> field) {
+    return _wire__crate__api__hash_get(port_, db_name, key, field);
+  }
+
+  late final _wire__crate__api__hash_getPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__hash_get');
+  late final _wire__crate__api__hash_get = _wire__crate__api__hash_getPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__hash_get_all(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__hash_get_all(db_name, key);
+  }
+
+  late final _wire__crate__api__hash_get_allPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_all');
+  late final _wire__crate__api__hash_get_all = _wire__crate__api__hash_get_allPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__hash_get_all_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__hash_get_all_on(handle, db_name, key);
+  }
+
+  late final _wire__crate__api__hash_get_all_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_all_on');
+  late final _wire__crate__api__hash_get_all_on = _wire__crate__api__hash_get_all_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__hash_get_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> field) {
+    return _wire__crate__api__hash_get_on(port_, handle, db_name, key, field);
+  }
+
+  late final _wire__crate__api__hash_get_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__hash_get_on');
+  late final _wire__crate__api__hash_get_on = _wire__crate__api__hash_get_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__hash_set(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> field, ffi.Pointer<wire_cst_list_prim_u_8_strict> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__hash_set(port_, db_name, key, field, value, secret_key);
+  }
+
+  late final _wire__crate__api__hash_setPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__hash_set');
+  late final _wire__crate__api__hash_set = _wire__crate__api__hash_setPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__hash_set_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> field, ffi.Pointer<wire_cst_list_prim_u_8_strict> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__hash_set_on(port_, handle, db_name, key, field, value, secret_key);
+  }
+
+  late final _wire__crate__api__hash_set_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__hash_set_on');
+  late final _wire__crate__api__hash_set_on = _wire__crate__api__hash_set_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__health_check(int port_) {
+    return _wire__crate__api__health_check(port_);
+  }
+
+  late final _wire__crate__api__health_checkPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__health_check');
+  late final _wire__crate__api__health_check = _wire__crate__api__health_checkPtr.asFunction<void Function(int)>();
+
+  void wire__crate__api__health_check_on(int port_, int handle) {
+    return _wire__crate__api__health_check_on(port_, handle);
+  }
+
+  late final _wire__crate__api__health_check_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__health_check_on');
+  late final _wire__crate__api__health_check_on = _wire__crate__api__health_check_onPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__api__import_backup(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> path, ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase) {
+    return _wire__crate__api__import_backup(port_, data_dir, path, passphrase);
+  }
+
+  late final _wire__crate__api__import_backupPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__import_backup');
+  late final _wire__crate__api__import_backup = _wire__crate__api__import_backupPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__import_db_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key_hex) {
+    return _wire__crate__api__import_db_key(db_name, key_hex);
+  }
+
+  late final _wire__crate__api__import_db_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__import_db_key');
+  late final _wire__crate__api__import_db_key = _wire__crate__api__import_db_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__import_db_key_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key_hex) {
+    return _wire__crate__api__import_db_key_on(handle, db_name, key_hex);
+  }
+
+  late final _wire__crate__api__import_db_key_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__import_db_key_on');
+  late final _wire__crate__api__import_db_key_on = _wire__crate__api__import_db_key_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__incr(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, int delta, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__incr(port_, db_name, key, delta, secret_key);
+  }
+
+  late final _wire__crate__api__incrPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__incr');
+  late final _wire__crate__api__incr = _wire__crate__api__incrPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__incr_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, int delta, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__incr_on(port_, handle, db_name, key, delta, secret_key);
+  }
+
+  late final _wire__crate__api__incr_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__incr_on');
+  late final _wire__crate__api__incr_on = _wire__crate__api__incr_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__init_logging() {
+    return _wire__crate__api__init_logging();
+  }
+
+  late final _wire__crate__api__init_loggingPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__init_logging');
+  late final _wire__crate__api__init_logging = _wire__crate__api__init_loggingPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_database_local_only(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__is_database_local_only(db_name);
+  }
+
+  late final _wire__crate__api__is_database_local_onlyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_database_local_only');
+  late final _wire__crate__api__is_database_local_only = _wire__crate__api__is_database_local_onlyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_database_local_only_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__is_database_local_only_on(handle, db_name);
+  }
+
+  late final _wire__crate__api__is_database_local_only_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_database_local_only_on');
+  late final _wire__crate__api__is_database_local_only_on = _wire__crate__api__is_database_local_only_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_networking_paused() {
+    return _wire__crate__api__is_networking_paused();
+  }
+
+  late final _wire__crate__api__is_networking_pausedPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_networking_paused');
+  late final _wire__crate__api__is_networking_paused = _wire__crate__api__is_networking_pausedPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_networking_paused_on(int handle) {
+    return _wire__crate__api__is_networking_paused_on(handle);
+  }
+
+  late final _wire__crate__api__is_networking_paused_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_networking_paused_on');
+  late final _wire__crate__api__is_networking_paused_on = _wire__crate__api__is_networking_paused_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_node_key_sealed(ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir) {
+    return _wire__crate__api__is_node_key_sealed(data_dir);
+  }
+
+  late final _wire__crate__api__is_node_key_sealedPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_node_key_sealed');
+  late final _wire__crate__api__is_node_key_sealed = _wire__crate__api__is_node_key_sealedPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_node_offline() {
+    return _wire__crate__api__is_node_offline();
+  }
+
+  late final _wire__crate__api__is_node_offlinePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_node_offline');
+  late final _wire__crate__api__is_node_offline = _wire__crate__api__is_node_offlinePtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_node_offline_on(int handle) {
+    return _wire__crate__api__is_node_offline_on(handle);
+  }
+
+  late final _wire__crate__api__is_node_offline_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_node_offline_on');
+  late final _wire__crate__api__is_node_offline_on = _wire__crate__api__is_node_offline_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_node_running() {
+    return _wire__crate__api__is_node_running();
+  }
+
+  late final _wire__crate__api__is_node_runningPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_node_running');
+  late final _wire__crate__api__is_node_running = _wire__crate__api__is_node_runningPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_verified_peer_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key) {
+    return _wire__crate__api__is_verified_peer_key(peer_id, public_key);
+  }
+
+  late final _wire__crate__api__is_verified_peer_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_verified_peer_key');
+  late final _wire__crate__api__is_verified_peer_key = _wire__crate__api__is_verified_peer_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__is_verified_peer_key_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key) {
+    return _wire__crate__api__is_verified_peer_key_on(handle, peer_id, public_key);
+  }
+
+  late final _wire__crate__api__is_verified_peer_key_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__is_verified_peer_key_on');
+  late final _wire__crate__api__is_verified_peer_key_on = _wire__crate__api__is_verified_peer_key_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__kadena_account_from_public_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex) {
+    return _wire__crate__api__kadena_account_from_public_key(public_key_hex);
+  }
+
+  late final _wire__crate__api__kadena_account_from_public_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__kadena_account_from_public_key');
+  late final _wire__crate__api__kadena_account_from_public_key = _wire__crate__api__kadena_account_from_public_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__kadena_node_registration_command(ffi.Pointer<wire_cst_list_prim_u_8_strict> network_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> chain_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> multiaddr, ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex) {
+    return _wire__crate__api__kadena_node_registration_command(network_id, chain_id, peer_id, multiaddr, public_key_hex);
+  }
+
+  late final _wire__crate__api__kadena_node_registration_commandPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__kadena_node_registration_command');
+  late final _wire__crate__api__kadena_node_registration_command = _wire__crate__api__kadena_node_registration_commandPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__kadena_sign_command(ffi.Pointer<wire_cst_list_prim_u_8_strict> cmd, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key_hex) {
+    return _wire__crate__api__kadena_sign_command(cmd, secret_key_hex);
+  }
+
+  late final _wire__crate__api__kadena_sign_commandPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__kadena_sign_command');
+  late final _wire__crate__api__kadena_sign_command = _wire__crate__api__kadena_sign_commandPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__link_device(ffi.Pointer<wire_cst_list_prim_u_8_strict> qr_payload, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__link_device(qr_payload, secret_key);
+  }
+
+  late final _wire__crate__api__link_devicePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__link_device');
+  late final _wire__crate__api__link_device = _wire__crate__api__link_devicePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__link_device_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> qr_payload, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__link_device_on(handle, qr_payload, secret_key);
+  }
+
+  late final _wire__crate__api__link_device_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__link_device_on');
+  late final _wire__crate__api__link_device_on = _wire__crate__api__link_device_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_conflicts(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__list_conflicts(db_name);
+  }
+
+  late final _wire__crate__api__list_conflictsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_conflicts');
+  late final _wire__crate__api__list_conflicts = _wire__crate__api__list_conflictsPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_conflicts_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__list_conflicts_on(handle, db_name);
+  }
+
+  late final _wire__crate__api__list_conflicts_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_conflicts_on');
+  late final _wire__crate__api__list_conflicts_on = _wire__crate__api__list_conflicts_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_databases() {
+    return _wire__crate__api__list_databases();
+  }
+
+  late final _wire__crate__api__list_databasesPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_databases');
+  late final _wire__crate__api__list_databases = _wire__crate__api__list_databasesPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_databases_on(int handle) {
+    return _wire__crate__api__list_databases_on(handle);
+  }
+
+  late final _wire__crate__api__list_databases_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_databases_on');
+  late final _wire__crate__api__list_databases_on = _wire__crate__api__list_databases_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_file_offers() {
+    return _wire__crate__api__list_file_offers();
+  }
+
+  late final _wire__crate__api__list_file_offersPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_file_offers');
+  late final _wire__crate__api__list_file_offers = _wire__crate__api__list_file_offersPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_file_offers_on(int handle) {
+    return _wire__crate__api__list_file_offers_on(handle);
+  }
+
+  late final _wire__crate__api__list_file_offers_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_file_offers_on');
+  late final _wire__crate__api__list_file_offers_on = _wire__crate__api__list_file_offers_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_keys(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__list_keys(db_name);
+  }
+
+  late final _wire__crate__api__list_keysPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_keys');
+  late final _wire__crate__api__list_keys = _wire__crate__api__list_keysPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_keys_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__list_keys_on(handle, db_name);
+  }
+
+  late final _wire__crate__api__list_keys_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_keys_on');
+  late final _wire__crate__api__list_keys_on = _wire__crate__api__list_keys_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_linked_devices() {
+    return _wire__crate__api__list_linked_devices();
+  }
+
+  late final _wire__crate__api__list_linked_devicesPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_linked_devices');
+  late final _wire__crate__api__list_linked_devices = _wire__crate__api__list_linked_devicesPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_linked_devices_on(int handle) {
+    return _wire__crate__api__list_linked_devices_on(handle);
+  }
+
+  late final _wire__crate__api__list_linked_devices_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_linked_devices_on');
+  late final _wire__crate__api__list_linked_devices_on = _wire__crate__api__list_linked_devices_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_node_instances() {
+    return _wire__crate__api__list_node_instances();
+  }
+
+  late final _wire__crate__api__list_node_instancesPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_node_instances');
+  late final _wire__crate__api__list_node_instances = _wire__crate__api__list_node_instancesPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  void wire__crate__api__list_push(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__list_push(port_, db_name, key, value, secret_key);
+  }
+
+  late final _wire__crate__api__list_pushPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_push');
+  late final _wire__crate__api__list_push = _wire__crate__api__list_pushPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__list_push_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__list_push_on(port_, handle, db_name, key, value, secret_key);
+  }
+
+  late final _wire__crate__api__list_push_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_push_on');
+  late final _wire__crate__api__list_push_on = _wire__crate__api__list_push_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_range(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__list_range(db_name, key);
+  }
+
+  late final _wire__crate__api__list_rangePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_range');
+  late final _wire__crate__api__list_range = _wire__crate__api__list_rangePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_range_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__list_range_on(handle, db_name, key);
+  }
+
+  late final _wire__crate__api__list_range_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_range_on');
+  late final _wire__crate__api__list_range_on = _wire__crate__api__list_range_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_replica_checks() {
+    return _wire__crate__api__list_replica_checks();
+  }
+
+  late final _wire__crate__api__list_replica_checksPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_replica_checks');
+  late final _wire__crate__api__list_replica_checks = _wire__crate__api__list_replica_checksPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__list_replica_checks_on(int handle) {
+    return _wire__crate__api__list_replica_checks_on(handle);
+  }
+
+  late final _wire__crate__api__list_replica_checks_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__list_replica_checks_on');
+  late final _wire__crate__api__list_replica_checks_on = _wire__crate__api__list_replica_checks_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  void wire__crate__api__measure_latency(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id) {
+    return _wire__crate__api__measure_latency(port_, peer_id);
+  }
+
+  late final _wire__crate__api__measure_latencyPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__measure_latency');
+  late final _wire__crate__api__measure_latency = _wire__crate__api__measure_latencyPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__measure_latency_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id) {
+    return _wire__crate__api__measure_latency_on(port_, handle, peer_id);
+  }
+
+  late final _wire__crate__api__measure_latency_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__measure_latency_on');
+  late final _wire__crate__api__measure_latency_on = _wire__crate__api__measure_latency_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__open_storage_read_only(ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir) {
+    return _wire__crate__api__open_storage_read_only(data_dir);
+  }
+
+  late final _wire__crate__api__open_storage_read_onlyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__open_storage_read_only');
+  late final _wire__crate__api__open_storage_read_only = _wire__crate__api__open_storage_read_onlyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__pause_networking(int port_) {
+    return _wire__crate__api__pause_networking(port_);
+  }
+
+  late final _wire__crate__api__pause_networkingPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__pause_networking');
+  late final _wire__crate__api__pause_networking = _wire__crate__api__pause_networkingPtr.asFunction<void Function(int)>();
+
+  void wire__crate__api__pause_networking_on(int port_, int handle) {
+    return _wire__crate__api__pause_networking_on(port_, handle);
+  }
+
+  late final _wire__crate__api__pause_networking_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__pause_networking_on');
+  late final _wire__crate__api__pause_networking_on = _wire__crate__api__pause_networking_onPtr.asFunction<void Function(int, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__pin_peer(ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> address) {
+    return _wire__crate__api__pin_peer(node_id, address);
+  }
+
+  late final _wire__crate__api__pin_peerPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__pin_peer');
+  late final _wire__crate__api__pin_peer = _wire__crate__api__pin_peerPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__pin_peer_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> address) {
+    return _wire__crate__api__pin_peer_on(handle, node_id, address);
+  }
+
+  late final _wire__crate__api__pin_peer_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__pin_peer_on');
+  late final _wire__crate__api__pin_peer_on = _wire__crate__api__pin_peer_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__prove_ownership(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id) {
+    return _wire__crate__api__prove_ownership(port_, peer_id);
+  }
+
+  late final _wire__crate__api__prove_ownershipPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__prove_ownership');
+  late final _wire__crate__api__prove_ownership = _wire__crate__api__prove_ownershipPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__prove_ownership_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id) {
+    return _wire__crate__api__prove_ownership_on(port_, handle, peer_id);
+  }
+
+  late final _wire__crate__api__prove_ownership_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__prove_ownership_on');
+  late final _wire__crate__api__prove_ownership_on = _wire__crate__api__prove_ownership_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__publish_topic(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> name, ffi.Pointer<wire_cst_list_prim_u_8_loose> content) {
+    return _wire__crate__api__publish_topic(port_, name, content);
+  }
+
+  late final _wire__crate__api__publish_topicPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__publish_topic');
+  late final _wire__crate__api__publish_topic = _wire__crate__api__publish_topicPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  void wire__crate__api__publish_topic_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> name, ffi.Pointer<wire_cst_list_prim_u_8_loose> content) {
+    return _wire__crate__api__publish_topic_on(port_, handle, name, content);
+  }
+
+  late final _wire__crate__api__publish_topic_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__publish_topic_on');
+  late final _wire__crate__api__publish_topic_on = _wire__crate__api__publish_topic_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  void wire__crate__api__put_json(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> json, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__put_json(port_, db_name, key, json, secret_key);
+  }
+
+  late final _wire__crate__api__put_jsonPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__put_json');
+  late final _wire__crate__api__put_json = _wire__crate__api__put_jsonPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__put_json_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> json, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__put_json_on(port_, handle, db_name, key, json, secret_key);
+  }
+
+  late final _wire__crate__api__put_json_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__put_json_on');
+  late final _wire__crate__api__put_json_on = _wire__crate__api__put_json_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__put_string(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__put_string(port_, db_name, key, value, secret_key);
+  }
+
+  late final _wire__crate__api__put_stringPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__put_string');
+  late final _wire__crate__api__put_string = _wire__crate__api__put_stringPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__put_string_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__put_string_on(port_, handle, db_name, key, value, secret_key);
+  }
+
+  late final _wire__crate__api__put_string_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__put_string_on');
+  late final _wire__crate__api__put_string_on = _wire__crate__api__put_string_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__query(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_query_spec_dto> spec) {
+    return _wire__crate__api__query(port_, db_name, spec);
+  }
+
+  late final _wire__crate__api__queryPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_query_spec_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__query');
+  late final _wire__crate__api__query = _wire__crate__api__queryPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_query_spec_dto>)>();
+
+  void wire__crate__api__query_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_query_spec_dto> spec) {
+    return _wire__crate__api__query_on(port_, handle, db_name, spec);
+  }
+
+  late final _wire__crate__api__query_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_query_spec_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__query_on');
+  late final _wire__crate__api__query_on = _wire__crate__api__query_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_query_spec_dto>)>();
+
+  void wire__crate__api__read_only_export_backup(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> path, ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase, ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key) {
+    return _wire__crate__api__read_only_export_backup(port_, path, passphrase, wallet_secret_key);
+  }
+
+  late final _wire__crate__api__read_only_export_backupPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__read_only_export_backup');
+  late final _wire__crate__api__read_only_export_backup = _wire__crate__api__read_only_export_backupPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__read_only_get_data(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__read_only_get_data(port_, db_name, key);
+  }
+
+  late final _wire__crate__api__read_only_get_dataPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__read_only_get_data');
+  late final _wire__crate__api__read_only_get_data = _wire__crate__api__read_only_get_dataPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__read_only_get_entries_page(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor, bool keys_only, ffi.Pointer<ffi.Uint64> max_bytes) {
+    return _wire__crate__api__read_only_get_entries_page(port_, db_name, limit, cursor, keys_only, max_bytes);
+  }
+
+  late final _wire__crate__api__read_only_get_entries_pagePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__read_only_get_entries_page');
+  late final _wire__crate__api__read_only_get_entries_page = _wire__crate__api__read_only_get_entries_pagePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool, ffi.Pointer<ffi.Uint64>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__read_only_list_databases() {
+    return _wire__crate__api__read_only_list_databases();
+  }
+
+  late final _wire__crate__api__read_only_list_databasesPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__read_only_list_databases');
+  late final _wire__crate__api__read_only_list_databases = _wire__crate__api__read_only_list_databasesPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__reject_rpc(int request_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> message) {
+    return _wire__crate__api__reject_rpc(request_id, message);
+  }
+
+  late final _wire__crate__api__reject_rpcPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__reject_rpc');
+  late final _wire__crate__api__reject_rpc = _wire__crate__api__reject_rpcPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__reject_rpc_on(int handle, int request_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> message) {
+    return _wire__crate__api__reject_rpc_on(handle, request_id, message);
+  }
+
+  late final _wire__crate__api__reject_rpc_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__reject_rpc_on');
+  late final _wire__crate__api__reject_rpc_on = _wire__crate__api__reject_rpc_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__request_sync(int port_, ffi.Pointer<ffi.Int64> since_timestamp) {
+    return _wire__crate__api__request_sync(port_, since_timestamp);
+  }
+
+  late final _wire__crate__api__request_syncPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Int64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__request_sync');
+  late final _wire__crate__api__request_sync = _wire__crate__api__request_syncPtr.asFunction<void Function(int, ffi.Pointer<ffi.Int64>)>();
+
+  void wire__crate__api__request_sync_on(int port_, int handle, ffi.Pointer<ffi.Int64> since_timestamp) {
+    return _wire__crate__api__request_sync_on(port_, handle, since_timestamp);
+  }
+
+  late final _wire__crate__api__request_sync_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<ffi.Int64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__request_sync_on');
+  late final _wire__crate__api__request_sync_on = _wire__crate__api__request_sync_onPtr.asFunction<void Function(int, int, ffi.Pointer<ffi.Int64>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__reset_log_levels() {
+    return _wire__crate__api__reset_log_levels();
+  }
+
+  late final _wire__crate__api__reset_log_levelsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>('frbgen_cyberfly_mobile_node_wire__crate__api__reset_log_levels');
+  late final _wire__crate__api__reset_log_levels = _wire__crate__api__reset_log_levelsPtr.asFunction<ffi.Pointer<DartCObject> Function()>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__respond_rpc(int request_id, ffi.Pointer<wire_cst_list_prim_u_8_loose> payload) {
+    return _wire__crate__api__respond_rpc(request_id, payload);
+  }
+
+  late final _wire__crate__api__respond_rpcPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__respond_rpc');
+  late final _wire__crate__api__respond_rpc = _wire__crate__api__respond_rpcPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__respond_rpc_on(int handle, int request_id, ffi.Pointer<wire_cst_list_prim_u_8_loose> payload) {
+    return _wire__crate__api__respond_rpc_on(handle, request_id, payload);
+  }
+
+  late final _wire__crate__api__respond_rpc_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__respond_rpc_on');
+  late final _wire__crate__api__respond_rpc_on = _wire__crate__api__respond_rpc_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  void wire__crate__api__resume_networking(int port_) {
+    return _wire__crate__api__resume_networking(port_);
+  }
+
+  late final _wire__crate__api__resume_networkingPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__resume_networking');
+  late final _wire__crate__api__resume_networking = _wire__crate__api__resume_networkingPtr.asFunction<void Function(int)>();
+
+  void wire__crate__api__resume_networking_on(int port_, int handle) {
+    return _wire__crate__api__resume_networking_on(port_, handle);
+  }
+
+  late final _wire__crate__api__resume_networking_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__resume_networking_on');
+  late final _wire__crate__api__resume_networking_on = _wire__crate__api__resume_networking_onPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__api__revoke_writer(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> writer_public_key, ffi.Pointer<wire_cst_list_prim_u_8_strict> owner_secret_key) {
+    return _wire__crate__api__revoke_writer(port_, db_name, writer_public_key, owner_secret_key);
+  }
+
+  late final _wire__crate__api__revoke_writerPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__revoke_writer');
+  late final _wire__crate__api__revoke_writer = _wire__crate__api__revoke_writerPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__revoke_writer_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> writer_public_key, ffi.Pointer<wire_cst_list_prim_u_8_strict> owner_secret_key) {
+    return _wire__crate__api__revoke_writer_on(port_, handle, db_name, writer_public_key, owner_secret_key);
+  }
+
+  late final _wire__crate__api__revoke_writer_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__revoke_writer_on');
+  late final _wire__crate__api__revoke_writer_on = _wire__crate__api__revoke_writer_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__rotate_identity(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> new_secret_key, ffi.Pointer<ffi.Uint64> grace_secs) {
+    return _wire__crate__api__rotate_identity(port_, new_secret_key, grace_secs);
+  }
+
+  late final _wire__crate__api__rotate_identityPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__rotate_identity');
+  late final _wire__crate__api__rotate_identity = _wire__crate__api__rotate_identityPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Uint64>)>();
+
+  void wire__crate__api__rotate_identity_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> new_secret_key, ffi.Pointer<ffi.Uint64> grace_secs) {
+    return _wire__crate__api__rotate_identity_on(port_, handle, new_secret_key, grace_secs);
+  }
+
+  late final _wire__crate__api__rotate_identity_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__rotate_identity_on');
+  late final _wire__crate__api__rotate_identity_on = _wire__crate__api__rotate_identity_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Uint64>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__rotate_key_wrapping_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_loose> old_wrapping_key, ffi.Pointer<wire_cst_list_prim_u_8_loose> new_wrapping_key) {
+    return _wire__crate__api__rotate_key_wrapping_key(data_dir, old_wrapping_key, new_wrapping_key);
+  }
+
+  late final _wire__crate__api__rotate_key_wrapping_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__rotate_key_wrapping_key');
+  late final _wire__crate__api__rotate_key_wrapping_key = _wire__crate__api__rotate_key_wrapping_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  void wire__crate__api__rpc_call(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> method, ffi.Pointer<wire_cst_list_prim_u_8_loose> payload, ffi.Pointer<ffi.Uint64> timeout_ms) {
+    return _wire__crate__api__rpc_call(port_, peer_id, method, payload, timeout_ms);
+  }
+
+  late final _wire__crate__api__rpc_callPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__rpc_call');
+  late final _wire__crate__api__rpc_call = _wire__crate__api__rpc_callPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<ffi.Uint64>)>();
+
+  void wire__crate__api__rpc_call_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> method, ffi.Pointer<wire_cst_list_prim_u_8_loose> payload, ffi.Pointer<ffi.Uint64> timeout_ms) {
+    return _wire__crate__api__rpc_call_on(port_, handle, peer_id, method, payload, timeout_ms);
+  }
+
+  late final _wire__crate__api__rpc_call_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<ffi.Uint64>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__rpc_call_on');
+  late final _wire__crate__api__rpc_call_on = _wire__crate__api__rpc_call_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<ffi.Uint64>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__scan_prefix(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> prefix, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor) {
+    return _wire__crate__api__scan_prefix(db_name, prefix, limit, cursor);
+  }
+
+  late final _wire__crate__api__scan_prefixPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__scan_prefix');
+  late final _wire__crate__api__scan_prefix = _wire__crate__api__scan_prefixPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__scan_prefix_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> prefix, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor) {
+    return _wire__crate__api__scan_prefix_on(handle, db_name, prefix, limit, cursor);
+  }
+
+  late final _wire__crate__api__scan_prefix_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__scan_prefix_on');
+  late final _wire__crate__api__scan_prefix_on = _wire__crate__api__scan_prefix_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__scan_range(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> start, ffi.Pointer<wire_cst_list_prim_u_8_strict> end, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor) {
+    return _wire__crate__api__scan_range(db_name, start, end, limit, cursor);
+  }
+
+  late final _wire__crate__api__scan_rangePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__scan_range');
+  late final _wire__crate__api__scan_range = _wire__crate__api__scan_rangePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__scan_range_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> start, ffi.Pointer<wire_cst_list_prim_u_8_strict> end, int limit, ffi.Pointer<wire_cst_list_prim_u_8_strict> cursor) {
+    return _wire__crate__api__scan_range_on(handle, db_name, start, end, limit, cursor);
+  }
+
+  late final _wire__crate__api__scan_range_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__scan_range_on');
+  late final _wire__crate__api__scan_range_on = _wire__crate__api__scan_range_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__seal_node_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_loose> wrapping_key) {
+    return _wire__crate__api__seal_node_key(data_dir, wrapping_key);
+  }
+
+  late final _wire__crate__api__seal_node_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__seal_node_key');
+  late final _wire__crate__api__seal_node_key = _wire__crate__api__seal_node_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  void wire__crate__api__search(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> query, int limit) {
+    return _wire__crate__api__search(port_, db_name, query, limit);
+  }
+
+  late final _wire__crate__api__searchPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__search');
+  late final _wire__crate__api__search = _wire__crate__api__searchPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  void wire__crate__api__search_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> query, int limit) {
+    return _wire__crate__api__search_on(port_, handle, db_name, query, limit);
+  }
+
+  late final _wire__crate__api__search_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__search_on');
+  late final _wire__crate__api__search_on = _wire__crate__api__search_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  void wire__crate__api__send_dm(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> text) {
+    return _wire__crate__api__send_dm(port_, peer_id, text);
+  }
+
+  late final _wire__crate__api__send_dmPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__send_dm');
+  late final _wire__crate__api__send_dm = _wire__crate__api__send_dmPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__send_dm_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> text) {
+    return _wire__crate__api__send_dm_on(port_, handle, peer_id, text);
+  }
+
+  late final _wire__crate__api__send_dm_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__send_dm_on');
+  late final _wire__crate__api__send_dm_on = _wire__crate__api__send_dm_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__send_file(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> path) {
+    return _wire__crate__api__send_file(port_, peer_id, path);
+  }
+
+  late final _wire__crate__api__send_filePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__send_file');
+  late final _wire__crate__api__send_file = _wire__crate__api__send_filePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__send_file_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id, ffi.Pointer<wire_cst_list_prim_u_8_strict> path) {
+    return _wire__crate__api__send_file_on(port_, handle, peer_id, path);
+  }
+
+  late final _wire__crate__api__send_file_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__send_file_on');
+  late final _wire__crate__api__send_file_on = _wire__crate__api__send_file_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__send_gossip(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> topic, ffi.Pointer<wire_cst_list_prim_u_8_strict> message) {
+    return _wire__crate__api__send_gossip(port_, topic, message);
+  }
+
+  late final _wire__crate__api__send_gossipPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__send_gossip');
+  late final _wire__crate__api__send_gossip = _wire__crate__api__send_gossipPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__send_gossip_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> topic, ffi.Pointer<wire_cst_list_prim_u_8_strict> message) {
+    return _wire__crate__api__send_gossip_on(port_, handle, topic, message);
+  }
+
+  late final _wire__crate__api__send_gossip_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__send_gossip_on');
+  late final _wire__crate__api__send_gossip_on = _wire__crate__api__send_gossip_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__send_latency_request(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id) {
+    return _wire__crate__api__send_latency_request(port_, peer_id);
+  }
+
+  late final _wire__crate__api__send_latency_requestPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__send_latency_request');
+  late final _wire__crate__api__send_latency_request = _wire__crate__api__send_latency_requestPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__send_latency_request_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id) {
+    return _wire__crate__api__send_latency_request_on(port_, handle, peer_id);
+  }
+
+  late final _wire__crate__api__send_latency_request_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__send_latency_request_on');
+  late final _wire__crate__api__send_latency_request_on = _wire__crate__api__send_latency_request_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__serve_rpc(ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__serve_rpc(sink);
+  }
+
+  late final _wire__crate__api__serve_rpcPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__serve_rpc');
+  late final _wire__crate__api__serve_rpc = _wire__crate__api__serve_rpcPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__serve_rpc_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__serve_rpc_on(handle, sink);
+  }
+
+  late final _wire__crate__api__serve_rpc_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__serve_rpc_on');
+  late final _wire__crate__api__serve_rpc_on = _wire__crate__api__serve_rpc_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__set_add(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> member, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__set_add(port_, db_name, key, member, secret_key);
+  }
+
+  late final _wire__crate__api__set_addPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_add');
+  late final _wire__crate__api__set_add = _wire__crate__api__set_addPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__set_add_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> member, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__set_add_on(port_, handle, db_name, key, member, secret_key);
+  }
+
+  late final _wire__crate__api__set_add_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_add_on');
+  late final _wire__crate__api__set_add_on = _wire__crate__api__set_add_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_bandwidth_caps(ffi.Pointer<wire_cst_bandwidth_caps_dto> caps) {
+    return _wire__crate__api__set_bandwidth_caps(caps);
+  }
+
+  late final _wire__crate__api__set_bandwidth_capsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_bandwidth_caps_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_bandwidth_caps');
+  late final _wire__crate__api__set_bandwidth_caps = _wire__crate__api__set_bandwidth_capsPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_bandwidth_caps_dto>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_bandwidth_caps_on(int handle, ffi.Pointer<wire_cst_bandwidth_caps_dto> caps) {
+    return _wire__crate__api__set_bandwidth_caps_on(handle, caps);
+  }
+
+  late final _wire__crate__api__set_bandwidth_caps_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_bandwidth_caps_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_bandwidth_caps_on');
+  late final _wire__crate__api__set_bandwidth_caps_on = _wire__crate__api__set_bandwidth_caps_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_bandwidth_caps_dto>)>();
+
+  void wire__crate__api__set_database_local_only(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, bool local_only) {
+    return _wire__crate__api__set_database_local_only(port_, db_name, local_only);
+  }
+
+  late final _wire__crate__api__set_database_local_onlyPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_database_local_only');
+  late final _wire__crate__api__set_database_local_only = _wire__crate__api__set_database_local_onlyPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)>();
+
+  void wire__crate__api__set_database_local_only_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, bool local_only) {
+    return _wire__crate__api__set_database_local_only_on(port_, handle, db_name, local_only);
+  }
+
+  late final _wire__crate__api__set_database_local_only_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_database_local_only_on');
+  late final _wire__crate__api__set_database_local_only_on = _wire__crate__api__set_database_local_only_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)>();
+
+  void wire__crate__api__set_device_conditions(int port_, ffi.Pointer<wire_cst_device_conditions_dto> conditions) {
+    return _wire__crate__api__set_device_conditions(port_, conditions);
+  }
+
+  late final _wire__crate__api__set_device_conditionsPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_device_conditions_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_device_conditions');
+  late final _wire__crate__api__set_device_conditions = _wire__crate__api__set_device_conditionsPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_device_conditions_dto>)>();
+
+  void wire__crate__api__set_device_conditions_on(int port_, int handle, ffi.Pointer<wire_cst_device_conditions_dto> conditions) {
+    return _wire__crate__api__set_device_conditions_on(port_, handle, conditions);
+  }
+
+  late final _wire__crate__api__set_device_conditions_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_device_conditions_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_device_conditions_on');
+  late final _wire__crate__api__set_device_conditions_on = _wire__crate__api__set_device_conditions_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_device_conditions_dto>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_key_wrapping_key(ffi.Pointer<wire_cst_list_prim_u_8_loose> wrapping_key) {
+    return _wire__crate__api__set_key_wrapping_key(wrapping_key);
+  }
+
+  late final _wire__crate__api__set_key_wrapping_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_key_wrapping_key');
+  late final _wire__crate__api__set_key_wrapping_key = _wire__crate__api__set_key_wrapping_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_log_buffer_size(int size) {
+    return _wire__crate__api__set_log_buffer_size(size);
+  }
+
+  late final _wire__crate__api__set_log_buffer_sizePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_log_buffer_size');
+  late final _wire__crate__api__set_log_buffer_size = _wire__crate__api__set_log_buffer_sizePtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_log_level(ffi.Pointer<wire_cst_list_prim_u_8_strict> level) {
+    return _wire__crate__api__set_log_level(level);
+  }
+
+  late final _wire__crate__api__set_log_levelPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_log_level');
+  late final _wire__crate__api__set_log_level = _wire__crate__api__set_log_levelPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_log_target_level(ffi.Pointer<wire_cst_list_prim_u_8_strict> target, ffi.Pointer<wire_cst_list_prim_u_8_strict> level) {
+    return _wire__crate__api__set_log_target_level(target, level);
+  }
+
+  late final _wire__crate__api__set_log_target_levelPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_log_target_level');
+  late final _wire__crate__api__set_log_target_level = _wire__crate__api__set_log_target_levelPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_members(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__set_members(db_name, key);
+  }
+
+  late final _wire__crate__api__set_membersPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_members');
+  late final _wire__crate__api__set_members = _wire__crate__api__set_membersPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_members_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key) {
+    return _wire__crate__api__set_members_on(handle, db_name, key);
+  }
+
+  late final _wire__crate__api__set_members_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_members_on');
+  late final _wire__crate__api__set_members_on = _wire__crate__api__set_members_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__set_network_passphrase(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase) {
+    return _wire__crate__api__set_network_passphrase(port_, data_dir, passphrase);
+  }
+
+  late final _wire__crate__api__set_network_passphrasePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_network_passphrase');
+  late final _wire__crate__api__set_network_passphrase = _wire__crate__api__set_network_passphrasePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__set_power_mode(int port_, int mode) {
+    return _wire__crate__api__set_power_mode(port_, mode);
+  }
+
+  late final _wire__crate__api__set_power_modePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_power_mode');
+  late final _wire__crate__api__set_power_mode = _wire__crate__api__set_power_modePtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__api__set_power_mode_on(int port_, int handle, int mode) {
+    return _wire__crate__api__set_power_mode_on(port_, handle, mode);
+  }
+
+  late final _wire__crate__api__set_power_mode_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Int32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_power_mode_on');
+  late final _wire__crate__api__set_power_mode_on = _wire__crate__api__set_power_mode_onPtr.asFunction<void Function(int, int, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_presence_status(ffi.Pointer<wire_cst_list_prim_u_8_strict> status) {
+    return _wire__crate__api__set_presence_status(status);
+  }
+
+  late final _wire__crate__api__set_presence_statusPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_presence_status');
+  late final _wire__crate__api__set_presence_status = _wire__crate__api__set_presence_statusPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_presence_status_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> status) {
+    return _wire__crate__api__set_presence_status_on(handle, status);
+  }
+
+  late final _wire__crate__api__set_presence_status_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_presence_status_on');
+  late final _wire__crate__api__set_presence_status_on = _wire__crate__api__set_presence_status_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_retain_conflicts(bool enabled) {
+    return _wire__crate__api__set_retain_conflicts(enabled);
+  }
+
+  late final _wire__crate__api__set_retain_conflictsPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_retain_conflicts');
+  late final _wire__crate__api__set_retain_conflicts = _wire__crate__api__set_retain_conflictsPtr.asFunction<ffi.Pointer<DartCObject> Function(bool)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_retain_conflicts_on(int handle, bool enabled) {
+    return _wire__crate__api__set_retain_conflicts_on(handle, enabled);
+  }
+
+  late final _wire__crate__api__set_retain_conflicts_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_retain_conflicts_on');
+  late final _wire__crate__api__set_retain_conflicts_on = _wire__crate__api__set_retain_conflicts_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, bool)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_selective_sync(bool selective) {
+    return _wire__crate__api__set_selective_sync(selective);
+  }
+
+  late final _wire__crate__api__set_selective_syncPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_selective_sync');
+  late final _wire__crate__api__set_selective_sync = _wire__crate__api__set_selective_syncPtr.asFunction<ffi.Pointer<DartCObject> Function(bool)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__set_selective_sync_on(int handle, bool selective) {
+    return _wire__crate__api__set_selective_sync_on(handle, selective);
+  }
+
+  late final _wire__crate__api__set_selective_sync_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__set_selective_sync_on');
+  late final _wire__crate__api__set_selective_sync_on = _wire__crate__api__set_selective_sync_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, bool)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__sha256_hash(ffi.Pointer<wire_cst_list_prim_u_8_loose> data) {
+    return _wire__crate__api__sha256_hash(data);
+  }
+
+  late final _wire__crate__api__sha256_hashPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__sha256_hash');
+  late final _wire__crate__api__sha256_hash = _wire__crate__api__sha256_hashPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__sign_bytes(ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key_hex, ffi.Pointer<wire_cst_list_prim_u_8_loose> message) {
+    return _wire__crate__api__sign_bytes(secret_key_hex, message);
+  }
+
+  late final _wire__crate__api__sign_bytesPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__sign_bytes');
+  late final _wire__crate__api__sign_bytes = _wire__crate__api__sign_bytesPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__sign_message_with_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key_hex, ffi.Pointer<wire_cst_list_prim_u_8_strict> message) {
+    return _wire__crate__api__sign_message_with_key(secret_key_hex, message);
+  }
+
+  late final _wire__crate__api__sign_message_with_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__sign_message_with_key');
+  late final _wire__crate__api__sign_message_with_key = _wire__crate__api__sign_message_with_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__start_debug_server(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> bind_addr, bool allow_writes) {
+    return _wire__crate__api__start_debug_server(port_, bind_addr, allow_writes);
+  }
+
+  late final _wire__crate__api__start_debug_serverPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_debug_server');
+  late final _wire__crate__api__start_debug_server = _wire__crate__api__start_debug_serverPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)>();
+
+  void wire__crate__api__start_debug_server_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> bind_addr, bool allow_writes) {
+    return _wire__crate__api__start_debug_server_on(port_, handle, bind_addr, allow_writes);
+  }
+
+  late final _wire__crate__api__start_debug_server_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_debug_server_on');
+  late final _wire__crate__api__start_debug_server_on = _wire__crate__api__start_debug_server_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)>();
+
+  void wire__crate__api__start_metrics_server(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> bind_addr) {
+    return _wire__crate__api__start_metrics_server(port_, bind_addr);
+  }
+
+  late final _wire__crate__api__start_metrics_serverPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_metrics_server');
+  late final _wire__crate__api__start_metrics_server = _wire__crate__api__start_metrics_serverPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__start_metrics_server_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> bind_addr) {
+    return _wire__crate__api__start_metrics_server_on(port_, handle, bind_addr);
+  }
+
+  late final _wire__crate__api__start_metrics_server_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_metrics_server_on');
+  late final _wire__crate__api__start_metrics_server_on = _wire__crate__api__start_metrics_server_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__start_mqtt_bridge(int port_, ffi.Pointer<wire_cst_mqtt_bridge_config_dto> config) {
+    return _wire__crate__api__start_mqtt_bridge(port_, config);
+  }
+
+  late final _wire__crate__api__start_mqtt_bridgePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_mqtt_bridge_config_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_mqtt_bridge');
+  late final _wire__crate__api__start_mqtt_bridge = _wire__crate__api__start_mqtt_bridgePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_mqtt_bridge_config_dto>)>();
+
+  void wire__crate__api__start_mqtt_bridge_on(int port_, int handle, ffi.Pointer<wire_cst_mqtt_bridge_config_dto> config) {
+    return _wire__crate__api__start_mqtt_bridge_on(port_, handle, config);
+  }
+
+  late final _wire__crate__api__start_mqtt_bridge_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_mqtt_bridge_config_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_mqtt_bridge_on');
+  late final _wire__crate__api__start_mqtt_bridge_on = _wire__crate__api__start_mqtt_bridge_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_mqtt_bridge_config_dto>)>();
+
+  void wire__crate__api__start_node(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key, ffi.Pointer<wire_cst_list_String> bootstrap_peers, ffi.Pointer<wire_cst_list_prim_u_8_strict> region) {
+    return _wire__crate__api__start_node(port_, data_dir, wallet_secret_key, bootstrap_peers, region);
+  }
+
+  late final _wire__crate__api__start_nodePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_node');
+  late final _wire__crate__api__start_node = _wire__crate__api__start_nodePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__start_node_in_network(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key, ffi.Pointer<wire_cst_list_String> bootstrap_peers, ffi.Pointer<wire_cst_list_prim_u_8_strict> region, ffi.Pointer<wire_cst_list_prim_u_8_strict> network_id) {
+    return _wire__crate__api__start_node_in_network(port_, data_dir, wallet_secret_key, bootstrap_peers, region, network_id);
+  }
+
+  late final _wire__crate__api__start_node_in_networkPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_node_in_network');
+  late final _wire__crate__api__start_node_in_network = _wire__crate__api__start_node_in_networkPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__start_node_instance(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key, ffi.Pointer<wire_cst_list_String> bootstrap_peers, ffi.Pointer<wire_cst_node_config_dto> config) {
+    return _wire__crate__api__start_node_instance(port_, data_dir, wallet_secret_key, bootstrap_peers, config);
+  }
+
+  late final _wire__crate__api__start_node_instancePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_node_config_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_node_instance');
+  late final _wire__crate__api__start_node_instance = _wire__crate__api__start_node_instancePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_node_config_dto>)>();
+
+  void wire__crate__api__start_node_with_config(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key, ffi.Pointer<wire_cst_list_String> bootstrap_peers, ffi.Pointer<wire_cst_node_config_dto> config) {
+    return _wire__crate__api__start_node_with_config(port_, data_dir, wallet_secret_key, bootstrap_peers, config);
+  }
+
+  late final _wire__crate__api__start_node_with_configPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_node_config_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_node_with_config');
+  late final _wire__crate__api__start_node_with_config = _wire__crate__api__start_node_with_configPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_node_config_dto>)>();
+
+  void wire__crate__api__start_node_with_progress(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key, ffi.Pointer<wire_cst_list_String> bootstrap_peers, ffi.Pointer<wire_cst_node_config_dto> config, ffi.Pointer<wire_cst_list_prim_u_8_strict> progress) {
+    return _wire__crate__api__start_node_with_progress(port_, data_dir, wallet_secret_key, bootstrap_peers, config, progress);
+  }
+
+  late final _wire__crate__api__start_node_with_progressPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_node_config_dto>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_node_with_progress');
+  late final _wire__crate__api__start_node_with_progress = _wire__crate__api__start_node_with_progressPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Pointer<wire_cst_node_config_dto>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__start_offline(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir) {
+    return _wire__crate__api__start_offline(port_, data_dir);
+  }
+
+  late final _wire__crate__api__start_offlinePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__start_offline');
+  late final _wire__crate__api__start_offline = _wire__crate__api__start_offlinePtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__stop_mqtt_bridge(int port_) {
+    return _wire__crate__api__stop_mqtt_bridge(port_);
+  }
+
+  late final _wire__crate__api__stop_mqtt_bridgePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__stop_mqtt_bridge');
+  late final _wire__crate__api__stop_mqtt_bridge = _wire__crate__api__stop_mqtt_bridgePtr.asFunction<void Function(int)>();
+
+  void wire__crate__api__stop_mqtt_bridge_on(int port_, int handle) {
+    return _wire__crate__api__stop_mqtt_bridge_on(port_, handle);
+  }
+
+  late final _wire__crate__api__stop_mqtt_bridge_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__stop_mqtt_bridge_on');
+  late final _wire__crate__api__stop_mqtt_bridge_on = _wire__crate__api__stop_mqtt_bridge_onPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__api__stop_node(int port_) {
+    return _wire__crate__api__stop_node(port_);
+  }
+
+  late final _wire__crate__api__stop_nodePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__stop_node');
+  late final _wire__crate__api__stop_node = _wire__crate__api__stop_nodePtr.asFunction<void Function(int)>();
+
+  void wire__crate__api__stop_node_instance(int port_, int handle) {
+    return _wire__crate__api__stop_node_instance(port_, handle);
+  }
+
+  late final _wire__crate__api__stop_node_instancePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__stop_node_instance');
+  late final _wire__crate__api__stop_node_instance = _wire__crate__api__stop_node_instancePtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__api__store_batch(int port_, ffi.Pointer<wire_cst_list_batch_entry_dto> entries) {
+    return _wire__crate__api__store_batch(port_, entries);
+  }
+
+  late final _wire__crate__api__store_batchPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_batch_entry_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_batch');
+  late final _wire__crate__api__store_batch = _wire__crate__api__store_batchPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_batch_entry_dto>)>();
+
+  void wire__crate__api__store_batch_on(int port_, int handle, ffi.Pointer<wire_cst_list_batch_entry_dto> entries) {
+    return _wire__crate__api__store_batch_on(port_, handle, entries);
+  }
+
+  late final _wire__crate__api__store_batch_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_batch_entry_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_batch_on');
+  late final _wire__crate__api__store_batch_on = _wire__crate__api__store_batch_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_batch_entry_dto>)>();
+
+  void wire__crate__api__store_data(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key, ffi.Pointer<wire_cst_list_prim_u_8_strict> signature) {
+    return _wire__crate__api__store_data(port_, db_name, key, value, public_key, signature);
+  }
+
+  late final _wire__crate__api__store_dataPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data');
+  late final _wire__crate__api__store_data = _wire__crate__api__store_dataPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_data_idempotent(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, int timestamp_ms, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__store_data_idempotent(port_, db_name, key, value, timestamp_ms, secret_key);
+  }
+
+  late final _wire__crate__api__store_data_idempotentPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_idempotent');
+  late final _wire__crate__api__store_data_idempotent = _wire__crate__api__store_data_idempotentPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_data_idempotent_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, int timestamp_ms, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__store_data_idempotent_on(port_, handle, db_name, key, value, timestamp_ms, secret_key);
+  }
+
+  late final _wire__crate__api__store_data_idempotent_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_idempotent_on');
+  late final _wire__crate__api__store_data_idempotent_on = _wire__crate__api__store_data_idempotent_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_data_local(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value) {
+    return _wire__crate__api__store_data_local(port_, db_name, key, value);
+  }
+
+  late final _wire__crate__api__store_data_localPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_local');
+  late final _wire__crate__api__store_data_local = _wire__crate__api__store_data_localPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  void wire__crate__api__store_data_local_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value) {
+    return _wire__crate__api__store_data_local_on(port_, handle, db_name, key, value);
+  }
+
+  late final _wire__crate__api__store_data_local_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_local_on');
+  late final _wire__crate__api__store_data_local_on = _wire__crate__api__store_data_local_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>)>();
+
+  void wire__crate__api__store_data_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key, ffi.Pointer<wire_cst_list_prim_u_8_strict> signature) {
+    return _wire__crate__api__store_data_on(port_, handle, db_name, key, value, public_key, signature);
+  }
+
+  late final _wire__crate__api__store_data_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_on');
+  late final _wire__crate__api__store_data_on = _wire__crate__api__store_data_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_data_signed(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> store_type) {
+    return _wire__crate__api__store_data_signed(port_, db_name, key, value, store_type);
+  }
+
+  late final _wire__crate__api__store_data_signedPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_signed');
+  late final _wire__crate__api__store_data_signed = _wire__crate__api__store_data_signedPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_data_signed_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> store_type) {
+    return _wire__crate__api__store_data_signed_on(port_, handle, db_name, key, value, store_type);
+  }
+
+  late final _wire__crate__api__store_data_signed_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_signed_on');
+  late final _wire__crate__api__store_data_signed_on = _wire__crate__api__store_data_signed_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_data_with_ttl(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, int ttl_secs, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__store_data_with_ttl(port_, db_name, key, value, ttl_secs, secret_key);
+  }
+
+  late final _wire__crate__api__store_data_with_ttlPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_with_ttl');
+  late final _wire__crate__api__store_data_with_ttl = _wire__crate__api__store_data_with_ttlPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_data_with_ttl_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, int ttl_secs, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__store_data_with_ttl_on(port_, handle, db_name, key, value, ttl_secs, secret_key);
+  }
+
+  late final _wire__crate__api__store_data_with_ttl_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Uint64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_data_with_ttl_on');
+  late final _wire__crate__api__store_data_with_ttl_on = _wire__crate__api__store_data_with_ttl_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_encrypted(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__store_encrypted(port_, db_name, key, value, secret_key);
+  }
+
+  late final _wire__crate__api__store_encryptedPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_encrypted');
+  late final _wire__crate__api__store_encrypted = _wire__crate__api__store_encryptedPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__store_encrypted_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_loose> value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__store_encrypted_on(port_, handle, db_name, key, value, secret_key);
+  }
+
+  late final _wire__crate__api__store_encrypted_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__store_encrypted_on');
+  late final _wire__crate__api__store_encrypted_on = _wire__crate__api__store_encrypted_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__subscribe_topic(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> name, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__subscribe_topic(port_, name, sink);
+  }
+
+  late final _wire__crate__api__subscribe_topicPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__subscribe_topic');
+  late final _wire__crate__api__subscribe_topic = _wire__crate__api__subscribe_topicPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__subscribe_topic_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> name, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__subscribe_topic_on(port_, handle, name, sink);
+  }
+
+  late final _wire__crate__api__subscribe_topic_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__subscribe_topic_on');
+  late final _wire__crate__api__subscribe_topic_on = _wire__crate__api__subscribe_topic_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__text_delta_stream(ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__text_delta_stream(sink);
+  }
+
+  late final _wire__crate__api__text_delta_streamPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__text_delta_stream');
+  late final _wire__crate__api__text_delta_stream = _wire__crate__api__text_delta_streamPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__text_delta_stream_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__text_delta_stream_on(handle, sink);
+  }
+
+  late final _wire__crate__api__text_delta_stream_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__text_delta_stream_on');
+  late final _wire__crate__api__text_delta_stream_on = _wire__crate__api__text_delta_stream_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__ts_add(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, int timestamp_ms, double value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__ts_add(port_, db_name, key, timestamp_ms, value, secret_key);
+  }
+
+  late final _wire__crate__api__ts_addPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Int64, ffi.Double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__ts_add');
+  late final _wire__crate__api__ts_add = _wire__crate__api__ts_addPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__ts_add_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, int timestamp_ms, double value, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__ts_add_on(port_, handle, db_name, key, timestamp_ms, value, secret_key);
+  }
+
+  late final _wire__crate__api__ts_add_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Int64, ffi.Double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__ts_add_on');
+  late final _wire__crate__api__ts_add_on = _wire__crate__api__ts_add_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__ts_range(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, int from_ms, int to_ms) {
+    return _wire__crate__api__ts_range(db_name, key, from_ms, to_ms);
+  }
+
+  late final _wire__crate__api__ts_rangePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Int64, ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__ts_range');
+  late final _wire__crate__api__ts_range = _wire__crate__api__ts_rangePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__ts_range_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, int from_ms, int to_ms) {
+    return _wire__crate__api__ts_range_on(handle, db_name, key, from_ms, to_ms);
+  }
+
+  late final _wire__crate__api__ts_range_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Int64, ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__ts_range_on');
+  late final _wire__crate__api__ts_range_on = _wire__crate__api__ts_range_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__unfollow_database(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__unfollow_database(db_name);
+  }
+
+  late final _wire__crate__api__unfollow_databasePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__unfollow_database');
+  late final _wire__crate__api__unfollow_database = _wire__crate__api__unfollow_databasePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__unfollow_database_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name) {
+    return _wire__crate__api__unfollow_database_on(handle, db_name);
+  }
+
+  late final _wire__crate__api__unfollow_database_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__unfollow_database_on');
+  late final _wire__crate__api__unfollow_database_on = _wire__crate__api__unfollow_database_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__unpin_peer(ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id) {
+    return _wire__crate__api__unpin_peer(node_id);
+  }
+
+  late final _wire__crate__api__unpin_peerPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__unpin_peer');
+  late final _wire__crate__api__unpin_peer = _wire__crate__api__unpin_peerPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__unpin_peer_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id) {
+    return _wire__crate__api__unpin_peer_on(handle, node_id);
+  }
+
+  late final _wire__crate__api__unpin_peer_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__unpin_peer_on');
+  late final _wire__crate__api__unpin_peer_on = _wire__crate__api__unpin_peer_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__unsubscribe_topic(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> name) {
+    return _wire__crate__api__unsubscribe_topic(port_, name);
+  }
+
+  late final _wire__crate__api__unsubscribe_topicPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__unsubscribe_topic');
+  late final _wire__crate__api__unsubscribe_topic = _wire__crate__api__unsubscribe_topicPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__unsubscribe_topic_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> name) {
+    return _wire__crate__api__unsubscribe_topic_on(port_, handle, name);
+  }
+
+  late final _wire__crate__api__unsubscribe_topic_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__unsubscribe_topic_on');
+  late final _wire__crate__api__unsubscribe_topic_on = _wire__crate__api__unsubscribe_topic_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__update_config(ffi.Pointer<wire_cst_node_config_dto> config) {
+    return _wire__crate__api__update_config(config);
+  }
+
+  late final _wire__crate__api__update_configPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_node_config_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__update_config');
+  late final _wire__crate__api__update_config = _wire__crate__api__update_configPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_node_config_dto>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__update_config_on(int handle, ffi.Pointer<wire_cst_node_config_dto> config) {
+    return _wire__crate__api__update_config_on(handle, config);
+  }
+
+  late final _wire__crate__api__update_config_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_node_config_dto>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__update_config_on');
+  late final _wire__crate__api__update_config_on = _wire__crate__api__update_config_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_node_config_dto>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__validate_timestamp(int timestamp) {
+    return _wire__crate__api__validate_timestamp(timestamp);
+  }
+
+  late final _wire__crate__api__validate_timestampPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__validate_timestamp');
+  late final _wire__crate__api__validate_timestamp = _wire__crate__api__validate_timestampPtr.asFunction<ffi.Pointer<DartCObject> Function(int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__verify_bytes(ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex, ffi.Pointer<wire_cst_list_prim_u_8_loose> message, ffi.Pointer<wire_cst_list_prim_u_8_strict> signature_hex) {
+    return _wire__crate__api__verify_bytes(public_key_hex, message, signature_hex);
+  }
+
+  late final _wire__crate__api__verify_bytesPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__verify_bytes');
+  late final _wire__crate__api__verify_bytes = _wire__crate__api__verify_bytesPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_loose>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__verify_db_name(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex) {
+    return _wire__crate__api__verify_db_name(db_name, public_key_hex);
+  }
+
+  late final _wire__crate__api__verify_db_namePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__verify_db_name');
+  late final _wire__crate__api__verify_db_name = _wire__crate__api__verify_db_namePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__verify_message_signature(ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key_hex, ffi.Pointer<wire_cst_list_prim_u_8_strict> message, ffi.Pointer<wire_cst_list_prim_u_8_strict> signature_hex) {
+    return _wire__crate__api__verify_message_signature(public_key_hex, message, signature_hex);
+  }
+
+  late final _wire__crate__api__verify_message_signaturePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__verify_message_signature');
+  late final _wire__crate__api__verify_message_signature = _wire__crate__api__verify_message_signaturePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__verify_storage(int port_, bool repair) {
+    return _wire__crate__api__verify_storage(port_, repair);
+  }
+
+  late final _wire__crate__api__verify_storagePtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__verify_storage');
+  late final _wire__crate__api__verify_storage = _wire__crate__api__verify_storagePtr.asFunction<void Function(int, bool)>();
+
+  void wire__crate__api__verify_storage_on(int port_, int handle, bool repair) {
+    return _wire__crate__api__verify_storage_on(port_, handle, repair);
+  }
+
+  late final _wire__crate__api__verify_storage_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Bool)>>('frbgen_cyberfly_mobile_node_wire__crate__api__verify_storage_on');
+  late final _wire__crate__api__verify_storage_on = _wire__crate__api__verify_storage_onPtr.asFunction<void Function(int, int, bool)>();
+
+  void wire__crate__api__wait_for_sync(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, int timeout_ms) {
+    return _wire__crate__api__wait_for_sync(port_, db_name, timeout_ms);
+  }
+
+  late final _wire__crate__api__wait_for_syncPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__wait_for_sync');
+  late final _wire__crate__api__wait_for_sync = _wire__crate__api__wait_for_syncPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  void wire__crate__api__wait_for_sync_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, int timeout_ms) {
+    return _wire__crate__api__wait_for_sync_on(port_, handle, db_name, timeout_ms);
+  }
+
+  late final _wire__crate__api__wait_for_sync_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint64)>>('frbgen_cyberfly_mobile_node_wire__crate__api__wait_for_sync_on');
+  late final _wire__crate__api__wait_for_sync_on = _wire__crate__api__wait_for_sync_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  void wire__crate__api__wake_and_sync(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key, ffi.Pointer<wire_cst_list_String> bootstrap_peers, int max_duration_secs) {
+    return _wire__crate__api__wake_and_sync(port_, data_dir, wallet_secret_key, bootstrap_peers, max_duration_secs);
+  }
+
+  late final _wire__crate__api__wake_and_syncPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__wake_and_sync');
+  late final _wire__crate__api__wake_and_sync = _wire__crate__api__wake_and_syncPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, int)>();
+
+  void wire__crate__api__wake_and_sync_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> data_dir, ffi.Pointer<wire_cst_list_prim_u_8_strict> wallet_secret_key, ffi.Pointer<wire_cst_list_String> bootstrap_peers, int max_duration_secs) {
+    return _wire__crate__api__wake_and_sync_on(port_, handle, data_dir, wallet_secret_key, bootstrap_peers, max_duration_secs);
+  }
+
+  late final _wire__crate__api__wake_and_sync_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, ffi.Uint32)>>('frbgen_cyberfly_mobile_node_wire__crate__api__wake_and_sync_on');
+  late final _wire__crate__api__wake_and_sync_on = _wire__crate__api__wake_and_sync_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_String>, int)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__watch_db(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__watch_db(db_name, sink);
+  }
+
+  late final _wire__crate__api__watch_dbPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__watch_db');
+  late final _wire__crate__api__watch_db = _wire__crate__api__watch_dbPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__watch_db_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__watch_db_on(handle, db_name, sink);
+  }
+
+  late final _wire__crate__api__watch_db_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__watch_db_on');
+  late final _wire__crate__api__watch_db_on = _wire__crate__api__watch_db_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__watch_key(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__watch_key(db_name, key, sink);
+  }
+
+  late final _wire__crate__api__watch_keyPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__watch_key');
+  late final _wire__crate__api__watch_key = _wire__crate__api__watch_keyPtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__watch_key_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> sink) {
+    return _wire__crate__api__watch_key_on(handle, db_name, key, sink);
+  }
+
+  late final _wire__crate__api__watch_key_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__watch_key_on');
+  late final _wire__crate__api__watch_key_on = _wire__crate__api__watch_key_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__zset_add(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> member, double score, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__zset_add(port_, db_name, key, member, score, secret_key);
+  }
+
+  late final _wire__crate__api__zset_addPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__zset_add');
+  late final _wire__crate__api__zset_add = _wire__crate__api__zset_addPtr.asFunction<void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__api__zset_add_on(int port_, int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<wire_cst_list_prim_u_8_strict> member, double score, ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key) {
+    return _wire__crate__api__zset_add_on(port_, handle, db_name, key, member, score, secret_key);
+  }
+
+  late final _wire__crate__api__zset_add_onPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__zset_add_on');
+  late final _wire__crate__api__zset_add_on = _wire__crate__api__zset_add_onPtr.asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, double, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__zset_range(ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<ffi.Double> min, ffi.Pointer<ffi.Double> max) {
+    return _wire__crate__api__zset_range(db_name, key, min, max);
+  }
+
+  late final _wire__crate__api__zset_rangePtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Double>, ffi.Pointer<ffi.Double>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__zset_range');
+  late final _wire__crate__api__zset_range = _wire__crate__api__zset_rangePtr.asFunction<ffi.Pointer<DartCObject> Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Double>, ffi.Pointer<ffi.Double>)>();
+
+  ffi.Pointer<DartCObject> wire__crate__api__zset_range_on(int handle, ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name, ffi.Pointer<wire_cst_list_prim_u_8_strict> key, ffi.Pointer<ffi.Double> min, ffi.Pointer<ffi.Double> max) {
+    return _wire__crate__api__zset_range_on(handle, db_name, key, min, max);
+  }
+
+  late final _wire__crate__api__zset_range_onPtr = _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Double>, ffi.Pointer<ffi.Double>)>>('frbgen_cyberfly_mobile_node_wire__crate__api__zset_range_on');
+  late final _wire__crate__api__zset_range_on = _wire__crate__api__zset_range_onPtr.asFunction<ffi.Pointer<DartCObject> Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Pointer<ffi.Double>, ffi.Pointer<ffi.Double>)>();
+
+  ffi.Pointer<wire_cst_bandwidth_caps_dto> cst_new_box_autoadd_bandwidth_caps_dto() {
+    return _cst_new_box_autoadd_bandwidth_caps_dto();
+  }
+
+  late final _cst_new_box_autoadd_bandwidth_caps_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_bandwidth_caps_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_bandwidth_caps_dto');
+  late final _cst_new_box_autoadd_bandwidth_caps_dto = _cst_new_box_autoadd_bandwidth_caps_dtoPtr.asFunction<ffi.Pointer<wire_cst_bandwidth_caps_dto> Function()>();
+
+  ffi.Pointer<wire_cst_bootstrap_manifest_dto> cst_new_box_autoadd_bootstrap_manifest_dto() {
+    return _cst_new_box_autoadd_bootstrap_manifest_dto();
+  }
+
+  late final _cst_new_box_autoadd_bootstrap_manifest_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_bootstrap_manifest_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_bootstrap_manifest_dto');
+  late final _cst_new_box_autoadd_bootstrap_manifest_dto = _cst_new_box_autoadd_bootstrap_manifest_dtoPtr.asFunction<ffi.Pointer<wire_cst_bootstrap_manifest_dto> Function()>();
+
+  ffi.Pointer<wire_cst_device_conditions_dto> cst_new_box_autoadd_device_conditions_dto() {
+    return _cst_new_box_autoadd_device_conditions_dto();
+  }
+
+  late final _cst_new_box_autoadd_device_conditions_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_device_conditions_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_device_conditions_dto');
+  late final _cst_new_box_autoadd_device_conditions_dto = _cst_new_box_autoadd_device_conditions_dtoPtr.asFunction<ffi.Pointer<wire_cst_device_conditions_dto> Function()>();
+
+  ffi.Pointer<ffi.Double> cst_new_box_autoadd_f_64(double value) {
+    return _cst_new_box_autoadd_f_64(value);
+  }
+
+  late final _cst_new_box_autoadd_f_64Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Double> Function(ffi.Double)>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_f_64');
+  late final _cst_new_box_autoadd_f_64 = _cst_new_box_autoadd_f_64Ptr.asFunction<ffi.Pointer<ffi.Double> Function(double)>();
+
+  ffi.Pointer<wire_cst_file_offer_dto> cst_new_box_autoadd_file_offer_dto() {
+    return _cst_new_box_autoadd_file_offer_dto();
+  }
+
+  late final _cst_new_box_autoadd_file_offer_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_file_offer_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_file_offer_dto');
+  late final _cst_new_box_autoadd_file_offer_dto = _cst_new_box_autoadd_file_offer_dtoPtr.asFunction<ffi.Pointer<wire_cst_file_offer_dto> Function()>();
+
+  ffi.Pointer<ffi.Int64> cst_new_box_autoadd_i_64(int value) {
+    return _cst_new_box_autoadd_i_64(value);
+  }
+
+  late final _cst_new_box_autoadd_i_64Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int64> Function(ffi.Int64)>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_i_64');
+  late final _cst_new_box_autoadd_i_64 = _cst_new_box_autoadd_i_64Ptr.asFunction<ffi.Pointer<ffi.Int64> Function(int)>();
+
+  ffi.Pointer<wire_cst_log_filter_dto> cst_new_box_autoadd_log_filter_dto() {
+    return _cst_new_box_autoadd_log_filter_dto();
+  }
+
+  late final _cst_new_box_autoadd_log_filter_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_log_filter_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_log_filter_dto');
+  late final _cst_new_box_autoadd_log_filter_dto = _cst_new_box_autoadd_log_filter_dtoPtr.asFunction<ffi.Pointer<wire_cst_log_filter_dto> Function()>();
+
+  ffi.Pointer<wire_cst_mqtt_bridge_config_dto> cst_new_box_autoadd_mqtt_bridge_config_dto() {
+    return _cst_new_box_autoadd_mqtt_bridge_config_dto();
+  }
+
+  late final _cst_new_box_autoadd_mqtt_bridge_config_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_mqtt_bridge_config_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_mqtt_bridge_config_dto');
+  late final _cst_new_box_autoadd_mqtt_bridge_config_dto = _cst_new_box_autoadd_mqtt_bridge_config_dtoPtr.asFunction<ffi.Pointer<wire_cst_mqtt_bridge_config_dto> Function()>();
+
+  ffi.Pointer<wire_cst_node_config_dto> cst_new_box_autoadd_node_config_dto() {
+    return _cst_new_box_autoadd_node_config_dto();
+  }
+
+  late final _cst_new_box_autoadd_node_config_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_node_config_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_node_config_dto');
+  late final _cst_new_box_autoadd_node_config_dto = _cst_new_box_autoadd_node_config_dtoPtr.asFunction<ffi.Pointer<wire_cst_node_config_dto> Function()>();
+
+  ffi.Pointer<wire_cst_node_info> cst_new_box_autoadd_node_info() {
+    return _cst_new_box_autoadd_node_info();
+  }
+
+  late final _cst_new_box_autoadd_node_infoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_node_info> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_node_info');
+  late final _cst_new_box_autoadd_node_info = _cst_new_box_autoadd_node_infoPtr.asFunction<ffi.Pointer<wire_cst_node_info> Function()>();
+
+  ffi.Pointer<wire_cst_oplog_gc_dto> cst_new_box_autoadd_oplog_gc_dto() {
+    return _cst_new_box_autoadd_oplog_gc_dto();
+  }
+
+  late final _cst_new_box_autoadd_oplog_gc_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_oplog_gc_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_oplog_gc_dto');
+  late final _cst_new_box_autoadd_oplog_gc_dto = _cst_new_box_autoadd_oplog_gc_dtoPtr.asFunction<ffi.Pointer<wire_cst_oplog_gc_dto> Function()>();
+
+  ffi.Pointer<wire_cst_peer_path_dto> cst_new_box_autoadd_peer_path_dto() {
+    return _cst_new_box_autoadd_peer_path_dto();
+  }
+
+  late final _cst_new_box_autoadd_peer_path_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_peer_path_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_peer_path_dto');
+  late final _cst_new_box_autoadd_peer_path_dto = _cst_new_box_autoadd_peer_path_dtoPtr.asFunction<ffi.Pointer<wire_cst_peer_path_dto> Function()>();
+
+  ffi.Pointer<wire_cst_presence_dto> cst_new_box_autoadd_presence_dto() {
+    return _cst_new_box_autoadd_presence_dto();
+  }
+
+  late final _cst_new_box_autoadd_presence_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_presence_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_presence_dto');
+  late final _cst_new_box_autoadd_presence_dto = _cst_new_box_autoadd_presence_dtoPtr.asFunction<ffi.Pointer<wire_cst_presence_dto> Function()>();
+
+  ffi.Pointer<wire_cst_query_spec_dto> cst_new_box_autoadd_query_spec_dto() {
+    return _cst_new_box_autoadd_query_spec_dto();
+  }
+
+  late final _cst_new_box_autoadd_query_spec_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_query_spec_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_query_spec_dto');
+  late final _cst_new_box_autoadd_query_spec_dto = _cst_new_box_autoadd_query_spec_dtoPtr.asFunction<ffi.Pointer<wire_cst_query_spec_dto> Function()>();
+
+  ffi.Pointer<wire_cst_successor_record_dto> cst_new_box_autoadd_successor_record_dto() {
+    return _cst_new_box_autoadd_successor_record_dto();
+  }
+
+  late final _cst_new_box_autoadd_successor_record_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_successor_record_dto> Function()>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_successor_record_dto');
+  late final _cst_new_box_autoadd_successor_record_dto = _cst_new_box_autoadd_successor_record_dtoPtr.asFunction<ffi.Pointer<wire_cst_successor_record_dto> Function()>();
+
+  ffi.Pointer<ffi.Uint16> cst_new_box_autoadd_u_16(int value) {
+    return _cst_new_box_autoadd_u_16(value);
+  }
+
+  late final _cst_new_box_autoadd_u_16Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint16> Function(ffi.Uint16)>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_16');
+  late final _cst_new_box_autoadd_u_16 = _cst_new_box_autoadd_u_16Ptr.asFunction<ffi.Pointer<ffi.Uint16> Function(int)>();
+
+  ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(int value) {
+    return _cst_new_box_autoadd_u_32(value);
+  }
+
+  late final _cst_new_box_autoadd_u_32Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_32');
+  late final _cst_new_box_autoadd_u_32 = _cst_new_box_autoadd_u_32Ptr.asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
+  ffi.Pointer<ffi.Uint64> cst_new_box_autoadd_u_64(int value) {
+    return _cst_new_box_autoadd_u_64(value);
+  }
+
+  late final _cst_new_box_autoadd_u_64Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_64');
+  late final _cst_new_box_autoadd_u_64 = _cst_new_box_autoadd_u_64Ptr.asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+
+  ffi.Pointer<ffi.Uint8> cst_new_box_autoadd_u_8(int value) {
+    return _cst_new_box_autoadd_u_8(value);
+  }
+
+  late final _cst_new_box_autoadd_u_8Ptr = _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint8> Function(ffi.Uint8)>>('frbgen_cyberfly_mobile_node_cst_new_box_autoadd_u_8');
+  late final _cst_new_box_autoadd_u_8 = _cst_new_box_autoadd_u_8Ptr.asFunction<ffi.Pointer<ffi.Uint8> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_String> cst_new_list_String(int len) {
+    return _cst_new_list_String(len);
+  }
+
+  late final _cst_new_list_StringPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_String');
+  late final _cst_new_list_String = _cst_new_list_StringPtr.asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_bandwidth_usage_dto> cst_new_list_bandwidth_usage_dto(int len) {
+    return _cst_new_list_bandwidth_usage_dto(len);
+  }
+
+  late final _cst_new_list_bandwidth_usage_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_bandwidth_usage_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_bandwidth_usage_dto');
+  late final _cst_new_list_bandwidth_usage_dto = _cst_new_list_bandwidth_usage_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_bandwidth_usage_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_batch_entry_dto> cst_new_list_batch_entry_dto(int len) {
+    return _cst_new_list_batch_entry_dto(len);
+  }
+
+  late final _cst_new_list_batch_entry_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_batch_entry_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_batch_entry_dto');
+  late final _cst_new_list_batch_entry_dto = _cst_new_list_batch_entry_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_batch_entry_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_conflict_dto> cst_new_list_conflict_dto(int len) {
+    return _cst_new_list_conflict_dto(len);
+  }
+
+  late final _cst_new_list_conflict_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_conflict_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_conflict_dto');
+  late final _cst_new_list_conflict_dto = _cst_new_list_conflict_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_conflict_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_db_entry_dto> cst_new_list_db_entry_dto(int len) {
+    return _cst_new_list_db_entry_dto(len);
+  }
+
+  late final _cst_new_list_db_entry_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_db_entry_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_db_entry_dto');
+  late final _cst_new_list_db_entry_dto = _cst_new_list_db_entry_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_db_entry_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_direct_message_dto> cst_new_list_direct_message_dto(int len) {
+    return _cst_new_list_direct_message_dto(len);
+  }
+
+  late final _cst_new_list_direct_message_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_direct_message_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_direct_message_dto');
+  late final _cst_new_list_direct_message_dto = _cst_new_list_direct_message_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_direct_message_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_file_offer_dto> cst_new_list_file_offer_dto(int len) {
+    return _cst_new_list_file_offer_dto(len);
+  }
+
+  late final _cst_new_list_file_offer_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_file_offer_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_file_offer_dto');
+  late final _cst_new_list_file_offer_dto = _cst_new_list_file_offer_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_file_offer_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_geo_match_dto> cst_new_list_geo_match_dto(int len) {
+    return _cst_new_list_geo_match_dto(len);
+  }
+
+  late final _cst_new_list_geo_match_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_geo_match_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_geo_match_dto');
+  late final _cst_new_list_geo_match_dto = _cst_new_list_geo_match_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_geo_match_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_hash_entry_dto> cst_new_list_hash_entry_dto(int len) {
+    return _cst_new_list_hash_entry_dto(len);
+  }
+
+  late final _cst_new_list_hash_entry_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_hash_entry_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_hash_entry_dto');
+  late final _cst_new_list_hash_entry_dto = _cst_new_list_hash_entry_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_hash_entry_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_integrity_issue_dto> cst_new_list_integrity_issue_dto(int len) {
+    return _cst_new_list_integrity_issue_dto(len);
+  }
+
+  late final _cst_new_list_integrity_issue_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_integrity_issue_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_integrity_issue_dto');
+  late final _cst_new_list_integrity_issue_dto = _cst_new_list_integrity_issue_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_integrity_issue_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_latency_bucket_dto> cst_new_list_latency_bucket_dto(int len) {
+    return _cst_new_list_latency_bucket_dto(len);
+  }
+
+  late final _cst_new_list_latency_bucket_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_latency_bucket_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_latency_bucket_dto');
+  late final _cst_new_list_latency_bucket_dto = _cst_new_list_latency_bucket_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_latency_bucket_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_linked_device_dto> cst_new_list_linked_device_dto(int len) {
+    return _cst_new_list_linked_device_dto(len);
+  }
+
+  late final _cst_new_list_linked_device_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_linked_device_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_linked_device_dto');
+  late final _cst_new_list_linked_device_dto = _cst_new_list_linked_device_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_linked_device_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_log_entry> cst_new_list_log_entry(int len) {
+    return _cst_new_list_log_entry(len);
+  }
+
+  late final _cst_new_list_log_entryPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_log_entry> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_log_entry');
+  late final _cst_new_list_log_entry = _cst_new_list_log_entryPtr.asFunction<ffi.Pointer<wire_cst_list_log_entry> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_mqtt_topic_mapping_dto> cst_new_list_mqtt_topic_mapping_dto(int len) {
+    return _cst_new_list_mqtt_topic_mapping_dto(len);
+  }
+
+  late final _cst_new_list_mqtt_topic_mapping_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_mqtt_topic_mapping_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_mqtt_topic_mapping_dto');
+  late final _cst_new_list_mqtt_topic_mapping_dto = _cst_new_list_mqtt_topic_mapping_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_mqtt_topic_mapping_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_node_event_record_dto> cst_new_list_node_event_record_dto(int len) {
+    return _cst_new_list_node_event_record_dto(len);
+  }
+
+  late final _cst_new_list_node_event_record_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_node_event_record_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_node_event_record_dto');
+  late final _cst_new_list_node_event_record_dto = _cst_new_list_node_event_record_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_node_event_record_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_opt_list_prim_u_8_strict> cst_new_list_opt_list_prim_u_8_strict(int len) {
+    return _cst_new_list_opt_list_prim_u_8_strict(len);
+  }
+
+  late final _cst_new_list_opt_list_prim_u_8_strictPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_opt_list_prim_u_8_strict> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_opt_list_prim_u_8_strict');
+  late final _cst_new_list_opt_list_prim_u_8_strict = _cst_new_list_opt_list_prim_u_8_strictPtr.asFunction<ffi.Pointer<wire_cst_list_opt_list_prim_u_8_strict> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_peer_circuit_dto> cst_new_list_peer_circuit_dto(int len) {
+    return _cst_new_list_peer_circuit_dto(len);
+  }
+
+  late final _cst_new_list_peer_circuit_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_peer_circuit_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_peer_circuit_dto');
+  late final _cst_new_list_peer_circuit_dto = _cst_new_list_peer_circuit_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_peer_circuit_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_peer_diagnostics_dto> cst_new_list_peer_diagnostics_dto(int len) {
+    return _cst_new_list_peer_diagnostics_dto(len);
+  }
+
+  late final _cst_new_list_peer_diagnostics_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_peer_diagnostics_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_peer_diagnostics_dto');
+  late final _cst_new_list_peer_diagnostics_dto = _cst_new_list_peer_diagnostics_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_peer_diagnostics_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_peer_info_dto> cst_new_list_peer_info_dto(int len) {
+    return _cst_new_list_peer_info_dto(len);
+  }
+
+  late final _cst_new_list_peer_info_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_peer_info_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_peer_info_dto');
+  late final _cst_new_list_peer_info_dto = _cst_new_list_peer_info_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_peer_info_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_peer_latency_dto> cst_new_list_peer_latency_dto(int len) {
+    return _cst_new_list_peer_latency_dto(len);
+  }
+
+  late final _cst_new_list_peer_latency_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_peer_latency_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_peer_latency_dto');
+  late final _cst_new_list_peer_latency_dto = _cst_new_list_peer_latency_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_peer_latency_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_peer_path_dto> cst_new_list_peer_path_dto(int len) {
+    return _cst_new_list_peer_path_dto(len);
+  }
+
+  late final _cst_new_list_peer_path_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_peer_path_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_peer_path_dto');
+  late final _cst_new_list_peer_path_dto = _cst_new_list_peer_path_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_peer_path_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_peer_sync_status_dto> cst_new_list_peer_sync_status_dto(int len) {
+    return _cst_new_list_peer_sync_status_dto(len);
+  }
+
+  late final _cst_new_list_peer_sync_status_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_peer_sync_status_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_peer_sync_status_dto');
+  late final _cst_new_list_peer_sync_status_dto = _cst_new_list_peer_sync_status_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_peer_sync_status_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_pinned_peer_dto> cst_new_list_pinned_peer_dto(int len) {
+    return _cst_new_list_pinned_peer_dto(len);
+  }
+
+  late final _cst_new_list_pinned_peer_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_pinned_peer_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_pinned_peer_dto');
+  late final _cst_new_list_pinned_peer_dto = _cst_new_list_pinned_peer_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_pinned_peer_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_32_strict> cst_new_list_prim_u_32_strict(int len) {
+    return _cst_new_list_prim_u_32_strict(len);
+  }
+
+  late final _cst_new_list_prim_u_32_strictPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_prim_u_32_strict> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_prim_u_32_strict');
+  late final _cst_new_list_prim_u_32_strict = _cst_new_list_prim_u_32_strictPtr.asFunction<ffi.Pointer<wire_cst_list_prim_u_32_strict> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_8_loose> cst_new_list_prim_u_8_loose(int len) {
+    return _cst_new_list_prim_u_8_loose(len);
+  }
+
+  late final _cst_new_list_prim_u_8_loosePtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_prim_u_8_loose> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_prim_u_8_loose');
+  late final _cst_new_list_prim_u_8_loose = _cst_new_list_prim_u_8_loosePtr.asFunction<ffi.Pointer<wire_cst_list_prim_u_8_loose> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(int len) {
+    return _cst_new_list_prim_u_8_strict(len);
+  }
+
+  late final _cst_new_list_prim_u_8_strictPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_prim_u_8_strict');
+  late final _cst_new_list_prim_u_8_strict = _cst_new_list_prim_u_8_strictPtr.asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_query_filter_dto> cst_new_list_query_filter_dto(int len) {
+    return _cst_new_list_query_filter_dto(len);
+  }
+
+  late final _cst_new_list_query_filter_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_query_filter_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_query_filter_dto');
+  late final _cst_new_list_query_filter_dto = _cst_new_list_query_filter_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_query_filter_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_rekeyed_database_dto> cst_new_list_rekeyed_database_dto(int len) {
+    return _cst_new_list_rekeyed_database_dto(len);
+  }
+
+  late final _cst_new_list_rekeyed_database_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_rekeyed_database_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_rekeyed_database_dto');
+  late final _cst_new_list_rekeyed_database_dto = _cst_new_list_rekeyed_database_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_rekeyed_database_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_replica_check_dto> cst_new_list_replica_check_dto(int len) {
+    return _cst_new_list_replica_check_dto(len);
+  }
+
+  late final _cst_new_list_replica_check_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_replica_check_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_replica_check_dto');
+  late final _cst_new_list_replica_check_dto = _cst_new_list_replica_check_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_replica_check_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_scored_member_dto> cst_new_list_scored_member_dto(int len) {
+    return _cst_new_list_scored_member_dto(len);
+  }
+
+  late final _cst_new_list_scored_member_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_scored_member_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_scored_member_dto');
+  late final _cst_new_list_scored_member_dto = _cst_new_list_scored_member_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_scored_member_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_time_point_dto> cst_new_list_time_point_dto(int len) {
+    return _cst_new_list_time_point_dto(len);
+  }
+
+  late final _cst_new_list_time_point_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_time_point_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_time_point_dto');
+  late final _cst_new_list_time_point_dto = _cst_new_list_time_point_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_time_point_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_topic_health_dto> cst_new_list_topic_health_dto(int len) {
+    return _cst_new_list_topic_health_dto(len);
+  }
+
+  late final _cst_new_list_topic_health_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_topic_health_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_topic_health_dto');
+  late final _cst_new_list_topic_health_dto = _cst_new_list_topic_health_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_topic_health_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_topic_metrics_dto> cst_new_list_topic_metrics_dto(int len) {
+    return _cst_new_list_topic_metrics_dto(len);
+  }
+
+  late final _cst_new_list_topic_metrics_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_topic_metrics_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_topic_metrics_dto');
+  late final _cst_new_list_topic_metrics_dto = _cst_new_list_topic_metrics_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_topic_metrics_dto> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_topic_stats_dto> cst_new_list_topic_stats_dto(int len) {
+    return _cst_new_list_topic_stats_dto(len);
+  }
+
+  late final _cst_new_list_topic_stats_dtoPtr = _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_topic_stats_dto> Function(ffi.Int32)>>('frbgen_cyberfly_mobile_node_cst_new_list_topic_stats_dto');
+  late final _cst_new_list_topic_stats_dto = _cst_new_list_topic_stats_dtoPtr.asFunction<ffi.Pointer<wire_cst_list_topic_stats_dto> Function(int)>();
+
+  int dummy_method_to_enforce_bundling() {
+    return _dummy_method_to_enforce_bundling();
+  }
+
+  late final _dummy_method_to_enforce_bundlingPtr = _lookup<ffi.NativeFunction<ffi.Int64 Function()>>('dummy_method_to_enforce_bundling');
+  late final _dummy_method_to_enforce_bundling = _dummy_method_to_enforce_bundlingPtr.asFunction<int Function()>();
+}
+
+
+
+
+
+
+
+typedef DartPostCObjectFnTypeFunction = ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message);
+typedef DartDartPostCObjectFnTypeFunction = bool Function(DartDartPort port_id, ffi.Pointer<ffi.Void> message);
+typedef DartPostCObjectFnType = ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnTypeFunction>>;
+
+final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_prim_u_8_loose extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_log_filter_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> min_level;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> target;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> contains;
+
+  external ffi.Pointer<ffi.Int64> since;
+
+  external ffi.Pointer<ffi.Int64> until;
+
+  external ffi.Pointer<ffi.Uint32> limit;
+}
+
+final class wire_cst_list_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_query_filter_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> field;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> op;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value_json;
+}
+
+final class wire_cst_list_query_filter_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_query_filter_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_query_spec_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key_prefix;
+
+  external ffi.Pointer<wire_cst_list_query_filter_dto> filters;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sort_by;
+
+  @ffi.Bool()
+  external bool descending;
+
+  @ffi.Uint32()
+  external int limit;
+
+  @ffi.Uint32()
+  external int offset;
+
+  external ffi.Pointer<wire_cst_list_String> fields;
+}
+
+final class wire_cst_bandwidth_caps_dto extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> daily_bytes;
+
+  external ffi.Pointer<ffi.Uint64> monthly_bytes;
+
+  @ffi.Bool()
+  external bool metered_only;
+}
+
+final class wire_cst_device_conditions_dto extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> battery_percent;
+
+  @ffi.Bool()
+  external bool charging;
+
+  @ffi.Int32()
+  external int connection_type;
+
+  @ffi.Bool()
+  external bool metered;
+}
+
+final class wire_cst_mqtt_topic_mapping_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> topic_prefix;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  @ffi.Int32()
+  external int direction;
+}
+
+final class wire_cst_list_mqtt_topic_mapping_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_mqtt_topic_mapping_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_mqtt_bridge_config_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> host;
+
+  external ffi.Pointer<ffi.Uint16> port;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> client_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> username;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> password;
+
+  external ffi.Pointer<wire_cst_list_mqtt_topic_mapping_dto> mappings;
+}
+
+final class wire_cst_RelayConfigDto_Custom extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_String> urls;
+}
+
+final class RelayConfigDtoKind extends ffi.Union {
+  external wire_cst_RelayConfigDto_Custom Custom;
+}
+
+final class wire_cst_relay_config_dto extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external RelayConfigDtoKind kind;
+}
+
+final class wire_cst_bootstrap_manifest_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> publisher_key;
+}
+
+final class wire_cst_oplog_gc_dto extends ffi.Struct {
+  @ffi.Uint32()
+  external int keep_versions;
+
+  @ffi.Uint64()
+  external int keep_for_secs;
+}
+
+final class wire_cst_node_config_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> region;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> network_id;
+
+  @ffi.Uint32()
+  external int max_known_peer_dials;
+
+  @ffi.Uint64()
+  external int announce_interval_secs;
+
+  @ffi.Uint64()
+  external int latency_sweep_interval_secs;
+
+  @ffi.Uint64()
+  external int pinned_peers_check_interval_secs;
+
+  @ffi.Uint64()
+  external int isolation_check_interval_secs;
+
+  @ffi.Uint32()
+  external int isolation_checks_before_reconnect;
+
+  @ffi.Uint32()
+  external int max_ops_per_response;
+
+  @ffi.Uint32()
+  external int max_connection_attempts_per_cycle;
+
+  @ffi.Uint64()
+  external int dial_interval_ms;
+
+  external wire_cst_relay_config_dto relay;
+
+  external ffi.Pointer<wire_cst_bootstrap_manifest_dto> bootstrap_manifest;
+
+  @ffi.Uint32()
+  external int max_value_bytes;
+
+  @ffi.Uint32()
+  external int max_key_length;
+
+  external ffi.Pointer<wire_cst_list_String> allowed_store_types;
+
+  external ffi.Pointer<wire_cst_oplog_gc_dto> oplog_gc;
+
+  @ffi.Uint32()
+  external int max_gossip_message_size;
+}
+
+final class wire_cst_batch_entry_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> signature;
+}
+
+final class wire_cst_list_batch_entry_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_batch_entry_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_file_offer_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> from;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  @ffi.Uint64()
+  external int size;
+
+  @ffi.Int64()
+  external int offered_at;
+}
+
+final class wire_cst_node_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+
+  @ffi.Bool()
+  external bool is_running;
+}
+
+final class wire_cst_peer_path_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> path;
+
+  @ffi.Bool()
+  external bool is_mixed;
+
+  external ffi.Pointer<wire_cst_list_String> direct_addrs;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> relay_url;
+
+  @ffi.Int64()
+  external int updated_at;
+}
+
+final class wire_cst_presence_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+
+  @ffi.Bool()
+  external bool online;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> status;
+
+  @ffi.Int64()
+  external int last_seen;
+}
+
+final class wire_cst_successor_record_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> old_node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> new_node_id;
+
+  @ffi.Int64()
+  external int timestamp;
+
+  @ffi.Int64()
+  external int grace_until;
+}
+
+final class wire_cst_bandwidth_usage_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  @ffi.Int32()
+  external int subsystem;
+
+  @ffi.Uint64()
+  external int bytes_sent;
+
+  @ffi.Uint64()
+  external int bytes_received;
+}
+
+final class wire_cst_list_bandwidth_usage_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_bandwidth_usage_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_conflict_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> conflict_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> field;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> kept_op_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> discarded_op_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> discarded_value;
+
+  @ffi.Int64()
+  external int discarded_timestamp;
+
+  @ffi.Int64()
+  external int resolved_at;
+}
+
+final class wire_cst_list_conflict_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_conflict_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_db_entry_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value_bytes;
+}
+
+final class wire_cst_list_db_entry_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_db_entry_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_direct_message_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+
+  @ffi.Bool()
+  external bool outgoing;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
+
+  @ffi.Int64()
+  external int sent_at;
+}
+
+final class wire_cst_list_direct_message_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_direct_message_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_file_offer_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_file_offer_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_geo_match_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> member;
+
+  @ffi.Double()
+  external double latitude;
+
+  @ffi.Double()
+  external double longitude;
+
+  @ffi.Double()
+  external double distance_m;
+}
+
+final class wire_cst_list_geo_match_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_geo_match_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_hash_entry_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> field;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+}
+
+final class wire_cst_list_hash_entry_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_hash_entry_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_integrity_issue_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> op_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> kind;
+}
+
+final class wire_cst_list_integrity_issue_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_integrity_issue_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_latency_bucket_dto extends ffi.Struct {
+  external ffi.Pointer<ffi.Double> le_ms;
+
+  @ffi.Uint64()
+  external int count;
+}
+
+final class wire_cst_list_latency_bucket_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_latency_bucket_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_linked_device_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> user_public_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> device_name;
+
+  @ffi.Int64()
+  external int linked_at;
+
+  @ffi.Bool()
+  external bool this_device;
+}
+
+final class wire_cst_list_linked_device_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_linked_device_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_log_entry extends ffi.Struct {
+  @ffi.Int64()
+  external int timestamp;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> level;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_list_log_entry extends ffi.Struct {
+  external ffi.Pointer<wire_cst_log_entry> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_NodeEventDto_Started extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+}
+
+final class wire_cst_NodeEventDto_PeerConnected extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+}
+
+final class wire_cst_NodeEventDto_PeerDisconnected extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+}
+
+final class wire_cst_NodeEventDto_PeerDiscovered extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+}
+
+final class wire_cst_NodeEventDto_GossipReceived extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> topic;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> from;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> content;
+
+  @ffi.Bool()
+  external bool verified;
+}
+
+final class wire_cst_NodeEventDto_SyncReceived extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+}
+
+final class wire_cst_NodeEventDto_SyncProgress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  @ffi.Uint64()
+  external int ops_received;
+
+  @ffi.Uint64()
+  external int ops_total_estimate;
+
+  @ffi.Uint64()
+  external int bytes;
+}
+
+final class wire_cst_NodeEventDto_ConflictResolved extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> kept_op_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> discarded_op_id;
+}
+
+final class wire_cst_NodeEventDto_LatencyMeasured extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+
+  @ffi.Uint64()
+  external int latency_ms;
+}
+
+final class wire_cst_NodeEventDto_PresenceChanged extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+
+  @ffi.Bool()
+  external bool online;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> status;
+}
+
+final class wire_cst_NodeEventDto_TopicRecovered extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> topic;
+}
+
+final class wire_cst_NodeEventDto_RelayConnected extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> relay_url;
+}
+
+final class wire_cst_NodeEventDto_BootstrapConnected extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+}
+
+final class wire_cst_NodeEventDto_IdentityRotated extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> old_node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> new_node_id;
+}
+
+final class wire_cst_NodeEventDto_DeviceLinked extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> user_public_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> device_node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> device_name;
+}
+
+final class wire_cst_NodeEventDto_ConnectivityChanged extends ffi.Struct {
+  @ffi.Int32()
+  external int state;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class wire_cst_NodeEventDto_Error extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class NodeEventDtoKind extends ffi.Union {
+  external wire_cst_NodeEventDto_Started Started;
+
+  external wire_cst_NodeEventDto_PeerConnected PeerConnected;
+
+  external wire_cst_NodeEventDto_PeerDisconnected PeerDisconnected;
+
+  external wire_cst_NodeEventDto_PeerDiscovered PeerDiscovered;
+
+  external wire_cst_NodeEventDto_GossipReceived GossipReceived;
+
+  external wire_cst_NodeEventDto_SyncReceived SyncReceived;
+
+  external wire_cst_NodeEventDto_SyncProgress SyncProgress;
+
+  external wire_cst_NodeEventDto_ConflictResolved ConflictResolved;
+
+  external wire_cst_NodeEventDto_LatencyMeasured LatencyMeasured;
+
+  external wire_cst_NodeEventDto_PresenceChanged PresenceChanged;
+
+  external wire_cst_NodeEventDto_TopicRecovered TopicRecovered;
+
+  external wire_cst_NodeEventDto_RelayConnected RelayConnected;
+
+  external wire_cst_NodeEventDto_BootstrapConnected BootstrapConnected;
+
+  external wire_cst_NodeEventDto_IdentityRotated IdentityRotated;
+
+  external wire_cst_NodeEventDto_DeviceLinked DeviceLinked;
+
+  external wire_cst_NodeEventDto_ConnectivityChanged ConnectivityChanged;
+
+  external wire_cst_NodeEventDto_Error Error;
+}
+
+final class wire_cst_node_event_dto extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external NodeEventDtoKind kind;
+}
+
+final class wire_cst_node_event_record_dto extends ffi.Struct {
+  @ffi.Uint64()
+  external int seq;
+
+  @ffi.Int64()
+  external int timestamp;
+
+  external wire_cst_node_event_dto event;
+}
+
+final class wire_cst_list_node_event_record_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_node_event_record_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_opt_list_prim_u_8_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_peer_circuit_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> state;
+
+  @ffi.Bool()
+  external bool unreachable;
+
+  @ffi.Uint32()
+  external int consecutive_failures;
+
+  @ffi.Int64()
+  external int last_failure_at;
+
+  external ffi.Pointer<ffi.Int64> retry_at;
+}
+
+final class wire_cst_list_peer_circuit_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_peer_circuit_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_peer_diagnostics_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_peer_path_dto> path;
+
+  @ffi.Bool()
+  external bool direct_established;
+
+  @ffi.Uint32()
+  external int dial_attempts;
+
+  @ffi.Uint32()
+  external int dial_failures;
+
+  external ffi.Pointer<ffi.Int64> last_dial_at;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_error;
+}
+
+final class wire_cst_list_peer_diagnostics_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_peer_diagnostics_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_peer_info_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> region;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> version;
+
+  external ffi.Pointer<ffi.Uint64> latency_ms;
+
+  @ffi.Bool()
+  external bool is_mobile;
+}
+
+final class wire_cst_list_peer_info_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_peer_info_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_peer_latency_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<ffi.Uint64> latency_ms;
+
+  @ffi.Double()
+  external double average_ms;
+
+  @ffi.Double()
+  external double jitter_ms;
+
+  @ffi.Uint32()
+  external int samples;
+}
+
+final class wire_cst_list_peer_latency_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_peer_latency_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_peer_path_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_peer_path_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_peer_sync_status_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+
+  @ffi.Uint64()
+  external int ops_sent;
+
+  @ffi.Uint64()
+  external int ops_received;
+
+  @ffi.Uint64()
+  external int bytes_sent;
+
+  @ffi.Uint64()
+  external int bytes_received;
+
+  external ffi.Pointer<ffi.Int64> last_request_at;
+
+  external ffi.Pointer<ffi.Int64> last_response_at;
+
+  @ffi.Bool()
+  external bool in_progress;
+
+  @ffi.Uint64()
+  external int ops_acked;
+
+  external ffi.Pointer<ffi.Int64> last_ack_at;
+}
+
+final class wire_cst_list_peer_sync_status_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_peer_sync_status_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_pinned_peer_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+}
+
+final class wire_cst_list_pinned_peer_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_pinned_peer_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_prim_u_32_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_rekeyed_database_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> old_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> new_name;
+}
+
+final class wire_cst_list_rekeyed_database_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_rekeyed_database_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_replica_check_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> publisher;
+
+  @ffi.Bool()
+  external bool matches;
+
+  @ffi.Uint32()
+  external int mismatched_buckets;
+
+  @ffi.Int64()
+  external int checked_at;
+}
+
+final class wire_cst_list_replica_check_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_replica_check_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_scored_member_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> member;
+
+  @ffi.Double()
+  external double score;
+}
+
+final class wire_cst_list_scored_member_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_scored_member_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_time_point_dto extends ffi.Struct {
+  @ffi.Int64()
+  external int timestamp;
+
+  @ffi.Double()
+  external double value;
+}
+
+final class wire_cst_list_time_point_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_time_point_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_topic_health_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> topic;
+
+  @ffi.Bool()
+  external bool subscribed;
+
+  @ffi.Uint32()
+  external int neighbors;
+}
+
+final class wire_cst_list_topic_health_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_topic_health_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_topic_metrics_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> topic;
+
+  @ffi.Uint64()
+  external int messages_sent;
+
+  @ffi.Uint64()
+  external int messages_received;
+
+  @ffi.Uint64()
+  external int duplicates_dropped;
+
+  @ffi.Uint64()
+  external int bytes_sent;
+
+  @ffi.Uint64()
+  external int bytes_received;
+}
+
+final class wire_cst_list_topic_metrics_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_topic_metrics_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_topic_stats_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> topic;
+
+  @ffi.Uint64()
+  external int messages_sent;
+
+  @ffi.Uint64()
+  external int messages_received;
+
+  @ffi.Uint64()
+  external int bytes_sent;
+
+  @ffi.Uint64()
+  external int bytes_received;
+
+  @ffi.Uint32()
+  external int neighbors;
+
+  external ffi.Pointer<ffi.Int64> last_event_at;
+}
+
+final class wire_cst_list_topic_stats_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_topic_stats_dto> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_accepted_invite_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> owner;
+
+  @ffi.Bool()
+  external bool can_write;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> writer_secret_key;
+
+  @ffi.Bool()
+  external bool encrypted;
+}
+
+final class wire_cst_backup_info_dto extends ffi.Struct {
+  @ffi.Int64()
+  external int created_at;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+
+  @ffi.Uint32()
+  external int tree_count;
+
+  @ffi.Uint64()
+  external int entry_count;
+}
+
+final class wire_cst_bandwidth_stats_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_bandwidth_usage_dto> topics;
+
+  external ffi.Pointer<wire_cst_list_bandwidth_usage_dto> subsystems;
+
+  @ffi.Uint64()
+  external int today_bytes;
+
+  @ffi.Uint64()
+  external int month_bytes;
+
+  external wire_cst_bandwidth_caps_dto caps;
+
+  @ffi.Bool()
+  external bool cap_exceeded;
+}
+
+final class wire_cst_change_event_dto extends ffi.Struct {
+  @ffi.Uint64()
+  external int seq;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value_bytes;
+}
+
+final class wire_cst_checkpoint_dto extends ffi.Struct {
+  external ffi.Pointer<ffi.Int64> high_water_mark;
+
+  @ffi.Uint32()
+  external int known_peers_saved;
+
+  @ffi.Uint32()
+  external int outbox_depth;
+
+  @ffi.Bool()
+  external bool flushed;
+
+  @ffi.Uint64()
+  external int duration_ms;
+}
+
+final class wire_cst_connection_diagnostics_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_String> local_addrs;
+
+  external ffi.Pointer<wire_cst_list_String> public_addrs;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> relay_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> nat_type;
+
+  external ffi.Pointer<wire_cst_list_peer_diagnostics_dto> peers;
+}
+
+final class wire_cst_connection_result_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> path;
+
+  @ffi.Uint64()
+  external int handshake_ms;
+}
+
+final class wire_cst_connectivity_dto extends ffi.Struct {
+  @ffi.Int32()
+  external int state;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class wire_cst_db_sync_info_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  @ffi.Uint64()
+  external int op_count;
+
+  external ffi.Pointer<ffi.Int64> last_local_write;
+
+  external ffi.Pointer<ffi.Int64> last_remote_op;
+
+  external ffi.Pointer<ffi.Int64> last_sync;
+}
+
+final class wire_cst_FileEventDto_Offer extends ffi.Struct {
+  external ffi.Pointer<wire_cst_file_offer_dto> field0;
+}
+
+final class wire_cst_FileEventDto_Progress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id;
+
+  @ffi.Uint64()
+  external int received_bytes;
+
+  @ffi.Uint64()
+  external int total_bytes;
+}
+
+final class wire_cst_FileEventDto_Retrying extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id;
+
+  @ffi.Uint32()
+  external int attempt;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> error;
+}
+
+final class wire_cst_FileEventDto_Completed extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> path;
+}
+
+final class wire_cst_FileEventDto_Failed extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> offer_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> error;
+}
+
+final class FileEventDtoKind extends ffi.Union {
+  external wire_cst_FileEventDto_Offer Offer;
+
+  external wire_cst_FileEventDto_Progress Progress;
+
+  external wire_cst_FileEventDto_Retrying Retrying;
+
+  external wire_cst_FileEventDto_Completed Completed;
+
+  external wire_cst_FileEventDto_Failed Failed;
+}
+
+final class wire_cst_debug_server_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> token;
+}
+
+final class wire_cst_file_event_dto extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external FileEventDtoKind kind;
+}
+
+final class wire_cst_subsystem_health_dto extends ffi.Struct {
+  @ffi.Int32()
+  external int status;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class wire_cst_health_report_dto extends ffi.Struct {
+  external wire_cst_subsystem_health_dto storage;
+
+  external wire_cst_subsystem_health_dto gossip;
+
+  external wire_cst_subsystem_health_dto sync;
+
+  external wire_cst_subsystem_health_dto discovery;
+
+  external wire_cst_subsystem_health_dto relay;
+
+  external ffi.Pointer<wire_cst_list_topic_health_dto> topics;
+
+  external ffi.Pointer<ffi.Int64> last_flush_at;
+
+  external ffi.Pointer<ffi.Int64> last_sync_at;
+
+  external ffi.Pointer<ffi.Int64> last_announcement_at;
+}
+
+final class wire_cst_identity_rotation_dto extends ffi.Struct {
+  external wire_cst_node_info node;
+
+  external wire_cst_successor_record_dto record;
+
+  external ffi.Pointer<wire_cst_list_rekeyed_database_dto> rekeyed_databases;
+
+  @ffi.Uint32()
+  external int rekeyed_operations;
+}
+
+final class wire_cst_integrity_report_dto extends ffi.Struct {
+  @ffi.Uint64()
+  external int checked;
+
+  external ffi.Pointer<wire_cst_list_integrity_issue_dto> issues;
+
+  @ffi.Uint64()
+  external int repaired;
+}
+
+final class wire_cst_kadena_transaction_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sig;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> cmd;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> json;
+}
+
+final class wire_cst_key_change_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  @ffi.Bool()
+  external bool deleted;
+}
+
+final class wire_cst_key_pair_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> secret_key;
+}
+
+final class wire_cst_metrics_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_topic_metrics_dto> topics;
+
+  @ffi.Uint64()
+  external int ops_applied;
+
+  @ffi.Uint64()
+  external int verify_failures;
+
+  @ffi.Uint64()
+  external int limit_violations;
+
+  external ffi.Pointer<wire_cst_list_latency_bucket_dto> connect_latency;
+
+  @ffi.Double()
+  external double connect_latency_sum_ms;
+
+  @ffi.Uint64()
+  external int storage_size_bytes;
+
+  @ffi.Uint64()
+  external int storage_keys;
+
+  @ffi.Uint64()
+  external int stored_operations;
+
+  @ffi.Uint64()
+  external int outbox_depth;
+}
+
+final class wire_cst_node_addr_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_String> direct_addresses;
+
+  external ffi.Pointer<wire_cst_list_String> relay_urls;
+}
+
+final class wire_cst_node_instance_dto extends ffi.Struct {
+  @ffi.Uint32()
+  external int handle;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+}
+
+final class wire_cst_node_stats_dto extends ffi.Struct {
+  @ffi.Uint64()
+  external int uptime_seconds;
+
+  @ffi.Uint64()
+  external int total_operations;
+
+  @ffi.Uint64()
+  external int bytes_sent;
+
+  @ffi.Uint64()
+  external int bytes_received;
+
+  @ffi.Uint64()
+  external int connects;
+
+  @ffi.Uint64()
+  external int reconnects;
+
+  @ffi.Uint64()
+  external int lifetime_sessions;
+
+  @ffi.Uint64()
+  external int lifetime_uptime_seconds;
+
+  @ffi.Uint64()
+  external int lifetime_bytes_sent;
+
+  @ffi.Uint64()
+  external int lifetime_bytes_received;
+
+  @ffi.Uint64()
+  external int lifetime_connects;
+
+  @ffi.Uint64()
+  external int lifetime_reconnects;
+
+  @ffi.Uint64()
+  external int outbox_depth;
+}
+
+final class wire_cst_node_status_dto extends ffi.Struct {
+  @ffi.Bool()
+  external bool is_running;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  @ffi.Uint32()
+  external int connected_peers;
+
+  @ffi.Uint32()
+  external int discovered_peers;
+
+  @ffi.Uint64()
+  external int uptime_seconds;
+
+  @ffi.Uint64()
+  external int gossip_messages_received;
+
+  @ffi.Uint64()
+  external int storage_size_bytes;
+
+  @ffi.Uint64()
+  external int total_keys;
+
+  @ffi.Uint32()
+  external int sync_operations;
+
+  @ffi.Uint64()
+  external int latency_requests_sent;
+
+  @ffi.Uint64()
+  external int latency_responses_received;
+
+  external ffi.Pointer<wire_cst_list_topic_stats_dto> topics;
+}
+
+final class wire_cst_power_policy_dto extends ffi.Struct {
+  @ffi.Bool()
+  external bool low_power;
+
+  @ffi.Bool()
+  external bool metered;
+
+  @ffi.Bool()
+  external bool sync_deferred;
+
+  @ffi.Bool()
+  external bool blob_transfers_allowed;
+}
+
+final class wire_cst_query_result_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_db_entry_dto> entries;
+
+  @ffi.Bool()
+  external bool has_more;
+}
+
+final class wire_cst_relay_status_dto extends ffi.Struct {
+  external wire_cst_relay_config_dto relay;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> active_url;
+
+  external ffi.Pointer<ffi.Uint64> rtt_ms;
+}
+
+final class wire_cst_rpc_request_dto extends ffi.Struct {
+  @ffi.Uint64()
+  external int request_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> peer_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> method;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payload;
+}
+
+final class wire_cst_scan_page_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_db_entry_dto> entries;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> next_cursor;
+}
+
+final class wire_cst_StartupStageDto_EndpointOnline extends ffi.Struct {
+  @ffi.Bool()
+  external bool relay_connected;
+}
+
+final class wire_cst_StartupStageDto_BootstrapConnecting extends ffi.Struct {
+  @ffi.Uint32()
+  external int attempt;
+}
+
+final class StartupStageDtoKind extends ffi.Union {
+  external wire_cst_StartupStageDto_EndpointOnline EndpointOnline;
+
+  external wire_cst_StartupStageDto_BootstrapConnecting BootstrapConnecting;
+}
+
+final class wire_cst_startup_stage_dto extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external StartupStageDtoKind kind;
+}
+
+final class wire_cst_sync_stats_dto extends ffi.Struct {
+  @ffi.Uint64()
+  external int total_operations;
+
+  @ffi.Uint64()
+  external int ops_sent;
+
+  @ffi.Uint64()
+  external int ops_received;
+
+  @ffi.Uint64()
+  external int bytes_sent;
+
+  @ffi.Uint64()
+  external int bytes_received;
+
+  external ffi.Pointer<ffi.Int64> last_sync_at;
+
+  external ffi.Pointer<wire_cst_list_peer_sync_status_dto> peers;
+}
+
+final class wire_cst_sync_wait_summary_dto extends ffi.Struct {
+  @ffi.Bool()
+  external bool synced;
+
+  @ffi.Uint32()
+  external int queued_ops;
+
+  @ffi.Uint32()
+  external int unacknowledged_ops;
+
+  @ffi.Uint64()
+  external int duration_ms;
+}
+
+final class wire_cst_text_delta_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  @ffi.Uint64()
+  external int index;
+
+  @ffi.Uint64()
+  external int deleted;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> inserted;
+}
+
+final class wire_cst_topic_message_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> topic;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> delivered_from;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> content;
+}
+
+final class wire_cst_value_update_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> db_name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> value_bytes;
+}
+
+final class wire_cst_verified_binding_dto extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> public_key;
+
+  @ffi.Int64()
+  external int verified_at;
+}
+
+final class wire_cst_wake_sync_summary_dto extends ffi.Struct {
+  @ffi.Bool()
+  external bool started_node;
+
+  @ffi.Uint32()
+  external int pinned_peers_dialed;
+
+  @ffi.Uint32()
+  external int pinned_peers_connected;
+
+  @ffi.Bool()
+  external bool synced;
+
+  @ffi.Uint64()
+  external int ops_received;
+
+  @ffi.Uint64()
+  external int ops_sent;
+
+  @ffi.Uint32()
+  external int outbox_sent;
+
+  @ffi.Uint32()
+  external int outbox_remaining;
+
+  @ffi.Uint64()
+  external int duration_ms;
+}
+
+const int DEFAULT_NODE_HANDLE = 0;
+
+const int VERIFIED_BINDING_TTL_MS = 3600000;
+
+const int MIN_PASSPHRASE_LENGTH = 8;
+
+const int OFFLOAD_THRESHOLD = 65536;
+
+const int MAX_MANIFEST_PEERS = 32;
+
+const int DEFAULT_MAX_MESSAGE_SIZE = 4096;
+
+const int MIN_MAX_MESSAGE_SIZE = 1024;
+
+const int MAX_CHUNKS = 256;
+
+const int MAX_OPS_PER_RESPONSE_LIMIT = 512;
+
+const int ED25519_PUBLIC_KEY_LENGTH = 32;
+
+const int ED25519_SIGNATURE_LENGTH = 64;
+
+const int MAX_MESSAGE_LENGTH = 1048576;
+
+const int MIN_TIMESTAMP_TOLERANCE = 300;
+
+const int MAX_TIMESTAMP_TOLERANCE = 3600;
+
+const int DB_KEY_LENGTH = 32;
+
+const int XNONCE_LENGTH = 24;
+
+const int DEDUP_CAPACITY = 4096;
+
+const int MAX_QUEUED_DIALS = 256;
+
+const int NEARBY_LATENCY_MS = 150;
+
+const int PEER_EXPIRY_SECS = 300;
+
+const int ANNOUNCE_INTERVAL_SECS = 10;
+
+const int MAX_KNOWN_PEERS = 64;
+
+const int KNOWN_PEER_TTL_SECS = 604800;
+
+const int ANNOUNCEMENT_MAX_SKEW_MS = 300000;
+
+const int MAX_ANNOUNCEMENT_CACHE = 1024;
+
+const int MAX_DIAL_RECORDS = 512;
+
+const int MAX_DM_LENGTH = 65536;
+
+const int EVENT_HISTORY_CAPACITY = 256;
+
+const int DEFAULT_TTL_SECS = 28800;
+
+const int DEFAULT_GAS_LIMIT = 2000;
+
+const double DEFAULT_GAS_PRICE = 1e-7;
+
+const int WRAPPING_KEY_LENGTH = 32;
+
+const int DEFAULT_CAPACITY = 500;
+
+const int MAX_CAPACITY = 50000;
+
+const int BUCKETS = 16;
+
+const int DEFAULT_MQTT_PORT = 1883;
+
+const int HOT_OPERATIONS = 1024;
+
+const int LOW_BATTERY_PERCENT = 20;
+
+const int MAX_STATUS_LENGTH = 256;
+
+const int MAX_QUERY_LIMIT = 1000;
+
+const int MAX_RPC_MESSAGE_SIZE = 1048576;
+
+const int MIN_TOKEN_CHARS = 2;
+
+const int MAX_TOKEN_CHARS = 64;
+
+const int MAX_SEARCH_LIMIT = 100;
+
+const int SEARCH_INDEX_VERSION = 1;
+
+const int SNAPSHOT_VERSION = 1;
+
+const int SNAPSHOT_MIN_OPS = 1000;
+
+const int MAX_CHANGE_LOG_ENTRIES = 100000;
+
+const int MAX_OUTBOX_ENTRIES = 10000;
+
+const int MAX_SCAN_LIMIT = 1000;
+
+const int MAX_OPS_PER_RESPONSE = 128;
+
+const int DEFAULT_MAX_VALUE_BYTES = 524288;
+
+const int DEFAULT_MAX_KEY_LEN = 1024;
+
+const int MAX_TICKET_ADDRS = 6;
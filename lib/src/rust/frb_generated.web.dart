@@ -3,6 +3,7 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
@@ -12,808 +13,3024 @@ import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  NodeInfo dco_decode_box_autoadd_node_info(dynamic raw);
+                  
 
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+@protected RustStreamSink<ChangeEventDto> dco_decode_StreamSink_change_event_dto_Dco(dynamic raw);
 
-  @protected
-  DbEntryDto dco_decode_db_entry_dto(dynamic raw);
+@protected RustStreamSink<DirectMessageDto> dco_decode_StreamSink_direct_message_dto_Dco(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+@protected RustStreamSink<FileEventDto> dco_decode_StreamSink_file_event_dto_Dco(dynamic raw);
 
-  @protected
-  KeyPairDto dco_decode_key_pair_dto(dynamic raw);
+@protected RustStreamSink<KeyChangeDto> dco_decode_StreamSink_key_change_dto_Dco(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected RustStreamSink<NodeEventRecordDto> dco_decode_StreamSink_node_event_record_dto_Dco(dynamic raw);
 
-  @protected
-  List<DbEntryDto> dco_decode_list_db_entry_dto(dynamic raw);
+@protected RustStreamSink<RpcRequestDto> dco_decode_StreamSink_rpc_request_dto_Dco(dynamic raw);
 
-  @protected
-  List<LogEntry> dco_decode_list_log_entry(dynamic raw);
+@protected RustStreamSink<StartupStageDto> dco_decode_StreamSink_startup_stage_dto_Dco(dynamic raw);
 
-  @protected
-  List<PeerInfoDto> dco_decode_list_peer_info_dto(dynamic raw);
+@protected RustStreamSink<TextDeltaDto> dco_decode_StreamSink_text_delta_dto_Dco(dynamic raw);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected RustStreamSink<TopicMessageDto> dco_decode_StreamSink_topic_message_dto_Dco(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected RustStreamSink<ValueUpdateDto> dco_decode_StreamSink_value_update_dto_Dco(dynamic raw);
 
-  @protected
-  LogEntry dco_decode_log_entry(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  NodeInfo dco_decode_node_info(dynamic raw);
+@protected AcceptedInviteDto dco_decode_accepted_invite_dto(dynamic raw);
 
-  @protected
-  NodeStatusDto dco_decode_node_status_dto(dynamic raw);
+@protected BackupInfoDto dco_decode_backup_info_dto(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected BandwidthCapsDto dco_decode_bandwidth_caps_dto(dynamic raw);
 
-  @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+@protected BandwidthStatsDto dco_decode_bandwidth_stats_dto(dynamic raw);
 
-  @protected
-  NodeInfo? dco_decode_opt_box_autoadd_node_info(dynamic raw);
+@protected BandwidthSubsystemDto dco_decode_bandwidth_subsystem_dto(dynamic raw);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+@protected BandwidthUsageDto dco_decode_bandwidth_usage_dto(dynamic raw);
 
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+@protected BatchEntryDto dco_decode_batch_entry_dto(dynamic raw);
 
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  PeerInfoDto dco_decode_peer_info_dto(dynamic raw);
+@protected BootstrapManifestDto dco_decode_bootstrap_manifest_dto(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected BandwidthCapsDto dco_decode_box_autoadd_bandwidth_caps_dto(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected BootstrapManifestDto dco_decode_box_autoadd_bootstrap_manifest_dto(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected DeviceConditionsDto dco_decode_box_autoadd_device_conditions_dto(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected FileOfferDto dco_decode_box_autoadd_file_offer_dto(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+@protected LogFilterDto dco_decode_box_autoadd_log_filter_dto(dynamic raw);
 
-  @protected
-  NodeInfo sse_decode_box_autoadd_node_info(SseDeserializer deserializer);
+@protected MqttBridgeConfigDto dco_decode_box_autoadd_mqtt_bridge_config_dto(dynamic raw);
 
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+@protected NodeConfigDto dco_decode_box_autoadd_node_config_dto(dynamic raw);
 
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+@protected NodeInfo dco_decode_box_autoadd_node_info(dynamic raw);
 
-  @protected
-  DbEntryDto sse_decode_db_entry_dto(SseDeserializer deserializer);
+@protected OplogGcDto dco_decode_box_autoadd_oplog_gc_dto(dynamic raw);
 
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+@protected PeerPathDto dco_decode_box_autoadd_peer_path_dto(dynamic raw);
 
-  @protected
-  KeyPairDto sse_decode_key_pair_dto(SseDeserializer deserializer);
+@protected PresenceDto dco_decode_box_autoadd_presence_dto(dynamic raw);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected QuerySpecDto dco_decode_box_autoadd_query_spec_dto(dynamic raw);
 
-  @protected
-  List<DbEntryDto> sse_decode_list_db_entry_dto(SseDeserializer deserializer);
+@protected SuccessorRecordDto dco_decode_box_autoadd_successor_record_dto(dynamic raw);
 
-  @protected
-  List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
 
-  @protected
-  List<PeerInfoDto> sse_decode_list_peer_info_dto(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+@protected ChangeEventDto dco_decode_change_event_dto(dynamic raw);
 
-  @protected
-  NodeInfo sse_decode_node_info(SseDeserializer deserializer);
+@protected CheckpointDto dco_decode_checkpoint_dto(dynamic raw);
 
-  @protected
-  NodeStatusDto sse_decode_node_status_dto(SseDeserializer deserializer);
+@protected ConflictDto dco_decode_conflict_dto(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected ConnectionDiagnosticsDto dco_decode_connection_diagnostics_dto(dynamic raw);
 
-  @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+@protected ConnectionResultDto dco_decode_connection_result_dto(dynamic raw);
 
-  @protected
-  NodeInfo? sse_decode_opt_box_autoadd_node_info(SseDeserializer deserializer);
+@protected ConnectionTypeDto dco_decode_connection_type_dto(dynamic raw);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+@protected ConnectivityDto dco_decode_connectivity_dto(dynamic raw);
 
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+@protected ConnectivityStateDto dco_decode_connectivity_state_dto(dynamic raw);
 
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected DbEntryDto dco_decode_db_entry_dto(dynamic raw);
 
-  @protected
-  PeerInfoDto sse_decode_peer_info_dto(SseDeserializer deserializer);
+@protected DbSyncInfoDto dco_decode_db_sync_info_dto(dynamic raw);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected DebugServerDto dco_decode_debug_server_dto(dynamic raw);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected DeviceConditionsDto dco_decode_device_conditions_dto(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected DirectMessageDto dco_decode_direct_message_dto(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
-  @protected
-  String cst_encode_String(String raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_i_64(PlatformInt64 raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_64(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_node_info(NodeInfo raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_node_info(raw);
-  }
-
-  @protected
-  int cst_encode_box_autoadd_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_u_32(raw);
-  }
-
-  @protected
-  JSAny cst_encode_box_autoadd_u_64(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_u_64(raw);
-  }
-
-  @protected
-  JSAny cst_encode_db_entry_dto(DbEntryDto raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.dbName),
-      cst_encode_String(raw.key),
-      cst_encode_String(raw.value),
-      cst_encode_list_prim_u_8_strict(raw.valueBytes),
-    ].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_i_64(PlatformInt64 raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return castNativeBigInt(raw);
-  }
-
-  @protected
-  JSAny cst_encode_key_pair_dto(KeyPairDto raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.publicKey),
-      cst_encode_String(raw.secretKey),
-    ].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_String(List<String> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_String).toList().jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_db_entry_dto(List<DbEntryDto> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_db_entry_dto).toList().jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_log_entry(List<LogEntry> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_log_entry).toList().jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_peer_info_dto(List<PeerInfoDto> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_peer_info_dto).toList().jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_prim_u_8_loose(List<int> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_list_prim_u_8_strict(Uint8List raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_log_entry(LogEntry raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_i_64(raw.timestamp),
-      cst_encode_String(raw.level),
-      cst_encode_String(raw.message),
-    ].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_node_info(NodeInfo raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.nodeId),
-      cst_encode_String(raw.publicKey),
-      cst_encode_bool(raw.isRunning),
-    ].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_node_status_dto(NodeStatusDto raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_bool(raw.isRunning),
-      cst_encode_opt_String(raw.nodeId),
-      cst_encode_u_32(raw.connectedPeers),
-      cst_encode_u_32(raw.discoveredPeers),
-      cst_encode_u_64(raw.uptimeSeconds),
-      cst_encode_u_64(raw.gossipMessagesReceived),
-      cst_encode_u_64(raw.storageSizeBytes),
-      cst_encode_u_64(raw.totalKeys),
-      cst_encode_u_32(raw.syncOperations),
-      cst_encode_u_64(raw.latencyRequestsSent),
-      cst_encode_u_64(raw.latencyResponsesReceived),
-    ].jsify()!;
-  }
-
-  @protected
-  String? cst_encode_opt_String(String? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_String(raw);
-  }
-
-  @protected
-  JSAny? cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_i_64(raw);
-  }
-
-  @protected
-  JSAny? cst_encode_opt_box_autoadd_node_info(NodeInfo? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_node_info(raw);
-  }
-
-  @protected
-  int? cst_encode_opt_box_autoadd_u_32(int? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_u_32(raw);
-  }
-
-  @protected
-  JSAny? cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  JSAny? cst_encode_opt_list_prim_u_8_strict(Uint8List? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_list_prim_u_8_strict(raw);
-  }
-
-  @protected
-  JSAny cst_encode_peer_info_dto(PeerInfoDto raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.nodeId),
-      cst_encode_String(raw.publicKey),
-      cst_encode_opt_String(raw.address),
-      cst_encode_opt_String(raw.region),
-      cst_encode_opt_String(raw.version),
-      cst_encode_opt_box_autoadd_u_64(raw.latencyMs),
-      cst_encode_bool(raw.isMobile),
-    ].jsify()!;
-  }
-
-  @protected
-  JSAny cst_encode_u_64(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return castNativeBigInt(raw);
-  }
-
-  @protected
-  bool cst_encode_bool(bool raw);
-
-  @protected
-  int cst_encode_u_32(int raw);
-
-  @protected
-  int cst_encode_u_8(int raw);
-
-  @protected
-  void cst_encode_unit(void raw);
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_i_64(
-    PlatformInt64 self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_node_info(
-    NodeInfo self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_db_entry_dto(DbEntryDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_key_pair_dto(KeyPairDto self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_db_entry_dto(
-    List<DbEntryDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_log_entry(List<LogEntry> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_peer_info_dto(
-    List<PeerInfoDto> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_node_info(NodeInfo self, SseSerializer serializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  void sse_encode_node_status_dto(NodeStatusDto self, SseSerializer serializer);
+@protected FileEventDto dco_decode_file_event_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected FileOfferDto dco_decode_file_offer_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_i_64(
-    PlatformInt64? self,
-    SseSerializer serializer,
-  );
+@protected GeoMatchDto dco_decode_geo_match_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_node_info(
-    NodeInfo? self,
-    SseSerializer serializer,
-  );
+@protected HashEntryDto dco_decode_hash_entry_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+@protected HealthReportDto dco_decode_health_report_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+@protected HealthStatusDto dco_decode_health_status_dto(dynamic raw);
 
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-    Uint8List? self,
-    SseSerializer serializer,
-  );
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  void sse_encode_peer_info_dto(PeerInfoDto self, SseSerializer serializer);
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected IdentityRotationDto dco_decode_identity_rotation_dto(dynamic raw);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected IntegrityIssueDto dco_decode_integrity_issue_dto(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected IntegrityReportDto dco_decode_integrity_report_dto(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected KadenaTransactionDto dco_decode_kadena_transaction_dto(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
+@protected KeyChangeDto dco_decode_key_change_dto(dynamic raw);
 
-// Section: wire_class
+@protected KeyPairDto dco_decode_key_pair_dto(dynamic raw);
 
-class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-
-  void wire__crate__api__add_log_entry(
-    NativePortType port_,
-    String level,
-    String message,
-  ) => wasmModule.wire__crate__api__add_log_entry(port_, level, message);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__clear_logs() => wasmModule.wire__crate__api__clear_logs();
-
-  void wire__crate__api__delete_data(
-    NativePortType port_,
-    String db_name,
-    String key,
-  ) => wasmModule.wire__crate__api__delete_data(port_, db_name, key);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__extract_name_from_db(String db_name) =>
-      wasmModule.wire__crate__api__extract_name_from_db(db_name);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__generate_db_name(String name, String public_key_hex) =>
-      wasmModule.wire__crate__api__generate_db_name(name, public_key_hex);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__generate_keypair() =>
-      wasmModule.wire__crate__api__generate_keypair();
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__generate_peer_id_from_secret_key(String secret_key_hex) =>
-      wasmModule.wire__crate__api__generate_peer_id_from_secret_key(
-        secret_key_hex,
-      );
-
-  void wire__crate__api__get_all_data(NativePortType port_) =>
-      wasmModule.wire__crate__api__get_all_data(port_);
-
-  void wire__crate__api__get_all_entries(
-    NativePortType port_,
-    String db_name,
-  ) => wasmModule.wire__crate__api__get_all_entries(port_, db_name);
-
-  void wire__crate__api__get_data(
-    NativePortType port_,
-    String db_name,
-    String key,
-  ) => wasmModule.wire__crate__api__get_data(port_, db_name, key);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__get_logs(int? limit) =>
-      wasmModule.wire__crate__api__get_logs(limit);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__get_node_info() =>
-      wasmModule.wire__crate__api__get_node_info();
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__get_node_status() =>
-      wasmModule.wire__crate__api__get_node_status();
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__get_peers() => wasmModule.wire__crate__api__get_peers();
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__greet(String name) =>
-      wasmModule.wire__crate__api__greet(name);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__init_logging() =>
-      wasmModule.wire__crate__api__init_logging();
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__is_node_running() =>
-      wasmModule.wire__crate__api__is_node_running();
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__list_databases() =>
-      wasmModule.wire__crate__api__list_databases();
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__list_keys(String db_name) =>
-      wasmModule.wire__crate__api__list_keys(db_name);
-
-  void wire__crate__api__request_sync(
-    NativePortType port_,
-    JSAny? since_timestamp,
-  ) => wasmModule.wire__crate__api__request_sync(port_, since_timestamp);
-
-  void wire__crate__api__send_gossip(
-    NativePortType port_,
-    String topic,
-    String message,
-  ) => wasmModule.wire__crate__api__send_gossip(port_, topic, message);
-
-  void wire__crate__api__send_latency_request(
-    NativePortType port_,
-    String peer_id,
-  ) => wasmModule.wire__crate__api__send_latency_request(port_, peer_id);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__sign_message_with_key(
-    String secret_key_hex,
-    String message,
-  ) => wasmModule.wire__crate__api__sign_message_with_key(
-    secret_key_hex,
-    message,
-  );
-
-  void wire__crate__api__start_node(
-    NativePortType port_,
-    String data_dir,
-    String? wallet_secret_key,
-    JSAny bootstrap_peers,
-    String? region,
-  ) => wasmModule.wire__crate__api__start_node(
-    port_,
-    data_dir,
-    wallet_secret_key,
-    bootstrap_peers,
-    region,
-  );
-
-  void wire__crate__api__stop_node(NativePortType port_) =>
-      wasmModule.wire__crate__api__stop_node(port_);
-
-  void wire__crate__api__store_data(
-    NativePortType port_,
-    String db_name,
-    String key,
-    JSAny value,
-    String public_key,
-    String signature,
-  ) => wasmModule.wire__crate__api__store_data(
-    port_,
-    db_name,
-    key,
-    value,
-    public_key,
-    signature,
-  );
-
-  void wire__crate__api__store_data_local(
-    NativePortType port_,
-    String db_name,
-    String key,
-    JSAny value,
-  ) =>
-      wasmModule.wire__crate__api__store_data_local(port_, db_name, key, value);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__validate_timestamp(JSAny timestamp) =>
-      wasmModule.wire__crate__api__validate_timestamp(timestamp);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__verify_db_name(String db_name, String public_key_hex) =>
-      wasmModule.wire__crate__api__verify_db_name(db_name, public_key_hex);
-
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__verify_message_signature(
-    String public_key_hex,
-    String message,
-    String signature_hex,
-  ) => wasmModule.wire__crate__api__verify_message_signature(
-    public_key_hex,
-    message,
-    signature_hex,
-  );
-}
-
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
-
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void wire__crate__api__add_log_entry(
-    NativePortType port_,
-    String level,
-    String message,
-  );
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__clear_logs();
-
-  external void wire__crate__api__delete_data(
-    NativePortType port_,
-    String db_name,
-    String key,
-  );
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__extract_name_from_db(String db_name);
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__generate_db_name(String name, String public_key_hex);
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__generate_keypair();
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__generate_peer_id_from_secret_key(String secret_key_hex);
-
-  external void wire__crate__api__get_all_data(NativePortType port_);
-
-  external void wire__crate__api__get_all_entries(
-    NativePortType port_,
-    String db_name,
-  );
-
-  external void wire__crate__api__get_data(
-    NativePortType port_,
-    String db_name,
-    String key,
-  );
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__get_logs(int? limit);
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__get_node_info();
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__get_node_status();
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__get_peers();
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__greet(String name);
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__init_logging();
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__is_node_running();
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__list_databases();
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__list_keys(String db_name);
-
-  external void wire__crate__api__request_sync(
-    NativePortType port_,
-    JSAny? since_timestamp,
-  );
-
-  external void wire__crate__api__send_gossip(
-    NativePortType port_,
-    String topic,
-    String message,
-  );
-
-  external void wire__crate__api__send_latency_request(
-    NativePortType port_,
-    String peer_id,
-  );
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__sign_message_with_key(
-    String secret_key_hex,
-    String message,
-  );
-
-  external void wire__crate__api__start_node(
-    NativePortType port_,
-    String data_dir,
-    String? wallet_secret_key,
-    JSAny bootstrap_peers,
-    String? region,
-  );
-
-  external void wire__crate__api__stop_node(NativePortType port_);
-
-  external void wire__crate__api__store_data(
-    NativePortType port_,
-    String db_name,
-    String key,
-    JSAny value,
-    String public_key,
-    String signature,
-  );
-
-  external void wire__crate__api__store_data_local(
-    NativePortType port_,
-    String db_name,
-    String key,
-    JSAny value,
-  );
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__validate_timestamp(JSAny timestamp);
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__verify_db_name(String db_name, String public_key_hex);
-
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__verify_message_signature(
-    String public_key_hex,
-    String message,
-    String signature_hex,
-  );
-}
+@protected LatencyBucketDto dco_decode_latency_bucket_dto(dynamic raw);
+
+@protected LinkedDeviceDto dco_decode_linked_device_dto(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<BandwidthUsageDto> dco_decode_list_bandwidth_usage_dto(dynamic raw);
+
+@protected List<BatchEntryDto> dco_decode_list_batch_entry_dto(dynamic raw);
+
+@protected List<ConflictDto> dco_decode_list_conflict_dto(dynamic raw);
+
+@protected List<DbEntryDto> dco_decode_list_db_entry_dto(dynamic raw);
+
+@protected List<DirectMessageDto> dco_decode_list_direct_message_dto(dynamic raw);
+
+@protected List<FileOfferDto> dco_decode_list_file_offer_dto(dynamic raw);
+
+@protected List<GeoMatchDto> dco_decode_list_geo_match_dto(dynamic raw);
+
+@protected List<HashEntryDto> dco_decode_list_hash_entry_dto(dynamic raw);
+
+@protected List<IntegrityIssueDto> dco_decode_list_integrity_issue_dto(dynamic raw);
+
+@protected List<LatencyBucketDto> dco_decode_list_latency_bucket_dto(dynamic raw);
+
+@protected List<LinkedDeviceDto> dco_decode_list_linked_device_dto(dynamic raw);
+
+@protected List<LogEntry> dco_decode_list_log_entry(dynamic raw);
+
+@protected List<MqttTopicMappingDto> dco_decode_list_mqtt_topic_mapping_dto(dynamic raw);
+
+@protected List<NodeEventRecordDto> dco_decode_list_node_event_record_dto(dynamic raw);
+
+@protected List<Uint8List?> dco_decode_list_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected List<PeerCircuitDto> dco_decode_list_peer_circuit_dto(dynamic raw);
+
+@protected List<PeerDiagnosticsDto> dco_decode_list_peer_diagnostics_dto(dynamic raw);
+
+@protected List<PeerInfoDto> dco_decode_list_peer_info_dto(dynamic raw);
+
+@protected List<PeerLatencyDto> dco_decode_list_peer_latency_dto(dynamic raw);
+
+@protected List<PeerPathDto> dco_decode_list_peer_path_dto(dynamic raw);
+
+@protected List<PeerSyncStatusDto> dco_decode_list_peer_sync_status_dto(dynamic raw);
+
+@protected List<PinnedPeerDto> dco_decode_list_pinned_peer_dto(dynamic raw);
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<QueryFilterDto> dco_decode_list_query_filter_dto(dynamic raw);
+
+@protected List<RekeyedDatabaseDto> dco_decode_list_rekeyed_database_dto(dynamic raw);
+
+@protected List<ReplicaCheckDto> dco_decode_list_replica_check_dto(dynamic raw);
+
+@protected List<ScoredMemberDto> dco_decode_list_scored_member_dto(dynamic raw);
+
+@protected List<TimePointDto> dco_decode_list_time_point_dto(dynamic raw);
+
+@protected List<TopicHealthDto> dco_decode_list_topic_health_dto(dynamic raw);
+
+@protected List<TopicMetricsDto> dco_decode_list_topic_metrics_dto(dynamic raw);
+
+@protected List<TopicStatsDto> dco_decode_list_topic_stats_dto(dynamic raw);
+
+@protected LogEntry dco_decode_log_entry(dynamic raw);
+
+@protected LogFilterDto dco_decode_log_filter_dto(dynamic raw);
+
+@protected MetricsDto dco_decode_metrics_dto(dynamic raw);
+
+@protected MqttBridgeConfigDto dco_decode_mqtt_bridge_config_dto(dynamic raw);
+
+@protected MqttDirectionDto dco_decode_mqtt_direction_dto(dynamic raw);
+
+@protected MqttTopicMappingDto dco_decode_mqtt_topic_mapping_dto(dynamic raw);
+
+@protected NodeAddrDto dco_decode_node_addr_dto(dynamic raw);
+
+@protected NodeConfigDto dco_decode_node_config_dto(dynamic raw);
+
+@protected NodeEventDto dco_decode_node_event_dto(dynamic raw);
+
+@protected NodeEventRecordDto dco_decode_node_event_record_dto(dynamic raw);
+
+@protected NodeInfo dco_decode_node_info(dynamic raw);
+
+@protected NodeInstanceDto dco_decode_node_instance_dto(dynamic raw);
+
+@protected NodeStatsDto dco_decode_node_stats_dto(dynamic raw);
+
+@protected NodeStatusDto dco_decode_node_status_dto(dynamic raw);
+
+@protected OplogGcDto dco_decode_oplog_gc_dto(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected BootstrapManifestDto? dco_decode_opt_box_autoadd_bootstrap_manifest_dto(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected MqttBridgeConfigDto? dco_decode_opt_box_autoadd_mqtt_bridge_config_dto(dynamic raw);
+
+@protected NodeInfo? dco_decode_opt_box_autoadd_node_info(dynamic raw);
+
+@protected OplogGcDto? dco_decode_opt_box_autoadd_oplog_gc_dto(dynamic raw);
+
+@protected PeerPathDto? dco_decode_opt_box_autoadd_peer_path_dto(dynamic raw);
+
+@protected PresenceDto? dco_decode_opt_box_autoadd_presence_dto(dynamic raw);
+
+@protected SuccessorRecordDto? dco_decode_opt_box_autoadd_successor_record_dto(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected PeerCircuitDto dco_decode_peer_circuit_dto(dynamic raw);
+
+@protected PeerDiagnosticsDto dco_decode_peer_diagnostics_dto(dynamic raw);
+
+@protected PeerInfoDto dco_decode_peer_info_dto(dynamic raw);
+
+@protected PeerLatencyDto dco_decode_peer_latency_dto(dynamic raw);
+
+@protected PeerPathDto dco_decode_peer_path_dto(dynamic raw);
+
+@protected PeerSyncStatusDto dco_decode_peer_sync_status_dto(dynamic raw);
+
+@protected PinnedPeerDto dco_decode_pinned_peer_dto(dynamic raw);
+
+@protected PowerModeDto dco_decode_power_mode_dto(dynamic raw);
+
+@protected PowerPolicyDto dco_decode_power_policy_dto(dynamic raw);
+
+@protected PresenceDto dco_decode_presence_dto(dynamic raw);
+
+@protected QueryFilterDto dco_decode_query_filter_dto(dynamic raw);
+
+@protected QueryResultDto dco_decode_query_result_dto(dynamic raw);
+
+@protected QuerySpecDto dco_decode_query_spec_dto(dynamic raw);
+
+@protected RekeyedDatabaseDto dco_decode_rekeyed_database_dto(dynamic raw);
+
+@protected RelayConfigDto dco_decode_relay_config_dto(dynamic raw);
+
+@protected RelayStatusDto dco_decode_relay_status_dto(dynamic raw);
+
+@protected ReplicaCheckDto dco_decode_replica_check_dto(dynamic raw);
+
+@protected RpcRequestDto dco_decode_rpc_request_dto(dynamic raw);
+
+@protected ScanPageDto dco_decode_scan_page_dto(dynamic raw);
+
+@protected ScoredMemberDto dco_decode_scored_member_dto(dynamic raw);
+
+@protected StartupStageDto dco_decode_startup_stage_dto(dynamic raw);
+
+@protected SubsystemHealthDto dco_decode_subsystem_health_dto(dynamic raw);
+
+@protected SuccessorRecordDto dco_decode_successor_record_dto(dynamic raw);
+
+@protected SyncStatsDto dco_decode_sync_stats_dto(dynamic raw);
+
+@protected SyncWaitSummaryDto dco_decode_sync_wait_summary_dto(dynamic raw);
+
+@protected TextDeltaDto dco_decode_text_delta_dto(dynamic raw);
+
+@protected TimePointDto dco_decode_time_point_dto(dynamic raw);
+
+@protected TopicHealthDto dco_decode_topic_health_dto(dynamic raw);
+
+@protected TopicMessageDto dco_decode_topic_message_dto(dynamic raw);
+
+@protected TopicMetricsDto dco_decode_topic_metrics_dto(dynamic raw);
+
+@protected TopicStatsDto dco_decode_topic_stats_dto(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected ValueUpdateDto dco_decode_value_update_dto(dynamic raw);
+
+@protected VerifiedBindingDto dco_decode_verified_binding_dto(dynamic raw);
+
+@protected WakeSyncSummaryDto dco_decode_wake_sync_summary_dto(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected RustStreamSink<ChangeEventDto> sse_decode_StreamSink_change_event_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<DirectMessageDto> sse_decode_StreamSink_direct_message_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<FileEventDto> sse_decode_StreamSink_file_event_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<KeyChangeDto> sse_decode_StreamSink_key_change_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<NodeEventRecordDto> sse_decode_StreamSink_node_event_record_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<RpcRequestDto> sse_decode_StreamSink_rpc_request_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<StartupStageDto> sse_decode_StreamSink_startup_stage_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<TextDeltaDto> sse_decode_StreamSink_text_delta_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<TopicMessageDto> sse_decode_StreamSink_topic_message_dto_Dco(SseDeserializer deserializer);
+
+@protected RustStreamSink<ValueUpdateDto> sse_decode_StreamSink_value_update_dto_Dco(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AcceptedInviteDto sse_decode_accepted_invite_dto(SseDeserializer deserializer);
+
+@protected BackupInfoDto sse_decode_backup_info_dto(SseDeserializer deserializer);
+
+@protected BandwidthCapsDto sse_decode_bandwidth_caps_dto(SseDeserializer deserializer);
+
+@protected BandwidthStatsDto sse_decode_bandwidth_stats_dto(SseDeserializer deserializer);
+
+@protected BandwidthSubsystemDto sse_decode_bandwidth_subsystem_dto(SseDeserializer deserializer);
+
+@protected BandwidthUsageDto sse_decode_bandwidth_usage_dto(SseDeserializer deserializer);
+
+@protected BatchEntryDto sse_decode_batch_entry_dto(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected BootstrapManifestDto sse_decode_bootstrap_manifest_dto(SseDeserializer deserializer);
+
+@protected BandwidthCapsDto sse_decode_box_autoadd_bandwidth_caps_dto(SseDeserializer deserializer);
+
+@protected BootstrapManifestDto sse_decode_box_autoadd_bootstrap_manifest_dto(SseDeserializer deserializer);
+
+@protected DeviceConditionsDto sse_decode_box_autoadd_device_conditions_dto(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected FileOfferDto sse_decode_box_autoadd_file_offer_dto(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected LogFilterDto sse_decode_box_autoadd_log_filter_dto(SseDeserializer deserializer);
+
+@protected MqttBridgeConfigDto sse_decode_box_autoadd_mqtt_bridge_config_dto(SseDeserializer deserializer);
+
+@protected NodeConfigDto sse_decode_box_autoadd_node_config_dto(SseDeserializer deserializer);
+
+@protected NodeInfo sse_decode_box_autoadd_node_info(SseDeserializer deserializer);
+
+@protected OplogGcDto sse_decode_box_autoadd_oplog_gc_dto(SseDeserializer deserializer);
+
+@protected PeerPathDto sse_decode_box_autoadd_peer_path_dto(SseDeserializer deserializer);
+
+@protected PresenceDto sse_decode_box_autoadd_presence_dto(SseDeserializer deserializer);
+
+@protected QuerySpecDto sse_decode_box_autoadd_query_spec_dto(SseDeserializer deserializer);
+
+@protected SuccessorRecordDto sse_decode_box_autoadd_successor_record_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected ChangeEventDto sse_decode_change_event_dto(SseDeserializer deserializer);
+
+@protected CheckpointDto sse_decode_checkpoint_dto(SseDeserializer deserializer);
+
+@protected ConflictDto sse_decode_conflict_dto(SseDeserializer deserializer);
+
+@protected ConnectionDiagnosticsDto sse_decode_connection_diagnostics_dto(SseDeserializer deserializer);
+
+@protected ConnectionResultDto sse_decode_connection_result_dto(SseDeserializer deserializer);
+
+@protected ConnectionTypeDto sse_decode_connection_type_dto(SseDeserializer deserializer);
+
+@protected ConnectivityDto sse_decode_connectivity_dto(SseDeserializer deserializer);
+
+@protected ConnectivityStateDto sse_decode_connectivity_state_dto(SseDeserializer deserializer);
+
+@protected DbEntryDto sse_decode_db_entry_dto(SseDeserializer deserializer);
+
+@protected DbSyncInfoDto sse_decode_db_sync_info_dto(SseDeserializer deserializer);
+
+@protected DebugServerDto sse_decode_debug_server_dto(SseDeserializer deserializer);
+
+@protected DeviceConditionsDto sse_decode_device_conditions_dto(SseDeserializer deserializer);
+
+@protected DirectMessageDto sse_decode_direct_message_dto(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FileEventDto sse_decode_file_event_dto(SseDeserializer deserializer);
+
+@protected FileOfferDto sse_decode_file_offer_dto(SseDeserializer deserializer);
+
+@protected GeoMatchDto sse_decode_geo_match_dto(SseDeserializer deserializer);
+
+@protected HashEntryDto sse_decode_hash_entry_dto(SseDeserializer deserializer);
+
+@protected HealthReportDto sse_decode_health_report_dto(SseDeserializer deserializer);
+
+@protected HealthStatusDto sse_decode_health_status_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IdentityRotationDto sse_decode_identity_rotation_dto(SseDeserializer deserializer);
+
+@protected IntegrityIssueDto sse_decode_integrity_issue_dto(SseDeserializer deserializer);
+
+@protected IntegrityReportDto sse_decode_integrity_report_dto(SseDeserializer deserializer);
+
+@protected KadenaTransactionDto sse_decode_kadena_transaction_dto(SseDeserializer deserializer);
+
+@protected KeyChangeDto sse_decode_key_change_dto(SseDeserializer deserializer);
+
+@protected KeyPairDto sse_decode_key_pair_dto(SseDeserializer deserializer);
+
+@protected LatencyBucketDto sse_decode_latency_bucket_dto(SseDeserializer deserializer);
+
+@protected LinkedDeviceDto sse_decode_linked_device_dto(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<BandwidthUsageDto> sse_decode_list_bandwidth_usage_dto(SseDeserializer deserializer);
+
+@protected List<BatchEntryDto> sse_decode_list_batch_entry_dto(SseDeserializer deserializer);
+
+@protected List<ConflictDto> sse_decode_list_conflict_dto(SseDeserializer deserializer);
+
+@protected List<DbEntryDto> sse_decode_list_db_entry_dto(SseDeserializer deserializer);
+
+@protected List<DirectMessageDto> sse_decode_list_direct_message_dto(SseDeserializer deserializer);
+
+@protected List<FileOfferDto> sse_decode_list_file_offer_dto(SseDeserializer deserializer);
+
+@protected List<GeoMatchDto> sse_decode_list_geo_match_dto(SseDeserializer deserializer);
+
+@protected List<HashEntryDto> sse_decode_list_hash_entry_dto(SseDeserializer deserializer);
+
+@protected List<IntegrityIssueDto> sse_decode_list_integrity_issue_dto(SseDeserializer deserializer);
+
+@protected List<LatencyBucketDto> sse_decode_list_latency_bucket_dto(SseDeserializer deserializer);
+
+@protected List<LinkedDeviceDto> sse_decode_list_linked_device_dto(SseDeserializer deserializer);
+
+@protected List<LogEntry> sse_decode_list_log_entry(SseDeserializer deserializer);
+
+@protected List<MqttTopicMappingDto> sse_decode_list_mqtt_topic_mapping_dto(SseDeserializer deserializer);
+
+@protected List<NodeEventRecordDto> sse_decode_list_node_event_record_dto(SseDeserializer deserializer);
+
+@protected List<Uint8List?> sse_decode_list_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<PeerCircuitDto> sse_decode_list_peer_circuit_dto(SseDeserializer deserializer);
+
+@protected List<PeerDiagnosticsDto> sse_decode_list_peer_diagnostics_dto(SseDeserializer deserializer);
+
+@protected List<PeerInfoDto> sse_decode_list_peer_info_dto(SseDeserializer deserializer);
+
+@protected List<PeerLatencyDto> sse_decode_list_peer_latency_dto(SseDeserializer deserializer);
+
+@protected List<PeerPathDto> sse_decode_list_peer_path_dto(SseDeserializer deserializer);
+
+@protected List<PeerSyncStatusDto> sse_decode_list_peer_sync_status_dto(SseDeserializer deserializer);
+
+@protected List<PinnedPeerDto> sse_decode_list_pinned_peer_dto(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QueryFilterDto> sse_decode_list_query_filter_dto(SseDeserializer deserializer);
+
+@protected List<RekeyedDatabaseDto> sse_decode_list_rekeyed_database_dto(SseDeserializer deserializer);
+
+@protected List<ReplicaCheckDto> sse_decode_list_replica_check_dto(SseDeserializer deserializer);
+
+@protected List<ScoredMemberDto> sse_decode_list_scored_member_dto(SseDeserializer deserializer);
+
+@protected List<TimePointDto> sse_decode_list_time_point_dto(SseDeserializer deserializer);
+
+@protected List<TopicHealthDto> sse_decode_list_topic_health_dto(SseDeserializer deserializer);
+
+@protected List<TopicMetricsDto> sse_decode_list_topic_metrics_dto(SseDeserializer deserializer);
+
+@protected List<TopicStatsDto> sse_decode_list_topic_stats_dto(SseDeserializer deserializer);
+
+@protected LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+@protected LogFilterDto sse_decode_log_filter_dto(SseDeserializer deserializer);
+
+@protected MetricsDto sse_decode_metrics_dto(SseDeserializer deserializer);
+
+@protected MqttBridgeConfigDto sse_decode_mqtt_bridge_config_dto(SseDeserializer deserializer);
+
+@protected MqttDirectionDto sse_decode_mqtt_direction_dto(SseDeserializer deserializer);
+
+@protected MqttTopicMappingDto sse_decode_mqtt_topic_mapping_dto(SseDeserializer deserializer);
+
+@protected NodeAddrDto sse_decode_node_addr_dto(SseDeserializer deserializer);
+
+@protected NodeConfigDto sse_decode_node_config_dto(SseDeserializer deserializer);
+
+@protected NodeEventDto sse_decode_node_event_dto(SseDeserializer deserializer);
+
+@protected NodeEventRecordDto sse_decode_node_event_record_dto(SseDeserializer deserializer);
+
+@protected NodeInfo sse_decode_node_info(SseDeserializer deserializer);
+
+@protected NodeInstanceDto sse_decode_node_instance_dto(SseDeserializer deserializer);
+
+@protected NodeStatsDto sse_decode_node_stats_dto(SseDeserializer deserializer);
+
+@protected NodeStatusDto sse_decode_node_status_dto(SseDeserializer deserializer);
+
+@protected OplogGcDto sse_decode_oplog_gc_dto(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected BootstrapManifestDto? sse_decode_opt_box_autoadd_bootstrap_manifest_dto(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected MqttBridgeConfigDto? sse_decode_opt_box_autoadd_mqtt_bridge_config_dto(SseDeserializer deserializer);
+
+@protected NodeInfo? sse_decode_opt_box_autoadd_node_info(SseDeserializer deserializer);
+
+@protected OplogGcDto? sse_decode_opt_box_autoadd_oplog_gc_dto(SseDeserializer deserializer);
+
+@protected PeerPathDto? sse_decode_opt_box_autoadd_peer_path_dto(SseDeserializer deserializer);
+
+@protected PresenceDto? sse_decode_opt_box_autoadd_presence_dto(SseDeserializer deserializer);
+
+@protected SuccessorRecordDto? sse_decode_opt_box_autoadd_successor_record_dto(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PeerCircuitDto sse_decode_peer_circuit_dto(SseDeserializer deserializer);
+
+@protected PeerDiagnosticsDto sse_decode_peer_diagnostics_dto(SseDeserializer deserializer);
+
+@protected PeerInfoDto sse_decode_peer_info_dto(SseDeserializer deserializer);
+
+@protected PeerLatencyDto sse_decode_peer_latency_dto(SseDeserializer deserializer);
+
+@protected PeerPathDto sse_decode_peer_path_dto(SseDeserializer deserializer);
+
+@protected PeerSyncStatusDto sse_decode_peer_sync_status_dto(SseDeserializer deserializer);
+
+@protected PinnedPeerDto sse_decode_pinned_peer_dto(SseDeserializer deserializer);
+
+@protected PowerModeDto sse_decode_power_mode_dto(SseDeserializer deserializer);
+
+@protected PowerPolicyDto sse_decode_power_policy_dto(SseDeserializer deserializer);
+
+@protected PresenceDto sse_decode_presence_dto(SseDeserializer deserializer);
+
+@protected QueryFilterDto sse_decode_query_filter_dto(SseDeserializer deserializer);
+
+@protected QueryResultDto sse_decode_query_result_dto(SseDeserializer deserializer);
+
+@protected QuerySpecDto sse_decode_query_spec_dto(SseDeserializer deserializer);
+
+@protected RekeyedDatabaseDto sse_decode_rekeyed_database_dto(SseDeserializer deserializer);
+
+@protected RelayConfigDto sse_decode_relay_config_dto(SseDeserializer deserializer);
+
+@protected RelayStatusDto sse_decode_relay_status_dto(SseDeserializer deserializer);
+
+@protected ReplicaCheckDto sse_decode_replica_check_dto(SseDeserializer deserializer);
+
+@protected RpcRequestDto sse_decode_rpc_request_dto(SseDeserializer deserializer);
+
+@protected ScanPageDto sse_decode_scan_page_dto(SseDeserializer deserializer);
+
+@protected ScoredMemberDto sse_decode_scored_member_dto(SseDeserializer deserializer);
+
+@protected StartupStageDto sse_decode_startup_stage_dto(SseDeserializer deserializer);
+
+@protected SubsystemHealthDto sse_decode_subsystem_health_dto(SseDeserializer deserializer);
+
+@protected SuccessorRecordDto sse_decode_successor_record_dto(SseDeserializer deserializer);
+
+@protected SyncStatsDto sse_decode_sync_stats_dto(SseDeserializer deserializer);
+
+@protected SyncWaitSummaryDto sse_decode_sync_wait_summary_dto(SseDeserializer deserializer);
+
+@protected TextDeltaDto sse_decode_text_delta_dto(SseDeserializer deserializer);
+
+@protected TimePointDto sse_decode_time_point_dto(SseDeserializer deserializer);
+
+@protected TopicHealthDto sse_decode_topic_health_dto(SseDeserializer deserializer);
+
+@protected TopicMessageDto sse_decode_topic_message_dto(SseDeserializer deserializer);
+
+@protected TopicMetricsDto sse_decode_topic_metrics_dto(SseDeserializer deserializer);
+
+@protected TopicStatsDto sse_decode_topic_stats_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected ValueUpdateDto sse_decode_value_update_dto(SseDeserializer deserializer);
+
+@protected VerifiedBindingDto sse_decode_verified_binding_dto(SseDeserializer deserializer);
+
+@protected WakeSyncSummaryDto sse_decode_wake_sync_summary_dto(SseDeserializer deserializer);
+
+@protected String cst_encode_AnyhowException(AnyhowException raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String cst_encode_StreamSink_change_event_dto_Dco(RustStreamSink<ChangeEventDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_change_event_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_direct_message_dto_Dco(RustStreamSink<DirectMessageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_direct_message_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_file_event_dto_Dco(RustStreamSink<FileEventDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_file_event_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_key_change_dto_Dco(RustStreamSink<KeyChangeDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_key_change_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_node_event_record_dto_Dco(RustStreamSink<NodeEventRecordDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_node_event_record_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_rpc_request_dto_Dco(RustStreamSink<RpcRequestDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_rpc_request_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_startup_stage_dto_Dco(RustStreamSink<StartupStageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_startup_stage_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_text_delta_dto_Dco(RustStreamSink<TextDeltaDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_text_delta_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_topic_message_dto_Dco(RustStreamSink<TopicMessageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_topic_message_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_StreamSink_value_update_dto_Dco(RustStreamSink<ValueUpdateDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_String(raw.setupAndSerialize(codec: DcoCodec(
+            decodeSuccessData: dco_decode_value_update_dto,
+            decodeErrorData: dco_decode_AnyhowException,
+        ))); }
+
+@protected String cst_encode_String(String raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw; }
+
+@protected JSAny cst_encode_accepted_invite_dto(AcceptedInviteDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_String(raw.owner),cst_encode_bool(raw.canWrite),cst_encode_opt_String(raw.writerSecretKey),cst_encode_bool(raw.encrypted)].jsify()!; }
+
+@protected JSAny cst_encode_backup_info_dto(BackupInfoDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_i_64(raw.createdAt),cst_encode_String(raw.publicKey),cst_encode_u_32(raw.treeCount),cst_encode_u_64(raw.entryCount)].jsify()!; }
+
+@protected JSAny cst_encode_bandwidth_caps_dto(BandwidthCapsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_box_autoadd_u_64(raw.dailyBytes),cst_encode_opt_box_autoadd_u_64(raw.monthlyBytes),cst_encode_bool(raw.meteredOnly)].jsify()!; }
+
+@protected JSAny cst_encode_bandwidth_stats_dto(BandwidthStatsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_list_bandwidth_usage_dto(raw.topics),cst_encode_list_bandwidth_usage_dto(raw.subsystems),cst_encode_u_64(raw.todayBytes),cst_encode_u_64(raw.monthBytes),cst_encode_bandwidth_caps_dto(raw.caps),cst_encode_bool(raw.capExceeded)].jsify()!; }
+
+@protected JSAny cst_encode_bandwidth_usage_dto(BandwidthUsageDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.name),cst_encode_bandwidth_subsystem_dto(raw.subsystem),cst_encode_u_64(raw.bytesSent),cst_encode_u_64(raw.bytesReceived)].jsify()!; }
+
+@protected JSAny cst_encode_batch_entry_dto(BatchEntryDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_list_prim_u_8_strict(raw.value),cst_encode_String(raw.publicKey),cst_encode_String(raw.signature)].jsify()!; }
+
+@protected JSAny cst_encode_bootstrap_manifest_dto(BootstrapManifestDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.url),cst_encode_String(raw.publisherKey)].jsify()!; }
+
+@protected JSAny cst_encode_box_autoadd_bandwidth_caps_dto(BandwidthCapsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_bandwidth_caps_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_bootstrap_manifest_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_device_conditions_dto(DeviceConditionsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_device_conditions_dto(raw); }
+
+@protected double cst_encode_box_autoadd_f_64(double raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_f_64(raw); }
+
+@protected JSAny cst_encode_box_autoadd_file_offer_dto(FileOfferDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_file_offer_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_i_64(PlatformInt64 raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_i_64(raw); }
+
+@protected JSAny cst_encode_box_autoadd_log_filter_dto(LogFilterDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_log_filter_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_mqtt_bridge_config_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_node_config_dto(NodeConfigDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_node_config_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_node_info(NodeInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_node_info(raw); }
+
+@protected JSAny cst_encode_box_autoadd_oplog_gc_dto(OplogGcDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_oplog_gc_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_peer_path_dto(PeerPathDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_peer_path_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_presence_dto(PresenceDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_presence_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_query_spec_dto(QuerySpecDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_query_spec_dto(raw); }
+
+@protected JSAny cst_encode_box_autoadd_successor_record_dto(SuccessorRecordDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_successor_record_dto(raw); }
+
+@protected int cst_encode_box_autoadd_u_16(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_u_16(raw); }
+
+@protected int cst_encode_box_autoadd_u_32(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_u_32(raw); }
+
+@protected JSAny cst_encode_box_autoadd_u_64(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_u_64(raw); }
+
+@protected int cst_encode_box_autoadd_u_8(int raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return cst_encode_u_8(raw); }
+
+@protected JSAny cst_encode_change_event_dto(ChangeEventDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_64(raw.seq),cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_opt_String(raw.value),cst_encode_opt_list_prim_u_8_strict(raw.valueBytes)].jsify()!; }
+
+@protected JSAny cst_encode_checkpoint_dto(CheckpointDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_box_autoadd_i_64(raw.highWaterMark),cst_encode_u_32(raw.knownPeersSaved),cst_encode_u_32(raw.outboxDepth),cst_encode_bool(raw.flushed),cst_encode_u_64(raw.durationMs)].jsify()!; }
+
+@protected JSAny cst_encode_conflict_dto(ConflictDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.conflictKey),cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_opt_String(raw.field),cst_encode_String(raw.keptOpId),cst_encode_String(raw.discardedOpId),cst_encode_String(raw.discardedValue),cst_encode_i_64(raw.discardedTimestamp),cst_encode_i_64(raw.resolvedAt)].jsify()!; }
+
+@protected JSAny cst_encode_connection_diagnostics_dto(ConnectionDiagnosticsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_list_String(raw.localAddrs),cst_encode_list_String(raw.publicAddrs),cst_encode_opt_String(raw.relayUrl),cst_encode_String(raw.natType),cst_encode_list_peer_diagnostics_dto(raw.peers)].jsify()!; }
+
+@protected JSAny cst_encode_connection_result_dto(ConnectionResultDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_String(raw.path),cst_encode_u_64(raw.handshakeMs)].jsify()!; }
+
+@protected JSAny cst_encode_connectivity_dto(ConnectivityDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_connectivity_state_dto(raw.state),cst_encode_String(raw.reason)].jsify()!; }
+
+@protected JSAny cst_encode_db_entry_dto(DbEntryDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_String(raw.value),cst_encode_list_prim_u_8_strict(raw.valueBytes)].jsify()!; }
+
+@protected JSAny cst_encode_db_sync_info_dto(DbSyncInfoDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_u_64(raw.opCount),cst_encode_opt_box_autoadd_i_64(raw.lastLocalWrite),cst_encode_opt_box_autoadd_i_64(raw.lastRemoteOp),cst_encode_opt_box_autoadd_i_64(raw.lastSync)].jsify()!; }
+
+@protected JSAny cst_encode_debug_server_dto(DebugServerDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.address),cst_encode_String(raw.token)].jsify()!; }
+
+@protected JSAny cst_encode_device_conditions_dto(DeviceConditionsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_box_autoadd_u_8(raw.batteryPercent),cst_encode_bool(raw.charging),cst_encode_connection_type_dto(raw.connectionType),cst_encode_bool(raw.metered)].jsify()!; }
+
+@protected JSAny cst_encode_direct_message_dto(DirectMessageDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.id),cst_encode_String(raw.peerId),cst_encode_bool(raw.outgoing),cst_encode_String(raw.text),cst_encode_i_64(raw.sentAt)].jsify()!; }
+
+@protected JSAny cst_encode_file_event_dto(FileEventDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+if (raw is FileEventDto_Offer) {
+            return [0 ,cst_encode_box_autoadd_file_offer_dto(raw.field0)].jsify()!;
+        }
+if (raw is FileEventDto_Progress) {
+            return [1 ,cst_encode_String(raw.offerId),cst_encode_u_64(raw.receivedBytes),cst_encode_u_64(raw.totalBytes)].jsify()!;
+        }
+if (raw is FileEventDto_Retrying) {
+            return [2 ,cst_encode_String(raw.offerId),cst_encode_u_32(raw.attempt),cst_encode_String(raw.error)].jsify()!;
+        }
+if (raw is FileEventDto_Completed) {
+            return [3 ,cst_encode_String(raw.offerId),cst_encode_String(raw.path)].jsify()!;
+        }
+if (raw is FileEventDto_Failed) {
+            return [4 ,cst_encode_String(raw.offerId),cst_encode_String(raw.error)].jsify()!;
+        }
+
+                throw Exception('unreachable'); }
+
+@protected JSAny cst_encode_file_offer_dto(FileOfferDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.offerId),cst_encode_String(raw.from),cst_encode_String(raw.name),cst_encode_u_64(raw.size),cst_encode_i_64(raw.offeredAt)].jsify()!; }
+
+@protected JSAny cst_encode_geo_match_dto(GeoMatchDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.member),cst_encode_f_64(raw.latitude),cst_encode_f_64(raw.longitude),cst_encode_f_64(raw.distanceM)].jsify()!; }
+
+@protected JSAny cst_encode_hash_entry_dto(HashEntryDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.field),cst_encode_String(raw.value)].jsify()!; }
+
+@protected JSAny cst_encode_health_report_dto(HealthReportDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_subsystem_health_dto(raw.storage),cst_encode_subsystem_health_dto(raw.gossip),cst_encode_subsystem_health_dto(raw.sync_),cst_encode_subsystem_health_dto(raw.discovery),cst_encode_subsystem_health_dto(raw.relay),cst_encode_list_topic_health_dto(raw.topics),cst_encode_opt_box_autoadd_i_64(raw.lastFlushAt),cst_encode_opt_box_autoadd_i_64(raw.lastSyncAt),cst_encode_opt_box_autoadd_i_64(raw.lastAnnouncementAt)].jsify()!; }
+
+@protected JSAny cst_encode_i_64(PlatformInt64 raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return castNativeBigInt(raw); }
+
+@protected JSAny cst_encode_identity_rotation_dto(IdentityRotationDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_node_info(raw.node),cst_encode_successor_record_dto(raw.record),cst_encode_list_rekeyed_database_dto(raw.rekeyedDatabases),cst_encode_u_32(raw.rekeyedOperations)].jsify()!; }
+
+@protected JSAny cst_encode_integrity_issue_dto(IntegrityIssueDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_String(raw.opId),cst_encode_String(raw.kind)].jsify()!; }
+
+@protected JSAny cst_encode_integrity_report_dto(IntegrityReportDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_64(raw.checked),cst_encode_list_integrity_issue_dto(raw.issues),cst_encode_u_64(raw.repaired)].jsify()!; }
+
+@protected JSAny cst_encode_kadena_transaction_dto(KadenaTransactionDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.hash),cst_encode_String(raw.sig),cst_encode_String(raw.cmd),cst_encode_String(raw.json)].jsify()!; }
+
+@protected JSAny cst_encode_key_change_dto(KeyChangeDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_bool(raw.deleted)].jsify()!; }
+
+@protected JSAny cst_encode_key_pair_dto(KeyPairDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.publicKey),cst_encode_String(raw.secretKey)].jsify()!; }
+
+@protected JSAny cst_encode_latency_bucket_dto(LatencyBucketDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_box_autoadd_f_64(raw.leMs),cst_encode_u_64(raw.count)].jsify()!; }
+
+@protected JSAny cst_encode_linked_device_dto(LinkedDeviceDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.userPublicKey),cst_encode_String(raw.nodeId),cst_encode_String(raw.deviceName),cst_encode_i_64(raw.linkedAt),cst_encode_bool(raw.thisDevice)].jsify()!; }
+
+@protected JSAny cst_encode_list_String(List<String> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_String).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_bandwidth_usage_dto(List<BandwidthUsageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_bandwidth_usage_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_batch_entry_dto(List<BatchEntryDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_batch_entry_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_conflict_dto(List<ConflictDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_conflict_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_db_entry_dto(List<DbEntryDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_db_entry_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_direct_message_dto(List<DirectMessageDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_direct_message_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_file_offer_dto(List<FileOfferDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_file_offer_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_geo_match_dto(List<GeoMatchDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_geo_match_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_hash_entry_dto(List<HashEntryDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_hash_entry_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_integrity_issue_dto(List<IntegrityIssueDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_integrity_issue_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_latency_bucket_dto(List<LatencyBucketDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_latency_bucket_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_linked_device_dto(List<LinkedDeviceDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_linked_device_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_log_entry(List<LogEntry> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_log_entry).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_mqtt_topic_mapping_dto(List<MqttTopicMappingDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_mqtt_topic_mapping_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_node_event_record_dto(List<NodeEventRecordDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_node_event_record_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_opt_list_prim_u_8_strict(List<Uint8List?> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_opt_list_prim_u_8_strict).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_peer_circuit_dto(List<PeerCircuitDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_peer_circuit_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_peer_diagnostics_dto(List<PeerDiagnosticsDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_peer_diagnostics_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_peer_info_dto(List<PeerInfoDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_peer_info_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_peer_latency_dto(List<PeerLatencyDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_peer_latency_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_peer_path_dto(List<PeerPathDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_peer_path_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_peer_sync_status_dto(List<PeerSyncStatusDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_peer_sync_status_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_pinned_peer_dto(List<PinnedPeerDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_pinned_peer_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_prim_u_32_strict(Uint32List raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.jsify()!; }
+
+@protected JSAny cst_encode_list_prim_u_8_loose(List<int> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.jsify()!; }
+
+@protected JSAny cst_encode_list_prim_u_8_strict(Uint8List raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.jsify()!; }
+
+@protected JSAny cst_encode_list_query_filter_dto(List<QueryFilterDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_query_filter_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_rekeyed_database_dto(List<RekeyedDatabaseDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_rekeyed_database_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_replica_check_dto(List<ReplicaCheckDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_replica_check_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_scored_member_dto(List<ScoredMemberDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_scored_member_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_time_point_dto(List<TimePointDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_time_point_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_topic_health_dto(List<TopicHealthDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_topic_health_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_topic_metrics_dto(List<TopicMetricsDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_topic_metrics_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_list_topic_stats_dto(List<TopicStatsDto> raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw.map(cst_encode_topic_stats_dto).toList().jsify()!; }
+
+@protected JSAny cst_encode_log_entry(LogEntry raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_i_64(raw.timestamp),cst_encode_String(raw.level),cst_encode_String(raw.message)].jsify()!; }
+
+@protected JSAny cst_encode_log_filter_dto(LogFilterDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_String(raw.minLevel),cst_encode_opt_String(raw.target),cst_encode_opt_String(raw.contains),cst_encode_opt_box_autoadd_i_64(raw.since),cst_encode_opt_box_autoadd_i_64(raw.until),cst_encode_opt_box_autoadd_u_32(raw.limit)].jsify()!; }
+
+@protected JSAny cst_encode_metrics_dto(MetricsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_list_topic_metrics_dto(raw.topics),cst_encode_u_64(raw.opsApplied),cst_encode_u_64(raw.verifyFailures),cst_encode_u_64(raw.limitViolations),cst_encode_list_latency_bucket_dto(raw.connectLatency),cst_encode_f_64(raw.connectLatencySumMs),cst_encode_u_64(raw.storageSizeBytes),cst_encode_u_64(raw.storageKeys),cst_encode_u_64(raw.storedOperations),cst_encode_u_64(raw.outboxDepth)].jsify()!; }
+
+@protected JSAny cst_encode_mqtt_bridge_config_dto(MqttBridgeConfigDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.host),cst_encode_opt_box_autoadd_u_16(raw.port),cst_encode_String(raw.clientId),cst_encode_opt_String(raw.username),cst_encode_opt_String(raw.password),cst_encode_list_mqtt_topic_mapping_dto(raw.mappings)].jsify()!; }
+
+@protected JSAny cst_encode_mqtt_topic_mapping_dto(MqttTopicMappingDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.topicPrefix),cst_encode_String(raw.dbName),cst_encode_mqtt_direction_dto(raw.direction)].jsify()!; }
+
+@protected JSAny cst_encode_node_addr_dto(NodeAddrDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_list_String(raw.directAddresses),cst_encode_list_String(raw.relayUrls)].jsify()!; }
+
+@protected JSAny cst_encode_node_config_dto(NodeConfigDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_String(raw.region),cst_encode_opt_String(raw.networkId),cst_encode_u_32(raw.maxKnownPeerDials),cst_encode_u_64(raw.announceIntervalSecs),cst_encode_u_64(raw.latencySweepIntervalSecs),cst_encode_u_64(raw.pinnedPeersCheckIntervalSecs),cst_encode_u_64(raw.isolationCheckIntervalSecs),cst_encode_u_32(raw.isolationChecksBeforeReconnect),cst_encode_u_32(raw.maxOpsPerResponse),cst_encode_u_32(raw.maxConnectionAttemptsPerCycle),cst_encode_u_64(raw.dialIntervalMs),cst_encode_relay_config_dto(raw.relay),cst_encode_opt_box_autoadd_bootstrap_manifest_dto(raw.bootstrapManifest),cst_encode_u_32(raw.maxValueBytes),cst_encode_u_32(raw.maxKeyLength),cst_encode_list_String(raw.allowedStoreTypes),cst_encode_opt_box_autoadd_oplog_gc_dto(raw.oplogGc),cst_encode_u_32(raw.maxGossipMessageSize)].jsify()!; }
+
+@protected JSAny cst_encode_node_event_dto(NodeEventDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+if (raw is NodeEventDto_Started) {
+            return [0 ,cst_encode_String(raw.nodeId),cst_encode_String(raw.publicKey)].jsify()!;
+        }
+if (raw is NodeEventDto_Stopped) {
+            return [1 ].jsify()!;
+        }
+if (raw is NodeEventDto_PeerConnected) {
+            return [2 ,cst_encode_String(raw.peerId)].jsify()!;
+        }
+if (raw is NodeEventDto_PeerDisconnected) {
+            return [3 ,cst_encode_String(raw.peerId)].jsify()!;
+        }
+if (raw is NodeEventDto_PeerDiscovered) {
+            return [4 ,cst_encode_String(raw.peerId),cst_encode_opt_String(raw.address)].jsify()!;
+        }
+if (raw is NodeEventDto_GossipReceived) {
+            return [5 ,cst_encode_String(raw.topic),cst_encode_String(raw.from),cst_encode_String(raw.content),cst_encode_bool(raw.verified)].jsify()!;
+        }
+if (raw is NodeEventDto_SyncReceived) {
+            return [6 ,cst_encode_String(raw.dbName),cst_encode_String(raw.key)].jsify()!;
+        }
+if (raw is NodeEventDto_SyncProgress) {
+            return [7 ,cst_encode_String(raw.dbName),cst_encode_u_64(raw.opsReceived),cst_encode_u_64(raw.opsTotalEstimate),cst_encode_u_64(raw.bytes)].jsify()!;
+        }
+if (raw is NodeEventDto_ConflictResolved) {
+            return [8 ,cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_String(raw.keptOpId),cst_encode_String(raw.discardedOpId)].jsify()!;
+        }
+if (raw is NodeEventDto_LatencyMeasured) {
+            return [9 ,cst_encode_String(raw.peerId),cst_encode_u_64(raw.latencyMs)].jsify()!;
+        }
+if (raw is NodeEventDto_PresenceChanged) {
+            return [10 ,cst_encode_String(raw.peerId),cst_encode_bool(raw.online),cst_encode_opt_String(raw.status)].jsify()!;
+        }
+if (raw is NodeEventDto_TopicRecovered) {
+            return [11 ,cst_encode_String(raw.topic)].jsify()!;
+        }
+if (raw is NodeEventDto_RelayConnected) {
+            return [12 ,cst_encode_String(raw.relayUrl)].jsify()!;
+        }
+if (raw is NodeEventDto_BootstrapConnected) {
+            return [13 ,cst_encode_String(raw.peerId)].jsify()!;
+        }
+if (raw is NodeEventDto_IdentityRotated) {
+            return [14 ,cst_encode_String(raw.oldNodeId),cst_encode_String(raw.newNodeId)].jsify()!;
+        }
+if (raw is NodeEventDto_DeviceLinked) {
+            return [15 ,cst_encode_String(raw.userPublicKey),cst_encode_String(raw.deviceNodeId),cst_encode_String(raw.deviceName)].jsify()!;
+        }
+if (raw is NodeEventDto_ConnectivityChanged) {
+            return [16 ,cst_encode_connectivity_state_dto(raw.state),cst_encode_String(raw.reason)].jsify()!;
+        }
+if (raw is NodeEventDto_Error) {
+            return [17 ,cst_encode_String(raw.message)].jsify()!;
+        }
+
+                throw Exception('unreachable'); }
+
+@protected JSAny cst_encode_node_event_record_dto(NodeEventRecordDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_64(raw.seq),cst_encode_i_64(raw.timestamp),cst_encode_node_event_dto(raw.event)].jsify()!; }
+
+@protected JSAny cst_encode_node_info(NodeInfo raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_String(raw.publicKey),cst_encode_bool(raw.isRunning)].jsify()!; }
+
+@protected JSAny cst_encode_node_instance_dto(NodeInstanceDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_32(raw.handle),cst_encode_String(raw.nodeId),cst_encode_String(raw.publicKey)].jsify()!; }
+
+@protected JSAny cst_encode_node_stats_dto(NodeStatsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_64(raw.uptimeSeconds),cst_encode_u_64(raw.totalOperations),cst_encode_u_64(raw.bytesSent),cst_encode_u_64(raw.bytesReceived),cst_encode_u_64(raw.connects),cst_encode_u_64(raw.reconnects),cst_encode_u_64(raw.lifetimeSessions),cst_encode_u_64(raw.lifetimeUptimeSeconds),cst_encode_u_64(raw.lifetimeBytesSent),cst_encode_u_64(raw.lifetimeBytesReceived),cst_encode_u_64(raw.lifetimeConnects),cst_encode_u_64(raw.lifetimeReconnects),cst_encode_u_64(raw.outboxDepth)].jsify()!; }
+
+@protected JSAny cst_encode_node_status_dto(NodeStatusDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_bool(raw.isRunning),cst_encode_opt_String(raw.nodeId),cst_encode_u_32(raw.connectedPeers),cst_encode_u_32(raw.discoveredPeers),cst_encode_u_64(raw.uptimeSeconds),cst_encode_u_64(raw.gossipMessagesReceived),cst_encode_u_64(raw.storageSizeBytes),cst_encode_u_64(raw.totalKeys),cst_encode_u_32(raw.syncOperations),cst_encode_u_64(raw.latencyRequestsSent),cst_encode_u_64(raw.latencyResponsesReceived),cst_encode_list_topic_stats_dto(raw.topics)].jsify()!; }
+
+@protected JSAny cst_encode_oplog_gc_dto(OplogGcDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_32(raw.keepVersions),cst_encode_u_64(raw.keepForSecs)].jsify()!; }
+
+@protected String? cst_encode_opt_String(String? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_String(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_bootstrap_manifest_dto(raw); }
+
+@protected double? cst_encode_opt_box_autoadd_f_64(double? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_f_64(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_i_64(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_mqtt_bridge_config_dto(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_node_info(NodeInfo? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_node_info(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_oplog_gc_dto(OplogGcDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_oplog_gc_dto(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_peer_path_dto(PeerPathDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_peer_path_dto(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_presence_dto(PresenceDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_presence_dto(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_successor_record_dto(SuccessorRecordDto? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_successor_record_dto(raw); }
+
+@protected int? cst_encode_opt_box_autoadd_u_16(int? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_u_16(raw); }
+
+@protected int? cst_encode_opt_box_autoadd_u_32(int? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_u_32(raw); }
+
+@protected JSAny? cst_encode_opt_box_autoadd_u_64(BigInt? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_u_64(raw); }
+
+@protected int? cst_encode_opt_box_autoadd_u_8(int? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_box_autoadd_u_8(raw); }
+
+@protected JSAny? cst_encode_opt_list_String(List<String>? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_list_String(raw); }
+
+@protected JSAny? cst_encode_opt_list_prim_u_8_strict(Uint8List? raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return raw == null ? null : cst_encode_list_prim_u_8_strict(raw); }
+
+@protected JSAny cst_encode_peer_circuit_dto(PeerCircuitDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_String(raw.state),cst_encode_bool(raw.unreachable),cst_encode_u_32(raw.consecutiveFailures),cst_encode_i_64(raw.lastFailureAt),cst_encode_opt_box_autoadd_i_64(raw.retryAt)].jsify()!; }
+
+@protected JSAny cst_encode_peer_diagnostics_dto(PeerDiagnosticsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_opt_box_autoadd_peer_path_dto(raw.path),cst_encode_bool(raw.directEstablished),cst_encode_u_32(raw.dialAttempts),cst_encode_u_32(raw.dialFailures),cst_encode_opt_box_autoadd_i_64(raw.lastDialAt),cst_encode_opt_String(raw.lastError)].jsify()!; }
+
+@protected JSAny cst_encode_peer_info_dto(PeerInfoDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_String(raw.publicKey),cst_encode_opt_String(raw.address),cst_encode_opt_String(raw.region),cst_encode_opt_String(raw.version),cst_encode_opt_box_autoadd_u_64(raw.latencyMs),cst_encode_bool(raw.isMobile)].jsify()!; }
+
+@protected JSAny cst_encode_peer_latency_dto(PeerLatencyDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_opt_box_autoadd_u_64(raw.latencyMs),cst_encode_f_64(raw.averageMs),cst_encode_f_64(raw.jitterMs),cst_encode_u_32(raw.samples)].jsify()!; }
+
+@protected JSAny cst_encode_peer_path_dto(PeerPathDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_String(raw.path),cst_encode_bool(raw.isMixed),cst_encode_list_String(raw.directAddrs),cst_encode_opt_String(raw.relayUrl),cst_encode_i_64(raw.updatedAt)].jsify()!; }
+
+@protected JSAny cst_encode_peer_sync_status_dto(PeerSyncStatusDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.peerId),cst_encode_u_64(raw.opsSent),cst_encode_u_64(raw.opsReceived),cst_encode_u_64(raw.bytesSent),cst_encode_u_64(raw.bytesReceived),cst_encode_opt_box_autoadd_i_64(raw.lastRequestAt),cst_encode_opt_box_autoadd_i_64(raw.lastResponseAt),cst_encode_bool(raw.inProgress),cst_encode_u_64(raw.opsAcked),cst_encode_opt_box_autoadd_i_64(raw.lastAckAt)].jsify()!; }
+
+@protected JSAny cst_encode_pinned_peer_dto(PinnedPeerDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_opt_String(raw.address)].jsify()!; }
+
+@protected JSAny cst_encode_power_policy_dto(PowerPolicyDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_bool(raw.lowPower),cst_encode_bool(raw.metered),cst_encode_bool(raw.syncDeferred),cst_encode_bool(raw.blobTransfersAllowed)].jsify()!; }
+
+@protected JSAny cst_encode_presence_dto(PresenceDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.peerId),cst_encode_bool(raw.online),cst_encode_opt_String(raw.status),cst_encode_i_64(raw.lastSeen)].jsify()!; }
+
+@protected JSAny cst_encode_query_filter_dto(QueryFilterDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.field),cst_encode_String(raw.op),cst_encode_String(raw.valueJson)].jsify()!; }
+
+@protected JSAny cst_encode_query_result_dto(QueryResultDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_list_db_entry_dto(raw.entries),cst_encode_bool(raw.hasMore)].jsify()!; }
+
+@protected JSAny cst_encode_query_spec_dto(QuerySpecDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_opt_String(raw.keyPrefix),cst_encode_list_query_filter_dto(raw.filters),cst_encode_opt_String(raw.sortBy),cst_encode_bool(raw.descending),cst_encode_u_32(raw.limit),cst_encode_u_32(raw.offset),cst_encode_opt_list_String(raw.fields)].jsify()!; }
+
+@protected JSAny cst_encode_rekeyed_database_dto(RekeyedDatabaseDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.oldName),cst_encode_String(raw.newName)].jsify()!; }
+
+@protected JSAny cst_encode_relay_config_dto(RelayConfigDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+if (raw is RelayConfigDto_Default) {
+            return [0 ].jsify()!;
+        }
+if (raw is RelayConfigDto_Disabled) {
+            return [1 ].jsify()!;
+        }
+if (raw is RelayConfigDto_Custom) {
+            return [2 ,cst_encode_list_String(raw.urls)].jsify()!;
+        }
+
+                throw Exception('unreachable'); }
+
+@protected JSAny cst_encode_relay_status_dto(RelayStatusDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_relay_config_dto(raw.relay),cst_encode_opt_String(raw.activeUrl),cst_encode_opt_box_autoadd_u_64(raw.rttMs)].jsify()!; }
+
+@protected JSAny cst_encode_replica_check_dto(ReplicaCheckDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_String(raw.publisher),cst_encode_bool(raw.matches),cst_encode_u_32(raw.mismatchedBuckets),cst_encode_i_64(raw.checkedAt)].jsify()!; }
+
+@protected JSAny cst_encode_rpc_request_dto(RpcRequestDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_64(raw.requestId),cst_encode_String(raw.peerId),cst_encode_String(raw.method),cst_encode_list_prim_u_8_strict(raw.payload)].jsify()!; }
+
+@protected JSAny cst_encode_scan_page_dto(ScanPageDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_list_db_entry_dto(raw.entries),cst_encode_opt_String(raw.nextCursor)].jsify()!; }
+
+@protected JSAny cst_encode_scored_member_dto(ScoredMemberDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.member),cst_encode_f_64(raw.score)].jsify()!; }
+
+@protected JSAny cst_encode_startup_stage_dto(StartupStageDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+if (raw is StartupStageDto_KeyLoaded) {
+            return [0 ].jsify()!;
+        }
+if (raw is StartupStageDto_EndpointOnline) {
+            return [1 ,cst_encode_bool(raw.relayConnected)].jsify()!;
+        }
+if (raw is StartupStageDto_BootstrapConnecting) {
+            return [2 ,cst_encode_u_32(raw.attempt)].jsify()!;
+        }
+if (raw is StartupStageDto_TopicsSubscribed) {
+            return [3 ].jsify()!;
+        }
+if (raw is StartupStageDto_Ready) {
+            return [4 ].jsify()!;
+        }
+
+                throw Exception('unreachable'); }
+
+@protected JSAny cst_encode_subsystem_health_dto(SubsystemHealthDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_health_status_dto(raw.status),cst_encode_String(raw.reason)].jsify()!; }
+
+@protected JSAny cst_encode_successor_record_dto(SuccessorRecordDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.oldNodeId),cst_encode_String(raw.newNodeId),cst_encode_i_64(raw.timestamp),cst_encode_i_64(raw.graceUntil)].jsify()!; }
+
+@protected JSAny cst_encode_sync_stats_dto(SyncStatsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_u_64(raw.totalOperations),cst_encode_u_64(raw.opsSent),cst_encode_u_64(raw.opsReceived),cst_encode_u_64(raw.bytesSent),cst_encode_u_64(raw.bytesReceived),cst_encode_opt_box_autoadd_i_64(raw.lastSyncAt),cst_encode_list_peer_sync_status_dto(raw.peers)].jsify()!; }
+
+@protected JSAny cst_encode_sync_wait_summary_dto(SyncWaitSummaryDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_bool(raw.synced),cst_encode_u_32(raw.queuedOps),cst_encode_u_32(raw.unacknowledgedOps),cst_encode_u_64(raw.durationMs)].jsify()!; }
+
+@protected JSAny cst_encode_text_delta_dto(TextDeltaDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_u_64(raw.index),cst_encode_u_64(raw.deleted),cst_encode_String(raw.inserted)].jsify()!; }
+
+@protected JSAny cst_encode_time_point_dto(TimePointDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_i_64(raw.timestamp),cst_encode_f_64(raw.value)].jsify()!; }
+
+@protected JSAny cst_encode_topic_health_dto(TopicHealthDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.topic),cst_encode_bool(raw.subscribed),cst_encode_u_32(raw.neighbors)].jsify()!; }
+
+@protected JSAny cst_encode_topic_message_dto(TopicMessageDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.topic),cst_encode_String(raw.deliveredFrom),cst_encode_list_prim_u_8_strict(raw.content)].jsify()!; }
+
+@protected JSAny cst_encode_topic_metrics_dto(TopicMetricsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.topic),cst_encode_u_64(raw.messagesSent),cst_encode_u_64(raw.messagesReceived),cst_encode_u_64(raw.duplicatesDropped),cst_encode_u_64(raw.bytesSent),cst_encode_u_64(raw.bytesReceived)].jsify()!; }
+
+@protected JSAny cst_encode_topic_stats_dto(TopicStatsDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.topic),cst_encode_u_64(raw.messagesSent),cst_encode_u_64(raw.messagesReceived),cst_encode_u_64(raw.bytesSent),cst_encode_u_64(raw.bytesReceived),cst_encode_u_32(raw.neighbors),cst_encode_opt_box_autoadd_i_64(raw.lastEventAt)].jsify()!; }
+
+@protected JSAny cst_encode_u_64(BigInt raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return castNativeBigInt(raw); }
+
+@protected JSAny cst_encode_value_update_dto(ValueUpdateDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.dbName),cst_encode_String(raw.key),cst_encode_opt_String(raw.value),cst_encode_opt_list_prim_u_8_strict(raw.valueBytes)].jsify()!; }
+
+@protected JSAny cst_encode_verified_binding_dto(VerifiedBindingDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_String(raw.nodeId),cst_encode_String(raw.publicKey),cst_encode_i_64(raw.verifiedAt)].jsify()!; }
+
+@protected JSAny cst_encode_wake_sync_summary_dto(WakeSyncSummaryDto raw){ // Codec=Cst (C-struct based), see doc to use other codecs
+return [cst_encode_bool(raw.startedNode),cst_encode_u_32(raw.pinnedPeersDialed),cst_encode_u_32(raw.pinnedPeersConnected),cst_encode_bool(raw.synced),cst_encode_u_64(raw.opsReceived),cst_encode_u_64(raw.opsSent),cst_encode_u_32(raw.outboxSent),cst_encode_u_32(raw.outboxRemaining),cst_encode_u_64(raw.durationMs)].jsify()!; }
+
+@protected int cst_encode_bandwidth_subsystem_dto(BandwidthSubsystemDto raw);
+
+@protected bool cst_encode_bool(bool raw);
+
+@protected int cst_encode_connection_type_dto(ConnectionTypeDto raw);
+
+@protected int cst_encode_connectivity_state_dto(ConnectivityStateDto raw);
+
+@protected double cst_encode_f_64(double raw);
+
+@protected int cst_encode_health_status_dto(HealthStatusDto raw);
+
+@protected int cst_encode_i_32(int raw);
+
+@protected int cst_encode_mqtt_direction_dto(MqttDirectionDto raw);
+
+@protected int cst_encode_power_mode_dto(PowerModeDto raw);
+
+@protected int cst_encode_u_16(int raw);
+
+@protected int cst_encode_u_32(int raw);
+
+@protected int cst_encode_u_8(int raw);
+
+@protected void cst_encode_unit(void raw);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_change_event_dto_Dco(RustStreamSink<ChangeEventDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_direct_message_dto_Dco(RustStreamSink<DirectMessageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_file_event_dto_Dco(RustStreamSink<FileEventDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_key_change_dto_Dco(RustStreamSink<KeyChangeDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_node_event_record_dto_Dco(RustStreamSink<NodeEventRecordDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_rpc_request_dto_Dco(RustStreamSink<RpcRequestDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_startup_stage_dto_Dco(RustStreamSink<StartupStageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_text_delta_dto_Dco(RustStreamSink<TextDeltaDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_topic_message_dto_Dco(RustStreamSink<TopicMessageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_value_update_dto_Dco(RustStreamSink<ValueUpdateDto> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_accepted_invite_dto(AcceptedInviteDto self, SseSerializer serializer);
+
+@protected void sse_encode_backup_info_dto(BackupInfoDto self, SseSerializer serializer);
+
+@protected void sse_encode_bandwidth_caps_dto(BandwidthCapsDto self, SseSerializer serializer);
+
+@protected void sse_encode_bandwidth_stats_dto(BandwidthStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_bandwidth_subsystem_dto(BandwidthSubsystemDto self, SseSerializer serializer);
+
+@protected void sse_encode_bandwidth_usage_dto(BandwidthUsageDto self, SseSerializer serializer);
+
+@protected void sse_encode_batch_entry_dto(BatchEntryDto self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_bootstrap_manifest_dto(BootstrapManifestDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bandwidth_caps_dto(BandwidthCapsDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_device_conditions_dto(DeviceConditionsDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_file_offer_dto(FileOfferDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_log_filter_dto(LogFilterDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_node_config_dto(NodeConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_node_info(NodeInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_oplog_gc_dto(OplogGcDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_peer_path_dto(PeerPathDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_presence_dto(PresenceDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_query_spec_dto(QuerySpecDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_successor_record_dto(SuccessorRecordDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_change_event_dto(ChangeEventDto self, SseSerializer serializer);
+
+@protected void sse_encode_checkpoint_dto(CheckpointDto self, SseSerializer serializer);
+
+@protected void sse_encode_conflict_dto(ConflictDto self, SseSerializer serializer);
+
+@protected void sse_encode_connection_diagnostics_dto(ConnectionDiagnosticsDto self, SseSerializer serializer);
+
+@protected void sse_encode_connection_result_dto(ConnectionResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_connection_type_dto(ConnectionTypeDto self, SseSerializer serializer);
+
+@protected void sse_encode_connectivity_dto(ConnectivityDto self, SseSerializer serializer);
+
+@protected void sse_encode_connectivity_state_dto(ConnectivityStateDto self, SseSerializer serializer);
+
+@protected void sse_encode_db_entry_dto(DbEntryDto self, SseSerializer serializer);
+
+@protected void sse_encode_db_sync_info_dto(DbSyncInfoDto self, SseSerializer serializer);
+
+@protected void sse_encode_debug_server_dto(DebugServerDto self, SseSerializer serializer);
+
+@protected void sse_encode_device_conditions_dto(DeviceConditionsDto self, SseSerializer serializer);
+
+@protected void sse_encode_direct_message_dto(DirectMessageDto self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_file_event_dto(FileEventDto self, SseSerializer serializer);
+
+@protected void sse_encode_file_offer_dto(FileOfferDto self, SseSerializer serializer);
+
+@protected void sse_encode_geo_match_dto(GeoMatchDto self, SseSerializer serializer);
+
+@protected void sse_encode_hash_entry_dto(HashEntryDto self, SseSerializer serializer);
+
+@protected void sse_encode_health_report_dto(HealthReportDto self, SseSerializer serializer);
+
+@protected void sse_encode_health_status_dto(HealthStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_identity_rotation_dto(IdentityRotationDto self, SseSerializer serializer);
+
+@protected void sse_encode_integrity_issue_dto(IntegrityIssueDto self, SseSerializer serializer);
+
+@protected void sse_encode_integrity_report_dto(IntegrityReportDto self, SseSerializer serializer);
+
+@protected void sse_encode_kadena_transaction_dto(KadenaTransactionDto self, SseSerializer serializer);
+
+@protected void sse_encode_key_change_dto(KeyChangeDto self, SseSerializer serializer);
+
+@protected void sse_encode_key_pair_dto(KeyPairDto self, SseSerializer serializer);
+
+@protected void sse_encode_latency_bucket_dto(LatencyBucketDto self, SseSerializer serializer);
+
+@protected void sse_encode_linked_device_dto(LinkedDeviceDto self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_bandwidth_usage_dto(List<BandwidthUsageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_batch_entry_dto(List<BatchEntryDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_conflict_dto(List<ConflictDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_db_entry_dto(List<DbEntryDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_direct_message_dto(List<DirectMessageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_file_offer_dto(List<FileOfferDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_geo_match_dto(List<GeoMatchDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_hash_entry_dto(List<HashEntryDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_integrity_issue_dto(List<IntegrityIssueDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_latency_bucket_dto(List<LatencyBucketDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_linked_device_dto(List<LinkedDeviceDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_log_entry(List<LogEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mqtt_topic_mapping_dto(List<MqttTopicMappingDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_node_event_record_dto(List<NodeEventRecordDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_list_prim_u_8_strict(List<Uint8List?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_circuit_dto(List<PeerCircuitDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_diagnostics_dto(List<PeerDiagnosticsDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_info_dto(List<PeerInfoDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_latency_dto(List<PeerLatencyDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_path_dto(List<PeerPathDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_peer_sync_status_dto(List<PeerSyncStatusDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_pinned_peer_dto(List<PinnedPeerDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_filter_dto(List<QueryFilterDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_rekeyed_database_dto(List<RekeyedDatabaseDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_replica_check_dto(List<ReplicaCheckDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_scored_member_dto(List<ScoredMemberDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_time_point_dto(List<TimePointDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_topic_health_dto(List<TopicHealthDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_topic_metrics_dto(List<TopicMetricsDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_topic_stats_dto(List<TopicStatsDto> self, SseSerializer serializer);
+
+@protected void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+
+@protected void sse_encode_log_filter_dto(LogFilterDto self, SseSerializer serializer);
+
+@protected void sse_encode_metrics_dto(MetricsDto self, SseSerializer serializer);
+
+@protected void sse_encode_mqtt_bridge_config_dto(MqttBridgeConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_mqtt_direction_dto(MqttDirectionDto self, SseSerializer serializer);
+
+@protected void sse_encode_mqtt_topic_mapping_dto(MqttTopicMappingDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_addr_dto(NodeAddrDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_config_dto(NodeConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_event_dto(NodeEventDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_event_record_dto(NodeEventRecordDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_info(NodeInfo self, SseSerializer serializer);
+
+@protected void sse_encode_node_instance_dto(NodeInstanceDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_stats_dto(NodeStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_node_status_dto(NodeStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_oplog_gc_dto(OplogGcDto self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bootstrap_manifest_dto(BootstrapManifestDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_mqtt_bridge_config_dto(MqttBridgeConfigDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_node_info(NodeInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_oplog_gc_dto(OplogGcDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_peer_path_dto(PeerPathDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_presence_dto(PresenceDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_successor_record_dto(SuccessorRecordDto? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_peer_circuit_dto(PeerCircuitDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_diagnostics_dto(PeerDiagnosticsDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_info_dto(PeerInfoDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_latency_dto(PeerLatencyDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_path_dto(PeerPathDto self, SseSerializer serializer);
+
+@protected void sse_encode_peer_sync_status_dto(PeerSyncStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_pinned_peer_dto(PinnedPeerDto self, SseSerializer serializer);
+
+@protected void sse_encode_power_mode_dto(PowerModeDto self, SseSerializer serializer);
+
+@protected void sse_encode_power_policy_dto(PowerPolicyDto self, SseSerializer serializer);
+
+@protected void sse_encode_presence_dto(PresenceDto self, SseSerializer serializer);
+
+@protected void sse_encode_query_filter_dto(QueryFilterDto self, SseSerializer serializer);
+
+@protected void sse_encode_query_result_dto(QueryResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_query_spec_dto(QuerySpecDto self, SseSerializer serializer);
+
+@protected void sse_encode_rekeyed_database_dto(RekeyedDatabaseDto self, SseSerializer serializer);
+
+@protected void sse_encode_relay_config_dto(RelayConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_relay_status_dto(RelayStatusDto self, SseSerializer serializer);
+
+@protected void sse_encode_replica_check_dto(ReplicaCheckDto self, SseSerializer serializer);
+
+@protected void sse_encode_rpc_request_dto(RpcRequestDto self, SseSerializer serializer);
+
+@protected void sse_encode_scan_page_dto(ScanPageDto self, SseSerializer serializer);
+
+@protected void sse_encode_scored_member_dto(ScoredMemberDto self, SseSerializer serializer);
+
+@protected void sse_encode_startup_stage_dto(StartupStageDto self, SseSerializer serializer);
+
+@protected void sse_encode_subsystem_health_dto(SubsystemHealthDto self, SseSerializer serializer);
+
+@protected void sse_encode_successor_record_dto(SuccessorRecordDto self, SseSerializer serializer);
+
+@protected void sse_encode_sync_stats_dto(SyncStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_sync_wait_summary_dto(SyncWaitSummaryDto self, SseSerializer serializer);
+
+@protected void sse_encode_text_delta_dto(TextDeltaDto self, SseSerializer serializer);
+
+@protected void sse_encode_time_point_dto(TimePointDto self, SseSerializer serializer);
+
+@protected void sse_encode_topic_health_dto(TopicHealthDto self, SseSerializer serializer);
+
+@protected void sse_encode_topic_message_dto(TopicMessageDto self, SseSerializer serializer);
+
+@protected void sse_encode_topic_metrics_dto(TopicMetricsDto self, SseSerializer serializer);
+
+@protected void sse_encode_topic_stats_dto(TopicStatsDto self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_value_update_dto(ValueUpdateDto self, SseSerializer serializer);
+
+@protected void sse_encode_verified_binding_dto(VerifiedBindingDto self, SseSerializer serializer);
+
+@protected void sse_encode_wake_sync_summary_dto(WakeSyncSummaryDto self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__accept_db_invite(String token) => wasmModule.wire__crate__api__accept_db_invite(token);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__accept_db_invite_on(int handle,String token) => wasmModule.wire__crate__api__accept_db_invite_on(handle,token);
+
+void wire__crate__api__accept_file(NativePortType port_,String offer_id,String dest_path) => wasmModule.wire__crate__api__accept_file(port_,offer_id,dest_path);
+
+void wire__crate__api__accept_file_on(NativePortType port_,int handle,String offer_id,String dest_path) => wasmModule.wire__crate__api__accept_file_on(port_,handle,offer_id,dest_path);
+
+void wire__crate__api__add_log_entry(NativePortType port_,String level,String message) => wasmModule.wire__crate__api__add_log_entry(port_,level,message);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__blake3_hash(JSAny data) => wasmModule.wire__crate__api__blake3_hash(data);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__change_feed(String db_name,JSAny since_seq,String sink) => wasmModule.wire__crate__api__change_feed(db_name,since_seq,sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__change_feed_on(int handle,String db_name,JSAny since_seq,String sink) => wasmModule.wire__crate__api__change_feed_on(handle,db_name,since_seq,sink);
+
+void wire__crate__api__checkpoint(NativePortType port_,JSAny? budget_ms) => wasmModule.wire__crate__api__checkpoint(port_,budget_ms);
+
+void wire__crate__api__checkpoint_on(NativePortType port_,int handle,JSAny? budget_ms) => wasmModule.wire__crate__api__checkpoint_on(port_,handle,budget_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__clear_logs() => wasmModule.wire__crate__api__clear_logs();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__close_storage_read_only() => wasmModule.wire__crate__api__close_storage_read_only();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__compare_and_swap(String db_name,String key,JSAny? expected,JSAny? new_value) => wasmModule.wire__crate__api__compare_and_swap(db_name,key,expected,new_value);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__compare_and_swap_on(int handle,String db_name,String key,JSAny? expected,JSAny? new_value) => wasmModule.wire__crate__api__compare_and_swap_on(handle,db_name,key,expected,new_value);
+
+void wire__crate__api__connect_peer(NativePortType port_,String node_id,String? address) => wasmModule.wire__crate__api__connect_peer(port_,node_id,address);
+
+void wire__crate__api__connect_peer_on(NativePortType port_,int handle,String node_id,String? address) => wasmModule.wire__crate__api__connect_peer_on(port_,handle,node_id,address);
+
+void wire__crate__api__create_db_invite(NativePortType port_,String db_name,String owner_secret_key,bool can_write,bool include_encryption_key,JSAny expires_in_secs) => wasmModule.wire__crate__api__create_db_invite(port_,db_name,owner_secret_key,can_write,include_encryption_key,expires_in_secs);
+
+void wire__crate__api__create_db_invite_on(NativePortType port_,int handle,String db_name,String owner_secret_key,bool can_write,bool include_encryption_key,JSAny expires_in_secs) => wasmModule.wire__crate__api__create_db_invite_on(port_,handle,db_name,owner_secret_key,can_write,include_encryption_key,expires_in_secs);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__create_device_link_request(String device_name) => wasmModule.wire__crate__api__create_device_link_request(device_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__create_device_link_request_on(int handle,String device_name) => wasmModule.wire__crate__api__create_device_link_request_on(handle,device_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__create_encrypted_db(String db_name) => wasmModule.wire__crate__api__create_encrypted_db(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__create_encrypted_db_on(int handle,String db_name) => wasmModule.wire__crate__api__create_encrypted_db_on(handle,db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__decline_file(String offer_id) => wasmModule.wire__crate__api__decline_file(offer_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__decline_file_on(int handle,String offer_id) => wasmModule.wire__crate__api__decline_file_on(handle,offer_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__default_node_config() => wasmModule.wire__crate__api__default_node_config();
+
+void wire__crate__api__delete_data(NativePortType port_,String db_name,String key) => wasmModule.wire__crate__api__delete_data(port_,db_name,key);
+
+void wire__crate__api__delete_data_on(NativePortType port_,int handle,String db_name,String key) => wasmModule.wire__crate__api__delete_data_on(port_,handle,db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__dm_stream(String sink) => wasmModule.wire__crate__api__dm_stream(sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__dm_stream_on(int handle,String sink) => wasmModule.wire__crate__api__dm_stream_on(handle,sink);
+
+void wire__crate__api__drop_database(NativePortType port_,String db_name,String owner_secret_key) => wasmModule.wire__crate__api__drop_database(port_,db_name,owner_secret_key);
+
+void wire__crate__api__drop_database_on(NativePortType port_,int handle,String db_name,String owner_secret_key) => wasmModule.wire__crate__api__drop_database_on(port_,handle,db_name,owner_secret_key);
+
+void wire__crate__api__edit_text(NativePortType port_,String db_name,String key,JSAny index,JSAny delete,String insert,String secret_key) => wasmModule.wire__crate__api__edit_text(port_,db_name,key,index,delete,insert,secret_key);
+
+void wire__crate__api__edit_text_on(NativePortType port_,int handle,String db_name,String key,JSAny index,JSAny delete,String insert,String secret_key) => wasmModule.wire__crate__api__edit_text_on(port_,handle,db_name,key,index,delete,insert,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__event_stream(JSAny since_seq,String sink) => wasmModule.wire__crate__api__event_stream(since_seq,sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__event_stream_on(int handle,JSAny since_seq,String sink) => wasmModule.wire__crate__api__event_stream_on(handle,since_seq,sink);
+
+void wire__crate__api__export_backup(NativePortType port_,String path,String passphrase) => wasmModule.wire__crate__api__export_backup(port_,path,passphrase);
+
+void wire__crate__api__export_backup_on(NativePortType port_,int handle,String path,String passphrase) => wasmModule.wire__crate__api__export_backup_on(port_,handle,path,passphrase);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__export_db_key(String db_name) => wasmModule.wire__crate__api__export_db_key(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__export_db_key_on(int handle,String db_name) => wasmModule.wire__crate__api__export_db_key_on(handle,db_name);
+
+void wire__crate__api__export_logs(NativePortType port_,String path) => wasmModule.wire__crate__api__export_logs(port_,path);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__extract_name_from_db(String db_name) => wasmModule.wire__crate__api__extract_name_from_db(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__file_event_stream(String sink) => wasmModule.wire__crate__api__file_event_stream(sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__file_event_stream_on(int handle,String sink) => wasmModule.wire__crate__api__file_event_stream_on(handle,sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__follow_database(String db_name) => wasmModule.wire__crate__api__follow_database(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__follow_database_on(int handle,String db_name) => wasmModule.wire__crate__api__follow_database_on(handle,db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__generate_db_name(String name,String public_key_hex) => wasmModule.wire__crate__api__generate_db_name(name,public_key_hex);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__generate_keypair() => wasmModule.wire__crate__api__generate_keypair();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__generate_peer_id_from_secret_key(String secret_key_hex) => wasmModule.wire__crate__api__generate_peer_id_from_secret_key(secret_key_hex);
+
+void wire__crate__api__geo_add(NativePortType port_,String db_name,String key,String member,double latitude,double longitude,String secret_key) => wasmModule.wire__crate__api__geo_add(port_,db_name,key,member,latitude,longitude,secret_key);
+
+void wire__crate__api__geo_add_on(NativePortType port_,int handle,String db_name,String key,String member,double latitude,double longitude,String secret_key) => wasmModule.wire__crate__api__geo_add_on(port_,handle,db_name,key,member,latitude,longitude,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__geo_radius(String db_name,String key,double latitude,double longitude,double radius_m) => wasmModule.wire__crate__api__geo_radius(db_name,key,latitude,longitude,radius_m);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__geo_radius_on(int handle,String db_name,String key,double latitude,double longitude,double radius_m) => wasmModule.wire__crate__api__geo_radius_on(handle,db_name,key,latitude,longitude,radius_m);
+
+void wire__crate__api__get_all_data(NativePortType port_) => wasmModule.wire__crate__api__get_all_data(port_);
+
+void wire__crate__api__get_all_data_on(NativePortType port_,int handle) => wasmModule.wire__crate__api__get_all_data_on(port_,handle);
+
+void wire__crate__api__get_all_data_page(NativePortType port_,int limit,String? cursor,bool keys_only,JSAny? max_bytes) => wasmModule.wire__crate__api__get_all_data_page(port_,limit,cursor,keys_only,max_bytes);
+
+void wire__crate__api__get_all_data_page_on(NativePortType port_,int handle,int limit,String? cursor,bool keys_only,JSAny? max_bytes) => wasmModule.wire__crate__api__get_all_data_page_on(port_,handle,limit,cursor,keys_only,max_bytes);
+
+void wire__crate__api__get_all_entries(NativePortType port_,String db_name) => wasmModule.wire__crate__api__get_all_entries(port_,db_name);
+
+void wire__crate__api__get_all_entries_on(NativePortType port_,int handle,String db_name) => wasmModule.wire__crate__api__get_all_entries_on(port_,handle,db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_bandwidth_stats() => wasmModule.wire__crate__api__get_bandwidth_stats();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_bandwidth_stats_on(int handle) => wasmModule.wire__crate__api__get_bandwidth_stats_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_connection_diagnostics() => wasmModule.wire__crate__api__get_connection_diagnostics();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_connection_diagnostics_on(int handle) => wasmModule.wire__crate__api__get_connection_diagnostics_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_connectivity_state() => wasmModule.wire__crate__api__get_connectivity_state();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_connectivity_state_on(int handle) => wasmModule.wire__crate__api__get_connectivity_state_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_counter(String db_name,String key) => wasmModule.wire__crate__api__get_counter(db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_counter_on(int handle,String db_name,String key) => wasmModule.wire__crate__api__get_counter_on(handle,db_name,key);
+
+void wire__crate__api__get_data(NativePortType port_,String db_name,String key) => wasmModule.wire__crate__api__get_data(port_,db_name,key);
+
+void wire__crate__api__get_data_on(NativePortType port_,int handle,String db_name,String key) => wasmModule.wire__crate__api__get_data_on(port_,handle,db_name,key);
+
+void wire__crate__api__get_database_root(NativePortType port_,String db_name) => wasmModule.wire__crate__api__get_database_root(port_,db_name);
+
+void wire__crate__api__get_database_root_on(NativePortType port_,int handle,String db_name) => wasmModule.wire__crate__api__get_database_root_on(port_,handle,db_name);
+
+void wire__crate__api__get_db_sync_info(NativePortType port_,String db_name) => wasmModule.wire__crate__api__get_db_sync_info(port_,db_name);
+
+void wire__crate__api__get_db_sync_info_on(NativePortType port_,int handle,String db_name) => wasmModule.wire__crate__api__get_db_sync_info_on(port_,handle,db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_decrypted(String db_name,String key) => wasmModule.wire__crate__api__get_decrypted(db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_decrypted_on(int handle,String db_name,String key) => wasmModule.wire__crate__api__get_decrypted_on(handle,db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_dm_history(String peer_id,int limit) => wasmModule.wire__crate__api__get_dm_history(peer_id,limit);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_dm_history_on(int handle,String peer_id,int limit) => wasmModule.wire__crate__api__get_dm_history_on(handle,peer_id,limit);
+
+void wire__crate__api__get_entries_page(NativePortType port_,String db_name,int limit,String? cursor,bool keys_only,JSAny? max_bytes) => wasmModule.wire__crate__api__get_entries_page(port_,db_name,limit,cursor,keys_only,max_bytes);
+
+void wire__crate__api__get_entries_page_on(NativePortType port_,int handle,String db_name,int limit,String? cursor,bool keys_only,JSAny? max_bytes) => wasmModule.wire__crate__api__get_entries_page_on(port_,handle,db_name,limit,cursor,keys_only,max_bytes);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_followed_databases() => wasmModule.wire__crate__api__get_followed_databases();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_followed_databases_on(int handle) => wasmModule.wire__crate__api__get_followed_databases_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_log_levels_filter() => wasmModule.wire__crate__api__get_log_levels_filter();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_logs(int? limit) => wasmModule.wire__crate__api__get_logs(limit);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_logs_filtered(JSAny filter) => wasmModule.wire__crate__api__get_logs_filtered(filter);
+
+void wire__crate__api__get_many(NativePortType port_,String db_name,JSAny keys) => wasmModule.wire__crate__api__get_many(port_,db_name,keys);
+
+void wire__crate__api__get_many_on(NativePortType port_,int handle,String db_name,JSAny keys) => wasmModule.wire__crate__api__get_many_on(port_,handle,db_name,keys);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_metrics() => wasmModule.wire__crate__api__get_metrics();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_metrics_on(int handle) => wasmModule.wire__crate__api__get_metrics_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_mqtt_bridge_config() => wasmModule.wire__crate__api__get_mqtt_bridge_config();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_mqtt_bridge_config_on(int handle) => wasmModule.wire__crate__api__get_mqtt_bridge_config_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_network_id(String data_dir) => wasmModule.wire__crate__api__get_network_id(data_dir);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_addr() => wasmModule.wire__crate__api__get_node_addr();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_addr_on(int handle) => wasmModule.wire__crate__api__get_node_addr_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_config() => wasmModule.wire__crate__api__get_node_config();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_config_on(int handle) => wasmModule.wire__crate__api__get_node_config_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_info() => wasmModule.wire__crate__api__get_node_info();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_info_on(int handle) => wasmModule.wire__crate__api__get_node_info_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_stats() => wasmModule.wire__crate__api__get_node_stats();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_stats_on(int handle) => wasmModule.wire__crate__api__get_node_stats_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_status() => wasmModule.wire__crate__api__get_node_status();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_status_on(int handle) => wasmModule.wire__crate__api__get_node_status_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_ticket() => wasmModule.wire__crate__api__get_node_ticket();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_ticket_on(int handle) => wasmModule.wire__crate__api__get_node_ticket_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peer_circuits() => wasmModule.wire__crate__api__get_peer_circuits();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peer_circuits_on(int handle) => wasmModule.wire__crate__api__get_peer_circuits_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peer_paths() => wasmModule.wire__crate__api__get_peer_paths();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peer_paths_on(int handle) => wasmModule.wire__crate__api__get_peer_paths_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peers() => wasmModule.wire__crate__api__get_peers();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peers_filtered(JSAny capabilities,String? region,JSAny? max_latency_ms) => wasmModule.wire__crate__api__get_peers_filtered(capabilities,region,max_latency_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peers_filtered_on(int handle,JSAny capabilities,String? region,JSAny? max_latency_ms) => wasmModule.wire__crate__api__get_peers_filtered_on(handle,capabilities,region,max_latency_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peers_on(int handle) => wasmModule.wire__crate__api__get_peers_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_pinned_peers() => wasmModule.wire__crate__api__get_pinned_peers();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_pinned_peers_on(int handle) => wasmModule.wire__crate__api__get_pinned_peers_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_power_mode() => wasmModule.wire__crate__api__get_power_mode();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_power_mode_on(int handle) => wasmModule.wire__crate__api__get_power_mode_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_power_policy() => wasmModule.wire__crate__api__get_power_policy();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_power_policy_on(int handle) => wasmModule.wire__crate__api__get_power_policy_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_presence(String peer_id) => wasmModule.wire__crate__api__get_presence(peer_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_presence_on(int handle,String peer_id) => wasmModule.wire__crate__api__get_presence_on(handle,peer_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_ranked_peers() => wasmModule.wire__crate__api__get_ranked_peers();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_ranked_peers_on(int handle) => wasmModule.wire__crate__api__get_ranked_peers_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_recent_events(JSAny since_seq) => wasmModule.wire__crate__api__get_recent_events(since_seq);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_recent_events_on(int handle,JSAny since_seq) => wasmModule.wire__crate__api__get_recent_events_on(handle,since_seq);
+
+void wire__crate__api__get_relay_status(NativePortType port_) => wasmModule.wire__crate__api__get_relay_status(port_);
+
+void wire__crate__api__get_relay_status_on(NativePortType port_,int handle) => wasmModule.wire__crate__api__get_relay_status_on(port_,handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_successor(String node_id) => wasmModule.wire__crate__api__get_successor(node_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_successor_on(int handle,String node_id) => wasmModule.wire__crate__api__get_successor_on(handle,node_id);
+
+void wire__crate__api__get_sync_stats(NativePortType port_) => wasmModule.wire__crate__api__get_sync_stats(port_);
+
+void wire__crate__api__get_sync_stats_on(NativePortType port_,int handle) => wasmModule.wire__crate__api__get_sync_stats_on(port_,handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_text(String db_name,String key) => wasmModule.wire__crate__api__get_text(db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_text_on(int handle,String db_name,String key) => wasmModule.wire__crate__api__get_text_on(handle,db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_writers(String db_name) => wasmModule.wire__crate__api__get_writers(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_writers_on(int handle,String db_name) => wasmModule.wire__crate__api__get_writers_on(handle,db_name);
+
+void wire__crate__api__go_online(NativePortType port_,JSAny bootstrap_peers,String? region) => wasmModule.wire__crate__api__go_online(port_,bootstrap_peers,region);
+
+void wire__crate__api__go_online_on(NativePortType port_,int handle,JSAny bootstrap_peers,String? region) => wasmModule.wire__crate__api__go_online_on(port_,handle,bootstrap_peers,region);
+
+void wire__crate__api__grant_writer(NativePortType port_,String db_name,String writer_public_key,String owner_secret_key) => wasmModule.wire__crate__api__grant_writer(port_,db_name,writer_public_key,owner_secret_key);
+
+void wire__crate__api__grant_writer_on(NativePortType port_,int handle,String db_name,String writer_public_key,String owner_secret_key) => wasmModule.wire__crate__api__grant_writer_on(port_,handle,db_name,writer_public_key,owner_secret_key);
+
+void wire__crate__api__graphql_execute(NativePortType port_,String query,String? variables) => wasmModule.wire__crate__api__graphql_execute(port_,query,variables);
+
+void wire__crate__api__graphql_execute_on(NativePortType port_,int handle,String query,String? variables) => wasmModule.wire__crate__api__graphql_execute_on(port_,handle,query,variables);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__greet(String name) => wasmModule.wire__crate__api__greet(name);
+
+void wire__crate__api__hash_get(NativePortType port_,String db_name,String key,String field) => wasmModule.wire__crate__api__hash_get(port_,db_name,key,field);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__hash_get_all(String db_name,String key) => wasmModule.wire__crate__api__hash_get_all(db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__hash_get_all_on(int handle,String db_name,String key) => wasmModule.wire__crate__api__hash_get_all_on(handle,db_name,key);
+
+void wire__crate__api__hash_get_on(NativePortType port_,int handle,String db_name,String key,String field) => wasmModule.wire__crate__api__hash_get_on(port_,handle,db_name,key,field);
+
+void wire__crate__api__hash_set(NativePortType port_,String db_name,String key,String field,String value,String secret_key) => wasmModule.wire__crate__api__hash_set(port_,db_name,key,field,value,secret_key);
+
+void wire__crate__api__hash_set_on(NativePortType port_,int handle,String db_name,String key,String field,String value,String secret_key) => wasmModule.wire__crate__api__hash_set_on(port_,handle,db_name,key,field,value,secret_key);
+
+void wire__crate__api__health_check(NativePortType port_) => wasmModule.wire__crate__api__health_check(port_);
+
+void wire__crate__api__health_check_on(NativePortType port_,int handle) => wasmModule.wire__crate__api__health_check_on(port_,handle);
+
+void wire__crate__api__import_backup(NativePortType port_,String data_dir,String path,String passphrase) => wasmModule.wire__crate__api__import_backup(port_,data_dir,path,passphrase);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__import_db_key(String db_name,String key_hex) => wasmModule.wire__crate__api__import_db_key(db_name,key_hex);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__import_db_key_on(int handle,String db_name,String key_hex) => wasmModule.wire__crate__api__import_db_key_on(handle,db_name,key_hex);
+
+void wire__crate__api__incr(NativePortType port_,String db_name,String key,JSAny delta,String secret_key) => wasmModule.wire__crate__api__incr(port_,db_name,key,delta,secret_key);
+
+void wire__crate__api__incr_on(NativePortType port_,int handle,String db_name,String key,JSAny delta,String secret_key) => wasmModule.wire__crate__api__incr_on(port_,handle,db_name,key,delta,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__init_logging() => wasmModule.wire__crate__api__init_logging();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_database_local_only(String db_name) => wasmModule.wire__crate__api__is_database_local_only(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_database_local_only_on(int handle,String db_name) => wasmModule.wire__crate__api__is_database_local_only_on(handle,db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_networking_paused() => wasmModule.wire__crate__api__is_networking_paused();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_networking_paused_on(int handle) => wasmModule.wire__crate__api__is_networking_paused_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_node_key_sealed(String data_dir) => wasmModule.wire__crate__api__is_node_key_sealed(data_dir);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_node_offline() => wasmModule.wire__crate__api__is_node_offline();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_node_offline_on(int handle) => wasmModule.wire__crate__api__is_node_offline_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_node_running() => wasmModule.wire__crate__api__is_node_running();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_verified_peer_key(String peer_id,String public_key) => wasmModule.wire__crate__api__is_verified_peer_key(peer_id,public_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_verified_peer_key_on(int handle,String peer_id,String public_key) => wasmModule.wire__crate__api__is_verified_peer_key_on(handle,peer_id,public_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__kadena_account_from_public_key(String public_key_hex) => wasmModule.wire__crate__api__kadena_account_from_public_key(public_key_hex);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__kadena_node_registration_command(String network_id,String chain_id,String peer_id,String multiaddr,String public_key_hex) => wasmModule.wire__crate__api__kadena_node_registration_command(network_id,chain_id,peer_id,multiaddr,public_key_hex);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__kadena_sign_command(String cmd,String secret_key_hex) => wasmModule.wire__crate__api__kadena_sign_command(cmd,secret_key_hex);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__link_device(String qr_payload,String secret_key) => wasmModule.wire__crate__api__link_device(qr_payload,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__link_device_on(int handle,String qr_payload,String secret_key) => wasmModule.wire__crate__api__link_device_on(handle,qr_payload,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_conflicts(String db_name) => wasmModule.wire__crate__api__list_conflicts(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_conflicts_on(int handle,String db_name) => wasmModule.wire__crate__api__list_conflicts_on(handle,db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_databases() => wasmModule.wire__crate__api__list_databases();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_databases_on(int handle) => wasmModule.wire__crate__api__list_databases_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_file_offers() => wasmModule.wire__crate__api__list_file_offers();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_file_offers_on(int handle) => wasmModule.wire__crate__api__list_file_offers_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_keys(String db_name) => wasmModule.wire__crate__api__list_keys(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_keys_on(int handle,String db_name) => wasmModule.wire__crate__api__list_keys_on(handle,db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_linked_devices() => wasmModule.wire__crate__api__list_linked_devices();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_linked_devices_on(int handle) => wasmModule.wire__crate__api__list_linked_devices_on(handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_node_instances() => wasmModule.wire__crate__api__list_node_instances();
+
+void wire__crate__api__list_push(NativePortType port_,String db_name,String key,String value,String secret_key) => wasmModule.wire__crate__api__list_push(port_,db_name,key,value,secret_key);
+
+void wire__crate__api__list_push_on(NativePortType port_,int handle,String db_name,String key,String value,String secret_key) => wasmModule.wire__crate__api__list_push_on(port_,handle,db_name,key,value,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_range(String db_name,String key) => wasmModule.wire__crate__api__list_range(db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_range_on(int handle,String db_name,String key) => wasmModule.wire__crate__api__list_range_on(handle,db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_replica_checks() => wasmModule.wire__crate__api__list_replica_checks();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_replica_checks_on(int handle) => wasmModule.wire__crate__api__list_replica_checks_on(handle);
+
+void wire__crate__api__measure_latency(NativePortType port_,String peer_id) => wasmModule.wire__crate__api__measure_latency(port_,peer_id);
+
+void wire__crate__api__measure_latency_on(NativePortType port_,int handle,String peer_id) => wasmModule.wire__crate__api__measure_latency_on(port_,handle,peer_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__open_storage_read_only(String data_dir) => wasmModule.wire__crate__api__open_storage_read_only(data_dir);
+
+void wire__crate__api__pause_networking(NativePortType port_) => wasmModule.wire__crate__api__pause_networking(port_);
+
+void wire__crate__api__pause_networking_on(NativePortType port_,int handle) => wasmModule.wire__crate__api__pause_networking_on(port_,handle);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__pin_peer(String node_id,String? address) => wasmModule.wire__crate__api__pin_peer(node_id,address);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__pin_peer_on(int handle,String node_id,String? address) => wasmModule.wire__crate__api__pin_peer_on(handle,node_id,address);
+
+void wire__crate__api__prove_ownership(NativePortType port_,String peer_id) => wasmModule.wire__crate__api__prove_ownership(port_,peer_id);
+
+void wire__crate__api__prove_ownership_on(NativePortType port_,int handle,String peer_id) => wasmModule.wire__crate__api__prove_ownership_on(port_,handle,peer_id);
+
+void wire__crate__api__publish_topic(NativePortType port_,String name,JSAny content) => wasmModule.wire__crate__api__publish_topic(port_,name,content);
+
+void wire__crate__api__publish_topic_on(NativePortType port_,int handle,String name,JSAny content) => wasmModule.wire__crate__api__publish_topic_on(port_,handle,name,content);
+
+void wire__crate__api__put_json(NativePortType port_,String db_name,String key,String json,String secret_key) => wasmModule.wire__crate__api__put_json(port_,db_name,key,json,secret_key);
+
+void wire__crate__api__put_json_on(NativePortType port_,int handle,String db_name,String key,String json,String secret_key) => wasmModule.wire__crate__api__put_json_on(port_,handle,db_name,key,json,secret_key);
+
+void wire__crate__api__put_string(NativePortType port_,String db_name,String key,String value,String secret_key) => wasmModule.wire__crate__api__put_string(port_,db_name,key,value,secret_key);
+
+void wire__crate__api__put_string_on(NativePortType port_,int handle,String db_name,String key,String value,String secret_key) => wasmModule.wire__crate__api__put_string_on(port_,handle,db_name,key,value,secret_key);
+
+void wire__crate__api__query(NativePortType port_,String db_name,JSAny spec) => wasmModule.wire__crate__api__query(port_,db_name,spec);
+
+void wire__crate__api__query_on(NativePortType port_,int handle,String db_name,JSAny spec) => wasmModule.wire__crate__api__query_on(port_,handle,db_name,spec);
+
+void wire__crate__api__read_only_export_backup(NativePortType port_,String path,String passphrase,String? wallet_secret_key) => wasmModule.wire__crate__api__read_only_export_backup(port_,path,passphrase,wallet_secret_key);
+
+void wire__crate__api__read_only_get_data(NativePortType port_,String db_name,String key) => wasmModule.wire__crate__api__read_only_get_data(port_,db_name,key);
+
+void wire__crate__api__read_only_get_entries_page(NativePortType port_,String db_name,int limit,String? cursor,bool keys_only,JSAny? max_bytes) => wasmModule.wire__crate__api__read_only_get_entries_page(port_,db_name,limit,cursor,keys_only,max_bytes);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__read_only_list_databases() => wasmModule.wire__crate__api__read_only_list_databases();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__reject_rpc(JSAny request_id,String message) => wasmModule.wire__crate__api__reject_rpc(request_id,message);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__reject_rpc_on(int handle,JSAny request_id,String message) => wasmModule.wire__crate__api__reject_rpc_on(handle,request_id,message);
+
+void wire__crate__api__request_sync(NativePortType port_,JSAny? since_timestamp) => wasmModule.wire__crate__api__request_sync(port_,since_timestamp);
+
+void wire__crate__api__request_sync_on(NativePortType port_,int handle,JSAny? since_timestamp) => wasmModule.wire__crate__api__request_sync_on(port_,handle,since_timestamp);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__reset_log_levels() => wasmModule.wire__crate__api__reset_log_levels();
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__respond_rpc(JSAny request_id,JSAny payload) => wasmModule.wire__crate__api__respond_rpc(request_id,payload);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__respond_rpc_on(int handle,JSAny request_id,JSAny payload) => wasmModule.wire__crate__api__respond_rpc_on(handle,request_id,payload);
+
+void wire__crate__api__resume_networking(NativePortType port_) => wasmModule.wire__crate__api__resume_networking(port_);
+
+void wire__crate__api__resume_networking_on(NativePortType port_,int handle) => wasmModule.wire__crate__api__resume_networking_on(port_,handle);
+
+void wire__crate__api__revoke_writer(NativePortType port_,String db_name,String writer_public_key,String owner_secret_key) => wasmModule.wire__crate__api__revoke_writer(port_,db_name,writer_public_key,owner_secret_key);
+
+void wire__crate__api__revoke_writer_on(NativePortType port_,int handle,String db_name,String writer_public_key,String owner_secret_key) => wasmModule.wire__crate__api__revoke_writer_on(port_,handle,db_name,writer_public_key,owner_secret_key);
+
+void wire__crate__api__rotate_identity(NativePortType port_,String? new_secret_key,JSAny? grace_secs) => wasmModule.wire__crate__api__rotate_identity(port_,new_secret_key,grace_secs);
+
+void wire__crate__api__rotate_identity_on(NativePortType port_,int handle,String? new_secret_key,JSAny? grace_secs) => wasmModule.wire__crate__api__rotate_identity_on(port_,handle,new_secret_key,grace_secs);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__rotate_key_wrapping_key(String data_dir,JSAny old_wrapping_key,JSAny new_wrapping_key) => wasmModule.wire__crate__api__rotate_key_wrapping_key(data_dir,old_wrapping_key,new_wrapping_key);
+
+void wire__crate__api__rpc_call(NativePortType port_,String peer_id,String method,JSAny payload,JSAny? timeout_ms) => wasmModule.wire__crate__api__rpc_call(port_,peer_id,method,payload,timeout_ms);
+
+void wire__crate__api__rpc_call_on(NativePortType port_,int handle,String peer_id,String method,JSAny payload,JSAny? timeout_ms) => wasmModule.wire__crate__api__rpc_call_on(port_,handle,peer_id,method,payload,timeout_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__scan_prefix(String db_name,String prefix,int limit,String? cursor) => wasmModule.wire__crate__api__scan_prefix(db_name,prefix,limit,cursor);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__scan_prefix_on(int handle,String db_name,String prefix,int limit,String? cursor) => wasmModule.wire__crate__api__scan_prefix_on(handle,db_name,prefix,limit,cursor);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__scan_range(String db_name,String? start,String? end,int limit,String? cursor) => wasmModule.wire__crate__api__scan_range(db_name,start,end,limit,cursor);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__scan_range_on(int handle,String db_name,String? start,String? end,int limit,String? cursor) => wasmModule.wire__crate__api__scan_range_on(handle,db_name,start,end,limit,cursor);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__seal_node_key(String data_dir,JSAny wrapping_key) => wasmModule.wire__crate__api__seal_node_key(data_dir,wrapping_key);
+
+void wire__crate__api__search(NativePortType port_,String db_name,String query,int limit) => wasmModule.wire__crate__api__search(port_,db_name,query,limit);
+
+void wire__crate__api__search_on(NativePortType port_,int handle,String db_name,String query,int limit) => wasmModule.wire__crate__api__search_on(port_,handle,db_name,query,limit);
+
+void wire__crate__api__send_dm(NativePortType port_,String peer_id,String text) => wasmModule.wire__crate__api__send_dm(port_,peer_id,text);
+
+void wire__crate__api__send_dm_on(NativePortType port_,int handle,String peer_id,String text) => wasmModule.wire__crate__api__send_dm_on(port_,handle,peer_id,text);
+
+void wire__crate__api__send_file(NativePortType port_,String peer_id,String path) => wasmModule.wire__crate__api__send_file(port_,peer_id,path);
+
+void wire__crate__api__send_file_on(NativePortType port_,int handle,String peer_id,String path) => wasmModule.wire__crate__api__send_file_on(port_,handle,peer_id,path);
+
+void wire__crate__api__send_gossip(NativePortType port_,String topic,String message) => wasmModule.wire__crate__api__send_gossip(port_,topic,message);
+
+void wire__crate__api__send_gossip_on(NativePortType port_,int handle,String topic,String message) => wasmModule.wire__crate__api__send_gossip_on(port_,handle,topic,message);
+
+void wire__crate__api__send_latency_request(NativePortType port_,String peer_id) => wasmModule.wire__crate__api__send_latency_request(port_,peer_id);
+
+void wire__crate__api__send_latency_request_on(NativePortType port_,int handle,String peer_id) => wasmModule.wire__crate__api__send_latency_request_on(port_,handle,peer_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__serve_rpc(String sink) => wasmModule.wire__crate__api__serve_rpc(sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__serve_rpc_on(int handle,String sink) => wasmModule.wire__crate__api__serve_rpc_on(handle,sink);
+
+void wire__crate__api__set_add(NativePortType port_,String db_name,String key,String member,String secret_key) => wasmModule.wire__crate__api__set_add(port_,db_name,key,member,secret_key);
+
+void wire__crate__api__set_add_on(NativePortType port_,int handle,String db_name,String key,String member,String secret_key) => wasmModule.wire__crate__api__set_add_on(port_,handle,db_name,key,member,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_bandwidth_caps(JSAny caps) => wasmModule.wire__crate__api__set_bandwidth_caps(caps);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_bandwidth_caps_on(int handle,JSAny caps) => wasmModule.wire__crate__api__set_bandwidth_caps_on(handle,caps);
+
+void wire__crate__api__set_database_local_only(NativePortType port_,String db_name,bool local_only) => wasmModule.wire__crate__api__set_database_local_only(port_,db_name,local_only);
+
+void wire__crate__api__set_database_local_only_on(NativePortType port_,int handle,String db_name,bool local_only) => wasmModule.wire__crate__api__set_database_local_only_on(port_,handle,db_name,local_only);
+
+void wire__crate__api__set_device_conditions(NativePortType port_,JSAny conditions) => wasmModule.wire__crate__api__set_device_conditions(port_,conditions);
+
+void wire__crate__api__set_device_conditions_on(NativePortType port_,int handle,JSAny conditions) => wasmModule.wire__crate__api__set_device_conditions_on(port_,handle,conditions);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_key_wrapping_key(JSAny wrapping_key) => wasmModule.wire__crate__api__set_key_wrapping_key(wrapping_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_log_buffer_size(int size) => wasmModule.wire__crate__api__set_log_buffer_size(size);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_log_level(String level) => wasmModule.wire__crate__api__set_log_level(level);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_log_target_level(String target,String? level) => wasmModule.wire__crate__api__set_log_target_level(target,level);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_members(String db_name,String key) => wasmModule.wire__crate__api__set_members(db_name,key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_members_on(int handle,String db_name,String key) => wasmModule.wire__crate__api__set_members_on(handle,db_name,key);
+
+void wire__crate__api__set_network_passphrase(NativePortType port_,String data_dir,String? passphrase) => wasmModule.wire__crate__api__set_network_passphrase(port_,data_dir,passphrase);
+
+void wire__crate__api__set_power_mode(NativePortType port_,int mode) => wasmModule.wire__crate__api__set_power_mode(port_,mode);
+
+void wire__crate__api__set_power_mode_on(NativePortType port_,int handle,int mode) => wasmModule.wire__crate__api__set_power_mode_on(port_,handle,mode);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_presence_status(String? status) => wasmModule.wire__crate__api__set_presence_status(status);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_presence_status_on(int handle,String? status) => wasmModule.wire__crate__api__set_presence_status_on(handle,status);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_retain_conflicts(bool enabled) => wasmModule.wire__crate__api__set_retain_conflicts(enabled);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_retain_conflicts_on(int handle,bool enabled) => wasmModule.wire__crate__api__set_retain_conflicts_on(handle,enabled);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_selective_sync(bool selective) => wasmModule.wire__crate__api__set_selective_sync(selective);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_selective_sync_on(int handle,bool selective) => wasmModule.wire__crate__api__set_selective_sync_on(handle,selective);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__sha256_hash(JSAny data) => wasmModule.wire__crate__api__sha256_hash(data);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__sign_bytes(String secret_key_hex,JSAny message) => wasmModule.wire__crate__api__sign_bytes(secret_key_hex,message);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__sign_message_with_key(String secret_key_hex,String message) => wasmModule.wire__crate__api__sign_message_with_key(secret_key_hex,message);
+
+void wire__crate__api__start_debug_server(NativePortType port_,String bind_addr,bool allow_writes) => wasmModule.wire__crate__api__start_debug_server(port_,bind_addr,allow_writes);
+
+void wire__crate__api__start_debug_server_on(NativePortType port_,int handle,String bind_addr,bool allow_writes) => wasmModule.wire__crate__api__start_debug_server_on(port_,handle,bind_addr,allow_writes);
+
+void wire__crate__api__start_metrics_server(NativePortType port_,String bind_addr) => wasmModule.wire__crate__api__start_metrics_server(port_,bind_addr);
+
+void wire__crate__api__start_metrics_server_on(NativePortType port_,int handle,String bind_addr) => wasmModule.wire__crate__api__start_metrics_server_on(port_,handle,bind_addr);
+
+void wire__crate__api__start_mqtt_bridge(NativePortType port_,JSAny config) => wasmModule.wire__crate__api__start_mqtt_bridge(port_,config);
+
+void wire__crate__api__start_mqtt_bridge_on(NativePortType port_,int handle,JSAny config) => wasmModule.wire__crate__api__start_mqtt_bridge_on(port_,handle,config);
+
+void wire__crate__api__start_node(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,String? region) => wasmModule.wire__crate__api__start_node(port_,data_dir,wallet_secret_key,bootstrap_peers,region);
+
+void wire__crate__api__start_node_in_network(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,String? region,String network_id) => wasmModule.wire__crate__api__start_node_in_network(port_,data_dir,wallet_secret_key,bootstrap_peers,region,network_id);
+
+void wire__crate__api__start_node_instance(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,JSAny config) => wasmModule.wire__crate__api__start_node_instance(port_,data_dir,wallet_secret_key,bootstrap_peers,config);
+
+void wire__crate__api__start_node_with_config(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,JSAny config) => wasmModule.wire__crate__api__start_node_with_config(port_,data_dir,wallet_secret_key,bootstrap_peers,config);
+
+void wire__crate__api__start_node_with_progress(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,JSAny config,String progress) => wasmModule.wire__crate__api__start_node_with_progress(port_,data_dir,wallet_secret_key,bootstrap_peers,config,progress);
+
+void wire__crate__api__start_offline(NativePortType port_,String data_dir) => wasmModule.wire__crate__api__start_offline(port_,data_dir);
+
+void wire__crate__api__stop_mqtt_bridge(NativePortType port_) => wasmModule.wire__crate__api__stop_mqtt_bridge(port_);
+
+void wire__crate__api__stop_mqtt_bridge_on(NativePortType port_,int handle) => wasmModule.wire__crate__api__stop_mqtt_bridge_on(port_,handle);
+
+void wire__crate__api__stop_node(NativePortType port_) => wasmModule.wire__crate__api__stop_node(port_);
+
+void wire__crate__api__stop_node_instance(NativePortType port_,int handle) => wasmModule.wire__crate__api__stop_node_instance(port_,handle);
+
+void wire__crate__api__store_batch(NativePortType port_,JSAny entries) => wasmModule.wire__crate__api__store_batch(port_,entries);
+
+void wire__crate__api__store_batch_on(NativePortType port_,int handle,JSAny entries) => wasmModule.wire__crate__api__store_batch_on(port_,handle,entries);
+
+void wire__crate__api__store_data(NativePortType port_,String db_name,String key,JSAny value,String public_key,String signature) => wasmModule.wire__crate__api__store_data(port_,db_name,key,value,public_key,signature);
+
+void wire__crate__api__store_data_idempotent(NativePortType port_,String db_name,String key,JSAny value,JSAny timestamp_ms,String secret_key) => wasmModule.wire__crate__api__store_data_idempotent(port_,db_name,key,value,timestamp_ms,secret_key);
+
+void wire__crate__api__store_data_idempotent_on(NativePortType port_,int handle,String db_name,String key,JSAny value,JSAny timestamp_ms,String secret_key) => wasmModule.wire__crate__api__store_data_idempotent_on(port_,handle,db_name,key,value,timestamp_ms,secret_key);
+
+void wire__crate__api__store_data_local(NativePortType port_,String db_name,String key,JSAny value) => wasmModule.wire__crate__api__store_data_local(port_,db_name,key,value);
+
+void wire__crate__api__store_data_local_on(NativePortType port_,int handle,String db_name,String key,JSAny value) => wasmModule.wire__crate__api__store_data_local_on(port_,handle,db_name,key,value);
+
+void wire__crate__api__store_data_on(NativePortType port_,int handle,String db_name,String key,JSAny value,String public_key,String signature) => wasmModule.wire__crate__api__store_data_on(port_,handle,db_name,key,value,public_key,signature);
+
+void wire__crate__api__store_data_signed(NativePortType port_,String db_name,String key,JSAny value,String store_type) => wasmModule.wire__crate__api__store_data_signed(port_,db_name,key,value,store_type);
+
+void wire__crate__api__store_data_signed_on(NativePortType port_,int handle,String db_name,String key,JSAny value,String store_type) => wasmModule.wire__crate__api__store_data_signed_on(port_,handle,db_name,key,value,store_type);
+
+void wire__crate__api__store_data_with_ttl(NativePortType port_,String db_name,String key,JSAny value,JSAny ttl_secs,String secret_key) => wasmModule.wire__crate__api__store_data_with_ttl(port_,db_name,key,value,ttl_secs,secret_key);
+
+void wire__crate__api__store_data_with_ttl_on(NativePortType port_,int handle,String db_name,String key,JSAny value,JSAny ttl_secs,String secret_key) => wasmModule.wire__crate__api__store_data_with_ttl_on(port_,handle,db_name,key,value,ttl_secs,secret_key);
+
+void wire__crate__api__store_encrypted(NativePortType port_,String db_name,String key,JSAny value,String secret_key) => wasmModule.wire__crate__api__store_encrypted(port_,db_name,key,value,secret_key);
+
+void wire__crate__api__store_encrypted_on(NativePortType port_,int handle,String db_name,String key,JSAny value,String secret_key) => wasmModule.wire__crate__api__store_encrypted_on(port_,handle,db_name,key,value,secret_key);
+
+void wire__crate__api__subscribe_topic(NativePortType port_,String name,String sink) => wasmModule.wire__crate__api__subscribe_topic(port_,name,sink);
+
+void wire__crate__api__subscribe_topic_on(NativePortType port_,int handle,String name,String sink) => wasmModule.wire__crate__api__subscribe_topic_on(port_,handle,name,sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__text_delta_stream(String sink) => wasmModule.wire__crate__api__text_delta_stream(sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__text_delta_stream_on(int handle,String sink) => wasmModule.wire__crate__api__text_delta_stream_on(handle,sink);
+
+void wire__crate__api__ts_add(NativePortType port_,String db_name,String key,JSAny timestamp_ms,double value,String secret_key) => wasmModule.wire__crate__api__ts_add(port_,db_name,key,timestamp_ms,value,secret_key);
+
+void wire__crate__api__ts_add_on(NativePortType port_,int handle,String db_name,String key,JSAny timestamp_ms,double value,String secret_key) => wasmModule.wire__crate__api__ts_add_on(port_,handle,db_name,key,timestamp_ms,value,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__ts_range(String db_name,String key,JSAny from_ms,JSAny to_ms) => wasmModule.wire__crate__api__ts_range(db_name,key,from_ms,to_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__ts_range_on(int handle,String db_name,String key,JSAny from_ms,JSAny to_ms) => wasmModule.wire__crate__api__ts_range_on(handle,db_name,key,from_ms,to_ms);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__unfollow_database(String db_name) => wasmModule.wire__crate__api__unfollow_database(db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__unfollow_database_on(int handle,String db_name) => wasmModule.wire__crate__api__unfollow_database_on(handle,db_name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__unpin_peer(String node_id) => wasmModule.wire__crate__api__unpin_peer(node_id);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__unpin_peer_on(int handle,String node_id) => wasmModule.wire__crate__api__unpin_peer_on(handle,node_id);
+
+void wire__crate__api__unsubscribe_topic(NativePortType port_,String name) => wasmModule.wire__crate__api__unsubscribe_topic(port_,name);
+
+void wire__crate__api__unsubscribe_topic_on(NativePortType port_,int handle,String name) => wasmModule.wire__crate__api__unsubscribe_topic_on(port_,handle,name);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__update_config(JSAny config) => wasmModule.wire__crate__api__update_config(config);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__update_config_on(int handle,JSAny config) => wasmModule.wire__crate__api__update_config_on(handle,config);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__validate_timestamp(JSAny timestamp) => wasmModule.wire__crate__api__validate_timestamp(timestamp);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__verify_bytes(String public_key_hex,JSAny message,String signature_hex) => wasmModule.wire__crate__api__verify_bytes(public_key_hex,message,signature_hex);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__verify_db_name(String db_name,String public_key_hex) => wasmModule.wire__crate__api__verify_db_name(db_name,public_key_hex);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__verify_message_signature(String public_key_hex,String message,String signature_hex) => wasmModule.wire__crate__api__verify_message_signature(public_key_hex,message,signature_hex);
+
+void wire__crate__api__verify_storage(NativePortType port_,bool repair) => wasmModule.wire__crate__api__verify_storage(port_,repair);
+
+void wire__crate__api__verify_storage_on(NativePortType port_,int handle,bool repair) => wasmModule.wire__crate__api__verify_storage_on(port_,handle,repair);
+
+void wire__crate__api__wait_for_sync(NativePortType port_,String db_name,JSAny timeout_ms) => wasmModule.wire__crate__api__wait_for_sync(port_,db_name,timeout_ms);
+
+void wire__crate__api__wait_for_sync_on(NativePortType port_,int handle,String db_name,JSAny timeout_ms) => wasmModule.wire__crate__api__wait_for_sync_on(port_,handle,db_name,timeout_ms);
+
+void wire__crate__api__wake_and_sync(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,int max_duration_secs) => wasmModule.wire__crate__api__wake_and_sync(port_,data_dir,wallet_secret_key,bootstrap_peers,max_duration_secs);
+
+void wire__crate__api__wake_and_sync_on(NativePortType port_,int handle,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,int max_duration_secs) => wasmModule.wire__crate__api__wake_and_sync_on(port_,handle,data_dir,wallet_secret_key,bootstrap_peers,max_duration_secs);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__watch_db(String db_name,String sink) => wasmModule.wire__crate__api__watch_db(db_name,sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__watch_db_on(int handle,String db_name,String sink) => wasmModule.wire__crate__api__watch_db_on(handle,db_name,sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__watch_key(String db_name,String key,String sink) => wasmModule.wire__crate__api__watch_key(db_name,key,sink);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__watch_key_on(int handle,String db_name,String key,String sink) => wasmModule.wire__crate__api__watch_key_on(handle,db_name,key,sink);
+
+void wire__crate__api__zset_add(NativePortType port_,String db_name,String key,String member,double score,String secret_key) => wasmModule.wire__crate__api__zset_add(port_,db_name,key,member,score,secret_key);
+
+void wire__crate__api__zset_add_on(NativePortType port_,int handle,String db_name,String key,String member,double score,String secret_key) => wasmModule.wire__crate__api__zset_add_on(port_,handle,db_name,key,member,score,secret_key);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__zset_range(String db_name,String key,double? min,double? max) => wasmModule.wire__crate__api__zset_range(db_name,key,min,max);
+
+JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__zset_range_on(int handle,String db_name,String key,double? min,double? max) => wasmModule.wire__crate__api__zset_range_on(handle,db_name,key,min,max);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__accept_db_invite(String token);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__accept_db_invite_on(int handle,String token);
+
+external void wire__crate__api__accept_file(NativePortType port_,String offer_id,String dest_path);
+
+external void wire__crate__api__accept_file_on(NativePortType port_,int handle,String offer_id,String dest_path);
+
+external void wire__crate__api__add_log_entry(NativePortType port_,String level,String message);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__blake3_hash(JSAny data);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__change_feed(String db_name,JSAny since_seq,String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__change_feed_on(int handle,String db_name,JSAny since_seq,String sink);
+
+external void wire__crate__api__checkpoint(NativePortType port_,JSAny? budget_ms);
+
+external void wire__crate__api__checkpoint_on(NativePortType port_,int handle,JSAny? budget_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__clear_logs();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__close_storage_read_only();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__compare_and_swap(String db_name,String key,JSAny? expected,JSAny? new_value);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__compare_and_swap_on(int handle,String db_name,String key,JSAny? expected,JSAny? new_value);
+
+external void wire__crate__api__connect_peer(NativePortType port_,String node_id,String? address);
+
+external void wire__crate__api__connect_peer_on(NativePortType port_,int handle,String node_id,String? address);
+
+external void wire__crate__api__create_db_invite(NativePortType port_,String db_name,String owner_secret_key,bool can_write,bool include_encryption_key,JSAny expires_in_secs);
+
+external void wire__crate__api__create_db_invite_on(NativePortType port_,int handle,String db_name,String owner_secret_key,bool can_write,bool include_encryption_key,JSAny expires_in_secs);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__create_device_link_request(String device_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__create_device_link_request_on(int handle,String device_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__create_encrypted_db(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__create_encrypted_db_on(int handle,String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__decline_file(String offer_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__decline_file_on(int handle,String offer_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__default_node_config();
+
+external void wire__crate__api__delete_data(NativePortType port_,String db_name,String key);
+
+external void wire__crate__api__delete_data_on(NativePortType port_,int handle,String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__dm_stream(String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__dm_stream_on(int handle,String sink);
+
+external void wire__crate__api__drop_database(NativePortType port_,String db_name,String owner_secret_key);
+
+external void wire__crate__api__drop_database_on(NativePortType port_,int handle,String db_name,String owner_secret_key);
+
+external void wire__crate__api__edit_text(NativePortType port_,String db_name,String key,JSAny index,JSAny delete,String insert,String secret_key);
+
+external void wire__crate__api__edit_text_on(NativePortType port_,int handle,String db_name,String key,JSAny index,JSAny delete,String insert,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__event_stream(JSAny since_seq,String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__event_stream_on(int handle,JSAny since_seq,String sink);
+
+external void wire__crate__api__export_backup(NativePortType port_,String path,String passphrase);
+
+external void wire__crate__api__export_backup_on(NativePortType port_,int handle,String path,String passphrase);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__export_db_key(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__export_db_key_on(int handle,String db_name);
+
+external void wire__crate__api__export_logs(NativePortType port_,String path);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__extract_name_from_db(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__file_event_stream(String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__file_event_stream_on(int handle,String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__follow_database(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__follow_database_on(int handle,String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__generate_db_name(String name,String public_key_hex);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__generate_keypair();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__generate_peer_id_from_secret_key(String secret_key_hex);
+
+external void wire__crate__api__geo_add(NativePortType port_,String db_name,String key,String member,double latitude,double longitude,String secret_key);
+
+external void wire__crate__api__geo_add_on(NativePortType port_,int handle,String db_name,String key,String member,double latitude,double longitude,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__geo_radius(String db_name,String key,double latitude,double longitude,double radius_m);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__geo_radius_on(int handle,String db_name,String key,double latitude,double longitude,double radius_m);
+
+external void wire__crate__api__get_all_data(NativePortType port_);
+
+external void wire__crate__api__get_all_data_on(NativePortType port_,int handle);
+
+external void wire__crate__api__get_all_data_page(NativePortType port_,int limit,String? cursor,bool keys_only,JSAny? max_bytes);
+
+external void wire__crate__api__get_all_data_page_on(NativePortType port_,int handle,int limit,String? cursor,bool keys_only,JSAny? max_bytes);
+
+external void wire__crate__api__get_all_entries(NativePortType port_,String db_name);
+
+external void wire__crate__api__get_all_entries_on(NativePortType port_,int handle,String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_bandwidth_stats();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_bandwidth_stats_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_connection_diagnostics();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_connection_diagnostics_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_connectivity_state();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_connectivity_state_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_counter(String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_counter_on(int handle,String db_name,String key);
+
+external void wire__crate__api__get_data(NativePortType port_,String db_name,String key);
+
+external void wire__crate__api__get_data_on(NativePortType port_,int handle,String db_name,String key);
+
+external void wire__crate__api__get_database_root(NativePortType port_,String db_name);
+
+external void wire__crate__api__get_database_root_on(NativePortType port_,int handle,String db_name);
+
+external void wire__crate__api__get_db_sync_info(NativePortType port_,String db_name);
+
+external void wire__crate__api__get_db_sync_info_on(NativePortType port_,int handle,String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_decrypted(String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_decrypted_on(int handle,String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_dm_history(String peer_id,int limit);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_dm_history_on(int handle,String peer_id,int limit);
+
+external void wire__crate__api__get_entries_page(NativePortType port_,String db_name,int limit,String? cursor,bool keys_only,JSAny? max_bytes);
+
+external void wire__crate__api__get_entries_page_on(NativePortType port_,int handle,String db_name,int limit,String? cursor,bool keys_only,JSAny? max_bytes);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_followed_databases();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_followed_databases_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_log_levels_filter();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_logs(int? limit);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_logs_filtered(JSAny filter);
+
+external void wire__crate__api__get_many(NativePortType port_,String db_name,JSAny keys);
+
+external void wire__crate__api__get_many_on(NativePortType port_,int handle,String db_name,JSAny keys);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_metrics();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_metrics_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_mqtt_bridge_config();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_mqtt_bridge_config_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_network_id(String data_dir);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_addr();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_addr_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_config();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_config_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_info();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_info_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_stats();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_stats_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_status();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_status_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_ticket();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_node_ticket_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peer_circuits();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peer_circuits_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peer_paths();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peer_paths_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peers();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peers_filtered(JSAny capabilities,String? region,JSAny? max_latency_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peers_filtered_on(int handle,JSAny capabilities,String? region,JSAny? max_latency_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_peers_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_pinned_peers();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_pinned_peers_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_power_mode();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_power_mode_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_power_policy();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_power_policy_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_presence(String peer_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_presence_on(int handle,String peer_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_ranked_peers();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_ranked_peers_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_recent_events(JSAny since_seq);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_recent_events_on(int handle,JSAny since_seq);
+
+external void wire__crate__api__get_relay_status(NativePortType port_);
+
+external void wire__crate__api__get_relay_status_on(NativePortType port_,int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_successor(String node_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_successor_on(int handle,String node_id);
+
+external void wire__crate__api__get_sync_stats(NativePortType port_);
+
+external void wire__crate__api__get_sync_stats_on(NativePortType port_,int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_text(String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_text_on(int handle,String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_writers(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__get_writers_on(int handle,String db_name);
+
+external void wire__crate__api__go_online(NativePortType port_,JSAny bootstrap_peers,String? region);
+
+external void wire__crate__api__go_online_on(NativePortType port_,int handle,JSAny bootstrap_peers,String? region);
+
+external void wire__crate__api__grant_writer(NativePortType port_,String db_name,String writer_public_key,String owner_secret_key);
+
+external void wire__crate__api__grant_writer_on(NativePortType port_,int handle,String db_name,String writer_public_key,String owner_secret_key);
+
+external void wire__crate__api__graphql_execute(NativePortType port_,String query,String? variables);
+
+external void wire__crate__api__graphql_execute_on(NativePortType port_,int handle,String query,String? variables);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__greet(String name);
+
+external void wire__crate__api__hash_get(NativePortType port_,String db_name,String key,String field);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__hash_get_all(String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__hash_get_all_on(int handle,String db_name,String key);
+
+external void wire__crate__api__hash_get_on(NativePortType port_,int handle,String db_name,String key,String field);
+
+external void wire__crate__api__hash_set(NativePortType port_,String db_name,String key,String field,String value,String secret_key);
+
+external void wire__crate__api__hash_set_on(NativePortType port_,int handle,String db_name,String key,String field,String value,String secret_key);
+
+external void wire__crate__api__health_check(NativePortType port_);
+
+external void wire__crate__api__health_check_on(NativePortType port_,int handle);
+
+external void wire__crate__api__import_backup(NativePortType port_,String data_dir,String path,String passphrase);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__import_db_key(String db_name,String key_hex);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__import_db_key_on(int handle,String db_name,String key_hex);
+
+external void wire__crate__api__incr(NativePortType port_,String db_name,String key,JSAny delta,String secret_key);
+
+external void wire__crate__api__incr_on(NativePortType port_,int handle,String db_name,String key,JSAny delta,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__init_logging();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_database_local_only(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_database_local_only_on(int handle,String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_networking_paused();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_networking_paused_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_node_key_sealed(String data_dir);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_node_offline();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_node_offline_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_node_running();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_verified_peer_key(String peer_id,String public_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__is_verified_peer_key_on(int handle,String peer_id,String public_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__kadena_account_from_public_key(String public_key_hex);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__kadena_node_registration_command(String network_id,String chain_id,String peer_id,String multiaddr,String public_key_hex);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__kadena_sign_command(String cmd,String secret_key_hex);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__link_device(String qr_payload,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__link_device_on(int handle,String qr_payload,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_conflicts(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_conflicts_on(int handle,String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_databases();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_databases_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_file_offers();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_file_offers_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_keys(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_keys_on(int handle,String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_linked_devices();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_linked_devices_on(int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_node_instances();
+
+external void wire__crate__api__list_push(NativePortType port_,String db_name,String key,String value,String secret_key);
+
+external void wire__crate__api__list_push_on(NativePortType port_,int handle,String db_name,String key,String value,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_range(String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_range_on(int handle,String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_replica_checks();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__list_replica_checks_on(int handle);
+
+external void wire__crate__api__measure_latency(NativePortType port_,String peer_id);
+
+external void wire__crate__api__measure_latency_on(NativePortType port_,int handle,String peer_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__open_storage_read_only(String data_dir);
+
+external void wire__crate__api__pause_networking(NativePortType port_);
+
+external void wire__crate__api__pause_networking_on(NativePortType port_,int handle);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__pin_peer(String node_id,String? address);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__pin_peer_on(int handle,String node_id,String? address);
+
+external void wire__crate__api__prove_ownership(NativePortType port_,String peer_id);
+
+external void wire__crate__api__prove_ownership_on(NativePortType port_,int handle,String peer_id);
+
+external void wire__crate__api__publish_topic(NativePortType port_,String name,JSAny content);
+
+external void wire__crate__api__publish_topic_on(NativePortType port_,int handle,String name,JSAny content);
+
+external void wire__crate__api__put_json(NativePortType port_,String db_name,String key,String json,String secret_key);
+
+external void wire__crate__api__put_json_on(NativePortType port_,int handle,String db_name,String key,String json,String secret_key);
+
+external void wire__crate__api__put_string(NativePortType port_,String db_name,String key,String value,String secret_key);
+
+external void wire__crate__api__put_string_on(NativePortType port_,int handle,String db_name,String key,String value,String secret_key);
+
+external void wire__crate__api__query(NativePortType port_,String db_name,JSAny spec);
+
+external void wire__crate__api__query_on(NativePortType port_,int handle,String db_name,JSAny spec);
+
+external void wire__crate__api__read_only_export_backup(NativePortType port_,String path,String passphrase,String? wallet_secret_key);
+
+external void wire__crate__api__read_only_get_data(NativePortType port_,String db_name,String key);
+
+external void wire__crate__api__read_only_get_entries_page(NativePortType port_,String db_name,int limit,String? cursor,bool keys_only,JSAny? max_bytes);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__read_only_list_databases();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__reject_rpc(JSAny request_id,String message);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__reject_rpc_on(int handle,JSAny request_id,String message);
+
+external void wire__crate__api__request_sync(NativePortType port_,JSAny? since_timestamp);
+
+external void wire__crate__api__request_sync_on(NativePortType port_,int handle,JSAny? since_timestamp);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__reset_log_levels();
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__respond_rpc(JSAny request_id,JSAny payload);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__respond_rpc_on(int handle,JSAny request_id,JSAny payload);
+
+external void wire__crate__api__resume_networking(NativePortType port_);
+
+external void wire__crate__api__resume_networking_on(NativePortType port_,int handle);
+
+external void wire__crate__api__revoke_writer(NativePortType port_,String db_name,String writer_public_key,String owner_secret_key);
+
+external void wire__crate__api__revoke_writer_on(NativePortType port_,int handle,String db_name,String writer_public_key,String owner_secret_key);
+
+external void wire__crate__api__rotate_identity(NativePortType port_,String? new_secret_key,JSAny? grace_secs);
+
+external void wire__crate__api__rotate_identity_on(NativePortType port_,int handle,String? new_secret_key,JSAny? grace_secs);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__rotate_key_wrapping_key(String data_dir,JSAny old_wrapping_key,JSAny new_wrapping_key);
+
+external void wire__crate__api__rpc_call(NativePortType port_,String peer_id,String method,JSAny payload,JSAny? timeout_ms);
+
+external void wire__crate__api__rpc_call_on(NativePortType port_,int handle,String peer_id,String method,JSAny payload,JSAny? timeout_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__scan_prefix(String db_name,String prefix,int limit,String? cursor);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__scan_prefix_on(int handle,String db_name,String prefix,int limit,String? cursor);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__scan_range(String db_name,String? start,String? end,int limit,String? cursor);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__scan_range_on(int handle,String db_name,String? start,String? end,int limit,String? cursor);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__seal_node_key(String data_dir,JSAny wrapping_key);
+
+external void wire__crate__api__search(NativePortType port_,String db_name,String query,int limit);
+
+external void wire__crate__api__search_on(NativePortType port_,int handle,String db_name,String query,int limit);
+
+external void wire__crate__api__send_dm(NativePortType port_,String peer_id,String text);
+
+external void wire__crate__api__send_dm_on(NativePortType port_,int handle,String peer_id,String text);
+
+external void wire__crate__api__send_file(NativePortType port_,String peer_id,String path);
+
+external void wire__crate__api__send_file_on(NativePortType port_,int handle,String peer_id,String path);
+
+external void wire__crate__api__send_gossip(NativePortType port_,String topic,String message);
+
+external void wire__crate__api__send_gossip_on(NativePortType port_,int handle,String topic,String message);
+
+external void wire__crate__api__send_latency_request(NativePortType port_,String peer_id);
+
+external void wire__crate__api__send_latency_request_on(NativePortType port_,int handle,String peer_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__serve_rpc(String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__serve_rpc_on(int handle,String sink);
+
+external void wire__crate__api__set_add(NativePortType port_,String db_name,String key,String member,String secret_key);
+
+external void wire__crate__api__set_add_on(NativePortType port_,int handle,String db_name,String key,String member,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_bandwidth_caps(JSAny caps);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_bandwidth_caps_on(int handle,JSAny caps);
+
+external void wire__crate__api__set_database_local_only(NativePortType port_,String db_name,bool local_only);
+
+external void wire__crate__api__set_database_local_only_on(NativePortType port_,int handle,String db_name,bool local_only);
+
+external void wire__crate__api__set_device_conditions(NativePortType port_,JSAny conditions);
+
+external void wire__crate__api__set_device_conditions_on(NativePortType port_,int handle,JSAny conditions);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_key_wrapping_key(JSAny wrapping_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_log_buffer_size(int size);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_log_level(String level);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_log_target_level(String target,String? level);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_members(String db_name,String key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_members_on(int handle,String db_name,String key);
+
+external void wire__crate__api__set_network_passphrase(NativePortType port_,String data_dir,String? passphrase);
+
+external void wire__crate__api__set_power_mode(NativePortType port_,int mode);
+
+external void wire__crate__api__set_power_mode_on(NativePortType port_,int handle,int mode);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_presence_status(String? status);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_presence_status_on(int handle,String? status);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_retain_conflicts(bool enabled);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_retain_conflicts_on(int handle,bool enabled);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_selective_sync(bool selective);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__set_selective_sync_on(int handle,bool selective);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__sha256_hash(JSAny data);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__sign_bytes(String secret_key_hex,JSAny message);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__sign_message_with_key(String secret_key_hex,String message);
+
+external void wire__crate__api__start_debug_server(NativePortType port_,String bind_addr,bool allow_writes);
+
+external void wire__crate__api__start_debug_server_on(NativePortType port_,int handle,String bind_addr,bool allow_writes);
+
+external void wire__crate__api__start_metrics_server(NativePortType port_,String bind_addr);
+
+external void wire__crate__api__start_metrics_server_on(NativePortType port_,int handle,String bind_addr);
+
+external void wire__crate__api__start_mqtt_bridge(NativePortType port_,JSAny config);
+
+external void wire__crate__api__start_mqtt_bridge_on(NativePortType port_,int handle,JSAny config);
+
+external void wire__crate__api__start_node(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,String? region);
+
+external void wire__crate__api__start_node_in_network(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,String? region,String network_id);
+
+external void wire__crate__api__start_node_instance(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,JSAny config);
+
+external void wire__crate__api__start_node_with_config(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,JSAny config);
+
+external void wire__crate__api__start_node_with_progress(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,JSAny config,String progress);
+
+external void wire__crate__api__start_offline(NativePortType port_,String data_dir);
+
+external void wire__crate__api__stop_mqtt_bridge(NativePortType port_);
+
+external void wire__crate__api__stop_mqtt_bridge_on(NativePortType port_,int handle);
+
+external void wire__crate__api__stop_node(NativePortType port_);
+
+external void wire__crate__api__stop_node_instance(NativePortType port_,int handle);
+
+external void wire__crate__api__store_batch(NativePortType port_,JSAny entries);
+
+external void wire__crate__api__store_batch_on(NativePortType port_,int handle,JSAny entries);
+
+external void wire__crate__api__store_data(NativePortType port_,String db_name,String key,JSAny value,String public_key,String signature);
+
+external void wire__crate__api__store_data_idempotent(NativePortType port_,String db_name,String key,JSAny value,JSAny timestamp_ms,String secret_key);
+
+external void wire__crate__api__store_data_idempotent_on(NativePortType port_,int handle,String db_name,String key,JSAny value,JSAny timestamp_ms,String secret_key);
+
+external void wire__crate__api__store_data_local(NativePortType port_,String db_name,String key,JSAny value);
+
+external void wire__crate__api__store_data_local_on(NativePortType port_,int handle,String db_name,String key,JSAny value);
+
+external void wire__crate__api__store_data_on(NativePortType port_,int handle,String db_name,String key,JSAny value,String public_key,String signature);
+
+external void wire__crate__api__store_data_signed(NativePortType port_,String db_name,String key,JSAny value,String store_type);
+
+external void wire__crate__api__store_data_signed_on(NativePortType port_,int handle,String db_name,String key,JSAny value,String store_type);
+
+external void wire__crate__api__store_data_with_ttl(NativePortType port_,String db_name,String key,JSAny value,JSAny ttl_secs,String secret_key);
+
+external void wire__crate__api__store_data_with_ttl_on(NativePortType port_,int handle,String db_name,String key,JSAny value,JSAny ttl_secs,String secret_key);
+
+external void wire__crate__api__store_encrypted(NativePortType port_,String db_name,String key,JSAny value,String secret_key);
+
+external void wire__crate__api__store_encrypted_on(NativePortType port_,int handle,String db_name,String key,JSAny value,String secret_key);
+
+external void wire__crate__api__subscribe_topic(NativePortType port_,String name,String sink);
+
+external void wire__crate__api__subscribe_topic_on(NativePortType port_,int handle,String name,String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__text_delta_stream(String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__text_delta_stream_on(int handle,String sink);
+
+external void wire__crate__api__ts_add(NativePortType port_,String db_name,String key,JSAny timestamp_ms,double value,String secret_key);
+
+external void wire__crate__api__ts_add_on(NativePortType port_,int handle,String db_name,String key,JSAny timestamp_ms,double value,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__ts_range(String db_name,String key,JSAny from_ms,JSAny to_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__ts_range_on(int handle,String db_name,String key,JSAny from_ms,JSAny to_ms);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__unfollow_database(String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__unfollow_database_on(int handle,String db_name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__unpin_peer(String node_id);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__unpin_peer_on(int handle,String node_id);
+
+external void wire__crate__api__unsubscribe_topic(NativePortType port_,String name);
+
+external void wire__crate__api__unsubscribe_topic_on(NativePortType port_,int handle,String name);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__update_config(JSAny config);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__update_config_on(int handle,JSAny config);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__validate_timestamp(JSAny timestamp);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__verify_bytes(String public_key_hex,JSAny message,String signature_hex);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__verify_db_name(String db_name,String public_key_hex);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__verify_message_signature(String public_key_hex,String message,String signature_hex);
+
+external void wire__crate__api__verify_storage(NativePortType port_,bool repair);
+
+external void wire__crate__api__verify_storage_on(NativePortType port_,int handle,bool repair);
+
+external void wire__crate__api__wait_for_sync(NativePortType port_,String db_name,JSAny timeout_ms);
+
+external void wire__crate__api__wait_for_sync_on(NativePortType port_,int handle,String db_name,JSAny timeout_ms);
+
+external void wire__crate__api__wake_and_sync(NativePortType port_,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,int max_duration_secs);
+
+external void wire__crate__api__wake_and_sync_on(NativePortType port_,int handle,String data_dir,String? wallet_secret_key,JSAny bootstrap_peers,int max_duration_secs);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__watch_db(String db_name,String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__watch_db_on(int handle,String db_name,String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__watch_key(String db_name,String key,String sink);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__watch_key_on(int handle,String db_name,String key,String sink);
+
+external void wire__crate__api__zset_add(NativePortType port_,String db_name,String key,String member,double score,String secret_key);
+
+external void wire__crate__api__zset_add_on(NativePortType port_,int handle,String db_name,String key,String member,double score,String secret_key);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__zset_range(String db_name,String key,double? min,double? max);
+
+external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */ wire__crate__api__zset_range_on(int handle,String db_name,String key,double? min,double? max);
+        }
+        
\ No newline at end of file
@@ -0,0 +1,323 @@
+//! Per-database access control (authorized writers)
+//!
+//! A database named `<name>-<owner_public_key>` belongs to that key. The owner
+//! publishes a signed `WriterList` naming additional public keys allowed to
+//! write; operations signed by anyone else are rejected by the sync store.
+//! Databases without a list only accept the owner. Legacy databases whose name
+//! carries no owner key are left open, as before.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use ed25519_dalek::SigningKey;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::crypto;
+use crate::storage::{Storage, INTERNAL_TREE_PREFIX};
+
+/// Signed list of public keys allowed to write to a database
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WriterList {
+    pub db_name: String,
+    /// Owner public key (hex), must match the db name suffix
+    pub owner: String,
+    /// Additional writers (hex public keys), sorted; the owner is implicit
+    pub writers: Vec<String>,
+    /// Monotonic version, newer lists replace older ones
+    pub version: u64,
+    /// Owner's Ed25519 signature over `signing_message()` (hex)
+    pub signature: String,
+}
+
+impl WriterList {
+    /// Build and sign a writer list with the owner's key
+    pub fn create_and_sign(
+        db_name: &str,
+        mut writers: Vec<String>,
+        version: u64,
+        owner_key: &SigningKey,
+    ) -> Result<Self> {
+        let owner = crypto::public_key_hex(owner_key);
+        crypto::verify_db_name_secure(db_name, &owner)?;
+
+        writers.retain(|w| *w != owner);
+        writers.sort();
+        writers.dedup();
+
+        let mut list = Self {
+            db_name: db_name.to_string(),
+            owner,
+            writers,
+            version,
+            signature: String::new(),
+        };
+        list.signature = crypto::sign_message(owner_key, list.signing_message().as_bytes());
+        Ok(list)
+    }
+
+    /// Message covered by the signature: acl:<db_name>:<version>:<w1,w2,...>
+    pub fn signing_message(&self) -> String {
+        format!("acl:{}:{}:{}", self.db_name, self.version, self.writers.join(","))
+    }
+
+    /// Check that the list is signed by the database owner
+    pub fn verify(&self) -> Result<()> {
+        crypto::verify_db_name_secure(&self.db_name, &self.owner)?;
+        if !self.writers.windows(2).all(|pair| pair[0] < pair[1]) {
+            return Err(anyhow!("Writer list for {} is not sorted", self.db_name));
+        }
+        for writer in &self.writers {
+            if writer.len() != crypto::ED25519_PUBLIC_KEY_LENGTH * 2 {
+                return Err(anyhow!("Invalid writer public key {}", writer));
+            }
+            crypto::secure_hex_decode(writer)?;
+        }
+        if !crypto::verify_signature(&self.owner, self.signing_message().as_bytes(), &self.signature)? {
+            return Err(anyhow!("Invalid writer list signature for {}", self.db_name));
+        }
+        Ok(())
+    }
+
+    /// Whether `public_key` may write to the database
+    pub fn allows(&self, public_key: &str) -> bool {
+        public_key == self.owner || self.writers.binary_search_by(|w| w.as_str().cmp(public_key)).is_ok()
+    }
+}
+
+/// Owner-signed deletion of a whole database. Operations written up to
+/// `dropped_at` are removed and rejected; later writes recreate the database.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DbTombstone {
+    pub db_name: String,
+    /// Owner public key (hex), must match the db name suffix
+    pub owner: String,
+    /// Unix timestamp (milliseconds)
+    pub dropped_at: i64,
+    /// Owner's Ed25519 signature over `signing_message()` (hex)
+    pub signature: String,
+}
+
+impl DbTombstone {
+    /// Build and sign a tombstone with the owner's key
+    pub fn create_and_sign(db_name: &str, owner_key: &SigningKey) -> Result<Self> {
+        let owner = crypto::public_key_hex(owner_key);
+        crypto::verify_db_name_secure(db_name, &owner)?;
+
+        let mut tombstone = Self {
+            db_name: db_name.to_string(),
+            owner,
+            dropped_at: chrono::Utc::now().timestamp_millis(),
+            signature: String::new(),
+        };
+        tombstone.signature = crypto::sign_message(owner_key, tombstone.signing_message().as_bytes());
+        Ok(tombstone)
+    }
+
+    /// Message covered by the signature: drop:<db_name>:<dropped_at>
+    pub fn signing_message(&self) -> String {
+        format!("drop:{}:{}", self.db_name, self.dropped_at)
+    }
+
+    /// Check that the tombstone is signed by the database owner
+    pub fn verify(&self) -> Result<()> {
+        crypto::verify_db_name_secure(&self.db_name, &self.owner)?;
+        if !crypto::verify_signature(&self.owner, self.signing_message().as_bytes(), &self.signature)? {
+            return Err(anyhow!("Invalid tombstone signature for {}", self.db_name));
+        }
+        Ok(())
+    }
+
+    /// Whether an operation written at `timestamp` was deleted by this drop
+    pub fn covers(&self, timestamp: i64) -> bool {
+        timestamp <= self.dropped_at
+    }
+}
+
+/// Owner public key encoded in a `<name>-<public_key>` database name
+pub fn db_owner(db_name: &str) -> Option<&str> {
+    let (_, owner) = db_name.rsplit_once('-')?;
+    let valid = owner.len() == crypto::ED25519_PUBLIC_KEY_LENGTH * 2
+        && owner.bytes().all(|b| b.is_ascii_hexdigit());
+    valid.then_some(owner)
+}
+
+/// Verified writer lists, persisted in storage
+pub struct AclStore {
+    storage: Arc<Storage>,
+    lists: RwLock<HashMap<String, WriterList>>,
+}
+
+impl AclStore {
+    pub fn new(storage: Arc<Storage>) -> Self {
+        Self {
+            storage,
+            lists: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Load persisted writer lists (call on startup)
+    pub fn load(&self) -> Result<usize> {
+        let mut lists = self.lists.write();
+        for bytes in self.storage.get_all_acls()? {
+            match serde_json::from_slice::<WriterList>(&bytes) {
+                Ok(list) if list.verify().is_ok() => {
+                    lists.insert(list.db_name.clone(), list);
+                }
+                _ => warn!("Skipping invalid persisted writer list"),
+            }
+        }
+        info!("Loaded {} writer lists", lists.len());
+        Ok(lists.len())
+    }
+
+    /// Current writer list of a database, if the owner published one
+    pub fn get(&self, db_name: &str) -> Option<WriterList> {
+        self.lists.read().get(db_name).cloned()
+    }
+
+    /// All known writer lists for the given databases
+    pub fn lists_for<'a>(&self, db_names: impl IntoIterator<Item = &'a str>) -> Vec<WriterList> {
+        let lists = self.lists.read();
+        let mut found: Vec<WriterList> = db_names.into_iter().filter_map(|db| lists.get(db).cloned()).collect();
+        found.sort_by(|a, b| a.db_name.cmp(&b.db_name));
+        found.dedup_by(|a, b| a.db_name == b.db_name);
+        found
+    }
+
+    /// Verify and store a writer list. Returns false if it is not newer than
+    /// the one we already have.
+    pub fn apply(&self, list: WriterList) -> Result<bool> {
+        list.verify()?;
+
+        let mut lists = self.lists.write();
+        if let Some(existing) = lists.get(&list.db_name) {
+            if list.version <= existing.version {
+                debug!(db = %list.db_name, version = list.version, "Ignoring stale writer list");
+                return Ok(false);
+            }
+        }
+
+        self.storage.put_acl(&list.db_name, &serde_json::to_vec(&list)?)?;
+        info!(db = %list.db_name, version = list.version, writers = list.writers.len(), "Applied writer list");
+        lists.insert(list.db_name.clone(), list);
+        Ok(true)
+    }
+
+    /// Whether an operation signed by `public_key` may be written to `db_name`
+    pub fn is_authorized(&self, db_name: &str, public_key: &str) -> bool {
+        // Internal trees are only written locally, whoever signs
+        if db_name.starts_with(INTERNAL_TREE_PREFIX) {
+            return false;
+        }
+        if let Some(list) = self.lists.read().get(db_name) {
+            return list.allows(public_key);
+        }
+        match db_owner(db_name) {
+            Some(owner) => owner == public_key,
+            // Legacy database without an owner key in its name
+            None => true,
+        }
+    }
+
+    /// Build the next signed writer list for `db_name` with `grant` added and
+    /// `revoke` removed
+    pub fn next_list(
+        &self,
+        db_name: &str,
+        grant: &[String],
+        revoke: &[String],
+        owner_key: &SigningKey,
+    ) -> Result<WriterList> {
+        let current = self.get(db_name);
+        let mut writers = current.as_ref().map(|l| l.writers.clone()).unwrap_or_default();
+        writers.extend(grant.iter().cloned());
+        writers.retain(|w| !revoke.contains(w));
+
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        let version = current.map_or(now, |l| now.max(l.version + 1));
+        WriterList::create_and_sign(db_name, writers, version, owner_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_writer_list_sign_and_verify() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let (_, writer) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+
+        let list = WriterList::create_and_sign(&db_name, vec![writer.clone()], 1, &owner_key).unwrap();
+        assert!(list.verify().is_ok());
+        assert!(list.allows(&owner));
+        assert!(list.allows(&writer));
+
+        let mut tampered = list.clone();
+        tampered.writers.push("ab".repeat(32));
+        assert!(tampered.verify().is_err());
+    }
+
+    #[test]
+    fn test_writer_list_requires_db_owner() {
+        let (owner_key, _) = crypto::generate_keypair();
+        let (_, other) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &other);
+
+        assert!(WriterList::create_and_sign(&db_name, vec![], 1, &owner_key).is_err());
+    }
+
+    #[test]
+    fn test_tombstone_sign_and_verify() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let (other_key, _) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+
+        let tombstone = DbTombstone::create_and_sign(&db_name, &owner_key).unwrap();
+        assert!(tombstone.verify().is_ok());
+        assert!(tombstone.covers(tombstone.dropped_at));
+        assert!(!tombstone.covers(tombstone.dropped_at + 1));
+
+        let mut moved = tombstone.clone();
+        moved.dropped_at += 1000;
+        assert!(moved.verify().is_err());
+
+        assert!(DbTombstone::create_and_sign(&db_name, &other_key).is_err());
+    }
+
+    #[test]
+    fn test_acl_store_authorization() {
+        let dir = tempdir().unwrap();
+        let storage = Arc::new(Storage::new(dir.path().to_path_buf()).unwrap());
+        let acl = AclStore::new(storage.clone());
+
+        let (owner_key, owner) = crypto::generate_keypair();
+        let (_, writer) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+
+        // Without a list only the owner may write; legacy names stay open
+        assert!(acl.is_authorized(&db_name, &owner));
+        assert!(!acl.is_authorized(&db_name, &writer));
+        assert!(acl.is_authorized("legacy", &writer));
+        assert!(!acl.is_authorized("__db_keys__", &writer));
+
+        let granted = acl.next_list(&db_name, std::slice::from_ref(&writer), &[], &owner_key).unwrap();
+        assert!(acl.apply(granted.clone()).unwrap());
+        assert!(acl.is_authorized(&db_name, &writer));
+        assert!(!acl.apply(granted).unwrap());
+
+        let revoked = acl.next_list(&db_name, &[], std::slice::from_ref(&writer), &owner_key).unwrap();
+        assert!(acl.apply(revoked).unwrap());
+        assert!(!acl.is_authorized(&db_name, &writer));
+
+        // Persisted lists survive a reload
+        let reloaded = AclStore::new(storage);
+        assert_eq!(reloaded.load().unwrap(), 1);
+        assert!(!reloaded.is_authorized(&db_name, &writer));
+    }
+}
@@ -3,46 +3,109 @@
 //! This module exposes Rust functions to Flutter via FFI.
 
 use std::sync::Arc;
-use std::io::Write;
-use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+use std::collections::HashMap;
 use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
 use tokio::runtime::Runtime;
+use tokio_util::sync::CancellationToken;
 use flutter_rust_bridge::frb;
 use log::{info, error, warn};
 
-use crate::node::CyberflyNode;
-use crate::discovery::DiscoveredPeer;
+use crate::frb_generated::StreamSink;
+use crate::auth::VerifiedBinding;
+use crate::devices::DeviceCertificate;
+use crate::dm::DirectMessage;
+use crate::files::{FileEvent, FileOffer};
+use crate::graphql;
+use crate::events::RecordedEvent;
+use crate::node::{ConnectivityState, CyberflyNode, NodeEvent, StartupStage, TopicMessage, WakeSyncSummary};
+use crate::storage::{DataPage, ScanOptions, ScanPage, Storage, StorageChange};
+use crate::text::TextDelta;
+use crate::typed::{self, GeoMember, ScoredMember, TimePoint, TypedWrite};
+use crate::discovery::{DiscoveredPeer, PeerPath};
+use crate::health::{HealthStatus, SubsystemHealth};
+use crate::identity::{SuccessorRecord, DEFAULT_GRACE_PERIOD};
+use crate::kadena::{self, NodeRegistration};
+use crate::logs::{self, LogBuffer, LogFilter, LogLevels, LogRecord, SpanLogLayer};
+use crate::keystore::{self, WRAPPING_KEY_LENGTH};
+use crate::config::{BootstrapManifestSource, NodeConfig, RelayConfig};
+use crate::sync::{OperationLimits, OplogGcPolicy};
 use crate::crypto;
+use crate::metrics::{BandwidthCaps, ByteCounts, Subsystem};
+use crate::mqtt::{BridgeDirection, MqttBridgeConfig, TopicMapping, DEFAULT_MQTT_PORT};
+use crate::network_resilience::CircuitState;
+use crate::power::{ConnectionType, DeviceConditions, PowerMode};
+use crate::private_network::NetworkKey;
 
-/// Global node instance
-static NODE: OnceCell<Arc<RwLock<Option<Arc<CyberflyNode>>>>> = OnceCell::new();
+/// Running nodes by handle. The functions without a handle use
+/// `DEFAULT_NODE_HANDLE`, the node of `start_node`.
+static NODES: OnceCell<RwLock<HashMap<u32, Arc<CyberflyNode>>>> = OnceCell::new();
+
+/// Handle of the node started with `start_node`
+pub const DEFAULT_NODE_HANDLE: u32 = 0;
+
+/// Next handle `start_node_instance` gives out
+static NEXT_NODE_HANDLE: AtomicU32 = AtomicU32::new(DEFAULT_NODE_HANDLE + 1);
+
+/// Part of a `wake_and_sync` budget kept for stopping the node
+const WAKE_STOP_RESERVE: Duration = Duration::from_secs(2);
+
+/// Time `checkpoint` may take by default
+const DEFAULT_CHECKPOINT_BUDGET: Duration = Duration::from_secs(2);
 
 /// Global tokio runtime
 static RUNTIME: OnceCell<Runtime> = OnceCell::new();
 
 /// Global log buffer for Flutter UI - circular buffer of recent logs
-const MAX_LOG_ENTRIES: usize = 500;
-static LOG_BUFFER: OnceCell<Arc<RwLock<VecDeque<LogEntry>>>> = OnceCell::new();
+static LOG_BUFFER: OnceCell<RwLock<LogBuffer>> = OnceCell::new();
 
-fn get_log_buffer() -> &'static Arc<RwLock<VecDeque<LogEntry>>> {
-    LOG_BUFFER.get_or_init(|| Arc::new(RwLock::new(VecDeque::with_capacity(MAX_LOG_ENTRIES))))
+fn get_log_buffer() -> &'static RwLock<LogBuffer> {
+    LOG_BUFFER.get_or_init(|| RwLock::new(LogBuffer::default()))
 }
 
-/// Add a log entry to the buffer (called from our custom logger)
-pub fn add_log_entry(level: &str, message: String) {
-    let buffer = get_log_buffer();
-    let mut guard = buffer.write();
-    if guard.len() >= MAX_LOG_ENTRIES {
-        guard.pop_front();
-    }
-    guard.push_back(LogEntry {
+fn record_log(level: log::Level, target: &str, message: String) {
+    get_log_buffer().write().push(LogRecord {
         timestamp: chrono::Utc::now().timestamp_millis(),
-        level: level.to_string(),
+        level,
+        target: target.to_string(),
         message,
     });
 }
 
+/// Levels logged, adjustable with `set_log_level` and `set_log_target_level`
+static LOG_LEVELS: OnceCell<RwLock<LogLevels>> = OnceCell::new();
+
+/// Swaps the tracing filter when the levels change
+static LOG_FILTER_HANDLE: OnceCell<
+    tracing_subscriber::reload::Handle<tracing_subscriber::EnvFilter, tracing_subscriber::Registry>,
+> = OnceCell::new();
+
+fn get_log_levels() -> &'static RwLock<LogLevels> {
+    LOG_LEVELS.get_or_init(|| RwLock::new(LogLevels::default()))
+}
+
+/// Make the loggers follow the current levels
+fn apply_log_levels() -> Result<(), String> {
+    let levels = get_log_levels().read().clone();
+    log::set_max_level(levels.max_level());
+    if let Some(handle) = LOG_FILTER_HANDLE.get() {
+        let filter = tracing_subscriber::EnvFilter::try_new(levels.directives()).map_err(|e| e.to_string())?;
+        handle.reload(filter).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Add a log entry to the buffer under the "flutter" target, if that's
+/// logged at its level; unknown levels are taken as info
+pub fn add_log_entry(level: &str, message: String) {
+    let level = logs::parse_level(level).unwrap_or(log::Level::Info);
+    if get_log_levels().read().enabled("flutter", level) {
+        record_log(level, "flutter", message);
+    }
+}
+
 fn get_runtime() -> &'static Runtime {
     RUNTIME.get_or_init(|| {
         tokio::runtime::Builder::new_multi_thread()
@@ -52,13 +115,36 @@ fn get_runtime() -> &'static Runtime {
     })
 }
 
-fn get_node_holder() -> &'static Arc<RwLock<Option<Arc<CyberflyNode>>>> {
-    NODE.get_or_init(|| Arc::new(RwLock::new(None)))
+fn get_nodes() -> &'static RwLock<HashMap<u32, Arc<CyberflyNode>>> {
+    NODES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+fn get_node_handle(handle: u32) -> Result<Arc<CyberflyNode>, String> {
+    let nodes = get_nodes().read();
+    match nodes.get(&handle) {
+        Some(node) => Ok(node.clone()),
+        None if handle == DEFAULT_NODE_HANDLE => Err("Node not running".to_string()),
+        None => Err(format!("Node {} not running", handle)),
+    }
+}
+
+/// Storage opened read-only while the node is stopped (recovery screen,
+/// companion tools)
+static READ_ONLY_STORAGE: OnceCell<RwLock<Option<ReadOnlyStorage>>> = OnceCell::new();
+
+#[derive(Clone)]
+struct ReadOnlyStorage {
+    data_dir: std::path::PathBuf,
+    storage: Storage,
+}
+
+fn get_read_only_holder() -> &'static RwLock<Option<ReadOnlyStorage>> {
+    READ_ONLY_STORAGE.get_or_init(|| RwLock::new(None))
 }
 
-fn get_node() -> Result<Arc<CyberflyNode>, String> {
-    let guard = get_node_holder().read();
-    guard.clone().ok_or_else(|| "Node not running".to_string())
+fn get_read_only_storage() -> Result<ReadOnlyStorage, String> {
+    let guard = get_read_only_holder().read();
+    guard.clone().ok_or_else(|| "Storage not open read-only".to_string())
 }
 
 /// Node info returned to Flutter
@@ -109,6 +195,357 @@ pub struct NodeStatusDto {
     pub sync_operations: u32,
     pub latency_requests_sent: u64,
     pub latency_responses_received: u64,
+    /// Activity of the node's gossip topics: data, discovery, sync, peers
+    /// and discovery-v2
+    pub topics: Vec<TopicStatsDto>,
+}
+
+/// Session activity of one gossip topic for Flutter
+#[frb(dart_metadata=("freezed"))]
+pub struct TopicStatsDto {
+    pub topic: String,
+    pub messages_sent: u64,
+    pub messages_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub neighbors: u32,
+    /// Last message or neighbor change (ms); None if nothing arrived yet
+    pub last_event_at: Option<i64>,
+}
+
+/// Traffic and connection counters for Flutter, for this session and over
+/// every session of the node
+#[frb(dart_metadata=("freezed"))]
+pub struct NodeStatsDto {
+    pub uptime_seconds: u64,
+    pub total_operations: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub connects: u64,
+    pub reconnects: u64,
+    pub lifetime_sessions: u64,
+    pub lifetime_uptime_seconds: u64,
+    pub lifetime_bytes_sent: u64,
+    pub lifetime_bytes_received: u64,
+    pub lifetime_connects: u64,
+    pub lifetime_reconnects: u64,
+    /// Sync messages queued until a peer is reachable
+    pub outbox_depth: u64,
+}
+
+/// Relay servers, see `RelayConfig`
+pub enum RelayConfigDto {
+    /// n0's public relays
+    Default,
+    /// Direct connections only, e.g. LAN-only deployments
+    Disabled,
+    Custom { urls: Vec<String> },
+}
+
+impl From<RelayConfig> for RelayConfigDto {
+    fn from(relay: RelayConfig) -> Self {
+        match relay {
+            RelayConfig::Default => RelayConfigDto::Default,
+            RelayConfig::Disabled => RelayConfigDto::Disabled,
+            RelayConfig::Custom(urls) => RelayConfigDto::Custom { urls },
+        }
+    }
+}
+
+impl From<RelayConfigDto> for RelayConfig {
+    fn from(dto: RelayConfigDto) -> Self {
+        match dto {
+            RelayConfigDto::Default => RelayConfig::Default,
+            RelayConfigDto::Disabled => RelayConfig::Disabled,
+            RelayConfigDto::Custom { urls } => RelayConfig::Custom(urls),
+        }
+    }
+}
+
+/// Signed bootstrap peer list to fetch at startup, see `BootstrapManifestSource`
+#[frb(dart_metadata=("freezed"))]
+pub struct BootstrapManifestDto {
+    /// HTTPS URL of the manifest
+    pub url: String,
+    /// Public key (hex) the manifest must be signed with
+    pub publisher_key: String,
+}
+
+impl From<BootstrapManifestSource> for BootstrapManifestDto {
+    fn from(source: BootstrapManifestSource) -> Self {
+        Self { url: source.url, publisher_key: source.publisher_key }
+    }
+}
+
+impl From<BootstrapManifestDto> for BootstrapManifestSource {
+    fn from(dto: BootstrapManifestDto) -> Self {
+        Self { url: dto.url, publisher_key: dto.publisher_key }
+    }
+}
+
+/// Superseded operations removed from the oplog, see `OplogGcPolicy`
+#[frb(dart_metadata=("freezed"))]
+pub struct OplogGcDto {
+    /// Newest versions kept per key, the current one included
+    pub keep_versions: u32,
+    /// Versions written within this are kept too
+    pub keep_for_secs: u64,
+}
+
+impl From<OplogGcPolicy> for OplogGcDto {
+    fn from(policy: OplogGcPolicy) -> Self {
+        Self { keep_versions: policy.keep_versions as u32, keep_for_secs: policy.keep_for.as_secs() }
+    }
+}
+
+impl From<OplogGcDto> for OplogGcPolicy {
+    fn from(dto: OplogGcDto) -> Self {
+        Self { keep_versions: dto.keep_versions as usize, keep_for: Duration::from_secs(dto.keep_for_secs) }
+    }
+}
+
+/// Node configuration for Flutter, see `NodeConfig`. Start from
+/// `default_node_config()`. Region, network ID, relays, the bootstrap
+/// manifest and known peer dials only apply at startup; the rest can be changed with `update_config()`.
+#[frb(dart_metadata=("freezed"))]
+pub struct NodeConfigDto {
+    pub region: Option<String>,
+    pub network_id: Option<String>,
+    pub max_known_peer_dials: u32,
+    pub announce_interval_secs: u64,
+    pub latency_sweep_interval_secs: u64,
+    pub pinned_peers_check_interval_secs: u64,
+    pub isolation_check_interval_secs: u64,
+    pub isolation_checks_before_reconnect: u32,
+    pub max_ops_per_response: u32,
+    pub max_connection_attempts_per_cycle: u32,
+    /// How often the next queued peer is dialed
+    pub dial_interval_ms: u64,
+    pub relay: RelayConfigDto,
+    pub bootstrap_manifest: Option<BootstrapManifestDto>,
+    /// Largest value accepted from peers
+    pub max_value_bytes: u32,
+    /// Longest key (and field, database name) accepted from peers
+    pub max_key_length: u32,
+    /// Store types accepted from peers
+    pub allowed_store_types: Vec<String>,
+    /// None keeps every superseded operation
+    pub oplog_gc: Option<OplogGcDto>,
+    /// Largest gossip message; larger data and sync messages are chunked
+    pub max_gossip_message_size: u32,
+}
+
+impl From<NodeConfig> for NodeConfigDto {
+    fn from(config: NodeConfig) -> Self {
+        Self {
+            region: config.region,
+            network_id: config.network_id,
+            max_known_peer_dials: config.max_known_peer_dials as u32,
+            announce_interval_secs: config.announce_interval.as_secs(),
+            latency_sweep_interval_secs: config.latency_sweep_interval.as_secs(),
+            pinned_peers_check_interval_secs: config.pinned_peers_check_interval.as_secs(),
+            isolation_check_interval_secs: config.isolation_check_interval.as_secs(),
+            isolation_checks_before_reconnect: config.isolation_checks_before_reconnect,
+            max_ops_per_response: config.max_ops_per_response as u32,
+            max_connection_attempts_per_cycle: config.max_connection_attempts_per_cycle,
+            dial_interval_ms: config.dial_interval.as_millis() as u64,
+            relay: config.relay.into(),
+            bootstrap_manifest: config.bootstrap_manifest.map(BootstrapManifestDto::from),
+            max_value_bytes: config.operation_limits.max_value_bytes as u32,
+            max_key_length: config.operation_limits.max_key_len as u32,
+            allowed_store_types: config.operation_limits.store_types,
+            oplog_gc: config.oplog_gc.map(OplogGcDto::from),
+            max_gossip_message_size: config.max_gossip_message_size as u32,
+        }
+    }
+}
+
+impl From<NodeConfigDto> for NodeConfig {
+    fn from(dto: NodeConfigDto) -> Self {
+        Self {
+            region: dto.region,
+            network_id: dto.network_id,
+            max_known_peer_dials: dto.max_known_peer_dials as usize,
+            announce_interval: Duration::from_secs(dto.announce_interval_secs),
+            latency_sweep_interval: Duration::from_secs(dto.latency_sweep_interval_secs),
+            pinned_peers_check_interval: Duration::from_secs(dto.pinned_peers_check_interval_secs),
+            isolation_check_interval: Duration::from_secs(dto.isolation_check_interval_secs),
+            isolation_checks_before_reconnect: dto.isolation_checks_before_reconnect,
+            max_ops_per_response: dto.max_ops_per_response as usize,
+            max_connection_attempts_per_cycle: dto.max_connection_attempts_per_cycle,
+            dial_interval: Duration::from_millis(dto.dial_interval_ms),
+            relay: dto.relay.into(),
+            bootstrap_manifest: dto.bootstrap_manifest.map(BootstrapManifestSource::from),
+            operation_limits: OperationLimits {
+                max_value_bytes: dto.max_value_bytes as usize,
+                max_key_len: dto.max_key_length as usize,
+                store_types: dto.allowed_store_types,
+            },
+            oplog_gc: dto.oplog_gc.map(OplogGcPolicy::from),
+            max_gossip_message_size: dto.max_gossip_message_size as usize,
+        }
+    }
+}
+
+/// Connection type reported by Flutter
+pub enum ConnectionTypeDto {
+    Wifi,
+    Ethernet,
+    Cellular,
+    Other,
+    Offline,
+}
+
+/// Battery and network state reported by Flutter
+#[frb(dart_metadata=("freezed"))]
+pub struct DeviceConditionsDto {
+    /// 0-100, None when unknown
+    pub battery_percent: Option<u8>,
+    pub charging: bool,
+    pub connection_type: ConnectionTypeDto,
+    pub metered: bool,
+}
+
+/// How the node currently adapts to the device conditions
+#[frb(dart_metadata=("freezed"))]
+pub struct PowerPolicyDto {
+    pub low_power: bool,
+    pub metered: bool,
+    /// A full sync waits for the device to charge or leave metered data
+    pub sync_deferred: bool,
+    pub blob_transfers_allowed: bool,
+}
+
+/// Power mode picked by the user
+pub enum PowerModeDto {
+    Normal,
+    /// Sync topic only, through one bootstrap peer, with periodic work 6x
+    /// less often; for running overnight in a foreground service
+    Lite,
+}
+
+impl From<PowerMode> for PowerModeDto {
+    fn from(mode: PowerMode) -> Self {
+        match mode {
+            PowerMode::Normal => Self::Normal,
+            PowerMode::Lite => Self::Lite,
+        }
+    }
+}
+
+impl From<PowerModeDto> for PowerMode {
+    fn from(mode: PowerModeDto) -> Self {
+        match mode {
+            PowerModeDto::Normal => Self::Normal,
+            PowerModeDto::Lite => Self::Lite,
+        }
+    }
+}
+
+/// Part of the node traffic is attributed to
+pub enum BandwidthSubsystemDto {
+    Gossip,
+    Sync,
+    Blobs,
+    Discovery,
+}
+
+impl From<Subsystem> for BandwidthSubsystemDto {
+    fn from(subsystem: Subsystem) -> Self {
+        match subsystem {
+            Subsystem::Gossip => BandwidthSubsystemDto::Gossip,
+            Subsystem::Sync => BandwidthSubsystemDto::Sync,
+            Subsystem::Blobs => BandwidthSubsystemDto::Blobs,
+            Subsystem::Discovery => BandwidthSubsystemDto::Discovery,
+        }
+    }
+}
+
+/// Session traffic of a gossip topic (application topics are `app:<name>`)
+/// or subsystem
+#[frb(dart_metadata=("freezed"))]
+pub struct BandwidthUsageDto {
+    pub name: String,
+    pub subsystem: BandwidthSubsystemDto,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+}
+
+/// Data caps for Flutter; None means no cap
+#[frb(dart_metadata=("freezed"))]
+pub struct BandwidthCapsDto {
+    pub daily_bytes: Option<u64>,
+    pub monthly_bytes: Option<u64>,
+    /// Only count traffic while on metered data (see `set_device_conditions`)
+    pub metered_only: bool,
+}
+
+impl From<BandwidthCaps> for BandwidthCapsDto {
+    fn from(caps: BandwidthCaps) -> Self {
+        Self { daily_bytes: caps.daily_bytes, monthly_bytes: caps.monthly_bytes, metered_only: caps.metered_only }
+    }
+}
+
+/// Bandwidth used this session, and towards the caps today and this month (UTC)
+#[frb(dart_metadata=("freezed"))]
+pub struct BandwidthStatsDto {
+    pub topics: Vec<BandwidthUsageDto>,
+    pub subsystems: Vec<BandwidthUsageDto>,
+    pub today_bytes: u64,
+    pub month_bytes: u64,
+    pub caps: BandwidthCapsDto,
+    /// Sync is paused until the day or month is over
+    pub cap_exceeded: bool,
+}
+
+/// How well the node is connected, for an offline banner
+pub enum ConnectivityStateDto {
+    Online,
+    /// Connected, but without a relay or still without gossip neighbors
+    Degraded,
+    /// No gossip neighbors, or networking paused
+    Isolated,
+}
+
+impl From<ConnectivityState> for ConnectivityStateDto {
+    fn from(state: ConnectivityState) -> Self {
+        match state {
+            ConnectivityState::Online => ConnectivityStateDto::Online,
+            ConnectivityState::Degraded => ConnectivityStateDto::Degraded,
+            ConnectivityState::Isolated => ConnectivityStateDto::Isolated,
+        }
+    }
+}
+
+/// Connectivity and a short reason, e.g. "no gossip neighbors"
+#[frb(dart_metadata=("freezed"))]
+pub struct ConnectivityDto {
+    pub state: ConnectivityStateDto,
+    pub reason: String,
+}
+
+/// Step of starting the node, see `start_node_with_progress`
+#[frb(dart_metadata=("freezed"))]
+pub enum StartupStageDto {
+    KeyLoaded,
+    /// The endpoint is bound; the relay may still be connecting
+    EndpointOnline { relay_connected: bool },
+    /// Bootstrap peers are being dialed in the background
+    BootstrapConnecting { attempt: u32 },
+    TopicsSubscribed,
+    Ready,
+}
+
+impl From<StartupStage> for StartupStageDto {
+    fn from(stage: StartupStage) -> Self {
+        match stage {
+            StartupStage::KeyLoaded => StartupStageDto::KeyLoaded,
+            StartupStage::EndpointOnline { relay_connected } => StartupStageDto::EndpointOnline { relay_connected },
+            StartupStage::BootstrapConnecting { attempt } => StartupStageDto::BootstrapConnecting { attempt },
+            StartupStage::TopicsSubscribed => StartupStageDto::TopicsSubscribed,
+            StartupStage::Ready => StartupStageDto::Ready,
+        }
+    }
 }
 
 /// Log entry for Flutter console
@@ -128,12 +565,104 @@ pub enum NodeEventDto {
     PeerConnected { peer_id: String },
     PeerDisconnected { peer_id: String },
     PeerDiscovered { peer_id: String, address: Option<String> },
-    GossipReceived { topic: String, from: String, content: String },
+    /// `verified` is false if `from` couldn't be authenticated
+    GossipReceived { topic: String, from: String, content: String, verified: bool },
     SyncReceived { db_name: String, key: String },
+    SyncProgress { db_name: String, ops_received: u64, ops_total_estimate: u64, bytes: u64 },
+    ConflictResolved { db_name: String, key: String, kept_op_id: String, discarded_op_id: String },
     LatencyMeasured { peer_id: String, latency_ms: u64 },
+    PresenceChanged { peer_id: String, online: bool, status: Option<String> },
+    TopicRecovered { topic: String },
+    RelayConnected { relay_url: String },
+    BootstrapConnected { peer_id: String },
+    IdentityRotated { old_node_id: String, new_node_id: String },
+    DeviceLinked { user_public_key: String, device_node_id: String, device_name: String },
+    ConnectivityChanged { state: ConnectivityStateDto, reason: String },
     Error { message: String },
 }
 
+impl From<NodeEvent> for NodeEventDto {
+    fn from(event: NodeEvent) -> Self {
+        match event {
+            NodeEvent::Started { node_id, public_key } => NodeEventDto::Started { node_id, public_key },
+            NodeEvent::Stopped => NodeEventDto::Stopped,
+            NodeEvent::PeerConnected { peer_id } => NodeEventDto::PeerConnected { peer_id },
+            NodeEvent::PeerDisconnected { peer_id } => NodeEventDto::PeerDisconnected { peer_id },
+            NodeEvent::PeerDiscovered { peer_id, address } => NodeEventDto::PeerDiscovered { peer_id, address },
+            NodeEvent::GossipReceived { topic, from, content, verified } => {
+                NodeEventDto::GossipReceived { topic, from, content, verified }
+            }
+            NodeEvent::SyncReceived { db_name, key } => NodeEventDto::SyncReceived { db_name, key },
+            NodeEvent::SyncProgress { db_name, ops_received, ops_total_estimate, bytes } => {
+                NodeEventDto::SyncProgress { db_name, ops_received, ops_total_estimate, bytes }
+            }
+            NodeEvent::ConflictResolved { db_name, key, kept_op_id, discarded_op_id } => {
+                NodeEventDto::ConflictResolved { db_name, key, kept_op_id, discarded_op_id }
+            }
+            NodeEvent::LatencyMeasured { peer_id, latency_ms } => NodeEventDto::LatencyMeasured { peer_id, latency_ms },
+            NodeEvent::PresenceChanged { peer_id, online, status } => {
+                NodeEventDto::PresenceChanged { peer_id, online, status }
+            }
+            NodeEvent::TopicRecovered { topic } => NodeEventDto::TopicRecovered { topic },
+            NodeEvent::RelayConnected { relay_url } => NodeEventDto::RelayConnected { relay_url },
+            NodeEvent::BootstrapConnected { peer_id } => NodeEventDto::BootstrapConnected { peer_id },
+            NodeEvent::IdentityRotated { old_node_id, new_node_id } => {
+                NodeEventDto::IdentityRotated { old_node_id, new_node_id }
+            }
+            NodeEvent::DeviceLinked { user_public_key, device_node_id, device_name } => {
+                NodeEventDto::DeviceLinked { user_public_key, device_node_id, device_name }
+            }
+            NodeEvent::ConnectivityChanged { state, reason } => {
+                NodeEventDto::ConnectivityChanged { state: state.into(), reason }
+            }
+            NodeEvent::Error { message } => NodeEventDto::Error { message },
+        }
+    }
+}
+
+/// A node event with its sequence number, see `get_recent_events`
+#[frb(dart_metadata=("freezed"))]
+pub struct NodeEventRecordDto {
+    pub seq: u64,
+    /// Unix ms
+    pub timestamp: i64,
+    pub event: NodeEventDto,
+}
+
+impl From<RecordedEvent> for NodeEventRecordDto {
+    fn from(recorded: RecordedEvent) -> Self {
+        Self { seq: recorded.seq, timestamp: recorded.timestamp, event: recorded.event.into() }
+    }
+}
+
+/// Per-peer sync status for Flutter
+#[frb(dart_metadata=("freezed"))]
+pub struct PeerSyncStatusDto {
+    pub peer_id: String,
+    pub ops_sent: u64,
+    pub ops_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub last_request_at: Option<i64>,
+    pub last_response_at: Option<i64>,
+    pub in_progress: bool,
+    /// Our writes this peer confirmed holding
+    pub ops_acked: u64,
+    pub last_ack_at: Option<i64>,
+}
+
+/// Sync statistics for Flutter
+#[frb(dart_metadata=("freezed"))]
+pub struct SyncStatsDto {
+    pub total_operations: u64,
+    pub ops_sent: u64,
+    pub ops_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub last_sync_at: Option<i64>,
+    pub peers: Vec<PeerSyncStatusDto>,
+}
+
 /// Keypair for signing
 #[frb(dart_metadata=("freezed"))]
 pub struct KeyPairDto {
@@ -141,41 +670,95 @@ pub struct KeyPairDto {
     pub secret_key: String,
 }
 
-/// Initialize logging
+/// Initialize logging at info, with iroh's internals at warn; see
+/// `set_log_level` and `set_log_target_level` to change that later
 #[frb(sync)]
 pub fn init_logging() {
     #[cfg(target_os = "android")]
     {
+        // The logger passes everything; `LogLevels` decides what's written
         android_logger::init_once(
             android_logger::Config::default()
-                .with_max_level(log::LevelFilter::Info)
+                .with_max_level(log::LevelFilter::Trace)
                 .with_tag("CyberflyRust")
                 .format(|buf, record| {
-                    // Filter out very verbose iroh internal logs, but keep important ones
                     let target = record.target();
-                    if target.starts_with("iroh") && record.level() > log::Level::Warn {
+                    if !get_log_levels().read().enabled(target, record.level()) {
                         return Ok(());
                     }
                     
                     // Also capture to our buffer for Flutter UI
                     let message = format!("{}: {}", target, record.args());
-                    add_log_entry(&record.level().to_string(), message.clone());
+                    record_log(record.level(), target, message.clone());
                     
                     writeln!(buf, "[{}] {}", record.level(), message)
                 }),
         );
     }
     
+    // Tracing events keep the fields of their spans (see `SpanLogLayer`)
+    use tracing_subscriber::layer::SubscriberExt;
+    let directives = get_log_levels().read().directives();
+    let (filter, handle) = tracing_subscriber::reload::Layer::new(tracing_subscriber::EnvFilter::new(directives));
+    let registry = tracing_subscriber::registry().with(filter);
+
+    // The android logger writes and buffers them like `log` records
+    #[cfg(target_os = "android")]
+    let installed = tracing::subscriber::set_global_default(registry.with(SpanLogLayer::new(|level, target: &str, line| {
+        log::logger().log(&log::Record::builder().level(level).target(target).args(format_args!("{}", line)).build());
+    })))
+    .is_ok();
+
     #[cfg(not(target_os = "android"))]
-    {
-        // iroh 0.98 folded `iroh_net` into `iroh`; keep `iroh_relay` muted since
-        // relay client chatter is noisy at info level.
-        let _ = tracing_subscriber::fmt()
-            .with_env_filter("warn,cyberfly=info,iroh=error,iroh_gossip=error,iroh_relay=error,quinn=error")
-            .try_init();
+    let installed = {
+        use tracing_subscriber::util::SubscriberInitExt;
+        registry
+            .with(tracing_subscriber::fmt::layer())
+            .with(SpanLogLayer::new(|level, target: &str, line| record_log(level, target, format!("{}: {}", target, line))))
+            .try_init()
+            .is_ok()
+    };
+
+    if installed {
+        let _ = LOG_FILTER_HANDLE.set(handle);
+    }
+    if let Err(e) = apply_log_levels() {
+        warn!("Failed to apply log levels: {}", e);
     }
 }
 
+/// Log at `level` ("off", "error", "warn", "info", "debug" or "trace") for
+/// every target without its own level
+#[frb(sync)]
+pub fn set_log_level(level: String) -> Result<(), String> {
+    let level = logs::parse_level_filter(&level).map_err(|e| e.to_string())?;
+    get_log_levels().write().default = level;
+    apply_log_levels()
+}
+
+/// Log `target`, a module path prefix such as "iroh" or
+/// "rust_lib_cyberfly_mobile_node::sync", at `level`; None returns it to the
+/// default level
+#[frb(sync)]
+pub fn set_log_target_level(target: String, level: Option<String>) -> Result<(), String> {
+    let level = level.as_deref().map(logs::parse_level_filter).transpose().map_err(|e| e.to_string())?;
+    get_log_levels().write().set_target(&target, level).map_err(|e| e.to_string())?;
+    apply_log_levels()
+}
+
+/// Back to the levels `init_logging` starts with
+#[frb(sync)]
+pub fn reset_log_levels() -> Result<(), String> {
+    *get_log_levels().write() = LogLevels::default();
+    apply_log_levels()
+}
+
+/// Current levels as filter directives, e.g. "info,iroh=debug,quinn=warn"
+#[frb(sync)]
+pub fn get_log_levels_filter() -> String {
+    get_log_levels().read().directives()
+}
+
 /// Start the Cyberfly node
 #[frb]
 pub async fn start_node(
@@ -184,309 +767,4500 @@ pub async fn start_node(
     bootstrap_peers: Vec<String>,
     region: Option<String>,
 ) -> Result<NodeInfo, String> {
-    info!(">>> RUST API: start_node called");
-    let runtime = get_runtime();
-    info!(">>> RUST API: got runtime, about to spawn");
-    
-    let result = runtime.spawn(async move {
-        info!(">>> RUST API: inside runtime.spawn, calling CyberflyNode::start");
-        let result = CyberflyNode::start(data_dir, wallet_secret_key, bootstrap_peers, region).await;
-        info!(">>> RUST API: CyberflyNode::start returned: {:?}", result.is_ok());
-        result
-    }).await;
-    
-    info!(">>> RUST API: spawn completed, result: {:?}", result.is_ok());
-    
-    match result {
-        Ok(Ok(node)) => {
-            let info = NodeInfo {
-                node_id: node.node_id().to_string(),
-                public_key: node.public_key().to_string(),
-                is_running: true,
-            };
-            *get_node_holder().write() = Some(Arc::new(node));
-            Ok(info)
-        }
-        Ok(Err(e)) => Err(format!("Failed to start node: {}", e)),
-        Err(e) => Err(format!("Task error: {}", e)),
-    }
+    let config = NodeConfig { region, ..NodeConfig::default() };
+    launch_node(data_dir, wallet_secret_key, bootstrap_peers, config, None).await
 }
 
-/// Stop the node
+/// Start the Cyberfly node in the application network `network_id`. Its
+/// gossip topics are separate from the default network's and from other
+/// applications', so it only meets nodes started with the same ID.
 #[frb]
-pub async fn stop_node() -> Result<(), String> {
-    let node_opt = get_node_holder().write().take();
-    if let Some(node) = node_opt {
-        let runtime = get_runtime();
-        runtime.spawn(async move {
-            if let Ok(inner_node) = Arc::try_unwrap(node) {
-                let _ = inner_node.stop().await;
+pub async fn start_node_in_network(
+    data_dir: String,
+    wallet_secret_key: Option<String>,
+    bootstrap_peers: Vec<String>,
+    region: Option<String>,
+    network_id: String,
+) -> Result<NodeInfo, String> {
+    let config = NodeConfig { region, network_id: Some(network_id), ..NodeConfig::default() };
+    launch_node(data_dir, wallet_secret_key, bootstrap_peers, config, None).await
+}
+
+/// Start the Cyberfly node with `config`
+#[frb]
+pub async fn start_node_with_config(
+    data_dir: String,
+    wallet_secret_key: Option<String>,
+    bootstrap_peers: Vec<String>,
+    config: NodeConfigDto,
+) -> Result<NodeInfo, String> {
+    launch_node(data_dir, wallet_secret_key, bootstrap_peers, config.into(), None).await
+}
+
+/// `start_node_with_config`, streaming each step of starting up to
+/// `progress`. The stream ends after `Ready`, or when starting fails.
+#[frb]
+pub async fn start_node_with_progress(
+    data_dir: String,
+    wallet_secret_key: Option<String>,
+    bootstrap_peers: Vec<String>,
+    config: NodeConfigDto,
+    progress: StreamSink<StartupStageDto>,
+) -> Result<NodeInfo, String> {
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+    get_runtime().spawn(async move {
+        while let Some(stage) = progress_rx.recv().await {
+            if progress.add(StartupStageDto::from(stage)).is_err() {
+                break;
             }
-        }).await.map_err(|e| format!("Task error: {}", e))?;
-    }
-    Ok(())
+        }
+    });
+    launch_node(data_dir, wallet_secret_key, bootstrap_peers, config.into(), Some(progress_tx)).await
 }
 
-/// Check if node is running
+/// Configuration `start_node` uses
 #[frb(sync)]
-pub fn is_node_running() -> bool {
-    get_node_holder().read().is_some()
+pub fn default_node_config() -> NodeConfigDto {
+    NodeConfig::default().into()
 }
 
-/// Get node status - synchronous version using shared state
+/// Configuration of the running node
 #[frb(sync)]
-pub fn get_node_status() -> Result<NodeStatusDto, String> {
-    let node = get_node()?;
-    let status = node.get_status_sync();
-    
-    Ok(NodeStatusDto {
-        is_running: status.is_running,
-        node_id: status.node_id,
-        connected_peers: status.connected_peers as u32,
-        discovered_peers: status.discovered_peers as u32,
-        uptime_seconds: status.uptime_seconds,
-        gossip_messages_received: status.gossip_messages_received,
-        storage_size_bytes: status.storage_size_bytes,
-        total_keys: status.total_keys,
-        sync_operations: status.sync_operations as u32,
-        latency_requests_sent: status.latency_requests_sent,
-        latency_responses_received: status.latency_responses_received,
-    })
+pub fn get_node_config() -> Result<NodeConfigDto, String> {
+    get_node_config_on(DEFAULT_NODE_HANDLE)
 }
 
-/// Get node info
+/// `get_node_config` of a `start_node_instance` node
 #[frb(sync)]
-pub fn get_node_info() -> Option<NodeInfo> {
-    let guard = get_node_holder().read();
-    guard.as_ref().map(|node| NodeInfo {
-        node_id: node.node_id().to_string(),
-        public_key: node.public_key().to_string(),
-        is_running: true,
-    })
+pub fn get_node_config_on(handle: u32) -> Result<NodeConfigDto, String> {
+    Ok(get_node_handle(handle)?.config().into())
 }
 
-/// Get discovered peers - synchronous version using shared state
+/// Change the running node's intervals and limits without restarting it.
+/// Fails without changing anything if a value is out of range or a
+/// startup-only setting differs.
 #[frb(sync)]
-pub fn get_peers() -> Result<Vec<PeerInfoDto>, String> {
-    let node = get_node()?;
-    let peers = node.get_peers_sync();
-    
-    Ok(peers.iter().map(PeerInfoDto::from).collect())
+pub fn update_config(config: NodeConfigDto) -> Result<(), String> {
+    update_config_on(DEFAULT_NODE_HANDLE, config)
 }
 
-/// Send gossip message
-#[frb]
-pub async fn send_gossip(topic: String, message: String) -> Result<(), String> {
-    let node = get_node()?;
-    
-    node.send_gossip(topic, message).await.map_err(|e| e.to_string())
+/// `update_config` on a `start_node_instance` node
+#[frb(sync)]
+pub fn update_config_on(handle: u32, config: NodeConfigDto) -> Result<(), String> {
+    get_node_handle(handle)?.update_config(config.into()).map_err(|e| e.to_string())
 }
 
-/// Send latency request to measure peer latency
-#[frb]
-pub async fn send_latency_request(peer_id: String) -> Result<(), String> {
-    let node = get_node()?;
-    
-    // This sends the request, actual latency comes via event
-    let _ = node.send_latency_request(peer_id).await;
-    Ok(())
-}
+async fn launch_node(
+    data_dir: String,
+    wallet_secret_key: Option<String>,
+    bootstrap_peers: Vec<String>,
+    config: NodeConfig,
+    progress: Option<tokio::sync::mpsc::UnboundedSender<StartupStage>>,
+) -> Result<NodeInfo, String> {
+    let node = spawn_node(data_dir, wallet_secret_key, bootstrap_peers, config, progress).await?;
+    Ok(set_node(DEFAULT_NODE_HANDLE, node))
+}
+
+/// Register `node` under `handle`, replacing the node it had
+fn set_node(handle: u32, node: CyberflyNode) -> NodeInfo {
+    let info = NodeInfo {
+        node_id: node.node_id().to_string(),
+        public_key: node.public_key().to_string(),
+        is_running: true,
+    };
+    get_nodes().write().insert(handle, Arc::new(node));
+    info
+}
+
+/// Open the node in `data_dir` without networking, e.g. in airplane mode or
+/// until the user opts into sync. Storage and the data functions work;
+/// writes are queued and sent to peers after `go_online`.
+#[frb]
+pub async fn start_offline(data_dir: String) -> Result<NodeInfo, String> {
+    if get_read_only_holder().read().is_some() {
+        return Err("Close the read-only storage before starting the node".to_string());
+    }
+    let node = get_runtime()
+        .spawn(CyberflyNode::start_offline(data_dir))
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to start node: {}", e))?;
+    Ok(set_node(DEFAULT_NODE_HANDLE, node))
+}
+
+/// Connect the node of `start_offline` to the network, keeping its data.
+/// Streams opened while offline end and have to be opened again.
+#[frb]
+pub async fn go_online(bootstrap_peers: Vec<String>, region: Option<String>) -> Result<NodeInfo, String> {
+    go_online_on(DEFAULT_NODE_HANDLE, bootstrap_peers, region).await
+}
+
+/// `go_online` on a `start_node_instance` node
+#[frb]
+pub async fn go_online_on(handle: u32, bootstrap_peers: Vec<String>, region: Option<String>) -> Result<NodeInfo, String> {
+    let offline = get_node_handle(handle)?;
+    let config = NodeConfig { region, ..NodeConfig::default() };
+    let node = get_runtime()
+        .spawn(async move { offline.go_online(bootstrap_peers, config).await })
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to go online: {}", e))?;
+    Ok(set_node(handle, node))
+}
+
+/// What `wake_and_sync` got done
+#[frb(dart_metadata=("freezed"))]
+pub struct WakeSyncSummaryDto {
+    /// The node wasn't running, so it was started and stopped again
+    pub started_node: bool,
+    pub pinned_peers_dialed: u32,
+    pub pinned_peers_connected: u32,
+    /// A peer answered the sync request in time
+    pub synced: bool,
+    pub ops_received: u64,
+    pub ops_sent: u64,
+    /// Queued writes sent to peers
+    pub outbox_sent: u32,
+    /// Queued writes still waiting for a peer
+    pub outbox_remaining: u32,
+    /// Including starting and stopping the node
+    pub duration_ms: u64,
+}
+
+impl WakeSyncSummaryDto {
+    fn new(summary: WakeSyncSummary, started_node: bool, started: Instant) -> Self {
+        Self {
+            started_node,
+            pinned_peers_dialed: summary.pinned_peers_dialed as u32,
+            pinned_peers_connected: summary.pinned_peers_connected as u32,
+            synced: summary.synced,
+            ops_received: summary.ops_received,
+            ops_sent: summary.ops_sent,
+            outbox_sent: summary.outbox_sent as u32,
+            outbox_remaining: summary.outbox_remaining as u32,
+            duration_ms: started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+/// For the background handler of a push notification (FCM/APNs), which the
+/// OS only gives a few seconds: start the node unless it's running, dial
+/// pinned and bootstrap peers, fetch what changed since the last wake, send
+/// queued writes, flush, and stop the node again. Gives up syncing after
+/// `max_duration_secs`, which includes starting and stopping the node.
+#[frb]
+pub async fn wake_and_sync(
+    data_dir: String,
+    wallet_secret_key: Option<String>,
+    bootstrap_peers: Vec<String>,
+    max_duration_secs: u32,
+) -> Result<WakeSyncSummaryDto, String> {
+    wake_and_sync_on(DEFAULT_NODE_HANDLE, data_dir, wallet_secret_key, bootstrap_peers, max_duration_secs).await
+}
+
+/// `wake_and_sync` of a `start_node_instance` node; a node started for the
+/// wake isn't registered under `handle`
+#[frb]
+pub async fn wake_and_sync_on(
+    handle: u32,
+    data_dir: String,
+    wallet_secret_key: Option<String>,
+    bootstrap_peers: Vec<String>,
+    max_duration_secs: u32,
+) -> Result<WakeSyncSummaryDto, String> {
+    if max_duration_secs == 0 {
+        return Err("The wake sync needs at least one second".to_string());
+    }
+    let started = Instant::now();
+    let budget = Duration::from_secs(max_duration_secs as u64);
+
+    // The app is in the foreground or still syncing from an earlier wake
+    if let Ok(node) = get_node_handle(handle) {
+        let summary = get_runtime()
+            .spawn(async move { node.wake_sync(budget).await })
+            .await
+            .map_err(|e| format!("Task error: {}", e))?
+            .map_err(|e| e.to_string())?;
+        return Ok(WakeSyncSummaryDto::new(summary, false, started));
+    }
+
+    let node = spawn_node(data_dir, wallet_secret_key, bootstrap_peers, NodeConfig::default(), None).await?;
+    let remaining = budget.saturating_sub(started.elapsed()).saturating_sub(WAKE_STOP_RESERVE);
+    let result = get_runtime()
+        .spawn(async move {
+            let result = node.wake_sync(remaining).await;
+            // Stop even if syncing failed, the OS suspends the app next
+            let _ = node.stop().await;
+            result
+        })
+        .await
+        .map_err(|e| format!("Task error: {}", e))?;
+    let summary = result.map_err(|e| e.to_string())?;
+    Ok(WakeSyncSummaryDto::new(summary, true, started))
+}
+
+/// Signed statement that a node's key was retired for a new node ID
+#[frb(dart_metadata=("freezed"))]
+pub struct SuccessorRecordDto {
+    pub old_node_id: String,
+    pub new_node_id: String,
+    pub timestamp: i64,
+    /// Unix ms until which the rotated node broadcasts the record
+    pub grace_until: i64,
+}
+
+impl From<SuccessorRecord> for SuccessorRecordDto {
+    fn from(record: SuccessorRecord) -> Self {
+        Self {
+            old_node_id: record.old_node_id,
+            new_node_id: record.new_node_id,
+            timestamp: record.timestamp,
+            grace_until: record.grace_until,
+        }
+    }
+}
+
+#[frb(dart_metadata=("freezed"))]
+pub struct RekeyedDatabaseDto {
+    pub old_name: String,
+    pub new_name: String,
+}
+
+#[frb(dart_metadata=("freezed"))]
+pub struct IdentityRotationDto {
+    /// The node, restarted under the new key
+    pub node: NodeInfo,
+    pub record: SuccessorRecordDto,
+    pub rekeyed_databases: Vec<RekeyedDatabaseDto>,
+    pub rekeyed_operations: u32,
+}
+
+/// Retire the node key, e.g. after it was compromised, for
+/// `new_secret_key` (hex; a fresh key if None) and restart the node under
+/// it. Peers learn the new node ID from a record signed by the old key, which
+/// is broadcast for `grace_secs` (a week by default); meanwhile the old key
+/// keeps answering peers. Databases named after the old public key are copied
+/// to the same name under the new one. On failure the node keeps running
+/// under the old key.
+#[frb]
+pub async fn rotate_identity(new_secret_key: Option<String>, grace_secs: Option<u64>) -> Result<IdentityRotationDto, String> {
+    rotate_identity_on(DEFAULT_NODE_HANDLE, new_secret_key, grace_secs).await
+}
+
+/// `rotate_identity` on a `start_node_instance` node
+#[frb]
+pub async fn rotate_identity_on(handle: u32, new_secret_key: Option<String>, grace_secs: Option<u64>) -> Result<IdentityRotationDto, String> {
+    let node = get_node_handle(handle)?;
+    let new_key = new_secret_key.as_deref().map(signing_key_from_hex).transpose()?;
+    let grace = grace_secs.map(Duration::from_secs).unwrap_or(DEFAULT_GRACE_PERIOD);
+    let (node, rotation) = get_runtime()
+        .spawn(async move { node.rotate_identity(new_key, grace).await })
+        .await
+        .map_err(|e| format!("Task error: {}", e))?
+        .map_err(|e| format!("Failed to rotate identity: {}", e))?;
+    Ok(IdentityRotationDto {
+        node: set_node(handle, node),
+        record: rotation.record.into(),
+        rekeyed_databases: rotation
+            .rekeyed_databases
+            .into_iter()
+            .map(|(old_name, new_name)| RekeyedDatabaseDto { old_name, new_name })
+            .collect(),
+        rekeyed_operations: rotation.rekeyed_operations as u32,
+    })
+}
+
+/// Successor of a node that rotated its key, if known
+#[frb(sync)]
+pub fn get_successor(node_id: String) -> Result<Option<SuccessorRecordDto>, String> {
+    get_successor_on(DEFAULT_NODE_HANDLE, node_id)
+}
+
+/// `get_successor` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_successor_on(handle: u32, node_id: String) -> Result<Option<SuccessorRecordDto>, String> {
+    let record = get_node_handle(handle)?.successor_of(&node_id).map_err(|e| e.to_string())?;
+    Ok(record.map(SuccessorRecordDto::from))
+}
+
+/// The node was started with `start_offline` and isn't online yet
+#[frb(sync)]
+pub fn is_node_offline() -> Result<bool, String> {
+    is_node_offline_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `is_node_offline` of a `start_node_instance` node
+#[frb(sync)]
+pub fn is_node_offline_on(handle: u32) -> Result<bool, String> {
+    Ok(get_node_handle(handle)?.is_offline())
+}
+
+async fn spawn_node(
+    data_dir: String,
+    wallet_secret_key: Option<String>,
+    bootstrap_peers: Vec<String>,
+    config: NodeConfig,
+    progress: Option<tokio::sync::mpsc::UnboundedSender<StartupStage>>,
+) -> Result<CyberflyNode, String> {
+    info!(">>> RUST API: start_node called");
+    if get_read_only_holder().read().is_some() {
+        return Err("Close the read-only storage before starting the node".to_string());
+    }
+    let runtime = get_runtime();
+    info!(">>> RUST API: got runtime, about to spawn");
+    
+    let result = runtime.spawn(async move {
+        info!(">>> RUST API: inside runtime.spawn, calling CyberflyNode::start");
+        let result = CyberflyNode::start_with_progress(data_dir, wallet_secret_key, bootstrap_peers, config, progress).await;
+        info!(">>> RUST API: CyberflyNode::start returned: {:?}", result.is_ok());
+        result
+    }).await;
+    
+    info!(">>> RUST API: spawn completed, result: {:?}", result.is_ok());
+    
+    match result {
+        Ok(Ok(node)) => Ok(node),
+        Ok(Err(e)) => Err(format!("Failed to start node: {}", e)),
+        Err(e) => Err(format!("Task error: {}", e)),
+    }
+}
+
+/// Join the private network of everyone using `passphrase`, or the public
+/// network with None. Nodes only see members of their own network. Takes
+/// effect when the node starts, so every node must be stopped.
+#[frb]
+pub async fn set_network_passphrase(data_dir: String, passphrase: Option<String>) -> Result<(), String> {
+    // Instances other than the default node may run from `data_dir` too
+    if !get_nodes().read().is_empty() {
+        return Err("Stop every node before changing the network".to_string());
+    }
+    let key = passphrase
+        .map(|passphrase| NetworkKey::from_passphrase(&passphrase))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    NetworkKey::store(std::path::Path::new(&data_dir), key.as_ref()).map_err(|e| e.to_string())
+}
+
+/// Short identifier of the private network the node in `data_dir` is in
+/// (equal on all members), None on the public network
+#[frb(sync)]
+pub fn get_network_id(data_dir: String) -> Result<Option<String>, String> {
+    let key = NetworkKey::load(std::path::Path::new(&data_dir)).map_err(|e| e.to_string())?;
+
+    Ok(key.map(|key| key.id()))
+}
+
+/// Stop the node
+#[frb]
+pub async fn stop_node() -> Result<(), String> {
+    stop_node_handle(DEFAULT_NODE_HANDLE).await
+}
+
+async fn stop_node_handle(handle: u32) -> Result<(), String> {
+    let node_opt = get_nodes().write().remove(&handle);
+    if let Some(node) = node_opt {
+        // Streams may still hold the node, so stop it through the shared
+        // handle rather than waiting for the last one to go
+        let runtime = get_runtime();
+        runtime.spawn(async move {
+            node.stop().await
+        }).await.map_err(|e| format!("Task error: {}", e))?.map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Node started with `start_node_instance`
+#[frb(dart_metadata=("freezed"))]
+pub struct NodeInstanceDto {
+    /// Pass to the `*_on` functions
+    pub handle: u32,
+    pub node_id: String,
+    pub public_key: String,
+}
+
+/// Start another node next to the one of `start_node`, e.g. for a second
+/// identity. Each node needs its own `data_dir`. The functions ending in
+/// `_on` take the returned handle; the others use the `start_node` node.
+#[frb]
+pub async fn start_node_instance(
+    data_dir: String,
+    wallet_secret_key: Option<String>,
+    bootstrap_peers: Vec<String>,
+    config: NodeConfigDto,
+) -> Result<NodeInstanceDto, String> {
+    let node = spawn_node(data_dir, wallet_secret_key, bootstrap_peers, config.into(), None).await?;
+    let handle = NEXT_NODE_HANDLE.fetch_add(1, Ordering::Relaxed);
+    let instance = NodeInstanceDto {
+        handle,
+        node_id: node.node_id().to_string(),
+        public_key: node.public_key().to_string(),
+    };
+    get_nodes().write().insert(handle, Arc::new(node));
+    Ok(instance)
+}
+
+/// Stop a node started with `start_node_instance`
+#[frb]
+pub async fn stop_node_instance(handle: u32) -> Result<(), String> {
+    stop_node_handle(handle).await
+}
+
+/// Handles of the running nodes, `DEFAULT_NODE_HANDLE` (0) for the node of
+/// `start_node`
+#[frb(sync)]
+pub fn list_node_instances() -> Vec<u32> {
+    let mut handles: Vec<u32> = get_nodes().read().keys().copied().collect();
+    handles.sort_unstable();
+    handles
+}
+
+/// Write an encrypted backup of all node data (databases, sync log, writer
+/// lists, database keys) and the node identity key to `path`
+#[frb]
+pub async fn export_backup(path: String, passphrase: String) -> Result<BackupInfoDto, String> {
+    export_backup_on(DEFAULT_NODE_HANDLE, path, passphrase).await
+}
+
+/// `export_backup` on a `start_node_instance` node
+#[frb]
+pub async fn export_backup_on(handle: u32, path: String, passphrase: String) -> Result<BackupInfoDto, String> {
+    let node = get_node_handle(handle)?;
+    let summary = node.export_backup(&path, &passphrase).map_err(|e| e.to_string())?;
+    Ok(BackupInfoDto::from(summary))
+}
+
+/// Restore a backup made with `export_backup` into `data_dir` (e.g. on a new
+/// device). The node must be stopped; start it afterwards with the same
+/// `data_dir` and no `wallet_secret_key` to use the restored identity.
+#[frb]
+pub async fn import_backup(data_dir: String, path: String, passphrase: String) -> Result<BackupInfoDto, String> {
+    if is_node_running() || get_read_only_holder().read().is_some() {
+        return Err("Stop the node and close read-only storage before restoring a backup".to_string());
+    }
+    let summary = crate::backup::import_backup(
+        std::path::Path::new(&data_dir),
+        std::path::Path::new(&path),
+        &passphrase,
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(BackupInfoDto::from(summary))
+}
+
+fn wrapping_key_from_bytes(wrapping_key: Vec<u8>) -> Result<[u8; WRAPPING_KEY_LENGTH], String> {
+    wrapping_key
+        .try_into()
+        .map_err(|_| format!("Invalid wrapping key length (expected {} bytes)", WRAPPING_KEY_LENGTH))
+}
+
+fn node_key_path(data_dir: &str) -> std::path::PathBuf {
+    std::path::Path::new(data_dir).join(crate::backup::SECRET_KEY_FILE)
+}
+
+/// Set the key sealing the node key at rest, kept by the app in the platform
+/// keystore (Android Keystore, iOS Keychain). Call before starting the node:
+/// a sealed node key can't be read without it, and new keys are sealed with it.
+#[frb(sync)]
+pub fn set_key_wrapping_key(wrapping_key: Vec<u8>) -> Result<(), String> {
+    keystore::set_wrapping_key(Some(wrapping_key_from_bytes(wrapping_key)?));
+    Ok(())
+}
+
+/// Whether the node key in `data_dir` is sealed with a wrapping key
+#[frb(sync)]
+pub fn is_node_key_sealed(data_dir: String) -> Result<bool, String> {
+    keystore::is_sealed(&node_key_path(&data_dir)).map_err(|e| e.to_string())
+}
+
+/// Seal the plaintext node key in `data_dir` with `wrapping_key`, which is
+/// set as the wrapping key from now on
+#[frb(sync)]
+pub fn seal_node_key(data_dir: String, wrapping_key: Vec<u8>) -> Result<(), String> {
+    let wrapping_key = wrapping_key_from_bytes(wrapping_key)?;
+    keystore::seal_key_file(&node_key_path(&data_dir), &wrapping_key).map_err(|e| e.to_string())?;
+    keystore::set_wrapping_key(Some(wrapping_key));
+    Ok(())
+}
+
+/// Seal the node key in `data_dir` with `new_wrapping_key` instead of
+/// `old_wrapping_key`, and use the new one from now on
+#[frb(sync)]
+pub fn rotate_key_wrapping_key(
+    data_dir: String,
+    old_wrapping_key: Vec<u8>,
+    new_wrapping_key: Vec<u8>,
+) -> Result<(), String> {
+    let old_wrapping_key = wrapping_key_from_bytes(old_wrapping_key)?;
+    let new_wrapping_key = wrapping_key_from_bytes(new_wrapping_key)?;
+    keystore::rotate_wrapping_key(&node_key_path(&data_dir), &old_wrapping_key, &new_wrapping_key)
+        .map_err(|e| e.to_string())?;
+    keystore::set_wrapping_key(Some(new_wrapping_key));
+    Ok(())
+}
+
+/// Open the storage in `data_dir` read-only without starting the node, to
+/// inspect databases or export a backup when the node can't run. Fails while
+/// the node is running.
+#[frb(sync)]
+pub fn open_storage_read_only(data_dir: String) -> Result<(), String> {
+    if is_node_running() {
+        return Err("Node is running".to_string());
+    }
+    let mut guard = get_read_only_holder().write();
+    if guard.is_some() {
+        return Err("Storage already open read-only".to_string());
+    }
+
+    let data_dir = std::path::PathBuf::from(data_dir);
+    let storage = Storage::open_read_only(&data_dir.join(crate::backup::SLED_DIR)).map_err(|e| e.to_string())?;
+    *guard = Some(ReadOnlyStorage { data_dir, storage });
+    Ok(())
+}
+
+/// Close storage opened with `open_storage_read_only`
+#[frb(sync)]
+pub fn close_storage_read_only() {
+    get_read_only_holder().write().take();
+}
+
+/// List databases of the read-only storage
+#[frb(sync)]
+pub fn read_only_list_databases() -> Result<Vec<String>, String> {
+    let opened = get_read_only_storage()?;
+    opened.storage.list_databases().map_err(|e| e.to_string())
+}
+
+/// Get a raw value from the read-only storage (encrypted databases return ciphertext)
+#[frb]
+pub async fn read_only_get_data(db_name: String, key: String) -> Result<Option<Vec<u8>>, String> {
+    let opened = get_read_only_storage()?;
+    opened.storage.get(&db_name, &key).map_err(|e| e.to_string())
+}
+
+/// Page through a database of the read-only storage, same options as `get_entries_page`
+#[frb]
+pub async fn read_only_get_entries_page(
+    db_name: String,
+    limit: u32,
+    cursor: Option<String>,
+    keys_only: bool,
+    max_bytes: Option<u64>,
+) -> Result<ScanPageDto, String> {
+    let opened = get_read_only_storage()?;
+    let options = scan_options(limit, keys_only, max_bytes);
+    let page = opened
+        .storage
+        .entries_page(&db_name, cursor.as_deref(), options)
+        .map_err(|e| e.to_string())?;
+    Ok(ScanPageDto::from_page(&db_name, page))
+}
+
+/// Export a backup from the read-only storage. The identity is
+/// `wallet_secret_key` if the node is started with one, otherwise the key
+/// file in the data directory.
+#[frb]
+pub async fn read_only_export_backup(
+    path: String,
+    passphrase: String,
+    wallet_secret_key: Option<String>,
+) -> Result<BackupInfoDto, String> {
+    let opened = get_read_only_storage()?;
+    let node_key = match wallet_secret_key {
+        Some(secret_key) => signing_key_from_hex(&secret_key)?,
+        None => {
+            let secret = keystore::read_key(&opened.data_dir.join(crate::backup::SECRET_KEY_FILE))
+                .map_err(|e| format!("Failed to read node key: {}", e))?;
+            ed25519_dalek::SigningKey::from_bytes(&secret)
+        }
+    };
+
+    let summary = crate::backup::export_backup(&opened.storage, &node_key, std::path::Path::new(&path), &passphrase)
+        .map_err(|e| e.to_string())?;
+    Ok(BackupInfoDto::from(summary))
+}
+
+/// Contents of an exported or restored backup
+#[frb(dart_metadata=("freezed"))]
+pub struct BackupInfoDto {
+    pub created_at: i64,
+    /// Node identity public key (hex)
+    pub public_key: String,
+    pub tree_count: u32,
+    pub entry_count: u64,
+}
+
+impl From<crate::backup::BackupSummary> for BackupInfoDto {
+    fn from(summary: crate::backup::BackupSummary) -> Self {
+        Self {
+            created_at: summary.created_at,
+            public_key: summary.public_key,
+            tree_count: summary.tree_count as u32,
+            entry_count: summary.entry_count as u64,
+        }
+    }
+}
+
+/// Check if node is running
+#[frb(sync)]
+pub fn is_node_running() -> bool {
+    get_nodes().read().contains_key(&DEFAULT_NODE_HANDLE)
+}
+
+/// Get node status - synchronous version using shared state
+#[frb(sync)]
+pub fn get_node_status() -> Result<NodeStatusDto, String> {
+    get_node_status_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_node_status` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_node_status_on(handle: u32) -> Result<NodeStatusDto, String> {
+    get_node_handle(handle).map(|node| node_status(&node))
+}
+
+fn node_status(node: &CyberflyNode) -> NodeStatusDto {
+    let status = node.get_status_sync();
+
+    NodeStatusDto {
+        is_running: status.is_running,
+        node_id: status.node_id,
+        connected_peers: status.connected_peers as u32,
+        discovered_peers: status.discovered_peers as u32,
+        uptime_seconds: status.uptime_seconds,
+        gossip_messages_received: status.gossip_messages_received,
+        storage_size_bytes: status.storage_size_bytes,
+        total_keys: status.total_keys,
+        sync_operations: status.sync_operations as u32,
+        latency_requests_sent: status.latency_requests_sent,
+        latency_responses_received: status.latency_responses_received,
+        topics: status
+            .topics
+            .into_iter()
+            .map(|topic| TopicStatsDto {
+                topic: topic.topic,
+                messages_sent: topic.messages_sent,
+                messages_received: topic.messages_received,
+                bytes_sent: topic.bytes_sent,
+                bytes_received: topic.bytes_received,
+                neighbors: topic.neighbors as u32,
+                last_event_at: topic.last_event_at,
+            })
+            .collect(),
+    }
+}
+
+/// Get session and lifetime statistics
+#[frb(sync)]
+pub fn get_node_stats() -> Result<NodeStatsDto, String> {
+    get_node_stats_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_node_stats` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_node_stats_on(handle: u32) -> Result<NodeStatsDto, String> {
+    let node = get_node_handle(handle)?;
+    let status = node.get_status_sync();
+
+    Ok(NodeStatsDto {
+        uptime_seconds: status.uptime_seconds,
+        total_operations: status.total_operations,
+        bytes_sent: status.session.bytes_sent,
+        bytes_received: status.session.bytes_received,
+        connects: status.session.connects,
+        reconnects: status.session.reconnects,
+        lifetime_sessions: status.lifetime.sessions,
+        lifetime_uptime_seconds: status.lifetime.uptime_seconds,
+        lifetime_bytes_sent: status.lifetime.traffic.bytes_sent,
+        lifetime_bytes_received: status.lifetime.traffic.bytes_received,
+        lifetime_connects: status.lifetime.traffic.connects,
+        lifetime_reconnects: status.lifetime.traffic.reconnects,
+        outbox_depth: status.outbox_depth as u64,
+    })
+}
+
+/// Get node info
+#[frb(sync)]
+pub fn get_node_info() -> Option<NodeInfo> {
+    get_node_info_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_node_info` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_node_info_on(handle: u32) -> Option<NodeInfo> {
+    get_node_handle(handle).ok().map(|node| NodeInfo {
+        node_id: node.node_id().to_string(),
+        public_key: node.public_key().to_string(),
+        is_running: true,
+    })
+}
+
+/// The last node events after `since_seq`, oldest first; 0 for all that are
+/// kept. Pass the last `seq` seen to catch up after missing some.
+#[frb(sync)]
+pub fn get_recent_events(since_seq: u64) -> Result<Vec<NodeEventRecordDto>, String> {
+    get_recent_events_on(DEFAULT_NODE_HANDLE, since_seq)
+}
+
+/// `get_recent_events` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_recent_events_on(handle: u32, since_seq: u64) -> Result<Vec<NodeEventRecordDto>, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.recent_events(since_seq).into_iter().map(NodeEventRecordDto::from).collect())
+}
+
+/// Stream node events: first the kept ones after `since_seq` (0 for all, so
+/// `Started` isn't missed when subscribing late), then new ones as they
+/// happen. Streams can be opened any number of times.
+#[frb(sync)]
+pub fn event_stream(since_seq: u64, sink: StreamSink<NodeEventRecordDto>) -> Result<(), String> {
+    event_stream_on(DEFAULT_NODE_HANDLE, since_seq, sink)
+}
+
+/// `event_stream` on a `start_node_instance` node
+#[frb(sync)]
+pub fn event_stream_on(handle: u32, since_seq: u64, sink: StreamSink<NodeEventRecordDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let (replay, mut events) = node.subscribe_events(since_seq);
+
+    get_runtime().spawn(async move {
+        use tokio::sync::broadcast::error::RecvError;
+
+        for recorded in replay {
+            if sink.add(NodeEventRecordDto::from(recorded)).is_err() {
+                return;
+            }
+        }
+        loop {
+            match events.recv().await {
+                Ok(recorded) => {
+                    if sink.add(NodeEventRecordDto::from(recorded)).is_err() {
+                        break;
+                    }
+                }
+                // Missed events can be fetched with get_recent_events
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Event stream lagged, skipped {} events", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Get discovered peers - synchronous version using shared state
+#[frb(sync)]
+pub fn get_peers() -> Result<Vec<PeerInfoDto>, String> {
+    get_peers_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_peers` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_peers_on(handle: u32) -> Result<Vec<PeerInfoDto>, String> {
+    Ok(get_node_handle(handle)?.get_peers_sync().iter().map(PeerInfoDto::from).collect())
+}
+
+/// Get active peers that have every listed capability ("mqtt", "streams",
+/// "timeseries", "geo", "blobs"), are in `region` and within
+/// `max_latency_ms`, lowest latency first. E.g. blob-capable peers nearby to
+/// fetch a file from.
+#[frb(sync)]
+pub fn get_peers_filtered(
+    capabilities: Vec<String>,
+    region: Option<String>,
+    max_latency_ms: Option<u64>,
+) -> Result<Vec<PeerInfoDto>, String> {
+    get_peers_filtered_on(DEFAULT_NODE_HANDLE, capabilities, region, max_latency_ms)
+}
+
+/// `get_peers_filtered` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_peers_filtered_on(
+    handle: u32,
+    capabilities: Vec<String>,
+    region: Option<String>,
+    max_latency_ms: Option<u64>,
+) -> Result<Vec<PeerInfoDto>, String> {
+    let node = get_node_handle(handle)?;
+    let filter = crate::discovery::PeerFilter {
+        capabilities,
+        region,
+        max_latency_ms,
+    };
+    let peers = node.get_peers_filtered(&filter).map_err(|e| e.to_string())?;
+
+    Ok(peers.iter().map(PeerInfoDto::from).collect())
+}
+
+/// How a connected peer is reached
+#[frb(dart_metadata=("freezed"))]
+pub struct PeerPathDto {
+    pub node_id: String,
+    /// "direct", "relayed", or "unknown" until a path is selected
+    pub path: String,
+    /// Both direct and relay paths are open
+    pub is_mixed: bool,
+    pub direct_addrs: Vec<String>,
+    pub relay_url: Option<String>,
+    /// Unix millis of the last path change
+    pub updated_at: i64,
+}
+
+/// Connection path of every peer we hold a connection to, updated as paths
+/// change. Lets the UI badge peers as P2P or relayed and diagnose NAT issues.
+#[frb(sync)]
+pub fn get_peer_paths() -> Result<Vec<PeerPathDto>, String> {
+    get_peer_paths_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_peer_paths` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_peer_paths_on(handle: u32) -> Result<Vec<PeerPathDto>, String> {
+    let node = get_node_handle(handle)?;
+
+    Ok(node.get_peer_paths().into_iter().map(|(node_id, path)| peer_path_dto(node_id, path)).collect())
+}
+
+fn peer_path_dto(node_id: String, path: PeerPath) -> PeerPathDto {
+    PeerPathDto {
+        node_id,
+        path: path.selected.map(|p| p.as_str()).unwrap_or("unknown").to_string(),
+        is_mixed: path.is_mixed(),
+        direct_addrs: path.direct_addrs,
+        relay_url: path.relay_url,
+        updated_at: path.updated_at,
+    }
+}
+
+/// Circuit breaker of a peer that failed to connect since its last success
+#[frb(dart_metadata=("freezed"))]
+pub struct PeerCircuitDto {
+    pub node_id: String,
+    /// "closed", "open" or "half_open"
+    pub state: String,
+    /// The circuit is open or probing: show the peer as unreachable
+    pub unreachable: bool,
+    pub consecutive_failures: u32,
+    /// Unix millis
+    pub last_failure_at: i64,
+    /// Unix millis when an open circuit lets the next connect through
+    pub retry_at: Option<i64>,
+}
+
+/// Peers the node failed to connect to recently. After repeated failures a
+/// peer's circuit opens and it isn't dialed for a cooldown, then a single
+/// probe connect decides whether it closes again.
+#[frb(sync)]
+pub fn get_peer_circuits() -> Result<Vec<PeerCircuitDto>, String> {
+    get_peer_circuits_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_peer_circuits` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_peer_circuits_on(handle: u32) -> Result<Vec<PeerCircuitDto>, String> {
+    let node = get_node_handle(handle)?;
+
+    Ok(node
+        .get_peer_circuits()
+        .into_iter()
+        .map(|(node_id, circuit)| PeerCircuitDto {
+            node_id,
+            state: circuit.state.as_str().to_string(),
+            unreachable: circuit.state != CircuitState::Closed,
+            consecutive_failures: circuit.consecutive_failures,
+            last_failure_at: circuit.last_failure_at.timestamp_millis(),
+            retry_at: circuit.retry_at().map(|at| at.timestamp_millis()),
+        })
+        .collect())
+}
+
+/// What the node knows about reaching a peer
+#[frb(dart_metadata=("freezed"))]
+pub struct PeerDiagnosticsDto {
+    pub node_id: String,
+    /// Current connection, None while not connected
+    pub path: Option<PeerPathDto>,
+    /// A direct (hole punched or LAN) path was used this session
+    pub direct_established: bool,
+    pub dial_attempts: u32,
+    pub dial_failures: u32,
+    /// Unix millis of the last dial, None if never dialed
+    pub last_dial_at: Option<i64>,
+    /// Error of the last dial, None if it succeeded
+    pub last_error: Option<String>,
+}
+
+/// Connection diagnostics for "can't connect" reports
+#[frb(dart_metadata=("freezed"))]
+pub struct ConnectionDiagnosticsDto {
+    /// Private, loopback and link-local addresses
+    pub local_addrs: Vec<String>,
+    /// Addresses as seen from the internet
+    pub public_addrs: Vec<String>,
+    /// Home relay, None when relays are disabled or none is reachable yet
+    pub relay_url: Option<String>,
+    /// Heuristic: "unknown", "no_public_address", "hole_punchable",
+    /// "symmetric" or "relay_only"
+    pub nat_type: String,
+    pub peers: Vec<PeerDiagnosticsDto>,
+}
+
+/// Our addresses, relay and NAT guess, with every connected or dialed peer's
+/// path, dial attempts and last error
+#[frb(sync)]
+pub fn get_connection_diagnostics() -> Result<ConnectionDiagnosticsDto, String> {
+    get_connection_diagnostics_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_connection_diagnostics` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_connection_diagnostics_on(handle: u32) -> Result<ConnectionDiagnosticsDto, String> {
+    let diagnostics = get_node_handle(handle)?.connection_diagnostics();
+
+    let peers = diagnostics
+        .peers
+        .into_iter()
+        .map(|peer| {
+            let direct_established = peer.direct_established();
+            let dials = peer.dials.unwrap_or_default();
+            PeerDiagnosticsDto {
+                path: peer.path.map(|path| peer_path_dto(peer.node_id.clone(), path)),
+                node_id: peer.node_id,
+                direct_established,
+                dial_attempts: dials.attempts,
+                dial_failures: dials.failures,
+                last_dial_at: (dials.attempts > 0).then_some(dials.last_attempt_at),
+                last_error: dials.last_error,
+            }
+        })
+        .collect();
+    Ok(ConnectionDiagnosticsDto {
+        local_addrs: diagnostics.local_addrs,
+        public_addrs: diagnostics.public_addrs,
+        relay_url: diagnostics.relay_url,
+        nat_type: diagnostics.nat_type.as_str().to_string(),
+        peers,
+    })
+}
+
+/// How a subsystem is doing in `health_check`
+pub enum HealthStatusDto {
+    Ok,
+    /// Working, but not fully
+    Degraded,
+    Failed,
+}
+
+impl From<HealthStatus> for HealthStatusDto {
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Ok => HealthStatusDto::Ok,
+            HealthStatus::Degraded => HealthStatusDto::Degraded,
+            HealthStatus::Failed => HealthStatusDto::Failed,
+        }
+    }
+}
+
+/// Status of a subsystem and why, e.g. "no relay connected"
+#[frb(dart_metadata=("freezed"))]
+pub struct SubsystemHealthDto {
+    pub status: HealthStatusDto,
+    pub reason: String,
+}
+
+impl From<SubsystemHealth> for SubsystemHealthDto {
+    fn from(health: SubsystemHealth) -> Self {
+        Self { status: health.status.into(), reason: health.reason }
+    }
+}
+
+/// One of the node's gossip topics
+#[frb(dart_metadata=("freezed"))]
+pub struct TopicHealthDto {
+    pub topic: String,
+    pub subscribed: bool,
+    pub neighbors: u32,
+}
+
+/// Health of each subsystem for a diagnostics screen
+#[frb(dart_metadata=("freezed"))]
+pub struct HealthReportDto {
+    pub storage: SubsystemHealthDto,
+    pub gossip: SubsystemHealthDto,
+    pub sync: SubsystemHealthDto,
+    pub discovery: SubsystemHealthDto,
+    pub relay: SubsystemHealthDto,
+    pub topics: Vec<TopicHealthDto>,
+    /// Unix millis, None if not yet this session
+    pub last_flush_at: Option<i64>,
+    pub last_sync_at: Option<i64>,
+    pub last_announcement_at: Option<i64>,
+}
+
+/// Check storage (with a test write), the gossip topics, sync, discovery and
+/// the relay
+#[frb]
+pub async fn health_check() -> Result<HealthReportDto, String> {
+    health_check_on(DEFAULT_NODE_HANDLE).await
+}
+
+/// `health_check` on a `start_node_instance` node
+#[frb]
+pub async fn health_check_on(handle: u32) -> Result<HealthReportDto, String> {
+    let report = get_node_handle(handle)?.health_check().await.map_err(|e| e.to_string())?;
+
+    Ok(HealthReportDto {
+        storage: report.storage.into(),
+        gossip: report.gossip.into(),
+        sync: report.sync.into(),
+        discovery: report.discovery.into(),
+        relay: report.relay.into(),
+        topics: report
+            .topics
+            .into_iter()
+            .map(|topic| TopicHealthDto { topic: topic.topic, subscribed: topic.subscribed, neighbors: topic.neighbors as u32 })
+            .collect(),
+        last_flush_at: report.last_flush_at,
+        last_sync_at: report.last_sync_at,
+        last_announcement_at: report.last_announcement_at,
+    })
+}
+
+/// Latency of a peer, measured in the background every few minutes
+#[frb(dart_metadata=("freezed"))]
+pub struct PeerLatencyDto {
+    pub node_id: String,
+    /// Last measurement
+    pub latency_ms: Option<u64>,
+    /// Rolling average
+    pub average_ms: f64,
+    pub jitter_ms: f64,
+    pub samples: u32,
+}
+
+/// Measured active peers, best first (lowest average latency, then lowest
+/// jitter)
+#[frb(sync)]
+pub fn get_ranked_peers() -> Result<Vec<PeerLatencyDto>, String> {
+    get_ranked_peers_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_ranked_peers` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_ranked_peers_on(handle: u32) -> Result<Vec<PeerLatencyDto>, String> {
+    let node = get_node_handle(handle)?;
+
+    Ok(node
+        .get_ranked_peers()
+        .into_iter()
+        .map(|peer| PeerLatencyDto {
+            node_id: peer.node_id,
+            latency_ms: peer.latency_ms,
+            average_ms: peer.latency_stats.average_ms,
+            jitter_ms: peer.latency_stats.jitter_ms,
+            samples: peer.latency_stats.samples,
+        })
+        .collect())
+}
+
+/// A peer the node always keeps connected
+#[frb(dart_metadata=("freezed"))]
+pub struct PinnedPeerDto {
+    pub node_id: String,
+    pub address: Option<String>,
+}
+
+/// Pin a peer: it's redialed whenever it isn't connected, joins the gossip
+/// topics like a bootstrap node and never expires from the peer list.
+/// Persisted across restarts.
+#[frb(sync)]
+pub fn pin_peer(node_id: String, address: Option<String>) -> Result<(), String> {
+    pin_peer_on(DEFAULT_NODE_HANDLE, node_id, address)
+}
+
+/// `pin_peer` on a `start_node_instance` node
+#[frb(sync)]
+pub fn pin_peer_on(handle: u32, node_id: String, address: Option<String>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+
+    node.pin_peer(&node_id, address).map_err(|e| e.to_string())
+}
+
+/// Unpin a peer; it stays connected until it expires like any other
+#[frb(sync)]
+pub fn unpin_peer(node_id: String) -> Result<(), String> {
+    unpin_peer_on(DEFAULT_NODE_HANDLE, node_id)
+}
+
+/// `unpin_peer` on a `start_node_instance` node
+#[frb(sync)]
+pub fn unpin_peer_on(handle: u32, node_id: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+
+    node.unpin_peer(&node_id).map_err(|e| e.to_string())
+}
+
+/// List pinned peers
+#[frb(sync)]
+pub fn get_pinned_peers() -> Result<Vec<PinnedPeerDto>, String> {
+    get_pinned_peers_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_pinned_peers` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_pinned_peers_on(handle: u32) -> Result<Vec<PinnedPeerDto>, String> {
+    let node = get_node_handle(handle)?;
+    let peers = node.pinned_peers().map_err(|e| e.to_string())?;
+
+    Ok(peers
+        .into_iter()
+        .map(|(node_id, address)| PinnedPeerDto { node_id, address })
+        .collect())
+}
+
+/// Send gossip message
+#[frb]
+pub async fn send_gossip(topic: String, message: String) -> Result<(), String> {
+    send_gossip_on(DEFAULT_NODE_HANDLE, topic, message).await
+}
+
+/// `send_gossip` on a `start_node_instance` node
+#[frb]
+pub async fn send_gossip_on(handle: u32, topic: String, message: String) -> Result<(), String> {
+    get_node_handle(handle)?.send_gossip(topic, message).await.map_err(|e| e.to_string())
+}
+
+/// Message received on an application topic
+#[frb(dart_metadata=("freezed"))]
+pub struct TopicMessageDto {
+    pub topic: String,
+    /// Neighbor that delivered the message, not necessarily its author
+    pub delivered_from: String,
+    pub content: Vec<u8>,
+}
+
+impl From<TopicMessage> for TopicMessageDto {
+    fn from(message: TopicMessage) -> Self {
+        Self {
+            topic: message.topic,
+            delivered_from: message.delivered_from,
+            content: message.content,
+        }
+    }
+}
+
+/// Join the gossip topic `name` of this application and stream its messages.
+/// Several streams may watch one topic; it stays joined after they are
+/// cancelled, until `unsubscribe_topic`.
+#[frb]
+pub async fn subscribe_topic(name: String, sink: StreamSink<TopicMessageDto>) -> Result<(), String> {
+    subscribe_topic_on(DEFAULT_NODE_HANDLE, name, sink).await
+}
+
+/// `subscribe_topic` on a `start_node_instance` node
+#[frb]
+pub async fn subscribe_topic_on(handle: u32, name: String, sink: StreamSink<TopicMessageDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    stream_topic(node.subscribe_topic(name).await?, sink);
+    Ok(())
+}
+
+fn stream_topic(mut messages: tokio::sync::broadcast::Receiver<TopicMessage>, sink: StreamSink<TopicMessageDto>) {
+    get_runtime().spawn(async move {
+        use tokio::sync::broadcast::error::RecvError;
+
+        loop {
+            match messages.recv().await {
+                Ok(message) => {
+                    if sink.add(TopicMessageDto::from(message)).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Topic stream lagged, skipped {} messages", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// Broadcast `content` on a topic joined with `subscribe_topic`. Identical
+/// payloads are delivered once, so include an ID or timestamp in messages
+/// that may repeat.
+#[frb]
+pub async fn publish_topic(name: String, content: Vec<u8>) -> Result<(), String> {
+    publish_topic_on(DEFAULT_NODE_HANDLE, name, content).await
+}
+
+/// `publish_topic` on a `start_node_instance` node
+#[frb]
+pub async fn publish_topic_on(handle: u32, name: String, content: Vec<u8>) -> Result<(), String> {
+    get_node_handle(handle)?.publish_topic(name, content).await
+}
+
+/// Leave a topic, ending its streams. Returns false if it wasn't joined.
+#[frb]
+pub async fn unsubscribe_topic(name: String) -> Result<bool, String> {
+    unsubscribe_topic_on(DEFAULT_NODE_HANDLE, name).await
+}
+
+/// `unsubscribe_topic` on a `start_node_instance` node
+#[frb]
+pub async fn unsubscribe_topic_on(handle: u32, name: String) -> Result<bool, String> {
+    get_node_handle(handle)?.unsubscribe_topic(name).await.map_err(|e| e.to_string())
+}
+
+/// Report battery and network state whenever it changes. On low battery or
+/// metered data the node announces and reconnects less often and defers full
+/// syncs; on metered data it doesn't transfer snapshot blobs.
+#[frb]
+pub async fn set_device_conditions(conditions: DeviceConditionsDto) -> Result<(), String> {
+    set_device_conditions_on(DEFAULT_NODE_HANDLE, conditions).await
+}
+
+/// `set_device_conditions` on a `start_node_instance` node
+#[frb]
+pub async fn set_device_conditions_on(handle: u32, conditions: DeviceConditionsDto) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let connection = match conditions.connection_type {
+        ConnectionTypeDto::Wifi => ConnectionType::Wifi,
+        ConnectionTypeDto::Ethernet => ConnectionType::Ethernet,
+        ConnectionTypeDto::Cellular => ConnectionType::Cellular,
+        ConnectionTypeDto::Other => ConnectionType::Other,
+        ConnectionTypeDto::Offline => ConnectionType::Offline,
+    };
+    let conditions = DeviceConditions {
+        battery_percent: conditions.battery_percent.map(|percent| percent.min(100)),
+        charging: conditions.charging,
+        connection,
+        metered: conditions.metered,
+    };
+    node.set_device_conditions(conditions).await.map_err(|e| e.to_string())
+}
+
+/// Get the power policy in effect
+#[frb(sync)]
+pub fn get_power_policy() -> Result<PowerPolicyDto, String> {
+    get_power_policy_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_power_policy` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_power_policy_on(handle: u32) -> Result<PowerPolicyDto, String> {
+    let (policy, sync_deferred) = get_node_handle(handle)?.power_policy();
+    Ok(PowerPolicyDto {
+        low_power: policy.low_power,
+        metered: policy.metered,
+        sync_deferred,
+        blob_transfers_allowed: policy.allow_blob_transfers(),
+    })
+}
+
+/// Switch the running node between normal and lite mode, e.g. lite while
+/// it runs in an Android foreground service overnight. Lite mode leaves every
+/// topic but sync, so peers aren't discovered and application topics are
+/// silent until it's switched off.
+#[frb]
+pub async fn set_power_mode(mode: PowerModeDto) -> Result<(), String> {
+    set_power_mode_on(DEFAULT_NODE_HANDLE, mode).await
+}
+
+/// `set_power_mode` on a `start_node_instance` node
+#[frb]
+pub async fn set_power_mode_on(handle: u32, mode: PowerModeDto) -> Result<(), String> {
+    get_node_handle(handle)?.set_power_mode(mode.into()).await.map_err(|e| e.to_string())
+}
+
+#[frb(sync)]
+pub fn get_power_mode() -> Result<PowerModeDto, String> {
+    get_power_mode_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_power_mode` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_power_mode_on(handle: u32) -> Result<PowerModeDto, String> {
+    Ok(get_node_handle(handle)?.power_mode().into())
+}
+
+/// Get the connectivity found by the last isolation check
+#[frb(sync)]
+pub fn get_connectivity_state() -> Result<ConnectivityDto, String> {
+    get_connectivity_state_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_connectivity_state` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_connectivity_state_on(handle: u32) -> Result<ConnectivityDto, String> {
+    let connectivity = get_node_handle(handle)?.connectivity();
+    Ok(ConnectivityDto { state: connectivity.state.into(), reason: connectivity.reason })
+}
+
+/// Relay configuration, the relay in use and its round trip time
+#[frb(dart_metadata=("freezed"))]
+pub struct RelayStatusDto {
+    pub relay: RelayConfigDto,
+    /// None when relays are disabled or none is reachable yet
+    pub active_url: Option<String>,
+    /// None if the relay didn't answer a ping
+    pub rtt_ms: Option<u64>,
+}
+
+/// Get the active relay, pinging it to measure the round trip time
+#[frb]
+pub async fn get_relay_status() -> Result<RelayStatusDto, String> {
+    get_relay_status_on(DEFAULT_NODE_HANDLE).await
+}
+
+/// `get_relay_status` of a `start_node_instance` node
+#[frb]
+pub async fn get_relay_status_on(handle: u32) -> Result<RelayStatusDto, String> {
+    let node = get_node_handle(handle)?;
+    let status = node.relay_status().await;
+    Ok(RelayStatusDto {
+        relay: status.relay.into(),
+        active_url: status.active_url,
+        rtt_ms: status.rtt.map(|rtt| rtt.as_millis() as u64),
+    })
+}
+
+/// Get bytes sent and received per topic and subsystem, and data cap usage
+#[frb(sync)]
+pub fn get_bandwidth_stats() -> Result<BandwidthStatsDto, String> {
+    get_bandwidth_stats_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_bandwidth_stats` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_bandwidth_stats_on(handle: u32) -> Result<BandwidthStatsDto, String> {
+    let stats = get_node_handle(handle)?.bandwidth_stats();
+    let usage = |name: String, subsystem: Subsystem, counts: ByteCounts| BandwidthUsageDto {
+        name,
+        subsystem: subsystem.into(),
+        bytes_sent: counts.sent,
+        bytes_received: counts.received,
+    };
+    Ok(BandwidthStatsDto {
+        topics: stats
+            .topics
+            .into_iter()
+            .map(|(name, subsystem, counts)| usage(name, subsystem, counts))
+            .collect(),
+        subsystems: stats
+            .subsystems
+            .into_iter()
+            .map(|(subsystem, counts)| usage(subsystem.name().to_string(), subsystem, counts))
+            .collect(),
+        today_bytes: stats.today_bytes,
+        month_bytes: stats.month_bytes,
+        caps: stats.caps.into(),
+        cap_exceeded: stats.cap_exceeded,
+    })
+}
+
+/// Set daily and monthly data caps, kept across restarts. Once one is used up
+/// the node stops syncing (and transferring snapshots) until the day or
+/// month is over.
+#[frb(sync)]
+pub fn set_bandwidth_caps(caps: BandwidthCapsDto) -> Result<(), String> {
+    set_bandwidth_caps_on(DEFAULT_NODE_HANDLE, caps)
+}
+
+/// `set_bandwidth_caps` on a `start_node_instance` node
+#[frb(sync)]
+pub fn set_bandwidth_caps_on(handle: u32, caps: BandwidthCapsDto) -> Result<(), String> {
+    let caps = BandwidthCaps {
+        daily_bytes: caps.daily_bytes,
+        monthly_bytes: caps.monthly_bytes,
+        metered_only: caps.metered_only,
+    };
+    get_node_handle(handle)?.set_bandwidth_caps(caps).map_err(|e| e.to_string())
+}
+
+/// Session traffic of one topic
+#[frb(dart_metadata=("freezed"))]
+pub struct TopicMetricsDto {
+    pub topic: String,
+    pub messages_sent: u64,
+    pub messages_received: u64,
+    /// Copies of recently received messages, dropped
+    pub duplicates_dropped: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+}
+
+/// Connects that took at most `le_ms`
+#[frb(dart_metadata=("freezed"))]
+pub struct LatencyBucketDto {
+    /// None for the bucket counting every connect
+    pub le_ms: Option<f64>,
+    pub count: u64,
+}
+
+#[frb(dart_metadata=("freezed"))]
+pub struct MetricsDto {
+    pub topics: Vec<TopicMetricsDto>,
+    pub ops_applied: u64,
+    pub verify_failures: u64,
+    /// Operations rejected for exceeding the operation limits
+    pub limit_violations: u64,
+    /// Cumulative
+    pub connect_latency: Vec<LatencyBucketDto>,
+    pub connect_latency_sum_ms: f64,
+    pub storage_size_bytes: u64,
+    pub storage_keys: u64,
+    pub stored_operations: u64,
+    pub outbox_depth: u64,
+}
+
+/// Get counters and histograms for an in-app dashboard: messages and bytes
+/// per topic, sync operations applied, signature verify failures, connect
+/// latencies and storage sizes
+#[frb(sync)]
+pub fn get_metrics() -> Result<MetricsDto, String> {
+    get_metrics_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_metrics` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_metrics_on(handle: u32) -> Result<MetricsDto, String> {
+    let metrics = get_node_handle(handle)?.metrics();
+    let topics = metrics
+        .topic_bytes
+        .into_iter()
+        .map(|(topic, bytes)| {
+            let messages = metrics
+                .messages
+                .iter()
+                .find(|(name, _)| *name == topic)
+                .map(|(_, counts)| *counts)
+                .unwrap_or_default();
+            TopicMetricsDto {
+                topic,
+                messages_sent: messages.sent,
+                messages_received: messages.received,
+                duplicates_dropped: messages.duplicates,
+                bytes_sent: bytes.sent,
+                bytes_received: bytes.received,
+            }
+        })
+        .collect();
+    let latency = &metrics.connect_latency;
+    let bounds = latency.bounds.iter().map(|bound| Some(bound * 1000.0)).chain([None]);
+    Ok(MetricsDto {
+        topics,
+        ops_applied: metrics.ops_applied,
+        verify_failures: metrics.verify_failures,
+        limit_violations: metrics.limit_violations,
+        connect_latency: bounds
+            .zip(latency.cumulative())
+            .map(|(le_ms, count)| LatencyBucketDto { le_ms, count })
+            .collect(),
+        connect_latency_sum_ms: latency.sum * 1000.0,
+        storage_size_bytes: metrics.storage_size_bytes,
+        storage_keys: metrics.storage_keys,
+        stored_operations: metrics.stored_operations,
+        outbox_depth: metrics.outbox_depth,
+    })
+}
+
+/// Serve the metrics in the Prometheus text format at `/metrics` on
+/// `bind_addr` (e.g. "0.0.0.0:9464") until the node stops, for fleet
+/// monitoring of rooted and desktop deployments. Returns the address bound.
+#[frb]
+pub async fn start_metrics_server(bind_addr: String) -> Result<String, String> {
+    start_metrics_server_on(DEFAULT_NODE_HANDLE, bind_addr).await
+}
+
+/// `start_metrics_server` on a `start_node_instance` node
+#[frb]
+pub async fn start_metrics_server_on(handle: u32, bind_addr: String) -> Result<String, String> {
+    let addr = bind_addr.parse().map_err(|e| format!("Invalid address {}: {}", bind_addr, e))?;
+    let node = get_node_handle(handle)?;
+    let local_addr = node.serve_metrics(addr).await.map_err(|e| e.to_string())?;
+    Ok(local_addr.to_string())
+}
+
+/// A running debug API server
+#[frb(dart_metadata=("freezed"))]
+pub struct DebugServerDto {
+    /// Address bound, e.g. "127.0.0.1:8765"
+    pub address: String,
+    /// Bearer token every request must carry, new on each start
+    pub token: String,
+}
+
+/// Serve a debug API (status, peers, databases, keys, logs, metrics and
+/// GraphQL) on a loopback address such as "127.0.0.1:8765", until the node
+/// stops. Reach it from a computer with `adb forward tcp:8765 tcp:8765`,
+/// sending `Authorization: Bearer <token>` with the returned token.
+/// GraphQL's `submitData` is only served if `allow_writes`.
+#[frb]
+pub async fn start_debug_server(bind_addr: String, allow_writes: bool) -> Result<DebugServerDto, String> {
+    start_debug_server_on(DEFAULT_NODE_HANDLE, bind_addr, allow_writes).await
+}
+
+/// `start_debug_server` on a `start_node_instance` node
+#[frb]
+pub async fn start_debug_server_on(handle: u32, bind_addr: String, allow_writes: bool) -> Result<DebugServerDto, String> {
+    let addr = bind_addr.parse().map_err(|e| format!("Invalid address {}: {}", bind_addr, e))?;
+    let node = get_node_handle(handle)?;
+    let logs = |limit: usize| {
+        get_logs(Some(limit.min(logs::MAX_CAPACITY) as u32))
+            .into_iter()
+            .map(|entry| serde_json::json!({ "timestamp": entry.timestamp, "level": entry.level, "message": entry.message }))
+            .collect()
+    };
+    let (local_addr, token) = node.serve_debug_api(addr, allow_writes, logs).await.map_err(|e| e.to_string())?;
+    Ok(DebugServerDto { address: local_addr.to_string(), token })
+}
+
+/// Execute a GraphQL query or mutation with the desktop node's schema
+/// (`getString`, `getAll`, `submitData`, ...). `variables` is a JSON object.
+/// Returns the GraphQL response as JSON; query errors are in its `errors`.
+#[frb]
+pub async fn graphql_execute(query: String, variables: Option<String>) -> Result<String, String> {
+    graphql_execute_on(DEFAULT_NODE_HANDLE, query, variables).await
+}
+
+/// `graphql_execute` on a `start_node_instance` node
+#[frb]
+pub async fn graphql_execute_on(handle: u32, query: String, variables: Option<String>) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    graphql::execute(node, &query, variables.as_deref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Which way an MQTT topic mapping carries messages
+pub enum MqttDirectionDto {
+    /// Device publishes become signed operations
+    Inbound,
+    /// Database changes are published to the broker
+    Outbound,
+    Both,
+}
+
+/// MQTT topics under `topic_prefix` mirror the keys of `db_name`
+#[frb(dart_metadata=("freezed"))]
+pub struct MqttTopicMappingDto {
+    pub topic_prefix: String,
+    pub db_name: String,
+    pub direction: MqttDirectionDto,
+}
+
+/// Broker and topic mappings of the MQTT bridge
+#[frb(dart_metadata=("freezed"))]
+pub struct MqttBridgeConfigDto {
+    pub host: String,
+    /// Default 1883
+    pub port: Option<u16>,
+    pub client_id: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub mappings: Vec<MqttTopicMappingDto>,
+}
+
+impl From<MqttBridgeConfigDto> for MqttBridgeConfig {
+    fn from(dto: MqttBridgeConfigDto) -> Self {
+        Self {
+            host: dto.host,
+            port: dto.port.unwrap_or(DEFAULT_MQTT_PORT),
+            client_id: dto.client_id,
+            username: dto.username,
+            password: dto.password,
+            mappings: dto
+                .mappings
+                .into_iter()
+                .map(|mapping| TopicMapping {
+                    topic_prefix: mapping.topic_prefix,
+                    db_name: mapping.db_name,
+                    direction: match mapping.direction {
+                        MqttDirectionDto::Inbound => BridgeDirection::Inbound,
+                        MqttDirectionDto::Outbound => BridgeDirection::Outbound,
+                        MqttDirectionDto::Both => BridgeDirection::Both,
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<MqttBridgeConfig> for MqttBridgeConfigDto {
+    fn from(config: MqttBridgeConfig) -> Self {
+        Self {
+            host: config.host,
+            port: Some(config.port),
+            client_id: config.client_id,
+            username: config.username,
+            // Not handed back to Dart
+            password: None,
+            mappings: config
+                .mappings
+                .into_iter()
+                .map(|mapping| MqttTopicMappingDto {
+                    topic_prefix: mapping.topic_prefix,
+                    db_name: mapping.db_name,
+                    direction: match mapping.direction {
+                        BridgeDirection::Inbound => MqttDirectionDto::Inbound,
+                        BridgeDirection::Outbound => MqttDirectionDto::Outbound,
+                        BridgeDirection::Both => MqttDirectionDto::Both,
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Act as the gateway for local IoT devices: bridge topics of an MQTT broker
+/// to databases, replacing the running bridge. Device publishes are signed
+/// with the node key; database changes are published retained. The node
+/// announces the `mqtt` capability while the bridge runs.
+#[frb]
+pub async fn start_mqtt_bridge(config: MqttBridgeConfigDto) -> Result<(), String> {
+    start_mqtt_bridge_on(DEFAULT_NODE_HANDLE, config).await
+}
+
+/// `start_mqtt_bridge` on a `start_node_instance` node
+#[frb]
+pub async fn start_mqtt_bridge_on(handle: u32, config: MqttBridgeConfigDto) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.start_mqtt_bridge(config.into()).await.map_err(|e| e.to_string())
+}
+
+/// Stop the MQTT bridge; false if none was running
+#[frb]
+pub async fn stop_mqtt_bridge() -> Result<bool, String> {
+    stop_mqtt_bridge_on(DEFAULT_NODE_HANDLE).await
+}
+
+/// `stop_mqtt_bridge` on a `start_node_instance` node
+#[frb]
+pub async fn stop_mqtt_bridge_on(handle: u32) -> Result<bool, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.stop_mqtt_bridge().await)
+}
+
+/// Configuration of the running MQTT bridge (without the password)
+#[frb(sync)]
+pub fn get_mqtt_bridge_config() -> Result<Option<MqttBridgeConfigDto>, String> {
+    get_mqtt_bridge_config_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_mqtt_bridge_config` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_mqtt_bridge_config_on(handle: u32) -> Result<Option<MqttBridgeConfigDto>, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.mqtt_bridge_config().map(MqttBridgeConfigDto::from))
+}
+
+/// Leave the gossip network while the app is in the background (call from
+/// the paused lifecycle hook). Storage stays open; joined topics are rejoined
+/// by `resume_networking`.
+#[frb]
+pub async fn pause_networking() -> Result<(), String> {
+    pause_networking_on(DEFAULT_NODE_HANDLE).await
+}
+
+/// `pause_networking` on a `start_node_instance` node
+#[frb]
+pub async fn pause_networking_on(handle: u32) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.pause_networking().await.map_err(|e| e.to_string())
+}
+
+/// Rejoin the gossip network and sync what was missed while paused (call
+/// from the resumed lifecycle hook)
+#[frb]
+pub async fn resume_networking() -> Result<(), String> {
+    resume_networking_on(DEFAULT_NODE_HANDLE).await
+}
+
+/// `resume_networking` on a `start_node_instance` node
+#[frb]
+pub async fn resume_networking_on(handle: u32) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.resume_networking().await.map_err(|e| e.to_string())
+}
+
+/// What `checkpoint` saved
+#[frb(dart_metadata=("freezed"))]
+pub struct CheckpointDto {
+    /// Timestamp of the newest operation (ms)
+    pub high_water_mark: Option<i64>,
+    pub known_peers_saved: u32,
+    /// Queued writes, now on disk
+    pub outbox_depth: u32,
+    /// Storage reached the disk within the budget
+    pub flushed: bool,
+    pub duration_ms: u64,
+}
+
+/// Save the node's in-memory state and flush storage to disk, taking at
+/// most `budget_ms` (2 seconds by default). Call from the paused lifecycle
+/// hook: Android may kill the process afterwards without `stop_node`.
+#[frb]
+pub async fn checkpoint(budget_ms: Option<u64>) -> Result<CheckpointDto, String> {
+    checkpoint_on(DEFAULT_NODE_HANDLE, budget_ms).await
+}
+
+/// `checkpoint` on a `start_node_instance` node
+#[frb]
+pub async fn checkpoint_on(handle: u32, budget_ms: Option<u64>) -> Result<CheckpointDto, String> {
+    let node = get_node_handle(handle)?;
+    let budget = budget_ms.map(Duration::from_millis).unwrap_or(DEFAULT_CHECKPOINT_BUDGET);
+    let summary = node.checkpoint(budget).await.map_err(|e| e.to_string())?;
+
+    Ok(CheckpointDto {
+        high_water_mark: summary.high_water_mark,
+        known_peers_saved: summary.known_peers_saved as u32,
+        outbox_depth: summary.outbox_depth as u32,
+        flushed: summary.flushed,
+        duration_ms: summary.duration_ms,
+    })
+}
+
+/// Whether networking is paused
+#[frb(sync)]
+pub fn is_networking_paused() -> Result<bool, String> {
+    is_networking_paused_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `is_networking_paused` of a `start_node_instance` node
+#[frb(sync)]
+pub fn is_networking_paused_on(handle: u32) -> Result<bool, String> {
+    Ok(get_node_handle(handle)?.is_networking_paused())
+}
+
+/// Call `method` on a peer directly and wait for its response, by default
+/// for 30 seconds. Peers answer the built-in method "get" (payload
+/// `{"db_name": .., "key": ..}`) themselves; other methods are answered by
+/// their application through `serve_rpc`.
+#[frb]
+pub async fn rpc_call(
+    peer_id: String,
+    method: String,
+    payload: Vec<u8>,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<u8>, String> {
+    rpc_call_on(DEFAULT_NODE_HANDLE, peer_id, method, payload, timeout_ms).await
+}
+
+/// `rpc_call` on a `start_node_instance` node
+#[frb]
+pub async fn rpc_call_on(
+    handle: u32,
+    peer_id: String,
+    method: String,
+    payload: Vec<u8>,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<u8>, String> {
+    let node = get_node_handle(handle)?;
+    let timeout = timeout_ms.map_or(crate::rpc::DEFAULT_RPC_TIMEOUT, std::time::Duration::from_millis);
+
+    node.rpc_call(peer_id, method, payload, timeout).await
+}
+
+/// RPC request from a peer, answered with `respond_rpc` or `reject_rpc`
+#[frb(dart_metadata=("freezed"))]
+pub struct RpcRequestDto {
+    pub request_id: u64,
+    pub peer_id: String,
+    pub method: String,
+    pub payload: Vec<u8>,
+}
+
+/// Stream peers' RPC requests to the application. Requests not answered
+/// within 30 seconds fail; only the latest stream receives requests.
+#[frb(sync)]
+pub fn serve_rpc(sink: StreamSink<RpcRequestDto>) -> Result<(), String> {
+    serve_rpc_on(DEFAULT_NODE_HANDLE, sink)
+}
+
+/// `serve_rpc` on a `start_node_instance` node
+#[frb(sync)]
+pub fn serve_rpc_on(handle: u32, sink: StreamSink<RpcRequestDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let mut requests = node.serve_rpc();
+
+    get_runtime().spawn(async move {
+        while let Some(request) = requests.recv().await {
+            let request = RpcRequestDto {
+                request_id: request.request_id,
+                peer_id: request.peer_id,
+                method: request.method,
+                payload: request.payload,
+            };
+            if sink.add(request).is_err() {
+                break;
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Answer an RPC request. Returns false if it timed out already.
+#[frb(sync)]
+pub fn respond_rpc(request_id: u64, payload: Vec<u8>) -> Result<bool, String> {
+    respond_rpc_on(DEFAULT_NODE_HANDLE, request_id, payload)
+}
+
+/// `respond_rpc` on a `start_node_instance` node
+#[frb(sync)]
+pub fn respond_rpc_on(handle: u32, request_id: u64, payload: Vec<u8>) -> Result<bool, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.respond_rpc(request_id, Ok(payload)))
+}
+
+/// Fail an RPC request with `message`, returned to the caller as its error.
+/// Returns false if it timed out already.
+#[frb(sync)]
+pub fn reject_rpc(request_id: u64, message: String) -> Result<bool, String> {
+    reject_rpc_on(DEFAULT_NODE_HANDLE, request_id, message)
+}
+
+/// `reject_rpc` on a `start_node_instance` node
+#[frb(sync)]
+pub fn reject_rpc_on(handle: u32, request_id: u64, message: String) -> Result<bool, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.respond_rpc(request_id, Err(message)))
+}
+
+/// Presence of a peer, from its heartbeats
+#[frb(dart_metadata=("freezed"))]
+pub struct PresenceDto {
+    pub peer_id: String,
+    pub online: bool,
+    pub status: Option<String>,
+    /// Unix ms of the last heartbeat
+    pub last_seen: i64,
+}
+
+/// Last known presence of a peer, None if it was never heard from. Peers are
+/// offline 45 seconds after their last heartbeat.
+#[frb(sync)]
+pub fn get_presence(peer_id: String) -> Result<Option<PresenceDto>, String> {
+    get_presence_on(DEFAULT_NODE_HANDLE, peer_id)
+}
+
+/// `get_presence` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_presence_on(handle: u32, peer_id: String) -> Result<Option<PresenceDto>, String> {
+    let node = get_node_handle(handle)?;
+
+    Ok(node.get_presence(&peer_id).map(|presence| PresenceDto {
+        peer_id,
+        online: presence.online,
+        status: presence.status,
+        last_seen: presence.last_seen,
+    }))
+}
+
+/// Set the status string (e.g. "away") other nodes see with our presence
+#[frb(sync)]
+pub fn set_presence_status(status: Option<String>) -> Result<(), String> {
+    set_presence_status_on(DEFAULT_NODE_HANDLE, status)
+}
+
+/// `set_presence_status` on a `start_node_instance` node
+#[frb(sync)]
+pub fn set_presence_status_on(handle: u32, status: Option<String>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.set_presence_status(status).map_err(|e| e.to_string())
+}
+
+/// End-to-end encrypted direct message
+#[frb(dart_metadata=("freezed"))]
+pub struct DirectMessageDto {
+    pub id: String,
+    /// The other side of the conversation
+    pub peer_id: String,
+    /// Sent by this node rather than received
+    pub outgoing: bool,
+    pub text: String,
+    /// Unix ms
+    pub sent_at: i64,
+}
+
+impl From<DirectMessage> for DirectMessageDto {
+    fn from(message: DirectMessage) -> Self {
+        Self {
+            id: message.id,
+            peer_id: message.peer_id,
+            outgoing: message.outgoing,
+            text: message.text,
+            sent_at: message.sent_at,
+        }
+    }
+}
+
+/// Send an end-to-end encrypted message to a peer. Fails if the peer can't
+/// be reached; delivered messages are kept for `get_dm_history`.
+#[frb]
+pub async fn send_dm(peer_id: String, text: String) -> Result<DirectMessageDto, String> {
+    send_dm_on(DEFAULT_NODE_HANDLE, peer_id, text).await
+}
+
+/// `send_dm` on a `start_node_instance` node
+#[frb]
+pub async fn send_dm_on(handle: u32, peer_id: String, text: String) -> Result<DirectMessageDto, String> {
+    let node = get_node_handle(handle)?;
+    let message = node.send_dm(&peer_id, text).await.map_err(|e| e.to_string())?;
+
+    Ok(message.into())
+}
+
+/// Stream direct messages received from now on
+#[frb(sync)]
+pub fn dm_stream(sink: StreamSink<DirectMessageDto>) -> Result<(), String> {
+    dm_stream_on(DEFAULT_NODE_HANDLE, sink)
+}
+
+/// `dm_stream` on a `start_node_instance` node
+#[frb(sync)]
+pub fn dm_stream_on(handle: u32, sink: StreamSink<DirectMessageDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let mut messages = node.subscribe_dms();
+
+    get_runtime().spawn(async move {
+        use tokio::sync::broadcast::error::RecvError;
+
+        loop {
+            match messages.recv().await {
+                Ok(message) => {
+                    if sink.add(DirectMessageDto::from(message)).is_err() {
+                        break;
+                    }
+                }
+                // Missed messages are in the history
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Direct message stream lagged, skipped {} messages", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+/// The last `limit` direct messages exchanged with a peer, oldest first
+#[frb(sync)]
+pub fn get_dm_history(peer_id: String, limit: u32) -> Result<Vec<DirectMessageDto>, String> {
+    get_dm_history_on(DEFAULT_NODE_HANDLE, peer_id, limit)
+}
+
+/// `get_dm_history` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_dm_history_on(handle: u32, peer_id: String, limit: u32) -> Result<Vec<DirectMessageDto>, String> {
+    let node = get_node_handle(handle)?;
+    let messages = node.dm_history(&peer_id, limit as usize).map_err(|e| e.to_string())?;
+
+    Ok(messages.into_iter().map(DirectMessageDto::from).collect())
+}
+
+/// A file a peer offers to send
+#[frb(dart_metadata=("freezed"))]
+pub struct FileOfferDto {
+    pub offer_id: String,
+    /// Node ID of the sender
+    pub from: String,
+    pub name: String,
+    pub size: u64,
+    /// Unix ms
+    pub offered_at: i64,
+}
+
+impl From<FileOffer> for FileOfferDto {
+    fn from(offer: FileOffer) -> Self {
+        Self {
+            offer_id: offer.offer_id,
+            from: offer.from,
+            name: offer.name,
+            size: offer.size,
+            offered_at: offer.offered_at,
+        }
+    }
+}
+
+/// File offer or transfer progress
+#[frb(dart_metadata=("freezed"))]
+pub enum FileEventDto {
+    Offer(FileOfferDto),
+    Progress { offer_id: String, received_bytes: u64, total_bytes: u64 },
+    /// The download was interrupted and is retried from where it stopped
+    Retrying { offer_id: String, attempt: u32, error: String },
+    Completed { offer_id: String, path: String },
+    /// Gave up retrying; `accept_file` again resumes the download
+    Failed { offer_id: String, error: String },
+}
+
+impl From<FileEvent> for FileEventDto {
+    fn from(event: FileEvent) -> Self {
+        match event {
+            FileEvent::Offer(offer) => Self::Offer(offer.into()),
+            FileEvent::Progress { offer_id, received_bytes, total_bytes } => {
+                Self::Progress { offer_id, received_bytes, total_bytes }
+            }
+            FileEvent::Retrying { offer_id, attempt, error } => Self::Retrying { offer_id, attempt, error },
+            FileEvent::Completed { offer_id, path } => Self::Completed { offer_id, path },
+            FileEvent::Failed { offer_id, error } => Self::Failed { offer_id, error },
+        }
+    }
+}
+
+/// Offer the file at `path` to a peer, which receives a `FileEventDto::Offer`.
+/// Fails if the peer can't be reached; the file is served until the node stops.
+#[frb]
+pub async fn send_file(peer_id: String, path: String) -> Result<FileOfferDto, String> {
+    send_file_on(DEFAULT_NODE_HANDLE, peer_id, path).await
+}
+
+/// `send_file` on a `start_node_instance` node
+#[frb]
+pub async fn send_file_on(handle: u32, peer_id: String, path: String) -> Result<FileOfferDto, String> {
+    let node = get_node_handle(handle)?;
+    let offer = node.send_file(&peer_id, std::path::Path::new(&path)).await.map_err(|e| e.to_string())?;
+
+    Ok(offer.into())
+}
+
+/// Download an offered file to `dest_path`, reporting progress on
+/// `file_event_stream`. Interrupted downloads are retried, and calling this
+/// again after a failure or restart resumes where it stopped. Returns the
+/// file size.
+#[frb]
+pub async fn accept_file(offer_id: String, dest_path: String) -> Result<u64, String> {
+    accept_file_on(DEFAULT_NODE_HANDLE, offer_id, dest_path).await
+}
+
+/// `accept_file` on a `start_node_instance` node
+#[frb]
+pub async fn accept_file_on(handle: u32, offer_id: String, dest_path: String) -> Result<u64, String> {
+    let node = get_node_handle(handle)?;
+    node.accept_file(&offer_id, std::path::Path::new(&dest_path)).await.map_err(|e| e.to_string())
+}
+
+/// Forget a file offer without downloading it
+#[frb(sync)]
+pub fn decline_file(offer_id: String) -> Result<(), String> {
+    decline_file_on(DEFAULT_NODE_HANDLE, offer_id)
+}
+
+/// `decline_file` on a `start_node_instance` node
+#[frb(sync)]
+pub fn decline_file_on(handle: u32, offer_id: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.decline_file(&offer_id).map_err(|e| e.to_string())
+}
+
+/// File offers not accepted or declined yet, oldest first
+#[frb(sync)]
+pub fn list_file_offers() -> Result<Vec<FileOfferDto>, String> {
+    list_file_offers_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `list_file_offers` of a `start_node_instance` node
+#[frb(sync)]
+pub fn list_file_offers_on(handle: u32) -> Result<Vec<FileOfferDto>, String> {
+    let node = get_node_handle(handle)?;
+    let offers = node.file_offers().map_err(|e| e.to_string())?;
+
+    Ok(offers.into_iter().map(FileOfferDto::from).collect())
+}
+
+/// Stream file offers and transfer progress from now on
+#[frb(sync)]
+pub fn file_event_stream(sink: StreamSink<FileEventDto>) -> Result<(), String> {
+    file_event_stream_on(DEFAULT_NODE_HANDLE, sink)
+}
+
+/// `file_event_stream` on a `start_node_instance` node
+#[frb(sync)]
+pub fn file_event_stream_on(handle: u32, sink: StreamSink<FileEventDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let mut events = node.subscribe_files();
+
+    get_runtime().spawn(async move {
+        use tokio::sync::broadcast::error::RecvError;
+
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if sink.add(FileEventDto::from(event)).is_err() {
+                        break;
+                    }
+                }
+                // Missed offers are in `list_file_offers`
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("File event stream lagged, skipped {} events", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+/// A device linked to a user key
+#[frb(dart_metadata=("freezed"))]
+pub struct LinkedDeviceDto {
+    pub user_public_key: String,
+    /// Node ID of the device
+    pub node_id: String,
+    pub device_name: String,
+    /// Unix ms
+    pub linked_at: i64,
+    /// Whether the device is this one
+    pub this_device: bool,
+}
+
+impl LinkedDeviceDto {
+    fn new(certificate: DeviceCertificate, own_node_id: &str) -> Self {
+        Self {
+            this_device: certificate.device_node_id() == own_node_id,
+            user_public_key: certificate.user_public_key,
+            node_id: certificate.request.node_id,
+            device_name: certificate.request.device_name,
+            linked_at: certificate.linked_at,
+        }
+    }
+}
+
+/// Payload for the QR code a new device shows to be linked; valid for ten
+/// minutes
+#[frb(sync)]
+pub fn create_device_link_request(device_name: String) -> Result<String, String> {
+    create_device_link_request_on(DEFAULT_NODE_HANDLE, device_name)
+}
+
+/// `create_device_link_request` on a `start_node_instance` node
+#[frb(sync)]
+pub fn create_device_link_request_on(handle: u32, device_name: String) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    node.device_link_request(&device_name).map_err(|e| e.to_string())
+}
+
+/// Link the device whose QR code was scanned to the user key `secret_key`
+/// (hex), run on the primary device
+#[frb(sync)]
+pub fn link_device(qr_payload: String, secret_key: String) -> Result<LinkedDeviceDto, String> {
+    link_device_on(DEFAULT_NODE_HANDLE, qr_payload, secret_key)
+}
+
+/// `link_device` on a `start_node_instance` node
+#[frb(sync)]
+pub fn link_device_on(handle: u32, qr_payload: String, secret_key: String) -> Result<LinkedDeviceDto, String> {
+    let node = get_node_handle(handle)?;
+    let user_key = signing_key_from_hex(&secret_key)?;
+    let certificate = node.link_device(&qr_payload, &user_key).map_err(|e| e.to_string())?;
+
+    Ok(LinkedDeviceDto::new(certificate, node.node_id()))
+}
+
+/// Devices linked to this device's user keys, oldest link first
+#[frb(sync)]
+pub fn list_linked_devices() -> Result<Vec<LinkedDeviceDto>, String> {
+    list_linked_devices_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `list_linked_devices` of a `start_node_instance` node
+#[frb(sync)]
+pub fn list_linked_devices_on(handle: u32) -> Result<Vec<LinkedDeviceDto>, String> {
+    let node = get_node_handle(handle)?;
+    let certificates = node.linked_devices().map_err(|e| e.to_string())?;
+
+    Ok(certificates.into_iter().map(|certificate| LinkedDeviceDto::new(certificate, node.node_id())).collect())
+}
+
+/// Send latency request to measure peer latency
+#[frb]
+pub async fn send_latency_request(peer_id: String) -> Result<(), String> {
+    send_latency_request_on(DEFAULT_NODE_HANDLE, peer_id).await
+}
+
+/// `send_latency_request` on a `start_node_instance` node
+#[frb]
+pub async fn send_latency_request_on(handle: u32, peer_id: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    
+    // The measured latency also comes via event
+    let _ = node.send_latency_request(peer_id).await;
+    Ok(())
+}
+
+/// Measure one-way latency to a peer in milliseconds (half a direct ping
+/// round trip). Also updates the peer's `latency_ms`.
+#[frb]
+pub async fn measure_latency(peer_id: String) -> Result<u64, String> {
+    measure_latency_on(DEFAULT_NODE_HANDLE, peer_id).await
+}
+
+/// `measure_latency` on a `start_node_instance` node
+#[frb]
+pub async fn measure_latency_on(handle: u32, peer_id: String) -> Result<u64, String> {
+    let node = get_node_handle(handle)?;
+
+    node.send_latency_request(peer_id).await
+}
+
+/// Peer that proved it controls its node ID and public key
+#[frb(dart_metadata=("freezed"))]
+pub struct VerifiedBindingDto {
+    pub node_id: String,
+    pub public_key: String,
+    /// Unix ms
+    pub verified_at: i64,
+}
+
+impl From<VerifiedBinding> for VerifiedBindingDto {
+    fn from(binding: VerifiedBinding) -> Self {
+        Self {
+            node_id: binding.node_id,
+            public_key: binding.public_key,
+            verified_at: binding.verified_at,
+        }
+    }
+}
+
+/// Challenge a peer over a direct connection to prove it controls its node
+/// ID and the ed25519 key it advertises. Do this before trusting a peer
+/// with sensitive operations; a proof from the last hour is reused.
+#[frb]
+pub async fn prove_ownership(peer_id: String) -> Result<VerifiedBindingDto, String> {
+    prove_ownership_on(DEFAULT_NODE_HANDLE, peer_id).await
+}
+
+/// `prove_ownership` on a `start_node_instance` node
+#[frb]
+pub async fn prove_ownership_on(handle: u32, peer_id: String) -> Result<VerifiedBindingDto, String> {
+    let node = get_node_handle(handle)?;
+
+    node.prove_ownership(peer_id).await.map(VerifiedBindingDto::from)
+}
+
+/// Whether a peer recently proved it holds `public_key` (see `prove_ownership`)
+#[frb(sync)]
+pub fn is_verified_peer_key(peer_id: String, public_key: String) -> Result<bool, String> {
+    is_verified_peer_key_on(DEFAULT_NODE_HANDLE, peer_id, public_key)
+}
+
+/// `is_verified_peer_key` of a `start_node_instance` node
+#[frb(sync)]
+pub fn is_verified_peer_key_on(handle: u32, peer_id: String, public_key: String) -> Result<bool, String> {
+    let node = get_node_handle(handle)?;
+
+    Ok(node.is_verified_peer_key(&peer_id, &public_key))
+}
+
+/// Result of dialing a peer
+#[frb(dart_metadata=("freezed"))]
+pub struct ConnectionResultDto {
+    pub node_id: String,
+    /// "direct", "relayed", or "unknown" if no path was selected yet
+    pub path: String,
+    pub handshake_ms: u64,
+}
+
+/// Where this node can be dialed
+#[frb(dart_metadata=("freezed"))]
+pub struct NodeAddrDto {
+    pub node_id: String,
+    /// `ip:port`
+    pub direct_addresses: Vec<String>,
+    pub relay_urls: Vec<String>,
+}
+
+/// Our node ID, direct addresses and relay URLs
+#[frb(sync)]
+pub fn get_node_addr() -> Result<NodeAddrDto, String> {
+    get_node_addr_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_node_addr` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_node_addr_on(handle: u32) -> Result<NodeAddrDto, String> {
+    let node = get_node_handle(handle)?;
+    let addr = node.node_addr().ok_or("The node is offline")?;
+    Ok(NodeAddrDto {
+        node_id: addr.id.to_string(),
+        direct_addresses: addr.ip_addrs().map(|ip| ip.to_string()).collect(),
+        relay_urls: addr.relay_urls().map(|url| url.to_string()).collect(),
+    })
+}
+
+/// Short string with our node ID and addresses, e.g. to show as a QR code.
+/// Another device passes it to `connect_peer` as the node ID to pair
+/// without gossip discovery.
+#[frb(sync)]
+pub fn get_node_ticket() -> Result<String, String> {
+    get_node_ticket_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_node_ticket` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_node_ticket_on(handle: u32) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    let ticket = node.node_ticket().ok_or("The node is offline")?;
+    Ok(ticket.to_string())
+}
+
+/// Dial a peer by node ID or ticket (see `get_node_ticket`), optionally at
+/// a known `ip:port` or `host:port` (e.g. pasted or scanned for pairing).
+/// Host names are looked up on every dial. Without an
+/// address the peer is found through the ticket, address lookup and relays.
+#[frb]
+pub async fn connect_peer(node_id: String, address: Option<String>) -> Result<ConnectionResultDto, String> {
+    connect_peer_on(DEFAULT_NODE_HANDLE, node_id, address).await
+}
+
+/// `connect_peer` on a `start_node_instance` node
+#[frb]
+pub async fn connect_peer_on(handle: u32, node_id: String, address: Option<String>) -> Result<ConnectionResultDto, String> {
+    let node = get_node_handle(handle)?;
+    let result = node.connect_peer(node_id, address).await?;
+
+    Ok(ConnectionResultDto {
+        node_id: result.node_id,
+        path: result.path.map(|p| p.as_str()).unwrap_or("unknown").to_string(),
+        handshake_ms: result.handshake_ms,
+    })
+}
+
+/// Store data in local database with signature for sync. Values over 64 KiB
+/// are kept in the blob store and replicated as a reference; `get_data`
+/// returns the bytes either way.
+#[frb]
+pub async fn store_data(
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    public_key: String,
+    signature: String,
+) -> Result<(), String> {
+    store_data_on(DEFAULT_NODE_HANDLE, db_name, key, value, public_key, signature).await
+}
+
+/// Store data signed with this node's key, so Flutter doesn't sign anything.
+/// `db_name` must be named after the node's public key (see
+/// `generate_db_name`) or list it as a writer. Returns the op_id.
+#[frb]
+pub async fn store_data_signed(
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    store_type: String,
+) -> Result<String, String> {
+    store_data_signed_on(DEFAULT_NODE_HANDLE, db_name, key, value, store_type).await
+}
+
+/// `store_data_signed` on a `start_node_instance` node
+#[frb]
+pub async fn store_data_signed_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    store_type: String,
+) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+
+    node.store_data_signed(&db_name, &key, &value, &store_type)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// `store_data` on a `start_node_instance` node
+#[frb]
+pub async fn store_data_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    public_key: String,
+    signature: String,
+) -> Result<(), String> {
+    get_node_handle(handle)?
+        .store_data(db_name, key, value, public_key, signature)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Store many signed entries at once (e.g. importing contacts): one storage
+/// batch and a single flush instead of one per entry. Returns the count stored.
+#[frb]
+pub async fn store_batch(entries: Vec<BatchEntryDto>) -> Result<u32, String> {
+    store_batch_on(DEFAULT_NODE_HANDLE, entries).await
+}
+
+/// `store_batch` on a `start_node_instance` node
+#[frb]
+pub async fn store_batch_on(handle: u32, entries: Vec<BatchEntryDto>) -> Result<u32, String> {
+    let node = get_node_handle(handle)?;
+    let stored = node.store_batch(entries).await.map_err(|e| e.to_string())?;
+    Ok(stored as u32)
+}
+
+/// Store a value that expires after `ttl_secs` on this device and every peer
+/// (e.g. presence/status). Signed with `secret_key`, like `store_encrypted`.
+#[frb]
+pub async fn store_data_with_ttl(
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    ttl_secs: u64,
+    secret_key: String,
+) -> Result<(), String> {
+    store_data_with_ttl_on(DEFAULT_NODE_HANDLE, db_name, key, value, ttl_secs, secret_key).await
+}
+
+/// `store_data_with_ttl` on a `start_node_instance` node
+#[frb]
+pub async fn store_data_with_ttl_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    ttl_secs: u64,
+    secret_key: String,
+) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let signing_key = signing_key_from_hex(&secret_key)?;
+
+    node.store_data_with_ttl(&db_name, &key, &value, ttl_secs, &signing_key)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Store a value whose op_id is derived from its content and `timestamp_ms`,
+/// so retrying a write that may have failed (with the same timestamp) doesn't
+/// create a duplicate operation. Signed with `secret_key`; returns the op_id.
+#[frb]
+pub async fn store_data_idempotent(
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    timestamp_ms: i64,
+    secret_key: String,
+) -> Result<String, String> {
+    store_data_idempotent_on(DEFAULT_NODE_HANDLE, db_name, key, value, timestamp_ms, secret_key).await
+}
+
+/// `store_data_idempotent` on a `start_node_instance` node
+#[frb]
+pub async fn store_data_idempotent_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    timestamp_ms: i64,
+    secret_key: String,
+) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    let signing_key = signing_key_from_hex(&secret_key)?;
+
+    node.store_data_idempotent(&db_name, &key, &value, timestamp_ms, &signing_key)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Add `delta` (may be negative) to a replicated counter and return its new
+/// value. Concurrent increments on different devices add up.
+#[frb]
+pub async fn incr(db_name: String, key: String, delta: i64, secret_key: String) -> Result<i64, String> {
+    incr_on(DEFAULT_NODE_HANDLE, db_name, key, delta, secret_key).await
+}
+
+/// `incr` on a `start_node_instance` node
+#[frb]
+pub async fn incr_on(handle: u32, db_name: String, key: String, delta: i64, secret_key: String) -> Result<i64, String> {
+    let node = get_node_handle(handle)?;
+    let signing_key = signing_key_from_hex(&secret_key)?;
+
+    node.incr(&db_name, &key, delta, &signing_key).await.map_err(|e| e.to_string())
+}
+
+/// Current value of a counter (0 if it was never incremented)
+#[frb(sync)]
+pub fn get_counter(db_name: String, key: String) -> Result<i64, String> {
+    get_counter_on(DEFAULT_NODE_HANDLE, db_name, key)
+}
+
+/// `get_counter` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_counter_on(handle: u32, db_name: String, key: String) -> Result<i64, String> {
+    let node = get_node_handle(handle)?;
+    node.get_counter(&db_name, &key).map_err(|e| e.to_string())
+}
+
+/// Change of a collaborative text: `deleted` characters at `index` replaced
+/// by `inserted`
+#[frb(dart_metadata=("freezed"))]
+pub struct TextDeltaDto {
+    pub db_name: String,
+    pub key: String,
+    pub index: u64,
+    pub deleted: u64,
+    pub inserted: String,
+}
+
+impl From<TextDelta> for TextDeltaDto {
+    fn from(delta: TextDelta) -> Self {
+        Self {
+            db_name: delta.db_name,
+            key: delta.key,
+            index: delta.index,
+            deleted: delta.deleted,
+            inserted: delta.inserted,
+        }
+    }
+}
+
+/// Edit a collaborative text: replace `delete` characters at `index` with
+/// `insert` and return the new text. Positions count Unicode scalar values.
+/// Concurrent edits on different devices merge.
+#[frb]
+pub async fn edit_text(
+    db_name: String,
+    key: String,
+    index: u64,
+    delete: u64,
+    insert: String,
+    secret_key: String,
+) -> Result<String, String> {
+    edit_text_on(DEFAULT_NODE_HANDLE, db_name, key, index, delete, insert, secret_key).await
+}
+
+/// `edit_text` on a `start_node_instance` node
+#[frb]
+pub async fn edit_text_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    index: u64,
+    delete: u64,
+    insert: String,
+    secret_key: String,
+) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    let signing_key = signing_key_from_hex(&secret_key)?;
+
+    node.edit_text(&db_name, &key, index, delete, &insert, &signing_key).await.map_err(|e| e.to_string())
+}
+
+/// Current value of a collaborative text (empty if it was never edited)
+#[frb(sync)]
+pub fn get_text(db_name: String, key: String) -> Result<String, String> {
+    get_text_on(DEFAULT_NODE_HANDLE, db_name, key)
+}
+
+/// `get_text` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_text_on(handle: u32, db_name: String, key: String) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    node.get_text(&db_name, &key).map_err(|e| e.to_string())
+}
+
+/// Stream changes of collaborative texts made by other devices, to apply to
+/// an open editor
+#[frb(sync)]
+pub fn text_delta_stream(sink: StreamSink<TextDeltaDto>) -> Result<(), String> {
+    text_delta_stream_on(DEFAULT_NODE_HANDLE, sink)
+}
+
+/// `text_delta_stream` on a `start_node_instance` node
+#[frb(sync)]
+pub fn text_delta_stream_on(handle: u32, sink: StreamSink<TextDeltaDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let own_node_id = node.node_id().to_string();
+    let mut deltas = node.subscribe_text_deltas();
+
+    get_runtime().spawn(async move {
+        use tokio::sync::broadcast::error::RecvError;
+
+        loop {
+            match deltas.recv().await {
+                Ok(delta) if delta.replica == own_node_id => {}
+                Ok(delta) => {
+                    if sink.add(TextDeltaDto::from(delta)).is_err() {
+                        break;
+                    }
+                }
+                // Deltas no longer line up; re-read with `get_text`
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Text delta stream lagged, skipped {} deltas", skipped);
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+async fn put_typed(handle: u32, db_name: String, key: String, write: TypedWrite, secret_key: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let signing_key = signing_key_from_hex(&secret_key)?;
+
+    node.put_typed(&db_name, &key, write, &signing_key).await.map_err(|e| e.to_string())
+}
+
+fn typed_collection(handle: u32, db_name: &str, key: &str) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let node = get_node_handle(handle)?;
+    node.typed_collection(db_name, key).map_err(|e| e.to_string())
+}
+
+/// Set a string value
+#[frb]
+pub async fn put_string(db_name: String, key: String, value: String, secret_key: String) -> Result<(), String> {
+    put_string_on(DEFAULT_NODE_HANDLE, db_name, key, value, secret_key).await
+}
+
+/// `put_string` on a `start_node_instance` node
+#[frb]
+pub async fn put_string_on(handle: u32, db_name: String, key: String, value: String, secret_key: String) -> Result<(), String> {
+    put_typed(handle, db_name, key, TypedWrite::String { value }, secret_key).await
+}
+
+/// Set a JSON document; `json` must encode a map (e.g. `jsonEncode(map)`)
+#[frb]
+pub async fn put_json(db_name: String, key: String, json: String, secret_key: String) -> Result<(), String> {
+    put_json_on(DEFAULT_NODE_HANDLE, db_name, key, json, secret_key).await
+}
+
+/// `put_json` on a `start_node_instance` node
+#[frb]
+pub async fn put_json_on(handle: u32, db_name: String, key: String, json: String, secret_key: String) -> Result<(), String> {
+    put_typed(handle, db_name, key, TypedWrite::Json { value: json }, secret_key).await
+}
+
+/// Set a field of a hash
+#[frb]
+pub async fn hash_set(
+    db_name: String,
+    key: String,
+    field: String,
+    value: String,
+    secret_key: String,
+) -> Result<(), String> {
+    hash_set_on(DEFAULT_NODE_HANDLE, db_name, key, field, value, secret_key).await
+}
+
+/// `hash_set` on a `start_node_instance` node
+#[frb]
+pub async fn hash_set_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    field: String,
+    value: String,
+    secret_key: String,
+) -> Result<(), String> {
+    put_typed(handle, db_name, key, TypedWrite::Hash { field, value }, secret_key).await
+}
+
+/// A field of a hash
+#[frb]
+pub async fn hash_get(db_name: String, key: String, field: String) -> Result<Option<String>, String> {
+    hash_get_on(DEFAULT_NODE_HANDLE, db_name, key, field).await
+}
+
+/// `hash_get` on a `start_node_instance` node
+#[frb]
+pub async fn hash_get_on(handle: u32, db_name: String, key: String, field: String) -> Result<Option<String>, String> {
+    let node = get_node_handle(handle)?;
+    let value = node.get_data(db_name, format!("{}:{}", key, field)).await.map_err(|e| e.to_string())?;
+    Ok(value.map(|value| String::from_utf8_lossy(&value).into_owned()))
+}
+
+/// A field of a hash and its value
+#[frb(dart_metadata=("freezed"))]
+pub struct HashEntryDto {
+    pub field: String,
+    pub value: String,
+}
+
+/// All fields of a hash, by field name
+#[frb(sync)]
+pub fn hash_get_all(db_name: String, key: String) -> Result<Vec<HashEntryDto>, String> {
+    hash_get_all_on(DEFAULT_NODE_HANDLE, db_name, key)
+}
+
+/// `hash_get_all` on a `start_node_instance` node
+#[frb(sync)]
+pub fn hash_get_all_on(handle: u32, db_name: String, key: String) -> Result<Vec<HashEntryDto>, String> {
+    Ok(typed_collection(handle, &db_name, &key)?
+        .into_iter()
+        .map(|(field, value)| HashEntryDto { field, value: String::from_utf8_lossy(&value).into_owned() })
+        .collect())
+}
+
+/// Append an element to a list. Pushes from different devices interleave
+/// by write time.
+#[frb]
+pub async fn list_push(db_name: String, key: String, value: String, secret_key: String) -> Result<(), String> {
+    list_push_on(DEFAULT_NODE_HANDLE, db_name, key, value, secret_key).await
+}
+
+/// `list_push` of a `start_node_instance` node
+#[frb]
+pub async fn list_push_on(handle: u32, db_name: String, key: String, value: String, secret_key: String) -> Result<(), String> {
+    put_typed(handle, db_name, key, TypedWrite::List { value }, secret_key).await
+}
+
+/// Elements of a list in push order
+#[frb(sync)]
+pub fn list_range(db_name: String, key: String) -> Result<Vec<String>, String> {
+    list_range_on(DEFAULT_NODE_HANDLE, db_name, key)
+}
+
+/// `list_range` of a `start_node_instance` node
+#[frb(sync)]
+pub fn list_range_on(handle: u32, db_name: String, key: String) -> Result<Vec<String>, String> {
+    Ok(typed::values(typed_collection(handle, &db_name, &key)?))
+}
+
+/// Add a member to a set
+#[frb]
+pub async fn set_add(db_name: String, key: String, member: String, secret_key: String) -> Result<(), String> {
+    set_add_on(DEFAULT_NODE_HANDLE, db_name, key, member, secret_key).await
+}
+
+/// `set_add` on a `start_node_instance` node
+#[frb]
+pub async fn set_add_on(handle: u32, db_name: String, key: String, member: String, secret_key: String) -> Result<(), String> {
+    put_typed(handle, db_name, key, TypedWrite::Set { member }, secret_key).await
+}
+
+/// Members of a set, sorted
+#[frb(sync)]
+pub fn set_members(db_name: String, key: String) -> Result<Vec<String>, String> {
+    set_members_on(DEFAULT_NODE_HANDLE, db_name, key)
+}
+
+/// `set_members` on a `start_node_instance` node
+#[frb(sync)]
+pub fn set_members_on(handle: u32, db_name: String, key: String) -> Result<Vec<String>, String> {
+    Ok(typed::values(typed_collection(handle, &db_name, &key)?))
+}
+
+/// A sorted set member and its score
+#[frb(dart_metadata=("freezed"))]
+pub struct ScoredMemberDto {
+    pub member: String,
+    pub score: f64,
+}
+
+/// Add a member to a sorted set, or change its score
+#[frb]
+pub async fn zset_add(
+    db_name: String,
+    key: String,
+    member: String,
+    score: f64,
+    secret_key: String,
+) -> Result<(), String> {
+    zset_add_on(DEFAULT_NODE_HANDLE, db_name, key, member, score, secret_key).await
+}
+
+/// `zset_add` on a `start_node_instance` node
+#[frb]
+pub async fn zset_add_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    member: String,
+    score: f64,
+    secret_key: String,
+) -> Result<(), String> {
+    put_typed(handle, db_name, key, TypedWrite::SortedSet(ScoredMember { member, score }), secret_key).await
+}
+
+/// Members of a sorted set with a score between `min` and `max` (inclusive,
+/// unbounded if `None`), lowest score first
+#[frb(sync)]
+pub fn zset_range(
+    db_name: String,
+    key: String,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> Result<Vec<ScoredMemberDto>, String> {
+    zset_range_on(DEFAULT_NODE_HANDLE, db_name, key, min, max)
+}
+
+/// `zset_range` on a `start_node_instance` node
+#[frb(sync)]
+pub fn zset_range_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> Result<Vec<ScoredMemberDto>, String> {
+    let members = typed::sorted_members(
+        typed_collection(handle, &db_name, &key)?,
+        min.unwrap_or(f64::NEG_INFINITY),
+        max.unwrap_or(f64::INFINITY),
+    );
+    Ok(members.into_iter().map(|m| ScoredMemberDto { member: m.member, score: m.score }).collect())
+}
+
+/// A time series sample
+#[frb(dart_metadata=("freezed"))]
+pub struct TimePointDto {
+    /// Unix ms
+    pub timestamp: i64,
+    pub value: f64,
+}
+
+/// Add a sample to a time series; a sample at the same timestamp replaces it
+#[frb]
+pub async fn ts_add(
+    db_name: String,
+    key: String,
+    timestamp_ms: i64,
+    value: f64,
+    secret_key: String,
+) -> Result<(), String> {
+    ts_add_on(DEFAULT_NODE_HANDLE, db_name, key, timestamp_ms, value, secret_key).await
+}
+
+/// `ts_add` on a `start_node_instance` node
+#[frb]
+pub async fn ts_add_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    timestamp_ms: i64,
+    value: f64,
+    secret_key: String,
+) -> Result<(), String> {
+    let point = TimePoint { timestamp: timestamp_ms, value };
+    put_typed(handle, db_name, key, TypedWrite::TimeSeries(point), secret_key).await
+}
+
+/// Samples of a time series between `from_ms` and `to_ms` (inclusive),
+/// oldest first
+#[frb(sync)]
+pub fn ts_range(db_name: String, key: String, from_ms: i64, to_ms: i64) -> Result<Vec<TimePointDto>, String> {
+    ts_range_on(DEFAULT_NODE_HANDLE, db_name, key, from_ms, to_ms)
+}
+
+/// `ts_range` on a `start_node_instance` node
+#[frb(sync)]
+pub fn ts_range_on(handle: u32, db_name: String, key: String, from_ms: i64, to_ms: i64) -> Result<Vec<TimePointDto>, String> {
+    let samples = typed::samples(typed_collection(handle, &db_name, &key)?, from_ms, to_ms);
+    Ok(samples.into_iter().map(|p| TimePointDto { timestamp: p.timestamp, value: p.value }).collect())
+}
+
+/// A geo set member and its distance from the queried point
+#[frb(dart_metadata=("freezed"))]
+pub struct GeoMatchDto {
+    pub member: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub distance_m: f64,
+}
+
+/// Add a member to a geo set, or move it
+#[frb]
+pub async fn geo_add(
+    db_name: String,
+    key: String,
+    member: String,
+    latitude: f64,
+    longitude: f64,
+    secret_key: String,
+) -> Result<(), String> {
+    geo_add_on(DEFAULT_NODE_HANDLE, db_name, key, member, latitude, longitude, secret_key).await
+}
+
+/// `geo_add` on a `start_node_instance` node
+#[frb]
+pub async fn geo_add_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    member: String,
+    latitude: f64,
+    longitude: f64,
+    secret_key: String,
+) -> Result<(), String> {
+    let geo = GeoMember { member, latitude, longitude };
+    put_typed(handle, db_name, key, TypedWrite::Geo(geo), secret_key).await
+}
+
+/// Members of a geo set within `radius_m` meters of a point, nearest first
+#[frb(sync)]
+pub fn geo_radius(
+    db_name: String,
+    key: String,
+    latitude: f64,
+    longitude: f64,
+    radius_m: f64,
+) -> Result<Vec<GeoMatchDto>, String> {
+    geo_radius_on(DEFAULT_NODE_HANDLE, db_name, key, latitude, longitude, radius_m)
+}
+
+/// `geo_radius` on a `start_node_instance` node
+#[frb(sync)]
+pub fn geo_radius_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    latitude: f64,
+    longitude: f64,
+    radius_m: f64,
+) -> Result<Vec<GeoMatchDto>, String> {
+    let members = typed::members_within(typed_collection(handle, &db_name, &key)?, latitude, longitude, radius_m);
+    Ok(members
+        .into_iter()
+        .map(|(geo, distance_m)| GeoMatchDto {
+            member: geo.member,
+            latitude: geo.latitude,
+            longitude: geo.longitude,
+            distance_m,
+        })
+        .collect())
+}
+
+/// Atomically set `key` to `new_value` if it currently equals `expected`
+/// (`None` = absent; a `new_value` of `None` deletes). Local only, not synced.
+/// Returns whether the swap happened.
+#[frb(sync)]
+pub fn compare_and_swap(
+    db_name: String,
+    key: String,
+    expected: Option<Vec<u8>>,
+    new_value: Option<Vec<u8>>,
+) -> Result<bool, String> {
+    compare_and_swap_on(DEFAULT_NODE_HANDLE, db_name, key, expected, new_value)
+}
+
+/// `compare_and_swap` on a `start_node_instance` node
+#[frb(sync)]
+pub fn compare_and_swap_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    expected: Option<Vec<u8>>,
+    new_value: Option<Vec<u8>>,
+) -> Result<bool, String> {
+    let node = get_node_handle(handle)?;
+    node.compare_and_swap(&db_name, &key, expected.as_deref(), new_value.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+/// Store data without signature on this device only: it never enters the
+/// oplog and is not sent to peers
+#[frb]
+pub async fn store_data_local(db_name: String, key: String, value: Vec<u8>) -> Result<(), String> {
+    store_data_local_on(DEFAULT_NODE_HANDLE, db_name, key, value).await
+}
+
+/// `store_data_local` on a `start_node_instance` node
+#[frb]
+pub async fn store_data_local_on(handle: u32, db_name: String, key: String, value: Vec<u8>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+
+    node.store_data_local(&db_name, &key, &value).map_err(|e| e.to_string())
+}
+
+/// Keep a database on this device only (`true`), or sync it again. Marking
+/// it removes its operations from the oplog, so nothing of it is sent to or
+/// accepted from peers; its data stays. Once synced again, only later
+/// writes are replicated.
+#[frb]
+pub async fn set_database_local_only(db_name: String, local_only: bool) -> Result<(), String> {
+    set_database_local_only_on(DEFAULT_NODE_HANDLE, db_name, local_only).await
+}
+
+/// `set_database_local_only` on a `start_node_instance` node
+#[frb]
+pub async fn set_database_local_only_on(handle: u32, db_name: String, local_only: bool) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.set_local_only(&db_name, local_only).await.map_err(|e| e.to_string())
+}
+
+/// Whether a database is kept on this device only
+#[frb(sync)]
+pub fn is_database_local_only(db_name: String) -> Result<bool, String> {
+    is_database_local_only_on(DEFAULT_NODE_HANDLE, db_name)
+}
+
+/// `is_database_local_only` of a `start_node_instance` node
+#[frb(sync)]
+pub fn is_database_local_only_on(handle: u32, db_name: String) -> Result<bool, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.is_local_only(&db_name))
+}
+
+/// Get data from local database; large values not on this device yet are
+/// fetched from peers
+#[frb]
+pub async fn get_data(db_name: String, key: String) -> Result<Option<Vec<u8>>, String> {
+    get_data_on(DEFAULT_NODE_HANDLE, db_name, key).await
+}
+
+/// `get_data` on a `start_node_instance` node
+#[frb]
+pub async fn get_data_on(handle: u32, db_name: String, key: String) -> Result<Option<Vec<u8>>, String> {
+    get_node_handle(handle)?.get_data(db_name, key).await.map_err(|e| e.to_string())
+}
+
+/// Get several values of one database at once; results are in the order of
+/// `keys`, `None` for missing keys
+#[frb]
+pub async fn get_many(db_name: String, keys: Vec<String>) -> Result<Vec<Option<Vec<u8>>>, String> {
+    get_many_on(DEFAULT_NODE_HANDLE, db_name, keys).await
+}
+
+/// `get_many` of a `start_node_instance` node
+#[frb]
+pub async fn get_many_on(handle: u32, db_name: String, keys: Vec<String>) -> Result<Vec<Option<Vec<u8>>>, String> {
+    let node = get_node_handle(handle)?;
+    node.get_many(&db_name, &keys).map_err(|e| e.to_string())
+}
+
+/// Offline full-text search of a database: entries whose (string or JSON)
+/// value contains every word of `query`, the last word matching as a prefix.
+/// Encrypted values are not searchable. At most `limit` (up to 100) hits, in
+/// key order.
+#[frb]
+pub async fn search(db_name: String, query: String, limit: u32) -> Result<Vec<DbEntryDto>, String> {
+    search_on(DEFAULT_NODE_HANDLE, db_name, query, limit).await
+}
+
+/// `search` on a `start_node_instance` node
+#[frb]
+pub async fn search_on(handle: u32, db_name: String, query: String, limit: u32) -> Result<Vec<DbEntryDto>, String> {
+    let node = get_node_handle(handle)?;
+    let hits = node
+        .search(&db_name, &query, limit as usize)
+        .map_err(|e| e.to_string())?;
+
+    Ok(hits
+        .into_iter()
+        .map(|(key, value_bytes)| DbEntryDto {
+            db_name: db_name.clone(),
+            key,
+            value: String::from_utf8_lossy(&value_bytes).to_string(),
+            value_bytes,
+        })
+        .collect())
+}
+
+/// Field predicate of a query
+#[frb(dart_metadata=("freezed"))]
+pub struct QueryFilterDto {
+    /// Field path, dots for nested objects (`address.city`)
+    pub field: String,
+    /// "eq", "ne", "lt", "lte", "gt" or "gte"
+    pub op: String,
+    /// Value to compare with, as JSON (`"\"Ada\""`, `"30"`)
+    pub value_json: String,
+}
+
+/// Query over the JSON values of a database
+#[frb(dart_metadata=("freezed"))]
+pub struct QuerySpecDto {
+    pub key_prefix: Option<String>,
+    /// All must match
+    pub filters: Vec<QueryFilterDto>,
+    /// Field to sort by (entries without it come last); key order if `None`
+    pub sort_by: Option<String>,
+    pub descending: bool,
+    /// At most 1000
+    pub limit: u32,
+    pub offset: u32,
+    /// Return only these fields, as a JSON object keyed by field path
+    pub fields: Option<Vec<String>>,
+}
+
+/// One window of query results
+#[frb(dart_metadata=("freezed"))]
+pub struct QueryResultDto {
+    pub entries: Vec<DbEntryDto>,
+    /// Whether more results follow (query again with a larger offset)
+    pub has_more: bool,
+}
+
+/// Query a database: key-prefix and JSON field filters, sorting, offset/limit
+/// and projection, all evaluated in Rust. Values that aren't JSON objects only
+/// match queries without filters, sorting or projection; encrypted databases
+/// aren't queryable.
+#[frb]
+pub async fn query(db_name: String, spec: QuerySpecDto) -> Result<QueryResultDto, String> {
+    query_on(DEFAULT_NODE_HANDLE, db_name, spec).await
+}
+
+/// `query` on a `start_node_instance` node
+#[frb]
+pub async fn query_on(handle: u32, db_name: String, spec: QuerySpecDto) -> Result<QueryResultDto, String> {
+    let node = get_node_handle(handle)?;
+
+    let filters = spec
+        .filters
+        .into_iter()
+        .map(|filter| {
+            Ok(crate::query::FieldFilter {
+                op: crate::query::FilterOp::parse(&filter.op)?,
+                value: serde_json::from_str(&filter.value_json)?,
+                field: filter.field,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map_err(|e| e.to_string())?;
+    let spec = crate::query::QuerySpec {
+        key_prefix: spec.key_prefix,
+        filters,
+        sort_by: spec.sort_by,
+        descending: spec.descending,
+        limit: spec.limit as usize,
+        offset: spec.offset as usize,
+        fields: spec.fields,
+    };
+
+    let result = node.query(&db_name, &spec).map_err(|e| e.to_string())?;
+    Ok(QueryResultDto {
+        entries: result
+            .entries
+            .into_iter()
+            .map(|(key, value_bytes)| DbEntryDto {
+                db_name: db_name.clone(),
+                key,
+                value: String::from_utf8_lossy(&value_bytes).to_string(),
+                value_bytes,
+            })
+            .collect(),
+        has_more: result.has_more,
+    })
+}
+
+/// Request sync from peers
+#[frb]
+pub async fn request_sync(since_timestamp: Option<i64>) -> Result<(), String> {
+    request_sync_on(DEFAULT_NODE_HANDLE, since_timestamp).await
+}
+
+/// `request_sync` on a `start_node_instance` node
+#[frb]
+pub async fn request_sync_on(handle: u32, since_timestamp: Option<i64>) -> Result<(), String> {
+    get_node_handle(handle)?.request_sync(since_timestamp).await.map_err(|e| e.to_string())
+}
+
+/// Get sync statistics (ops/bytes transferred, last sync time, per-peer status)
+#[frb]
+pub async fn get_sync_stats() -> Result<SyncStatsDto, String> {
+    get_sync_stats_on(DEFAULT_NODE_HANDLE).await
+}
+
+/// `get_sync_stats` of a `start_node_instance` node
+#[frb]
+pub async fn get_sync_stats_on(handle: u32) -> Result<SyncStatsDto, String> {
+    let node = get_node_handle(handle)?;
+    let stats = node.get_sync_stats().await;
+
+    Ok(SyncStatsDto {
+        total_operations: stats.total_operations as u64,
+        ops_sent: stats.ops_sent,
+        ops_received: stats.ops_received,
+        bytes_sent: stats.bytes_sent,
+        bytes_received: stats.bytes_received,
+        last_sync_at: stats.last_sync_at,
+        peers: stats
+            .peers
+            .into_iter()
+            .map(|p| PeerSyncStatusDto {
+                peer_id: p.peer_id,
+                ops_sent: p.ops_sent,
+                ops_received: p.ops_received,
+                bytes_sent: p.bytes_sent,
+                bytes_received: p.bytes_received,
+                last_request_at: p.last_request_at,
+                last_response_at: p.last_response_at,
+                in_progress: p.in_progress,
+                ops_acked: p.ops_acked,
+                last_ack_at: p.last_ack_at,
+            })
+            .collect(),
+    })
+}
+
+/// Parse a hex-encoded Ed25519 secret key
+fn signing_key_from_hex(secret_key_hex: &str) -> Result<ed25519_dalek::SigningKey, String> {
+    let secret_bytes = hex::decode(secret_key_hex)
+        .map_err(|e| format!("Invalid secret key hex: {}", e))?;
+
+    let secret_array: [u8; 32] = secret_bytes
+        .try_into()
+        .map_err(|_| "Invalid secret key length (expected 32 bytes)")?;
+
+    Ok(ed25519_dalek::SigningKey::from_bytes(&secret_array))
+}
+
+/// Authorize another public key to write to a database (owner only)
+#[frb]
+pub async fn grant_writer(
+    db_name: String,
+    writer_public_key: String,
+    owner_secret_key: String,
+) -> Result<(), String> {
+    grant_writer_on(DEFAULT_NODE_HANDLE, db_name, writer_public_key, owner_secret_key).await
+}
+
+/// `grant_writer` on a `start_node_instance` node
+#[frb]
+pub async fn grant_writer_on(
+    handle: u32,
+    db_name: String,
+    writer_public_key: String,
+    owner_secret_key: String,
+) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let owner_key = signing_key_from_hex(&owner_secret_key)?;
+
+    node.update_writers(&db_name, vec![writer_public_key], vec![], &owner_key)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Revoke a writer's access to a database (owner only)
+#[frb]
+pub async fn revoke_writer(
+    db_name: String,
+    writer_public_key: String,
+    owner_secret_key: String,
+) -> Result<(), String> {
+    revoke_writer_on(DEFAULT_NODE_HANDLE, db_name, writer_public_key, owner_secret_key).await
+}
+
+/// `revoke_writer` on a `start_node_instance` node
+#[frb]
+pub async fn revoke_writer_on(
+    handle: u32,
+    db_name: String,
+    writer_public_key: String,
+    owner_secret_key: String,
+) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let owner_key = signing_key_from_hex(&owner_secret_key)?;
+
+    node.update_writers(&db_name, vec![], vec![writer_public_key], &owner_key)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a whole database (owner only). The drop is signed and replicated,
+/// so every peer holding the database removes it too.
+#[frb]
+pub async fn drop_database(db_name: String, owner_secret_key: String) -> Result<(), String> {
+    drop_database_on(DEFAULT_NODE_HANDLE, db_name, owner_secret_key).await
+}
+
+/// `drop_database` on a `start_node_instance` node
+#[frb]
+pub async fn drop_database_on(handle: u32, db_name: String, owner_secret_key: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let owner_key = signing_key_from_hex(&owner_secret_key)?;
+
+    node.drop_database(&db_name, &owner_key).await.map_err(|e| e.to_string())
+}
+
+/// List public keys authorized to write to a database (besides its owner)
+#[frb(sync)]
+pub fn get_writers(db_name: String) -> Result<Vec<String>, String> {
+    get_writers_on(DEFAULT_NODE_HANDLE, db_name)
+}
+
+/// `get_writers` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_writers_on(handle: u32, db_name: String) -> Result<Vec<String>, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.get_writers(&db_name))
+}
+
+/// Create an end-to-end encrypted database: generates its symmetric key,
+/// which never leaves this device unless exported
+#[frb(sync)]
+pub fn create_encrypted_db(db_name: String) -> Result<(), String> {
+    create_encrypted_db_on(DEFAULT_NODE_HANDLE, db_name)
+}
+
+/// `create_encrypted_db` on a `start_node_instance` node
+#[frb(sync)]
+pub fn create_encrypted_db_on(handle: u32, db_name: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.create_encrypted_db(&db_name).map_err(|e| e.to_string())
+}
+
+/// Encrypt a value client-side, sign the ciphertext with `secret_key` and sync it
+#[frb]
+pub async fn store_encrypted(
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    secret_key: String,
+) -> Result<(), String> {
+    store_encrypted_on(DEFAULT_NODE_HANDLE, db_name, key, value, secret_key).await
+}
+
+/// `store_encrypted` on a `start_node_instance` node
+#[frb]
+pub async fn store_encrypted_on(
+    handle: u32,
+    db_name: String,
+    key: String,
+    value: Vec<u8>,
+    secret_key: String,
+) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let signing_key = signing_key_from_hex(&secret_key)?;
+
+    node.store_encrypted(&db_name, &key, &value, &signing_key)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Read and decrypt a value from an encrypted database
+#[frb(sync)]
+pub fn get_decrypted(db_name: String, key: String) -> Result<Option<Vec<u8>>, String> {
+    get_decrypted_on(DEFAULT_NODE_HANDLE, db_name, key)
+}
+
+/// `get_decrypted` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_decrypted_on(handle: u32, db_name: String, key: String) -> Result<Option<Vec<u8>>, String> {
+    let node = get_node_handle(handle)?;
+    node.get_decrypted(&db_name, &key).map_err(|e| e.to_string())
+}
+
+/// Export an encrypted database's key (hex) to explicitly share it
+#[frb(sync)]
+pub fn export_db_key(db_name: String) -> Result<String, String> {
+    export_db_key_on(DEFAULT_NODE_HANDLE, db_name)
+}
+
+/// `export_db_key` on a `start_node_instance` node
+#[frb(sync)]
+pub fn export_db_key_on(handle: u32, db_name: String) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    node.export_db_key(&db_name).map_err(|e| e.to_string())
+}
+
+/// Import an encrypted database's key shared from another device
+#[frb(sync)]
+pub fn import_db_key(db_name: String, key_hex: String) -> Result<(), String> {
+    import_db_key_on(DEFAULT_NODE_HANDLE, db_name, key_hex)
+}
+
+/// `import_db_key` on a `start_node_instance` node
+#[frb(sync)]
+pub fn import_db_key_on(handle: u32, db_name: String, key_hex: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.import_db_key(&db_name, &key_hex).map_err(|e| e.to_string())
+}
+
+/// Create a signed invite token for a database (owner only). Write invites
+/// authorize a fresh writer key; the token then contains its secret (and the
+/// database key if `include_encryption_key`), so share it privately.
+#[frb]
+pub async fn create_db_invite(
+    db_name: String,
+    owner_secret_key: String,
+    can_write: bool,
+    include_encryption_key: bool,
+    expires_in_secs: u64,
+) -> Result<String, String> {
+    create_db_invite_on(DEFAULT_NODE_HANDLE, db_name, owner_secret_key, can_write, include_encryption_key, expires_in_secs).await
+}
+
+/// `create_db_invite` on a `start_node_instance` node
+#[frb]
+pub async fn create_db_invite_on(
+    handle: u32,
+    db_name: String,
+    owner_secret_key: String,
+    can_write: bool,
+    include_encryption_key: bool,
+    expires_in_secs: u64,
+) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    let owner_key = signing_key_from_hex(&owner_secret_key)?;
+
+    node.create_db_invite(&db_name, &owner_key, can_write, include_encryption_key, expires_in_secs)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Redeem an invite token: registers the database in the sync filter and
+/// applies its writer list and encryption key
+#[frb(sync)]
+pub fn accept_db_invite(token: String) -> Result<AcceptedInviteDto, String> {
+    accept_db_invite_on(DEFAULT_NODE_HANDLE, token)
+}
+
+/// `accept_db_invite` on a `start_node_instance` node
+#[frb(sync)]
+pub fn accept_db_invite_on(handle: u32, token: String) -> Result<AcceptedInviteDto, String> {
+    let node = get_node_handle(handle)?;
+    let invite = node.accept_db_invite(&token).map_err(|e| e.to_string())?;
+
+    Ok(AcceptedInviteDto {
+        can_write: invite.can_write(),
+        encrypted: invite.encryption_key.is_some(),
+        db_name: invite.db_name,
+        owner: invite.owner,
+        writer_secret_key: invite.writer_secret_key,
+    })
+}
+
+/// Only sync followed databases (`true`) or every database (`false`, default)
+#[frb(sync)]
+pub fn set_selective_sync(selective: bool) -> Result<(), String> {
+    set_selective_sync_on(DEFAULT_NODE_HANDLE, selective)
+}
+
+/// `set_selective_sync` on a `start_node_instance` node
+#[frb(sync)]
+pub fn set_selective_sync_on(handle: u32, selective: bool) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.set_selective_sync(selective).map_err(|e| e.to_string())
+}
+
+/// Follow a database so it is synced in selective sync mode
+#[frb(sync)]
+pub fn follow_database(db_name: String) -> Result<(), String> {
+    follow_database_on(DEFAULT_NODE_HANDLE, db_name)
+}
+
+/// `follow_database` on a `start_node_instance` node
+#[frb(sync)]
+pub fn follow_database_on(handle: u32, db_name: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.follow_database(&db_name).map_err(|e| e.to_string())
+}
+
+/// Stop following a database
+#[frb(sync)]
+pub fn unfollow_database(db_name: String) -> Result<(), String> {
+    unfollow_database_on(DEFAULT_NODE_HANDLE, db_name)
+}
+
+/// `unfollow_database` on a `start_node_instance` node
+#[frb(sync)]
+pub fn unfollow_database_on(handle: u32, db_name: String) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.unfollow_database(&db_name).map_err(|e| e.to_string())
+}
+
+/// List followed databases
+#[frb(sync)]
+pub fn get_followed_databases() -> Result<Vec<String>, String> {
+    get_followed_databases_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `get_followed_databases` of a `start_node_instance` node
+#[frb(sync)]
+pub fn get_followed_databases_on(handle: u32) -> Result<Vec<String>, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.get_followed_databases())
+}
+
+/// Sign a message using Ed25519
+#[frb(sync)]
+pub fn sign_message_with_key(secret_key_hex: String, message: String) -> Result<String, String> {
+    let secret_bytes = hex::decode(&secret_key_hex)
+        .map_err(|e| format!("Invalid secret key hex: {}", e))?;
+    
+    let secret_array: [u8; 32] = secret_bytes
+        .try_into()
+        .map_err(|_| "Invalid secret key length (expected 32 bytes)")?;
+    
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&secret_array);
+    let signature = crypto::sign_message(&signing_key, message.as_bytes());
+    
+    Ok(signature)
+}
+
+/// Verify an Ed25519 signature
+#[frb(sync)]
+pub fn verify_message_signature(
+    public_key_hex: String,
+    message: String,
+    signature_hex: String,
+) -> Result<bool, String> {
+    crypto::verify_signature(&public_key_hex, message.as_bytes(), &signature_hex)
+        .map_err(|e| e.to_string())
+}
+
+/// Sign raw bytes (e.g. a file hash or CBOR payload) using Ed25519
+#[frb(sync)]
+pub fn sign_bytes(secret_key_hex: String, message: Vec<u8>) -> Result<String, String> {
+    let signing_key = signing_key_from_hex(&secret_key_hex)?;
+
+    Ok(crypto::sign_message(&signing_key, &message))
+}
+
+/// Verify an Ed25519 signature over raw bytes
+#[frb(sync)]
+pub fn verify_bytes(public_key_hex: String, message: Vec<u8>, signature_hex: String) -> Result<bool, String> {
+    crypto::verify_signature(&public_key_hex, &message, &signature_hex)
+        .map_err(|e| e.to_string())
+}
+
+/// BLAKE3 hash of `data` (hex), for content addressing
+#[frb(sync)]
+pub fn blake3_hash(data: Vec<u8>) -> String {
+    crypto::blake3_hex(&data)
+}
+
+/// SHA-256 hash of `data` (hex)
+#[frb(sync)]
+pub fn sha256_hash(data: Vec<u8>) -> String {
+    crypto::sha256_hex(&data)
+}
+
+/// Generate a new Ed25519 keypair
+#[frb(sync)]
+pub fn generate_keypair() -> KeyPairDto {
+    let (signing_key, public_key) = crypto::generate_keypair();
+    KeyPairDto {
+        public_key,
+        secret_key: hex::encode(signing_key.to_bytes()),
+    }
+}
+
+/// Generate database name from name and public key
+/// Format: <name>-<public_key_hex> (matching cyberfly-rust-node)
+#[frb(sync)]
+pub fn generate_db_name(name: String, public_key_hex: String) -> String {
+    crypto::generate_db_name(&name, &public_key_hex)
+}
+
+/// Verify that the database name matches the public key
+#[frb(sync)]
+pub fn verify_db_name(db_name: String, public_key_hex: String) -> Result<bool, String> {
+    crypto::verify_db_name_secure(&db_name, &public_key_hex)
+        .map(|_| true)
+        .map_err(|e| e.to_string())
+}
+
+/// Extract name part from database name (removes public key suffix)
+#[frb(sync)]
+pub fn extract_name_from_db(db_name: String) -> Option<String> {
+    crypto::extract_name_from_db(&db_name)
+}
+
+/// Validate timestamp (check if within acceptable range)
+#[frb(sync)]
+pub fn validate_timestamp(timestamp: i64) -> Result<bool, String> {
+    crypto::validate_timestamp(timestamp, Some(crypto::MAX_TIMESTAMP_TOLERANCE))
+        .map(|_| true)
+        .map_err(|e| e.to_string())
+}
+
+/// Greet function for testing
+#[frb(sync)]
+pub fn greet(name: String) -> String {
+    format!("Hello, {}! From Cyberfly Rust.", name)
+}
+
+/// Generate libp2p PeerId from secret key (for Kadena blockchain registration)
+/// This matches the desktop cyberfly-rust-node implementation for backward compatibility
+#[frb(sync)]
+pub fn generate_peer_id_from_secret_key(secret_key_hex: String) -> Result<String, String> {
+    // Decode the hex-encoded secret key
+    let secret_bytes = hex::decode(&secret_key_hex)
+        .map_err(|e| format!("Failed to decode secret key: {}", e))?;
+    
+    if secret_bytes.len() != 32 {
+        return Err(format!("Invalid secret key length: expected 32 bytes, got {}", secret_bytes.len()));
+    }
+
+    // Create ed25519 secret key and derive keypair
+    let secret = libp2p_identity::ed25519::SecretKey::try_from_bytes(secret_bytes)
+        .map_err(|e| format!("Failed to create secret key: {}", e))?;
+    
+    let keypair = libp2p_identity::ed25519::Keypair::from(secret);
+    
+    // Generate PeerId from the keypair's public key
+    let peer_id = libp2p_identity::PeerId::from_public_key(&keypair.public().into());
+    
+    Ok(peer_id.to_string())
+}
+
+/// Kadena command signed for the Pact API
+#[frb(dart_metadata=("freezed"))]
+pub struct KadenaTransactionDto {
+    /// Unpadded base64url blake2b-256 of `cmd`
+    pub hash: String,
+    /// Hex ed25519 signature of the hash
+    pub sig: String,
+    pub cmd: String,
+    /// `{"hash", "sigs", "cmd"}` as posted in `cmds` of `/send`
+    pub json: String,
+}
+
+/// Kadena `k:` account of an ed25519 public key (hex)
+#[frb(sync)]
+pub fn kadena_account_from_public_key(public_key_hex: String) -> Result<String, String> {
+    kadena::account_from_public_key(&public_key_hex).map_err(|e| e.to_string())
+}
+
+/// Hash a Kadena command (its JSON) and sign the hash with a secret key (hex)
+#[frb(sync)]
+pub fn kadena_sign_command(cmd: String, secret_key_hex: String) -> Result<KadenaTransactionDto, String> {
+    let secret_bytes: [u8; 32] = crypto::secure_hex_decode(&secret_key_hex)
+        .map_err(|e| e.to_string())?
+        .try_into()
+        .map_err(|bytes: Vec<u8>| format!("Invalid secret key length: expected 32 bytes, got {}", bytes.len()))?;
+    let signing_key = crypto::secret_to_signing_key(&secret_bytes);
+    let tx = kadena::sign_command(&signing_key, &cmd).map_err(|e| e.to_string())?;
+    let json = serde_json::to_string(&tx).map_err(|e| e.to_string())?;
+    Ok(KadenaTransactionDto {
+        hash: tx.hash,
+        sig: tx.sigs.into_iter().next().map(|sig| sig.sig).unwrap_or_default(),
+        cmd: tx.cmd,
+        json,
+    })
+}
+
+/// Build the command registering the node in the Cyberfly node contract, to
+/// sign with `kadena_sign_command`. The `k:` account of `public_key_hex`
+/// owns the entry and pays gas.
+#[frb(sync)]
+pub fn kadena_node_registration_command(
+    network_id: String,
+    chain_id: String,
+    peer_id: String,
+    multiaddr: String,
+    public_key_hex: String,
+) -> Result<String, String> {
+    let registration = NodeRegistration {
+        network_id,
+        chain_id,
+        peer_id,
+        multiaddr,
+        public_key: public_key_hex,
+        creation_time: chrono::Utc::now().timestamp(),
+        nonce: uuid::Uuid::new_v4().to_string(),
+    };
+    kadena::registration_command(&registration).map_err(|e| e.to_string())
+}
+
+/// Database entry for Flutter
+#[frb(dart_metadata=("freezed"))]
+pub struct DbEntryDto {
+    pub db_name: String,
+    pub key: String,
+    pub value: String,
+    pub value_bytes: Vec<u8>,
+}
+
+/// List all databases in storage
+#[frb(sync)]
+pub fn list_databases() -> Result<Vec<String>, String> {
+    list_databases_on(DEFAULT_NODE_HANDLE)
+}
+
+/// List all keys in a specific database
+#[frb(sync)]
+pub fn list_keys(db_name: String) -> Result<Vec<String>, String> {
+    list_keys_on(DEFAULT_NODE_HANDLE, db_name)
+}
+
+/// `list_databases` of a `start_node_instance` node
+#[frb(sync)]
+pub fn list_databases_on(handle: u32) -> Result<Vec<String>, String> {
+    get_node_handle(handle)?.list_databases().map_err(|e| e.to_string())
+}
+
+/// `list_keys` of a `start_node_instance` node
+#[frb(sync)]
+pub fn list_keys_on(handle: u32, db_name: String) -> Result<Vec<String>, String> {
+    get_node_handle(handle)?.list_keys(&db_name).map_err(|e| e.to_string())
+}
+
+/// Get all entries from a specific database. Loads the whole database into
+/// memory; prefer `get_entries_page` for large databases.
+#[frb]
+pub async fn get_all_entries(db_name: String) -> Result<Vec<DbEntryDto>, String> {
+    get_all_entries_on(DEFAULT_NODE_HANDLE, db_name).await
+}
+
+/// `get_all_entries` of a `start_node_instance` node
+#[frb]
+pub async fn get_all_entries_on(handle: u32, db_name: String) -> Result<Vec<DbEntryDto>, String> {
+    let node = get_node_handle(handle)?;
+    node.get_all_entries(&db_name).await.map_err(|e| e.to_string())
+}
+
+/// Get all entries from all databases. Loads everything into memory; prefer
+/// `get_all_data_page`.
+#[frb]
+pub async fn get_all_data() -> Result<Vec<DbEntryDto>, String> {
+    get_all_data_on(DEFAULT_NODE_HANDLE).await
+}
+
+/// `get_all_data` of a `start_node_instance` node
+#[frb]
+pub async fn get_all_data_on(handle: u32) -> Result<Vec<DbEntryDto>, String> {
+    let node = get_node_handle(handle)?;
+    node.get_all_data().await.map_err(|e| e.to_string())
+}
+
+/// Entries whose key starts with `prefix`, in key order (e.g. `msg:` for
+/// `msg:0001`, `msg:0002`, ...). Pass the returned `next_cursor` to get the
+/// next page; `limit` is capped at 1000.
+#[frb(sync)]
+pub fn scan_prefix(
+    db_name: String,
+    prefix: String,
+    limit: u32,
+    cursor: Option<String>,
+) -> Result<ScanPageDto, String> {
+    scan_prefix_on(DEFAULT_NODE_HANDLE, db_name, prefix, limit, cursor)
+}
+
+/// `scan_prefix` on a `start_node_instance` node
+#[frb(sync)]
+pub fn scan_prefix_on(
+    handle: u32,
+    db_name: String,
+    prefix: String,
+    limit: u32,
+    cursor: Option<String>,
+) -> Result<ScanPageDto, String> {
+    let node = get_node_handle(handle)?;
+    let page = node
+        .scan_prefix(&db_name, &prefix, limit as usize, cursor.as_deref())
+        .map_err(|e| e.to_string())?;
+    Ok(ScanPageDto::from_page(&db_name, page))
+}
+
+/// Entries with `start <= key < end` (either bound optional), in key order.
+/// Pass the returned `next_cursor` to get the next page.
+#[frb(sync)]
+pub fn scan_range(
+    db_name: String,
+    start: Option<String>,
+    end: Option<String>,
+    limit: u32,
+    cursor: Option<String>,
+) -> Result<ScanPageDto, String> {
+    scan_range_on(DEFAULT_NODE_HANDLE, db_name, start, end, limit, cursor)
+}
+
+/// `scan_range` on a `start_node_instance` node
+#[frb(sync)]
+pub fn scan_range_on(
+    handle: u32,
+    db_name: String,
+    start: Option<String>,
+    end: Option<String>,
+    limit: u32,
+    cursor: Option<String>,
+) -> Result<ScanPageDto, String> {
+    let node = get_node_handle(handle)?;
+    let page = node
+        .scan_range(&db_name, start.as_deref(), end.as_deref(), limit as usize, cursor.as_deref())
+        .map_err(|e| e.to_string())?;
+    Ok(ScanPageDto::from_page(&db_name, page))
+}
+
+/// Entry for `store_batch`, signed like `store_data`
+#[frb(dart_metadata=("freezed"))]
+pub struct BatchEntryDto {
+    pub db_name: String,
+    pub key: String,
+    pub value: Vec<u8>,
+    pub public_key: String,
+    pub signature: String,
+}
+
+/// One page of a key-ordered scan
+#[frb(dart_metadata=("freezed"))]
+pub struct ScanPageDto {
+    pub entries: Vec<DbEntryDto>,
+    /// Cursor for the next page, `None` when there are no more entries
+    pub next_cursor: Option<String>,
+}
+
+impl ScanPageDto {
+    fn from_page(db_name: &str, page: ScanPage) -> Self {
+        let entries = page
+            .entries
+            .into_iter()
+            .map(|(key, value_bytes)| DbEntryDto {
+                db_name: db_name.to_string(),
+                key,
+                value: String::from_utf8_lossy(&value_bytes).to_string(),
+                value_bytes,
+            })
+            .collect();
+        Self { entries, next_cursor: page.next_cursor }
+    }
+
+    fn from_data_page(page: DataPage) -> Self {
+        let entries = page
+            .entries
+            .into_iter()
+            .map(|(db_name, key, value_bytes)| DbEntryDto {
+                db_name,
+                key,
+                value: String::from_utf8_lossy(&value_bytes).to_string(),
+                value_bytes,
+            })
+            .collect();
+        Self { entries, next_cursor: page.next_cursor }
+    }
+}
 
-/// Store data in local database with signature for sync
+/// Page through the entries of a database in key order. `keys_only` returns
+/// empty values; `max_bytes` caps the keys + values returned per call (at
+/// least one entry is always returned). Pass `next_cursor` to continue.
 #[frb]
-pub async fn store_data(
+pub async fn get_entries_page(
     db_name: String,
-    key: String,
-    value: Vec<u8>,
-    public_key: String,
-    signature: String,
-) -> Result<(), String> {
-    let node = get_node()?;
-    
-    node.store_data(db_name, key, value, public_key, signature)
-        .await
-        .map_err(|e| e.to_string())
+    limit: u32,
+    cursor: Option<String>,
+    keys_only: bool,
+    max_bytes: Option<u64>,
+) -> Result<ScanPageDto, String> {
+    get_entries_page_on(DEFAULT_NODE_HANDLE, db_name, limit, cursor, keys_only, max_bytes).await
 }
 
-/// Store data without signature (local only, not synced)
+/// `get_entries_page` of a `start_node_instance` node
 #[frb]
-pub async fn store_data_local(db_name: String, key: String, value: Vec<u8>) -> Result<(), String> {
-    let node = get_node()?;
-    
-    // Use empty signature for local-only storage
-    node.store_data(db_name, key, value, String::new(), String::new())
-        .await
-        .map_err(|e| e.to_string())
+pub async fn get_entries_page_on(
+    handle: u32,
+    db_name: String,
+    limit: u32,
+    cursor: Option<String>,
+    keys_only: bool,
+    max_bytes: Option<u64>,
+) -> Result<ScanPageDto, String> {
+    let node = get_node_handle(handle)?;
+    let options = scan_options(limit, keys_only, max_bytes);
+    let page = node
+        .get_entries_page(&db_name, cursor.as_deref(), options)
+        .map_err(|e| e.to_string())?;
+    Ok(ScanPageDto::from_page(&db_name, page))
 }
 
-/// Get data from local database
+/// Page through the entries of all databases, same options as `get_entries_page`
 #[frb]
-pub async fn get_data(db_name: String, key: String) -> Result<Option<Vec<u8>>, String> {
-    let node = get_node()?;
-    
-    node.get_data(db_name, key).await.map_err(|e| e.to_string())
+pub async fn get_all_data_page(
+    limit: u32,
+    cursor: Option<String>,
+    keys_only: bool,
+    max_bytes: Option<u64>,
+) -> Result<ScanPageDto, String> {
+    get_all_data_page_on(DEFAULT_NODE_HANDLE, limit, cursor, keys_only, max_bytes).await
 }
 
-/// Request sync from peers
+/// `get_all_data_page` of a `start_node_instance` node
 #[frb]
-pub async fn request_sync(since_timestamp: Option<i64>) -> Result<(), String> {
-    let node = get_node()?;
-    
-    node.request_sync(since_timestamp).await.map_err(|e| e.to_string())
+pub async fn get_all_data_page_on(
+    handle: u32,
+    limit: u32,
+    cursor: Option<String>,
+    keys_only: bool,
+    max_bytes: Option<u64>,
+) -> Result<ScanPageDto, String> {
+    let node = get_node_handle(handle)?;
+    let options = scan_options(limit, keys_only, max_bytes);
+    let page = node
+        .get_all_data_page(cursor.as_deref(), options)
+        .map_err(|e| e.to_string())?;
+    Ok(ScanPageDto::from_data_page(page))
 }
 
-/// Sign a message using Ed25519
-#[frb(sync)]
-pub fn sign_message_with_key(secret_key_hex: String, message: String) -> Result<String, String> {
-    let secret_bytes = hex::decode(&secret_key_hex)
-        .map_err(|e| format!("Invalid secret key hex: {}", e))?;
-    
-    let secret_array: [u8; 32] = secret_bytes
-        .try_into()
-        .map_err(|_| "Invalid secret key length (expected 32 bytes)")?;
-    
-    let signing_key = ed25519_dalek::SigningKey::from_bytes(&secret_array);
-    let signature = crypto::sign_message(&signing_key, message.as_bytes());
-    
-    Ok(signature)
+fn scan_options(limit: u32, keys_only: bool, max_bytes: Option<u64>) -> ScanOptions {
+    ScanOptions {
+        limit: limit as usize,
+        max_bytes: max_bytes.map(|b| b as usize),
+        keys_only,
+    }
 }
 
-/// Verify an Ed25519 signature
-#[frb(sync)]
-pub fn verify_message_signature(
-    public_key_hex: String,
-    message: String,
-    signature_hex: String,
-) -> Result<bool, String> {
-    crypto::verify_signature(&public_key_hex, message.as_bytes(), &signature_hex)
-        .map_err(|e| e.to_string())
+/// Delete a key from a database
+#[frb]
+pub async fn delete_data(db_name: String, key: String) -> Result<(), String> {
+    delete_data_on(DEFAULT_NODE_HANDLE, db_name, key).await
 }
 
-/// Generate a new Ed25519 keypair
-#[frb(sync)]
-pub fn generate_keypair() -> KeyPairDto {
-    let (signing_key, public_key) = crypto::generate_keypair();
-    KeyPairDto {
-        public_key,
-        secret_key: hex::encode(signing_key.to_bytes()),
-    }
+/// `delete_data` on a `start_node_instance` node
+#[frb]
+pub async fn delete_data_on(handle: u32, db_name: String, key: String) -> Result<(), String> {
+    get_node_handle(handle)?.delete_data(&db_name, &key).await.map_err(|e| e.to_string())
 }
 
-/// Generate database name from name and public key
-/// Format: <name>-<public_key_hex> (matching cyberfly-rust-node)
+/// Watch a single key: emits its current value, then every change from local
+/// writes or remote sync. The stream ends when Dart cancels it.
 #[frb(sync)]
-pub fn generate_db_name(name: String, public_key_hex: String) -> String {
-    crypto::generate_db_name(&name, &public_key_hex)
+pub fn watch_key(db_name: String, key: String, sink: StreamSink<ValueUpdateDto>) -> Result<(), String> {
+    watch_key_on(DEFAULT_NODE_HANDLE, db_name, key, sink)
 }
 
-/// Verify that the database name matches the public key
+/// `watch_key` on a `start_node_instance` node
 #[frb(sync)]
-pub fn verify_db_name(db_name: String, public_key_hex: String) -> Result<bool, String> {
-    crypto::verify_db_name_secure(&db_name, &public_key_hex)
-        .map(|_| true)
-        .map_err(|e| e.to_string())
+pub fn watch_key_on(handle: u32, db_name: String, key: String, sink: StreamSink<ValueUpdateDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let mut changes = node.watch_changes();
+
+    spawn_until_stopped(node.shutdown_token(), async move {
+        // Subscribed before reading, so no change between the two is lost
+        let current = node.get_data(db_name.clone(), key.clone()).await.ok().flatten();
+        let initial = StorageChange { seq: 0, db_name, key, value: current };
+        if sink.add(ValueUpdateDto::from(initial.clone())).is_err() {
+            return;
+        }
+
+        while let Some(change) = next_change(&mut changes).await {
+            if change.db_name == initial.db_name
+                && change.key == initial.key
+                && sink.add(ValueUpdateDto::from(change)).is_err()
+            {
+                break;
+            }
+        }
+    });
+    Ok(())
 }
 
-/// Extract name part from database name (removes public key suffix)
+/// Watch every key of a database for changes from local writes or remote sync
 #[frb(sync)]
-pub fn extract_name_from_db(db_name: String) -> Option<String> {
-    crypto::extract_name_from_db(&db_name)
+pub fn watch_db(db_name: String, sink: StreamSink<KeyChangeDto>) -> Result<(), String> {
+    watch_db_on(DEFAULT_NODE_HANDLE, db_name, sink)
 }
 
-/// Validate timestamp (check if within acceptable range)
+/// `watch_db` on a `start_node_instance` node
 #[frb(sync)]
-pub fn validate_timestamp(timestamp: i64) -> Result<bool, String> {
-    crypto::validate_timestamp(timestamp, Some(crypto::MAX_TIMESTAMP_TOLERANCE))
-        .map(|_| true)
-        .map_err(|e| e.to_string())
+pub fn watch_db_on(handle: u32, db_name: String, sink: StreamSink<KeyChangeDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let mut changes = node.watch_changes();
+
+    spawn_until_stopped(node.shutdown_token(), async move {
+        while let Some(change) = next_change(&mut changes).await {
+            if change.db_name != db_name {
+                continue;
+            }
+            let update = KeyChangeDto {
+                deleted: change.value.is_none(),
+                db_name: change.db_name,
+                key: change.key,
+            };
+            if sink.add(update).is_err() {
+                break;
+            }
+        }
+    });
+    Ok(())
 }
 
-/// Greet function for testing
+/// Tail the writes (local and sync-applied) to a database in order, starting
+/// after `since_seq`. Every event carries its persisted `seq`: store the last
+/// one processed and pass it back after a restart to consume each change
+/// exactly once. Only the latest 100,000 changes are kept; a jump in `seq`
+/// past `since_seq + 1` means older ones were trimmed.
 #[frb(sync)]
-pub fn greet(name: String) -> String {
-    format!("Hello, {}! From Cyberfly Rust.", name)
+pub fn change_feed(db_name: String, since_seq: u64, sink: StreamSink<ChangeEventDto>) -> Result<(), String> {
+    change_feed_on(DEFAULT_NODE_HANDLE, db_name, since_seq, sink)
 }
 
-/// Generate libp2p PeerId from secret key (for Kadena blockchain registration)
-/// This matches the desktop cyberfly-rust-node implementation for backward compatibility
+/// `change_feed` on a `start_node_instance` node
 #[frb(sync)]
-pub fn generate_peer_id_from_secret_key(secret_key_hex: String) -> Result<String, String> {
-    // Decode the hex-encoded secret key
-    let secret_bytes = hex::decode(&secret_key_hex)
-        .map_err(|e| format!("Failed to decode secret key: {}", e))?;
-    
-    if secret_bytes.len() != 32 {
-        return Err(format!("Invalid secret key length: expected 32 bytes, got {}", secret_bytes.len()));
+pub fn change_feed_on(handle: u32, db_name: String, since_seq: u64, sink: StreamSink<ChangeEventDto>) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    let mut changes = node.watch_changes();
+
+    spawn_until_stopped(node.shutdown_token(), async move {
+        use tokio::sync::broadcast::error::RecvError;
+
+        // Subscribed before reading the log, so nothing falls between the two
+        let mut last_seq = since_seq;
+        if !replay_changes(&node, &db_name, &mut last_seq, &sink) {
+            return;
+        }
+        loop {
+            match changes.recv().await {
+                Ok(change) if change.seq > last_seq && change.db_name == db_name => {
+                    last_seq = change.seq;
+                    if sink.add(ChangeEventDto::from(change)).is_err() {
+                        return;
+                    }
+                }
+                Ok(_) => {}
+                // The log has everything the channel dropped
+                Err(RecvError::Lagged(_)) => {
+                    if !replay_changes(&node, &db_name, &mut last_seq, &sink) {
+                        return;
+                    }
+                }
+                Err(RecvError::Closed) => return,
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Send every logged change of `db_name` after `last_seq`; false once the
+/// feed should stop
+fn replay_changes(node: &CyberflyNode, db_name: &str, last_seq: &mut u64, sink: &StreamSink<ChangeEventDto>) -> bool {
+    loop {
+        let backlog = match node.changes_since(Some(db_name), *last_seq, crate::storage::MAX_SCAN_LIMIT) {
+            Ok(backlog) => backlog,
+            Err(e) => {
+                error!("Change feed for {} failed: {}", db_name, e);
+                return false;
+            }
+        };
+        if backlog.is_empty() {
+            return true;
+        }
+        for change in backlog {
+            *last_seq = change.seq;
+            if sink.add(ChangeEventDto::from(change)).is_err() {
+                return false;
+            }
+        }
     }
+}
 
-    // Create ed25519 secret key and derive keypair
-    let secret = libp2p_identity::ed25519::SecretKey::try_from_bytes(secret_bytes)
-        .map_err(|e| format!("Failed to create secret key: {}", e))?;
-    
-    let keypair = libp2p_identity::ed25519::Keypair::from(secret);
-    
-    // Generate PeerId from the keypair's public key
-    let peer_id = libp2p_identity::PeerId::from_public_key(&keypair.public().into());
-    
-    Ok(peer_id.to_string())
+/// One logged write or delete
+#[frb(dart_metadata=("freezed"))]
+pub struct ChangeEventDto {
+    pub seq: u64,
+    pub db_name: String,
+    pub key: String,
+    /// UTF-8 (lossy) view of `value_bytes`; `None` if the key was deleted
+    pub value: Option<String>,
+    pub value_bytes: Option<Vec<u8>>,
 }
 
-/// Database entry for Flutter
+impl From<StorageChange> for ChangeEventDto {
+    fn from(change: StorageChange) -> Self {
+        Self {
+            seq: change.seq,
+            db_name: change.db_name,
+            key: change.key,
+            value: change.value.as_ref().map(|v| String::from_utf8_lossy(v).to_string()),
+            value_bytes: change.value,
+        }
+    }
+}
+
+/// Run a stream task of a node on the runtime until the node stops
+fn spawn_until_stopped<F>(shutdown: CancellationToken, task: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    get_runtime().spawn(async move {
+        shutdown.run_until_cancelled(task).await;
+    });
+}
+
+/// Next storage change for a watcher; skips over changes it was too slow for
+async fn next_change(changes: &mut tokio::sync::broadcast::Receiver<StorageChange>) -> Option<StorageChange> {
+    use tokio::sync::broadcast::error::RecvError;
+    loop {
+        match changes.recv().await {
+            Ok(change) => return Some(change),
+            Err(RecvError::Lagged(skipped)) => warn!("Watcher lagged, skipped {} changes", skipped),
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
+
+/// New value of a watched key
 #[frb(dart_metadata=("freezed"))]
-pub struct DbEntryDto {
+pub struct ValueUpdateDto {
     pub db_name: String,
     pub key: String,
-    pub value: String,
-    pub value_bytes: Vec<u8>,
+    /// UTF-8 (lossy) view of `value_bytes`; `None` if the key doesn't exist
+    pub value: Option<String>,
+    pub value_bytes: Option<Vec<u8>>,
 }
 
-/// List all databases in storage
+impl From<StorageChange> for ValueUpdateDto {
+    fn from(change: StorageChange) -> Self {
+        Self {
+            db_name: change.db_name,
+            key: change.key,
+            value: change.value.as_ref().map(|v| String::from_utf8_lossy(v).into_owned()),
+            value_bytes: change.value,
+        }
+    }
+}
+
+/// Key written or deleted in a watched database
+#[frb(dart_metadata=("freezed"))]
+pub struct KeyChangeDto {
+    pub db_name: String,
+    pub key: String,
+    pub deleted: bool,
+}
+
+/// Database shared with this device through an invite
+#[frb(dart_metadata=("freezed"))]
+pub struct AcceptedInviteDto {
+    pub db_name: String,
+    pub owner: String,
+    pub can_write: bool,
+    /// Delegated writer key (hex) to sign operations with, if `can_write`
+    pub writer_secret_key: Option<String>,
+    pub encrypted: bool,
+}
+
+/// Local write discarded by LWW, retained for manual merge
+#[frb(dart_metadata=("freezed"))]
+pub struct ConflictDto {
+    /// Storage key of the retained version; delete it via `delete_data` once merged
+    pub conflict_key: String,
+    pub db_name: String,
+    pub key: String,
+    pub field: Option<String>,
+    pub kept_op_id: String,
+    pub discarded_op_id: String,
+    pub discarded_value: String,
+    pub discarded_timestamp: i64,
+    pub resolved_at: i64,
+}
+
+/// Retain both versions on LWW conflicts (the losing local write is kept
+/// under a conflict key) instead of only emitting `ConflictResolved`
 #[frb(sync)]
-pub fn list_databases() -> Result<Vec<String>, String> {
-    let node = get_node()?;
-    node.list_databases().map_err(|e| e.to_string())
+pub fn set_retain_conflicts(enabled: bool) -> Result<(), String> {
+    set_retain_conflicts_on(DEFAULT_NODE_HANDLE, enabled)
 }
 
-/// List all keys in a specific database
+/// `set_retain_conflicts` on a `start_node_instance` node
 #[frb(sync)]
-pub fn list_keys(db_name: String) -> Result<Vec<String>, String> {
-    let node = get_node()?;
-    node.list_keys(&db_name).map_err(|e| e.to_string())
+pub fn set_retain_conflicts_on(handle: u32, enabled: bool) -> Result<(), String> {
+    let node = get_node_handle(handle)?;
+    node.set_retain_conflicts(enabled);
+    Ok(())
+}
+
+/// List retained conflicts of a database
+#[frb(sync)]
+pub fn list_conflicts(db_name: String) -> Result<Vec<ConflictDto>, String> {
+    list_conflicts_on(DEFAULT_NODE_HANDLE, db_name)
 }
 
-/// Get all entries from a specific database
+/// `list_conflicts` of a `start_node_instance` node
+#[frb(sync)]
+pub fn list_conflicts_on(handle: u32, db_name: String) -> Result<Vec<ConflictDto>, String> {
+    let node = get_node_handle(handle)?;
+    let conflicts = node.list_conflicts(&db_name).map_err(|e| e.to_string())?;
+
+    Ok(conflicts
+        .into_iter()
+        .map(|(conflict_key, c)| ConflictDto {
+            conflict_key,
+            db_name: c.db_name,
+            key: c.key,
+            field: c.field,
+            kept_op_id: c.kept_op_id,
+            discarded_op_id: c.discarded_op_id,
+            discarded_value: c.discarded_value,
+            discarded_timestamp: c.discarded_timestamp,
+            resolved_at: c.resolved_at,
+        })
+        .collect())
+}
+
+/// Key whose stored value disagrees with the sync log
+#[frb(dart_metadata=("freezed"))]
+pub struct IntegrityIssueDto {
+    pub db_name: String,
+    pub key: String,
+    pub op_id: String,
+    /// "missing", "mismatch" or "invalid_signature"
+    pub kind: String,
+}
+
+/// Result of a storage integrity check
+#[frb(dart_metadata=("freezed"))]
+pub struct IntegrityReportDto {
+    pub checked: u64,
+    pub issues: Vec<IntegrityIssueDto>,
+    pub repaired: u64,
+}
+
+/// Check stored values against the latest operation of every key in the sync
+/// log (re-verifying signatures), e.g. after a crash. With `repair`, missing
+/// and mismatched values are rewritten by replaying their operation.
 #[frb]
-pub async fn get_all_entries(db_name: String) -> Result<Vec<DbEntryDto>, String> {
-    let node = get_node()?;
-    node.get_all_entries(&db_name).await.map_err(|e| e.to_string())
+pub async fn verify_storage(repair: bool) -> Result<IntegrityReportDto, String> {
+    verify_storage_on(DEFAULT_NODE_HANDLE, repair).await
 }
 
-/// Get all entries from all databases
+/// `verify_storage` on a `start_node_instance` node
 #[frb]
-pub async fn get_all_data() -> Result<Vec<DbEntryDto>, String> {
-    let node = get_node()?;
-    node.get_all_data().await.map_err(|e| e.to_string())
+pub async fn verify_storage_on(handle: u32, repair: bool) -> Result<IntegrityReportDto, String> {
+    let node = get_node_handle(handle)?;
+    let report = node.verify_storage(repair).await.map_err(|e| e.to_string())?;
+
+    Ok(IntegrityReportDto {
+        checked: report.checked as u64,
+        issues: report
+            .issues
+            .into_iter()
+            .map(|issue| IntegrityIssueDto {
+                db_name: issue.db_name,
+                key: issue.key,
+                op_id: issue.op_id,
+                kind: issue.kind.as_str().to_string(),
+            })
+            .collect(),
+        repaired: report.repaired as u64,
+    })
 }
 
-/// Delete a key from a database
+/// How this replica of a database compared to the root its owner published
+#[frb(dart_metadata=("freezed"))]
+pub struct ReplicaCheckDto {
+    pub db_name: String,
+    /// Node ID of the device that published the root
+    pub publisher: String,
+    pub matches: bool,
+    /// Key ranges that differed and were requested for repair
+    pub mismatched_buckets: u32,
+    /// Unix ms
+    pub checked_at: i64,
+}
+
+/// Merkle root (hex) of this device's copy of a database; equal roots mean
+/// equal contents
 #[frb]
-pub async fn delete_data(db_name: String, key: String) -> Result<(), String> {
-    let node = get_node()?;
-    node.delete_data(&db_name, &key).await.map_err(|e| e.to_string())
+pub async fn get_database_root(db_name: String) -> Result<String, String> {
+    get_database_root_on(DEFAULT_NODE_HANDLE, db_name).await
+}
+
+/// `get_database_root` of a `start_node_instance` node
+#[frb]
+pub async fn get_database_root_on(handle: u32, db_name: String) -> Result<String, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node.database_root(&db_name).await)
+}
+
+/// Sync activity of one database
+#[frb(dart_metadata=("freezed"))]
+pub struct DbSyncInfoDto {
+    pub db_name: String,
+    /// Keys with a current operation
+    pub op_count: u64,
+    /// Unix ms of the last write on this device
+    pub last_local_write: Option<i64>,
+    /// Unix ms an operation from a peer was last accepted
+    pub last_remote_op: Option<i64>,
+    /// Unix ms of the last completed sync exchange covering the database
+    pub last_sync: Option<i64>,
+}
+
+/// When a database last changed locally, got a peer's change and was last
+/// synced, e.g. to show "synced 2 minutes ago" per collection
+#[frb]
+pub async fn get_db_sync_info(db_name: String) -> Result<DbSyncInfoDto, String> {
+    get_db_sync_info_on(DEFAULT_NODE_HANDLE, db_name).await
+}
+
+/// `get_db_sync_info` of a `start_node_instance` node
+#[frb]
+pub async fn get_db_sync_info_on(handle: u32, db_name: String) -> Result<DbSyncInfoDto, String> {
+    let node = get_node_handle(handle)?;
+    let info = node.db_sync_info(&db_name).await;
+    Ok(DbSyncInfoDto {
+        db_name,
+        op_count: info.op_count as u64,
+        last_local_write: info.last_local_write,
+        last_remote_op: info.last_remote_op,
+        last_sync: info.last_sync,
+    })
+}
+
+/// Where a database's writes stood when `wait_for_sync` returned
+#[frb(dart_metadata=("freezed"))]
+pub struct SyncWaitSummaryDto {
+    /// Every write was sent and confirmed by a peer in time
+    pub synced: bool,
+    /// Writes still waiting for a peer to send them to
+    pub queued_ops: u32,
+    /// Writes no peer confirmed yet, including the queued ones
+    pub unacknowledged_ops: u32,
+    pub duration_ms: u64,
+}
+
+/// Wait up to `timeout_ms` until this device's writes to a database were
+/// sent and at least one peer confirmed holding them, e.g. to show "saved &
+/// synced" only when it's true. Not an error when the time runs out; check
+/// `synced`.
+#[frb]
+pub async fn wait_for_sync(db_name: String, timeout_ms: u64) -> Result<SyncWaitSummaryDto, String> {
+    wait_for_sync_on(DEFAULT_NODE_HANDLE, db_name, timeout_ms).await
+}
+
+/// `wait_for_sync` on a `start_node_instance` node
+#[frb]
+pub async fn wait_for_sync_on(handle: u32, db_name: String, timeout_ms: u64) -> Result<SyncWaitSummaryDto, String> {
+    let node = get_node_handle(handle)?;
+    let summary = node
+        .wait_for_sync(&db_name, Duration::from_millis(timeout_ms))
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(SyncWaitSummaryDto {
+        synced: summary.synced,
+        queued_ops: summary.queued_ops as u32,
+        unacknowledged_ops: summary.unacknowledged_ops as u32,
+        duration_ms: summary.duration_ms,
+    })
+}
+
+/// Whether this device's copy of each database matched the signed root its
+/// owner last published; mismatches are repaired from the owner
+#[frb(sync)]
+pub fn list_replica_checks() -> Result<Vec<ReplicaCheckDto>, String> {
+    list_replica_checks_on(DEFAULT_NODE_HANDLE)
+}
+
+/// `list_replica_checks` of a `start_node_instance` node
+#[frb(sync)]
+pub fn list_replica_checks_on(handle: u32) -> Result<Vec<ReplicaCheckDto>, String> {
+    let node = get_node_handle(handle)?;
+    Ok(node
+        .replica_checks()
+        .into_iter()
+        .map(|check| ReplicaCheckDto {
+            matches: check.matches(),
+            mismatched_buckets: check.mismatched_buckets.len() as u32,
+            db_name: check.db_name,
+            publisher: check.publisher,
+            checked_at: check.checked_at,
+        })
+        .collect())
+}
+
+impl From<LogRecord> for LogEntry {
+    fn from(record: LogRecord) -> Self {
+        Self { timestamp: record.timestamp, level: record.level.to_string(), message: record.message }
+    }
 }
 
 /// Get recent logs from the buffer
 #[frb(sync)]
 pub fn get_logs(limit: Option<u32>) -> Vec<LogEntry> {
-    let buffer = get_log_buffer();
-    let guard = buffer.read();
-    let limit = limit.unwrap_or(100) as usize;
-    guard.iter().rev().take(limit).cloned().collect::<Vec<_>>().into_iter().rev().collect()
+    let filter = LogFilter { limit: Some(limit.unwrap_or(100) as usize), ..LogFilter::default() };
+    get_log_buffer().read().query(&filter).into_iter().map(LogEntry::from).collect()
+}
+
+/// Which logs `get_logs_filtered` returns; unset fields match every entry
+#[frb(dart_metadata=("freezed"))]
+pub struct LogFilterDto {
+    /// Least severe level included: "error", "warn", "info", "debug" or
+    /// "trace"
+    pub min_level: Option<String>,
+    /// Module path prefix, e.g. "rust_lib_cyberfly_mobile_node::sync", or
+    /// "flutter" for entries added with `add_log_entry`
+    pub target: Option<String>,
+    /// Case-insensitive text the message contains
+    pub contains: Option<String>,
+    /// Unix ms, inclusive
+    pub since: Option<i64>,
+    /// Unix ms, inclusive
+    pub until: Option<i64>,
+    /// Most recent entries returned at most; all matching if None
+    pub limit: Option<u32>,
+}
+
+/// Logs in the buffer matching `filter`, oldest first
+#[frb(sync)]
+pub fn get_logs_filtered(filter: LogFilterDto) -> Result<Vec<LogEntry>, String> {
+    let filter = LogFilter {
+        min_level: filter.min_level.as_deref().map(logs::parse_level).transpose().map_err(|e| e.to_string())?,
+        target: filter.target,
+        contains: filter.contains,
+        since: filter.since,
+        until: filter.until,
+        limit: filter.limit.map(|limit| limit as usize),
+    };
+    Ok(get_log_buffer().read().query(&filter).into_iter().map(LogEntry::from).collect())
+}
+
+/// Keep up to `size` log entries (500 by default, at most 50000); the
+/// oldest are dropped if the buffer holds more
+#[frb(sync)]
+pub fn set_log_buffer_size(size: u32) -> Result<(), String> {
+    get_log_buffer().write().set_capacity(size as usize).map_err(|e| e.to_string())
+}
+
+/// Write the whole log buffer to the file at `path`, one entry per line, to
+/// attach to bug reports. Returns the number of entries written.
+#[frb]
+pub async fn export_logs(path: String) -> Result<u32, String> {
+    let written = get_log_buffer().read().export(std::path::Path::new(&path)).map_err(|e| e.to_string())?;
+    Ok(written as u32)
 }
 
 /// Clear the log buffer
 #[frb(sync)]
 pub fn clear_logs() {
-    let buffer = get_log_buffer();
-    buffer.write().clear();
+    get_log_buffer().write().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_node_instances_are_isolated() {
+        // Separate networks, so the nodes don't sync with each other
+        let config = |network_id: &str| -> NodeConfigDto {
+            NodeConfig {
+                network_id: Some(network_id.to_string()),
+                ..NodeConfig::default().with_relay(RelayConfig::Disabled)
+            }
+            .into()
+        };
+        let (dir_a, dir_b) = (tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap());
+        let a = start_node_instance(dir_a.path().to_string_lossy().into_owned(), None, Vec::new(), config("a")).await.unwrap();
+        let b = start_node_instance(dir_b.path().to_string_lossy().into_owned(), None, Vec::new(), config("b")).await.unwrap();
+        assert_ne!(a.handle, b.handle);
+        assert_ne!(a.node_id, b.node_id);
+        assert_eq!(get_node_info_on(b.handle).unwrap().node_id, b.node_id);
+
+        let db_name = crypto::generate_db_name("notes", &a.public_key);
+        store_data_signed_on(a.handle, db_name.clone(), "k".to_string(), b"a".to_vec(), "String".to_string())
+            .await
+            .unwrap();
+        assert_eq!(get_data_on(a.handle, db_name.clone(), "k".to_string()).await.unwrap(), Some(b"a".to_vec()));
+        assert_eq!(get_data_on(b.handle, db_name.clone(), "k".to_string()).await.unwrap(), None);
+        assert_eq!(list_keys_on(a.handle, db_name.clone()).unwrap(), vec!["k".to_string()]);
+        assert!(list_keys_on(b.handle, db_name.clone()).unwrap().is_empty());
+        // b can't write to a's database
+        assert!(store_data_signed_on(b.handle, db_name.clone(), "k".to_string(), b"b".to_vec(), "String".to_string())
+            .await
+            .is_err());
+
+        // Neither is the default node
+        assert_eq!(get_data(db_name.clone(), "k".to_string()).await.unwrap_err(), "Node not running");
+
+        // Waking an instance syncs the running node rather than starting one
+        let dir = |dir: &tempfile::TempDir| dir.path().to_string_lossy().into_owned();
+        let summary = wake_and_sync_on(b.handle, dir(&dir_b), None, Vec::new(), 1).await.unwrap();
+        assert!(!summary.started_node);
+        // The network can't change under any running node
+        assert!(set_network_passphrase(dir(&dir_b), Some("secret".to_string())).await.is_err());
+
+        // Stopping one leaves the other running
+        stop_node_instance(a.handle).await.unwrap();
+        assert_eq!(
+            get_data_on(a.handle, db_name.clone(), "k".to_string()).await.unwrap_err(),
+            format!("Node {} not running", a.handle)
+        );
+        assert!(get_node_status_on(b.handle).unwrap().is_running);
+        assert_eq!(list_node_instances(), vec![b.handle]);
+        stop_node_instance(b.handle).await.unwrap();
+        set_network_passphrase(dir(&dir_b), Some("secret".to_string())).await.unwrap();
+    }
 }
@@ -0,0 +1,200 @@
+//! Challenge-response proof of peer identity
+//!
+//! A gossip message names its sender, but only the message format vouches
+//! for it. Before trusting a peer with something sensitive, the challenger
+//! opens a QUIC connection on `AUTH_ALPN` (whose handshake proves the peer
+//! holds its EndpointId) and sends a random nonce; the peer signs the nonce
+//! together with both node IDs and the public key it advertises. The
+//! challenger binds the proof to its own node ID, so a proof can't be relayed
+//! to a third node. Verified bindings are cached in `PeerRegistry`.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use ed25519_dalek::SigningKey;
+use iroh::endpoint::Connection;
+use iroh::protocol::{AcceptError, ProtocolHandler};
+use iroh::{Endpoint, EndpointId};
+use serde::{Deserialize, Serialize};
+
+use crate::crypto;
+
+/// ALPN of the ownership proof protocol
+pub const AUTH_ALPN: &[u8] = b"/cyberfly/auth/1";
+
+/// How long a proof stays trusted before the peer is challenged again
+pub const VERIFIED_BINDING_TTL_MS: i64 = 60 * 60 * 1000;
+
+const AUTH_TIMEOUT: Duration = Duration::from_secs(15);
+
+const NONCE_LEN: usize = 32;
+
+/// Largest proof accepted
+const MAX_PROOF_SIZE: usize = 1024;
+
+/// Signed answer to a challenge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnershipProof {
+    /// EndpointId of the prover
+    pub node_id: String,
+    /// ed25519 public key (hex) the prover advertises
+    pub public_key: String,
+    /// Over `signing_message`, by `public_key`
+    pub signature: String,
+}
+
+impl OwnershipProof {
+    pub fn new(signing_key: &SigningKey, node_id: String, challenger: &str, nonce: &[u8]) -> Self {
+        let public_key = crypto::public_key_hex(signing_key);
+        let message = signing_message(challenger, &node_id, &public_key, nonce);
+        let signature = crypto::sign_message(signing_key, message.as_bytes());
+        Self { node_id, public_key, signature }
+    }
+
+    /// Whether the proof answers `nonce` from `challenger`
+    pub fn verify(&self, challenger: &str, nonce: &[u8]) -> Result<bool> {
+        let message = signing_message(challenger, &self.node_id, &self.public_key, nonce);
+        crypto::verify_signature(&self.public_key, message.as_bytes(), &self.signature)
+    }
+}
+
+fn signing_message(challenger: &str, node_id: &str, public_key: &str, nonce: &[u8]) -> String {
+    format!("ownership:{}:{}:{}:{}", challenger, node_id, public_key, hex::encode(nonce))
+}
+
+/// A peer shown to control both its EndpointId and `public_key`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedBinding {
+    pub node_id: String,
+    pub public_key: String,
+    /// Unix ms
+    pub verified_at: i64,
+}
+
+impl VerifiedBinding {
+    pub fn is_fresh(&self, now: i64) -> bool {
+        now - self.verified_at < VERIFIED_BINDING_TTL_MS
+    }
+}
+
+/// Check `proof`, received from `peer_id` over an authenticated connection,
+/// against our challenge and the public key the peer advertises (if known)
+pub fn check_proof(
+    proof: &OwnershipProof,
+    challenger: &str,
+    peer_id: &str,
+    nonce: &[u8],
+    advertised_public_key: Option<&str>,
+) -> Result<VerifiedBinding> {
+    if proof.node_id != peer_id {
+        return Err(anyhow!("Proof is for {}, not {}", proof.node_id, peer_id));
+    }
+    if let Some(advertised) = advertised_public_key {
+        if !proof.public_key.eq_ignore_ascii_case(advertised) {
+            return Err(anyhow!("{} proved key {}, but advertises {}", peer_id, proof.public_key, advertised));
+        }
+    }
+    if !proof.verify(challenger, nonce)? {
+        return Err(anyhow!("Invalid ownership proof from {}", peer_id));
+    }
+    Ok(VerifiedBinding {
+        node_id: proof.node_id.clone(),
+        public_key: proof.public_key.to_lowercase(),
+        verified_at: chrono::Utc::now().timestamp_millis(),
+    })
+}
+
+/// Answers challenges with proofs signed by the node key
+#[derive(Clone)]
+pub struct AuthProtocol {
+    signing_key: SigningKey,
+    node_id: String,
+}
+
+impl std::fmt::Debug for AuthProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthProtocol").field("node_id", &self.node_id).finish()
+    }
+}
+
+impl AuthProtocol {
+    pub fn new(signing_key: SigningKey, node_id: String) -> Self {
+        Self { signing_key, node_id }
+    }
+}
+
+impl ProtocolHandler for AuthProtocol {
+    async fn accept(&self, connection: Connection) -> Result<(), AcceptError> {
+        // Sign for the challenger the handshake authenticated, not one the
+        // challenge could name
+        let challenger = connection.remote_id().to_string();
+        let (mut send, mut recv) = connection.accept_bi().await.map_err(AcceptError::from_err)?;
+        let mut nonce = [0u8; NONCE_LEN];
+        recv.read_exact(&mut nonce).await.map_err(AcceptError::from_err)?;
+        let proof = OwnershipProof::new(&self.signing_key, self.node_id.clone(), &challenger, &nonce);
+        let encoded = postcard::to_allocvec(&proof).map_err(AcceptError::from_err)?;
+        send.write_all(&encoded).await.map_err(AcceptError::from_err)?;
+        send.finish().map_err(AcceptError::from_err)?;
+        // The challenger closes once it has the proof
+        connection.closed().await;
+        Ok(())
+    }
+}
+
+/// Challenge `peer_id` to prove it holds its EndpointId and the ed25519 key
+/// it advertises
+pub async fn prove_ownership(
+    endpoint: &Endpoint,
+    local_node_id: &str,
+    peer_id: EndpointId,
+    advertised_public_key: Option<&str>,
+) -> Result<VerifiedBinding> {
+    let nonce: [u8; NONCE_LEN] = rand::random();
+    let response = tokio::time::timeout(AUTH_TIMEOUT, async {
+        let conn = endpoint.connect(peer_id, AUTH_ALPN).await?;
+        let (mut send, mut recv) = conn.open_bi().await?;
+        send.write_all(&nonce).await?;
+        send.finish()?;
+        let response = recv.read_to_end(MAX_PROOF_SIZE).await?;
+        conn.close(0u32.into(), b"done");
+        anyhow::Ok(response)
+    })
+    .await
+    .map_err(|_| anyhow!("Ownership challenge to {} timed out", peer_id))??;
+
+    let proof: OwnershipProof = postcard::from_bytes(&response)?;
+    check_proof(&proof, local_node_id, &peer_id.to_string(), &nonce, advertised_public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ownership_proof() {
+        let (peer_key, peer_public_key) = crypto::generate_keypair();
+        let (_, challenger) = crypto::generate_keypair();
+        let nonce = [9u8; NONCE_LEN];
+
+        let proof = OwnershipProof::new(&peer_key, peer_public_key.clone(), &challenger, &nonce);
+        let binding = check_proof(&proof, &challenger, &peer_public_key, &nonce, Some(&peer_public_key)).unwrap();
+        assert_eq!((binding.node_id.as_str(), binding.public_key.as_str()), (peer_public_key.as_str(), peer_public_key.as_str()));
+        assert!(binding.is_fresh(binding.verified_at));
+        assert!(!binding.is_fresh(binding.verified_at + VERIFIED_BINDING_TTL_MS));
+
+        // Bound to the challenge, the challenger and the connection's peer
+        assert!(check_proof(&proof, &challenger, &peer_public_key, &[0u8; NONCE_LEN], None).is_err());
+        let (_, other) = crypto::generate_keypair();
+        assert!(check_proof(&proof, &other, &peer_public_key, &nonce, None).is_err());
+        assert!(check_proof(&proof, &challenger, &other, &nonce, None).is_err());
+
+        // The proven key must be the advertised one
+        assert!(check_proof(&proof, &challenger, &peer_public_key, &nonce, Some(&other)).is_err());
+
+        // Claiming the peer's key takes the peer's signature
+        let (impostor_key, _) = crypto::generate_keypair();
+        let mut forged = OwnershipProof::new(&impostor_key, peer_public_key.clone(), &challenger, &nonce);
+        forged.public_key = peer_public_key.clone();
+        assert!(check_proof(&forged, &challenger, &peer_public_key, &nonce, None).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+//! Encrypted backup and restore of all node data
+//!
+//! A backup holds every sled tree (user databases, the oplog and internal
+//! trees such as writer lists and database keys) plus the node secret key, so
+//! a device restored from it keeps the same identity. The archive is
+//! postcard-encoded, zstd-compressed and sealed with XChaCha20-Poly1305 under
+//! a key derived from the passphrase with Argon2id.
+//!
+//! File layout: `BACKUP_MAGIC || salt || nonce || ciphertext`
+
+use std::io::Read;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use ed25519_dalek::SigningKey;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::crypto;
+use crate::keystore;
+use crate::storage::{Storage, TreeDump};
+
+/// File signature, also identifies the format version
+const BACKUP_MAGIC: &[u8; 8] = b"CFBKUP01";
+
+/// Archive encoding version
+const BACKUP_VERSION: u32 = 1;
+
+const SALT_LENGTH: usize = 16;
+
+/// Shortest accepted backup passphrase
+pub const MIN_PASSPHRASE_LENGTH: usize = 8;
+
+/// zstd level for backup archives
+const BACKUP_COMPRESSION_LEVEL: i32 = 3;
+
+/// Upper bound on a decompressed archive, guards against zstd bombs
+const MAX_BACKUP_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+/// File the node secret key is kept in, inside the data directory
+pub const SECRET_KEY_FILE: &str = "secret_key";
+
+/// Directory of the sled database, inside the data directory
+pub const SLED_DIR: &str = "sled_db";
+
+/// Backup contents (before compression and encryption)
+#[derive(Serialize, Deserialize)]
+struct BackupArchive {
+    version: u32,
+    /// Unix timestamp (milliseconds)
+    created_at: i64,
+    node_secret_key: [u8; 32],
+    trees: Vec<TreeDump>,
+}
+
+/// What a backup contains
+#[derive(Debug, Clone)]
+pub struct BackupSummary {
+    pub created_at: i64,
+    /// Public key of the node identity in the backup (hex)
+    pub public_key: String,
+    pub tree_count: usize,
+    pub entry_count: usize,
+}
+
+impl BackupArchive {
+    fn summary(&self) -> BackupSummary {
+        BackupSummary {
+            created_at: self.created_at,
+            public_key: crypto::public_key_hex(&SigningKey::from_bytes(&self.node_secret_key)),
+            tree_count: self.trees.len(),
+            entry_count: self.trees.iter().map(|(_, entries)| entries.len()).sum(),
+        }
+    }
+}
+
+/// Write an encrypted backup of `storage` and the node key to `path`
+pub fn export_backup(storage: &Storage, node_key: &SigningKey, path: &Path, passphrase: &str) -> Result<BackupSummary> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LENGTH {
+        return Err(anyhow!("Passphrase must be at least {} characters", MIN_PASSPHRASE_LENGTH));
+    }
+
+    let archive = BackupArchive {
+        version: BACKUP_VERSION,
+        created_at: chrono::Utc::now().timestamp_millis(),
+        node_secret_key: node_key.to_bytes(),
+        trees: storage.export_trees()?,
+    };
+    let summary = archive.summary();
+
+    let encoded = postcard::to_allocvec(&archive)?;
+    let compressed = zstd::stream::encode_all(&encoded[..], BACKUP_COMPRESSION_LEVEL)?;
+
+    let mut salt = [0u8; SALT_LENGTH];
+    let mut nonce = [0u8; crypto::XNONCE_LENGTH];
+    rand::rng().fill_bytes(&mut salt);
+    rand::rng().fill_bytes(&mut nonce);
+
+    let key = derive_key(passphrase, &salt)?;
+    let ciphertext = XChaCha20Poly1305::new((&key).into())
+        .encrypt(XNonce::from_slice(&nonce), compressed.as_slice())
+        .map_err(|_| anyhow!("Backup encryption failed"))?;
+
+    let mut file = Vec::with_capacity(BACKUP_MAGIC.len() + SALT_LENGTH + nonce.len() + ciphertext.len());
+    file.extend_from_slice(BACKUP_MAGIC);
+    file.extend_from_slice(&salt);
+    file.extend_from_slice(&nonce);
+    file.extend_from_slice(&ciphertext);
+    std::fs::write(path, file)?;
+
+    info!(
+        "Exported backup to {} ({} trees, {} entries)",
+        path.display(), summary.tree_count, summary.entry_count
+    );
+    Ok(summary)
+}
+
+/// Restore a backup into `data_dir`: every tree is replaced by its backed up
+/// contents and the node key is written to `SECRET_KEY_FILE` (sealed if a
+/// wrapping key is set). The node must not be running.
+pub fn import_backup(data_dir: &Path, path: &Path, passphrase: &str) -> Result<BackupSummary> {
+    let archive = read_backup(path, passphrase)?;
+    let summary = archive.summary();
+
+    std::fs::create_dir_all(data_dir)?;
+    let storage = Storage::new(data_dir.join(SLED_DIR))?;
+    storage.import_trees(archive.trees)?;
+    storage.flush()?;
+    keystore::write_key(&data_dir.join(SECRET_KEY_FILE), &archive.node_secret_key)?;
+
+    info!(
+        "Restored backup from {} ({} trees, {} entries)",
+        path.display(), summary.tree_count, summary.entry_count
+    );
+    Ok(summary)
+}
+
+fn read_backup(path: &Path, passphrase: &str) -> Result<BackupArchive> {
+    let file = std::fs::read(path)?;
+    let header_len = BACKUP_MAGIC.len() + SALT_LENGTH + crypto::XNONCE_LENGTH;
+    if file.len() < header_len || !file.starts_with(BACKUP_MAGIC) {
+        return Err(anyhow!("Not a backup file"));
+    }
+    let (salt, rest) = file[BACKUP_MAGIC.len()..].split_at(SALT_LENGTH);
+    let (nonce, ciphertext) = rest.split_at(crypto::XNONCE_LENGTH);
+
+    let key = derive_key(passphrase, salt)?;
+    let compressed = XChaCha20Poly1305::new((&key).into())
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("Wrong passphrase or corrupted backup"))?;
+
+    let mut encoded = Vec::new();
+    zstd::stream::Decoder::new(&compressed[..])?
+        .take(MAX_BACKUP_BYTES + 1)
+        .read_to_end(&mut encoded)?;
+    if encoded.len() as u64 > MAX_BACKUP_BYTES {
+        return Err(anyhow!("Backup exceeds {} bytes", MAX_BACKUP_BYTES));
+    }
+
+    let archive: BackupArchive = postcard::from_bytes(&encoded)?;
+    if archive.version != BACKUP_VERSION {
+        return Err(anyhow!("Unsupported backup version {}", archive.version));
+    }
+    Ok(archive)
+}
+
+/// Argon2id key for a passphrase and salt
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; crypto::DB_KEY_LENGTH]> {
+    let mut key = [0u8; crypto::DB_KEY_LENGTH];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_backup_roundtrip() {
+        let source = tempdir().unwrap();
+        let storage = Storage::new(source.path().join(SLED_DIR)).unwrap();
+        storage.put("notes", "k", b"v").unwrap();
+        storage.put_operation("op-1", b"{}").unwrap();
+
+        let (node_key, public_key) = crypto::generate_keypair();
+        let backup_path = source.path().join("node.backup");
+        export_backup(&storage, &node_key, &backup_path, "correct horse").unwrap();
+
+        let target = tempdir().unwrap();
+        assert!(import_backup(target.path(), &backup_path, "wrong horse").is_err());
+
+        let summary = import_backup(target.path(), &backup_path, "correct horse").unwrap();
+        assert_eq!(summary.public_key, public_key);
+        assert_eq!(
+            std::fs::read(target.path().join(SECRET_KEY_FILE)).unwrap(),
+            node_key.to_bytes()
+        );
+
+        let restored = Storage::new(target.path().join(SLED_DIR)).unwrap();
+        assert_eq!(restored.get("notes", "k").unwrap().unwrap(), b"v");
+        assert!(restored.has_operation("op-1").unwrap());
+    }
+
+    #[test]
+    fn test_backup_rejects_short_passphrase() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().join(SLED_DIR)).unwrap();
+        let (node_key, _) = crypto::generate_keypair();
+
+        assert!(export_backup(&storage, &node_key, &dir.path().join("b"), "short").is_err());
+    }
+}
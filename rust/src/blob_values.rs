@@ -0,0 +1,112 @@
+//! Large values offloaded to iroh-blobs
+//!
+//! Photos and documents would bloat sled and every sync payload. Values over
+//! `OFFLOAD_THRESHOLD` written with `store_data` go into the blob store
+//! instead: the operation carries a `BlobRef` (hash, size, provider) as its
+//! value with store type `Blob`, and storage keeps only that reference.
+//! The writer's signature still covers the content, so a peer fetches the
+//! blob before it accepts such an operation (see `SyncStore::add_operation`),
+//! and `get_data` resolves the reference back to the bytes.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use iroh::{Endpoint, EndpointId};
+use iroh_blobs::store::fs::FsStore;
+use iroh_blobs::Hash;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// Store type of operations whose value is a `BlobRef`
+pub const BLOB_STORE_TYPE: &str = "Blob";
+
+/// Values larger than this are offloaded to the blob store
+pub const OFFLOAD_THRESHOLD: usize = 64 * 1024;
+
+/// Largest blob value we are willing to download
+const MAX_BLOB_VALUE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Give up on a blob value download after this long
+const BLOB_FETCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Reference to an offloaded value, the value of a `Blob` operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlobRef {
+    /// iroh-blobs hash of the content
+    pub hash: String,
+    pub size: u64,
+    /// Node ID of the writer, which serves the blob
+    pub provider: String,
+}
+
+impl BlobRef {
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).expect("BlobRef serializes")
+    }
+
+    pub fn decode(value: &str) -> Result<Self> {
+        Ok(serde_json::from_str(value)?)
+    }
+}
+
+/// Puts offloaded values into the blob store and gets them back, from peers
+/// if they're not here
+pub struct BlobValues {
+    endpoint: Endpoint,
+    store: FsStore,
+    node_id: String,
+}
+
+impl BlobValues {
+    pub fn new(endpoint: Endpoint, store: FsStore, node_id: String) -> Self {
+        Self { endpoint, store, node_id }
+    }
+
+    /// Add `content` to the blob store; the reference points peers at us
+    pub async fn put(&self, content: Vec<u8>) -> Result<BlobRef> {
+        let size = content.len() as u64;
+        let tag = self.store.blobs().add_bytes(content).await?;
+        Ok(BlobRef { hash: tag.hash.to_string(), size, provider: self.node_id.clone() })
+    }
+
+    /// Content of `blob`, downloaded from its provider or `peers` unless it's
+    /// stored here already
+    pub async fn get(&self, blob: &BlobRef, peers: &[EndpointId]) -> Result<Vec<u8>> {
+        if blob.size > MAX_BLOB_VALUE_BYTES {
+            return Err(anyhow!("Blob value {} too large ({} bytes)", blob.hash, blob.size));
+        }
+        let hash: Hash = blob.hash.parse()?;
+        if !self.store.has(hash).await? {
+            let mut providers = Vec::with_capacity(peers.len() + 1);
+            if let Ok(provider) = blob.provider.parse::<EndpointId>() {
+                providers.push(provider);
+            }
+            providers.extend(peers.iter().filter(|peer| !providers.contains(peer)).copied().collect::<Vec<_>>());
+            debug!("Fetching blob value {} ({} bytes)", blob.hash, blob.size);
+            let downloader = self.store.downloader(&self.endpoint);
+            tokio::time::timeout(BLOB_FETCH_TIMEOUT, downloader.download(hash, providers))
+                .await
+                .map_err(|_| anyhow!("Timed out fetching blob value {}", blob.hash))??;
+        }
+        let bytes = self.store.blobs().get_bytes(hash).await?;
+        if bytes.len() as u64 != blob.size {
+            return Err(anyhow!("Blob value {} has {} bytes, expected {}", blob.hash, bytes.len(), blob.size));
+        }
+        Ok(bytes.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blob_ref_encoding() {
+        let blob = BlobRef { hash: "a".repeat(64), size: 1 << 20, provider: "b".repeat(64) };
+        let encoded = blob.encode();
+        assert!(encoded.len() < 256);
+        assert_eq!(BlobRef::decode(&encoded).unwrap(), blob);
+        assert!(BlobRef::decode("plain text").is_err());
+        assert!(BlobRef::decode(r#"{"hash":"x"}"#).is_err());
+    }
+}
@@ -0,0 +1,304 @@
+//! Bootstrap peers and signed bootstrap manifests
+//!
+//! Bootstrap peers are given as `node_id@host:port`, where the host is an IP
+//! address or a host name. Host names are resolved on every dial, so servers
+//! with dynamic IPs stay reachable across reconnects.
+//!
+//! The built-in bootstrap peers go stale when servers move. A manifest
+//! published at an HTTPS URL lists the current ones, signed by a publisher
+//! key the app pins (see `NodeConfig::bootstrap_manifest`). At startup the
+//! node dials the peers of the cached copy along with the built-in and
+//! user-supplied ones, and fetches the manifest in the background; once its
+//! signature and expiry check out it is cached and its new peers are dialed.
+//! A fetched manifest older than the cached one is ignored, so an old copy
+//! can't be replayed.
+
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use iroh::{EndpointAddr, EndpointId, TransportAddr};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::config::BootstrapManifestSource;
+use crate::crypto;
+use crate::storage::Storage;
+
+/// How long fetching the manifest may take
+pub const MANIFEST_FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Largest manifest accepted
+const MAX_MANIFEST_BYTES: usize = 64 * 1024;
+
+/// Most peers taken from a manifest
+pub const MAX_MANIFEST_PEERS: usize = 32;
+
+/// Setting caching the last verified manifest, as published
+const MANIFEST_SETTING: &str = "bootstrap_manifest";
+
+/// How long resolving a host name may take
+pub const RESOLVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Most addresses of a host name dialed
+const MAX_RESOLVED_ADDRS: usize = 4;
+
+/// Bootstrap peers, as signed by the publisher
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BootstrapManifest {
+    /// `node_id@host:port`
+    pub peers: Vec<String>,
+    /// Unix ms; a newer manifest replaces an older one
+    pub issued_at: i64,
+    /// Unix ms after which the manifest isn't used
+    pub expires_at: Option<i64>,
+}
+
+/// Manifest plus the publisher's signature over its JSON encoding, as
+/// published
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SignedManifest {
+    manifest: BootstrapManifest,
+    signature: String,
+}
+
+impl BootstrapManifest {
+    /// Decode a published manifest and check its signature, expiry and peers
+    pub fn verify(published: &[u8], publisher_key: &str) -> Result<Self> {
+        if published.len() > MAX_MANIFEST_BYTES {
+            return Err(anyhow!("Bootstrap manifest exceeds {} bytes", MAX_MANIFEST_BYTES));
+        }
+        let signed: SignedManifest = serde_json::from_slice(published)?;
+        let manifest = signed.manifest;
+        if !crypto::verify_signature(publisher_key, &serde_json::to_vec(&manifest)?, &signed.signature)? {
+            return Err(anyhow!("Invalid bootstrap manifest signature"));
+        }
+        if manifest.expires_at.is_some_and(|at| at < chrono::Utc::now().timestamp_millis()) {
+            return Err(anyhow!("Bootstrap manifest has expired"));
+        }
+        if manifest.peers.is_empty() || manifest.peers.len() > MAX_MANIFEST_PEERS {
+            return Err(anyhow!("Bootstrap manifest must list between 1 and {} peers", MAX_MANIFEST_PEERS));
+        }
+        for peer in &manifest.peers {
+            parse_peer(peer)?;
+        }
+        Ok(manifest)
+    }
+}
+
+/// Node ID and address of a `node_id@host:port` bootstrap entry
+pub fn parse_peer(peer: &str) -> Result<(EndpointId, &str)> {
+    let (node_id, addr) = peer.split_once('@').ok_or_else(|| anyhow!("Bootstrap peer {} isn't node_id@host:port", peer))?;
+    let node_id = node_id.parse().map_err(|e| anyhow!("Invalid bootstrap peer ID {}: {}", node_id, e))?;
+    check_addr(addr)?;
+    Ok((node_id, addr))
+}
+
+/// Check that `addr` is `ip:port` or `host:port`, without resolving it
+pub fn check_addr(addr: &str) -> Result<()> {
+    if addr.parse::<SocketAddr>().is_ok() {
+        return Ok(());
+    }
+    let (host, port) = addr.rsplit_once(':').ok_or_else(|| anyhow!("Address {} has no port", addr))?;
+    if port.parse::<u16>().is_err() {
+        return Err(anyhow!("Invalid port in address {}", addr));
+    }
+    let valid_host = !host.is_empty()
+        && host.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    if !valid_host {
+        return Err(anyhow!("Invalid host in address {}", addr));
+    }
+    Ok(())
+}
+
+/// Socket addresses of `addr`, looking host names up now; IPv4 first
+pub async fn resolve(addr: &str) -> Result<Vec<SocketAddr>> {
+    if let Ok(socket_addr) = addr.parse::<SocketAddr>() {
+        return Ok(vec![socket_addr]);
+    }
+    check_addr(addr)?;
+    let resolved = tokio::time::timeout(RESOLVE_TIMEOUT, tokio::net::lookup_host(addr))
+        .await
+        .map_err(|_| anyhow!("Resolving {} timed out", addr))??;
+    let mut addrs: Vec<SocketAddr> = resolved.collect();
+    if addrs.is_empty() {
+        return Err(anyhow!("{} didn't resolve to any address", addr));
+    }
+    addrs.sort_by_key(SocketAddr::is_ipv6);
+    addrs.truncate(MAX_RESOLVED_ADDRS);
+    Ok(addrs)
+}
+
+/// Address to dial `peer_id` at: `addr` (`ip:port` or `host:port`, resolved
+/// now) if given and it resolves, otherwise whatever address lookup finds
+pub async fn endpoint_addr(peer_id: EndpointId, addr: Option<&str>) -> EndpointAddr {
+    let Some(addr) = addr else {
+        return EndpointAddr::from(peer_id);
+    };
+    match resolve(addr).await {
+        Ok(addrs) => EndpointAddr::from_parts(peer_id, addrs.into_iter().map(TransportAddr::Ip)),
+        Err(e) => {
+            warn!(peer = %peer_id.fmt_short(), error = %e, "Dialing without an address");
+            EndpointAddr::from(peer_id)
+        }
+    }
+}
+
+/// Download a published manifest
+async fn fetch(url: &str) -> Result<Vec<u8>> {
+    let client = reqwest::Client::builder().timeout(MANIFEST_FETCH_TIMEOUT).build()?;
+    let mut response = client.get(url).send().await?.error_for_status()?;
+    if response.content_length().is_some_and(|len| len > MAX_MANIFEST_BYTES as u64) {
+        return Err(anyhow!("Bootstrap manifest exceeds {} bytes", MAX_MANIFEST_BYTES));
+    }
+    // The length header may be missing or wrong, so count as the body arrives
+    let mut published = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if published.len() + chunk.len() > MAX_MANIFEST_BYTES {
+            return Err(anyhow!("Bootstrap manifest exceeds {} bytes", MAX_MANIFEST_BYTES));
+        }
+        published.extend_from_slice(&chunk);
+    }
+    Ok(published)
+}
+
+/// Cached manifest, if it still verifies with `publisher_key`
+pub fn cached(storage: &Storage, publisher_key: &str) -> Option<BootstrapManifest> {
+    let published = storage.get_setting(MANIFEST_SETTING).ok()??;
+    match BootstrapManifest::verify(&published, publisher_key) {
+        Ok(manifest) => Some(manifest),
+        Err(e) => {
+            warn!("Ignoring cached bootstrap manifest: {}", e);
+            None
+        }
+    }
+}
+
+/// Fetch the manifest of `source` and cache it; None if the fetch fails, it
+/// doesn't verify or it is older than the cached one
+pub async fn refresh(storage: &Storage, source: &BootstrapManifestSource) -> Option<BootstrapManifest> {
+    let cached = cached(storage, &source.publisher_key);
+    let fetched = fetch(&source.url).await.and_then(|published| {
+        let manifest = BootstrapManifest::verify(&published, &source.publisher_key)?;
+        Ok((published, manifest))
+    });
+    match fetched {
+        Ok((_, manifest)) if cached.as_ref().is_some_and(|cached| cached.issued_at > manifest.issued_at) => {
+            warn!(issued_at = manifest.issued_at, "Ignoring bootstrap manifest older than the cached one");
+            None
+        }
+        Ok((published, manifest)) => {
+            if let Err(e) = storage.put_setting(MANIFEST_SETTING, &published) {
+                warn!("Failed to cache bootstrap manifest: {}", e);
+            }
+            info!(peers = manifest.peers.len(), issued_at = manifest.issued_at, "Fetched bootstrap manifest");
+            Some(manifest)
+        }
+        Err(e) => {
+            warn!(url = %source.url, error = %e, cached = cached.is_some(), "Failed to fetch bootstrap manifest");
+            None
+        }
+    }
+}
+
+/// Bootstrap entries to dial, one per node ID: the user's first, then the
+/// manifest's, then the built-in ones
+pub fn merge_peers(user: &[String], manifest: Option<&BootstrapManifest>, built_in: &[&str]) -> Vec<String> {
+    let manifest_peers = manifest.map(|manifest| manifest.peers.as_slice()).unwrap_or_default();
+    let mut seen = HashSet::new();
+    user.iter()
+        .chain(manifest_peers)
+        .map(String::as_str)
+        .chain(built_in.iter().copied())
+        .filter(|peer| seen.insert(peer.split_once('@').map_or(*peer, |(node_id, _)| node_id).to_string()))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::SigningKey;
+
+    fn publish(manifest: &BootstrapManifest, publisher: &SigningKey) -> Vec<u8> {
+        let signature = crypto::sign_message(publisher, &serde_json::to_vec(manifest).unwrap());
+        serde_json::to_vec(&SignedManifest { manifest: manifest.clone(), signature }).unwrap()
+    }
+
+    fn peer(seed: u8, port: u16) -> String {
+        let node_id = iroh::SecretKey::from_bytes(&[seed; 32]).public();
+        format!("{}@203.0.113.{}:{}", node_id, seed, port)
+    }
+
+    #[test]
+    fn test_manifest_verify_and_merge() {
+        let (publisher, publisher_key) = crypto::generate_keypair();
+        let manifest = BootstrapManifest { peers: vec![peer(1, 31001), peer(2, 31001)], issued_at: 1_000, expires_at: None };
+        let published = publish(&manifest, &publisher);
+        assert_eq!(BootstrapManifest::verify(&published, &publisher_key).unwrap(), manifest);
+
+        // Another publisher, tampering, expiry and malformed peers are rejected
+        let (_, other_key) = crypto::generate_keypair();
+        assert!(BootstrapManifest::verify(&published, &other_key).is_err());
+        let tampered = String::from_utf8(published.clone()).unwrap().replace("31001", "31002");
+        assert!(BootstrapManifest::verify(tampered.as_bytes(), &publisher_key).is_err());
+        let expired = BootstrapManifest { expires_at: Some(1), ..manifest.clone() };
+        assert!(BootstrapManifest::verify(&publish(&expired, &publisher), &publisher_key).is_err());
+        let malformed = BootstrapManifest { peers: vec!["not-a-peer".to_string()], ..manifest.clone() };
+        assert!(BootstrapManifest::verify(&publish(&malformed, &publisher), &publisher_key).is_err());
+
+        // One entry per node ID, the user's address winning
+        let user = vec![peer(2, 4433)];
+        let built_in = peer(3, 31001);
+        let merged = merge_peers(&user, Some(&manifest), &[built_in.as_str(), manifest.peers[0].as_str()]);
+        assert_eq!(merged, [peer(2, 4433), peer(1, 31001), built_in]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_stops_at_the_size_cap() {
+        // Chunked replies carry no length, so only counting the body catches them
+        async fn serve(body_len: usize) -> String {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/bootstrap.json", listener.local_addr().unwrap());
+            tokio::spawn(async move {
+                use tokio::io::{AsyncReadExt, AsyncWriteExt};
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let _ = stream.read(&mut [0; 1024]).await;
+                let mut reply = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n".to_vec();
+                for chunk in vec![b'x'; body_len].chunks(8 * 1024) {
+                    reply.extend_from_slice(format!("{:x}\r\n", chunk.len()).as_bytes());
+                    reply.extend_from_slice(chunk);
+                    reply.extend_from_slice(b"\r\n");
+                }
+                reply.extend_from_slice(b"0\r\n\r\n");
+                let _ = stream.write_all(&reply).await;
+            });
+            url
+        }
+
+        assert_eq!(fetch(&serve(MAX_MANIFEST_BYTES).await).await.unwrap().len(), MAX_MANIFEST_BYTES);
+        let err = fetch(&serve(MAX_MANIFEST_BYTES + 1).await).await.unwrap_err();
+        assert!(err.to_string().contains("exceeds"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_host_names() {
+        check_addr("bootstrap.cyberfly.io:31001").unwrap();
+        check_addr("[2001:db8::1]:31001").unwrap();
+        for invalid in ["bootstrap.cyberfly.io", "bootstrap.cyberfly.io:http", ":31001", "boot strap.io:31001", "a..b:1"] {
+            assert!(check_addr(invalid).is_err(), "{}", invalid);
+        }
+
+        let node_id = iroh::SecretKey::from_bytes(&[1; 32]).public();
+        let peer = format!("{}@bootstrap.cyberfly.io:31001", node_id);
+        let (parsed_id, addr) = parse_peer(&peer).unwrap();
+        assert_eq!((parsed_id, addr), (node_id, "bootstrap.cyberfly.io:31001"));
+
+        assert_eq!(resolve("203.0.113.7:31001").await.unwrap(), ["203.0.113.7:31001".parse::<SocketAddr>().unwrap()]);
+        let localhost = resolve("localhost:31001").await.unwrap();
+        assert!(localhost.iter().all(|addr| addr.ip().is_loopback() && addr.port() == 31001));
+    }
+}
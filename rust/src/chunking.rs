@@ -0,0 +1,200 @@
+//! Chunking of oversized gossip messages
+//!
+//! Gossip drops messages over its max message size (see
+//! `NodeConfig::max_gossip_message_size`). Larger payloads on the data and
+//! sync topics are split into `Chunk` messages that each fit; receivers
+//! collect the chunks of a payload by its chunk ID, the hash of the whole
+//! payload, and hand it on once every chunk arrived and the hash matches.
+//! Payloads whose chunks don't all arrive within `CHUNK_TIMEOUT` are
+//! dropped. Older nodes don't parse chunk messages and ignore them.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Default max gossip message size, iroh-gossip's own default
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 4096;
+
+/// Smallest max message size chunks still fit in
+pub const MIN_MAX_MESSAGE_SIZE: usize = 1024;
+
+/// Most chunks one payload is split into
+pub const MAX_CHUNKS: usize = 256;
+
+/// How long the chunks of a payload may take to arrive
+pub const CHUNK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Payloads being reassembled at once; the oldest is dropped beyond that
+const MAX_PENDING: usize = 32;
+
+/// Bytes of a chunk message besides its data: tag, chunk ID and indices
+const CHUNK_OVERHEAD: usize = 160;
+
+/// Start of every serialized chunk message
+const CHUNK_PREFIX: &[u8] = br#"{"type":"Chunk","#;
+
+/// Error of payloads too large to send even in chunks
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("Message is too large for gossip: {len} bytes, at most {max} can be sent")]
+pub struct MessageTooLarge {
+    /// Size of the payload
+    pub len: usize,
+    /// Largest payload that can be sent
+    pub max: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum ChunkMessage {
+    Chunk {
+        /// Hex BLAKE3 hash of the whole payload
+        chunk_id: String,
+        index: u32,
+        total: u32,
+        /// Base64
+        data: String,
+    },
+}
+
+/// Payload bytes per chunk for messages of at most `max_message_size`
+fn chunk_data_len(max_message_size: usize) -> usize {
+    max_message_size.saturating_sub(CHUNK_OVERHEAD) / 4 * 3
+}
+
+/// Largest payload that can be sent with messages of at most
+/// `max_message_size`
+pub fn max_payload_len(max_message_size: usize) -> usize {
+    chunk_data_len(max_message_size) * MAX_CHUNKS
+}
+
+/// Messages to broadcast for `payload`: itself if it fits, or its chunks
+pub fn split(payload: &[u8], max_message_size: usize) -> Result<Vec<Vec<u8>>> {
+    if payload.len() <= max_message_size {
+        return Ok(vec![payload.to_vec()]);
+    }
+    if payload.len() > max_payload_len(max_message_size) {
+        return Err(MessageTooLarge { len: payload.len(), max: max_payload_len(max_message_size) }.into());
+    }
+    let chunk_id = blake3::hash(payload).to_hex().to_string();
+    let parts: Vec<&[u8]> = payload.chunks(chunk_data_len(max_message_size)).collect();
+    let total = parts.len() as u32;
+    parts
+        .into_iter()
+        .enumerate()
+        .map(|(index, data)| {
+            let chunk = ChunkMessage::Chunk {
+                chunk_id: chunk_id.clone(),
+                index: index as u32,
+                total,
+                data: base64::engine::general_purpose::STANDARD.encode(data),
+            };
+            Ok(serde_json::to_vec(&chunk)?)
+        })
+        .collect()
+}
+
+/// Whether a received message is a chunk
+pub fn is_chunk(content: &[u8]) -> bool {
+    content.starts_with(CHUNK_PREFIX)
+}
+
+struct Partial {
+    parts: Vec<Option<Vec<u8>>>,
+    received: usize,
+    started: Instant,
+}
+
+/// Chunks received on one topic, by chunk ID
+#[derive(Default)]
+pub struct Reassembler {
+    pending: HashMap<String, Partial>,
+}
+
+impl Reassembler {
+    /// Take a chunk; the whole payload once this was its last missing chunk
+    pub fn accept(&mut self, content: &[u8]) -> Option<Vec<u8>> {
+        self.accept_at(content, Instant::now())
+    }
+
+    fn accept_at(&mut self, content: &[u8], now: Instant) -> Option<Vec<u8>> {
+        self.pending.retain(|chunk_id, partial| {
+            let live = now.duration_since(partial.started) < CHUNK_TIMEOUT;
+            if !live {
+                warn!("Dropping message {}: {} of {} chunks arrived in time", chunk_id, partial.received, partial.parts.len());
+            }
+            live
+        });
+
+        let ChunkMessage::Chunk { chunk_id, index, total, data } = serde_json::from_slice(content).ok()?;
+        let (index, total) = (index as usize, total as usize);
+        if total == 0 || total > MAX_CHUNKS || index >= total {
+            return None;
+        }
+        let data = base64::engine::general_purpose::STANDARD.decode(data).ok()?;
+
+        if !self.pending.contains_key(&chunk_id) && self.pending.len() >= MAX_PENDING {
+            let oldest = self.pending.iter().min_by_key(|(_, partial)| partial.started).map(|(id, _)| id.clone());
+            if let Some(oldest) = oldest {
+                self.pending.remove(&oldest);
+            }
+        }
+        let partial = self.pending.entry(chunk_id.clone()).or_insert_with(|| Partial {
+            parts: vec![None; total],
+            received: 0,
+            started: now,
+        });
+        if partial.parts.len() != total || partial.parts[index].is_some() {
+            return None;
+        }
+        partial.parts[index] = Some(data);
+        partial.received += 1;
+        if partial.received < total {
+            return None;
+        }
+
+        let partial = self.pending.remove(&chunk_id)?;
+        let payload: Vec<u8> = partial.parts.into_iter().flatten().flatten().collect();
+        (blake3::hash(&payload).to_hex().as_str() == chunk_id).then_some(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_and_reassemble() {
+        let max = MIN_MAX_MESSAGE_SIZE;
+        assert_eq!(split(b"small", max).unwrap(), [b"small".to_vec()]);
+
+        let payload: Vec<u8> = (0..5000u32).map(|i| (i % 251) as u8).collect();
+        let chunks = split(&payload, max).unwrap();
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= max && is_chunk(chunk)));
+
+        // Any order, duplicates ignored
+        let mut reassembler = Reassembler::default();
+        let mut whole = None;
+        for chunk in chunks.iter().rev().chain(chunks.first()) {
+            if let Some(payload) = reassembler.accept(chunk) {
+                whole = Some(payload);
+            }
+        }
+        assert_eq!(whole.unwrap(), payload);
+
+        // Incomplete payloads time out
+        let start = Instant::now();
+        let mut reassembler = Reassembler::default();
+        assert!(reassembler.accept_at(&chunks[0], start).is_none());
+        assert!(reassembler.accept_at(&chunks[1], start + CHUNK_TIMEOUT).is_none());
+        assert_eq!(reassembler.pending[&blake3::hash(&payload).to_hex().to_string()].received, 1);
+
+        let too_large = vec![0u8; max_payload_len(max) + 1];
+        let error = split(&too_large, max).unwrap_err();
+        assert_eq!(error.downcast_ref(), Some(&MessageTooLarge { len: too_large.len(), max: max_payload_len(max) }));
+    }
+}
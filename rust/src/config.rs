@@ -0,0 +1,363 @@
+//! Node configuration
+//!
+//! `NodeConfig` is given to `CyberflyNode::start_with_config`. Region,
+//! network ID, relays, the bootstrap manifest and the number of remembered
+//! peers dialed only matter at startup; intervals and limits are read by the running node each time
+//! they're used and can be changed with `CyberflyNode::update_config`.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+
+use crate::chunking::{DEFAULT_MAX_MESSAGE_SIZE, MIN_MAX_MESSAGE_SIZE};
+use crate::store_types::StoreType;
+use crate::discovery::{ANNOUNCE_INTERVAL_SECS, MAX_KNOWN_PEERS, MIN_ANNOUNCEMENT_INTERVAL, PEER_EXPIRY_SECS};
+use crate::crypto::{self, MAX_MESSAGE_LENGTH};
+use crate::sync::{OperationLimits, OplogGcPolicy, MAX_OPS_PER_RESPONSE};
+
+/// Largest `max_ops_per_response`, keeping responses well under the gossip
+/// message size
+pub const MAX_OPS_PER_RESPONSE_LIMIT: usize = 512;
+
+/// Longest announce interval; peers forget peers silent for `PEER_EXPIRY_SECS`
+pub const MAX_ANNOUNCE_INTERVAL: Duration = Duration::from_secs(PEER_EXPIRY_SECS / 3);
+
+/// Shortest interval of the periodic checks
+const MIN_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Shortest interval between queued dials
+pub const MIN_DIAL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Relay servers that forward traffic to peers we can't reach directly
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum RelayConfig {
+    /// n0's public relays
+    #[default]
+    Default,
+    /// No relays, only direct connections, e.g. for LAN-only deployments
+    Disabled,
+    /// These relay URLs instead of n0's, e.g. relays run next to the bootstrap
+    /// nodes
+    Custom(Vec<String>),
+}
+
+/// Where a signed list of bootstrap peers is published, see `bootstrap`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BootstrapManifestSource {
+    /// HTTPS URL of the manifest
+    pub url: String,
+    /// Public key (hex) the manifest must be signed with
+    pub publisher_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeConfig {
+    /// Region advertised to peers (startup only)
+    pub region: Option<String>,
+    /// Application network to join, see `start_node_in_network` (startup only)
+    pub network_id: Option<String>,
+    /// Remembered peers dialed on startup, most recently seen first (startup only)
+    pub max_known_peer_dials: usize,
+    /// How often we announce ourselves on the discovery topics
+    pub announce_interval: Duration,
+    /// How often every active peer's latency is measured
+    pub latency_sweep_interval: Duration,
+    /// How often pinned peers that aren't gossip neighbors are redialed
+    pub pinned_peers_check_interval: Duration,
+    /// How often the node checks whether it has any gossip neighbors
+    pub isolation_check_interval: Duration,
+    /// Consecutive isolated checks before bootstrap peers are redialed
+    pub isolation_checks_before_reconnect: u32,
+    /// Operations per sync response chunk
+    pub max_ops_per_response: usize,
+    /// Bootstrap reconnect attempts per 30 second cycle
+    pub max_connection_attempts_per_cycle: u32,
+    /// How often the next queued peer is dialed (see `dial_queue`)
+    pub dial_interval: Duration,
+    /// Relay servers (startup only)
+    pub relay: RelayConfig,
+    /// Signed bootstrap peer list fetched at startup, in addition to the
+    /// built-in peers (startup only)
+    pub bootstrap_manifest: Option<BootstrapManifestSource>,
+    /// Operations from peers exceeding these are rejected
+    pub operation_limits: OperationLimits,
+    /// Superseded operations removed from the oplog by the periodic sweep;
+    /// None keeps them all
+    pub oplog_gc: Option<OplogGcPolicy>,
+    /// Largest gossip message; larger data and sync messages are sent in
+    /// chunks. Peers drop messages over their own limit (startup only)
+    pub max_gossip_message_size: usize,
+}
+
+impl Default for NodeConfig {
+    fn default() -> Self {
+        Self {
+            region: None,
+            network_id: None,
+            max_known_peer_dials: 8,
+            announce_interval: Duration::from_secs(ANNOUNCE_INTERVAL_SECS),
+            latency_sweep_interval: Duration::from_secs(5 * 60),
+            pinned_peers_check_interval: Duration::from_secs(30),
+            isolation_check_interval: Duration::from_secs(30),
+            isolation_checks_before_reconnect: 3,
+            max_ops_per_response: MAX_OPS_PER_RESPONSE,
+            max_connection_attempts_per_cycle: 8,
+            dial_interval: Duration::from_secs(2),
+            relay: RelayConfig::Default,
+            bootstrap_manifest: None,
+            operation_limits: OperationLimits::default(),
+            oplog_gc: Some(OplogGcPolicy::default()),
+            max_gossip_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+}
+
+impl NodeConfig {
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn with_network_id(mut self, network_id: impl Into<String>) -> Self {
+        self.network_id = Some(network_id.into());
+        self
+    }
+
+    pub fn with_max_known_peer_dials(mut self, dials: usize) -> Self {
+        self.max_known_peer_dials = dials;
+        self
+    }
+
+    pub fn with_announce_interval(mut self, interval: Duration) -> Self {
+        self.announce_interval = interval;
+        self
+    }
+
+    pub fn with_latency_sweep_interval(mut self, interval: Duration) -> Self {
+        self.latency_sweep_interval = interval;
+        self
+    }
+
+    pub fn with_pinned_peers_check_interval(mut self, interval: Duration) -> Self {
+        self.pinned_peers_check_interval = interval;
+        self
+    }
+
+    /// Check every `interval` and redial bootstrap peers after `checks`
+    /// isolated checks in a row
+    pub fn with_isolation_check(mut self, interval: Duration, checks: u32) -> Self {
+        self.isolation_check_interval = interval;
+        self.isolation_checks_before_reconnect = checks;
+        self
+    }
+
+    pub fn with_max_ops_per_response(mut self, max_ops: usize) -> Self {
+        self.max_ops_per_response = max_ops;
+        self
+    }
+
+    pub fn with_max_connection_attempts_per_cycle(mut self, attempts: u32) -> Self {
+        self.max_connection_attempts_per_cycle = attempts;
+        self
+    }
+
+    pub fn with_dial_interval(mut self, interval: Duration) -> Self {
+        self.dial_interval = interval;
+        self
+    }
+
+    pub fn with_relay(mut self, relay: RelayConfig) -> Self {
+        self.relay = relay;
+        self
+    }
+
+    pub fn with_bootstrap_manifest(mut self, url: impl Into<String>, publisher_key: impl Into<String>) -> Self {
+        self.bootstrap_manifest = Some(BootstrapManifestSource { url: url.into(), publisher_key: publisher_key.into() });
+        self
+    }
+
+    pub fn with_operation_limits(mut self, limits: OperationLimits) -> Self {
+        self.operation_limits = limits;
+        self
+    }
+
+    pub fn with_oplog_gc(mut self, policy: Option<OplogGcPolicy>) -> Self {
+        self.oplog_gc = policy;
+        self
+    }
+
+    pub fn with_max_gossip_message_size(mut self, size: usize) -> Self {
+        self.max_gossip_message_size = size;
+        self
+    }
+
+    /// Reject values that would break the node or its peers
+    pub fn validate(&self) -> Result<()> {
+        if self.network_id.as_ref().is_some_and(|id| id.trim().is_empty()) {
+            return Err(anyhow!("Network ID is empty"));
+        }
+        if self.max_known_peer_dials > MAX_KNOWN_PEERS {
+            return Err(anyhow!("At most {} known peers can be dialed", MAX_KNOWN_PEERS));
+        }
+        // Peers drop announcements arriving faster than MIN_ANNOUNCEMENT_INTERVAL
+        if self.announce_interval < MIN_ANNOUNCEMENT_INTERVAL || self.announce_interval > MAX_ANNOUNCE_INTERVAL {
+            return Err(anyhow!(
+                "Announce interval must be between {:?} and {:?}",
+                MIN_ANNOUNCEMENT_INTERVAL,
+                MAX_ANNOUNCE_INTERVAL
+            ));
+        }
+        let intervals = [
+            ("Latency sweep", self.latency_sweep_interval),
+            ("Pinned peer check", self.pinned_peers_check_interval),
+            ("Isolation check", self.isolation_check_interval),
+        ];
+        if let Some((name, _)) = intervals.iter().find(|(_, interval)| *interval < MIN_CHECK_INTERVAL) {
+            return Err(anyhow!("{} interval must be at least {:?}", name, MIN_CHECK_INTERVAL));
+        }
+        if self.isolation_checks_before_reconnect == 0 {
+            return Err(anyhow!("Isolation checks before reconnect must be at least 1"));
+        }
+        if self.max_ops_per_response == 0 || self.max_ops_per_response > MAX_OPS_PER_RESPONSE_LIMIT {
+            return Err(anyhow!("Max ops per response must be between 1 and {}", MAX_OPS_PER_RESPONSE_LIMIT));
+        }
+        if self.max_connection_attempts_per_cycle == 0 {
+            return Err(anyhow!("Max connection attempts per cycle must be at least 1"));
+        }
+        if self.dial_interval < MIN_DIAL_INTERVAL {
+            return Err(anyhow!("Dial interval must be at least {:?}", MIN_DIAL_INTERVAL));
+        }
+        let limits = &self.operation_limits;
+        // Larger values couldn't be signed anyway
+        if limits.max_value_bytes == 0 || limits.max_value_bytes > MAX_MESSAGE_LENGTH {
+            return Err(anyhow!("Max value bytes must be between 1 and {}", MAX_MESSAGE_LENGTH));
+        }
+        if limits.max_key_len == 0 {
+            return Err(anyhow!("Max key length must be at least 1"));
+        }
+        if limits.store_types.is_empty() {
+            return Err(anyhow!("At least one store type must be accepted"));
+        }
+        for store_type in &limits.store_types {
+            store_type.parse::<StoreType>()?;
+        }
+        // The current version of a key is never collected
+        if self.oplog_gc.as_ref().is_some_and(|policy| policy.keep_versions == 0) {
+            return Err(anyhow!("Oplog GC must keep at least 1 version per key"));
+        }
+        if self.max_gossip_message_size < MIN_MAX_MESSAGE_SIZE || self.max_gossip_message_size > MAX_MESSAGE_LENGTH {
+            return Err(anyhow!("Max gossip message size must be between {} and {}", MIN_MAX_MESSAGE_SIZE, MAX_MESSAGE_LENGTH));
+        }
+        if let RelayConfig::Custom(urls) = &self.relay {
+            if urls.is_empty() {
+                return Err(anyhow!("Custom relays need at least one URL"));
+            }
+            for url in urls {
+                let parsed = reqwest::Url::parse(url).map_err(|e| anyhow!("Invalid relay URL {}: {}", url, e))?;
+                if !matches!(parsed.scheme(), "http" | "https") {
+                    return Err(anyhow!("Relay URL {} must be http or https", url));
+                }
+            }
+        }
+        if let Some(source) = &self.bootstrap_manifest {
+            let parsed = reqwest::Url::parse(&source.url)
+                .map_err(|e| anyhow!("Invalid bootstrap manifest URL {}: {}", source.url, e))?;
+            if parsed.scheme() != "https" {
+                return Err(anyhow!("Bootstrap manifest URL {} must be https", source.url));
+            }
+            if crypto::secure_hex_decode(&source.publisher_key).map_or(true, |key| key.len() != 32) {
+                return Err(anyhow!("Bootstrap manifest publisher key must be 32 bytes of hex"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that `update` only changes what the running node can apply
+    pub fn check_update(&self, update: &NodeConfig) -> Result<()> {
+        update.validate()?;
+        if update.region != self.region {
+            return Err(anyhow!("Region can only be set when the node starts"));
+        }
+        if update.network_id != self.network_id {
+            return Err(anyhow!("Network ID can only be set when the node starts"));
+        }
+        if update.max_known_peer_dials != self.max_known_peer_dials {
+            return Err(anyhow!("Known peer dials can only be set when the node starts"));
+        }
+        if update.relay != self.relay {
+            return Err(anyhow!("Relays can only be set when the node starts"));
+        }
+        if update.bootstrap_manifest != self.bootstrap_manifest {
+            return Err(anyhow!("The bootstrap manifest can only be set when the node starts"));
+        }
+        if update.max_gossip_message_size != self.max_gossip_message_size {
+            return Err(anyhow!("Max gossip message size can only be set when the node starts"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_config() {
+        let config = NodeConfig::default().with_region("eu").with_network_id("chat-app");
+        config.validate().unwrap();
+
+        assert!(NodeConfig::default().with_network_id(" ").validate().is_err());
+        assert!(NodeConfig::default().with_announce_interval(Duration::from_secs(1)).validate().is_err());
+        assert!(NodeConfig::default().with_announce_interval(Duration::from_secs(PEER_EXPIRY_SECS)).validate().is_err());
+        assert!(NodeConfig::default().with_isolation_check(Duration::from_secs(30), 0).validate().is_err());
+        assert!(NodeConfig::default().with_max_ops_per_response(0).validate().is_err());
+        assert!(NodeConfig::default()
+            .with_max_ops_per_response(MAX_OPS_PER_RESPONSE_LIMIT + 1)
+            .validate()
+            .is_err());
+        let relays = |urls: &[&str]| RelayConfig::Custom(urls.iter().map(|url| url.to_string()).collect());
+        NodeConfig::default().with_relay(relays(&["https://relay.cyberfly.io"])).validate().unwrap();
+        NodeConfig::default().with_relay(RelayConfig::Disabled).validate().unwrap();
+        assert!(NodeConfig::default().with_relay(relays(&[])).validate().is_err());
+        assert!(NodeConfig::default().with_relay(relays(&["relay.cyberfly.io"])).validate().is_err());
+        assert!(NodeConfig::default().with_relay(relays(&["ftp://relay.cyberfly.io"])).validate().is_err());
+        let publisher_key = "ab".repeat(32);
+        let manifest = |url: &str, key: &str| NodeConfig::default().with_bootstrap_manifest(url, key);
+        manifest("https://cyberfly.io/bootstrap.json", &publisher_key).validate().unwrap();
+        assert!(manifest("http://cyberfly.io/bootstrap.json", &publisher_key).validate().is_err());
+        assert!(manifest("https://cyberfly.io/bootstrap.json", "abcd").validate().is_err());
+        let limits = OperationLimits::default();
+        let with_limits = |limits: OperationLimits| NodeConfig::default().with_operation_limits(limits);
+        assert!(with_limits(OperationLimits { max_value_bytes: MAX_MESSAGE_LENGTH + 1, ..limits.clone() }).validate().is_err());
+        assert!(with_limits(OperationLimits { max_key_len: 0, ..limits.clone() }).validate().is_err());
+        assert!(with_limits(OperationLimits { store_types: Vec::new(), ..limits.clone() }).validate().is_err());
+        assert!(with_limits(OperationLimits { store_types: vec!["Document".to_string()], ..limits.clone() }).validate().is_err());
+        assert!(NodeConfig::default().with_max_gossip_message_size(512).validate().is_err());
+        assert!(NodeConfig::default().with_dial_interval(Duration::from_millis(10)).validate().is_err());
+        let gc = OplogGcPolicy { keep_versions: 0, ..OplogGcPolicy::default() };
+        assert!(NodeConfig::default().with_oplog_gc(Some(gc)).validate().is_err());
+        NodeConfig::default().with_oplog_gc(None).validate().unwrap();
+
+        // Runtime changes
+        let update = config
+            .clone()
+            .with_announce_interval(Duration::from_secs(30))
+            .with_max_ops_per_response(64)
+            .with_isolation_check(Duration::from_secs(10), 5)
+            .with_dial_interval(Duration::from_millis(500))
+            .with_oplog_gc(None)
+            .with_operation_limits(OperationLimits { max_value_bytes: 4096, ..limits });
+        config.check_update(&update).unwrap();
+        assert!(config.check_update(&update.clone().with_latency_sweep_interval(Duration::ZERO)).is_err());
+
+        // Startup-only changes
+        assert!(config.check_update(&config.clone().with_region("us")).is_err());
+        assert!(config.check_update(&config.clone().with_network_id("notes-app")).is_err());
+        assert!(config.check_update(&config.clone().with_max_known_peer_dials(2)).is_err());
+        assert!(config.check_update(&config.clone().with_relay(RelayConfig::Disabled)).is_err());
+        assert!(config
+            .check_update(&config.clone().with_bootstrap_manifest("https://cyberfly.io/bootstrap.json", &publisher_key))
+            .is_err());
+        assert!(config.check_update(&config.clone().with_max_gossip_message_size(64 * 1024)).is_err());
+    }
+}
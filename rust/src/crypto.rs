@@ -7,7 +7,11 @@
 //! We do NOT pre-hash messages before signing - messages are signed directly.
 
 use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine as _};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
 use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 // Security constants (matching cyberfly-rust-node)
@@ -17,6 +21,12 @@ pub const MAX_MESSAGE_LENGTH: usize = 1024 * 1024; // 1MB max message size
 pub const MIN_TIMESTAMP_TOLERANCE: u64 = 300; // 5 minutes in seconds
 pub const MAX_TIMESTAMP_TOLERANCE: u64 = 3600; // 1 hour in seconds
 
+// Encrypted database constants
+pub const DB_KEY_LENGTH: usize = 32;
+pub const XNONCE_LENGTH: usize = 24;
+/// Marks a value as XChaCha20-Poly1305 ciphertext: enc:v1:<base64(nonce || ciphertext)>
+pub const ENCRYPTED_VALUE_PREFIX: &str = "enc:v1:";
+
 // Security error messages
 pub const INVALID_PUBLIC_KEY_LENGTH: &str = "Invalid public key length - must be 32 bytes";
 pub const INVALID_SIGNATURE_LENGTH: &str = "Invalid signature length - must be 64 bytes";
@@ -90,7 +100,7 @@ pub fn secure_hex_decode(hex_str: &str) -> Result<Vec<u8>> {
     }
     
     // Validate hex string format
-    if hex_str.len() % 2 != 0 {
+    if !hex_str.len().is_multiple_of(2) {
         return Err(anyhow!(MALFORMED_HEX_ENCODING));
     }
     
@@ -159,9 +169,7 @@ pub fn verify_db_name_secure(db_name: &str, public_key_hex: &str) -> Result<()>
     secure_hex_decode(public_key_hex)?;
     
     // Check database name format
-    if !db_name.ends_with(&format!("-{}", public_key_hex)) {
-        return Err(anyhow!("Database name does not match public key"));
-    }
+    verify_db_name(db_name, public_key_hex)?;
     
     // Extract and validate the name part
     let name_part = extract_name_from_db(db_name)
@@ -187,12 +195,66 @@ pub fn generate_keypair() -> (SigningKey, String) {
     // Instead, draw 32 random bytes ourselves and feed them to `from_bytes`.
     use rand::RngCore;
     let mut seed = [0u8; 32];
-    rand::thread_rng().fill_bytes(&mut seed);
+    rand::rng().fill_bytes(&mut seed);
     let signing_key = SigningKey::from_bytes(&seed);
     let public_key_hex = hex::encode(signing_key.verifying_key().as_bytes());
     (signing_key, public_key_hex)
 }
 
+/// Generate a random symmetric key for an encrypted database
+pub fn generate_db_key() -> [u8; DB_KEY_LENGTH] {
+    use rand::RngCore;
+    let mut key = [0u8; DB_KEY_LENGTH];
+    rand::rng().fill_bytes(&mut key);
+    key
+}
+
+/// Associated data binding a ciphertext to its location, so an encrypted
+/// value can't be replayed under another key or database
+pub fn encryption_aad(db_name: &str, key: &str) -> String {
+    format!("{}:{}", db_name, key)
+}
+
+/// Encrypt a value with XChaCha20-Poly1305 under a random nonce
+pub fn encrypt_value(db_key: &[u8; DB_KEY_LENGTH], aad: &[u8], plaintext: &[u8]) -> Result<String> {
+    use rand::RngCore;
+    let mut nonce = [0u8; XNONCE_LENGTH];
+    rand::rng().fill_bytes(&mut nonce);
+
+    let cipher = XChaCha20Poly1305::new(db_key.into());
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), Payload { msg: plaintext, aad })
+        .map_err(|_| anyhow!("Encryption failed"))?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+    Ok(format!("{}{}", ENCRYPTED_VALUE_PREFIX, general_purpose::STANDARD.encode(sealed)))
+}
+
+/// Decrypt a value produced by `encrypt_value`
+pub fn decrypt_value(db_key: &[u8; DB_KEY_LENGTH], aad: &[u8], encoded: &str) -> Result<Vec<u8>> {
+    let body = encoded
+        .strip_prefix(ENCRYPTED_VALUE_PREFIX)
+        .ok_or_else(|| anyhow!("Value is not encrypted"))?;
+    let sealed = general_purpose::STANDARD
+        .decode(body)
+        .map_err(|e| anyhow!("Malformed encrypted value: {}", e))?;
+    if sealed.len() < XNONCE_LENGTH {
+        return Err(anyhow!("Malformed encrypted value: too short"));
+    }
+
+    let (nonce, ciphertext) = sealed.split_at(XNONCE_LENGTH);
+    let cipher = XChaCha20Poly1305::new(db_key.into());
+    cipher
+        .decrypt(XNonce::from_slice(nonce), Payload { msg: ciphertext, aad })
+        .map_err(|_| anyhow!("Decryption failed (wrong key or tampered value)"))
+}
+
+/// Whether a stored value is ciphertext from `encrypt_value`
+pub fn is_encrypted_value(value: &str) -> bool {
+    value.starts_with(ENCRYPTED_VALUE_PREFIX)
+}
+
 /// Convert secret key bytes to SigningKey
 pub fn secret_to_signing_key(secret_bytes: &[u8; 32]) -> SigningKey {
     SigningKey::from_bytes(secret_bytes)
@@ -203,6 +265,16 @@ pub fn public_key_hex(signing_key: &SigningKey) -> String {
     hex::encode(signing_key.verifying_key().as_bytes())
 }
 
+/// BLAKE3 hash of `data` (hex), for content addressing
+pub fn blake3_hex(data: &[u8]) -> String {
+    blake3::hash(data).to_hex().to_string()
+}
+
+/// SHA-256 hash of `data` (hex)
+pub fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,6 +358,34 @@ mod tests {
         assert!(verify_db_name(&db_name, &other_key).is_err());
     }
 
+    #[test]
+    fn test_encrypt_decrypt_value() {
+        let db_key = generate_db_key();
+        let aad = encryption_aad("notes", "k1");
+
+        let sealed = encrypt_value(&db_key, aad.as_bytes(), b"secret").unwrap();
+        assert!(is_encrypted_value(&sealed));
+        assert_eq!(decrypt_value(&db_key, aad.as_bytes(), &sealed).unwrap(), b"secret");
+
+        // Wrong key, or the value moved to another key, must not decrypt
+        let other_key = generate_db_key();
+        assert!(decrypt_value(&other_key, aad.as_bytes(), &sealed).is_err());
+        let moved = encryption_aad("notes", "k2");
+        assert!(decrypt_value(&db_key, moved.as_bytes(), &sealed).is_err());
+    }
+
+    #[test]
+    fn test_content_hashes() {
+        assert_eq!(sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(blake3_hex(b"abc"), "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85");
+
+        // Binary payloads sign as-is
+        let (signing_key, public_key_hex) = generate_keypair();
+        let payload = [0xffu8, 0x00, 0xc3, 0x28];
+        let signature = sign_message(&signing_key, &payload);
+        assert!(verify_signature(&public_key_hex, &payload, &signature).unwrap());
+    }
+
     #[test]
     fn test_extract_name_from_db() {
         let db_name = "myapp-abc123";
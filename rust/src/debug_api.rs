@@ -0,0 +1,147 @@
+//! HTTP API for inspecting a running node
+//!
+//! Opt-in and bound to loopback only, so developers can look at a phone's
+//! node from a browser or scripts through `adb forward tcp:<port> tcp:<port>`
+//! without building UI for everything. Any app on the device can reach
+//! loopback, so every request needs `Authorization: Bearer <token>` with the
+//! random token made when the server starts. The REST endpoints only list
+//! key names; GraphQL returns values. Its one write path, `submitData`, is
+//! left out unless the app starts the server with writes allowed, and even
+//! then only takes operations signed by a writer of the database.
+//!
+//! - `GET /status`
+//! - `GET /peers`
+//! - `GET /databases`
+//! - `GET /databases/<db>/keys?prefix=&limit=&cursor=`
+//! - `GET /logs?limit=`
+//! - `GET /metrics` (Prometheus text format)
+//! - `POST /graphql` (see `graphql`)
+
+use std::sync::Arc;
+
+use rand::RngCore;
+use serde_json::{json, Value};
+
+use crate::graphql;
+use crate::http::{HttpRequest, HttpResponse};
+use crate::node::CyberflyNode;
+
+/// Keys listed per page by default
+const DEFAULT_KEY_LIMIT: usize = 100;
+
+/// Most keys listed per page
+const MAX_KEY_LIMIT: usize = 1000;
+
+const DEFAULT_LOG_LIMIT: usize = 100;
+
+/// A new random token for requests to carry
+pub fn generate_token() -> String {
+    let mut token = [0u8; 32];
+    rand::rng().fill_bytes(&mut token);
+    hex::encode(token)
+}
+
+/// Whether `request` carries `token`; compared in constant time
+fn authorized(request: &HttpRequest, token: &str) -> bool {
+    request
+        .bearer_token()
+        .is_some_and(|given| blake3::hash(given.as_bytes()) == blake3::hash(token.as_bytes()))
+}
+
+/// Answer `request` if it carries `token`; `logs(limit)` gives the most
+/// recent log entries. GraphQL mutations are served only if `allow_writes`.
+pub async fn route(
+    node: Arc<CyberflyNode>,
+    token: &str,
+    allow_writes: bool,
+    logs: &(dyn Fn(usize) -> Value + Send + Sync),
+    request: HttpRequest,
+) -> HttpResponse {
+    if !authorized(&request, token) {
+        return HttpResponse::error("401 Unauthorized", "missing or wrong bearer token".to_string());
+    }
+    let segments: Vec<&str> = request.segments.iter().map(String::as_str).collect();
+    if segments == ["graphql"] {
+        if !request.is_post() {
+            return HttpResponse::method_not_allowed();
+        }
+        return match graphql::execute_json(node.clone(), &request.body, allow_writes).await {
+            Ok(body) => HttpResponse { status: "200 OK", content_type: "application/json", body },
+            Err(e) => HttpResponse::error("400 Bad Request", e.to_string()),
+        };
+    }
+    if !request.is_get() {
+        return HttpResponse::method_not_allowed();
+    }
+    let node = node.as_ref();
+    let result = match segments.as_slice() {
+        [] => Ok(HttpResponse::json(&json!({
+            "endpoints": ["/status", "/peers", "/databases", "/databases/<db>/keys", "/logs", "/metrics", "/graphql"],
+        }))),
+        ["status"] => Ok(HttpResponse::json(&status(node))),
+        ["peers"] => Ok(HttpResponse::json(&peers(node))),
+        ["databases"] => node.list_databases().map(|dbs| HttpResponse::json(&dbs)).map_err(|e| e.to_string()),
+        ["databases", db_name, "keys"] => keys(node, db_name, &request),
+        ["logs"] => request
+            .query_usize("limit", DEFAULT_LOG_LIMIT)
+            .map(|limit| HttpResponse::json(&logs(limit))),
+        ["metrics"] => Ok(HttpResponse {
+            content_type: "text/plain; version=0.0.4",
+            ..HttpResponse::text(node.metrics().to_prometheus())
+        }),
+        _ => return HttpResponse::not_found(),
+    };
+    result.unwrap_or_else(|message| HttpResponse::error("400 Bad Request", message))
+}
+
+fn status(node: &CyberflyNode) -> Value {
+    let status = node.get_status_sync();
+    json!({
+        "node_id": status.node_id,
+        "is_running": status.is_running,
+        "uptime_seconds": status.uptime_seconds,
+        "connected_peers": status.connected_peers,
+        "discovered_peers": status.discovered_peers,
+        "gossip_messages_received": status.gossip_messages_received,
+        "storage_size_bytes": status.storage_size_bytes,
+        "total_keys": status.total_keys,
+        "total_operations": status.total_operations,
+        "sync_operations": status.sync_operations,
+        "outbox_depth": status.outbox_depth,
+        "session": status.session,
+        "lifetime": status.lifetime,
+        "topics": status.topics,
+    })
+}
+
+fn peers(node: &CyberflyNode) -> Value {
+    node.get_peers_sync()
+        .iter()
+        .map(|peer| {
+            json!({
+                "node_id": peer.node_id,
+                "public_key": peer.public_key,
+                "name": peer.name,
+                "address": peer.address,
+                "region": peer.region,
+                "version": peer.version,
+                "capabilities": peer.capabilities,
+                "latency_ms": peer.average_latency_ms(),
+                "expired": peer.is_expired(),
+            })
+        })
+        .collect()
+}
+
+fn keys(node: &CyberflyNode, db_name: &str, request: &HttpRequest) -> Result<HttpResponse, String> {
+    // Don't create trees for databases we don't have
+    if !node.list_databases().map_err(|e| e.to_string())?.iter().any(|db| db == db_name) {
+        return Ok(HttpResponse::not_found());
+    }
+    let prefix = request.query.get("prefix").map(String::as_str).unwrap_or_default();
+    let limit = request.query_usize("limit", DEFAULT_KEY_LIMIT)?.clamp(1, MAX_KEY_LIMIT);
+    let cursor = request.query.get("cursor").map(String::as_str);
+    let page = node.scan_prefix(db_name, prefix, limit, cursor).map_err(|e| e.to_string())?;
+    let keys: Vec<&str> = page.entries.iter().map(|(key, _)| key.as_str()).collect();
+    Ok(HttpResponse::json(&json!({ "keys": keys, "next_cursor": page.next_cursor })))
+}
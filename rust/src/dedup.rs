@@ -0,0 +1,89 @@
+//! Duplicate gossip suppression
+//!
+//! Gossip hands us a message once per neighbor that forwards it, so the
+//! same payload can arrive several times in a row. Each topic keeps the
+//! content hashes it received in the last `DEDUP_WINDOW`; copies within the
+//! window are dropped before they are parsed and verified. The window is
+//! short so deliberate repeats, e.g. a retried sync request, still get
+//! through.
+
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, Instant};
+
+/// How long a payload is remembered
+pub const DEDUP_WINDOW: Duration = Duration::from_secs(20);
+
+/// Most payloads remembered per topic; the oldest are forgotten first
+pub const DEDUP_CAPACITY: usize = 4096;
+
+/// Content hashes of the payloads received recently on one topic
+#[derive(Debug)]
+pub struct DedupWindow {
+    seen: HashSet<[u8; 32]>,
+    /// Arrival order, for expiry
+    order: VecDeque<(Instant, [u8; 32])>,
+    window: Duration,
+    capacity: usize,
+}
+
+impl Default for DedupWindow {
+    fn default() -> Self {
+        Self::new(DEDUP_WINDOW, DEDUP_CAPACITY)
+    }
+}
+
+impl DedupWindow {
+    pub fn new(window: Duration, capacity: usize) -> Self {
+        Self { seen: HashSet::new(), order: VecDeque::new(), window, capacity }
+    }
+
+    /// Whether `content` was received within the window; remembers it if not
+    pub fn is_duplicate(&mut self, content: &[u8]) -> bool {
+        self.is_duplicate_at(content, Instant::now())
+    }
+
+    fn is_duplicate_at(&mut self, content: &[u8], now: Instant) -> bool {
+        while self.order.front().is_some_and(|(at, _)| now.duration_since(*at) >= self.window) {
+            self.forget_oldest();
+        }
+        let hash = *blake3::hash(content).as_bytes();
+        if self.seen.contains(&hash) {
+            return true;
+        }
+        if self.order.len() >= self.capacity {
+            self.forget_oldest();
+        }
+        self.seen.insert(hash);
+        self.order.push_back((now, hash));
+        false
+    }
+
+    fn forget_oldest(&mut self) {
+        if let Some((_, hash)) = self.order.pop_front() {
+            self.seen.remove(&hash);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_window() {
+        let mut dedup = DedupWindow::new(Duration::from_secs(10), 2);
+        let start = Instant::now();
+        assert!(!dedup.is_duplicate_at(b"a", start));
+        assert!(dedup.is_duplicate_at(b"a", start + Duration::from_secs(1)));
+        assert!(!dedup.is_duplicate_at(b"b", start + Duration::from_secs(2)));
+
+        // Expired after the window
+        assert!(!dedup.is_duplicate_at(b"a", start + Duration::from_secs(11)));
+
+        // The oldest is forgotten once full
+        assert!(!dedup.is_duplicate_at(b"c", start + Duration::from_secs(12)));
+        assert!(!dedup.is_duplicate_at(b"d", start + Duration::from_secs(13)));
+        assert!(dedup.is_duplicate_at(b"c", start + Duration::from_secs(13)));
+        assert!(!dedup.is_duplicate_at(b"a", start + Duration::from_secs(13)));
+    }
+}
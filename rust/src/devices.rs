@@ -0,0 +1,222 @@
+//! Multi-device identity
+//!
+//! One user key (the key databases are named after) can span several
+//! devices, each with its own node key. The new device shows a `LinkRequest`
+//! as a QR code, signed by its node key; the primary device, which holds the
+//! user key, scans it and issues a `DeviceCertificate` signed by the user
+//! key that carries the request's signature too, so both keys vouch for the
+//! binding. Certificates are broadcast on the discovery topic by the devices
+//! they concern, and a device linked to a user key replicates every database
+//! the key owns, even in selective sync mode (see `SyncFilter::owners`).
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine as _};
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+
+use crate::crypto;
+use crate::storage::Storage;
+
+/// QR payload prefix, also identifies the encoding version
+pub const LINK_REQUEST_PREFIX: &str = "cyberfly-link:v1:";
+
+/// How long a link request can be scanned
+const LINK_REQUEST_TTL_MS: i64 = 10 * 60 * 1000;
+
+/// Longest device name accepted
+const MAX_DEVICE_NAME_LEN: usize = 64;
+
+/// A device asking to be linked, shown as QR code
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkRequest {
+    pub node_id: String,
+    pub device_name: String,
+    /// Unix ms
+    pub requested_at: i64,
+    /// By the device's node key
+    pub signature: String,
+}
+
+impl LinkRequest {
+    pub fn new(node_key: &SigningKey, device_name: &str) -> Result<Self> {
+        if device_name.is_empty() || device_name.len() > MAX_DEVICE_NAME_LEN {
+            return Err(anyhow!("Device names must be 1 to {} bytes", MAX_DEVICE_NAME_LEN));
+        }
+        let mut request = Self {
+            node_id: crypto::public_key_hex(node_key),
+            device_name: device_name.to_string(),
+            requested_at: chrono::Utc::now().timestamp_millis(),
+            signature: String::new(),
+        };
+        request.signature = crypto::sign_message(node_key, request.signing_message().as_bytes());
+        Ok(request)
+    }
+
+    fn signing_message(&self) -> String {
+        format!("link-request:{}:{}:{}", self.node_id, self.device_name, self.requested_at)
+    }
+
+    /// Encode as QR payload
+    pub fn to_payload(&self) -> Result<String> {
+        let encoded = general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(self)?);
+        Ok(format!("{}{}", LINK_REQUEST_PREFIX, encoded))
+    }
+
+    /// Decode a QR payload and check its signature and age
+    pub fn from_payload(payload: &str) -> Result<Self> {
+        let body = payload
+            .trim()
+            .strip_prefix(LINK_REQUEST_PREFIX)
+            .ok_or_else(|| anyhow!("Not a device link code"))?;
+        let json = general_purpose::URL_SAFE_NO_PAD
+            .decode(body)
+            .map_err(|e| anyhow!("Malformed device link code: {}", e))?;
+        let request: Self = serde_json::from_slice(&json)?;
+        if !request.verify()? {
+            return Err(anyhow!("Invalid device link code signature"));
+        }
+        if request.requested_at + LINK_REQUEST_TTL_MS < chrono::Utc::now().timestamp_millis() {
+            return Err(anyhow!("Device link code has expired"));
+        }
+        Ok(request)
+    }
+
+    fn verify(&self) -> Result<bool> {
+        if self.device_name.is_empty() || self.device_name.len() > MAX_DEVICE_NAME_LEN {
+            return Ok(false);
+        }
+        crypto::verify_signature(&self.node_id, self.signing_message().as_bytes(), &self.signature)
+    }
+}
+
+/// Statement by a user key that a device (node key) acts for it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceCertificate {
+    /// Public key (hex) the user's databases are named after
+    pub user_public_key: String,
+    pub request: LinkRequest,
+    /// Unix ms
+    pub linked_at: i64,
+    /// By the user key
+    pub signature: String,
+}
+
+impl DeviceCertificate {
+    /// Certify the device of `request` for `user_key`
+    pub fn issue(user_key: &SigningKey, request: LinkRequest) -> Result<Self> {
+        let user_public_key = crypto::public_key_hex(user_key);
+        if request.node_id == user_public_key {
+            return Err(anyhow!("A device can't be linked to its own key"));
+        }
+        let mut certificate =
+            Self { user_public_key, request, linked_at: chrono::Utc::now().timestamp_millis(), signature: String::new() };
+        certificate.signature = crypto::sign_message(user_key, certificate.signing_message().as_bytes());
+        Ok(certificate)
+    }
+
+    pub fn device_node_id(&self) -> &str {
+        &self.request.node_id
+    }
+
+    fn signing_message(&self) -> String {
+        format!(
+            "device:{}:{}:{}:{}",
+            self.user_public_key, self.request.node_id, self.request.requested_at, self.linked_at
+        )
+    }
+
+    /// Whether the user key and the device key both signed
+    pub fn verify(&self) -> Result<bool> {
+        Ok(self.request.verify()?
+            && crypto::verify_signature(&self.user_public_key, self.signing_message().as_bytes(), &self.signature)?)
+    }
+}
+
+/// Keep a received certificate if it concerns this node: one naming it, or
+/// one for a user key it is linked to. Returns whether it was new.
+pub fn save_certificate(storage: &Storage, node_id: &str, certificate: &DeviceCertificate) -> Result<bool> {
+    if certificate.device_node_id() != node_id && !linked_user_keys(storage)?.contains(&certificate.user_public_key) {
+        return Ok(false);
+    }
+    keep_certificate(storage, certificate)
+}
+
+/// Keep a verified certificate, e.g. one this node issued; returns whether
+/// it was new
+pub fn keep_certificate(storage: &Storage, certificate: &DeviceCertificate) -> Result<bool> {
+    if !certificate.verify()? {
+        return Err(anyhow!("Invalid device certificate signature"));
+    }
+    if storage.get_device_certificate(&certificate.user_public_key, certificate.device_node_id())?.is_some() {
+        return Ok(false);
+    }
+    storage.put_device_certificate(
+        &certificate.user_public_key,
+        certificate.device_node_id(),
+        &serde_json::to_vec(certificate)?,
+    )?;
+    Ok(true)
+}
+
+/// Certificates kept, oldest link first
+pub fn certificates(storage: &Storage) -> Result<Vec<DeviceCertificate>> {
+    let mut certificates: Vec<DeviceCertificate> = storage
+        .device_certificates()?
+        .into_iter()
+        .filter_map(|bytes| serde_json::from_slice(&bytes).ok())
+        .collect();
+    certificates.sort_by_key(|certificate| certificate.linked_at);
+    Ok(certificates)
+}
+
+/// User keys with linked devices known here; their databases are replicated
+pub fn linked_user_keys(storage: &Storage) -> Result<HashSet<String>> {
+    Ok(certificates(storage)?.into_iter().map(|certificate| certificate.user_public_key).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_device_linking() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        let (user_key, user_public_key) = crypto::generate_keypair();
+        let (device_key, device_node_id) = crypto::generate_keypair();
+
+        let payload = LinkRequest::new(&device_key, "Tablet").unwrap().to_payload().unwrap();
+        let request = LinkRequest::from_payload(&payload).unwrap();
+        assert_eq!(request.node_id, device_node_id);
+        assert!(LinkRequest::from_payload("cyberfly-invite:v1:abc").is_err());
+        let stale = LinkRequest { requested_at: 1, ..request.clone() };
+        assert!(LinkRequest::from_payload(&stale.to_payload().unwrap()).is_err());
+
+        let certificate = DeviceCertificate::issue(&user_key, request.clone()).unwrap();
+        assert!(certificate.verify().unwrap());
+        let (other_key, _) = crypto::generate_keypair();
+        let forged = DeviceCertificate { user_public_key: crypto::public_key_hex(&other_key), ..certificate.clone() };
+        assert!(!forged.verify().unwrap());
+        let renamed = DeviceCertificate {
+            request: LinkRequest { device_name: "Phone".to_string(), ..request },
+            ..certificate.clone()
+        };
+        assert!(!renamed.verify().unwrap());
+        assert!(DeviceCertificate::issue(&device_key, LinkRequest::new(&device_key, "Self").unwrap()).is_err());
+
+        // Only certificates concerning this node or its user keys are kept
+        let (third_key, _) = crypto::generate_keypair();
+        assert!(!save_certificate(&storage, &crypto::public_key_hex(&third_key), &certificate).unwrap());
+        assert!(save_certificate(&storage, &device_node_id, &forged).is_err());
+        assert!(save_certificate(&storage, &device_node_id, &certificate).unwrap());
+        assert!(!save_certificate(&storage, &device_node_id, &certificate).unwrap());
+        assert!(linked_user_keys(&storage).unwrap().contains(&user_public_key));
+
+        let sibling = DeviceCertificate::issue(&user_key, LinkRequest::new(&third_key, "Laptop").unwrap()).unwrap();
+        assert!(save_certificate(&storage, &device_node_id, &sibling).unwrap());
+        assert_eq!(certificates(&storage).unwrap(), [certificate, sibling]);
+    }
+}
@@ -0,0 +1,188 @@
+//! Connection diagnostics for field debugging
+//!
+//! Gathers what the node knows about reaching peers: our local and public
+//! addresses, the relay in use, each peer's dials and current path, and a
+//! guess at the NAT we're behind from how those connections turned out.
+
+use std::net::{IpAddr, SocketAddr};
+
+use crate::discovery::{ConnectionPath, DialRecord, PeerPath};
+
+/// Relay-only peers before hole punching is judged not to work
+const RELAY_ONLY_PEERS_FOR_VERDICT: usize = 2;
+
+/// Guess at the NAT in front of the node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NatType {
+    /// No connection settled yet to tell from
+    Unknown,
+    /// No public address was found; UDP is probably blocked, only relays work
+    NoPublicAddress,
+    /// Some peer was reached directly, so hole punching works
+    HolePunchable,
+    /// The NAT maps our socket to different ports per destination, which
+    /// defeats hole punching
+    Symmetric,
+    /// Several peers were only ever reached through a relay
+    RelayOnly,
+}
+
+impl NatType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NatType::Unknown => "unknown",
+            NatType::NoPublicAddress => "no_public_address",
+            NatType::HolePunchable => "hole_punchable",
+            NatType::Symmetric => "symmetric",
+            NatType::RelayOnly => "relay_only",
+        }
+    }
+}
+
+/// What we know about reaching one peer
+#[derive(Debug, Clone)]
+pub struct PeerDiagnostics {
+    pub node_id: String,
+    /// Paths of the current connection, None while not connected
+    pub path: Option<PeerPath>,
+    /// None if we never dialed the peer this session
+    pub dials: Option<DialRecord>,
+}
+
+impl PeerDiagnostics {
+    pub fn direct_established(&self) -> bool {
+        self.dials.as_ref().is_some_and(|dials| dials.direct_established)
+            || self.path.as_ref().is_some_and(|path| path.selected == Some(ConnectionPath::Direct))
+    }
+
+    fn relay_only(&self) -> bool {
+        !self.direct_established() && self.path.as_ref().is_some_and(|path| path.selected == Some(ConnectionPath::Relayed))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectionDiagnostics {
+    /// Addresses on private, loopback or link-local networks
+    pub local_addrs: Vec<String>,
+    /// Addresses as seen from the internet
+    pub public_addrs: Vec<String>,
+    /// Home relay, None when relays are disabled or none is reachable yet
+    pub relay_url: Option<String>,
+    pub nat_type: NatType,
+    /// By node_id
+    pub peers: Vec<PeerDiagnostics>,
+}
+
+impl ConnectionDiagnostics {
+    /// Combine our endpoint's addresses with the connection paths and dial
+    /// records of the peer registry
+    pub fn collect(
+        addrs: Vec<SocketAddr>,
+        relay_url: Option<String>,
+        paths: Vec<(String, PeerPath)>,
+        dials: Vec<(String, DialRecord)>,
+    ) -> Self {
+        let (local, public): (Vec<SocketAddr>, Vec<SocketAddr>) = addrs.into_iter().partition(|addr| is_local(&addr.ip()));
+
+        let mut peers: Vec<PeerDiagnostics> = paths
+            .into_iter()
+            .map(|(node_id, path)| PeerDiagnostics { node_id, path: Some(path), dials: None })
+            .collect();
+        for (node_id, record) in dials {
+            match peers.iter_mut().find(|peer| peer.node_id == node_id) {
+                Some(peer) => peer.dials = Some(record),
+                None => peers.push(PeerDiagnostics { node_id, path: None, dials: Some(record) }),
+            }
+        }
+        peers.sort_by(|a, b| a.node_id.cmp(&b.node_id));
+
+        Self {
+            local_addrs: local.iter().map(|addr| addr.to_string()).collect(),
+            public_addrs: public.iter().map(|addr| addr.to_string()).collect(),
+            relay_url,
+            nat_type: guess_nat_type(&public, &peers),
+            peers,
+        }
+    }
+}
+
+/// Whether `ip` is only reachable from the local network or host
+pub fn is_local(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => ip.is_private() || ip.is_loopback() || ip.is_link_local() || ip.is_unspecified(),
+        IpAddr::V6(ip) => ip.is_loopback() || ip.is_unique_local() || ip.is_unicast_link_local() || ip.is_unspecified(),
+    }
+}
+
+fn guess_nat_type(public_addrs: &[SocketAddr], peers: &[PeerDiagnostics]) -> NatType {
+    if public_addrs.is_empty() {
+        return NatType::NoPublicAddress;
+    }
+    if peers.iter().any(PeerDiagnostics::direct_established) {
+        return NatType::HolePunchable;
+    }
+    // One public IP showing up with several ports means the mapping depends
+    // on the destination
+    let varying_ports = public_addrs
+        .iter()
+        .any(|a| public_addrs.iter().any(|b| a.ip() == b.ip() && a.port() != b.port()));
+    if varying_ports {
+        return NatType::Symmetric;
+    }
+    if peers.iter().filter(|peer| peer.relay_only()).count() >= RELAY_ONLY_PEERS_FOR_VERDICT {
+        return NatType::RelayOnly;
+    }
+    NatType::Unknown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::PeerRegistry;
+
+    fn relayed() -> PeerPath {
+        PeerPath {
+            selected: Some(ConnectionPath::Relayed),
+            relay_url: Some("https://relay.example".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_connection_diagnostics() {
+        let addrs = |addrs: &[&str]| addrs.iter().map(|addr| addr.parse().unwrap()).collect::<Vec<SocketAddr>>();
+
+        let mut registry = PeerRegistry::new("local".to_string());
+        registry.record_dial("a", Some("timed out".to_string()));
+        registry.record_dial("b", None);
+        registry.set_connection_path("b", relayed());
+        registry.set_connection_path("c", relayed());
+
+        let diagnostics = ConnectionDiagnostics::collect(
+            addrs(&["192.168.1.5:4000", "203.0.113.7:4000"]),
+            None,
+            registry.connection_paths(),
+            registry.dial_records(),
+        );
+        assert_eq!(diagnostics.local_addrs, ["192.168.1.5:4000"]);
+        assert_eq!(diagnostics.public_addrs, ["203.0.113.7:4000"]);
+        let ids: Vec<&str> = diagnostics.peers.iter().map(|peer| peer.node_id.as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+        let a = diagnostics.peers[0].dials.as_ref().unwrap();
+        assert_eq!((a.attempts, a.failures, a.last_error.as_deref()), (1, 1, Some("timed out")));
+        assert!(diagnostics.peers[0].path.is_none());
+        assert_eq!(diagnostics.nat_type, NatType::RelayOnly);
+
+        // A direct path anywhere shows hole punching works
+        registry.set_connection_path("b", PeerPath { selected: Some(ConnectionPath::Direct), ..Default::default() });
+        registry.set_connection_path("b", relayed());
+        let diagnostics =
+            ConnectionDiagnostics::collect(addrs(&["203.0.113.7:4000"]), None, registry.connection_paths(), registry.dial_records());
+        assert!(diagnostics.peers[1].direct_established());
+        assert_eq!(diagnostics.nat_type, NatType::HolePunchable);
+
+        assert_eq!(guess_nat_type(&[], &[]), NatType::NoPublicAddress);
+        assert_eq!(guess_nat_type(&addrs(&["203.0.113.7:4000", "203.0.113.7:5123"]), &[]), NatType::Symmetric);
+        assert_eq!(guess_nat_type(&addrs(&["203.0.113.7:4000"]), &[]), NatType::Unknown);
+    }
+}
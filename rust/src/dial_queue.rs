@@ -0,0 +1,159 @@
+//! Queue of discovered peers to dial
+//!
+//! Discovery can turn up dozens of peers at once, and dialing them all right
+//! away keeps the radio busy. Peers from discovery and from earlier runs are
+//! queued instead and dialed one per `NodeConfig::dial_interval`, most
+//! important first: bootstrap peers, pinned peers, peers that are likely
+//! close (our region or a low measured latency), then the rest. The queue is
+//! saved so dials still pending when the node stops run after the next start.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Most peers queued at once; the least important are dropped beyond that
+pub const MAX_QUEUED_DIALS: usize = 256;
+
+/// Peers measured below this latency count as nearby
+pub const NEARBY_LATENCY_MS: u64 = 150;
+
+/// How urgently a peer is dialed, most urgent first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DialPriority {
+    Bootstrap,
+    Pinned,
+    /// In our region or measured with a low latency
+    Nearby,
+    Other,
+}
+
+impl DialPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DialPriority::Bootstrap => "bootstrap",
+            DialPriority::Pinned => "pinned",
+            DialPriority::Nearby => "nearby",
+            DialPriority::Other => "other",
+        }
+    }
+}
+
+/// A peer waiting to be dialed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueuedDial {
+    pub node_id: String,
+    pub address: Option<String>,
+    pub priority: DialPriority,
+    /// Unix ms
+    pub queued_at: i64,
+    /// How the peer was found, for logs
+    pub via: String,
+}
+
+impl QueuedDial {
+    /// Dials sort by this, the next one first
+    fn order(&self) -> (DialPriority, i64) {
+        (self.priority, self.queued_at)
+    }
+}
+
+/// Queued dials by node ID
+#[derive(Debug, Default)]
+pub struct DialQueue {
+    dials: HashMap<String, QueuedDial>,
+}
+
+impl DialQueue {
+    /// Queue restored from `dials()` of an earlier run
+    pub fn from_saved(saved: Vec<QueuedDial>) -> Self {
+        let mut queue = Self::default();
+        for dial in saved {
+            queue.push(dial);
+        }
+        queue
+    }
+
+    /// Queue a dial; whether the queue changed. A peer already queued keeps
+    /// its place, takes the more urgent priority and the newer address.
+    pub fn push(&mut self, dial: QueuedDial) -> bool {
+        if let Some(queued) = self.dials.get_mut(&dial.node_id) {
+            let before = queued.clone();
+            queued.priority = queued.priority.min(dial.priority);
+            if dial.address.is_some() {
+                queued.address = dial.address;
+            }
+            return *queued != before;
+        }
+        if self.dials.len() >= MAX_QUEUED_DIALS {
+            let Some(last) = self.dials.values().max_by_key(|queued| queued.order()) else {
+                return false;
+            };
+            if last.order() <= dial.order() {
+                return false;
+            }
+            let last = last.node_id.clone();
+            self.dials.remove(&last);
+        }
+        self.dials.insert(dial.node_id.clone(), dial);
+        true
+    }
+
+    /// Take the next dial
+    pub fn pop(&mut self) -> Option<QueuedDial> {
+        let next = self.dials.values().min_by_key(|queued| queued.order())?.node_id.clone();
+        self.dials.remove(&next)
+    }
+
+    pub fn len(&self) -> usize {
+        self.dials.len()
+    }
+
+    /// Queued dials, the next one first
+    pub fn dials(&self) -> Vec<QueuedDial> {
+        let mut dials: Vec<QueuedDial> = self.dials.values().cloned().collect();
+        dials.sort_by_key(QueuedDial::order);
+        dials
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dial(node_id: &str, priority: DialPriority, queued_at: i64) -> QueuedDial {
+        QueuedDial { node_id: node_id.to_string(), address: None, priority, queued_at, via: "test".to_string() }
+    }
+
+    #[test]
+    fn test_dial_queue_order() {
+        let mut queue = DialQueue::default();
+        assert!(queue.push(dial("a", DialPriority::Other, 1)));
+        assert!(queue.push(dial("b", DialPriority::Nearby, 2)));
+        assert!(queue.push(dial("c", DialPriority::Other, 0)));
+        assert!(queue.push(dial("d", DialPriority::Bootstrap, 3)));
+
+        // Queued again: more urgent and with an address, same place otherwise
+        let mut again = dial("a", DialPriority::Pinned, 9);
+        again.address = Some("203.0.113.7:4433".to_string());
+        assert!(queue.push(again));
+        assert!(!queue.push(dial("a", DialPriority::Other, 10)));
+
+        let restored = DialQueue::from_saved(queue.dials());
+        assert_eq!(restored.dials(), queue.dials());
+
+        let order: Vec<String> = std::iter::from_fn(|| queue.pop()).map(|dial| dial.node_id).collect();
+        assert_eq!(order, ["d", "a", "b", "c"]);
+        assert_eq!(queue.len(), 0);
+
+        // A full queue drops its least important dial for a more important one
+        let mut queue = DialQueue::default();
+        for i in 0..MAX_QUEUED_DIALS {
+            queue.push(dial(&i.to_string(), DialPriority::Other, i as i64));
+        }
+        assert!(!queue.push(dial("late", DialPriority::Other, i64::MAX)));
+        assert!(queue.push(dial("pinned", DialPriority::Pinned, i64::MAX)));
+        assert_eq!(queue.len(), MAX_QUEUED_DIALS);
+        assert_eq!(queue.pop().unwrap().node_id, "pinned");
+        assert!(queue.dials().iter().all(|dial| dial.node_id != (MAX_QUEUED_DIALS - 1).to_string()));
+    }
+}
@@ -3,14 +3,19 @@
 //! This module handles peer discovery through signed announcements over gossip,
 //! matching the cyberfly-rust-node gossip_discovery implementation.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
 
+use crate::auth::VerifiedBinding;
 use crate::crypto;
+use crate::devices::DeviceCertificate;
+use crate::identity::SuccessorRecord;
+use crate::presence::PresenceHeartbeat;
+use crate::private_network::NetworkKey;
 
 /// How long before a peer is considered expired (no announcement)
 pub const PEER_EXPIRY_SECS: u64 = 300;
@@ -18,6 +23,29 @@ pub const PEER_EXPIRY_SECS: u64 = 300;
 /// How often to announce ourselves
 pub const ANNOUNCE_INTERVAL_SECS: u64 = 10;
 
+/// Most peers remembered across restarts (the most recently seen are kept)
+pub const MAX_KNOWN_PEERS: usize = 64;
+
+/// Known peers not seen for this long are forgotten
+pub const KNOWN_PEER_TTL_SECS: i64 = 7 * 24 * 3600;
+
+/// Announcements timestamped further than this from our clock are rejected,
+/// so captured announcements can't be replayed later
+pub const ANNOUNCEMENT_MAX_SKEW_MS: i64 = 5 * 60 * 1000;
+
+/// Minimum time between accepted announcements of one node
+pub const MIN_ANNOUNCEMENT_INTERVAL: Duration = Duration::from_secs(ANNOUNCE_INTERVAL_SECS / 2);
+
+/// Most nodes whose last announcement is remembered (least recently
+/// announced are forgotten first)
+pub const MAX_ANNOUNCEMENT_CACHE: usize = 1024;
+
+/// Peers whose dials are remembered; the least recently dialed go first
+pub const MAX_DIAL_RECORDS: usize = 512;
+
+/// Weight of a new sample in the rolling latency average
+const LATENCY_AVERAGE_WEIGHT: f64 = 0.25;
+
 /// Node capabilities
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct NodeCapabilities {
@@ -43,6 +71,81 @@ impl NodeCapabilities {
             blobs: true,
         }
     }
+
+    /// Whether the capability with this field name is set
+    pub fn has(&self, capability: &str) -> Result<bool> {
+        Ok(match capability {
+            "mqtt" => self.mqtt,
+            "streams" => self.streams,
+            "timeseries" => self.timeseries,
+            "geo" => self.geo,
+            "blobs" => self.blobs,
+            other => return Err(anyhow!("Unknown capability {}", other)),
+        })
+    }
+}
+
+/// Criteria for `PeerRegistry::get_peers_filtered`
+#[derive(Debug, Clone, Default)]
+pub struct PeerFilter {
+    /// Capabilities a peer must all have (`NodeCapabilities` field names).
+    /// Peers only known from a connection, not an announcement, have none.
+    pub capabilities: Vec<String>,
+    /// Region to match (case-insensitive)
+    pub region: Option<String>,
+    /// Peers without a latency measurement don't pass a latency limit
+    pub max_latency_ms: Option<u64>,
+}
+
+/// How a connection to a peer is carried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionPath {
+    /// UDP straight to the peer (holepunched or on the same network)
+    Direct,
+    /// Through a relay server
+    Relayed,
+}
+
+impl ConnectionPath {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Direct => "direct",
+            Self::Relayed => "relayed",
+        }
+    }
+}
+
+/// Paths of our connection to a peer, updated as iroh switches between them
+#[derive(Debug, Clone, Default)]
+pub struct PeerPath {
+    /// Path currently used to send; None until one is selected
+    pub selected: Option<ConnectionPath>,
+    /// Open direct UDP paths (ip:port)
+    pub direct_addrs: Vec<String>,
+    /// Relay the peer is reachable through
+    pub relay_url: Option<String>,
+    /// Unix millis of the last change
+    pub updated_at: i64,
+}
+
+impl PeerPath {
+    /// Both a direct and a relay path are open
+    pub fn is_mixed(&self) -> bool {
+        !self.direct_addrs.is_empty() && self.relay_url.is_some()
+    }
+}
+
+/// Our dials of a peer this session
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DialRecord {
+    pub attempts: u32,
+    pub failures: u32,
+    /// Unix millis of the last dial
+    pub last_attempt_at: i64,
+    /// Error of the last dial, cleared when one succeeds
+    pub last_error: Option<String>,
+    /// A direct path was selected at some point
+    pub direct_established: bool,
 }
 
 /// Discovery node announcement - matches cyberfly-rust-node exactly
@@ -130,6 +233,32 @@ impl SignedDiscoveryMessage {
     }
 }
 
+/// Rolling latency statistics of a peer
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LatencyStats {
+    pub samples: u32,
+    /// Exponentially weighted moving average (ms)
+    pub average_ms: f64,
+    /// Smoothed difference between consecutive samples (ms), as RTP jitter
+    pub jitter_ms: f64,
+    last_ms: u64,
+}
+
+impl LatencyStats {
+    pub fn record(&mut self, sample_ms: u64) {
+        let sample = sample_ms as f64;
+        if self.samples == 0 {
+            self.average_ms = sample;
+        } else {
+            self.average_ms += LATENCY_AVERAGE_WEIGHT * (sample - self.average_ms);
+            let delta = (sample - self.last_ms as f64).abs();
+            self.jitter_ms += (delta - self.jitter_ms) / 16.0;
+        }
+        self.last_ms = sample_ms;
+        self.samples = self.samples.saturating_add(1);
+    }
+}
+
 /// Peer information discovered through gossip
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscoveredPeer {
@@ -145,15 +274,30 @@ pub struct DiscoveredPeer {
     pub region: Option<String>,
     /// Version string
     pub version: Option<String>,
+    /// Human-readable name (from v2 discovery)
+    #[serde(default)]
+    pub name: Option<String>,
     /// Last seen timestamp (local)
     #[serde(skip)]
     pub last_seen: Option<Instant>,
     /// Measured latency in milliseconds
     #[serde(skip)]
     pub latency_ms: Option<u64>,
+    /// Rolling average and jitter of the measurements
+    #[serde(skip)]
+    pub latency_stats: LatencyStats,
 }
 
 impl DiscoveredPeer {
+    /// Rolling average latency, or the last measurement if there's no average
+    pub fn average_latency_ms(&self) -> Option<u64> {
+        if self.latency_stats.samples > 0 {
+            Some(self.latency_stats.average_ms.round() as u64)
+        } else {
+            self.latency_ms
+        }
+    }
+
     pub fn is_expired(&self) -> bool {
         self.last_seen
             .map(|t| t.elapsed() > Duration::from_secs(PEER_EXPIRY_SECS))
@@ -182,6 +326,17 @@ pub struct PeerAnnouncement {
     pub timestamp: i64,
     /// Ed25519 signature of the announcement (hex)
     pub signature: String,
+    /// Private network membership tag over the signing message (see
+    /// `private_network`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_tag: Option<String>,
+    /// Further direct addresses after `address`. Not signed, so older nodes
+    /// still verify the announcement; dials check the node ID anyway.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub addresses: Vec<String>,
+    /// Home relay URL, not signed either
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relay_url: Option<String>,
 }
 
 impl PeerAnnouncement {
@@ -204,9 +359,21 @@ impl PeerAnnouncement {
             version,
             timestamp: chrono::Utc::now().timestamp_millis(),
             signature: String::new(),
+            network_tag: None,
+            addresses: Vec::new(),
+            relay_url: None,
         }
     }
 
+    /// Set the addresses peers can dial back, most preferred first; the
+    /// first goes in `address`, which older nodes dial. Call before signing.
+    pub fn set_dial_addrs(&mut self, addrs: Vec<String>, relay_url: Option<String>) {
+        let mut addrs = addrs.into_iter();
+        self.address = addrs.next();
+        self.addresses = addrs.collect();
+        self.relay_url = relay_url;
+    }
+
     /// Get the message to sign
     pub fn signing_message(&self) -> String {
         format!(
@@ -224,6 +391,11 @@ impl PeerAnnouncement {
         self.signature = crypto::sign_message(signing_key, message.as_bytes());
     }
 
+    /// Prove membership of a private network
+    pub fn tag_network(&mut self, network_key: &NetworkKey) {
+        self.network_tag = Some(network_key.tag(self.signing_message().as_bytes()));
+    }
+
     /// Verify the signature
     pub fn verify(&self) -> Result<bool> {
         if self.signature.is_empty() {
@@ -242,8 +414,10 @@ impl PeerAnnouncement {
             capabilities: self.capabilities.clone(),
             region: self.region.clone(),
             version: self.version.clone(),
+            name: None,
             last_seen: Some(Instant::now()),
             latency_ms: None,
+            latency_stats: LatencyStats::default(),
         }
     }
 }
@@ -271,7 +445,7 @@ pub struct PeerDiscoveryAnnouncement {
     pub node_id: String,
     /// List of connected peer addresses in format "peerId@ip:port"
     pub connected_peers: Vec<String>,
-    /// Unix timestamp when announcement was created
+    /// Unix timestamp when announcement was created (ms)
     pub timestamp: i64,
     /// Region of the announcing node
     pub region: String,
@@ -279,6 +453,33 @@ pub struct PeerDiscoveryAnnouncement {
     pub signature: String,
 }
 
+impl PeerDiscoveryAnnouncement {
+    pub fn new(node_id: String, connected_peers: Vec<String>, region: String) -> Self {
+        Self {
+            node_id,
+            connected_peers,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            region,
+            signature: String::new(),
+        }
+    }
+
+    pub fn signing_message(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.node_id,
+            self.timestamp,
+            self.connected_peers.join(",")
+        )
+    }
+
+    /// Sign with the node key, whose public key is `node_id`
+    pub fn sign(&mut self, signing_key: &ed25519_dalek::SigningKey) {
+        let message = self.signing_message();
+        self.signature = crypto::sign_message(signing_key, message.as_bytes());
+    }
+}
+
 impl PeerListAnnouncement {
     pub fn new(from_node_id: String, public_key: String, peers: Vec<String>) -> Self {
         Self {
@@ -313,7 +514,8 @@ impl PeerListAnnouncement {
     }
 }
 
-/// Latency request message
+/// Latency request message (other nodes' requests are answered; our own
+/// measurements use direct pings, see `ping`)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LatencyRequest {
     /// Request ID for matching response
@@ -329,25 +531,10 @@ pub struct LatencyRequest {
 }
 
 impl LatencyRequest {
-    pub fn new(from_node_id: String, public_key: String) -> Self {
-        Self {
-            request_id: uuid::Uuid::new_v4().to_string(),
-            from_node_id,
-            public_key,
-            sent_at: chrono::Utc::now().timestamp_millis(),
-            signature: String::new(),
-        }
-    }
-
     pub fn signing_message(&self) -> String {
         format!("{}:{}:{}", self.request_id, self.from_node_id, self.sent_at)
     }
 
-    pub fn sign(&mut self, signing_key: &ed25519_dalek::SigningKey) {
-        let message = self.signing_message();
-        self.signature = crypto::sign_message(signing_key, message.as_bytes());
-    }
-
     pub fn verify(&self) -> Result<bool> {
         if self.signature.is_empty() {
             return Ok(false);
@@ -398,6 +585,9 @@ impl LatencyResponse {
         self.signature = crypto::sign_message(signing_key, message.as_bytes());
     }
 
+    // This node answers the desktop node's latency requests but measures
+    // with direct pings (see `ping`), so it never checks a response itself
+    #[allow(dead_code)]
     pub fn verify(&self) -> Result<bool> {
         if self.signature.is_empty() {
             return Ok(false);
@@ -407,6 +597,7 @@ impl LatencyResponse {
     }
 
     /// Calculate latency from original request
+    #[allow(dead_code)]
     pub fn calculate_latency(&self, request_sent_at: i64) -> u64 {
         let rtt = self.responded_at - request_sent_at;
         if rtt > 0 {
@@ -429,6 +620,63 @@ pub enum DiscoveryMessage {
     LatencyRequest(LatencyRequest),
     /// Latency check response
     LatencyResponse(LatencyResponse),
+    /// Presence heartbeat (see `presence`)
+    Presence(PresenceHeartbeat),
+    /// A node's key was rotated (see `identity`)
+    Successor(SuccessorRecord),
+    /// A device was linked to a user key (see `devices`)
+    DeviceLink(DeviceCertificate),
+}
+
+/// A peer remembered across restarts, dialed on startup before discovery
+/// finds anyone
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KnownPeer {
+    pub node_id: String,
+    /// Public key for signing (hex), empty if never announced
+    pub public_key: String,
+    /// Direct address (ip:port)
+    pub address: Option<String>,
+    pub region: Option<String>,
+    /// Unix timestamp (milliseconds)
+    pub last_seen: i64,
+    pub latency_ms: Option<u64>,
+}
+
+/// Fold the registry's current peers into the remembered ones: newer
+/// sightings win but keep a stored address or key they lack. Drops peers older
+/// than `KNOWN_PEER_TTL_SECS` and keeps the `MAX_KNOWN_PEERS` most recent.
+pub fn merge_known_peers(stored: Vec<KnownPeer>, current: Vec<KnownPeer>, now: i64) -> Vec<KnownPeer> {
+    let mut merged: HashMap<String, KnownPeer> = stored.into_iter().map(|p| (p.node_id.clone(), p)).collect();
+    for mut peer in current {
+        if let Some(old) = merged.get(&peer.node_id) {
+            if old.last_seen > peer.last_seen {
+                continue;
+            }
+            peer.address = peer.address.or_else(|| old.address.clone());
+            peer.region = peer.region.or_else(|| old.region.clone());
+            peer.latency_ms = peer.latency_ms.or(old.latency_ms);
+            if peer.public_key.is_empty() {
+                peer.public_key = old.public_key.clone();
+            }
+        }
+        merged.insert(peer.node_id.clone(), peer);
+    }
+
+    let cutoff = now - KNOWN_PEER_TTL_SECS * 1000;
+    let mut peers: Vec<KnownPeer> = merged.into_values().filter(|p| p.last_seen >= cutoff).collect();
+    peers.sort_by(|a, b| b.last_seen.cmp(&a.last_seen).then_with(|| a.node_id.cmp(&b.node_id)));
+    peers.truncate(MAX_KNOWN_PEERS);
+    peers
+}
+
+/// Last accepted announcement of a node
+#[derive(Debug, Clone, Copy)]
+struct SeenAnnouncement {
+    timestamp: i64,
+    accepted_at: Instant,
+    /// Position in `PeerRegistry::announcement_lru`
+    tick: u64,
 }
 
 /// Peer registry that tracks discovered peers
@@ -437,8 +685,23 @@ pub struct PeerRegistry {
     peers: HashMap<String, DiscoveredPeer>,
     /// Local node ID
     local_node_id: String,
-    /// Announcement cache to prevent reconnection loops
-    announcement_cache: HashMap<String, i64>,
+    /// Last accepted announcement by node_id, to reject replays and rate
+    /// limit announcers (prevents reconnection loops)
+    announcement_cache: HashMap<String, SeenAnnouncement>,
+    /// node_ids of `announcement_cache` by last use, oldest first
+    announcement_lru: BTreeMap<u64, String>,
+    announcement_tick: u64,
+    /// Paths of connections we hold, by node_id (kept apart from `peers` so
+    /// dialing doesn't count as discovery)
+    paths: HashMap<String, PeerPath>,
+    /// Our dials by node_id, for connection diagnostics
+    dials: HashMap<String, DialRecord>,
+    /// Peers the user pinned; they never expire or get unregistered
+    pinned: HashSet<String>,
+    /// Announcements must prove membership of this private network
+    network_key: Option<NetworkKey>,
+    /// Peers that proved their EndpointId and public key (see `auth`)
+    verified: HashMap<String, VerifiedBinding>,
 }
 
 impl PeerRegistry {
@@ -447,9 +710,22 @@ impl PeerRegistry {
             peers: HashMap::new(),
             local_node_id,
             announcement_cache: HashMap::new(),
+            announcement_lru: BTreeMap::new(),
+            announcement_tick: 0,
+            paths: HashMap::new(),
+            dials: HashMap::new(),
+            pinned: HashSet::new(),
+            network_key: None,
+            verified: HashMap::new(),
         }
     }
 
+    /// Only accept announcements from members of this private network
+    pub fn with_network_key(mut self, network_key: Option<NetworkKey>) -> Self {
+        self.network_key = network_key;
+        self
+    }
+
     /// Process a peer announcement
     pub fn process_announcement(&mut self, announcement: &PeerAnnouncement) -> Result<bool> {
         // Don't process our own announcements
@@ -457,12 +733,24 @@ impl PeerRegistry {
             return Ok(false);
         }
 
-        // Check announcement cache
-        if let Some(&cached_ts) = self.announcement_cache.get(&announcement.id) {
-            if cached_ts >= announcement.timestamp {
+        // Only announcements made around now; older ones may be replays
+        let skew = chrono::Utc::now().timestamp_millis() - announcement.timestamp;
+        if skew.abs() > ANNOUNCEMENT_MAX_SKEW_MS {
+            debug!("Skipping announcement {} from {} ({}ms off)", announcement.id, announcement.node_id, skew);
+            return Ok(false);
+        }
+
+        // Check announcement cache: each node's announcements must be newer
+        // than the last accepted one and not come too often
+        if let Some(seen) = self.announcement_cache.get(&announcement.node_id) {
+            if seen.timestamp >= announcement.timestamp {
                 debug!("Skipping cached announcement {}", announcement.id);
                 return Ok(false);
             }
+            if seen.accepted_at.elapsed() < MIN_ANNOUNCEMENT_INTERVAL {
+                debug!("Rate limiting announcements from {}", announcement.node_id);
+                return Ok(false);
+            }
         }
 
         // Verify signature
@@ -470,9 +758,20 @@ impl PeerRegistry {
             warn!("Invalid signature on announcement from {}", announcement.node_id);
             return Ok(false);
         }
+        if let Some(network_key) = &self.network_key {
+            let message = announcement.signing_message();
+            let member = announcement
+                .network_tag
+                .as_deref()
+                .is_some_and(|tag| network_key.verify_tag(message.as_bytes(), tag));
+            if !member {
+                warn!("Announcement from {} is not from our private network", announcement.node_id);
+                return Ok(false);
+            }
+        }
 
         // Update cache
-        self.announcement_cache.insert(announcement.id.clone(), announcement.timestamp);
+        self.remember_announcement(&announcement.node_id, announcement.timestamp);
 
         // Update or insert peer
         let peer = announcement.to_discovered_peer();
@@ -489,6 +788,26 @@ impl PeerRegistry {
         Ok(is_new)
     }
 
+    fn remember_announcement(&mut self, node_id: &str, timestamp: i64) {
+        self.announcement_tick += 1;
+        let seen = SeenAnnouncement {
+            timestamp,
+            accepted_at: Instant::now(),
+            tick: self.announcement_tick,
+        };
+        if let Some(previous) = self.announcement_cache.insert(node_id.to_string(), seen) {
+            self.announcement_lru.remove(&previous.tick);
+        }
+        self.announcement_lru.insert(seen.tick, node_id.to_string());
+
+        while self.announcement_cache.len() > MAX_ANNOUNCEMENT_CACHE {
+            let Some((_, oldest)) = self.announcement_lru.pop_first() else {
+                break;
+            };
+            self.announcement_cache.remove(&oldest);
+        }
+    }
+
     /// Process a peer list announcement
     pub fn process_peer_list(&mut self, list: &PeerListAnnouncement) -> Vec<String> {
         if list.from_node_id == self.local_node_id {
@@ -515,10 +834,60 @@ impl PeerRegistry {
             .collect()
     }
 
-    /// Update peer latency
+    /// Register or refresh a peer from a verified v2 discovery message
+    /// (see `SignedDiscoveryMessage::verify_and_decode`). The claimed node ID
+    /// must be the signing key so one node can't announce as another.
+    pub fn process_discovery_node(
+        &mut self,
+        verifying_key: &ed25519_dalek::VerifyingKey,
+        node: &DiscoveryNode,
+    ) -> Result<bool> {
+        let signer = iroh::PublicKey::from_bytes(verifying_key.as_bytes())
+            .map_err(|e| anyhow!("Invalid public key in discovery message: {}", e))?;
+        if node.node_id != signer {
+            return Err(anyhow!("Discovery message claims {} but is signed by {}", node.node_id, signer));
+        }
+
+        let node_id = node.node_id.to_string();
+        if node_id == self.local_node_id {
+            return Ok(false);
+        }
+
+        let name = Some(node.name.clone()).filter(|name| !name.is_empty());
+        let region = Some(node.region.clone()).filter(|region| !region.is_empty());
+        let public_key = hex::encode(verifying_key.as_bytes());
+        let is_new = !self.peers.contains_key(&node_id);
+        let peer = self.peers.entry(node_id.clone()).or_insert_with(|| DiscoveredPeer {
+            node_id: node_id.clone(),
+            public_key: String::new(),
+            address: None,
+            capabilities: NodeCapabilities::default(),
+            region: None,
+            version: None,
+            name: None,
+            last_seen: None,
+            latency_ms: None,
+            latency_stats: LatencyStats::default(),
+        });
+        peer.public_key = public_key;
+        peer.capabilities = node.capabilities.clone();
+        peer.name = name;
+        if region.is_some() {
+            peer.region = region;
+        }
+        peer.last_seen = Some(Instant::now());
+
+        if is_new {
+            info!("Discovered new peer via v2 discovery: {}", node_id);
+        }
+        Ok(is_new)
+    }
+
+    /// Record a latency measurement of a peer
     pub fn update_latency(&mut self, node_id: &str, latency_ms: u64) {
         if let Some(peer) = self.peers.get_mut(node_id) {
             peer.latency_ms = Some(latency_ms);
+            peer.latency_stats.record(latency_ms);
             debug!("Updated latency for {}: {}ms", node_id, latency_ms);
         }
     }
@@ -540,8 +909,10 @@ impl PeerRegistry {
                 capabilities: NodeCapabilities::default(),
                 region: None,
                 version: None,
+                name: None,
                 last_seen: Some(std::time::Instant::now()),
                 latency_ms: None,
+                latency_stats: LatencyStats::default(),
             };
             self.peers.insert(node_id.clone(), peer);
             info!("Registered connected peer from NeighborUp: {}", node_id);
@@ -555,13 +926,101 @@ impl PeerRegistry {
         is_new
     }
 
+    /// Record how a connected peer is currently reached
+    pub fn set_connection_path(&mut self, node_id: &str, path: PeerPath) {
+        if path.selected == Some(ConnectionPath::Direct) {
+            if let Some(dials) = self.dials.get_mut(node_id) {
+                dials.direct_established = true;
+            }
+        }
+        self.paths.insert(node_id.to_string(), path);
+    }
+
+    /// Record a dial of `node_id` and its error, if it failed
+    pub fn record_dial(&mut self, node_id: &str, error: Option<String>) {
+        if !self.dials.contains_key(node_id) && self.dials.len() >= MAX_DIAL_RECORDS {
+            let oldest = self.dials.iter().min_by_key(|(_, dials)| dials.last_attempt_at).map(|(id, _)| id.clone());
+            if let Some(oldest) = oldest {
+                self.dials.remove(&oldest);
+            }
+        }
+        let dials = self.dials.entry(node_id.to_string()).or_default();
+        dials.attempts += 1;
+        dials.last_attempt_at = chrono::Utc::now().timestamp_millis();
+        if error.is_some() {
+            dials.failures += 1;
+        }
+        dials.last_error = error;
+    }
+
+    /// Dials of every peer dialed this session, by node_id
+    pub fn dial_records(&self) -> Vec<(String, DialRecord)> {
+        self.dials.iter().map(|(id, dials)| (id.clone(), dials.clone())).collect()
+    }
+
+    /// Forget the path of a closed connection
+    pub fn clear_connection_path(&mut self, node_id: &str) {
+        self.paths.remove(node_id);
+    }
+
+    pub fn has_connection_path(&self, node_id: &str) -> bool {
+        self.paths.contains_key(node_id)
+    }
+
+    /// Paths of all connected peers, by node_id
+    pub fn connection_paths(&self) -> Vec<(String, PeerPath)> {
+        let mut paths: Vec<(String, PeerPath)> =
+            self.paths.iter().map(|(id, path)| (id.clone(), path.clone())).collect();
+        paths.sort_by(|a, b| a.0.cmp(&b.0));
+        paths
+    }
+
+    /// Keep a peer registered regardless of announcements and neighbor events
+    pub fn pin_peer(&mut self, node_id: String, address: Option<String>) {
+        self.pinned.insert(node_id.clone());
+        self.register_peer_from_list(node_id, address, None);
+    }
+
+    /// Let a pinned peer expire like any other again
+    pub fn unpin_peer(&mut self, node_id: &str) {
+        self.pinned.remove(node_id);
+    }
+
+    pub fn is_pinned(&self, node_id: &str) -> bool {
+        self.pinned.contains(node_id)
+    }
+
+    /// Pinned or recently seen
+    fn is_active(&self, peer: &DiscoveredPeer) -> bool {
+        self.is_pinned(&peer.node_id) || !peer.is_expired()
+    }
+
     /// Unregister a peer (from NeighborDown)
     pub fn unregister_peer(&mut self, node_id: &str) {
+        if self.is_pinned(node_id) {
+            return;
+        }
         if self.peers.remove(node_id).is_some() {
             info!("Unregistered peer from NeighborDown: {}", node_id);
         }
     }
 
+    /// Remember a peer's proven binding, replacing an older one
+    pub fn record_verified_binding(&mut self, binding: VerifiedBinding) {
+        self.verified.insert(binding.node_id.clone(), binding);
+    }
+
+    /// Proven binding of a peer, if it's still fresh
+    pub fn verified_binding(&self, node_id: &str, now: i64) -> Option<&VerifiedBinding> {
+        self.verified.get(node_id).filter(|binding| binding.is_fresh(now))
+    }
+
+    /// Whether `node_id` recently proved it holds `public_key`
+    pub fn is_verified_key(&self, node_id: &str, public_key: &str, now: i64) -> bool {
+        self.verified_binding(node_id, now)
+            .is_some_and(|binding| binding.public_key.eq_ignore_ascii_case(public_key))
+    }
+
     /// Get a peer by node ID
     pub fn get_peer(&self, node_id: &str) -> Option<&DiscoveredPeer> {
         self.peers.get(node_id)
@@ -574,7 +1033,48 @@ impl PeerRegistry {
 
     /// Get active (non-expired) peers
     pub fn get_active_peers(&self) -> Vec<&DiscoveredPeer> {
-        self.peers.values().filter(|p| !p.is_expired()).collect()
+        self.peers.values().filter(|p| self.is_active(p)).collect()
+    }
+
+    /// Active peers matching `filter`, lowest average latency first
+    /// (unmeasured last)
+    pub fn get_peers_filtered(&self, filter: &PeerFilter) -> Result<Vec<&DiscoveredPeer>> {
+        let mut peers = Vec::new();
+        for peer in self.get_active_peers() {
+            let mut capable = true;
+            for capability in &filter.capabilities {
+                capable &= peer.capabilities.has(capability)?;
+            }
+            let in_region = filter.region.as_ref().is_none_or(|region| {
+                peer.region.as_ref().is_some_and(|r| r.eq_ignore_ascii_case(region))
+            });
+            let fast_enough = filter
+                .max_latency_ms
+                .is_none_or(|max| peer.average_latency_ms().is_some_and(|latency| latency <= max));
+            if capable && in_region && fast_enough {
+                peers.push(peer);
+            }
+        }
+        peers.sort_by_key(|peer| {
+            let latency = peer.average_latency_ms();
+            (latency.is_none(), latency, peer.node_id.clone())
+        });
+        Ok(peers)
+    }
+
+    /// Measured active peers, best first: lowest average latency, then
+    /// steadiest
+    pub fn ranked_peers(&self) -> Vec<&DiscoveredPeer> {
+        let mut peers: Vec<&DiscoveredPeer> = self
+            .get_active_peers()
+            .into_iter()
+            .filter(|peer| peer.latency_stats.samples > 0)
+            .collect();
+        peers.sort_by(|a, b| {
+            let (a, b) = (&a.latency_stats, &b.latency_stats);
+            a.average_ms.total_cmp(&b.average_ms).then(a.jitter_ms.total_cmp(&b.jitter_ms))
+        });
+        peers
     }
 
     /// Get peer count
@@ -604,8 +1104,10 @@ impl PeerRegistry {
                 capabilities: NodeCapabilities::default(),
                 region,
                 version: None,
+                name: None,
                 last_seen: Some(std::time::Instant::now()),
                 latency_ms: None,
+                latency_stats: LatencyStats::default(),
             };
             self.peers.insert(node_id.clone(), peer);
             info!("Registered peer from list: {} (region: {:?})", node_id, region_str);
@@ -627,18 +1129,28 @@ impl PeerRegistry {
 
     /// Get active peer count
     pub fn active_peer_count(&self) -> usize {
-        self.peers.values().filter(|p| !p.is_expired()).count()
+        self.peers.values().filter(|p| self.is_active(p)).count()
     }
 
     /// Remove expired peers
     pub fn cleanup_expired(&mut self) -> usize {
         let before = self.peers.len();
-        self.peers.retain(|_, p| !p.is_expired());
+        let pinned = &self.pinned;
+        self.peers.retain(|node_id, p| pinned.contains(node_id) || !p.is_expired());
         let removed = before - self.peers.len();
         
         // Also cleanup old announcement cache entries
         let cutoff = chrono::Utc::now().timestamp_millis() - (PEER_EXPIRY_SECS as i64 * 1000);
-        self.announcement_cache.retain(|_, ts| *ts > cutoff);
+        let lru = &mut self.announcement_lru;
+        self.announcement_cache.retain(|_, seen| {
+            let keep = seen.timestamp > cutoff;
+            if !keep {
+                lru.remove(&seen.tick);
+            }
+            keep
+        });
+        let now = chrono::Utc::now().timestamp_millis();
+        self.verified.retain(|_, binding| binding.is_fresh(now));
         
         if removed > 0 {
             info!("Cleaned up {} expired peers", removed);
@@ -646,22 +1158,54 @@ impl PeerRegistry {
         removed
     }
 
+    /// Current peers in persistable form (see `merge_known_peers`)
+    pub fn known_peers(&self) -> Vec<KnownPeer> {
+        let now = chrono::Utc::now().timestamp_millis();
+        self.peers
+            .values()
+            .filter_map(|p| {
+                let age_ms = p.last_seen?.elapsed().as_millis() as i64;
+                Some(KnownPeer {
+                    node_id: p.node_id.clone(),
+                    public_key: p.public_key.clone(),
+                    address: p.address.clone(),
+                    region: p.region.clone(),
+                    last_seen: now - age_ms,
+                    latency_ms: p.latency_ms,
+                })
+            })
+            .collect()
+    }
+
     /// Get list of peer addresses for peer list announcement
     pub fn get_peer_list_for_broadcast(&self) -> Vec<String> {
         self.peers
             .values()
-            .filter(|p| !p.is_expired())
-            .map(|p| {
-                if let Some(ref addr) = p.address {
-                    format!("{}@{}", p.node_id, addr)
-                } else {
-                    p.node_id.clone()
-                }
+            .filter(|p| self.is_active(p))
+            .map(|p| peer_list_entry(&p.node_id, p.address.as_deref()))
+            .collect()
+    }
+
+    /// `node_ids` in peer list format, with the address where known
+    pub fn peer_list_of(&self, node_ids: impl IntoIterator<Item = String>) -> Vec<String> {
+        node_ids
+            .into_iter()
+            .map(|node_id| {
+                let address = self.peers.get(&node_id).and_then(|p| p.address.as_deref());
+                peer_list_entry(&node_id, address)
             })
             .collect()
     }
 }
 
+/// "NodeId@ip:port", or just "NodeId" without an address
+fn peer_list_entry(node_id: &str, address: Option<&str>) -> String {
+    match address {
+        Some(addr) => format!("{}@{}", node_id, addr),
+        None => node_id.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -685,6 +1229,51 @@ mod tests {
         assert!(announcement.verify().unwrap());
     }
 
+    #[test]
+    fn test_peer_discovery_announcement() {
+        let (signing_key, public_key) = generate_keypair();
+        let mut registry = PeerRegistry::new("local-node".to_string());
+        registry.register_peer_from_list("peer-a".to_string(), Some("203.0.113.7:4433".to_string()), None);
+        let connected = registry.peer_list_of(["peer-a".to_string(), "peer-b".to_string()]);
+        assert_eq!(connected, ["peer-a@203.0.113.7:4433", "peer-b"]);
+
+        let mut announcement = PeerDiscoveryAnnouncement::new(public_key.clone(), connected, "eu".to_string());
+        announcement.sign(&signing_key);
+        let message = announcement.signing_message();
+        assert!(crypto::verify_signature(&public_key, message.as_bytes(), &announcement.signature).unwrap());
+
+        // Exactly the desktop's fields
+        let json = serde_json::to_value(&announcement).unwrap();
+        let mut fields: Vec<&String> = json.as_object().unwrap().keys().collect();
+        fields.sort();
+        assert_eq!(fields, ["connected_peers", "node_id", "region", "signature", "timestamp"]);
+    }
+
+    #[test]
+    fn test_announcement_dial_addrs() {
+        let (signing_key, public_key) = generate_keypair();
+        let mut announcement =
+            PeerAnnouncement::new("node123".to_string(), public_key, None, NodeCapabilities::mobile_node(), None, None);
+        let addrs = vec!["203.0.113.7:4433".to_string(), "[2001:db8::7]:4433".to_string()];
+        announcement.set_dial_addrs(addrs.clone(), Some("https://relay.example/".to_string()));
+        announcement.sign(&signing_key);
+        assert_eq!(announcement.address.as_deref(), Some("203.0.113.7:4433"));
+
+        let decoded: PeerAnnouncement = serde_json::from_slice(&serde_json::to_vec(&announcement).unwrap()).unwrap();
+        assert!(decoded.verify().unwrap());
+        assert_eq!(decoded.addresses, addrs[1..]);
+        assert_eq!(decoded.relay_url.as_deref(), Some("https://relay.example/"));
+
+        // Older nodes only know `address`, and verify it the same way
+        let mut json = serde_json::to_value(&announcement).unwrap();
+        let object = json.as_object_mut().unwrap();
+        object.remove("addresses");
+        object.remove("relay_url");
+        let legacy: PeerAnnouncement = serde_json::from_value(json).unwrap();
+        assert!(legacy.verify().unwrap());
+        assert!(legacy.addresses.is_empty());
+    }
+
     #[test]
     fn test_peer_registry() {
         let (signing_key, public_key) = generate_keypair();
@@ -709,6 +1298,254 @@ mod tests {
         assert!(!is_new_again);
     }
 
+    #[test]
+    fn test_announcement_replay_and_rate_limits() {
+        let (signing_key, public_key) = generate_keypair();
+        let mut registry = PeerRegistry::new("local-node".to_string());
+        let announce = |node_id: &str, age_ms: i64| {
+            let mut announcement =
+                PeerAnnouncement::new(node_id.to_string(), public_key.clone(), None, NodeCapabilities::default(), None, None);
+            announcement.timestamp -= age_ms;
+            announcement.sign(&signing_key);
+            announcement
+        };
+
+        // Outside the timestamp window
+        assert!(!registry.process_announcement(&announce("node-a", ANNOUNCEMENT_MAX_SKEW_MS + 1000)).unwrap());
+        assert!(!registry.process_announcement(&announce("node-a", -ANNOUNCEMENT_MAX_SKEW_MS - 1000)).unwrap());
+
+        assert!(registry.process_announcement(&announce("node-a", 1000)).unwrap());
+        // Older than the last accepted one
+        assert!(!registry.process_announcement(&announce("node-a", 2000)).unwrap());
+
+        // Newer but too soon, then accepted once the interval passed
+        let fresh = announce("node-a", 0);
+        registry.process_announcement(&fresh).unwrap();
+        assert_ne!(registry.announcement_cache["node-a"].timestamp, fresh.timestamp);
+        let seen = registry.announcement_cache.get_mut("node-a").unwrap();
+        seen.accepted_at = Instant::now().checked_sub(MIN_ANNOUNCEMENT_INTERVAL).unwrap();
+        registry.process_announcement(&fresh).unwrap();
+        assert_eq!(registry.announcement_cache["node-a"].timestamp, fresh.timestamp);
+
+        // The cache is bounded, forgetting the least recently announced node
+        for i in 0..MAX_ANNOUNCEMENT_CACHE {
+            registry.remember_announcement(&format!("node-{}", i), 0);
+        }
+        assert_eq!(registry.announcement_cache.len(), MAX_ANNOUNCEMENT_CACHE);
+        assert_eq!(registry.announcement_lru.len(), MAX_ANNOUNCEMENT_CACHE);
+        assert!(!registry.announcement_cache.contains_key("node-a"));
+    }
+
+    #[test]
+    fn test_private_network_announcements() {
+        let (signing_key, public_key) = generate_keypair();
+        let network_key = NetworkKey::from_passphrase("family").unwrap();
+        let mut registry = PeerRegistry::new("local-node".to_string()).with_network_key(Some(network_key));
+        let announce = |node_id: &str, key: Option<&NetworkKey>| {
+            let mut announcement =
+                PeerAnnouncement::new(node_id.to_string(), public_key.clone(), None, NodeCapabilities::default(), None, None);
+            announcement.sign(&signing_key);
+            if let Some(key) = key {
+                announcement.tag_network(key);
+            }
+            announcement
+        };
+
+        let outsider = NetworkKey::from_passphrase("office").unwrap();
+        assert!(!registry.process_announcement(&announce("public", None)).unwrap());
+        assert!(!registry.process_announcement(&announce("outsider", Some(&outsider))).unwrap());
+        assert!(registry.process_announcement(&announce("member", Some(&network_key))).unwrap());
+        assert_eq!(registry.peer_count(), 1);
+    }
+
+    #[test]
+    fn test_process_discovery_node() {
+        let (signing_key, public_key) = generate_keypair();
+        let node_id = iroh::PublicKey::from_bytes(signing_key.verifying_key().as_bytes()).unwrap();
+        let node = DiscoveryNode {
+            name: "desktop-1".to_string(),
+            node_id,
+            count: 7,
+            region: "eu".to_string(),
+            capabilities: NodeCapabilities { mqtt: true, blobs: true, ..Default::default() },
+        };
+        let encoded = SignedDiscoveryMessage::sign_and_encode(&signing_key, &node).unwrap();
+
+        let mut registry = PeerRegistry::new("local-node".to_string());
+        let (key, decoded) = SignedDiscoveryMessage::verify_and_decode(&encoded).unwrap();
+        assert!(registry.process_discovery_node(&key, &decoded).unwrap());
+        assert!(!registry.process_discovery_node(&key, &decoded).unwrap());
+
+        let peer = registry.get_peer(&node_id.to_string()).unwrap();
+        assert_eq!(peer.name.as_deref(), Some("desktop-1"));
+        assert_eq!(peer.region.as_deref(), Some("eu"));
+        assert_eq!(peer.public_key, public_key);
+        assert!(peer.capabilities.mqtt && !peer.capabilities.geo);
+
+        // Tampered payloads fail verification
+        let mut tampered = encoded.clone();
+        let at = tampered.len() - 70;
+        tampered[at] ^= 1;
+        assert!(SignedDiscoveryMessage::verify_and_decode(&tampered).is_err());
+
+        // A validly signed message claiming another node's ID is rejected
+        let (other_key, _) = generate_keypair();
+        let spoofed = SignedDiscoveryMessage::sign_and_encode(&other_key, &node).unwrap();
+        let (key, decoded) = SignedDiscoveryMessage::verify_and_decode(&spoofed).unwrap();
+        assert!(registry.process_discovery_node(&key, &decoded).is_err());
+    }
+
+    #[test]
+    fn test_connection_paths_are_not_discovered_peers() {
+        let mut registry = PeerRegistry::new("local-node".to_string());
+        let path = PeerPath {
+            selected: Some(ConnectionPath::Direct),
+            direct_addrs: vec!["192.168.1.2:4433".to_string()],
+            relay_url: Some("https://relay.example".to_string()),
+            updated_at: 1,
+        };
+        registry.set_connection_path("peer-b", path);
+        registry.set_connection_path("peer-a", PeerPath::default());
+
+        assert_eq!(registry.peer_count(), 0);
+        let paths = registry.connection_paths();
+        assert_eq!(paths.iter().map(|(id, _)| id.as_str()).collect::<Vec<_>>(), ["peer-a", "peer-b"]);
+        assert!(paths[1].1.is_mixed() && !paths[0].1.is_mixed());
+
+        registry.clear_connection_path("peer-b");
+        assert!(!registry.has_connection_path("peer-b"));
+    }
+
+    #[test]
+    fn test_latency_stats_and_ranking() {
+        let mut stats = LatencyStats::default();
+        stats.record(100);
+        assert_eq!((stats.average_ms, stats.jitter_ms), (100.0, 0.0));
+        stats.record(20);
+        assert_eq!((stats.average_ms, stats.jitter_ms), (80.0, 5.0));
+
+        let mut registry = PeerRegistry::new("local-node".to_string());
+        for node_id in ["steady", "jittery", "slow", "unmeasured"] {
+            registry.register_connected_peer(node_id.to_string());
+        }
+        for (node_id, samples) in [("steady", [50, 50]), ("jittery", [10, 90]), ("slow", [200, 200])] {
+            for sample in samples {
+                registry.update_latency(node_id, sample);
+            }
+        }
+
+        let ranked: Vec<&str> = registry.ranked_peers().iter().map(|p| p.node_id.as_str()).collect();
+        assert_eq!(ranked, ["jittery", "steady", "slow"]);
+        assert_eq!(registry.get_peer("jittery").unwrap().average_latency_ms(), Some(30));
+        assert_eq!(registry.get_peer("jittery").unwrap().latency_ms, Some(90));
+    }
+
+    #[test]
+    fn test_verified_bindings() {
+        let mut registry = PeerRegistry::new("local-node".to_string());
+        let now = chrono::Utc::now().timestamp_millis();
+        assert!(registry.verified_binding("peer", now).is_none());
+
+        registry.record_verified_binding(VerifiedBinding {
+            node_id: "peer".to_string(),
+            public_key: "abcd".to_string(),
+            verified_at: now,
+        });
+        assert!(registry.is_verified_key("peer", "ABCD", now));
+        assert!(!registry.is_verified_key("peer", "ef01", now));
+        assert!(!registry.is_verified_key("other", "abcd", now));
+
+        // Stale proofs don't count
+        let later = now + crate::auth::VERIFIED_BINDING_TTL_MS;
+        assert!(!registry.is_verified_key("peer", "abcd", later));
+    }
+
+    #[test]
+    fn test_pinned_peers_never_expire() {
+        let mut registry = PeerRegistry::new("local-node".to_string());
+        registry.pin_peer("pinned".to_string(), Some("10.0.0.2:4433".to_string()));
+        registry.register_connected_peer("other".to_string());
+        for peer in registry.peers.values_mut() {
+            peer.last_seen = None;
+        }
+
+        assert_eq!(registry.active_peer_count(), 1);
+        registry.unregister_peer("pinned");
+        assert_eq!(registry.cleanup_expired(), 1);
+        assert_eq!(registry.get_peer("pinned").unwrap().address.as_deref(), Some("10.0.0.2:4433"));
+
+        registry.unpin_peer("pinned");
+        assert_eq!(registry.cleanup_expired(), 1);
+        assert_eq!(registry.peer_count(), 0);
+    }
+
+    #[test]
+    fn test_get_peers_filtered() {
+        let mut registry = PeerRegistry::new("local-node".to_string());
+        for (node_id, region, latency, capabilities) in [
+            ("mobile-eu", "eu", Some(40), NodeCapabilities::mobile_node()),
+            ("server-eu", "EU", Some(20), NodeCapabilities { blobs: true, mqtt: true, ..Default::default() }),
+            ("mobile-us", "us", Some(5), NodeCapabilities::mobile_node()),
+            ("unmeasured-eu", "eu", None, NodeCapabilities::mobile_node()),
+        ] {
+            registry.peers.insert(
+                node_id.to_string(),
+                DiscoveredPeer {
+                    node_id: node_id.to_string(),
+                    public_key: String::new(),
+                    address: None,
+                    capabilities,
+                    region: Some(region.to_string()),
+                    version: None,
+                    name: None,
+                    last_seen: Some(Instant::now()),
+                    latency_ms: latency,
+                    latency_stats: LatencyStats::default(),
+                },
+            );
+        }
+
+        let ids = |filter: PeerFilter| -> Vec<String> {
+            registry.get_peers_filtered(&filter).unwrap().iter().map(|p| p.node_id.clone()).collect()
+        };
+        let blobs_in_eu = PeerFilter {
+            capabilities: vec!["blobs".to_string()],
+            region: Some("eu".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(ids(blobs_in_eu.clone()), ["server-eu", "mobile-eu", "unmeasured-eu"]);
+        assert_eq!(ids(PeerFilter { max_latency_ms: Some(30), ..blobs_in_eu }), ["server-eu"]);
+        assert_eq!(ids(PeerFilter { capabilities: vec!["mqtt".to_string()], ..Default::default() }), ["server-eu"]);
+
+        let unknown = PeerFilter { capabilities: vec!["teleport".to_string()], ..Default::default() };
+        assert!(registry.get_peers_filtered(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_merge_known_peers() {
+        let now = 10 * KNOWN_PEER_TTL_SECS * 1000;
+        let peer = |node_id: &str, address: Option<&str>, last_seen: i64| KnownPeer {
+            node_id: node_id.to_string(),
+            public_key: String::new(),
+            address: address.map(str::to_string),
+            region: None,
+            last_seen,
+            latency_ms: None,
+        };
+
+        let stored = vec![peer("a", Some("10.0.0.1:1"), now - 1000), peer("stale", None, 0)];
+        let current = vec![peer("a", None, now), peer("b", None, now - 500)];
+        let merged = merge_known_peers(stored, current, now);
+
+        // Newer sighting keeps the stored address; stale peers are dropped
+        assert_eq!(merged, [peer("a", Some("10.0.0.1:1"), now), peer("b", None, now - 500)]);
+
+        let many: Vec<KnownPeer> = (0..MAX_KNOWN_PEERS as i64 + 5).map(|i| peer(&i.to_string(), None, now - i)).collect();
+        let merged = merge_known_peers(Vec::new(), many, now);
+        assert_eq!(merged.len(), MAX_KNOWN_PEERS);
+        assert_eq!(merged[0].node_id, "0");
+    }
+
     #[test]
     fn test_latency_calculation() {
         let sent_at = 1000i64;
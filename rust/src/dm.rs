@@ -0,0 +1,324 @@
+//! End-to-end encrypted direct messages
+//!
+//! Each pair of nodes shares a key: X25519 of one node's key with the other's
+//! (both ed25519 keys converted to their Montgomery form), hashed together
+//! with the two public keys. Messages are sealed with XChaCha20-Poly1305 under
+//! it and sent to the recipient over `DM_ALPN`; the QUIC handshake
+//! authenticates the sender. Both sides keep the sealed message in an
+//! internal tree, out of reach of sync, and decrypt it on read, so the
+//! history can't be read without one of the two node keys.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use iroh::endpoint::Connection;
+use iroh::protocol::{AcceptError, ProtocolHandler};
+use iroh::{Endpoint, EndpointId};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::crypto;
+use crate::storage::Storage;
+
+/// ALPN of the direct message protocol
+pub const DM_ALPN: &[u8] = b"/cyberfly/dm/1";
+
+/// Longest message text in bytes
+pub const MAX_DM_LENGTH: usize = 64 * 1024;
+
+const KEY_CONTEXT: &str = "cyberfly mobile-node 2024 direct message key v1";
+
+/// Largest sealed message accepted (base64 text plus envelope)
+const MAX_SEALED_SIZE: usize = MAX_DM_LENGTH * 2;
+
+const SEND_TIMEOUT: Duration = Duration::from_secs(15);
+
+const ACK: &[u8] = b"ok";
+
+/// Messages buffered for slow `subscribe` receivers
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Message as sent and stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SealedMessage {
+    id: String,
+    from: String,
+    to: String,
+    sent_at: i64,
+    ciphertext: String,
+}
+
+impl SealedMessage {
+    fn aad(&self) -> String {
+        format!("dm:{}:{}:{}:{}", self.from, self.to, self.id, self.sent_at)
+    }
+
+    fn storage_key(&self) -> String {
+        format!("{:020}-{}", self.sent_at, self.id)
+    }
+}
+
+/// Decrypted direct message
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectMessage {
+    pub id: String,
+    /// The other side of the conversation
+    pub peer_id: String,
+    /// Sent by us rather than received
+    pub outgoing: bool,
+    pub text: String,
+    /// Unix ms
+    pub sent_at: i64,
+}
+
+/// Key shared by the holder of `signing_key` and `peer`
+fn conversation_key(signing_key: &SigningKey, peer: &VerifyingKey) -> Result<[u8; 32]> {
+    let shared = peer.to_montgomery() * signing_key.to_scalar();
+    if shared.to_bytes() == [0u8; 32] {
+        return Err(anyhow!("Peer key is a low-order point"));
+    }
+
+    let ours = signing_key.verifying_key();
+    let (first, second) = if ours.as_bytes() < peer.as_bytes() { (ours, *peer) } else { (*peer, ours) };
+    let mut material = shared.to_bytes().to_vec();
+    material.extend_from_slice(first.as_bytes());
+    material.extend_from_slice(second.as_bytes());
+    Ok(blake3::derive_key(KEY_CONTEXT, &material))
+}
+
+fn verifying_key(node_id: &str) -> Result<VerifyingKey> {
+    let endpoint_id: EndpointId = node_id.parse().map_err(|e| anyhow!("Invalid node ID {}: {}", node_id, e))?;
+    Ok(VerifyingKey::from_bytes(endpoint_id.as_bytes())?)
+}
+
+/// Seal `text` from `node_id` (holding `signing_key`) to `peer_id`
+fn seal(signing_key: &SigningKey, node_id: &str, peer_id: &str, text: &str) -> Result<SealedMessage> {
+    if text.len() > MAX_DM_LENGTH {
+        return Err(anyhow!("Message is longer than {} bytes", MAX_DM_LENGTH));
+    }
+    let key = conversation_key(signing_key, &verifying_key(peer_id)?)?;
+    let mut sealed = SealedMessage {
+        id: uuid::Uuid::new_v4().to_string(),
+        from: node_id.to_string(),
+        to: peer_id.to_string(),
+        sent_at: Utc::now().timestamp_millis(),
+        ciphertext: String::new(),
+    };
+    sealed.ciphertext = crypto::encrypt_value(&key, sealed.aad().as_bytes(), text.as_bytes())?;
+    Ok(sealed)
+}
+
+/// Decrypt a message of `node_id`'s (holding `signing_key`) conversations
+fn open(signing_key: &SigningKey, node_id: &str, sealed: &SealedMessage) -> Result<DirectMessage> {
+    let outgoing = sealed.from == node_id;
+    let peer_id = if outgoing { &sealed.to } else { &sealed.from };
+    let key = conversation_key(signing_key, &verifying_key(peer_id)?)?;
+    let text = crypto::decrypt_value(&key, sealed.aad().as_bytes(), &sealed.ciphertext)?;
+    Ok(DirectMessage {
+        id: sealed.id.clone(),
+        peer_id: peer_id.clone(),
+        outgoing,
+        text: String::from_utf8(text)?,
+        sent_at: sealed.sent_at,
+    })
+}
+
+/// Sends, receives and stores direct messages
+#[derive(Clone)]
+pub struct DmService {
+    /// None on an offline node, which can only read its history
+    endpoint: Option<Endpoint>,
+    storage: Storage,
+    signing_key: SigningKey,
+    node_id: String,
+    incoming: broadcast::Sender<DirectMessage>,
+}
+
+impl std::fmt::Debug for DmService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DmService").field("node_id", &self.node_id).finish()
+    }
+}
+
+impl DmService {
+    pub fn new(endpoint: Endpoint, storage: Storage, signing_key: SigningKey, node_id: String) -> Self {
+        let (incoming, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { endpoint: Some(endpoint), storage, signing_key, node_id, incoming }
+    }
+
+    /// Service of a node without networking
+    pub fn offline(storage: Storage, signing_key: SigningKey, node_id: String) -> Self {
+        let (incoming, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { endpoint: None, storage, signing_key, node_id, incoming }
+    }
+
+    /// Messages received from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<DirectMessage> {
+        self.incoming.subscribe()
+    }
+
+    /// Deliver `text` to `peer_id` and keep it in the conversation
+    pub async fn send(&self, peer_id: &str, text: String) -> Result<DirectMessage> {
+        let peer: EndpointId = peer_id.parse().map_err(|e| anyhow!("Invalid node ID {}: {}", peer_id, e))?;
+        let sealed = seal(&self.signing_key, &self.node_id, peer_id, &text)?;
+        let encoded = postcard::to_allocvec(&sealed)?;
+        let endpoint = self.endpoint.as_ref().ok_or_else(|| anyhow!("Sending needs the node online"))?;
+
+        tokio::time::timeout(SEND_TIMEOUT, async {
+            let conn = endpoint.connect(peer, DM_ALPN).await?;
+            let (mut send, mut recv) = conn.open_bi().await?;
+            send.write_all(&encoded).await?;
+            send.finish()?;
+            let ack = recv.read_to_end(ACK.len()).await?;
+            conn.close(0u32.into(), b"done");
+            if ack != ACK {
+                return Err(anyhow!("{} didn't accept the message", peer_id));
+            }
+            anyhow::Ok(())
+        })
+        .await
+        .map_err(|_| anyhow!("Sending to {} timed out", peer_id))??;
+
+        self.store(peer_id, &sealed)?;
+        Ok(DirectMessage { id: sealed.id, peer_id: peer_id.to_string(), outgoing: true, text, sent_at: sealed.sent_at })
+    }
+
+    /// The last `limit` messages exchanged with `peer_id`, oldest first.
+    /// Messages that can't be read are skipped.
+    pub fn history(&self, peer_id: &str, limit: usize) -> Result<Vec<DirectMessage>> {
+        verifying_key(peer_id)?;
+        let messages = self
+            .storage
+            .last_direct_messages(peer_id, limit)?
+            .into_iter()
+            .filter_map(|value| {
+                let message = serde_json::from_slice(&value)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|sealed| open(&self.signing_key, &self.node_id, &sealed));
+                match message {
+                    Ok(message) if message.peer_id == peer_id => Some(message),
+                    Ok(message) => {
+                        tracing::warn!(peer_id, "Skipping stored message {} of another conversation", message.id);
+                        None
+                    }
+                    Err(e) => {
+                        tracing::warn!(peer_id, "Skipping unreadable stored message: {}", e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        Ok(messages)
+    }
+
+    fn store(&self, peer_id: &str, sealed: &SealedMessage) -> Result<()> {
+        self.storage.put_direct_message(peer_id, &sealed.storage_key(), &serde_json::to_vec(sealed)?)
+    }
+
+    /// Accept a sealed message delivered by `sender`
+    fn receive(&self, sender: EndpointId, encoded: &[u8]) -> Result<DirectMessage> {
+        let sealed: SealedMessage = postcard::from_bytes(encoded)?;
+        let sender = sender.to_string();
+        if sealed.from != sender || sealed.to != self.node_id {
+            return Err(anyhow!("Message from {} to {} delivered by {}", sealed.from, sealed.to, sender));
+        }
+        let message = open(&self.signing_key, &self.node_id, &sealed)?;
+        self.store(&sender, &sealed)?;
+        // Nobody listening is fine, the message is stored
+        let _ = self.incoming.send(message.clone());
+        Ok(message)
+    }
+}
+
+impl ProtocolHandler for DmService {
+    async fn accept(&self, connection: Connection) -> Result<(), AcceptError> {
+        let sender = connection.remote_id();
+        while let Ok((mut send, mut recv)) = connection.accept_bi().await {
+            let encoded = recv.read_to_end(MAX_SEALED_SIZE).await.map_err(AcceptError::from_err)?;
+            match self.receive(sender, &encoded) {
+                Ok(_) => {
+                    send.write_all(ACK).await.map_err(AcceptError::from_err)?;
+                }
+                Err(e) => tracing::warn!(peer_id = %sender, "Rejected direct message: {}", e),
+            }
+            send.finish().map_err(AcceptError::from_err)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity() -> (SigningKey, String) {
+        let (signing_key, _) = crypto::generate_keypair();
+        let node_id = iroh::PublicKey::from_bytes(signing_key.verifying_key().as_bytes()).unwrap().to_string();
+        (signing_key, node_id)
+    }
+
+    #[test]
+    fn test_direct_message_sealing() {
+        let (alice_key, alice) = identity();
+        let (bob_key, bob) = identity();
+        let (eve_key, eve) = identity();
+
+        assert_eq!(
+            conversation_key(&alice_key, &bob_key.verifying_key()).unwrap(),
+            conversation_key(&bob_key, &alice_key.verifying_key()).unwrap()
+        );
+        assert_ne!(
+            conversation_key(&alice_key, &bob_key.verifying_key()).unwrap(),
+            conversation_key(&alice_key, &eve_key.verifying_key()).unwrap()
+        );
+
+        let sealed = seal(&alice_key, &alice, &bob, "hi bob").unwrap();
+        assert!(!sealed.ciphertext.contains("hi bob"));
+
+        let received = open(&bob_key, &bob, &sealed).unwrap();
+        assert_eq!(received.text, "hi bob");
+        assert_eq!(received.peer_id, alice);
+        assert!(!received.outgoing);
+
+        let sent = open(&alice_key, &alice, &sealed).unwrap();
+        assert_eq!(sent.peer_id, bob);
+        assert!(sent.outgoing);
+
+        // A third node can't read it
+        assert!(open(&eve_key, &eve, &sealed).is_err());
+
+        // Nor can the envelope be changed
+        let mut tampered = sealed.clone();
+        tampered.sent_at += 1;
+        assert!(open(&bob_key, &bob, &tampered).is_err());
+
+        assert!(seal(&alice_key, &alice, &bob, &"x".repeat(MAX_DM_LENGTH + 1)).is_err());
+        assert!(seal(&alice_key, &alice, "not a node id", "hi").is_err());
+    }
+
+    #[test]
+    fn test_history_skips_unreadable_messages() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        let (alice_key, alice) = identity();
+        let (bob_key, bob) = identity();
+        let (eve_key, eve) = identity();
+        let service = DmService::offline(storage.clone(), alice_key.clone(), alice.clone());
+
+        service.store(&bob, &seal(&alice_key, &alice, &bob, "first").unwrap()).unwrap();
+        storage.put_direct_message(&bob, "00000000000000000001-junk", b"not json").unwrap();
+        // Sealed between others, or for another conversation
+        storage.put_direct_message(&bob, "00000000000000000002-eve", &serde_json::to_vec(&seal(&eve_key, &eve, &bob, "x").unwrap()).unwrap()).unwrap();
+        service.store(&bob, &seal(&alice_key, &alice, &eve, "for eve").unwrap()).unwrap();
+        service.store(&bob, &seal(&bob_key, &bob, &alice, "second").unwrap()).unwrap();
+
+        // Sent in the same millisecond they may come in either order
+        let mut texts: Vec<String> = service.history(&bob, 10).unwrap().into_iter().map(|message| message.text).collect();
+        texts.sort();
+        assert_eq!(texts, ["first", "second"]);
+        // Conversations aren't user databases, so sync can't reach them
+        assert!(storage.list_databases().unwrap().is_empty());
+    }
+}
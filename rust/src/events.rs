@@ -0,0 +1,127 @@
+//! Recent node events for late subscribers
+//!
+//! Events are sent from all over the node, and some go out before the app
+//! subscribes, e.g. `Started` and the first `PeerConnected`. Every event
+//! gets a sequence number and the last `EVENT_HISTORY_CAPACITY` are kept. A
+//! subscriber gets the kept events after the last sequence number it saw,
+//! then new ones as they happen, without gaps or repeats in between.
+
+use std::collections::VecDeque;
+
+use parking_lot::RwLock;
+use tokio::sync::{broadcast, mpsc};
+
+use crate::node::NodeEvent;
+
+/// Events kept for replay; the oldest are dropped beyond that
+pub const EVENT_HISTORY_CAPACITY: usize = 256;
+
+/// An event with its place in the history
+#[derive(Debug, Clone)]
+pub struct RecordedEvent {
+    /// Starts at 1, so 0 is before every event
+    pub seq: u64,
+    /// Unix ms
+    pub timestamp: i64,
+    pub event: NodeEvent,
+}
+
+struct Buffer {
+    events: VecDeque<RecordedEvent>,
+    next_seq: u64,
+}
+
+/// The last events of a node and their live feed
+pub struct EventHistory {
+    buffer: RwLock<Buffer>,
+    live: broadcast::Sender<RecordedEvent>,
+    capacity: usize,
+}
+
+impl Default for EventHistory {
+    fn default() -> Self {
+        Self::new(EVENT_HISTORY_CAPACITY)
+    }
+}
+
+impl EventHistory {
+    pub fn new(capacity: usize) -> Self {
+        let (live, _) = broadcast::channel(capacity.max(1));
+        Self { buffer: RwLock::new(Buffer { events: VecDeque::new(), next_seq: 1 }), live, capacity }
+    }
+
+    /// Record every event sent on `events` until all its senders are gone
+    pub async fn run(&self, mut events: mpsc::Receiver<NodeEvent>) {
+        while let Some(event) = events.recv().await {
+            self.record(event);
+        }
+    }
+
+    /// Keep `event` and hand it to the subscribers; its sequence number
+    pub fn record(&self, event: NodeEvent) -> u64 {
+        let mut buffer = self.buffer.write();
+        let recorded = RecordedEvent {
+            seq: buffer.next_seq,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            event,
+        };
+        buffer.next_seq += 1;
+        if buffer.events.len() >= self.capacity {
+            buffer.events.pop_front();
+        }
+        buffer.events.push_back(recorded.clone());
+        // Sent under the lock so `subscribe` sees each event exactly once
+        let _ = self.live.send(recorded.clone());
+        recorded.seq
+    }
+
+    /// Kept events after `since_seq`, oldest first
+    pub fn since(&self, since_seq: u64) -> Vec<RecordedEvent> {
+        let buffer = self.buffer.read();
+        buffer.events.iter().filter(|recorded| recorded.seq > since_seq).cloned().collect()
+    }
+
+    /// Kept events after `since_seq`, and a receiver of the events after those
+    pub fn subscribe(&self, since_seq: u64) -> (Vec<RecordedEvent>, broadcast::Receiver<RecordedEvent>) {
+        let buffer = self.buffer.read();
+        let replay = buffer.events.iter().filter(|recorded| recorded.seq > since_seq).cloned().collect();
+        (replay, self.live.subscribe())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connected(peer_id: &str) -> NodeEvent {
+        NodeEvent::PeerConnected { peer_id: peer_id.to_string() }
+    }
+
+    fn peer(recorded: &RecordedEvent) -> &str {
+        match &recorded.event {
+            NodeEvent::PeerConnected { peer_id } => peer_id,
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_event_history_replay() {
+        let history = EventHistory::new(2);
+        assert_eq!(history.record(connected("a")), 1);
+        history.record(connected("b"));
+        history.record(connected("c"));
+
+        // Only the last two are kept
+        let kept = history.since(0);
+        assert_eq!(kept.iter().map(peer).collect::<Vec<_>>(), ["b", "c"]);
+        assert_eq!(history.since(2).len(), 1);
+        assert!(history.since(3).is_empty());
+
+        let (replay, mut live) = history.subscribe(2);
+        assert_eq!(replay.iter().map(|recorded| recorded.seq).collect::<Vec<_>>(), [3]);
+        assert!(live.try_recv().is_err());
+        history.record(connected("d"));
+        let next = live.try_recv().unwrap();
+        assert_eq!((next.seq, peer(&next)), (4, "d"));
+    }
+}
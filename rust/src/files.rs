@@ -0,0 +1,357 @@
+//! File transfer over iroh-blobs
+//!
+//! The sender adds the file to its blob store and sends a `FileOffer`
+//! (name, size, blob hash) to the recipient over `FILES_ALPN`. Accepting
+//! downloads the blob from the sender, whose `BlobsProtocol` serves it.
+//! iroh-blobs verifies every chunk against the hash and keeps what arrived,
+//! so a download cut off by a dropped connection is retried from where it
+//! stopped, and an offer accepted again after a restart resumes as well.
+
+use std::future::IntoFuture;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use iroh::endpoint::Connection;
+use iroh::protocol::{AcceptError, ProtocolHandler};
+use iroh::{Endpoint, EndpointId};
+use iroh_blobs::store::fs::FsStore;
+use iroh_blobs::Hash;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+use crate::storage::Storage;
+
+/// ALPN of the file offer protocol
+pub const FILES_ALPN: &[u8] = b"/cyberfly/files/1";
+
+/// Longest file name accepted in an offer
+const MAX_FILE_NAME_LEN: usize = 255;
+
+/// Largest offer accepted
+const MAX_OFFER_SIZE: usize = 1024;
+
+/// Offers kept at most; further ones are rejected until some are accepted or
+/// declined
+const MAX_PENDING_OFFERS: usize = 100;
+
+const OFFER_TIMEOUT: Duration = Duration::from_secs(15);
+
+const ACK: &[u8] = b"ok";
+
+/// How often download progress is reported
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Download attempts before `accept` gives up; accepting again resumes
+const MAX_DOWNLOAD_ATTEMPTS: u32 = 8;
+
+/// Wait before the first retry of an interrupted download, doubled after
+/// each further one up to `MAX_RETRY_DELAY`
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Events buffered for slow `subscribe` receivers
+const CHANNEL_CAPACITY: usize = 256;
+
+/// A file a peer offers to send
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileOffer {
+    pub offer_id: String,
+    /// Node ID of the sender, which serves the blob
+    pub from: String,
+    /// File name without directories
+    pub name: String,
+    pub size: u64,
+    /// iroh-blobs hash of the content
+    pub hash: String,
+    /// Unix ms
+    pub offered_at: i64,
+}
+
+impl FileOffer {
+    /// Checks what a receiver relies on: a bare file name and a valid hash
+    fn validate(&self) -> Result<()> {
+        if !is_bare_file_name(&self.name) {
+            return Err(anyhow!("Invalid file name {:?}", self.name));
+        }
+        self.hash.parse::<Hash>()?;
+        Ok(())
+    }
+}
+
+/// A file name the UI can offer as default destination as is
+fn is_bare_file_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_FILE_NAME_LEN
+        && name != "."
+        && name != ".."
+        && !name.contains(['/', '\\', '\0'])
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileEvent {
+    /// A peer offered a file; `accept` downloads it
+    Offer(FileOffer),
+    Progress { offer_id: String, received_bytes: u64, total_bytes: u64 },
+    /// The download was interrupted and is retried from where it stopped
+    Retrying { offer_id: String, attempt: u32, error: String },
+    Completed { offer_id: String, path: String },
+    /// Gave up after `MAX_DOWNLOAD_ATTEMPTS`; accepting again resumes
+    Failed { offer_id: String, error: String },
+}
+
+/// Offers files to peers and downloads the ones offered to us
+#[derive(Clone)]
+pub struct FileService {
+    /// None for a node without networking
+    network: Option<(Endpoint, FsStore)>,
+    storage: Storage,
+    node_id: String,
+    events: broadcast::Sender<FileEvent>,
+}
+
+impl std::fmt::Debug for FileService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileService").field("node_id", &self.node_id).finish()
+    }
+}
+
+impl FileService {
+    pub fn new(endpoint: Endpoint, store: FsStore, storage: Storage, node_id: String) -> Self {
+        let (events, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { network: Some((endpoint, store)), storage, node_id, events }
+    }
+
+    /// Service of a node without networking; offers kept can still be listed
+    /// and declined
+    pub fn offline(storage: Storage, node_id: String) -> Self {
+        let (events, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { network: None, storage, node_id, events }
+    }
+
+    fn network(&self) -> Result<(&Endpoint, &FsStore)> {
+        let (endpoint, store) = self.network.as_ref().ok_or_else(|| anyhow!("File transfers need the node online"))?;
+        Ok((endpoint, store))
+    }
+
+    /// Offers and transfer progress from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<FileEvent> {
+        self.events.subscribe()
+    }
+
+    /// Add the file at `path` to the blob store and offer it to `peer_id`
+    pub async fn send(&self, peer_id: &str, path: &Path) -> Result<FileOffer> {
+        let peer: EndpointId = peer_id.parse().map_err(|e| anyhow!("Invalid node ID {}: {}", peer_id, e))?;
+        let (endpoint, store) = self.network()?;
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| anyhow!("{} has no file name", path.display()))?
+            .to_string();
+        let size = tokio::fs::metadata(path).await?.len();
+        let tag = store.blobs().add_path(path).await?;
+        let offer = FileOffer {
+            offer_id: uuid::Uuid::new_v4().to_string(),
+            from: self.node_id.clone(),
+            name,
+            size,
+            hash: tag.hash.to_string(),
+            offered_at: Utc::now().timestamp_millis(),
+        };
+        offer.validate()?;
+        let encoded = postcard::to_allocvec(&offer)?;
+
+        tokio::time::timeout(OFFER_TIMEOUT, async {
+            let conn = endpoint.connect(peer, FILES_ALPN).await?;
+            let (mut send, mut recv) = conn.open_bi().await?;
+            send.write_all(&encoded).await?;
+            send.finish()?;
+            let ack = recv.read_to_end(ACK.len()).await?;
+            conn.close(0u32.into(), b"done");
+            if ack != ACK {
+                return Err(anyhow!("{} didn't accept the offer", peer_id));
+            }
+            anyhow::Ok(())
+        })
+        .await
+        .map_err(|_| anyhow!("Offering {} to {} timed out", offer.name, peer_id))??;
+
+        info!("Offered {} ({} bytes) to {}", offer.name, offer.size, peer_id);
+        Ok(offer)
+    }
+
+    /// Offers received and not completed or declined yet
+    pub fn offers(&self) -> Result<Vec<FileOffer>> {
+        let mut offers = self
+            .storage
+            .file_offers()?
+            .into_iter()
+            .filter_map(|value| serde_json::from_slice::<FileOffer>(&value).ok())
+            .collect::<Vec<_>>();
+        offers.sort_by_key(|offer| offer.offered_at);
+        Ok(offers)
+    }
+
+    fn offer(&self, offer_id: &str) -> Result<FileOffer> {
+        let value = self
+            .storage
+            .get_file_offer(offer_id)?
+            .ok_or_else(|| anyhow!("No file offer {}", offer_id))?;
+        Ok(serde_json::from_slice(&value)?)
+    }
+
+    /// Forget an offer without downloading it
+    pub fn decline(&self, offer_id: &str) -> Result<()> {
+        if !self.storage.remove_file_offer(offer_id)? {
+            return Err(anyhow!("No file offer {}", offer_id));
+        }
+        Ok(())
+    }
+
+    /// Download the file of `offer_id` and write it to `dest_path`,
+    /// reporting `FileEvent::Progress`. Interrupted downloads are retried from
+    /// where they stopped. Returns the file size.
+    pub async fn accept(&self, offer_id: &str, dest_path: &Path) -> Result<u64> {
+        let offer = self.offer(offer_id)?;
+        let (endpoint, store) = self.network()?;
+        let hash: Hash = offer.hash.parse()?;
+        let provider: EndpointId = offer.from.parse()?;
+
+        let mut retry_delay = RETRY_DELAY;
+        let mut attempt = 1;
+        loop {
+            match self.download(endpoint, store, &offer, hash, provider).await {
+                Ok(()) => break,
+                Err(e) if attempt < MAX_DOWNLOAD_ATTEMPTS => {
+                    warn!("Download of {} interrupted (attempt {}): {}", offer.name, attempt, e);
+                    let _ = self.events.send(FileEvent::Retrying {
+                        offer_id: offer.offer_id.clone(),
+                        attempt,
+                        error: e.to_string(),
+                    });
+                    tokio::time::sleep(retry_delay).await;
+                    retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+                    attempt += 1;
+                }
+                Err(e) => {
+                    let _ = self.events.send(FileEvent::Failed { offer_id: offer.offer_id.clone(), error: e.to_string() });
+                    return Err(e);
+                }
+            }
+        }
+
+        let size = store.blobs().export(hash, dest_path).await?;
+        self.storage.remove_file_offer(offer_id)?;
+        info!("Received {} ({} bytes) from {}", offer.name, size, offer.from);
+        let _ = self.events.send(FileEvent::Completed {
+            offer_id: offer.offer_id,
+            path: dest_path.display().to_string(),
+        });
+        Ok(size)
+    }
+
+    /// One download attempt; what's already stored isn't fetched again
+    async fn download(
+        &self,
+        endpoint: &Endpoint,
+        store: &FsStore,
+        offer: &FileOffer,
+        hash: Hash,
+        provider: EndpointId,
+    ) -> Result<()> {
+        let downloader = store.downloader(endpoint);
+        let download = downloader.download(hash, vec![provider]).into_future();
+        tokio::pin!(download);
+        let mut progress = tokio::time::interval(PROGRESS_INTERVAL);
+        loop {
+            tokio::select! {
+                result = &mut download => {
+                    result?;
+                    break;
+                }
+                _ = progress.tick() => {
+                    let received_bytes = store.remote().local(hash).await?.local_bytes();
+                    let _ = self.events.send(FileEvent::Progress {
+                        offer_id: offer.offer_id.clone(),
+                        received_bytes,
+                        total_bytes: offer.size,
+                    });
+                }
+            }
+        }
+        if !store.remote().local(hash).await?.is_complete() {
+            return Err(anyhow!("Download of {} ended incomplete", offer.name));
+        }
+        let _ = self.events.send(FileEvent::Progress {
+            offer_id: offer.offer_id.clone(),
+            received_bytes: offer.size,
+            total_bytes: offer.size,
+        });
+        Ok(())
+    }
+
+    /// Keep an offer delivered by `sender`
+    fn receive(&self, sender: EndpointId, encoded: &[u8]) -> Result<FileOffer> {
+        let offer: FileOffer = postcard::from_bytes(encoded)?;
+        if offer.from != sender.to_string() {
+            return Err(anyhow!("Offer from {} delivered by {}", offer.from, sender));
+        }
+        offer.validate()?;
+        if let Some(kept) = self.storage.get_file_offer(&offer.offer_id)? {
+            if serde_json::from_slice::<FileOffer>(&kept)?.from != offer.from {
+                return Err(anyhow!("Offer {} was made by another peer", offer.offer_id));
+            }
+        } else if self.storage.file_offers()?.len() >= MAX_PENDING_OFFERS {
+            return Err(anyhow!("Too many pending file offers"));
+        }
+        self.storage.put_file_offer(&offer.offer_id, &serde_json::to_vec(&offer)?)?;
+        // Nobody listening is fine, the offer is kept
+        let _ = self.events.send(FileEvent::Offer(offer.clone()));
+        Ok(offer)
+    }
+}
+
+impl ProtocolHandler for FileService {
+    async fn accept(&self, connection: Connection) -> Result<(), AcceptError> {
+        let sender = connection.remote_id();
+        let (mut send, mut recv) = connection.accept_bi().await.map_err(AcceptError::from_err)?;
+        let encoded = recv.read_to_end(MAX_OFFER_SIZE).await.map_err(AcceptError::from_err)?;
+        match self.receive(sender, &encoded) {
+            Ok(_) => {
+                send.write_all(ACK).await.map_err(AcceptError::from_err)?;
+            }
+            Err(e) => warn!(peer_id = %sender, "Rejected file offer: {}", e),
+        }
+        send.finish().map_err(AcceptError::from_err)?;
+        connection.closed().await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offer_encoding_and_names() {
+        let offer = FileOffer {
+            offer_id: uuid::Uuid::new_v4().to_string(),
+            from: "a".repeat(64),
+            name: "x".repeat(MAX_FILE_NAME_LEN),
+            size: u64::MAX,
+            hash: "b".repeat(64),
+            offered_at: 1_700_000_000_000,
+        };
+        let encoded = postcard::to_allocvec(&offer).unwrap();
+        assert!(encoded.len() <= MAX_OFFER_SIZE);
+        assert_eq!(postcard::from_bytes::<FileOffer>(&encoded).unwrap(), offer);
+
+        assert!(is_bare_file_name("photo.jpg"));
+        assert!(is_bare_file_name(&offer.name));
+        for name in ["", ".", "..", "../photo.jpg", "dir\\photo.jpg", "a\0b", &"x".repeat(MAX_FILE_NAME_LEN + 1)] {
+            assert!(!is_bare_file_name(name), "{:?}", name);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+//! GraphQL API compatible with the desktop node
+//!
+//! Serves the subset of the cyberfly-rust-node schema that Cyberfly client
+//! code uses, so it works unchanged against a phone: queries read local
+//! storage, and `submitData` takes an operation signed by the client (short
+//! `db_name:key:value` or full format) and publishes it like a local write.
+//! Callable from Dart (`graphql_execute`) and from the debug API's
+//! `POST /graphql`, which only serves queries unless the app allowed writes.
+
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_graphql::{Context, EmptyMutation, EmptySubscription, InputObject, Object, Request, Schema, SimpleObject, Variables};
+use chrono::Utc;
+use once_cell::sync::Lazy;
+
+use crate::crypto;
+use crate::node::CyberflyNode;
+use crate::store_types::StoreType;
+use crate::sync::SignedOperation;
+
+/// Most entries `getAll` and `getAllHash` return
+const MAX_ENTRIES: usize = 10_000;
+
+type CyberflySchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+type ReadOnlySchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Built once; the node a request runs against is passed as request data
+static SCHEMA: Lazy<CyberflySchema> = Lazy::new(|| Schema::build(QueryRoot, MutationRoot, EmptySubscription).finish());
+
+/// `SCHEMA` without mutations
+static READ_ONLY_SCHEMA: Lazy<ReadOnlySchema> =
+    Lazy::new(|| Schema::build(QueryRoot, EmptyMutation, EmptySubscription).finish());
+
+#[derive(SimpleObject)]
+struct NodeInfo {
+    node_id: Option<String>,
+    public_key: String,
+    connected_peers: usize,
+    discovered_peers: usize,
+    uptime_seconds: u64,
+}
+
+#[derive(SimpleObject)]
+struct PeerInfo {
+    node_id: String,
+    public_key: String,
+    name: Option<String>,
+    region: Option<String>,
+    version: Option<String>,
+}
+
+#[derive(SimpleObject)]
+struct StorageEntry {
+    db_name: String,
+    key: String,
+    value: String,
+}
+
+#[derive(SimpleObject)]
+struct HashEntry {
+    field: String,
+    value: String,
+}
+
+#[derive(SimpleObject)]
+struct SubmitResult {
+    success: bool,
+    message: String,
+    op_id: Option<String>,
+}
+
+/// A write signed by the client, as `submitData` takes it
+#[derive(Debug, Clone, InputObject)]
+pub struct SignedData {
+    pub db_name: String,
+    pub key: String,
+    pub value: String,
+    pub public_key: String,
+    pub signature: String,
+    /// String (default), Hash or JSON
+    pub store_type: Option<String>,
+    /// Required for Hash
+    pub field: Option<String>,
+    pub json_path: Option<String>,
+    /// Unix ms; now if absent
+    pub timestamp: Option<i64>,
+}
+
+impl SignedData {
+    /// Operation for this write; the signature is checked when it's submitted
+    pub fn into_operation(self) -> Result<SignedOperation> {
+        let store_type = self.store_type.unwrap_or_else(|| "String".to_string());
+        match store_type.parse::<StoreType>() {
+            Ok(StoreType::String | StoreType::Json) => {}
+            Ok(StoreType::Hash) if self.field.is_some() => {}
+            Ok(StoreType::Hash) => return Err(anyhow!("Field required for Hash type")),
+            _ => return Err(anyhow!("Unsupported store type {}", store_type)),
+        }
+        if self.json_path.is_some() {
+            return Err(anyhow!("JSON paths aren't supported, submit the whole document"));
+        }
+        let mut op = SignedOperation::new(self.db_name, self.key, self.value, store_type, self.public_key, self.signature);
+        op.field = self.field;
+        if let Some(timestamp) = self.timestamp {
+            // The short format doesn't sign the timestamp; a write from the
+            // future would win every later one
+            if timestamp > Utc::now().timestamp_millis() + (crypto::MIN_TIMESTAMP_TOLERANCE as i64) * 1000 {
+                return Err(anyhow!(crypto::TIMESTAMP_TOO_FUTURE));
+            }
+            op.timestamp = timestamp;
+        }
+        Ok(op)
+    }
+}
+
+fn node<'a>(ctx: &Context<'a>) -> &'a Arc<CyberflyNode> {
+    ctx.data_unchecked::<Arc<CyberflyNode>>()
+}
+
+/// Whether `db_name` exists; reads of unknown databases mustn't create them
+fn has_database(node: &CyberflyNode, db_name: &str) -> Result<bool> {
+    Ok(node.list_databases()?.iter().any(|db| db == db_name))
+}
+
+fn get_value(node: &CyberflyNode, db_name: &str, key: &str) -> Result<Option<String>> {
+    if !has_database(node, db_name)? {
+        return Ok(None);
+    }
+    Ok(node.get_many(db_name, &[key.to_string()])?.remove(0).map(|v| String::from_utf8_lossy(&v).to_string()))
+}
+
+fn scan(node: &CyberflyNode, db_name: &str, prefix: &str) -> Result<Vec<(String, String)>> {
+    if !has_database(node, db_name)? {
+        return Ok(Vec::new());
+    }
+    let page = node.scan_prefix(db_name, prefix, MAX_ENTRIES, None)?;
+    Ok(page.entries.into_iter().map(|(key, value)| (key, String::from_utf8_lossy(&value).to_string())).collect())
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn node_info(&self, ctx: &Context<'_>) -> NodeInfo {
+        let node = node(ctx);
+        let status = node.get_status_sync();
+        NodeInfo {
+            node_id: status.node_id,
+            public_key: node.public_key().to_string(),
+            connected_peers: status.connected_peers,
+            discovered_peers: status.discovered_peers,
+            uptime_seconds: status.uptime_seconds,
+        }
+    }
+
+    async fn peers(&self, ctx: &Context<'_>) -> Vec<PeerInfo> {
+        node(ctx)
+            .get_peers_sync()
+            .into_iter()
+            .map(|peer| PeerInfo {
+                node_id: peer.node_id,
+                public_key: peer.public_key,
+                name: peer.name,
+                region: peer.region,
+                version: peer.version,
+            })
+            .collect()
+    }
+
+    async fn get_string(&self, ctx: &Context<'_>, db_name: String, key: String) -> async_graphql::Result<Option<String>> {
+        Ok(get_value(node(ctx), &db_name, &key)?)
+    }
+
+    async fn get_hash(
+        &self,
+        ctx: &Context<'_>,
+        db_name: String,
+        key: String,
+        field: String,
+    ) -> async_graphql::Result<Option<String>> {
+        Ok(get_value(node(ctx), &db_name, &format!("{}:{}", key, field))?)
+    }
+
+    async fn get_all_hash(&self, ctx: &Context<'_>, db_name: String, key: String) -> async_graphql::Result<Vec<HashEntry>> {
+        let prefix = format!("{}:", key);
+        let entries = scan(node(ctx), &db_name, &prefix)?;
+        Ok(entries
+            .into_iter()
+            .map(|(storage_key, value)| HashEntry { field: storage_key[prefix.len()..].to_string(), value })
+            .collect())
+    }
+
+    /// Up to `MAX_ENTRIES` entries of `db_name`
+    async fn get_all(&self, ctx: &Context<'_>, db_name: String) -> async_graphql::Result<Vec<StorageEntry>> {
+        let entries = scan(node(ctx), &db_name, "")?;
+        Ok(entries
+            .into_iter()
+            .map(|(key, value)| StorageEntry { db_name: db_name.clone(), key, value })
+            .collect())
+    }
+}
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn submit_data(&self, ctx: &Context<'_>, input: SignedData) -> SubmitResult {
+        let result = async {
+            let op = input.into_operation()?;
+            let op_id = op.op_id.clone();
+            node(ctx).submit_operation(op).await?;
+            anyhow::Ok(op_id)
+        }
+        .await;
+        match result {
+            Ok(op_id) => SubmitResult { success: true, message: "Data submitted".to_string(), op_id: Some(op_id) },
+            Err(e) => SubmitResult { success: false, message: e.to_string(), op_id: None },
+        }
+    }
+}
+
+/// Execute `query` with `variables` (a JSON object) against `node`; returns
+/// the GraphQL response as JSON, with any errors in its `errors` field
+pub async fn execute(node: Arc<CyberflyNode>, query: &str, variables: Option<&str>) -> Result<String> {
+    let mut request = Request::new(query);
+    if let Some(variables) = variables.filter(|v| !v.trim().is_empty()) {
+        request = request.variables(Variables::from_json(serde_json::from_str(variables)?));
+    }
+    execute_request(node, request).await
+}
+
+/// Execute a GraphQL-over-HTTP request body (`{"query", "variables", "operationName"}`);
+/// mutations are unknown unless `allow_writes`
+pub async fn execute_json(node: Arc<CyberflyNode>, body: &[u8], allow_writes: bool) -> Result<String> {
+    let request: Request = serde_json::from_slice(body)?;
+    if allow_writes {
+        return execute_request(node, request).await;
+    }
+    Ok(serde_json::to_string(&READ_ONLY_SCHEMA.execute(request.data(node)).await)?)
+}
+
+async fn execute_request(node: Arc<CyberflyNode>, request: Request) -> Result<String> {
+    Ok(serde_json::to_string(&SCHEMA.execute(request.data(node)).await)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_data_into_operation() {
+        let (signing_key, public_key) = crypto::generate_keypair();
+        let db_name = format!("notes-{}", public_key);
+        let signature = crypto::sign_message(&signing_key, format!("{}:title:hello", db_name).as_bytes());
+        let input = SignedData {
+            db_name: db_name.clone(),
+            key: "title".to_string(),
+            value: "hello".to_string(),
+            public_key,
+            signature,
+            store_type: None,
+            field: None,
+            json_path: None,
+            timestamp: Some(1_700_000_000_000),
+        };
+
+        // Client signatures use the short format
+        let op = input.clone().into_operation().unwrap();
+        assert_eq!((op.store_type.as_str(), op.timestamp), ("String", 1_700_000_000_000));
+        assert!(op.verify().unwrap());
+        let tampered = SignedData { value: "bye".to_string(), ..input.clone() };
+        assert!(!tampered.into_operation().unwrap().verify().unwrap());
+
+        let hash = SignedData { store_type: Some("Hash".to_string()), field: Some("en".to_string()), ..input.clone() };
+        assert_eq!(hash.into_operation().unwrap().storage_key().unwrap(), "title:en");
+        assert!(SignedData { store_type: Some("Hash".to_string()), ..input.clone() }.into_operation().is_err());
+        assert!(SignedData { store_type: Some("List".to_string()), ..input.clone() }.into_operation().is_err());
+        assert!(SignedData { json_path: Some("$.a".to_string()), ..input.clone() }.into_operation().is_err());
+        let future = Utc::now().timestamp_millis() + 3_600_000;
+        assert!(SignedData { timestamp: Some(future), ..input }.into_operation().is_err());
+    }
+}
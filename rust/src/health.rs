@@ -0,0 +1,243 @@
+//! Health of the node's subsystems for a diagnostics screen
+//!
+//! Each subsystem is judged Ok, Degraded or Failed with a short reason, from
+//! what the event loop knows when asked: whether storage takes writes, which
+//! gossip topics are joined and have neighbors, when sync last succeeded and
+//! discovery last announced us, and whether a relay is connected.
+
+use std::time::Duration;
+
+use crate::config::RelayConfig;
+
+/// Sync older than this counts as stale
+const SYNC_STALE_AFTER: Duration = Duration::from_secs(60 * 60);
+
+/// Announce intervals missed before discovery counts as stalled
+const MISSED_ANNOUNCEMENTS: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Ok,
+    /// Working, but not fully
+    Degraded,
+    Failed,
+}
+
+impl HealthStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HealthStatus::Ok => "ok",
+            HealthStatus::Degraded => "degraded",
+            HealthStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubsystemHealth {
+    pub status: HealthStatus,
+    pub reason: String,
+}
+
+impl SubsystemHealth {
+    fn new(status: HealthStatus, reason: impl Into<String>) -> Self {
+        Self { status, reason: reason.into() }
+    }
+}
+
+/// One of the node's gossip topics
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicHealth {
+    pub topic: String,
+    pub subscribed: bool,
+    pub neighbors: usize,
+}
+
+/// What the health of the subsystems is judged from
+#[derive(Debug, Clone)]
+pub struct HealthInputs {
+    /// Error of the storage write probe
+    pub storage_error: Option<String>,
+    pub last_flush_at: Option<i64>,
+    pub topics: Vec<TopicHealth>,
+    pub last_sync_at: Option<i64>,
+    pub last_announcement_at: Option<i64>,
+    pub announce_interval: Duration,
+    pub relay: RelayConfig,
+    pub relay_url: Option<String>,
+    /// Why the node isn't networking at all, e.g. "networking paused"
+    pub network_off: Option<&'static str>,
+    /// Lite mode (see `power::PowerMode`): only the sync topic is joined, on
+    /// purpose
+    pub lite_mode: bool,
+    /// Time of the check in ms
+    pub now: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    pub storage: SubsystemHealth,
+    pub gossip: SubsystemHealth,
+    pub sync: SubsystemHealth,
+    pub discovery: SubsystemHealth,
+    pub relay: SubsystemHealth,
+    pub topics: Vec<TopicHealth>,
+    pub last_flush_at: Option<i64>,
+    pub last_sync_at: Option<i64>,
+    pub last_announcement_at: Option<i64>,
+}
+
+impl HealthReport {
+    pub fn assess(inputs: HealthInputs) -> Self {
+        let storage = match &inputs.storage_error {
+            Some(e) => SubsystemHealth::new(HealthStatus::Failed, format!("not writable: {}", e)),
+            None => SubsystemHealth::new(HealthStatus::Ok, "writable"),
+        };
+        let (gossip, sync, discovery, relay) = match inputs.network_off {
+            Some(reason) => {
+                let off = SubsystemHealth::new(HealthStatus::Degraded, reason);
+                (off.clone(), off.clone(), off.clone(), off)
+            }
+            None => (
+                gossip_health(&inputs.topics),
+                sync_health(&inputs),
+                discovery_health(&inputs),
+                relay_health(&inputs.relay, inputs.relay_url.as_deref()),
+            ),
+        };
+        Self {
+            storage,
+            gossip,
+            sync,
+            discovery,
+            relay,
+            topics: inputs.topics,
+            last_flush_at: inputs.last_flush_at,
+            last_sync_at: inputs.last_sync_at,
+            last_announcement_at: inputs.last_announcement_at,
+        }
+    }
+}
+
+fn subscribed(topics: &[TopicHealth], name: &str) -> bool {
+    topics.iter().any(|topic| topic.topic == name && topic.subscribed)
+}
+
+fn gossip_health(topics: &[TopicHealth]) -> SubsystemHealth {
+    let unsubscribed: Vec<&str> = topics.iter().filter(|topic| !topic.subscribed).map(|topic| topic.topic.as_str()).collect();
+    if unsubscribed.len() == topics.len() {
+        return SubsystemHealth::new(HealthStatus::Failed, "no topic joined");
+    }
+    if !unsubscribed.is_empty() {
+        return SubsystemHealth::new(HealthStatus::Degraded, format!("not joined: {}", unsubscribed.join(", ")));
+    }
+    if topics.iter().all(|topic| topic.neighbors == 0) {
+        return SubsystemHealth::new(HealthStatus::Degraded, "no neighbors");
+    }
+    SubsystemHealth::new(HealthStatus::Ok, format!("{} topics joined", topics.len()))
+}
+
+fn sync_health(inputs: &HealthInputs) -> SubsystemHealth {
+    if !subscribed(&inputs.topics, "sync") {
+        return SubsystemHealth::new(HealthStatus::Failed, "sync topic not joined");
+    }
+    match inputs.last_sync_at {
+        None => SubsystemHealth::new(HealthStatus::Degraded, "no sync yet"),
+        Some(at) if inputs.now - at > SYNC_STALE_AFTER.as_millis() as i64 => {
+            SubsystemHealth::new(HealthStatus::Degraded, format!("last sync {}s ago", (inputs.now - at) / 1000))
+        }
+        Some(at) => SubsystemHealth::new(HealthStatus::Ok, format!("last sync {}s ago", (inputs.now - at) / 1000)),
+    }
+}
+
+fn discovery_health(inputs: &HealthInputs) -> SubsystemHealth {
+    if inputs.lite_mode {
+        return SubsystemHealth::new(HealthStatus::Ok, "off in lite mode");
+    }
+    if !subscribed(&inputs.topics, "discovery") {
+        return SubsystemHealth::new(HealthStatus::Failed, "discovery topic not joined");
+    }
+    let stalled_after = (inputs.announce_interval * MISSED_ANNOUNCEMENTS).as_millis() as i64;
+    match inputs.last_announcement_at {
+        None => SubsystemHealth::new(HealthStatus::Degraded, "not announced yet"),
+        Some(at) if inputs.now - at > stalled_after => {
+            SubsystemHealth::new(HealthStatus::Degraded, format!("last announcement {}s ago", (inputs.now - at) / 1000))
+        }
+        Some(at) => SubsystemHealth::new(HealthStatus::Ok, format!("last announcement {}s ago", (inputs.now - at) / 1000)),
+    }
+}
+
+fn relay_health(relay: &RelayConfig, relay_url: Option<&str>) -> SubsystemHealth {
+    match (relay, relay_url) {
+        (RelayConfig::Disabled, _) => SubsystemHealth::new(HealthStatus::Ok, "relays disabled"),
+        (_, Some(url)) => SubsystemHealth::new(HealthStatus::Ok, format!("connected to {}", url)),
+        // Direct connections still work
+        (_, None) => SubsystemHealth::new(HealthStatus::Degraded, "no relay connected"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topic(name: &str, subscribed: bool, neighbors: usize) -> TopicHealth {
+        TopicHealth { topic: name.to_string(), subscribed, neighbors }
+    }
+
+    fn inputs() -> HealthInputs {
+        HealthInputs {
+            storage_error: None,
+            last_flush_at: Some(1_000),
+            topics: vec![topic("data", true, 2), topic("sync", true, 1), topic("discovery", true, 0)],
+            last_sync_at: Some(50_000),
+            last_announcement_at: Some(40_000),
+            announce_interval: Duration::from_secs(30),
+            relay: RelayConfig::Default,
+            relay_url: Some("https://relay.example".to_string()),
+            network_off: None,
+            lite_mode: false,
+            now: 60_000,
+        }
+    }
+
+    #[test]
+    fn test_health_assessment() {
+        let report = HealthReport::assess(inputs());
+        for health in [&report.storage, &report.gossip, &report.sync, &report.discovery, &report.relay] {
+            assert_eq!(health.status, HealthStatus::Ok, "{}", health.reason);
+        }
+        assert_eq!(report.sync.reason, "last sync 10s ago");
+
+        let report = HealthReport::assess(HealthInputs {
+            storage_error: Some("disk full".to_string()),
+            topics: vec![topic("data", true, 0), topic("sync", false, 0), topic("discovery", true, 0)],
+            last_announcement_at: Some(-60_000),
+            relay_url: None,
+            ..inputs()
+        });
+        assert_eq!(report.storage, SubsystemHealth::new(HealthStatus::Failed, "not writable: disk full"));
+        assert_eq!(report.gossip, SubsystemHealth::new(HealthStatus::Degraded, "not joined: sync"));
+        assert_eq!(report.sync.status, HealthStatus::Failed);
+        assert_eq!(report.discovery.reason, "last announcement 120s ago");
+        assert_eq!(report.relay.status, HealthStatus::Degraded);
+
+        // Without networking only storage is judged
+        let report = HealthReport::assess(HealthInputs { network_off: Some("offline"), ..inputs() });
+        assert_eq!(report.storage.status, HealthStatus::Ok);
+        assert_eq!(report.gossip, SubsystemHealth::new(HealthStatus::Degraded, "offline"));
+        assert_eq!(report.relay.reason, "offline");
+
+        // Lite mode only keeps the sync topic
+        let report = HealthReport::assess(HealthInputs {
+            topics: vec![topic("sync", true, 1)],
+            last_announcement_at: None,
+            lite_mode: true,
+            ..inputs()
+        });
+        assert_eq!(report.gossip.status, HealthStatus::Ok);
+        assert_eq!(report.discovery, SubsystemHealth::new(HealthStatus::Ok, "off in lite mode"));
+
+        assert_eq!(gossip_health(&[topic("data", false, 0)]).status, HealthStatus::Failed);
+        assert_eq!(relay_health(&RelayConfig::Disabled, None).status, HealthStatus::Ok);
+    }
+}
@@ -0,0 +1,266 @@
+//! Minimal HTTP/1.1 server for local tooling
+//!
+//! Enough HTTP for endpoints used by browsers, curl, Prometheus and GraphQL
+//! clients: one request per connection, bodies by `Content-Length` only,
+//! `Connection: close`. Serves the `/metrics` endpoint and the debug API
+//! (see `debug_api`).
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::Serialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, warn};
+
+/// Time a request may take to arrive
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Largest request head read
+const REQUEST_MAX_BYTES: usize = 8 * 1024;
+
+/// Largest request body read
+const BODY_MAX_BYTES: usize = 1024 * 1024;
+
+/// Request line and body of an HTTP request
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HttpRequest {
+    pub method: String,
+    /// Percent-decoded path segments, e.g. `["databases", "notes", "keys"]`
+    pub segments: Vec<String>,
+    /// Percent-decoded query parameters
+    pub query: HashMap<String, String>,
+    /// Header values by lowercase name
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// Parse the request line and headers of `head`; None if it's
+    /// malformed. The body is left empty.
+    pub fn parse(head: &str) -> Option<Self> {
+        let mut request_line = head.lines().next()?.split_whitespace();
+        let method = request_line.next()?.to_string();
+        let target = request_line.next()?;
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(percent_decode)
+            .collect::<Option<Vec<_>>>()?;
+        let query = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                Some((percent_decode(name)?, percent_decode(value)?))
+            })
+            .collect::<Option<HashMap<_, _>>>()?;
+        let headers = head
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+            .collect();
+        Some(Self { method, segments, query, headers, body: Vec::new() })
+    }
+
+    pub fn is_get(&self) -> bool {
+        self.method == "GET"
+    }
+
+    pub fn is_post(&self) -> bool {
+        self.method == "POST"
+    }
+
+    /// Token of an `Authorization: Bearer <token>` header
+    pub fn bearer_token(&self) -> Option<&str> {
+        self.headers.get("authorization")?.strip_prefix("Bearer ").map(str::trim)
+    }
+
+    /// Query parameter `name` as a number, or `default` if absent
+    pub fn query_usize(&self, name: &str, default: usize) -> Result<usize, String> {
+        match self.query.get(name) {
+            Some(value) => value.parse().map_err(|_| format!("Invalid {}: {}", name, value)),
+            None => Ok(default),
+        }
+    }
+}
+
+/// `+` and `%XX` escapes; None on invalid escapes or UTF-8
+fn percent_decode(input: &str) -> Option<String> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+                decoded.push(u8::from_str_radix(hex, 16).ok()?);
+                i += 3;
+            }
+            b'+' => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Response to an `HttpRequest`
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    /// e.g. "200 OK"
+    pub status: &'static str,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn text(body: String) -> Self {
+        Self { status: "200 OK", content_type: "text/plain; charset=utf-8", body }
+    }
+
+    pub fn json<T: Serialize>(value: &T) -> Self {
+        match serde_json::to_string_pretty(value) {
+            Ok(body) => Self { status: "200 OK", content_type: "application/json", body },
+            Err(e) => Self::error("500 Internal Server Error", e.to_string()),
+        }
+    }
+
+    pub fn error(status: &'static str, message: String) -> Self {
+        Self { status, content_type: "text/plain; charset=utf-8", body: format!("{}\n", message) }
+    }
+
+    pub fn not_found() -> Self {
+        Self::error("404 Not Found", "not found".to_string())
+    }
+
+    pub fn method_not_allowed() -> Self {
+        Self::error("405 Method Not Allowed", "method not allowed".to_string())
+    }
+}
+
+/// `Content-Length` of a request head, 0 if absent
+fn content_length(head: &str) -> Option<usize> {
+    for line in head.lines().skip(1) {
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                return value.trim().parse().ok();
+            }
+        }
+    }
+    Some(0)
+}
+
+/// Answer requests on `listener` with `handle` until the future is dropped
+pub async fn serve<F, Fut>(listener: TcpListener, handle: F)
+where
+    F: Fn(HttpRequest) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = HttpResponse> + Send,
+{
+    let handle = Arc::new(handle);
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!(error = %e, "Failed to accept HTTP connection");
+                // Out of file descriptors and the like; don't spin
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+        };
+        let handle = handle.clone();
+        tokio::spawn(async move {
+            if let Err(e) = answer(stream, handle.as_ref()).await {
+                debug!(error = %e, "HTTP request failed");
+            }
+        });
+    }
+}
+
+async fn answer<F, Fut>(mut stream: TcpStream, handle: &F) -> Result<()>
+where
+    F: Fn(HttpRequest) -> Fut,
+    Fut: Future<Output = HttpResponse>,
+{
+    let mut received = Vec::new();
+    let mut buf = [0u8; 1024];
+    let head_end = loop {
+        if let Some(end) = received.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end + 4;
+        }
+        let read = tokio::time::timeout(REQUEST_TIMEOUT, stream.read(&mut buf)).await??;
+        if read == 0 || received.len() + read > REQUEST_MAX_BYTES {
+            return Ok(());
+        }
+        received.extend_from_slice(&buf[..read]);
+    };
+
+    let head = String::from_utf8_lossy(&received[..head_end]).to_string();
+    let response = match (HttpRequest::parse(&head), content_length(&head)) {
+        (Some(_), Some(length)) if length > BODY_MAX_BYTES => {
+            HttpResponse::error("413 Payload Too Large", "request body too large".to_string())
+        }
+        (Some(mut request), Some(length)) => {
+            let mut body = received.split_off(head_end);
+            if body.len() < length {
+                let mut rest = vec![0u8; length - body.len()];
+                tokio::time::timeout(REQUEST_TIMEOUT, stream.read_exact(&mut rest)).await??;
+                body.extend_from_slice(&rest);
+            }
+            body.truncate(length);
+            request.body = body;
+            handle(request).await
+        }
+        _ => HttpResponse::error("400 Bad Request", "bad request".to_string()),
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        response.body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request() {
+        let request = HttpRequest::parse("GET /databases/my%20notes/keys?prefix=a%2Fb&limit=5 HTTP/1.1\r\nHost: x\r\n\r\n").unwrap();
+        assert!(request.is_get());
+        assert_eq!(request.segments, ["databases", "my notes", "keys"]);
+        assert_eq!(request.query.get("prefix").map(String::as_str), Some("a/b"));
+        assert_eq!(request.query_usize("limit", 100), Ok(5));
+        assert_eq!(request.query_usize("offset", 7), Ok(7));
+
+        assert_eq!(request.headers.get("host").map(String::as_str), Some("x"));
+        assert_eq!(request.bearer_token(), None);
+        let authorized = HttpRequest::parse("GET / HTTP/1.1\r\nauthorization: Bearer abc\r\n\r\n").unwrap();
+        assert_eq!(authorized.bearer_token(), Some("abc"));
+
+        let root = HttpRequest::parse("GET / HTTP/1.1").unwrap();
+        assert!(root.segments.is_empty() && root.query.is_empty());
+        assert!(HttpRequest::parse("GET /bad%zz HTTP/1.1").is_none());
+        assert!(HttpRequest::parse("").is_none());
+        assert!(HttpRequest::parse("GET /?limit=x HTTP/1.1").unwrap().query_usize("limit", 1).is_err());
+
+        assert_eq!(content_length("POST /graphql HTTP/1.1\r\ncontent-length: 42\r\n\r\n"), Some(42));
+        assert_eq!(content_length("GET / HTTP/1.1\r\nHost: x\r\n\r\n"), Some(0));
+        assert_eq!(content_length("POST / HTTP/1.1\r\nContent-Length: -1\r\n\r\n"), None);
+    }
+}
@@ -0,0 +1,211 @@
+//! Node identity rotation
+//!
+//! Retiring a (possibly compromised) node key: the old key signs a
+//! `SuccessorRecord` naming the new node ID, which the new key countersigns.
+//! The node broadcasts the record on the discovery topic until its grace
+//! period ends, and peers keep it so apps can follow the old identity to the
+//! new one. Databases named after the old public key are copied, re-signed,
+//! under the same name with the new public key.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+
+use crate::acl;
+use crate::crypto;
+use crate::storage::Storage;
+use crate::sync::SignedOperation;
+
+/// How long a rotated node keeps broadcasting its successor record by default
+pub const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Our own successor record, broadcast during its grace period
+const OWN_SUCCESSOR_SETTING: &str = "own_successor_record";
+
+/// Successor records of peers, by old node ID
+const SUCCESSOR_SETTING_PREFIX: &str = "successor:";
+
+/// Statement by a node's old key that `new_node_id` succeeds it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuccessorRecord {
+    pub old_node_id: String,
+    pub new_node_id: String,
+    /// Unix ms
+    pub timestamp: i64,
+    /// Unix ms until which the record is broadcast
+    pub grace_until: i64,
+    /// By the old key
+    pub signature: String,
+    /// By the new key, proving the successor holds it
+    pub successor_signature: String,
+}
+
+impl SuccessorRecord {
+    pub fn new(old_key: &SigningKey, new_key: &SigningKey, grace: Duration) -> Self {
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let mut record = Self {
+            old_node_id: crypto::public_key_hex(old_key),
+            new_node_id: crypto::public_key_hex(new_key),
+            timestamp,
+            grace_until: timestamp + grace.as_millis() as i64,
+            signature: String::new(),
+            successor_signature: String::new(),
+        };
+        let message = record.signing_message();
+        record.signature = crypto::sign_message(old_key, message.as_bytes());
+        record.successor_signature = crypto::sign_message(new_key, message.as_bytes());
+        record
+    }
+
+    fn signing_message(&self) -> String {
+        format!("successor:{}:{}:{}:{}", self.old_node_id, self.new_node_id, self.timestamp, self.grace_until)
+    }
+
+    /// Whether both the old and the new key signed the record
+    pub fn verify(&self) -> Result<bool> {
+        if self.old_node_id == self.new_node_id {
+            return Err(anyhow!("Successor is the node itself"));
+        }
+        let message = self.signing_message();
+        Ok(crypto::verify_signature(&self.old_node_id, message.as_bytes(), &self.signature)?
+            && crypto::verify_signature(&self.new_node_id, message.as_bytes(), &self.successor_signature)?)
+    }
+}
+
+/// Outcome of `CyberflyNode::rotate_identity`
+#[derive(Debug, Clone)]
+pub struct IdentityRotation {
+    pub record: SuccessorRecord,
+    /// Old and new names of the re-keyed databases
+    pub rekeyed_databases: Vec<(String, String)>,
+    pub rekeyed_operations: usize,
+}
+
+/// Name under the new key of a database named after `old_public_key`
+pub fn rekeyed_db_name(db_name: &str, old_public_key: &str, new_public_key: &str) -> Option<String> {
+    if acl::db_owner(db_name) != Some(old_public_key) {
+        return None;
+    }
+    crypto::extract_name_from_db(db_name).map(|name| crypto::generate_db_name(&name, new_public_key))
+}
+
+/// Copy of `op` in `db_name` with `value`, signed by `signing_key`. The
+/// timestamp is kept, so last-write-wins still orders the copies like the
+/// originals.
+pub fn rekey_operation(op: &SignedOperation, db_name: &str, value: String, signing_key: &SigningKey) -> SignedOperation {
+    let mut rekeyed = op.clone();
+    rekeyed.op_id = uuid::Uuid::new_v4().to_string();
+    rekeyed.db_name = db_name.to_string();
+    rekeyed.value = value;
+    rekeyed.public_key = crypto::public_key_hex(signing_key);
+    rekeyed.signature = crypto::sign_message(signing_key, rekeyed.full_message().as_bytes());
+    rekeyed
+}
+
+/// Keep our own successor record for broadcasting
+pub fn save_own_record(storage: &Storage, record: &SuccessorRecord) -> Result<()> {
+    storage.put_setting(OWN_SUCCESSOR_SETTING, &serde_json::to_vec(record)?)
+}
+
+/// Our own successor record, naming `public_key` as the successor, while
+/// its grace period lasts
+pub fn own_record(storage: &Storage, public_key: &str, now: i64) -> Result<Option<SuccessorRecord>> {
+    let Some(bytes) = storage.get_setting(OWN_SUCCESSOR_SETTING)? else {
+        return Ok(None);
+    };
+    let record = stored_record(&bytes)?;
+    if record.new_node_id != public_key {
+        return Err(anyhow!("Own successor record names {} as the successor", record.new_node_id));
+    }
+    Ok((now < record.grace_until).then_some(record))
+}
+
+/// Keep a peer's verified successor record; returns whether it was new
+pub fn save_successor(storage: &Storage, record: &SuccessorRecord) -> Result<bool> {
+    if !record.verify()? {
+        return Err(anyhow!("Invalid successor record signature"));
+    }
+    let name = format!("{}{}", SUCCESSOR_SETTING_PREFIX, record.old_node_id);
+    if storage.get_setting(&name)?.is_some() {
+        return Ok(false);
+    }
+    storage.put_setting(&name, &serde_json::to_vec(record)?)?;
+    Ok(true)
+}
+
+/// Successor record of `node_id`, if it was rotated
+pub fn successor_of(storage: &Storage, node_id: &str) -> Result<Option<SuccessorRecord>> {
+    let Some(bytes) = storage.get_setting(&format!("{}{}", SUCCESSOR_SETTING_PREFIX, node_id))? else {
+        return Ok(None);
+    };
+    let record = stored_record(&bytes)?;
+    if record.old_node_id != node_id {
+        return Err(anyhow!("Successor record stored for {} retires {}", node_id, record.old_node_id));
+    }
+    Ok(Some(record))
+}
+
+/// A kept record, checked again as settings aren't trusted blindly
+fn stored_record(bytes: &[u8]) -> Result<SuccessorRecord> {
+    let record: SuccessorRecord = serde_json::from_slice(bytes)?;
+    if !record.verify()? {
+        return Err(anyhow!("Invalid successor record signature"));
+    }
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_successor_record() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        let (old_key, old_public_key) = crypto::generate_keypair();
+        let (new_key, new_public_key) = crypto::generate_keypair();
+
+        let record = SuccessorRecord::new(&old_key, &new_key, Duration::from_secs(60));
+        assert_eq!((record.old_node_id.as_str(), record.new_node_id.as_str()), (old_public_key.as_str(), new_public_key.as_str()));
+        assert!(record.verify().unwrap());
+
+        // Both keys must sign
+        let (other_key, _) = crypto::generate_keypair();
+        let forged = SuccessorRecord { new_node_id: crypto::public_key_hex(&other_key), ..record.clone() };
+        assert!(!forged.verify().unwrap());
+        assert!(save_successor(&storage, &forged).is_err());
+
+        assert!(save_successor(&storage, &record).unwrap());
+        assert!(!save_successor(&storage, &record).unwrap());
+        assert_eq!(successor_of(&storage, &old_public_key).unwrap(), Some(record.clone()));
+        assert_eq!(successor_of(&storage, &new_public_key).unwrap(), None);
+
+        save_own_record(&storage, &record).unwrap();
+        assert!(own_record(&storage, &new_public_key, record.timestamp).unwrap().is_some());
+        assert!(own_record(&storage, &new_public_key, record.grace_until).unwrap().is_none());
+        assert!(own_record(&storage, &old_public_key, record.timestamp).is_err());
+
+        // Kept records are checked again when read
+        let (mallory_key, _) = crypto::generate_keypair();
+        let planted = SuccessorRecord::new(&mallory_key, &other_key, Duration::from_secs(60));
+        storage.put_setting(&format!("{}{}", SUCCESSOR_SETTING_PREFIX, new_public_key), &serde_json::to_vec(&planted).unwrap()).unwrap();
+        assert!(successor_of(&storage, &new_public_key).is_err());
+        let tampered = SuccessorRecord { grace_until: record.grace_until + 1, ..record.clone() };
+        save_own_record(&storage, &tampered).unwrap();
+        assert!(own_record(&storage, &new_public_key, record.timestamp).is_err());
+
+        let db_name = crypto::generate_db_name("notes", &old_public_key);
+        let rekeyed = rekeyed_db_name(&db_name, &old_public_key, &new_public_key).unwrap();
+        assert_eq!(rekeyed, crypto::generate_db_name("notes", &new_public_key));
+        assert_eq!(rekeyed_db_name(&rekeyed, &old_public_key, &new_public_key), None);
+
+        let op = SignedOperation::create_and_sign(db_name, "k".to_string(), "v".to_string(), "String".to_string(), &old_key);
+        let copy = rekey_operation(&op, &rekeyed, op.value.clone(), &new_key);
+        assert!(copy.verify().unwrap());
+        assert_eq!((copy.timestamp, copy.public_key.as_str()), (op.timestamp, new_public_key.as_str()));
+        assert_ne!(copy.op_id, op.op_id);
+    }
+}
@@ -0,0 +1,162 @@
+//! Database sharing invites (capability tickets)
+//!
+//! An invite is an owner-signed ticket naming a database and what the holder
+//! may do with it. Write access is delegated through a fresh writer key: the
+//! owner authorizes its public key in the database's writer list and embeds the
+//! secret in the ticket, so whoever redeems it can sign operations without any
+//! further coordination. The owner revokes it like any other writer.
+//!
+//! Tickets can carry the database encryption key and a writer secret, so they
+//! must be shared over a private channel (QR code, direct message).
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine as _};
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+
+use crate::acl::WriterList;
+use crate::crypto;
+
+/// Token prefix, also identifies the encoding version
+pub const INVITE_TOKEN_PREFIX: &str = "cyberfly-invite:v1:";
+
+/// Invite payload, signed by the database owner
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DbInvite {
+    pub invite_id: String,
+    pub db_name: String,
+    /// Owner public key (hex), must match the db name suffix
+    pub owner: String,
+    /// Database encryption key (hex), for end-to-end encrypted databases
+    pub encryption_key: Option<String>,
+    /// Secret of the delegated writer key (hex); `None` for read-only invites
+    pub writer_secret_key: Option<String>,
+    /// Writer list authorizing the delegated key, so the redeemer can apply it
+    /// before the owner's update reaches it over gossip
+    pub acl: Option<WriterList>,
+    /// Unix timestamp (milliseconds) after which the ticket can't be redeemed
+    pub expires_at: i64,
+}
+
+/// Invite plus the owner's signature over its JSON encoding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SignedInvite {
+    invite: DbInvite,
+    signature: String,
+}
+
+impl DbInvite {
+    /// Whether the ticket grants write access
+    pub fn can_write(&self) -> bool {
+        self.writer_secret_key.is_some()
+    }
+
+    /// Sign the invite with the owner's key and encode it as a token
+    pub fn to_token(&self, owner_key: &SigningKey) -> Result<String> {
+        if crypto::public_key_hex(owner_key) != self.owner {
+            return Err(anyhow!("Invite must be signed by the database owner"));
+        }
+
+        let signature = crypto::sign_message(owner_key, &serde_json::to_vec(self)?);
+        let signed = SignedInvite {
+            invite: self.clone(),
+            signature,
+        };
+        let encoded = general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(&signed)?);
+        Ok(format!("{}{}", INVITE_TOKEN_PREFIX, encoded))
+    }
+
+    /// Decode a token and check the owner's signature and expiry
+    pub fn from_token(token: &str) -> Result<Self> {
+        let body = token
+            .trim()
+            .strip_prefix(INVITE_TOKEN_PREFIX)
+            .ok_or_else(|| anyhow!("Not a database invite token"))?;
+        let json = general_purpose::URL_SAFE_NO_PAD
+            .decode(body)
+            .map_err(|e| anyhow!("Malformed invite token: {}", e))?;
+        let signed: SignedInvite = serde_json::from_slice(&json)?;
+        let invite = signed.invite;
+
+        crypto::verify_db_name_secure(&invite.db_name, &invite.owner)?;
+        let message = serde_json::to_vec(&invite)?;
+        if !crypto::verify_signature(&invite.owner, &message, &signed.signature)? {
+            return Err(anyhow!("Invalid invite signature"));
+        }
+        if invite.expires_at < chrono::Utc::now().timestamp_millis() {
+            return Err(anyhow!("Invite for {} has expired", invite.db_name));
+        }
+        if let Some(acl) = &invite.acl {
+            if acl.db_name != invite.db_name {
+                return Err(anyhow!("Invite writer list is for another database"));
+            }
+        }
+        Ok(invite)
+    }
+
+    /// Delegated writer key carried by the ticket
+    pub fn writer_key(&self) -> Result<Option<SigningKey>> {
+        let Some(secret_hex) = &self.writer_secret_key else {
+            return Ok(None);
+        };
+        let secret: [u8; 32] = crypto::secure_hex_decode(secret_hex)?
+            .try_into()
+            .map_err(|_| anyhow!("Invalid writer key length"))?;
+        Ok(Some(SigningKey::from_bytes(&secret)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invite_for(owner: &str, expires_at: i64) -> DbInvite {
+        DbInvite {
+            invite_id: "invite-1".to_string(),
+            db_name: crypto::generate_db_name("shared", owner),
+            owner: owner.to_string(),
+            encryption_key: Some(hex::encode(crypto::generate_db_key())),
+            writer_secret_key: None,
+            acl: None,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_invite_token_roundtrip() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let invite = invite_for(&owner, chrono::Utc::now().timestamp_millis() + 60_000);
+
+        let token = invite.to_token(&owner_key).unwrap();
+        assert!(token.starts_with(INVITE_TOKEN_PREFIX));
+        assert_eq!(DbInvite::from_token(&token).unwrap(), invite);
+    }
+
+    #[test]
+    fn test_invite_token_rejects_tampering_and_expiry() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let future = chrono::Utc::now().timestamp_millis() + 60_000;
+
+        // Signed by someone other than the owner
+        let (other_key, _) = crypto::generate_keypair();
+        assert!(invite_for(&owner, future).to_token(&other_key).is_err());
+
+        // Expired
+        let expired = invite_for(&owner, 1).to_token(&owner_key).unwrap();
+        assert!(DbInvite::from_token(&expired).is_err());
+
+        // Payload swapped under a valid signature
+        let token = invite_for(&owner, future).to_token(&owner_key).unwrap();
+        let json = general_purpose::URL_SAFE_NO_PAD
+            .decode(token.strip_prefix(INVITE_TOKEN_PREFIX).unwrap())
+            .unwrap();
+        let mut signed: SignedInvite = serde_json::from_slice(&json).unwrap();
+        signed.invite.expires_at += 1;
+        let forged = format!(
+            "{}{}",
+            INVITE_TOKEN_PREFIX,
+            general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(&signed).unwrap())
+        );
+        assert!(DbInvite::from_token(&forged).is_err());
+    }
+}
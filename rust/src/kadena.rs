@@ -0,0 +1,173 @@
+//! Kadena account and transaction utilities
+//!
+//! Lets the app talk to the chain with the node's own key: `k:` accounts are
+//! derived from an ed25519 public key, a command's hash is the unpadded
+//! base64url blake2b-256 of its JSON, and its signature is the hex ed25519
+//! signature of the raw hash bytes. `registration_command` builds the command
+//! that registers the node in the Cyberfly node contract.
+
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine as _};
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use ed25519_dalek::{Signer, SigningKey};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::crypto;
+
+type Blake2b256 = Blake2b<U32>;
+
+/// Module holding the node registry
+pub const NODE_REGISTRY_MODULE: &str = "free.cyberfly_node";
+
+/// Time to live of a command, in seconds
+pub const DEFAULT_TTL_SECS: u64 = 28800;
+pub const DEFAULT_GAS_LIMIT: u64 = 2000;
+pub const DEFAULT_GAS_PRICE: f64 = 0.0000001;
+
+/// Command signed for submission through the Pact API (`/send`, `/local`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KadenaTransaction {
+    pub hash: String,
+    pub sigs: Vec<KadenaSig>,
+    /// The command JSON, exactly as hashed
+    pub cmd: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KadenaSig {
+    pub sig: String,
+}
+
+/// Node registration command parameters
+#[derive(Debug, Clone)]
+pub struct NodeRegistration {
+    /// e.g. "mainnet01" or "testnet04"
+    pub network_id: String,
+    pub chain_id: String,
+    /// libp2p peer ID of the node
+    pub peer_id: String,
+    pub multiaddr: String,
+    /// Node public key (hex); its `k:` account owns the entry and pays gas
+    pub public_key: String,
+    /// Unix seconds
+    pub creation_time: i64,
+    pub nonce: String,
+}
+
+/// `k:` account of an ed25519 public key (hex)
+pub fn account_from_public_key(public_key_hex: &str) -> Result<String> {
+    let public_key = crypto::secure_hex_decode(public_key_hex)?;
+    if public_key.len() != crypto::ED25519_PUBLIC_KEY_LENGTH {
+        return Err(anyhow!(crypto::INVALID_PUBLIC_KEY_LENGTH));
+    }
+    Ok(format!("k:{}", hex::encode(public_key)))
+}
+
+/// Hash of a command as Kadena computes it: unpadded base64url of its blake2b-256
+pub fn hash_command(cmd: &str) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(Blake2b256::digest(cmd.as_bytes()))
+}
+
+/// Hex signature of a command hash
+pub fn sign_hash(signing_key: &SigningKey, hash: &str) -> Result<String> {
+    let hash_bytes = general_purpose::URL_SAFE_NO_PAD
+        .decode(hash)
+        .map_err(|e| anyhow!("Invalid command hash: {}", e))?;
+    if hash_bytes.len() != 32 {
+        return Err(anyhow!("Invalid command hash length: expected 32 bytes, got {}", hash_bytes.len()));
+    }
+    Ok(hex::encode(signing_key.sign(&hash_bytes).to_bytes()))
+}
+
+/// Hash and sign `cmd` with the single key it names as signer
+pub fn sign_command(signing_key: &SigningKey, cmd: &str) -> Result<KadenaTransaction> {
+    let hash = hash_command(cmd);
+    let sig = sign_hash(signing_key, &hash)?;
+    Ok(KadenaTransaction { hash, sigs: vec![KadenaSig { sig }], cmd: cmd.to_string() })
+}
+
+/// Command registering the node, with the node's key as keyset (`ks`),
+/// signer and gas payer
+pub fn registration_command(registration: &NodeRegistration) -> Result<String> {
+    let account = account_from_public_key(&registration.public_key)?;
+    let public_key = registration.public_key.to_lowercase();
+    // JSON string literals are valid Pact string literals
+    let code = format!(
+        "({}.new-node {} \"active\" {} {} (read-keyset \"ks\"))",
+        NODE_REGISTRY_MODULE,
+        serde_json::to_string(&registration.peer_id)?,
+        serde_json::to_string(&registration.multiaddr)?,
+        serde_json::to_string(&account)?,
+    );
+    let cmd = json!({
+        "networkId": registration.network_id,
+        "payload": {
+            "exec": {
+                "code": code,
+                "data": { "ks": { "keys": [public_key], "pred": "keys-all" } },
+            }
+        },
+        "signers": [{
+            "pubKey": public_key,
+            "clist": [
+                { "name": "coin.GAS", "args": [] },
+                { "name": format!("{}.NEW_NODE", NODE_REGISTRY_MODULE), "args": [] },
+            ],
+        }],
+        "meta": {
+            "chainId": registration.chain_id,
+            "sender": account,
+            "gasLimit": DEFAULT_GAS_LIMIT,
+            "gasPrice": DEFAULT_GAS_PRICE,
+            "ttl": DEFAULT_TTL_SECS,
+            "creationTime": registration.creation_time,
+        },
+        "nonce": registration.nonce,
+    });
+    Ok(serde_json::to_string(&cmd)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_registration_command() {
+        let (signing_key, public_key) = crypto::generate_keypair();
+        let account = account_from_public_key(&public_key).unwrap();
+        assert_eq!(account, format!("k:{}", public_key));
+        assert!(account_from_public_key("abcd").is_err());
+
+        assert_eq!(hash_command(r#"{"hello":"kadena"}"#), "iMIhaYUoWvYazxAPA8zjVkck1ZlUmK7js1BetPNSYEE");
+
+        let registration = NodeRegistration {
+            network_id: "testnet04".to_string(),
+            chain_id: "1".to_string(),
+            peer_id: "12D3KooWPeer".to_string(),
+            multiaddr: "/ip4/203.0.113.7/tcp/31001".to_string(),
+            public_key: public_key.clone(),
+            creation_time: 1_700_000_000,
+            nonce: "nonce-1".to_string(),
+        };
+        let cmd = registration_command(&registration).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&cmd).unwrap();
+        assert_eq!(
+            parsed["payload"]["exec"]["code"],
+            format!(
+                "(free.cyberfly_node.new-node \"12D3KooWPeer\" \"active\" \"/ip4/203.0.113.7/tcp/31001\" \"{}\" (read-keyset \"ks\"))",
+                account
+            )
+        );
+        assert_eq!(parsed["meta"]["sender"], account);
+        assert_eq!(parsed["signers"][0]["pubKey"], public_key);
+
+        // The signature covers the raw hash bytes
+        let tx = sign_command(&signing_key, &cmd).unwrap();
+        assert_eq!(tx.hash, hash_command(&cmd));
+        let hash_bytes = general_purpose::URL_SAFE_NO_PAD.decode(&tx.hash).unwrap();
+        assert!(crypto::verify_signature(&public_key, &hash_bytes, &tx.sigs[0].sig).unwrap());
+        assert!(sign_hash(&signing_key, "not a hash").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! Node secret key at rest
+//!
+//! The node key file (`backup::SECRET_KEY_FILE`) holds either the 32 raw key
+//! bytes or, once sealed, the key encrypted with XChaCha20-Poly1305 under a
+//! wrapping key the app keeps in the platform keystore (Android Keystore, iOS
+//! Keychain). The wrapping key is set for the process with
+//! `set_wrapping_key` before the node starts; new keys are sealed with it.
+//!
+//! Sealed file layout: `SEALED_KEY_MAGIC || nonce || ciphertext`
+
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use parking_lot::RwLock;
+use rand::RngCore;
+use tracing::{info, warn};
+
+use crate::crypto;
+
+/// File signature of a sealed key, also identifies the format version
+const SEALED_KEY_MAGIC: &[u8; 8] = b"CFKEY001";
+
+/// Binds the ciphertext to its purpose
+const SEALED_KEY_AAD: &[u8] = b"cyberfly-node-secret-key";
+
+pub const WRAPPING_KEY_LENGTH: usize = 32;
+
+/// Wrapping key of this process
+static WRAPPING_KEY: RwLock<Option<[u8; WRAPPING_KEY_LENGTH]>> = RwLock::new(None);
+
+/// Seal node keys with `key` from now on, or stop sealing new keys with None
+pub fn set_wrapping_key(key: Option<[u8; WRAPPING_KEY_LENGTH]>) {
+    *WRAPPING_KEY.write() = key;
+}
+
+fn wrapping_key() -> Option<[u8; WRAPPING_KEY_LENGTH]> {
+    *WRAPPING_KEY.read()
+}
+
+/// Whether the key file at `path` is sealed
+pub fn is_sealed(path: &Path) -> Result<bool> {
+    Ok(std::fs::read(path)?.starts_with(SEALED_KEY_MAGIC))
+}
+
+/// Read the node key at `path`, unsealing it with the process wrapping key
+pub fn read_key(path: &Path) -> Result<[u8; 32]> {
+    let file = std::fs::read(path)?;
+    if !file.starts_with(SEALED_KEY_MAGIC) {
+        if wrapping_key().is_some() {
+            warn!("Node key at {} is not sealed yet", path.display());
+        }
+        return plain_key(&file);
+    }
+    let wrapping_key = wrapping_key().ok_or_else(|| anyhow!("Node key is sealed; set the wrapping key first"))?;
+    unseal(&file, &wrapping_key)
+}
+
+/// Write the node key to `path`, sealed if a wrapping key is set
+pub fn write_key(path: &Path, key: &[u8; 32]) -> Result<()> {
+    match wrapping_key() {
+        Some(wrapping_key) => write_atomic(path, &seal(key, &wrapping_key)?),
+        None => write_atomic(path, key),
+    }
+}
+
+/// Seal the plaintext key file at `path` with `wrapping_key`
+pub fn seal_key_file(path: &Path, wrapping_key: &[u8; WRAPPING_KEY_LENGTH]) -> Result<()> {
+    let file = std::fs::read(path)?;
+    if file.starts_with(SEALED_KEY_MAGIC) {
+        return Err(anyhow!("Node key is already sealed"));
+    }
+    write_atomic(path, &seal(&plain_key(&file)?, wrapping_key)?)?;
+    info!("Sealed node key at {}", path.display());
+    Ok(())
+}
+
+/// Seal the key file at `path` with `new_key` instead of `old_key`
+pub fn rotate_wrapping_key(
+    path: &Path,
+    old_key: &[u8; WRAPPING_KEY_LENGTH],
+    new_key: &[u8; WRAPPING_KEY_LENGTH],
+) -> Result<()> {
+    let file = std::fs::read(path)?;
+    if !file.starts_with(SEALED_KEY_MAGIC) {
+        return Err(anyhow!("Node key is not sealed"));
+    }
+    write_atomic(path, &seal(&unseal(&file, old_key)?, new_key)?)?;
+    info!("Rotated wrapping key of node key at {}", path.display());
+    Ok(())
+}
+
+fn plain_key(file: &[u8]) -> Result<[u8; 32]> {
+    file.get(..32)
+        .and_then(|key| key.try_into().ok())
+        .ok_or_else(|| anyhow!("Node key file is too short"))
+}
+
+fn seal(key: &[u8; 32], wrapping_key: &[u8; WRAPPING_KEY_LENGTH]) -> Result<Vec<u8>> {
+    let mut nonce = [0u8; crypto::XNONCE_LENGTH];
+    rand::rng().fill_bytes(&mut nonce);
+    let ciphertext = XChaCha20Poly1305::new(wrapping_key.into())
+        .encrypt(XNonce::from_slice(&nonce), Payload { msg: key, aad: SEALED_KEY_AAD })
+        .map_err(|_| anyhow!("Sealing the node key failed"))?;
+
+    let mut file = SEALED_KEY_MAGIC.to_vec();
+    file.extend_from_slice(&nonce);
+    file.extend_from_slice(&ciphertext);
+    Ok(file)
+}
+
+fn unseal(file: &[u8], wrapping_key: &[u8; WRAPPING_KEY_LENGTH]) -> Result<[u8; 32]> {
+    let body = &file[SEALED_KEY_MAGIC.len()..];
+    if body.len() < crypto::XNONCE_LENGTH {
+        return Err(anyhow!("Sealed node key is truncated"));
+    }
+    let (nonce, ciphertext) = body.split_at(crypto::XNONCE_LENGTH);
+    let key = XChaCha20Poly1305::new(wrapping_key.into())
+        .decrypt(XNonce::from_slice(nonce), Payload { msg: ciphertext, aad: SEALED_KEY_AAD })
+        .map_err(|_| anyhow!("Wrong wrapping key or corrupted node key"))?;
+    plain_key(&key)
+}
+
+/// Replace `path` without leaving a half-written key behind
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, contents)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_seal_and_rotate_key_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("secret_key");
+        let key = [7u8; 32];
+        let (old_wrapping, new_wrapping) = ([1u8; 32], [2u8; 32]);
+
+        // Existing plaintext keys are migrated
+        std::fs::write(&path, key).unwrap();
+        assert_eq!(read_key(&path).unwrap(), key);
+        seal_key_file(&path, &old_wrapping).unwrap();
+        assert!(is_sealed(&path).unwrap());
+        assert!(!std::fs::read(&path).unwrap().windows(32).any(|window| window == key));
+        assert!(seal_key_file(&path, &old_wrapping).is_err());
+
+        let file = std::fs::read(&path).unwrap();
+        assert_eq!(unseal(&file, &old_wrapping).unwrap(), key);
+        assert!(unseal(&file, &new_wrapping).is_err());
+
+        rotate_wrapping_key(&path, &old_wrapping, &new_wrapping).unwrap();
+        let file = std::fs::read(&path).unwrap();
+        assert_eq!(unseal(&file, &new_wrapping).unwrap(), key);
+        assert!(rotate_wrapping_key(&path, &old_wrapping, &new_wrapping).is_err());
+
+        let mut tampered = file.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(unseal(&tampered, &new_wrapping).is_err());
+        assert!(unseal(SEALED_KEY_MAGIC, &new_wrapping).is_err());
+    }
+}
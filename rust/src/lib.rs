@@ -3,13 +3,54 @@
 //! P2P networking using Iroh with gossip protocol and sled storage.
 //! Implements peer discovery, sync, and latency measurement matching cyberfly-rust-node.
 
+mod acl;
 mod api;
+mod auth;
+mod backup;
+mod blob_values;
+mod bootstrap;
+mod chunking;
+mod config;
+mod counter;
 mod crypto;
+mod debug_api;
+mod dedup;
+mod devices;
+mod diagnostics;
+mod dial_queue;
 mod discovery;
+mod dm;
+mod events;
+mod files;
+mod graphql;
+mod health;
+mod http;
+mod identity;
+mod invite;
+mod kadena;
+mod keystore;
+mod logs;
+mod merkle;
+mod metrics;
+mod mqtt;
 mod network_resilience;
 mod node;
+mod op_cache;
+mod ping;
+mod power;
+mod presence;
+mod private_network;
+mod query;
+mod rpc;
+mod search;
+mod snapshot;
+mod stats;
 mod storage;
+mod store_types;
 mod sync;
+mod text;
+mod ticket;
+mod typed;
 mod frb_generated;
 
 #[cfg(target_os = "android")]
@@ -20,7 +61,9 @@ pub use api::*;
 // Re-export for external use
 pub use crypto::{sign_message, verify_signature, generate_keypair};
 pub use discovery::{PeerRegistry, PeerAnnouncement, DiscoveredPeer, NodeCapabilities};
-pub use sync::{SyncManager, SyncMessage, SignedOperation, SyncStats};
+pub use sync::{SyncManager, SyncMessage, SignedOperation, SyncStats, PeerSyncStatus, ConflictRecord, SyncFilter};
 pub use node::{CyberflyNode, NodeStatus, NodeEvent, GossipMessage};
 pub use storage::Storage;
+pub use acl::WriterList;
+pub use invite::DbInvite;
 pub use network_resilience::NetworkResilience;
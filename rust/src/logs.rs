@@ -0,0 +1,403 @@
+//! In-memory log buffer and log levels
+//!
+//! Keeps the most recent log records for the app's console and bug reports.
+//! The buffer is a ring: once it holds `capacity` records the oldest is
+//! dropped for each new one. Records keep the logger target (the module
+//! path, or "flutter" for entries added by the app) separately so they can
+//! be filtered by module; messages of the Rust logger start with it too.
+//!
+//! `LogLevels` decides which records are logged at all: a default level and
+//! overrides by target prefix, adjustable while the app runs so support can
+//! raise verbosity (say `iroh=debug`) without a reinstall.
+//!
+//! `SpanLogLayer` turns tracing events into log lines that carry the spans
+//! they happened in with their fields, e.g.
+//! `sync_request{request_id=3f2a9c1e peer=ab12.. ops_sent=40}: Sending sync response`,
+//! so the steps of one sync exchange or connect attempt can be told apart in
+//! a log dump.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{self, Write as _};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use chrono::{TimeZone, Utc};
+use log::{Level, LevelFilter};
+use tracing::field::{Field, Visit};
+use tracing::{span, Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Records kept until `LogBuffer::set_capacity` changes it
+pub const DEFAULT_CAPACITY: usize = 500;
+
+/// Largest buffer that can be configured
+pub const MAX_CAPACITY: usize = 50_000;
+
+/// One log record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    /// Unix ms
+    pub timestamp: i64,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// Which records `LogBuffer::query` returns; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    /// Least severe level included, e.g. `Warn` for warnings and errors
+    pub min_level: Option<Level>,
+    /// Target prefix, e.g. "rust_lib_cyberfly_mobile_node::sync"
+    pub target: Option<String>,
+    /// Case-insensitive text the message contains
+    pub contains: Option<String>,
+    /// Unix ms, inclusive
+    pub since: Option<i64>,
+    /// Unix ms, inclusive
+    pub until: Option<i64>,
+    /// Most recent records returned at most
+    pub limit: Option<usize>,
+}
+
+impl LogFilter {
+    fn matches(&self, record: &LogRecord, contains: Option<&str>) -> bool {
+        self.min_level.is_none_or(|level| record.level <= level)
+            && self.target.as_ref().is_none_or(|target| record.target.starts_with(target.as_str()))
+            && contains.is_none_or(|text| record.message.to_lowercase().contains(text))
+            && self.since.is_none_or(|since| record.timestamp >= since)
+            && self.until.is_none_or(|until| record.timestamp <= until)
+    }
+}
+
+/// Parse a level name such as "warn" or "ERROR"
+pub fn parse_level(level: &str) -> Result<Level> {
+    Level::from_str(level).map_err(|_| anyhow!("Unknown log level {}", level))
+}
+
+/// Parse a level filter name such as "debug" or "off"
+pub fn parse_level_filter(level: &str) -> Result<LevelFilter> {
+    LevelFilter::from_str(level).map_err(|_| anyhow!("Unknown log level {}", level))
+}
+
+/// Levels logged, by target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLevels {
+    /// Level of targets without an override
+    pub default: LevelFilter,
+    /// Levels by target prefix; the longest matching prefix applies
+    overrides: BTreeMap<String, LevelFilter>,
+}
+
+impl Default for LogLevels {
+    /// Info, with iroh's and QUIC's chatter limited to warnings
+    fn default() -> Self {
+        let overrides = ["iroh", "quinn"].into_iter().map(|target| (target.to_string(), LevelFilter::Warn)).collect();
+        Self { default: LevelFilter::Info, overrides }
+    }
+}
+
+impl LogLevels {
+    /// Log `target` (and the targets under it) at `level`, or at the default
+    /// level again with None
+    pub fn set_target(&mut self, target: &str, level: Option<LevelFilter>) -> Result<()> {
+        let valid = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ':');
+        if target.is_empty() || !target.chars().all(valid) {
+            return Err(anyhow!("Invalid log target {:?}", target));
+        }
+        match level {
+            Some(level) => self.overrides.insert(target.to_string(), level),
+            None => self.overrides.remove(target),
+        };
+        Ok(())
+    }
+
+    pub fn level(&self, target: &str) -> LevelFilter {
+        self.overrides
+            .iter()
+            .filter(|(prefix, _)| target.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        level <= self.level(target)
+    }
+
+    /// Most verbose level of any target
+    pub fn max_level(&self) -> LevelFilter {
+        self.overrides.values().copied().fold(self.default, Ord::max)
+    }
+
+    /// As `tracing_subscriber::EnvFilter` directives, e.g. "info,iroh=warn"
+    pub fn directives(&self) -> String {
+        std::iter::once(self.default.as_str().to_lowercase())
+            .chain(self.overrides.iter().map(|(target, level)| format!("{}={}", target, level.as_str().to_lowercase())))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Tracing layer passing each event to a sink as (level, target, line), the
+/// line being the event's spans with their fields, its message and its own
+/// fields
+pub struct SpanLogLayer<F> {
+    sink: F,
+}
+
+impl<F> SpanLogLayer<F>
+where
+    F: Fn(Level, &str, String) + Send + Sync + 'static,
+{
+    pub fn new(sink: F) -> Self {
+        Self { sink }
+    }
+}
+
+/// Fields of a span as formatted so far, kept in its extensions
+struct SpanFields(String);
+
+/// Collects the message and the other fields of an event or span
+#[derive(Default)]
+struct FieldWriter {
+    message: String,
+    /// " name=value" per field
+    fields: String,
+    /// Target of a record forwarded from the `log` crate
+    log_target: Option<String>,
+}
+
+impl Visit for FieldWriter {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message.push_str(value),
+            "log.target" => self.log_target = Some(value.to_string()),
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.fields, " {}={}", name, value);
+            }
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => {
+                let _ = write!(self.message, "{:?}", value);
+            }
+            // File, line and module of records forwarded from `log`
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.fields, " {}={:?}", name, value);
+            }
+        }
+    }
+}
+
+fn log_level(level: &tracing::Level) -> Level {
+    match *level {
+        tracing::Level::ERROR => Level::Error,
+        tracing::Level::WARN => Level::Warn,
+        tracing::Level::INFO => Level::Info,
+        tracing::Level::DEBUG => Level::Debug,
+        tracing::Level::TRACE => Level::Trace,
+    }
+}
+
+impl<S, F> Layer<S> for SpanLogLayer<F>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    F: Fn(Level, &str, String) + Send + Sync + 'static,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let mut writer = FieldWriter::default();
+        attrs.record(&mut writer);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(writer.fields));
+        }
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        let mut writer = FieldWriter::default();
+        values.record(&mut writer);
+        if let Some(span) = ctx.span(id) {
+            let mut extensions = span.extensions_mut();
+            match extensions.get_mut::<SpanFields>() {
+                Some(fields) => fields.0.push_str(&writer.fields),
+                None => extensions.insert(SpanFields(writer.fields)),
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut writer = FieldWriter::default();
+        event.record(&mut writer);
+        let mut line = String::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                let extensions = span.extensions();
+                let fields = extensions.get::<SpanFields>().map_or("", |fields| fields.0.trim_start());
+                let _ = write!(line, "{}{{{}}}:", span.name(), fields);
+            }
+            line.push(' ');
+        }
+        line.push_str(&writer.message);
+        line.push_str(&writer.fields);
+        let target = writer.log_target.as_deref().unwrap_or(event.metadata().target());
+        (self.sink)(log_level(event.metadata().level()), target, line);
+    }
+}
+
+#[derive(Debug)]
+pub struct LogBuffer {
+    records: VecDeque<LogRecord>,
+    capacity: usize,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self { records: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    pub fn push(&mut self, record: LogRecord) {
+        while self.records.len() >= self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    /// Keep up to `capacity` records, dropping the oldest if there are more
+    pub fn set_capacity(&mut self, capacity: usize) -> Result<()> {
+        if capacity == 0 || capacity > MAX_CAPACITY {
+            return Err(anyhow!("Log buffer size must be between 1 and {}", MAX_CAPACITY));
+        }
+        while self.records.len() > capacity {
+            self.records.pop_front();
+        }
+        self.capacity = capacity;
+        Ok(())
+    }
+
+    /// Records matching `filter`, oldest first
+    pub fn query(&self, filter: &LogFilter) -> Vec<LogRecord> {
+        let contains = filter.contains.as_ref().map(|text| text.to_lowercase());
+        let mut records: Vec<LogRecord> = self
+            .records
+            .iter()
+            .rev()
+            .filter(|record| filter.matches(record, contains.as_deref()))
+            .take(filter.limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+        records.reverse();
+        records
+    }
+
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    /// Write every record to `path`, one line each; how many were written
+    pub fn export(&self, path: &Path) -> Result<usize> {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        for record in &self.records {
+            let time = Utc
+                .timestamp_millis_opt(record.timestamp)
+                .single()
+                .map(|time| time.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+                .unwrap_or_else(|| record.timestamp.to_string());
+            writeln!(file, "{} {:<5} {}", time, record.level, record.message)?;
+        }
+        file.flush()?;
+        Ok(self.records.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_buffer_filters() {
+        let mut buffer = LogBuffer::new(4);
+        let record = |timestamp: i64, level: Level, target: &str, message: &str| LogRecord {
+            timestamp,
+            level,
+            target: target.into(),
+            message: message.into(),
+        };
+        buffer.push(record(1, Level::Info, "node", "dropped by the ring"));
+        buffer.push(record(2, Level::Info, "node::sync", "Sync request sent"));
+        buffer.push(record(3, Level::Warn, "node::sync", "Sync peer timed out"));
+        buffer.push(record(4, Level::Error, "node::storage", "Flush failed"));
+        buffer.push(record(5, Level::Debug, "flutter", "Tapped sync"));
+
+        let messages = |filter: LogFilter| -> Vec<String> { buffer.query(&filter).into_iter().map(|r| r.message).collect() };
+        assert_eq!(messages(LogFilter::default()).len(), 4);
+        assert_eq!(messages(LogFilter { min_level: Some(Level::Warn), ..Default::default() }), ["Sync peer timed out", "Flush failed"]);
+        assert_eq!(
+            messages(LogFilter { target: Some("node::sync".into()), contains: Some("SYNC".into()), ..Default::default() }),
+            ["Sync request sent", "Sync peer timed out"]
+        );
+        assert_eq!(messages(LogFilter { since: Some(3), until: Some(4), ..Default::default() }), ["Sync peer timed out", "Flush failed"]);
+        assert_eq!(messages(LogFilter { limit: Some(1), ..Default::default() }), ["Tapped sync"]);
+        assert_eq!(parse_level("warn").unwrap(), Level::Warn);
+        assert!(parse_level("loud").is_err());
+
+        buffer.set_capacity(2).unwrap();
+        assert!(buffer.set_capacity(0).is_err());
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs.txt");
+        assert_eq!(buffer.export(&path).unwrap(), 2);
+        let exported = std::fs::read_to_string(&path).unwrap();
+        assert!(exported.lines().next().unwrap().ends_with("ERROR Flush failed"), "{}", exported);
+    }
+
+    #[test]
+    fn test_span_log_layer() {
+        use std::sync::{Arc, Mutex};
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let sink = lines.clone();
+        let subscriber = tracing_subscriber::registry()
+            .with(SpanLogLayer::new(move |level, target: &str, line| sink.lock().unwrap().push((level, target.to_string(), line))));
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("sync_request", request_id = "3f2a", ops_sent = tracing::field::Empty);
+            let _entered = span.enter();
+            span.record("ops_sent", 40);
+            tracing::warn!(has_more = true, "Sending sync response");
+        });
+
+        let lines = lines.lock().unwrap();
+        assert_eq!(lines[0].0, Level::Warn);
+        assert_eq!(lines[0].1, module_path!());
+        assert_eq!(lines[0].2, "sync_request{request_id=3f2a ops_sent=40}: Sending sync response has_more=true");
+    }
+
+    #[test]
+    fn test_log_levels() {
+        let mut levels = LogLevels::default();
+        assert_eq!(levels.directives(), "info,iroh=warn,quinn=warn");
+        assert!(!levels.enabled("iroh_gossip::net", Level::Info));
+
+        levels.set_target("iroh_gossip", Some(LevelFilter::Debug)).unwrap();
+        assert!(levels.enabled("iroh_gossip::net", Level::Debug));
+        assert!(!levels.enabled("iroh::endpoint", Level::Info));
+        assert_eq!(levels.max_level(), LevelFilter::Debug);
+
+        levels.default = parse_level_filter("off").unwrap();
+        assert!(!levels.enabled("rust_lib_cyberfly_mobile_node::node", Level::Error));
+        levels.set_target("iroh_gossip", None).unwrap();
+        assert_eq!(levels.directives(), "off,iroh=warn,quinn=warn");
+        assert!(levels.set_target("iroh=debug", Some(LevelFilter::Debug)).is_err());
+    }
+}
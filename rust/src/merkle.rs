@@ -0,0 +1,241 @@
+//! Signed Merkle roots of database state
+//!
+//! A database's state is its latest operation per CRDT key. Its tree has a
+//! leaf per key, the hash of (CRDT key, op ID, signature), grouped into
+//! `BUCKETS` buckets by the hash of the key; the root is the hash of the
+//! bucket hashes. The owner of a database, or a device linked to the owner's
+//! key (see `devices`), periodically publishes a signed root with its bucket
+//! hashes on the sync topic. A peer whose replica has another root asks the
+//! publisher for the operations of the buckets that differ.
+
+use anyhow::{anyhow, Result};
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+
+use crate::acl;
+use crate::crypto;
+use crate::devices::DeviceCertificate;
+use crate::sync::SignedOperation;
+
+/// Buckets (ranges of key hashes) a root commits to
+pub const BUCKETS: usize = 16;
+
+/// Bucket of a CRDT key
+pub fn bucket_of(crdt_key: &str) -> u8 {
+    blake3::hash(crdt_key.as_bytes()).as_bytes()[0] >> 4
+}
+
+/// Leaf of an operation in its database's tree
+pub fn leaf_hash(op: &SignedOperation) -> blake3::Hash {
+    let mut leaf = blake3::Hasher::new();
+    for part in [&op.crdt_key(), &op.op_id, &op.signature] {
+        leaf.update(part.as_bytes()).update(b"\n");
+    }
+    leaf.finalize()
+}
+
+/// Bucket hashes of a database's state
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateTree {
+    buckets: Vec<blake3::Hash>,
+}
+
+impl StateTree {
+    /// Tree over the latest operation of every key of one database
+    pub fn build<'a>(operations: impl IntoIterator<Item = &'a SignedOperation>) -> Self {
+        Self::from_leaves(operations.into_iter().map(|op| (op.crdt_key(), leaf_hash(op))))
+    }
+
+    /// Tree over (CRDT key, `leaf_hash`) of the latest operation of every
+    /// key, e.g. from `SyncStore::database_metas`
+    pub fn from_leaves(leaves: impl IntoIterator<Item = (String, blake3::Hash)>) -> Self {
+        let mut buckets: Vec<Vec<(String, blake3::Hash)>> = vec![Vec::new(); BUCKETS];
+        for (crdt_key, leaf) in leaves {
+            buckets[bucket_of(&crdt_key) as usize].push((crdt_key, leaf));
+        }
+        let buckets = buckets
+            .into_iter()
+            .map(|mut bucket| {
+                bucket.sort_by(|a, b| a.0.cmp(&b.0));
+                let mut hasher = blake3::Hasher::new();
+                for (_, leaf) in bucket {
+                    hasher.update(leaf.as_bytes());
+                }
+                hasher.finalize()
+            })
+            .collect();
+        Self { buckets }
+    }
+
+    pub fn root(&self) -> blake3::Hash {
+        root_of(&self.buckets)
+    }
+
+    /// Bucket hashes (hex), in bucket order
+    pub fn bucket_hashes(&self) -> Vec<String> {
+        self.buckets.iter().map(|hash| hash.to_hex().to_string()).collect()
+    }
+
+    /// Buckets whose hash differs from `other` (hex)
+    pub fn mismatching_buckets(&self, other: &[String]) -> Vec<u8> {
+        self.buckets
+            .iter()
+            .enumerate()
+            .filter(|(i, hash)| other.get(*i).is_none_or(|other| *other != hash.to_hex().as_str()))
+            .map(|(i, _)| i as u8)
+            .collect()
+    }
+}
+
+fn root_of(buckets: &[blake3::Hash]) -> blake3::Hash {
+    let mut hasher = blake3::Hasher::new();
+    for bucket in buckets {
+        hasher.update(bucket.as_bytes());
+    }
+    hasher.finalize()
+}
+
+/// A database's root as published by its owner
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignedRoot {
+    pub db_name: String,
+    /// Hex
+    pub root: String,
+    /// Bucket hashes (hex) the root is computed from
+    pub buckets: Vec<String>,
+    /// Keys in the database
+    pub keys: u64,
+    /// Unix ms
+    pub computed_at: i64,
+    /// Node ID of the publisher, which answers repair requests
+    pub publisher: String,
+    /// Link of the publisher to the owner's key, unless it is the owner
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<DeviceCertificate>,
+    /// By the publisher's node key
+    pub signature: String,
+}
+
+impl SignedRoot {
+    /// Sign the root of `tree` with a node key that owns `db_name` or is
+    /// linked to its owner by `certificate`
+    pub fn create(
+        db_name: &str,
+        tree: &StateTree,
+        keys: u64,
+        node_key: &SigningKey,
+        certificate: Option<DeviceCertificate>,
+    ) -> Result<Self> {
+        let mut root = Self {
+            db_name: db_name.to_string(),
+            root: tree.root().to_hex().to_string(),
+            buckets: tree.bucket_hashes(),
+            keys,
+            computed_at: chrono::Utc::now().timestamp_millis(),
+            publisher: crypto::public_key_hex(node_key),
+            certificate,
+            signature: String::new(),
+        };
+        root.check_publisher()?;
+        root.signature = crypto::sign_message(node_key, root.signing_message().as_bytes());
+        Ok(root)
+    }
+
+    fn signing_message(&self) -> String {
+        format!(
+            "merkle-root:{}:{}:{}:{}:{}",
+            self.db_name,
+            self.root,
+            self.buckets.join(","),
+            self.keys,
+            self.computed_at
+        )
+    }
+
+    /// The publisher must be the database owner or a device linked to it
+    fn check_publisher(&self) -> Result<()> {
+        let owner = acl::db_owner(&self.db_name).ok_or_else(|| anyhow!("{} has no owner", self.db_name))?;
+        if self.publisher == owner {
+            return Ok(());
+        }
+        match &self.certificate {
+            Some(certificate)
+                if certificate.user_public_key == owner
+                    && certificate.device_node_id() == self.publisher
+                    && certificate.verify()? =>
+            {
+                Ok(())
+            }
+            _ => Err(anyhow!("{} doesn't act for the owner of {}", self.publisher, self.db_name)),
+        }
+    }
+
+    /// Check the publisher, the signature and that the root matches the buckets
+    pub fn verify(&self) -> Result<()> {
+        self.check_publisher()?;
+        let buckets = self
+            .buckets
+            .iter()
+            .map(|hash| blake3::Hash::from_hex(hash).map_err(|e| anyhow!("Invalid bucket hash: {}", e)))
+            .collect::<Result<Vec<_>>>()?;
+        if buckets.len() != BUCKETS || root_of(&buckets).to_hex().as_str() != self.root {
+            return Err(anyhow!("Root of {} doesn't match its buckets", self.db_name));
+        }
+        if !crypto::verify_signature(&self.publisher, self.signing_message().as_bytes(), &self.signature)? {
+            return Err(anyhow!("Invalid root signature for {}", self.db_name));
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of comparing this replica against a published root
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootCheck {
+    pub db_name: String,
+    pub publisher: String,
+    /// Buckets that differed; empty if the replica matched
+    pub mismatched_buckets: Vec<u8>,
+    /// Unix ms
+    pub checked_at: i64,
+}
+
+impl RootCheck {
+    pub fn matches(&self) -> bool {
+        self.mismatched_buckets.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::devices::LinkRequest;
+
+    #[test]
+    fn test_signed_root() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+        let op = |key: &str, value: &str| {
+            SignedOperation::create_and_sign(db_name.clone(), key.into(), value.into(), "String".into(), &owner_key)
+        };
+        let ops = [op("a", "1"), op("b", "2"), op("c", "3")];
+
+        // Order doesn't matter, a changed key shows in its bucket only
+        let tree = StateTree::build(&ops);
+        assert_eq!(tree, StateTree::build(ops.iter().rev()));
+        let changed = [ops[0].clone(), ops[1].clone(), op("c", "4")];
+        let bucket = bucket_of(&ops[2].crdt_key());
+        assert_eq!(StateTree::build(&changed).mismatching_buckets(&tree.bucket_hashes()), [bucket]);
+
+        let root = SignedRoot::create(&db_name, &tree, 3, &owner_key, None).unwrap();
+        root.verify().unwrap();
+        let mut forged = root.clone();
+        forged.buckets[0] = blake3::hash(b"x").to_hex().to_string();
+        assert!(forged.verify().is_err());
+
+        // Other devices publish only with a link to the owner's key
+        let (device_key, _) = crypto::generate_keypair();
+        assert!(SignedRoot::create(&db_name, &tree, 3, &device_key, None).is_err());
+        let certificate = DeviceCertificate::issue(&owner_key, LinkRequest::new(&device_key, "Tablet").unwrap()).unwrap();
+        SignedRoot::create(&db_name, &tree, 3, &device_key, Some(certificate)).unwrap().verify().unwrap();
+    }
+}
@@ -0,0 +1,586 @@
+//! Bandwidth accounting and data caps
+//!
+//! `BandwidthMeter` counts the bytes of each gossip topic and subsystem in
+//! this session, and the bytes used today and this month (UTC) across
+//! sessions, kept in the `bandwidth_usage` setting. Once a cap in
+//! `BandwidthCaps` is used up the node stops syncing until the day or month
+//! is over: full sync requests are deferred, peers' sync requests go
+//! unanswered and snapshots aren't transferred.
+//!
+//! `MetricsSnapshot` gathers the node's counters and histograms for in-app
+//! dashboards, and renders them in the Prometheus text format, which
+//! `serve_prometheus` serves at `/metrics` for fleet monitoring.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+
+use crate::http::{self, HttpResponse};
+use crate::storage::Storage;
+
+const BANDWIDTH_CAPS_SETTING: &str = "bandwidth_caps";
+const BANDWIDTH_USAGE_SETTING: &str = "bandwidth_usage";
+
+/// Name snapshot blob downloads are counted under
+pub const SNAPSHOTS_TOPIC: &str = "snapshots";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Subsystem {
+    /// Data topic and application topics
+    Gossip,
+    Sync,
+    /// Snapshot downloads over iroh-blobs (uploads are served by iroh-blobs
+    /// and not counted)
+    Blobs,
+    /// Announcements, peer exchange, presence and latency probes
+    Discovery,
+}
+
+impl Subsystem {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Subsystem::Gossip => "gossip",
+            Subsystem::Sync => "sync",
+            Subsystem::Blobs => "blobs",
+            Subsystem::Discovery => "discovery",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ByteCounts {
+    pub sent: u64,
+    pub received: u64,
+}
+
+impl ByteCounts {
+    fn add(&mut self, other: &ByteCounts) {
+        self.sent += other.sent;
+        self.received += other.received;
+    }
+}
+
+/// Gossip messages, or snapshot downloads for `SNAPSHOTS_TOPIC`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MessageCounts {
+    pub sent: u64,
+    pub received: u64,
+    /// Received copies of a recent message, dropped (see `dedup`)
+    pub duplicates: u64,
+}
+
+impl MessageCounts {
+    fn add(&mut self, other: &MessageCounts) {
+        self.sent += other.sent;
+        self.received += other.received;
+        self.duplicates += other.duplicates;
+    }
+}
+
+/// Data caps; traffic in both directions counts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BandwidthCaps {
+    pub daily_bytes: Option<u64>,
+    pub monthly_bytes: Option<u64>,
+    /// Only count traffic on metered connections, e.g. cellular
+    pub metered_only: bool,
+}
+
+/// Bytes used in the current day and month
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct PeriodUsage {
+    /// `%Y-%m-%d`
+    day: String,
+    /// `%Y-%m`
+    month: String,
+    day_bytes: u64,
+    day_metered_bytes: u64,
+    month_bytes: u64,
+    month_metered_bytes: u64,
+}
+
+impl PeriodUsage {
+    /// Start over when `now` is in a new day or month
+    fn roll(&mut self, now: DateTime<Utc>) {
+        let day = now.format("%Y-%m-%d").to_string();
+        if self.day != day {
+            self.day = day;
+            self.day_bytes = 0;
+            self.day_metered_bytes = 0;
+        }
+        let month = now.format("%Y-%m").to_string();
+        if self.month != month {
+            self.month = month;
+            self.month_bytes = 0;
+            self.month_metered_bytes = 0;
+        }
+    }
+
+    fn add(&mut self, now: DateTime<Utc>, bytes: u64, metered: bool) {
+        self.roll(now);
+        self.day_bytes += bytes;
+        self.month_bytes += bytes;
+        if metered {
+            self.day_metered_bytes += bytes;
+            self.month_metered_bytes += bytes;
+        }
+    }
+
+    /// Bytes of the day and month counting towards `caps`
+    fn counted(&self, caps: &BandwidthCaps) -> (u64, u64) {
+        if caps.metered_only {
+            (self.day_metered_bytes, self.month_metered_bytes)
+        } else {
+            (self.day_bytes, self.month_bytes)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BandwidthStats {
+    /// Session traffic per topic, by name
+    pub topics: Vec<(String, Subsystem, ByteCounts)>,
+    /// Session traffic per subsystem
+    pub subsystems: Vec<(Subsystem, ByteCounts)>,
+    /// Bytes counting towards the caps today and this month
+    pub today_bytes: u64,
+    pub month_bytes: u64,
+    pub caps: BandwidthCaps,
+    pub cap_exceeded: bool,
+}
+
+#[derive(Debug)]
+pub struct BandwidthMeter {
+    topics: Mutex<HashMap<String, (Subsystem, ByteCounts)>>,
+    messages: Mutex<HashMap<String, MessageCounts>>,
+    /// Last gossip event received per topic (ms)
+    last_events: Mutex<HashMap<String, i64>>,
+    usage: Mutex<PeriodUsage>,
+    caps: RwLock<BandwidthCaps>,
+    /// The device is on a metered connection
+    metered: AtomicBool,
+}
+
+impl BandwidthMeter {
+    /// Start counting on top of the usage and caps saved in `storage`
+    pub fn load(storage: &Storage) -> Result<Self> {
+        let usage = match storage.get_setting(BANDWIDTH_USAGE_SETTING)? {
+            Some(bytes) => serde_json::from_slice(&bytes)?,
+            None => PeriodUsage::default(),
+        };
+        let caps = match storage.get_setting(BANDWIDTH_CAPS_SETTING)? {
+            Some(bytes) => serde_json::from_slice(&bytes)?,
+            None => BandwidthCaps::default(),
+        };
+        Ok(Self {
+            topics: Mutex::new(HashMap::new()),
+            messages: Mutex::new(HashMap::new()),
+            last_events: Mutex::new(HashMap::new()),
+            usage: Mutex::new(usage),
+            caps: RwLock::new(caps),
+            metered: AtomicBool::new(false),
+        })
+    }
+
+    pub fn record_sent(&self, subsystem: Subsystem, topic: &str, bytes: usize) {
+        let message = MessageCounts { sent: 1, ..Default::default() };
+        self.record(subsystem, topic, ByteCounts { sent: bytes as u64, received: 0 }, message);
+    }
+
+    pub fn record_received(&self, subsystem: Subsystem, topic: &str, bytes: usize) {
+        let message = MessageCounts { received: 1, ..Default::default() };
+        self.record(subsystem, topic, ByteCounts { sent: 0, received: bytes as u64 }, message);
+    }
+
+    /// A duplicate message was received and dropped; its bytes still count
+    pub fn record_duplicate(&self, subsystem: Subsystem, topic: &str, bytes: usize) {
+        let message = MessageCounts { duplicates: 1, ..Default::default() };
+        self.record(subsystem, topic, ByteCounts { sent: 0, received: bytes as u64 }, message);
+    }
+
+    /// A message or neighbor change arrived on `topic`
+    pub fn record_event(&self, topic: &str) {
+        self.last_events.lock().insert(topic.to_string(), Utc::now().timestamp_millis());
+    }
+
+    fn record(&self, subsystem: Subsystem, topic: &str, counts: ByteCounts, message: MessageCounts) {
+        {
+            let mut topics = self.topics.lock();
+            match topics.get_mut(topic) {
+                Some((_, topic_counts)) => topic_counts.add(&counts),
+                None => {
+                    topics.insert(topic.to_string(), (subsystem, counts));
+                }
+            }
+        }
+        {
+            let mut messages = self.messages.lock();
+            match messages.get_mut(topic) {
+                Some(topic_messages) => topic_messages.add(&message),
+                None => {
+                    messages.insert(topic.to_string(), message);
+                }
+            }
+        }
+        let metered = self.metered.load(Ordering::Relaxed);
+        self.usage.lock().add(Utc::now(), counts.sent + counts.received, metered);
+    }
+
+    /// Count traffic from now on as metered or not
+    pub fn set_metered(&self, metered: bool) {
+        self.metered.store(metered, Ordering::Relaxed);
+    }
+
+    pub fn caps(&self) -> BandwidthCaps {
+        *self.caps.read()
+    }
+
+    pub fn set_caps(&self, storage: &Storage, caps: BandwidthCaps) -> Result<()> {
+        storage.put_setting(BANDWIDTH_CAPS_SETTING, &serde_json::to_vec(&caps)?)?;
+        *self.caps.write() = caps;
+        Ok(())
+    }
+
+    /// The daily or monthly cap is used up
+    pub fn cap_exceeded(&self) -> bool {
+        let caps = self.caps();
+        let mut usage = self.usage.lock();
+        usage.roll(Utc::now());
+        Self::exceeds(&usage, &caps)
+    }
+
+    fn exceeds(usage: &PeriodUsage, caps: &BandwidthCaps) -> bool {
+        let (day_bytes, month_bytes) = usage.counted(caps);
+        caps.daily_bytes.is_some_and(|cap| day_bytes >= cap) || caps.monthly_bytes.is_some_and(|cap| month_bytes >= cap)
+    }
+
+    pub fn stats(&self) -> BandwidthStats {
+        let mut topics: Vec<(String, Subsystem, ByteCounts)> = self
+            .topics
+            .lock()
+            .iter()
+            .map(|(name, (subsystem, counts))| (name.clone(), *subsystem, *counts))
+            .collect();
+        topics.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut subsystems: Vec<(Subsystem, ByteCounts)> = [Subsystem::Gossip, Subsystem::Sync, Subsystem::Blobs, Subsystem::Discovery]
+            .into_iter()
+            .map(|subsystem| (subsystem, ByteCounts::default()))
+            .collect();
+        for (_, subsystem, counts) in &topics {
+            if let Some((_, total)) = subsystems.iter_mut().find(|(s, _)| s == subsystem) {
+                total.add(counts);
+            }
+        }
+
+        let caps = self.caps();
+        let mut usage = self.usage.lock();
+        usage.roll(Utc::now());
+        let (today_bytes, month_bytes) = usage.counted(&caps);
+        BandwidthStats {
+            topics,
+            subsystems,
+            today_bytes,
+            month_bytes,
+            caps,
+            cap_exceeded: Self::exceeds(&usage, &caps),
+        }
+    }
+
+    /// Session messages per topic, by name
+    pub fn message_counts(&self) -> Vec<(String, MessageCounts)> {
+        let mut messages: Vec<(String, MessageCounts)> =
+            self.messages.lock().iter().map(|(name, counts)| (name.clone(), *counts)).collect();
+        messages.sort_by(|a, b| a.0.cmp(&b.0));
+        messages
+    }
+
+    /// When the last gossip event of `topic` arrived (ms)
+    pub fn last_event_at(&self, topic: &str) -> Option<i64> {
+        self.last_events.lock().get(topic).copied()
+    }
+
+    /// Save today's and this month's usage
+    pub fn save(&self, storage: &Storage) -> Result<()> {
+        let usage = self.usage.lock().clone();
+        storage.put_setting(BANDWIDTH_USAGE_SETTING, &serde_json::to_vec(&usage)?)
+    }
+}
+
+/// Connect latency bucket bounds in seconds
+pub const CONNECT_LATENCY_BUCKETS: [f64; 8] = [0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// Upper bounds of the buckets, ascending
+    pub bounds: Vec<f64>,
+    /// Observations per bucket; the last one counts those above every bound
+    pub counts: Vec<u64>,
+    pub sum: f64,
+    pub count: u64,
+}
+
+impl Histogram {
+    pub fn new(bounds: &[f64]) -> Self {
+        Self { bounds: bounds.to_vec(), counts: vec![0; bounds.len() + 1], sum: 0.0, count: 0 }
+    }
+
+    pub fn observe(&mut self, value: f64) {
+        let bucket = self.bounds.iter().position(|bound| value <= *bound).unwrap_or(self.bounds.len());
+        self.counts[bucket] += 1;
+        self.sum += value;
+        self.count += 1;
+    }
+
+    /// Observations at or below each bound, then all of them
+    pub fn cumulative(&self) -> Vec<u64> {
+        self.counts
+            .iter()
+            .scan(0, |total, count| {
+                *total += count;
+                Some(*total)
+            })
+            .collect()
+    }
+}
+
+/// Counters and histograms of the running node
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Session messages per topic, by name
+    pub messages: Vec<(String, MessageCounts)>,
+    /// Session bytes per topic, by name
+    pub topic_bytes: Vec<(String, ByteCounts)>,
+    /// Operations written to storage
+    pub ops_applied: u64,
+    /// Operations rejected for a signature that doesn't verify
+    pub verify_failures: u64,
+    /// Operations rejected for exceeding the operation limits
+    pub limit_violations: u64,
+    /// Time to connect to a peer, in seconds
+    pub connect_latency: Histogram,
+    pub storage_size_bytes: u64,
+    pub storage_keys: u64,
+    pub stored_operations: u64,
+    pub outbox_depth: u64,
+}
+
+impl MetricsSnapshot {
+    /// Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        header(&mut out, "cyberfly_gossip_messages_total", "counter", "Gossip messages per topic");
+        for (topic, counts) in &self.messages {
+            for (direction, value) in [("sent", counts.sent), ("received", counts.received)] {
+                let _ = writeln!(
+                    out,
+                    "cyberfly_gossip_messages_total{{topic=\"{}\",direction=\"{}\"}} {}",
+                    escape_label(topic),
+                    direction,
+                    value
+                );
+            }
+        }
+        header(&mut out, "cyberfly_gossip_duplicates_total", "counter", "Duplicate gossip messages dropped per topic");
+        for (topic, counts) in &self.messages {
+            let _ = writeln!(out, "cyberfly_gossip_duplicates_total{{topic=\"{}\"}} {}", escape_label(topic), counts.duplicates);
+        }
+        header(&mut out, "cyberfly_gossip_bytes_total", "counter", "Bytes per topic");
+        for (topic, counts) in &self.topic_bytes {
+            for (direction, value) in [("sent", counts.sent), ("received", counts.received)] {
+                let _ = writeln!(
+                    out,
+                    "cyberfly_gossip_bytes_total{{topic=\"{}\",direction=\"{}\"}} {}",
+                    escape_label(topic),
+                    direction,
+                    value
+                );
+            }
+        }
+        sample(&mut out, "cyberfly_sync_ops_applied_total", "counter", "Operations written to storage", self.ops_applied);
+        sample(
+            &mut out,
+            "cyberfly_verify_failures_total",
+            "counter",
+            "Operations rejected for a bad signature",
+            self.verify_failures,
+        );
+        sample(
+            &mut out,
+            "cyberfly_limit_violations_total",
+            "counter",
+            "Operations rejected for exceeding the operation limits",
+            self.limit_violations,
+        );
+
+        let latency = &self.connect_latency;
+        header(&mut out, "cyberfly_connect_latency_seconds", "histogram", "Time to connect to a peer");
+        let bounds = latency.bounds.iter().map(|bound| bound.to_string()).chain(["+Inf".to_string()]);
+        for (bound, count) in bounds.zip(latency.cumulative()) {
+            let _ = writeln!(out, "cyberfly_connect_latency_seconds_bucket{{le=\"{}\"}} {}", bound, count);
+        }
+        let _ = writeln!(out, "cyberfly_connect_latency_seconds_sum {}", latency.sum);
+        let _ = writeln!(out, "cyberfly_connect_latency_seconds_count {}", latency.count);
+
+        sample(&mut out, "cyberfly_storage_size_bytes", "gauge", "Bytes of keys and values stored", self.storage_size_bytes);
+        sample(&mut out, "cyberfly_storage_keys", "gauge", "Keys stored", self.storage_keys);
+        sample(&mut out, "cyberfly_stored_operations", "gauge", "Operations in the log", self.stored_operations);
+        sample(&mut out, "cyberfly_outbox_depth", "gauge", "Sync messages waiting for a peer", self.outbox_depth);
+        out
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn sample(out: &mut String, name: &str, kind: &str, help: &str, value: u64) {
+    header(out, name, kind, help);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+/// Application topic names are chosen by apps
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Answer `GET /metrics` on `listener` with `render()`, and anything else
+/// with 404, until the future is dropped
+pub async fn serve_prometheus<F>(listener: TcpListener, render: F)
+where
+    F: Fn() -> String + Send + Sync + 'static,
+{
+    http::serve(listener, move |request| {
+        let response = match request.segments.as_slice() {
+            [path] if request.is_get() && path == "metrics" => HttpResponse {
+                content_type: "text/plain; version=0.0.4",
+                ..HttpResponse::text(render())
+            },
+            _ => HttpResponse::not_found(),
+        };
+        std::future::ready(response)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    #[test]
+    fn test_bandwidth_meter() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+
+        let meter = BandwidthMeter::load(&storage).unwrap();
+        meter.record_sent(Subsystem::Sync, "sync", 100);
+        meter.record_received(Subsystem::Sync, "sync", 50);
+        meter.record_received(Subsystem::Discovery, "discovery", 10);
+        meter.record_received(Subsystem::Blobs, SNAPSHOTS_TOPIC, 1000);
+
+        let stats = meter.stats();
+        assert_eq!(stats.topics[2], ("sync".to_string(), Subsystem::Sync, ByteCounts { sent: 100, received: 50 }));
+        assert!(stats.subsystems.contains(&(Subsystem::Blobs, ByteCounts { sent: 0, received: 1000 })));
+        assert!(stats.subsystems.contains(&(Subsystem::Gossip, ByteCounts::default())));
+        assert_eq!(stats.today_bytes, 1160);
+        assert_eq!(meter.last_event_at("sync"), None);
+        meter.record_event("sync");
+        assert!(meter.last_event_at("sync").is_some());
+        assert!(!stats.cap_exceeded);
+
+        // Only metered traffic counts towards a metered-only cap
+        let caps = BandwidthCaps { daily_bytes: Some(500), monthly_bytes: None, metered_only: true };
+        meter.set_caps(&storage, caps).unwrap();
+        assert!(!meter.cap_exceeded());
+        meter.set_metered(true);
+        meter.record_sent(Subsystem::Gossip, "data", 500);
+        assert!(meter.cap_exceeded());
+        assert_eq!(meter.stats().today_bytes, 500);
+
+        // Usage and caps outlive the session
+        meter.save(&storage).unwrap();
+        let reloaded = BandwidthMeter::load(&storage).unwrap();
+        assert_eq!(reloaded.caps(), caps);
+        assert!(reloaded.cap_exceeded());
+        assert!(reloaded.stats().topics.is_empty());
+
+        // A new day lifts the daily cap but not the monthly one
+        reloaded.set_caps(&storage, BandwidthCaps { monthly_bytes: Some(500), ..caps }).unwrap();
+        let mut usage = reloaded.usage.lock().clone();
+        usage.day = "2000-01-01".to_string();
+        usage.roll(Utc::now());
+        assert_eq!(usage.counted(&caps), (0, 500));
+        assert!(!BandwidthMeter::exceeds(&usage, &caps));
+        assert!(BandwidthMeter::exceeds(&usage, &reloaded.caps()));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_export() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        let meter = BandwidthMeter::load(&storage).unwrap();
+        meter.record_sent(Subsystem::Gossip, "app \"chat\"", 10);
+        meter.record_received(Subsystem::Gossip, "app \"chat\"", 20);
+        meter.record_received(Subsystem::Gossip, "app \"chat\"", 30);
+        meter.record_duplicate(Subsystem::Gossip, "app \"chat\"", 30);
+        let counts = MessageCounts { sent: 1, received: 2, duplicates: 1 };
+        assert_eq!(meter.message_counts(), [("app \"chat\"".to_string(), counts)]);
+
+        let mut latency = Histogram::new(&[0.1, 1.0]);
+        for seconds in [0.05, 0.1, 0.5, 3.0] {
+            latency.observe(seconds);
+        }
+        assert_eq!(latency.counts, [2, 1, 1]);
+        assert_eq!(latency.cumulative(), [2, 3, 4]);
+
+        let snapshot = MetricsSnapshot {
+            messages: meter.message_counts(),
+            topic_bytes: meter.stats().topics.into_iter().map(|(name, _, counts)| (name, counts)).collect(),
+            ops_applied: 7,
+            verify_failures: 1,
+            limit_violations: 2,
+            connect_latency: latency,
+            storage_size_bytes: 4096,
+            storage_keys: 12,
+            stored_operations: 7,
+            outbox_depth: 0,
+        };
+        let text = snapshot.to_prometheus();
+        assert!(text.contains("cyberfly_gossip_messages_total{topic=\"app \\\"chat\\\"\",direction=\"received\"} 2\n"));
+        assert!(text.contains("cyberfly_gossip_bytes_total{topic=\"app \\\"chat\\\"\",direction=\"received\"} 80\n"));
+        assert!(text.contains("cyberfly_gossip_duplicates_total{topic=\"app \\\"chat\\\"\"} 1\n"));
+        assert!(text.contains("# TYPE cyberfly_connect_latency_seconds histogram\n"));
+        assert!(text.contains("cyberfly_connect_latency_seconds_bucket{le=\"1\"} 3\n"));
+        assert!(text.contains("cyberfly_connect_latency_seconds_bucket{le=\"+Inf\"} 4\n"));
+        assert!(text.contains("cyberfly_connect_latency_seconds_count 4\n"));
+        assert!(text.contains("cyberfly_verify_failures_total 1\n"));
+        assert!(text.contains("cyberfly_limit_violations_total 2\n"));
+        assert!(text.contains("cyberfly_storage_size_bytes 4096\n"));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(serve_prometheus(listener, move || text.clone()));
+        let get = |path: &'static str| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream.write_all(format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+        let response = get("/metrics").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with("cyberfly_outbox_depth 0\n"));
+        assert!(get("/").await.starts_with("HTTP/1.1 404"));
+        server.abort();
+    }
+}
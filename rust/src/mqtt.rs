@@ -0,0 +1,315 @@
+//! MQTT bridge for local IoT devices
+//!
+//! Lets a phone act as the gateway for sensors that speak MQTT. The bridge
+//! connects to a broker (usually on the LAN) and maps each topic prefix to a
+//! database: a publish on `<prefix>/<rest>` becomes an operation on key
+//! `<rest>` signed by the node key, and a change to a key of the database
+//! (a synced operation or a local write) is published on `<prefix>/<key>`.
+//! Values the bridge wrote itself aren't published back.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use ed25519_dalek::SigningKey;
+use parking_lot::Mutex;
+use rumqttc::{AsyncClient, Event, MqttOptions, Packet, QoS};
+use tokio::sync::{broadcast, mpsc};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use crate::storage::StorageChange;
+use crate::sync::SignedOperation;
+
+pub const DEFAULT_MQTT_PORT: u16 = 1883;
+
+const KEEP_ALIVE: Duration = Duration::from_secs(30);
+
+/// Wait before polling the broker again after a connection error
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Requests buffered between the bridge and the MQTT event loop
+const CLIENT_CAPACITY: usize = 64;
+
+/// Values written from MQTT by (database, key), so their change isn't
+/// published back
+type Echoes = Arc<Mutex<HashMap<(String, String), Vec<u8>>>>;
+
+/// Which way a mapping carries messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BridgeDirection {
+    /// MQTT publishes become operations
+    Inbound,
+    /// Database changes are published to MQTT
+    Outbound,
+    Both,
+}
+
+impl BridgeDirection {
+    fn inbound(self) -> bool {
+        matches!(self, Self::Inbound | Self::Both)
+    }
+
+    fn outbound(self) -> bool {
+        matches!(self, Self::Outbound | Self::Both)
+    }
+}
+
+/// Topics under `topic_prefix` mirror the keys of `db_name`
+#[derive(Debug, Clone)]
+pub struct TopicMapping {
+    /// e.g. "home/sensors"; no wildcards
+    pub topic_prefix: String,
+    pub db_name: String,
+    pub direction: BridgeDirection,
+}
+
+impl TopicMapping {
+    /// Key for a publish on `topic`, if the mapping takes it in
+    fn key_for_topic(&self, topic: &str) -> Option<String> {
+        if !self.direction.inbound() {
+            return None;
+        }
+        let rest = topic.strip_prefix(self.topic_prefix.as_str())?.strip_prefix('/')?;
+        (!rest.is_empty()).then(|| rest.to_string())
+    }
+
+    /// Topic to publish a change of `key` in `db_name` on, if the mapping sends it
+    fn topic_for_key(&self, db_name: &str, key: &str) -> Option<String> {
+        (self.direction.outbound() && self.db_name == db_name).then(|| format!("{}/{}", self.topic_prefix, key))
+    }
+}
+
+/// Broker and topic mappings of the bridge
+#[derive(Debug, Clone)]
+pub struct MqttBridgeConfig {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub mappings: Vec<TopicMapping>,
+}
+
+impl MqttBridgeConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.host.is_empty() || self.client_id.is_empty() {
+            return Err(anyhow!("MQTT host and client ID are required"));
+        }
+        if self.mappings.is_empty() {
+            return Err(anyhow!("MQTT bridge needs at least one topic mapping"));
+        }
+        for mapping in &self.mappings {
+            let prefix = &mapping.topic_prefix;
+            if prefix.is_empty() || prefix.ends_with('/') || prefix.contains(['+', '#']) {
+                return Err(anyhow!("Invalid MQTT topic prefix {:?}", prefix));
+            }
+            if mapping.db_name.is_empty() {
+                return Err(anyhow!("Topic prefix {} has no database", prefix));
+            }
+        }
+        Ok(())
+    }
+
+    /// Database and key of a publish on `topic`
+    pub fn route_inbound(&self, topic: &str) -> Option<(String, String)> {
+        self.mappings
+            .iter()
+            .find_map(|mapping| Some((mapping.db_name.clone(), mapping.key_for_topic(topic)?)))
+    }
+
+    /// Topics to publish a change of `key` in `db_name` on
+    pub fn route_outbound(&self, db_name: &str, key: &str) -> Vec<String> {
+        self.mappings.iter().filter_map(|mapping| mapping.topic_for_key(db_name, key)).collect()
+    }
+
+    fn subscriptions(&self) -> Vec<String> {
+        self.mappings
+            .iter()
+            .filter(|mapping| mapping.direction.inbound())
+            .map(|mapping| format!("{}/#", mapping.topic_prefix))
+            .collect()
+    }
+}
+
+/// Running bridge; stops when dropped or on `stop`
+pub struct MqttBridge {
+    config: MqttBridgeConfig,
+    client: AsyncClient,
+    shutdown: CancellationToken,
+}
+
+impl std::fmt::Debug for MqttBridge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MqttBridge").field("config", &self.config).finish()
+    }
+}
+
+impl MqttBridge {
+    /// Connect to the broker and bridge until `shutdown` is cancelled. Inbound
+    /// publishes come out of the returned receiver as signed operations for
+    /// the node to store and publish; `changes` feeds the outbound side.
+    pub fn start(
+        config: MqttBridgeConfig,
+        signing_key: SigningKey,
+        changes: broadcast::Receiver<StorageChange>,
+        shutdown: CancellationToken,
+    ) -> Result<(Self, mpsc::UnboundedReceiver<SignedOperation>)> {
+        config.validate()?;
+        let mut options = MqttOptions::new(config.client_id.clone(), config.host.clone(), config.port);
+        options.set_keep_alive(KEEP_ALIVE);
+        if let Some(username) = &config.username {
+            options.set_credentials(username.clone(), config.password.clone().unwrap_or_default());
+        }
+        let (client, mut event_loop) = AsyncClient::new(options, CLIENT_CAPACITY);
+        let (ops_tx, ops_rx) = mpsc::unbounded_channel();
+        let echoes = Echoes::default();
+
+        let inbound_config = config.clone();
+        let inbound_client = client.clone();
+        let inbound_echoes = echoes.clone();
+        let inbound_shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            loop {
+                let event = tokio::select! {
+                    _ = inbound_shutdown.cancelled() => break,
+                    event = event_loop.poll() => event,
+                };
+                match event {
+                    // Subscriptions don't survive a reconnect with a clean session
+                    Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                        info!("MQTT bridge connected to {}:{}", inbound_config.host, inbound_config.port);
+                        // Not awaiting: the requests are queued for this very loop
+                        for filter in inbound_config.subscriptions() {
+                            if let Err(e) = inbound_client.try_subscribe(filter.clone(), QoS::AtLeastOnce) {
+                                warn!("Failed to subscribe to MQTT topic {}: {}", filter, e);
+                            }
+                        }
+                    }
+                    Ok(Event::Incoming(Packet::Publish(publish))) => {
+                        let Some((db_name, key)) = inbound_config.route_inbound(&publish.topic) else {
+                            continue;
+                        };
+                        let value = String::from_utf8_lossy(&publish.payload).to_string();
+                        inbound_echoes.lock().insert((db_name.clone(), key.clone()), value.as_bytes().to_vec());
+                        let op = SignedOperation::create_and_sign(db_name, key, value, "String".to_string(), &signing_key);
+                        if ops_tx.send(op).is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        warn!("MQTT bridge connection error: {}", e);
+                        tokio::select! {
+                            _ = inbound_shutdown.cancelled() => break,
+                            _ = tokio::time::sleep(RECONNECT_DELAY) => {}
+                        }
+                    }
+                }
+            }
+            debug!("MQTT bridge event loop stopped");
+        });
+
+        let outbound_config = config.clone();
+        let outbound_client = client.clone();
+        let outbound_shutdown = shutdown.clone();
+        tokio::spawn(async move {
+            let mut changes = changes;
+            loop {
+                let change = tokio::select! {
+                    _ = outbound_shutdown.cancelled() => break,
+                    change = changes.recv() => change,
+                };
+                let change = match change {
+                    Ok(change) => change,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("MQTT bridge missed {} database changes", skipped);
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                let topics = outbound_config.route_outbound(&change.db_name, &change.key);
+                if topics.is_empty() {
+                    continue;
+                }
+                let echo_key = (change.db_name, change.key);
+                let value = change.value.unwrap_or_default();
+                {
+                    let mut echoes = echoes.lock();
+                    if echoes.get(&echo_key) == Some(&value) {
+                        echoes.remove(&echo_key);
+                        continue;
+                    }
+                }
+                for topic in topics {
+                    // Retained, so devices that connect later get the current value
+                    if let Err(e) = outbound_client.publish(topic.clone(), QoS::AtLeastOnce, true, value.clone()).await {
+                        warn!("Failed to publish to MQTT topic {}: {}", topic, e);
+                    }
+                }
+            }
+        });
+
+        Ok((Self { config, client, shutdown }, ops_rx))
+    }
+
+    pub fn config(&self) -> &MqttBridgeConfig {
+        &self.config
+    }
+
+    /// Disconnect from the broker and stop bridging
+    pub async fn stop(&self) {
+        let _ = self.client.disconnect().await;
+        self.shutdown.cancel();
+    }
+}
+
+impl Drop for MqttBridge {
+    fn drop(&mut self) {
+        self.shutdown.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(topic_prefix: &str, db_name: &str, direction: BridgeDirection) -> TopicMapping {
+        TopicMapping { topic_prefix: topic_prefix.to_string(), db_name: db_name.to_string(), direction }
+    }
+
+    #[test]
+    fn test_topic_routing() {
+        let config = MqttBridgeConfig {
+            host: "192.168.1.10".to_string(),
+            port: DEFAULT_MQTT_PORT,
+            client_id: "phone".to_string(),
+            username: None,
+            password: None,
+            mappings: vec![
+                mapping("home/sensors", "sensors", BridgeDirection::Inbound),
+                mapping("home/lights", "lights", BridgeDirection::Both),
+                mapping("home/alerts", "alerts", BridgeDirection::Outbound),
+            ],
+        };
+        config.validate().unwrap();
+        assert_eq!(config.subscriptions(), ["home/sensors/#", "home/lights/#"]);
+
+        assert_eq!(config.route_inbound("home/sensors/kitchen/temp"), Some(("sensors".to_string(), "kitchen/temp".to_string())));
+        assert_eq!(config.route_inbound("home/lights/porch"), Some(("lights".to_string(), "porch".to_string())));
+        assert_eq!(config.route_inbound("home/sensorsx/temp"), None);
+        assert_eq!(config.route_inbound("home/sensors"), None);
+        assert_eq!(config.route_inbound("home/alerts/smoke"), None);
+
+        assert_eq!(config.route_outbound("lights", "porch"), ["home/lights/porch"]);
+        assert_eq!(config.route_outbound("alerts", "smoke"), ["home/alerts/smoke"]);
+        assert!(config.route_outbound("sensors", "kitchen/temp").is_empty());
+
+        for prefix in ["", "home/#", "home/+/temp", "home/"] {
+            let invalid = MqttBridgeConfig { mappings: vec![mapping(prefix, "db", BridgeDirection::Both)], ..config.clone() };
+            assert!(invalid.validate().is_err(), "{:?} accepted", prefix);
+        }
+        assert!(MqttBridgeConfig { mappings: vec![], ..config }.validate().is_err());
+    }
+}
@@ -1,40 +1,141 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use chrono::Utc;
-use dashmap::DashMap;
-use iroh::EndpointId;
-use tokio::sync::Mutex;
-use std::sync::atomic::{AtomicU32, Ordering};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use dashmap::{DashMap, DashSet};
+use iroh::{Endpoint, EndpointId};
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::CancellationToken;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc as StdArc;
 use rand::Rng;
-use iroh::{Endpoint, protocol::Router};
-use iroh_gossip::net::Gossip;
-use std::net::SocketAddr;
+
+use crate::metrics::{Histogram, CONNECT_LATENCY_BUCKETS};
+
+/// Consecutive failed connects after which a peer's circuit opens
+const CIRCUIT_FAILURE_THRESHOLD: u32 = 5;
+/// How long an open circuit refuses connects before letting a probe through
+const CIRCUIT_COOLDOWN_SECS: i64 = 600;
+/// A probe that hasn't reported back by then (e.g. it timed out) is given up on
+const CIRCUIT_PROBE_TIMEOUT_SECS: i64 = 60;
+/// Dials running at once; further ones wait for a slot
+const MAX_CONCURRENT_DIALS: usize = 4;
+
+/// The dial of one peer, from `NetworkResilience::begin_dial`: no other dial
+/// of that peer starts until this is dropped
+pub struct DialPermit {
+    peer_id: EndpointId,
+    dialing: Arc<DashSet<EndpointId>>,
+    _slot: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for DialPermit {
+    fn drop(&mut self) {
+        self.dialing.remove(&self.peer_id);
+    }
+}
+
+/// Circuit breaker state of a peer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Connects go through, subject to backoff
+    Closed,
+    /// Too many consecutive failures; connects are refused until the cooldown ends
+    Open,
+    /// The cooldown is over and a single probe connect is let through
+    HalfOpen,
+}
+
+impl CircuitState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Circuit breaker of a peer that failed to connect since its last success
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerCircuit {
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub last_failure_at: DateTime<Utc>,
+    /// When the circuit last opened
+    pub opened_at: Option<DateTime<Utc>>,
+    /// When the half-open probe was let through
+    probe_started_at: Option<DateTime<Utc>>,
+}
+
+impl PeerCircuit {
+    /// When an open circuit lets a probe through
+    pub fn retry_at(&self) -> Option<DateTime<Utc>> {
+        match self.state {
+            CircuitState::Open => self.opened_at.map(|at| at + chrono::Duration::seconds(CIRCUIT_COOLDOWN_SECS)),
+            _ => None,
+        }
+    }
+
+    fn allows_connect(&self, now: DateTime<Utc>) -> bool {
+        match self.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => self.retry_at().is_none_or(|at| now >= at),
+            CircuitState::HalfOpen => self
+                .probe_started_at
+                .is_none_or(|at| now - at >= chrono::Duration::seconds(CIRCUIT_PROBE_TIMEOUT_SECS)),
+        }
+    }
+}
 
 /// Minimal NetworkResilience helper for mobile crate.
 /// Holds shared backoff state and exposes small helpers used by node.rs.
 pub struct NetworkResilience {
     // backoff map: EndpointId -> (failure_count, next_allowed_time)
     peer_backoff: Arc<DashMap<EndpointId, (u32, chrono::DateTime<chrono::Utc>)>>,
+    // circuit breakers of peers that failed since their last successful connect
+    circuits: Arc<DashMap<EndpointId, PeerCircuit>>,
     // internal state placeholder for future resilience features
     _state: Mutex<u8>,
     // connection attempts counter for current cycle
     connection_attempts: StdArc<AtomicU32>,
     // maximum allowed connection attempts per cycle
-    max_connections_per_cycle: u32,
+    max_connections_per_cycle: AtomicU32,
     // cycle length in seconds
     cycle_secs: u64,
+    // no connection attempts while networking is paused
+    paused: AtomicBool,
+    // ends the background tasks
+    shutdown: CancellationToken,
+    // time successful connects took, in seconds
+    connect_latency: parking_lot::Mutex<Histogram>,
+    // peers being dialed right now
+    dialing: Arc<DashSet<EndpointId>>,
+    // bounds the dials running at once
+    dial_slots: Arc<Semaphore>,
+}
+
+impl Default for NetworkResilience {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl NetworkResilience {
     pub fn new() -> Self {
         Self {
             peer_backoff: Arc::new(DashMap::new()),
+            circuits: Arc::new(DashMap::new()),
             _state: Mutex::new(0),
             connection_attempts: StdArc::new(AtomicU32::new(0)),
-            max_connections_per_cycle: 8,
+            max_connections_per_cycle: AtomicU32::new(8),
             cycle_secs: 30,
+            paused: AtomicBool::new(false),
+            shutdown: CancellationToken::new(),
+            connect_latency: parking_lot::Mutex::new(Histogram::new(&CONNECT_LATENCY_BUCKETS)),
+            dialing: Arc::new(DashSet::new()),
+            dial_slots: Arc::new(Semaphore::new(MAX_CONCURRENT_DIALS)),
         }
     }
 
@@ -55,12 +156,148 @@ impl NetworkResilience {
         self.peer_backoff.clone()
     }
 
+    /// Check whether `peer_id` may be dialed now: it must not be backing off,
+    /// its circuit must let the connect through and the current cycle must
+    /// have attempts left (this takes one). Past an open circuit's cooldown
+    /// this starts its single half-open probe.
+    pub fn check_connect(&self, peer_id: &EndpointId) -> Result<()> {
+        let now = Utc::now();
+        if let Some(back) = self.peer_backoff.get(peer_id) {
+            let (_fails, next_allowed) = *back.value();
+            if now < next_allowed {
+                tracing::debug!(%peer_id, %next_allowed, "Skipping connect due to backoff");
+                return Err(anyhow!("backoff"));
+            }
+        }
+        let circuit = self.circuits.get(peer_id).map(|circuit| *circuit);
+        if circuit.is_some_and(|circuit| !circuit.allows_connect(now)) {
+            tracing::debug!(%peer_id, "Skipping connect: circuit open");
+            return Err(anyhow!("circuit_open"));
+        }
+        if !self.allow_connection_attempt() {
+            tracing::debug!(%peer_id, "Skipping connect: connection attempts exceeded for this cycle");
+            return Err(anyhow!("connection_attempts_exceeded"));
+        }
+        if circuit.is_some_and(|circuit| circuit.state != CircuitState::Closed) {
+            if let Some(mut circuit) = self.circuits.get_mut(peer_id) {
+                circuit.state = CircuitState::HalfOpen;
+                circuit.probe_started_at = Some(now);
+                tracing::info!(%peer_id, "Circuit half-open, probing peer");
+            }
+        }
+        Ok(())
+    }
+
+    /// Claim the dial of `peer_id`, waiting for one of the
+    /// `MAX_CONCURRENT_DIALS` slots. Fails while it is being dialed already,
+    /// so discovery paths learning of the same peer at once dial it once.
+    pub async fn begin_dial(&self, peer_id: EndpointId) -> Result<DialPermit> {
+        if !self.dialing.insert(peer_id) {
+            tracing::debug!(%peer_id, "Skipping connect: already dialing");
+            return Err(anyhow!("dial_in_progress"));
+        }
+        // Releases the claim if this is cancelled while waiting
+        let mut permit = DialPermit { peer_id, dialing: self.dialing.clone(), _slot: None };
+        permit._slot = Some(self.dial_slots.clone().acquire_owned().await?);
+        Ok(permit)
+    }
+
+    /// Random delay of up to a second before a dial, to avoid stampedes.
+    pub async fn connect_jitter(&self) {
+        let jitter_ms: u64 = rand::rng().random_range(0..=1000);
+        tokio::time::sleep(Duration::from_millis(jitter_ms)).await;
+    }
+
+    /// Forget the backoff of `peer_id` and close its circuit after a successful connect.
+    pub fn connect_succeeded(&self, peer_id: &EndpointId) {
+        self.peer_backoff.remove(peer_id);
+        if let Some((_, circuit)) = self.circuits.remove(peer_id) {
+            if circuit.state != CircuitState::Closed {
+                tracing::info!(%peer_id, "Circuit closed after successful connect");
+            }
+        }
+    }
+
+    /// Count how long a successful connect took
+    pub fn record_connect_latency(&self, elapsed: Duration) {
+        self.connect_latency.lock().observe(elapsed.as_secs_f64());
+    }
+
+    /// Time successful connects took, in seconds
+    pub fn connect_latency(&self) -> Histogram {
+        self.connect_latency.lock().clone()
+    }
+
+    /// Back off from `peer_id` exponentially after a failed connect, opening
+    /// its circuit after `CIRCUIT_FAILURE_THRESHOLD` failures in a row or a
+    /// failed probe.
+    pub fn connect_failed(&self, peer_id: &EndpointId) {
+        let now = Utc::now();
+        {
+            let mut circuit = self.circuits.entry(*peer_id).or_insert(PeerCircuit {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                last_failure_at: now,
+                opened_at: None,
+                probe_started_at: None,
+            });
+            circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+            circuit.last_failure_at = now;
+            let open = match circuit.state {
+                CircuitState::Closed => circuit.consecutive_failures >= CIRCUIT_FAILURE_THRESHOLD,
+                CircuitState::Open | CircuitState::HalfOpen => true,
+            };
+            if open {
+                circuit.state = CircuitState::Open;
+                circuit.opened_at = Some(now);
+                circuit.probe_started_at = None;
+                tracing::warn!(%peer_id, failures = circuit.consecutive_failures, "Circuit open, peer unreachable");
+            }
+        }
+
+        let mut failures = 1u32;
+        if let Some(mut entry) = self.peer_backoff.get_mut(peer_id) {
+            failures = entry.value_mut().0.saturating_add(1);
+        }
+        let base_secs = 2u64;
+        let max_secs = 300u64; // 5 minutes
+        let backoff_secs = base_secs.checked_shl(failures - 1).unwrap_or(max_secs).min(max_secs);
+        let next_allowed = Utc::now() + chrono::Duration::seconds(backoff_secs as i64);
+        self.peer_backoff.insert(*peer_id, (failures, next_allowed));
+        tracing::info!(%peer_id, failures, %next_allowed, "Set backoff after failed connect");
+    }
+
+    /// Let the next dial of `peer_id` through right away (e.g. one the user
+    /// asked for). Its circuit stays as it is until that dial reports back.
+    pub fn clear_backoff(&self, peer_id: &EndpointId) {
+        self.peer_backoff.remove(peer_id);
+    }
+
+    /// Circuits of the peers that failed to connect since their last success.
+    pub fn circuits(&self) -> Vec<(EndpointId, PeerCircuit)> {
+        self.circuits.iter().map(|entry| (*entry.key(), *entry.value())).collect()
+    }
+
+    /// Run `task` in the background until it ends or `shutdown` is called.
+    fn spawn(&self, task: impl std::future::Future<Output = ()> + Send + 'static) {
+        let shutdown = self.shutdown.clone();
+        tokio::spawn(async move {
+            shutdown.run_until_cancelled(task).await;
+        });
+    }
+
+    /// Stop the background and bootstrap reconnect tasks, releasing what
+    /// they hold (e.g. the endpoint).
+    pub fn shutdown(&self) {
+        self.shutdown.cancel();
+    }
+
     /// Start a background maintenance task (stub).
     pub fn start_background(self: Arc<Self>) {
         // Reset per-cycle connection attempt counter.
         {
             let this = self.clone();
-            tokio::spawn(async move {
+            self.spawn(async move {
                 loop {
                     tokio::time::sleep(Duration::from_secs(this.cycle_secs)).await;
                     this.connection_attempts.store(0, Ordering::SeqCst);
@@ -72,10 +309,13 @@ impl NetworkResilience {
         // unbounded on long-running mobile nodes that repeatedly fail to connect.
         {
             let pb = self.peer_backoff.clone();
-            tokio::spawn(async move {
+            let circuits = self.circuits.clone();
+            self.spawn(async move {
                 loop {
                     tokio::time::sleep(Duration::from_secs(3600)).await;
                     let cutoff = Utc::now() - chrono::Duration::hours(2);
+                    // Peers that haven't been tried for that long get a fresh start
+                    circuits.retain(|_, circuit| circuit.last_failure_at > cutoff);
                     let before = pb.len();
                     pb.retain(|_, (_, next_allowed)| *next_allowed > cutoff);
                     let removed = before.saturating_sub(pb.len());
@@ -87,11 +327,24 @@ impl NetworkResilience {
         }
     }
 
+    /// Change the connection attempts allowed per cycle, from the current cycle on.
+    pub fn set_max_connections_per_cycle(&self, max_connections: u32) {
+        self.max_connections_per_cycle.store(max_connections, Ordering::SeqCst);
+    }
+
+    /// Deny every connection attempt while `paused`.
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::SeqCst);
+    }
+
     /// Return true if a new connection attempt is allowed in the current cycle.
     /// This increments the internal counter when allowed.
     pub fn allow_connection_attempt(&self) -> bool {
+        if self.paused.load(Ordering::SeqCst) {
+            return false;
+        }
         let prev = self.connection_attempts.fetch_add(1, Ordering::SeqCst);
-        if prev < self.max_connections_per_cycle {
+        if prev < self.max_connections_per_cycle.load(Ordering::SeqCst) {
             true
         } else {
             // revert increment
@@ -101,78 +354,150 @@ impl NetworkResilience {
     }
 
     /// Start bootstrap reconnect tasks: periodically attempt to connect to the
-    /// provided bootstrap peers using the shared backoff map and jitter.
+    /// provided bootstrap peers using the shared backoff map and jitter. The
+    /// first round runs a cycle after startup, which dials them itself.
     pub fn start_bootstrap_reconnects(self: Arc<Self>, endpoint: Endpoint, bootstrap_strings: Vec<String>) {
-        let pb = self.peer_backoff.clone();
         let res_arc = self.clone();
-        tokio::spawn(async move {
+        self.spawn(async move {
             loop {
+                tokio::time::sleep(Duration::from_secs(res_arc.cycle_secs)).await;
                 for peer_str in &bootstrap_strings {
                     if let Some((node_id_str, addr_opt)) = peer_str.split_once('@') {
                         if let Ok(peer_id) = node_id_str.parse::<EndpointId>() {
-                            // Check backoff
-                            if let Some(back) = pb.get(&peer_id) {
-                                let (_fails, next_allowed) = *back.value();
-                                if Utc::now() < next_allowed {
-                                    continue;
-                                }
-                            }
-
-                            // Respect per-cycle connection limits
-                            if !res_arc.allow_connection_attempt() {
-                                // skip this attempt due to cycle limit
+                            // Respect dials in flight, backoff and per-cycle connection limits
+                            let Ok(_permit) = res_arc.begin_dial(peer_id).await else {
+                                continue;
+                            };
+                            if res_arc.check_connect(&peer_id).is_err() {
                                 continue;
                             }
 
                             // jitter per connect attempt to avoid stampedes
-                            let jitter_ms: u64 = {
-                                let mut rng = rand::thread_rng();
-                                rng.gen_range(0..=1000)
-                            };
-                            tokio::time::sleep(Duration::from_millis(jitter_ms)).await;
-
-                            // Build endpoint address if parseable
-                            let res = if let Some(addr_str) = Some(addr_opt.to_string()) {
-                                match addr_str.parse::<SocketAddr>() {
-                                    Ok(socket_addr) => {
-                                        let endpoint_addr = iroh::EndpointAddr::from_parts(
-                                            peer_id,
-                                            vec![iroh::TransportAddr::Ip(socket_addr)],
-                                        );
-                                        tokio::time::timeout(Duration::from_secs(10), endpoint.connect(endpoint_addr, iroh_gossip::ALPN)).await
-                                    }
-                                    Err(_) => {
-                                        tokio::time::timeout(Duration::from_secs(10), endpoint.connect(peer_id, iroh_gossip::ALPN)).await
-                                    }
-                                }
-                            } else {
-                                tokio::time::timeout(Duration::from_secs(10), endpoint.connect(peer_id, iroh_gossip::ALPN)).await
-                            };
+                            res_arc.connect_jitter().await;
+
+                            // Resolved on every attempt so a moved host name is followed
+                            let endpoint_addr = crate::bootstrap::endpoint_addr(peer_id, Some(addr_opt)).await;
+                            let res = tokio::time::timeout(Duration::from_secs(10), endpoint.connect(endpoint_addr, iroh_gossip::ALPN)).await;
 
                             match res {
-                                Ok(Ok(_conn)) => {
-                                    pb.remove(&peer_id);
-                                }
-                                _ => {
-                                    // increase backoff on failure
-                                    let mut failures = 1u32;
-                                    if let Some(mut entry) = pb.get_mut(&peer_id) {
-                                        failures = entry.value_mut().0.saturating_add(1);
-                                    }
-                                    let base_secs = 2u64;
-                                    let max_secs = 300u64;
-                                    let backoff_secs = base_secs.checked_shl((failures - 1) as u32).unwrap_or(max_secs).min(max_secs);
-                                    let next_allowed = Utc::now() + chrono::Duration::seconds(backoff_secs as i64);
-                                    pb.insert(peer_id, (failures, next_allowed));
-                                }
+                                Ok(Ok(_conn)) => res_arc.connect_succeeded(&peer_id),
+                                _ => res_arc.connect_failed(&peer_id),
                             }
                         }
                     }
                 }
-
-                // Wait before next round
-                tokio::time::sleep(Duration::from_secs(30)).await;
             }
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connect_gating() {
+        let resilience = NetworkResilience::new();
+        resilience.set_max_connections_per_cycle(2);
+        let a = iroh::SecretKey::from_bytes(&[1; 32]).public();
+        let b = iroh::SecretKey::from_bytes(&[2; 32]).public();
+
+        // A failed connect backs off from that peer only
+        resilience.check_connect(&a).unwrap();
+        resilience.connect_failed(&a);
+        assert!(resilience.check_connect(&a).is_err());
+        resilience.check_connect(&b).unwrap();
+
+        // Both attempts of the cycle are used up
+        resilience.clear_backoff(&a);
+        assert!(resilience.check_connect(&a).is_err());
+        resilience.set_max_connections_per_cycle(3);
+        resilience.check_connect(&a).unwrap();
+
+        // Failures back off longer each time until a connect succeeds
+        resilience.connect_failed(&b);
+        resilience.connect_failed(&b);
+        assert_eq!(resilience.peer_backoff().get(&b).unwrap().0, 2);
+        resilience.connect_succeeded(&b);
+        assert!(resilience.peer_backoff().get(&b).is_none());
+
+        resilience.set_paused(true);
+        resilience.set_max_connections_per_cycle(10);
+        assert!(resilience.check_connect(&b).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dial_permits() {
+        let resilience = NetworkResilience::new();
+        let peer = |i: u8| iroh::SecretKey::from_bytes(&[i; 32]).public();
+
+        let first = resilience.begin_dial(peer(1)).await.unwrap();
+        assert_eq!(resilience.begin_dial(peer(1)).await.err().unwrap().to_string(), "dial_in_progress");
+        drop(first);
+        let mut permits = vec![resilience.begin_dial(peer(1)).await.unwrap()];
+
+        // Past the cap dials wait for a slot
+        for i in 2..=MAX_CONCURRENT_DIALS as u8 {
+            permits.push(resilience.begin_dial(peer(i)).await.unwrap());
+        }
+        let waiting = tokio::time::timeout(Duration::from_millis(50), resilience.begin_dial(peer(9))).await;
+        assert!(waiting.is_err());
+        // ...without keeping the peer claimed once given up
+        permits.pop();
+        assert!(resilience.begin_dial(peer(9)).await.is_ok());
+    }
+
+    #[test]
+    fn test_circuit_breaker() {
+        let resilience = NetworkResilience::new();
+        resilience.set_max_connections_per_cycle(100);
+        let peer = EndpointId::from_bytes(&[3; 32]).unwrap();
+        let circuit = |resilience: &NetworkResilience| resilience.circuits.get(&peer).map(|c| *c).unwrap();
+
+        for _ in 0..CIRCUIT_FAILURE_THRESHOLD - 1 {
+            resilience.connect_failed(&peer);
+        }
+        assert_eq!(circuit(&resilience).state, CircuitState::Closed);
+        resilience.connect_failed(&peer);
+        assert_eq!(circuit(&resilience).state, CircuitState::Open);
+        assert!(circuit(&resilience).retry_at().is_some());
+
+        // Refused during the cooldown, even without backoff
+        resilience.clear_backoff(&peer);
+        assert_eq!(resilience.check_connect(&peer).unwrap_err().to_string(), "circuit_open");
+
+        // A single probe once it's over
+        let cooled_down = Utc::now() - chrono::Duration::seconds(CIRCUIT_COOLDOWN_SECS + 1);
+        resilience.circuits.get_mut(&peer).unwrap().opened_at = Some(cooled_down);
+        resilience.check_connect(&peer).unwrap();
+        assert_eq!(circuit(&resilience).state, CircuitState::HalfOpen);
+        assert!(resilience.check_connect(&peer).is_err());
+
+        // A failed probe opens it again, a successful one closes it
+        resilience.connect_failed(&peer);
+        assert_eq!(circuit(&resilience).state, CircuitState::Open);
+        resilience.circuits.get_mut(&peer).unwrap().opened_at = Some(cooled_down);
+        resilience.clear_backoff(&peer);
+        resilience.check_connect(&peer).unwrap();
+        resilience.connect_succeeded(&peer);
+        assert!(resilience.circuits().is_empty());
+        resilience.check_connect(&peer).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_ends_background_tasks() {
+        let resilience = Arc::new(NetworkResilience::new());
+        resilience.clone().start_background();
+        assert!(Arc::strong_count(&resilience) > 1);
+
+        resilience.shutdown();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while Arc::strong_count(&resilience) > 1 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+}
+
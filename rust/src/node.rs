@@ -6,44 +6,89 @@
 //! Implements the same logic as cyberfly-rust-node for peer connect, gossip,
 //! storage, sync, discovery, and latency measurement.
 
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 use chrono::Utc;
-use rand::Rng;
 
 use anyhow::{anyhow, Result};
 use bytes::Bytes;
 use dashmap::DashMap;
 use ed25519_dalek::SigningKey;
+use futures::stream::BoxStream;
 use futures::StreamExt;
-use iroh::{Endpoint, EndpointId, SecretKey, protocol::Router};
-use iroh::endpoint::presets;
+use iroh::{Endpoint, EndpointId, SecretKey, Watcher, protocol::Router};
+use iroh::endpoint::{presets, Connection};
+use iroh::protocol::{AcceptError, ProtocolHandler};
 use iroh::address_lookup::pkarr::dht::DhtAddressLookup;
 use iroh::address_lookup::mdns::MdnsAddressLookup;
 use iroh_blobs::BlobsProtocol;
+use iroh_blobs::store::fs::FsStore;
 use iroh_gossip::net::Gossip;
 use iroh_gossip::proto::TopicId;
-use iroh_gossip::api::{Event as GossipEvent, GossipSender};
+use iroh_gossip::api::{ApiError, Event as GossipEvent, GossipSender, GossipTopic};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
-use tokio::sync::{mpsc, oneshot, Mutex};
-use tracing::{error, info, warn, debug};
+use tokio::sync::{broadcast, mpsc, oneshot, watch, Mutex};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, info_span, warn, debug, Instrument};
 
 // Also use log macros for Android logcat output
 #[allow(unused_imports)]
 use log::{info as log_info, error as log_error, warn as log_warn};
 
-use crate::storage::Storage;
-use crate::sync::{SyncManager, SyncMessage, SignedOperation};
+use crate::acl::{self, DbTombstone};
+use crate::auth::{self, AuthProtocol, VerifiedBinding};
+use crate::backup::{self, BackupSummary};
+use crate::chunking::{self, MessageTooLarge, Reassembler};
+use crate::config::{NodeConfig, RelayConfig};
+use crate::query::{self, QueryResult, QuerySpec};
+use crate::crypto;
+use crate::debug_api;
+use crate::dedup::DedupWindow;
+use crate::devices::{self, DeviceCertificate, LinkRequest};
+use crate::diagnostics::{self, ConnectionDiagnostics};
+use crate::dial_queue::{DialPriority, DialQueue, QueuedDial, NEARBY_LATENCY_MS};
+use crate::health::{HealthInputs, HealthReport, TopicHealth};
+use crate::http::{self, HttpResponse};
+use crate::identity::{self, IdentityRotation, SuccessorRecord};
+use crate::invite::DbInvite;
+use crate::keystore;
+use crate::merkle::{RootCheck, StateTree};
+use crate::metrics::{
+    self, BandwidthCaps, BandwidthMeter, BandwidthStats, ByteCounts, Histogram, MessageCounts, MetricsSnapshot, Subsystem,
+    CONNECT_LATENCY_BUCKETS,
+};
+use crate::mqtt::{MqttBridge, MqttBridgeConfig};
+use crate::snapshot::SnapshotManager;
+use crate::stats::{LifetimeStats, SessionStats, TrafficStats};
+use crate::storage::{DataPage, ScanOptions, ScanPage, Storage, StorageChange};
+use crate::store_types::StoreType;
+use crate::sync::{ConflictRecord, DbSyncInfo, IntegrityReport, SyncManager, SyncMessage, SignedOperation, SyncStats};
+use crate::text::TextDelta;
+use crate::ticket::NodeTicket;
+use crate::typed::{self, TypedWrite};
 use crate::discovery::{
     PeerRegistry, PeerAnnouncement, PeerListAnnouncement, PeerDiscoveryAnnouncement,
     DiscoveryMessage, LatencyRequest, LatencyResponse,
-    NodeCapabilities, DiscoveredPeer, ANNOUNCE_INTERVAL_SECS,
+    NodeCapabilities, DiscoveredPeer, KnownPeer, PeerFilter, merge_known_peers,
+    ConnectionPath, PeerPath,
     DiscoveryNode, SignedDiscoveryMessage,
 };
-use crate::network_resilience::NetworkResilience;
+use crate::network_resilience::{DialPermit, NetworkResilience, PeerCircuit};
+use crate::blob_values::{self, BlobRef, BlobValues};
+use crate::bootstrap;
+use crate::dm::{self, DirectMessage, DmService};
+use crate::events::{EventHistory, RecordedEvent};
+use crate::files::{self, FileEvent, FileOffer, FileService};
+use crate::power::{DeviceConditions, PowerMode, PowerPolicy, LITE_MODE_TOPICS};
+use crate::presence::{self, Presence, PresenceHeartbeat, PresenceTracker};
+use crate::private_network::{self, NetworkKey};
+use crate::rpc::{self, IncomingRpc, RpcProtocol};
 
 /// Bootstrap peers for the Cyberfly network
 const DEFAULT_BOOTSTRAP: &str = "04b754ba2a3da0970d72d08b8740fb2ad96e63cf8f8bef6b7f1ab84e5b09a7f8@67.211.219.34:31001";
@@ -59,9 +104,113 @@ const IMPROVED_DISCOVERY_TOPIC: &[u8; 32] = b"cyberfly-discovery-v2-postcard!!";
 /// Fetch latency request topic - matches cyberfly-rust-node
 const LATENCY_TOPIC: &[u8; 32] = b"cyberfly-fetch-latency-request!!";
 
+/// Topic `name` (`base` on the default network) in the namespace of the
+/// application network `network_id`
+fn namespaced_topic(base: &[u8; 32], name: &str, network_id: Option<&str>) -> [u8; 32] {
+    match network_id {
+        Some(network_id) => *blake3::hash(format!("cyberfly:{}:{}", network_id, name).as_bytes()).as_bytes(),
+        None => *base,
+    }
+}
+
+/// Longest application topic name
+const MAX_TOPIC_NAME_LEN: usize = 256;
+/// Messages buffered per application topic for slow subscribers
+const TOPIC_CHANNEL_CAPACITY: usize = 256;
+
+/// Topic ID of the application topic `name` in the application network
+/// `network_id`
+fn application_topic(name: &str, network_id: Option<&str>) -> [u8; 32] {
+    *blake3::hash(format!("cyberfly:{}:topic:{}", network_id.unwrap_or("default"), name).as_bytes()).as_bytes()
+}
+
 /// Node version
 const NODE_VERSION: &str = "cyberfly-mobile-0.1.0";
 
+/// Most direct addresses put in one announcement
+const MAX_ANNOUNCED_ADDRS: usize = 4;
+
+/// Public addresses of `endpoint` peers can dial back, IPv4 first, and its
+/// home relay URL. Local network addresses are left to mDNS.
+fn announced_addrs(endpoint: &Endpoint) -> (Vec<String>, Option<String>) {
+    let addr = endpoint.addr();
+    let mut public: Vec<SocketAddr> = addr.ip_addrs().filter(|ip| !diagnostics::is_local(&ip.ip())).copied().collect();
+    public.sort_by_key(SocketAddr::is_ipv6);
+    let addrs = public.into_iter().take(MAX_ANNOUNCED_ADDRS).map(|ip| ip.to_string()).collect();
+    let relay_url = addr.relay_urls().next().map(|url| url.to_string());
+    (addrs, relay_url)
+}
+
+/// How often expired keys (see `SignedOperation::expires_at`) are removed
+const EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How often superseded operations are collected (see `NodeConfig::oplog_gc`)
+const OPLOG_GC_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How often discovered peers are saved for the next launch
+const KNOWN_PEERS_SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Setting holding the dials still queued (see `dial_queue`)
+const DIAL_QUEUE_SETTING: &str = "dial_queue";
+
+/// How long a dial requested through `Node::connect_peer` may take
+const MANUAL_CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How long a latency measurement of one peer may take
+const LATENCY_PING_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Peers pinged at once during a latency sweep
+const LATENCY_SWEEP_CONCURRENCY: usize = 4;
+
+/// Setting holding the status string of our presence heartbeats
+const PRESENCE_STATUS_SETTING: &str = "presence_status";
+
+/// Wait before retrying to rejoin a topic after resuming
+const TOPIC_REJOIN_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+/// Wait before rejoining a topic whose gossip stream ended
+const TOPIC_RECOVERY_DELAY: Duration = Duration::from_secs(2);
+
+/// Wait after resuming networking before asking peers what we missed, so the
+/// topics are rejoined
+const CATCH_UP_SYNC_DELAY: Duration = Duration::from_secs(5);
+
+/// How often lifetime stats are saved and the operation count refreshed
+const STATS_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Longest wait for a relay to answer a ping
+const RELAY_PING_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often queued sync messages are retried
+const OUTBOX_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Queued sync messages sent per outbox read
+const OUTBOX_FLUSH_BATCH: usize = 100;
+
+/// How often received operations are confirmed to their writers, in one
+/// batch rather than an ack per operation
+const ACK_FLUSH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often signed roots of owned databases are published (see `merkle`)
+const MERKLE_ROOT_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Setting holding when the last wake sync (see `Node::wake_sync`) was answered
+const LAST_WAKE_SYNC_SETTING: &str = "last_wake_sync";
+
+/// A wake sync asks for operations this much older than the last one, for
+/// clock skew between peers
+const WAKE_SYNC_OVERLAP_MS: i64 = 5 * 60 * 1000;
+
+/// How often a wake sync checks whether it's done
+const WAKE_SYNC_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How often `Node::wait_for_sync` checks whether the writes got through
+const SYNC_WAIT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Wait before asking again when no peer answered a wake sync, e.g. as gossip
+/// neighbors are still being found
+const WAKE_SYNC_RETRY_INTERVAL: Duration = Duration::from_secs(3);
+
 /// Whitelisted public keys for latency requests (matching cyberfly-rust-node)
 const WHITELISTED_KEYS: &[&str] = &[
     "f53f94261cd3c60832c347fda7b92c6c8b7249baab8196a5bfc3915418c43e72"
@@ -71,11 +220,15 @@ const WHITELISTED_KEYS: &[&str] = &[
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "msg_type")]
 pub enum GossipMessage {
-    /// Custom message
+    /// Custom message, signed by `from` (older nodes send it unsigned)
     Custom {
         from: String,
         content: String,
         timestamp: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        public_key: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        signature: Option<String>,
     },
     /// Latency request
     LatencyRequest {
@@ -96,6 +249,45 @@ pub enum GossipMessage {
     },
 }
 
+impl GossipMessage {
+    /// Custom message from the node `from`, signed with its key
+    pub fn custom(signing_key: &SigningKey, from: String, content: String) -> Self {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let signature = crypto::sign_message(signing_key, custom_signing_message(&from, &content, timestamp).as_bytes());
+        GossipMessage::Custom {
+            from,
+            content,
+            timestamp,
+            public_key: Some(hex::encode(signing_key.verifying_key().as_bytes())),
+            signature: Some(signature),
+        }
+    }
+
+    /// Whether a Custom message is signed by the node in its `from` field
+    pub fn is_authentic(&self) -> bool {
+        let GossipMessage::Custom { from, content, timestamp, public_key: Some(public_key), signature: Some(signature) } = self else {
+            return false;
+        };
+        let Ok(sender) = from.parse::<EndpointId>() else {
+            return false;
+        };
+        let signer = hex::decode(public_key)
+            .ok()
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .and_then(|bytes| iroh::PublicKey::from_bytes(&bytes).ok());
+        signer == Some(sender)
+            && crypto::verify_signature(public_key, custom_signing_message(from, content, *timestamp).as_bytes(), signature)
+                .unwrap_or(false)
+    }
+}
+
+fn custom_signing_message(from: &str, content: &str, timestamp: u64) -> String {
+    format!("custom:{}:{}:{}", from, timestamp, content)
+}
+
 /// Signed request format for fetch-latency-request (matches cyberfly-rust-node)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignedLatencyRequest {
@@ -168,325 +360,1993 @@ mod base64_bytes {
     }
 }
 
-// Helper: connect to a peer with per-peer backoff handling and metrics.
-async fn connect_peer(
-    endpoint: Endpoint,
-    peer_id: EndpointId,
-    addr_opt: Option<String>,
-    peer_backoff: Arc<DashMap<EndpointId, (u32, chrono::DateTime<chrono::Utc>)>>,
-    resilience: Option<Arc<NetworkResilience>>,
-) -> Result<()> {
-    // Check backoff
-    if let Some(back) = peer_backoff.get(&peer_id) {
-        let (_fails, next_allowed) = *back.value();
-        let now = Utc::now();
-        if now < next_allowed {
-            tracing::debug!(%peer_id, %next_allowed, "Skipping connect due to backoff");
-            return Err(anyhow!("backoff"));
-        }
-    }
+/// Spawn a background task of the node that ends when `shutdown` is cancelled
+fn spawn_until<F>(shutdown: &CancellationToken, task: F) -> JoinHandle<()>
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    let shutdown = shutdown.clone();
+    tokio::spawn(async move {
+        shutdown.run_until_cancelled(task).await;
+    })
+}
+
+/// The node key given as hex, or else the one kept in `data_path`,
+/// generated on first use (sealed if a wrapping key is set, see `keystore`)
+fn load_secret_key(data_path: &std::path::Path, wallet_secret_key: Option<String>) -> Result<SecretKey> {
+    let key_path = data_path.join(backup::SECRET_KEY_FILE);
+    let secret_key = if let Some(sk_hex) = wallet_secret_key {
+        let sk_bytes = hex::decode(&sk_hex)?;
+        let sk_array: [u8; 32] = sk_bytes.try_into()
+            .map_err(|_| anyhow!("Invalid secret key length"))?;
+        SecretKey::try_from(&sk_array[..])?
+    } else if key_path.exists() {
+        SecretKey::try_from(&keystore::read_key(&key_path)?[..])?
+    } else {
+        // iroh 0.98: SecretKey::generate() no longer takes an RNG argument.
+        let key = SecretKey::generate();
+        keystore::write_key(&key_path, &key.to_bytes())?;
+        key
+    };
+    Ok(secret_key)
+}
 
-    // Check cycle connection limits via NetworkResilience if present
-    if let Some(res) = &resilience {
-        if !res.allow_connection_attempt() {
-            tracing::debug!(%peer_id, "Skipping connect: connection attempts exceeded for this cycle");
-            return Err(anyhow!("connection_attempts_exceeded"));
+/// Open the node's database in `data_path`, logging what's in it
+fn open_storage(data_path: &std::path::Path) -> Result<Storage> {
+    let storage = Storage::new(data_path.join(backup::SLED_DIR))?;
+    let db_count = storage.list_databases().unwrap_or_default().len();
+    let key_count = storage.key_count().unwrap_or(0);
+    let size_bytes = storage.size_bytes().unwrap_or(0);
+    info!("📦 Storage loaded: {} databases, {} keys, {} bytes", db_count, key_count, size_bytes);
+    if db_count > 0 {
+        for db_name in storage.list_databases().unwrap_or_default() {
+            let keys = storage.list_keys(&db_name).unwrap_or_default();
+            info!("  📁 Database '{}': {} keys", db_name, keys.len());
         }
     }
+    Ok(storage)
+}
 
-    // (metrics not wired in mobile crate) -- skip recording here
-
-    // Attempt connect
-    let res = if let Some(addr_str) = addr_opt {
-        if let Ok(socket_addr) = addr_str.parse::<std::net::SocketAddr>() {
-            let endpoint_addr = iroh::EndpointAddr::from_parts(
-                peer_id,
-                vec![iroh::TransportAddr::Ip(socket_addr)],
-            );
-            endpoint.connect(endpoint_addr, iroh_gossip::ALPN).await
-        } else {
-            endpoint.connect(peer_id, iroh_gossip::ALPN).await
+/// Peers remembered by earlier runs, most recently seen first
+fn load_known_peers(storage: &Storage) -> Vec<KnownPeer> {
+    let stored = match storage.get_all_known_peers() {
+        Ok(stored) => stored,
+        Err(e) => {
+            log_warn!("Failed to load known peers: {}", e);
+            return Vec::new();
         }
-    } else {
-        endpoint.connect(peer_id, iroh_gossip::ALPN).await
     };
+    let peers = stored
+        .iter()
+        .filter_map(|bytes| serde_json::from_slice::<KnownPeer>(bytes).ok())
+        .collect();
+    merge_known_peers(peers, Vec::new(), Utc::now().timestamp_millis())
+}
 
-    match res {
-        Ok(_) => {
-            // Clear backoff on success
-            peer_backoff.remove(&peer_id);
-            Ok(())
-        }
-        Err(e) => {
-            // (metrics not wired in mobile crate) -- skip recording failure
-            // Update backoff state (exponential)
-            let mut failures = 1u32;
-            if let Some(mut entry) = peer_backoff.get_mut(&peer_id) {
-                failures = entry.value_mut().0.saturating_add(1);
-            }
-            let base_secs = 2u64;
-            let max_secs = 300u64; // 5 minutes
-            let backoff_secs = base_secs.checked_shl((failures - 1) as u32).unwrap_or(max_secs).min(max_secs);
-            let next_allowed = Utc::now() + chrono::Duration::seconds(backoff_secs as i64);
-            peer_backoff.insert(peer_id, (failures, next_allowed));
-            tracing::info!(%peer_id, failures, %next_allowed, "Set backoff after failed connect");
-            Err(anyhow!(e))
+/// Fold the registry's current peers into the remembered ones and persist them
+fn save_known_peers(storage: &Storage, peer_registry: &RwLock<PeerRegistry>) -> Result<usize> {
+    let current = peer_registry.read().known_peers();
+    let peers = merge_known_peers(load_known_peers(storage), current, Utc::now().timestamp_millis());
+    let entries = peers
+        .iter()
+        .map(|peer| Ok((peer.node_id.clone(), serde_json::to_vec(peer)?)))
+        .collect::<Result<Vec<_>>>()?;
+    storage.replace_known_peers(&entries)?;
+    Ok(entries.len())
+}
+
+/// Broadcast a presence heartbeat on the discovery topic
+async fn broadcast_presence(discovery_sender: &Mutex<Option<CountedSender>>, heartbeat: PresenceHeartbeat) {
+    if let Some(sender) = discovery_sender.lock().await.as_ref() {
+        match serde_json::to_vec(&DiscoveryMessage::Presence(heartbeat)) {
+            Ok(bytes) => { let _ = sender.broadcast(Bytes::from(bytes)).await; }
+            Err(e) => log_warn!("Failed to serialize presence heartbeat: {}", e),
         }
     }
 }
 
-/// Node status
+/// Relay mode for the endpoint
+fn relay_mode(relay: &RelayConfig) -> Result<iroh::RelayMode> {
+    Ok(match relay {
+        RelayConfig::Default => iroh::RelayMode::Default,
+        RelayConfig::Disabled => iroh::RelayMode::Disabled,
+        RelayConfig::Custom(urls) => iroh::RelayMode::Custom(
+            urls.iter().map(|url| url.parse::<iroh::RelayUrl>()).collect::<Result<_, _>>()?,
+        ),
+    })
+}
+
+/// Relay the endpoint is currently using
 #[derive(Debug, Clone)]
-pub struct NodeStatus {
-    pub is_running: bool,
-    pub node_id: Option<String>,
-    pub connected_peers: usize,
-    pub discovered_peers: usize,
-    pub uptime_seconds: u64,
-    pub gossip_messages_received: u64,
-    pub storage_size_bytes: u64,
-    pub total_keys: u64,
-    pub total_operations: u64,
-    pub sync_operations: usize,
-    pub latency_requests_sent: u64,
-    pub latency_responses_received: u64,
+pub struct RelayStatus {
+    pub relay: RelayConfig,
+    /// Home relay, None when relays are disabled or none is reachable yet
+    pub active_url: Option<String>,
+    /// Round trip time of an HTTP ping to the active relay, None if it failed
+    pub rtt: Option<Duration>,
 }
 
-/// Node events sent to Flutter
+/// Time a request to the relay's ping endpoint, after a first one sets up
+/// the connection
+async fn ping_relay(url: &str) -> Result<Duration> {
+    let ping_url = reqwest::Url::parse(url)?.join("ping")?;
+    let client = reqwest::Client::builder().timeout(RELAY_PING_TIMEOUT).build()?;
+    client.get(ping_url.clone()).send().await?.error_for_status()?;
+    let start = Instant::now();
+    client.get(ping_url).send().await?.error_for_status()?;
+    Ok(start.elapsed())
+}
+
+/// Outcome of a dial requested through `Node::connect_peer`
 #[derive(Debug, Clone)]
-pub enum NodeEvent {
-    Started { node_id: String, public_key: String },
-    Stopped,
-    PeerConnected { peer_id: String },
-    PeerDisconnected { peer_id: String },
-    PeerDiscovered { peer_id: String, address: Option<String> },
-    GossipReceived { topic: String, from: String, content: String },
-    SyncReceived { db_name: String, key: String },
-    LatencyMeasured { peer_id: String, latency_ms: u64 },
-    Error { message: String },
+pub struct ConnectionResult {
+    pub node_id: String,
+    /// None if no path was selected yet when the handshake completed
+    pub path: Option<ConnectionPath>,
+    pub handshake_ms: u64,
 }
 
-/// Pending latency requests
-struct PendingLatencyRequest {
-    sent_at: i64,
-    callback: Option<oneshot::Sender<u64>>,
+/// What a background wake sync (see `Node::wake_sync`) got done
+#[derive(Debug, Clone, Default)]
+pub struct WakeSyncSummary {
+    pub pinned_peers_dialed: usize,
+    pub pinned_peers_connected: usize,
+    /// A peer answered the sync request in time
+    pub synced: bool,
+    /// Operations received from peers, including ones already known
+    pub ops_received: u64,
+    pub ops_sent: u64,
+    /// Queued sync messages sent during the wake
+    pub outbox_sent: usize,
+    /// Queued sync messages still waiting for a peer
+    pub outbox_remaining: usize,
+    pub duration_ms: u64,
 }
 
-/// Commands sent to the node
-enum NodeCommand {
-    Stop(oneshot::Sender<()>),
-    GetStatus(oneshot::Sender<NodeStatus>),
-    GetPeers(oneshot::Sender<Vec<DiscoveredPeer>>),
-    SendGossip { topic: String, message: String },
-    SendLatencyRequest { peer_id: String, response: oneshot::Sender<Result<u64, String>> },
-    StoreData { db_name: String, key: String, value: Vec<u8>, public_key: String, signature: String },
-    GetData { db_name: String, key: String, response: oneshot::Sender<Option<Vec<u8>>> },
-    RequestSync { since_timestamp: Option<i64> },
+/// Where the writes to a database stood when `Node::wait_for_sync` returned
+#[derive(Debug, Clone, Default)]
+pub struct SyncWaitSummary {
+    /// Every write was sent and confirmed by a peer in time
+    pub synced: bool,
+    /// Writes still queued in the outbox
+    pub queued_ops: usize,
+    /// Writes no peer confirmed yet, including the queued ones
+    pub unacknowledged_ops: usize,
+    pub duration_ms: u64,
 }
 
-/// Shared node state - updated by run_node, read by API
+/// What a `Node::checkpoint` saved
+#[derive(Debug, Clone, Default)]
+pub struct CheckpointSummary {
+    /// Newest operation timestamp, see `SyncStore::save_high_water_mark`
+    pub high_water_mark: Option<i64>,
+    pub known_peers_saved: usize,
+    /// Queued sync messages, now on disk
+    pub outbox_depth: usize,
+    /// Storage reached the disk within the budget
+    pub flushed: bool,
+    pub duration_ms: u64,
+}
+
+/// Message received on an application topic
 #[derive(Debug, Clone)]
-pub struct SharedNodeState {
-    pub is_running: bool,
-    pub connected_peers: usize,
-    pub discovered_peers: usize,
-    pub gossip_messages_received: u64,
-    pub latency_requests_sent: u64,
-    pub latency_responses_received: u64,
-    pub sync_operations: usize,
+pub struct TopicMessage {
+    pub topic: String,
+    /// Neighbor that delivered the message, not necessarily its author
+    pub delivered_from: String,
+    pub content: Vec<u8>,
 }
 
-impl Default for SharedNodeState {
-    fn default() -> Self {
-        Self {
-            is_running: true,
-            connected_peers: 0,
-            discovered_peers: 0,
-            gossip_messages_received: 0,
-            latency_requests_sent: 0,
-            latency_responses_received: 0,
-            sync_operations: 0,
-        }
+/// Wait for the next tick of `interval`, first picking up a change of the
+/// period `period_of` reads from the config
+async fn tick_configured(
+    interval: &mut tokio::time::Interval,
+    config: &RwLock<NodeConfig>,
+    period_of: impl Fn(&NodeConfig) -> Duration,
+) {
+    let period = period_of(&config.read());
+    if interval.period() != period {
+        *interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
     }
+    interval.tick().await;
 }
 
-/// Main Cyberfly node
-pub struct CyberflyNode {
-    command_tx: mpsc::Sender<NodeCommand>,
-    event_rx: Arc<RwLock<Option<mpsc::Receiver<NodeEvent>>>>,
-    node_id: String,
-    public_key: String,
-    start_time: Instant,
-    // Shared state for sync access
-    shared_state: Arc<RwLock<SharedNodeState>>,
-    peer_registry: Arc<RwLock<PeerRegistry>>,
-    storage: Arc<Storage>,
-    // Optional network resilience manager (initialized on start)
-    resilience: Option<Arc<NetworkResilience>>,
+/// Counts a topic's traffic in the session stats and the bandwidth meter
+#[derive(Clone)]
+struct TrafficCounter {
+    stats: Arc<SessionStats>,
+    bandwidth: Arc<BandwidthMeter>,
+    topic: Arc<str>,
+    subsystem: Subsystem,
 }
 
-impl CyberflyNode {
-    /// Create and start a new node
-    pub async fn start(
-        data_dir: String,
-        wallet_secret_key: Option<String>,
-        bootstrap_peers: Vec<String>,
-        region: Option<String>,
-    ) -> Result<Self> {
-        let data_path = PathBuf::from(&data_dir);
-        std::fs::create_dir_all(&data_path)?;
+impl TrafficCounter {
+    fn sent(&self, bytes: usize) {
+        self.stats.record_sent(bytes);
+        self.bandwidth.record_sent(self.subsystem, &self.topic, bytes);
+    }
 
-        // Generate or restore secret key
-        let secret_key = if let Some(sk_hex) = wallet_secret_key {
-            let sk_bytes = hex::decode(&sk_hex)?;
-            let sk_array: [u8; 32] = sk_bytes.try_into()
-                .map_err(|_| anyhow!("Invalid secret key length"))?;
-            SecretKey::try_from(&sk_array[..])?
-        } else {
-            // Load or generate key
-            let key_path = data_path.join("secret_key");
-            if key_path.exists() {
-                let key_bytes = std::fs::read(&key_path)?;
-                SecretKey::try_from(&key_bytes[0..32])?
-            } else {
-                // iroh 0.98: SecretKey::generate() no longer takes an RNG argument.
-                let key = SecretKey::generate();
-                std::fs::write(&key_path, key.to_bytes())?;
-                key
-            }
-        };
+    fn received(&self, bytes: usize) {
+        self.stats.record_received(bytes);
+        self.bandwidth.record_received(self.subsystem, &self.topic, bytes);
+    }
 
-        let public_key_hex = hex::encode(secret_key.public().as_bytes());
-        
-        // Create ed25519 signing key from the same secret
-        let signing_key = SigningKey::from_bytes(&secret_key.to_bytes());
-        
-        info!("Starting Cyberfly node...");
+    fn duplicate(&self, bytes: usize) {
+        self.stats.record_received(bytes);
+        self.bandwidth.record_duplicate(self.subsystem, &self.topic, bytes);
+    }
 
-        // Initialize storage
-        let storage = Storage::new(data_path.join("sled_db"))?;
-        
-        // Log existing data on startup
-        let db_count = storage.list_databases().unwrap_or_default().len();
-        let key_count = storage.key_count().unwrap_or(0);
-        let size_bytes = storage.size_bytes().unwrap_or(0);
-        info!("📦 Storage loaded: {} databases, {} keys, {} bytes", db_count, key_count, size_bytes);
-        if db_count > 0 {
-            for db_name in storage.list_databases().unwrap_or_default() {
-                let keys = storage.list_keys(&db_name).unwrap_or_default();
-                info!("  📁 Database '{}': {} keys", db_name, keys.len());
-            }
+    fn event(&self) {
+        self.bandwidth.record_event(&self.topic);
+    }
+}
+
+/// Topics whose messages over the max message size are sent in chunks
+const CHUNKED_TOPICS: [&str; 2] = ["data", "sync"];
+
+/// Gossip sender counting what it sends
+#[derive(Clone)]
+struct CountedSender {
+    sender: GossipSender,
+    counter: TrafficCounter,
+    max_message_size: usize,
+    /// Oversized payloads are split up instead of rejected
+    chunked: bool,
+}
+
+impl CountedSender {
+    /// Broadcast `payload`, in chunks if it's too large for one message on a
+    /// chunked topic; errors with `chunking::MessageTooLarge` if it can't
+    /// be delivered at all
+    async fn broadcast(&self, payload: Bytes) -> Result<()> {
+        let messages = if self.chunked {
+            chunking::split(&payload, self.max_message_size)?
+        } else if payload.len() > self.max_message_size {
+            return Err(MessageTooLarge { len: payload.len(), max: self.max_message_size }.into());
+        } else {
+            vec![payload.to_vec()]
+        };
+        for message in messages {
+            self.counter.sent(message.len());
+            self.sender.broadcast(Bytes::from(message)).await?;
         }
+        Ok(())
+    }
+    async fn join_peers(&self, peers: Vec<EndpointId>) -> Result<(), ApiError> {
+        self.sender.join_peers(peers).await
+    }
+}
 
-        // Create channels
-        let (command_tx, command_rx) = mpsc::channel(100);
-        let (event_tx, event_rx) = mpsc::channel(100);
+type GossipEvents = BoxStream<'static, Result<GossipEvent, ApiError>>;
 
-        // Build address-lookup services - DHT + mDNS for local network peers (matching desktop).
-        // iroh 0.98 renamed `discovery` → `address_lookup` and `DhtDiscovery`/`MdnsDiscovery`
-        // to `DhtAddressLookup`/`MdnsAddressLookup`.
-        let dht_discovery = DhtAddressLookup::builder();
-        let mdns_discovery = MdnsAddressLookup::builder();
+/// Split a subscription, counting its traffic with `counter` and dropping
+/// messages `dedup` saw recently. With a `reassembler`, chunks are held back
+/// until the whole message arrived, which is then passed on instead.
+fn split_counted(
+    topic: GossipTopic,
+    counter: &TrafficCounter,
+    dedup: Arc<parking_lot::Mutex<DedupWindow>>,
+    reassembler: Option<Arc<parking_lot::Mutex<Reassembler>>>,
+    max_message_size: usize,
+) -> (CountedSender, GossipEvents) {
+    let (sender, receiver) = topic.split();
+    let received = counter.clone();
+    let chunked = reassembler.is_some();
+    let receiver = receiver
+        .filter_map(move |event| {
+            let event = match event {
+                Ok(GossipEvent::Received(msg)) if dedup.lock().is_duplicate(&msg.content) => {
+                    received.duplicate(msg.content.len());
+                    None
+                }
+                Ok(GossipEvent::Received(mut msg)) => {
+                    received.received(msg.content.len());
+                    match &reassembler {
+                        Some(reassembler) if chunking::is_chunk(&msg.content) => {
+                            reassembler.lock().accept(&msg.content).map(|payload| {
+                                msg.content = Bytes::from(payload);
+                                Ok(GossipEvent::Received(msg))
+                            })
+                        }
+                        _ => Some(Ok(GossipEvent::Received(msg))),
+                    }
+                }
+                event => Some(event),
+            };
+            futures::future::ready(event)
+        })
+        .boxed();
+    (CountedSender { sender, counter: counter.clone(), max_message_size, chunked }, receiver)
+}
 
-        // Create endpoint via the new preset-based builder API.
-        // `presets::N0` keeps n0's default relays + DNS address-lookup configured; we add
-        // DHT and mDNS on top to match the previous behaviour as closely as possible.
-        let endpoint = Endpoint::builder(presets::N0)
-            .secret_key(secret_key.clone())
-            .address_lookup(dht_discovery)
-            .address_lookup(mdns_discovery)
-            .relay_mode(iroh::RelayMode::Default)
-            .bind()
-            .await?;
+/// Which gossip topics stay joined, following `Node::pause_networking` and
+/// `Node::set_power_mode`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct TopicGate {
+    paused: bool,
+    /// Only `LITE_MODE_TOPICS` are kept, joined through one bootstrap peer
+    lite: bool,
+}
 
-        let node_id = endpoint.id();
-        let node_id_str = node_id.to_string();
+impl TopicGate {
+    fn keeps(&self, topic: &str) -> bool {
+        !self.paused && (!self.lite || LITE_MODE_TOPICS.contains(&topic))
+    }
+}
 
-        info!("Node ID: {}", node_id_str);
-        log_info!("Node ID: {}", node_id_str);
-        
-        // Try to wait for endpoint to be online with a short timeout
-        // Don't block startup on relay connection - it can happen in background
-        log_info!(">>> Checking for relay connection (non-blocking, 2s timeout)...");
-        let online_result = tokio::time::timeout(
-            Duration::from_secs(2),
-            endpoint.online()
-        ).await;
-        
-        if online_result.is_ok() {
-            log_info!(">>> Endpoint is online with relay!");
-        } else {
-            log_info!(">>> Relay not ready yet, continuing startup (will connect in background)");
+/// Joins gossip topics for the node, following the `TopicGate`
+#[derive(Clone)]
+struct TopicJoiner {
+    gossip: Gossip,
+    bootstrap_peers: Vec<EndpointId>,
+    peer_registry: Arc<RwLock<PeerRegistry>>,
+    stats: Arc<SessionStats>,
+    bandwidth: Arc<BandwidthMeter>,
+    gate: watch::Receiver<TopicGate>,
+    event_tx: mpsc::Sender<NodeEvent>,
+    max_message_size: usize,
+}
+
+impl TopicJoiner {
+    /// Join `topic_id` through `peers` (later through `rejoin_peers`), keeping
+    /// its sender in `slot` while joined. Traffic is metered as `name`.
+    async fn subscribe(
+        &self,
+        topic_id: TopicId,
+        name: &str,
+        subsystem: Subsystem,
+        peers: Vec<EndpointId>,
+        slot: Arc<Mutex<Option<CountedSender>>>,
+    ) -> Result<TopicEvents> {
+        let counter = TrafficCounter {
+            stats: self.stats.clone(),
+            bandwidth: self.bandwidth.clone(),
+            topic: name.into(),
+            subsystem,
+        };
+        let mut events = TopicEvents {
+            joiner: self.clone(),
+            topic_id,
+            counter,
+            slot,
+            receiver: None,
+            dedup: Arc::new(parking_lot::Mutex::new(DedupWindow::default())),
+            reassembler: CHUNKED_TOPICS
+                .contains(&name)
+                .then(|| Arc::new(parking_lot::Mutex::new(Reassembler::default()))),
+            recovering: false,
+            joined_lite: false,
+        };
+        // A topic the gate holds back is joined once it opens
+        let keeps = self.gate.borrow().keeps(name);
+        if keeps {
+            events.join(peers).await?;
         }
-        
-        // Log endpoint addresses
-        let my_addr = endpoint.addr();
-        log_info!(">>> Our endpoint relay URLs: {:?}", 
-            my_addr.relay_urls().collect::<Vec<_>>());
+        Ok(events)
+    }
 
-        // Create blob store
-        let store = iroh_blobs::store::fs::FsStore::load(&data_path.join("blobs")).await?;
-        let blobs = BlobsProtocol::new(&store, None);
+    /// Bootstrap peers and the active peers we know of; in lite mode a
+    /// single bootstrap peer (or known peer without any)
+    fn rejoin_peers(&self) -> Vec<EndpointId> {
+        let lite = self.gate.borrow().lite;
+        if lite {
+            if let Some(peer_id) = self.bootstrap_peers.first() {
+                return vec![*peer_id];
+            }
+        }
+        let mut peers = self.bootstrap_peers.clone();
+        let known: Vec<EndpointId> = self
+            .peer_registry
+            .read()
+            .get_active_peers()
+            .iter()
+            .filter_map(|peer| peer.node_id.parse().ok())
+            .collect();
+        for peer_id in known {
+            if !peers.contains(&peer_id) {
+                peers.push(peer_id);
+            }
+        }
+        if lite {
+            peers.truncate(1);
+        }
+        peers
+    }
+}
 
-        // Create gossip
-        let gossip = Gossip::builder().spawn(endpoint.clone());
+/// Events of a joined topic. The topic is left while the `TopicGate` holds
+/// it back and rejoined when it opens, and joined again if its gossip stream ends
+/// (emitting `NodeEvent::TopicRecovered`); the events end when the node stops.
+struct TopicEvents {
+    joiner: TopicJoiner,
+    topic_id: TopicId,
+    counter: TrafficCounter,
+    slot: Arc<Mutex<Option<CountedSender>>>,
+    receiver: Option<GossipEvents>,
+    /// Messages received recently, kept across rejoins
+    dedup: Arc<parking_lot::Mutex<DedupWindow>>,
+    /// Chunks of messages being received, on `CHUNKED_TOPICS`
+    reassembler: Option<Arc<parking_lot::Mutex<Reassembler>>>,
+    /// The gossip stream ended and the topic hasn't been joined again yet
+    recovering: bool,
+    /// Joined in lite mode, through one peer
+    joined_lite: bool,
+}
 
-        // Build router
-        let router = Router::builder(endpoint.clone())
-            .accept(iroh_blobs::ALPN, blobs.clone())
-            .accept(iroh_gossip::ALPN, gossip.clone())
-            .spawn();
+impl TopicEvents {
+    async fn join(&mut self, peers: Vec<EndpointId>) -> Result<()> {
+        let (sender, receiver) = split_counted(
+            self.joiner.gossip.subscribe(self.topic_id, peers).await?,
+            &self.counter,
+            self.dedup.clone(),
+            self.reassembler.clone(),
+            self.joiner.max_message_size,
+        );
+        *self.slot.lock().await = Some(sender);
+        self.receiver = Some(receiver);
+        self.joined_lite = self.joiner.gate.borrow().lite;
+        Ok(())
+    }
 
-        // Parse bootstrap peers - we'll connect in background
-        let mut bootstrap_node_ids: Vec<EndpointId> = Vec::new();
-        let all_bootstrap_strings: Vec<String> = {
-            let mut v = vec![DEFAULT_BOOTSTRAP.to_string(), DEFAULT_BOOTSTRAP_2.to_string()];
-            v.extend(bootstrap_peers.iter().cloned());
-            v
-        };
-        
-        // Parse peer IDs first (fast, no network)
-        for peer_str in &all_bootstrap_strings {
-            if let Some((node_id_str, _)) = peer_str.split_once('@') {
-                match node_id_str.parse::<EndpointId>() {
-                    Ok(peer_node_id) => {
-                        if peer_node_id != node_id {
-                            bootstrap_node_ids.push(peer_node_id);
-                        }
-                    }
-                    Err(e) => {
-                        log_warn!("Invalid bootstrap peer id '{}': {}", node_id_str, e);
+    async fn next(&mut self) -> Option<Result<GossipEvent, ApiError>> {
+        loop {
+            let gate = *self.joiner.gate.borrow_and_update();
+            // Dropping the sender and receiver leaves the topic; switching to
+            // lite mode joins again through one peer
+            let keeps = gate.keeps(&self.counter.topic);
+            if self.receiver.is_some() && (!keeps || (gate.lite && !self.joined_lite)) {
+                self.receiver = None;
+                *self.slot.lock().await = None;
+            }
+            if !keeps {
+                self.joiner.gate.changed().await.ok()?;
+                continue;
+            }
+            if self.receiver.is_none() {
+                if let Err(e) = self.join(self.joiner.rejoin_peers()).await {
+                    log_warn!("Failed to rejoin gossip topic {}: {}", self.counter.topic, e);
+                    tokio::select! {
+                        _ = tokio::time::sleep(TOPIC_REJOIN_RETRY_DELAY) => {}
+                        changed = self.joiner.gate.changed() => changed.ok()?,
                     }
+                    continue;
+                }
+                if std::mem::take(&mut self.recovering) {
+                    log_info!("Recovered gossip topic {}", self.counter.topic);
+                    let _ = self.joiner.event_tx.send(NodeEvent::TopicRecovered { topic: self.counter.topic.to_string() }).await;
                 }
-            } else {
-                log_warn!("Malformed bootstrap peer entry (missing '@'): '{}'", peer_str);
+            }
+            let receiver = self.receiver.as_mut()?;
+            tokio::select! {
+                event = receiver.next() => match event {
+                    Some(event) => {
+                        if event.is_ok() {
+                            self.counter.event();
+                        }
+                        return Some(event);
+                    }
+                    None => {
+                        // The stream died under us; join again with the current peers
+                        log_warn!("Gossip topic {} ended, rejoining", self.counter.topic);
+                        self.receiver = None;
+                        *self.slot.lock().await = None;
+                        self.recovering = true;
+                        tokio::select! {
+                            _ = tokio::time::sleep(TOPIC_RECOVERY_DELAY) => {}
+                            changed = self.joiner.gate.changed() => changed.ok()?,
+                        }
+                    }
+                },
+                changed = self.joiner.gate.changed() => changed.ok()?,
+            }
+        }
+    }
+}
+
+/// Ask peers for the operations we're missing; false if the power policy
+/// or a used up data cap defers it (it's sent once that changes)
+async fn send_sync_request(
+    sync_sender: &Mutex<Option<CountedSender>>,
+    sync_manager: &SyncManager,
+    shared_state: &RwLock<SharedNodeState>,
+) -> Result<bool> {
+    {
+        let mut state = shared_state.write();
+        if state.power_policy.defer_sync() || sync_manager.bandwidth_cap_exceeded() {
+            state.sync_deferred = true;
+            return Ok(false);
+        }
+    }
+    let sync_request = sync_manager.create_sync_request(None); // Full sync
+    let sender = sync_sender.lock().await;
+    let sender = sender.as_ref().ok_or_else(|| anyhow!("Not joined to the sync topic"))?;
+    sender.broadcast(Bytes::from(serde_json::to_vec(&sync_request)?)).await?;
+    Ok(true)
+}
+
+/// Metrics of the node; connect latency stays empty while offline
+fn collect_metrics(
+    storage: &Storage,
+    sync_manager: &SyncManager,
+    bandwidth: &BandwidthMeter,
+    resilience: Option<&NetworkResilience>,
+) -> MetricsSnapshot {
+    let sync_store = sync_manager.sync_store();
+    MetricsSnapshot {
+        messages: bandwidth.message_counts(),
+        topic_bytes: bandwidth.stats().topics.into_iter().map(|(name, _, counts)| (name, counts)).collect(),
+        ops_applied: sync_store.ops_applied(),
+        verify_failures: sync_store.verify_failures(),
+        limit_violations: sync_store.limit_violations(),
+        connect_latency: resilience
+            .map(NetworkResilience::connect_latency)
+            .unwrap_or_else(|| Histogram::new(&CONNECT_LATENCY_BUCKETS)),
+        storage_size_bytes: storage.size_bytes().unwrap_or(0),
+        storage_keys: storage.key_count().unwrap_or(0) as u64,
+        stored_operations: storage.operation_count().unwrap_or(0) as u64,
+        outbox_depth: storage.outbox_len().unwrap_or(0) as u64,
+    }
+}
+
+/// Broadcast `message` on the sync topic, or queue it in the outbox when
+/// nobody would receive it: no sync neighbors, or older messages still queued
+/// (so peers get them in order)
+async fn publish_sync_message(
+    message: &SyncMessage,
+    sync_sender: &Mutex<Option<CountedSender>>,
+    storage: &Storage,
+    neighbors: &GossipNeighbors,
+) {
+    let payload = match serde_json::to_vec(message) {
+        Ok(payload) => payload,
+        Err(e) => {
+            log_warn!("Failed to serialize sync message: {}", e);
+            return;
+        }
+    };
+    if neighbors.on_topic("sync") && storage.outbox_is_empty().unwrap_or(false) {
+        if let Some(sender) = sync_sender.lock().await.as_ref() {
+            match sender.broadcast(Bytes::from(payload.clone())).await {
+                Ok(()) => return,
+                // Queueing wouldn't help
+                Err(e) if e.downcast_ref::<MessageTooLarge>().is_some() => {
+                    log_warn!("Dropping sync message: {}", e);
+                    return;
+                }
+                Err(_) => {}
+            }
+        }
+    }
+    let db_name = match message {
+        SyncMessage::Operation { operation } => Some(operation.db_name.as_str()),
+        _ => None,
+    };
+    if let Err(e) = storage.outbox_push(&payload, db_name) {
+        log_warn!("Failed to queue sync message: {}", e);
+    }
+}
+
+/// Send queued sync messages oldest first while there are sync neighbors;
+/// how many were sent
+async fn flush_outbox(
+    sync_sender: &Mutex<Option<CountedSender>>,
+    storage: &Storage,
+    neighbors: &GossipNeighbors,
+) -> Result<usize> {
+    let mut sent = 0;
+    while neighbors.on_topic("sync") {
+        let queued = storage.outbox_peek(OUTBOX_FLUSH_BATCH)?;
+        if queued.is_empty() {
+            break;
+        }
+        let sender = sync_sender.lock().await;
+        let Some(sender) = sender.as_ref() else {
+            break;
+        };
+        for (id, payload) in queued {
+            match sender.broadcast(Bytes::from(payload)).await {
+                Ok(()) => {}
+                // Queued under a larger max message size; it can't be sent
+                Err(e) if e.downcast_ref::<MessageTooLarge>().is_some() => {
+                    log_warn!("Dropping queued sync message: {}", e);
+                    storage.outbox_remove(id)?;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            }
+            storage.outbox_remove(id)?;
+            sent += 1;
+        }
+    }
+    Ok(sent)
+}
+
+/// Application topic joined through `Node::subscribe_topic`
+struct AppTopic {
+    sender: Arc<Mutex<Option<CountedSender>>>,
+    messages: broadcast::Sender<TopicMessage>,
+    listener: tokio::task::JoinHandle<()>,
+}
+
+/// Summarize the connection's open paths
+fn peer_path(paths: &iroh::endpoint::PathInfoList) -> PeerPath {
+    let mut peer_path = PeerPath { updated_at: Utc::now().timestamp_millis(), ..Default::default() };
+    for path in paths.iter() {
+        if path.is_selected() {
+            peer_path.selected = Some(if path.is_relay() { ConnectionPath::Relayed } else { ConnectionPath::Direct });
+        }
+        if let iroh::TransportAddr::Ip(addr) = path.remote_addr() {
+            peer_path.direct_addrs.push(addr.to_string());
+        } else if let iroh::TransportAddr::Relay(url) = path.remote_addr() {
+            peer_path.relay_url = Some(url.to_string());
+        }
+    }
+    peer_path
+}
+
+/// Keep the registry's view of how `peer_id` is reached current until the
+/// connection closes
+async fn watch_connection_path(
+    conn: iroh::endpoint::Connection,
+    peer_id: EndpointId,
+    peer_registry: Arc<RwLock<PeerRegistry>>,
+) {
+    let peer_id = peer_id.to_string();
+    let mut paths = conn.paths();
+    peer_registry.write().set_connection_path(&peer_id, peer_path(&paths.get()));
+    loop {
+        tokio::select! {
+            _ = conn.closed() => break,
+            updated = paths.updated() => match updated {
+                Ok(list) => peer_registry.write().set_connection_path(&peer_id, peer_path(&list)),
+                Err(_) => break,
+            },
+        }
+    }
+    peer_registry.write().clear_connection_path(&peer_id);
+}
+
+/// Ping a peer and record its one-way latency (half the round trip, as
+/// gossip latency responses report it)
+async fn measure_latency(
+    endpoint: &Endpoint,
+    peer_id: &str,
+    shared_state: &RwLock<SharedNodeState>,
+    peer_registry: &RwLock<PeerRegistry>,
+    event_tx: &mpsc::Sender<NodeEvent>,
+) -> Result<u64, String> {
+    let peer_endpoint_id = peer_id
+        .parse::<EndpointId>()
+        .map_err(|_| format!("Invalid node ID {}", peer_id))?;
+    shared_state.write().latency_requests_sent += 1;
+
+    let rtt = match tokio::time::timeout(LATENCY_PING_TIMEOUT, crate::ping::round_trip(endpoint, peer_endpoint_id)).await {
+        Ok(Ok(rtt)) => rtt,
+        Ok(Err(e)) => return Err(format!("Failed to measure latency to {}: {}", peer_id, e)),
+        Err(_) => return Err(format!("Timed out measuring latency to {}", peer_id)),
+    };
+    let latency_ms = rtt.as_millis() as u64 / 2;
+    shared_state.write().latency_responses_received += 1;
+    peer_registry.write().update_latency(peer_id, latency_ms);
+    let _ = event_tx.send(NodeEvent::LatencyMeasured {
+        peer_id: peer_id.to_string(),
+        latency_ms,
+    }).await;
+    Ok(latency_ms)
+}
+
+/// Dial `peer_id` through `resilience`: skipped while it is being dialed
+/// already, the peer backs off or the cycle's connection attempts are used
+/// up, and jittered
+async fn connect_peer(
+    endpoint: Endpoint,
+    peer_id: EndpointId,
+    addr_opt: Option<String>,
+    resilience: Arc<NetworkResilience>,
+    peer_registry: Arc<RwLock<PeerRegistry>>,
+) -> Result<iroh::endpoint::Connection> {
+    let permit = resilience.begin_dial(peer_id).await?;
+    resilience.check_connect(&peer_id)?;
+    resilience.connect_jitter().await;
+    // Host names are resolved again on every dial
+    let addr = bootstrap::endpoint_addr(peer_id, addr_opt.as_deref()).await;
+    dial_peer(endpoint, addr, &resilience, peer_registry, permit).await
+}
+
+/// Queues peers for the dial task so listeners aren't held up and bursts of
+/// discovered peers are dialed one at a time, see `dial_queue`
+struct Dialer {
+    queue: RwLock<DialQueue>,
+    storage: Arc<Storage>,
+    peer_registry: Arc<RwLock<PeerRegistry>>,
+    bootstrap: HashSet<String>,
+    region: Option<String>,
+}
+
+impl Dialer {
+    /// Dialer continuing the queue saved by the last run
+    fn new(
+        storage: Arc<Storage>,
+        peer_registry: Arc<RwLock<PeerRegistry>>,
+        bootstrap: &[EndpointId],
+        region: Option<String>,
+    ) -> Self {
+        let saved: Vec<QueuedDial> = match storage.get_setting(DIAL_QUEUE_SETTING) {
+            Ok(Some(bytes)) => serde_json::from_slice(&bytes).unwrap_or_default(),
+            Ok(None) => Vec::new(),
+            Err(e) => {
+                log_warn!("Failed to load dial queue: {}", e);
+                Vec::new()
+            }
+        };
+        Self {
+            queue: RwLock::new(DialQueue::from_saved(saved)),
+            storage,
+            peer_registry,
+            bootstrap: bootstrap.iter().map(ToString::to_string).collect(),
+            region,
+        }
+    }
+
+    /// Queue a dial of `peer_id`; `via` says how it was found
+    fn enqueue(&self, peer_id: EndpointId, address: Option<String>, via: &str) {
+        let node_id = peer_id.to_string();
+        let dial = QueuedDial {
+            priority: self.priority(&node_id),
+            node_id,
+            address,
+            queued_at: Utc::now().timestamp_millis(),
+            via: via.to_string(),
+        };
+        let mut queue = self.queue.write();
+        if queue.push(dial) {
+            self.save(&queue);
+        }
+    }
+
+    fn priority(&self, node_id: &str) -> DialPriority {
+        if self.bootstrap.contains(node_id) {
+            return DialPriority::Bootstrap;
+        }
+        let registry = self.peer_registry.read();
+        if registry.is_pinned(node_id) {
+            return DialPriority::Pinned;
+        }
+        let nearby = registry.get_peer(node_id).is_some_and(|peer| {
+            (self.region.is_some() && peer.region == self.region)
+                || peer.latency_ms.is_some_and(|latency_ms| latency_ms < NEARBY_LATENCY_MS)
+        });
+        if nearby { DialPriority::Nearby } else { DialPriority::Other }
+    }
+
+    fn len(&self) -> usize {
+        self.queue.read().len()
+    }
+
+    /// Take the next dial
+    fn next(&self) -> Option<QueuedDial> {
+        let mut queue = self.queue.write();
+        let next = queue.pop();
+        if next.is_some() {
+            self.save(&queue);
+        }
+        next
+    }
+
+    fn save(&self, queue: &DialQueue) {
+        let saved = serde_json::to_vec(&queue.dials()).map_err(anyhow::Error::from);
+        if let Err(e) = saved.and_then(|bytes| self.storage.put_setting(DIAL_QUEUE_SETTING, &bytes)) {
+            log_warn!("Failed to save dial queue: {}", e);
+        }
+    }
+}
+
+/// Dial the peer at `addr` right away, recording the outcome for its
+/// backoff; the permit is held until the dial is done
+///
+/// The attempt runs in a `connect` span with the peer and address, closed
+/// by an event with its outcome and duration.
+async fn dial_peer(
+    endpoint: Endpoint,
+    addr: iroh::EndpointAddr,
+    resilience: &NetworkResilience,
+    peer_registry: Arc<RwLock<PeerRegistry>>,
+    _permit: DialPermit,
+) -> Result<iroh::endpoint::Connection> {
+    let peer_id = addr.id;
+    let addr_field = addr.ip_addrs().next().map(|ip| ip.to_string()).unwrap_or_else(|| "-".to_string());
+    let span = info_span!("connect", peer = %peer_id.fmt_short(), addr = %addr_field);
+    let started = Instant::now();
+    let res = endpoint.connect(addr, iroh_gossip::ALPN).instrument(span.clone()).await;
+    let duration_ms = started.elapsed().as_millis() as u64;
+
+    match res {
+        Ok(conn) => {
+            span.in_scope(|| info!(duration_ms, "Connected"));
+            resilience.connect_succeeded(&peer_id);
+            resilience.record_connect_latency(started.elapsed());
+            peer_registry.write().record_dial(&peer_id.to_string(), None);
+            // Track the path while this connection lasts, unless one already is
+            if !peer_registry.read().has_connection_path(&peer_id.to_string()) {
+                tokio::spawn(watch_connection_path(conn.clone(), peer_id, peer_registry));
+            }
+            Ok(conn)
+        }
+        Err(e) => {
+            span.in_scope(|| warn!(duration_ms, error = %e, "Connect failed"));
+            resilience.connect_failed(&peer_id);
+            peer_registry.write().record_dial(&peer_id.to_string(), Some(e.to_string()));
+            Err(anyhow!(e))
+        }
+    }
+}
+
+/// Node status
+#[derive(Debug, Clone)]
+pub struct NodeStatus {
+    pub is_running: bool,
+    pub node_id: Option<String>,
+    pub connected_peers: usize,
+    pub discovered_peers: usize,
+    pub uptime_seconds: u64,
+    pub gossip_messages_received: u64,
+    pub storage_size_bytes: u64,
+    pub total_keys: u64,
+    pub total_operations: u64,
+    pub sync_operations: usize,
+    pub latency_requests_sent: u64,
+    pub latency_responses_received: u64,
+    /// Sync messages waiting in the outbox for a reachable peer
+    pub outbox_depth: usize,
+    /// Traffic since the node started
+    pub session: TrafficStats,
+    pub lifetime: LifetimeStats,
+    /// The node's own gossip topics, in `STATUS_TOPICS` order
+    pub topics: Vec<TopicStats>,
+}
+
+/// Gossip topics broken down in `NodeStatus::topics`
+const STATUS_TOPICS: [&str; 5] = ["data", "discovery", "sync", "peers", "discovery-v2"];
+
+/// Session activity of one gossip topic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TopicStats {
+    pub topic: String,
+    pub messages_sent: u64,
+    pub messages_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub neighbors: usize,
+    /// When the last message or neighbor change arrived (ms)
+    pub last_event_at: Option<i64>,
+}
+
+/// Activity of `STATUS_TOPICS`; topics without any yet are all zero
+fn topic_stats(bandwidth: &BandwidthMeter, state: &SharedNodeState) -> Vec<TopicStats> {
+    let messages: HashMap<String, MessageCounts> = bandwidth.message_counts().into_iter().collect();
+    let bytes: HashMap<String, ByteCounts> =
+        bandwidth.stats().topics.into_iter().map(|(name, _, counts)| (name, counts)).collect();
+    STATUS_TOPICS
+        .iter()
+        .map(|topic| {
+            let messages = messages.get(*topic).copied().unwrap_or_default();
+            let bytes = bytes.get(*topic).copied().unwrap_or_default();
+            TopicStats {
+                topic: topic.to_string(),
+                messages_sent: messages.sent,
+                messages_received: messages.received,
+                bytes_sent: bytes.sent,
+                bytes_received: bytes.received,
+                neighbors: state.topic_neighbors.get(*topic).copied().unwrap_or(0),
+                last_event_at: bandwidth.last_event_at(topic),
+            }
+        })
+        .collect()
+}
+
+/// How well the node is connected to the network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectivityState {
+    Online,
+    /// Connected, but without a relay or still without gossip neighbors
+    Degraded,
+    /// No gossip neighbors for several isolation checks, or networking paused
+    Isolated,
+}
+
+impl ConnectivityState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConnectivityState::Online => "online",
+            ConnectivityState::Degraded => "degraded",
+            ConnectivityState::Isolated => "isolated",
+        }
+    }
+}
+
+/// Steps of starting the node, reported to `start_with_progress` callers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupStage {
+    /// The identity key was loaded or generated
+    KeyLoaded,
+    /// The endpoint is bound; the relay may still be connecting
+    EndpointOnline { relay_connected: bool },
+    /// Bootstrap peers are being dialed in the background
+    BootstrapConnecting { attempt: u32 },
+    /// Joined the node's gossip topics
+    TopicsSubscribed,
+    /// The node handles commands; no more stages follow
+    Ready,
+}
+
+/// Log a startup stage and pass it on to whoever waits for it
+fn report_startup(progress: &Option<mpsc::UnboundedSender<StartupStage>>, stage: StartupStage) {
+    log_info!("Startup: {:?}", stage);
+    if let Some(progress) = progress {
+        let _ = progress.send(stage);
+    }
+}
+
+/// Connectivity as of the last isolation check, and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Connectivity {
+    pub state: ConnectivityState,
+    pub reason: String,
+}
+
+impl Connectivity {
+    fn new(state: ConnectivityState, reason: &str) -> Self {
+        Self { state, reason: reason.to_string() }
+    }
+
+    /// Judge an isolation check. The node is degraded until
+    /// `checks_before_isolated` checks in a row found it isolated, then stays
+    /// isolated until it has gossip neighbors again.
+    fn assess(
+        connected: usize,
+        gossip_received: u64,
+        has_relay: bool,
+        isolated_checks: u32,
+        checks_before_isolated: u32,
+        previous: ConnectivityState,
+    ) -> Self {
+        if connected == 0 || (gossip_received == 0 && !has_relay) {
+            let reason = if connected == 0 { "no gossip neighbors" } else { "no relay and no gossip received" };
+            let state = if previous == ConnectivityState::Isolated || isolated_checks >= checks_before_isolated {
+                ConnectivityState::Isolated
+            } else {
+                ConnectivityState::Degraded
+            };
+            Self::new(state, reason)
+        } else if !has_relay {
+            Self::new(ConnectivityState::Degraded, "no relay, only direct connections")
+        } else {
+            Self::new(ConnectivityState::Online, "connected")
+        }
+    }
+}
+
+/// Record the node's connectivity, emitting `ConnectivityChanged` when the
+/// state changes
+async fn update_connectivity(
+    shared_state: &RwLock<SharedNodeState>,
+    event_tx: &mpsc::Sender<NodeEvent>,
+    connectivity: Connectivity,
+) {
+    let changed = {
+        let mut state = shared_state.write();
+        let changed = state.connectivity.state != connectivity.state;
+        state.connectivity = connectivity.clone();
+        changed
+    };
+    if changed {
+        log_info!("🔍 Connectivity {}: {}", connectivity.state.as_str(), connectivity.reason);
+        let _ = event_tx
+            .send(NodeEvent::ConnectivityChanged { state: connectivity.state, reason: connectivity.reason })
+            .await;
+    }
+}
+
+/// Node events sent to Flutter
+#[derive(Debug, Clone)]
+pub enum NodeEvent {
+    Started { node_id: String, public_key: String },
+    Stopped,
+    PeerConnected { peer_id: String },
+    PeerDisconnected { peer_id: String },
+    PeerDiscovered { peer_id: String, address: Option<String> },
+    /// `verified` is false if the message isn't signed by `from`, which may
+    /// then be spoofed
+    GossipReceived { topic: String, from: String, content: String, verified: bool },
+    SyncReceived { db_name: String, key: String },
+    SyncProgress { db_name: String, ops_received: u64, ops_total_estimate: u64, bytes: u64 },
+    ConflictResolved { db_name: String, key: String, kept_op_id: String, discarded_op_id: String },
+    LatencyMeasured { peer_id: String, latency_ms: u64 },
+    PresenceChanged { peer_id: String, online: bool, status: Option<String> },
+    /// A topic's gossip stream ended and it was joined again; application
+    /// topics are named `app:<name>`
+    TopicRecovered { topic: String },
+    /// The endpoint reached its home relay; peers behind NATs can be dialed
+    /// from now on
+    RelayConnected { relay_url: String },
+    /// A bootstrap peer was reached; failed ones are retried by the bootstrap
+    /// monitor
+    BootstrapConnected { peer_id: String },
+    /// A peer retired its key for a new node ID (see `identity`)
+    IdentityRotated { old_node_id: String, new_node_id: String },
+    /// A device was linked to a user key this node acts for (see `devices`)
+    DeviceLinked { user_public_key: String, device_node_id: String, device_name: String },
+    ConnectivityChanged { state: ConnectivityState, reason: String },
+    Error { message: String },
+}
+
+/// Commands sent to the node
+enum NodeCommand {
+    Stop(oneshot::Sender<()>),
+    SendGossip { topic: String, message: String, response: oneshot::Sender<Result<(), String>> },
+    SendLatencyRequest { peer_id: String, response: oneshot::Sender<Result<u64, String>> },
+    StoreData { db_name: String, key: String, value: Vec<u8>, public_key: String, signature: String },
+    GetData { db_name: String, key: String, response: oneshot::Sender<Option<Vec<u8>>> },
+    RequestSync { since_timestamp: Option<i64> },
+    BroadcastSync { message: SyncMessage },
+    PublishOperation { op: SignedOperation },
+    /// Answered once the data commands sent before it are handled
+    Barrier(oneshot::Sender<()>),
+    StoreBatch { entries: Vec<crate::api::BatchEntryDto>, response: oneshot::Sender<Result<usize, String>> },
+    IncrementCounter {
+        db_name: String,
+        key: String,
+        delta: i64,
+        signing_key: SigningKey,
+        response: oneshot::Sender<Result<i64, String>>,
+    },
+    EditText {
+        db_name: String,
+        key: String,
+        index: u64,
+        delete: u64,
+        insert: String,
+        signing_key: SigningKey,
+        response: oneshot::Sender<Result<String, String>>,
+    },
+    ConnectPeer {
+        peer_id: String,
+        address: Option<String>,
+        response: oneshot::Sender<Result<ConnectionResult, String>>,
+    },
+    SubscribeTopic { name: String, response: oneshot::Sender<Result<broadcast::Receiver<TopicMessage>, String>> },
+    PublishTopic { name: String, content: Vec<u8>, response: oneshot::Sender<Result<(), String>> },
+    UnsubscribeTopic { name: String, response: oneshot::Sender<bool> },
+    PauseNetworking(oneshot::Sender<()>),
+    ResumeNetworking(oneshot::Sender<()>),
+    SetLiteMode { lite: bool, response: oneshot::Sender<()> },
+    RpcCall {
+        peer_id: String,
+        method: String,
+        payload: Vec<u8>,
+        timeout: Duration,
+        response: oneshot::Sender<Result<Vec<u8>, String>>,
+    },
+    ProveOwnership { peer_id: String, response: oneshot::Sender<Result<VerifiedBinding, String>> },
+    HealthCheck(oneshot::Sender<HealthReport>),
+}
+
+/// Shared node state - updated by run_node, read by API
+#[derive(Debug, Clone)]
+pub struct SharedNodeState {
+    pub is_running: bool,
+    pub connected_peers: usize,
+    /// Gossip neighbors per topic
+    pub topic_neighbors: HashMap<String, usize>,
+    pub discovered_peers: usize,
+    pub gossip_messages_received: u64,
+    pub latency_requests_sent: u64,
+    pub latency_responses_received: u64,
+    pub sync_operations: usize,
+    /// Operations in the sync store, refreshed every `STATS_REFRESH_INTERVAL`
+    pub total_operations: usize,
+    /// See `Node::pause_networking`
+    pub networking_paused: bool,
+    /// From the device conditions the app reported
+    pub power_policy: PowerPolicy,
+    /// See `Node::set_power_mode`
+    pub power_mode: PowerMode,
+    /// A full sync was held back by the power policy
+    pub sync_deferred: bool,
+    /// Updated by the bootstrap monitor
+    pub connectivity: Connectivity,
+    /// When we last announced ourselves on the discovery topic (ms)
+    pub last_announcement_at: Option<i64>,
+    /// An MQTT bridge is running; announced as the `mqtt` capability
+    pub mqtt_bridge: bool,
+}
+
+impl Default for SharedNodeState {
+    fn default() -> Self {
+        Self {
+            is_running: true,
+            connected_peers: 0,
+            topic_neighbors: HashMap::new(),
+            discovered_peers: 0,
+            gossip_messages_received: 0,
+            latency_requests_sent: 0,
+            latency_responses_received: 0,
+            sync_operations: 0,
+            total_operations: 0,
+            networking_paused: false,
+            power_policy: PowerPolicy::default(),
+            power_mode: PowerMode::default(),
+            sync_deferred: false,
+            connectivity: Connectivity::new(ConnectivityState::Degraded, "connecting"),
+            last_announcement_at: None,
+            mqtt_bridge: false,
+        }
+    }
+}
+
+/// Gossip neighbors across all topics. A peer counts once while it's a
+/// neighbor on any topic; `SharedNodeState::connected_peers` follows the count.
+struct GossipNeighbors {
+    peers: DashMap<String, NeighborTopics>,
+    shared_state: Arc<RwLock<SharedNodeState>>,
+    stats: Arc<SessionStats>,
+}
+
+struct NeighborTopics {
+    topics: HashSet<&'static str>,
+    /// Last NeighborUp on any topic
+    last_up: Instant,
+}
+
+impl GossipNeighbors {
+    fn new(shared_state: Arc<RwLock<SharedNodeState>>, stats: Arc<SessionStats>) -> Self {
+        Self { peers: DashMap::new(), shared_state, stats }
+    }
+
+    /// Record a NeighborUp on `topic`
+    fn up(&self, topic: &'static str, peer_id: &EndpointId) {
+        match self.peers.entry(peer_id.to_string()) {
+            dashmap::Entry::Occupied(mut entry) => {
+                let entry = entry.get_mut();
+                entry.topics.insert(topic);
+                entry.last_up = Instant::now();
+            }
+            dashmap::Entry::Vacant(entry) => {
+                self.stats.record_connect(entry.key());
+                entry.insert(NeighborTopics { topics: HashSet::from([topic]), last_up: Instant::now() });
+            }
+        }
+        self.publish_count();
+    }
+
+    /// Record a NeighborDown on `topic`
+    fn down(&self, topic: &'static str, peer_id: &EndpointId) {
+        let peer_id = peer_id.to_string();
+        if let Some(mut entry) = self.peers.get_mut(&peer_id) {
+            entry.topics.remove(topic);
+        }
+        self.peers.remove_if(&peer_id, |_, entry| entry.topics.is_empty());
+        self.publish_count();
+    }
+
+    fn contains(&self, peer_id: &str) -> bool {
+        self.peers.contains_key(peer_id)
+    }
+
+    /// Some peer is a neighbor on `topic`
+    fn on_topic(&self, topic: &str) -> bool {
+        self.peers.iter().any(|entry| entry.topics.contains(topic))
+    }
+
+    fn count_on_topic(&self, topic: &str) -> usize {
+        self.peers.iter().filter(|entry| entry.topics.contains(topic)).count()
+    }
+
+    fn ids(&self) -> Vec<EndpointId> {
+        self.peers.iter().filter_map(|entry| entry.key().parse().ok()).collect()
+    }
+
+    /// Forget peers without a NeighborUp for `max_age`, in case a
+    /// NeighborDown was missed; how many were removed
+    fn remove_stale(&self, max_age: Duration) -> usize {
+        let before = self.peers.len();
+        self.peers.retain(|_, entry| entry.last_up.elapsed() < max_age);
+        self.publish_count();
+        before.saturating_sub(self.peers.len())
+    }
+
+    /// Forget every neighbor, as when leaving all topics
+    fn clear(&self) {
+        self.peers.clear();
+        self.publish_count();
+    }
+
+    fn publish_count(&self) {
+        let mut topic_neighbors: HashMap<String, usize> = HashMap::new();
+        for entry in self.peers.iter() {
+            for topic in &entry.topics {
+                *topic_neighbors.entry(topic.to_string()).or_default() += 1;
+            }
+        }
+        let mut state = self.shared_state.write();
+        state.connected_peers = self.peers.len();
+        state.topic_neighbors = topic_neighbors;
+    }
+}
+
+/// Main Cyberfly node
+/// What the commands about local data need; shared by the event loops of
+/// online and offline nodes
+struct DataCommands {
+    node_id: String,
+    storage: Arc<Storage>,
+    sync_manager: Arc<SyncManager>,
+    /// Empty while offline, so sync messages go to the outbox
+    sync_sender: Arc<Mutex<Option<CountedSender>>>,
+    neighbors: Arc<GossipNeighbors>,
+}
+
+impl DataCommands {
+    /// Apply a write or read of local data; writes are published to peers.
+    /// A node retiring its key takes no writes: its successor on the same
+    /// storage does.
+    async fn handle(&self, command: NodeCommand) {
+        const RETIRING: &str = "The node key is retired";
+        if self.sync_manager.is_answer_only() {
+            match command {
+                NodeCommand::StoreBatch { response, .. } => {
+                    let _ = response.send(Err(RETIRING.to_string()));
+                    return;
+                }
+                NodeCommand::IncrementCounter { response, .. } => {
+                    let _ = response.send(Err(RETIRING.to_string()));
+                    return;
+                }
+                NodeCommand::EditText { response, .. } => {
+                    let _ = response.send(Err(RETIRING.to_string()));
+                    return;
+                }
+                NodeCommand::StoreData { .. } | NodeCommand::PublishOperation { .. } | NodeCommand::BroadcastSync { .. } => {
+                    warn!("Dropping a write to a node whose key is retired");
+                    return;
+                }
+                _ => {}
+            }
+        }
+        match command {
+            NodeCommand::StoreData { db_name, key, value, public_key: pk, signature } => {
+                // Large values go to the blob store; storage and the operation
+                // only hold a reference
+                let blob_values = self
+                    .sync_manager
+                    .sync_store()
+                    .blob_values()
+                    .filter(|_| value.len() > blob_values::OFFLOAD_THRESHOLD);
+                let (stored, value_str, store_type) = match blob_values {
+                    Some(blob_values) => match blob_values.put(value).await {
+                        Ok(blob) => {
+                            let reference = blob.encode();
+                            (reference.clone().into_bytes(), reference, blob_values::BLOB_STORE_TYPE)
+                        }
+                        Err(e) => {
+                            error!("Failed to offload value of {}: {}", key, e);
+                            return;
+                        }
+                    },
+                    None => {
+                        let value_str = String::from_utf8_lossy(&value).to_string();
+                        (value, value_str, "String")
+                    }
+                };
+
+                // Store locally
+                if let Err(e) = self.storage.put(&db_name, &key, &stored) {
+                    error!("Failed to store data: {}", e);
+                    return;
+                }
+                // Flush immediately to ensure persistence
+                let _ = self.storage.flush();
+                
+                // Create sync operation and broadcast
+                let op = SignedOperation::new(
+                    db_name.clone(),
+                    key.clone(),
+                    value_str,
+                    store_type.to_string(),
+                    pk,
+                    signature,
+                );
+                self.replicate(op).await;
+            }
+            NodeCommand::StoreBatch { entries, response } => {
+                let values: Vec<(String, String, Vec<u8>)> = entries
+                    .iter()
+                    .map(|e| (e.db_name.clone(), e.key.clone(), e.value.clone()))
+                    .collect();
+                if let Err(e) = self.storage.put_many(&values) {
+                    error!("Failed to store batch: {}", e);
+                    let _ = response.send(Err(e.to_string()));
+                    return;
+                }
+
+                let stored = entries.len();
+                let mut ops = Vec::with_capacity(entries.len());
+                for entry in entries {
+                    if self.sync_manager.sync_store().is_local_only(&entry.db_name) {
+                        continue;
+                    }
+                    let op = SignedOperation::new(
+                        entry.db_name,
+                        entry.key,
+                        String::from_utf8_lossy(&entry.value).to_string(),
+                        "String".to_string(),
+                        entry.public_key,
+                        entry.signature,
+                    );
+                    let _ = self.sync_manager.sync_store().add_local_operation(op.clone()).await;
+                    ops.push(op);
+                }
+                // One flush for the whole batch
+                let _ = self.storage.flush();
+                let _ = response.send(Ok(stored));
+
+                for op in ops {
+                    let sync_msg = self.sync_manager.create_operation_message(op);
+                    publish_sync_message(&sync_msg, &self.sync_sender, &self.storage, &self.neighbors).await;
+                }
+            }
+            NodeCommand::GetData { db_name, key, response } => {
+                let data = self.storage.get(&db_name, &key).ok().flatten();
+                let _ = response.send(data);
+            }
+            NodeCommand::IncrementCounter { db_name, key, delta, signing_key, response } => {
+                // Handled here so increments of this replica are serialized
+                let store = self.sync_manager.sync_store();
+                let result = async {
+                    let op = store.counter_operation(&db_name, &key, &self.node_id, delta, &signing_key).await?;
+                    store.add_local_operation(op.clone()).await?;
+                    store.apply_to_storage(&op).await?;
+                    Ok::<_, anyhow::Error>(op)
+                }
+                .await;
+
+                match result {
+                    Ok(op) => {
+                        let value = self.storage
+                            .get(&db_name, &key)
+                            .ok()
+                            .flatten()
+                            .and_then(|v| String::from_utf8(v).ok())
+                            .and_then(|v| v.parse().ok())
+                            .unwrap_or(0);
+                        let _ = response.send(Ok(value));
+
+                        let sync_msg = self.sync_manager.create_operation_message(op);
+                        publish_sync_message(&sync_msg, &self.sync_sender, &self.storage, &self.neighbors).await;
+                    }
+                    Err(e) => {
+                        let _ = response.send(Err(e.to_string()));
+                    }
+                }
+            }
+            NodeCommand::EditText { db_name, key, index, delete, insert, signing_key, response } => {
+                // Handled here so edits of this replica are serialized
+                let store = self.sync_manager.sync_store();
+                let result = async {
+                    let op = store.text_operation(&db_name, &key, &self.node_id, index, delete, &insert, &signing_key)?;
+                    if let Some(op) = &op {
+                        store.add_local_operation(op.clone()).await?;
+                        store.apply_to_storage(op).await?;
+                    }
+                    Ok::<_, anyhow::Error>(op)
+                }
+                .await;
+
+                match result {
+                    Ok(op) => {
+                        let text = self.storage
+                            .get(&db_name, &key)
+                            .ok()
+                            .flatten()
+                            .map(|v| String::from_utf8_lossy(&v).into_owned())
+                            .unwrap_or_default();
+                        let _ = response.send(Ok(text));
+
+                        if let Some(op) = op {
+                            let sync_msg = self.sync_manager.create_operation_message(op);
+                            publish_sync_message(&sync_msg, &self.sync_sender, &self.storage, &self.neighbors).await;
+                        }
+                    }
+                    Err(e) => {
+                        let _ = response.send(Err(e.to_string()));
+                    }
+                }
+            }
+            NodeCommand::PublishOperation { op } => {
+                // Store locally
+                let stored = op
+                    .storage_key()
+                    .and_then(|storage_key| self.storage.put(&op.db_name, &storage_key, op.value.as_bytes()));
+                if let Err(e) = stored {
+                    error!("Failed to store operation {}: {}", op.op_id, e);
+                    return;
+                }
+                let _ = self.storage.flush();
+                self.replicate(op).await;
+            }
+            NodeCommand::BroadcastSync { message } => {
+                publish_sync_message(&message, &self.sync_sender, &self.storage, &self.neighbors).await;
+            }
+            NodeCommand::Barrier(response) => {
+                let _ = response.send(());
+            }
+            _ => debug!("Not a data command"),
+        }
+    }
+
+    /// Add a stored local write to the oplog and broadcast it to the sync
+    /// topic (or queue it until a peer is reachable), unless its database
+    /// is local only
+    async fn replicate(&self, op: SignedOperation) {
+        if self.sync_manager.sync_store().is_local_only(&op.db_name) {
+            return;
+        }
+        let _ = self.sync_manager.sync_store().add_local_operation(op.clone()).await;
+
+        let sync_msg = self.sync_manager.create_operation_message(op);
+        publish_sync_message(&sync_msg, &self.sync_sender, &self.storage, &self.neighbors).await;
+    }
+}
+
+/// Blob protocol of a node. The store can be lent to a node started on the
+/// same data dir while this one runs (see `rotate_identity`); only the node
+/// owning it shuts it down.
+#[derive(Debug, Clone)]
+struct NodeBlobs {
+    store: FsStore,
+    protocol: BlobsProtocol,
+    owns_store: Arc<AtomicBool>,
+}
+
+impl NodeBlobs {
+    fn new(store: FsStore, owns_store: bool) -> Self {
+        let protocol = BlobsProtocol::new(&store, None);
+        Self { store, protocol, owns_store: Arc::new(AtomicBool::new(owns_store)) }
+    }
+}
+
+impl ProtocolHandler for NodeBlobs {
+    async fn accept(&self, connection: Connection) -> Result<(), AcceptError> {
+        self.protocol.accept(connection).await
+    }
+
+    async fn shutdown(&self) {
+        if self.owns_store.load(Ordering::Acquire) {
+            self.protocol.shutdown().await;
+        }
+    }
+}
+
+pub struct CyberflyNode {
+    command_tx: mpsc::Sender<NodeCommand>,
+    /// None while offline (see `start_offline`)
+    endpoint: Option<Endpoint>,
+    /// Recent events for `recent_events` and `subscribe_events`
+    events: Arc<EventHistory>,
+    data_path: PathBuf,
+    /// Bootstrap peers given at start, for restarts
+    bootstrap_peers: Vec<String>,
+    node_id: String,
+    public_key: String,
+    /// Uptime and traffic of this session
+    stats: Arc<SessionStats>,
+    /// Traffic per topic and subsystem, and data caps
+    bandwidth: Arc<BandwidthMeter>,
+    // Shared state for sync access
+    shared_state: Arc<RwLock<SharedNodeState>>,
+    peer_registry: Arc<RwLock<PeerRegistry>>,
+    storage: Arc<Storage>,
+    sync_manager: Arc<SyncManager>,
+    /// Node identity key, included in backups
+    signing_key: SigningKey,
+    // Optional network resilience manager (initialized on start)
+    resilience: Option<Arc<NetworkResilience>>,
+    /// Configuration the node was started with, as updated by `update_config`
+    base_config: RwLock<NodeConfig>,
+    /// `base_config` adjusted to the power policy; what the node runs with
+    config: Arc<RwLock<NodeConfig>>,
+    /// Serves peers' RPC requests
+    rpc: RpcProtocol,
+    /// Direct messages with other nodes
+    dm: DmService,
+    /// File transfers with other nodes
+    files: FileService,
+    /// None while offline
+    blobs: Option<NodeBlobs>,
+    presence: Arc<RwLock<PresenceTracker>>,
+    /// Running MQTT bridge (see `start_mqtt_bridge`)
+    mqtt: parking_lot::Mutex<Option<MqttBridge>>,
+    /// Cancelled on stop, ending every background task of the node
+    shutdown: CancellationToken,
+}
+
+impl CyberflyNode {
+    /// Create and start a new node
+    pub async fn start(
+        data_dir: String,
+        wallet_secret_key: Option<String>,
+        bootstrap_peers: Vec<String>,
+        region: Option<String>,
+        network_id: Option<String>,
+    ) -> Result<Self> {
+        let config = NodeConfig { region, network_id, ..NodeConfig::default() };
+        Self::start_with_config(data_dir, wallet_secret_key, bootstrap_peers, config).await
+    }
+
+    /// Create and start a new node with `config`. Returns once storage and
+    /// the endpoint are up; the relay and bootstrap peers are connected in
+    /// the background (see `NodeEvent::RelayConnected` and
+    /// `NodeEvent::BootstrapConnected`)
+    pub async fn start_with_config(
+        data_dir: String,
+        wallet_secret_key: Option<String>,
+        bootstrap_peers: Vec<String>,
+        config: NodeConfig,
+    ) -> Result<Self> {
+        Self::start_with_progress(data_dir, wallet_secret_key, bootstrap_peers, config, None).await
+    }
+
+    /// `start_with_config`, sending each `StartupStage` to `progress` as it's
+    /// reached. The sender is dropped after `Ready`, or when starting fails.
+    pub async fn start_with_progress(
+        data_dir: String,
+        wallet_secret_key: Option<String>,
+        bootstrap_peers: Vec<String>,
+        config: NodeConfig,
+        progress: Option<mpsc::UnboundedSender<StartupStage>>,
+    ) -> Result<Self> {
+        config.validate()?;
+        let data_path = PathBuf::from(&data_dir);
+        std::fs::create_dir_all(&data_path)?;
+        let secret_key = load_secret_key(&data_path, wallet_secret_key)?;
+        report_startup(&progress, StartupStage::KeyLoaded);
+
+        info!("Starting Cyberfly node...");
+        let storage = open_storage(&data_path)?;
+        Self::start_networking(data_path, secret_key, storage, bootstrap_peers, config, None, progress).await
+    }
+
+    /// Open the node in `data_dir` without networking: storage, the oplog
+    /// and the data APIs work, and writes are queued in the outbox for
+    /// `go_online`. Uses the node key kept in `data_dir`.
+    pub async fn start_offline(data_dir: String) -> Result<Self> {
+        let data_path = PathBuf::from(&data_dir);
+        std::fs::create_dir_all(&data_path)?;
+        let secret_key = load_secret_key(&data_path, None)?;
+
+        info!("Starting Cyberfly node offline...");
+        let storage = open_storage(&data_path)?;
+        Self::start_offline_with(data_path, secret_key, storage).await
+    }
+
+    /// Start the offline event loop on an opened `storage`
+    async fn start_offline_with(data_path: PathBuf, secret_key: SecretKey, storage: Storage) -> Result<Self> {
+        let signing_key = SigningKey::from_bytes(&secret_key.to_bytes());
+        let node_id = secret_key.public().to_string();
+        let public_key = hex::encode(secret_key.public().as_bytes());
+
+        let (command_tx, command_rx) = mpsc::channel(100);
+        let (event_tx, event_rx) = mpsc::channel(100);
+        let events = Arc::new(EventHistory::default());
+        tokio::spawn({
+            let events = events.clone();
+            async move { events.run(event_rx).await }
+        });
+        let session_stats = Arc::new(SessionStats::load(&storage)?);
+        let bandwidth = Arc::new(BandwidthMeter::load(&storage)?);
+        let shared_state = Arc::new(RwLock::new(SharedNodeState {
+            connectivity: Connectivity::new(ConnectivityState::Isolated, "offline"),
+            ..SharedNodeState::default()
+        }));
+        let presence_status = storage
+            .get_setting(PRESENCE_STATUS_SETTING)?
+            .filter(|status| !status.is_empty())
+            .map(|status| String::from_utf8_lossy(&status).into_owned());
+        let presence = Arc::new(RwLock::new(PresenceTracker::new(node_id.clone(), presence_status)));
+        let rpc = RpcProtocol::new(storage.clone());
+        let dm = DmService::offline(storage.clone(), signing_key.clone(), node_id.clone());
+        let files = FileService::offline(storage.clone(), node_id.clone());
+
+        let storage = Arc::new(storage);
+        let sync_manager = Arc::new(
+            SyncManager::new(storage.clone(), node_id.clone(), Some(event_tx.clone()))
+                .with_bandwidth_meter(bandwidth.clone())
+                .with_node_key(signing_key.clone()),
+        );
+        let loaded = sync_manager.sync_store().load_from_storage().await?;
+        log_info!("✓ Loaded {} operations from persistent storage", loaded);
+
+        let config = NodeConfig::default();
+        sync_manager.set_max_ops_per_response(config.max_ops_per_response);
+        sync_manager.sync_store().set_operation_limits(config.operation_limits.clone());
+        let shutdown = CancellationToken::new();
+        let data_commands = DataCommands {
+            node_id: node_id.clone(),
+            storage: storage.clone(),
+            sync_manager: sync_manager.clone(),
+            sync_sender: Arc::new(Mutex::new(None)),
+            neighbors: Arc::new(GossipNeighbors::new(shared_state.clone(), session_stats.clone())),
+        };
+        tokio::spawn(Self::run_offline(
+            command_rx,
+            event_tx,
+            data_commands,
+            session_stats.clone(),
+            bandwidth.clone(),
+            shutdown.clone(),
+        ));
+
+        Ok(Self {
+            command_tx,
+            endpoint: None,
+            events,
+            data_path,
+            bootstrap_peers: Vec::new(),
+            peer_registry: Arc::new(RwLock::new(PeerRegistry::new(node_id.clone()))),
+            node_id,
+            public_key,
+            stats: session_stats,
+            bandwidth,
+            shared_state,
+            storage,
+            sync_manager,
+            signing_key,
+            resilience: None,
+            base_config: RwLock::new(config.clone()),
+            config: Arc::new(RwLock::new(config)),
+            rpc,
+            dm,
+            files,
+            blobs: None,
+            presence,
+            mqtt: parking_lot::Mutex::new(None),
+            shutdown,
+        })
+    }
+
+    /// Attach networking to a node from `start_offline`: it's stopped and
+    /// started again on the same storage with `config`, and the writes queued
+    /// while offline go out once peers are reachable. Streams opened on the
+    /// offline node end.
+    pub async fn go_online(&self, bootstrap_peers: Vec<String>, config: NodeConfig) -> Result<Self> {
+        if !self.is_offline() {
+            return Err(anyhow!("The node is online already"));
+        }
+        config.validate()?;
+        self.stop().await?;
+        let secret_key = SecretKey::try_from(&self.signing_key.to_bytes()[..])?;
+        let storage = (*self.storage).clone();
+        Self::start_networking(self.data_path.clone(), secret_key, storage, bootstrap_peers, config, None, None).await
+    }
+
+    /// Started with `start_offline` and not brought online
+    pub fn is_offline(&self) -> bool {
+        self.endpoint.is_none()
+    }
+
+    /// Retire the node key for `new_key` (a fresh one if None) for a node
+    /// started under it, online or offline as this one. The old key signs a
+    /// successor record, broadcast on the discovery topic until `grace` is
+    /// over, and an online node keeps answering sync requests under the old
+    /// key until then (or until the new node stops), leaving writes, merges
+    /// and the outbox to the new one. Databases named after the old
+    /// public key are copied, re-signed, to the same name under the new one;
+    /// the originals stay. The new key replaces the key file once the new
+    /// node is up; if anything fails, this node and the key file are left as
+    /// they were.
+    pub async fn rotate_identity(&self, new_key: Option<SigningKey>, grace: Duration) -> Result<(Self, IdentityRotation)> {
+        let new_key = new_key.unwrap_or_else(|| crypto::generate_keypair().0);
+        let record = SuccessorRecord::new(&self.signing_key, &new_key, grace);
+        // Refuses the current key
+        record.verify()?;
+
+        let secret_key = SecretKey::try_from(&new_key.to_bytes()[..])?;
+        let storage = (*self.storage).clone();
+        let node = match &self.blobs {
+            None => Self::start_offline_with(self.data_path.clone(), secret_key, storage).await?,
+            Some(blobs) => {
+                let config = self.config();
+                let store = Some(blobs.store.clone());
+                Self::start_networking(self.data_path.clone(), secret_key, storage, self.bootstrap_peers.clone(), config, store, None).await?
+            }
+        };
+        // From here the new node writes to the shared storage and sends
+        // its outbox
+        self.sync_manager.set_answer_only(true);
+        let rotation = match node.take_over(&self.public_key, record).await {
+            Ok(rotation) => rotation,
+            Err(e) => {
+                let _ = node.stop().await;
+                self.sync_manager.set_answer_only(false);
+                return Err(e);
+            }
+        };
+        info!(
+            "Rotated node key {} to {} ({} databases re-keyed)",
+            rotation.record.old_node_id, rotation.record.new_node_id, rotation.rekeyed_databases.len()
+        );
+
+        match (&self.blobs, &node.blobs) {
+            (Some(old), Some(new)) => {
+                new.owns_store.store(true, Ordering::Release);
+                old.owns_store.store(false, Ordering::Release);
+                self.retire_after(grace, node.shutdown.clone());
+            }
+            _ => {
+                if let Err(e) = self.stop().await {
+                    warn!("Failed to stop the node of the old key: {}", e);
+                }
+            }
+        }
+        Ok((node, rotation))
+    }
+
+    /// Take over the identity `old_public_key` retires by `record`: keep the
+    /// record, re-key the old databases and make our key the node key
+    async fn take_over(&self, old_public_key: &str, record: SuccessorRecord) -> Result<IdentityRotation> {
+        identity::save_successor(&self.storage, &record)?;
+        let (rekeyed_databases, rekeyed_operations) = self.rekey_databases(old_public_key).await?;
+        identity::save_own_record(&self.storage, &record)?;
+        keystore::write_key(&self.data_path.join(backup::SECRET_KEY_FILE), &self.signing_key.to_bytes())?;
+        Ok(IdentityRotation { record, rekeyed_databases, rekeyed_operations })
+    }
+
+    /// Stop once `grace` is over or `successor` is cancelled
+    fn retire_after(&self, grace: Duration, successor: CancellationToken) {
+        let command_tx = self.command_tx.clone();
+        let shutdown = self.shutdown.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = shutdown.cancelled() => return,
+                _ = successor.cancelled() => {}
+                _ = tokio::time::sleep(grace) => {}
+            }
+            info!("Retiring the old node key");
+            let (tx, rx) = oneshot::channel();
+            if command_tx.send(NodeCommand::Stop(tx)).await.is_ok() {
+                let _ = rx.await;
+            }
+        });
+    }
+
+    /// Publish re-signed copies of the databases named after
+    /// `old_public_key` under our name, with their writers and encryption
+    /// key. Returns the renamed databases and the number of operations
+    /// copied.
+    async fn rekey_databases(&self, old_public_key: &str) -> Result<(Vec<(String, String)>, usize)> {
+        let new_key = &self.signing_key;
+        let acl = self.sync_manager.sync_store().acl();
+        let now = Utc::now().timestamp_millis();
+        let mut rekeyed_databases = Vec::new();
+        let mut rekeyed_operations = 0;
+
+        let mut ops_by_db: HashMap<String, Vec<SignedOperation>> = HashMap::new();
+        for op in self.sync_manager.sync_store().get_all_operations().await {
+            if !op.is_expired(now) {
+                ops_by_db.entry(op.db_name.clone()).or_default().push(op);
+            }
+        }
+        for (db_name, ops) in ops_by_db {
+            let Some(new_db_name) = identity::rekeyed_db_name(&db_name, old_public_key, &self.public_key) else {
+                continue;
+            };
+            let db_key = self.storage.get_db_key(&db_name)?;
+            if let Some(key) = &db_key {
+                self.storage.put_db_key(&new_db_name, key)?;
+            }
+            if let Some(list) = acl.get(&db_name) {
+                let writers: Vec<String> = list.writers.into_iter().filter(|writer| writer != old_public_key).collect();
+                let list = acl.next_list(&new_db_name, &writers, &[], new_key)?;
+                acl.apply(list.clone())?;
+                self.command_tx.send(NodeCommand::BroadcastSync { message: SyncMessage::AclUpdate { acl: list } }).await?;
+            }
+
+            for op in ops {
+                // Ciphertext is bound to the database name
+                let value = match &db_key {
+                    Some(_) if crypto::is_encrypted_value(&op.value) => {
+                        let db_key = self.db_key(&db_name)?;
+                        let plaintext = crypto::decrypt_value(&db_key, crypto::encryption_aad(&db_name, &op.key).as_bytes(), &op.value)?;
+                        crypto::encrypt_value(&db_key, crypto::encryption_aad(&new_db_name, &op.key).as_bytes(), &plaintext)?
+                    }
+                    _ => op.value.clone(),
+                };
+                let op = identity::rekey_operation(&op, &new_db_name, value, new_key);
+                self.command_tx.send(NodeCommand::PublishOperation { op }).await?;
+                rekeyed_operations += 1;
+            }
+            rekeyed_databases.push((db_name, new_db_name));
+        }
+        rekeyed_databases.sort();
+        Ok((rekeyed_databases, rekeyed_operations))
+    }
+
+    /// Successor record of a peer (or of this node's retired identity) that
+    /// rotated its key
+    pub fn successor_of(&self, node_id: &str) -> Result<Option<SuccessorRecord>> {
+        identity::successor_of(&self.storage, node_id)
+    }
+
+    /// Bind the endpoint and start the event loop on an opened `storage`,
+    /// with the blob store of a node still running on `data_path` if given
+    async fn start_networking(
+        data_path: PathBuf,
+        secret_key: SecretKey,
+        storage: Storage,
+        bootstrap_peers: Vec<String>,
+        config: NodeConfig,
+        blob_store: Option<FsStore>,
+        progress: Option<mpsc::UnboundedSender<StartupStage>>,
+    ) -> Result<Self> {
+        let network_id = config.network_id.clone();
+        let public_key_hex = hex::encode(secret_key.public().as_bytes());
+
+        // Private network membership (see `private_network`)
+        let network_key = NetworkKey::load(&data_path)?;
+        if let Some(key) = &network_key {
+            log_info!("Joining private network {}", key.id());
+        }
+        if let Some(network_id) = &network_id {
+            log_info!("Joining application network '{}'", network_id);
+        }
+        
+        // Create ed25519 signing key from the same secret
+        let signing_key = SigningKey::from_bytes(&secret_key.to_bytes());
+
+        // Create channels
+        let (command_tx, command_rx) = mpsc::channel(100);
+        let (event_tx, event_rx) = mpsc::channel(100);
+        let events = Arc::new(EventHistory::default());
+        tokio::spawn({
+            let events = events.clone();
+            async move { events.run(event_rx).await }
+        });
+
+        // Build address-lookup services - DHT + mDNS for local network peers (matching desktop).
+        // iroh 0.98 renamed `discovery` → `address_lookup` and `DhtDiscovery`/`MdnsDiscovery`
+        // to `DhtAddressLookup`/`MdnsAddressLookup`.
+        let dht_discovery = DhtAddressLookup::builder();
+        let mdns_discovery = MdnsAddressLookup::builder();
+
+        // Create endpoint via the new preset-based builder API.
+        // `presets::N0` keeps n0's default relays + DNS address-lookup configured; we add
+        // DHT and mDNS on top to match the previous behaviour as closely as possible.
+        let endpoint = Endpoint::builder(presets::N0)
+            .secret_key(secret_key.clone())
+            .address_lookup(dht_discovery)
+            .address_lookup(mdns_discovery)
+            .relay_mode(relay_mode(&config.relay)?)
+            .bind()
+            .await?;
+
+        let node_id = endpoint.id();
+        let node_id_str = node_id.to_string();
+
+        info!("Node ID: {}", node_id_str);
+        log_info!("Node ID: {}", node_id_str);
+        
+        // Don't wait for the relay; run_node reports `RelayConnected` once
+        // there is one
+        let relay_connected = endpoint.addr().relay_urls().next().is_some();
+        report_startup(&progress, StartupStage::EndpointOnline { relay_connected });
+        
+        // Log endpoint addresses
+        let my_addr = endpoint.addr();
+        log_info!(">>> Our endpoint relay URLs: {:?}", 
+            my_addr.relay_urls().collect::<Vec<_>>());
+
+        // Create blob store
+        let blobs = match blob_store {
+            Some(store) => NodeBlobs::new(store, false),
+            None => NodeBlobs::new(FsStore::load(&data_path.join("blobs")).await?, true),
+        };
+        let store = blobs.store.clone();
+
+        // Create gossip
+        let gossip = Gossip::builder().max_message_size(config.max_gossip_message_size).spawn(endpoint.clone());
+
+        // Build router
+        let rpc = RpcProtocol::new(storage.clone());
+        let dm = DmService::new(endpoint.clone(), storage.clone(), signing_key.clone(), node_id_str.clone());
+        let files = FileService::new(endpoint.clone(), store.clone(), storage.clone(), node_id_str.clone());
+        let router = Router::builder(endpoint.clone())
+            .accept(iroh_blobs::ALPN, blobs.clone())
+            .accept(iroh_gossip::ALPN, gossip.clone())
+            .accept(crate::ping::PING_ALPN, crate::ping::PingProtocol)
+            .accept(rpc::RPC_ALPN, rpc.clone())
+            .accept(dm::DM_ALPN, dm.clone())
+            .accept(files::FILES_ALPN, files.clone())
+            .accept(auth::AUTH_ALPN, AuthProtocol::new(signing_key.clone(), node_id_str.clone()))
+            .spawn();
+
+        // Parse bootstrap peers - we'll connect in background. The user's come
+        // first, then those of the cached bootstrap manifest, then the
+        // built-in ones; a fresh manifest is fetched once the node is up
+        let mut bootstrap_node_ids: Vec<EndpointId> = Vec::new();
+        let manifest = config
+            .bootstrap_manifest
+            .as_ref()
+            .and_then(|source| bootstrap::cached(&storage, &source.publisher_key));
+        // The public bootstrap nodes only serve the default public network
+        let built_in: &[&str] = if network_key.is_none() && network_id.is_none() {
+            &[DEFAULT_BOOTSTRAP, DEFAULT_BOOTSTRAP_2]
+        } else {
+            &[]
+        };
+        let all_bootstrap_strings = bootstrap::merge_peers(&bootstrap_peers, manifest.as_ref(), built_in);
+        
+        // Parse peer IDs first (fast, no network)
+        for peer_str in &all_bootstrap_strings {
+            if let Some((node_id_str, _)) = peer_str.split_once('@') {
+                match node_id_str.parse::<EndpointId>() {
+                    Ok(peer_node_id) => {
+                        if peer_node_id != node_id {
+                            bootstrap_node_ids.push(peer_node_id);
+                        }
+                    }
+                    Err(e) => {
+                        log_warn!("Invalid bootstrap peer id '{}': {}", node_id_str, e);
+                    }
+                }
+            } else {
+                log_warn!("Malformed bootstrap peer entry (missing '@'): '{}'", peer_str);
             }
         }
         
+        // Create shared peer registry
+        let peer_registry = Arc::new(RwLock::new(
+            PeerRegistry::new(node_id_str.clone()).with_network_key(network_key),
+        ));
+        let peer_registry_clone = peer_registry.clone();
+        let presence_status = storage
+            .get_setting(PRESENCE_STATUS_SETTING)?
+            .filter(|status| !status.is_empty())
+            .map(|status| String::from_utf8_lossy(&status).into_owned());
+        let presence = Arc::new(RwLock::new(PresenceTracker::new(node_id_str.clone(), presence_status)));
+        let presence_clone = presence.clone();
+
+        // Every outbound connect goes through one resilience manager, sharing
+        // per-peer backoff and the per-cycle connection limit
+        let resilience = std::sync::Arc::new(NetworkResilience::new());
+        resilience.set_max_connections_per_cycle(config.max_connection_attempts_per_cycle);
+
+        let shutdown = CancellationToken::new();
+
         // Spawn bootstrap connections in background (non-blocking)
+        if !bootstrap_node_ids.is_empty() {
+            report_startup(&progress, StartupStage::BootstrapConnecting { attempt: 1 });
+        }
         let endpoint_clone = endpoint.clone();
         let bootstrap_strings = all_bootstrap_strings.clone();
-        // Per-peer backoff map for bootstrap attempts (keeps state across attempts)
-        let peer_backoff_start: Arc<DashMap<EndpointId, (u32, chrono::DateTime<chrono::Utc>)>> = Arc::new(DashMap::new());
         for peer_str in &bootstrap_strings {
             if let Some((node_id_str, addr_str)) = peer_str.split_once('@') {
                 if let Ok(peer_node_id) = node_id_str.parse::<EndpointId>() {
-                    // spawn a small background connect task per bootstrap peer with jitter
+                    // spawn a small background connect task per bootstrap peer (connect_peer jitters)
                     let endpoint_clone2 = endpoint_clone.clone();
                     let addr_opt = Some(addr_str.to_string());
-                    let pb = peer_backoff_start.clone();
-                    tokio::spawn(async move {
+                    let res = resilience.clone();
+                    let registry = peer_registry.clone();
+                    let event_tx = event_tx.clone();
+                    spawn_until(&shutdown, async move {
                         log_info!(">>> Background bootstrap connect task for {}", peer_node_id.fmt_short());
-                        // small randomized jitter up to 1s to avoid synchronized storms
-                        let jitter_ms: u64 = rand::thread_rng().gen_range(0..=1000);
-                        tokio::time::sleep(Duration::from_millis(jitter_ms)).await;
                         let connect_res = tokio::time::timeout(
                             Duration::from_secs(5),
-                            connect_peer(endpoint_clone2, peer_node_id, addr_opt, pb.clone(), None),
+                            connect_peer(endpoint_clone2, peer_node_id, addr_opt, res, registry),
                         ).await;
                         match connect_res {
-                            Ok(Ok(())) => {
+                            Ok(Ok(_)) => {
                                 log_info!("✓ Background connected to bootstrap: {}", peer_node_id.fmt_short());
+                                let _ = event_tx.send(NodeEvent::BootstrapConnected { peer_id: peer_node_id.to_string() }).await;
                             }
                             _ => {
                                 log_warn!(">>> Background bootstrap connect failed: {}", peer_node_id.fmt_short());
@@ -497,30 +2357,54 @@ impl CyberflyNode {
             }
         }
 
+        // Dial peers remembered from earlier runs so a restart doesn't depend
+        // on cold discovery; they join the gossip topics like bootstrap peers
+        let mut known_peer_ids: Vec<EndpointId> = Vec::new();
+        let mut known_peer_dials: Vec<(EndpointId, Option<String>)> = Vec::new();
+        for peer in load_known_peers(&storage) {
+            if known_peer_ids.len() >= config.max_known_peer_dials {
+                break;
+            }
+            let Ok(peer_node_id) = peer.node_id.parse::<EndpointId>() else {
+                continue;
+            };
+            if peer_node_id == node_id || bootstrap_node_ids.contains(&peer_node_id) {
+                continue;
+            }
+            known_peer_ids.push(peer_node_id);
+            known_peer_dials.push((peer_node_id, peer.address));
+        }
+        if !known_peer_ids.is_empty() {
+            log_info!("Dialing {} known peers from the last run", known_peer_ids.len());
+        }
+
+        // Pinned peers join the gossip topics like bootstrap peers and never
+        // expire; the pinned peer keeper in run_node dials them
+        let mut pinned_peer_ids: Vec<EndpointId> = Vec::new();
+        for (pinned_id, address) in storage.pinned_peers().unwrap_or_default() {
+            let Ok(peer_node_id) = pinned_id.parse::<EndpointId>() else {
+                continue;
+            };
+            peer_registry.write().pin_peer(pinned_id, address);
+            if !bootstrap_node_ids.contains(&peer_node_id) && !known_peer_ids.contains(&peer_node_id) {
+                pinned_peer_ids.push(peer_node_id);
+            }
+        }
+
         // Clone for the task
         let node_id_clone = node_id_str.clone();
         let public_key_clone = public_key_hex.clone();
-        let start_time = Instant::now();
+        let session_stats = Arc::new(SessionStats::load(&storage)?);
+        let session_stats_clone = session_stats.clone();
+        let bandwidth = Arc::new(BandwidthMeter::load(&storage)?);
+        let bandwidth_clone = bandwidth.clone();
 
         // Create shared state
         let shared_state = Arc::new(RwLock::new(SharedNodeState::default()));
         let shared_state_clone = shared_state.clone();
         
-        // Create shared peer registry
-        let peer_registry = Arc::new(RwLock::new(PeerRegistry::new(node_id_str.clone())));
-        let peer_registry_clone = peer_registry.clone();
-        
-        // Mark connected bootstrap peers in the shared state
-        // This ensures stats show connected peers even if HyParView NeighborUp hasn't fired yet
-        {
-            let connected_count = bootstrap_node_ids.len();
-            let mut state = shared_state.write();
-            state.connected_peers = connected_count;
-            state.discovered_peers = connected_count;
-            state.is_running = true;
-            log::info!("Initial shared state: connected={}, discovered={}, is_running=true", 
-                connected_count, connected_count);
-        }
+        // Peer counts follow gossip neighbors and the registry from here on
+        shared_state.write().is_running = true;
         
         // Also register bootstrap peers in peer registry
         for peer_id in &bootstrap_node_ids {
@@ -532,13 +2416,77 @@ impl CyberflyNode {
         let storage_arc = Arc::new(storage);
         let storage_clone = storage_arc.clone();
 
+        let dialer = Arc::new(Dialer::new(
+            storage_arc.clone(),
+            peer_registry.clone(),
+            &bootstrap_node_ids,
+            config.region.clone(),
+        ));
+        for (peer_id, address) in known_peer_dials {
+            dialer.enqueue(peer_id, address, "known peer");
+        }
+
+        // Fetch the bootstrap manifest without holding up startup, queueing
+        // dials of the peers it adds
+        if let Some(source) = config.bootstrap_manifest.clone() {
+            let storage = storage_arc.clone();
+            let dialer = dialer.clone();
+            let dialed = all_bootstrap_strings.clone();
+            let user_peers = bootstrap_peers.clone();
+            spawn_until(&shutdown, async move {
+                let Some(manifest) = bootstrap::refresh(&storage, &source).await else {
+                    return;
+                };
+                for peer in bootstrap::merge_peers(&user_peers, Some(&manifest), built_in) {
+                    if dialed.contains(&peer) {
+                        continue;
+                    }
+                    match bootstrap::parse_peer(&peer) {
+                        Ok((peer_id, addr)) if peer_id != node_id => {
+                            dialer.enqueue(peer_id, Some(addr.to_string()), "bootstrap manifest");
+                        }
+                        Ok(_) => {}
+                        Err(e) => log_warn!("Invalid bootstrap peer '{}': {}", peer, e),
+                    }
+                }
+            });
+        }
+
+        // Sync manager lives on the node so sync stats can be read from the API
+        let snapshots = Arc::new(SnapshotManager::new(store.clone(), endpoint.clone(), node_id_str.clone()));
+        let sync_manager = Arc::new(
+            SyncManager::new(storage_arc.clone(), node_id_str.clone(), Some(event_tx.clone()))
+                .with_snapshots(snapshots)
+                .with_bandwidth_meter(bandwidth.clone())
+                .with_node_key(signing_key.clone()),
+        );
+        sync_manager
+            .sync_store()
+            .set_blob_values(Arc::new(BlobValues::new(endpoint.clone(), store.clone(), node_id_str.clone())));
+        // Load persisted operations before the node is handed out, so they
+        // can be read (and re-keyed) right away
+        match sync_manager.sync_store().load_from_storage().await {
+            Ok(loaded) => {
+                log_info!("✓ Loaded {} operations from persistent storage", loaded);
+            }
+            Err(e) => {
+                log_error!("Failed to load operations from storage: {}", e);
+            }
+        }
+        let sync_manager_clone = sync_manager.clone();
+        let node_signing_key = signing_key.clone();
+
         // Get the current runtime handle to spawn run_node on
         // This ensures run_node runs on the same runtime as the caller
         let runtime_handle = tokio::runtime::Handle::current();
         
         // Spawn the main node task using the runtime handle
-        // Initialize network resilience manager and start background tasks
-        let resilience = std::sync::Arc::new(NetworkResilience::new());
+        // Start the network resilience background tasks
+        sync_manager.set_max_ops_per_response(config.max_ops_per_response);
+        sync_manager.sync_store().set_operation_limits(config.operation_limits.clone());
+        let base_config = RwLock::new(config.clone());
+        let config = Arc::new(RwLock::new(config));
+        let config_clone = config.clone();
         resilience.clone().start_background();
 
         // Also start bootstrap reconnect tasks using the full bootstrap strings
@@ -546,147 +2494,279 @@ impl CyberflyNode {
         resilience.clone().start_bootstrap_reconnects(endpoint.clone(), bs_clone);
 
         let resilience_clone_for_task = resilience.clone();
+        let mut gossip_peers = bootstrap_node_ids;
+        gossip_peers.extend(known_peer_ids);
+        gossip_peers.extend(pinned_peer_ids);
 
+        let node_endpoint = endpoint.clone();
+        let shutdown_clone = shutdown.clone();
         runtime_handle.spawn(async move {
             Self::run_node(
                 endpoint,
                 router,
                 gossip,
                 storage_clone,
+                sync_manager_clone,
                 command_rx,
                 event_tx,
                 node_id_clone,
                 public_key_clone,
-                bootstrap_node_ids,
+                gossip_peers,
                 signing_key,
-                region,
-                Some(resilience_clone_for_task),
+                resilience_clone_for_task,
                 shared_state_clone,
                 peer_registry_clone,
+                network_key,
+                config_clone,
+                presence_clone,
+                session_stats_clone,
+                bandwidth_clone,
+                dialer,
+                shutdown_clone,
+                progress,
             ).await;
         });
 
         Ok(Self {
             command_tx,
-            event_rx: Arc::new(RwLock::new(Some(event_rx))),
+            endpoint: Some(node_endpoint),
+            events,
+            data_path,
+            bootstrap_peers,
             node_id: node_id_str,
             public_key: public_key_hex,
-            start_time,
+            stats: session_stats,
+            bandwidth,
             shared_state,
             peer_registry,
             storage: storage_arc,
+            sync_manager,
+            signing_key: node_signing_key,
             resilience: Some(resilience),
+            base_config,
+            config,
+            rpc,
+            dm,
+            files,
+            blobs: Some(blobs),
+            presence,
+            mqtt: parking_lot::Mutex::new(None),
+            shutdown,
         })
     }
 
     /// Main node event loop
+    #[allow(clippy::too_many_arguments)]
     async fn run_node(
         endpoint: Endpoint,
         router: Router,
         gossip: Gossip,
         storage: Arc<Storage>,
+        sync_manager: Arc<SyncManager>,
         mut command_rx: mpsc::Receiver<NodeCommand>,
         event_tx: mpsc::Sender<NodeEvent>,
         node_id: String,
         public_key: String,
         bootstrap_peers: Vec<EndpointId>,
         signing_key: SigningKey,
-        region: Option<String>,
-        resilience: Option<Arc<NetworkResilience>>,
+        resilience: Arc<NetworkResilience>,
         shared_state: Arc<RwLock<SharedNodeState>>,
         peer_registry: Arc<RwLock<PeerRegistry>>,
+        network_key: Option<NetworkKey>,
+        config: Arc<RwLock<NodeConfig>>,
+        presence: Arc<RwLock<PresenceTracker>>,
+        session_stats: Arc<SessionStats>,
+        bandwidth: Arc<BandwidthMeter>,
+        dialer: Arc<Dialer>,
+        shutdown: CancellationToken,
+        progress: Option<mpsc::UnboundedSender<StartupStage>>,
     ) {
         eprintln!(">>> RUST: run_node starting for node_id: {}", node_id);
         info!(">>> run_node starting for node_id: {}", node_id);
+        let region = config.read().region.clone();
+        let network_id = config.read().network_id.clone();
         
         // Gossip message counter - use shared state
         // (we'll update shared_state directly instead)
         
-        // Connected peers (from NeighborUp events)
-        let connected_peers: Arc<DashMap<String, Instant>> = Arc::new(DashMap::new());
-        
-        // Sync manager
-        let sync_manager = Arc::new(SyncManager::new(storage.clone(), node_id.clone()));
+        // Connected peers (from NeighborUp events on every topic)
+        let neighbors = Arc::new(GossipNeighbors::new(shared_state.clone(), session_stats.clone()));
+
         
-        // Load persisted operations from storage
-        match sync_manager.sync_store().load_from_storage().await {
-            Ok(loaded) => {
-                log_info!("✓ Loaded {} operations from persistent storage", loaded);
-            }
-            Err(e) => {
-                log_error!("Failed to load operations from storage: {}", e);
-            }
+        // Cleanup task: remove stale neighbor entries (>10 min without NeighborUp refresh).
+        // Guards against leaks when NeighborDown events are missed.
+        {
+            let neighbors_cleanup = neighbors.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(300));
+                loop {
+                    interval.tick().await;
+                    let removed = neighbors_cleanup.remove_stale(Duration::from_secs(600));
+                    if removed > 0 {
+                        log_warn!("Cleaned up {} stale connected peer entries", removed);
+                    }
+                }
+            });
+        }
+
+        // Dial queued peers one per dial interval, skipping those that became
+        // neighbors in the meantime
+        {
+            let endpoint = endpoint.clone();
+            let resilience = resilience.clone();
+            let peer_registry = peer_registry.clone();
+            let shared_state = shared_state.clone();
+            let neighbors = neighbors.clone();
+            let dialer = dialer.clone();
+            let config = config.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(config.read().dial_interval);
+                loop {
+                    tick_configured(&mut interval, &config, |config| config.dial_interval).await;
+                    if shared_state.read().networking_paused {
+                        continue;
+                    }
+                    let Some(dial) = std::iter::from_fn(|| dialer.next()).find(|dial| !neighbors.contains(&dial.node_id)) else {
+                        continue;
+                    };
+                    let Ok(peer_id) = dial.node_id.parse::<EndpointId>() else {
+                        continue;
+                    };
+                    let span = info_span!(
+                        "queued_dial",
+                        via = %dial.via,
+                        priority = dial.priority.as_str(),
+                        still_queued = dialer.len(),
+                    );
+                    let (endpoint, resilience, peer_registry) = (endpoint.clone(), resilience.clone(), peer_registry.clone());
+                    tokio::spawn(
+                        async move {
+                            // `dial_peer` logs the outcome of attempts that get dialed
+                            if let Err(e) = connect_peer(endpoint, peer_id, dial.address, resilience, peer_registry).await {
+                                debug!(peer = %peer_id.fmt_short(), error = %e, "Not connecting to peer");
+                            }
+                        }
+                        .instrument(span),
+                    );
+                }
+            });
         }
-        
-        // Pending latency requests
-        let pending_latency: Arc<RwLock<HashMap<String, PendingLatencyRequest>>> = 
-            Arc::new(RwLock::new(HashMap::new()));
 
-        // Cleanup task: remove orphaned pending latency requests (>60s old)
-        // Prevents unbounded HashMap growth when responses are lost on mobile.
+        // Background task: remove keys whose TTL has passed. Peers run the
+        // same sweep, so expirations don't depend on anyone broadcasting.
         {
-            let pending_latency_cleanup = pending_latency.clone();
-            tokio::spawn(async move {
-                let mut interval = tokio::time::interval(Duration::from_secs(30));
+            let sync_manager_expiry = sync_manager.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(EXPIRY_SWEEP_INTERVAL);
                 loop {
                     interval.tick().await;
-                    let now_ms = chrono::Utc::now().timestamp_millis();
-                    let mut guard = pending_latency_cleanup.write();
-                    let before = guard.len();
-                    guard.retain(|_, req| now_ms.saturating_sub(req.sent_at) < 60_000);
-                    let removed = before.saturating_sub(guard.len());
-                    if removed > 0 {
-                        log_warn!("Cleaned up {} stale pending latency request(s)", removed);
+                    if sync_manager_expiry.is_answer_only() {
+                        continue;
+                    }
+                    let now = chrono::Utc::now().timestamp_millis();
+                    match sync_manager_expiry.sync_store().expire_operations(now).await {
+                        Ok(0) => {}
+                        Ok(removed) => debug!("Expired {} keys", removed),
+                        Err(e) => log_warn!("Expiry sweep failed: {}", e),
                     }
                 }
             });
         }
 
-        // Cleanup task: remove stale connected_peers entries (>10 min without NeighborUp refresh).
-        // Guards against leaks when NeighborDown events are missed.
+        // Background task: drop superseded operations from the oplog
         {
-            let connected_peers_cleanup = connected_peers.clone();
-            tokio::spawn(async move {
-                let mut interval = tokio::time::interval(Duration::from_secs(300));
+            let sync_manager = sync_manager.clone();
+            let config = config.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(OPLOG_GC_INTERVAL);
+                interval.tick().await;
                 loop {
                     interval.tick().await;
-                    let now = Instant::now();
-                    let before = connected_peers_cleanup.len();
-                    connected_peers_cleanup.retain(|_, last_seen| {
-                        now.duration_since(*last_seen) < Duration::from_secs(600)
-                    });
-                    let removed = before.saturating_sub(connected_peers_cleanup.len());
-                    if removed > 0 {
-                        log_warn!("Cleaned up {} stale connected peer entries", removed);
+                    let Some(policy) = config.read().oplog_gc.clone().filter(|_| !sync_manager.is_answer_only()) else {
+                        continue;
+                    };
+                    let now = chrono::Utc::now().timestamp_millis();
+                    if let Err(e) = sync_manager.sync_store().collect_garbage(&policy, now).await {
+                        log_warn!("Oplog GC failed: {}", e);
+                    }
+                }
+            });
+        }
+
+        // Background task: keep peer latencies fresh for the UI and ranking
+        {
+            let endpoint = endpoint.clone();
+            let shared_state = shared_state.clone();
+            let peer_registry = peer_registry.clone();
+            let event_tx = event_tx.clone();
+            let config = config.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(config.read().latency_sweep_interval);
+                interval.tick().await;
+                loop {
+                    tick_configured(&mut interval, &config, |config| config.latency_sweep_interval).await;
+                    if shared_state.read().networking_paused {
+                        continue;
                     }
+                    let peer_ids: Vec<String> = peer_registry
+                        .read()
+                        .get_active_peers()
+                        .iter()
+                        .map(|peer| peer.node_id.clone())
+                        .collect();
+                    debug!("Latency sweep over {} peers", peer_ids.len());
+                    futures::stream::iter(peer_ids)
+                        .for_each_concurrent(LATENCY_SWEEP_CONCURRENCY, |peer_id| {
+                            let (endpoint, shared_state, peer_registry, event_tx) =
+                                (&endpoint, &shared_state, &peer_registry, &event_tx);
+                            async move {
+                                if let Err(e) = measure_latency(endpoint, &peer_id, shared_state, peer_registry, event_tx).await {
+                                    debug!("{}", e);
+                                }
+                            }
+                        })
+                        .await;
                 }
             });
         }
 
-        // Background task: periodically refresh storage size/key-count cache.
-        // The scan is O(N) over every tree so we don't want it on the status
-        // read hot path. Every 30s is plenty for a "bytes stored" UI stat.
+        // Background task: keep lifetime stats, bandwidth usage and the operation count current
         {
-            let storage_refresh = storage.clone();
-            tokio::spawn(async move {
-                // Light delay so we don't race with the initial prime scan.
-                tokio::time::sleep(Duration::from_secs(30)).await;
-                let mut interval = tokio::time::interval(Duration::from_secs(30));
+            let storage_stats = storage.clone();
+            let session_stats = session_stats.clone();
+            let bandwidth = bandwidth.clone();
+            let sync_manager = sync_manager.clone();
+            let shared_state = shared_state.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(STATS_REFRESH_INTERVAL);
                 loop {
                     interval.tick().await;
-                    storage_refresh.refresh_stats();
+                    shared_state.write().total_operations = sync_manager.sync_store().operation_count().await;
+                    if let Err(e) = session_stats.save(&storage_stats) {
+                        log_warn!("Failed to save lifetime stats: {}", e);
+                    }
+                    if let Err(e) = bandwidth.save(&storage_stats) {
+                        log_warn!("Failed to save bandwidth usage: {}", e);
+                    }
                 }
             });
         }
 
-        // Per-peer backoff state to avoid connect storms after failures.
-        // Prefer the shared map provided by NetworkResilience when available.
-        let peer_backoff: Arc<DashMap<EndpointId, (u32, chrono::DateTime<chrono::Utc>)>> =
-            if let Some(res) = &resilience {
-                res.peer_backoff()
-            } else {
-                Arc::new(DashMap::new())
-            };
+        // Background task: remember discovered peers for the next launch
+        {
+            let storage_peers = storage.clone();
+            let peer_registry_save = peer_registry.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(KNOWN_PEERS_SAVE_INTERVAL);
+                interval.tick().await;
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = save_known_peers(&storage_peers, &peer_registry_save) {
+                        log_warn!("Failed to save known peers: {}", e);
+                    }
+                }
+            });
+        }
 
         // Send started event
         log_info!(">>> About to send Started event");
@@ -698,24 +2778,46 @@ impl CyberflyNode {
 
         // Create topic IDs
         log_info!(">>> Creating topic IDs");
-        let data_topic_id = TopicId::from_bytes(*DATA_TOPIC);
-        let discovery_topic_id = TopicId::from_bytes(*DISCOVERY_TOPIC);
-        let sync_topic_id = TopicId::from_bytes(*SYNC_TOPIC);
-        let peer_discovery_topic_id = TopicId::from_bytes(*PEER_DISCOVERY_TOPIC);
-        let improved_discovery_topic_id = TopicId::from_bytes(*IMPROVED_DISCOVERY_TOPIC);
-        let latency_topic_id = TopicId::from_bytes(*LATENCY_TOPIC);
+        // Topics are namespaced by the application network, and in a private
+        // network every topic is keyed on top (see `private_network`)
+        let topic_id = |base: &[u8; 32], name: &str| {
+            let topic = namespaced_topic(base, name, network_id.as_deref());
+            TopicId::from_bytes(private_network::topic_bytes(&topic, network_key.as_ref()))
+        };
+        let data_topic_id = topic_id(DATA_TOPIC, "data");
+        let discovery_topic_id = topic_id(DISCOVERY_TOPIC, "discovery");
+        let sync_topic_id = topic_id(SYNC_TOPIC, "sync");
+        let peer_discovery_topic_id = topic_id(PEER_DISCOVERY_TOPIC, "peers");
+        let improved_discovery_topic_id = topic_id(IMPROVED_DISCOVERY_TOPIC, "discovery-v2");
+        let latency_topic_id = topic_id(LATENCY_TOPIC, "latency");
         log_info!(">>> Topic IDs created successfully (including v2 improved discovery and latency)");
 
         // Gossip senders for each topic
         log_info!(">>> Creating gossip senders");
-        let data_sender: Arc<Mutex<Option<GossipSender>>> = Arc::new(Mutex::new(None));
-        let discovery_sender: Arc<Mutex<Option<GossipSender>>> = Arc::new(Mutex::new(None));
-        let sync_sender: Arc<Mutex<Option<GossipSender>>> = Arc::new(Mutex::new(None));
-        let peer_discovery_sender: Arc<Mutex<Option<GossipSender>>> = Arc::new(Mutex::new(None));
-        let improved_discovery_sender: Arc<Mutex<Option<GossipSender>>> = Arc::new(Mutex::new(None));
-        let latency_sender: Arc<Mutex<Option<GossipSender>>> = Arc::new(Mutex::new(None));
+        let data_sender: Arc<Mutex<Option<CountedSender>>> = Arc::new(Mutex::new(None));
+        let discovery_sender: Arc<Mutex<Option<CountedSender>>> = Arc::new(Mutex::new(None));
+        let sync_sender: Arc<Mutex<Option<CountedSender>>> = Arc::new(Mutex::new(None));
+        let peer_discovery_sender: Arc<Mutex<Option<CountedSender>>> = Arc::new(Mutex::new(None));
+        let improved_discovery_sender: Arc<Mutex<Option<CountedSender>>> = Arc::new(Mutex::new(None));
+        let latency_sender: Arc<Mutex<Option<CountedSender>>> = Arc::new(Mutex::new(None));
         log_info!(">>> Gossip senders created (including latency sender)");
 
+        // Topics are left while networking is paused or in lite mode
+        let (gate_tx, gate_rx) = watch::channel(TopicGate {
+            paused: false,
+            lite: shared_state.read().power_mode.is_lite(),
+        });
+        let joiner = TopicJoiner {
+            gossip: gossip.clone(),
+            bootstrap_peers: bootstrap_peers.clone(),
+            peer_registry: peer_registry.clone(),
+            stats: session_stats.clone(),
+            bandwidth: bandwidth.clone(),
+            gate: gate_rx,
+            event_tx: event_tx.clone(),
+            max_message_size: config.read().max_gossip_message_size,
+        };
+
         let peer_ids_str: Vec<String> = bootstrap_peers.iter().map(|p| p.fmt_short().to_string()).collect();
         log_info!("About to subscribe to data topic with {} bootstrap peers: {:?}", 
             bootstrap_peers.len(), peer_ids_str);
@@ -725,28 +2827,25 @@ impl CyberflyNode {
         // Desktop uses subscribe() which returns immediately, not subscribe_and_join()
         log_info!(">>> Calling gossip.subscribe for data topic (non-blocking like desktop)...");
         
-        let data_subscribe_result = gossip.subscribe(data_topic_id, bootstrap_peers.clone()).await;
+        let data_subscribe_result = joiner.subscribe(data_topic_id, "data", Subsystem::Gossip, bootstrap_peers.clone(), data_sender.clone()).await;
         log_info!("Data topic subscribe result: success={}", data_subscribe_result.is_ok());
         
         match data_subscribe_result {
-            Ok(topic_handle) => {
+            Ok(mut receiver) => {
             log_info!("Successfully subscribed to data topic");
             info!("Successfully subscribed to data topic");
-            let (sender, mut receiver) = topic_handle.split();
-            *data_sender.lock().await = Some(sender);
             
             let event_tx_clone = event_tx.clone();
             let shared_state_clone = shared_state.clone();
-            let connected_peers_clone = connected_peers.clone();
+            let neighbors_clone = neighbors.clone();
             let peer_registry_clone = peer_registry.clone();
-            let pending_latency_clone = pending_latency.clone();
             let signing_key_clone = signing_key.clone();
             let node_id_clone = node_id.clone();
             let public_key_clone = public_key.clone();
             let region_clone = region.clone();
             let data_sender_clone = data_sender.clone();
 
-            tokio::spawn(async move {
+            spawn_until(&shutdown, async move {
                 log_info!("📡 DATA_TOPIC LISTENER TASK STARTED");
                 log_info!("Data topic listener started, waiting for gossip events...");
                 info!("Data topic listener started, waiting for gossip events...");
@@ -772,15 +2871,18 @@ impl CyberflyNode {
                         Ok(GossipEvent::Received(msg)) => {
                             log_info!("Received gossip message from {}", msg.delivered_from);
                             shared_state_clone.write().gossip_messages_received += 1;
-                            let from = msg.delivered_from.to_string();
-                            
                             if let Ok(gossip_msg) = serde_json::from_slice::<GossipMessage>(&msg.content) {
+                                let verified = gossip_msg.is_authentic();
                                 match gossip_msg {
                                     GossipMessage::Custom { from: sender, content, .. } => {
+                                        if !verified {
+                                            debug!("Unverified custom gossip delivered by {}", msg.delivered_from.fmt_short());
+                                        }
                                         let _ = event_tx_clone.send(NodeEvent::GossipReceived {
                                             topic: "data".to_string(),
                                             from: sender,
                                             content,
+                                            verified,
                                         }).await;
                                     }
                                     GossipMessage::LatencyRequest { request_id, from_node_id, public_key, sent_at, signature } => {
@@ -819,38 +2921,8 @@ impl CyberflyNode {
                                             }
                                         }
                                     }
-                                    GossipMessage::LatencyResponse { request_id, from_node_id, responded_at, .. } => {
-                                        // Check if we have a pending request - scope lock to avoid Send issue
-                                        let pending_and_latency = {
-                                            let pending = pending_latency_clone.write().remove(&request_id);
-                                            pending.map(|p| {
-                                                let latency = if responded_at > p.sent_at {
-                                                    (responded_at.saturating_sub(p.sent_at) / 2) as u64
-                                                } else {
-                                                    0
-                                                };
-                                                (latency, p.callback)
-                                            })
-                                        };
-                                        
-                                        if let Some((latency, callback)) = pending_and_latency {
-                                            // Increment latency responses received counter
-                                            shared_state_clone.write().latency_responses_received += 1;
-                                            
-                                            // Update peer registry
-                                            peer_registry_clone.write().update_latency(&from_node_id, latency);
-                                            
-                                            // Send event
-                                            let _ = event_tx_clone.send(NodeEvent::LatencyMeasured {
-                                                peer_id: from_node_id.clone(),
-                                                latency_ms: latency,
-                                            }).await;
-                                            
-                                            // Respond to callback if present
-                                            if let Some(callback) = callback {
-                                                let _ = callback.send(latency);
-                                            }
-                                        }
+                                    GossipMessage::LatencyResponse { .. } => {
+                                        // Our own measurements use direct pings (see `ping`)
                                     }
                                 }
                             }
@@ -859,17 +2931,15 @@ impl CyberflyNode {
                             let peer_str = peer_id.to_string();
                             log_info!("NeighborUp! peer={}", peer_str);
                             info!("NeighborUp! peer={}", peer_str);
-                            connected_peers_clone.insert(peer_str.clone(), Instant::now());
+                            neighbors_clone.up("data", &peer_id);
                             
                             // Register in peer_registry to match desktop node behavior
                             peer_registry_clone.write().register_connected_peer(peer_str.clone());
                             
-                            // Update both counts from peer_registry (source of truth)
                             let peer_count = peer_registry_clone.read().peer_count();
                             log_info!("Peer registry count after NeighborUp: {}", peer_count);
                             {
                                 let mut state = shared_state_clone.write();
-                                state.connected_peers = peer_count;
                                 state.discovered_peers = peer_count;
                                 log_info!("SharedState updated: connected={}, discovered={}", state.connected_peers, state.discovered_peers);
                             }
@@ -879,18 +2949,13 @@ impl CyberflyNode {
                             let peer_str = peer_id.to_string();
                             log_info!("NeighborDown! peer={}", peer_str);
                             info!("NeighborDown! peer={}", peer_str);
-                            connected_peers_clone.remove(&peer_str);
+                            neighbors_clone.down("data", &peer_id);
                             
                             // Unregister from peer_registry
                             peer_registry_clone.write().unregister_peer(&peer_str);
                             
-                            // Update counts from peer_registry
                             let peer_count = peer_registry_clone.read().peer_count();
-                            {
-                                let mut state = shared_state_clone.write();
-                                state.connected_peers = peer_count;
-                                state.discovered_peers = peer_count;
-                            }
+                            shared_state_clone.write().discovered_peers = peer_count;
                             let _ = event_tx_clone.send(NodeEvent::PeerDisconnected { peer_id: peer_str }).await;
                         }
                         Ok(GossipEvent::Lagged) => {
@@ -912,22 +2977,42 @@ impl CyberflyNode {
         }
 
         // Subscribe to discovery topic
-        if let Ok(topic_handle) = gossip.subscribe(discovery_topic_id, bootstrap_peers.clone()).await {
-            let (sender, mut receiver) = topic_handle.split();
-            *discovery_sender.lock().await = Some(sender);
+        if let Ok(mut receiver) = joiner.subscribe(discovery_topic_id, "discovery", Subsystem::Discovery, bootstrap_peers.clone(), discovery_sender.clone()).await {
             
             let event_tx_clone = event_tx.clone();
             let peer_registry_clone = peer_registry.clone();
             let shared_state_clone = shared_state.clone();
-            let endpoint_clone = endpoint.clone();
-            let pb = peer_backoff.clone();
-            let res_clone = resilience.clone();
+            let dialer_clone = dialer.clone();
+            let presence_clone = presence.clone();
+            let neighbors_clone = neighbors.clone();
+            let storage_clone = storage.clone();
+            let sync_manager_clone = sync_manager.clone();
+            let node_id_clone = node_id.clone();
 
-            tokio::spawn(async move {
+            spawn_until(&shutdown, async move {
                 while let Some(event) = receiver.next().await {
+                    match &event {
+                        Ok(GossipEvent::NeighborUp(peer_id)) => neighbors_clone.up("discovery", peer_id),
+                        Ok(GossipEvent::NeighborDown(peer_id)) => neighbors_clone.down("discovery", peer_id),
+                        _ => {}
+                    }
                     if let Ok(GossipEvent::Received(msg)) = event {
                         if let Ok(disc_msg) = serde_json::from_slice::<DiscoveryMessage>(&msg.content) {
                             match disc_msg {
+                                DiscoveryMessage::Presence(heartbeat) => {
+                                    let change = presence_clone.write().process_heartbeat(&heartbeat);
+                                    match change {
+                                        Ok(Some(change)) => {
+                                            let _ = event_tx_clone.send(NodeEvent::PresenceChanged {
+                                                peer_id: change.peer_id,
+                                                online: change.online,
+                                                status: change.status,
+                                            }).await;
+                                        }
+                                        Ok(None) => {}
+                                        Err(e) => debug!("Ignoring presence heartbeat: {}", e),
+                                    }
+                                }
                                 DiscoveryMessage::Announce(announcement) => {
                                     // Scope the lock to avoid Send issue
                                     let (is_new, node_id, address) = {
@@ -943,7 +3028,6 @@ impl CyberflyNode {
                                     {
                                         let mut state = shared_state_clone.write();
                                         state.discovered_peers = peer_count;
-                                        state.connected_peers = peer_count;
                                     }
                                     
                                     if is_new {
@@ -956,17 +3040,34 @@ impl CyberflyNode {
                                         if let Ok(peer_endpoint_id) = node_id.parse::<EndpointId>() {
                                             // Use backoff-aware connect helper
                                             let addr_opt = address.clone();
-                                            match connect_peer(endpoint_clone.clone(), peer_endpoint_id, addr_opt, pb.clone(), res_clone.clone()).await {
-                                                Ok(_) => {
-                                                    log_info!("✓ Connected to discovered peer {} via announcement", node_id);
-                                                }
-                                                Err(e) => {
-                                                    log_warn!("Failed to connect to peer {}: {}", node_id, e);
-                                                }
-                                            }
+                                            dialer_clone.enqueue(peer_endpoint_id, addr_opt, "via announcement");
                                         }
                                     }
                                 }
+                                DiscoveryMessage::Successor(record) => match identity::save_successor(&storage_clone, &record) {
+                                    Ok(true) => {
+                                        log_info!("Node {} rotated its key to {}", record.old_node_id, record.new_node_id);
+                                        let _ = event_tx_clone.send(NodeEvent::IdentityRotated {
+                                            old_node_id: record.old_node_id,
+                                            new_node_id: record.new_node_id,
+                                        }).await;
+                                    }
+                                    Ok(false) => {}
+                                    Err(e) => debug!("Ignoring successor record: {}", e),
+                                },
+                                DiscoveryMessage::DeviceLink(certificate) => match devices::save_certificate(&storage_clone, &node_id_clone, &certificate) {
+                                    Ok(true) => {
+                                        log_info!("Device {} linked to user key {}", certificate.device_node_id(), certificate.user_public_key);
+                                        sync_manager_clone.sync_store().follow_owner(&certificate.user_public_key);
+                                        let _ = event_tx_clone.send(NodeEvent::DeviceLinked {
+                                            device_node_id: certificate.device_node_id().to_string(),
+                                            device_name: certificate.request.device_name,
+                                            user_public_key: certificate.user_public_key,
+                                        }).await;
+                                    }
+                                    Ok(false) => {}
+                                    Err(e) => debug!("Ignoring device certificate: {}", e),
+                                },
                                 _ => {}
                             }
                         }
@@ -976,63 +3077,55 @@ impl CyberflyNode {
         }
 
         // Subscribe to sync topic
-        if let Ok(topic_handle) = gossip.subscribe(sync_topic_id, bootstrap_peers.clone()).await {
-            let (sender, mut receiver) = topic_handle.split();
-            *sync_sender.lock().await = Some(sender);
+        if let Ok(mut receiver) = joiner.subscribe(sync_topic_id, "sync", Subsystem::Sync, bootstrap_peers.clone(), sync_sender.clone()).await {
             
             let sync_manager_clone = sync_manager.clone();
             let event_tx_clone = event_tx.clone();
             let sync_sender_clone = sync_sender.clone();
             let shared_state_clone = shared_state.clone();
+            let neighbors_clone = neighbors.clone();
 
-            tokio::spawn(async move {
+            spawn_until(&shutdown, async move {
                 log_info!("Sync topic listener started, waiting for sync messages...");
                 while let Some(event) = receiver.next().await {
                     match event {
                         Ok(GossipEvent::Received(msg)) => {
                             let from_peer = msg.delivered_from.to_string();
-                            log_info!("📨 Received sync message from {} ({} bytes)", from_peer, msg.content.len());
-                            
+
                             match serde_json::from_slice::<SyncMessage>(&msg.content) {
                                 Ok(sync_msg) => {
-                                    // Log what type of message we received
-                                    match &sync_msg {
-                                        SyncMessage::Operation { operation } => {
-                                            log_info!("📥 Received Operation: {} db={} key={}", 
-                                                operation.op_id, operation.db_name, operation.key);
-                                        }
-                                        SyncMessage::SyncRequest { requester, since_timestamp } => {
-                                            log_info!("📥 Received SyncRequest from {} since={:?}", 
-                                                requester, since_timestamp);
-                                        }
-                                        SyncMessage::SyncResponse { requester, operations, .. } => {
-                                            log_info!("📥 Received SyncResponse for {} with {} ops", 
-                                                requester, operations.len());
-                                        }
-                                    }
-                                    
-                                    // Update sync operations counter
-                                    shared_state_clone.write().sync_operations += 1;
-                                    
-                                    match sync_manager_clone.handle_sync_message(sync_msg, &from_peer).await {
-                                        Ok(Some(response)) => {
-                                            log_info!("📤 Sending sync response");
-                                            // Send response back
-                                            if let Some(sender) = sync_sender_clone.lock().await.as_ref() {
-                                                if let Ok(payload) = serde_json::to_vec(&response) {
-                                                    let _ = sender.broadcast(Bytes::from(payload)).await;
+                                    let span = info_span!(
+                                        "sync_message",
+                                        peer = %msg.delivered_from.fmt_short(),
+                                        kind = sync_msg.kind(),
+                                        bytes = msg.content.len(),
+                                    );
+                                    async {
+                                        info!("Received sync message");
+                                        // Update sync operations counter
+                                        shared_state_clone.write().sync_operations += 1;
+
+                                        match sync_manager_clone.handle_sync_message(sync_msg, &from_peer).await {
+                                            Ok(Some(_)) if sync_manager_clone.bandwidth_cap_exceeded() => {
+                                                info!("Data cap used up, not sending sync response");
+                                            }
+                                            Ok(Some(response)) => {
+                                                info!(response = response.kind(), "Sending sync response");
+                                                if let Some(sender) = sync_sender_clone.lock().await.as_ref() {
+                                                    if let Ok(payload) = serde_json::to_vec(&response) {
+                                                        if let Err(e) = sender.broadcast(Bytes::from(payload)).await {
+                                                            warn!(error = %e, "Failed to send sync response");
+                                                        }
+                                                    }
                                                 }
                                             }
-                                        }
-                                        Ok(None) => {
-                                            log_info!("✓ Sync message handled (no response needed)");
-                                        }
-                                        Err(e) => {
-                                            log_error!("❌ Failed to handle sync message: {}", e);
-                                            error!("Failed to handle sync message: {}", e);
+                                            Ok(None) => debug!("Sync message handled, no response needed"),
+                                            Err(e) => error!(error = %e, "Failed to handle sync message"),
                                         }
                                     }
-                                    
+                                    .instrument(span)
+                                    .await;
+
                                     // Send event for Operation messages
                                     if let Ok(SyncMessage::Operation { operation }) = serde_json::from_slice::<SyncMessage>(&msg.content) {
                                         let _ = event_tx_clone.send(NodeEvent::SyncReceived {
@@ -1042,18 +3135,25 @@ impl CyberflyNode {
                                     }
                                 }
                                 Err(e) => {
-                                    log_error!("❌ Failed to deserialize sync message: {}", e);
-                                    // Log first 200 bytes for debugging
+                                    // First 200 bytes for debugging
                                     let preview = String::from_utf8_lossy(&msg.content[..msg.content.len().min(200)]);
-                                    log_error!("Message preview: {}", preview);
+                                    error!(
+                                        peer = %msg.delivered_from.fmt_short(),
+                                        bytes = msg.content.len(),
+                                        error = %e,
+                                        %preview,
+                                        "Failed to deserialize sync message"
+                                    );
                                 }
                             }
                         }
                         Ok(GossipEvent::NeighborUp(peer_id)) => {
                             log_info!("Sync topic: NeighborUp {}", peer_id);
+                            neighbors_clone.up("sync", &peer_id);
                         }
                         Ok(GossipEvent::NeighborDown(peer_id)) => {
                             log_info!("Sync topic: NeighborDown {}", peer_id);
+                            neighbors_clone.down("sync", &peer_id);
                         }
                         Ok(GossipEvent::Lagged) => {
                             log_warn!("Sync topic gossip lagged");
@@ -1071,23 +3171,20 @@ impl CyberflyNode {
 
         // Subscribe to peer discovery topic - use subscribe() like desktop (non-blocking)
         log_info!(">>> Subscribing to peer_discovery topic with subscribe() (non-blocking like desktop)...");
-        let peer_discovery_result = gossip.subscribe(peer_discovery_topic_id, bootstrap_peers.clone()).await;
+        let peer_discovery_result = joiner.subscribe(peer_discovery_topic_id, "peers", Subsystem::Discovery, bootstrap_peers.clone(), peer_discovery_sender.clone()).await;
         
         match peer_discovery_result {
-            Ok(topic_handle) => {
+            Ok(mut receiver) => {
             log_info!(">>> PEER_DISCOVERY_TOPIC subscription SUCCESS!");
-            let (sender, mut receiver) = topic_handle.split();
-            *peer_discovery_sender.lock().await = Some(sender);
             
             let peer_registry_clone = peer_registry.clone();
-            let endpoint_clone = endpoint.clone();
             let event_tx_clone = event_tx.clone();
             let shared_state_clone = shared_state.clone();
             let node_id_clone = node_id.clone();
-            let pb = peer_backoff.clone();
-            let resilience_pd = resilience.clone();
+            let dialer_clone = dialer.clone();
+            let neighbors_clone = neighbors.clone();
 
-            tokio::spawn(async move {
+            spawn_until(&shutdown, async move {
                 log_info!("📡 PEER_DISCOVERY LISTENER TASK STARTED");
                 log_info!("📡 Peer discovery listener started (topic: decentralized-peer-list-v1-iroh!)");
                 
@@ -1117,7 +3214,7 @@ impl CyberflyNode {
                             let from_peer = msg.delivered_from;
                             
                             // Skip our own messages
-                            if from_peer.to_string() == node_id_clone.to_string() {
+                            if from_peer.to_string() == node_id_clone {
                                 continue;
                             }
                             
@@ -1135,7 +3232,7 @@ impl CyberflyNode {
                                     let address_str = peer_str.split('@').nth(1).map(|s| s.to_string());
 
                                     // Skip our own ID
-                                    if node_id_str == node_id_clone.to_string() {
+                                    if node_id_str == node_id_clone {
                                         continue;
                                     }
 
@@ -1155,14 +3252,7 @@ impl CyberflyNode {
                                         // Try to connect (backoff-aware)
                                         if let Ok(peer_endpoint_id) = node_id_str.parse::<EndpointId>() {
                                             let addr_opt = address_str.clone();
-                                            match connect_peer(endpoint_clone.clone(), peer_endpoint_id, addr_opt, pb.clone(), resilience_pd.clone()).await {
-                                                Ok(_) => {
-                                                    log_info!("✓ Connected to peer {} from discovery", node_id_str);
-                                                }
-                                                Err(e) => {
-                                                    log_warn!("Failed to connect to peer {}: {}", node_id_str, e);
-                                                }
-                                            }
+                                            dialer_clone.enqueue(peer_endpoint_id, addr_opt, "from discovery");
                                         }
 
                                         let _ = event_tx_clone.send(NodeEvent::PeerDiscovered {
@@ -1177,59 +3267,46 @@ impl CyberflyNode {
                                 {
                                     let mut state = shared_state_clone.write();
                                     state.discovered_peers = peer_count;
-                                    state.connected_peers = peer_count;
                                 }
-                            }
-                            // Also try our mobile format
-                            else if let Ok(disc_msg) = serde_json::from_slice::<DiscoveryMessage>(&msg.content) {
-                                if let DiscoveryMessage::PeerList(list) = disc_msg {
-                                    log_info!("📋 Parsed PeerList from {}: {} peers", 
-                                        list.from_node_id, list.peers.len());
-                                    
-                                    let unknown_peers = peer_registry_clone.write().process_peer_list(&list);
-                                    let peer_count = peer_registry_clone.read().peer_count();
-                                    {
-                                        let mut state = shared_state_clone.write();
-                                        state.discovered_peers = peer_count;
-                                        state.connected_peers = peer_count;
-                                    }
+                            }
+                            // Also try our mobile format
+                            else if let Ok(DiscoveryMessage::PeerList(list)) = serde_json::from_slice::<DiscoveryMessage>(&msg.content) {
+                                log_info!("📋 Parsed PeerList from {}: {} peers", 
+                                    list.from_node_id, list.peers.len());
+                                
+                                let unknown_peers = peer_registry_clone.write().process_peer_list(&list);
+                                let peer_count = peer_registry_clone.read().peer_count();
+                                {
+                                    let mut state = shared_state_clone.write();
+                                    state.discovered_peers = peer_count;
+                                }
+                                
+                                for peer_str in unknown_peers {
+                                    let node_id_str = peer_str.split('@').next().unwrap_or(&peer_str);
+                                    let address_str = peer_str.split('@').nth(1).map(|s| s.to_string());
                                     
-                                    for peer_str in unknown_peers {
-                                        let node_id_str = peer_str.split('@').next().unwrap_or(&peer_str);
-                                        let address_str = peer_str.split('@').nth(1).map(|s| s.to_string());
+                                    if let Ok(peer_endpoint_id) = node_id_str.parse::<EndpointId>() {
+                                        let addr_opt = address_str.clone();
+                                        dialer_clone.enqueue(peer_endpoint_id, addr_opt, "from peer list");
                                         
-                                        if let Ok(peer_endpoint_id) = node_id_str.parse::<EndpointId>() {
-                                            let addr_opt = address_str.clone();
-                                            match connect_peer(endpoint_clone.clone(), peer_endpoint_id, addr_opt, pb.clone(), resilience_pd.clone()).await {
-                                                Ok(_) => {
-                                                    log_info!("✓ Connected to peer {} from peer list", node_id_str);
-                                                }
-                                                Err(e) => {
-                                                    log_warn!("Failed to connect to peer {}: {}", node_id_str, e);
-                                                }
-                                            }
-                                            
-                                            let _ = event_tx_clone.send(NodeEvent::PeerDiscovered {
-                                                peer_id: node_id_str.to_string(),
-                                                address: address_str,
-                                            }).await;
-                                        }
+                                        let _ = event_tx_clone.send(NodeEvent::PeerDiscovered {
+                                            peer_id: node_id_str.to_string(),
+                                            address: address_str,
+                                        }).await;
                                     }
                                 }
                             }
                         }
                         Ok(GossipEvent::NeighborUp(peer_id)) => {
                             log_info!("📡 Peer discovery NeighborUp: {}", peer_id.fmt_short());
+                            neighbors_clone.up("peers", &peer_id);
                             peer_registry_clone.write().register_connected_peer(peer_id.to_string());
                             let peer_count = peer_registry_clone.read().peer_count();
-                            {
-                                let mut state = shared_state_clone.write();
-                                state.discovered_peers = peer_count;
-                                state.connected_peers = peer_count;
-                            }
+                            shared_state_clone.write().discovered_peers = peer_count;
                         }
                         Ok(GossipEvent::NeighborDown(peer_id)) => {
                             log_info!("📡 Peer discovery NeighborDown: {}", peer_id.fmt_short());
+                            neighbors_clone.down("peers", &peer_id);
                         }
                         Ok(GossipEvent::Lagged) => {
                             log_warn!("📡 Peer discovery gossip lagged");
@@ -1248,19 +3325,15 @@ impl CyberflyNode {
 
         // Subscribe to improved discovery topic (v2 postcard format) - matches cyberfly-rust-node
         // This allows mobile nodes to participate in the newer discovery protocol
-        if let Ok(topic_handle) = gossip.subscribe(improved_discovery_topic_id, bootstrap_peers.clone()).await {
-            let (sender, mut receiver) = topic_handle.split();
-            *improved_discovery_sender.lock().await = Some(sender);
+        if let Ok(mut receiver) = joiner.subscribe(improved_discovery_topic_id, "discovery-v2", Subsystem::Discovery, bootstrap_peers.clone(), improved_discovery_sender.clone()).await {
             
             let peer_registry_clone = peer_registry.clone();
             let shared_state_clone = shared_state.clone();
             let event_tx_clone = event_tx.clone();
-            let endpoint_clone = endpoint.clone();
-            let node_id_clone = node_id.clone();
-            let pb = peer_backoff.clone();
-            let resilience_id = resilience.clone();
+            let dialer_clone = dialer.clone();
+            let neighbors_clone = neighbors.clone();
 
-            tokio::spawn(async move {
+            spawn_until(&shutdown, async move {
                 log_info!("✓ Improved discovery (v2 postcard) listener started");
                 while let Some(event) = receiver.next().await {
                     match event {
@@ -1269,41 +3342,22 @@ impl CyberflyNode {
                             // This matches cyberfly-rust-node format exactly
                             match SignedDiscoveryMessage::verify_and_decode(&msg.content) {
                                 Ok((verifying_key, discovery_node)) => {
-                                    // Verify NodeId matches the signing key (prevent spoofing)
-                                    let key_bytes = verifying_key.to_bytes();
-                                    let expected_node_id = if let Ok(pk) = iroh::PublicKey::from_bytes(&key_bytes) {
-                                        iroh::EndpointId::from(pk)
-                                    } else {
-                                        log_warn!("Invalid public key in discovery message");
-                                        continue;
-                                    };
-                                    
-                                    if discovery_node.node_id != expected_node_id {
-                                        log_warn!("NodeId spoofing detected: claimed {} but key is {}", 
-                                            discovery_node.node_id, expected_node_id);
-                                        continue;
-                                    }
-                                    
+                                    // Records name, region, capabilities and public key;
+                                    // rejects messages whose node ID isn't the signer's
                                     let from_peer = discovery_node.node_id.to_string();
-                                    
-                                    // Skip our own messages
-                                    if from_peer == node_id_clone {
-                                        continue;
-                                    }
-                                    
-                                    // Register peer with full info from discovery
-                                    let is_new = peer_registry_clone.write().register_peer_from_list(
-                                        from_peer.clone(),
-                                        None, // No address in v2 format
-                                        Some(discovery_node.region.clone()),
-                                    );
+                                    let is_new = match peer_registry_clone.write().process_discovery_node(&verifying_key, &discovery_node) {
+                                        Ok(is_new) => is_new,
+                                        Err(e) => {
+                                            log_warn!("Rejected v2 discovery message: {}", e);
+                                            continue;
+                                        }
+                                    };
                                     
                                     // Update peer counts
                                     let peer_count = peer_registry_clone.read().peer_count();
                                     {
                                         let mut state = shared_state_clone.write();
                                         state.discovered_peers = peer_count;
-                                        state.connected_peers = peer_count;
                                     }
                                     
                                     if is_new {
@@ -1316,14 +3370,7 @@ impl CyberflyNode {
                                         
                                         // ACTIVELY CONNECT to this peer (like desktop node does)
                                         if let Ok(peer_endpoint_id) = from_peer.parse::<EndpointId>() {
-                                            match connect_peer(endpoint_clone.clone(), peer_endpoint_id, None, pb.clone(), resilience_id.clone()).await {
-                                                Ok(_) => {
-                                                    log_info!("✓ Connected to peer {} via v2 discovery", from_peer);
-                                                }
-                                                Err(e) => {
-                                                    log_warn!("Failed to connect to peer {}: {}", from_peer, e);
-                                                }
-                                            }
+                                            dialer_clone.enqueue(peer_endpoint_id, None, "via v2 discovery");
                                         }
                                     }
                                 }
@@ -1336,16 +3383,14 @@ impl CyberflyNode {
                         Ok(GossipEvent::NeighborUp(peer_id)) => {
                             let peer_str = peer_id.to_string();
                             log_info!("Improved discovery: NeighborUp {}", peer_str);
+                            neighbors_clone.up("discovery-v2", &peer_id);
                             peer_registry_clone.write().register_connected_peer(peer_str);
                             let peer_count = peer_registry_clone.read().peer_count();
-                            {
-                                let mut state = shared_state_clone.write();
-                                state.discovered_peers = peer_count;
-                                state.connected_peers = peer_count;
-                            }
+                            shared_state_clone.write().discovered_peers = peer_count;
                         }
                         Ok(GossipEvent::NeighborDown(peer_id)) => {
                             log_info!("Improved discovery: NeighborDown {}", peer_id);
+                            neighbors_clone.down("discovery-v2", &peer_id);
                         }
                         Ok(GossipEvent::Lagged) => {
                             log_warn!("Improved discovery gossip lagged");
@@ -1361,17 +3406,16 @@ impl CyberflyNode {
         // Subscribe to fetch-latency-request topic - matches cyberfly-rust-node
         // This allows mobile nodes to participate in HTTP latency monitoring
         log_info!(">>> Subscribing to fetch-latency-request topic...");
-        if let Ok(topic_handle) = gossip.subscribe(latency_topic_id, bootstrap_peers.clone()).await {
-            let (sender, mut receiver) = topic_handle.split();
-            *latency_sender.lock().await = Some(sender);
+        if let Ok(mut receiver) = joiner.subscribe(latency_topic_id, "latency", Subsystem::Discovery, bootstrap_peers.clone(), latency_sender.clone()).await {
             log_info!("✓ Subscribed to fetch-latency-request topic");
             
             let latency_sender_clone = latency_sender.clone();
             let node_id_clone = node_id.clone();
             let region_clone = region.clone();
             let resilience_clone_for_latency = resilience.clone();
+            let neighbors_clone = neighbors.clone();
 
-            tokio::spawn(async move {
+            spawn_until(&shutdown, async move {
                 log_info!("⏱️ LATENCY_TOPIC LISTENER TASK STARTED");
                 while let Some(event) = receiver.next().await {
                     match event {
@@ -1385,7 +3429,7 @@ impl CyberflyNode {
                             let sender = latency_sender_clone.clone();
                             let node_id = node_id_clone.clone();
                             let region = region_clone.clone();
-                            // clone the optional Arc per task so the outer value isn't moved
+                            // clone the Arc per task so the outer value isn't moved
                             let resilience_for_task = resilience_clone_for_latency.clone();
 
                             tokio::spawn(async move {
@@ -1396,9 +3440,11 @@ impl CyberflyNode {
                         }
                         Ok(GossipEvent::NeighborUp(peer_id)) => {
                             log_info!("⏱️ Latency topic NeighborUp: {}", peer_id.fmt_short());
+                            neighbors_clone.up("latency", &peer_id);
                         }
                         Ok(GossipEvent::NeighborDown(peer_id)) => {
                             log_info!("⏱️ Latency topic NeighborDown: {}", peer_id.fmt_short());
+                            neighbors_clone.down("latency", &peer_id);
                         }
                         Ok(GossipEvent::Lagged) => {
                             log_warn!("Latency topic gossip lagged");
@@ -1413,6 +3459,34 @@ impl CyberflyNode {
         } else {
             log_error!("Failed to subscribe to fetch-latency-request topic");
         }
+        report_startup(&progress, StartupStage::TopicsSubscribed);
+
+        // Presence heartbeats, and offline detection for silent peers
+        {
+            let discovery_sender = discovery_sender.clone();
+            let presence = presence.clone();
+            let event_tx = event_tx.clone();
+            let signing_key = signing_key.clone();
+            let node_id = node_id.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(presence::HEARTBEAT_INTERVAL);
+                loop {
+                    interval.tick().await;
+                    let status = presence.read().local_status();
+                    let heartbeat = PresenceHeartbeat::new(&signing_key, node_id.clone(), true, status);
+                    broadcast_presence(&discovery_sender, heartbeat).await;
+
+                    let changes = presence.write().sweep(Instant::now());
+                    for change in changes {
+                        let _ = event_tx.send(NodeEvent::PresenceChanged {
+                            peer_id: change.peer_id,
+                            online: change.online,
+                            status: change.status,
+                        }).await;
+                    }
+                }
+            });
+        }
 
         // Periodic announcement task
         let discovery_sender_announce = discovery_sender.clone();
@@ -1423,30 +3497,69 @@ impl CyberflyNode {
         let signing_key_announce = signing_key.clone();
         let region_announce = region.clone();
         let peer_registry_announce = peer_registry.clone();
+        let config_announce = config.clone();
+        let shared_state_announce = shared_state.clone();
+        let storage_announce = storage.clone();
+        let endpoint_announce = endpoint.clone();
+        let neighbors_announce = neighbors.clone();
 
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_secs(ANNOUNCE_INTERVAL_SECS));
+        spawn_until(&shutdown, async move {
+            let mut interval = tokio::time::interval(config_announce.read().announce_interval);
             loop {
-                interval.tick().await;
+                tick_configured(&mut interval, &config_announce, |config| config.announce_interval).await;
+                let capabilities = NodeCapabilities {
+                    mqtt: shared_state_announce.read().mqtt_bridge,
+                    ..NodeCapabilities::mobile_node()
+                };
                 
                 // Send peer announcement
                 let mut announcement = PeerAnnouncement::new(
                     node_id_announce.clone(),
                     public_key_announce.clone(),
-                    None, // Mobile nodes typically don't have direct addresses
-                    NodeCapabilities::mobile_node(),
+                    None,
+                    capabilities.clone(),
                     region_announce.clone(),
                     Some(NODE_VERSION.to_string()),
                 );
+                // Without a public address peers reach us through the relay
+                let (addrs, relay_url) = announced_addrs(&endpoint_announce);
+                announcement.set_dial_addrs(addrs, relay_url);
                 announcement.sign(&signing_key_announce);
+                if let Some(key) = &network_key {
+                    announcement.tag_network(key);
+                }
                 
                 let disc_msg = DiscoveryMessage::Announce(announcement);
                 if let Some(sender) = discovery_sender_announce.lock().await.as_ref() {
                     match serde_json::to_vec(&disc_msg) {
-                        Ok(bytes) => { let _ = sender.broadcast(Bytes::from(bytes)).await; }
+                        Ok(bytes) => {
+                            if sender.broadcast(Bytes::from(bytes)).await.is_ok() {
+                                shared_state_announce.write().last_announcement_at = Some(Utc::now().timestamp_millis());
+                            }
+                        }
                         Err(e) => log_warn!("Failed to serialize Announce: {}", e),
                     }
                 }
+
+                // Point peers from our retired identity to this one
+                if let Ok(Some(record)) = identity::own_record(&storage_announce, &public_key_announce, Utc::now().timestamp_millis()) {
+                    if let Some(sender) = discovery_sender_announce.lock().await.as_ref() {
+                        if let Ok(bytes) = serde_json::to_vec(&DiscoveryMessage::Successor(record)) {
+                            let _ = sender.broadcast(Bytes::from(bytes)).await;
+                        }
+                    }
+                }
+
+                // Advertise the device links this node knows of
+                if let Ok(certificates) = devices::certificates(&storage_announce) {
+                    if let Some(sender) = discovery_sender_announce.lock().await.as_ref() {
+                        for certificate in certificates {
+                            if let Ok(bytes) = serde_json::to_vec(&DiscoveryMessage::DeviceLink(certificate)) {
+                                let _ = sender.broadcast(Bytes::from(bytes)).await;
+                            }
+                        }
+                    }
+                }
                 
                 // Send peer list
                 let peer_list = peer_registry_announce.read().get_peer_list_for_broadcast();
@@ -1466,6 +3579,23 @@ impl CyberflyNode {
                         }
                     }
                 }
+
+                // Desktop nodes learn our gossip neighbors from their own format
+                let connected_peers = peer_registry_announce
+                    .read()
+                    .peer_list_of(neighbors_announce.ids().iter().map(ToString::to_string));
+                let mut desktop_announcement = PeerDiscoveryAnnouncement::new(
+                    node_id_announce.clone(),
+                    connected_peers,
+                    region_announce.clone().unwrap_or_else(|| "unknown".to_string()),
+                );
+                desktop_announcement.sign(&signing_key_announce);
+                if let Some(sender) = peer_discovery_sender_announce.lock().await.as_ref() {
+                    match serde_json::to_vec(&desktop_announcement) {
+                        Ok(bytes) => { let _ = sender.broadcast(Bytes::from(bytes)).await; }
+                        Err(e) => log_warn!("Failed to serialize PeerDiscoveryAnnouncement: {}", e),
+                    }
+                }
                 
                 // Also broadcast on improved discovery topic (v2 postcard format)
                 // This uses postcard binary serialization matching cyberfly-rust-node EXACTLY
@@ -1474,14 +3604,14 @@ impl CyberflyNode {
                     if let Ok(our_endpoint_id) = node_id_announce.parse::<iroh::EndpointId>() {
                         // We use a simple counter that increments each announcement
                         static ANNOUNCE_COUNT: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
-                        let count = ANNOUNCE_COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        let count = ANNOUNCE_COUNT.fetch_add(1, Ordering::Relaxed);
                         
                         let node = DiscoveryNode {
                             name: format!("cyberfly-mobile-{}", &node_id_announce[..8]),
                             node_id: our_endpoint_id,
                             count,
                             region: region_announce.clone().unwrap_or_else(|| "unknown".to_string()),
-                            capabilities: NodeCapabilities::mobile_node(),
+                            capabilities: capabilities.clone(),
                         };
                         
                         // Use SignedDiscoveryMessage::sign_and_encode - matches desktop exactly
@@ -1504,42 +3634,81 @@ impl CyberflyNode {
 
         // Initial sync request - request full sync from bootstrap peers after a short delay
         let sync_sender_initial = sync_sender.clone();
-        let node_id_sync = node_id.clone();
-        tokio::spawn(async move {
+        let sync_manager_initial = sync_manager.clone();
+        let shared_state_initial = shared_state.clone();
+        spawn_until(&shutdown, async move {
             // Wait a bit for connections to establish
             tokio::time::sleep(Duration::from_secs(5)).await;
             
             log_info!("📤 Sending initial sync request to bootstrap peers...");
-            let sync_request = SyncMessage::SyncRequest {
-                requester: node_id_sync,
-                since_timestamp: None, // Full sync
-            };
-            
-            if let Some(sender) = sync_sender_initial.lock().await.as_ref() {
-                if let Ok(payload) = serde_json::to_vec(&sync_request) {
-                    match sender.broadcast(Bytes::from(payload)).await {
-                        Ok(_) => log_info!("✓ Initial sync request sent"),
-                        Err(e) => log_error!("Failed to send initial sync request: {}", e),
+            match send_sync_request(&sync_sender_initial, &sync_manager_initial, &shared_state_initial).await {
+                Ok(true) => log_info!("✓ Initial sync request sent"),
+                Ok(false) => log_info!("Initial sync deferred by the power policy or data cap"),
+                Err(e) => log_error!("Failed to send initial sync request: {}", e),
+            }
+        });
+
+        // Send a full sync the data cap held back once the day or month is over
+        let sync_sender_cap = sync_sender.clone();
+        let sync_manager_cap = sync_manager.clone();
+        let shared_state_cap = shared_state.clone();
+        spawn_until(&shutdown, async move {
+            let mut interval = tokio::time::interval(STATS_REFRESH_INTERVAL);
+            loop {
+                interval.tick().await;
+                let send_deferred_sync = {
+                    let mut state = shared_state_cap.write();
+                    state.sync_deferred
+                        && !state.power_policy.defer_sync()
+                        && !sync_manager_cap.bandwidth_cap_exceeded()
+                        && std::mem::take(&mut state.sync_deferred)
+                };
+                if send_deferred_sync {
+                    log_info!("Data cap reset, sending the deferred sync request");
+                    if let Err(e) = send_sync_request(&sync_sender_cap, &sync_manager_cap, &shared_state_cap).await {
+                        log_warn!("Failed to send deferred sync request: {}", e);
                     }
                 }
             }
         });
 
+        // Startup doesn't wait for the relay, so say when it's there
+        if config.read().relay != RelayConfig::Disabled {
+            let endpoint = endpoint.clone();
+            let event_tx = event_tx.clone();
+            spawn_until(&shutdown, async move {
+                endpoint.online().await;
+                let relay_url = endpoint.addr().relay_urls().next().map(|url| url.to_string()).unwrap_or_default();
+                log_info!("✓ Connected to relay {}", relay_url);
+                let _ = event_tx.send(NodeEvent::RelayConnected { relay_url }).await;
+            });
+        }
+
         // Bootstrap connection monitor - check and reconnect if isolated
         let endpoint_monitor = endpoint.clone();
         let gossip_monitor = gossip.clone();
         let bootstrap_peers_monitor = bootstrap_peers.clone();
         let shared_state_monitor = shared_state.clone();
-        let node_id_monitor = node_id.clone();
-        tokio::spawn(async move {
+        let config_monitor = config.clone();
+        let resilience_monitor = resilience.clone();
+        let event_tx_monitor = event_tx.clone();
+        let peer_registry_monitor = peer_registry.clone();
+        spawn_until(&shutdown, async move {
             log_info!("🔍 Bootstrap connection monitor started");
-            let mut check_interval = tokio::time::interval(Duration::from_secs(30));
+            let mut check_interval = tokio::time::interval(config_monitor.read().isolation_check_interval);
             let mut consecutive_isolation_count = 0u32;
             
             loop {
-                check_interval.tick().await;
+                tick_configured(&mut check_interval, &config_monitor, |config| config.isolation_check_interval).await;
+                let checks_before_reconnect = config_monitor.read().isolation_checks_before_reconnect;
                 
                 let state = shared_state_monitor.read().clone();
+                if state.networking_paused {
+                    consecutive_isolation_count = 0;
+                    let paused = Connectivity::new(ConnectivityState::Isolated, "networking paused");
+                    update_connectivity(&shared_state_monitor, &event_tx_monitor, paused).await;
+                    continue;
+                }
                 let connected = state.connected_peers;
                 let msgs = state.gossip_messages_received;
                 
@@ -1555,14 +3724,28 @@ impl CyberflyNode {
                 
                 // Check if we're isolated (no gossip neighbors)
                 // In HyParView, we need NeighborUp events to be "connected" to the overlay
-                let is_isolated = connected == 0 || (msgs == 0 && relay_urls.is_empty());
+                // Without relays only direct connections are expected
+                let has_relay = !relay_urls.is_empty() || config_monitor.read().relay == RelayConfig::Disabled;
+                let is_isolated = connected == 0 || (msgs == 0 && !has_relay);
                 
                 if is_isolated {
                     consecutive_isolation_count += 1;
-                    log_warn!("🔍 Node appears ISOLATED ({}/3 checks)", consecutive_isolation_count);
+                }
+                let connectivity = Connectivity::assess(
+                    connected,
+                    msgs,
+                    has_relay,
+                    consecutive_isolation_count,
+                    checks_before_reconnect,
+                    state.connectivity.state,
+                );
+                update_connectivity(&shared_state_monitor, &event_tx_monitor, connectivity).await;
+
+                if is_isolated {
+                    log_warn!("🔍 Node appears ISOLATED ({}/{} checks)", consecutive_isolation_count, checks_before_reconnect);
                     
-                    // After 3 consecutive isolated checks, try to reconnect
-                    if consecutive_isolation_count >= 3 {
+                    // After enough consecutive isolated checks, try to reconnect
+                    if consecutive_isolation_count >= checks_before_reconnect {
                         log_warn!("🔍 Attempting bootstrap reconnection...");
                         
                         for peer_id in &bootstrap_peers_monitor {
@@ -1575,8 +3758,20 @@ impl CyberflyNode {
                                 }
                             };
                             
-                            // Try relay-assisted connection
+                            // Try relay-assisted connection, unless backing off
+                            // from this peer or out of attempts for the cycle
                             if let Some(relay_url) = relay_urls.first() {
+                                let _permit = match resilience_monitor.begin_dial(*peer_id).await {
+                                    Ok(permit) => permit,
+                                    Err(e) => {
+                                        log_info!("🔍 Not reconnecting to {} now: {}", peer_id.fmt_short(), e);
+                                        continue;
+                                    }
+                                };
+                                if let Err(e) = resilience_monitor.check_connect(peer_id) {
+                                    log_info!("🔍 Not reconnecting to {} now: {}", peer_id.fmt_short(), e);
+                                    continue;
+                                }
                                 log_info!("🔍 Reconnecting to {} via relay {}", peer_id.fmt_short(), relay_url);
                                 
                                 let relay_addr = iroh::EndpointAddr::from_parts(
@@ -1587,23 +3782,30 @@ impl CyberflyNode {
                                     ],
                                 );
                                 
+                                let started = Instant::now();
                                 match tokio::time::timeout(
                                     Duration::from_secs(15),
                                     endpoint_monitor.connect(relay_addr, iroh_gossip::ALPN)
                                 ).await {
                                     Ok(Ok(conn)) => {
+                                        resilience_monitor.connect_succeeded(peer_id);
+                                        resilience_monitor.record_connect_latency(started.elapsed());
+                                        peer_registry_monitor.write().record_dial(&peer_id.to_string(), None);
                                         log_info!("🔍 ✓ Reconnected to bootstrap {} (remote: {:?})", 
                                             peer_id.fmt_short(), conn.remote_id());
                                         
                                         // Re-subscribe to data topic to rejoin HyParView
                                         log_info!("🔍 Re-joining gossip topics...");
-                                        let data_topic_id = TopicId::from_bytes(*DATA_TOPIC);
                                         let _ = gossip_monitor.subscribe(data_topic_id, vec![*peer_id]).await;
                                     }
                                     Ok(Err(e)) => {
+                                        resilience_monitor.connect_failed(peer_id);
+                                        peer_registry_monitor.write().record_dial(&peer_id.to_string(), Some(e.to_string()));
                                         log_warn!("🔍 Reconnect failed: {}", e);
                                     }
                                     Err(_) => {
+                                        resilience_monitor.connect_failed(peer_id);
+                                        peer_registry_monitor.write().record_dial(&peer_id.to_string(), Some("timed out".to_string()));
                                         log_warn!("🔍 Reconnect timed out");
                                     }
                                 }
@@ -1621,148 +3823,547 @@ impl CyberflyNode {
             }
         });
 
+        // Pinned peer keeper: redial pinned peers that aren't gossip neighbors
+        // (with the usual backoff) and join them to the data and sync topics
+        {
+            let endpoint = endpoint.clone();
+            let storage = storage.clone();
+            let neighbors = neighbors.clone();
+            let resilience = resilience.clone();
+            let peer_registry = peer_registry.clone();
+            let data_sender = data_sender.clone();
+            let sync_sender = sync_sender.clone();
+            let config = config.clone();
+            let shared_state = shared_state.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(config.read().pinned_peers_check_interval);
+                loop {
+                    tick_configured(&mut interval, &config, |config| config.pinned_peers_check_interval).await;
+                    if shared_state.read().networking_paused {
+                        continue;
+                    }
+                    let pinned = match storage.pinned_peers() {
+                        Ok(pinned) => pinned,
+                        Err(e) => {
+                            log_warn!("Failed to load pinned peers: {}", e);
+                            continue;
+                        }
+                    };
+                    for (pinned_id, address) in pinned {
+                        if neighbors.contains(&pinned_id) {
+                            continue;
+                        }
+                        let Ok(peer_node_id) = pinned_id.parse::<EndpointId>() else {
+                            continue;
+                        };
+                        let connect_res = tokio::time::timeout(
+                            Duration::from_secs(10),
+                            connect_peer(endpoint.clone(), peer_node_id, address, resilience.clone(), peer_registry.clone()),
+                        ).await;
+                        if matches!(connect_res, Ok(Ok(_))) {
+                            log_info!("✓ Connected to pinned peer {}", peer_node_id.fmt_short());
+                            for sender in [&data_sender, &sync_sender] {
+                                if let Some(sender) = sender.lock().await.as_ref() {
+                                    let _ = sender.join_peers(vec![peer_node_id]).await;
+                                }
+                            }
+                        } else {
+                            debug!("Pinned peer unreachable: {}", peer_node_id.fmt_short());
+                        }
+                    }
+                }
+            });
+        }
+
+        // Outbox flusher: send sync messages queued while no peer was
+        // reachable. A node retiring its key leaves the outbox it shares to
+        // its successor.
+        {
+            let storage = storage.clone();
+            let neighbors = neighbors.clone();
+            let sync_sender = sync_sender.clone();
+            let shared_state = shared_state.clone();
+            let sync_manager = sync_manager.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(OUTBOX_FLUSH_INTERVAL);
+                loop {
+                    interval.tick().await;
+                    if shared_state.read().networking_paused || sync_manager.is_answer_only() {
+                        continue;
+                    }
+                    match flush_outbox(&sync_sender, &storage, &neighbors).await {
+                        Ok(0) => {}
+                        Ok(sent) => log_info!("📤 Sent {} queued sync messages", sent),
+                        Err(e) => log_warn!("Failed to flush outbox: {}", e),
+                    }
+                }
+            });
+        }
+
+        // Confirm received operations to their writers in batches
+        {
+            let sync_sender = sync_sender.clone();
+            let sync_manager = sync_manager.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(ACK_FLUSH_INTERVAL);
+                loop {
+                    interval.tick().await;
+                    while let Some(ack) = sync_manager.take_operation_ack() {
+                        let Ok(payload) = serde_json::to_vec(&ack) else { break };
+                        if let Some(sender) = sync_sender.lock().await.as_ref() {
+                            if let Err(e) = sender.broadcast(Bytes::from(payload)).await {
+                                log_warn!("Failed to send operation acks: {}", e);
+                            }
+                        }
+                    }
+                }
+            });
+        }
+
+        // Publish signed roots of the databases this node owns or is linked
+        // to, so replicas can check they hold a faithful copy
+        {
+            let sync_manager = sync_manager.clone();
+            let neighbors = neighbors.clone();
+            let sync_sender = sync_sender.clone();
+            let signing_key = signing_key.clone();
+            spawn_until(&shutdown, async move {
+                let mut interval = tokio::time::interval(MERKLE_ROOT_INTERVAL);
+                loop {
+                    interval.tick().await;
+                    if !neighbors.on_topic("sync") {
+                        continue;
+                    }
+                    let roots = match sync_manager.signed_roots(&signing_key).await {
+                        Ok(roots) => roots,
+                        Err(e) => {
+                            log_warn!("Failed to compute database roots: {}", e);
+                            continue;
+                        }
+                    };
+                    if let Some(sender) = sync_sender.lock().await.as_ref() {
+                        for root in roots {
+                            if let Ok(payload) = serde_json::to_vec(&SyncMessage::MerkleRoot { root }) {
+                                let _ = sender.broadcast(Bytes::from(payload)).await;
+                            }
+                        }
+                    }
+                }
+            });
+        }
+
+        // Application topics, by name
+        let mut app_topics: HashMap<String, AppTopic> = HashMap::new();
+
         // Handle commands
+        let data_commands = DataCommands {
+            node_id: node_id.clone(),
+            storage: storage.clone(),
+            sync_manager: sync_manager.clone(),
+            sync_sender: sync_sender.clone(),
+            neighbors: neighbors.clone(),
+        };
+        report_startup(&progress, StartupStage::Ready);
+        drop(progress);
         info!(">>> run_node: entering command loop");
         while let Some(cmd) = command_rx.recv().await {
             info!(">>> run_node: received command");
             match cmd {
                 NodeCommand::Stop(response) => {
                     info!("Stopping node");
+                    let status = presence.read().local_status();
+                    let heartbeat = PresenceHeartbeat::new(&signing_key, node_id.clone(), false, status);
+                    broadcast_presence(&discovery_sender, heartbeat).await;
+                    if let Err(e) = save_known_peers(&storage, &peer_registry) {
+                        error!("Failed to save known peers on stop: {}", e);
+                    }
+                    if let Err(e) = session_stats.save(&storage) {
+                        error!("Failed to save lifetime stats on stop: {}", e);
+                    }
+                    if let Err(e) = bandwidth.save(&storage) {
+                        error!("Failed to save bandwidth usage on stop: {}", e);
+                    }
                     // Flush storage to disk before stopping
                     if let Err(e) = storage.flush() {
                         error!("Failed to flush storage on stop: {}", e);
                     } else {
                         info!("Storage flushed to disk successfully");
                     }
+                    // End the background tasks and leave every topic, so nothing
+                    // keeps the storage or the endpoint alive after stop
+                    shutdown.cancel();
+                    resilience.shutdown();
+                    for (_, topic) in app_topics.drain() {
+                        topic.listener.abort();
+                    }
+                    for sender in [&data_sender, &discovery_sender, &sync_sender, &peer_discovery_sender, &improved_discovery_sender, &latency_sender] {
+                        sender.lock().await.take();
+                    }
                     let _ = event_tx.send(NodeEvent::Stopped).await;
                     let _ = router.shutdown().await;
+                    endpoint.close().await;
                     let _ = response.send(());
                     break;
                 }
-                NodeCommand::GetStatus(response) => {
-                    let sync_stats = sync_manager.get_stats().await;
-                    let state = shared_state.read().clone();
-                    let status = NodeStatus {
-                        is_running: true,
-                        node_id: Some(node_id.clone()),
-                        connected_peers: state.connected_peers,
-                        discovered_peers: state.discovered_peers,
-                        uptime_seconds: 0,
-                        gossip_messages_received: state.gossip_messages_received,
-                        storage_size_bytes: storage.size_bytes().unwrap_or(0),
-                        total_keys: storage.key_count().unwrap_or(0) as u64,
-                        total_operations: sync_stats.total_operations as u64,
-                        sync_operations: sync_stats.total_operations,
-                        latency_requests_sent: state.latency_requests_sent,
-                        latency_responses_received: state.latency_responses_received,
+                NodeCommand::SendGossip { topic, message, response } => {
+                    // Custom messages all go out on the data topic
+                    debug!(%topic, "Sending custom gossip on the data topic");
+                    let msg = GossipMessage::custom(&signing_key, node_id.clone(), message);
+                    let result = match data_sender.lock().await.as_ref() {
+                        Some(sender) => match serde_json::to_vec(&msg) {
+                            Ok(bytes) => sender.broadcast(Bytes::from(bytes)).await
+                                .map_err(|e| format!("Failed to send gossip: {}", e)),
+                            Err(e) => Err(format!("Failed to serialize Custom gossip: {}", e)),
+                        },
+                        None => Err("Networking is paused".to_string()),
                     };
-                    let _ = response.send(status);
+                    let _ = response.send(result);
                 }
-                NodeCommand::GetPeers(response) => {
-                    let peers: Vec<DiscoveredPeer> = peer_registry
-                        .read()
-                        .get_all_peers()
-                        .into_iter()
-                        .cloned()
-                        .collect();
-                    let _ = response.send(peers);
-                }
-                NodeCommand::SendGossip { topic: _, message } => {
-                    let msg = GossipMessage::Custom {
-                        from: node_id.clone(),
-                        content: message,
-                        timestamp: std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .unwrap()
-                            .as_secs(),
+                NodeCommand::SubscribeTopic { name, response } => {
+                    if let Some(topic) = app_topics.get(&name) {
+                        let _ = response.send(Ok(topic.messages.subscribe()));
+                        continue;
+                    }
+                    if name.is_empty() || name.len() > MAX_TOPIC_NAME_LEN {
+                        let _ = response.send(Err(format!("Topic name must be 1 to {} bytes", MAX_TOPIC_NAME_LEN)));
+                        continue;
+                    }
+                    let topic_id = TopicId::from_bytes(private_network::topic_bytes(
+                        &application_topic(&name, network_id.as_deref()),
+                        network_key.as_ref(),
+                    ));
+                    let mut peers = bootstrap_peers.clone();
+                    peers.extend(neighbors.ids());
+                    let sender = Arc::new(Mutex::new(None));
+                    let mut receiver = match joiner.subscribe(topic_id, &format!("app:{}", name), Subsystem::Gossip, peers, sender.clone()).await {
+                        Ok(receiver) => receiver,
+                        Err(e) => {
+                            let _ = response.send(Err(format!("Failed to subscribe to topic {}: {}", name, e)));
+                            continue;
+                        }
                     };
-                    if let Some(sender) = data_sender.lock().await.as_ref() {
-                        match serde_json::to_vec(&msg) {
-                            Ok(bytes) => { let _ = sender.broadcast(Bytes::from(bytes)).await; }
-                            Err(e) => log_warn!("Failed to serialize Custom gossip: {}", e),
+                    let (messages, subscriber) = broadcast::channel(TOPIC_CHANNEL_CAPACITY);
+                    let messages_tx = messages.clone();
+                    let topic_name = name.clone();
+                    let listener = spawn_until(&shutdown, async move {
+                        while let Some(event) = receiver.next().await {
+                            match event {
+                                Ok(GossipEvent::Received(msg)) => {
+                                    // No subscriber right now is fine, the topic stays joined
+                                    let _ = messages_tx.send(TopicMessage {
+                                        topic: topic_name.clone(),
+                                        delivered_from: msg.delivered_from.to_string(),
+                                        content: msg.content.to_vec(),
+                                    });
+                                }
+                                Ok(GossipEvent::Lagged) => log_warn!("Topic {} gossip lagged", topic_name),
+                                Ok(_) => {}
+                                Err(e) => log_error!("Topic {} gossip error: {}", topic_name, e),
+                            }
                         }
-                    }
-                }
-                NodeCommand::SendLatencyRequest { peer_id: _, response } => {
-                    // Increment latency requests sent counter
-                    shared_state.write().latency_requests_sent += 1;
-                    
-                    // Create and send latency request
-                    let mut request = LatencyRequest::new(node_id.clone(), public_key.clone());
-                    request.sign(&signing_key);
-                    
-                    let request_id = request.request_id.clone();
-                    let sent_at = request.sent_at;
-                    
-                    // Store pending request
-                    pending_latency.write().insert(request_id.clone(), PendingLatencyRequest {
-                        sent_at,
-                        callback: None,
                     });
-                    
-                    let msg = GossipMessage::LatencyRequest {
-                        request_id,
-                        from_node_id: request.from_node_id,
-                        public_key: request.public_key,
-                        sent_at: request.sent_at,
-                        signature: request.signature,
+                    log_info!("✓ Subscribed to application topic {}", name);
+                    app_topics.insert(name, AppTopic { sender, messages, listener });
+                    let _ = response.send(Ok(subscriber));
+                }
+                NodeCommand::PublishTopic { name, content, response } => {
+                    let result = match app_topics.get(&name) {
+                        Some(topic) => match topic.sender.lock().await.as_ref() {
+                            Some(sender) => sender.broadcast(Bytes::from(content)).await
+                                .map_err(|e| format!("Failed to publish to topic {}: {}", name, e)),
+                            None => Err("Networking is paused".to_string()),
+                        },
+                        None => Err(format!("Not subscribed to topic {}", name)),
                     };
-                    
-                    if let Some(sender) = data_sender.lock().await.as_ref() {
-                        match serde_json::to_vec(&msg) {
-                            Ok(bytes) => { let _ = sender.broadcast(Bytes::from(bytes)).await; }
-                            Err(e) => log_warn!("Failed to serialize LatencyRequest: {}", e),
+                    let _ = response.send(result);
+                }
+                NodeCommand::UnsubscribeTopic { name, response } => {
+                    // Dropping the sender and the listener's receiver leaves the topic
+                    let removed = app_topics.remove(&name).map(|topic| topic.listener.abort());
+                    let _ = response.send(removed.is_some());
+                }
+                NodeCommand::PauseNetworking(response) => {
+                    if gate_tx.send_if_modified(|gate| !std::mem::replace(&mut gate.paused, true)) {
+                        log_info!("Pausing networking");
+                        shared_state.write().networking_paused = true;
+                        resilience.set_paused(true);
+                        // Topic listeners leave their topics, so no NeighborDown follows
+                        neighbors.clear();
+                    }
+                    let _ = response.send(());
+                }
+                NodeCommand::SetLiteMode { lite, response } => {
+                    if gate_tx.send_if_modified(|gate| std::mem::replace(&mut gate.lite, lite) != lite) {
+                        log_info!("Lite mode {}", if lite { "on" } else { "off" });
+                        if lite {
+                            // Topic listeners leave or rejoin their topics
+                            neighbors.clear();
                         }
                     }
-                    
-                    // For simplicity, we return immediately and rely on events
-                    let _ = response.send(Err("Latency request sent, check events for response".to_string()));
+                    let _ = response.send(());
+                }
+                NodeCommand::ResumeNetworking(response) => {
+                    if gate_tx.send_if_modified(|gate| std::mem::replace(&mut gate.paused, false)) {
+                        log_info!("Resuming networking");
+                        shared_state.write().networking_paused = false;
+                        resilience.set_paused(false);
+                        // Sockets may have died or the network changed while in the background
+                        endpoint.network_change().await;
+                        let sync_sender = sync_sender.clone();
+                        let sync_manager = sync_manager.clone();
+                        let shared_state = shared_state.clone();
+                        spawn_until(&shutdown, async move {
+                            tokio::time::sleep(CATCH_UP_SYNC_DELAY).await;
+                            if let Err(e) = send_sync_request(&sync_sender, &sync_manager, &shared_state).await {
+                                log_warn!("Failed to request catch-up sync: {}", e);
+                            }
+                        });
+                    }
+                    let _ = response.send(());
+                }
+                NodeCommand::RpcCall { peer_id, method, payload, timeout, response } => {
+                    let Ok(peer_endpoint_id) = peer_id.parse::<EndpointId>() else {
+                        let _ = response.send(Err(format!("Invalid node ID {}", peer_id)));
+                        continue;
+                    };
+                    let endpoint = endpoint.clone();
+                    spawn_until(&shutdown, async move {
+                        let result = rpc::call(&endpoint, peer_endpoint_id, method, payload, timeout).await;
+                        let _ = response.send(result.map_err(|e| e.to_string()));
+                    });
                 }
-                NodeCommand::StoreData { db_name, key, value, public_key: pk, signature } => {
-                    // Store locally
-                    if let Err(e) = storage.put(&db_name, &key, &value) {
-                        error!("Failed to store data: {}", e);
+                NodeCommand::ProveOwnership { peer_id, response } => {
+                    let Ok(peer_endpoint_id) = peer_id.parse::<EndpointId>() else {
+                        let _ = response.send(Err(format!("Invalid node ID {}", peer_id)));
                         continue;
+                    };
+                    let advertised_public_key = {
+                        let registry = peer_registry.read();
+                        if let Some(binding) = registry.verified_binding(&peer_id, Utc::now().timestamp_millis()) {
+                            let _ = response.send(Ok(binding.clone()));
+                            continue;
+                        }
+                        registry.get_peer(&peer_id).map(|peer| peer.public_key.clone())
+                    };
+                    let endpoint = endpoint.clone();
+                    let registry = peer_registry.clone();
+                    let local_node_id = node_id.clone();
+                    spawn_until(&shutdown, async move {
+                        let result = auth::prove_ownership(&endpoint, &local_node_id, peer_endpoint_id, advertised_public_key.as_deref()).await;
+                        match &result {
+                            Ok(binding) => registry.write().record_verified_binding(binding.clone()),
+                            Err(e) => log_warn!("{} failed to prove ownership: {}", peer_id, e),
+                        }
+                        let _ = response.send(result.map_err(|e| e.to_string()));
+                    });
+                }
+                NodeCommand::SendLatencyRequest { peer_id, response } => {
+                    let endpoint = endpoint.clone();
+                    let shared_state = shared_state.clone();
+                    let registry = peer_registry.clone();
+                    let event_tx = event_tx.clone();
+                    spawn_until(&shutdown, async move {
+                        let result = measure_latency(&endpoint, &peer_id, &shared_state, &registry, &event_tx).await;
+                        let _ = response.send(result);
+                    });
+                }
+                NodeCommand::ConnectPeer { peer_id, address, response } => {
+                    // A ticket (see `get_node_ticket`) brings its own addresses
+                    let ticket = peer_id.parse::<NodeTicket>().ok();
+                    let peer_endpoint_id = match &ticket {
+                        Some(ticket) => ticket.node_id,
+                        None => match peer_id.parse::<EndpointId>() {
+                            Ok(id) => id,
+                            Err(_) => {
+                                let _ = response.send(Err(format!("Invalid node ID {}", peer_id)));
+                                continue;
+                            }
+                        },
+                    };
+                    if let Some(addr) = address.as_deref() {
+                        if let Err(e) = bootstrap::check_addr(addr) {
+                            let _ = response.send(Err(format!("{}, expected ip:port or host:port", e)));
+                            continue;
+                        }
                     }
-                    // Flush immediately to ensure persistence
-                    let _ = storage.flush();
-                    
-                    // Create sync operation and broadcast
-                    let value_str = String::from_utf8_lossy(&value).to_string();
-                    let op = SignedOperation::new(
-                        db_name.clone(),
-                        key.clone(),
-                        value_str,
-                        "String".to_string(),
-                        pk,
-                        signature,
-                    );
-                    
-                    // Add to sync store
-                    let _ = sync_manager.sync_store().add_operation_unverified(op.clone()).await;
-                    
-                    // Broadcast to sync topic
-                    let sync_msg = sync_manager.create_operation_message(op);
+
+                    // A dial the user asked for shouldn't wait out backoff left
+                    // by earlier automatic attempts or the cycle's connection limit
+                    resilience.clear_backoff(&peer_endpoint_id);
+                    let ticket_addr = ticket.as_ref().filter(|_| address.is_none()).map(NodeTicket::endpoint_addr);
+                    let address = address.or_else(|| ticket?.addresses.first().map(|addr| addr.to_string()));
+                    let peer_id = peer_endpoint_id.to_string();
+                    let endpoint = endpoint.clone();
+                    let resilience = resilience.clone();
+                    let registry = peer_registry.clone();
+                    spawn_until(&shutdown, async move {
+                        let started = Instant::now();
+                        let connect_res = tokio::time::timeout(MANUAL_CONNECT_TIMEOUT, async {
+                            let permit = resilience.begin_dial(peer_endpoint_id).await?;
+                            let dial_addr = match ticket_addr {
+                                Some(addr) => addr,
+                                None => bootstrap::endpoint_addr(peer_endpoint_id, address.as_deref()).await,
+                            };
+                            dial_peer(endpoint, dial_addr, &resilience, registry.clone(), permit).await
+                        })
+                        .await;
+                        let result = match connect_res {
+                            Ok(Ok(conn)) => {
+                                let handshake_ms = started.elapsed().as_millis() as u64;
+                                let path = peer_path(&conn.paths().get()).selected;
+                                registry.write().register_peer_from_list(peer_id.clone(), address, None);
+                                log_info!("✓ Connected to {} ({}, {}ms)", peer_endpoint_id.fmt_short(),
+                                    path.map(|p| p.as_str()).unwrap_or("no path yet"), handshake_ms);
+                                Ok(ConnectionResult { node_id: peer_id, path, handshake_ms })
+                            }
+                            Ok(Err(e)) => Err(format!("Failed to connect to {}: {}", peer_id, e)),
+                            Err(_) => Err(format!("Timed out connecting to {}", peer_id)),
+                        };
+                        let _ = response.send(result);
+                    });
+                }
+                NodeCommand::RequestSync { since_timestamp } => {
+                    let sync_request = sync_manager.create_sync_request(since_timestamp);
                     if let Some(sender) = sync_sender.lock().await.as_ref() {
-                        if let Ok(payload) = serde_json::to_vec(&sync_msg) {
+                        if let Ok(payload) = serde_json::to_vec(&sync_request) {
                             let _ = sender.broadcast(Bytes::from(payload)).await;
                         }
                     }
                 }
-                NodeCommand::GetData { db_name, key, response } => {
-                    let data = storage.get(&db_name, &key).ok().flatten();
-                    let _ = response.send(data);
+                NodeCommand::HealthCheck(response) => {
+                    let slots = [
+                        ("data", &data_sender),
+                        ("discovery", &discovery_sender),
+                        ("sync", &sync_sender),
+                        ("peers", &peer_discovery_sender),
+                        ("discovery-v2", &improved_discovery_sender),
+                        ("latency", &latency_sender),
+                    ];
+                    let mut topics = Vec::with_capacity(slots.len());
+                    let lite = gate_tx.borrow().lite;
+                    // Topics lite mode leaves aren't missing
+                    for (topic, sender) in slots.into_iter().filter(|(topic, _)| !lite || LITE_MODE_TOPICS.contains(topic)) {
+                        topics.push(TopicHealth {
+                            topic: topic.to_string(),
+                            subscribed: sender.lock().await.is_some(),
+                            neighbors: neighbors.count_on_topic(topic),
+                        });
+                    }
+                    let state = shared_state.read().clone();
+                    let inputs = HealthInputs {
+                        storage_error: storage.check_writable().err().map(|e| e.to_string()),
+                        last_flush_at: storage.last_flush_at(),
+                        topics,
+                        last_sync_at: sync_manager.get_stats().await.last_sync_at,
+                        last_announcement_at: state.last_announcement_at,
+                        announce_interval: config.read().announce_interval,
+                        relay: config.read().relay.clone(),
+                        relay_url: endpoint.addr().relay_urls().next().map(|url| url.to_string()),
+                        network_off: state.networking_paused.then_some("networking paused"),
+                        lite_mode: state.power_mode.is_lite(),
+                        now: Utc::now().timestamp_millis(),
+                    };
+                    let _ = response.send(HealthReport::assess(inputs));
+                }
+                command @ (NodeCommand::StoreData { .. }
+                | NodeCommand::StoreBatch { .. }
+                | NodeCommand::GetData { .. }
+                | NodeCommand::IncrementCounter { .. }
+                | NodeCommand::EditText { .. }
+                | NodeCommand::PublishOperation { .. }
+                | NodeCommand::BroadcastSync { .. }
+                | NodeCommand::Barrier(_)) => data_commands.handle(command).await,
+            }
+        }
+
+        // Every handle to the node was dropped without a stop
+        shutdown.cancel();
+        resilience.shutdown();
+    }
+
+    /// Event loop of a node without networking: data commands are handled as
+    /// online, network commands fail
+    async fn run_offline(
+        mut command_rx: mpsc::Receiver<NodeCommand>,
+        event_tx: mpsc::Sender<NodeEvent>,
+        data_commands: DataCommands,
+        session_stats: Arc<SessionStats>,
+        bandwidth: Arc<BandwidthMeter>,
+        shutdown: CancellationToken,
+    ) {
+        const OFFLINE: &str = "The node is offline";
+        let DataCommands { storage, sync_manager, .. } = &data_commands;
+
+        while let Some(cmd) = command_rx.recv().await {
+            match cmd {
+                NodeCommand::Stop(response) => {
+                    info!("Stopping offline node");
+                    if let Err(e) = session_stats.save(storage) {
+                        error!("Failed to save lifetime stats on stop: {}", e);
+                    }
+                    if let Err(e) = bandwidth.save(storage) {
+                        error!("Failed to save bandwidth usage on stop: {}", e);
+                    }
+                    if let Err(e) = storage.flush() {
+                        error!("Failed to flush storage on stop: {}", e);
+                    }
+                    shutdown.cancel();
+                    let _ = event_tx.send(NodeEvent::Stopped).await;
+                    let _ = response.send(());
+                    break;
+                }
+                NodeCommand::SubscribeTopic { response, .. } => {
+                    let _ = response.send(Err(OFFLINE.to_string()));
+                }
+                NodeCommand::PublishTopic { response, .. } => {
+                    let _ = response.send(Err(OFFLINE.to_string()));
+                }
+                NodeCommand::UnsubscribeTopic { response, .. } => {
+                    let _ = response.send(false);
+                }
+                NodeCommand::PauseNetworking(response)
+                | NodeCommand::ResumeNetworking(response)
+                | NodeCommand::SetLiteMode { response, .. } => {
+                    let _ = response.send(());
                 }
-                NodeCommand::RequestSync { since_timestamp } => {
-                    let sync_request = sync_manager.create_sync_request(since_timestamp);
-                    if let Some(sender) = sync_sender.lock().await.as_ref() {
-                        if let Ok(payload) = serde_json::to_vec(&sync_request) {
-                            let _ = sender.broadcast(Bytes::from(payload)).await;
-                        }
-                    }
+                NodeCommand::RpcCall { response, .. } => {
+                    let _ = response.send(Err(OFFLINE.to_string()));
                 }
+                NodeCommand::ProveOwnership { response, .. } => {
+                    let _ = response.send(Err(OFFLINE.to_string()));
+                }
+                NodeCommand::SendLatencyRequest { response, .. } => {
+                    let _ = response.send(Err(OFFLINE.to_string()));
+                }
+                NodeCommand::ConnectPeer { response, .. } => {
+                    let _ = response.send(Err(OFFLINE.to_string()));
+                }
+                NodeCommand::HealthCheck(response) => {
+                    let inputs = HealthInputs {
+                        storage_error: storage.check_writable().err().map(|e| e.to_string()),
+                        last_flush_at: storage.last_flush_at(),
+                        topics: Vec::new(),
+                        last_sync_at: sync_manager.get_stats().await.last_sync_at,
+                        last_announcement_at: None,
+                        announce_interval: NodeConfig::default().announce_interval,
+                        relay: RelayConfig::default(),
+                        relay_url: None,
+                        network_off: Some("offline"),
+                        lite_mode: false,
+                        now: Utc::now().timestamp_millis(),
+                    };
+                    let _ = response.send(HealthReport::assess(inputs));
+                }
+                // Peers get the writes from the outbox, and catch-up sync runs
+                // when going online
+                NodeCommand::SendGossip { response, .. } => {
+                    let _ = response.send(Err(OFFLINE.to_string()));
+                }
+                NodeCommand::RequestSync { .. } => {}
+                command => data_commands.handle(command).await,
             }
         }
+        shutdown.cancel();
     }
 
     /// Get node ID
@@ -1775,30 +4376,340 @@ impl CyberflyNode {
         &self.public_key
     }
 
+    /// Our node ID with the direct addresses and relays we're reachable at;
+    /// None while offline
+    pub fn node_addr(&self) -> Option<iroh::EndpointAddr> {
+        self.endpoint.as_ref().map(|endpoint| endpoint.addr())
+    }
+
+    /// Ticket other devices can dial us with, see `ticket`
+    pub fn node_ticket(&self) -> Option<NodeTicket> {
+        self.node_addr().map(|addr| NodeTicket::new(&addr))
+    }
+
     /// Get node status - reads from shared state, no async needed
     pub fn get_status_sync(&self) -> NodeStatus {
         let state = self.shared_state.read().clone();
-        let uptime = self.start_time.elapsed().as_secs();
+        let uptime = self.stats.uptime_seconds();
         
-        // Get peer count directly from peer_registry for consistency with get_peers_sync
+        // Discovered peers straight from peer_registry for consistency with get_peers_sync;
+        // connected peers are the gossip neighbors
         let peer_count = self.peer_registry.read().peer_count();
         
         log_info!(">>> get_status_sync: uptime={}, connected={}, discovered={}, gossip_msgs={}", 
-            uptime, peer_count, peer_count, state.gossip_messages_received);
+            uptime, state.connected_peers, peer_count, state.gossip_messages_received);
         NodeStatus {
             is_running: state.is_running,
             node_id: Some(self.node_id.clone()),
-            connected_peers: peer_count,
+            connected_peers: state.connected_peers,
             discovered_peers: peer_count,
             uptime_seconds: uptime,
             gossip_messages_received: state.gossip_messages_received,
             storage_size_bytes: self.storage.size_bytes().unwrap_or(0),
             total_keys: self.storage.key_count().unwrap_or(0) as u64,
-            total_operations: 0, // sync_stats not tracked in shared state
+            total_operations: state.total_operations as u64,
             sync_operations: state.sync_operations,
             latency_requests_sent: state.latency_requests_sent,
             latency_responses_received: state.latency_responses_received,
+            outbox_depth: self.storage.outbox_len().unwrap_or(0),
+            session: self.stats.session(),
+            lifetime: self.stats.lifetime(),
+            topics: topic_stats(&self.bandwidth, &state),
+        }
+    }
+
+    /// Configuration as started or updated, before the power policy
+    pub fn config(&self) -> NodeConfig {
+        self.base_config.read().clone()
+    }
+
+    /// Apply changed intervals and limits; startup-only settings can't change
+    pub fn update_config(&self, update: NodeConfig) -> Result<()> {
+        let mut base_config = self.base_config.write();
+        base_config.check_update(&update)?;
+        *base_config = update;
+        self.apply_config(&base_config);
+        Ok(())
+    }
+
+    /// Run with `base_config` adjusted to the current power mode and policy
+    fn apply_config(&self, base_config: &NodeConfig) {
+        let (mode, policy) = {
+            let state = self.shared_state.read();
+            (state.power_mode, state.power_policy)
+        };
+        let config = policy.apply(&mode.apply(base_config));
+        self.sync_manager.set_max_ops_per_response(config.max_ops_per_response);
+        self.sync_manager.sync_store().set_operation_limits(config.operation_limits.clone());
+        self.sync_manager.set_blob_transfers(policy.allow_blob_transfers());
+        if let Some(resilience) = &self.resilience {
+            resilience.set_max_connections_per_cycle(config.max_connection_attempts_per_cycle);
+        }
+        *self.config.write() = config;
+    }
+
+    /// Adapt to the battery and network state reported by the app (see
+    /// `power`); a full sync held back earlier is sent once the policy allows
+    pub async fn set_device_conditions(&self, conditions: DeviceConditions) -> Result<()> {
+        let policy = PowerPolicy::for_conditions(&conditions);
+        self.bandwidth.set_metered(policy.metered);
+        let send_deferred_sync = {
+            let mut state = self.shared_state.write();
+            if state.power_policy != policy {
+                log_info!("Power policy: low_power={}, metered={}", policy.low_power, policy.metered);
+            }
+            state.power_policy = policy;
+            !policy.defer_sync()
+                && !self.sync_manager.bandwidth_cap_exceeded()
+                && std::mem::take(&mut state.sync_deferred)
+        };
+        self.apply_config(&self.base_config.read());
+        if send_deferred_sync {
+            self.command_tx.send(NodeCommand::RequestSync { since_timestamp: None }).await?;
+        }
+        Ok(())
+    }
+
+    /// Catch up with the network within `budget`, e.g. after a push
+    /// notification woke the app in the background: dial pinned peers, ask
+    /// for the operations since the last wake sync, and wait until a peer
+    /// answered and the outbox is sent, or the budget is used up. Storage is
+    /// flushed before returning.
+    pub async fn wake_sync(&self, budget: Duration) -> Result<WakeSyncSummary> {
+        if self.is_offline() {
+            return Err(anyhow!("The node is offline"));
+        }
+        let started = Instant::now();
+        let deadline = tokio::time::Instant::from_std(started + budget);
+        let stats_before = self.sync_manager.get_stats().await;
+        let outbox_before = self.storage.outbox_len()?;
+
+        let pinned = self.pinned_peers()?;
+        let pinned_peers_dialed = pinned.len();
+        let dials = pinned
+            .into_iter()
+            .map(|(peer_id, address)| tokio::time::timeout_at(deadline, self.connect_peer(peer_id, address)));
+        let pinned_peers_connected = futures::future::join_all(dials)
+            .await
+            .into_iter()
+            .filter(|result| matches!(result, Ok(Ok(_))))
+            .count();
+
+        let since = self
+            .storage
+            .get_setting(LAST_WAKE_SYNC_SETTING)?
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .and_then(|at| at.parse::<i64>().ok())
+            .map(|at| at - WAKE_SYNC_OVERLAP_MS);
+        let requested_at = Utc::now().timestamp_millis();
+        let mut synced = false;
+        let mut next_request = tokio::time::Instant::now();
+        loop {
+            let now = tokio::time::Instant::now();
+            if !synced && now >= next_request {
+                self.request_sync(since).await?;
+                next_request = now + WAKE_SYNC_RETRY_INTERVAL;
+            }
+            let last_sync_at = self.sync_manager.get_stats().await.last_sync_at;
+            synced = last_sync_at.is_some_and(|at| at >= requested_at);
+            if (synced && self.storage.outbox_is_empty()?) || now + WAKE_SYNC_POLL_INTERVAL >= deadline {
+                break;
+            }
+            tokio::time::sleep(WAKE_SYNC_POLL_INTERVAL).await;
+        }
+        if synced {
+            self.storage.put_setting(LAST_WAKE_SYNC_SETTING, requested_at.to_string().as_bytes())?;
+        }
+        self.storage.flush()?;
+
+        let stats = self.sync_manager.get_stats().await;
+        let outbox_remaining = self.storage.outbox_len()?;
+        let summary = WakeSyncSummary {
+            pinned_peers_dialed,
+            pinned_peers_connected,
+            synced,
+            ops_received: stats.ops_received.saturating_sub(stats_before.ops_received),
+            ops_sent: stats.ops_sent.saturating_sub(stats_before.ops_sent),
+            outbox_sent: outbox_before.saturating_sub(outbox_remaining),
+            outbox_remaining,
+            duration_ms: started.elapsed().as_millis() as u64,
+        };
+        log_info!("Wake sync: {:?}", summary);
+        Ok(summary)
+    }
+
+    /// Wait until the writes to `db_name` made before this call were
+    /// broadcast and at least one peer confirmed holding them, for at most
+    /// `timeout`, so the app can show "saved & synced" only once it's true
+    pub async fn wait_for_sync(&self, db_name: &str, timeout: Duration) -> Result<SyncWaitSummary> {
+        if self.is_offline() {
+            return Err(anyhow!("The node is offline"));
+        }
+        let started = Instant::now();
+        let deadline = tokio::time::Instant::from_std(started + timeout);
+        // Writes are added to the oplog by the command loop, in order
+        let (response, handled) = oneshot::channel();
+        self.command_tx.send(NodeCommand::Barrier(response)).await?;
+        let _ = tokio::time::timeout_at(deadline, handled).await;
+
+        let store = self.sync_manager.sync_store();
+        loop {
+            let queued_ops = self.storage.outbox_queued(db_name);
+            let unacknowledged_ops = store.unacknowledged(db_name);
+            let synced = queued_ops == 0 && unacknowledged_ops == 0;
+            if synced || tokio::time::Instant::now() + SYNC_WAIT_POLL_INTERVAL >= deadline {
+                return Ok(SyncWaitSummary {
+                    synced,
+                    queued_ops,
+                    unacknowledged_ops,
+                    duration_ms: started.elapsed().as_millis() as u64,
+                });
+            }
+            tokio::time::sleep(SYNC_WAIT_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Switch between normal and lite mode (see `power`), e.g. when the app
+    /// moves the node into a foreground service for the night
+    pub async fn set_power_mode(&self, mode: PowerMode) -> Result<()> {
+        self.shared_state.write().power_mode = mode;
+        self.apply_config(&self.base_config.read());
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::SetLiteMode { lite: mode.is_lite(), response: tx }).await?;
+        Ok(rx.await?)
+    }
+
+    pub fn power_mode(&self) -> PowerMode {
+        self.shared_state.read().power_mode
+    }
+
+    /// Policy from the last reported device conditions, and whether a full
+    /// sync is waiting for it to allow syncing
+    pub fn power_policy(&self) -> (PowerPolicy, bool) {
+        let state = self.shared_state.read();
+        (state.power_policy, state.sync_deferred)
+    }
+
+    /// Connectivity as of the last isolation check
+    pub fn connectivity(&self) -> Connectivity {
+        self.shared_state.read().connectivity.clone()
+    }
+
+    /// Our addresses, relay, NAT guess, and each peer's dials and paths
+    pub fn connection_diagnostics(&self) -> ConnectionDiagnostics {
+        let addr = self.endpoint.as_ref().map(|endpoint| endpoint.addr());
+        let relay_url = addr.as_ref().and_then(|addr| addr.relay_urls().next().map(|url| url.to_string()));
+        let registry = self.peer_registry.read();
+        ConnectionDiagnostics::collect(
+            addr.map(|addr| addr.ip_addrs().copied().collect()).unwrap_or_default(),
+            relay_url,
+            registry.connection_paths(),
+            registry.dial_records(),
+        )
+    }
+
+    /// Relay in use and its round trip time, pinging it now
+    pub async fn relay_status(&self) -> RelayStatus {
+        let active_url = self
+            .endpoint
+            .as_ref()
+            .and_then(|endpoint| endpoint.addr().relay_urls().next().map(|url| url.to_string()));
+        let rtt = match &active_url {
+            Some(url) => match ping_relay(url).await {
+                Ok(rtt) => Some(rtt),
+                Err(e) => {
+                    log_warn!("Failed to ping relay {}: {}", url, e);
+                    None
+                }
+            },
+            None => None,
+        };
+        RelayStatus { relay: self.base_config.read().relay.clone(), active_url, rtt }
+    }
+
+    /// Traffic per topic and subsystem this session, and usage of the data caps
+    pub fn bandwidth_stats(&self) -> BandwidthStats {
+        self.bandwidth.stats()
+    }
+
+    /// Counters and histograms for in-app dashboards
+    pub fn metrics(&self) -> MetricsSnapshot {
+        collect_metrics(&self.storage, &self.sync_manager, &self.bandwidth, self.resilience.as_deref())
+    }
+
+    /// Serve `metrics` in the Prometheus text format at
+    /// `http://<addr>/metrics` until the node stops. Returns the address
+    /// bound, e.g. the port picked for port 0.
+    pub async fn serve_metrics(&self, addr: SocketAddr) -> Result<SocketAddr> {
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let storage = self.storage.clone();
+        let sync_manager = self.sync_manager.clone();
+        let bandwidth = self.bandwidth.clone();
+        let resilience = self.resilience.clone();
+        spawn_until(
+            &self.shutdown,
+            metrics::serve_prometheus(listener, move || {
+                collect_metrics(&storage, &sync_manager, &bandwidth, resilience.as_deref()).to_prometheus()
+            }),
+        );
+        info!("Serving metrics at http://{}/metrics", local_addr);
+        Ok(local_addr)
+    }
+
+    /// Serve the debug API (see `debug_api`) on the loopback
+    /// address `addr` until the node stops. `logs(limit)` gives the most
+    /// recent log entries; GraphQL mutations are served only if
+    /// `allow_writes`. Returns the address bound and the bearer token
+    /// requests must carry.
+    pub async fn serve_debug_api<L>(
+        self: &Arc<Self>,
+        addr: SocketAddr,
+        allow_writes: bool,
+        logs: L,
+    ) -> Result<(SocketAddr, String)>
+    where
+        L: Fn(usize) -> serde_json::Value + Send + Sync + 'static,
+    {
+        if !addr.ip().is_loopback() {
+            return Err(anyhow!("The debug API only binds to loopback addresses, not {}", addr.ip()));
         }
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        // The server must not keep a stopped node alive
+        let node = Arc::downgrade(self);
+        let logs = Arc::new(logs);
+        let token = debug_api::generate_token();
+        let server_token = Arc::new(token.clone());
+        spawn_until(
+            &self.shutdown,
+            http::serve(listener, move |request| {
+                let node = node.upgrade();
+                let logs = logs.clone();
+                let token = server_token.clone();
+                async move {
+                    match node {
+                        Some(node) => debug_api::route(node, &token, allow_writes, logs.as_ref(), request).await,
+                        None => HttpResponse::error("503 Service Unavailable", "node stopped".to_string()),
+                    }
+                }
+            }),
+        );
+        info!("Serving the debug API at http://{}/", local_addr);
+        Ok((local_addr, token))
+    }
+
+    /// Set the daily and monthly data caps; sync stops while one is used up
+    pub fn set_bandwidth_caps(&self, caps: BandwidthCaps) -> Result<()> {
+        self.bandwidth.set_caps(&self.storage, caps)
+    }
+
+    /// Status of storage, gossip, sync, discovery and the relay, probing
+    /// that storage takes writes
+    pub async fn health_check(&self) -> Result<HealthReport> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::HealthCheck(tx)).await?;
+        Ok(rx.await?)
     }
 
     /// Get node status (async - kept for compatibility, now uses sync version)
@@ -1806,6 +4717,11 @@ impl CyberflyNode {
         Ok(self.get_status_sync())
     }
 
+    /// Get sync statistics (counters, last sync time, per-peer status)
+    pub async fn get_sync_stats(&self) -> SyncStats {
+        self.sync_manager.get_stats().await
+    }
+
     /// Get discovered peers - reads from shared state
     pub fn get_peers_sync(&self) -> Vec<DiscoveredPeer> {
         self.peer_registry
@@ -1816,6 +4732,61 @@ impl CyberflyNode {
             .collect()
     }
 
+    /// How each peer we hold a connection to is currently reached
+    pub fn get_peer_paths(&self) -> Vec<(String, PeerPath)> {
+        self.peer_registry.read().connection_paths()
+    }
+
+    /// Circuit breakers of peers that failed to connect since their last
+    /// success; open ones are unreachable for now
+    pub fn get_peer_circuits(&self) -> Vec<(String, PeerCircuit)> {
+        self.resilience
+            .as_ref()
+            .map(|resilience| {
+                resilience
+                    .circuits()
+                    .into_iter()
+                    .map(|(peer_id, circuit)| (peer_id.to_string(), circuit))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Pin a peer so the node always keeps it connected, optionally at a
+    /// known `ip:port`
+    pub fn pin_peer(&self, peer_id: &str, address: Option<String>) -> Result<()> {
+        peer_id.parse::<EndpointId>().map_err(|_| anyhow!("Invalid node ID {}", peer_id))?;
+        if let Some(addr) = address.as_deref() {
+            addr.parse::<std::net::SocketAddr>()
+                .map_err(|_| anyhow!("Invalid address {}, expected ip:port", addr))?;
+        }
+        self.storage.pin_peer(peer_id, address.as_deref())?;
+        self.peer_registry.write().pin_peer(peer_id.to_string(), address);
+        Ok(())
+    }
+
+    pub fn unpin_peer(&self, peer_id: &str) -> Result<()> {
+        self.storage.unpin_peer(peer_id)?;
+        self.peer_registry.write().unpin_peer(peer_id);
+        Ok(())
+    }
+
+    /// Pinned peers as (node_id, address)
+    pub fn pinned_peers(&self) -> Result<Vec<(String, Option<String>)>> {
+        self.storage.pinned_peers()
+    }
+
+    /// Measured active peers, lowest average latency first
+    pub fn get_ranked_peers(&self) -> Vec<DiscoveredPeer> {
+        self.peer_registry.read().ranked_peers().into_iter().cloned().collect()
+    }
+
+    /// Active peers matching `filter`, lowest latency first
+    pub fn get_peers_filtered(&self, filter: &PeerFilter) -> Result<Vec<DiscoveredPeer>> {
+        let registry = self.peer_registry.read();
+        Ok(registry.get_peers_filtered(filter)?.into_iter().cloned().collect())
+    }
+
     /// Get discovered peers (async - kept for compatibility)
     pub async fn get_peers(&self) -> Result<Vec<DiscoveredPeer>> {
         Ok(self.get_peers_sync())
@@ -1823,11 +4794,202 @@ impl CyberflyNode {
 
     /// Send gossip message
     pub async fn send_gossip(&self, topic: String, message: String) -> Result<()> {
-        self.command_tx.send(NodeCommand::SendGossip { topic, message }).await?;
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::SendGossip { topic, message, response: tx }).await?;
+        rx.await?.map_err(|e| anyhow!(e))
+    }
+
+    /// Join the application topic `name`, or get another receiver if it's
+    /// joined already. The topic stays joined until `unsubscribe_topic`.
+    pub async fn subscribe_topic(&self, name: String) -> Result<broadcast::Receiver<TopicMessage>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::SubscribeTopic { name, response: tx }).await
+            .map_err(|e| e.to_string())?;
+        rx.await.map_err(|e| e.to_string())?
+    }
+
+    /// Broadcast `content` on a joined application topic. Gossip delivers
+    /// identical payloads once, so messages should carry their own ID or
+    /// timestamp if they may repeat.
+    pub async fn publish_topic(&self, name: String, content: Vec<u8>) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::PublishTopic { name, content, response: tx }).await
+            .map_err(|e| e.to_string())?;
+        rx.await.map_err(|e| e.to_string())?
+    }
+
+    /// Leave an application topic, ending its subscribers' streams; false if
+    /// it wasn't joined
+    pub async fn unsubscribe_topic(&self, name: String) -> Result<bool> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::UnsubscribeTopic { name, response: tx }).await?;
+        Ok(rx.await?)
+    }
+
+    /// Leave every gossip topic and stop dialing peers, for when the app is
+    /// backgrounded; storage stays open and local reads and writes still work
+    pub async fn pause_networking(&self) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::PauseNetworking(tx)).await?;
+        Ok(rx.await?)
+    }
+
+    /// Rejoin the gossip topics and catch up on operations missed while paused
+    pub async fn resume_networking(&self) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::ResumeNetworking(tx)).await?;
+        Ok(rx.await?)
+    }
+
+    pub fn is_networking_paused(&self) -> bool {
+        self.shared_state.read().networking_paused
+    }
+
+    /// Call `method` on a peer and wait up to `timeout` for its response
+    /// (see `rpc`)
+    pub async fn rpc_call(&self, peer_id: String, method: String, payload: Vec<u8>, timeout: Duration) -> Result<Vec<u8>, String> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::RpcCall { peer_id, method, payload, timeout, response: tx }).await
+            .map_err(|e| e.to_string())?;
+        rx.await.map_err(|e| e.to_string())?
+    }
+
+    /// Receive peers' requests for non-built-in RPC methods, replacing the
+    /// previous receiver. Answer each with `respond_rpc`.
+    pub fn serve_rpc(&self) -> mpsc::UnboundedReceiver<IncomingRpc> {
+        self.rpc.set_handler()
+    }
+
+    /// Answer an RPC request; false if it's unknown or timed out
+    pub fn respond_rpc(&self, request_id: u64, result: Result<Vec<u8>, String>) -> bool {
+        self.rpc.respond(request_id, result)
+    }
+
+    /// Last known presence of a peer (see `presence`)
+    pub fn get_presence(&self, peer_id: &str) -> Option<Presence> {
+        self.presence.read().get(peer_id)
+    }
+
+    /// Set the status string of our presence heartbeats, kept across restarts
+    pub fn set_presence_status(&self, status: Option<String>) -> Result<()> {
+        self.presence.write().set_local_status(status.clone())?;
+        self.storage.put_setting(PRESENCE_STATUS_SETTING, status.unwrap_or_default().as_bytes())
+    }
+
+    /// Send an end-to-end encrypted message to a peer (see `dm`)
+    pub async fn send_dm(&self, peer_id: &str, text: String) -> Result<DirectMessage> {
+        self.dm.send(peer_id, text).await
+    }
+
+    /// Direct messages received from now on
+    pub fn subscribe_dms(&self) -> broadcast::Receiver<DirectMessage> {
+        self.dm.subscribe()
+    }
+
+    /// The last `limit` direct messages exchanged with a peer, oldest first
+    pub fn dm_history(&self, peer_id: &str, limit: usize) -> Result<Vec<DirectMessage>> {
+        self.dm.history(peer_id, limit)
+    }
+
+    /// Offer the file at `path` to a peer (see `files`)
+    pub async fn send_file(&self, peer_id: &str, path: &Path) -> Result<FileOffer> {
+        self.files.send(peer_id, path).await
+    }
+
+    /// Download an offered file to `dest_path`, resuming what an earlier
+    /// attempt fetched; returns its size
+    pub async fn accept_file(&self, offer_id: &str, dest_path: &Path) -> Result<u64> {
+        self.files.accept(offer_id, dest_path).await
+    }
+
+    pub fn decline_file(&self, offer_id: &str) -> Result<()> {
+        self.files.decline(offer_id)
+    }
+
+    /// File offers not accepted or declined yet, oldest first
+    pub fn file_offers(&self) -> Result<Vec<FileOffer>> {
+        self.files.offers()
+    }
+
+    /// File offers and transfer progress from now on
+    pub fn subscribe_files(&self) -> broadcast::Receiver<FileEvent> {
+        self.files.subscribe()
+    }
+
+    /// QR payload asking the primary device to link this one (see `devices`)
+    pub fn device_link_request(&self, device_name: &str) -> Result<String> {
+        LinkRequest::new(&self.signing_key, device_name)?.to_payload()
+    }
+
+    /// Link the device that showed `payload` to `user_key`. The certificate
+    /// goes out with the next announcement, and this node starts replicating
+    /// the user's databases too.
+    pub fn link_device(&self, payload: &str, user_key: &SigningKey) -> Result<DeviceCertificate> {
+        let certificate = DeviceCertificate::issue(user_key, LinkRequest::from_payload(payload)?)?;
+        devices::keep_certificate(&self.storage, &certificate)?;
+        self.sync_manager.sync_store().follow_owner(&certificate.user_public_key);
+        Ok(certificate)
+    }
+
+    /// Device links known to this node, oldest first
+    pub fn linked_devices(&self) -> Result<Vec<DeviceCertificate>> {
+        devices::certificates(&self.storage)
+    }
+
+    /// Bridge MQTT topics of a local broker to databases (see `mqtt`),
+    /// replacing the running bridge. Inbound publishes are signed with the
+    /// node key, so the mapped databases must be ones it may write to.
+    pub async fn start_mqtt_bridge(&self, config: MqttBridgeConfig) -> Result<()> {
+        self.stop_mqtt_bridge().await;
+        let (bridge, mut ops) = MqttBridge::start(
+            config,
+            self.signing_key.clone(),
+            self.storage.subscribe(),
+            self.shutdown.child_token(),
+        )?;
+        let command_tx = self.command_tx.clone();
+        spawn_until(&self.shutdown, async move {
+            while let Some(op) = ops.recv().await {
+                if command_tx.send(NodeCommand::PublishOperation { op }).await.is_err() {
+                    break;
+                }
+            }
+        });
+        *self.mqtt.lock() = Some(bridge);
+        self.shared_state.write().mqtt_bridge = true;
         Ok(())
     }
 
-    /// Send latency request
+    /// Stop the MQTT bridge; false if none was running
+    pub async fn stop_mqtt_bridge(&self) -> bool {
+        let Some(bridge) = self.mqtt.lock().take() else {
+            return false;
+        };
+        bridge.stop().await;
+        self.shared_state.write().mqtt_bridge = false;
+        true
+    }
+
+    /// Configuration of the running MQTT bridge
+    pub fn mqtt_bridge_config(&self) -> Option<MqttBridgeConfig> {
+        self.mqtt.lock().as_ref().map(|bridge| bridge.config().clone())
+    }
+
+    /// Challenge a peer to prove it controls its node ID and the public key it
+    /// advertises; a recent proof is reused (see `auth`)
+    pub async fn prove_ownership(&self, peer_id: String) -> Result<VerifiedBinding, String> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::ProveOwnership { peer_id, response: tx }).await
+            .map_err(|e| e.to_string())?;
+        rx.await.map_err(|e| e.to_string())?
+    }
+
+    /// Whether `peer_id` recently proved it holds `public_key`
+    pub fn is_verified_peer_key(&self, peer_id: &str, public_key: &str) -> bool {
+        self.peer_registry.read().is_verified_key(peer_id, public_key, Utc::now().timestamp_millis())
+    }
+
+    /// Measure one-way latency to a peer (half a direct ping round trip)
     pub async fn send_latency_request(&self, peer_id: String) -> Result<u64, String> {
         let (tx, rx) = oneshot::channel();
         self.command_tx.send(NodeCommand::SendLatencyRequest { peer_id, response: tx }).await
@@ -1835,6 +4997,15 @@ impl CyberflyNode {
         rx.await.map_err(|e| e.to_string())?
     }
 
+    /// Dial a peer, optionally at a known `ip:port` or `host:port`, e.g. from a
+    /// pairing QR code
+    pub async fn connect_peer(&self, peer_id: String, address: Option<String>) -> Result<ConnectionResult, String> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::ConnectPeer { peer_id, address, response: tx }).await
+            .map_err(|e| e.to_string())?;
+        rx.await.map_err(|e| e.to_string())?
+    }
+
     /// Store data with signature
     pub async fn store_data(
         &self,
@@ -1844,17 +5015,274 @@ impl CyberflyNode {
         public_key: String,
         signature: String,
     ) -> Result<()> {
+        // Peers would reject the operation
+        if public_key.is_empty() || signature.is_empty() {
+            return Err(anyhow!("Unsigned data can only be stored with store_data_local"));
+        }
         self.command_tx.send(NodeCommand::StoreData { 
             db_name, key, value, public_key, signature 
         }).await?;
         Ok(())
     }
 
-    /// Get data
+    /// Store data on this device only: no oplog entry, nothing is sent
+    pub fn store_data_local(&self, db_name: &str, key: &str, value: &[u8]) -> Result<()> {
+        self.storage.put(db_name, key, value)?;
+        self.storage.flush()
+    }
+
+    /// Keep a database on this device only, or sync its later writes again
+    pub async fn set_local_only(&self, db_name: &str, local_only: bool) -> Result<()> {
+        self.sync_manager.sync_store().set_local_only(db_name, local_only).await
+    }
+
+    /// Whether a database is kept on this device only
+    pub fn is_local_only(&self, db_name: &str) -> bool {
+        self.sync_manager.sync_store().is_local_only(db_name)
+    }
+
+    /// Store data signed with this node's key, so callers don't sign
+    /// themselves. The database must be named after the node's key or list
+    /// it as a writer. Returns the op_id.
+    pub async fn store_data_signed(&self, db_name: &str, key: &str, value: &[u8], store_type: &str) -> Result<String> {
+        // Counters, texts and blobs have their own value formats
+        if matches!(store_type.parse()?, StoreType::Counter | StoreType::Text | StoreType::Blob) {
+            return Err(anyhow!("Can't store a {} value with store_data_signed", store_type));
+        }
+        let owner = acl::db_owner(db_name)
+            .ok_or_else(|| anyhow!("{} is not named <name>-<owner public key>", db_name))?;
+        if !self.sync_manager.sync_store().acl().is_authorized(db_name, &self.public_key) {
+            return Err(anyhow!("{} belongs to {}, which hasn't made this node ({}) a writer", db_name, owner, self.public_key));
+        }
+
+        let op = SignedOperation::create_and_sign(
+            db_name.to_string(),
+            key.to_string(),
+            String::from_utf8_lossy(value).to_string(),
+            store_type.to_string(),
+            &self.signing_key,
+        );
+        // Store types that need a field can't be written this way
+        op.storage_key()?;
+        let op_id = op.op_id.clone();
+        self.command_tx.send(NodeCommand::PublishOperation { op }).await?;
+        Ok(op_id)
+    }
+
+    /// Store and publish an operation signed elsewhere, e.g. by a GraphQL
+    /// client, once its signature and signer check out
+    pub async fn submit_operation(&self, op: SignedOperation) -> Result<()> {
+        if !op.verify()? {
+            return Err(anyhow!("Invalid signature"));
+        }
+        crypto::verify_db_name_secure(&op.db_name, &op.public_key)?;
+        if !self.sync_manager.sync_store().acl().is_authorized(&op.db_name, &op.public_key) {
+            return Err(anyhow!("{} is not a writer of {}", op.public_key, op.db_name));
+        }
+        self.command_tx.send(NodeCommand::PublishOperation { op }).await?;
+        Ok(())
+    }
+
+    /// Sign and store a value that is removed on every peer after `ttl_secs`
+    pub async fn store_data_with_ttl(
+        &self,
+        db_name: &str,
+        key: &str,
+        value: &[u8],
+        ttl_secs: u64,
+        signing_key: &SigningKey,
+    ) -> Result<()> {
+        if ttl_secs == 0 {
+            return Err(anyhow!("TTL must be at least one second"));
+        }
+        let expires_at = Utc::now().timestamp_millis() + (ttl_secs as i64) * 1000;
+
+        let op = SignedOperation::create_and_sign_with_expiry(
+            db_name.to_string(),
+            key.to_string(),
+            String::from_utf8_lossy(value).to_string(),
+            "String".to_string(),
+            expires_at,
+            signing_key,
+        );
+        self.command_tx.send(NodeCommand::PublishOperation { op }).await?;
+        Ok(())
+    }
+
+    /// Sign and store a value under a content-derived op_id (see
+    /// `sync::content_op_id`). Retrying with the same `timestamp` (Unix ms)
+    /// re-publishes the same operation, which every replica applies once.
+    /// Returns the op_id.
+    pub async fn store_data_idempotent(
+        &self,
+        db_name: &str,
+        key: &str,
+        value: &[u8],
+        timestamp: i64,
+        signing_key: &SigningKey,
+    ) -> Result<String> {
+        // A write from the future would win every later one
+        if timestamp > Utc::now().timestamp_millis() + (crypto::MIN_TIMESTAMP_TOLERANCE as i64) * 1000 {
+            return Err(anyhow!(crypto::TIMESTAMP_TOO_FUTURE));
+        }
+        let op = SignedOperation::create_and_sign_idempotent(
+            db_name.to_string(),
+            key.to_string(),
+            String::from_utf8_lossy(value).to_string(),
+            "String".to_string(),
+            timestamp,
+            signing_key,
+        );
+        let op_id = op.op_id.clone();
+        self.command_tx.send(NodeCommand::PublishOperation { op }).await?;
+        Ok(op_id)
+    }
+
+    /// Add `delta` (may be negative) to a replicated PN-counter and return
+    /// its new value. Increments from different devices add up instead of
+    /// overwriting each other.
+    pub async fn incr(&self, db_name: &str, key: &str, delta: i64, signing_key: &SigningKey) -> Result<i64> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx
+            .send(NodeCommand::IncrementCounter {
+                db_name: db_name.to_string(),
+                key: key.to_string(),
+                delta,
+                signing_key: signing_key.clone(),
+                response: tx,
+            })
+            .await?;
+        rx.await?.map_err(|e| anyhow!(e))
+    }
+
+    /// Current value of a PN-counter (0 if it was never incremented)
+    pub fn get_counter(&self, db_name: &str, key: &str) -> Result<i64> {
+        let Some(value) = self.storage.get(db_name, key)? else {
+            return Ok(0);
+        };
+        String::from_utf8(value)?
+            .parse()
+            .map_err(|_| anyhow!("{} in {} is not a counter", key, db_name))
+    }
+
+    /// Edit a replicated collaborative text (see `text`): replace `delete`
+    /// characters at `index` with `insert`, and return the new text.
+    /// Concurrent edits on different devices merge instead of overwriting
+    /// each other.
+    pub async fn edit_text(
+        &self,
+        db_name: &str,
+        key: &str,
+        index: u64,
+        delete: u64,
+        insert: &str,
+        signing_key: &SigningKey,
+    ) -> Result<String> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx
+            .send(NodeCommand::EditText {
+                db_name: db_name.to_string(),
+                key: key.to_string(),
+                index,
+                delete,
+                insert: insert.to_string(),
+                signing_key: signing_key.clone(),
+                response: tx,
+            })
+            .await?;
+        rx.await?.map_err(|e| anyhow!(e))
+    }
+
+    /// Current value of a collaborative text (empty if it was never edited)
+    pub fn get_text(&self, db_name: &str, key: &str) -> Result<String> {
+        Ok(self.storage.get(db_name, key)?.map(|value| String::from_utf8_lossy(&value).into_owned()).unwrap_or_default())
+    }
+
+    /// Changes of collaborative texts from now on, local edits included
+    pub fn subscribe_text_deltas(&self) -> broadcast::Receiver<TextDelta> {
+        self.sync_manager.sync_store().subscribe_text_deltas()
+    }
+
+    /// Sign and store a write of a typed store (see `typed`)
+    pub async fn put_typed(&self, db_name: &str, key: &str, write: TypedWrite, signing_key: &SigningKey) -> Result<()> {
+        let op = write.sign(db_name, key, signing_key)?;
+        self.command_tx.send(NodeCommand::PublishOperation { op }).await?;
+        Ok(())
+    }
+
+    /// Elements of a hash, list, set, sorted set, time series or geo set as
+    /// (field, value), in field order
+    pub fn typed_collection(&self, db_name: &str, key: &str) -> Result<Vec<(String, Vec<u8>)>> {
+        typed::collection(&self.storage, db_name, key)
+    }
+
+    /// Atomically replace a local value if it equals `expected` (`None` =
+    /// absent); not replicated. Returns whether the swap happened.
+    pub fn compare_and_swap(
+        &self,
+        db_name: &str,
+        key: &str,
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<bool> {
+        self.storage.compare_and_swap(db_name, key, expected, new)
+    }
+
+    /// Store many signed entries with a single storage flush; returns how
+    /// many were stored
+    pub async fn store_batch(&self, entries: Vec<crate::api::BatchEntryDto>) -> Result<usize> {
+        let (tx, rx) = oneshot::channel();
+        self.command_tx.send(NodeCommand::StoreBatch { entries, response: tx }).await?;
+        rx.await?.map_err(|e| anyhow!(e))
+    }
+
+    /// Get data; offloaded values are read from the blob store, or fetched
+    /// from peers if they aren't here
     pub async fn get_data(&self, db_name: String, key: String) -> Result<Option<Vec<u8>>> {
+        let crdt_key = format!("{}:{}", db_name, key);
         let (tx, rx) = oneshot::channel();
         self.command_tx.send(NodeCommand::GetData { db_name, key, response: tx }).await?;
-        Ok(rx.await?)
+        let Some(value) = rx.await? else {
+            return Ok(None);
+        };
+        let store = self.sync_manager.sync_store();
+        if !store.get_operation(&crdt_key).await.is_some_and(|op| op.is_blob()) {
+            return Ok(Some(value));
+        }
+        let blob_values = store.blob_values().ok_or_else(|| anyhow!("Reading a large value needs the node online"))?;
+        let blob = BlobRef::decode(&String::from_utf8_lossy(&value))?;
+        let peers: Vec<EndpointId> = self
+            .get_peer_paths()
+            .into_iter()
+            .filter_map(|(peer_id, _)| peer_id.parse().ok())
+            .collect();
+        Ok(Some(blob_values.get(&blob, &peers).await?))
+    }
+
+    /// Get several values of one database in a single storage pass, in the
+    /// order of `keys`
+    pub fn get_many(&self, db_name: &str, keys: &[String]) -> Result<Vec<Option<Vec<u8>>>> {
+        self.storage.get_many(db_name, keys)
+    }
+
+    /// Full-text search of a database's values (see `search`)
+    pub fn search(&self, db_name: &str, query: &str, limit: usize) -> Result<Vec<(String, Vec<u8>)>> {
+        self.storage.search(db_name, query, limit)
+    }
+
+    /// Logged changes after `since_seq` (see `Storage::changes_since`)
+    pub fn changes_since(&self, db_name: Option<&str>, since_seq: u64, limit: usize) -> Result<Vec<StorageChange>> {
+        self.storage.changes_since(db_name, since_seq, limit)
+    }
+
+    /// Run a structured query against a database (see `query`)
+    pub fn query(&self, db_name: &str, spec: &QuerySpec) -> Result<QueryResult> {
+        query::run(&self.storage, db_name, spec)
+    }
+
+    /// Subscribe to data changes from local writes and remote sync
+    pub fn watch_changes(&self) -> broadcast::Receiver<StorageChange> {
+        self.storage.subscribe()
     }
 
     /// Request sync from peers
@@ -1863,19 +5291,81 @@ impl CyberflyNode {
         Ok(())
     }
 
-    /// Take event receiver (can only be called once)
-    pub fn take_event_receiver(&self) -> Option<mpsc::Receiver<NodeEvent>> {
-        self.event_rx.write().take()
+    /// Kept events after `since_seq` (0 for all), oldest first
+    pub fn recent_events(&self, since_seq: u64) -> Vec<RecordedEvent> {
+        self.events.since(since_seq)
+    }
+
+    /// Kept events after `since_seq`, and a receiver of the events after
+    /// those; any number of subscribers can replay
+    pub fn subscribe_events(&self, since_seq: u64) -> (Vec<RecordedEvent>, broadcast::Receiver<RecordedEvent>) {
+        self.events.subscribe(since_seq)
     }
 
-    /// Stop the node
+    /// Stop the node. Stopping an already stopped node does nothing
     pub async fn stop(&self) -> Result<()> {
+        if self.shutdown.is_cancelled() {
+            return Ok(());
+        }
         let (tx, rx) = oneshot::channel();
-        self.command_tx.send(NodeCommand::Stop(tx)).await?;
-        rx.await?;
+        if self.command_tx.send(NodeCommand::Stop(tx)).await.is_err() {
+            // The event loop is gone already
+            return Ok(());
+        }
+        let _ = rx.await;
         Ok(())
     }
 
+    /// Save what's only kept in memory and flush storage, within `budget`.
+    /// For when the app is paused: Android may kill the process afterwards
+    /// without a `stop`, losing up to `flush_every_ms` of writes. A flush
+    /// still running when the budget runs out finishes in the background.
+    pub async fn checkpoint(&self, budget: Duration) -> Result<CheckpointSummary> {
+        let started = Instant::now();
+        let deadline = tokio::time::Instant::now() + budget;
+        let mut summary = CheckpointSummary::default();
+
+        match tokio::time::timeout_at(deadline, self.sync_manager.sync_store().save_high_water_mark()).await {
+            Ok(Ok(mark)) => summary.high_water_mark = mark,
+            Ok(Err(e)) => log_warn!("Failed to save the oplog high-water mark: {}", e),
+            Err(_) => log_warn!("No time left to save the oplog high-water mark"),
+        }
+        match save_known_peers(&self.storage, &self.peer_registry) {
+            Ok(saved) => summary.known_peers_saved = saved,
+            Err(e) => log_warn!("Failed to save known peers: {}", e),
+        }
+        if let Err(e) = self.stats.save(&self.storage) {
+            log_warn!("Failed to save lifetime stats: {}", e);
+        }
+        if let Err(e) = self.bandwidth.save(&self.storage) {
+            log_warn!("Failed to save bandwidth usage: {}", e);
+        }
+        summary.outbox_depth = self.storage.outbox_len().unwrap_or(0);
+
+        let storage = self.storage.clone();
+        match tokio::time::timeout_at(deadline, tokio::task::spawn_blocking(move || storage.flush())).await {
+            Ok(flushed) => {
+                flushed??;
+                summary.flushed = true;
+            }
+            Err(_) => log_warn!("Storage flush didn't finish within {:?}", budget),
+        }
+        summary.duration_ms = started.elapsed().as_millis() as u64;
+        info!(
+            flushed = summary.flushed,
+            known_peers = summary.known_peers_saved,
+            outbox = summary.outbox_depth,
+            duration_ms = summary.duration_ms,
+            "Checkpoint done"
+        );
+        Ok(summary)
+    }
+
+    /// Cancelled once the node stops
+    pub fn shutdown_token(&self) -> CancellationToken {
+        self.shutdown.clone()
+    }
+
     /// List all databases
     pub fn list_databases(&self) -> Result<Vec<String>> {
         self.storage.list_databases()
@@ -1886,6 +5376,33 @@ impl CyberflyNode {
         self.storage.list_keys(db_name)
     }
 
+    /// Page through entries whose key starts with `prefix`
+    pub fn scan_prefix(&self, db_name: &str, prefix: &str, limit: usize, cursor: Option<&str>) -> Result<ScanPage> {
+        self.storage.scan_prefix(db_name, prefix, limit, cursor)
+    }
+
+    /// Page through entries with `start <= key < end`
+    pub fn scan_range(
+        &self,
+        db_name: &str,
+        start: Option<&str>,
+        end: Option<&str>,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<ScanPage> {
+        self.storage.range(db_name, start, end, limit, cursor)
+    }
+
+    /// Page through all entries of a database
+    pub fn get_entries_page(&self, db_name: &str, cursor: Option<&str>, options: ScanOptions) -> Result<ScanPage> {
+        self.storage.entries_page(db_name, cursor, options)
+    }
+
+    /// Page through the entries of every database
+    pub fn get_all_data_page(&self, cursor: Option<&str>, options: ScanOptions) -> Result<DataPage> {
+        self.storage.all_entries_page(cursor, options)
+    }
+
     /// Get all entries from a database
     pub async fn get_all_entries(&self, db_name: &str) -> Result<Vec<crate::api::DbEntryDto>> {
         let keys = self.storage.list_keys(db_name)?;
@@ -1923,16 +5440,238 @@ impl CyberflyNode {
     pub async fn delete_data(&self, db_name: &str, key: &str) -> Result<()> {
         self.storage.delete(db_name, key)
     }
+
+    /// Create the symmetric key of an end-to-end encrypted database. The key
+    /// stays on this device unless exported with `export_db_key`.
+    pub fn create_encrypted_db(&self, db_name: &str) -> Result<()> {
+        if self.storage.get_db_key(db_name)?.is_some() {
+            return Err(anyhow!("Database {} already has an encryption key", db_name));
+        }
+        self.storage.put_db_key(db_name, &crypto::generate_db_key())
+    }
+
+    fn db_key(&self, db_name: &str) -> Result<[u8; crypto::DB_KEY_LENGTH]> {
+        let key = self
+            .storage
+            .get_db_key(db_name)?
+            .ok_or_else(|| anyhow!("No encryption key for database {}", db_name))?;
+        key.try_into().map_err(|_| anyhow!("Corrupt encryption key for database {}", db_name))
+    }
+
+    /// Encrypt a value with the database key, sign the ciphertext and store +
+    /// replicate it like any other operation
+    pub async fn store_encrypted(
+        &self,
+        db_name: &str,
+        key: &str,
+        value: &[u8],
+        signing_key: &SigningKey,
+    ) -> Result<()> {
+        let db_key = self.db_key(db_name)?;
+        let aad = crypto::encryption_aad(db_name, key);
+        let sealed = crypto::encrypt_value(&db_key, aad.as_bytes(), value)?;
+
+        let op = SignedOperation::create_and_sign(
+            db_name.to_string(),
+            key.to_string(),
+            sealed,
+            "String".to_string(),
+            signing_key,
+        );
+        self.command_tx.send(NodeCommand::PublishOperation { op }).await?;
+        Ok(())
+    }
+
+    /// Read and decrypt a value of an encrypted database
+    pub fn get_decrypted(&self, db_name: &str, key: &str) -> Result<Option<Vec<u8>>> {
+        let Some(stored) = self.storage.get(db_name, key)? else {
+            return Ok(None);
+        };
+        let db_key = self.db_key(db_name)?;
+        let sealed = String::from_utf8(stored).map_err(|_| anyhow!("Stored value is not encrypted"))?;
+        let aad = crypto::encryption_aad(db_name, key);
+        Ok(Some(crypto::decrypt_value(&db_key, aad.as_bytes(), &sealed)?))
+    }
+
+    /// Export a database key (hex) to share it with another device
+    pub fn export_db_key(&self, db_name: &str) -> Result<String> {
+        Ok(hex::encode(self.db_key(db_name)?))
+    }
+
+    /// Import a database key shared from another device
+    pub fn import_db_key(&self, db_name: &str, key_hex: &str) -> Result<()> {
+        let key = crypto::secure_hex_decode(key_hex)?;
+        if key.len() != crypto::DB_KEY_LENGTH {
+            return Err(anyhow!("Database key must be {} bytes", crypto::DB_KEY_LENGTH));
+        }
+        self.storage.put_db_key(db_name, &key)
+    }
+
+    /// Grant and/or revoke writers of a database owned by `owner_key`, then
+    /// publish the new signed writer list to peers
+    pub async fn update_writers(
+        &self,
+        db_name: &str,
+        grant: Vec<String>,
+        revoke: Vec<String>,
+        owner_key: &SigningKey,
+    ) -> Result<()> {
+        let acl = self.sync_manager.sync_store().acl();
+        let list = acl.next_list(db_name, &grant, &revoke, owner_key)?;
+        acl.apply(list.clone())?;
+
+        self.command_tx
+            .send(NodeCommand::BroadcastSync { message: SyncMessage::AclUpdate { acl: list } })
+            .await?;
+        Ok(())
+    }
+
+    /// Write an encrypted backup of all data and the node key to `path`
+    pub fn export_backup(&self, path: &str, passphrase: &str) -> Result<BackupSummary> {
+        self.storage.flush()?;
+        backup::export_backup(&self.storage, &self.signing_key, std::path::Path::new(path), passphrase)
+    }
+
+    /// Drop a database owned by `owner_key` here and on every peer holding it
+    pub async fn drop_database(&self, db_name: &str, owner_key: &SigningKey) -> Result<()> {
+        let tombstone = DbTombstone::create_and_sign(db_name, owner_key)?;
+        self.sync_manager.sync_store().apply_tombstone(tombstone.clone()).await?;
+
+        self.command_tx
+            .send(NodeCommand::BroadcastSync { message: SyncMessage::DropDatabase { tombstone } })
+            .await?;
+        Ok(())
+    }
+
+    /// Writers of a database besides its owner
+    pub fn get_writers(&self, db_name: &str) -> Vec<String> {
+        self.sync_manager
+            .sync_store()
+            .acl()
+            .get(db_name)
+            .map(|list| list.writers)
+            .unwrap_or_default()
+    }
+
+    /// Create a signed invite token for a database owned by `owner_key`.
+    /// Write invites authorize a fresh writer key (published like any other
+    /// writer list update) and hand its secret to the redeemer.
+    pub async fn create_db_invite(
+        &self,
+        db_name: &str,
+        owner_key: &SigningKey,
+        can_write: bool,
+        include_encryption_key: bool,
+        expires_in_secs: u64,
+    ) -> Result<String> {
+        let owner = crypto::public_key_hex(owner_key);
+        crypto::verify_db_name_secure(db_name, &owner)?;
+
+        let writer_secret_key = if can_write {
+            let (writer_key, writer) = crypto::generate_keypair();
+            self.update_writers(db_name, vec![writer], Vec::new(), owner_key).await?;
+            Some(hex::encode(writer_key.to_bytes()))
+        } else {
+            None
+        };
+
+        let encryption_key = match self.storage.get_db_key(db_name)? {
+            Some(key) if include_encryption_key => Some(hex::encode(key)),
+            _ => None,
+        };
+
+        let invite = DbInvite {
+            invite_id: uuid::Uuid::new_v4().to_string(),
+            db_name: db_name.to_string(),
+            owner,
+            encryption_key,
+            writer_secret_key,
+            acl: self.sync_manager.sync_store().acl().get(db_name),
+            expires_at: chrono::Utc::now().timestamp_millis() + (expires_in_secs as i64) * 1000,
+        };
+        invite.to_token(owner_key)
+    }
+
+    /// Redeem an invite token: apply its writer list, import the encryption
+    /// key and follow the database so it is replicated to this device
+    pub fn accept_db_invite(&self, token: &str) -> Result<DbInvite> {
+        let invite = DbInvite::from_token(token)?;
+
+        if let Some(list) = &invite.acl {
+            self.sync_manager.sync_store().acl().apply(list.clone())?;
+        }
+        if let Some(key_hex) = &invite.encryption_key {
+            self.import_db_key(&invite.db_name, key_hex)?;
+        }
+        self.sync_manager.sync_store().follow_database(&invite.db_name)?;
+
+        info!(db = %invite.db_name, can_write = invite.can_write(), "Accepted database invite");
+        Ok(invite)
+    }
+
+    /// Only sync followed databases (`true`) or everything (`false`)
+    pub fn set_selective_sync(&self, selective: bool) -> Result<()> {
+        self.sync_manager.sync_store().set_selective_sync(selective)
+    }
+
+    /// Follow a database in selective sync mode
+    pub fn follow_database(&self, db_name: &str) -> Result<()> {
+        self.sync_manager.sync_store().follow_database(db_name)
+    }
+
+    /// Stop following a database
+    pub fn unfollow_database(&self, db_name: &str) -> Result<()> {
+        self.sync_manager.sync_store().unfollow_database(db_name)
+    }
+
+    /// Databases followed by this device, sorted
+    pub fn get_followed_databases(&self) -> Vec<String> {
+        let mut databases: Vec<String> = self.sync_manager.sync_store().sync_filter().databases.into_iter().collect();
+        databases.sort();
+        databases
+    }
+
+    /// Keep local writes that lose an LWW conflict under a conflict key
+    pub fn set_retain_conflicts(&self, enabled: bool) {
+        self.sync_manager.sync_store().set_retain_conflicts(enabled);
+    }
+
+    /// Cross-check stored values against the oplog, optionally repairing them
+    pub async fn verify_storage(&self, repair: bool) -> Result<IntegrityReport> {
+        self.sync_manager.sync_store().verify_storage(repair).await
+    }
+
+    /// Sync activity of a database
+    pub async fn db_sync_info(&self, db_name: &str) -> DbSyncInfo {
+        self.sync_manager.sync_store().db_sync_info(db_name).await
+    }
+
+    /// Merkle root (hex) of this replica of a database (see `merkle`)
+    pub async fn database_root(&self, db_name: &str) -> String {
+        let metas = self.sync_manager.sync_store().database_metas(db_name).await;
+        StateTree::from_leaves(metas.into_iter().map(|meta| (meta.crdt_key, meta.hash))).root().to_hex().to_string()
+    }
+
+    /// Latest comparison of each replicated database against the root its
+    /// owner published
+    pub fn replica_checks(&self) -> Vec<RootCheck> {
+        self.sync_manager.root_checks()
+    }
+
+    /// List retained conflicts of a database as (conflict key, record)
+    pub fn list_conflicts(&self, db_name: &str) -> Result<Vec<(String, ConflictRecord)>> {
+        self.sync_manager.sync_store().list_conflicts(db_name)
+    }
 }
 
 /// Handle fetch-latency-request (matches cyberfly-rust-node implementation)
 /// Verifies signature, executes HTTP request, measures latency, and publishes response
 async fn handle_fetch_latency_request(
     data: Vec<u8>,
-    latency_sender: Arc<Mutex<Option<GossipSender>>>,
+    latency_sender: Arc<Mutex<Option<CountedSender>>>,
     node_id: String,
     region: Option<String>,
-    resilience: Option<Arc<NetworkResilience>>,
+    resilience: Arc<NetworkResilience>,
 ) -> Result<()> {
     use std::time::Instant;
     
@@ -2068,11 +5807,9 @@ async fn handle_fetch_latency_request(
             log_info!("✅ Latency request {} completed: {:.2} ms (status: {})", 
                 request_id, latency_ms, status);
             
-            // Notify resilience of success (if available)
-            if let Some(res) = &resilience {
-                if let Some(peer_id) = requester_peer {
-                    res.record_success(peer_id, Some(latency_ms));
-                }
+            // Notify resilience of success
+            if let Some(peer_id) = requester_peer {
+                resilience.record_success(peer_id, Some(latency_ms));
             }
 
             FetchLatencyResponse {
@@ -2087,11 +5824,9 @@ async fn handle_fetch_latency_request(
         }
         Err(e) => {
             log_error!("❌ Latency request {} failed: {}", request_id, e);
-            // Notify resilience of failure (if available)
-            if let Some(res) = &resilience {
-                if let Some(peer_id) = requester_peer {
-                    res.record_failure(peer_id);
-                }
+            // Notify resilience of failure
+            if let Some(peer_id) = requester_peer {
+                resilience.record_failure(peer_id);
             }
             
             FetchLatencyResponse {
@@ -2113,7 +5848,7 @@ async fn handle_fetch_latency_request(
 /// Publish latency response to the latency topic wrapped in GossipMessage format
 async fn publish_latency_response(
     response: FetchLatencyResponse,
-    latency_sender: Arc<Mutex<Option<GossipSender>>>,
+    latency_sender: Arc<Mutex<Option<CountedSender>>>,
     node_id: String,
 ) -> Result<()> {
     // Wrap response in GossipMessage format matching cyberfly-rust-node
@@ -2138,3 +5873,752 @@ async fn publish_latency_response(
     Ok(())
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespaced_topic() {
+        assert_eq!(namespaced_topic(SYNC_TOPIC, "sync", None), *SYNC_TOPIC);
+
+        let sync = namespaced_topic(SYNC_TOPIC, "sync", Some("chat-app"));
+        assert_eq!(sync, *blake3::hash(b"cyberfly:chat-app:sync").as_bytes());
+        assert_ne!(sync, namespaced_topic(DATA_TOPIC, "data", Some("chat-app")));
+        assert_ne!(sync, namespaced_topic(SYNC_TOPIC, "sync", Some("notes-app")));
+    }
+
+    #[test]
+    fn test_custom_message_signature() {
+        let (signing_key, _) = crypto::generate_keypair();
+        let node_id = iroh::PublicKey::from_bytes(signing_key.verifying_key().as_bytes()).unwrap().to_string();
+        let msg = GossipMessage::custom(&signing_key, node_id.clone(), "hello".to_string());
+        assert!(msg.is_authentic());
+
+        // Survives the wire format
+        let decoded: GossipMessage = serde_json::from_slice(&serde_json::to_vec(&msg).unwrap()).unwrap();
+        assert!(decoded.is_authentic());
+
+        // Spoofed sender
+        let (other_key, _) = crypto::generate_keypair();
+        let spoofed = GossipMessage::custom(&other_key, node_id.clone(), "hello".to_string());
+        assert!(!spoofed.is_authentic());
+
+        // Tampered content
+        let GossipMessage::Custom { from, timestamp, public_key, signature, .. } = msg else { unreachable!() };
+        let tampered = GossipMessage::Custom {
+            from,
+            content: "goodbye".to_string(),
+            timestamp,
+            public_key,
+            signature,
+        };
+        assert!(!tampered.is_authentic());
+
+        // Unsigned message from an older node
+        let legacy: GossipMessage = serde_json::from_str(&format!(
+            r#"{{"msg_type":"Custom","from":"{}","content":"hi","timestamp":1}}"#,
+            node_id
+        )).unwrap();
+        assert!(!legacy.is_authentic());
+    }
+
+    #[test]
+    fn test_application_topic() {
+        let chat = application_topic("chat", None);
+        assert_eq!(chat, application_topic("chat", None));
+        assert_ne!(chat, application_topic("chat", Some("notes-app")));
+        assert_ne!(chat, application_topic("presence", None));
+        assert_ne!(chat, namespaced_topic(DATA_TOPIC, "data", None));
+    }
+
+    #[test]
+    fn test_gossip_neighbors() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        let state = Arc::new(RwLock::new(SharedNodeState::default()));
+        let stats = Arc::new(SessionStats::load(&storage).unwrap());
+        let neighbors = GossipNeighbors::new(state.clone(), stats.clone());
+        let peer = |seed: u8| SecretKey::from_bytes(&[seed; 32]).public();
+        let (a, b) = (peer(1), peer(2));
+
+        // A neighbor on several topics counts once
+        neighbors.up("data", &a);
+        neighbors.up("sync", &a);
+        neighbors.up("latency", &b);
+        assert_eq!(state.read().connected_peers, 2);
+        assert_eq!(state.read().topic_neighbors.get("sync"), Some(&1));
+        assert!(neighbors.contains(&a.to_string()));
+
+        // Connected until down on every topic
+        neighbors.down("data", &a);
+        assert_eq!(state.read().connected_peers, 2);
+        neighbors.down("sync", &a);
+        assert_eq!(state.read().connected_peers, 1);
+        assert_eq!(state.read().topic_neighbors.get("sync"), None);
+        assert_eq!(neighbors.ids(), vec![b]);
+
+        assert_eq!(neighbors.remove_stale(Duration::from_secs(600)), 0);
+        assert_eq!(neighbors.remove_stale(Duration::ZERO), 1);
+        assert_eq!(state.read().connected_peers, 0);
+
+        neighbors.up("data", &a);
+        assert_eq!(stats.session().connects, 2);
+        assert_eq!(stats.session().reconnects, 1);
+
+        // Leaving every topic when networking pauses
+        neighbors.up("sync", &b);
+        neighbors.clear();
+        assert_eq!(state.read().connected_peers, 0);
+        assert!(neighbors.ids().is_empty());
+    }
+
+    #[test]
+    fn test_connectivity_assessment() {
+        use ConnectivityState::*;
+
+        assert_eq!(Connectivity::assess(3, 10, true, 0, 3, Degraded).state, Online);
+        assert_eq!(Connectivity::assess(3, 10, false, 0, 3, Online).state, Degraded);
+
+        // Degraded until enough isolated checks in a row
+        let first = Connectivity::assess(0, 10, true, 1, 3, Online);
+        assert_eq!(first, Connectivity::new(Degraded, "no gossip neighbors"));
+        assert_eq!(Connectivity::assess(0, 10, true, 3, 3, Degraded).state, Isolated);
+
+        // Isolated survives the check count reset after a reconnect attempt
+        assert_eq!(Connectivity::assess(0, 10, true, 1, 3, Isolated).state, Isolated);
+        assert_eq!(Connectivity::assess(1, 10, true, 0, 3, Isolated).state, Online);
+    }
+
+    #[tokio::test]
+    async fn test_stop_releases_storage() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("db");
+
+        for _ in 0..2 {
+            let storage = Arc::new(Storage::new(path.clone()).unwrap());
+            let shutdown = CancellationToken::new();
+            let task = {
+                let storage = storage.clone();
+                spawn_until(&shutdown, async move {
+                    loop {
+                        let _ = storage.flush();
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                    }
+                })
+            };
+            drop(storage);
+
+            // The database stays locked until the task lets go of it
+            shutdown.cancel();
+            task.await.unwrap();
+            assert!(Storage::new(path.clone()).is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stop_and_restart_on_same_data_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_dir = dir.path().to_string_lossy().into_owned();
+        let node = CyberflyNode::start_offline(data_dir.clone()).await.unwrap();
+        let db_name = crypto::generate_db_name("notes", node.public_key());
+        node.store_data_signed(&db_name, "a", b"hello", "String").await.unwrap();
+        // Storage is locked while the node runs
+        assert!(CyberflyNode::start_offline(data_dir.clone()).await.is_err());
+
+        node.stop().await.unwrap();
+        assert!(node.shutdown.is_cancelled());
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !node.command_tx.is_closed() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("event loop still running after stop");
+        assert!(node.store_data_signed(&db_name, "b", b"late", "String").await.is_err());
+        let public_key = node.public_key().to_string();
+        drop(node);
+
+        let restarted = CyberflyNode::start_offline(data_dir).await.unwrap();
+        assert_eq!(restarted.public_key(), public_key);
+        assert_eq!(restarted.get_data(db_name.clone(), "a".to_string()).await.unwrap(), Some(b"hello".to_vec()));
+        assert_eq!(restarted.sync_manager.sync_store().database_operations(&db_name).await.len(), 1);
+        restarted.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_offline_writes_are_queued() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(Storage::new(dir.path().join("db")).unwrap());
+        let state = Arc::new(RwLock::new(SharedNodeState::default()));
+        let stats = Arc::new(SessionStats::load(&storage).unwrap());
+        let data_commands = DataCommands {
+            node_id: "local".to_string(),
+            storage: storage.clone(),
+            sync_manager: Arc::new(SyncManager::new(storage.clone(), "local".to_string(), None)),
+            sync_sender: Arc::new(Mutex::new(None)),
+            neighbors: Arc::new(GossipNeighbors::new(state, stats)),
+        };
+
+        let store = NodeCommand::StoreData {
+            db_name: "notes".to_string(),
+            key: "a".to_string(),
+            value: b"hello".to_vec(),
+            public_key: "pk".to_string(),
+            signature: "sig".to_string(),
+        };
+        data_commands.handle(store).await;
+        assert_eq!(storage.get("notes", "a").unwrap(), Some(b"hello".to_vec()));
+        assert_eq!(storage.outbox_len().unwrap(), 1);
+
+        let (tx, rx) = oneshot::channel();
+        data_commands.handle(NodeCommand::GetData { db_name: "notes".to_string(), key: "a".to_string(), response: tx }).await;
+        assert_eq!(rx.await.unwrap(), Some(b"hello".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_store_data_signed_with_node_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let node = CyberflyNode::start_offline(dir.path().to_string_lossy().into_owned()).await.unwrap();
+        let db_name = crypto::generate_db_name("notes", node.public_key());
+
+        node.store_data_signed(&db_name, "a", b"hello", "String").await.unwrap();
+        assert_eq!(node.get_data(db_name.clone(), "a".to_string()).await.unwrap(), Some(b"hello".to_vec()));
+        let ops = node.sync_manager.sync_store().database_operations(&db_name).await;
+        let op = ops.iter().find(|op| op.key == "a").unwrap();
+        assert!(op.verify().unwrap());
+        assert_eq!(op.public_key, node.public_key());
+
+        // Databases of other keys, and store types needing a field, are refused
+        let (_, other) = crypto::generate_keypair();
+        let foreign = crypto::generate_db_name("notes", &other);
+        assert!(node.store_data_signed(&foreign, "a", b"hello", "String").await.is_err());
+        assert!(node.store_data_signed(&db_name, "a", b"hello", "Hash").await.is_err());
+        assert!(node.store_data_signed(&db_name, "a", b"1", "Counter").await.is_err());
+        node.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rotate_identity_keeps_the_old_node_until_the_new_one_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = NodeConfig { network_id: Some("rotation".to_string()), ..NodeConfig::default() }
+            .with_relay(RelayConfig::Disabled);
+        let node = CyberflyNode::start_with_config(dir.path().to_string_lossy().into_owned(), None, Vec::new(), config)
+            .await
+            .unwrap();
+        let db_name = crypto::generate_db_name("notes", node.public_key());
+        node.store_data_signed(&db_name, "a", b"hello", "String").await.unwrap();
+        let key_file = dir.path().join(backup::SECRET_KEY_FILE);
+        let old_key = std::fs::read(&key_file).unwrap();
+
+        // The new node can't start with an unreadable network key
+        let network_key_file = dir.path().join(private_network::NETWORK_KEY_FILE);
+        std::fs::write(&network_key_file, b"garbage").unwrap();
+        assert!(node.rotate_identity(None, Duration::from_secs(60)).await.is_err());
+        assert_eq!(std::fs::read(&key_file).unwrap(), old_key);
+        assert!(node.successor_of(node.public_key()).unwrap().is_none());
+        node.store_data_signed(&db_name, "b", b"still running", "String").await.unwrap();
+        assert_eq!(node.get_data(db_name.clone(), "b".to_string()).await.unwrap(), Some(b"still running".to_vec()));
+
+        // Once it can, the old node keeps answering until its successor stops
+        std::fs::remove_file(&network_key_file).unwrap();
+        let (successor, rotation) = node.rotate_identity(None, Duration::from_secs(60)).await.unwrap();
+        assert_ne!(std::fs::read(&key_file).unwrap(), old_key);
+        assert_eq!(rotation.rekeyed_databases.len(), 1);
+        assert!(node.successor_of(node.public_key()).unwrap().is_some());
+        assert!(!node.shutdown.is_cancelled());
+        successor.stop().await.unwrap();
+        tokio::time::timeout(Duration::from_secs(10), node.shutdown.cancelled()).await.unwrap();
+    }
+
+    /// A loopback address `node` can be dialed at
+    async fn loopback_addr(node: &CyberflyNode) -> String {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(port) = node.node_addr().and_then(|addr| addr.ip_addrs().find(|ip| ip.is_ipv4()).map(|ip| ip.port())) {
+                    return format!("127.0.0.1:{}", port);
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .expect("no direct address")
+    }
+
+    #[tokio::test]
+    async fn test_retiring_node_leaves_the_outbox_to_its_successor() {
+        let config = NodeConfig {
+            network_id: Some("retiring".to_string()),
+            ..NodeConfig::default().with_relay(RelayConfig::Disabled)
+        }
+        .with_pinned_peers_check_interval(Duration::from_secs(5));
+        let dir = tempfile::tempdir().unwrap();
+        let node = CyberflyNode::start_with_config(dir.path().to_string_lossy().into_owned(), None, Vec::new(), config.clone())
+            .await
+            .unwrap();
+        let observer_dir = tempfile::tempdir().unwrap();
+        let observer = CyberflyNode::start_with_config(observer_dir.path().to_string_lossy().into_owned(), None, Vec::new(), config)
+            .await
+            .unwrap();
+        let (_, mut events) = observer.subscribe_events(0);
+        let mut received = async || {
+            while let Ok(recorded) = events.recv().await {
+                if matches!(&recorded.event, NodeEvent::SyncReceived { key, .. } if key == "queued") {
+                    return;
+                }
+            }
+        };
+
+        // Only the old node is a sync neighbor of the observer
+        observer.pin_peer(&node.node_id, Some(loopback_addr(&node).await)).unwrap();
+        tokio::time::timeout(Duration::from_secs(20), async {
+            while node.shared_state.read().topic_neighbors.get("sync").copied().unwrap_or(0) == 0 {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .expect("observer never joined the sync topic");
+
+        // A write during the grace period is queued by the successor, and the
+        // old node, though it has a neighbor, doesn't send it
+        let (successor, _) = node.rotate_identity(None, Duration::from_secs(60)).await.unwrap();
+        let handled = |node: &CyberflyNode| {
+            let command_tx = node.command_tx.clone();
+            async move {
+                let (tx, rx) = oneshot::channel();
+                command_tx.send(NodeCommand::Barrier(tx)).await.unwrap();
+                rx.await.unwrap();
+            }
+        };
+        let db_name = crypto::generate_db_name("notes", successor.public_key());
+        successor.store_data_signed(&db_name, "queued", b"once", "String").await.unwrap();
+        let old_db_name = crypto::generate_db_name("notes", node.public_key());
+        node.store_data_signed(&old_db_name, "old", b"retired", "String").await.unwrap();
+        handled(&successor).await;
+        handled(&node).await;
+        assert_eq!(node.get_data(old_db_name, "old".to_string()).await.unwrap(), None);
+        assert_eq!(successor.storage.outbox_len().unwrap(), 1);
+        assert_eq!(successor.storage.outbox_queued(&db_name), 1);
+        assert!(tokio::time::timeout(OUTBOX_FLUSH_INTERVAL * 2, received()).await.is_err());
+        assert_eq!(successor.storage.outbox_len().unwrap(), 1);
+
+        // The successor sends it once it has a neighbor
+        observer.pin_peer(&successor.node_id, Some(loopback_addr(&successor).await)).unwrap();
+        tokio::time::timeout(Duration::from_secs(30), received()).await.expect("queued write never arrived");
+        assert_eq!(successor.storage.outbox_len().unwrap(), 0);
+        assert_eq!(successor.storage.outbox_queued(&db_name), 0);
+        assert!(tokio::time::timeout(OUTBOX_FLUSH_INTERVAL * 2, received()).await.is_err());
+
+        successor.stop().await.unwrap();
+        observer.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connect_peer_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = NodeConfig {
+            network_id: Some("connect".to_string()),
+            ..NodeConfig::default().with_relay(RelayConfig::Disabled)
+        };
+        let node = CyberflyNode::start_with_config(dir.path().to_string_lossy().into_owned(), None, Vec::new(), config)
+            .await
+            .unwrap();
+        let peer_id = iroh::SecretKey::from_bytes(&[7; 32]).public().to_string();
+
+        let err = node.connect_peer("not-a-node-id".to_string(), None).await.unwrap_err();
+        assert!(err.starts_with("Invalid node ID"), "{}", err);
+        let err = node.connect_peer(peer_id.clone(), Some("no port".to_string())).await.unwrap_err();
+        assert!(err.contains("expected ip:port"), "{}", err);
+
+        // Stopping the node gives up on a dial still in flight
+        let started = Instant::now();
+        let (result, stopped) = tokio::join!(
+            node.connect_peer(peer_id, Some("127.0.0.1:9".to_string())),
+            async {
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                node.stop().await
+            },
+        );
+        stopped.unwrap();
+        assert!(result.is_err());
+        assert!(started.elapsed() < MANUAL_CONNECT_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn test_connect_peer_offline() {
+        let dir = tempfile::tempdir().unwrap();
+        let node = CyberflyNode::start_offline(dir.path().to_string_lossy().into_owned()).await.unwrap();
+        let peer_id = iroh::SecretKey::from_bytes(&[7; 32]).public().to_string();
+        assert_eq!(node.connect_peer(peer_id, None).await.unwrap_err(), "The node is offline");
+        node.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pause_and_resume_networking() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = NodeConfig {
+            network_id: Some("pause".to_string()),
+            ..NodeConfig::default().with_relay(RelayConfig::Disabled)
+        };
+        let node = CyberflyNode::start_with_config(dir.path().to_string_lossy().into_owned(), None, Vec::new(), config)
+            .await
+            .unwrap();
+        let node = &node;
+        let gossip_fails = |fails: bool| async move {
+            tokio::time::timeout(Duration::from_secs(5), async {
+                while node.send_gossip("chat".to_string(), "hi".to_string()).await.is_err() != fails {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+            })
+            .await
+            .is_ok()
+        };
+        assert!(gossip_fails(false).await);
+
+        // Pausing twice is the same as once
+        node.pause_networking().await.unwrap();
+        node.pause_networking().await.unwrap();
+        assert!(node.is_networking_paused());
+        assert!(gossip_fails(true).await);
+        let err = node.send_gossip("chat".to_string(), "hi".to_string()).await.unwrap_err();
+        assert_eq!(err.to_string(), "Networking is paused");
+        // Local writes still work
+        let db_name = crypto::generate_db_name("notes", node.public_key());
+        node.store_data_signed(&db_name, "a", b"hello", "String").await.unwrap();
+
+        node.resume_networking().await.unwrap();
+        assert!(!node.is_networking_paused());
+        assert!(gossip_fails(false).await);
+
+        node.stop().await.unwrap();
+        assert!(node.pause_networking().await.is_err());
+        assert!(node.resume_networking().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_topic_recovery() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        let endpoint = Endpoint::builder(presets::N0).relay_mode(iroh::RelayMode::Disabled).bind().await.unwrap();
+        let (gate_tx, gate_rx) = watch::channel(TopicGate::default());
+        let (event_tx, mut event_rx) = mpsc::channel(16);
+        let joiner = TopicJoiner {
+            gossip: Gossip::builder().spawn(endpoint.clone()),
+            bootstrap_peers: Vec::new(),
+            peer_registry: Arc::new(RwLock::new(PeerRegistry::new("local".to_string()))),
+            stats: Arc::new(SessionStats::load(&storage).unwrap()),
+            bandwidth: Arc::new(BandwidthMeter::load(&storage).unwrap()),
+            gate: gate_rx,
+            event_tx,
+            max_message_size: NodeConfig::default().max_gossip_message_size,
+        };
+        let slot = Arc::new(Mutex::new(None));
+        let topic_id = TopicId::from_bytes([1; 32]);
+        let mut events = joiner.subscribe(topic_id, "data", Subsystem::Gossip, Vec::new(), slot.clone()).await.unwrap();
+        assert!(slot.lock().await.is_some());
+
+        // The gossip stream ends, and networking is paused before the rejoin
+        events.receiver = Some(futures::stream::empty().boxed());
+        let listener = tokio::spawn(async move { while events.next().await.is_some() {} });
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(slot.lock().await.is_none());
+        gate_tx.send_modify(|gate| gate.paused = true);
+        tokio::time::sleep(TOPIC_RECOVERY_DELAY + Duration::from_secs(1)).await;
+        assert!(event_rx.try_recv().is_err());
+        assert!(slot.lock().await.is_none());
+
+        // Resuming rejoins the topic
+        gate_tx.send_modify(|gate| gate.paused = false);
+        let event = tokio::time::timeout(Duration::from_secs(5), event_rx.recv()).await.unwrap();
+        assert!(matches!(event, Some(NodeEvent::TopicRecovered { topic }) if topic == "data"));
+        assert!(slot.lock().await.is_some());
+
+        // The events end when the node stops
+        drop(gate_tx);
+        tokio::time::timeout(Duration::from_secs(5), listener).await.unwrap().unwrap();
+        assert!(event_rx.try_recv().is_err());
+        endpoint.close().await;
+    }
+
+    #[tokio::test]
+    async fn test_topic_stats_follow_publishing() {
+        use iroh::address_lookup::MemoryLookup;
+
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        let (_gate_tx, gate_rx) = watch::channel(TopicGate::default());
+        let lookup = MemoryLookup::new();
+        let mut routers = Vec::new();
+        let mut joiners = Vec::new();
+        for _ in 0..2 {
+            let endpoint = Endpoint::builder(presets::N0)
+                .relay_mode(iroh::RelayMode::Disabled)
+                .address_lookup(lookup.clone())
+                .bind()
+                .await
+                .unwrap();
+            let port = endpoint.bound_sockets().iter().find(|addr| addr.is_ipv4()).unwrap().port();
+            lookup.add_endpoint_info(iroh::EndpointAddr::new(endpoint.id()).with_ip_addr(SocketAddr::from(([127, 0, 0, 1], port))));
+            let gossip = Gossip::builder().spawn(endpoint.clone());
+            routers.push(Router::builder(endpoint.clone()).accept(iroh_gossip::ALPN, gossip.clone()).spawn());
+            joiners.push(TopicJoiner {
+                gossip,
+                bootstrap_peers: Vec::new(),
+                peer_registry: Arc::new(RwLock::new(PeerRegistry::new(endpoint.id().to_string()))),
+                stats: Arc::new(SessionStats::load(&storage).unwrap()),
+                bandwidth: Arc::new(BandwidthMeter::load(&storage).unwrap()),
+                gate: gate_rx.clone(),
+                event_tx: mpsc::channel(16).0,
+                max_message_size: NodeConfig::default().max_gossip_message_size,
+            });
+        }
+        let (publisher, subscriber) = (&joiners[0], &joiners[1]);
+        let topic_id = TopicId::from_bytes([2; 32]);
+        let slot = Arc::new(Mutex::new(None));
+        let mut published = publisher.subscribe(topic_id, "sync", Subsystem::Sync, Vec::new(), slot.clone()).await.unwrap();
+        tokio::spawn(async move { while published.next().await.is_some() {} });
+        let mut events = subscriber
+            .subscribe(topic_id, "sync", Subsystem::Sync, vec![routers[0].endpoint().id()], Arc::new(Mutex::new(None)))
+            .await
+            .unwrap();
+        let state = SharedNodeState::default();
+        let stats = |joiner: &TopicJoiner| topic_stats(&joiner.bandwidth, &state);
+        assert!(stats(publisher).iter().all(|topic| topic.messages_sent == 0 && topic.messages_received == 0));
+
+        // Once the subscriber is a neighbor, publish one message
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !matches!(events.next().await, Some(Ok(GossipEvent::NeighborUp(_)))) {}
+        })
+        .await
+        .unwrap();
+        let payload = Bytes::from_static(b"hello sync");
+        slot.lock().await.as_ref().unwrap().broadcast(payload.clone()).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !matches!(events.next().await, Some(Ok(GossipEvent::Received(_)))) {}
+        })
+        .await
+        .unwrap();
+
+        // Only the sync topic moved, on both ends
+        for (joiner, sent, received) in [(publisher, 1, 0), (subscriber, 0, 1)] {
+            for topic in stats(joiner) {
+                if topic.topic == "sync" {
+                    assert_eq!((topic.messages_sent, topic.messages_received), (sent, received));
+                    assert_eq!((topic.bytes_sent, topic.bytes_received), (sent * payload.len() as u64, received * payload.len() as u64));
+                } else {
+                    assert_eq!((topic.messages_sent, topic.messages_received, topic.bytes_sent, topic.bytes_received), (0, 0, 0, 0), "{}", topic.topic);
+                }
+            }
+        }
+
+        for router in routers {
+            router.shutdown().await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_relay_config_errors() {
+        assert!(matches!(relay_mode(&RelayConfig::Disabled).unwrap(), iroh::RelayMode::Disabled));
+        assert!(relay_mode(&RelayConfig::Custom(vec!["https://relay.example.com".to_string()])).is_ok());
+        assert!(relay_mode(&RelayConfig::Custom(vec!["not a url".to_string()])).is_err());
+        // Unreachable relay
+        assert!(ping_relay("http://127.0.0.1:9").await.is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let data_dir = dir.path().to_string_lossy().into_owned();
+        let bad = NodeConfig::default().with_relay(RelayConfig::Custom(vec!["not a url".to_string()]));
+        assert!(CyberflyNode::start_with_config(data_dir.clone(), None, Vec::new(), bad).await.is_err());
+
+        // No relay to report or ping, and the failed start left storage usable
+        let config = NodeConfig {
+            network_id: Some("relays".to_string()),
+            ..NodeConfig::default().with_relay(RelayConfig::Disabled)
+        };
+        let node = CyberflyNode::start_with_config(data_dir, None, Vec::new(), config).await.unwrap();
+        let status = node.relay_status().await;
+        assert_eq!(status.relay, RelayConfig::Disabled);
+        assert_eq!(status.active_url, None);
+        assert_eq!(status.rtt, None);
+        node.stop().await.unwrap();
+    }
+
+    /// Stages reported until the sender is dropped
+    async fn startup_stages(mut progress: mpsc::UnboundedReceiver<StartupStage>) -> Vec<StartupStage> {
+        let mut stages = Vec::new();
+        while let Some(stage) = progress.recv().await {
+            stages.push(stage);
+        }
+        stages
+    }
+
+    #[tokio::test]
+    async fn test_startup_progress() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_dir = dir.path().to_string_lossy().into_owned();
+        // A network of its own leaves out the built-in bootstrap peers
+        let config = NodeConfig {
+            network_id: Some("progress".to_string()),
+            ..NodeConfig::default().with_relay(RelayConfig::Disabled)
+        };
+        let bootstrap_peers = vec![format!("{}@127.0.0.1:9", iroh::SecretKey::from_bytes(&[7; 32]).public())];
+
+        // A rejected config reports nothing
+        let (tx, rx) = mpsc::unbounded_channel();
+        let invalid = NodeConfig { isolation_checks_before_reconnect: 0, ..config.clone() };
+        assert!(CyberflyNode::start_with_progress(data_dir.clone(), None, Vec::new(), invalid, Some(tx)).await.is_err());
+        assert!(startup_stages(rx).await.is_empty());
+
+        // Failing after the key is loaded, here on storage another node
+        // holds, ends the stages without Ready
+        let holder = CyberflyNode::start_offline(data_dir.clone()).await.unwrap();
+        let (tx, rx) = mpsc::unbounded_channel();
+        assert!(CyberflyNode::start_with_progress(data_dir.clone(), None, Vec::new(), config.clone(), Some(tx)).await.is_err());
+        assert_eq!(startup_stages(rx).await, vec![StartupStage::KeyLoaded]);
+        holder.stop().await.unwrap();
+        drop(holder);
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let node = CyberflyNode::start_with_progress(data_dir, None, bootstrap_peers, config, Some(tx)).await.unwrap();
+        let mut stages = Vec::new();
+        while stages.last() != Some(&StartupStage::Ready) {
+            stages.push(tokio::time::timeout(Duration::from_secs(5), rx.recv()).await.unwrap().unwrap());
+        }
+        assert_eq!(stages, vec![
+            StartupStage::KeyLoaded,
+            StartupStage::EndpointOnline { relay_connected: false },
+            // Dialed in the background, so it doesn't hold up the rest
+            StartupStage::BootstrapConnecting { attempt: 1 },
+            StartupStage::TopicsSubscribed,
+            StartupStage::Ready,
+        ]);
+        node.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_start_with_unreachable_relay_and_bootstrap() {
+        let dir = tempfile::tempdir().unwrap();
+        // A network of its own leaves out the built-in bootstrap peers
+        let config = NodeConfig {
+            network_id: Some("unreachable".to_string()),
+            ..NodeConfig::default().with_relay(RelayConfig::Custom(vec!["http://127.0.0.1:9".to_string()]))
+        };
+        let bootstrap_peers = vec![
+            format!("{}@127.0.0.1:9", iroh::SecretKey::from_bytes(&[7; 32]).public()),
+            "missing-address".to_string(),
+            "not-a-node-id@127.0.0.1:9".to_string(),
+        ];
+        let started = Instant::now();
+        let node = CyberflyNode::start_with_config(dir.path().to_string_lossy().into_owned(), None, bootstrap_peers, config)
+            .await
+            .unwrap();
+        // Neither the relay nor the bootstrap dial held up starting
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        // Nothing gets reported connected
+        let (recent, mut events) = node.subscribe_events(0);
+        let connected = |event: &NodeEvent| matches!(event, NodeEvent::RelayConnected { .. } | NodeEvent::BootstrapConnected { .. });
+        assert!(!recent.iter().any(|recorded| connected(&recorded.event)));
+        let wait = tokio::time::timeout(Duration::from_secs(1), async {
+            while let Ok(recorded) = events.recv().await {
+                if connected(&recorded.event) {
+                    return;
+                }
+            }
+        });
+        assert!(wait.await.is_err());
+        let stopping = Instant::now();
+        node.stop().await.unwrap();
+        assert!(stopping.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn test_wake_sync_without_peers() {
+        let dir = tempfile::tempdir().unwrap();
+        let offline = CyberflyNode::start_offline(dir.path().to_string_lossy().into_owned()).await.unwrap();
+        assert_eq!(offline.wake_sync(Duration::from_secs(1)).await.unwrap_err().to_string(), "The node is offline");
+        offline.stop().await.unwrap();
+        drop(offline);
+
+        let config = NodeConfig {
+            network_id: Some("wake".to_string()),
+            ..NodeConfig::default().with_relay(RelayConfig::Disabled)
+        };
+        let node = CyberflyNode::start_with_config(dir.path().to_string_lossy().into_owned(), None, Vec::new(), config)
+            .await
+            .unwrap();
+        let unreachable = iroh::SecretKey::from_bytes(&[7; 32]).public().to_string();
+        node.pin_peer(&unreachable, Some("127.0.0.1:9".to_string())).unwrap();
+
+        // The budget runs out with nobody to sync with
+        let summary = node.wake_sync(Duration::from_millis(200)).await.unwrap();
+        assert_eq!(summary.pinned_peers_dialed, 1);
+        assert_eq!(summary.pinned_peers_connected, 0);
+        assert!(!summary.synced);
+        assert!(summary.duration_ms < 2000, "{:?}", summary);
+        assert_eq!(node.storage.get_setting(LAST_WAKE_SYNC_SETTING).unwrap(), None);
+
+        node.stop().await.unwrap();
+        assert!(node.wake_sync(Duration::from_secs(1)).await.is_err());
+    }
+
+    /// Send a raw HTTP request to the debug API at `addr`; the response
+    async fn debug_api_request(addr: SocketAddr, request: String) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn test_debug_api_requires_token() {
+        let dir = tempfile::tempdir().unwrap();
+        let node = Arc::new(CyberflyNode::start_offline(dir.path().to_string_lossy().into_owned()).await.unwrap());
+        let (addr, token) = node.serve_debug_api("127.0.0.1:0".parse().unwrap(), false, |_| serde_json::json!([])).await.unwrap();
+        let get = |authorization: String| {
+            debug_api_request(addr, format!("GET /status HTTP/1.1\r\nHost: localhost\r\n{}\r\n", authorization))
+        };
+
+        assert!(get(String::new()).await.starts_with("HTTP/1.1 401"));
+        assert!(get("Authorization: Bearer wrong\r\n".to_string()).await.starts_with("HTTP/1.1 401"));
+        assert!(get(format!("Authorization: Bearer {}\r\n", token)).await.starts_with("HTTP/1.1 200"));
+
+        // A new server gets a new token
+        let (_, other) = node.serve_debug_api("127.0.0.1:0".parse().unwrap(), false, |_| serde_json::json!([])).await.unwrap();
+        assert_ne!(token, other);
+        node.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_debug_api_graphql_writes_are_opt_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let node = Arc::new(CyberflyNode::start_offline(dir.path().to_string_lossy().into_owned()).await.unwrap());
+        let post = |addr: SocketAddr, token: String, query: &str| {
+            let body = serde_json::json!({ "query": query }).to_string();
+            debug_api_request(
+                addr,
+                format!(
+                    "POST /graphql HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer {}\r\nContent-Length: {}\r\n\r\n{}",
+                    token,
+                    body.len(),
+                    body
+                ),
+            )
+        };
+        let submit = r#"mutation { submitData(input: { dbName: "notes", key: "k", value: "v", publicKey: "00", signature: "00" }) { success } }"#;
+
+        // Queries are served either way; without the opt-in there's no mutation
+        let (addr, token) = node.serve_debug_api("127.0.0.1:0".parse().unwrap(), false, |_| serde_json::json!([])).await.unwrap();
+        let response = post(addr, token.clone(), "{ __typename }").await;
+        assert!(response.contains(r#""__typename":"QueryRoot""#), "{}", response);
+        let response = post(addr, token, submit).await;
+        assert!(response.contains("errors") && !response.contains(r#""success""#), "{}", response);
+
+        let (addr, token) = node.serve_debug_api("127.0.0.1:0".parse().unwrap(), true, |_| serde_json::json!([])).await.unwrap();
+        let response = post(addr, token, submit).await;
+        assert!(response.contains(r#""success":false"#), "{}", response);
+        node.stop().await.unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+//! Recently used operations
+//!
+//! The sync store keeps only the metadata of its operations in memory and
+//! reads the operations themselves from the oplog. The ones written or read
+//! last are likely to be needed again soon, e.g. sent to a peer right after
+//! they were written, so the last `HOT_OPERATIONS` are kept here.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::sync::SignedOperation;
+
+/// Operations kept in memory; the least recently used are dropped beyond that
+pub const HOT_OPERATIONS: usize = 1024;
+
+struct Entry {
+    op: SignedOperation,
+    /// Position in `OpCache::lru`
+    tick: u64,
+}
+
+/// Operations by op ID, least recently used dropped first
+pub struct OpCache {
+    entries: HashMap<String, Entry>,
+    /// op IDs by last use, oldest first
+    lru: BTreeMap<u64, String>,
+    tick: u64,
+    capacity: usize,
+}
+
+impl Default for OpCache {
+    fn default() -> Self {
+        Self::new(HOT_OPERATIONS)
+    }
+}
+
+impl OpCache {
+    pub fn new(capacity: usize) -> Self {
+        Self { entries: HashMap::new(), lru: BTreeMap::new(), tick: 0, capacity }
+    }
+
+    /// The cached operation, now the most recently used
+    pub fn get(&mut self, op_id: &str) -> Option<SignedOperation> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(op_id)?;
+        self.lru.remove(&entry.tick);
+        entry.tick = self.tick;
+        self.lru.insert(entry.tick, op_id.to_string());
+        Some(entry.op.clone())
+    }
+
+    /// The cached operation, leaving its place alone (for scans that would
+    /// otherwise push out everything else)
+    pub fn peek(&self, op_id: &str) -> Option<SignedOperation> {
+        self.entries.get(op_id).map(|entry| entry.op.clone())
+    }
+
+    pub fn insert(&mut self, op: SignedOperation) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.tick += 1;
+        let op_id = op.op_id.clone();
+        if let Some(previous) = self.entries.insert(op_id.clone(), Entry { op, tick: self.tick }) {
+            self.lru.remove(&previous.tick);
+        }
+        self.lru.insert(self.tick, op_id);
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.lru.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+
+    /// Forget operations removed from the oplog
+    pub fn remove<'a>(&mut self, op_ids: impl IntoIterator<Item = &'a String>) {
+        for op_id in op_ids {
+            if let Some(entry) = self.entries.remove(op_id) {
+                self.lru.remove(&entry.tick);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(op_id: &str) -> SignedOperation {
+        let mut op = SignedOperation::new(
+            "db".to_string(),
+            op_id.to_string(),
+            "value".to_string(),
+            "String".to_string(),
+            "key".to_string(),
+            String::new(),
+        );
+        op.op_id = op_id.to_string();
+        op
+    }
+
+    #[test]
+    fn test_op_cache_lru() {
+        let mut cache = OpCache::new(2);
+        cache.insert(op("a"));
+        cache.insert(op("b"));
+        // Using `a` makes `b` the oldest
+        assert_eq!(cache.get("a").unwrap().op_id, "a");
+        cache.insert(op("c"));
+        assert!(cache.peek("b").is_none());
+
+        // Peeking doesn't count as a use
+        assert!(cache.peek("a").is_some());
+        cache.insert(op("d"));
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("c").is_some() && cache.get("d").is_some());
+
+        cache.remove(&["c".to_string()]);
+        assert!(cache.get("c").is_none());
+        assert!(cache.peek("d").is_some());
+    }
+}
@@ -0,0 +1,122 @@
+//! Direct latency probe between two nodes
+//!
+//! The requester opens a QUIC connection to the target on `PING_ALPN`, sends a
+//! random nonce on a bidirectional stream and times the echo, so the result is
+//! for that peer alone. Nodes that don't serve the ping protocol (e.g. desktop
+//! nodes) are measured with QUIC's own round-trip estimate of a gossip
+//! connection instead.
+
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use iroh::endpoint::Connection;
+use iroh::protocol::{AcceptError, ProtocolHandler};
+use iroh::{Endpoint, EndpointId, Watcher};
+
+/// ALPN of the ping protocol
+pub const PING_ALPN: &[u8] = b"/cyberfly/ping/1";
+
+const NONCE_LEN: usize = 8;
+
+/// Echoes ping nonces back to the requester
+#[derive(Debug, Clone, Default)]
+pub struct PingProtocol;
+
+impl ProtocolHandler for PingProtocol {
+    async fn accept(&self, connection: Connection) -> Result<(), AcceptError> {
+        let (mut send, mut recv) = connection.accept_bi().await.map_err(AcceptError::from_err)?;
+        let mut nonce = [0u8; NONCE_LEN];
+        recv.read_exact(&mut nonce).await.map_err(AcceptError::from_err)?;
+        send.write_all(&nonce).await.map_err(AcceptError::from_err)?;
+        send.finish().map_err(AcceptError::from_err)?;
+        // The requester closes once it has the echo
+        connection.closed().await;
+        Ok(())
+    }
+}
+
+/// Round-trip time to `peer_id`
+pub async fn round_trip(endpoint: &Endpoint, peer_id: EndpointId) -> Result<Duration> {
+    match ping(endpoint, peer_id).await {
+        Ok(rtt) => Ok(rtt),
+        Err(e) => {
+            tracing::debug!(%peer_id, "Ping failed ({}), using QUIC RTT estimate", e);
+            let conn = endpoint.connect(peer_id, iroh_gossip::ALPN).await?;
+            selected_path_rtt(&conn).ok_or_else(|| anyhow!("No RTT estimate for {} yet ({})", peer_id, e))
+        }
+    }
+}
+
+/// QUIC's RTT estimate on the path the connection currently sends on
+fn selected_path_rtt(conn: &Connection) -> Option<Duration> {
+    let path = conn.paths().get().into_iter().find(|path| path.is_selected())?;
+    conn.rtt(path.id())
+}
+
+async fn ping(endpoint: &Endpoint, peer_id: EndpointId) -> Result<Duration> {
+    let conn = endpoint.connect(peer_id, PING_ALPN).await?;
+    let (mut send, mut recv) = conn.open_bi().await?;
+
+    let nonce: [u8; NONCE_LEN] = rand::random();
+    let started = Instant::now();
+    send.write_all(&nonce).await?;
+    send.finish()?;
+    let mut echo = [0u8; NONCE_LEN];
+    recv.read_exact(&mut echo).await?;
+    let rtt = started.elapsed();
+
+    conn.close(0u32.into(), b"done");
+    if echo != nonce {
+        return Err(anyhow!("Ping echo from {} doesn't match", peer_id));
+    }
+    Ok(rtt)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use iroh::address_lookup::MemoryLookup;
+    use iroh::endpoint::presets;
+    use iroh::protocol::Router;
+    use iroh::{EndpointAddr, RelayMode, SecretKey};
+
+    use super::*;
+
+    async fn endpoint(lookup: MemoryLookup) -> Endpoint {
+        Endpoint::builder(presets::Minimal)
+            .relay_mode(RelayMode::Disabled)
+            .address_lookup(lookup)
+            .bind()
+            .await
+            .unwrap()
+    }
+
+    /// A node serving the ping protocol, and its loopback address
+    async fn responder() -> (Router, EndpointAddr) {
+        let endpoint = endpoint(MemoryLookup::new()).await;
+        let port = endpoint.bound_sockets().iter().find(|addr| addr.is_ipv4()).unwrap().port();
+        let addr = EndpointAddr::new(endpoint.id()).with_ip_addr(SocketAddr::from((Ipv4Addr::LOCALHOST, port)));
+        (Router::builder(endpoint).accept(PING_ALPN, PingProtocol).spawn(), addr)
+    }
+
+    #[tokio::test]
+    async fn test_ping() {
+        let (router, addr) = responder().await;
+        let lookup = MemoryLookup::new();
+        lookup.add_endpoint_info(addr.clone());
+        let requester = endpoint(lookup).await;
+
+        let rtt = ping(&requester, addr.id).await.unwrap();
+        assert!(rtt > Duration::ZERO);
+        assert!(round_trip(&requester, addr.id).await.unwrap() > Duration::ZERO);
+
+        // Unknown peers and closed nodes can't be pinged
+        let unknown = SecretKey::generate().public();
+        assert!(ping(&requester, unknown).await.is_err());
+        router.shutdown().await.unwrap();
+        assert!(ping(&requester, addr.id).await.is_err());
+
+        requester.close().await;
+    }
+}
@@ -0,0 +1,191 @@
+//! Adapting the node to battery and network conditions
+//!
+//! The app reports `DeviceConditions` (battery, charging, connection type)
+//! and the node runs with `PowerPolicy::apply` of its configuration: on low
+//! battery or metered data it announces and checks less often and dials less,
+//! defers automatic full syncs until conditions improve, and on metered data
+//! neither offers nor fetches iroh-blobs snapshots.
+//!
+//! Independently, the user can pick `PowerMode::Lite` for running the node
+//! overnight in an Android foreground service: only the sync topic is kept,
+//! joined through one bootstrap peer, and periodic work runs 6x less often.
+
+use crate::config::{NodeConfig, MAX_ANNOUNCE_INTERVAL};
+
+/// Battery level at or below which the node saves power (unless charging)
+pub const LOW_BATTERY_PERCENT: u8 = 20;
+
+/// Factor periodic work is slowed down by in low power mode
+const LOW_POWER_SLOWDOWN: u32 = 4;
+
+/// Factor announcements and latency sweeps are slowed down by on metered data
+const METERED_SLOWDOWN: u32 = 2;
+
+/// Factor periodic work is slowed down by in lite mode
+const LITE_SLOWDOWN: u32 = 6;
+
+/// Gossip topics kept in lite mode
+pub const LITE_MODE_TOPICS: &[&str] = &["sync"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionType {
+    Wifi,
+    Ethernet,
+    Cellular,
+    Other,
+    Offline,
+}
+
+/// Battery and network state reported by the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceConditions {
+    /// None when unknown
+    pub battery_percent: Option<u8>,
+    pub charging: bool,
+    pub connection: ConnectionType,
+    /// The OS considers the connection metered (cellular always is)
+    pub metered: bool,
+}
+
+impl Default for DeviceConditions {
+    fn default() -> Self {
+        Self { battery_percent: None, charging: false, connection: ConnectionType::Wifi, metered: false }
+    }
+}
+
+/// How the node adapts to the device conditions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PowerPolicy {
+    pub low_power: bool,
+    pub metered: bool,
+}
+
+impl PowerPolicy {
+    pub fn for_conditions(conditions: &DeviceConditions) -> Self {
+        let low_battery = conditions
+            .battery_percent
+            .is_some_and(|percent| percent <= LOW_BATTERY_PERCENT);
+        Self {
+            low_power: low_battery && !conditions.charging,
+            metered: conditions.metered || conditions.connection == ConnectionType::Cellular,
+        }
+    }
+
+    /// Hold automatic full syncs until conditions improve
+    pub fn defer_sync(&self) -> bool {
+        self.low_power || self.metered
+    }
+
+    /// Offer and fetch snapshots as blobs
+    pub fn allow_blob_transfers(&self) -> bool {
+        !self.metered
+    }
+
+    /// `config` adjusted to this policy
+    pub fn apply(&self, config: &NodeConfig) -> NodeConfig {
+        let mut config = config.clone();
+        if self.metered {
+            config.announce_interval *= METERED_SLOWDOWN;
+            config.latency_sweep_interval *= METERED_SLOWDOWN;
+        }
+        if self.low_power {
+            config.announce_interval *= LOW_POWER_SLOWDOWN;
+            config.latency_sweep_interval *= LOW_POWER_SLOWDOWN;
+            config.pinned_peers_check_interval *= LOW_POWER_SLOWDOWN;
+            config.isolation_check_interval *= LOW_POWER_SLOWDOWN;
+            config.dial_interval *= LOW_POWER_SLOWDOWN;
+            config.max_connection_attempts_per_cycle =
+                (config.max_connection_attempts_per_cycle / LOW_POWER_SLOWDOWN).max(1);
+        }
+        config.announce_interval = config.announce_interval.min(MAX_ANNOUNCE_INTERVAL);
+        config
+    }
+}
+
+/// Power mode picked by the user, on top of the `PowerPolicy`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PowerMode {
+    #[default]
+    Normal,
+    /// Sync topic only, through one bootstrap peer, with periodic work
+    /// slowed down by `LITE_SLOWDOWN`
+    Lite,
+}
+
+impl PowerMode {
+    pub fn is_lite(&self) -> bool {
+        *self == Self::Lite
+    }
+
+    /// `config` adjusted to this mode
+    pub fn apply(&self, config: &NodeConfig) -> NodeConfig {
+        let mut config = config.clone();
+        if self.is_lite() {
+            config.announce_interval = (config.announce_interval * LITE_SLOWDOWN).min(MAX_ANNOUNCE_INTERVAL);
+            config.latency_sweep_interval *= LITE_SLOWDOWN;
+            config.pinned_peers_check_interval *= LITE_SLOWDOWN;
+            config.isolation_check_interval *= LITE_SLOWDOWN;
+            config.dial_interval *= LITE_SLOWDOWN;
+            config.max_connection_attempts_per_cycle = 1;
+        }
+        config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_power_policy() {
+        let config = NodeConfig::default();
+        let normal = PowerPolicy::for_conditions(&DeviceConditions::default());
+        assert_eq!(normal, PowerPolicy::default());
+        assert!(!normal.defer_sync());
+        assert_eq!(normal.apply(&config), config);
+
+        let low = DeviceConditions { battery_percent: Some(LOW_BATTERY_PERCENT), ..Default::default() };
+        let low_power = PowerPolicy::for_conditions(&low);
+        assert!(low_power.low_power && !low_power.metered);
+        assert!(low_power.defer_sync());
+        assert!(low_power.allow_blob_transfers());
+        let slowed = low_power.apply(&config);
+        assert_eq!(slowed.announce_interval, config.announce_interval * LOW_POWER_SLOWDOWN);
+        assert_eq!(slowed.max_connection_attempts_per_cycle, 2);
+        slowed.validate().unwrap();
+
+        // Charging isn't low power
+        assert!(!PowerPolicy::for_conditions(&DeviceConditions { charging: true, ..low }).low_power);
+
+        let cellular = PowerPolicy::for_conditions(&DeviceConditions {
+            connection: ConnectionType::Cellular,
+            ..Default::default()
+        });
+        assert!(cellular.metered && !cellular.allow_blob_transfers());
+        assert_eq!(cellular.apply(&config).pinned_peers_check_interval, config.pinned_peers_check_interval);
+
+        // Both, with the announce interval capped so peers don't expire us
+        let config = config.with_announce_interval(Duration::from_secs(60));
+        let both = PowerPolicy { low_power: true, metered: true }.apply(&config);
+        assert_eq!(both.announce_interval, MAX_ANNOUNCE_INTERVAL);
+        both.validate().unwrap();
+    }
+
+    #[test]
+    fn test_lite_mode() {
+        let config = NodeConfig::default();
+        assert_eq!(PowerMode::Normal.apply(&config), config);
+
+        let lite = PowerMode::Lite.apply(&config);
+        assert_eq!(lite.pinned_peers_check_interval, config.pinned_peers_check_interval * LITE_SLOWDOWN);
+        assert_eq!(lite.isolation_check_interval, config.isolation_check_interval * LITE_SLOWDOWN);
+        assert_eq!(lite.max_connection_attempts_per_cycle, 1);
+        lite.validate().unwrap();
+
+        // Stacks with the power policy
+        let both = PowerPolicy { low_power: true, metered: false }.apply(&lite);
+        assert_eq!(both.latency_sweep_interval, config.latency_sweep_interval * LITE_SLOWDOWN * LOW_POWER_SLOWDOWN);
+        both.validate().unwrap();
+    }
+}
@@ -0,0 +1,233 @@
+//! Online/offline presence of peers
+//!
+//! Every node broadcasts a signed `PresenceHeartbeat` with its status string
+//! on the discovery topic each `HEARTBEAT_INTERVAL`, and a final offline one
+//! when it stops. A peer whose heartbeats stop for `PRESENCE_TIMEOUT` is
+//! considered offline, much sooner than discovery expires it.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+
+use crate::crypto;
+use crate::discovery::ANNOUNCEMENT_MAX_SKEW_MS;
+
+/// How often our heartbeat is broadcast
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Silence after which a peer is offline (three missed heartbeats)
+pub const PRESENCE_TIMEOUT: Duration = Duration::from_secs(45);
+
+/// Longest status string
+pub const MAX_STATUS_LENGTH: usize = 256;
+
+/// Signed presence of a node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresenceHeartbeat {
+    pub node_id: String,
+    pub online: bool,
+    pub status: Option<String>,
+    /// Unix ms
+    pub timestamp: i64,
+    pub signature: String,
+}
+
+impl PresenceHeartbeat {
+    /// Heartbeat of `node_id`, signed with its key
+    pub fn new(signing_key: &SigningKey, node_id: String, online: bool, status: Option<String>) -> Self {
+        let mut heartbeat = Self {
+            node_id,
+            online,
+            status,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            signature: String::new(),
+        };
+        heartbeat.signature = crypto::sign_message(signing_key, heartbeat.signing_message().as_bytes());
+        heartbeat
+    }
+
+    fn signing_message(&self) -> String {
+        format!(
+            "presence:{}:{}:{}:{}",
+            self.node_id,
+            self.online,
+            self.timestamp,
+            self.status.as_deref().unwrap_or("")
+        )
+    }
+
+    /// Whether the heartbeat is signed by the key of `node_id`
+    pub fn verify(&self) -> Result<bool> {
+        let node_id: iroh::EndpointId = self.node_id.parse().map_err(|e| anyhow!("Invalid node ID: {}", e))?;
+        crypto::verify_signature(&hex::encode(node_id.as_bytes()), self.signing_message().as_bytes(), &self.signature)
+    }
+}
+
+/// What we know about a peer's presence
+#[derive(Debug, Clone, PartialEq)]
+pub struct Presence {
+    pub online: bool,
+    pub status: Option<String>,
+    /// Unix ms of the last heartbeat
+    pub last_seen: i64,
+}
+
+/// A peer came online, went offline or changed its status
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresenceChange {
+    pub peer_id: String,
+    pub online: bool,
+    pub status: Option<String>,
+}
+
+#[derive(Debug)]
+struct PeerPresence {
+    presence: Presence,
+    received_at: Instant,
+}
+
+/// Our status and the presence of every peer heard from
+#[derive(Debug)]
+pub struct PresenceTracker {
+    local_node_id: String,
+    local_status: Option<String>,
+    peers: HashMap<String, PeerPresence>,
+}
+
+impl PresenceTracker {
+    pub fn new(local_node_id: String, local_status: Option<String>) -> Self {
+        Self { local_node_id, local_status, peers: HashMap::new() }
+    }
+
+    /// Status broadcast in our heartbeats
+    pub fn local_status(&self) -> Option<String> {
+        self.local_status.clone()
+    }
+
+    pub fn set_local_status(&mut self, status: Option<String>) -> Result<()> {
+        if status.as_ref().is_some_and(|status| status.len() > MAX_STATUS_LENGTH) {
+            return Err(anyhow!("Status is longer than {} bytes", MAX_STATUS_LENGTH));
+        }
+        self.local_status = status;
+        Ok(())
+    }
+
+    pub fn get(&self, peer_id: &str) -> Option<Presence> {
+        self.peers.get(peer_id).map(|peer| peer.presence.clone())
+    }
+
+    /// Apply a heartbeat; the change it makes, if any
+    pub fn process_heartbeat(&mut self, heartbeat: &PresenceHeartbeat) -> Result<Option<PresenceChange>> {
+        if heartbeat.node_id == self.local_node_id {
+            return Ok(None);
+        }
+        if (chrono::Utc::now().timestamp_millis() - heartbeat.timestamp).abs() > ANNOUNCEMENT_MAX_SKEW_MS {
+            return Ok(None);
+        }
+        if heartbeat.status.as_ref().is_some_and(|status| status.len() > MAX_STATUS_LENGTH) {
+            return Err(anyhow!("Status from {} is too long", heartbeat.node_id));
+        }
+        let previous = self.peers.get(&heartbeat.node_id).map(|peer| &peer.presence);
+        // Replayed or reordered
+        if previous.is_some_and(|previous| previous.last_seen >= heartbeat.timestamp) {
+            return Ok(None);
+        }
+        if !heartbeat.verify()? {
+            return Err(anyhow!("Invalid presence signature from {}", heartbeat.node_id));
+        }
+
+        let changed = previous.is_none_or(|previous| {
+            previous.online != heartbeat.online || previous.status != heartbeat.status
+        });
+        let presence = Presence {
+            online: heartbeat.online,
+            status: heartbeat.status.clone(),
+            last_seen: heartbeat.timestamp,
+        };
+        self.peers.insert(heartbeat.node_id.clone(), PeerPresence { presence, received_at: Instant::now() });
+
+        Ok(changed.then(|| PresenceChange {
+            peer_id: heartbeat.node_id.clone(),
+            online: heartbeat.online,
+            status: heartbeat.status.clone(),
+        }))
+    }
+
+    /// Mark peers silent for `PRESENCE_TIMEOUT` at `now` offline
+    pub fn sweep(&mut self, now: Instant) -> Vec<PresenceChange> {
+        self.peers
+            .iter_mut()
+            .filter(|(_, peer)| {
+                peer.presence.online && now.saturating_duration_since(peer.received_at) >= PRESENCE_TIMEOUT
+            })
+            .map(|(peer_id, peer)| {
+                peer.presence.online = false;
+                PresenceChange { peer_id: peer_id.clone(), online: false, status: peer.presence.status.clone() }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity() -> (SigningKey, String) {
+        let (signing_key, _) = crypto::generate_keypair();
+        let node_id = iroh::PublicKey::from_bytes(signing_key.verifying_key().as_bytes()).unwrap().to_string();
+        (signing_key, node_id)
+    }
+
+    /// Heartbeat `offset_ms` after now
+    fn heartbeat(key: &SigningKey, node_id: &str, online: bool, status: Option<&str>, offset_ms: i64) -> PresenceHeartbeat {
+        let mut heartbeat = PresenceHeartbeat::new(key, node_id.to_string(), online, status.map(str::to_string));
+        heartbeat.timestamp += offset_ms;
+        heartbeat.signature = crypto::sign_message(key, heartbeat.signing_message().as_bytes());
+        heartbeat
+    }
+
+    #[test]
+    fn test_presence_tracking() {
+        let (local_key, local) = identity();
+        let (peer_key, peer) = identity();
+        let mut tracker = PresenceTracker::new(local.clone(), None);
+
+        let online = heartbeat(&peer_key, &peer, true, Some("busy"), 0);
+        let change = tracker.process_heartbeat(&online).unwrap().unwrap();
+        assert!(change.online);
+        assert_eq!(change.status.as_deref(), Some("busy"));
+        assert_eq!(tracker.get(&peer).unwrap().status.as_deref(), Some("busy"));
+
+        // Replays and unchanged heartbeats
+        assert_eq!(tracker.process_heartbeat(&online).unwrap(), None);
+        assert_eq!(tracker.process_heartbeat(&heartbeat(&peer_key, &peer, true, Some("busy"), 1)).unwrap(), None);
+
+        // Forged by another key
+        let (other_key, _) = identity();
+        assert!(tracker.process_heartbeat(&heartbeat(&other_key, &peer, false, None, 2)).is_err());
+        assert!(tracker.get(&peer).unwrap().online);
+
+        // Timeout
+        assert!(tracker.sweep(Instant::now()).is_empty());
+        let later = Instant::now() + PRESENCE_TIMEOUT;
+        let changes = tracker.sweep(later);
+        assert_eq!(changes.len(), 1);
+        assert!(!changes[0].online);
+        assert!(tracker.sweep(later).is_empty());
+
+        // Back online, then an explicit offline heartbeat when the peer stops
+        assert!(tracker.process_heartbeat(&heartbeat(&peer_key, &peer, true, None, 3)).unwrap().unwrap().online);
+        assert!(!tracker.process_heartbeat(&heartbeat(&peer_key, &peer, false, None, 4)).unwrap().unwrap().online);
+        assert!(!tracker.get(&peer).unwrap().online);
+
+        // Own heartbeats are ignored
+        assert_eq!(tracker.process_heartbeat(&heartbeat(&local_key, &local, true, None, 0)).unwrap(), None);
+
+        assert!(tracker.set_local_status(Some("x".repeat(MAX_STATUS_LENGTH + 1))).is_err());
+        tracker.set_local_status(Some("away".to_string())).unwrap();
+        assert_eq!(tracker.local_status().as_deref(), Some("away"));
+    }
+}
@@ -0,0 +1,133 @@
+//! Private networks
+//!
+//! Nodes sharing a network key form their own network: every gossip topic ID
+//! is a keyed hash of the public topic, so nodes without the key never meet
+//! ours on gossip, and discovery announcements carry a tag over their signed
+//! content proving the announcer holds the key. The key is derived from a
+//! passphrase the user shares with the other members and kept in
+//! `NETWORK_KEY_FILE` next to the node key.
+//!
+//! Topic IDs are visible to every gossip observer, so the passphrase is
+//! stretched with Argon2id to keep it from being guessed offline. The salt is
+//! fixed (and versioned) so every member derives the same key.
+
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use argon2::Argon2;
+
+/// Network key file in the data directory (absent on the public network)
+pub const NETWORK_KEY_FILE: &str = "network_key";
+
+/// Argon2id salt shared by all private networks
+const KEY_SALT: &[u8] = b"cyberfly mobile-node private network key v2";
+
+/// Key of a private network
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NetworkKey([u8; 32]);
+
+impl std::fmt::Debug for NetworkKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NetworkKey({})", self.id())
+    }
+}
+
+impl NetworkKey {
+    /// Derive the key of the network with this passphrase
+    pub fn from_passphrase(passphrase: &str) -> Result<Self> {
+        if passphrase.trim().is_empty() {
+            return Err(anyhow!("Network passphrase is empty"));
+        }
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(passphrase.as_bytes(), KEY_SALT, &mut key)
+            .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+        Ok(Self(key))
+    }
+
+    /// Key stored in `data_dir`, if the node is in a private network
+    pub fn load(data_dir: &Path) -> Result<Option<Self>> {
+        let path = data_dir.join(NETWORK_KEY_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes: [u8; 32] = std::fs::read(&path)?
+            .try_into()
+            .map_err(|_| anyhow!("Invalid network key file"))?;
+        Ok(Some(Self(bytes)))
+    }
+
+    /// Store `key` in `data_dir`, or remove it to rejoin the public network
+    pub fn store(data_dir: &Path, key: Option<&Self>) -> Result<()> {
+        let path = data_dir.join(NETWORK_KEY_FILE);
+        match key {
+            Some(key) => {
+                std::fs::create_dir_all(data_dir)?;
+                std::fs::write(&path, key.0)?;
+            }
+            None if path.exists() => std::fs::remove_file(&path)?,
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Short public identifier of the network, safe to show and log
+    pub fn id(&self) -> String {
+        hex::encode(&blake3::hash(&self.0).as_bytes()[..4])
+    }
+
+    /// Topic ID of `base` in this network
+    pub fn topic(&self, base: &[u8; 32]) -> [u8; 32] {
+        *blake3::keyed_hash(&self.0, base).as_bytes()
+    }
+
+    /// Membership tag over `message` (hex)
+    pub fn tag(&self, message: &[u8]) -> String {
+        blake3::keyed_hash(&self.0, message).to_hex().to_string()
+    }
+
+    /// Whether `tag` was made with this key over `message`
+    pub fn verify_tag(&self, message: &[u8], tag: &str) -> bool {
+        // `blake3::Hash` compares in constant time
+        blake3::Hash::from_hex(tag).is_ok_and(|tag| tag == blake3::keyed_hash(&self.0, message))
+    }
+}
+
+/// Topic ID of `base` in the network the node is in
+pub fn topic_bytes(base: &[u8; 32], key: Option<&NetworkKey>) -> [u8; 32] {
+    key.map_or(*base, |key| key.topic(base))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_network_key() {
+        let key = NetworkKey::from_passphrase("family network").unwrap();
+        let other = NetworkKey::from_passphrase("office network").unwrap();
+        assert!(NetworkKey::from_passphrase("  ").is_err());
+
+        let base = b"decentralized-db-data-v1-iroh!!!";
+        assert_eq!(topic_bytes(base, None), *base);
+        assert_ne!(topic_bytes(base, Some(&key)), *base);
+        assert_ne!(key.topic(base), other.topic(base));
+        // Members on any device and version derive the same topics
+        assert_eq!(key, NetworkKey::from_passphrase("family network").unwrap());
+        assert_eq!(hex::encode(key.topic(base)), "e0169b8c7046689e19ec0362b0bb612a9c392c032c5a748205ba879abe0ad80e");
+
+        let tag = key.tag(b"announcement");
+        assert!(key.verify_tag(b"announcement", &tag));
+        assert!(!key.verify_tag(b"announcement!", &tag));
+        assert!(!other.verify_tag(b"announcement", &tag));
+        assert!(!key.verify_tag(b"announcement", "not hex"));
+
+        let dir = tempdir().unwrap();
+        assert_eq!(NetworkKey::load(dir.path()).unwrap(), None);
+        NetworkKey::store(dir.path(), Some(&key)).unwrap();
+        assert_eq!(NetworkKey::load(dir.path()).unwrap(), Some(key));
+        NetworkKey::store(dir.path(), None).unwrap();
+        assert_eq!(NetworkKey::load(dir.path()).unwrap(), None);
+    }
+}
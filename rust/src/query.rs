@@ -0,0 +1,259 @@
+//! Structured queries over JSON values
+//!
+//! A query scans one database (optionally only keys with a given prefix),
+//! keeps entries whose JSON value matches every field predicate, sorts them by
+//! a field and returns one window of the results, optionally projected to a
+//! few fields. Everything runs here so large datasets never cross the FFI
+//! boundary. Internal keys (`__` prefix) and values that aren't JSON objects
+//! never match a query with predicates, sorting or projection.
+
+use std::cmp::Ordering;
+
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+
+use crate::storage::{Storage, MAX_SCAN_LIMIT};
+
+/// Upper bound on entries returned by one query
+pub const MAX_QUERY_LIMIT: usize = 1000;
+
+/// Comparison of a field against a value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl FilterOp {
+    pub fn parse(op: &str) -> Result<Self> {
+        Ok(match op {
+            "eq" => Self::Eq,
+            "ne" => Self::Ne,
+            "lt" => Self::Lt,
+            "lte" => Self::Lte,
+            "gt" => Self::Gt,
+            "gte" => Self::Gte,
+            other => return Err(anyhow!("Unknown filter operator {}", other)),
+        })
+    }
+}
+
+/// Predicate on one field (dotted path into nested objects)
+#[derive(Debug, Clone)]
+pub struct FieldFilter {
+    pub field: String,
+    pub op: FilterOp,
+    pub value: Value,
+}
+
+impl FieldFilter {
+    fn matches(&self, document: &Value) -> bool {
+        let Some(actual) = field(document, &self.field) else {
+            return false;
+        };
+        match self.op {
+            FilterOp::Eq => json_eq(actual, &self.value),
+            FilterOp::Ne => !json_eq(actual, &self.value),
+            op => match compare(actual, &self.value) {
+                Some(ordering) => match op {
+                    FilterOp::Lt => ordering == Ordering::Less,
+                    FilterOp::Lte => ordering != Ordering::Greater,
+                    FilterOp::Gt => ordering == Ordering::Greater,
+                    _ => ordering != Ordering::Less,
+                },
+                None => false,
+            },
+        }
+    }
+}
+
+/// What to query
+#[derive(Debug, Clone, Default)]
+pub struct QuerySpec {
+    pub key_prefix: Option<String>,
+    /// All must match
+    pub filters: Vec<FieldFilter>,
+    /// Sort by this field (entries without it come last); key order otherwise
+    pub sort_by: Option<String>,
+    pub descending: bool,
+    /// Clamped to `1..=MAX_QUERY_LIMIT`
+    pub limit: usize,
+    pub offset: usize,
+    /// Return only these fields, keyed by their path
+    pub fields: Option<Vec<String>>,
+}
+
+impl QuerySpec {
+    /// Whether values must be parsed as JSON
+    fn needs_json(&self) -> bool {
+        !self.filters.is_empty() || self.sort_by.is_some() || self.fields.is_some()
+    }
+}
+
+/// One window of query results
+#[derive(Debug, Clone, Default)]
+pub struct QueryResult {
+    /// (key, value) in result order
+    pub entries: Vec<(String, Vec<u8>)>,
+    /// Whether more results follow the window
+    pub has_more: bool,
+}
+
+/// Run a query against one database
+pub fn run(storage: &Storage, db_name: &str, spec: &QuerySpec) -> Result<QueryResult> {
+    let limit = spec.limit.clamp(1, MAX_QUERY_LIMIT);
+    // Without sorting, results are in key order and the scan can stop early
+    let wanted = spec.sort_by.is_none().then(|| spec.offset + limit + 1);
+
+    let prefix = spec.key_prefix.as_deref().unwrap_or("");
+    let mut matches: Vec<(String, Vec<u8>, Option<Value>)> = Vec::new();
+    let mut cursor: Option<String> = None;
+    'scan: loop {
+        let page = storage.scan_prefix(db_name, prefix, MAX_SCAN_LIMIT, cursor.as_deref())?;
+        for (key, value) in page.entries {
+            if key.starts_with("__") {
+                continue;
+            }
+            let document = if spec.needs_json() {
+                match serde_json::from_slice::<Value>(&value) {
+                    Ok(document @ Value::Object(_)) => Some(document),
+                    _ => continue,
+                }
+            } else {
+                None
+            };
+            if let Some(document) = &document {
+                if !spec.filters.iter().all(|filter| filter.matches(document)) {
+                    continue;
+                }
+            }
+            matches.push((key, value, document));
+            if wanted.is_some_and(|wanted| matches.len() >= wanted) {
+                break 'scan;
+            }
+        }
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    if let Some(sort_by) = &spec.sort_by {
+        // Stable sort keeps key order among equal values
+        matches.sort_by(|(_, _, a), (_, _, b)| {
+            let a = a.as_ref().and_then(|doc| field(doc, sort_by));
+            let b = b.as_ref().and_then(|doc| field(doc, sort_by));
+            match (a, b) {
+                (Some(a), Some(b)) => {
+                    let ordering = compare(a, b).unwrap_or(Ordering::Equal);
+                    if spec.descending { ordering.reverse() } else { ordering }
+                }
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        });
+    }
+
+    let has_more = matches.len() > spec.offset + limit;
+    let mut entries = Vec::with_capacity(limit);
+    for (key, value, document) in matches.into_iter().skip(spec.offset).take(limit) {
+        let value = match (&spec.fields, document) {
+            (Some(fields), Some(document)) => serde_json::to_vec(&project(&document, fields))?,
+            _ => value,
+        };
+        entries.push((key, value));
+    }
+    Ok(QueryResult { entries, has_more })
+}
+
+/// Value at a dotted path
+fn field<'a>(document: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(document, |value, part| value.get(part))
+}
+
+fn project(document: &Value, fields: &[String]) -> Value {
+    let projected: Map<String, Value> = fields
+        .iter()
+        .filter_map(|path| field(document, path).map(|value| (path.clone(), value.clone())))
+        .collect();
+    Value::Object(projected)
+}
+
+/// Equality that treats 1 and 1.0 as the same number
+fn json_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => a == b,
+    }
+}
+
+/// Order of two numbers or two strings; other values don't compare
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_query_filters_sorts_and_projects() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        let people = [
+            ("user:1", json!({"name": "Ada", "age": 36, "address": {"city": "London"}})),
+            ("user:2", json!({"name": "Linus", "age": 28, "address": {"city": "Helsinki"}})),
+            ("user:3", json!({"name": "Grace", "age": 45, "address": {"city": "New York"}})),
+            ("user:4", json!({"name": "Anon"})),
+        ];
+        for (key, value) in &people {
+            storage.put("people", key, value.to_string().as_bytes()).unwrap();
+        }
+        storage.put("people", "team:1", b"not json").unwrap();
+
+        let spec = QuerySpec {
+            key_prefix: Some("user:".to_string()),
+            filters: vec![FieldFilter { field: "age".to_string(), op: FilterOp::Gte, value: json!(30) }],
+            sort_by: Some("age".to_string()),
+            descending: true,
+            limit: 10,
+            fields: Some(vec!["name".to_string(), "address.city".to_string()]),
+            ..Default::default()
+        };
+        let result = run(&storage, "people", &spec).unwrap();
+        let values: Vec<Value> = result.entries.iter().map(|(_, v)| serde_json::from_slice(v).unwrap()).collect();
+        assert_eq!(
+            values,
+            [json!({"name": "Grace", "address.city": "New York"}), json!({"name": "Ada", "address.city": "London"})]
+        );
+        assert!(!result.has_more);
+
+        // Sorting puts entries without the field last; offset/limit window the results
+        let spec = QuerySpec {
+            sort_by: Some("age".to_string()),
+            limit: 2,
+            offset: 1,
+            ..Default::default()
+        };
+        let result = run(&storage, "people", &spec).unwrap();
+        let keys: Vec<&str> = result.entries.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["user:1", "user:3"]);
+        assert!(result.has_more);
+
+        // No predicates: raw values in key order, non-JSON included
+        let result = run(&storage, "people", &QuerySpec { limit: 1, ..Default::default() }).unwrap();
+        assert_eq!(result.entries[0].0, "team:1");
+        assert!(result.has_more);
+    }
+}
@@ -0,0 +1,271 @@
+//! Direct request/response calls between two nodes
+//!
+//! The caller opens a QUIC connection to the peer on `RPC_ALPN` and sends one
+//! postcard-encoded `RpcRequest` per bidirectional stream; the peer answers on
+//! the same stream. Built-in methods (`GET_METHOD`) are answered by the node
+//! itself, every other method is handed to the application's handler (see
+//! `RpcProtocol::set_handler`) and answered through `RpcProtocol::respond`.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use dashmap::DashMap;
+use iroh::endpoint::{Connection, RecvStream, SendStream};
+use iroh::protocol::{AcceptError, ProtocolHandler};
+use iroh::{Endpoint, EndpointId};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::storage::Storage;
+
+/// ALPN of the RPC protocol
+pub const RPC_ALPN: &[u8] = b"/cyberfly/rpc/1";
+
+/// Largest request or response accepted
+pub const MAX_RPC_MESSAGE_SIZE: usize = 1024 * 1024;
+
+/// How long a call waits for its response by default
+pub const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long the application has to answer a request
+const HANDLER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Built-in method reading one key: the payload is a JSON
+/// `{"db_name": .., "key": ..}`, the response the raw value (empty if unset)
+pub const GET_METHOD: &str = "get";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RpcRequest {
+    method: String,
+    payload: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum RpcResponse {
+    Ok(Vec<u8>),
+    Err(String),
+}
+
+#[derive(Deserialize)]
+struct GetParams {
+    db_name: String,
+    key: String,
+}
+
+/// Request from a peer for the application to answer
+#[derive(Debug, Clone)]
+pub struct IncomingRpc {
+    pub request_id: u64,
+    pub peer_id: String,
+    pub method: String,
+    pub payload: Vec<u8>,
+}
+
+/// Serves RPC requests from peers
+#[derive(Clone)]
+pub struct RpcProtocol {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    storage: Storage,
+    next_request_id: AtomicU64,
+    handler: RwLock<Option<mpsc::UnboundedSender<IncomingRpc>>>,
+    pending: DashMap<u64, oneshot::Sender<RpcResponse>>,
+}
+
+impl std::fmt::Debug for RpcProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RpcProtocol")
+            .field("pending", &self.inner.pending.len())
+            .finish()
+    }
+}
+
+impl RpcProtocol {
+    pub fn new(storage: Storage) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                storage,
+                next_request_id: AtomicU64::new(1),
+                handler: RwLock::new(None),
+                pending: DashMap::new(),
+            }),
+        }
+    }
+
+    /// Route requests for non-built-in methods to the returned receiver,
+    /// replacing the previous handler
+    pub fn set_handler(&self) -> mpsc::UnboundedReceiver<IncomingRpc> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        *self.inner.handler.write() = Some(tx);
+        rx
+    }
+
+    /// Answer request `request_id`; false if it's unknown or timed out
+    pub fn respond(&self, request_id: u64, result: Result<Vec<u8>, String>) -> bool {
+        let Some((_, tx)) = self.inner.pending.remove(&request_id) else {
+            return false;
+        };
+        let response = match result {
+            Ok(payload) => RpcResponse::Ok(payload),
+            Err(message) => RpcResponse::Err(message),
+        };
+        tx.send(response).is_ok()
+    }
+
+    async fn handle(&self, peer_id: String, request: RpcRequest) -> RpcResponse {
+        if request.method == GET_METHOD {
+            return match self.get(&request.payload) {
+                Ok(value) => RpcResponse::Ok(value),
+                Err(e) => RpcResponse::Err(e.to_string()),
+            };
+        }
+
+        let request_id = self.inner.next_request_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = oneshot::channel();
+        self.inner.pending.insert(request_id, tx);
+        let incoming = IncomingRpc { request_id, peer_id, method: request.method, payload: request.payload };
+        let method = incoming.method.clone();
+        let delivered = {
+            let mut handler = self.inner.handler.write();
+            let delivered = handler.as_ref().is_some_and(|handler| handler.send(incoming).is_ok());
+            if !delivered {
+                // The application stopped listening
+                *handler = None;
+            }
+            delivered
+        };
+        if !delivered {
+            self.inner.pending.remove(&request_id);
+            return RpcResponse::Err(format!("Unknown method {}", method));
+        }
+
+        let response = tokio::time::timeout(HANDLER_TIMEOUT, rx).await;
+        self.inner.pending.remove(&request_id);
+        match response {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => RpcResponse::Err(format!("No response to {}", method)),
+            Err(_) => RpcResponse::Err(format!("Handling {} timed out", method)),
+        }
+    }
+
+    fn get(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        let params: GetParams = serde_json::from_slice(payload)?;
+        // Don't create trees for (or expose) databases we don't have
+        if !self.inner.storage.list_databases()?.contains(&params.db_name) {
+            return Ok(Vec::new());
+        }
+        Ok(self.inner.storage.get(&params.db_name, &params.key)?.unwrap_or_default())
+    }
+
+    async fn serve_stream(&self, peer_id: String, mut send: SendStream, mut recv: RecvStream) -> Result<()> {
+        let request: RpcRequest = postcard::from_bytes(&recv.read_to_end(MAX_RPC_MESSAGE_SIZE).await?)?;
+        let response = self.handle(peer_id, request).await;
+        let mut encoded = postcard::to_allocvec(&response)?;
+        if encoded.len() > MAX_RPC_MESSAGE_SIZE {
+            encoded = postcard::to_allocvec(&RpcResponse::Err(format!("Response of {} bytes is too large", encoded.len())))?;
+        }
+        send.write_all(&encoded).await?;
+        send.finish()?;
+        Ok(())
+    }
+}
+
+impl ProtocolHandler for RpcProtocol {
+    async fn accept(&self, connection: Connection) -> Result<(), AcceptError> {
+        let peer_id = connection.remote_id().to_string();
+        // One request per stream until the caller closes the connection
+        while let Ok((send, recv)) = connection.accept_bi().await {
+            let protocol = self.clone();
+            let peer_id = peer_id.clone();
+            tokio::spawn(async move {
+                if let Err(e) = protocol.serve_stream(peer_id.clone(), send, recv).await {
+                    tracing::debug!(%peer_id, "RPC stream failed: {}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Call `method` on `peer_id` and wait up to `timeout` for its response
+pub async fn call(
+    endpoint: &Endpoint,
+    peer_id: EndpointId,
+    method: String,
+    payload: Vec<u8>,
+    timeout: Duration,
+) -> Result<Vec<u8>> {
+    let request = postcard::to_allocvec(&RpcRequest { method, payload })?;
+    if request.len() > MAX_RPC_MESSAGE_SIZE {
+        return Err(anyhow!("Request of {} bytes is too large", request.len()));
+    }
+
+    let response = tokio::time::timeout(timeout, async {
+        let conn = endpoint.connect(peer_id, RPC_ALPN).await?;
+        let (mut send, mut recv) = conn.open_bi().await?;
+        send.write_all(&request).await?;
+        send.finish()?;
+        let response = recv.read_to_end(MAX_RPC_MESSAGE_SIZE).await?;
+        conn.close(0u32.into(), b"done");
+        anyhow::Ok(response)
+    })
+    .await
+    .map_err(|_| anyhow!("RPC to {} timed out", peer_id))??;
+
+    match postcard::from_bytes(&response)? {
+        RpcResponse::Ok(payload) => Ok(payload),
+        RpcResponse::Err(message) => Err(anyhow!(message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn request(method: &str, payload: &[u8]) -> RpcRequest {
+        RpcRequest { method: method.to_string(), payload: payload.to_vec() }
+    }
+
+    #[tokio::test]
+    async fn test_rpc_handling() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        storage.put("notes", "a", b"hello").unwrap();
+        let rpc = RpcProtocol::new(storage.clone());
+
+        // Built-in get
+        let get = |db_name: &str, key: &str| {
+            request(GET_METHOD, format!(r#"{{"db_name":"{}","key":"{}"}}"#, db_name, key).as_bytes())
+        };
+        assert!(matches!(rpc.handle("p".into(), get("notes", "a")).await, RpcResponse::Ok(v) if v == b"hello"));
+        assert!(matches!(rpc.handle("p".into(), get("notes", "b")).await, RpcResponse::Ok(v) if v.is_empty()));
+        assert!(matches!(rpc.handle("p".into(), get("missing", "a")).await, RpcResponse::Ok(v) if v.is_empty()));
+        assert!(!storage.list_databases().unwrap().contains(&"missing".to_string()));
+        assert!(matches!(rpc.handle("p".into(), request(GET_METHOD, b"{")).await, RpcResponse::Err(_)));
+
+        // No application handler
+        assert!(matches!(rpc.handle("p".into(), request("echo", b"x")).await, RpcResponse::Err(_)));
+
+        // Application handler
+        let mut incoming = rpc.set_handler();
+        let responder = rpc.clone();
+        tokio::spawn(async move {
+            while let Some(req) = incoming.recv().await {
+                let result = match req.method.as_str() {
+                    "echo" => Ok(req.payload),
+                    _ => Err(format!("{} not supported", req.method)),
+                };
+                assert!(responder.respond(req.request_id, result));
+            }
+        });
+        assert!(matches!(rpc.handle("p".into(), request("echo", b"x")).await, RpcResponse::Ok(v) if v == b"x"));
+        assert!(matches!(rpc.handle("p".into(), request("other", b"")).await, RpcResponse::Err(e) if e == "other not supported"));
+        assert!(!rpc.respond(999, Ok(Vec::new())));
+    }
+}
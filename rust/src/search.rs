@@ -0,0 +1,156 @@
+//! Full-text search over stored values
+//!
+//! String values, and the string and number leaves of JSON values, are split
+//! into lowercase alphanumeric tokens and kept in an inverted index that
+//! `Storage` maintains on every write to a user database, whether local or
+//! applied by sync. Encrypted values and internal keys (`__` prefix) are not
+//! indexed.
+//!
+//! Index layout: postings are `db \0 token \0 key` (empty value), and each
+//! indexed key's token set is kept under `db \0 key` so an update can remove
+//! the postings it no longer has.
+
+use std::collections::BTreeSet;
+
+use crate::crypto;
+
+/// Shorter tokens are not indexed (except as a query prefix)
+pub const MIN_TOKEN_CHARS: usize = 2;
+
+/// Longer tokens are truncated
+pub const MAX_TOKEN_CHARS: usize = 64;
+
+/// Upper bound on hits returned by one search
+pub const MAX_SEARCH_LIMIT: usize = 100;
+
+/// Bump when tokenization changes so existing indexes are rebuilt on open
+pub const SEARCH_INDEX_VERSION: u32 = 1;
+
+const SEPARATOR: u8 = 0;
+
+/// Separates tokens in a stored token set
+const TOKEN_LIST_SEPARATOR: char = ' ';
+
+/// Tokens to index for a value written under `key`
+pub fn index_tokens(key: &str, value: &[u8]) -> BTreeSet<String> {
+    let Ok(text) = std::str::from_utf8(value) else {
+        return BTreeSet::new();
+    };
+    if key.starts_with("__") || crypto::is_encrypted_value(text) {
+        return BTreeSet::new();
+    }
+
+    let mut tokens = BTreeSet::new();
+    match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(json) => collect_json_tokens(&json, &mut tokens),
+        Err(_) => tokens.extend(tokenize(text)),
+    }
+    tokens
+}
+
+fn collect_json_tokens(value: &serde_json::Value, tokens: &mut BTreeSet<String>) {
+    match value {
+        serde_json::Value::String(s) => tokens.extend(tokenize(s)),
+        serde_json::Value::Number(n) => tokens.extend(tokenize(&n.to_string())),
+        serde_json::Value::Array(items) => items.iter().for_each(|item| collect_json_tokens(item, tokens)),
+        serde_json::Value::Object(fields) => fields.values().for_each(|field| collect_json_tokens(field, tokens)),
+        serde_json::Value::Bool(_) | serde_json::Value::Null => {}
+    }
+}
+
+/// Lowercase alphanumeric words of `text` worth indexing
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    words(text).filter(|word| word.chars().count() >= MIN_TOKEN_CHARS)
+}
+
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.chars().take(MAX_TOKEN_CHARS).collect::<String>().to_lowercase())
+}
+
+/// Query terms: every term must match; the last one matches as a prefix so
+/// results update while the user types
+pub fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = words(query).collect();
+    let last = terms.pop();
+    terms.retain(|term| term.chars().count() >= MIN_TOKEN_CHARS);
+    let mut seen = BTreeSet::new();
+    terms.retain(|term| seen.insert(term.clone()));
+    terms.extend(last);
+    terms
+}
+
+/// Posting of `key` under `token`
+pub fn posting_key(db_name: &str, token: &str, key: &str) -> Vec<u8> {
+    let mut posting = token_prefix(db_name, token);
+    posting.push(SEPARATOR);
+    posting.extend_from_slice(key.as_bytes());
+    posting
+}
+
+/// Prefix of every posting of tokens starting with `token`
+pub fn token_prefix(db_name: &str, token: &str) -> Vec<u8> {
+    let mut prefix = db_prefix(db_name);
+    prefix.extend_from_slice(token.as_bytes());
+    prefix
+}
+
+/// Prefix of every posting and token set of a database
+pub fn db_prefix(db_name: &str) -> Vec<u8> {
+    let mut prefix = db_name.as_bytes().to_vec();
+    prefix.push(SEPARATOR);
+    prefix
+}
+
+/// Key of the posting (its database prefix already stripped)
+pub fn posting_target(posting_suffix: &[u8]) -> Option<String> {
+    let at = posting_suffix.iter().position(|&b| b == SEPARATOR)?;
+    String::from_utf8(posting_suffix[at + 1..].to_vec()).ok()
+}
+
+/// Key of an indexed value's token set
+pub fn doc_key(db_name: &str, key: &str) -> Vec<u8> {
+    let mut doc = db_prefix(db_name);
+    doc.extend_from_slice(key.as_bytes());
+    doc
+}
+
+pub fn encode_tokens(tokens: &BTreeSet<String>) -> Vec<u8> {
+    tokens
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(&TOKEN_LIST_SEPARATOR.to_string())
+        .into_bytes()
+}
+
+pub fn decode_tokens(bytes: &[u8]) -> BTreeSet<String> {
+    String::from_utf8_lossy(bytes)
+        .split(TOKEN_LIST_SEPARATOR)
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_tokens() {
+        let tokens = index_tokens("note", br#"{"title": "Hello, World!", "tags": ["Rust", "a"], "n": 42}"#);
+        assert_eq!(tokens, ["42", "hello", "rust", "world"].map(String::from).into());
+
+        assert_eq!(index_tokens("note", b"Grocery list: eggs").len(), 3);
+        assert!(index_tokens("__counter__:n:r", b"text").is_empty());
+        assert!(index_tokens("secret", b"enc:v1:AAAA").is_empty());
+        assert!(index_tokens("bin", &[0xff, 0xfe]).is_empty());
+    }
+
+    #[test]
+    fn test_query_terms_keep_short_last_word_as_prefix() {
+        assert_eq!(query_terms("Hello a hello W"), ["hello", "w"]);
+        assert!(query_terms("  ,. ").is_empty());
+    }
+}
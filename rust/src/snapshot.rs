@@ -0,0 +1,245 @@
+//! Database snapshots over iroh-blobs
+//!
+//! Bootstrapping a new device by streaming every `SignedOperation` through
+//! gossip is slow for large databases. A snapshot is the LWW head of the
+//! oplog for one database (the current state - every op still carries its
+//! own signature, so the snapshot needs no extra trust), zstd-compressed and
+//! stored as a blob. The blob hash is advertised in `SyncMessage::SnapshotOffer`;
+//! the requester downloads it from the provider and applies it in a single
+//! storage transaction.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use iroh::{Endpoint, EndpointId};
+use iroh_blobs::store::fs::FsStore;
+use iroh_blobs::Hash;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::acl::WriterList;
+use crate::sync::SignedOperation;
+
+/// Snapshot encoding version
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Full syncs with at least this many operations are answered with snapshots
+pub const SNAPSHOT_MIN_OPS: usize = 1000;
+
+/// zstd level for snapshot blobs
+const SNAPSHOT_COMPRESSION_LEVEL: i32 = 3;
+
+/// Largest snapshot blob we are willing to download
+const MAX_SNAPSHOT_BLOB_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Upper bound on a decompressed snapshot, guards against zstd bombs
+const MAX_SNAPSHOT_BYTES: usize = 256 * 1024 * 1024;
+
+/// Give up on a snapshot download after this long and fall back to op sync
+const SNAPSHOT_FETCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Current state of one database, as stored in a blob
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseSnapshot {
+    pub version: u32,
+    pub db_name: String,
+    /// Node ID that built the snapshot
+    pub created_by: String,
+    /// Unix timestamp (milliseconds)
+    pub created_at: i64,
+    /// Newest operation timestamp in the snapshot
+    pub head_timestamp: i64,
+    /// Winning (LWW) operation for every key in the database
+    pub operations: Vec<SignedOperation>,
+    /// Owner-signed writer list, applied before the operations
+    #[serde(default)]
+    pub acl: Option<WriterList>,
+}
+
+impl DatabaseSnapshot {
+    /// Serialize and compress for storage as a blob
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let json = serde_json::to_vec(self)?;
+        Ok(zstd::stream::encode_all(&json[..], SNAPSHOT_COMPRESSION_LEVEL)?)
+    }
+
+    /// Decompress and parse a snapshot blob
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut json = Vec::new();
+        zstd::stream::Decoder::new(bytes)?
+            .take(MAX_SNAPSHOT_BYTES as u64 + 1)
+            .read_to_end(&mut json)?;
+        if json.len() > MAX_SNAPSHOT_BYTES {
+            return Err(anyhow!("Snapshot exceeds {} bytes", MAX_SNAPSHOT_BYTES));
+        }
+
+        let snapshot: Self = serde_json::from_slice(&json)?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(anyhow!("Unsupported snapshot version {}", snapshot.version));
+        }
+        if let Some(op) = snapshot.operations.iter().find(|op| op.db_name != snapshot.db_name) {
+            return Err(anyhow!(
+                "Snapshot for {} contains operation {} from {}",
+                snapshot.db_name, op.op_id, op.db_name
+            ));
+        }
+        Ok(snapshot)
+    }
+}
+
+/// Advertised snapshot of one database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    pub db_name: String,
+    /// iroh-blobs hash of the encoded snapshot
+    pub hash: String,
+    /// Encoded (compressed) size in bytes
+    pub size: u64,
+    pub op_count: u64,
+    pub head_timestamp: i64,
+}
+
+/// Builds snapshot blobs for peers and fetches the ones offered to us
+pub struct SnapshotManager {
+    store: FsStore,
+    endpoint: Endpoint,
+    local_node_id: String,
+    /// db_name -> (sync store seq the snapshot was built at, snapshot)
+    cache: parking_lot::Mutex<HashMap<String, (u64, SnapshotInfo)>>,
+}
+
+impl SnapshotManager {
+    pub fn new(store: FsStore, endpoint: Endpoint, local_node_id: String) -> Self {
+        Self {
+            store,
+            endpoint,
+            local_node_id,
+            cache: parking_lot::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Store a snapshot of `db_name` as a blob. `seq` is the sync store
+    /// sequence the operations were read at; an unchanged store reuses the
+    /// previous blob.
+    pub async fn create(
+        &self,
+        db_name: &str,
+        seq: u64,
+        operations: Vec<SignedOperation>,
+        acl: Option<WriterList>,
+    ) -> Result<SnapshotInfo> {
+        if let Some((cached_seq, info)) = self.cache.lock().get(db_name) {
+            if *cached_seq == seq {
+                return Ok(info.clone());
+            }
+        }
+
+        let op_count = operations.len() as u64;
+        let head_timestamp = operations.iter().map(|op| op.timestamp).max().unwrap_or(0);
+        let snapshot = DatabaseSnapshot {
+            version: SNAPSHOT_VERSION,
+            db_name: db_name.to_string(),
+            created_by: self.local_node_id.clone(),
+            created_at: chrono::Utc::now().timestamp_millis(),
+            head_timestamp,
+            operations,
+            acl,
+        };
+
+        let encoded = snapshot.encode()?;
+        let size = encoded.len() as u64;
+        let tag = self.store.blobs().add_bytes(encoded).await?;
+
+        let info = SnapshotInfo {
+            db_name: db_name.to_string(),
+            hash: tag.hash.to_string(),
+            size,
+            op_count,
+            head_timestamp,
+        };
+        info!("Created snapshot of {} ({} ops, {} bytes)", db_name, op_count, size);
+
+        self.cache.lock().insert(db_name.to_string(), (seq, info.clone()));
+        Ok(info)
+    }
+
+    /// Download an offered snapshot from `provider` and decode it
+    pub async fn fetch(&self, info: &SnapshotInfo, provider: &str) -> Result<DatabaseSnapshot> {
+        if info.size > MAX_SNAPSHOT_BLOB_BYTES {
+            return Err(anyhow!("Snapshot of {} too large ({} bytes)", info.db_name, info.size));
+        }
+
+        let hash: Hash = info.hash.parse()?;
+        let provider: EndpointId = provider.parse()?;
+
+        debug!("Fetching snapshot {} of {} from {}", info.hash, info.db_name, provider);
+        let downloader = self.store.downloader(&self.endpoint);
+        tokio::time::timeout(SNAPSHOT_FETCH_TIMEOUT, downloader.download(hash, vec![provider]))
+            .await
+            .map_err(|_| anyhow!("Timed out fetching snapshot of {}", info.db_name))??;
+
+        let bytes = self.store.blobs().get_bytes(hash).await?;
+        let snapshot = DatabaseSnapshot::decode(&bytes)?;
+        if snapshot.db_name != info.db_name {
+            return Err(anyhow!(
+                "Snapshot blob is for {}, expected {}",
+                snapshot.db_name, info.db_name
+            ));
+        }
+
+        info!(
+            "Fetched snapshot of {} ({} ops, {} bytes)",
+            snapshot.db_name, snapshot.operations.len(), bytes.len()
+        );
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot_with(db_name: &str, op_db_names: &[&str]) -> DatabaseSnapshot {
+        let (signing_key, _) = crate::crypto::generate_keypair();
+        let operations = op_db_names
+            .iter()
+            .enumerate()
+            .map(|(i, db)| {
+                SignedOperation::create_and_sign(
+                    db.to_string(),
+                    format!("key{}", i),
+                    "value".to_string(),
+                    "String".to_string(),
+                    &signing_key,
+                )
+            })
+            .collect();
+        DatabaseSnapshot {
+            version: SNAPSHOT_VERSION,
+            db_name: db_name.to_string(),
+            created_by: "node".to_string(),
+            created_at: 0,
+            head_timestamp: 0,
+            operations,
+            acl: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_encode_roundtrip() {
+        let snapshot = snapshot_with("db", &["db", "db", "db"]);
+        let decoded = DatabaseSnapshot::decode(&snapshot.encode().unwrap()).unwrap();
+
+        assert_eq!(decoded.db_name, "db");
+        assert_eq!(decoded.operations.len(), 3);
+        assert_eq!(decoded.operations[1].key, "key1");
+    }
+
+    #[test]
+    fn test_snapshot_rejects_foreign_operations() {
+        let snapshot = snapshot_with("db", &["db", "other"]);
+        assert!(DatabaseSnapshot::decode(&snapshot.encode().unwrap()).is_err());
+    }
+}
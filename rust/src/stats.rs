@@ -0,0 +1,165 @@
+//! Traffic and connection counters
+//!
+//! `SessionStats` counts gossip bytes and neighbor connects since the node
+//! started. Totals of earlier sessions are kept in the `lifetime_stats`
+//! setting; the node saves its running lifetime totals there periodically
+//! and when it stops.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use anyhow::Result;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::Storage;
+
+const LIFETIME_STATS_SETTING: &str = "lifetime_stats";
+
+/// Gossip traffic and neighbor connections
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrafficStats {
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    /// Peers becoming a gossip neighbor for the first time in a session
+    pub connects: u64,
+    /// Peers becoming a gossip neighbor again after dropping
+    pub reconnects: u64,
+}
+
+impl TrafficStats {
+    fn add(&self, other: &TrafficStats) -> TrafficStats {
+        TrafficStats {
+            bytes_sent: self.bytes_sent + other.bytes_sent,
+            bytes_received: self.bytes_received + other.bytes_received,
+            connects: self.connects + other.connects,
+            reconnects: self.reconnects + other.reconnects,
+        }
+    }
+}
+
+/// Totals over every session of this node, including the current one
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifetimeStats {
+    pub sessions: u64,
+    pub uptime_seconds: u64,
+    #[serde(flatten)]
+    pub traffic: TrafficStats,
+}
+
+/// Counters of the running node
+#[derive(Debug)]
+pub struct SessionStats {
+    started_at: Instant,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    connects: AtomicU64,
+    reconnects: AtomicU64,
+    /// Peers that have been a neighbor this session
+    seen_peers: Mutex<HashSet<String>>,
+    /// Totals of earlier sessions
+    previous: LifetimeStats,
+}
+
+impl SessionStats {
+    /// Start a session on top of the totals saved in `storage`
+    pub fn load(storage: &Storage) -> Result<Self> {
+        let previous = match storage.get_setting(LIFETIME_STATS_SETTING)? {
+            Some(bytes) => serde_json::from_slice(&bytes)?,
+            None => LifetimeStats::default(),
+        };
+        Ok(Self {
+            started_at: Instant::now(),
+            bytes_sent: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            connects: AtomicU64::new(0),
+            reconnects: AtomicU64::new(0),
+            seen_peers: Mutex::new(HashSet::new()),
+            previous,
+        })
+    }
+
+    pub fn uptime_seconds(&self) -> u64 {
+        self.started_at.elapsed().as_secs()
+    }
+
+    pub fn record_sent(&self, bytes: usize) {
+        self.bytes_sent.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_received(&self, bytes: usize) {
+        self.bytes_received.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// `peer_id` became a gossip neighbor
+    pub fn record_connect(&self, peer_id: &str) {
+        if self.seen_peers.lock().insert(peer_id.to_string()) {
+            self.connects.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.reconnects.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn session(&self) -> TrafficStats {
+        TrafficStats {
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            connects: self.connects.load(Ordering::Relaxed),
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn lifetime(&self) -> LifetimeStats {
+        LifetimeStats {
+            sessions: self.previous.sessions + 1,
+            uptime_seconds: self.previous.uptime_seconds + self.uptime_seconds(),
+            traffic: self.previous.traffic.add(&self.session()),
+        }
+    }
+
+    /// Save the lifetime totals, this session included
+    pub fn save(&self, storage: &Storage) -> Result<()> {
+        storage.put_setting(LIFETIME_STATS_SETTING, &serde_json::to_vec(&self.lifetime())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_session_stats() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+
+        let first = SessionStats::load(&storage).unwrap();
+        first.record_sent(100);
+        first.record_received(40);
+        first.record_connect("a");
+        first.record_connect("b");
+        first.record_connect("a");
+        assert_eq!(
+            first.session(),
+            TrafficStats { bytes_sent: 100, bytes_received: 40, connects: 2, reconnects: 1 }
+        );
+        assert_eq!(first.lifetime().sessions, 1);
+
+        // Saving twice doesn't count the session twice
+        first.save(&storage).unwrap();
+        first.save(&storage).unwrap();
+
+        let second = SessionStats::load(&storage).unwrap();
+        assert_eq!(second.session(), TrafficStats::default());
+        second.record_sent(1);
+        // A peer from the last session connects afresh
+        second.record_connect("a");
+        let lifetime = second.lifetime();
+        assert_eq!(lifetime.sessions, 2);
+        assert_eq!(
+            lifetime.traffic,
+            TrafficStats { bytes_sent: 101, bytes_received: 40, connects: 3, reconnects: 1 }
+        );
+    }
+}
@@ -1,29 +1,240 @@
 //! Storage module using sled embedded database
 
-use std::path::PathBuf;
+use std::collections::BTreeSet;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
-use anyhow::Result;
-use sled::Db;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use anyhow::{anyhow, Result};
+use dashmap::DashMap;
+use sled::transaction::{ConflictableTransactionError, TransactionError};
+use sled::{Db, IVec, Transactional, Tree};
+use tokio::sync::broadcast;
+
+use crate::search::{self, MAX_SEARCH_LIMIT, SEARCH_INDEX_VERSION};
 
 /// Special tree name for storing the operations log (for sync)
 const OPLOG_TREE: &str = "__oplog__";
 
+/// Signed per-database writer lists (see `acl`)
+const ACL_TREE: &str = "__acl__";
+
+/// Owner-signed database drops (see `acl::DbTombstone`)
+const TOMBSTONES_TREE: &str = "__tombstones__";
+
+/// Symmetric keys of end-to-end encrypted databases (never synced)
+const DB_KEYS_TREE: &str = "__db_keys__";
+
+/// Databases this device follows (see `sync::SyncFilter`)
+const FOLLOWED_DBS_TREE: &str = "__followed_dbs__";
+
+/// Databases kept off the sync path (see `sync::SyncFilter`)
+const LOCAL_ONLY_DBS_TREE: &str = "__local_only_dbs__";
+
+/// Sync activity of each database: db_name -> JSON (see `sync::DbSyncInfo`)
+const DB_SYNC_INFO_TREE: &str = "__db_sync_info__";
+
+/// Peers that sign their sync envelopes (see `SyncMessage::sign_envelope`)
+const SIGNING_PEERS_TREE: &str = "__signing_peers__";
+
+/// Peers remembered across restarts (see `discovery::KnownPeer`)
+const KNOWN_PEERS_TREE: &str = "__known_peers__";
+
+/// Peers the node always keeps connected: node_id -> address (may be empty)
+const PINNED_PEERS_TREE: &str = "__pinned_peers__";
+
+/// File offers received and not yet downloaded: offer_id -> JSON (see `files`)
+const FILE_OFFERS_TREE: &str = "__file_offers__";
+
+/// Sealed direct messages (see `dm`): peer_id/sent_at-id -> JSON
+const DIRECT_MESSAGES_TREE: &str = "__direct_messages__";
+
+/// Device certificates (see `devices`): user_key:device_node_id -> JSON
+const DEVICE_CERTS_TREE: &str = "__device_certs__";
+
+/// Small persisted node settings
+const SETTINGS_TREE: &str = "__settings__";
+
+/// Inverted index of user database values (see `search`)
+const SEARCH_POSTINGS_TREE: &str = "__search_postings__";
+
+/// Indexed token set of each key, to update postings on overwrite
+const SEARCH_DOCS_TREE: &str = "__search_docs__";
+
+/// Setting holding the `SEARCH_INDEX_VERSION` the index was built with
+const SEARCH_INDEX_SETTING: &str = "search_index_version";
+
+/// Setting written by `check_writable`
+const WRITE_PROBE_SETTING: &str = "write_probe";
+
+/// Persisted log of user database changes, keyed by big-endian sequence
+const CHANGE_LOG_TREE: &str = "__changes__";
+
+/// Oldest change log entries are trimmed beyond this many
+pub const MAX_CHANGE_LOG_ENTRIES: u64 = 100_000;
+
+/// Trim the change log every this many appends
+const CHANGE_LOG_TRIM_INTERVAL: u64 = 1024;
+
+/// IDs of operations written on this device (for conflict detection)
+const LOCAL_OPS_TREE: &str = "__local_ops__";
+
+/// Sync messages waiting for peers to send them to, keyed by big-endian ID
+const OUTBOX_TREE: &str = "__outbox__";
+
+/// Database of each queued operation in the outbox, keyed like it
+const OUTBOX_DBS_TREE: &str = "__outbox_dbs__";
+
+/// Oldest outbox messages are dropped beyond this many; peers still get
+/// those operations through sync
+pub const MAX_OUTBOX_ENTRIES: usize = 10_000;
+
+/// Trim the outbox every this many pushes
+const OUTBOX_TRIM_INTERVAL: u64 = 256;
+
+/// Bookkeeping trees left out of `size_bytes` and `key_count`
+const UNCOUNTED_TREES: [&str; 3] = [SEARCH_POSTINGS_TREE, SEARCH_DOCS_TREE, CHANGE_LOG_TREE];
+
+/// Internal trees (sled's default tree included) are prefixed with this and
+/// never listed as user databases. Only their dedicated accessors write them.
+pub const INTERNAL_TREE_PREFIX: &str = "__";
+
+/// Buffered changes per watcher; slower watchers skip ahead (`Lagged`)
+const CHANGE_CHANNEL_CAPACITY: usize = 1024;
+
+/// Upper bound on entries returned by one scan call
+pub const MAX_SCAN_LIMIT: usize = 1000;
+
+/// Separates the database name and key in `all_entries_page` cursors
+const DATA_CURSOR_SEPARATOR: char = '\0';
+
+/// Limits for one page of a scan
+#[derive(Debug, Clone, Copy)]
+pub struct ScanOptions {
+    /// Maximum entries, clamped to `1..=MAX_SCAN_LIMIT`
+    pub limit: usize,
+    /// Stop before the page's keys and values exceed this many bytes (a page
+    /// always holds at least one entry)
+    pub max_bytes: Option<usize>,
+    /// Return keys with empty values
+    pub keys_only: bool,
+}
+
+impl ScanOptions {
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit,
+            max_bytes: None,
+            keys_only: false,
+        }
+    }
+}
+
+/// Tracks how much of a page's `ScanOptions` budget is used
+struct PageBudget {
+    options: ScanOptions,
+    count: usize,
+    bytes: usize,
+}
+
+impl PageBudget {
+    fn new(options: ScanOptions) -> Self {
+        Self { options, count: 0, bytes: 0 }
+    }
+
+    /// Account for an entry if it still fits on the page
+    fn admit(&mut self, key: &[u8], value: &[u8]) -> bool {
+        let size = key.len() + if self.options.keys_only { 0 } else { value.len() };
+        if self.count >= self.options.limit.clamp(1, MAX_SCAN_LIMIT) {
+            return false;
+        }
+        if self.count > 0 && self.options.max_bytes.is_some_and(|max| self.bytes + size > max) {
+            return false;
+        }
+        self.count += 1;
+        self.bytes += size;
+        true
+    }
+
+    fn value(&self, value: IVec) -> Vec<u8> {
+        if self.options.keys_only {
+            Vec::new()
+        } else {
+            value.to_vec()
+        }
+    }
+}
+
+/// Name and every (key, value) entry of one tree
+pub type TreeDump = (String, Vec<(Vec<u8>, Vec<u8>)>);
+
+/// One page of a key-ordered scan
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScanPage {
+    pub entries: Vec<(String, Vec<u8>)>,
+    /// Pass as `cursor` to get the next page; `None` when the scan is done
+    pub next_cursor: Option<String>,
+}
+
+/// One page of a scan over all user databases
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataPage {
+    /// (db_name, key, value)
+    pub entries: Vec<(String, String, Vec<u8>)>,
+    /// Pass as `cursor` to get the next page; `None` when the scan is done
+    pub next_cursor: Option<String>,
+}
+
+/// A write or delete in a user database, published to watchers
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorageChange {
+    /// Position in the change log (see `changes_since`); 0 if not logged
+    pub seq: u64,
+    pub db_name: String,
+    pub key: String,
+    /// New value, `None` if the key was deleted
+    pub value: Option<Vec<u8>>,
+}
+
 /// Storage wrapper for sled database.
 ///
-/// `size_bytes` and `key_count` are O(N) scans over every tree, so they are cached
-/// in atomics and refreshed by a background task (see `refresh_stats`). Readers
-/// (the status/UI path) get cheap atomic loads.
+/// Tree handles are opened once and cached. `size_bytes` and `key_count` are
+/// kept in atomics that every write adjusts by its delta, so the status/UI
+/// path gets cheap atomic loads; `refresh_stats` recomputes them from scratch.
 #[derive(Clone)]
 pub struct Storage {
     db: Db,
+    /// Opened for inspection only (see `open_read_only`)
+    read_only: bool,
+    trees: Arc<DashMap<String, Tree>>,
     cached_size_bytes: Arc<AtomicU64>,
     cached_key_count: Arc<AtomicU64>,
+    changes: broadcast::Sender<StorageChange>,
+    /// Time of the last `flush` in ms, 0 before the first
+    last_flush_at: Arc<AtomicI64>,
+    /// Last change log sequence; its lock keeps log and broadcast order equal
+    change_seq: Arc<parking_lot::Mutex<u64>>,
+    /// Queued operations per database, counted from `OUTBOX_DBS_TREE`
+    outbox_counts: Arc<DashMap<String, usize>>,
 }
 
 impl Storage {
     /// Create a new storage instance
     pub fn new(path: PathBuf) -> Result<Self> {
+        Self::open(path, false)
+    }
+
+    /// Open existing storage for inspection: every write fails and missing
+    /// trees aren't created. Sled locks the directory, so this only works
+    /// while the node isn't running.
+    pub fn open_read_only(path: &Path) -> Result<Self> {
+        if !path.is_dir() {
+            return Err(anyhow!("No storage found at {}", path.display()));
+        }
+        Self::open(path.to_path_buf(), true)
+    }
+
+    fn open(path: PathBuf, read_only: bool) -> Result<Self> {
         let db = sled::Config::new()
             .path(path)
             .cache_capacity(128 * 1024 * 1024) // 128MB cache for better read perf
@@ -34,36 +245,265 @@ impl Storage {
 
         let storage = Self {
             db,
+            read_only,
+            trees: Arc::new(DashMap::new()),
             cached_size_bytes: Arc::new(AtomicU64::new(0)),
             cached_key_count: Arc::new(AtomicU64::new(0)),
+            changes: broadcast::channel(CHANGE_CHANNEL_CAPACITY).0,
+            last_flush_at: Arc::new(AtomicI64::new(0)),
+            change_seq: Arc::new(parking_lot::Mutex::new(0)),
+            outbox_counts: Arc::new(DashMap::new()),
         };
+        if let Some((last, _)) = storage.tree(CHANGE_LOG_TREE).ok().and_then(|tree| tree.last().ok().flatten()) {
+            *storage.change_seq.lock() = decode_seq(&last)?;
+        }
+        if !read_only {
+            storage.ensure_search_index()?;
+        }
+        storage.load_outbox_counts()?;
         // Prime the cache so the first status read is accurate.
         storage.refresh_stats();
         Ok(storage)
     }
     
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn ensure_writable(&self) -> Result<()> {
+        if self.read_only {
+            return Err(anyhow!("Storage is open read-only"));
+        }
+        Ok(())
+    }
+
+    /// Writes by database name only reach user databases
+    fn ensure_user_db(db_name: &str) -> Result<()> {
+        if db_name.starts_with(INTERNAL_TREE_PREFIX) {
+            return Err(anyhow!("{} is an internal tree, not a database", db_name));
+        }
+        Ok(())
+    }
+
+    /// Cached handle of a tree, opened on first use
+    fn tree(&self, name: &str) -> Result<Tree> {
+        if let Some(tree) = self.trees.get(name) {
+            return Ok(tree.clone());
+        }
+        if self.read_only && !self.db.tree_names().iter().any(|n| n == name.as_bytes()) {
+            return Err(anyhow!("No database named {}", name));
+        }
+        let tree = self.db.open_tree(name)?;
+        self.trees.insert(name.to_string(), tree.clone());
+        Ok(tree)
+    }
+
+    /// Insert into a tree, keeping the stats counters in step
+    fn insert(&self, tree: &Tree, key: &[u8], value: &[u8]) -> Result<Option<IVec>> {
+        self.ensure_writable()?;
+        let old = tree.insert(key, value)?;
+        self.adjust_stats(entry_delta(key, old.as_deref(), Some(value)));
+        Ok(old)
+    }
+
+    /// Remove from a tree, keeping the stats counters in step
+    fn remove(&self, tree: &Tree, key: &[u8]) -> Result<Option<IVec>> {
+        self.ensure_writable()?;
+        let old = tree.remove(key)?;
+        self.adjust_stats(entry_delta(key, old.as_deref(), None));
+        Ok(old)
+    }
+
+    /// Apply a (key count, bytes) delta to the cached stats
+    fn adjust_stats(&self, (keys, bytes): (i64, i64)) {
+        if keys != 0 {
+            let _ = self.cached_key_count.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                Some(n.saturating_add_signed(keys))
+            });
+        }
+        if bytes != 0 {
+            let _ = self.cached_size_bytes.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                Some(n.saturating_add_signed(bytes))
+            });
+        }
+    }
+
+    /// Subscribe to writes and deletes in user databases
+    pub fn subscribe(&self) -> broadcast::Receiver<StorageChange> {
+        self.changes.subscribe()
+    }
+
+    /// Update the search index, append to the change log and notify
+    /// watchers of a user database write
+    fn on_change(&self, db_name: &str, key: &str, value: Option<&[u8]>) -> Result<()> {
+        if db_name.starts_with(INTERNAL_TREE_PREFIX) {
+            return Ok(());
+        }
+        self.index_value(db_name, key, value)?;
+
+        let log = self.tree(CHANGE_LOG_TREE)?;
+        let mut last_seq = self.change_seq.lock();
+        let seq = *last_seq + 1;
+        log.insert(seq.to_be_bytes(), postcard::to_allocvec(&(db_name, key, value))?)?;
+        *last_seq = seq;
+        if seq.is_multiple_of(CHANGE_LOG_TRIM_INTERVAL) && seq > MAX_CHANGE_LOG_ENTRIES {
+            let cutoff = (seq - MAX_CHANGE_LOG_ENTRIES).to_be_bytes();
+            for old in log.range(..cutoff).keys() {
+                log.remove(old?)?;
+            }
+        }
+
+        // No receivers is the common case, not an error
+        if self.changes.receiver_count() > 0 {
+            let _ = self.changes.send(StorageChange {
+                seq,
+                db_name: db_name.to_string(),
+                key: key.to_string(),
+                value: value.map(|v| v.to_vec()),
+            });
+        }
+        Ok(())
+    }
+
+    /// Sequence of the latest logged change (0 if none)
+    pub fn latest_change_seq(&self) -> u64 {
+        *self.change_seq.lock()
+    }
+
+    /// Logged changes after `since_seq`, oldest first, optionally of one
+    /// database. Only the last `MAX_CHANGE_LOG_ENTRIES` changes are kept; a
+    /// gap between `since_seq` and the first returned `seq` means some were
+    /// trimmed.
+    pub fn changes_since(&self, db_name: Option<&str>, since_seq: u64, limit: usize) -> Result<Vec<StorageChange>> {
+        let log = self.tree(CHANGE_LOG_TREE)?;
+        let mut changes = Vec::new();
+        for item in log.range(since_seq.saturating_add(1).to_be_bytes()..) {
+            if changes.len() >= limit.clamp(1, MAX_SCAN_LIMIT) {
+                break;
+            }
+            let (seq, entry) = item?;
+            let (change_db, key, value): (String, String, Option<Vec<u8>>) = postcard::from_bytes(&entry)?;
+            if db_name.is_some_and(|db| db != change_db) {
+                continue;
+            }
+            changes.push(StorageChange { seq: decode_seq(&seq)?, db_name: change_db, key, value });
+        }
+        Ok(changes)
+    }
+
+    /// Replace the search postings of a key with those of its new value. The
+    /// index is derived data and not counted in the storage stats.
+    fn index_value(&self, db_name: &str, key: &str, value: Option<&[u8]>) -> Result<()> {
+        let docs = self.tree(SEARCH_DOCS_TREE)?;
+        let postings = self.tree(SEARCH_POSTINGS_TREE)?;
+        let doc_key = search::doc_key(db_name, key);
+
+        let old = docs.get(&doc_key)?.map(|v| search::decode_tokens(&v)).unwrap_or_default();
+        let new = value.map(|v| search::index_tokens(key, v)).unwrap_or_default();
+        if old == new {
+            return Ok(());
+        }
+        for token in old.difference(&new) {
+            postings.remove(search::posting_key(db_name, token, key))?;
+        }
+        for token in new.difference(&old) {
+            postings.insert(search::posting_key(db_name, token, key), &[])?;
+        }
+        if new.is_empty() {
+            docs.remove(doc_key)?;
+        } else {
+            docs.insert(doc_key, search::encode_tokens(&new))?;
+        }
+        Ok(())
+    }
+
+    /// Re-index every user database if the index is missing or was built by
+    /// an older tokenizer
+    fn ensure_search_index(&self) -> Result<()> {
+        let version = SEARCH_INDEX_VERSION.to_string();
+        if self.get_setting(SEARCH_INDEX_SETTING)?.as_deref() == Some(version.as_bytes()) {
+            return Ok(());
+        }
+
+        self.tree(SEARCH_DOCS_TREE)?.clear()?;
+        self.tree(SEARCH_POSTINGS_TREE)?.clear()?;
+        for db_name in self.list_databases()? {
+            for item in self.tree(&db_name)?.iter() {
+                let (key, value) = item?;
+                if let Ok(key) = std::str::from_utf8(&key) {
+                    self.index_value(&db_name, key, Some(&value))?;
+                }
+            }
+        }
+        self.put_setting(SEARCH_INDEX_SETTING, version.as_bytes())?;
+        Ok(())
+    }
+
+    /// Keys of `db_name` whose values contain every term of `query` (the last
+    /// term as a prefix), in key order, with their values
+    pub fn search(&self, db_name: &str, query: &str, limit: usize) -> Result<Vec<(String, Vec<u8>)>> {
+        let postings = self.tree(SEARCH_POSTINGS_TREE)?;
+        let db_prefix = search::db_prefix(db_name);
+        let terms = search::query_terms(query);
+
+        let mut matches: Option<BTreeSet<String>> = None;
+        for (i, term) in terms.iter().enumerate() {
+            let mut prefix = search::token_prefix(db_name, term);
+            if i + 1 < terms.len() {
+                // Whole-token match
+                prefix = search::posting_key(db_name, term, "");
+            }
+            let keys: BTreeSet<String> = postings
+                .scan_prefix(&prefix)
+                .keys()
+                .filter_map(|posting| posting.ok())
+                .filter_map(|posting| search::posting_target(&posting[db_prefix.len()..]))
+                .collect();
+            let narrowed = match matches {
+                Some(previous) => previous.intersection(&keys).cloned().collect(),
+                None => keys,
+            };
+            if narrowed.is_empty() {
+                return Ok(Vec::new());
+            }
+            matches = Some(narrowed);
+        }
+
+        let data = self.tree(db_name)?;
+        let mut hits = Vec::new();
+        for key in matches.unwrap_or_default() {
+            if hits.len() >= limit.clamp(1, MAX_SEARCH_LIMIT) {
+                break;
+            }
+            if let Some(value) = data.get(&key)? {
+                hits.push((key, value.to_vec()));
+            }
+        }
+        Ok(hits)
+    }
+
     /// Store a signed operation to the operations log
     pub fn put_operation(&self, op_id: &str, operation_json: &[u8]) -> Result<()> {
-        let tree = self.db.open_tree(OPLOG_TREE)?;
-        tree.insert(op_id, operation_json)?;
+        let tree = self.tree(OPLOG_TREE)?;
+        self.insert(&tree, op_id.as_bytes(), operation_json)?;
         Ok(())
     }
     
     /// Get a signed operation from the operations log
     pub fn get_operation(&self, op_id: &str) -> Result<Option<Vec<u8>>> {
-        let tree = self.db.open_tree(OPLOG_TREE)?;
+        let tree = self.tree(OPLOG_TREE)?;
         Ok(tree.get(op_id)?.map(|v| v.to_vec()))
     }
     
     /// Check if an operation exists in the log
     pub fn has_operation(&self, op_id: &str) -> Result<bool> {
-        let tree = self.db.open_tree(OPLOG_TREE)?;
+        let tree = self.tree(OPLOG_TREE)?;
         Ok(tree.contains_key(op_id)?)
     }
     
     /// Get all operations from the log
     pub fn get_all_operations(&self) -> Result<Vec<Vec<u8>>> {
-        let tree = self.db.open_tree(OPLOG_TREE)?;
+        let tree = self.tree(OPLOG_TREE)?;
         let ops: Vec<Vec<u8>> = tree
             .iter()
             .values()
@@ -75,33 +515,511 @@ impl Storage {
     
     /// Get count of operations in the log
     pub fn operation_count(&self) -> Result<usize> {
-        let tree = self.db.open_tree(OPLOG_TREE)?;
+        let tree = self.tree(OPLOG_TREE)?;
         Ok(tree.len())
     }
 
+    /// Store the writer list of a database
+    pub fn put_acl(&self, db_name: &str, acl_json: &[u8]) -> Result<()> {
+        let tree = self.tree(ACL_TREE)?;
+        self.insert(&tree, db_name.as_bytes(), acl_json)?;
+        Ok(())
+    }
+
+    /// Get all stored writer lists
+    pub fn get_all_acls(&self) -> Result<Vec<Vec<u8>>> {
+        let tree = self.tree(ACL_TREE)?;
+        Ok(tree.iter().values().filter_map(|v| v.ok()).map(|v| v.to_vec()).collect())
+    }
+
+    /// Store the tombstone of a dropped database
+    pub fn put_tombstone(&self, db_name: &str, tombstone_json: &[u8]) -> Result<()> {
+        let tree = self.tree(TOMBSTONES_TREE)?;
+        self.insert(&tree, db_name.as_bytes(), tombstone_json)?;
+        Ok(())
+    }
+
+    /// Get all stored tombstones
+    pub fn get_all_tombstones(&self) -> Result<Vec<Vec<u8>>> {
+        let tree = self.tree(TOMBSTONES_TREE)?;
+        Ok(tree.iter().values().filter_map(|v| v.ok()).map(|v| v.to_vec()).collect())
+    }
+
+    /// Drop a database's tree and remove the given operations from the oplog
+    pub fn drop_database(&self, db_name: &str, op_ids: &[String]) -> Result<()> {
+        self.ensure_writable()?;
+        if db_name.starts_with(INTERNAL_TREE_PREFIX) {
+            return Err(anyhow!("Cannot drop internal tree {}", db_name));
+        }
+        let (keys, bytes) = tree_stats(&self.tree(db_name)?);
+        self.trees.remove(db_name);
+        self.db.drop_tree(db_name)?;
+        self.adjust_stats((-(keys as i64), -(bytes as i64)));
+
+        let db_prefix = search::db_prefix(db_name);
+        for tree in [self.tree(SEARCH_POSTINGS_TREE)?, self.tree(SEARCH_DOCS_TREE)?] {
+            for key in tree.scan_prefix(&db_prefix).keys() {
+                tree.remove(key?)?;
+            }
+        }
+
+        let oplog = self.tree(OPLOG_TREE)?;
+        let local_ops = self.tree(LOCAL_OPS_TREE)?;
+        for op_id in op_ids {
+            self.remove(&oplog, op_id.as_bytes())?;
+            self.remove(&local_ops, op_id.as_bytes())?;
+        }
+        self.remove(&self.tree(DB_SYNC_INFO_TREE)?, db_name.as_bytes())?;
+        self.flush()
+    }
+
+    /// Store the encryption key of a database
+    pub fn put_db_key(&self, db_name: &str, key: &[u8]) -> Result<()> {
+        let tree = self.tree(DB_KEYS_TREE)?;
+        self.insert(&tree, db_name.as_bytes(), key)?;
+        tree.flush()?;
+        Ok(())
+    }
+
+    /// Get the encryption key of a database
+    pub fn get_db_key(&self, db_name: &str) -> Result<Option<Vec<u8>>> {
+        let tree = self.tree(DB_KEYS_TREE)?;
+        Ok(tree.get(db_name)?.map(|v| v.to_vec()))
+    }
+
+    /// Add a database to the followed set
+    pub fn follow_database(&self, db_name: &str) -> Result<()> {
+        let tree = self.tree(FOLLOWED_DBS_TREE)?;
+        self.insert(&tree, db_name.as_bytes(), &[])?;
+        Ok(())
+    }
+
+    /// Remove a database from the followed set
+    pub fn unfollow_database(&self, db_name: &str) -> Result<()> {
+        let tree = self.tree(FOLLOWED_DBS_TREE)?;
+        self.remove(&tree, db_name.as_bytes())?;
+        Ok(())
+    }
+
+    /// Get all followed databases
+    pub fn followed_databases(&self) -> Result<Vec<String>> {
+        let tree = self.tree(FOLLOWED_DBS_TREE)?;
+        Ok(tree
+            .iter()
+            .keys()
+            .filter_map(|k| k.ok())
+            .filter_map(|k| String::from_utf8(k.to_vec()).ok())
+            .collect())
+    }
+
+    /// Mark a database as local only, or sync it again
+    pub fn set_local_only(&self, db_name: &str, local_only: bool) -> Result<()> {
+        let tree = self.tree(LOCAL_ONLY_DBS_TREE)?;
+        if local_only {
+            self.insert(&tree, db_name.as_bytes(), &[])?;
+        } else {
+            self.remove(&tree, db_name.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Get all local-only databases
+    pub fn local_only_databases(&self) -> Result<Vec<String>> {
+        let tree = self.tree(LOCAL_ONLY_DBS_TREE)?;
+        Ok(tree
+            .iter()
+            .keys()
+            .filter_map(|k| k.ok())
+            .filter_map(|k| String::from_utf8(k.to_vec()).ok())
+            .collect())
+    }
+
+    /// Store the sync activity of a database
+    pub fn put_db_sync_info(&self, db_name: &str, info_json: &[u8]) -> Result<()> {
+        let tree = self.tree(DB_SYNC_INFO_TREE)?;
+        self.insert(&tree, db_name.as_bytes(), info_json)?;
+        Ok(())
+    }
+
+    /// Sync activity of every database as (db_name, JSON)
+    pub fn get_all_db_sync_info(&self) -> Result<Vec<(String, Vec<u8>)>> {
+        let tree = self.tree(DB_SYNC_INFO_TREE)?;
+        let mut infos = Vec::new();
+        for entry in tree.iter() {
+            let (db_name, info) = entry?;
+            infos.push((String::from_utf8_lossy(&db_name).to_string(), info.to_vec()));
+        }
+        Ok(infos)
+    }
+
+    /// Remember that `node_id` signs its sync envelopes
+    pub fn add_signing_peer(&self, node_id: &str) -> Result<()> {
+        let tree = self.tree(SIGNING_PEERS_TREE)?;
+        self.insert(&tree, node_id.as_bytes(), &[])?;
+        Ok(())
+    }
+
+    /// Node IDs of the peers known to sign their sync envelopes
+    pub fn signing_peers(&self) -> Result<Vec<String>> {
+        Ok(self
+            .tree(SIGNING_PEERS_TREE)?
+            .iter()
+            .keys()
+            .filter_map(|k| k.ok())
+            .filter_map(|k| String::from_utf8(k.to_vec()).ok())
+            .collect())
+    }
+
+    /// Remove operations from the oplog, keeping the data they wrote
+    pub fn remove_operations(&self, op_ids: &[String]) -> Result<()> {
+        let oplog = self.tree(OPLOG_TREE)?;
+        let local_ops = self.tree(LOCAL_OPS_TREE)?;
+        for op_id in op_ids {
+            self.remove(&oplog, op_id.as_bytes())?;
+            self.remove(&local_ops, op_id.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Replace the remembered peers with `peers` (node_id, JSON)
+    pub fn replace_known_peers(&self, peers: &[(String, Vec<u8>)]) -> Result<()> {
+        let tree = self.tree(KNOWN_PEERS_TREE)?;
+        for key in tree.iter().keys() {
+            let key = key?;
+            if !peers.iter().any(|(node_id, _)| node_id.as_bytes() == key.as_ref()) {
+                self.remove(&tree, &key)?;
+            }
+        }
+        for (node_id, peer_json) in peers {
+            self.insert(&tree, node_id.as_bytes(), peer_json)?;
+        }
+        Ok(())
+    }
+
+    /// Get all remembered peers
+    pub fn get_all_known_peers(&self) -> Result<Vec<Vec<u8>>> {
+        let tree = self.tree(KNOWN_PEERS_TREE)?;
+        Ok(tree.iter().values().filter_map(|v| v.ok()).map(|v| v.to_vec()).collect())
+    }
+
+    /// Pin a peer, optionally at a known `ip:port`
+    pub fn pin_peer(&self, node_id: &str, address: Option<&str>) -> Result<()> {
+        let tree = self.tree(PINNED_PEERS_TREE)?;
+        self.insert(&tree, node_id.as_bytes(), address.unwrap_or("").as_bytes())?;
+        Ok(())
+    }
+
+    pub fn unpin_peer(&self, node_id: &str) -> Result<()> {
+        let tree = self.tree(PINNED_PEERS_TREE)?;
+        self.remove(&tree, node_id.as_bytes())?;
+        Ok(())
+    }
+
+    /// Pinned peers as (node_id, address)
+    pub fn pinned_peers(&self) -> Result<Vec<(String, Option<String>)>> {
+        let tree = self.tree(PINNED_PEERS_TREE)?;
+        let mut peers = Vec::new();
+        for entry in tree.iter() {
+            let (node_id, address) = entry?;
+            let address = String::from_utf8_lossy(&address).to_string();
+            peers.push((
+                String::from_utf8_lossy(&node_id).to_string(),
+                Some(address).filter(|a| !a.is_empty()),
+            ));
+        }
+        Ok(peers)
+    }
+
+    /// Keep a device certificate
+    pub fn put_device_certificate(&self, user_public_key: &str, device_node_id: &str, certificate: &[u8]) -> Result<()> {
+        let tree = self.tree(DEVICE_CERTS_TREE)?;
+        self.insert(&tree, format!("{}:{}", user_public_key, device_node_id).as_bytes(), certificate)?;
+        Ok(())
+    }
+
+    pub fn get_device_certificate(&self, user_public_key: &str, device_node_id: &str) -> Result<Option<Vec<u8>>> {
+        let tree = self.tree(DEVICE_CERTS_TREE)?;
+        Ok(tree.get(format!("{}:{}", user_public_key, device_node_id))?.map(|v| v.to_vec()))
+    }
+
+    /// Kept device certificates
+    pub fn device_certificates(&self) -> Result<Vec<Vec<u8>>> {
+        let tree = self.tree(DEVICE_CERTS_TREE)?;
+        tree.iter().values().map(|value| Ok(value?.to_vec())).collect()
+    }
+
+    /// Keep a received file offer
+    pub fn put_file_offer(&self, offer_id: &str, offer: &[u8]) -> Result<()> {
+        let tree = self.tree(FILE_OFFERS_TREE)?;
+        self.insert(&tree, offer_id.as_bytes(), offer)?;
+        Ok(())
+    }
+
+    pub fn get_file_offer(&self, offer_id: &str) -> Result<Option<Vec<u8>>> {
+        let tree = self.tree(FILE_OFFERS_TREE)?;
+        Ok(tree.get(offer_id)?.map(|v| v.to_vec()))
+    }
+
+    /// Forget a file offer; returns whether it was kept
+    pub fn remove_file_offer(&self, offer_id: &str) -> Result<bool> {
+        let tree = self.tree(FILE_OFFERS_TREE)?;
+        Ok(self.remove(&tree, offer_id.as_bytes())?.is_some())
+    }
+
+    /// Kept file offers
+    pub fn file_offers(&self) -> Result<Vec<Vec<u8>>> {
+        let tree = self.tree(FILE_OFFERS_TREE)?;
+        tree.iter().values().map(|value| Ok(value?.to_vec())).collect()
+    }
+
+    /// Keep a sealed message of the conversation with `peer_id`
+    pub fn put_direct_message(&self, peer_id: &str, key: &str, sealed: &[u8]) -> Result<()> {
+        let tree = self.tree(DIRECT_MESSAGES_TREE)?;
+        self.insert(&tree, format!("{}/{}", peer_id, key).as_bytes(), sealed)?;
+        Ok(())
+    }
+
+    /// The last `limit` sealed messages of the conversation with `peer_id`,
+    /// in key order
+    pub fn last_direct_messages(&self, peer_id: &str, limit: usize) -> Result<Vec<Vec<u8>>> {
+        let tree = self.tree(DIRECT_MESSAGES_TREE)?;
+        let mut messages = tree
+            .scan_prefix(format!("{}/", peer_id))
+            .values()
+            .rev()
+            .take(limit)
+            .map(|value| Ok(value?.to_vec()))
+            .collect::<Result<Vec<_>>>()?;
+        messages.reverse();
+        Ok(messages)
+    }
+
+    /// Get a node setting
+    pub fn get_setting(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        let tree = self.tree(SETTINGS_TREE)?;
+        Ok(tree.get(name)?.map(|v| v.to_vec()))
+    }
+
+    /// Store a node setting
+    pub fn put_setting(&self, name: &str, value: &[u8]) -> Result<()> {
+        let tree = self.tree(SETTINGS_TREE)?;
+        self.insert(&tree, name.as_bytes(), value)?;
+        Ok(())
+    }
+
+    /// Remember that an operation was written on this device
+    pub fn mark_local_operation(&self, op_id: &str) -> Result<()> {
+        let tree = self.tree(LOCAL_OPS_TREE)?;
+        self.insert(&tree, op_id.as_bytes(), &[])?;
+        Ok(())
+    }
+
+    /// Forget a local operation marker; returns whether the op was local
+    pub fn take_local_operation(&self, op_id: &str) -> Result<bool> {
+        let tree = self.tree(LOCAL_OPS_TREE)?;
+        Ok(self.remove(&tree, op_id.as_bytes())?.is_some())
+    }
+
+    /// Queue a sync message payload in the outbox, counted as an operation
+    /// of `db_name` if it is one; returns its ID
+    pub fn outbox_push(&self, payload: &[u8], db_name: Option<&str>) -> Result<u64> {
+        let tree = self.tree(OUTBOX_TREE)?;
+        let id = self.db.generate_id()?;
+        // The database goes first, `load_outbox_counts` drops it if the
+        // payload never landed
+        if let Some(db_name) = db_name {
+            self.insert(&self.tree(OUTBOX_DBS_TREE)?, &id.to_be_bytes(), db_name.as_bytes())?;
+            *self.outbox_counts.entry(db_name.to_string()).or_default() += 1;
+        }
+        self.insert(&tree, &id.to_be_bytes(), payload)?;
+        if id.is_multiple_of(OUTBOX_TRIM_INTERVAL) {
+            let excess = tree.len().saturating_sub(MAX_OUTBOX_ENTRIES);
+            for oldest in tree.iter().keys().take(excess) {
+                self.outbox_remove(decode_seq(&oldest?)?)?;
+            }
+        }
+        Ok(id)
+    }
+
+    /// Up to `limit` queued payloads, oldest first
+    pub fn outbox_peek(&self, limit: usize) -> Result<Vec<(u64, Vec<u8>)>> {
+        let tree = self.tree(OUTBOX_TREE)?;
+        let mut entries = Vec::new();
+        for entry in tree.iter().take(limit) {
+            let (id, payload) = entry?;
+            entries.push((decode_seq(&id)?, payload.to_vec()));
+        }
+        Ok(entries)
+    }
+
+    /// Remove a payload once it was sent
+    pub fn outbox_remove(&self, id: u64) -> Result<()> {
+        let tree = self.tree(OUTBOX_TREE)?;
+        self.remove(&tree, &id.to_be_bytes())?;
+        if let Some(db_name) = self.remove(&self.tree(OUTBOX_DBS_TREE)?, &id.to_be_bytes())? {
+            self.uncount_outbox_op(&String::from_utf8_lossy(&db_name));
+        }
+        Ok(())
+    }
+
+    /// Number of queued operations of `db_name`
+    pub fn outbox_queued(&self, db_name: &str) -> usize {
+        self.outbox_counts.get(db_name).map(|count| *count).unwrap_or(0)
+    }
+
+    fn uncount_outbox_op(&self, db_name: &str) {
+        self.outbox_counts.remove_if_mut(db_name, |_, count| {
+            *count = count.saturating_sub(1);
+            *count == 0
+        });
+    }
+
+    /// Count the queued operations per database, dropping the entries whose
+    /// payload is gone (the node stopped between the writes of a push or
+    /// a removal)
+    fn load_outbox_counts(&self) -> Result<()> {
+        let (Ok(outbox), Ok(dbs)) = (self.tree(OUTBOX_TREE), self.tree(OUTBOX_DBS_TREE)) else {
+            return Ok(());
+        };
+        for entry in dbs.iter() {
+            let (id, db_name) = entry?;
+            if outbox.contains_key(&id)? {
+                *self.outbox_counts.entry(String::from_utf8_lossy(&db_name).into_owned()).or_default() += 1;
+            } else if !self.read_only {
+                self.remove(&dbs, &id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of queued payloads
+    pub fn outbox_len(&self) -> Result<usize> {
+        Ok(self.tree(OUTBOX_TREE)?.len())
+    }
+
+    pub fn outbox_is_empty(&self) -> Result<bool> {
+        Ok(self.tree(OUTBOX_TREE)?.is_empty())
+    }
+
+    /// Write data entries for one database together with their oplog entries
+    /// in a single transaction - either everything lands or nothing does
+    pub fn apply_batch(
+        &self,
+        db_name: &str,
+        entries: &[(String, Vec<u8>)],
+        operations: &[(String, Vec<u8>)],
+    ) -> Result<()> {
+        self.ensure_writable()?;
+        Self::ensure_user_db(db_name)?;
+        let data = self.tree(db_name)?;
+        let oplog = self.tree(OPLOG_TREE)?;
+
+        // The closure may run more than once, so it returns the delta of its
+        // final (committed) attempt instead of accumulating
+        let delta = (&data, &oplog)
+            .transaction(|(data, oplog)| {
+                let mut delta = (0, 0);
+                let writes = entries
+                    .iter()
+                    .map(|(key, value)| (data, key, value))
+                    .chain(operations.iter().map(|(op_id, op_json)| (oplog, op_id, op_json)));
+                for (tree, key, value) in writes {
+                    let old = tree.insert(key.as_bytes(), value.as_slice())?;
+                    let (keys, bytes) = entry_delta(key.as_bytes(), old.as_deref(), Some(value.as_slice()));
+                    delta = (delta.0 + keys, delta.1 + bytes);
+                }
+                Ok::<(i64, i64), ConflictableTransactionError<()>>(delta)
+            })
+            .map_err(|e: TransactionError<()>| anyhow!("Batch write to {} failed: {:?}", db_name, e))?;
+        self.adjust_stats(delta);
+
+        for (key, value) in entries {
+            self.on_change(db_name, key, Some(value))?;
+        }
+        Ok(())
+    }
+
     /// Get a value by database name and key
     pub fn get(&self, db_name: &str, key: &str) -> Result<Option<Vec<u8>>> {
-        let tree = self.db.open_tree(db_name)?;
+        let tree = self.tree(db_name)?;
         Ok(tree.get(key)?.map(|v| v.to_vec()))
     }
 
+    /// Get several values of one database, in the order of `keys`
+    pub fn get_many(&self, db_name: &str, keys: &[String]) -> Result<Vec<Option<Vec<u8>>>> {
+        let tree = self.tree(db_name)?;
+        keys.iter()
+            .map(|key| Ok(tree.get(key)?.map(|v| v.to_vec())))
+            .collect()
+    }
+
     /// Put a value
     pub fn put(&self, db_name: &str, key: &str, value: &[u8]) -> Result<()> {
-        let tree = self.db.open_tree(db_name)?;
-        tree.insert(key, value)?;
+        Self::ensure_user_db(db_name)?;
+        let tree = self.tree(db_name)?;
+        self.insert(&tree, key.as_bytes(), value)?;
+        self.on_change(db_name, key, Some(value))?;
+        Ok(())
+    }
+
+    /// Put many values with one sled batch per database (no flush)
+    pub fn put_many(&self, entries: &[(String, String, Vec<u8>)]) -> Result<()> {
+        self.ensure_writable()?;
+        let mut batches: std::collections::BTreeMap<&str, sled::Batch> = std::collections::BTreeMap::new();
+        let mut delta = (0, 0);
+        for (db_name, key, value) in entries {
+            Self::ensure_user_db(db_name)?;
+            // Sled batches don't report replaced values, so look them up first
+            let old = self.tree(db_name)?.get(key)?;
+            let (keys, bytes) = entry_delta(key.as_bytes(), old.as_deref(), Some(value.as_slice()));
+            delta = (delta.0 + keys, delta.1 + bytes);
+            batches.entry(db_name.as_str()).or_default().insert(key.as_str(), value.as_slice());
+        }
+        for (db_name, batch) in batches {
+            self.tree(db_name)?.apply_batch(batch)?;
+        }
+        self.adjust_stats(delta);
+
+        for (db_name, key, value) in entries {
+            self.on_change(db_name, key, Some(value))?;
+        }
         Ok(())
     }
 
     /// Delete a value
     pub fn delete(&self, db_name: &str, key: &str) -> Result<()> {
-        let tree = self.db.open_tree(db_name)?;
-        tree.remove(key)?;
+        Self::ensure_user_db(db_name)?;
+        let tree = self.tree(db_name)?;
+        if self.remove(&tree, key.as_bytes())?.is_some() {
+            self.on_change(db_name, key, None)?;
+        }
         Ok(())
     }
 
+    /// Atomically replace the value of `key` if it currently equals `expected`
+    /// (`None` = absent). `new` of `None` deletes the key. Returns whether the
+    /// swap happened. Local only, not replicated.
+    pub fn compare_and_swap(
+        &self,
+        db_name: &str,
+        key: &str,
+        expected: Option<&[u8]>,
+        new: Option<&[u8]>,
+    ) -> Result<bool> {
+        self.ensure_writable()?;
+        Self::ensure_user_db(db_name)?;
+        let tree = self.tree(db_name)?;
+        if tree.compare_and_swap(key, expected, new)?.is_err() {
+            return Ok(false);
+        }
+        self.adjust_stats(entry_delta(key.as_bytes(), expected, new));
+        self.on_change(db_name, key, new)?;
+        Ok(true)
+    }
+
     /// List all keys in a database
     pub fn list_keys(&self, db_name: &str) -> Result<Vec<String>> {
-        let tree = self.db.open_tree(db_name)?;
+        let tree = self.tree(db_name)?;
         let keys: Vec<String> = tree
             .iter()
             .keys()
@@ -111,42 +1029,179 @@ impl Storage {
         Ok(keys)
     }
 
+    /// The last `limit` entries of a database, in key order
+    pub fn last_entries(&self, db_name: &str, limit: usize) -> Result<Vec<(String, Vec<u8>)>> {
+        let tree = self.tree(db_name)?;
+        let mut entries = tree
+            .iter()
+            .rev()
+            .take(limit)
+            .map(|item| {
+                let (key, value) = item?;
+                Ok((String::from_utf8_lossy(&key).into_owned(), value.to_vec()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        entries.reverse();
+        Ok(entries)
+    }
+
+    /// Entries whose key starts with `prefix`, in key order. `cursor` is the
+    /// `next_cursor` of the previous page.
+    pub fn scan_prefix(&self, db_name: &str, prefix: &str, limit: usize, cursor: Option<&str>) -> Result<ScanPage> {
+        let tree = self.tree(db_name)?;
+        let lower = match cursor {
+            Some(cursor) => Bound::Excluded(cursor.as_bytes()),
+            None => Bound::Included(prefix.as_bytes()),
+        };
+        let iter = tree
+            .range::<&[u8], _>((lower, Bound::Unbounded))
+            .take_while(|item| match item {
+                Ok((key, _)) => key.starts_with(prefix.as_bytes()),
+                Err(_) => true,
+            });
+        collect_page(iter, ScanOptions::with_limit(limit))
+    }
+
+    /// Entries with `start <= key < end` (either bound optional), in key
+    /// order. `cursor` is the `next_cursor` of the previous page.
+    pub fn range(
+        &self,
+        db_name: &str,
+        start: Option<&str>,
+        end: Option<&str>,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<ScanPage> {
+        let tree = self.tree(db_name)?;
+        let lower = match (cursor, start) {
+            (Some(cursor), _) => Bound::Excluded(cursor.as_bytes()),
+            (None, Some(start)) => Bound::Included(start.as_bytes()),
+            (None, None) => Bound::Unbounded,
+        };
+        let upper = end.map_or(Bound::Unbounded, |end| Bound::Excluded(end.as_bytes()));
+        collect_page(tree.range::<&[u8], _>((lower, upper)), ScanOptions::with_limit(limit))
+    }
+
+    /// Entries of one database in key order. `cursor` is the `next_cursor` of
+    /// the previous page.
+    pub fn entries_page(&self, db_name: &str, cursor: Option<&str>, options: ScanOptions) -> Result<ScanPage> {
+        let tree = self.tree(db_name)?;
+        let lower = cursor.map_or(Bound::Unbounded, |cursor| Bound::Excluded(cursor.as_bytes()));
+        collect_page(tree.range::<&[u8], _>((lower, Bound::Unbounded)), options)
+    }
+
+    /// Entries of every user database, in (database, key) order. `cursor` is
+    /// the `next_cursor` of the previous page.
+    pub fn all_entries_page(&self, cursor: Option<&str>, options: ScanOptions) -> Result<DataPage> {
+        let (start_db, start_key) = match cursor {
+            Some(cursor) => {
+                let (db, key) = cursor
+                    .split_once(DATA_CURSOR_SEPARATOR)
+                    .ok_or_else(|| anyhow!("Invalid cursor"))?;
+                (Some(db), Some(key))
+            }
+            None => (None, None),
+        };
+
+        let mut db_names = self.list_databases()?;
+        db_names.sort();
+
+        let mut budget = PageBudget::new(options);
+        let mut entries: Vec<(String, String, Vec<u8>)> = Vec::new();
+        for db_name in db_names.into_iter().filter(|db| start_db.is_none_or(|start| db.as_str() >= start)) {
+            let tree = self.tree(&db_name)?;
+            let lower = match start_key {
+                Some(key) if start_db == Some(db_name.as_str()) => Bound::Excluded(key.as_bytes()),
+                _ => Bound::Unbounded,
+            };
+            for item in tree.range::<&[u8], _>((lower, Bound::Unbounded)) {
+                let (key, value) = item?;
+                let Ok(key) = String::from_utf8(key.to_vec()) else {
+                    continue;
+                };
+                if !budget.admit(key.as_bytes(), &value) {
+                    let next_cursor = entries
+                        .last()
+                        .map(|(db, key, _)| format!("{}{}{}", db, DATA_CURSOR_SEPARATOR, key));
+                    return Ok(DataPage { entries, next_cursor });
+                }
+                entries.push((db_name.clone(), key, budget.value(value)));
+            }
+        }
+        Ok(DataPage { entries, next_cursor: None })
+    }
+
     /// Get all database names
     pub fn list_databases(&self) -> Result<Vec<String>> {
         let names: Vec<String> = self.db
             .tree_names()
             .iter()
             .filter_map(|n| String::from_utf8(n.to_vec()).ok())
-            .filter(|n| n != "__sled__default" && n != OPLOG_TREE)
+            .filter(|n| !n.starts_with(INTERNAL_TREE_PREFIX))
             .collect();
         Ok(names)
     }
 
-    /// Get cached storage size in bytes. Refreshed by `refresh_stats()`; this is
-    /// a cheap atomic load suitable for frequent polling from the UI.
+    /// Every tree (internal ones included, sled's default tree excluded) as
+    /// (name, entries), for backups
+    pub fn export_trees(&self) -> Result<Vec<TreeDump>> {
+        let default_name = self.db.name();
+        let mut trees = Vec::new();
+        for name in self.db.tree_names() {
+            if name == default_name {
+                continue;
+            }
+            let tree = self.db.open_tree(&name)?;
+            let entries = tree
+                .iter()
+                .map(|item| item.map(|(k, v)| (k.to_vec(), v.to_vec())))
+                .collect::<sled::Result<Vec<_>>>()?;
+            trees.push((String::from_utf8_lossy(&name).into_owned(), entries));
+        }
+        Ok(trees)
+    }
+
+    /// Replace the contents of the given trees (restore from a backup)
+    pub fn import_trees(&self, trees: Vec<TreeDump>) -> Result<()> {
+        self.ensure_writable()?;
+        for (name, entries) in trees {
+            let tree = self.tree(&name)?;
+            tree.clear()?;
+            let mut batch = sled::Batch::default();
+            for (key, value) in entries {
+                batch.insert(key, value);
+            }
+            tree.apply_batch(batch)?;
+        }
+        self.refresh_stats();
+        Ok(())
+    }
+
+    /// Get cached storage size in bytes (keys plus values). A cheap atomic load
+    /// suitable for frequent polling from the UI.
     pub fn size_bytes(&self) -> Result<u64> {
         Ok(self.cached_size_bytes.load(Ordering::Relaxed))
     }
 
-    /// Get cached total key count. Refreshed by `refresh_stats()`.
+    /// Get cached total key count.
     pub fn key_count(&self) -> Result<usize> {
         Ok(self.cached_key_count.load(Ordering::Relaxed) as usize)
     }
 
-    /// Recompute size/key-count by scanning every tree. O(N) — call from a
-    /// background task, not from request paths.
+    /// Recompute size/key-count by scanning every tree. O(N) — only needed
+    /// at open and after bulk replacement (`import_trees`); regular writes
+    /// keep the counters current.
     pub fn refresh_stats(&self) {
         let mut total_size: u64 = 0;
         let mut total_keys: u64 = 0;
         for name in self.db.tree_names() {
+            if UNCOUNTED_TREES.iter().any(|tree| name == tree.as_bytes()) {
+                continue;
+            }
             if let Ok(tree) = self.db.open_tree(&name) {
-                total_keys += tree.len() as u64;
-                for item in tree.iter() {
-                    if let Ok((key, value)) = item {
-                        total_size += key.len() as u64;
-                        total_size += value.len() as u64;
-                    }
-                }
+                let (keys, bytes) = tree_stats(&tree);
+                total_keys += keys;
+                total_size += bytes;
             }
         }
         self.cached_size_bytes.store(total_size, Ordering::Relaxed);
@@ -156,6 +1211,315 @@ impl Storage {
     /// Flush to disk
     pub fn flush(&self) -> Result<()> {
         self.db.flush()?;
+        self.last_flush_at.store(chrono::Utc::now().timestamp_millis(), Ordering::Relaxed);
         Ok(())
     }
+
+    /// When `flush` last succeeded in this session (ms since epoch)
+    pub fn last_flush_at(&self) -> Option<i64> {
+        Some(self.last_flush_at.load(Ordering::Relaxed)).filter(|at| *at > 0)
+    }
+
+    /// Write a setting and read it back, to tell whether the disk still
+    /// takes writes
+    pub fn check_writable(&self) -> Result<()> {
+        let probe = chrono::Utc::now().timestamp_millis().to_be_bytes();
+        self.put_setting(WRITE_PROBE_SETTING, &probe)?;
+        if self.get_setting(WRITE_PROBE_SETTING)?.as_deref() != Some(&probe[..]) {
+            return Err(anyhow!("Written value didn't read back"));
+        }
+        Ok(())
+    }
+}
+
+/// Take one page of entries with UTF-8 keys
+fn decode_seq(bytes: &[u8]) -> Result<u64> {
+    let bytes: [u8; 8] = bytes.try_into().map_err(|_| anyhow!("Invalid change sequence"))?;
+    Ok(u64::from_be_bytes(bytes))
+}
+
+/// (key count, bytes) of every entry in a tree
+fn tree_stats(tree: &Tree) -> (u64, u64) {
+    tree.iter()
+        .filter_map(|item| item.ok())
+        .fold((0, 0), |(keys, bytes), (key, value)| {
+            (keys + 1, bytes + key.len() as u64 + value.len() as u64)
+        })
+}
+
+/// Change in (key count, bytes) when `key` goes from `old` to `new`
+fn entry_delta(key: &[u8], old: Option<&[u8]>, new: Option<&[u8]>) -> (i64, i64) {
+    let size = |value: Option<&[u8]>| value.map_or(0, |v| (key.len() + v.len()) as i64);
+    (new.is_some() as i64 - old.is_some() as i64, size(new) - size(old))
+}
+
+fn collect_page(iter: impl Iterator<Item = sled::Result<(IVec, IVec)>>, options: ScanOptions) -> Result<ScanPage> {
+    let mut budget = PageBudget::new(options);
+    let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+
+    for item in iter {
+        let (key, value) = item?;
+        let Ok(key) = String::from_utf8(key.to_vec()) else {
+            continue;
+        };
+        if !budget.admit(key.as_bytes(), &value) {
+            // More entries follow, resume after the last one returned
+            let next_cursor = entries.last().map(|(key, _)| key.clone());
+            return Ok(ScanPage { entries, next_cursor });
+        }
+        entries.push((key, budget.value(value)));
+    }
+    Ok(ScanPage { entries, next_cursor: None })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_put_and_delete_notify_watchers() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        let mut rx = storage.subscribe();
+
+        storage.put("notes", "k", b"v1").unwrap();
+        storage.delete("notes", "k").unwrap();
+        // Deleting a missing key and internal trees are not reported
+        storage.delete("notes", "k").unwrap();
+        storage.put_setting("selective_sync", &[1]).unwrap();
+        // which can't be written by name
+        assert!(storage.put("__db_keys__", "notes", b"v").is_err());
+        assert!(storage.put_many(&[("__settings__".to_string(), "k".to_string(), b"v".to_vec())]).is_err());
+        assert!(storage.delete("__acl__", "notes").is_err());
+
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            StorageChange { seq: 1, db_name: "notes".into(), key: "k".into(), value: Some(b"v1".to_vec()) }
+        );
+        assert_eq!(rx.try_recv().unwrap().value, None);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_scan_prefix_and_range_paginate() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        for i in 1..=5 {
+            storage.put("chat", &format!("msg:{:04}", i), b"m").unwrap();
+        }
+        storage.put("chat", "msh", b"x").unwrap();
+        storage.put("chat", "member:alice", b"x").unwrap();
+
+        let first = storage.scan_prefix("chat", "msg:", 3, None).unwrap();
+        let keys: Vec<&str> = first.entries.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["msg:0001", "msg:0002", "msg:0003"]);
+        assert_eq!(first.next_cursor.as_deref(), Some("msg:0003"));
+
+        let rest = storage.scan_prefix("chat", "msg:", 3, first.next_cursor.as_deref()).unwrap();
+        assert_eq!(rest.entries.len(), 2);
+        assert_eq!(rest.next_cursor, None);
+
+        let range = storage.range("chat", Some("msg:0002"), Some("msg:0004"), 10, None).unwrap();
+        let keys: Vec<&str> = range.entries.iter().map(|(k, _)| k.as_str()).collect();
+        assert_eq!(keys, ["msg:0002", "msg:0003"]);
+        assert_eq!(range.next_cursor, None);
+    }
+
+    #[test]
+    fn test_all_entries_page_respects_limits() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        storage.put("a", "k1", &[0; 10]).unwrap();
+        storage.put("a", "k2", &[0; 10]).unwrap();
+        storage.put("b", "k1", &[0; 10]).unwrap();
+
+        let options = ScanOptions { limit: 2, max_bytes: None, keys_only: true };
+        let first = storage.all_entries_page(None, options).unwrap();
+        assert_eq!(first.entries.len(), 2);
+        assert!(first.entries.iter().all(|(_, _, value)| value.is_empty()));
+
+        let rest = storage.all_entries_page(first.next_cursor.as_deref(), options).unwrap();
+        assert_eq!(rest.entries, vec![("b".to_string(), "k1".to_string(), Vec::new())]);
+        assert_eq!(rest.next_cursor, None);
+
+        // Byte cap: each entry is 12 bytes, so only one fits in 20
+        let capped = ScanOptions { limit: 10, max_bytes: Some(20), keys_only: false };
+        let page = storage.entries_page("a", None, capped).unwrap();
+        assert_eq!(page.entries.len(), 1);
+        assert_eq!(page.next_cursor.as_deref(), Some("k1"));
+    }
+
+    #[test]
+    fn test_compare_and_swap() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+
+        assert!(storage.compare_and_swap("db", "k", None, Some(b"1")).unwrap());
+        assert!(!storage.compare_and_swap("db", "k", None, Some(b"2")).unwrap());
+        assert!(!storage.compare_and_swap("db", "k", Some(b"0"), Some(b"2")).unwrap());
+        assert!(storage.compare_and_swap("db", "k", Some(b"1"), Some(b"2")).unwrap());
+        assert_eq!(storage.get("db", "k").unwrap().unwrap(), b"2");
+        assert!(storage.compare_and_swap("db", "k", Some(b"2"), None).unwrap());
+        assert!(storage.get("db", "k").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_put_many_across_databases() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        let mut rx = storage.subscribe();
+
+        let entries = vec![
+            ("contacts".to_string(), "alice".to_string(), b"1".to_vec()),
+            ("contacts".to_string(), "bob".to_string(), b"2".to_vec()),
+            ("settings".to_string(), "theme".to_string(), b"dark".to_vec()),
+        ];
+        storage.put_many(&entries).unwrap();
+
+        assert_eq!(storage.list_keys("contacts").unwrap(), ["alice", "bob"]);
+        assert_eq!(storage.get("settings", "theme").unwrap().unwrap(), b"dark");
+        assert_eq!(rx.try_recv().unwrap().key, "alice");
+    }
+
+    #[test]
+    fn test_get_many_keeps_key_order() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        storage.put("db", "a", b"1").unwrap();
+        storage.put("db", "c", b"3").unwrap();
+
+        let keys = ["c", "b", "a"].map(String::from);
+        assert_eq!(
+            storage.get_many("db", &keys).unwrap(),
+            vec![Some(b"3".to_vec()), None, Some(b"1".to_vec())]
+        );
+    }
+
+    #[test]
+    fn test_stats_track_writes_incrementally() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        let assert_fresh = |storage: &Storage| {
+            let (keys, bytes) = (storage.key_count().unwrap(), storage.size_bytes().unwrap());
+            storage.refresh_stats();
+            assert_eq!((keys, bytes), (storage.key_count().unwrap(), storage.size_bytes().unwrap()));
+        };
+        // Internal settings written on open
+        let (base_keys, base_bytes) = (storage.key_count().unwrap(), storage.size_bytes().unwrap());
+
+        storage.put("db", "k", b"value").unwrap();
+        storage.put("db", "k", b"v").unwrap();
+        storage.put_operation("op-1", b"{}").unwrap();
+        assert_eq!(storage.key_count().unwrap(), base_keys + 2);
+        assert_eq!(storage.size_bytes().unwrap(), base_bytes + 2 + 6);
+        assert_fresh(&storage);
+
+        storage.put_many(&[("db".to_string(), "k".to_string(), b"vv".to_vec())]).unwrap();
+        storage
+            .apply_batch("other", &[("a".to_string(), b"1".to_vec())], &[("op-2".to_string(), b"{}".to_vec())])
+            .unwrap();
+        storage.compare_and_swap("db", "n", None, Some(b"1")).unwrap();
+        assert_fresh(&storage);
+
+        storage.delete("db", "k").unwrap();
+        storage.drop_database("other", &["op-2".to_string()]).unwrap();
+        assert_eq!(storage.key_count().unwrap(), base_keys + 2);
+        assert_fresh(&storage);
+    }
+
+    #[test]
+    fn test_read_only_storage_rejects_writes() {
+        let dir = tempdir().unwrap();
+        let key_count = {
+            let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+            storage.put("db", "k", b"v").unwrap();
+            storage.flush().unwrap();
+            storage.key_count().unwrap()
+        };
+
+        let storage = Storage::open_read_only(dir.path()).unwrap();
+        assert_eq!(storage.get("db", "k").unwrap().unwrap(), b"v");
+        assert_eq!(storage.key_count().unwrap(), key_count);
+        assert!(storage.put("db", "k", b"w").is_err());
+        assert!(storage.delete("db", "k").is_err());
+        assert!(storage.get("missing", "k").is_err());
+        assert_eq!(storage.list_databases().unwrap(), ["db"]);
+
+        assert!(Storage::open_read_only(&dir.path().join("nope")).is_err());
+    }
+
+    #[test]
+    fn test_search_follows_writes() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+
+        storage.put("notes", "a", br#"{"title": "Buy oat milk"}"#).unwrap();
+        storage.put("notes", "b", b"Oat cookies recipe").unwrap();
+        storage.put("other", "c", b"oat").unwrap();
+
+        let keys = |query: &str| -> Vec<String> {
+            storage.search("notes", query, 10).unwrap().into_iter().map(|(key, _)| key).collect()
+        };
+        assert_eq!(keys("oat"), ["a", "b"]);
+        assert_eq!(keys("OAT mi"), ["a"]);
+        assert!(keys("oa milk").is_empty());
+
+        storage.put("notes", "a", b"Buy bread").unwrap();
+        assert_eq!(keys("oat"), ["b"]);
+        storage.delete("notes", "b").unwrap();
+        assert!(keys("oat").is_empty());
+
+        storage.drop_database("other", &[]).unwrap();
+        assert!(storage.search("other", "oat", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_change_log_survives_reopen() {
+        let dir = tempdir().unwrap();
+        {
+            let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+            storage.put("notes", "a", b"1").unwrap();
+            storage.put("other", "b", b"2").unwrap();
+            storage.delete("notes", "a").unwrap();
+            assert_eq!(storage.latest_change_seq(), 3);
+            storage.flush().unwrap();
+        }
+
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        storage.put("notes", "c", b"3").unwrap();
+
+        let changes = storage.changes_since(Some("notes"), 0, 10).unwrap();
+        let seqs: Vec<u64> = changes.iter().map(|change| change.seq).collect();
+        assert_eq!(seqs, [1, 3, 4]);
+        assert_eq!(changes[1].value, None);
+        assert_eq!(storage.changes_since(None, 3, 10).unwrap()[0].key, "c");
+    }
+
+    #[test]
+    fn test_outbox_keeps_order_across_reopen() {
+        let dir = tempdir().unwrap();
+        {
+            let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+            assert!(storage.outbox_is_empty().unwrap());
+            storage.outbox_push(b"first", Some("notes")).unwrap();
+            storage.outbox_push(b"second", None).unwrap();
+            storage.flush().unwrap();
+        }
+
+        // Queued operations are counted again on open
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        assert_eq!(storage.outbox_queued("notes"), 1);
+        storage.outbox_push(b"third", Some("notes")).unwrap();
+        assert_eq!(storage.outbox_queued("notes"), 2);
+        assert_eq!(storage.outbox_queued("tasks"), 0);
+        let queued = storage.outbox_peek(2).unwrap();
+        let payloads: Vec<&[u8]> = queued.iter().map(|(_, payload)| payload.as_slice()).collect();
+        assert_eq!(payloads, [b"first".as_slice(), b"second".as_slice()]);
+
+        storage.outbox_remove(queued[0].0).unwrap();
+        assert_eq!(storage.outbox_len().unwrap(), 2);
+        assert_eq!(storage.outbox_queued("notes"), 1);
+        assert_eq!(storage.outbox_peek(10).unwrap()[0].1, b"second");
+    }
 }
@@ -0,0 +1,225 @@
+//! Store types and how their operations are applied
+//!
+//! Every operation names its store type. What differs between the types -
+//! which checks the signature doesn't cover, the key a value is stored
+//! under and what is derived from the stored values - is an `ApplyHandler`
+//! in the module of the type (`counter`, `text`, `typed`, or here for the
+//! plain ones), looked up by `handler`. A new store type is a `StoreType`
+//! variant plus its handler registered in `StoreTypeRegistry::with_builtins`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use anyhow::{anyhow, Result};
+use tokio::sync::broadcast;
+
+use crate::blob_values::BLOB_STORE_TYPE;
+use crate::counter::{CounterHandler, COUNTER_STORE_TYPE};
+use crate::storage::Storage;
+use crate::sync::SignedOperation;
+use crate::text::{TextDelta, TextHandler, TEXT_STORE_TYPE};
+use crate::typed::CollectionHandler;
+
+/// Store types of the desktop node's protocol and this node's own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StoreType {
+    String,
+    Hash,
+    List,
+    Set,
+    SortedSet,
+    Json,
+    Stream,
+    TimeSeries,
+    Geo,
+    Counter,
+    Blob,
+    Text,
+}
+
+impl StoreType {
+    pub const ALL: [StoreType; 12] = [
+        StoreType::String,
+        StoreType::Hash,
+        StoreType::List,
+        StoreType::Set,
+        StoreType::SortedSet,
+        StoreType::Json,
+        StoreType::Stream,
+        StoreType::TimeSeries,
+        StoreType::Geo,
+        StoreType::Counter,
+        StoreType::Blob,
+        StoreType::Text,
+    ];
+
+    /// Name in operations
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StoreType::String => "String",
+            StoreType::Hash => "Hash",
+            StoreType::List => "List",
+            StoreType::Set => "Set",
+            StoreType::SortedSet => "SortedSet",
+            StoreType::Json => "JSON",
+            StoreType::Stream => "Stream",
+            StoreType::TimeSeries => "TimeSeries",
+            StoreType::Geo => "Geo",
+            StoreType::Counter => COUNTER_STORE_TYPE,
+            StoreType::Blob => BLOB_STORE_TYPE,
+            StoreType::Text => TEXT_STORE_TYPE,
+        }
+    }
+}
+
+impl fmt::Display for StoreType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StoreType {
+    type Err = anyhow::Error;
+
+    /// Case-insensitive, like the desktop node
+    fn from_str(s: &str) -> Result<Self> {
+        StoreType::ALL
+            .into_iter()
+            .find(|store_type| store_type.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| anyhow!("Unknown store type {}", s))
+    }
+}
+
+/// What a handler may touch while applying an operation
+pub struct ApplyContext<'a> {
+    pub storage: &'a Storage,
+    /// Changes of collaborative texts, for `SyncStore::subscribe_text_deltas`
+    pub text_deltas: &'a broadcast::Sender<TextDelta>,
+}
+
+/// How operations of one store type are applied. The value of every
+/// operation is stored under `storage_key` (or removed once expired); a
+/// handler only adds what's particular to its type.
+pub trait ApplyHandler: Send + Sync {
+    fn store_type(&self) -> StoreType;
+
+    /// Check what the signature doesn't, e.g. that the value parses
+    fn validate(&self, _op: &SignedOperation) -> Result<()> {
+        Ok(())
+    }
+
+    /// Key the value is stored under in its database tree
+    fn storage_key(&self, op: &SignedOperation) -> Result<String> {
+        Ok(op.key.clone())
+    }
+
+    /// Update what's derived from the stored values of `op.key` after `op`
+    /// was written, e.g. a counter's sum. Once per key for a batch.
+    fn materialize(&self, _ctx: &ApplyContext<'_>, _op: &SignedOperation) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Values stored as they are under the plain key: String, JSON, Stream and
+/// Blob (a `BlobRef`)
+struct PlainHandler(StoreType);
+
+impl ApplyHandler for PlainHandler {
+    fn store_type(&self) -> StoreType {
+        self.0
+    }
+}
+
+/// One operation per hash field, stored under `<key>:<field>`
+struct HashHandler;
+
+impl ApplyHandler for HashHandler {
+    fn store_type(&self) -> StoreType {
+        StoreType::Hash
+    }
+
+    fn storage_key(&self, op: &SignedOperation) -> Result<String> {
+        let field = op.field.as_ref().ok_or_else(|| anyhow!("Field required for Hash type"))?;
+        Ok(format!("{}:{}", op.key, field))
+    }
+}
+
+/// Handlers by store type
+pub struct StoreTypeRegistry {
+    handlers: HashMap<StoreType, Box<dyn ApplyHandler>>,
+}
+
+impl StoreTypeRegistry {
+    /// A handler for every `StoreType`
+    pub fn with_builtins() -> Self {
+        let mut registry = Self { handlers: HashMap::new() };
+        for store_type in [StoreType::String, StoreType::Json, StoreType::Stream, StoreType::Blob] {
+            registry.register(Box::new(PlainHandler(store_type)));
+        }
+        registry.register(Box::new(HashHandler));
+        for store_type in [StoreType::List, StoreType::Set, StoreType::SortedSet, StoreType::TimeSeries, StoreType::Geo] {
+            registry.register(Box::new(CollectionHandler(store_type)));
+        }
+        registry.register(Box::new(CounterHandler));
+        registry.register(Box::new(TextHandler));
+        registry
+    }
+
+    /// Handle operations of `handler.store_type()` with `handler` from now on
+    pub fn register(&mut self, handler: Box<dyn ApplyHandler>) {
+        self.handlers.insert(handler.store_type(), handler);
+    }
+
+    pub fn get(&self, store_type: StoreType) -> Result<&dyn ApplyHandler> {
+        self.handlers
+            .get(&store_type)
+            .map(|handler| handler.as_ref())
+            .ok_or_else(|| anyhow!("No handler for store type {}", store_type))
+    }
+}
+
+static REGISTRY: LazyLock<StoreTypeRegistry> = LazyLock::new(StoreTypeRegistry::with_builtins);
+
+/// Handler of operations of `store_type`
+pub fn handler(store_type: StoreType) -> Result<&'static dyn ApplyHandler> {
+    REGISTRY.get(store_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(store_type: &str, field: Option<&str>) -> SignedOperation {
+        let mut op = SignedOperation::new(
+            "db".to_string(),
+            "key".to_string(),
+            "value".to_string(),
+            store_type.to_string(),
+            String::new(),
+            String::new(),
+        );
+        op.field = field.map(str::to_string);
+        op
+    }
+
+    #[test]
+    fn test_store_type_registry() {
+        for store_type in StoreType::ALL {
+            assert_eq!(store_type.as_str().to_lowercase().parse::<StoreType>().unwrap(), store_type);
+            assert_eq!(handler(store_type).unwrap().store_type(), store_type);
+        }
+        assert!("Document".parse::<StoreType>().is_err());
+        assert!(op("Document", None).storage_key().is_err());
+
+        assert_eq!(op("json", None).storage_key().unwrap(), "key");
+        assert_eq!(op("Hash", Some("en")).storage_key().unwrap(), "key:en");
+        assert!(op("Hash", None).storage_key().is_err());
+        // Collections take the element as field; older writers didn't
+        assert_eq!(op("Set", Some("a")).storage_key().unwrap(), "key:a");
+        assert_eq!(op("Geo", None).storage_key().unwrap(), "key");
+        assert_eq!(op("Counter", Some("r1")).storage_key().unwrap(), "__counter__:key:r1");
+        assert_eq!(op("Text", Some("r1:1")).storage_key().unwrap(), "__text__:key:r1:1");
+    }
+}
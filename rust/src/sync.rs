@@ -8,18 +8,163 @@
 //! 2. Short format: db_name:key:value (for client submissions)
 
 use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine as _};
 use serde::{Deserialize, Serialize};
-use tokio::sync::RwLock;
-use tracing::{debug, error, info, warn};
+use tokio::sync::{broadcast, mpsc, RwLock};
+use tracing::field::Empty;
+use tracing::{debug, error, info, info_span, warn, Instrument, Span};
 
+use crate::acl::{self, AclStore, DbTombstone, WriterList};
+use crate::blob_values::{BlobRef, BlobValues, BLOB_STORE_TYPE};
+use crate::counter::{CounterState, COUNTER_STORE_TYPE};
 use crate::crypto;
-use crate::storage::Storage;
+use crate::devices;
+use crate::merkle::{self, RootCheck, SignedRoot, StateTree};
+use crate::metrics::{BandwidthMeter, Subsystem, SNAPSHOTS_TOPIC};
+use crate::node::NodeEvent;
+use crate::op_cache::OpCache;
+use crate::snapshot::{SnapshotInfo, SnapshotManager, SNAPSHOT_MIN_OPS};
+use crate::storage::{Storage, INTERNAL_TREE_PREFIX};
+use crate::store_types::{self, ApplyContext, ApplyHandler, StoreType};
+use crate::text::{self, TextDelta, TextEdit, TEXT_STORE_TYPE};
 
-/// Maximum operations per sync response (to avoid oversized payloads)
-const MAX_OPS_PER_RESPONSE: usize = 128;
+/// Default operations per sync response (to avoid oversized payloads), see
+/// `NodeConfig::max_ops_per_response`
+pub const MAX_OPS_PER_RESPONSE: usize = 128;
+
+/// Most operation IDs confirmed by one `OperationAck`
+pub const MAX_ACK_BATCH: usize = 512;
+
+/// zstd level used for sync payloads (fast, still ~5-10x on JSON ops)
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Payloads smaller than this are sent uncompressed - not worth the CPU
+const COMPRESSION_MIN_BYTES: usize = 4 * 1024;
+
+/// Upper bound on a decompressed operations array, guards against zstd bombs
+const MAX_DECOMPRESSED_BYTES: usize = 16 * 1024 * 1024;
+
+/// How long a peer has to acknowledge the final response of a sync before
+/// its next request gets a full answer again
+const SYNC_MARK_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Responses to one of our sync requests are accepted this long after it
+/// was sent; later ones are stale or replayed
+const SYNC_REQUEST_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Prefix of keys holding a discarded local version after an LWW conflict
+/// (see `SyncStore::set_retain_conflicts`)
+pub const CONFLICT_KEY_PREFIX: &str = "__conflict__:";
+
+/// Text deltas buffered per subscriber before the oldest are dropped
+const TEXT_DELTA_CHANNEL_CAPACITY: usize = 256;
+
+/// Default `OperationLimits::max_value_bytes`
+pub const DEFAULT_MAX_VALUE_BYTES: usize = 512 * 1024;
+
+/// Default `OperationLimits::max_key_len`
+pub const DEFAULT_MAX_KEY_LEN: usize = 1024;
+
+/// Bounds on operations received from peers (see `NodeConfig::operation_limits`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationLimits {
+    pub max_value_bytes: usize,
+    /// Longest key, and also field, database name and operation ID
+    pub max_key_len: usize,
+    /// Accepted store types (see `StoreType`), compared case-insensitively
+    pub store_types: Vec<String>,
+}
+
+impl Default for OperationLimits {
+    fn default() -> Self {
+        Self {
+            max_value_bytes: DEFAULT_MAX_VALUE_BYTES,
+            max_key_len: DEFAULT_MAX_KEY_LEN,
+            store_types: StoreType::ALL.iter().map(|store_type| store_type.as_str().to_string()).collect(),
+        }
+    }
+}
+
+impl OperationLimits {
+    /// Error naming the limit `op` exceeds, if any
+    pub fn check(&self, op: &SignedOperation) -> Result<()> {
+        if op.value.len() > self.max_value_bytes {
+            return Err(anyhow!("Value of {} bytes exceeds {} bytes", op.value.len(), self.max_value_bytes));
+        }
+        let names = [
+            ("Key", Some(&op.key)),
+            ("Field", op.field.as_ref()),
+            ("Database name", Some(&op.db_name)),
+            ("Operation ID", Some(&op.op_id)),
+        ];
+        for (name, value) in names {
+            if let Some(value) = value.filter(|value| value.len() > self.max_key_len) {
+                return Err(anyhow!("{} of {} bytes exceeds {} bytes", name, value.len(), self.max_key_len));
+            }
+        }
+        if !self.store_types.iter().any(|store_type| store_type.eq_ignore_ascii_case(&op.store_type)) {
+            return Err(anyhow!("Store type {} isn't accepted", op.store_type));
+        }
+        Ok(())
+    }
+}
+
+/// Which superseded operations stay in the oplog (see `NodeConfig::oplog_gc`).
+/// Only the current operation of a key is ever synced, so the others are
+/// kept for a while only in case a late write still needs resolving.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OplogGcPolicy {
+    /// Newest versions kept per key, the current one included
+    pub keep_versions: usize,
+    /// Versions written within this are kept too
+    pub keep_for: Duration,
+}
+
+impl Default for OplogGcPolicy {
+    fn default() -> Self {
+        Self { keep_versions: 1, keep_for: Duration::from_secs(7 * 24 * 60 * 60) }
+    }
+}
+
+/// Setting key persisting `SyncFilter::selective`
+const SELECTIVE_SYNC_SETTING: &str = "selective_sync";
+
+/// Setting key persisting `SyncStore::high_water_mark` at checkpoints
+const HIGH_WATER_MARK_SETTING: &str = "oplog_high_water_mark";
+
+/// Which databases this device replicates. By default everything is synced;
+/// in selective mode only followed databases, and those of user keys this
+/// device is linked to, are requested and accepted.
+#[derive(Debug, Clone, Default)]
+pub struct SyncFilter {
+    pub selective: bool,
+    pub databases: HashSet<String>,
+    /// User keys with linked devices (see `devices`); all their databases
+    /// are replicated
+    pub owners: HashSet<String>,
+    /// Databases kept on this device only, whatever the mode: writes stay
+    /// out of the oplog and nothing is sent or accepted
+    pub local_only: HashSet<String>,
+}
+
+impl SyncFilter {
+    /// Whether operations for `db_name` should be replicated
+    pub fn allows(&self, db_name: &str) -> bool {
+        !self.local_only.contains(db_name)
+            && (!self.selective || self.databases.contains(db_name) || owned_by(db_name, &self.owners))
+    }
+}
+
+/// Whether `db_name` is named after one of `owners`
+fn owned_by<'a>(db_name: &str, owners: impl IntoIterator<Item = &'a String>) -> bool {
+    acl::db_owner(db_name).is_some_and(|owner| owners.into_iter().any(|o| o == owner))
+}
 
 /// Sync message types for gossip
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +174,33 @@ pub enum SyncMessage {
     SyncRequest {
         requester: String,            // Node ID as string
         since_timestamp: Option<i64>, // Unix timestamp ms, None = full sync
+        /// Requester can decode `compressed_operations` (older nodes omit this)
+        #[serde(default)]
+        accept_compression: bool,
+        /// Requester can fetch a `SnapshotOffer` over iroh-blobs
+        #[serde(default)]
+        accept_snapshot: bool,
+        /// Only these databases (requester is in selective sync mode)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        databases: Option<Vec<String>>,
+        /// Plus every database owned by these user keys
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        owners: Vec<String>,
+        /// Short ID tying the responses and follow-up requests of one sync
+        /// exchange together in logs (older nodes omit this)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+        /// Final responses of earlier exchanges the requester received, so
+        /// their responders only send what came after
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        acks: Vec<SyncAck>,
+        /// Unix ms the request was sent; signed requests older than
+        /// `SYNC_REQUEST_TTL` are stale (older nodes omit this)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sent_at: Option<i64>,
+        /// By the requester's node key (older nodes omit this)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        signature: Option<String>,
     },
     /// Response with data operations
     SyncResponse {
@@ -36,17 +208,214 @@ pub enum SyncMessage {
         operations: Vec<SignedOperation>,
         has_more: bool,
         continuation_token: Option<String>,
+        /// Base64 zstd-compressed JSON array of operations. When set,
+        /// `operations` is empty.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        compressed_operations: Option<String>,
+        /// Node ID of the responder (gossip `delivered_from` is only the neighbor)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        responder: Option<String>,
+        /// Operations the responder still has after this chunk, for progress UI
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        remaining: Option<u64>,
+        /// Writer lists of the databases in this chunk, applied before the ops
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        acls: Vec<WriterList>,
+        /// Databases dropped since the requested timestamp
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tombstones: Vec<DbTombstone>,
+        /// `request_id` of the request this answers
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+        /// By the responder's node key (older nodes omit this)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        signature: Option<String>,
     },
     /// New operation to be replicated
     Operation {
         operation: SignedOperation,
     },
+    /// `from` holds these broadcast operations, or newer writes to their
+    /// keys; lets the writer tell when a write reached a peer
+    OperationAck {
+        from: String,
+        op_ids: Vec<String>,
+        /// By `from`'s node key; unsigned acks aren't counted
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        signature: Option<String>,
+    },
+    /// Answer to a large full sync: per-database snapshots to fetch from
+    /// `provider` over iroh-blobs instead of op-by-op responses
+    SnapshotOffer {
+        requester: String,
+        provider: String,
+        snapshots: Vec<SnapshotInfo>,
+    },
+    /// Owner-signed update of a database's authorized writers
+    AclUpdate {
+        acl: WriterList,
+    },
+    /// Owner-signed drop of a whole database
+    DropDatabase {
+        tombstone: DbTombstone,
+    },
+    /// Owner-signed Merkle root of a database's state (see `merkle`)
+    MerkleRoot {
+        root: SignedRoot,
+    },
+    /// Ask the publisher of a root for the operations of the buckets where
+    /// the requester's replica differs
+    RepairRequest {
+        requester: String,
+        provider: String,
+        db_name: String,
+        buckets: Vec<u8>,
+        /// Echoed in the `SyncResponse` with the operations
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        request_id: Option<String>,
+    },
+}
+
+/// The requester received the final response `responder` sent to its
+/// request `request_id`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncAck {
+    pub responder: String,
+    pub request_id: String,
+}
+
+impl SyncMessage {
+    /// Name of the message type, for logs
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SyncMessage::SyncRequest { .. } => "SyncRequest",
+            SyncMessage::SyncResponse { .. } => "SyncResponse",
+            SyncMessage::Operation { .. } => "Operation",
+            SyncMessage::OperationAck { .. } => "OperationAck",
+            SyncMessage::SnapshotOffer { .. } => "SnapshotOffer",
+            SyncMessage::AclUpdate { .. } => "AclUpdate",
+            SyncMessage::DropDatabase { .. } => "DropDatabase",
+            SyncMessage::MerkleRoot { .. } => "MerkleRoot",
+            SyncMessage::RepairRequest { .. } => "RepairRequest",
+        }
+    }
+
+    /// Node ID whose key signs the envelope: the requester of a
+    /// `SyncRequest`, the responder of a `SyncResponse`, the sender of an
+    /// `OperationAck`
+    fn envelope_signer(&self) -> Option<&str> {
+        match self {
+            SyncMessage::SyncRequest { requester, .. } => Some(requester),
+            SyncMessage::SyncResponse { responder, .. } => responder.as_deref(),
+            SyncMessage::OperationAck { from, .. } => Some(from),
+            _ => None,
+        }
+    }
+
+    fn envelope_signature(&self) -> Option<&str> {
+        match self {
+            SyncMessage::SyncRequest { signature, .. }
+            | SyncMessage::SyncResponse { signature, .. }
+            | SyncMessage::OperationAck { signature, .. } => signature.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// What the envelope signature covers. Operations, writer lists and
+    /// tombstones are signed themselves, so their signatures stand in for
+    /// their content.
+    fn envelope_signing_message(&self) -> Option<String> {
+        match self {
+            SyncMessage::SyncRequest {
+                requester,
+                since_timestamp,
+                accept_compression,
+                accept_snapshot,
+                databases,
+                owners,
+                request_id,
+                acks,
+                sent_at,
+                ..
+            } => {
+                let acks: Vec<String> = acks.iter().map(|ack| format!("{}/{}", ack.responder, ack.request_id)).collect();
+                Some(format!(
+                    "sync-request:{}:{:?}:{}:{}:{:?}:{:?}:{:?}:{:?}:{:?}",
+                    requester, since_timestamp, accept_compression, accept_snapshot, databases, owners, request_id, acks, sent_at
+                ))
+            }
+            SyncMessage::SyncResponse {
+                requester,
+                operations,
+                has_more,
+                continuation_token,
+                compressed_operations,
+                responder,
+                remaining,
+                acls,
+                tombstones,
+                request_id,
+                ..
+            } => {
+                let mut contents = blake3::Hasher::new();
+                for op in operations {
+                    contents.update(format!("{}:{}\n", op.op_id, op.signature).as_bytes());
+                }
+                if let Some(encoded) = compressed_operations {
+                    contents.update(encoded.as_bytes());
+                }
+                for signature in acls.iter().map(|acl| &acl.signature).chain(tombstones.iter().map(|t| &t.signature)) {
+                    contents.update(format!("{}\n", signature).as_bytes());
+                }
+                Some(format!(
+                    "sync-response:{}:{:?}:{}:{:?}:{:?}:{:?}:{}",
+                    requester,
+                    responder,
+                    has_more,
+                    continuation_token,
+                    remaining,
+                    request_id,
+                    contents.finalize().to_hex()
+                ))
+            }
+            SyncMessage::OperationAck { from, op_ids, .. } => Some(format!("operation-ack:{}:{:?}", from, op_ids)),
+            _ => None,
+        }
+    }
+
+    /// Sign a `SyncRequest`, `SyncResponse` or `OperationAck` with the
+    /// sender's node key; other messages are left alone
+    pub fn sign_envelope(&mut self, node_key: &ed25519_dalek::SigningKey) {
+        let Some(message) = self.envelope_signing_message() else {
+            return;
+        };
+        let signed = crypto::sign_message(node_key, message.as_bytes());
+        if let SyncMessage::SyncRequest { signature, .. }
+        | SyncMessage::SyncResponse { signature, .. }
+        | SyncMessage::OperationAck { signature, .. } = self
+        {
+            *signature = Some(signed);
+        }
+    }
+
+    /// Whether the envelope is signed; an error if the signature doesn't
+    /// verify against its sender
+    pub fn verify_envelope(&self) -> Result<bool> {
+        let (Some(message), Some(signature)) = (self.envelope_signing_message(), self.envelope_signature()) else {
+            return Ok(false);
+        };
+        let signer = self.envelope_signer().ok_or_else(|| anyhow!("Signed {} without a sender", self.kind()))?;
+        if !crypto::verify_signature(signer, message.as_bytes(), signature)? {
+            return Err(anyhow!("Invalid {} signature from {}", self.kind(), signer));
+        }
+        Ok(true)
+    }
 }
 
 /// A signed data operation that can be verified and merged
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignedOperation {
-    /// Unique operation ID (UUID)
+    /// Unique operation ID: a UUID, or `content_op_id` for idempotent writes
     pub op_id: String,
     /// Unix timestamp (milliseconds)
     pub timestamp: i64,
@@ -56,7 +425,8 @@ pub struct SignedOperation {
     pub key: String,
     /// The data value (JSON string or raw value)
     pub value: String,
-    /// Store type: String, Hash, List, Set, SortedSet, JSON, Stream
+    /// Store type: String, Hash, List, Set, SortedSet, JSON, Stream,
+    /// TimeSeries, Geo, Counter, Blob, Text
     pub store_type: String,
     /// Optional field for Hash store type
     pub field: Option<String>,
@@ -72,6 +442,9 @@ pub struct SignedOperation {
     pub longitude: Option<f64>,
     /// Optional latitude for Geo
     pub latitude: Option<f64>,
+    /// Optional expiry (Unix ms) after which the key is removed on every peer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
     /// Public key of the signer (hex)
     pub public_key: String,
     /// Ed25519 signature (hex)
@@ -83,6 +456,7 @@ impl SignedOperation {
     /// Supports two formats:
     /// 1. Full format: op_id:timestamp:db_name:key:value (for sync operations)
     /// 2. Short format: db_name:key:value (for GraphQL/client submissions)
+    ///
     /// Matches cyberfly-rust-node's verify method
     pub fn verify(&self) -> Result<bool> {
         // Enhanced database name verification (if public key is provided)
@@ -100,18 +474,19 @@ impl SignedOperation {
         }
 
         // Try full format first (op_id:timestamp:db_name:key:value)
-        let full_message = format!(
-            "{}:{}:{}:{}:{}",
-            self.op_id, self.timestamp, self.db_name, self.key, self.value
-        );
-        
-        if crypto::verify_signature(&self.public_key, full_message.as_bytes(), &self.signature)
+        if crypto::verify_signature(&self.public_key, self.full_message().as_bytes(), &self.signature)
             .unwrap_or(false)
         {
             debug!(op_id = %self.op_id, "Signature verified with full format");
             return Ok(true);
         }
 
+        // The short format doesn't cover the expiry
+        if self.expires_at.is_some() {
+            warn!(op_id = %self.op_id, "Signature verification failed for expiring operation");
+            return Ok(false);
+        }
+
         // Try short format (db_name:key:value) - used by GraphQL client
         let short_message = format!("{}:{}:{}", self.db_name, self.key, self.value);
         
@@ -133,11 +508,82 @@ impl SignedOperation {
     
     /// Verify with full format only (for operations we created locally)
     pub fn verify_full(&self) -> Result<bool> {
+        crypto::verify_signature(&self.public_key, self.full_message().as_bytes(), &self.signature)
+    }
+
+    /// Full format signing message: op_id:timestamp:db_name:key:value, with
+    /// `:expires_at` appended for expiring operations so the TTL is signed too
+    pub fn full_message(&self) -> String {
         let message = format!(
             "{}:{}:{}:{}:{}",
             self.op_id, self.timestamp, self.db_name, self.key, self.value
         );
-        crypto::verify_signature(&self.public_key, message.as_bytes(), &self.signature)
+        match self.expires_at {
+            Some(expires_at) => format!("{}:{}", message, expires_at),
+            None => message,
+        }
+    }
+
+    /// Whether the operation's TTL has passed at `now` (Unix ms)
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Whether the value is end-to-end encrypted ciphertext (see `crypto::encrypt_value`)
+    pub fn is_encrypted(&self) -> bool {
+        crypto::is_encrypted_value(&self.value)
+    }
+
+    /// Handler of the operation's store type; an error for unknown types
+    pub fn handler(&self) -> Result<&'static dyn ApplyHandler> {
+        store_types::handler(self.store_type.parse()?)
+    }
+
+    /// Key the operation's value is stored under in its database tree
+    pub fn storage_key(&self) -> Result<String> {
+        self.handler()?.storage_key(self)
+    }
+
+    /// Whether this is a PN-counter operation
+    pub fn is_counter(&self) -> bool {
+        self.store_type.eq_ignore_ascii_case(COUNTER_STORE_TYPE)
+    }
+
+    /// Whether this is a collaborative text edit (see `text`)
+    pub fn is_text(&self) -> bool {
+        self.store_type.eq_ignore_ascii_case(TEXT_STORE_TYPE)
+    }
+
+    /// Whether the value is a `BlobRef` to content offloaded to iroh-blobs
+    pub fn is_blob(&self) -> bool {
+        self.store_type.eq_ignore_ascii_case(BLOB_STORE_TYPE)
+    }
+
+    /// Verify a `Blob` operation, whose signature covers the offloaded
+    /// `content` rather than the reference
+    pub fn verify_blob_content(&self, content: &[u8]) -> Result<bool> {
+        let op = SignedOperation { value: String::from_utf8_lossy(content).into_owned(), ..self.clone() };
+        op.verify()
+    }
+
+    /// Replica totals carried by a PN-counter operation. The replica is
+    /// inside the signed value, so it must match the (unsigned) field.
+    pub fn counter_state(&self) -> Result<CounterState> {
+        let state: CounterState = serde_json::from_str(&self.value)?;
+        if self.field.as_deref() != Some(state.replica.as_str()) {
+            return Err(anyhow!("Counter operation {} targets another replica", self.op_id));
+        }
+        Ok(state)
+    }
+
+    /// Edit carried by a text operation. Its ID is inside the signed value,
+    /// so it must match the (unsigned) field.
+    pub fn text_edit(&self) -> Result<TextEdit> {
+        let edit: TextEdit = serde_json::from_str(&self.value)?;
+        if self.field.as_deref() != Some(edit.edit_id().as_str()) {
+            return Err(anyhow!("Text operation {} has another edit ID", self.op_id));
+        }
+        Ok(edit)
     }
 
     /// Get a comparable key for CRDT ordering (db_name:key:field)
@@ -172,6 +618,7 @@ impl SignedOperation {
             ts_timestamp: None,
             longitude: None,
             latitude: None,
+            expires_at: None,
             public_key,
             signature,
         }
@@ -185,510 +632,3489 @@ impl SignedOperation {
         store_type: String,
         signing_key: &ed25519_dalek::SigningKey,
     ) -> Self {
-        let op_id = uuid::Uuid::new_v4().to_string();
-        let timestamp = chrono::Utc::now().timestamp_millis();
         let public_key = crypto::public_key_hex(signing_key);
-        
+        let mut op = Self::new(db_name, key, value, store_type, public_key, String::new());
+
         // Sign with full format
-        let message = format!("{}:{}:{}:{}:{}", op_id, timestamp, db_name, key, value);
-        let signature = crypto::sign_message(signing_key, message.as_bytes());
-        
+        op.signature = crypto::sign_message(signing_key, op.full_message().as_bytes());
+        op
+    }
+
+    /// Create and sign an operation whose key expires at `expires_at` (Unix ms)
+    pub fn create_and_sign_with_expiry(
+        db_name: String,
+        key: String,
+        value: String,
+        store_type: String,
+        expires_at: i64,
+        signing_key: &ed25519_dalek::SigningKey,
+    ) -> Self {
+        let public_key = crypto::public_key_hex(signing_key);
+        let mut op = Self::new(db_name, key, value, store_type, public_key, String::new());
+        op.expires_at = Some(expires_at);
+
+        op.signature = crypto::sign_message(signing_key, op.full_message().as_bytes());
+        op
+    }
+
+    /// Create and sign an operation whose op_id is `content_op_id`, so
+    /// submitting the same write (with the same timestamp) again yields the
+    /// same operation and is deduplicated instead of applied twice
+    pub fn create_and_sign_idempotent(
+        db_name: String,
+        key: String,
+        value: String,
+        store_type: String,
+        timestamp: i64,
+        signing_key: &ed25519_dalek::SigningKey,
+    ) -> Self {
+        let public_key = crypto::public_key_hex(signing_key);
+        let mut op = Self::new(db_name, key, value, store_type, public_key, String::new());
+        op.timestamp = timestamp;
+        op.op_id = content_op_id(&op.db_name, &op.key, &op.value, timestamp, &op.public_key);
+
+        op.signature = crypto::sign_message(signing_key, op.full_message().as_bytes());
+        op
+    }
+}
+
+/// Operation ID derived from a write's content: blake3 of
+/// `db_name:key:value:timestamp:public_key` (hex)
+pub fn content_op_id(db_name: &str, key: &str, value: &str, timestamp: i64, public_key: &str) -> String {
+    crypto::blake3_hex(format!("{}:{}:{}:{}:{}", db_name, key, value, timestamp, public_key).as_bytes())
+}
+
+/// Compress an operations array for a `SyncResponse` (zstd + base64)
+pub fn compress_operations(operations: &[SignedOperation]) -> Result<String> {
+    let json = serde_json::to_vec(operations)?;
+    let compressed = zstd::stream::encode_all(&json[..], COMPRESSION_LEVEL)?;
+    Ok(general_purpose::STANDARD.encode(compressed))
+}
+
+/// Decompress `compressed_operations`, refusing anything that inflates past
+/// `MAX_DECOMPRESSED_BYTES`
+pub fn decompress_operations(encoded: &str) -> Result<Vec<SignedOperation>> {
+    let compressed = general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| anyhow!("Invalid base64 in compressed operations: {}", e))?;
+
+    let decoder = zstd::stream::read::Decoder::new(&compressed[..])?;
+    let mut json = Vec::new();
+    decoder
+        .take(MAX_DECOMPRESSED_BYTES as u64 + 1)
+        .read_to_end(&mut json)?;
+    if json.len() > MAX_DECOMPRESSED_BYTES {
+        return Err(anyhow!(
+            "Decompressed operations exceed {} bytes",
+            MAX_DECOMPRESSED_BYTES
+        ));
+    }
+
+    Ok(serde_json::from_slice(&json)?)
+}
+
+/// A local write that lost to a remote one under LWW, retained so the app can
+/// offer a manual merge. Stored as JSON under `CONFLICT_KEY_PREFIX`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictRecord {
+    pub db_name: String,
+    pub key: String,
+    pub field: Option<String>,
+    pub kept_op_id: String,
+    pub discarded_op_id: String,
+    pub discarded_value: String,
+    pub discarded_timestamp: i64,
+    /// Unix timestamp (milliseconds) the conflict was resolved at
+    pub resolved_at: i64,
+}
+
+/// How a stored value disagrees with the oplog
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityIssueKind {
+    /// The operation's value isn't stored
+    Missing,
+    /// A different value is stored (or one is stored for an expired operation)
+    Mismatch,
+    /// The winning operation's signature doesn't verify
+    InvalidSignature,
+}
+
+impl IntegrityIssueKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Missing => "missing",
+            Self::Mismatch => "mismatch",
+            Self::InvalidSignature => "invalid_signature",
+        }
+    }
+}
+
+/// One key whose stored value disagrees with the oplog
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntegrityIssue {
+    pub db_name: String,
+    /// Storage key
+    pub key: String,
+    pub op_id: String,
+    pub kind: IntegrityIssueKind,
+}
+
+/// Result of `SyncStore::verify_storage`
+#[derive(Debug, Clone, Default)]
+pub struct IntegrityReport {
+    /// Number of keys checked
+    pub checked: usize,
+    pub issues: Vec<IntegrityIssue>,
+    /// Number of issues fixed by replaying operations
+    pub repaired: usize,
+}
+
+/// Sync activity of one database (see `SyncStore::db_sync_info`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DbSyncInfo {
+    /// Keys with a current operation, counted when read
+    #[serde(skip)]
+    pub op_count: usize,
+    /// Unix ms of the last write on this device
+    pub last_local_write: Option<i64>,
+    /// Unix ms an operation from a peer was last accepted
+    pub last_remote_op: Option<i64>,
+    /// Unix ms of the last sync exchange that completed while the database
+    /// was replicated
+    pub last_sync: Option<i64>,
+}
+
+/// What the store keeps in memory of the current operation of a CRDT key;
+/// the operation itself is read from the oplog
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpMeta {
+    pub crdt_key: String,
+    pub db_name: String,
+    pub op_id: String,
+    pub timestamp: i64,
+    /// For the expiry sweep
+    pub expires_at: Option<i64>,
+    /// Leaf of the operation in its database's state tree
+    pub hash: blake3::Hash,
+}
+
+impl OpMeta {
+    pub fn new(op: &SignedOperation) -> Self {
         Self {
-            op_id,
-            timestamp,
-            db_name,
-            key,
-            value,
-            store_type,
-            field: None,
-            score: None,
-            json_path: None,
-            stream_fields: None,
-            ts_timestamp: None,
-            longitude: None,
-            latitude: None,
-            public_key,
-            signature,
+            crdt_key: op.crdt_key(),
+            db_name: op.db_name.clone(),
+            op_id: op.op_id.clone(),
+            timestamp: op.timestamp,
+            expires_at: op.expires_at,
+            hash: merkle::leaf_hash(op),
         }
     }
+
+    /// Whether `op` wins against this operation under LWW: a newer
+    /// timestamp, or the greater op_id as tiebreaker
+    fn loses_to(&self, op: &SignedOperation) -> bool {
+        op.timestamp > self.timestamp || (op.timestamp == self.timestamp && op.op_id > self.op_id)
+    }
 }
 
 /// CRDT-based sync store that tracks operations and applies LWW (Last-Write-Wins)
 pub struct SyncStore {
-    /// Map of crdt_key -> (timestamp, operation)
+    /// Map of crdt_key -> metadata of its operation
     /// Last-Write-Wins: Keep only the operation with the latest timestamp
-    operations: Arc<RwLock<HashMap<String, (i64, SignedOperation)>>>,
+    operations: Arc<RwLock<HashMap<String, OpMeta>>>,
+    /// Operations written or read last, so they don't each take a read of
+    /// the oplog
+    hot_ops: Arc<parking_lot::Mutex<OpCache>>,
     /// Set of operation IDs that have been applied to storage
     applied_ops: Arc<RwLock<HashSet<String>>>,
+    /// Map of crdt_key -> local arrival sequence of the current operation
+    seqs: Arc<parking_lot::Mutex<HashMap<String, u64>>>,
+    /// Last assigned arrival sequence
+    last_seq: Arc<AtomicU64>,
+    /// Keep the losing local version when a remote write wins
+    retain_conflicts: Arc<AtomicBool>,
+    /// Authorized writers per database
+    acl: Arc<AclStore>,
+    /// Databases this device replicates
+    filter: Arc<parking_lot::RwLock<SyncFilter>>,
+    /// Dropped databases
+    tombstones: Arc<parking_lot::RwLock<HashMap<String, DbTombstone>>>,
+    /// Sync activity by database, persisted on every change
+    sync_info: Arc<parking_lot::Mutex<HashMap<String, DbSyncInfo>>>,
+    /// Map of crdt_key -> metadata of the local write no peer has confirmed
+    /// yet (this session)
+    unacked: Arc<parking_lot::Mutex<HashMap<String, OpMeta>>>,
+    event_tx: Option<mpsc::Sender<NodeEvent>>,
+    /// Operations written to storage this session
+    ops_applied: Arc<AtomicU64>,
+    /// Operations rejected this session for a signature that doesn't verify
+    verify_failures: Arc<AtomicU64>,
+    /// Bounds on operations from peers
+    limits: Arc<parking_lot::RwLock<OperationLimits>>,
+    /// Operations rejected this session for exceeding `limits`
+    limit_violations: Arc<AtomicU64>,
+    /// Offloaded values, to verify `Blob` operations (None while offline)
+    blob_values: Arc<parking_lot::RwLock<Option<Arc<BlobValues>>>>,
+    /// Changes of collaborative texts
+    text_deltas: broadcast::Sender<TextDelta>,
     /// Local storage reference
     storage: Arc<Storage>,
 }
 
 impl SyncStore {
     pub fn new(storage: Arc<Storage>) -> Self {
-        let store = Self {
+        Self {
             operations: Arc::new(RwLock::new(HashMap::new())),
+            hot_ops: Arc::new(parking_lot::Mutex::new(OpCache::default())),
             applied_ops: Arc::new(RwLock::new(HashSet::new())),
+            seqs: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            last_seq: Arc::new(AtomicU64::new(0)),
+            retain_conflicts: Arc::new(AtomicBool::new(false)),
+            acl: Arc::new(AclStore::new(storage.clone())),
+            filter: Arc::new(parking_lot::RwLock::new(SyncFilter::default())),
+            tombstones: Arc::new(parking_lot::RwLock::new(HashMap::new())),
+            sync_info: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            unacked: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            event_tx: None,
+            ops_applied: Arc::new(AtomicU64::new(0)),
+            verify_failures: Arc::new(AtomicU64::new(0)),
+            limits: Arc::new(parking_lot::RwLock::new(OperationLimits::default())),
+            limit_violations: Arc::new(AtomicU64::new(0)),
+            blob_values: Arc::new(parking_lot::RwLock::new(None)),
+            text_deltas: broadcast::channel(TEXT_DELTA_CHANNEL_CAPACITY).0,
             storage,
-        };
-        store
-    }
-    
-    /// Load operations from persistent storage (call on startup)
-    pub async fn load_from_storage(&self) -> Result<usize> {
-        let ops_data = self.storage.get_all_operations()?;
-        let mut loaded = 0;
-        
-        for op_bytes in ops_data {
-            if let Ok(op) = serde_json::from_slice::<SignedOperation>(&op_bytes) {
-                let crdt_key = op.crdt_key();
-                let mut ops = self.operations.write().await;
-                
-                // Apply LWW logic
-                if let Some((existing_ts, existing_op)) = ops.get(&crdt_key) {
-                    if op.timestamp < *existing_ts {
-                        continue;
-                    }
-                    if op.timestamp == *existing_ts && op.op_id <= existing_op.op_id {
-                        continue;
-                    }
-                }
-                
-                // Mark as already applied (it was persisted, so it must have been applied)
-                self.applied_ops.write().await.insert(op.op_id.clone());
-                ops.insert(crdt_key, (op.timestamp, op));
-                loaded += 1;
-            }
         }
-        
-        info!("Loaded {} operations from persistent storage", loaded);
-        Ok(loaded)
     }
 
-    /// Check whether an operation has already been applied to storage
-    pub async fn is_applied(&self, op_id: &str) -> bool {
-        self.applied_ops.read().await.contains(op_id)
+    /// Fetch the content of `Blob` operations from here on
+    pub fn set_blob_values(&self, blob_values: Arc<BlobValues>) {
+        *self.blob_values.write() = Some(blob_values);
     }
 
-    /// Mark an operation as applied to storage
-    pub async fn mark_applied(&self, op_id: &str) {
-        self.applied_ops.write().await.insert(op_id.to_string());
+    pub fn blob_values(&self) -> Option<Arc<BlobValues>> {
+        self.blob_values.read().clone()
     }
 
-    /// Add operation to memory with signature verification
-    pub async fn add_operation(&self, op: SignedOperation) -> Result<bool> {
-        // Verify signature first
-        if !op.verify().unwrap_or(false) {
-            warn!(op_id = %op.op_id, "Signature verification failed, rejecting operation");
-            return Ok(false);
-        }
-
-        let crdt_key = op.crdt_key();
-        let mut ops = self.operations.write().await;
+    /// Fetch the content of a `Blob` operation and check the signature
+    /// against it; an error if the content can't be had
+    async fn verify_blob(&self, op: &SignedOperation) -> Result<bool> {
+        let blob_values = self.blob_values().ok_or_else(|| anyhow!("Blob values need the node online"))?;
+        let content = blob_values.get(&BlobRef::decode(&op.value)?, &[]).await?;
+        op.verify_blob_content(&content)
+    }
 
-        // Check if we already have this operation
-        if let Some((existing_ts, existing_op)) = ops.get(&crdt_key) {
-            // LWW: Only update if new timestamp is newer
-            if op.timestamp < *existing_ts {
-                debug!(op_id = %op.op_id, "Rejecting older operation (LWW)");
-                return Ok(false);
-            }
-            // If same timestamp, use op_id as tiebreaker (lexicographic order)
-            if op.timestamp == *existing_ts && op.op_id <= existing_op.op_id {
-                debug!(op_id = %op.op_id, "Rejecting operation with same timestamp (tiebreaker)");
-                return Ok(false);
-            }
-        }
+    /// Writer lists used to authorize incoming operations
+    pub fn acl(&self) -> Arc<AclStore> {
+        self.acl.clone()
+    }
 
-        info!(
-            op_id = %op.op_id,
-            crdt_key = %crdt_key,
-            timestamp = op.timestamp,
-            "Adding operation to SyncStore"
-        );
+    /// Current sync filter
+    pub fn sync_filter(&self) -> SyncFilter {
+        self.filter.read().clone()
+    }
 
-        // Persist to operations log
-        if let Ok(op_json) = serde_json::to_vec(&op) {
-            if let Err(e) = self.storage.put_operation(&op.op_id, &op_json) {
-                error!(op_id = %op.op_id, error = %e, "Failed to persist operation");
-            }
+    /// Follow a database, so it is replicated in selective sync mode
+    pub fn follow_database(&self, db_name: &str) -> Result<()> {
+        if self.filter.read().databases.contains(db_name) {
+            return Ok(());
         }
+        self.storage.follow_database(db_name)?;
+        self.filter.write().databases.insert(db_name.to_string());
+        Ok(())
+    }
 
-        // Store operation in memory
-        ops.insert(crdt_key, (op.timestamp, op));
+    /// Stop following a database
+    pub fn unfollow_database(&self, db_name: &str) -> Result<()> {
+        self.storage.unfollow_database(db_name)?;
+        self.filter.write().databases.remove(db_name);
+        Ok(())
+    }
 
-        Ok(true)
+    /// Switch between syncing everything and only followed databases
+    pub fn set_selective_sync(&self, selective: bool) -> Result<()> {
+        self.storage.put_setting(SELECTIVE_SYNC_SETTING, &[selective as u8])?;
+        self.filter.write().selective = selective;
+        Ok(())
     }
 
-    /// Add operation without signature verification (use when already verified)
-    pub async fn add_operation_unverified(&self, op: SignedOperation) -> Result<bool> {
-        let crdt_key = op.crdt_key();
+    fn load_sync_filter(&self) -> Result<()> {
+        let selective = self
+            .storage
+            .get_setting(SELECTIVE_SYNC_SETTING)?
+            .is_some_and(|v| v.first() == Some(&1));
+        let databases = self.storage.followed_databases()?.into_iter().collect();
+        let owners = devices::linked_user_keys(&self.storage)?;
+        let local_only = self.storage.local_only_databases()?.into_iter().collect();
+        *self.filter.write() = SyncFilter { selective, databases, owners, local_only };
+        Ok(())
+    }
+
+    /// Whether `db_name` is kept off the sync path
+    pub fn is_local_only(&self, db_name: &str) -> bool {
+        self.filter.read().local_only.contains(db_name)
+    }
+
+    /// Keep a database on this device only, or sync it again. Its
+    /// operations leave the oplog, so peers aren't sent any of its data;
+    /// the data itself stays. Once synced again, only later writes are.
+    pub async fn set_local_only(&self, db_name: &str, local_only: bool) -> Result<()> {
+        self.storage.set_local_only(db_name, local_only)?;
+        if !local_only {
+            self.filter.write().local_only.remove(db_name);
+            return Ok(());
+        }
+        self.filter.write().local_only.insert(db_name.to_string());
+        self.unacked.lock().retain(|_, meta| meta.db_name != db_name);
+
+        let mut ops = self.operations.write().await;
+        ops.retain(|crdt_key, meta| {
+            let keep = meta.db_name != db_name;
+            if !keep {
+                self.seqs.lock().remove(crdt_key);
+            }
+            keep
+        });
+        // Superseded operations too, or a reload would bring them back
+        let op_ids: Vec<String> = self
+            .storage
+            .get_all_operations()?
+            .iter()
+            .filter_map(|json| serde_json::from_slice::<SignedOperation>(json).ok())
+            .filter(|op| op.db_name == db_name)
+            .map(|op| op.op_id)
+            .collect();
+        self.hot_ops.lock().remove(&op_ids);
+        self.storage.remove_operations(&op_ids)
+    }
+
+    /// Replicate every database of `user_public_key`, a user key this device
+    /// is linked to
+    pub fn follow_owner(&self, user_public_key: &str) {
+        self.filter.write().owners.insert(user_public_key.to_string());
+    }
+
+    /// Operations written to storage this session
+    pub fn ops_applied(&self) -> u64 {
+        self.ops_applied.load(Ordering::Relaxed)
+    }
+
+    /// Operations rejected this session for a signature that doesn't verify
+    pub fn verify_failures(&self) -> u64 {
+        self.verify_failures.load(Ordering::Relaxed)
+    }
+
+    /// Bounds on operations from peers from now on
+    pub fn set_operation_limits(&self, limits: OperationLimits) {
+        *self.limits.write() = limits;
+    }
+
+    /// Operations rejected this session for exceeding the operation limits
+    pub fn limit_violations(&self) -> u64 {
+        self.limit_violations.load(Ordering::Relaxed)
+    }
+
+    /// Check `op` against the operation limits, counting violations
+    fn within_limits(&self, op: &SignedOperation) -> bool {
+        let checked = self.limits.read().check(op);
+        if let Err(e) = &checked {
+            warn!(op_id = %op.op_id, error = %e, "Operation exceeds limits, rejecting");
+            self.limit_violations.fetch_add(1, Ordering::Relaxed);
+        }
+        checked.is_ok()
+    }
+
+    /// Report `ConflictResolved` events on this channel
+    pub fn with_events(mut self, event_tx: Option<mpsc::Sender<NodeEvent>>) -> Self {
+        self.event_tx = event_tx;
+        self
+    }
+
+    /// When enabled, a local write discarded by LWW is kept under a conflict
+    /// key (`CONFLICT_KEY_PREFIX`) instead of being dropped
+    pub fn set_retain_conflicts(&self, enabled: bool) {
+        self.retain_conflicts.store(enabled, Ordering::Relaxed);
+    }
+
+    /// List retained conflicts of a database as (conflict key, record)
+    pub fn list_conflicts(&self, db_name: &str) -> Result<Vec<(String, ConflictRecord)>> {
+        let mut conflicts = Vec::new();
+        for key in self.storage.list_keys(db_name)? {
+            if !key.starts_with(CONFLICT_KEY_PREFIX) {
+                continue;
+            }
+            if let Some(bytes) = self.storage.get(db_name, &key)? {
+                match serde_json::from_slice::<ConflictRecord>(&bytes) {
+                    Ok(record) => conflicts.push((key, record)),
+                    Err(e) => warn!("Skipping malformed conflict record {}: {}", key, e),
+                }
+            }
+        }
+        Ok(conflicts)
+    }
+
+    /// Add an operation written on this device; it is remembered as local so
+    /// that losing it to a remote write later is reported as a conflict
+    pub async fn add_local_operation(&self, op: SignedOperation) -> Result<bool> {
+        if self.is_local_only(&op.db_name) {
+            return Err(anyhow!("{} is local only", op.db_name));
+        }
+        let op_id = op.op_id.clone();
+        let db_name = op.db_name.clone();
+        let meta = OpMeta::new(&op);
+        let added = self.add_operation_unverified(op).await?;
+        if added {
+            // Replaces an unconfirmed earlier write to the key
+            self.unacked.lock().insert(meta.crdt_key.clone(), meta);
+            let now = chrono::Utc::now().timestamp_millis();
+            self.update_sync_info(&db_name, |info| info.last_local_write = Some(now));
+            self.storage.mark_local_operation(&op_id)?;
+            // Databases we write to are always replicated
+            self.follow_database(&db_name)?;
+        }
+        Ok(added)
+    }
+
+    /// Called when `winner` (remote) replaces `existing` under LWW. If the
+    /// replaced op was written locally, report it and optionally retain it.
+    fn note_replaced(&self, existing: &OpMeta, winner: &SignedOperation) {
+        // Nothing left to confirm once a remote write won the key
+        self.unacked.lock().remove(&existing.crdt_key);
+        match self.storage.take_local_operation(&existing.op_id) {
+            Ok(true) => {}
+            Ok(false) => return,
+            Err(e) => {
+                warn!(op_id = %existing.op_id, error = %e, "Failed to check local operation");
+                return;
+            }
+        }
+        // Replaced operations stay in the oplog
+        let Some(existing) = self.read_oplog(&existing.op_id) else {
+            return;
+        };
+
+        info!(
+            kept = %winner.op_id,
+            discarded = %existing.op_id,
+            "LWW conflict: remote write replaced local write to {}:{}",
+            existing.db_name, existing.key
+        );
+
+        if self.retain_conflicts.load(Ordering::Relaxed) {
+            let record = ConflictRecord {
+                db_name: existing.db_name.clone(),
+                key: existing.key.clone(),
+                field: existing.field.clone(),
+                kept_op_id: winner.op_id.clone(),
+                discarded_op_id: existing.op_id.clone(),
+                discarded_value: existing.value.clone(),
+                discarded_timestamp: existing.timestamp,
+                resolved_at: chrono::Utc::now().timestamp_millis(),
+            };
+            let storage_key = existing.storage_key().unwrap_or_else(|_| existing.key.clone());
+            let conflict_key = format!("{}{}:{}", CONFLICT_KEY_PREFIX, storage_key, existing.op_id);
+            let stored = serde_json::to_vec(&record)
+                .map_err(anyhow::Error::from)
+                .and_then(|json| self.storage.put(&existing.db_name, &conflict_key, &json));
+            if let Err(e) = stored {
+                error!(op_id = %existing.op_id, error = %e, "Failed to retain conflicting version");
+            }
+        }
+
+        if let Some(tx) = &self.event_tx {
+            let _ = tx.try_send(NodeEvent::ConflictResolved {
+                db_name: existing.db_name.clone(),
+                key: existing.key.clone(),
+                kept_op_id: winner.op_id.clone(),
+                discarded_op_id: existing.op_id.clone(),
+            });
+        }
+    }
+
+    /// Record that the operation for `crdt_key` changed locally
+    fn bump_seq(&self, crdt_key: &str) {
+        let seq = self.last_seq.fetch_add(1, Ordering::SeqCst) + 1;
+        self.seqs.lock().insert(crdt_key.to_string(), seq);
+    }
+
+    /// Arrival sequence of the newest local change
+    pub fn current_seq(&self) -> u64 {
+        self.last_seq.load(Ordering::SeqCst)
+    }
+    
+    /// Load operations from persistent storage (call on startup)
+    pub async fn load_from_storage(&self) -> Result<usize> {
+        if let Err(e) = self.acl.load() {
+            error!("Failed to load writer lists: {}", e);
+        }
+        if let Err(e) = self.load_sync_filter() {
+            error!("Failed to load sync filter: {}", e);
+        }
+        if let Err(e) = self.load_tombstones() {
+            error!("Failed to load tombstones: {}", e);
+        }
+        if let Err(e) = self.load_sync_info() {
+            error!("Failed to load database sync info: {}", e);
+        }
+
+        let ops_data = self.storage.get_all_operations()?;
+        let mut loaded = 0;
+        
+        for op_bytes in ops_data {
+            if let Ok(op) = serde_json::from_slice::<SignedOperation>(&op_bytes) {
+                let meta = OpMeta::new(&op);
+                let mut ops = self.operations.write().await;
+                
+                // Apply LWW logic
+                if ops.get(&meta.crdt_key).is_some_and(|existing| !existing.loses_to(&op)) {
+                    continue;
+                }
+                
+                // Mark as already applied (it was persisted, so it must have been applied)
+                self.applied_ops.write().await.insert(op.op_id.clone());
+                self.bump_seq(&meta.crdt_key);
+                ops.insert(meta.crdt_key.clone(), meta);
+                loaded += 1;
+            }
+        }
+        
+        info!("Loaded {} operations from persistent storage", loaded);
+        match self.saved_high_water_mark() {
+            Ok(Some(saved)) if self.high_water_mark().await.is_none_or(|loaded| loaded < saved) => {
+                warn!(saved, "Operations written before the last checkpoint are missing");
+            }
+            Ok(_) => {}
+            Err(e) => error!("Failed to read the oplog high-water mark: {}", e),
+        }
+        Ok(loaded)
+    }
+
+    /// Remove superseded operations `policy` doesn't keep from the oplog, as
+    /// of `now` (Unix ms). A key's versions are only collected once its
+    /// current operation is applied. Returns how many were removed.
+    pub async fn collect_garbage(&self, policy: &OplogGcPolicy, now: i64) -> Result<usize> {
+        let cutoff = now.saturating_sub(policy.keep_for.as_millis() as i64);
+        let ops = self.operations.read().await;
+        let mut superseded: HashMap<String, Vec<(i64, String)>> = HashMap::new();
+        for json in self.storage.get_all_operations()? {
+            let Ok(op) = serde_json::from_slice::<SignedOperation>(&json) else {
+                continue;
+            };
+            let crdt_key = op.crdt_key();
+            // Operations of dropped databases go with the drop
+            let Some(current) = ops.get(&crdt_key) else {
+                continue;
+            };
+            if current.op_id == op.op_id || current.loses_to(&op) {
+                continue;
+            }
+            superseded.entry(crdt_key).or_default().push((op.timestamp, op.op_id));
+        }
+
+        let mut collected = Vec::new();
+        for (crdt_key, mut versions) in superseded {
+            if !self.is_applied(&ops[&crdt_key].op_id).await {
+                continue;
+            }
+            // Newest first; the current version is one of those kept
+            versions.sort_by(|a, b| b.cmp(a));
+            collected.extend(
+                versions
+                    .into_iter()
+                    .skip(policy.keep_versions.saturating_sub(1))
+                    .filter(|(timestamp, _)| *timestamp < cutoff)
+                    .map(|(_, op_id)| op_id),
+            );
+        }
+        drop(ops);
+        if collected.is_empty() {
+            return Ok(0);
+        }
+
+        self.storage.remove_operations(&collected)?;
+        self.hot_ops.lock().remove(&collected);
+        let mut applied_ops = self.applied_ops.write().await;
+        for op_id in &collected {
+            applied_ops.remove(op_id);
+        }
+        info!("Collected {} superseded operations", collected.len());
+        Ok(collected.len())
+    }
+
+    /// Timestamp of the newest operation held (ms)
+    pub async fn high_water_mark(&self) -> Option<i64> {
+        self.operations.read().await.values().map(|meta| meta.timestamp).max()
+    }
+
+    /// Persist `high_water_mark`, so the next start can tell whether writes
+    /// were lost, e.g. when the process was killed before a flush
+    pub async fn save_high_water_mark(&self) -> Result<Option<i64>> {
+        let mark = self.high_water_mark().await;
+        if let Some(mark) = mark {
+            self.storage.put_setting(HIGH_WATER_MARK_SETTING, mark.to_string().as_bytes())?;
+        }
+        Ok(mark)
+    }
+
+    /// High-water mark of the last checkpoint
+    pub fn saved_high_water_mark(&self) -> Result<Option<i64>> {
+        Ok(self
+            .storage
+            .get_setting(HIGH_WATER_MARK_SETTING)?
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .and_then(|mark| mark.parse().ok()))
+    }
+
+    fn load_tombstones(&self) -> Result<()> {
+        let mut tombstones = self.tombstones.write();
+        for bytes in self.storage.get_all_tombstones()? {
+            match serde_json::from_slice::<DbTombstone>(&bytes) {
+                Ok(tombstone) if tombstone.verify().is_ok() => {
+                    tombstones.insert(tombstone.db_name.clone(), tombstone);
+                }
+                _ => warn!("Skipping invalid persisted tombstone"),
+            }
+        }
+        Ok(())
+    }
+
+    fn load_sync_info(&self) -> Result<()> {
+        let mut sync_info = self.sync_info.lock();
+        for (db_name, json) in self.storage.get_all_db_sync_info()? {
+            match serde_json::from_slice(&json) {
+                Ok(info) => {
+                    sync_info.insert(db_name, info);
+                }
+                Err(e) => warn!("Skipping invalid sync info of {}: {}", db_name, e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Change the sync activity of `db_name` and persist it
+    fn update_sync_info(&self, db_name: &str, update: impl FnOnce(&mut DbSyncInfo)) {
+        let mut sync_info = self.sync_info.lock();
+        let info = sync_info.entry(db_name.to_string()).or_default();
+        update(info);
+        let stored = serde_json::to_vec(info)
+            .map_err(anyhow::Error::from)
+            .and_then(|json| self.storage.put_db_sync_info(db_name, &json));
+        if let Err(e) = stored {
+            warn!("Failed to save sync info of {}: {}", db_name, e);
+        }
+    }
+
+    /// Record a completed sync exchange for every database replicated now
+    pub async fn note_synced(&self, now: i64) {
+        let mut databases: HashSet<String> = self.operations.read().await.values().map(|meta| meta.db_name.clone()).collect();
+        let filter = self.sync_filter();
+        databases.extend(filter.databases);
+        for db_name in databases.iter().filter(|db_name| !filter.local_only.contains(*db_name)) {
+            self.update_sync_info(db_name, |info| info.last_sync = Some(now));
+        }
+    }
+
+    /// Sync activity of a database, for "synced 2 minutes ago"
+    pub async fn db_sync_info(&self, db_name: &str) -> DbSyncInfo {
+        let op_count = self.operations.read().await.values().filter(|meta| meta.db_name == db_name).count();
+        let info = self.sync_info.lock().get(db_name).cloned().unwrap_or_default();
+        DbSyncInfo { op_count, ..info }
+    }
+
+    /// Whether `op` belongs to a database dropped after it was written
+    fn is_dropped(&self, op: &SignedOperation) -> bool {
+        self.tombstones
+            .read()
+            .get(&op.db_name)
+            .is_some_and(|tombstone| tombstone.covers(op.timestamp))
+    }
+
+    /// Tombstones of databases dropped at or after `since` (all if `None`)
+    pub fn tombstones_since(&self, since: Option<i64>) -> Vec<DbTombstone> {
+        self.tombstones
+            .read()
+            .values()
+            .filter(|t| since.is_none_or(|since| t.dropped_at >= since))
+            .cloned()
+            .collect()
+    }
+
+    /// Verify and apply a database drop: its tree and every operation written
+    /// before the drop are removed. Returns false if it is not newer than
+    /// the tombstone we already have.
+    pub async fn apply_tombstone(&self, tombstone: DbTombstone) -> Result<bool> {
+        tombstone.verify()?;
+        if let Some(existing) = self.tombstones.read().get(&tombstone.db_name) {
+            if tombstone.dropped_at <= existing.dropped_at {
+                return Ok(false);
+            }
+        }
+        self.storage.put_tombstone(&tombstone.db_name, &serde_json::to_vec(&tombstone)?)?;
+
+        let db_name = tombstone.db_name.clone();
+        let mut ops = self.operations.write().await;
+        let dropped_keys: Vec<String> = ops
+            .values()
+            .filter(|meta| meta.db_name == db_name && tombstone.covers(meta.timestamp))
+            .map(|meta| meta.crdt_key.clone())
+            .collect();
+        let mut dropped_ids = Vec::with_capacity(dropped_keys.len());
+        {
+            let mut seqs = self.seqs.lock();
+            for crdt_key in &dropped_keys {
+                if let Some(meta) = ops.remove(crdt_key) {
+                    dropped_ids.push(meta.op_id);
+                }
+                seqs.remove(crdt_key);
+            }
+        }
+        let survivors = self.load_operations(ops.values().filter(|meta| meta.db_name == db_name));
+        self.tombstones.write().insert(db_name.clone(), tombstone);
+        drop(ops);
+
+        self.hot_ops.lock().remove(&dropped_ids);
+        self.sync_info.lock().remove(&db_name);
+        self.unacked.lock().retain(|_, meta| meta.db_name != db_name);
+        self.storage.drop_database(&db_name, &dropped_ids)?;
+        {
+            let mut applied_ops = self.applied_ops.write().await;
+            for op_id in &dropped_ids {
+                applied_ops.remove(op_id);
+            }
+            // Writes made after the drop recreate the database
+            for op in &survivors {
+                applied_ops.remove(&op.op_id);
+            }
+        }
+        for op in &survivors {
+            self.apply_to_storage(op).await?;
+        }
+
+        info!("Dropped database {} ({} operations removed)", db_name, dropped_ids.len());
+        Ok(true)
+    }
+
+    /// Check whether an operation has already been applied to storage
+    pub async fn is_applied(&self, op_id: &str) -> bool {
+        self.applied_ops.read().await.contains(op_id)
+    }
+
+    /// Mark an operation as applied to storage
+    pub async fn mark_applied(&self, op_id: &str) {
+        self.applied_ops.write().await.insert(op_id.to_string());
+    }
+
+    /// Add operation to memory with signature verification
+    pub async fn add_operation(&self, op: SignedOperation) -> Result<bool> {
+        // Cheap bounds first, so oversized operations aren't even verified
+        if !self.within_limits(&op) {
+            return Ok(false);
+        }
+
+        // Internal trees (database keys, settings, ACLs) are never replicated
+        if op.db_name.starts_with(INTERNAL_TREE_PREFIX) {
+            warn!(op_id = %op.op_id, db = %op.db_name, "Operation targets an internal tree, rejecting");
+            return Ok(false);
+        }
+
+        // Then the signature; Blob operations once nothing else rules
+        // them out, as that takes a download
+        if !op.is_blob() && !op.verify().unwrap_or(false) {
+            warn!(op_id = %op.op_id, "Signature verification failed, rejecting operation");
+            self.verify_failures.fetch_add(1, Ordering::Relaxed);
+            return Ok(false);
+        }
+
+        if !self.acl.is_authorized(&op.db_name, &op.public_key) {
+            warn!(op_id = %op.op_id, db = %op.db_name, "Signer is not an authorized writer, rejecting operation");
+            return Ok(false);
+        }
+
+        if !self.filter.read().allows(&op.db_name) {
+            debug!(op_id = %op.op_id, db = %op.db_name, "Skipping operation for unfollowed database");
+            return Ok(false);
+        }
+
+        if let Err(e) = op.handler().and_then(|handler| handler.validate(&op)) {
+            warn!(op_id = %op.op_id, error = %e, "Invalid operation, rejecting");
+            return Ok(false);
+        }
+
+        if self.is_dropped(&op) {
+            debug!(op_id = %op.op_id, db = %op.db_name, "Rejecting operation for dropped database");
+            return Ok(false);
+        }
+
+        if self.storage.has_operation(&op.op_id)? {
+            debug!(op_id = %op.op_id, "Skipping duplicate operation");
+            return Ok(false);
+        }
+
+        if op.is_blob() {
+            match self.verify_blob(&op).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    warn!(op_id = %op.op_id, "Signature verification failed, rejecting operation");
+                    self.verify_failures.fetch_add(1, Ordering::Relaxed);
+                    return Ok(false);
+                }
+                // Not a bad signature; a later sync retries
+                Err(e) => {
+                    warn!(op_id = %op.op_id, error = %e, "Couldn't fetch blob value, skipping operation");
+                    return Ok(false);
+                }
+            }
+        }
+
+        let meta = OpMeta::new(&op);
+        let crdt_key = meta.crdt_key.clone();
         let mut ops = self.operations.write().await;
 
-        if let Some((existing_ts, existing_op)) = ops.get(&crdt_key) {
-            if op.timestamp < *existing_ts {
+        // Check if we already have this operation
+        if let Some(existing) = ops.get(&crdt_key) {
+            // LWW: Only update if new timestamp is newer
+            if op.timestamp < existing.timestamp {
+                debug!(op_id = %op.op_id, "Rejecting older operation (LWW)");
                 return Ok(false);
             }
-            if op.timestamp == *existing_ts && op.op_id <= existing_op.op_id {
+            // If same timestamp, use op_id as tiebreaker (lexicographic order)
+            if !existing.loses_to(&op) {
+                debug!(op_id = %op.op_id, "Rejecting operation with same timestamp (tiebreaker)");
                 return Ok(false);
             }
         }
 
-        // Persist to operations log
-        if let Ok(op_json) = serde_json::to_vec(&op) {
-            if let Err(e) = self.storage.put_operation(&op.op_id, &op_json) {
-                error!(op_id = %op.op_id, error = %e, "Failed to persist operation");
+        info!(
+            op_id = %op.op_id,
+            crdt_key = %crdt_key,
+            timestamp = op.timestamp,
+            "Adding operation to SyncStore"
+        );
+
+        // Persist to operations log, the only full copy
+        self.storage.put_operation(&op.op_id, &serde_json::to_vec(&op)?)?;
+
+        if let Some(existing) = ops.get(&crdt_key) {
+            self.note_replaced(existing, &op);
+        }
+
+        // Keep its metadata in memory
+        self.bump_seq(&crdt_key);
+        ops.insert(crdt_key, meta);
+        let now = chrono::Utc::now().timestamp_millis();
+        self.update_sync_info(&op.db_name, |info| info.last_remote_op = Some(now));
+        self.hot_ops.lock().insert(op);
+
+        Ok(true)
+    }
+
+    /// Add operation without signature verification (use when already verified)
+    pub async fn add_operation_unverified(&self, op: SignedOperation) -> Result<bool> {
+        // Re-submitted writes with a content op_id
+        if self.storage.has_operation(&op.op_id)? {
+            return Ok(false);
+        }
+
+        let meta = OpMeta::new(&op);
+        let crdt_key = meta.crdt_key.clone();
+        let mut ops = self.operations.write().await;
+
+        if ops.get(&crdt_key).is_some_and(|existing| !existing.loses_to(&op)) {
+            return Ok(false);
+        }
+
+        // Persist to operations log, the only full copy
+        self.storage.put_operation(&op.op_id, &serde_json::to_vec(&op)?)?;
+
+        // A local overwrite of our own write is not a conflict
+        if let Some(existing) = ops.get(&crdt_key) {
+            let _ = self.storage.take_local_operation(&existing.op_id);
+        }
+
+        self.bump_seq(&crdt_key);
+        ops.insert(crdt_key, meta);
+        self.hot_ops.lock().insert(op);
+        Ok(true)
+    }
+
+    /// Whether the store holds `op` or a newer write to its key
+    pub async fn holds(&self, op: &SignedOperation) -> bool {
+        let ops = self.operations.read().await;
+        ops.get(&op.crdt_key()).is_some_and(|meta| !meta.loses_to(op))
+    }
+
+    /// Take local writes a peer confirmed off the unconfirmed ones; how many
+    /// there were
+    pub fn acknowledge(&self, op_ids: &[String]) -> usize {
+        let mut unacked = self.unacked.lock();
+        let before = unacked.len();
+        unacked.retain(|_, meta| !op_ids.contains(&meta.op_id));
+        before - unacked.len()
+    }
+
+    /// Local writes to `db_name` this session that no peer confirmed yet
+    pub fn unacknowledged(&self, db_name: &str) -> usize {
+        self.unacked.lock().values().filter(|meta| meta.db_name == db_name).count()
+    }
+
+    /// The operation `meta` describes, from the hot operations or the oplog
+    fn read_operation(&self, meta: &OpMeta) -> Option<SignedOperation> {
+        if let Some(op) = self.hot_ops.lock().get(&meta.op_id) {
+            return Some(op);
+        }
+        let op = self.read_oplog(&meta.op_id)?;
+        self.hot_ops.lock().insert(op.clone());
+        Some(op)
+    }
+
+    /// The operations `metas` describe, read from the oplog. Scans don't make
+    /// operations hot, so they don't push out the ones in use.
+    pub fn load_operations<'a>(&self, metas: impl IntoIterator<Item = &'a OpMeta>) -> Vec<SignedOperation> {
+        metas
+            .into_iter()
+            .filter_map(|meta| {
+                let hot = self.hot_ops.lock().peek(&meta.op_id);
+                hot.or_else(|| self.read_oplog(&meta.op_id))
+            })
+            .collect()
+    }
+
+    fn read_oplog(&self, op_id: &str) -> Option<SignedOperation> {
+        let json = match self.storage.get_operation(op_id) {
+            Ok(Some(json)) => json,
+            Ok(None) => {
+                error!(op_id = %op_id, "Operation missing from the oplog");
+                return None;
+            }
+            Err(e) => {
+                error!(op_id = %op_id, error = %e, "Failed to read operation");
+                return None;
+            }
+        };
+        match serde_json::from_slice(&json) {
+            Ok(op) => Some(op),
+            Err(e) => {
+                error!(op_id = %op_id, error = %e, "Invalid operation in the oplog");
+                None
+            }
+        }
+    }
+
+    /// Get all operations (read from the oplog)
+    pub async fn get_all_operations(&self) -> Vec<SignedOperation> {
+        self.load_operations(self.operations.read().await.values())
+    }
+
+    /// Get operations since a timestamp (read from the oplog)
+    pub async fn get_operations_since(&self, timestamp: i64) -> Vec<SignedOperation> {
+        self.load_operations(self.operations.read().await.values().filter(|meta| meta.timestamp >= timestamp))
+    }
+
+    /// Metadata of the operations since a timestamp, paired with their local
+    /// arrival sequence; see `load_operations` for the operations
+    pub async fn get_metas_since_with_seq(&self, timestamp: Option<i64>) -> Vec<(u64, OpMeta)> {
+        let ops = self.operations.read().await;
+        let seqs = self.seqs.lock();
+        ops.values()
+            .filter(|meta| timestamp.is_none_or(|since| meta.timestamp >= since))
+            .map(|meta| (seqs.get(&meta.crdt_key).copied().unwrap_or(0), meta.clone()))
+            .collect()
+    }
+
+    /// Metadata of every operation
+    pub async fn all_metas(&self) -> Vec<OpMeta> {
+        self.operations.read().await.values().cloned().collect()
+    }
+
+    /// Metadata of the latest operation of every key of one database
+    pub async fn database_metas(&self, db_name: &str) -> Vec<OpMeta> {
+        self.operations
+            .read()
+            .await
+            .values()
+            .filter(|meta| meta.db_name == db_name)
+            .cloned()
+            .collect()
+    }
+
+    /// Current (winning) operation of a CRDT key (see `SignedOperation::crdt_key`)
+    pub async fn get_operation(&self, crdt_key: &str) -> Option<SignedOperation> {
+        let meta = self.operations.read().await.get(crdt_key).cloned()?;
+        self.read_operation(&meta)
+    }
+
+    /// Latest operation of every key of one database (read from the oplog)
+    pub async fn database_operations(&self, db_name: &str) -> Vec<SignedOperation> {
+        self.load_operations(&self.database_metas(db_name).await)
+    }
+
+    /// Get operations count
+    pub async fn operation_count(&self) -> usize {
+        self.operations.read().await.len()
+    }
+
+    /// Merge operations from another node
+    pub async fn merge_operations(&self, operations: Vec<SignedOperation>) -> Result<usize> {
+        let mut merged_count = 0;
+
+        for op in operations {
+            if self.add_operation(op).await? {
+                merged_count += 1;
+            }
+        }
+
+        Ok(merged_count)
+    }
+
+    /// Apply a single operation to local storage
+    pub async fn apply_to_storage(&self, op: &SignedOperation) -> Result<()> {
+        // Avoid re-applying the same operation
+        if self.is_applied(&op.op_id).await {
+            debug!(op_id = %op.op_id, "Skipping already-applied operation");
+            return Ok(());
+        }
+
+        let full_key = format!("{}:{}", op.db_name, op.key);
+        self.write_value(op)?;
+
+        // Flush immediately to ensure persistence
+        self.storage.flush()?;
+        
+        // Mark as applied
+        self.mark_applied(&op.op_id).await;
+        self.ops_applied.fetch_add(1, Ordering::Relaxed);
+        info!(op_id = %op.op_id, key = %full_key, "Applied operation to storage");
+        
+        Ok(())
+    }
+
+    /// Write the value an operation leaves in storage (nothing if it expired)
+    fn write_value(&self, op: &SignedOperation) -> Result<()> {
+        let handler = op.handler()?;
+        let storage_key = handler.storage_key(op)?;
+        if op.is_expired(chrono::Utc::now().timestamp_millis()) {
+            // Expired before it reached us: the key is gone
+            self.storage.delete(&op.db_name, &storage_key)?;
+        } else {
+            self.storage.put(&op.db_name, &storage_key, op.value.as_bytes())?;
+        }
+        handler.materialize(&self.apply_context(), op)
+    }
+
+    fn apply_context(&self) -> ApplyContext<'_> {
+        ApplyContext { storage: &self.storage, text_deltas: &self.text_deltas }
+    }
+
+    /// Apply a database snapshot atomically: every operation must verify,
+    /// then all winning (LWW) operations and their values are written in one
+    /// storage transaction. Returns how many operations were applied.
+    pub async fn apply_snapshot(&self, db_name: &str, operations: Vec<SignedOperation>) -> Result<usize> {
+        if db_name.starts_with(INTERNAL_TREE_PREFIX) {
+            return Err(anyhow!("Snapshot of {} rejected: internal trees aren't replicated", db_name));
+        }
+        for op in &operations {
+            let valid = op.db_name == db_name
+                && self.within_limits(op)
+                && op.handler().and_then(|handler| handler.validate(op)).is_ok()
+                && if op.is_blob() {
+                    self.verify_blob(op).await.unwrap_or(false)
+                } else {
+                    op.verify().unwrap_or(false)
+                };
+            if !valid {
+                return Err(anyhow!("Snapshot of {} rejected: operation {} is invalid", db_name, op.op_id));
+            }
+        }
+        if !self.filter.read().allows(db_name) {
+            debug!("Skipping snapshot of unfollowed database {}", db_name);
+            return Ok(0);
+        }
+
+        let mut ops = self.operations.write().await;
+
+        // Keep only authorized operations that win against local state (and each other)
+        let mut winners: HashMap<String, SignedOperation> = HashMap::new();
+        for op in operations {
+            if !self.acl.is_authorized(&op.db_name, &op.public_key) {
+                debug!(op_id = %op.op_id, "Skipping snapshot operation from unauthorized writer");
+                continue;
+            }
+            if self.is_dropped(&op) {
+                continue;
+            }
+            let crdt_key = op.crdt_key();
+            let newest = winners
+                .get(&crdt_key)
+                .map(|w| (w.timestamp, w.op_id.as_str()))
+                .or_else(|| ops.get(&crdt_key).map(|existing| (existing.timestamp, existing.op_id.as_str())));
+            if let Some((ts, op_id)) = newest {
+                if op.timestamp < ts || (op.timestamp == ts && op.op_id.as_str() <= op_id) {
+                    continue;
+                }
+            }
+            winners.insert(crdt_key, op);
+        }
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut entries = Vec::with_capacity(winners.len());
+        let mut expired = Vec::new();
+        let mut oplog = Vec::with_capacity(winners.len());
+        for op in winners.values() {
+            if op.is_expired(now) {
+                expired.push(op.storage_key()?);
+            } else {
+                entries.push((op.storage_key()?, op.value.as_bytes().to_vec()));
+            }
+            oplog.push((op.op_id.clone(), serde_json::to_vec(op)?));
+        }
+        self.storage.apply_batch(db_name, &entries, &oplog)?;
+        for key in expired {
+            self.storage.delete(db_name, &key)?;
+        }
+        // Derived values once per key, e.g. a counter's sum
+        let derived: HashMap<(&str, &str), &SignedOperation> =
+            winners.values().map(|op| ((op.store_type.as_str(), op.key.as_str()), op)).collect();
+        let ctx = self.apply_context();
+        for op in derived.into_values() {
+            op.handler()?.materialize(&ctx, op)?;
+        }
+        self.storage.flush()?;
+
+        let applied = winners.len();
+        self.ops_applied.fetch_add(applied as u64, Ordering::Relaxed);
+        if applied > 0 {
+            let now = chrono::Utc::now().timestamp_millis();
+            self.update_sync_info(db_name, |info| info.last_remote_op = Some(now));
+        }
+        let mut applied_ops = self.applied_ops.write().await;
+        for (crdt_key, op) in winners {
+            if let Some(existing) = ops.get(&crdt_key) {
+                self.note_replaced(existing, &op);
+            }
+            applied_ops.insert(op.op_id.clone());
+            self.bump_seq(&crdt_key);
+            ops.insert(crdt_key, OpMeta::new(&op));
+        }
+
+        info!("Applied snapshot of {} ({} operations)", db_name, applied);
+        Ok(applied)
+    }
+
+    /// Build this replica's next operation for a PN-counter: its running
+    /// totals with `delta` added, timestamped after its previous state so LWW
+    /// can't drop it if the clock went backwards. Callers must serialize
+    /// increments of the same counter.
+    pub async fn counter_operation(
+        &self,
+        db_name: &str,
+        key: &str,
+        replica: &str,
+        delta: i64,
+        signing_key: &ed25519_dalek::SigningKey,
+    ) -> Result<SignedOperation> {
+        let crdt_key = format!("{}:{}:{}", db_name, key, replica);
+        let previous = self.get_operation(&crdt_key).await;
+
+        let mut state = match &previous {
+            Some(op) => op.counter_state()?,
+            None => CounterState {
+                replica: replica.to_string(),
+                ..Default::default()
+            },
+        };
+        state.add(delta);
+
+        let mut op = SignedOperation::new(
+            db_name.to_string(),
+            key.to_string(),
+            serde_json::to_string(&state)?,
+            COUNTER_STORE_TYPE.to_string(),
+            crypto::public_key_hex(signing_key),
+            String::new(),
+        );
+        op.field = Some(replica.to_string());
+        if let Some(previous) = previous {
+            op.timestamp = op.timestamp.max(previous.timestamp + 1);
+        }
+        op.signature = crypto::sign_message(signing_key, op.full_message().as_bytes());
+        Ok(op)
+    }
+
+    /// Build the operation for a local edit of a collaborative text by
+    /// `replica`: `delete` characters at `index` replaced by `insert`.
+    /// `None` if the edit changes nothing. Callers must serialize edits of
+    /// the same text.
+    #[allow(clippy::too_many_arguments)]
+    pub fn text_operation(
+        &self,
+        db_name: &str,
+        key: &str,
+        replica: &str,
+        index: u64,
+        delete: u64,
+        insert: &str,
+        signing_key: &ed25519_dalek::SigningKey,
+    ) -> Result<Option<SignedOperation>> {
+        let Some(edit) = text::document(&self.storage, db_name, key)?.edit(replica, index, delete, insert)? else {
+            return Ok(None);
+        };
+
+        let mut op = SignedOperation::new(
+            db_name.to_string(),
+            key.to_string(),
+            serde_json::to_string(&edit)?,
+            TEXT_STORE_TYPE.to_string(),
+            crypto::public_key_hex(signing_key),
+            String::new(),
+        );
+        op.field = Some(edit.edit_id());
+        op.signature = crypto::sign_message(signing_key, op.full_message().as_bytes());
+        Ok(Some(op))
+    }
+
+    /// Changes of collaborative texts from now on, local edits included
+    pub fn subscribe_text_deltas(&self) -> broadcast::Receiver<TextDelta> {
+        self.text_deltas.subscribe()
+    }
+
+    /// Remove values whose operation expired by `now` (Unix ms). The
+    /// operation stays in the log, so older writes can't bring the key back.
+    /// Returns how many keys were removed.
+    pub async fn expire_operations(&self, now: i64) -> Result<usize> {
+        let expired = self.load_operations(
+            self.operations
+                .read()
+                .await
+                .values()
+                .filter(|meta| meta.expires_at.is_some_and(|expires_at| expires_at <= now)),
+        );
+
+        let mut removed = 0;
+        for op in expired {
+            let key = op.storage_key()?;
+            if self.storage.get(&op.db_name, &key)?.is_some() {
+                self.storage.delete(&op.db_name, &key)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Cross-check stored values against the winning operation of every key,
+    /// re-verifying signatures. With `repair`, mismatched values are rewritten
+    /// by replaying their operation; operations with invalid signatures are
+    /// only reported.
+    pub async fn verify_storage(&self, repair: bool) -> Result<IntegrityReport> {
+        let winners: Vec<SignedOperation> = self
+            .get_all_operations()
+            .await
+            .into_iter()
+            .filter(|op| !self.is_dropped(op))
+            .collect();
+
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut report = IntegrityReport {
+            checked: winners.len(),
+            ..Default::default()
+        };
+        for op in winners {
+            let key = op.storage_key()?;
+            // Blob content that can't be fetched now was verified on arrival
+            let verified = if op.is_blob() {
+                self.verify_blob(&op).await.unwrap_or(true)
+            } else {
+                op.verify().unwrap_or(false)
+            };
+            let kind = if !verified {
+                IntegrityIssueKind::InvalidSignature
+            } else {
+                let expected = (!op.is_expired(now)).then_some(op.value.as_bytes());
+                match (self.storage.get(&op.db_name, &key)?, expected) {
+                    (stored, expected) if stored.as_deref() == expected => continue,
+                    (None, Some(_)) => IntegrityIssueKind::Missing,
+                    _ => IntegrityIssueKind::Mismatch,
+                }
+            };
+
+            if repair && kind != IntegrityIssueKind::InvalidSignature {
+                self.write_value(&op)?;
+                report.repaired += 1;
+            }
+            report.issues.push(IntegrityIssue {
+                db_name: op.db_name,
+                key,
+                op_id: op.op_id,
+                kind,
+            });
+        }
+        if report.repaired > 0 {
+            self.storage.flush()?;
+        }
+
+        if report.issues.is_empty() {
+            info!("Storage check passed ({} keys)", report.checked);
+        } else {
+            warn!(
+                "Storage check found {} issues in {} keys ({} repaired)",
+                report.issues.len(), report.checked, report.repaired
+            );
+        }
+        Ok(report)
+    }
+
+    /// Apply all pending operations to storage
+    pub async fn apply_all_to_storage(&self) -> Result<usize> {
+        let mut pending = Vec::new();
+        for meta in self.all_metas().await {
+            if !self.is_applied(&meta.op_id).await {
+                pending.push(meta);
+            }
+        }
+        let mut applied = 0;
+
+        for op in self.load_operations(&pending) {
+            if let Err(e) = self.apply_to_storage(&op).await {
+                error!(op_id = %op.op_id, error = %e, "Failed to apply operation");
+            } else {
+                applied += 1;
+            }
+        }
+
+        Ok(applied)
+    }
+}
+
+/// Running sync counters, shared between clones of a `SyncManager`
+#[derive(Debug, Default)]
+struct SyncCounters {
+    ops_sent: u64,
+    ops_received: u64,
+    bytes_sent: u64,
+    bytes_received: u64,
+    last_sync_at: Option<i64>,
+    peers: HashMap<String, PeerSyncStatus>,
+}
+
+impl SyncCounters {
+    fn peer(&mut self, peer_id: &str) -> &mut PeerSyncStatus {
+        self.peers
+            .entry(peer_id.to_string())
+            .or_insert_with(|| PeerSyncStatus {
+                peer_id: peer_id.to_string(),
+                ..Default::default()
+            })
+    }
+}
+
+/// High-water mark of what a peer received from us in a completed sync:
+/// every op with `timestamp >= since` and arrival `seq <= seq`
+#[derive(Debug, Clone, Copy)]
+struct SyncMark {
+    since: Option<i64>,
+    seq: u64,
+    recorded_at: Instant,
+}
+
+impl SyncMark {
+    fn covers(&self, seq: u64, timestamp: i64) -> bool {
+        seq <= self.seq && self.since.is_none_or(|since| timestamp >= since)
+    }
+}
+
+/// A (possibly multi-chunk) sync exchange we are answering
+#[derive(Debug, Clone)]
+struct SyncSession {
+    request_id: Option<String>,
+    since: Option<i64>,
+    seq: u64,
+    /// Mark the requester acknowledged when the exchange started; ops it
+    /// covers are skipped in every chunk
+    base: Option<SyncMark>,
+    /// `since_timestamp` the requester will use for the next chunk
+    next_since: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+struct PeerSyncMarks {
+    /// Mark of the last completed exchange, by its request ID; only trusted
+    /// once the requester acknowledges that exchange
+    pending: Option<(String, SyncMark)>,
+    session: Option<SyncSession>,
+}
+
+/// Sync manager handles data synchronization across nodes
+pub struct SyncManager {
+    sync_store: Arc<SyncStore>,
+    local_node_id: String,
+    counters: Arc<parking_lot::Mutex<SyncCounters>>,
+    /// Per-requester high-water marks, so repeat requests only get the delta
+    peer_marks: Arc<parking_lot::Mutex<HashMap<String, PeerSyncMarks>>>,
+    snapshots: Option<Arc<SnapshotManager>>,
+    event_tx: Option<mpsc::Sender<NodeEvent>>,
+    max_ops_per_response: Arc<AtomicUsize>,
+    /// Snapshots are offered and fetched (off on metered connections)
+    blob_transfers: Arc<AtomicBool>,
+    /// Counts snapshot downloads; sync pauses while its cap is used up
+    bandwidth: Option<Arc<BandwidthMeter>>,
+    /// Latest comparison against a published root, per database
+    root_checks: Arc<parking_lot::Mutex<HashMap<String, RootCheck>>>,
+    /// Signs our sync requests and responses
+    node_key: Option<ed25519_dalek::SigningKey>,
+    /// Peers that sign their sync envelopes; unsigned ones claiming to come
+    /// from them are rejected
+    signing_peers: Arc<parking_lot::RwLock<HashSet<String>>>,
+    /// IDs of the sync requests we sent, by when
+    issued_requests: Arc<parking_lot::Mutex<HashMap<String, Instant>>>,
+    /// Request ID of the last exchange each responder completed for us,
+    /// acknowledged in our next request
+    received_exchanges: Arc<parking_lot::Mutex<HashMap<String, String>>>,
+    /// Signatures of recent signed requests, so a replayed one is dropped
+    seen_requests: Arc<parking_lot::Mutex<HashMap<String, Instant>>>,
+    /// Only sync and repair requests are handled (see `set_answer_only`)
+    answer_only: Arc<AtomicBool>,
+    /// IDs of received operations not yet confirmed to their writers (see
+    /// `take_operation_ack`)
+    pending_acks: Arc<parking_lot::Mutex<Vec<String>>>,
+}
+
+impl SyncManager {
+    pub fn new(
+        storage: Arc<Storage>,
+        local_node_id: String,
+        event_tx: Option<mpsc::Sender<NodeEvent>>,
+    ) -> Self {
+        let signing_peers = storage.signing_peers().unwrap_or_else(|e| {
+            warn!("Failed to load signing peers: {}", e);
+            Vec::new()
+        });
+        Self {
+            sync_store: Arc::new(SyncStore::new(storage).with_events(event_tx.clone())),
+            local_node_id,
+            counters: Arc::new(parking_lot::Mutex::new(SyncCounters::default())),
+            peer_marks: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            snapshots: None,
+            event_tx,
+            max_ops_per_response: Arc::new(AtomicUsize::new(MAX_OPS_PER_RESPONSE)),
+            blob_transfers: Arc::new(AtomicBool::new(true)),
+            bandwidth: None,
+            root_checks: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            node_key: None,
+            signing_peers: Arc::new(parking_lot::RwLock::new(signing_peers.into_iter().collect())),
+            issued_requests: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            received_exchanges: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            seen_requests: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            answer_only: Arc::new(AtomicBool::new(false)),
+            pending_acks: Arc::new(parking_lot::Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Allow or stop offering and fetching snapshots; op sync is used instead
+    pub fn set_blob_transfers(&self, enabled: bool) {
+        self.blob_transfers.store(enabled, Ordering::Relaxed);
+    }
+
+    fn blob_transfers_enabled(&self) -> bool {
+        self.snapshots.is_some() && self.blob_transfers.load(Ordering::Relaxed) && !self.bandwidth_cap_exceeded()
+    }
+
+    /// A data cap of the bandwidth meter is used up
+    pub fn bandwidth_cap_exceeded(&self) -> bool {
+        self.bandwidth.as_ref().is_some_and(|meter| meter.cap_exceeded())
+    }
+
+    /// Only answer sync and repair requests from now on, merging nothing;
+    /// for a node retiring its key while its successor runs on the same
+    /// storage (see `CyberflyNode::rotate_identity`)
+    pub fn set_answer_only(&self, answer_only: bool) {
+        self.answer_only.store(answer_only, Ordering::Release);
+    }
+
+    pub fn is_answer_only(&self) -> bool {
+        self.answer_only.load(Ordering::Acquire)
+    }
+
+    /// Operations per sync response chunk from now on
+    pub fn set_max_ops_per_response(&self, max_ops: usize) {
+        self.max_ops_per_response.store(max_ops, Ordering::Relaxed);
+    }
+
+    /// Answer large full syncs with iroh-blobs snapshots
+    pub fn with_snapshots(mut self, snapshots: Arc<SnapshotManager>) -> Self {
+        self.snapshots = Some(snapshots);
+        self
+    }
+
+    /// Count snapshot downloads in `meter` and respect its data caps
+    pub fn with_bandwidth_meter(mut self, meter: Arc<BandwidthMeter>) -> Self {
+        self.bandwidth = Some(meter);
+        self
+    }
+
+    /// Sign our sync requests and responses with `node_key`, the key of
+    /// `local_node_id`
+    pub fn with_node_key(mut self, node_key: ed25519_dalek::SigningKey) -> Self {
+        self.node_key = Some(node_key);
+        self
+    }
+
+    /// `msg` signed with our node key, if we have one
+    fn signed(&self, mut msg: SyncMessage) -> SyncMessage {
+        if let Some(node_key) = &self.node_key {
+            msg.sign_envelope(node_key);
+        }
+        msg
+    }
+
+    /// Whether a sync request or response addressed to us may be handled: a
+    /// valid signature, or none while the node it names as sender is not
+    /// known to sign. `from_peer` is only the neighbor that relayed it.
+    fn check_envelope(&self, msg: &SyncMessage, from_peer: &str) -> bool {
+        match msg.verify_envelope() {
+            Ok(true) => {
+                // The signature shows the named sender signs, however it got here
+                if let Some(signer) = msg.envelope_signer() {
+                    if self.signing_peers.write().insert(signer.to_string()) {
+                        info!(peer = signer, "Peer signs its sync messages");
+                        if let Err(e) = self.sync_store.storage.add_signing_peer(signer) {
+                            warn!("Failed to save signing peer {}: {}", signer, e);
+                        }
+                    }
+                }
+                true
+            }
+            Ok(false) if msg.envelope_signer().is_some_and(|sender| self.signing_peers.read().contains(sender)) => {
+                warn!(peer = from_peer, kind = msg.kind(), "Rejecting unsigned sync message in the name of a peer that signs");
+                false
+            }
+            Ok(false) => true,
+            Err(e) => {
+                warn!(kind = msg.kind(), error = %e, "Rejecting sync message");
+                false
+            }
+        }
+    }
+
+    /// Record `request_id`, or a new ID, as sent now; responses are only
+    /// accepted to recent requests
+    fn issue_request(&self, request_id: Option<String>) -> String {
+        let request_id = request_id.unwrap_or_else(|| uuid::Uuid::new_v4().simple().to_string()[..8].to_string());
+        let mut issued = self.issued_requests.lock();
+        issued.retain(|_, sent| sent.elapsed() < SYNC_REQUEST_TTL);
+        issued.insert(request_id.clone(), Instant::now());
+        request_id
+    }
+
+    /// Whether `request_id` is one of our recent sync requests
+    fn issued_request(&self, request_id: &str) -> bool {
+        self.issued_requests.lock().get(request_id).is_some_and(|sent| sent.elapsed() < SYNC_REQUEST_TTL)
+    }
+
+    /// Whether any of our sync requests is recent enough to be answered
+    fn awaiting_response(&self) -> bool {
+        self.issued_requests.lock().values().any(|sent| sent.elapsed() < SYNC_REQUEST_TTL)
+    }
+
+    /// Whether a signed sync request is recent and not seen before; a
+    /// replayed one could otherwise move its requester's mark
+    fn fresh_request(&self, msg: &SyncMessage) -> bool {
+        let SyncMessage::SyncRequest { sent_at, signature: Some(signature), .. } = msg else {
+            return true;
+        };
+        let now = chrono::Utc::now().timestamp_millis();
+        let ttl_ms = SYNC_REQUEST_TTL.as_millis() as i64;
+        if !sent_at.is_some_and(|sent_at| (now - sent_at).abs() < ttl_ms) {
+            warn!(sent_at = ?sent_at, "Rejecting stale sync request");
+            return false;
+        }
+        let mut seen = self.seen_requests.lock();
+        seen.retain(|_, at| at.elapsed() < SYNC_REQUEST_TTL);
+        if seen.insert(signature.clone(), Instant::now()).is_some() {
+            warn!("Rejecting replayed sync request");
+            return false;
+        }
+        true
+    }
+
+    /// Emit an event without blocking the sync path; progress events are
+    /// best-effort and must never stall gossip handling.
+    fn emit(&self, event: NodeEvent) {
+        if let Some(tx) = &self.event_tx {
+            let _ = tx.try_send(event);
+        }
+    }
+
+    /// Get sync store reference
+    pub fn sync_store(&self) -> Arc<SyncStore> {
+        self.sync_store.clone()
+    }
+
+    /// Handle incoming sync message
+    ///
+    /// Requests and responses of this node's exchanges are handled in a
+    /// `sync_request` or `sync_response` span carrying the request ID, peer
+    /// and op counts, closed by an event with the duration.
+    pub async fn handle_sync_message(
+        &self,
+        msg: SyncMessage,
+        from_peer: &str,
+    ) -> Result<Option<SyncMessage>> {
+        if self.is_answer_only() && !matches!(msg, SyncMessage::SyncRequest { .. } | SyncMessage::RepairRequest { .. }) {
+            debug!(kind = msg.kind(), "Answering requests only, ignoring sync message");
+            return Ok(None);
+        }
+        let addressed = match &msg {
+            SyncMessage::SyncRequest { .. } => true,
+            SyncMessage::SyncResponse { requester, .. } => *requester == self.local_node_id,
+            _ => false,
+        };
+        if addressed && !(self.check_envelope(&msg, from_peer) && self.fresh_request(&msg)) {
+            return Ok(None);
+        }
+        // Anyone could confirm writes in another peer's name
+        if matches!(msg, SyncMessage::OperationAck { .. }) && !matches!(msg.verify_envelope(), Ok(true)) {
+            debug!(peer = from_peer, "Ignoring unsigned or forged operation ack");
+            return Ok(None);
+        }
+
+        let span = match &msg {
+            SyncMessage::SyncRequest { requester, since_timestamp, request_id, .. } => info_span!(
+                "sync_request",
+                request_id = request_id.as_deref().unwrap_or("-"),
+                peer = %requester,
+                since = ?since_timestamp,
+                ops_sent = Empty,
+                has_more = Empty,
+            ),
+            SyncMessage::SyncResponse { requester, responder, request_id, .. } if *requester == self.local_node_id => {
+                info_span!(
+                    "sync_response",
+                    request_id = request_id.as_deref().unwrap_or("-"),
+                    peer = responder.as_deref().unwrap_or(from_peer),
+                    ops = Empty,
+                    merged = Empty,
+                    has_more = Empty,
+                )
+            }
+            _ => return self.dispatch_sync_message(msg, from_peer).await,
+        };
+
+        let started = Instant::now();
+        let result = self.dispatch_sync_message(msg, from_peer).instrument(span.clone()).await;
+        let duration_ms = started.elapsed().as_millis() as u64;
+        span.in_scope(|| match &result {
+            Ok(_) => info!(duration_ms, "Sync step done"),
+            Err(e) => warn!(duration_ms, error = %e, "Sync step failed"),
+        });
+        result
+    }
+
+    async fn dispatch_sync_message(
+        &self,
+        msg: SyncMessage,
+        from_peer: &str,
+    ) -> Result<Option<SyncMessage>> {
+        match msg {
+            SyncMessage::SyncRequest {
+                requester,
+                since_timestamp,
+                accept_compression,
+                accept_snapshot,
+                databases,
+                owners,
+                request_id,
+                acks,
+                sent_at: _,
+                signature,
+            } => {
+                info!("Received sync request");
+
+                // Only the requester itself, delivering the request or having
+                // signed it (checked in `handle_sync_message`), uses and moves
+                // its mark: anyone naming another node could make it skip ops
+                let own_request = requester == from_peer || signature.is_some();
+                let session = own_request.then(|| self.begin_sync_session(&requester, since_timestamp, request_id.as_deref(), &acks));
+                let mark = session.as_ref().and_then(|session| session.base);
+
+                // Fresh full sync of a large store: point the requester at snapshots
+                if since_timestamp.is_none() && accept_snapshot && mark.is_none() {
+                    if let Some(offer) = self.offer_snapshots(&requester, databases.as_deref(), &owners).await {
+                        info!("Offering snapshots instead of operations");
+                        return Ok(Some(offer));
+                    }
+                }
+
+                // Skip everything this peer already got from a recent exchange
+                let candidates = self.sync_store.get_metas_since_with_seq(since_timestamp).await;
+                let candidate_count = candidates.len();
+                let mut metas: Vec<OpMeta> = candidates
+                    .into_iter()
+                    .filter(|(seq, meta)| !mark.is_some_and(|m| m.covers(*seq, meta.timestamp)))
+                    .filter(|(_, meta)| {
+                        databases.as_ref().is_none_or(|dbs| dbs.contains(&meta.db_name) || owned_by(&meta.db_name, &owners))
+                    })
+                    .map(|(_, meta)| meta)
+                    .collect();
+                if metas.len() < candidate_count {
+                    debug!(skipped = candidate_count - metas.len(), "Skipping ops already sent");
+                }
+
+                // Sort by timestamp, then op_id for determinism
+                metas.sort_by(|a, b| {
+                    a.timestamp.cmp(&b.timestamp).then(a.op_id.cmp(&b.op_id))
+                });
+
+                // Chunk to avoid large payloads; only the chunk is read from the oplog
+                let total = metas.len();
+                metas.truncate(self.max_ops_per_response.load(Ordering::Relaxed));
+                let chunk = self.sync_store.load_operations(&metas);
+                    
+                let has_more = total > metas.len();
+                let continuation_token = if has_more {
+                    let next_ts = chunk.last().map(|op| op.timestamp).unwrap_or(0);
+                    Some(format!("ts:{}", next_ts))
+                } else {
+                    None
+                };
+
+                Span::current().record("ops_sent", chunk.len()).record("has_more", has_more);
+                info!(remaining = total - chunk.len(), "Sending sync response");
+
+                let acls = self.sync_store.acl.lists_for(chunk.iter().map(|op| op.db_name.as_str()));
+                let tombstones = self.sync_store.tombstones_since(since_timestamp);
+
+                if let Some(session) = session {
+                    self.finish_sync_chunk(&requester, session, has_more, chunk.last().map(|op| op.timestamp));
+                }
+
+                // Compress only when the requester understands it and the
+                // payload is big enough to be worth it
+                let mut compressed_operations = None;
+                if accept_compression {
+                    let raw_size = serde_json::to_vec(&chunk).map(|v| v.len()).unwrap_or(0);
+                    if raw_size >= COMPRESSION_MIN_BYTES {
+                        match compress_operations(&chunk) {
+                            Ok(encoded) => {
+                                debug!("Compressed sync response {} -> {} bytes", raw_size, encoded.len());
+                                compressed_operations = Some(encoded);
+                            }
+                            Err(e) => warn!("Failed to compress sync response, sending plain: {}", e),
+                        }
+                    }
+                }
+                let ops_sent = chunk.len() as u64;
+                let bytes_sent = match &compressed_operations {
+                    Some(encoded) => encoded.len() as u64,
+                    None => serde_json::to_vec(&chunk).map(|v| v.len() as u64).unwrap_or(0),
+                };
+                let operations = if compressed_operations.is_some() { Vec::new() } else { chunk };
+
+                {
+                    let mut counters = self.counters.lock();
+                    counters.ops_sent += ops_sent;
+                    counters.bytes_sent += bytes_sent;
+                    let peer = counters.peer(&requester);
+                    peer.ops_sent += ops_sent;
+                    peer.bytes_sent += bytes_sent;
+                    peer.last_request_at = Some(chrono::Utc::now().timestamp_millis());
+                }
+
+                Ok(Some(self.signed(SyncMessage::SyncResponse {
+                    requester,
+                    operations,
+                    has_more,
+                    continuation_token,
+                    compressed_operations,
+                    responder: Some(self.local_node_id.clone()),
+                    remaining: Some((total as u64).saturating_sub(ops_sent)),
+                    acls,
+                    tombstones,
+                    request_id,
+                    signature: None,
+                })))
+            }
+            
+            SyncMessage::SyncResponse {
+                requester,
+                operations,
+                has_more,
+                continuation_token,
+                compressed_operations,
+                responder,
+                remaining,
+                acls,
+                tombstones,
+                request_id,
+                signature,
+            } => {
+                // Only process responses intended for this node
+                if requester != self.local_node_id {
+                    debug!("Ignoring SyncResponse intended for {}", requester);
+                    return Ok(None);
+                }
+                // ... and to a request it made lately. Older nodes neither
+                // sign nor echo the request ID, so an unsigned response
+                // without one is taken only while a request of ours is open
+                let answers_ours = match request_id.as_deref() {
+                    Some(id) => self.issued_request(id),
+                    None => signature.is_none() && self.awaiting_response(),
+                };
+                if !answers_ours {
+                    debug!("Ignoring SyncResponse that answers no recent request of ours");
+                    return Ok(None);
+                }
+
+                let (operations, bytes) = match compressed_operations {
+                    Some(encoded) => {
+                        let len = encoded.len() as u64;
+                        (decompress_operations(&encoded)?, len)
+                    }
+                    None => {
+                        let len = serde_json::to_vec(&operations).map(|v| v.len() as u64).unwrap_or(0);
+                        (operations, len)
+                    }
+                };
+                let peer_id = responder.unwrap_or_else(|| from_peer.to_string());
+
+                Span::current().record("ops", operations.len()).record("has_more", has_more);
+                info!(bytes, remaining, "Received sync response");
+
+                self.record_response_progress(&peer_id, &operations, bytes, has_more, remaining);
+
+                for acl in acls {
+                    if let Err(e) = self.sync_store.acl.apply(acl) {
+                        warn!("Rejected writer list from {}: {}", peer_id, e);
+                    }
+                }
+                for tombstone in tombstones {
+                    if let Err(e) = self.sync_store.apply_tombstone(tombstone).await {
+                        warn!("Rejected tombstone from {}: {}", peer_id, e);
+                    }
+                }
+
+                // Merge and apply
+                let merged = self.sync_store.merge_operations(operations).await?;
+                Span::current().record("merged", merged);
+                
+                let _ = self.sync_store.apply_all_to_storage().await?;
+                if !has_more {
+                    self.sync_store.note_synced(chrono::Utc::now().timestamp_millis()).await;
+                    // Only a signature shows the named responder sent it
+                    if let (Some(request_id), true) = (&request_id, signature.is_some()) {
+                        self.received_exchanges.lock().insert(peer_id.clone(), request_id.clone());
+                    }
+                }
+
+                // If more data is available, request next chunk
+                if has_more {
+                    if let Some(token) = continuation_token {
+                        if let Some(ts_str) = token.strip_prefix("ts:") {
+                            if let Ok(ts) = ts_str.parse::<i64>() {
+                                info!(since = ts, "Requesting next chunk");
+                                let accept_snapshot = self.blob_transfers_enabled();
+                                return Ok(Some(self.sync_request(Some(ts), accept_snapshot, request_id)));
+                            }
+                        }
+                    }
+                }
+
+                Ok(None)
+            }
+            
+            SyncMessage::SnapshotOffer { requester, provider, snapshots } => {
+                if requester != self.local_node_id {
+                    debug!("Ignoring SnapshotOffer intended for {}", requester);
+                    return Ok(None);
+                }
+                // Offers aren't signed, so only take one from the provider
+                // itself; anyone else could point us at a node of their choice
+                if provider != from_peer {
+                    warn!(peer = from_peer, "Ignoring SnapshotOffer naming {} as provider", provider);
+                    return Ok(None);
+                }
+
+                info!("Received {} snapshot(s) offered by {}", snapshots.len(), provider);
+                if !self.blob_transfers_enabled() {
+                    info!("Blob transfers are off, asking {} for op sync instead", provider);
+                    return Ok(Some(self.sync_request(None, false, None)));
+                }
+                match self.fetch_snapshots(&provider, &snapshots).await {
+                    // Catch up on anything written after the snapshots were taken
+                    Ok(head) => Ok(Some(self.create_sync_request(Some(head)))),
+                    Err(e) => {
+                        warn!("Snapshot sync from {} failed, falling back to op sync: {}", provider, e);
+                        Ok(Some(self.sync_request(None, false, None)))
+                    }
+                }
+            }
+
+            SyncMessage::AclUpdate { acl } => {
+                info!("Received writer list for {} (version {}) from {}", acl.db_name, acl.version, from_peer);
+                if let Err(e) = self.sync_store.acl.apply(acl) {
+                    warn!("Rejected writer list from {}: {}", from_peer, e);
+                }
+                Ok(None)
+            }
+
+            SyncMessage::DropDatabase { tombstone } => {
+                info!("Received drop of {} from {}", tombstone.db_name, from_peer);
+                if let Err(e) = self.sync_store.apply_tombstone(tombstone).await {
+                    warn!("Rejected tombstone from {}: {}", from_peer, e);
+                }
+                Ok(None)
+            }
+
+            SyncMessage::MerkleRoot { root } => {
+                if root.publisher == self.local_node_id || !self.sync_store.sync_filter().allows(&root.db_name) {
+                    return Ok(None);
+                }
+                if let Err(e) = root.verify() {
+                    warn!("Rejected root of {} from {}: {}", root.db_name, from_peer, e);
+                    return Ok(None);
+                }
+
+                let metas = self.sync_store.database_metas(&root.db_name).await;
+                let tree = StateTree::from_leaves(metas.into_iter().map(|meta| (meta.crdt_key, meta.hash)));
+                let mismatched_buckets = tree.mismatching_buckets(&root.buckets);
+                let check = RootCheck {
+                    db_name: root.db_name.clone(),
+                    publisher: root.publisher.clone(),
+                    mismatched_buckets: mismatched_buckets.clone(),
+                    checked_at: chrono::Utc::now().timestamp_millis(),
+                };
+                self.root_checks.lock().insert(root.db_name.clone(), check);
+                if mismatched_buckets.is_empty() {
+                    debug!("Replica of {} matches the root of {}", root.db_name, root.publisher);
+                    return Ok(None);
+                }
+
+                info!(
+                    "Replica of {} differs from the root of {} in {} buckets, requesting repair",
+                    root.db_name,
+                    root.publisher,
+                    mismatched_buckets.len()
+                );
+                Ok(Some(SyncMessage::RepairRequest {
+                    requester: self.local_node_id.clone(),
+                    provider: root.publisher,
+                    db_name: root.db_name,
+                    buckets: mismatched_buckets,
+                    request_id: Some(self.issue_request(None)),
+                }))
+            }
+
+            SyncMessage::RepairRequest { requester, provider, db_name, buckets, request_id } => {
+                if provider != self.local_node_id {
+                    return Ok(None);
+                }
+
+                let mut metas: Vec<OpMeta> = self
+                    .sync_store
+                    .database_metas(&db_name)
+                    .await
+                    .into_iter()
+                    .filter(|meta| buckets.contains(&merkle::bucket_of(&meta.crdt_key)))
+                    .collect();
+                metas.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.op_id.cmp(&b.op_id)));
+                // The rest is repaired after the next root
+                let total = metas.len();
+                metas.truncate(self.max_ops_per_response.load(Ordering::Relaxed));
+                let operations = self.sync_store.load_operations(&metas);
+                info!("Sending {} of {} ops of {} to repair {}", operations.len(), total, db_name, requester);
+
+                let compressed_operations = match serde_json::to_vec(&operations) {
+                    Ok(raw) if raw.len() >= COMPRESSION_MIN_BYTES => compress_operations(&operations).ok(),
+                    _ => None,
+                };
+                let acls = self.sync_store.acl.lists_for([db_name.as_str()]);
+                Ok(Some(self.signed(SyncMessage::SyncResponse {
+                    requester,
+                    operations: if compressed_operations.is_some() { Vec::new() } else { operations },
+                    has_more: false,
+                    continuation_token: None,
+                    compressed_operations,
+                    responder: Some(self.local_node_id.clone()),
+                    remaining: None,
+                    acls,
+                    tombstones: Vec::new(),
+                    request_id,
+                    signature: None,
+                })))
+            }
+
+            SyncMessage::Operation { operation } => {
+                info!(
+                    op_id = %operation.op_id,
+                    peer = from_peer,
+                    db = %operation.db_name,
+                    key = %operation.key,
+                    "Received operation"
+                );
+
+                // Add to store (will verify signature)
+                let added = match self.sync_store.add_operation(operation.clone()).await {
+                    Ok(true) => {
+                        info!(op_id = %operation.op_id, "Operation accepted");
+                        // Apply to storage
+                        if let Err(e) = self.sync_store.apply_to_storage(&operation).await {
+                            error!(op_id = %operation.op_id, error = %e, "Failed to apply to storage");
+                        }
+                        true
+                    }
+                    Ok(false) => {
+                        debug!(op_id = %operation.op_id, "⏭️ Operation rejected (duplicate or older)");
+                        false
+                    }
+                    Err(e) => {
+                        error!(op_id = %operation.op_id, error = %e, "Failed to add operation");
+                        false
+                    }
+                };
+
+                // Tell the writer we have it (or something newer) with the
+                // next batch of acks, which only a node key can sign
+                if self.node_key.is_some() && (added || self.sync_store.holds(&operation).await) {
+                    self.pending_acks.lock().push(operation.op_id);
+                }
+                Ok(None)
+            }
+
+            SyncMessage::OperationAck { from, op_ids, .. } => {
+                if from == self.local_node_id {
+                    return Ok(None);
+                }
+                let acked = self.sync_store.acknowledge(&op_ids);
+                if acked > 0 {
+                    debug!(peer = %from, acked, "Peer confirmed operations");
+                    let mut counters = self.counters.lock();
+                    let peer = counters.peer(&from);
+                    peer.ops_acked += acked as u64;
+                    peer.last_ack_at = Some(chrono::Utc::now().timestamp_millis());
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    /// Signed roots of the databases `node_key` may publish: those it owns
+    /// and those of user keys it is linked to
+    pub async fn signed_roots(&self, node_key: &ed25519_dalek::SigningKey) -> Result<Vec<SignedRoot>> {
+        let certificates = devices::certificates(&self.sync_store.storage)?;
+        let mut databases: HashMap<String, Vec<OpMeta>> = HashMap::new();
+        for meta in self.sync_store.all_metas().await {
+            databases.entry(meta.db_name.clone()).or_default().push(meta);
+        }
+
+        let mut roots = Vec::new();
+        for (db_name, metas) in databases {
+            let Some(owner) = acl::db_owner(&db_name) else {
+                continue;
+            };
+            let certificate = if owner == self.local_node_id {
+                None
+            } else {
+                match certificates
+                    .iter()
+                    .find(|c| c.user_public_key == owner && c.device_node_id() == self.local_node_id)
+                {
+                    Some(certificate) => Some(certificate.clone()),
+                    None => continue,
+                }
+            };
+            let keys = metas.len() as u64;
+            let tree = StateTree::from_leaves(metas.into_iter().map(|meta| (meta.crdt_key, meta.hash)));
+            roots.push(SignedRoot::create(&db_name, &tree, keys, node_key, certificate)?);
+        }
+        Ok(roots)
+    }
+
+    /// Latest comparison of each replicated database against its owner's root
+    pub fn root_checks(&self) -> Vec<RootCheck> {
+        let mut checks: Vec<RootCheck> = self.root_checks.lock().values().cloned().collect();
+        checks.sort_by(|a, b| a.db_name.cmp(&b.db_name));
+        checks
+    }
+
+    /// The exchange this request belongs to: a continuation keeps the
+    /// session it started, a new one starts from the mark of the exchange
+    /// the requester acknowledges in `acks`, if any
+    fn begin_sync_session(
+        &self,
+        requester: &str,
+        since_timestamp: Option<i64>,
+        request_id: Option<&str>,
+        acks: &[SyncAck],
+    ) -> SyncSession {
+        // Read before fetching ops: anything arriving meanwhile stays above the mark
+        let current_seq = self.sync_store.current_seq();
+        let mut marks = self.peer_marks.lock();
+        let state = marks.entry(requester.to_string()).or_default();
+
+        if let Some(session) = &state.session {
+            if since_timestamp.is_some() && session.next_since == since_timestamp && session.request_id.as_deref() == request_id {
+                return session.clone();
+            }
+        }
+
+        // An unacknowledged final response may have been lost, so only an
+        // acknowledged mark is used, and only once
+        let base = state.pending.take().and_then(|(pending_id, mark)| {
+            let acked = acks.iter().any(|ack| ack.responder == self.local_node_id && ack.request_id == pending_id);
+            (acked && mark.recorded_at.elapsed() <= SYNC_MARK_TTL).then_some(mark)
+        });
+        SyncSession {
+            request_id: request_id.map(str::to_string),
+            since: since_timestamp,
+            seq: current_seq,
+            base,
+            next_since: None,
+        }
+    }
+
+    /// Advance the requester's session; once the final chunk is out the
+    /// session becomes the mark its next request may acknowledge
+    fn finish_sync_chunk(
+        &self,
+        requester: &str,
+        mut session: SyncSession,
+        has_more: bool,
+        last_timestamp: Option<i64>,
+    ) {
+        let mut marks = self.peer_marks.lock();
+        let state = marks.entry(requester.to_string()).or_default();
+
+        if has_more {
+            session.next_since = last_timestamp;
+            state.session = Some(session);
+        } else {
+            state.session = None;
+            // Requests without an ID can't be acknowledged
+            state.pending = session.request_id.map(|request_id| {
+                let mark = SyncMark {
+                    since: session.since,
+                    seq: session.seq,
+                    recorded_at: Instant::now(),
+                };
+                (request_id, mark)
+            });
+        }
+    }
+
+    /// Build snapshots of every database for `requester`, or `None` when the
+    /// store is small (or snapshots are unavailable) and op sync is fine
+    async fn offer_snapshots(
+        &self,
+        requester: &str,
+        databases: Option<&[String]>,
+        owners: &[String],
+    ) -> Option<SyncMessage> {
+        if !self.blob_transfers_enabled() {
+            return None;
+        }
+        let manager = self.snapshots.as_ref()?;
+        let seq = self.sync_store.current_seq();
+        let mut metas = self.sync_store.all_metas().await;
+        if let Some(dbs) = databases {
+            metas.retain(|meta| dbs.contains(&meta.db_name) || owned_by(&meta.db_name, owners));
+        }
+        if metas.len() < SNAPSHOT_MIN_OPS {
+            return None;
+        }
+        let operations = self.sync_store.load_operations(&metas);
+
+        let mut by_db: HashMap<String, Vec<SignedOperation>> = HashMap::new();
+        for op in operations {
+            by_db.entry(op.db_name.clone()).or_default().push(op);
+        }
+
+        let mut snapshots = Vec::with_capacity(by_db.len());
+        for (db_name, ops) in by_db {
+            let acl = self.sync_store.acl.get(&db_name);
+            match manager.create(&db_name, seq, ops, acl).await {
+                Ok(info) => snapshots.push(info),
+                Err(e) => {
+                    warn!("Failed to create snapshot of {}, using op sync: {}", db_name, e);
+                    return None;
+                }
+            }
+        }
+
+        {
+            let mut counters = self.counters.lock();
+            let ops_sent: u64 = snapshots.iter().map(|s| s.op_count).sum();
+            let bytes_sent: u64 = snapshots.iter().map(|s| s.size).sum();
+            counters.ops_sent += ops_sent;
+            counters.bytes_sent += bytes_sent;
+            let peer = counters.peer(requester);
+            peer.ops_sent += ops_sent;
+            peer.bytes_sent += bytes_sent;
+            peer.last_request_at = Some(chrono::Utc::now().timestamp_millis());
+        }
+
+        info!("Offering {} snapshot(s) to {}", snapshots.len(), requester);
+        Some(SyncMessage::SnapshotOffer {
+            requester: requester.to_string(),
+            provider: self.local_node_id.clone(),
+            snapshots,
+        })
+    }
+
+    /// Download and apply offered snapshots; returns the oldest snapshot head
+    /// timestamp so the caller can request the tail
+    async fn fetch_snapshots(&self, provider: &str, snapshots: &[SnapshotInfo]) -> Result<i64> {
+        let manager = self.snapshots.as_ref().ok_or_else(|| anyhow!("Snapshots not supported"))?;
+        if snapshots.is_empty() {
+            return Err(anyhow!("Empty snapshot offer"));
+        }
+
+        for info in snapshots {
+            let snapshot = manager.fetch(info, provider).await?;
+            if let Some(meter) = &self.bandwidth {
+                meter.record_received(Subsystem::Blobs, SNAPSHOTS_TOPIC, info.size as usize);
+            }
+            if let Some(acl) = snapshot.acl.clone() {
+                if let Err(e) = self.sync_store.acl.apply(acl) {
+                    warn!("Rejected writer list in snapshot of {}: {}", snapshot.db_name, e);
+                }
+            }
+            self.record_response_progress(provider, &snapshot.operations, info.size, false, Some(0));
+            self.sync_store.apply_snapshot(&snapshot.db_name, snapshot.operations).await?;
+        }
+
+        Ok(snapshots.iter().map(|s| s.head_timestamp).min().unwrap_or(0))
+    }
+
+    /// Request full sync from a peer
+    pub fn create_sync_request(&self, since_timestamp: Option<i64>) -> SyncMessage {
+        self.sync_request(since_timestamp, self.blob_transfers_enabled(), None)
+    }
+
+    /// A sync request continuing the exchange `request_id`, or starting a
+    /// new one
+    fn sync_request(&self, since_timestamp: Option<i64>, accept_snapshot: bool, request_id: Option<String>) -> SyncMessage {
+        let request_id = self.issue_request(request_id);
+        let filter = self.sync_store.sync_filter();
+        let databases = filter.selective.then(|| {
+            let mut dbs: Vec<String> = filter.databases.into_iter().collect();
+            dbs.sort();
+            dbs
+        });
+        let mut owners: Vec<String> = if filter.selective { filter.owners.into_iter().collect() } else { Vec::new() };
+        owners.sort();
+        let mut acks: Vec<SyncAck> = self
+            .received_exchanges
+            .lock()
+            .iter()
+            .map(|(responder, request_id)| SyncAck { responder: responder.clone(), request_id: request_id.clone() })
+            .collect();
+        acks.sort_by(|a, b| a.responder.cmp(&b.responder));
+
+        self.signed(SyncMessage::SyncRequest {
+            requester: self.local_node_id.clone(),
+            since_timestamp,
+            accept_compression: true,
+            accept_snapshot,
+            databases,
+            owners,
+            request_id: Some(request_id),
+            acks,
+            sent_at: Some(chrono::Utc::now().timestamp_millis()),
+            signature: None,
+        })
+    }
+
+    /// Create operation message for broadcast
+    pub fn create_operation_message(&self, op: SignedOperation) -> SyncMessage {
+        SyncMessage::Operation { operation: op }
+    }
+
+    /// A signed ack for up to `MAX_ACK_BATCH` of the operations received
+    /// since the last one, if any. Only nodes with a node key queue acks,
+    /// as unsigned ones aren't counted.
+    pub fn take_operation_ack(&self) -> Option<SyncMessage> {
+        let op_ids: Vec<String> = {
+            let mut pending = self.pending_acks.lock();
+            let batch = pending.len().min(MAX_ACK_BATCH);
+            pending.drain(..batch).collect()
+        };
+        if op_ids.is_empty() {
+            return None;
+        }
+        Some(self.signed(SyncMessage::OperationAck {
+            from: self.local_node_id.clone(),
+            op_ids,
+            signature: None,
+        }))
+    }
+
+    /// Update counters for a received `SyncResponse` chunk and emit one
+    /// `SyncProgress` event per database contained in it
+    fn record_response_progress(
+        &self,
+        peer_id: &str,
+        operations: &[SignedOperation],
+        bytes: u64,
+        has_more: bool,
+        remaining: Option<u64>,
+    ) {
+        let received = operations.len() as u64;
+        let session_received = {
+            let mut counters = self.counters.lock();
+            counters.ops_received += received;
+            counters.bytes_received += bytes;
+            let now = chrono::Utc::now().timestamp_millis();
+            if !has_more {
+                counters.last_sync_at = Some(now);
+            }
+            let peer = counters.peer(peer_id);
+            peer.ops_received += received;
+            peer.bytes_received += bytes;
+            peer.last_response_at = Some(now);
+            peer.session_ops_received += received;
+            peer.in_progress = has_more;
+            let session_received = peer.session_ops_received;
+            if !has_more {
+                peer.session_ops_received = 0;
+            }
+            session_received
+        };
+
+        // Older responders don't report `remaining`; assume one more full chunk
+        let remaining = remaining.unwrap_or(if has_more { MAX_OPS_PER_RESPONSE as u64 } else { 0 });
+        let ops_total_estimate = session_received + remaining;
+
+        let mut per_db: HashMap<&str, u64> = HashMap::new();
+        for op in operations {
+            *per_db.entry(op.db_name.as_str()).or_default() += 1;
+        }
+        for (db_name, ops_received) in per_db {
+            self.emit(NodeEvent::SyncProgress {
+                db_name: db_name.to_string(),
+                ops_received,
+                ops_total_estimate,
+                bytes,
+            });
+        }
+    }
+
+    /// Get sync statistics
+    pub async fn get_stats(&self) -> SyncStats {
+        let total_operations = self.sync_store.operation_count().await;
+        let counters = self.counters.lock();
+        let mut peers: Vec<PeerSyncStatus> = counters.peers.values().cloned().collect();
+        peers.sort_by(|a, b| a.peer_id.cmp(&b.peer_id));
+        SyncStats {
+            total_operations,
+            local_node_id: self.local_node_id.clone(),
+            ops_sent: counters.ops_sent,
+            ops_received: counters.ops_received,
+            bytes_sent: counters.bytes_sent,
+            bytes_received: counters.bytes_received,
+            last_sync_at: counters.last_sync_at,
+            peers,
+        }
+    }
+}
+
+impl Clone for SyncManager {
+    fn clone(&self) -> Self {
+        Self {
+            sync_store: self.sync_store.clone(),
+            local_node_id: self.local_node_id.clone(),
+            counters: self.counters.clone(),
+            peer_marks: self.peer_marks.clone(),
+            snapshots: self.snapshots.clone(),
+            event_tx: self.event_tx.clone(),
+            max_ops_per_response: self.max_ops_per_response.clone(),
+            blob_transfers: self.blob_transfers.clone(),
+            bandwidth: self.bandwidth.clone(),
+            root_checks: self.root_checks.clone(),
+            node_key: self.node_key.clone(),
+            signing_peers: self.signing_peers.clone(),
+            issued_requests: self.issued_requests.clone(),
+            received_exchanges: self.received_exchanges.clone(),
+            seen_requests: self.seen_requests.clone(),
+            answer_only: self.answer_only.clone(),
+            pending_acks: self.pending_acks.clone(),
+        }
+    }
+}
+
+/// Sync statistics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncStats {
+    pub total_operations: usize,
+    pub local_node_id: String,
+    pub ops_sent: u64,
+    pub ops_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    /// Unix ms of the last completed sync exchange (final chunk received)
+    pub last_sync_at: Option<i64>,
+    pub peers: Vec<PeerSyncStatus>,
+}
+
+/// Sync status with a single peer
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PeerSyncStatus {
+    pub peer_id: String,
+    pub ops_sent: u64,
+    pub ops_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    /// Last SyncRequest we answered for this peer (unix ms)
+    pub last_request_at: Option<i64>,
+    /// Last SyncResponse chunk received from this peer (unix ms)
+    pub last_response_at: Option<i64>,
+    /// A multi-chunk sync from this peer is still in flight
+    pub in_progress: bool,
+    /// Ops received in the current (or last) exchange
+    pub session_ops_received: u64,
+    /// Our writes this peer confirmed holding
+    pub ops_acked: u64,
+    /// Last confirmation of our writes from this peer (unix ms)
+    pub last_ack_at: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::{tempdir, TempDir};
+
+    /// Returns the TempDir too so the sled directory outlives the test body
+    fn create_test_storage() -> (TempDir, Arc<Storage>) {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().to_path_buf()).unwrap();
+        (dir, Arc::new(storage))
+    }
+
+    fn test_op(op_id: &str, timestamp: i64, key: &str, value: &str) -> SignedOperation {
+        let mut op = SignedOperation::new(
+            "testdb".to_string(),
+            key.to_string(),
+            value.to_string(),
+            "String".to_string(),
+            "a".repeat(64),
+            format!("sig-{}", op_id),
+        );
+        op.op_id = op_id.to_string();
+        op.timestamp = timestamp;
+        op
+    }
+
+    #[tokio::test]
+    async fn test_sync_store_lww() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage);
+
+        let op1 = test_op("op1", 1000, "key1", "value1");
+        let op2 = test_op("op2", 2000, "key1", "value2"); // Newer
+
+        // Add older operation first (unverified for test)
+        store.add_operation_unverified(op1.clone()).await.unwrap();
+        
+        // Add newer operation
+        store.add_operation_unverified(op2.clone()).await.unwrap();
+
+        let ops = store.get_all_operations().await;
+        assert_eq!(ops.len(), 1);
+        assert_eq!(ops[0].value, "value2"); // Newer value wins
+    }
+
+    #[tokio::test]
+    async fn test_high_water_mark_checkpoint() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        assert_eq!(store.save_high_water_mark().await.unwrap(), None);
+        assert_eq!(store.saved_high_water_mark().unwrap(), None);
+
+        store.add_operation_unverified(test_op("op1", 2000, "k1", "v")).await.unwrap();
+        store.add_operation_unverified(test_op("op2", 1000, "k2", "v")).await.unwrap();
+        assert_eq!(store.save_high_water_mark().await.unwrap(), Some(2000));
+
+        let reopened = SyncStore::new(storage);
+        assert_eq!(reopened.load_from_storage().await.unwrap(), 2);
+        assert_eq!(reopened.high_water_mark().await, reopened.saved_high_water_mark().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_operations_read_from_oplog() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let old = test_op("op1", 1000, "k1", "old");
+        let new = test_op("op2", 2000, "k1", "new");
+        let other = test_op("op3", 1500, "k2", "v");
+        for op in [&old, &new, &other] {
+            store.add_operation_unverified(op.clone()).await.unwrap();
+        }
+
+        // A reopened store has only metadata in memory, and nothing hot
+        let reopened = SyncStore::new(storage);
+        reopened.load_from_storage().await.unwrap();
+        let metas = reopened.database_metas("testdb").await;
+        assert_eq!(metas.len(), 2);
+        let tree = StateTree::from_leaves(metas.iter().map(|meta| (meta.crdt_key.clone(), meta.hash)));
+        assert_eq!(tree, StateTree::build([&new, &other]));
+
+        assert_eq!(reopened.get_operation("testdb:k1").await.unwrap().value, "new");
+        assert!(reopened.hot_ops.lock().peek("op2").is_some());
+        let since = reopened.get_operations_since(1500).await;
+        assert_eq!(since.len(), 2);
+        // Scans leave the hot operations alone
+        assert!(reopened.hot_ops.lock().peek("op3").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_oplog_garbage_collection() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let day = 24 * 60 * 60 * 1000;
+        let now = 30 * day;
+        for (op_id, timestamp) in [("v1", day), ("v2", 2 * day), ("v3", 3 * day), ("v4", 29 * day), ("v5", 29 * day + 1)] {
+            store.add_operation_unverified(test_op(op_id, timestamp, "k1", op_id)).await.unwrap();
+        }
+        store.add_operation_unverified(test_op("other", day, "k2", "v")).await.unwrap();
+        let policy = OplogGcPolicy { keep_versions: 2, keep_for: Duration::from_millis(7 * day as u64) };
+
+        // Nothing goes before the current operation is applied
+        assert_eq!(store.collect_garbage(&policy, now).await.unwrap(), 0);
+        store.apply_all_to_storage().await.unwrap();
+
+        // v5 is current and v4 the second version kept
+        assert_eq!(store.collect_garbage(&policy, now).await.unwrap(), 3);
+        assert!(storage.has_operation("v4").unwrap() && storage.has_operation("other").unwrap());
+        assert!(["v1", "v2", "v3"].iter().all(|op_id| !storage.has_operation(op_id).unwrap()));
+        assert!(!store.is_applied("v1").await);
+
+        // Full syncs are answered from what's left
+        let mut current: Vec<String> = store.get_all_operations().await.into_iter().map(|op| op.op_id).collect();
+        current.sort();
+        assert_eq!(current, ["other", "v5"]);
+        let reopened = SyncStore::new(storage);
+        reopened.load_from_storage().await.unwrap();
+        assert_eq!(reopened.get_operation("testdb:k1").await.unwrap().op_id, "v5");
+        // Recent versions are kept past `keep_versions`
+        let current_only = OplogGcPolicy { keep_versions: 1, ..policy };
+        assert_eq!(reopened.collect_garbage(&current_only, now).await.unwrap(), 0);
+        assert_eq!(reopened.collect_garbage(&current_only, now + 7 * day).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sync_message_serialization() {
+        let op = test_op("test-op", 12345, "key", "value");
+
+        let msg = SyncMessage::Operation { operation: op };
+        let json = serde_json::to_string(&msg).unwrap();
+        let decoded: SyncMessage = serde_json::from_str(&json).unwrap();
+
+        if let SyncMessage::Operation { operation } = decoded {
+            assert_eq!(operation.op_id, "test-op");
+        } else {
+            panic!("Wrong message type");
+        }
+    }
+
+    #[test]
+    fn test_compress_operations_roundtrip() {
+        let ops: Vec<SignedOperation> = (0..64)
+            .map(|i| test_op(&format!("op{}", i), 1000 + i, &format!("key{}", i), "some repeated value"))
+            .collect();
+
+        let encoded = compress_operations(&ops).unwrap();
+        assert!(encoded.len() < serde_json::to_vec(&ops).unwrap().len());
+
+        let decoded = decompress_operations(&encoded).unwrap();
+        assert_eq!(decoded.len(), ops.len());
+        assert_eq!(decoded[10].op_id, "op10");
+    }
+
+    #[test]
+    fn test_decompress_rejects_oversized_payload() {
+        let bomb = vec![b' '; MAX_DECOMPRESSED_BYTES + 1];
+        let compressed = zstd::stream::encode_all(&bomb[..], COMPRESSION_LEVEL).unwrap();
+        let encoded = general_purpose::STANDARD.encode(compressed);
+
+        assert!(decompress_operations(&encoded).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sync_progress_updates_stats_and_emits_events() {
+        let (_dir, storage) = create_test_storage();
+        let (tx, mut rx) = mpsc::channel(16);
+        let manager = SyncManager::new(storage, "local".to_string(), Some(tx));
+
+        let ops = vec![test_op("op1", 1000, "k1", "v1"), test_op("op2", 1001, "k2", "v2")];
+        manager.record_response_progress("peer1", &ops, 512, true, Some(3));
+
+        match rx.try_recv().unwrap() {
+            NodeEvent::SyncProgress { db_name, ops_received, ops_total_estimate, bytes } => {
+                assert_eq!(db_name, "testdb");
+                assert_eq!(ops_received, 2);
+                assert_eq!(ops_total_estimate, 5);
+                assert_eq!(bytes, 512);
+            }
+            _ => panic!("Wrong event type"),
+        }
+
+        let stats = manager.get_stats().await;
+        assert_eq!(stats.ops_received, 2);
+        assert_eq!(stats.bytes_received, 512);
+        assert!(stats.last_sync_at.is_none());
+        assert!(stats.peers[0].in_progress);
+
+        manager.record_response_progress("peer1", &ops[..1], 100, false, Some(0));
+        let stats = manager.get_stats().await;
+        assert_eq!(stats.ops_received, 3);
+        assert!(stats.last_sync_at.is_some());
+        assert!(!stats.peers[0].in_progress);
+    }
+
+    /// A signing responder holding `count` ops and a signing requester
+    async fn sync_pair(count: i64) -> (Vec<TempDir>, SyncManager, SyncManager) {
+        let (responder_key, responder_id) = crypto::generate_keypair();
+        let (requester_key, requester_id) = crypto::generate_keypair();
+        let (dir, storage) = create_test_storage();
+        let responder = SyncManager::new(storage, responder_id, None).with_node_key(responder_key);
+        for i in 0..count {
+            let op = test_op(&format!("op{}", i), 1000 + i, &format!("k{}", i), "v");
+            responder.sync_store().add_operation_unverified(op).await.unwrap();
+        }
+        let (dir_b, storage_b) = create_test_storage();
+        let requester = SyncManager::new(storage_b, requester_id, None).with_node_key(requester_key);
+        (vec![dir, dir_b], responder, requester)
+    }
+
+    /// Op IDs of `responder`'s answer to `request`, delivered by `from_peer`
+    async fn answer_ids(responder: &SyncManager, request: SyncMessage, from_peer: &str) -> Vec<String> {
+        match responder.handle_sync_message(request, from_peer).await.unwrap() {
+            Some(SyncMessage::SyncResponse { operations, .. }) => operations.into_iter().map(|op| op.op_id).collect(),
+            _ => panic!("Expected SyncResponse"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repeat_sync_request_only_sends_delta() {
+        let (_dirs, responder, requester) = sync_pair(3).await;
+        let store = responder.sync_store();
+        let requester_id = requester.local_node_id.clone();
+
+        // Each answer the requester receives is acknowledged in its next request
+        let sync = || async {
+            let request = requester.create_sync_request(None);
+            let response = responder.handle_sync_message(request, &requester_id).await.unwrap().unwrap();
+            let SyncMessage::SyncResponse { operations, .. } = &response else { panic!("Expected SyncResponse") };
+            let ids: Vec<String> = operations.iter().map(|op| op.op_id.clone()).collect();
+            requester.handle_sync_message(response, &responder.local_node_id).await.unwrap();
+            ids
+        };
+        assert_eq!(sync().await.len(), 3);
+        assert!(sync().await.is_empty());
+
+        // An older-timestamped op arriving later is still part of the delta
+        store.add_operation_unverified(test_op("late", 500, "k9", "v")).await.unwrap();
+        assert_eq!(sync().await, ["late"]);
+
+        // Other peers are unaffected
+        let unsigned = |request_id: &str, acks: Vec<SyncAck>| SyncMessage::SyncRequest {
+            requester: "peer2".to_string(),
+            since_timestamp: None,
+            accept_compression: false,
+            accept_snapshot: false,
+            databases: None,
+            owners: Vec::new(),
+            request_id: Some(request_id.to_string()),
+            acks,
+            sent_at: None,
+            signature: None,
+        };
+        assert_eq!(answer_ids(&responder, unsigned("a", Vec::new()), "peer2").await.len(), 4);
+
+        // A request naming a peer, delivered by someone else, can't
+        // acknowledge anything in its name
+        let ack = SyncAck { responder: responder.local_node_id.clone(), request_id: "a".to_string() };
+        assert_eq!(answer_ids(&responder, unsigned("b", vec![ack.clone()]), "mallory").await.len(), 4);
+        assert!(answer_ids(&responder, unsigned("c", vec![ack]), "peer2").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lost_sync_response_is_sent_again() {
+        let (_dirs, responder, requester) = sync_pair(3).await;
+        let requester_id = requester.local_node_id.clone();
+
+        // The first answer never arrives, so the next request gets it all again
+        let first = requester.create_sync_request(None);
+        assert_eq!(answer_ids(&responder, first, &requester_id).await.len(), 3);
+        let second = requester.create_sync_request(None);
+        let response = responder.handle_sync_message(second, &requester_id).await.unwrap().unwrap();
+        assert!(matches!(&response, SyncMessage::SyncResponse { operations, .. } if operations.len() == 3));
+
+        // Once one arrives, the mark it acknowledges holds
+        requester.handle_sync_message(response, &responder.local_node_id).await.unwrap();
+        let third = requester.create_sync_request(None);
+        assert!(matches!(&third, SyncMessage::SyncRequest { acks, .. } if acks.len() == 1));
+        assert!(answer_ids(&responder, third, &requester_id).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replayed_or_stale_sync_request_is_rejected() {
+        let (_dirs, responder, requester) = sync_pair(1).await;
+        let requester_id = requester.local_node_id.clone();
+
+        let request = requester.create_sync_request(None);
+        assert_eq!(answer_ids(&responder, request.clone(), &requester_id).await.len(), 1);
+        assert!(responder.handle_sync_message(request, "mallory").await.unwrap().is_none());
+
+        let mut stale = requester.create_sync_request(None);
+        let SyncMessage::SyncRequest { sent_at, .. } = &mut stale else { unreachable!() };
+        *sent_at = Some(chrono::Utc::now().timestamp_millis() - SYNC_REQUEST_TTL.as_millis() as i64 - 1);
+        stale.sign_envelope(requester.node_key.as_ref().unwrap());
+        assert!(responder.handle_sync_message(stale, &requester_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sync_exchange_keeps_request_id() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+        let (_dir, storage) = create_test_storage();
+        let responder = SyncManager::new(storage, "responder".to_string(), None);
+        responder.set_max_ops_per_response(2);
+        for i in 0..3 {
+            let op = SignedOperation::create_and_sign(db_name.clone(), format!("k{}", i), "v".into(), "String".into(), &owner_key);
+            responder.sync_store().add_operation(op).await.unwrap();
+        }
+        let (_dir_b, storage_b) = create_test_storage();
+        let requester = SyncManager::new(storage_b, "requester".to_string(), None);
+
+        let request = requester.create_sync_request(None);
+        let SyncMessage::SyncRequest { request_id: Some(id), .. } = &request else { panic!("Expected request ID") };
+        let id = id.clone();
+        assert_eq!(id.len(), 8);
+
+        let response = responder.handle_sync_message(request, "requester").await.unwrap().unwrap();
+        assert!(matches!(&response, SyncMessage::SyncResponse { request_id: Some(r), has_more: true, .. } if *r == id));
+
+        // The request for the next chunk continues the same exchange
+        let next = requester.handle_sync_message(response, "responder").await.unwrap().unwrap();
+        assert!(matches!(&next, SyncMessage::SyncRequest { request_id: Some(r), since_timestamp: Some(_), .. } if *r == id));
+    }
+
+    #[tokio::test]
+    async fn test_operation_acks() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+        let (_dir, storage) = create_test_storage();
+        let writer = SyncManager::new(storage, "writer".to_string(), None);
+        let (peer_key, peer_id) = crypto::generate_keypair();
+        let (_dir_b, storage_b) = create_test_storage();
+        let peer = SyncManager::new(storage_b, peer_id.clone(), None).with_node_key(peer_key);
+
+        let first = SignedOperation::create_and_sign(db_name.clone(), "k".into(), "1".into(), "String".into(), &owner_key);
+        let other = SignedOperation::create_and_sign(db_name.clone(), "j".into(), "1".into(), "String".into(), &owner_key);
+        writer.sync_store().add_local_operation(first.clone()).await.unwrap();
+        writer.sync_store().add_local_operation(other.clone()).await.unwrap();
+        assert_eq!(writer.sync_store().unacknowledged(&db_name), 2);
+
+        // A newer write to the key is all that's left to confirm for it
+        let mut second = SignedOperation::create_and_sign(db_name.clone(), "k".into(), "2".into(), "String".into(), &owner_key);
+        second.timestamp = first.timestamp + 1;
+        second.signature = crypto::sign_message(&owner_key, second.full_message().as_bytes());
+        writer.sync_store().add_local_operation(second.clone()).await.unwrap();
+        assert_eq!(writer.sync_store().unacknowledged(&db_name), 2);
+
+        // The peer confirms what it accepted, and a stale write it has a
+        // newer version of, in one signed ack
+        assert!(peer.take_operation_ack().is_none());
+        assert!(peer.handle_sync_message(writer.create_operation_message(second.clone()), "writer").await.unwrap().is_none());
+        assert!(peer.handle_sync_message(writer.create_operation_message(first), "writer").await.unwrap().is_none());
+        let ack = peer.take_operation_ack().unwrap();
+        assert!(matches!(&ack, SyncMessage::OperationAck { op_ids, .. } if op_ids.len() == 2));
+        assert!(ack.verify_envelope().unwrap());
+        assert!(peer.take_operation_ack().is_none());
+
+        // Unsigned or forged acks in the peer's name aren't counted
+        let SyncMessage::OperationAck { op_ids, .. } = &ack else { unreachable!() };
+        let unsigned = SyncMessage::OperationAck { from: peer_id.clone(), op_ids: op_ids.clone(), signature: None };
+        writer.handle_sync_message(unsigned, "peer").await.unwrap();
+        let mut forged = SyncMessage::OperationAck { from: peer_id.clone(), op_ids: op_ids.clone(), signature: None };
+        forged.sign_envelope(&crypto::generate_keypair().0);
+        writer.handle_sync_message(forged, "peer").await.unwrap();
+        assert_eq!(writer.sync_store().unacknowledged(&db_name), 2);
+
+        assert!(writer.handle_sync_message(ack.clone(), "peer").await.unwrap().is_none());
+        assert_eq!(writer.sync_store().unacknowledged(&db_name), 1);
+        // Repeated acks don't count again
+        writer.handle_sync_message(ack, "peer").await.unwrap();
+        let status = writer.get_stats().await.peers.into_iter().find(|p| p.peer_id == peer_id).unwrap();
+        assert_eq!(status.ops_acked, 1);
+        assert!(status.last_ack_at.is_some());
+
+        peer.handle_sync_message(writer.create_operation_message(other), "writer").await.unwrap();
+        writer.handle_sync_message(peer.take_operation_ack().unwrap(), "peer").await.unwrap();
+        assert_eq!(writer.sync_store().unacknowledged(&db_name), 0);
+    }
+
+    #[tokio::test]
+    async fn test_signed_sync_envelopes() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+        let (responder_key, responder_id) = crypto::generate_keypair();
+        let (requester_key, requester_id) = crypto::generate_keypair();
+        let (_dir, storage) = create_test_storage();
+        let responder = SyncManager::new(storage.clone(), responder_id.clone(), None).with_node_key(responder_key.clone());
+        let op = SignedOperation::create_and_sign(db_name.clone(), "k".into(), "v".into(), "String".into(), &owner_key);
+        responder.sync_store().add_operation(op).await.unwrap();
+        let (_dir_b, storage_b) = create_test_storage();
+        let requester = SyncManager::new(storage_b.clone(), requester_id.clone(), None).with_node_key(requester_key.clone());
+
+        let request = requester.create_sync_request(None);
+        assert!(request.verify_envelope().unwrap());
+        let response = responder.handle_sync_message(request.clone(), &requester_id).await.unwrap().unwrap();
+        assert!(response.verify_envelope().unwrap());
+
+        // Once a peer signed, unsigned messages in its name are dropped
+        let mut unsigned = request.clone();
+        let SyncMessage::SyncRequest { signature, .. } = &mut unsigned else { unreachable!() };
+        *signature = None;
+        assert!(responder.handle_sync_message(unsigned, &requester_id).await.unwrap().is_none());
+
+        // Tampered responses are dropped
+        let mut tampered = response.clone();
+        let SyncMessage::SyncResponse { operations, .. } = &mut tampered else { unreachable!() };
+        operations.clear();
+        assert!(tampered.verify_envelope().is_err());
+        assert!(requester.handle_sync_message(tampered, &responder_id).await.unwrap().is_none());
+        assert!(requester.sync_store().get_all_operations().await.is_empty());
+
+        // So are signed responses to requests we didn't make, e.g. replayed
+        // from before a restart
+        let (_dir_c, storage_c) = create_test_storage();
+        let earlier = SyncManager::new(storage_c, requester_id.clone(), None).with_node_key(requester_key);
+        let restarted = SyncManager::new(storage, responder_id.clone(), None).with_node_key(responder_key);
+        restarted.sync_store().load_from_storage().await.unwrap();
+        let foreign = restarted.handle_sync_message(earlier.create_sync_request(None), &requester_id).await.unwrap().unwrap();
+        assert!(matches!(&foreign, SyncMessage::SyncResponse { operations, .. } if operations.len() == 1));
+        assert!(foreign.verify_envelope().unwrap());
+        assert!(requester.handle_sync_message(foreign, &responder_id).await.unwrap().is_none());
+        assert!(requester.sync_store().get_all_operations().await.is_empty());
+
+        requester.handle_sync_message(response, &responder_id).await.unwrap();
+        assert_eq!(requester.sync_store().get_all_operations().await.len(), 1);
+        // Signing peers are remembered across restarts
+        assert_eq!(storage_b.signing_peers().unwrap(), [responder_id]);
+    }
+
+    #[tokio::test]
+    async fn test_forged_sync_response_is_rejected() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+        let (responder_key, responder_id) = crypto::generate_keypair();
+        let (requester_key, requester_id) = crypto::generate_keypair();
+        let (_dir, storage) = create_test_storage();
+        let responder = SyncManager::new(storage, responder_id.clone(), None).with_node_key(responder_key.clone());
+        let (_dir_b, storage_b) = create_test_storage();
+        let requester = SyncManager::new(storage_b.clone(), requester_id.clone(), None).with_node_key(requester_key);
+        let op = SignedOperation::create_and_sign(db_name, "k".into(), "v".into(), "String".into(), &owner_key);
+        let forged = |request_id: Option<String>| SyncMessage::SyncResponse {
+            requester: requester_id.clone(),
+            operations: vec![op.clone()],
+            has_more: false,
+            continuation_token: None,
+            compressed_operations: None,
+            responder: None,
+            remaining: None,
+            acls: Vec::new(),
+            tombstones: Vec::new(),
+            request_id,
+            signature: None,
+        };
+
+        // Unsigned, naming no responder and answering no request of ours,
+        // whether it names a made-up one or none at all
+        assert!(requester.handle_sync_message(forged(Some("made-up".into())), "mallory").await.unwrap().is_none());
+        assert!(requester.handle_sync_message(forged(None), "mallory").await.unwrap().is_none());
+        assert!(requester.sync_store().get_all_operations().await.is_empty());
+
+        // Once a peer signed, unsigned responses in its name are dropped,
+        // even to a request we did make and however they are relayed
+        let request = requester.create_sync_request(None);
+        let SyncMessage::SyncRequest { request_id, .. } = &request else { unreachable!() };
+        let request_id = request_id.clone();
+        let response = responder.handle_sync_message(request, &requester_id).await.unwrap().unwrap();
+        requester.handle_sync_message(response, "relay").await.unwrap();
+        assert_eq!(storage_b.signing_peers().unwrap(), std::slice::from_ref(&responder_id));
+        let mut impostor = forged(request_id);
+        let SyncMessage::SyncResponse { responder, .. } = &mut impostor else { unreachable!() };
+        *responder = Some(responder_id.clone());
+        assert!(requester.handle_sync_message(impostor, "mallory").await.unwrap().is_none());
+        // ... and so are signed ones that leave the request ID out
+        let mut unechoed = forged(None);
+        let SyncMessage::SyncResponse { responder, .. } = &mut unechoed else { unreachable!() };
+        *responder = Some(responder_id.clone());
+        unechoed.sign_envelope(&responder_key);
+        assert!(requester.handle_sync_message(unechoed, &responder_id).await.unwrap().is_none());
+        assert!(requester.sync_store().get_all_operations().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_legacy_sync_response_is_applied() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+        let (requester_key, requester_id) = crypto::generate_keypair();
+        let (_dir, storage) = create_test_storage();
+        let requester = SyncManager::new(storage, requester_id.clone(), None).with_node_key(requester_key);
+        let op = SignedOperation::create_and_sign(db_name, "k".into(), "v".into(), "String".into(), &owner_key);
+
+        // Older mobile builds and desktop nodes neither sign nor echo the
+        // request ID; their answers are taken while a request is open
+        requester.create_sync_request(None);
+        let legacy = SyncMessage::SyncResponse {
+            requester: requester_id,
+            operations: vec![op],
+            has_more: false,
+            continuation_token: None,
+            compressed_operations: None,
+            responder: None,
+            remaining: None,
+            acls: Vec::new(),
+            tombstones: Vec::new(),
+            request_id: None,
+            signature: None,
+        };
+        requester.handle_sync_message(legacy, "desktop").await.unwrap();
+        assert_eq!(requester.sync_store().get_all_operations().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_relayed_legacy_sync_response_is_applied() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+        let (relay_key, relay_id) = crypto::generate_keypair();
+        let (requester_key, requester_id) = crypto::generate_keypair();
+        let (_dir, storage) = create_test_storage();
+        let relay = SyncManager::new(storage, relay_id.clone(), None).with_node_key(relay_key);
+        let (_dir_b, storage_b) = create_test_storage();
+        let requester = SyncManager::new(storage_b, requester_id.clone(), None).with_node_key(requester_key);
+
+        // Our neighbor signs its own answers
+        let request = requester.create_sync_request(None);
+        let SyncMessage::SyncRequest { request_id, .. } = &request else { unreachable!() };
+        let request_id = request_id.clone();
+        let response = relay.handle_sync_message(request, &requester_id).await.unwrap().unwrap();
+        requester.handle_sync_message(response, &relay_id).await.unwrap();
+
+        // ... and relays unsigned ones of a legacy node, echoing the request
+        // ID or not
+        let legacy = |key: &str, request_id: Option<String>| SyncMessage::SyncResponse {
+            requester: requester_id.clone(),
+            operations: vec![SignedOperation::create_and_sign(db_name.clone(), key.into(), "v".into(), "String".into(), &owner_key)],
+            has_more: false,
+            continuation_token: None,
+            compressed_operations: None,
+            responder: Some("legacy".to_string()),
+            remaining: None,
+            acls: Vec::new(),
+            tombstones: Vec::new(),
+            request_id,
+            signature: None,
+        };
+        requester.handle_sync_message(legacy("a", request_id), &relay_id).await.unwrap();
+        requester.handle_sync_message(legacy("b", None), &relay_id).await.unwrap();
+        assert_eq!(requester.sync_store().get_all_operations().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_merkle_root_repairs_replica() {
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
+        let (_dir, storage) = create_test_storage();
+        let publisher = SyncManager::new(storage, owner.clone(), None);
+        let (_dir_b, storage_b) = create_test_storage();
+        let replica = SyncManager::new(storage_b.clone(), "replica".to_string(), None);
+
+        let ops: Vec<SignedOperation> = (0..8)
+            .map(|i| {
+                SignedOperation::create_and_sign(db_name.clone(), format!("k{}", i), "v".into(), "String".into(), &owner_key)
+            })
+            .collect();
+        for (i, op) in ops.iter().enumerate() {
+            assert!(publisher.sync_store().add_operation(op.clone()).await.unwrap());
+            if i % 2 == 0 {
+                assert!(replica.sync_store().add_operation(op.clone()).await.unwrap());
             }
         }
 
-        ops.insert(crdt_key, (op.timestamp, op));
-        Ok(true)
+        let roots = publisher.signed_roots(&owner_key).await.unwrap();
+        assert_eq!(roots.len(), 1);
+        let root_message = SyncMessage::MerkleRoot { root: roots[0].clone() };
+        let request = replica.handle_sync_message(root_message.clone(), &owner).await.unwrap().unwrap();
+        assert!(!replica.root_checks()[0].matches());
+        let repair = publisher.handle_sync_message(request, "replica").await.unwrap().unwrap();
+        assert!(replica.handle_sync_message(repair, &owner).await.unwrap().is_none());
+
+        assert!(replica.handle_sync_message(root_message, &owner).await.unwrap().is_none());
+        assert!(replica.root_checks()[0].matches());
+        assert_eq!(storage_b.get(&db_name, "k1").unwrap().unwrap(), b"v");
+
+        // Nodes not acting for the owner don't publish its databases
+        assert!(replica.signed_roots(&crypto::generate_keypair().0).await.unwrap().is_empty());
     }
 
-    /// Get all operations
-    pub async fn get_all_operations(&self) -> Vec<SignedOperation> {
-        self.operations
-            .read()
-            .await
-            .values()
-            .map(|(_, op)| op.clone())
-            .collect()
+    #[tokio::test]
+    async fn test_apply_snapshot_is_all_or_nothing() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (signing_key, _) = crypto::generate_keypair();
+        let signed = |key: &str| {
+            SignedOperation::create_and_sign(
+                "snapdb".to_string(),
+                key.to_string(),
+                format!("value-{}", key),
+                "String".to_string(),
+                &signing_key,
+            )
+        };
+
+        // One forged op rejects the whole snapshot
+        let mut forged = signed("k2");
+        forged.value = "tampered".to_string();
+        let result = store.apply_snapshot("snapdb", vec![signed("k1"), forged]).await;
+        assert!(result.is_err());
+        assert!(storage.get("snapdb", "k1").unwrap().is_none());
+
+        let applied = store.apply_snapshot("snapdb", vec![signed("k1"), signed("k2")]).await.unwrap();
+        assert_eq!(applied, 2);
+        assert_eq!(storage.get("snapdb", "k2").unwrap().unwrap(), b"value-k2");
+        assert_eq!(store.operation_count().await, 2);
+        assert_eq!(storage.operation_count().unwrap(), 2);
     }
 
-    /// Get operations since a timestamp
-    pub async fn get_operations_since(&self, timestamp: i64) -> Vec<SignedOperation> {
-        self.operations
-            .read()
-            .await
-            .values()
-            .filter(|(ts, _)| *ts >= timestamp)
-            .map(|(_, op)| op.clone())
-            .collect()
+    #[tokio::test]
+    async fn test_remote_ops_for_internal_trees_are_rejected() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (attacker_key, _) = crypto::generate_keypair();
+        let key_swap = SignedOperation::create_and_sign(
+            "__db_keys__".to_string(),
+            "victimdb".to_string(),
+            "0123456789abcdef0123456789abcdef".to_string(),
+            "String".to_string(),
+            &attacker_key,
+        );
+        assert!(key_swap.verify().unwrap());
+
+        assert!(!store.add_operation(key_swap.clone()).await.unwrap());
+        assert!(store.apply_snapshot("__db_keys__", vec![key_swap]).await.is_err());
+        assert_eq!(store.operation_count().await, 0);
+        assert!(storage.get_db_key("victimdb").unwrap().is_none());
     }
 
-    /// Get operations count
-    pub async fn operation_count(&self) -> usize {
-        self.operations.read().await.len()
+    #[tokio::test]
+    async fn test_snapshot_offer_is_only_taken_from_its_provider() {
+        let (_dir, storage) = create_test_storage();
+        let manager = SyncManager::new(storage, "local".to_string(), None);
+        let offer = SyncMessage::SnapshotOffer {
+            requester: "local".to_string(),
+            provider: "provider".to_string(),
+            snapshots: Vec::new(),
+        };
+
+        assert!(manager.handle_sync_message(offer.clone(), "mallory").await.unwrap().is_none());
+        // Blob transfers are off, so the provider's own offer turns into op sync
+        let response = manager.handle_sync_message(offer, "provider").await.unwrap();
+        assert!(matches!(response, Some(SyncMessage::SyncRequest { .. })));
     }
 
-    /// Merge operations from another node
-    pub async fn merge_operations(&self, operations: Vec<SignedOperation>) -> Result<usize> {
-        let mut merged_count = 0;
+    #[tokio::test]
+    async fn test_remote_write_over_local_reports_conflict() {
+        let (_dir, storage) = create_test_storage();
+        let (tx, mut rx) = mpsc::channel(16);
+        let store = SyncStore::new(storage.clone()).with_events(Some(tx));
+        store.set_retain_conflicts(true);
 
-        for op in operations {
-            if self.add_operation(op).await? {
-                merged_count += 1;
+        store.add_local_operation(test_op("local", 1000, "k", "mine")).await.unwrap();
+
+        let (signing_key, _) = crypto::generate_keypair();
+        let remote = SignedOperation::create_and_sign(
+            "testdb".to_string(),
+            "k".to_string(),
+            "theirs".to_string(),
+            "String".to_string(),
+            &signing_key,
+        );
+        assert!(store.add_operation(remote.clone()).await.unwrap());
+
+        match rx.try_recv().unwrap() {
+            NodeEvent::ConflictResolved { db_name, key, kept_op_id, discarded_op_id } => {
+                assert_eq!(db_name, "testdb");
+                assert_eq!(key, "k");
+                assert_eq!(kept_op_id, remote.op_id);
+                assert_eq!(discarded_op_id, "local");
             }
+            _ => panic!("Wrong event type"),
         }
 
-        Ok(merged_count)
+        let conflicts = store.list_conflicts("testdb").unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].1.discarded_value, "mine");
+
+        // Remote replacing remote is not a conflict
+        let newer = SignedOperation::create_and_sign(
+            "testdb".to_string(),
+            "k".to_string(),
+            "newer".to_string(),
+            "String".to_string(),
+            &signing_key,
+        );
+        store.add_operation(newer).await.unwrap();
+        assert!(rx.try_recv().is_err());
     }
 
-    /// Apply a single operation to local storage
-    pub async fn apply_to_storage(&self, op: &SignedOperation) -> Result<()> {
-        // Avoid re-applying the same operation
-        if self.is_applied(&op.op_id).await {
-            debug!(op_id = %op.op_id, "Skipping already-applied operation");
-            return Ok(());
-        }
+    #[tokio::test]
+    async fn test_db_sync_info() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        assert_eq!(store.db_sync_info("testdb").await, DbSyncInfo::default());
 
-        let full_key = format!("{}:{}", op.db_name, op.key);
+        store.add_local_operation(test_op("local", 1000, "k1", "mine")).await.unwrap();
+        let (signing_key, _) = crypto::generate_keypair();
+        let remote = SignedOperation::create_and_sign("testdb".into(), "k2".into(), "theirs".into(), "String".into(), &signing_key);
+        assert!(store.add_operation(remote).await.unwrap());
+        store.note_synced(5000).await;
 
-        match op.store_type.to_lowercase().as_str() {
-            "string" => {
-                self.storage.put(&op.db_name, &op.key, op.value.as_bytes())?;
-            }
-            "hash" => {
-                let field = op.field.as_ref().ok_or_else(|| anyhow!("Field required for Hash type"))?;
-                let hash_key = format!("{}:{}", op.key, field);
-                self.storage.put(&op.db_name, &hash_key, op.value.as_bytes())?;
-            }
-            "json" => {
-                // Store JSON as-is
-                self.storage.put(&op.db_name, &op.key, op.value.as_bytes())?;
-            }
-            _ => {
-                // Default to string storage
-                self.storage.put(&op.db_name, &op.key, op.value.as_bytes())?;
-            }
-        }
+        let info = store.db_sync_info("testdb").await;
+        assert_eq!(info.op_count, 2);
+        assert!(info.last_local_write.is_some() && info.last_remote_op.is_some());
+        assert_eq!(info.last_sync, Some(5000));
 
-        // Flush immediately to ensure persistence
-        self.storage.flush()?;
-        
-        // Mark as applied
-        self.mark_applied(&op.op_id).await;
-        info!(op_id = %op.op_id, key = %full_key, "Applied operation to storage");
-        
-        Ok(())
+        // Persisted, and the count comes from the reloaded operations
+        let reopened = SyncStore::new(storage);
+        reopened.load_from_storage().await.unwrap();
+        assert_eq!(reopened.db_sync_info("testdb").await, info);
     }
 
-    /// Apply all pending operations to storage
-    pub async fn apply_all_to_storage(&self) -> Result<usize> {
-        let operations = self.get_all_operations().await;
-        let mut applied = 0;
+    #[tokio::test]
+    async fn test_selective_sync_skips_unfollowed_databases() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        store.set_selective_sync(true).unwrap();
 
-        for op in operations {
-            if !self.is_applied(&op.op_id).await {
-                if let Err(e) = self.apply_to_storage(&op).await {
-                    error!(op_id = %op.op_id, error = %e, "Failed to apply operation");
-                } else {
-                    applied += 1;
-                }
-            }
-        }
+        let (signing_key, _) = crypto::generate_keypair();
+        let op = SignedOperation::create_and_sign(
+            "testdb".to_string(),
+            "k".to_string(),
+            "v".to_string(),
+            "String".to_string(),
+            &signing_key,
+        );
+        assert!(!store.add_operation(op.clone()).await.unwrap());
 
-        Ok(applied)
+        store.follow_database("testdb").unwrap();
+        assert!(store.add_operation(op).await.unwrap());
+
+        // Filter survives a reload
+        let reloaded = SyncStore::new(storage);
+        reloaded.load_from_storage().await.unwrap();
+        let filter = reloaded.sync_filter();
+        assert!(filter.selective);
+        assert!(filter.allows("testdb"));
+        assert!(!filter.allows("other"));
     }
-}
 
-/// Sync manager handles data synchronization across nodes
-pub struct SyncManager {
-    sync_store: Arc<SyncStore>,
-    local_node_id: String,
-}
+    #[tokio::test]
+    async fn test_local_only_database_leaves_sync_path() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        assert!(store.add_local_operation(test_op("mine", 1000, "k", "v")).await.unwrap());
+        assert!(store.add_local_operation(test_op("other", 1000, "k", "v")).await.unwrap());
 
-impl SyncManager {
-    pub fn new(storage: Arc<Storage>, local_node_id: String) -> Self {
-        Self {
-            sync_store: Arc::new(SyncStore::new(storage)),
-            local_node_id,
-        }
-    }
+        // Existing operations leave the oplog, superseded ones included;
+        // the data stays
+        storage.put("testdb", "k", b"v").unwrap();
+        store.set_local_only("testdb", true).await.unwrap();
+        assert!(store.get_all_operations().await.is_empty());
+        assert!(!storage.has_operation("mine").unwrap());
+        assert_eq!(storage.get("testdb", "k").unwrap(), Some(b"v".to_vec()));
 
-    /// Get sync store reference
-    pub fn sync_store(&self) -> Arc<SyncStore> {
-        self.sync_store.clone()
+        // Nothing enters it again, from this device or from peers
+        assert!(store.add_local_operation(test_op("later", 2000, "k", "w")).await.is_err());
+        let (signing_key, _) = crypto::generate_keypair();
+        let op = SignedOperation::create_and_sign("testdb".into(), "k".into(), "x".into(), "String".into(), &signing_key);
+        assert!(!store.add_operation(op).await.unwrap());
+
+        let reloaded = SyncStore::new(storage);
+        reloaded.load_from_storage().await.unwrap();
+        assert!(reloaded.is_local_only("testdb"));
+        reloaded.set_local_only("testdb", false).await.unwrap();
+        assert!(reloaded.add_local_operation(test_op("synced", 3000, "k", "y")).await.unwrap());
     }
 
-    /// Handle incoming sync message
-    pub async fn handle_sync_message(
-        &self,
-        msg: SyncMessage,
-        from_peer: &str,
-    ) -> Result<Option<SyncMessage>> {
-        match msg {
-            SyncMessage::SyncRequest { requester, since_timestamp } => {
-                info!(
-                    "Received sync request from {} (since: {:?})",
-                    requester, since_timestamp
-                );
+    #[tokio::test]
+    async fn test_blob_operation_needs_its_content() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage);
+        let (signing_key, public_key) = crypto::generate_keypair();
+        let content = "x".repeat(100);
+        let signature = crypto::sign_message(&signing_key, format!("testdb:photo:{}", content).as_bytes());
+        let blob = BlobRef { hash: "a".repeat(64), size: 100, provider: "node".to_string() };
+        let op = SignedOperation::new(
+            "testdb".to_string(),
+            "photo".to_string(),
+            blob.encode(),
+            BLOB_STORE_TYPE.to_string(),
+            public_key,
+            signature,
+        );
 
-                let mut operations = if let Some(ts) = since_timestamp {
-                    self.sync_store.get_operations_since(ts).await
-                } else {
-                    self.sync_store.get_all_operations().await
-                };
-                
-                // Sort by timestamp, then op_id for determinism
-                operations.sort_by(|a, b| {
-                    a.timestamp.cmp(&b.timestamp).then(a.op_id.cmp(&b.op_id))
-                });
+        assert!(op.is_blob());
+        assert!(!op.verify().unwrap());
+        assert!(op.verify_blob_content(content.as_bytes()).unwrap());
+        assert!(!op.verify_blob_content(b"tampered").unwrap());
+        // Without a blob store the content can't be had
+        assert!(!store.add_operation(op).await.unwrap());
+        assert_eq!(store.verify_failures.load(Ordering::Relaxed), 0);
+    }
 
-                // Chunk to avoid large payloads
-                let total = operations.len();
-                let chunk: Vec<SignedOperation> = operations
-                    .into_iter()
-                    .take(MAX_OPS_PER_RESPONSE)
-                    .collect();
-                    
-                let has_more = total > chunk.len();
-                let continuation_token = if has_more {
-                    let next_ts = chunk.last().map(|op| op.timestamp).unwrap_or(0);
-                    Some(format!("ts:{}", next_ts))
-                } else {
-                    None
-                };
+    #[tokio::test]
+    async fn test_expiring_operation() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (signing_key, _) = crypto::generate_keypair();
+        let now = chrono::Utc::now().timestamp_millis();
 
-                info!("Sending {} ops (has_more: {}) to {}", chunk.len(), has_more, requester);
+        let op = SignedOperation::create_and_sign_with_expiry(
+            "testdb".to_string(),
+            "presence".to_string(),
+            "online".to_string(),
+            "String".to_string(),
+            now + 60_000,
+            &signing_key,
+        );
+        assert!(op.verify().unwrap());
 
-                Ok(Some(SyncMessage::SyncResponse {
-                    requester,
-                    operations: chunk,
-                    has_more,
-                    continuation_token,
-                }))
-            }
-            
-            SyncMessage::SyncResponse { requester, operations, has_more, continuation_token } => {
-                // Only process responses intended for this node
-                if requester != self.local_node_id {
-                    debug!("Ignoring SyncResponse intended for {}", requester);
-                    return Ok(None);
-                }
+        // The expiry is covered by the signature
+        let mut extended = op.clone();
+        extended.expires_at = Some(now + 120_000);
+        assert!(!extended.verify().unwrap());
+        let mut stripped = op.clone();
+        stripped.expires_at = None;
+        assert!(!stripped.verify().unwrap());
 
-                info!(
-                    "Received sync response with {} operations from {}",
-                    operations.len(), from_peer
-                );
+        assert!(store.add_operation(op.clone()).await.unwrap());
+        store.apply_to_storage(&op).await.unwrap();
+        assert!(storage.get("testdb", "presence").unwrap().is_some());
 
-                // Merge and apply
-                let merged = self.sync_store.merge_operations(operations).await?;
-                info!("Merged {} new operations", merged);
-                
-                let _ = self.sync_store.apply_all_to_storage().await?;
+        assert_eq!(store.expire_operations(now).await.unwrap(), 0);
+        assert_eq!(store.expire_operations(now + 60_000).await.unwrap(), 1);
+        assert!(storage.get("testdb", "presence").unwrap().is_none());
+    }
 
-                // If more data is available, request next chunk
-                if has_more {
-                    if let Some(token) = continuation_token {
-                        if let Some(ts_str) = token.strip_prefix("ts:") {
-                            if let Ok(ts) = ts_str.parse::<i64>() {
-                                return Ok(Some(SyncMessage::SyncRequest {
-                                    requester: self.local_node_id.clone(),
-                                    since_timestamp: Some(ts),
-                                }));
-                            }
-                        }
-                    }
-                }
+    #[tokio::test]
+    async fn test_idempotent_writes_deduplicate() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (signing_key, public_key) = crypto::generate_keypair();
+        let now = chrono::Utc::now().timestamp_millis();
+        let write = || {
+            SignedOperation::create_and_sign_idempotent(
+                "testdb".to_string(),
+                "k".to_string(),
+                "v".to_string(),
+                "String".to_string(),
+                now,
+                &signing_key,
+            )
+        };
 
-                Ok(None)
-            }
-            
-            SyncMessage::Operation { operation } => {
-                info!(
-                    "📥 Received operation {} from {} (db: {}, key: {})",
-                    operation.op_id, from_peer, operation.db_name, operation.key
-                );
+        let op = write();
+        assert!(op.verify().unwrap());
+        assert_eq!(op.op_id, content_op_id("testdb", "k", "v", now, &public_key));
+        assert_eq!(write().op_id, op.op_id);
 
-                // Add to store (will verify signature)
-                match self.sync_store.add_operation(operation.clone()).await {
-                    Ok(true) => {
-                        info!(op_id = %operation.op_id, "✓ Operation accepted");
-                        // Apply to storage
-                        if let Err(e) = self.sync_store.apply_to_storage(&operation).await {
-                            error!(op_id = %operation.op_id, error = %e, "Failed to apply to storage");
-                        }
-                    }
-                    Ok(false) => {
-                        debug!(op_id = %operation.op_id, "⏭️ Operation rejected (duplicate or older)");
-                    }
-                    Err(e) => {
-                        error!(op_id = %operation.op_id, error = %e, "Failed to add operation");
-                    }
-                }
+        assert!(store.add_operation(op).await.unwrap());
+        assert!(!store.add_operation(write()).await.unwrap());
+        assert!(!store.add_local_operation(write()).await.unwrap());
 
-                Ok(None)
-            }
-        }
+        // Also after a restart that hasn't loaded the oplog yet
+        let restarted = SyncStore::new(storage.clone());
+        assert!(!restarted.add_operation(write()).await.unwrap());
+        assert_eq!(storage.get_all_operations().unwrap().len(), 1);
     }
 
-    /// Request full sync from a peer
-    pub fn create_sync_request(&self, since_timestamp: Option<i64>) -> SyncMessage {
-        SyncMessage::SyncRequest {
-            requester: self.local_node_id.clone(),
-            since_timestamp,
+    #[tokio::test]
+    async fn test_counter_increments_from_replicas_merge() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (signing_key, _) = crypto::generate_keypair();
+
+        let a1 = store.counter_operation("testdb", "likes", "phone", 5, &signing_key).await.unwrap();
+        assert!(store.add_local_operation(a1.clone()).await.unwrap());
+        store.apply_to_storage(&a1).await.unwrap();
+
+        // Concurrent increments on another device
+        let b1 = store.counter_operation("testdb", "likes", "tablet", 3, &signing_key).await.unwrap();
+        let a2 = store.counter_operation("testdb", "likes", "phone", -2, &signing_key).await.unwrap();
+        assert!(a2.timestamp > a1.timestamp);
+        for op in [b1, a2] {
+            assert!(store.add_operation(op.clone()).await.unwrap());
+            store.apply_to_storage(&op).await.unwrap();
         }
-    }
 
-    /// Create operation message for broadcast
-    pub fn create_operation_message(&self, op: SignedOperation) -> SyncMessage {
-        SyncMessage::Operation { operation: op }
+        // A replayed older state of a replica doesn't go backwards
+        assert!(!store.add_operation(a1).await.unwrap());
+        assert_eq!(storage.get("testdb", "likes").unwrap().unwrap(), b"6");
+
+        // The replica named in the signed value must match the field
+        let mut forged = store.counter_operation("testdb", "likes", "phone", 1, &signing_key).await.unwrap();
+        forged.field = Some("tablet".to_string());
+        assert!(!store.add_operation(forged).await.unwrap());
     }
 
-    /// Get sync statistics
-    pub async fn get_stats(&self) -> SyncStats {
-        SyncStats {
-            total_operations: self.sync_store.operation_count().await,
-            local_node_id: self.local_node_id.clone(),
-        }
+    #[tokio::test]
+    async fn test_operation_limits() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (signing_key, _) = crypto::generate_keypair();
+        store.set_operation_limits(OperationLimits { max_value_bytes: 16, max_key_len: 40, ..Default::default() });
+
+        let op = |key: &str, value: &str, store_type: &str| {
+            SignedOperation::create_and_sign(
+                "testdb".to_string(),
+                key.to_string(),
+                value.to_string(),
+                store_type.to_string(),
+                &signing_key,
+            )
+        };
+        assert!(!store.add_operation(op("k", &"v".repeat(17), "String")).await.unwrap());
+        assert!(!store.add_operation(op(&"k".repeat(41), "v", "String")).await.unwrap());
+        assert!(!store.add_operation(op("k", "v", "Graph")).await.unwrap());
+        assert_eq!(store.limit_violations(), 3);
+        assert_eq!(store.verify_failures(), 0);
+
+        // Store types match case-insensitively
+        assert!(store.add_operation(op("k", "v", "json")).await.unwrap());
+        assert_eq!(store.limit_violations(), 3);
     }
-}
 
-impl Clone for SyncManager {
-    fn clone(&self) -> Self {
-        Self {
-            sync_store: self.sync_store.clone(),
-            local_node_id: self.local_node_id.clone(),
+    #[tokio::test]
+    async fn test_text_edits_from_replicas_merge() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (_dir_b, storage_b) = create_test_storage();
+        let store_b = SyncStore::new(storage_b.clone());
+        let (signing_key, _) = crypto::generate_keypair();
+        let mut deltas = store_b.subscribe_text_deltas();
+
+        let base = store.text_operation("testdb", "note", "phone", 0, 0, "Shopping", &signing_key).unwrap().unwrap();
+        for (store, op) in [(&store, &base), (&store_b, &base)] {
+            assert!(store.add_operation(op.clone()).await.unwrap());
+            store.apply_to_storage(op).await.unwrap();
         }
-    }
-}
+        assert!(store.text_operation("testdb", "note", "phone", 3, 0, "", &signing_key).unwrap().is_none());
 
-/// Sync statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SyncStats {
-    pub total_operations: usize,
-    pub local_node_id: String,
-}
+        // Both devices edit concurrently, then exchange their edits
+        let a = store.text_operation("testdb", "note", "phone", 8, 0, " list", &signing_key).unwrap().unwrap();
+        let b = store_b.text_operation("testdb", "note", "tablet", 0, 1, "s", &signing_key).unwrap().unwrap();
+        for (store, local, remote) in [(&store, &a, &b), (&store_b, &b, &a)] {
+            for op in [local, remote] {
+                assert!(store.add_operation(op.clone()).await.unwrap());
+                store.apply_to_storage(op).await.unwrap();
+            }
+        }
+        assert_eq!(storage.get("testdb", "note").unwrap().unwrap(), b"shopping list");
+        assert_eq!(storage_b.get("testdb", "note").unwrap().unwrap(), b"shopping list");
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tempfile::tempdir;
+        let remote = std::iter::from_fn(|| deltas.try_recv().ok()).last().unwrap();
+        assert_eq!((remote.index, remote.deleted, remote.inserted.as_str(), remote.replica.as_str()), (8, 0, " list", "phone"));
 
-    fn create_test_storage() -> Storage {
-        let dir = tempdir().unwrap();
-        Storage::new(dir.path().to_path_buf()).unwrap()
+        // The edit ID named in the signed value must match the field
+        let mut forged = store.text_operation("testdb", "note", "phone", 0, 0, "!", &signing_key).unwrap().unwrap();
+        forged.field = Some("1:tablet".to_string());
+        assert!(!store.add_operation(forged).await.unwrap());
     }
 
     #[tokio::test]
-    async fn test_sync_store_lww() {
-        let storage = create_test_storage();
-        let store = SyncStore::new(storage);
+    async fn test_drop_database_tombstone() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (owner_key, owner) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("notes", &owner);
 
-        let op1 = SignedOperation {
-            op_id: "op1".to_string(),
-            timestamp: 1000,
-            db_name: "testdb".to_string(),
-            key: "key1".to_string(),
-            value: "value1".to_string(),
-            store_type: "String".to_string(),
-            field: None,
-            score: None,
-            json_path: None,
-            public_key: "a".repeat(64),
-            signature: "sig1".to_string(),
-        };
+        let op = SignedOperation::create_and_sign(
+            db_name.clone(),
+            "k".to_string(),
+            "v".to_string(),
+            "String".to_string(),
+            &owner_key,
+        );
+        assert!(store.add_operation(op.clone()).await.unwrap());
+        store.apply_to_storage(&op).await.unwrap();
 
-        let op2 = SignedOperation {
-            op_id: "op2".to_string(),
-            timestamp: 2000, // Newer
-            db_name: "testdb".to_string(),
-            key: "key1".to_string(),
-            value: "value2".to_string(),
-            store_type: "String".to_string(),
-            field: None,
-            score: None,
-            json_path: None,
-            public_key: "a".repeat(64),
-            signature: "sig2".to_string(),
-        };
+        let mut tombstone = DbTombstone::create_and_sign(&db_name, &owner_key).unwrap();
+        tombstone.dropped_at = op.timestamp;
+        tombstone.signature = crypto::sign_message(&owner_key, tombstone.signing_message().as_bytes());
+        assert!(store.apply_tombstone(tombstone.clone()).await.unwrap());
+        assert!(!store.apply_tombstone(tombstone).await.unwrap());
 
-        // Add older operation first (unverified for test)
-        store.add_operation_unverified(op1.clone()).await.unwrap();
-        
-        // Add newer operation
-        store.add_operation_unverified(op2.clone()).await.unwrap();
+        assert!(storage.get(&db_name, "k").unwrap().is_none());
+        assert!(storage.get_operation(&op.op_id).unwrap().is_none());
+        assert!(store.get_all_operations().await.is_empty());
 
-        let ops = store.get_all_operations().await;
-        assert_eq!(ops.len(), 1);
-        assert_eq!(ops[0].value, "value2"); // Newer value wins
+        // Replaying the dropped write is rejected
+        assert!(!store.add_operation(op).await.unwrap());
     }
 
     #[tokio::test]
-    async fn test_sync_message_serialization() {
-        let op = SignedOperation {
-            op_id: "test-op".to_string(),
-            timestamp: 12345,
-            db_name: "testdb".to_string(),
-            key: "key".to_string(),
-            value: "value".to_string(),
-            store_type: "String".to_string(),
-            field: None,
-            score: None,
-            json_path: None,
-            public_key: "pub".to_string(),
-            signature: "sig".to_string(),
-        };
+    async fn test_verify_storage_reports_and_repairs() {
+        let (_dir, storage) = create_test_storage();
+        let store = SyncStore::new(storage.clone());
+        let (signing_key, _) = crypto::generate_keypair();
 
-        let msg = SyncMessage::Operation { operation: op };
-        let json = serde_json::to_string(&msg).unwrap();
-        let decoded: SyncMessage = serde_json::from_str(&json).unwrap();
+        for key in ["k1", "k2", "k3"] {
+            let op = SignedOperation::create_and_sign(
+                "testdb".to_string(),
+                key.to_string(),
+                format!("value-{}", key),
+                "String".to_string(),
+                &signing_key,
+            );
+            assert!(store.add_operation(op.clone()).await.unwrap());
+            store.apply_to_storage(&op).await.unwrap();
+        }
+        assert!(store.verify_storage(false).await.unwrap().issues.is_empty());
 
-        if let SyncMessage::Operation { operation } = decoded {
-            assert_eq!(operation.op_id, "test-op");
-        } else {
-            panic!("Wrong message type");
+        storage.delete("testdb", "k1").unwrap();
+        storage.put("testdb", "k2", b"corrupted").unwrap();
+        store.add_operation_unverified(test_op("forged", 1000, "k4", "v")).await.unwrap();
+
+        let report = store.verify_storage(false).await.unwrap();
+        assert_eq!(report.checked, 4);
+        let mut kinds: Vec<(String, IntegrityIssueKind)> =
+            report.issues.into_iter().map(|issue| (issue.key, issue.kind)).collect();
+        kinds.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            kinds,
+            [
+                ("k1".to_string(), IntegrityIssueKind::Missing),
+                ("k2".to_string(), IntegrityIssueKind::Mismatch),
+                ("k4".to_string(), IntegrityIssueKind::InvalidSignature),
+            ]
+        );
+
+        let report = store.verify_storage(true).await.unwrap();
+        assert_eq!(report.repaired, 2);
+        assert_eq!(storage.get("testdb", "k1").unwrap().unwrap(), b"value-k1");
+        assert_eq!(storage.get("testdb", "k2").unwrap().unwrap(), b"value-k2");
+        assert_eq!(store.verify_storage(false).await.unwrap().issues.len(), 1);
+    }
+
+    #[test]
+    fn test_sync_request_without_compression_flag_decodes() {
+        // Older nodes don't send accept_compression
+        let json = r#"{"type":"SyncRequest","requester":"node1","since_timestamp":null}"#;
+        let decoded: SyncMessage = serde_json::from_str(json).unwrap();
+
+        match decoded {
+            SyncMessage::SyncRequest { accept_compression, .. } => assert!(!accept_compression),
+            _ => panic!("Wrong message type"),
         }
     }
 }
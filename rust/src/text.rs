@@ -0,0 +1,366 @@
+//! Collaborative text
+//!
+//! `Text` values are a replicated growable array (RGA): every character has
+//! an id (Lamport counter, replica) and is inserted after another character,
+//! deletions only mark characters as gone. Each local edit becomes one
+//! operation keyed by (key, edit id), so LWW keeps all of them like counter
+//! replicas, and replaying every edit gives the same text on every device no
+//! matter the order they arrived in. The text is materialized under the plain
+//! key, the edits under `__text__:<key>:<edit id>`.
+//!
+//! Positions are in characters (Unicode scalar values).
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{Storage, MAX_SCAN_LIMIT};
+use crate::store_types::{ApplyContext, ApplyHandler, StoreType};
+use crate::sync::SignedOperation;
+
+/// Store type of collaborative text operations
+pub const TEXT_STORE_TYPE: &str = "Text";
+
+/// Edits are stored under `__text__:<key>:<edit id>`; the text under `<key>`
+pub const TEXT_KEY_PREFIX: &str = "__text__:";
+
+/// Longest text a single edit may insert
+const MAX_INSERT_CHARS: usize = 64 * 1024;
+
+/// Most characters a single edit may delete
+const MAX_DELETE_CHARS: u64 = 1024 * 1024;
+
+/// Highest counter an edit may use, far enough below `u64::MAX` that
+/// counters never overflow
+const MAX_COUNTER: u64 = u64::MAX / 2;
+
+/// Identity of a character, ordered by counter then replica
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CharId {
+    pub counter: u64,
+    pub replica: String,
+}
+
+/// Characters `start..start + len` inserted by `replica`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharSpan {
+    pub replica: String,
+    pub start: u64,
+    pub len: u64,
+}
+
+/// One edit of a text: a run of characters inserted after `after` (`None`
+/// = at the start), which get counters from `counter` on, and characters
+/// deleted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextEdit {
+    pub replica: String,
+    pub counter: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<CharId>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub insert: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub delete: Vec<CharSpan>,
+}
+
+impl TextEdit {
+    /// Unique within the text, used as operation field
+    pub fn edit_id(&self) -> String {
+        format!("{}:{}", self.counter, self.replica)
+    }
+
+    /// Highest counter the edit uses
+    fn last_counter(&self) -> u64 {
+        self.counter + (self.insert.chars().count() as u64).saturating_sub(1)
+    }
+
+    /// Whether the edit is small enough to replay and its counters can't
+    /// overflow
+    pub fn check(&self) -> Result<()> {
+        if self.insert.chars().count() > MAX_INSERT_CHARS {
+            return Err(anyhow!("Edit inserts more than {} characters", MAX_INSERT_CHARS));
+        }
+        let deleted = self.delete.iter().try_fold(0u64, |total, span| total.checked_add(span.len));
+        if deleted.is_none_or(|deleted| deleted > MAX_DELETE_CHARS) {
+            return Err(anyhow!("Edit deletes more than {} characters", MAX_DELETE_CHARS));
+        }
+        if self.counter > MAX_COUNTER || self.delete.iter().any(|span| span.start > MAX_COUNTER) {
+            return Err(anyhow!("Edit counters are above {}", MAX_COUNTER));
+        }
+        Ok(())
+    }
+}
+
+/// Change of a text, as position edit: `deleted` characters at `index`
+/// replaced by `inserted`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextDelta {
+    pub db_name: String,
+    pub key: String,
+    pub index: u64,
+    pub deleted: u64,
+    pub inserted: String,
+    /// Node ID of the device whose edit caused the change
+    pub replica: String,
+}
+
+impl TextDelta {
+    /// The edit turning `old` into `new`, `None` if they're equal
+    pub fn between(old: &str, new: &str) -> Option<(u64, u64, String)> {
+        if old == new {
+            return None;
+        }
+        let old: Vec<char> = old.chars().collect();
+        let new: Vec<char> = new.chars().collect();
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let inserted = new[prefix..new.len() - suffix].iter().collect();
+        Some((prefix as u64, (old.len() - suffix - prefix) as u64, inserted))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Char {
+    id: CharId,
+    value: char,
+    deleted: bool,
+}
+
+/// A text rebuilt from its edits
+#[derive(Debug, Clone, Default)]
+pub struct TextDocument {
+    /// In document order, deleted characters included
+    chars: Vec<Char>,
+    /// Highest counter seen, the next local edit goes above it
+    max_counter: u64,
+}
+
+impl TextDocument {
+    /// Replay `edits` in any order. An edit inserting after a character
+    /// that isn't known yet is left out until that one arrives.
+    pub fn from_edits(mut edits: Vec<TextEdit>) -> Self {
+        // Characters are always inserted after lower counters
+        edits.sort_by(|a, b| (a.counter, &a.replica).cmp(&(b.counter, &b.replica)));
+        let mut document = Self::default();
+        let mut deleted = HashSet::new();
+        for edit in &edits {
+            document.max_counter = document.max_counter.max(edit.last_counter());
+            document.insert(edit);
+            for span in &edit.delete {
+                for counter in span.start..span.start.saturating_add(span.len) {
+                    deleted.insert(CharId { counter, replica: span.replica.clone() });
+                }
+            }
+        }
+        for c in &mut document.chars {
+            c.deleted = deleted.contains(&c.id);
+        }
+        document
+    }
+
+    fn insert(&mut self, edit: &TextEdit) {
+        if edit.insert.is_empty() {
+            return;
+        }
+        let mut index = match &edit.after {
+            None => 0,
+            Some(after) => match self.chars.iter().position(|c| &c.id == after) {
+                Some(position) => position + 1,
+                None => return,
+            },
+        };
+        // Concurrent inserts at the same place: the newer one goes first
+        let first = CharId { counter: edit.counter, replica: edit.replica.clone() };
+        while index < self.chars.len() && self.chars[index].id.cmp(&first) == Ordering::Greater {
+            index += 1;
+        }
+        let run = edit.insert.chars().enumerate().map(|(offset, value)| Char {
+            id: CharId { counter: edit.counter + offset as u64, replica: edit.replica.clone() },
+            value,
+            deleted: false,
+        });
+        self.chars.splice(index..index, run);
+    }
+
+    pub fn text(&self) -> String {
+        self.chars.iter().filter(|c| !c.deleted).map(|c| c.value).collect()
+    }
+
+    /// The edit by `replica` deleting `delete` characters at `index` and
+    /// inserting `insert` there; `None` if it changes nothing
+    pub fn edit(&self, replica: &str, index: u64, delete: u64, insert: &str) -> Result<Option<TextEdit>> {
+        if insert.chars().count() > MAX_INSERT_CHARS {
+            return Err(anyhow!("Can't insert more than {} characters at once", MAX_INSERT_CHARS));
+        }
+        if delete > MAX_DELETE_CHARS {
+            return Err(anyhow!("Can't delete more than {} characters at once", MAX_DELETE_CHARS));
+        }
+        let visible: Vec<&CharId> = self.chars.iter().filter(|c| !c.deleted).map(|c| &c.id).collect();
+        let (index, delete) = (index as usize, delete as usize);
+        if index.checked_add(delete).is_none_or(|end| end > visible.len()) {
+            return Err(anyhow!("Edit at {}..{} is outside the text ({} characters)", index, index.saturating_add(delete), visible.len()));
+        }
+        if delete == 0 && insert.is_empty() {
+            return Ok(None);
+        }
+
+        let mut spans: Vec<CharSpan> = Vec::new();
+        for id in &visible[index..index + delete] {
+            match spans.last_mut() {
+                Some(span) if span.replica == id.replica && span.start + span.len == id.counter => span.len += 1,
+                _ => spans.push(CharSpan { replica: id.replica.clone(), start: id.counter, len: 1 }),
+            }
+        }
+        Ok(Some(TextEdit {
+            replica: replica.to_string(),
+            counter: self.max_counter + 1,
+            after: index.checked_sub(1).map(|previous| visible[previous].clone()),
+            insert: insert.to_string(),
+            delete: spans,
+        }))
+    }
+}
+
+/// A text rebuilt from its stored edits
+pub fn document(storage: &Storage, db_name: &str, key: &str) -> Result<TextDocument> {
+    let prefix = format!("{}{}:", TEXT_KEY_PREFIX, key);
+    let mut edits = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = storage.scan_prefix(db_name, &prefix, MAX_SCAN_LIMIT, cursor.as_deref())?;
+        edits.extend(
+            page.entries
+                .iter()
+                .filter_map(|(_, value)| serde_json::from_slice::<TextEdit>(value).ok())
+                .filter(|edit| edit.check().is_ok()),
+        );
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    Ok(TextDocument::from_edits(edits))
+}
+
+pub struct TextHandler;
+
+impl ApplyHandler for TextHandler {
+    fn store_type(&self) -> StoreType {
+        StoreType::Text
+    }
+
+    fn validate(&self, op: &SignedOperation) -> Result<()> {
+        op.text_edit()?.check()
+    }
+
+    fn storage_key(&self, op: &SignedOperation) -> Result<String> {
+        let edit_id = op.field.as_ref().ok_or_else(|| anyhow!("Edit ID required for Text type"))?;
+        Ok(format!("{}{}:{}", TEXT_KEY_PREFIX, op.key, edit_id))
+    }
+
+    /// Recompute the text from its edits, store it under the text key and
+    /// announce the change, caused by the replica of `op`
+    fn materialize(&self, ctx: &ApplyContext<'_>, op: &SignedOperation) -> Result<()> {
+        let (db_name, key) = (op.db_name.as_str(), op.key.as_str());
+        let text = document(ctx.storage, db_name, key)?.text();
+        let old = ctx.storage.get(db_name, key)?.map(|value| String::from_utf8_lossy(&value).into_owned());
+        if let Some((index, deleted, inserted)) = TextDelta::between(old.as_deref().unwrap_or_default(), &text) {
+            ctx.storage.put(db_name, key, text.as_bytes())?;
+            let _ = ctx.text_deltas.send(TextDelta {
+                db_name: db_name.to_string(),
+                key: key.to_string(),
+                index,
+                deleted,
+                inserted,
+                replica: op.text_edit()?.replica,
+            });
+        } else if old.is_none() {
+            ctx.storage.put(db_name, key, text.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(edits: &mut Vec<TextEdit>, replica: &str, index: u64, delete: u64, insert: &str) -> TextEdit {
+        let edit = TextDocument::from_edits(edits.clone()).edit(replica, index, delete, insert).unwrap().unwrap();
+        edits.push(edit.clone());
+        edit
+    }
+
+    #[test]
+    fn test_concurrent_edits_converge() {
+        let mut base = Vec::new();
+        apply(&mut base, "a", 0, 0, "Hello world");
+        assert_eq!(TextDocument::from_edits(base.clone()).text(), "Hello world");
+
+        // Two devices edit the same version concurrently
+        let mut a = base.clone();
+        let from_a = apply(&mut a, "a", 5, 0, ", dear");
+        let mut b = base.clone();
+        let from_b = [apply(&mut b, "b", 5, 6, ""), apply(&mut b, "b", 5, 0, "!")];
+        assert_eq!(TextDocument::from_edits(b.clone()).text(), "Hello!");
+
+        a.extend(from_b.iter().cloned());
+        b.push(from_a);
+        let merged = TextDocument::from_edits(a.clone()).text();
+        assert_eq!(merged, TextDocument::from_edits(b.iter().rev().cloned().collect()).text());
+        assert!(merged == "Hello!, dear" || merged == "Hello, dear!", "{}", merged);
+
+        // Inserts at the same position keep both, in the same order everywhere
+        let mut c = base.clone();
+        let first = apply(&mut c, "c", 0, 0, "x");
+        let mut d = base;
+        let second = apply(&mut d, "d", 0, 0, "y");
+        c.push(second);
+        d.push(first);
+        assert_eq!(TextDocument::from_edits(c).text(), TextDocument::from_edits(d).text());
+
+        // An edit whose anchor is missing waits for it
+        let orphan = TextEdit { after: Some(CharId { counter: 99, replica: "z".into() }), ..from_b[1].clone() };
+        assert_eq!(TextDocument::from_edits(vec![orphan]).text(), "");
+
+        let document = TextDocument::from_edits(a);
+        assert!(document.edit("a", 100, 0, "x").is_err());
+        assert!(document.edit("a", u64::MAX, 1, "").is_err());
+        assert!(document.edit("a", 0, 0, "").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_edit_bounds() {
+        let mut edits = Vec::new();
+        let edit = apply(&mut edits, "a", 0, 0, "Hello");
+        assert!(edit.check().is_ok());
+        assert!(apply(&mut edits, "a", 0, 5, "").check().is_ok());
+
+        let huge_delete = TextEdit { delete: vec![CharSpan { replica: "a".into(), start: 0, len: u64::MAX }], ..edit.clone() };
+        assert!(huge_delete.check().is_err());
+        let split_delete = TextEdit {
+            delete: vec![CharSpan { replica: "a".into(), start: 0, len: u64::MAX / 2 + 1 }; 2],
+            ..edit.clone()
+        };
+        assert!(split_delete.check().is_err());
+        let overflowing = TextEdit { counter: u64::MAX, ..edit.clone() };
+        assert!(overflowing.check().is_err());
+        assert!(TextEdit { insert: "x".repeat(MAX_INSERT_CHARS + 1), ..edit }.check().is_err());
+    }
+
+    #[test]
+    fn test_delta_between_texts() {
+        assert_eq!(TextDelta::between("abc", "abc"), None);
+        assert_eq!(TextDelta::between("Hello world", "Hello, world"), Some((5, 0, ",".into())));
+        assert_eq!(TextDelta::between("aaa", "aa"), Some((2, 1, String::new())));
+        assert_eq!(TextDelta::between("héllo", "hallo"), Some((1, 1, "a".into())));
+    }
+}
@@ -0,0 +1,90 @@
+//! Node tickets for out-of-band pairing
+//!
+//! A ticket carries everything needed to dial a node without gossip
+//! discovery: its node ID, some of its direct addresses and its relay URL.
+//! It is encoded as one short string (`TICKET_PREFIX` followed by base64url
+//! postcard) that fits in a QR code and can be passed to `connect_peer` in
+//! place of a node ID.
+
+use std::fmt;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use base64::Engine;
+use iroh::{EndpointAddr, EndpointId, TransportAddr};
+use serde::{Deserialize, Serialize};
+
+/// Start of every ticket string
+pub const TICKET_PREFIX: &str = "cyberfly";
+
+/// Most direct addresses put in a ticket, to keep its QR code readable
+pub const MAX_TICKET_ADDRS: usize = 6;
+
+/// Where a node can be dialed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeTicket {
+    pub node_id: EndpointId,
+    /// IPv4 first
+    pub addresses: Vec<SocketAddr>,
+    pub relay_url: Option<String>,
+}
+
+impl NodeTicket {
+    /// Ticket of a node at `addr`
+    pub fn new(addr: &EndpointAddr) -> Self {
+        let mut addresses: Vec<SocketAddr> = addr.ip_addrs().copied().collect();
+        addresses.sort_by_key(SocketAddr::is_ipv6);
+        addresses.truncate(MAX_TICKET_ADDRS);
+        let relay_url = addr.relay_urls().next().map(|url| url.to_string());
+        Self { node_id: addr.id, addresses, relay_url }
+    }
+
+    /// Address to dial the node at
+    pub fn endpoint_addr(&self) -> EndpointAddr {
+        let ips = self.addresses.iter().copied().map(TransportAddr::Ip);
+        let relay = self.relay_url.as_deref().and_then(|url| url.parse().ok()).map(TransportAddr::Relay);
+        EndpointAddr::from_parts(self.node_id, ips.chain(relay))
+    }
+}
+
+impl fmt::Display for NodeTicket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = postcard::to_allocvec(self).map_err(|_| fmt::Error)?;
+        write!(f, "{}{}", TICKET_PREFIX, base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes))
+    }
+}
+
+impl FromStr for NodeTicket {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let encoded = s.trim().strip_prefix(TICKET_PREFIX).ok_or_else(|| anyhow!("Not a node ticket"))?;
+        let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|e| anyhow!("Invalid node ticket: {}", e))?;
+        postcard::from_bytes(&bytes).map_err(|e| anyhow!("Invalid node ticket: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticket_roundtrip() {
+        let ticket = NodeTicket {
+            node_id: iroh::SecretKey::generate().public(),
+            addresses: vec!["203.0.113.7:4433".parse().unwrap(), "[2001:db8::1]:4433".parse().unwrap()],
+            relay_url: Some("https://relay.example.com/".to_string()),
+        };
+        let encoded = ticket.to_string();
+        assert!(encoded.starts_with(TICKET_PREFIX));
+        assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(encoded.parse::<NodeTicket>().unwrap(), ticket);
+
+        // A node ID alone is not a ticket
+        assert!(ticket.node_id.to_string().parse::<NodeTicket>().is_err());
+        assert!(format!("{}!!", TICKET_PREFIX).parse::<NodeTicket>().is_err());
+    }
+}
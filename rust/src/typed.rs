@@ -0,0 +1,296 @@
+//! Typed store values
+//!
+//! Builds the `SignedOperation` of a write for each store type, so apps don't
+//! assemble fields and signatures themselves, and reads the values back.
+//! Every collection element is its own operation keyed by (key, field) and
+//! stored under `<key>:<field>`, like hash fields, so concurrent writes on
+//! different devices add up instead of replacing the collection:
+//!
+//! - List: one element per push, the field orders elements by write time
+//! - Set: the member is the field
+//! - SortedSet: the member is the field, the score is in the value
+//! - TimeSeries: the zero-padded timestamp is the field
+//! - Geo: the member is the field, the coordinates are in the value
+//!
+//! Scores, timestamps and coordinates are also set on the operation for the
+//! desktop node, but only the value is signed, so reads use the value.
+
+use anyhow::{anyhow, Result};
+use ed25519_dalek::SigningKey;
+use serde::{Deserialize, Serialize};
+
+use crate::crypto;
+use crate::storage::{Storage, MAX_SCAN_LIMIT};
+use crate::store_types::{ApplyHandler, StoreType};
+use crate::sync::SignedOperation;
+
+/// Mean Earth radius used for distances, in meters
+const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+/// A member of a sorted set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoredMember {
+    pub member: String,
+    pub score: f64,
+}
+
+/// A time series sample
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimePoint {
+    /// Unix ms
+    pub timestamp: i64,
+    pub value: f64,
+}
+
+/// A member of a geo set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeoMember {
+    pub member: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// A write of one store type
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedWrite {
+    String { value: String },
+    /// A JSON object, replacing the whole document
+    Json { value: String },
+    Hash { field: String, value: String },
+    List { value: String },
+    Set { member: String },
+    SortedSet(ScoredMember),
+    TimeSeries(TimePoint),
+    Geo(GeoMember),
+}
+
+impl TypedWrite {
+    fn store_type(&self) -> StoreType {
+        match self {
+            TypedWrite::String { .. } => StoreType::String,
+            TypedWrite::Json { .. } => StoreType::Json,
+            TypedWrite::Hash { .. } => StoreType::Hash,
+            TypedWrite::List { .. } => StoreType::List,
+            TypedWrite::Set { .. } => StoreType::Set,
+            TypedWrite::SortedSet(_) => StoreType::SortedSet,
+            TypedWrite::TimeSeries(_) => StoreType::TimeSeries,
+            TypedWrite::Geo(_) => StoreType::Geo,
+        }
+    }
+
+    /// The operation writing this to `key`, signed by `signing_key`
+    pub fn sign(self, db_name: &str, key: &str, signing_key: &SigningKey) -> Result<SignedOperation> {
+        if key.is_empty() {
+            return Err(anyhow!("Key is empty"));
+        }
+        let mut op = SignedOperation::new(
+            db_name.to_string(),
+            key.to_string(),
+            String::new(),
+            self.store_type().as_str().to_string(),
+            crypto::public_key_hex(signing_key),
+            String::new(),
+        );
+        match self {
+            TypedWrite::String { value } => op.value = value,
+            TypedWrite::Json { value } => {
+                let document: serde_json::Value = serde_json::from_str(&value)?;
+                if !document.is_object() {
+                    return Err(anyhow!("JSON value must be an object"));
+                }
+                op.value = document.to_string();
+            }
+            TypedWrite::Hash { field, value } => {
+                op.field = Some(field);
+                op.value = value;
+            }
+            TypedWrite::List { value } => {
+                // Sorts by write time; the op ID breaks ties
+                op.field = Some(format!("{:013}-{}", op.timestamp, &op.op_id[..8]));
+                op.value = value;
+            }
+            TypedWrite::Set { member } => {
+                op.field = Some(member.clone());
+                op.value = member;
+            }
+            TypedWrite::SortedSet(scored) => {
+                if !scored.score.is_finite() {
+                    return Err(anyhow!("Score must be a finite number"));
+                }
+                op.field = Some(scored.member.clone());
+                op.score = Some(scored.score);
+                op.value = serde_json::to_string(&scored)?;
+            }
+            TypedWrite::TimeSeries(point) => {
+                if point.timestamp < 0 || !point.value.is_finite() {
+                    return Err(anyhow!("Samples need a timestamp after 1970 and a finite value"));
+                }
+                op.field = Some(format!("{:013}", point.timestamp));
+                op.ts_timestamp = Some(point.timestamp.to_string());
+                op.value = serde_json::to_string(&point)?;
+            }
+            TypedWrite::Geo(geo) => {
+                if !(-90.0..=90.0).contains(&geo.latitude) || !(-180.0..=180.0).contains(&geo.longitude) {
+                    return Err(anyhow!("Coordinates out of range"));
+                }
+                op.field = Some(geo.member.clone());
+                op.latitude = Some(geo.latitude);
+                op.longitude = Some(geo.longitude);
+                op.value = serde_json::to_string(&geo)?;
+            }
+        }
+        if op.field.as_ref().is_some_and(|field| field.is_empty()) {
+            return Err(anyhow!("Field or member is empty"));
+        }
+        op.signature = crypto::sign_message(signing_key, op.full_message().as_bytes());
+        Ok(op)
+    }
+}
+
+/// List, Set, SortedSet, TimeSeries and Geo: one operation per element
+/// stored under `<key>:<field>`. Older writers put the whole collection
+/// under the key.
+pub struct CollectionHandler(pub StoreType);
+
+impl ApplyHandler for CollectionHandler {
+    fn store_type(&self) -> StoreType {
+        self.0
+    }
+
+    fn storage_key(&self, op: &SignedOperation) -> Result<String> {
+        match &op.field {
+            Some(field) => Ok(format!("{}:{}", op.key, field)),
+            None => Ok(op.key.clone()),
+        }
+    }
+}
+
+/// Elements of the collection at `key` as (field, value), in field order
+pub fn collection(storage: &Storage, db_name: &str, key: &str) -> Result<Vec<(String, Vec<u8>)>> {
+    let prefix = format!("{}:", key);
+    let mut elements = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = storage.scan_prefix(db_name, &prefix, MAX_SCAN_LIMIT, cursor.as_deref())?;
+        elements.extend(
+            page.entries
+                .into_iter()
+                .filter_map(|(storage_key, value)| Some((storage_key.strip_prefix(&prefix)?.to_string(), value))),
+        );
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    Ok(elements)
+}
+
+/// Values of a collection as text, e.g. list elements in push order
+pub fn values(elements: Vec<(String, Vec<u8>)>) -> Vec<String> {
+    elements.into_iter().map(|(_, value)| String::from_utf8_lossy(&value).into_owned()).collect()
+}
+
+/// Members of a sorted set with a score in `min..=max`, lowest first
+pub fn sorted_members(elements: Vec<(String, Vec<u8>)>, min: f64, max: f64) -> Vec<ScoredMember> {
+    let mut members: Vec<ScoredMember> = parse(elements)
+        .into_iter()
+        .filter(|scored: &ScoredMember| scored.score >= min && scored.score <= max)
+        .collect();
+    members.sort_by(|a, b| a.score.total_cmp(&b.score).then_with(|| a.member.cmp(&b.member)));
+    members
+}
+
+/// Samples of a time series in `from..=to` (Unix ms), oldest first
+pub fn samples(elements: Vec<(String, Vec<u8>)>, from: i64, to: i64) -> Vec<TimePoint> {
+    // Fields are zero-padded timestamps, so elements are in time order
+    parse(elements)
+        .into_iter()
+        .filter(|point: &TimePoint| point.timestamp >= from && point.timestamp <= to)
+        .collect()
+}
+
+/// Members of a geo set within `radius_m` of a point with their distance in
+/// meters, nearest first
+pub fn members_within(
+    elements: Vec<(String, Vec<u8>)>,
+    latitude: f64,
+    longitude: f64,
+    radius_m: f64,
+) -> Vec<(GeoMember, f64)> {
+    let mut members: Vec<(GeoMember, f64)> = parse(elements)
+        .into_iter()
+        .map(|geo: GeoMember| {
+            let distance = distance_m(latitude, longitude, geo.latitude, geo.longitude);
+            (geo, distance)
+        })
+        .filter(|(_, distance)| *distance <= radius_m)
+        .collect();
+    members.sort_by(|a, b| a.1.total_cmp(&b.1));
+    members
+}
+
+fn parse<T: for<'de> Deserialize<'de>>(elements: Vec<(String, Vec<u8>)>) -> Vec<T> {
+    elements.into_iter().filter_map(|(_, value)| serde_json::from_slice(&value).ok()).collect()
+}
+
+/// Great-circle (haversine) distance in meters
+fn distance_m(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lon2 - lon1).to_radians();
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_M * a.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_typed_writes_and_reads() {
+        let dir = tempdir().unwrap();
+        let storage = Storage::new(dir.path().join("db")).unwrap();
+        let (signing_key, public_key) = crypto::generate_keypair();
+        let db_name = crypto::generate_db_name("places", &public_key);
+        let write = |key: &str, write: TypedWrite| {
+            let op = write.sign(&db_name, key, &signing_key).unwrap();
+            assert!(op.verify().unwrap());
+            storage.put(&db_name, &op.storage_key().unwrap(), op.value.as_bytes()).unwrap();
+        };
+
+        write("todo", TypedWrite::List { value: "milk".into() });
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        write("todo", TypedWrite::List { value: "eggs".into() });
+        write("tags", TypedWrite::Set { member: "b".into() });
+        write("tags", TypedWrite::Set { member: "a".into() });
+        write("tags", TypedWrite::Set { member: "a".into() });
+        for (member, score) in [("carol", 7.5), ("alice", 3.0), ("bob", 12.0)] {
+            write("scores", TypedWrite::SortedSet(ScoredMember { member: member.into(), score }));
+        }
+        for timestamp in [3_000, 1_000, 2_000] {
+            write("temp", TypedWrite::TimeSeries(TimePoint { timestamp, value: timestamp as f64 / 100.0 }));
+        }
+        let cafes = [("louvre", 48.8606, 2.3376), ("orsay", 48.8600, 2.3266), ("versailles", 48.8049, 2.1204)];
+        for (member, latitude, longitude) in cafes {
+            write("cafes", TypedWrite::Geo(GeoMember { member: member.into(), latitude, longitude }));
+        }
+
+        let read = |key: &str| collection(&storage, &db_name, key).unwrap();
+        assert_eq!(values(read("todo")), ["milk", "eggs"]);
+        assert_eq!(values(read("tags")), ["a", "b"]);
+        let ranked: Vec<String> = sorted_members(read("scores"), 0.0, 10.0).into_iter().map(|m| m.member).collect();
+        assert_eq!(ranked, ["alice", "carol"]);
+        let series: Vec<i64> = samples(read("temp"), 1_500, 3_000).into_iter().map(|p| p.timestamp).collect();
+        assert_eq!(series, [2_000, 3_000]);
+        let near = members_within(read("cafes"), 48.8584, 2.2945, 5_000.0);
+        assert_eq!(near.iter().map(|(geo, _)| geo.member.as_str()).collect::<Vec<_>>(), ["orsay", "louvre"]);
+        assert!((near[0].1 - 2_360.0).abs() < 100.0, "{}", near[0].1);
+
+        let bad = |write: TypedWrite| write.sign(&db_name, "k", &signing_key).is_err();
+        assert!(bad(TypedWrite::Json { value: "[1, 2]".into() }));
+        assert!(bad(TypedWrite::SortedSet(ScoredMember { member: "x".into(), score: f64::NAN })));
+        assert!(bad(TypedWrite::Geo(GeoMember { member: "x".into(), latitude: 91.0, longitude: 0.0 })));
+        assert!(bad(TypedWrite::Hash { field: String::new(), value: "v".into() }));
+    }
+}